@@ -0,0 +1,596 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Backups of the MVCC data of a range as of a timestamp, and their restore.
+//!
+//! Each brane of the range is read from a snapshot of its leader, taken once
+//! the leader applied everything committed before. `max_ts` is pushed to the
+//! backup's timestamp first, so that nothing commits at or before it later,
+//! and the locks of the transactions that still may are resolved. A brane's
+//! share goes to an SST file per causet_merge family, of the MVCC soliton_ids
+//! without the data prefix; the manifest is written once every file is.
+//!
+//! A restore cuts the files along the branes of the cluster and ingests each
+//! piece through the brane's log, so that every replica has it: the values
+//! before the write records pointing at them.
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use fdb_traits::{Compression, SnapshotExt, SstWriter};
+use pd::tso::extract_physical;
+use pd::PdClient;
+use sha2::{Digest, Sha256};
+use soliton_lsm::{LsmOptions, LsmSnapshot, LsmSstReader, LsmSstWriter};
+use txn::{
+    append_ts, decode_key, encode_key, split_ts, ClusterEngine, Lock, LockInfo, LockType, Snapshot,
+    Storage, StoreSnapshot, TxnClient, Write, WriteType, CF_DEFAULT, CF_LOCK, CF_WRITE,
+};
+use violetabft::Codec;
+use violetabftstore::cluster::Cluster;
+use violetabftstore::{keys, Brane};
+
+use crate::errors::{Error, Result};
+use crate::manifest::{hex, BackupFile, BackupManifest};
+use crate::storage::ExternalStorage;
+
+const BACKOFF: Duration = Duration::from_millis(5);
+const MAX_BACKOFFS: usize = 400;
+/// How long a restore waits for the TSO to pass the backup's timestamp.
+const MAX_TSO_LAG: Duration = Duration::from_secs(10);
+
+/// What a backup holds.
+#[derive(Clone, Debug, Default)]
+pub struct BackupRequest {
+    /// The soliton_ids `[start_key, end_key)`; an empty end is unbounded.
+    pub start_key: Vec<u8>,
+    pub end_key: Vec<u8>,
+    /// The changes committed after it are backed up, deletes included; 0 for a
+    /// full backup, of the causet_locales as of `backup_ts`.
+    pub start_ts: u64,
+    /// The timestamp the backup is consistent at; one taken from the TSO if 0.
+    pub backup_ts: u64,
+}
+
+/// The part of `[start, end)` in `brane`; empty ends are unbounded.
+fn intersect(start: &[u8], end: &[u8], brane: &Brane) -> (Vec<u8>, Vec<u8>) {
+    let start = start.max(&brane.start_key[..]).to_vec();
+    let end = match (end.is_empty(), brane.end_key.is_empty()) {
+        (true, _) => brane.end_key.clone(),
+        (false, true) => end.to_vec(),
+        (false, false) => end.min(&brane.end_key[..]).to_vec(),
+    };
+    (start, end)
+}
+
+/// The locks in `[start, end)` of transactions that may commit at or before
+/// `ts`.
+fn blocking_locks(
+    snap: &StoreSnapshot,
+    (start, end): (&[u8], &[u8]),
+    ts: u64,
+) -> Result<Vec<LockInfo>> {
+    let mut locks = Vec::new();
+    for (k, v) in snap.scan_cf(CF_LOCK, start, end, 0)? {
+        let lock = Lock::decode(&v)?;
+        if lock.start_ts > ts
+            || lock.min_commit_ts > ts
+            || matches!(lock.lock_type, LockType::Lock | LockType::Pessimistic)
+        {
+            continue;
+        }
+        locks.push(LockInfo {
+            soliton_id: decode_key(&k)?.0,
+            lock,
+        });
+    }
+    Ok(locks)
+}
+
+/// The entries of the write and default causet_merge families in `[start,
+/// end)` that a backup from `start_ts` to `backup_ts` holds, each in order.
+#[allow(clippy::type_complexity)]
+fn collect_range(
+    snap: &StoreSnapshot,
+    (start, end): (&[u8], &[u8]),
+    start_ts: u64,
+    backup_ts: u64,
+) -> Result<(Vec<(Vec<u8>, Vec<u8>)>, Vec<(Vec<u8>, Vec<u8>)>)> {
+    let (mut writes, mut values) = (Vec::new(), Vec::new());
+    // The soliton_id whose version as of `backup_ts` a full backup found.
+    let mut last: Option<Vec<u8>> = None;
+    for (k, v) in snap.scan_cf(CF_WRITE, start, end, 0)? {
+        let (encoded, commit_ts) = split_ts(&k)?;
+        if commit_ts > backup_ts || commit_ts <= start_ts {
+            continue;
+        }
+        if start_ts == 0 && last.as_deref() == Some(encoded) {
+            continue;
+        }
+        let write = Write::decode(&v)?;
+        match write.write_type {
+            WriteType::Lock | WriteType::Rollback => continue,
+            WriteType::Delete if start_ts == 0 => {
+                last = Some(encoded.to_vec());
+                continue;
+            }
+            _ => {}
+        }
+        if start_ts == 0 {
+            last = Some(encoded.to_vec());
+        }
+        if write.write_type == WriteType::Put && write.short_value.is_none() {
+            let default_key = append_ts(encoded, write.start_ts);
+            let causet_locale = snap.get_cf(CF_DEFAULT, &default_key)?.ok_or_else(|| {
+                Error::Other(format!(
+                    "causet_locale of {:?} written at {} is missing",
+                    encoded, write.start_ts
+                ))
+            })?;
+            values.push((default_key, causet_locale));
+        }
+        writes.push((k, v));
+    }
+    // The versions of a soliton_id are newest first by commit_ts, which need not
+    // order their start_ts.
+    values.sort();
+    Ok((writes, values))
+}
+
+fn sha256(data: &[u8]) -> String {
+    hex::encode(&Sha256::digest(data))
+}
+
+pub struct BackupService {
+    client: TxnClient<ClusterEngine>,
+    pd: Arc<dyn PdClient>,
+    /// Where files are built before they are uploaded or ingested.
+    scratch_dir: PathBuf,
+}
+
+impl BackupService {
+    /// A service of the cluster under `storage`, with timestamps from `pd`.
+    pub fn new(
+        storage: Arc<Storage<ClusterEngine>>,
+        pd: Arc<dyn PdClient>,
+        scratch_dir: &Path,
+    ) -> Result<BackupService> {
+        fs::create_dir_all(scratch_dir)?;
+        Ok(BackupService {
+            client: TxnClient::new(storage, pd.clone()),
+            pd,
+            scratch_dir: scratch_dir.to_owned(),
+        })
+    }
+
+    pub(crate) fn pd(&self) -> &Arc<dyn PdClient> {
+        &self.pd
+    }
+
+    pub(crate) fn scratch_dir(&self) -> &Path {
+        &self.scratch_dir
+    }
+
+    fn cluster(&self) -> &Arc<Mutex<Cluster>> {
+        self.client.storage().engine().cluster()
+    }
+
+    /// Backs up what `req` asks to `storage`; the manifest written last.
+    pub fn backup(
+        &self,
+        req: &BackupRequest,
+        storage: &dyn ExternalStorage,
+    ) -> Result<BackupManifest> {
+        let backup_ts = match req.backup_ts {
+            0 => self.pd.get_tso()?,
+            ts => ts,
+        };
+        if backup_ts <= req.start_ts {
+            return Err(Error::Other(format!(
+                "backup_ts {} is not after start_ts {}",
+                backup_ts, req.start_ts
+            )));
+        }
+        self.client.storage().push_max_ts(backup_ts);
+        let end = match req.end_key.is_empty() {
+            true => Vec::new(),
+            false => encode_key(&req.end_key),
+        };
+        let mut manifest = BackupManifest {
+            start_key: req.start_key.clone(),
+            end_key: req.end_key.clone(),
+            start_ts: req.start_ts,
+            backup_ts,
+            files: Vec::new(),
+        };
+        let mut start = encode_key(&req.start_key);
+        // Ranges are numbered, as a brane merged meanwhile may hold another.
+        for seq in 1.. {
+            let (brane, snap) = self.resolved_snapshot(&start, &end, backup_ts)?;
+            let range = intersect(&start, &end, &brane);
+            let (writes, values) =
+                collect_range(&snap, (&range.0, &range.1), req.start_ts, backup_ts)?;
+            for (namespaced, entries) in [(CF_DEFAULT, values), (CF_WRITE, writes)] {
+                if entries.is_empty() {
+                    continue;
+                }
+                let name = format!("{}_{}_{}.sst", brane.id, seq, namespaced);
+                let file = self.upload(storage, name, namespaced, &range, &entries)?;
+                manifest.files.push(file);
+            }
+            if range.1.is_empty() || range.1 == end {
+                break;
+            }
+            start = range.1;
+        }
+        manifest.save(storage)?;
+        Ok(manifest)
+    }
+
+    /// A snapshot of the leader of the brane holding `start`, with no lock in
+    /// the part of `[start, end)` there of a transaction that may commit at or
+    /// before `backup_ts`; the brane with it.
+    fn resolved_snapshot(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        backup_ts: u64,
+    ) -> Result<(Brane, StoreSnapshot)> {
+        for _ in 0..MAX_BACKOFFS {
+            let (brane, snap) = self.leader_snapshot(start)?;
+            let snap = StoreSnapshot(snap);
+            let range = intersect(start, end, &brane);
+            let locks = blocking_locks(&snap, (&range.0, &range.1), backup_ts)?;
+            if locks.is_empty() {
+                return Ok((brane, snap));
+            }
+            let mut retry_at_once = true;
+            for info in &locks {
+                retry_at_once &= self.client.resolve_lock(info, backup_ts)?;
+            }
+            if !retry_at_once {
+                thread::sleep(BACKOFF);
+            }
+        }
+        Err(Error::Other(format!(
+            "locks after {:?} are not resolved after {} attempts",
+            start, MAX_BACKOFFS
+        )))
+    }
+
+    /// A snapshot of the leader of the brane holding `start`, taken once it
+    /// applied everything committed before; the brane with it.
+    fn leader_snapshot(&self, start: &[u8]) -> Result<(Brane, LsmSnapshot)> {
+        let mut cluster = self.cluster().lock().unwrap();
+        let brane = cluster
+            .lookup_brane(start)
+            .ok_or_else(|| Error::Other(format!("no brane holds soliton_id {:?}", start)))?;
+        let store_id = cluster.wait_leader(brane.id)?.store_id;
+        cluster.confirm_applied(&brane, store_id)?;
+        let store = cluster.store(store_id).unwrap();
+        let brane = match store.peer(brane.id) {
+            Some(peer) if peer.brane().contains(start) => peer.brane().clone(),
+            _ => {
+                return Err(Error::Other(format!(
+                    "brane {} on store {} does not hold soliton_id {:?}",
+                    brane.id, store_id, start
+                )))
+            }
+        };
+        Ok((brane, store.kv().snapshot()))
+    }
+
+    /// Writes `entries` of `namespaced` in `range` to the file `name` of
+    /// `storage`.
+    fn upload(
+        &self,
+        storage: &dyn ExternalStorage,
+        name: String,
+        namespaced: &str,
+        (start, end): &(Vec<u8>, Vec<u8>),
+        entries: &[(Vec<u8>, Vec<u8>)],
+    ) -> Result<BackupFile> {
+        let path = self.scratch_dir.join(&name);
+        let mut writer = LsmSstWriter::create(&path, &LsmOptions::default(), Compression::Lz4)?;
+        let built = entries
+            .iter()
+            .try_for_each(|(k, v)| writer.put(k, v))
+            .and_then(|_| writer.finish());
+        let data = built
+            .map_err(Error::from)
+            .and_then(|_| Ok(fs::read(&path)?));
+        let _ = fs::remove_file(&path);
+        let data = data?;
+        storage.write(&name, &data)?;
+        Ok(BackupFile {
+            name,
+            namespaced: namespaced.to_owned(),
+            start_key: start.clone(),
+            end_key: end.clone(),
+            num_entries: entries.len() as u64,
+            size: data.len() as u64,
+            sha256: sha256(&data),
+        })
+    }
+
+    /// Restores the backup in `storage`; its manifest. An incremental backup
+    /// goes on top of the backup before it, restored first.
+    ///
+    /// The versions restored keep their timestamps, so the cluster must hand
+    /// out later ones already.
+    pub fn restore(&self, storage: &dyn ExternalStorage) -> Result<BackupManifest> {
+        let manifest = BackupManifest::load(storage)?;
+        self.wait_tso_past(manifest.backup_ts)?;
+        for namespaced in [CF_DEFAULT, CF_WRITE] {
+            for file in manifest.files.iter().filter(|f| f.namespaced == namespaced) {
+                let data = storage.read(&file.name)?;
+                if data.len() as u64 != file.size || sha256(&data) != file.sha256 {
+                    return Err(Error::Checksum(file.name.clone()));
+                }
+                let path = self.scratch_dir.join(&file.name);
+                fs::write(&path, &data)?;
+                let res = self.ingest(&path, namespaced);
+                fs::remove_file(&path)?;
+                res?;
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Waits for the TSO to hand out timestamps past `ts`, if its clock is
+    /// behind by little.
+    pub(crate) fn wait_tso_past(&self, ts: u64) -> Result<()> {
+        let deadline = Instant::now() + MAX_TSO_LAG;
+        loop {
+            let now = self.pd.get_tso()?;
+            if now > ts {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Other(format!(
+                    "the timestamp of the cluster {} is not past {}",
+                    now, ts
+                )));
+            }
+            let lag = extract_physical(ts) - extract_physical(now) + 1;
+            thread::sleep(Duration::from_millis(lag).min(MAX_TSO_LAG));
+        }
+    }
+
+    /// Ingests the backup file `path` into `namespaced`.
+    fn ingest(&self, path: &Path, namespaced: &str) -> Result<()> {
+        let mut entries = Vec::new();
+        LsmSstReader::open(path)?.scan(|k, v| {
+            entries.push((k.to_vec(), v.map(|v| v.to_vec())));
+            Ok(true)
+        })?;
+        self.ingest_entries(&entries, namespaced, path)
+    }
+
+    /// Ingests `entries` of MVCC soliton_ids, in order, into `namespaced`: a
+    /// piece per brane, built at `path` with the brane's id appended.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn ingest_entries(
+        &self,
+        entries: &[(Vec<u8>, Option<Vec<u8>>)],
+        namespaced: &str,
+        path: &Path,
+    ) -> Result<()> {
+        let mut cluster = self.cluster().lock().unwrap();
+        let mut rest = entries;
+        while let Some((first, _)) = rest.first() {
+            let brane = cluster
+                .lookup_brane(first)
+                .ok_or_else(|| Error::Other(format!("no brane holds soliton_id {:?}", first)))?;
+            let n = rest
+                .iter()
+                .take_while(|(k, _)| brane.end_key.is_empty() || *k < brane.end_key)
+                .count();
+            let mut piece = OsString::from(path);
+            piece.push(format!(".{}", brane.id));
+            let piece = PathBuf::from(piece);
+            let mut writer =
+                LsmSstWriter::create(&piece, &LsmOptions::default(), Compression::Lz4)?;
+            let built = rest[..n]
+                .iter()
+                .try_for_each(|(k, v)| match v {
+                    Some(v) => writer.put(&keys::data_key(k), v),
+                    None => writer.delete(&keys::data_key(k)),
+                })
+                .and_then(|_| writer.finish());
+            let res = built
+                .map_err(Error::from)
+                .and_then(|sst| Ok(cluster.ingest(namespaced, sst)?));
+            let _ = fs::remove_file(&piece);
+            res?;
+            rest = &rest[n..];
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pd::{LocalClient, PdConfig, PdServer};
+    use tempfile::TempDir;
+    use txn::{Mutation, PrewriteRequest, TxnOptions};
+    use violetabftstore::StoreConfig;
+
+    use super::*;
+    use crate::storage::LocalStorage;
+
+    struct Env {
+        client: TxnClient<ClusterEngine>,
+        storage: Arc<Storage<ClusterEngine>>,
+        pd: Arc<dyn PdClient>,
+        br: BackupService,
+    }
+
+    impl Env {
+        /// Three stores under `dir`, with the branes split at `split_keys`.
+        fn new(dir: &Path, split_keys: &[&[u8]]) -> Env {
+            let cfg = StoreConfig {
+                violetabft_election_ticks: 5,
+                violetabft_heartbeat_ticks: 1,
+                sync_log: false,
+                ..Default::default()
+            };
+            let mut cluster = Cluster::new(&dir.join("cluster"), 3, cfg).unwrap();
+            for k in split_keys {
+                cluster.split(&encode_key(k)).unwrap();
+            }
+            let engine = ClusterEngine::new(Arc::new(Mutex::new(cluster)));
+            let pd: Arc<dyn PdClient> = Arc::new(LocalClient::new(Arc::new(
+                PdServer::new(PdConfig::default()).unwrap(),
+            )));
+            let storage = Arc::new(Storage::new(engine));
+            Env {
+                client: TxnClient::new(storage.clone(), pd.clone()),
+                br: BackupService::new(storage.clone(), pd.clone(), &dir.join("scratch")).unwrap(),
+                storage,
+                pd,
+            }
+        }
+
+        /// Commits the puts, or deletes if `None`; the commit_ts.
+        fn write(&self, rows: &[(&[u8], Option<&[u8]>)]) -> u64 {
+            let mut txn = self.client.begin(TxnOptions::default()).unwrap();
+            for (k, v) in rows {
+                match v {
+                    Some(v) => txn.put(k.to_vec(), v.to_vec()).unwrap(),
+                    None => txn.delete(k.to_vec()).unwrap(),
+                }
+            }
+            txn.commit().unwrap()
+        }
+
+        fn scan(&self, ts: u64) -> Vec<(Vec<u8>, Vec<u8>)> {
+            self.client.scan(b"", b"", 0, ts).unwrap()
+        }
+    }
+
+    fn pairs(pairs: &[(&[u8], &[u8])]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_vec(), v.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn test_backup_and_restore() {
+        let dir = TempDir::new().unwrap();
+        let src = Env::new(&dir.path().join("src"), &[b"m"]);
+        let long = vec![b'v'; 300];
+        src.write(&[(b"a", Some(b"1")), (b"b", Some(&long)), (b"x", Some(b"1"))]);
+        src.write(&[(b"a", Some(b"2"))]);
+        let full_storage = LocalStorage::new(&dir.path().join("full")).unwrap();
+        let full = src
+            .br
+            .backup(&BackupRequest::default(), &full_storage)
+            .unwrap();
+        // A full backup has the newest version of each soliton_id only.
+        let writes: u64 = full
+            .files
+            .iter()
+            .filter(|f| f.namespaced == CF_WRITE)
+            .map(|f| f.num_entries)
+            .sum();
+        assert_eq!(writes, 3);
+        let changed = src.write(&[(b"c", Some(b"3")), (b"x", None), (b"a", Some(b"4"))]);
+        let inc_storage = LocalStorage::new(&dir.path().join("inc")).unwrap();
+        let req = BackupRequest {
+            start_ts: full.backup_ts,
+            ..Default::default()
+        };
+        let inc = src.br.backup(&req, &inc_storage).unwrap();
+        // Committed after the backup, so not in it.
+        src.write(&[(b"d", Some(b"5"))]);
+
+        // Restored into branes cut elsewhere, one backup on the other.
+        let dst = Env::new(&dir.path().join("dst"), &[b"b", b"y"]);
+        dst.br.restore(&full_storage).unwrap();
+        let ts = dst.pd.get_tso().unwrap();
+        assert_eq!(
+            dst.scan(ts),
+            pairs(&[(b"a", b"2"), (b"b", &long), (b"x", b"1")])
+        );
+        dst.br.restore(&inc_storage).unwrap();
+        let ts = dst.pd.get_tso().unwrap();
+        assert_eq!(
+            dst.scan(ts),
+            pairs(&[(b"a", b"4"), (b"b", &long), (b"c", b"3")])
+        );
+        assert_eq!(dst.scan(changed - 1), dst.scan(full.backup_ts));
+        assert_eq!(src.scan(inc.backup_ts), dst.scan(inc.backup_ts));
+        // Transactions go on over what was restored.
+        dst.write(&[(b"x", Some(b"6"))]);
+        assert_eq!(
+            dst.client.get(b"x", dst.pd.get_tso().unwrap()).unwrap(),
+            Some(b"6".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_backup_resolves_locks() {
+        let dir = TempDir::new().unwrap();
+        let env = Env::new(dir.path(), &[]);
+        env.write(&[(b"a", Some(b"1"))]);
+        // A transaction that died after its prewrite, and one still alive.
+        let mut start_ts = Vec::new();
+        for (k, ttl) in [(b"a", 0), (b"b", 60_000)] {
+            let ts = env.pd.get_tso().unwrap();
+            env.storage
+                .prewrite(PrewriteRequest {
+                    mutations: vec![Mutation::put(k.to_vec(), b"2".to_vec())],
+                    primary: k.to_vec(),
+                    start_ts: ts,
+                    lock_ttl: ttl,
+                    ..Default::default()
+                })
+                .unwrap();
+            start_ts.push(ts);
+        }
+        thread::sleep(Duration::from_millis(2));
+        let storage = LocalStorage::new(&dir.path().join("backup")).unwrap();
+        let manifest = env.br.backup(&BackupRequest::default(), &storage).unwrap();
+        assert_eq!(BackupManifest::load(&storage).unwrap(), manifest);
+        assert_eq!(manifest.files.len(), 1);
+        assert_eq!(manifest.files[0].num_entries, 1);
+
+        // The dead one was rolled back, the live one may commit only after the
+        // backup.
+        let (a, b) = (vec![b"a".to_vec()], vec![b"b".to_vec()]);
+        assert!(env
+            .storage
+            .commit(&a, start_ts[0], manifest.backup_ts)
+            .is_err());
+        assert!(env
+            .storage
+            .commit(&b, start_ts[1], manifest.backup_ts)
+            .is_err());
+        let commit_ts = env.pd.get_tso().unwrap();
+        env.storage.commit(&b, start_ts[1], commit_ts).unwrap();
+        assert_eq!(env.scan(manifest.backup_ts), pairs(&[(b"a", b"1")]));
+    }
+
+    #[test]
+    fn test_restore_checks_files() {
+        let dir = TempDir::new().unwrap();
+        let env = Env::new(&dir.path().join("src"), &[]);
+        env.write(&[(b"a", Some(b"1"))]);
+        let storage = LocalStorage::new(&dir.path().join("backup")).unwrap();
+        let manifest = env.br.backup(&BackupRequest::default(), &storage).unwrap();
+        let name = &manifest.files[0].name;
+        let mut data = storage.read(name).unwrap();
+        *data.last_mut().unwrap() ^= 1;
+        storage.write(name, &data).unwrap();
+
+        let dst = Env::new(&dir.path().join("dst"), &[]);
+        assert!(matches!(dst.br.restore(&storage), Err(Error::Checksum(n)) if n == *name));
+        let empty = LocalStorage::new(&dir.path().join("empty")).unwrap();
+        assert!(matches!(dst.br.restore(&empty), Err(Error::NotFound(_))));
+    }
+}
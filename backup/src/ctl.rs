@@ -0,0 +1,253 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The `backup`, `log-backup` and `restore` commands of `einsteindb-ctl`, run
+//! on the data directory of a cluster: its placement driver under `pd` and its
+//! stores under `cluster`.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use pd::{LocalClient, PdConfig, PdServer};
+use txn::{ClusterEngine, Storage};
+use violetabftstore::cluster::Cluster;
+use violetabftstore::StoreConfig;
+
+use crate::backup::{BackupRequest, BackupService};
+use crate::errors::{Error, Result};
+use crate::log_backup::{LogBackup, LogBackupConfig};
+use crate::storage::create_storage;
+
+const USAGE: &str = "usage:
+    backup --data-dir DIR --storage URL [--start KEY] [--end KEY] [--since TS] [--ts TS]
+    log-backup --data-dir DIR --storage URL [--interval SECS] [--base-interval SECS] [--rounds N]
+    restore --data-dir DIR --storage URL [--stores N] [--point-in-time TS]";
+
+fn usage() -> Error {
+    Error::Other(USAGE.to_owned())
+}
+
+/// Runs the command of `args`, those after the program's name; what it
+/// prints.
+pub fn run(args: &[String]) -> Result<String> {
+    let (cmd, rest) = args.split_first().ok_or_else(usage)?;
+    let allowed: &[&str] = match cmd.as_str() {
+        "backup" => &["data-dir", "storage", "start", "end", "since", "ts"],
+        "log-backup" => &["data-dir", "storage", "interval", "base-interval", "rounds"],
+        "restore" => &["data-dir", "storage", "stores", "point-in-time"],
+        _ => return Err(usage()),
+    };
+    let mut opts = HashMap::new();
+    let mut rest = rest.iter();
+    while let Some(flag) = rest.next() {
+        match (flag.strip_prefix("--"), rest.next()) {
+            (Some(name), Some(causet_locale)) if allowed.contains(&name) => {
+                opts.insert(name, causet_locale.as_str());
+            }
+            _ => return Err(usage()),
+        }
+    }
+    let num = |name: &str, default: u64| match opts.get(name) {
+        Some(v) => v
+            .parse()
+            .map_err(|_| Error::Other(format!("--{} takes a number, not {:?}", name, v))),
+        None => Ok(default),
+    };
+    let bytes = |name: &str| opts.get(name).map_or(Vec::new(), |k| k.as_bytes().to_vec());
+    let data_dir = opts.get("data-dir").ok_or_else(usage)?;
+    let storage = create_storage(opts.get("storage").ok_or_else(usage)?)?;
+    let (store, pd) = open(Path::new(data_dir), num("stores", 3)?)?;
+    let scratch_dir = Path::new(data_dir).join("scratch");
+    if cmd == "log-backup" {
+        let cfg = LogBackupConfig {
+            base_interval: Duration::from_secs(num("base-interval", 3600)?),
+        };
+        let log = LogBackup::start(store, pd, Arc::from(storage), &scratch_dir, cfg)?;
+        let interval = Duration::from_secs(num("interval", 10)?);
+        return run_log_backup(&log, interval, num("rounds", 0)?);
+    }
+    let service = BackupService::new(store, pd, &scratch_dir)?;
+    if cmd == "backup" {
+        let req = BackupRequest {
+            start_key: bytes("start"),
+            end_key: bytes("end"),
+            start_ts: num("since", 0)?,
+            backup_ts: num("ts", 0)?,
+        };
+        let manifest = service.backup(&req, storage.as_ref())?;
+        Ok(format!(
+            "backed up {} files at {}",
+            manifest.files.len(),
+            manifest.backup_ts
+        ))
+    } else if opts.contains_key("point-in-time") {
+        let ts = num("point-in-time", 0)?;
+        let manifest = service.restore_point_in_time(storage.as_ref(), ts)?;
+        Ok(format!(
+            "restored to {} on the base backed up at {}",
+            ts, manifest.backup_ts
+        ))
+    } else {
+        let manifest = service.restore(storage.as_ref())?;
+        Ok(format!(
+            "restored {} files backed up at {}",
+            manifest.files.len(),
+            manifest.backup_ts
+        ))
+    }
+}
+
+/// Advances `log` every `interval`, `rounds` times or for ever if it is 0;
+/// the checkpoint it got to. The checkpoint is kept in the log's metadata as
+/// it goes.
+fn run_log_backup(log: &LogBackup, interval: Duration, rounds: u64) -> Result<String> {
+    let mut round = 0;
+    loop {
+        let checkpoint_ts = log.advance()?;
+        round += 1;
+        if round == rounds {
+            return Ok(format!("log backed up to {}", checkpoint_ts));
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// The cluster under `data_dir` and its placement driver, bootstrapping one of
+/// `store_count` stores if there is none. The commands run on what it opens.
+pub fn open(
+    data_dir: &Path,
+    store_count: u64,
+) -> Result<(Arc<Storage<ClusterEngine>>, Arc<LocalClient>)> {
+    let pd_cfg = PdConfig {
+        data_dir: Some(data_dir.join("pd")),
+        ..Default::default()
+    };
+    let pd = Arc::new(LocalClient::new(Arc::new(PdServer::new(pd_cfg)?)));
+    let dir = data_dir.join("cluster");
+    let cfg = StoreConfig::default();
+    let cluster = match dir.exists() {
+        true => Cluster::open(&dir, cfg, pd.clone())?,
+        false => Cluster::with_id_allocator(&dir, store_count, cfg, pd.clone())?,
+    };
+    let engine = ClusterEngine::new(Arc::new(Mutex::new(cluster)));
+    Ok((Arc::new(Storage::new(engine)), pd))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+    use txn::{TxnClient, TxnOptions};
+
+    use super::*;
+
+    fn run_args(args: &[&str]) -> Result<String> {
+        run(&args.iter().map(|a| a.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_ctl() {
+        let dir = TempDir::new().unwrap();
+        let path = |name: &str| dir.path().join(name).to_str().unwrap().to_owned();
+        let (src, dst) = (path("src"), path("dst"));
+        let storage = format!("local://{}", path("backup"));
+        let open_client = |data_dir: &str| {
+            let (storage, pd) = open(Path::new(data_dir), 1).unwrap();
+            TxnClient::new(storage, pd)
+        };
+        {
+            let client = open_client(&src);
+            let mut txn = client.begin(TxnOptions::default()).unwrap();
+            txn.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+            txn.commit().unwrap();
+        }
+        let out = run_args(&["backup", "--data-dir", &src, "--storage", &storage]).unwrap();
+        assert!(out.starts_with("backed up 1 files at "), "{}", out);
+        let out = run_args(&[
+            "restore",
+            "--data-dir",
+            &dst,
+            "--storage",
+            &storage,
+            "--stores",
+            "1",
+        ])
+        .unwrap();
+        assert!(out.starts_with("restored 1 files"), "{}", out);
+        let client = open_client(&dst);
+        let ts = client.begin(TxnOptions::default()).unwrap().start_ts();
+        assert_eq!(client.get(b"a", ts).unwrap(), Some(b"1".to_vec()));
+        drop(client);
+
+        assert!(run_args(&["restore", "--data-dir", &dst]).is_err());
+        // Not a log backup.
+        let pitr = [
+            "restore",
+            "--data-dir",
+            &dst,
+            "--storage",
+            &storage,
+            "--point-in-time",
+            "1",
+        ];
+        assert!(matches!(run_args(&pitr), Err(Error::NotFound(_))));
+
+        // A log backup going on across runs, restored at its checkpoint.
+        let log_storage = format!("local://{}", path("log"));
+        let log_backup = |rounds: &str| {
+            let out = run_args(&[
+                "log-backup",
+                "--data-dir",
+                &src,
+                "--storage",
+                &log_storage,
+                "--interval",
+                "0",
+                "--rounds",
+                rounds,
+            ])
+            .unwrap();
+            let ts = out.strip_prefix("log backed up to ").unwrap();
+            ts.parse::<u64>().unwrap()
+        };
+        log_backup("2");
+        {
+            let client = open_client(&src);
+            let mut txn = client.begin(TxnOptions::default()).unwrap();
+            txn.put(b"b".to_vec(), b"2".to_vec()).unwrap();
+            txn.commit().unwrap();
+        }
+        let checkpoint_ts = log_backup("1").to_string();
+        let dst = path("dst_pitr");
+        let pitr = [
+            "restore",
+            "--data-dir",
+            &dst,
+            "--storage",
+            &log_storage,
+            "--stores",
+            "1",
+            "--point-in-time",
+            &checkpoint_ts,
+        ];
+        run_args(&pitr).unwrap();
+        let client = open_client(&dst);
+        let ts = client.begin(TxnOptions::default()).unwrap().start_ts();
+        assert_eq!(client.get(b"a", ts).unwrap(), Some(b"1".to_vec()));
+        assert_eq!(client.get(b"b", ts).unwrap(), Some(b"2".to_vec()));
+        drop(client);
+
+        assert!(run_args(&["backup", "--data-dir", &src, "--stores", "1"]).is_err());
+        assert!(run_args(&[
+            "backup",
+            "--data-dir",
+            &src,
+            "--storage",
+            &storage,
+            "--ts",
+            "x"
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Backups of the MVCC data of a cluster, consistent at a timestamp across its
+//! branes, and their restore.
+//!
+//! A backup is a set of SST files, one per causet_merge family of each brane's
+//! share, and a manifest recording their ranges, sizes and SHA-256, written to
+//! an `ExternalStorage`: a local directory, or a bucket of an S3-compatible
+//! service. A full backup holds the causet_locale of each soliton_id as of its
+//! timestamp; an incremental one the changes committed between two
+//! timestamps, to be restored on top of the backup before it. Restoring cuts
+//! the files along the branes of the target cluster and ingests the pieces
+//! through their logs.
+//!
+//! A log backup ships the rows committed in the cluster as its stores apply
+//! them, on top of base snapshots taken now and then, so that the cluster can
+//! be restored as of any timestamp the log covers.
+
+mod backup;
+pub mod ctl;
+mod errors;
+mod log_backup;
+mod manifest;
+mod s3;
+mod storage;
+
+pub use crate::backup::{BackupRequest, BackupService};
+pub use crate::errors::{Error, Result};
+pub use crate::log_backup::{LogBackup, LogBackupConfig, LogMeta, LOG_META_NAME};
+pub use crate::manifest::{BackupFile, BackupManifest, MANIFEST_NAME};
+pub use crate::s3::{S3Config, S3Storage};
+pub use crate::storage::{create_storage, ExternalStorage, LocalStorage};
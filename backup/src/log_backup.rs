@@ -0,0 +1,472 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Log backup: the rows committed in a cluster, shipped to external storage
+//! as the stores apply them, on top of base snapshots taken now and then, for
+//! restoring the cluster as of any timestamp the log covers.
+//!
+//! The rows come from a change feed over every soliton_id; its checkpoint
+//! tells up to when the log is complete. The storage holds:
+//!
+//! ```text
+//!   base/{backup_ts}/..   full backups, as `BackupService::backup` writes them
+//!   log/{n}.jsonl         the rows committed after the first base, as events of
+//!                         the feed, in files numbered in order
+//!   logmeta               the bases, the number of files and the checkpoint
+//! ```
+//!
+//! A row may be logged more than once, as a feed takes a brane again from its
+//! resolved timestamp. What is ingested into the cluster, as by a restore, is
+//! not in the log.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use cdc::{CdcService, DownstreamID, Event, EventKind, Row, RowOp, Sink, SubscribeRequest};
+use pd::tso::extract_physical;
+use pd::PdClient;
+use serde::{Deserialize, Serialize};
+use txn::{
+    append_ts, encode_key, ClusterEngine, Storage, Write, WriteType, CF_DEFAULT, CF_WRITE,
+    SHORT_VALUE_MAX_LEN,
+};
+use violetabft::Codec;
+
+use crate::backup::{BackupRequest, BackupService};
+use crate::errors::{Error, Result};
+use crate::manifest::BackupManifest;
+use crate::storage::{ExternalStorage, SubStorage};
+
+/// The name of the metadata of a log backup in its storage.
+pub const LOG_META_NAME: &str = "logmeta";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogMeta {
+    /// The backup_ts of each base snapshot, ascending.
+    pub bases: Vec<u64>,
+    /// Every row committed after the first base and at or before it is in the
+    /// log.
+    pub checkpoint_ts: u64,
+    /// The number of log files.
+    pub files: u64,
+    /// The least and the greatest commit_ts of the rows of each log file, by
+    /// number. A file without one, written before they were recorded, may
+    /// hold any.
+    #[serde(default)]
+    pub file_ts_ranges: Vec<(u64, u64)>,
+}
+
+impl LogMeta {
+    pub fn load(storage: &dyn ExternalStorage) -> Result<LogMeta> {
+        Ok(serde_json::from_slice(&storage.read(LOG_META_NAME)?)?)
+    }
+
+    pub fn save(&self, storage: &dyn ExternalStorage) -> Result<()> {
+        storage.write(LOG_META_NAME, &serde_json::to_vec_pretty(self)?)
+    }
+}
+
+fn log_file_name(n: u64) -> String {
+    format!("log/{:020}.jsonl", n)
+}
+
+fn base_dir(backup_ts: u64) -> String {
+    format!("base/{}", backup_ts)
+}
+
+#[derive(Clone, Debug)]
+pub struct LogBackupConfig {
+    /// How long after a base snapshot `advance` takes the next.
+    pub base_interval: Duration,
+}
+
+impl Default for LogBackupConfig {
+    fn default() -> LogBackupConfig {
+        LogBackupConfig {
+            base_interval: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// Writes the rows of the feed to a log file each time it is sent some.
+struct LogSink {
+    storage: Arc<dyn ExternalStorage>,
+    meta: Arc<Mutex<LogMeta>>,
+}
+
+impl Sink for LogSink {
+    fn send(&mut self, events: &[Event]) -> cdc::Result<()> {
+        let mut data = Vec::new();
+        let (mut min_ts, mut max_ts) = (u64::MAX, 0);
+        for e in events {
+            if let EventKind::Row(row) = &e.kind {
+                min_ts = min_ts.min(row.commit_ts);
+                max_ts = max_ts.max(row.commit_ts);
+                serde_json::to_writer(&mut data, e)?;
+                data.push(b'\n');
+            }
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+        let mut meta = self.meta.lock().unwrap();
+        self.storage
+            .write(&log_file_name(meta.files), &data)
+            .map_err(|e| cdc::Error::Other(e.to_string()))?;
+        meta.files += 1;
+        meta.file_ts_ranges.push((min_ts, max_ts));
+        Ok(())
+    }
+}
+
+/// A log backup of a cluster. It owns no thread: its owner calls `advance`
+/// periodically.
+pub struct LogBackup {
+    service: BackupService,
+    cdc: CdcService,
+    feed_id: DownstreamID,
+    storage: Arc<dyn ExternalStorage>,
+    meta: Arc<Mutex<LogMeta>>,
+    cfg: LogBackupConfig,
+}
+
+impl LogBackup {
+    /// Starts backing up the cluster under `storage` to `external`: going on
+    /// from the checkpoint of the log there, or from a first base snapshot if
+    /// there is none.
+    pub fn start(
+        storage: Arc<Storage<ClusterEngine>>,
+        pd: Arc<dyn PdClient>,
+        external: Arc<dyn ExternalStorage>,
+        scratch_dir: &Path,
+        cfg: LogBackupConfig,
+    ) -> Result<LogBackup> {
+        let service = BackupService::new(storage.clone(), pd.clone(), scratch_dir)?;
+        let meta = match LogMeta::load(external.as_ref()) {
+            Ok(meta) => meta,
+            Err(Error::NotFound(_)) => {
+                let base_ts = take_base(&service, external.as_ref())?;
+                let meta = LogMeta {
+                    bases: vec![base_ts],
+                    checkpoint_ts: base_ts,
+                    ..Default::default()
+                };
+                meta.save(external.as_ref())?;
+                meta
+            }
+            Err(e) => return Err(e),
+        };
+        let req = SubscribeRequest {
+            checkpoint_ts: meta.checkpoint_ts,
+            ..Default::default()
+        };
+        let meta = Arc::new(Mutex::new(meta));
+        let sink = LogSink {
+            storage: external.clone(),
+            meta: meta.clone(),
+        };
+        let cdc = CdcService::new(storage, pd);
+        let feed_id = cdc.subscribe(req, Box::new(sink))?;
+        Ok(LogBackup {
+            service,
+            cdc,
+            feed_id,
+            storage: external,
+            meta,
+            cfg,
+        })
+    }
+
+    /// Ships the rows applied since the last call, takes a base snapshot if the
+    /// last is `base_interval` old, and records how far the log is complete;
+    /// the checkpoint.
+    pub fn advance(&self) -> Result<u64> {
+        self.cdc.advance()?;
+        let checkpoint_ts = self.cdc.checkpoint_ts(self.feed_id)?;
+        let last_base = *self.meta.lock().unwrap().bases.last().unwrap_or(&0);
+        let age = extract_physical(checkpoint_ts).saturating_sub(extract_physical(last_base));
+        if Duration::from_millis(age) >= self.cfg.base_interval {
+            self.take_base()?;
+        }
+        let mut meta = self.meta.lock().unwrap();
+        meta.checkpoint_ts = meta.checkpoint_ts.max(checkpoint_ts);
+        meta.save(self.storage.as_ref())?;
+        Ok(meta.checkpoint_ts)
+    }
+
+    /// Takes a base snapshot now; its timestamp.
+    pub fn take_base(&self) -> Result<u64> {
+        let base_ts = take_base(&self.service, self.storage.as_ref())?;
+        let mut meta = self.meta.lock().unwrap();
+        meta.bases.push(base_ts);
+        meta.save(self.storage.as_ref())?;
+        Ok(base_ts)
+    }
+}
+
+/// Backs up the whole cluster under `base/` of `storage`; its backup_ts.
+fn take_base(service: &BackupService, storage: &dyn ExternalStorage) -> Result<u64> {
+    let backup_ts = service.pd().get_tso()?;
+    let req = BackupRequest {
+        backup_ts,
+        ..Default::default()
+    };
+    service.backup(&req, &SubStorage::new(storage, base_dir(backup_ts)))?;
+    Ok(backup_ts)
+}
+
+/// The rows of the log in `storage` committed in `(after, until]`. Only the
+/// files that may hold some are read.
+fn read_rows(
+    storage: &dyn ExternalStorage,
+    meta: &LogMeta,
+    after: u64,
+    until: u64,
+) -> Result<Vec<Row>> {
+    let mut rows = Vec::new();
+    for n in 0..meta.files {
+        if let Some(&(min_ts, max_ts)) = meta.file_ts_ranges.get(n as usize) {
+            if max_ts <= after || min_ts > until {
+                continue;
+            }
+        }
+        let data = storage.read(&log_file_name(n))?;
+        for line in data.split(|&b| b == b'\n').filter(|l| !l.is_empty()) {
+            let event: Event = serde_json::from_slice(line)?;
+            match event.kind {
+                EventKind::Row(row) if row.commit_ts > after && row.commit_ts <= until => {
+                    rows.push(row)
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(rows)
+}
+
+impl BackupService {
+    /// Restores the cluster as of `ts` from the log backup in `storage`: its
+    /// latest base snapshot at or before `ts`, and the rows logged after the
+    /// base up to `ts`. The base manifest.
+    pub fn restore_point_in_time(
+        &self,
+        storage: &dyn ExternalStorage,
+        ts: u64,
+    ) -> Result<BackupManifest> {
+        let meta = LogMeta::load(storage)?;
+        if ts > meta.checkpoint_ts {
+            return Err(Error::Other(format!(
+                "the log is complete up to {} only, not {}",
+                meta.checkpoint_ts, ts
+            )));
+        }
+        let base_ts = *meta
+            .bases
+            .iter()
+            .rev()
+            .find(|&&b| b <= ts)
+            .ok_or_else(|| Error::Other(format!("no base snapshot at or before {}", ts)))?;
+        self.wait_tso_past(ts)?;
+        let manifest = self.restore(&SubStorage::new(storage, base_dir(base_ts)))?;
+
+        // The MVCC records the rows were committed as, each once.
+        let (mut writes, mut values) = (BTreeMap::new(), BTreeMap::new());
+        for row in read_rows(storage, &meta, base_ts, ts)? {
+            let encoded = encode_key(&row.soliton_id);
+            let write = match (row.op, row.causet_locale) {
+                (RowOp::Put, Some(v)) if v.len() <= SHORT_VALUE_MAX_LEN => {
+                    Write::new(WriteType::Put, row.start_ts, Some(v))
+                }
+                (RowOp::Put, Some(v)) => {
+                    values.insert(append_ts(&encoded, row.start_ts), Some(v));
+                    Write::new(WriteType::Put, row.start_ts, None)
+                }
+                _ => Write::new(WriteType::Delete, row.start_ts, None),
+            };
+            writes.insert(append_ts(&encoded, row.commit_ts), Some(write.encode()));
+        }
+        for (namespaced, entries) in [(CF_DEFAULT, values), (CF_WRITE, writes)] {
+            let entries: Vec<_> = entries.into_iter().collect();
+            let path = self.scratch_dir().join(format!("log_{}.sst", namespaced));
+            self.ingest_entries(&entries, namespaced, &path)?;
+        }
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pd::{LocalClient, PdConfig, PdServer};
+    use tempfile::TempDir;
+    use txn::{TxnClient, TxnOptions};
+    use violetabftstore::cluster::Cluster;
+    use violetabftstore::StoreConfig;
+
+    use super::*;
+    use crate::storage::LocalStorage;
+
+    /// A cluster of `store_count` stores under `dir`, split at "m" if it has
+    /// more than one.
+    fn cluster(dir: &Path, store_count: u64) -> (Arc<Storage<ClusterEngine>>, Arc<dyn PdClient>) {
+        let cfg = StoreConfig {
+            violetabft_election_ticks: 5,
+            violetabft_heartbeat_ticks: 1,
+            sync_log: false,
+            ..Default::default()
+        };
+        let mut cluster = Cluster::new(dir, store_count, cfg).unwrap();
+        if store_count > 1 {
+            cluster.split(&encode_key(b"m")).unwrap();
+        }
+        let engine = ClusterEngine::new(Arc::new(Mutex::new(cluster)));
+        let pd: Arc<dyn PdClient> = Arc::new(LocalClient::new(Arc::new(
+            PdServer::new(PdConfig::default()).unwrap(),
+        )));
+        (Arc::new(Storage::new(engine)), pd)
+    }
+
+    type Pairs<'a> = &'a [(&'a [u8], &'a [u8])];
+
+    /// Commits the puts, or deletes if `None`; the commit_ts.
+    fn write(client: &TxnClient<ClusterEngine>, rows: &[(&[u8], Option<&[u8]>)]) -> u64 {
+        let mut txn = client.begin(TxnOptions::default()).unwrap();
+        for (k, v) in rows {
+            match v {
+                Some(v) => txn.put(k.to_vec(), v.to_vec()).unwrap(),
+                None => txn.delete(k.to_vec()).unwrap(),
+            }
+        }
+        txn.commit().unwrap()
+    }
+
+    #[test]
+    fn test_point_in_time_restore() {
+        let dir = TempDir::new().unwrap();
+        let (storage, pd) = cluster(&dir.path().join("src"), 3);
+        let client = TxnClient::new(storage.clone(), pd.clone());
+        let external: Arc<dyn ExternalStorage> =
+            Arc::new(LocalStorage::new(&dir.path().join("log")).unwrap());
+        let start = |cfg| {
+            let scratch = dir.path().join("scratch");
+            LogBackup::start(storage.clone(), pd.clone(), external.clone(), &scratch, cfg).unwrap()
+        };
+        let long = vec![b'v'; 300];
+        write(&client, &[(b"a", Some(b"1"))]);
+        let log = start(LogBackupConfig::default());
+        let ts2 = write(&client, &[(b"a", Some(b"2")), (b"x", Some(&long))]);
+        assert!(log.advance().unwrap() > ts2);
+        let base = log.take_base().unwrap();
+        let ts3 = write(&client, &[(b"a", None), (b"b", Some(b"3"))]);
+        log.advance().unwrap();
+        drop(log);
+        // Committed while the log backup is stopped, found when it goes on.
+        let ts4 = write(&client, &[(b"x", Some(b"4"))]);
+        let log = start(LogBackupConfig::default());
+        let checkpoint = log.advance().unwrap();
+        assert!(checkpoint > ts4);
+        let meta = LogMeta::load(external.as_ref()).unwrap();
+        assert_eq!((meta.bases.len(), meta.bases[1]), (2, base));
+        let newest = meta.file_ts_ranges.iter().map(|&(_, max)| max).max();
+        assert_eq!(newest, Some(ts4));
+
+        let cases: [(u64, Pairs); 8] = [
+            (meta.bases[0], &[(b"a", b"1")]),
+            (ts2, &[(b"a", b"2"), (b"x", &long)]),
+            // Just before the second base, from the first one and the log.
+            (base - 1, &[(b"a", b"2"), (b"x", &long)]),
+            (base, &[(b"a", b"2"), (b"x", &long)]),
+            (ts3 - 1, &[(b"a", b"2"), (b"x", &long)]),
+            (ts3, &[(b"b", b"3"), (b"x", &long)]),
+            // The newest row logged, and past it up to the checkpoint.
+            (ts4, &[(b"b", b"3"), (b"x", b"4")]),
+            (checkpoint, &[(b"b", b"3"), (b"x", b"4")]),
+        ];
+        for (i, (ts, expected)) in cases.into_iter().enumerate() {
+            let (dst, dst_pd) = cluster(&dir.path().join(format!("dst_{}", i)), 1);
+            let service =
+                BackupService::new(dst.clone(), dst_pd.clone(), &dir.path().join("dst_scratch"))
+                    .unwrap();
+            let manifest = service
+                .restore_point_in_time(external.as_ref(), ts)
+                .unwrap();
+            assert!(manifest.backup_ts <= ts);
+            let client = TxnClient::new(dst, dst_pd.clone());
+            let now = dst_pd.get_tso().unwrap();
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|(k, v)| (k.to_vec(), v.to_vec()))
+                .collect();
+            assert_eq!(
+                client.scan(b"", b"", 0, now).unwrap(),
+                expected,
+                "at {}",
+                ts
+            );
+        }
+
+        // Past the checkpoint the log is complete to, and before the first base.
+        let (dst, dst_pd) = cluster(&dir.path().join("dst"), 1);
+        let service = BackupService::new(dst, dst_pd, &dir.path().join("dst_scratch")).unwrap();
+        assert!(service
+            .restore_point_in_time(external.as_ref(), checkpoint + 1)
+            .is_err());
+        assert!(service
+            .restore_point_in_time(external.as_ref(), meta.bases[0] - 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_read_rows_by_ts_range() {
+        let dir = TempDir::new().unwrap();
+        let storage: Arc<dyn ExternalStorage> = Arc::new(LocalStorage::new(dir.path()).unwrap());
+        let meta = Arc::new(Mutex::new(LogMeta::default()));
+        let mut sink = LogSink {
+            storage: storage.clone(),
+            meta: meta.clone(),
+        };
+        let row = |commit_ts: u64| Event {
+            brane_id: 1,
+            kind: EventKind::Row(Row {
+                op: RowOp::Put,
+                soliton_id: commit_ts.to_string().into_bytes(),
+                causet_locale: Some(b"v".to_vec()),
+                old_causet_locale: None,
+                start_ts: commit_ts - 1,
+                commit_ts,
+            }),
+        };
+        sink.send(&[row(20), row(10)]).unwrap();
+        sink.send(&[Event {
+            brane_id: 1,
+            kind: EventKind::ResolvedTs { ts: 25 },
+        }])
+        .unwrap();
+        sink.send(&[row(30), row(40)]).unwrap();
+        sink.send(&[row(50)]).unwrap();
+        let meta = meta.lock().unwrap().clone();
+        assert_eq!(meta.files, 3);
+        assert_eq!(meta.file_ts_ranges, vec![(10, 20), (30, 40), (50, 50)]);
+
+        // The files out of the range are not read.
+        storage.write(&log_file_name(0), b"garbage").unwrap();
+        storage.write(&log_file_name(2), b"garbage").unwrap();
+        let ts = |rows: Vec<Row>| rows.iter().map(|r| r.commit_ts).collect::<Vec<_>>();
+        assert_eq!(
+            ts(read_rows(storage.as_ref(), &meta, 20, 40).unwrap()),
+            vec![30, 40]
+        );
+        assert_eq!(
+            ts(read_rows(storage.as_ref(), &meta, 35, 45).unwrap()),
+            vec![40]
+        );
+        assert!(read_rows(storage.as_ref(), &meta, 15, 40).is_err());
+
+        // Those of a log without their ranges are.
+        let old = LogMeta {
+            file_ts_ranges: vec![],
+            ..meta
+        };
+        assert!(read_rows(storage.as_ref(), &old, 20, 40).is_err());
+    }
+}
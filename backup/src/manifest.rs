@@ -0,0 +1,83 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! What a backup holds, written to its storage once every file of it is.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+use crate::storage::ExternalStorage;
+
+/// The name of the manifest in the storage of a backup. A backup without one
+/// did not finish.
+pub const MANIFEST_NAME: &str = "backupmeta";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// The soliton_ids backed up, `[start_key, end_key)`; an empty end is
+    /// unbounded.
+    #[serde(with = "hex")]
+    pub start_key: Vec<u8>,
+    #[serde(with = "hex")]
+    pub end_key: Vec<u8>,
+    /// The changes committed after it are backed up; 0 for a full backup.
+    pub start_ts: u64,
+    /// The timestamp the backup is consistent at.
+    pub backup_ts: u64,
+    pub files: Vec<BackupFile>,
+}
+
+/// An SST file of one causet_merge family of a range, holding the MVCC
+/// soliton_ids of it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    pub name: String,
+    pub namespaced: String,
+    /// The encoded soliton_ids the file covers, `[start_key, end_key)`.
+    #[serde(with = "hex")]
+    pub start_key: Vec<u8>,
+    #[serde(with = "hex")]
+    pub end_key: Vec<u8>,
+    pub num_entries: u64,
+    pub size: u64,
+    /// The SHA-256 of the file, in hex.
+    pub sha256: String,
+}
+
+impl BackupManifest {
+    pub fn load(storage: &dyn ExternalStorage) -> Result<BackupManifest> {
+        Ok(serde_json::from_slice(&storage.read(MANIFEST_NAME)?)?)
+    }
+
+    pub fn save(&self, storage: &dyn ExternalStorage) -> Result<()> {
+        storage.write(MANIFEST_NAME, &serde_json::to_vec_pretty(self)?)
+    }
+}
+
+/// Bytes as a string of lowercase hex digits.
+pub(crate) mod hex {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    pub fn decode(s: &str) -> Option<Vec<u8>> {
+        if !s.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(d)?;
+        decode(&s).ok_or_else(|| D::Error::custom(format!("invalid hex {:?}", s)))
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Where backups are kept: whole files, by name.
+
+use std::fs::{self, File};
+use std::io::{ErrorKind, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::errors::{Error, Result};
+use crate::s3::{S3Config, S3Storage};
+
+pub trait ExternalStorage: Send + Sync {
+    /// Writes file `name`, replacing the one of that name if there is one. It
+    /// is complete once this returns, and not seen before.
+    fn write(&self, name: &str, data: &[u8]) -> Result<()>;
+
+    /// The content of file `name`, or `NotFound`.
+    fn read(&self, name: &str) -> Result<Vec<u8>>;
+}
+
+/// Opens the storage `url` names:
+///
+/// ```text
+///   local:///path, or a path        a directory of the local filesystem
+///   s3://bucket/prefix?endpoint=http://host:port&region=..&access-key=..&secret-key=..
+///                                   a bucket of an S3-compatible service
+/// ```
+///
+/// The keys of S3 default to `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`.
+pub fn create_storage(url: &str) -> Result<Box<dyn ExternalStorage>> {
+    if url.starts_with("s3://") {
+        return Ok(Box::new(S3Storage::new(S3Config::from_url(url)?)?));
+    }
+    let path = url.strip_prefix("local://").unwrap_or(url);
+    if path.contains("://") {
+        return Err(Error::Other(format!("unknown external storage {}", url)));
+    }
+    Ok(Box::new(LocalStorage::new(Path::new(path))?))
+}
+
+/// Checks that `name` is a relative path going down only.
+pub(crate) fn check_name(name: &str) -> Result<()> {
+    let path = Path::new(name);
+    if name.is_empty() || !path.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(Error::Other(format!("invalid file name {:?}", name)));
+    }
+    Ok(())
+}
+
+/// A directory of the local filesystem; names may have slashes, for
+/// subdirectories.
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    /// Opens `root`, creating it if it does not exist.
+    pub fn new(root: &Path) -> Result<LocalStorage> {
+        fs::create_dir_all(root)?;
+        Ok(LocalStorage {
+            root: root.to_owned(),
+        })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl ExternalStorage for LocalStorage {
+    fn write(&self, name: &str, data: &[u8]) -> Result<()> {
+        check_name(name)?;
+        let path = self.root.join(name);
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir)?;
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        let mut file = File::create(&tmp)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        fs::rename(&tmp, &path)?;
+        File::open(dir)?.sync_all()?;
+        Ok(())
+    }
+
+    fn read(&self, name: &str) -> Result<Vec<u8>> {
+        check_name(name)?;
+        match fs::read(self.root.join(name)) {
+            Ok(data) => Ok(data),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(Error::NotFound(name.to_owned())),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// The files of a storage under a directory of it.
+pub(crate) struct SubStorage<'a> {
+    inner: &'a dyn ExternalStorage,
+    dir: String,
+}
+
+impl<'a> SubStorage<'a> {
+    pub fn new(inner: &'a dyn ExternalStorage, dir: String) -> SubStorage<'a> {
+        SubStorage { inner, dir }
+    }
+}
+
+impl ExternalStorage for SubStorage<'_> {
+    fn write(&self, name: &str, data: &[u8]) -> Result<()> {
+        check_name(name)?;
+        self.inner.write(&format!("{}/{}", self.dir, name), data)
+    }
+
+    fn read(&self, name: &str) -> Result<Vec<u8>> {
+        check_name(name)?;
+        self.inner.read(&format!("{}/{}", self.dir, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_local_storage() {
+        let dir = TempDir::new().unwrap();
+        let url = format!("local://{}", dir.path().join("backup").display());
+        let storage = create_storage(&url).unwrap();
+        storage.write("a/1.sst", b"one").unwrap();
+        storage.write("a/1.sst", b"two").unwrap();
+        assert_eq!(storage.read("a/1.sst").unwrap(), b"two");
+        assert!(matches!(storage.read("a/2.sst"), Err(Error::NotFound(_))));
+        for name in ["", "/etc/passwd", "../x", "a/../../x"] {
+            assert!(storage.write(name, b"").is_err());
+        }
+        assert!(create_storage("gcs://bucket").is_err());
+    }
+}
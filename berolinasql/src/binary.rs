@@ -1,137 +1,93 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
-
-
-// use std::io::{self, Read, Write};
-// use std::mem;
-// use std::cmp;
-// use std::fmt;
+//Copyright 2021-2023 WHTCORPS INC
 //
-// use byteorder::{ByteOrder, BigEndian, ReadBytesExt, WriteBytesExt};
-// use byteorder::{LittleEndian, WriteBytesExt};
-// use byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
-
-    use std::io::{self, Read, Write};
-    use std::mem;
-    use std::cmp;
-    use std::fmt;
-    use std::io::{Error, ErrorKind};
-    use std::io::{Cursor};
-    use std::io::{Seek, SeekFrom};
-
-
-    use byteorder::{ByteOrder, BigEndian, ReadBytesExt, WriteBytesExt};
-    use byteorder::{LittleEndian, WriteBytesExt};
-    use byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
-
-
-    use super::{Binary};
-    use super::{BinaryReader};
-
-
-    pub struct BinaryWriter {
-        pub buffer: Vec<u8>,
-        pub position: usize,
-    }
-
-
-    impl BinaryWriter {
-        pub fn new() -> BinaryWriter {
-            BinaryWriter {
-                buffer: Vec::new(),
-                position: 0,
-            }
-        }
-
-        pub fn write_u8(&mut self, value: u8) {
-            self.buffer.push(value);
-            self.position += 1;
-        }
-
-        pub fn write_u16(&mut self, value: u16) {
-            self.buffer.write_u16::<BigEndian>(value).unwrap();
-            self.position += 2;
-        }
-
-        pub fn write_u32(&mut self, value: u32) {
-            self.buffer.write_u32::<BigEndian>(value).unwrap();
-            self.position += 4;
-        }
-
-        pub fn write_u64(&mut self, value: u64) {
-            self.buffer.write_u64::<BigEndian>(value).unwrap();
-            self.position += 8;
-        }
-
-        pub fn write_i8(&mut self, value: i8) {
-            self.buffer.write_i8(value).unwrap();
-            self.position += 1;
-        }
-
-        pub fn write_i16(&mut self, value: i16) {
-            self.buffer.write_i16::<BigEndian>(value).unwrap();
-            self.position += 2;
-        }
-
-        pub fn write_i32(&mut self, value: i32) {
-            self.buffer.write_i32::<BigEndian>(value).unwrap();
-            self.position += 4;
-        }
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+use std::convert::TryInto;
+
+use byteorder::{BigEndian, WriteBytesExt};
+
+use crate::codec::NumberCodec;
+use crate::constants::*;
+use crate::error::Result;
+use crate::json::{JsonRef, JsonType, ERR_CONVERT_FAILED};
+
+/// Writes big-endian numbers into a growing buffer.
+#[derive(Default)]
+pub struct BinaryWriter {
+    pub buffer: Vec<u8>,
+    pub position: usize,
+}
 
-        pub fn write_i64(&mut self, value: i64) {
-            self.buffer.write_i64::<BigEndian>(value).unwrap();
-            self.position += 8;
+impl BinaryWriter {
+    pub fn new() -> BinaryWriter {
+        BinaryWriter {
+            buffer: Vec::new(),
+            position: 0,
         }
+    }
 
-        pub fn write_f32(&mut self, value: f32) {
-            self.buffer.write_f32::<BigEndian>(value).unwrap();
-            self.position += 4;
-        }
+    pub fn write_u8(&mut self, value: u8) {
+        self.buffer.push(value);
+        self.position += 1;
+    }
 
-        pub fn write_f64(&mut self, value: f64) {
-            self.buffer.write_f64::<BigEndian>(value).unwrap();
-            self.position += 8;
-        }
+    pub fn write_u16(&mut self, value: u16) {
+        self.buffer.write_u16::<BigEndian>(value).unwrap();
+        self.position += 2;
     }
 
+    pub fn write_u32(&mut self, value: u32) {
+        self.buffer.write_u32::<BigEndian>(value).unwrap();
+        self.position += 4;
+    }
 
+    pub fn write_u64(&mut self, value: u64) {
+        self.buffer.write_u64::<BigEndian>(value).unwrap();
+        self.position += 8;
+    }
 
+    pub fn write_i8(&mut self, value: i8) {
+        self.buffer.write_i8(value).unwrap();
+        self.position += 1;
+    }
 
- //JsonRef
- pub trait JsonRef {
-     fn as_str(&self) -> &str;
-     fn as_u64(&self) -> Option<u64>;
-     fn as_i64(&self) -> Option<i64>;
-     fn as_f64(&self) -> Option<f64>;
-     fn as_bool(&self) -> Option<bool>;
-     fn as_array(&self) -> Option<&[dyn JsonRef]>;
-     fn as_object(&self) -> Option<&JsonObject>;
- }
+    pub fn write_i16(&mut self, value: i16) {
+        self.buffer.write_i16::<BigEndian>(value).unwrap();
+        self.position += 2;
+    }
 
- #[derive(Debug, Clone, PartialEq)]
- pub struct JsonObject {
-     pub(crate) map: Vec<(String, dyn JsonRef)>,
- }
+    pub fn write_i32(&mut self, value: i32) {
+        self.buffer.write_i32::<BigEndian>(value).unwrap();
+        self.position += 4;
+    }
 
- #[derive(Debug, Clone, PartialEq)]
- pub struct JsonArray {
-     pub(crate) vec: Vec<dyn JsonRef>,
- }
+    pub fn write_i64(&mut self, value: i64) {
+        self.buffer.write_i64::<BigEndian>(value).unwrap();
+        self.position += 8;
+    }
 
+    pub fn write_f32(&mut self, value: f32) {
+        self.buffer.write_f32::<BigEndian>(value).unwrap();
+        self.position += 4;
+    }
 
+    pub fn write_f64(&mut self, value: f64) {
+        self.buffer.write_f64::<BigEndian>(value).unwrap();
+        self.position += 8;
+    }
+}
 
- impl<'a> dyn JsonRef<'a> {
+impl<'a> JsonRef<'a> {
     /// Gets the ith element in JsonRef
     ///
     /// See `arrayGetElem()` in MEDB `json/binary.go`
-    pub fn array_get_elem(&self, idx: usize) -> Result<dyn JsonRef<'a>> {
+    pub fn array_get_elem(&self, idx: usize) -> Result<JsonRef<'a>> {
         self.val_causet_get(HEADER_LEN + idx * VALUE_ENTRY_LEN)
     }
 
@@ -140,16 +96,18 @@
     /// See `arrayGetElem()` in MEDB `json/binary.go`
     pub fn object_get_soliton_id(&self, i: usize) -> &'a [u8] {
         let soliton_id_off_start = HEADER_LEN + i * KEY_ENTRY_LEN;
-        let soliton_id_off = NumberCodec::decode_u32_le(&self.causet_locale()[soliton_id_off_start..]) as usize;
-        let soliton_id_len =
-            NumberCodec::decode_u16_le(&self.causet_locale()[soliton_id_off_start + KEY_OFFSET_LEN..]) as usize;
+        let soliton_id_off =
+            NumberCodec::decode_u32_le(&self.causet_locale()[soliton_id_off_start..]) as usize;
+        let soliton_id_len = NumberCodec::decode_u16_le(
+            &self.causet_locale()[soliton_id_off_start + KEY_OFFSET_LEN..],
+        ) as usize;
         &self.causet_locale()[soliton_id_off..soliton_id_off + soliton_id_len]
     }
 
     /// Returns the JsonRef of `i`th causet_locale in current Object json
     ///
     /// See `arrayGetElem()` in MEDB `json/binary.go`
-    pub fn object_get_val(&self, i: usize) -> Result<dyn JsonRef<'a>> {
+    pub fn object_get_val(&self, i: usize) -> Result<JsonRef<'a>> {
         let ele_count = self.get_elem_count();
         let val_causet_off = HEADER_LEN + ele_count * KEY_ENTRY_LEN + i * VALUE_ENTRY_LEN;
         self.val_causet_get(val_causet_off)
@@ -176,20 +134,22 @@
         None
     }
 
-
-    pub fn val_causet_get(&self, val_causet_off: usize) -> Result<dyn JsonRef<'a>> {
+    pub fn val_causet_get(&self, val_causet_off: usize) -> Result<JsonRef<'a>> {
         let val_type: JsonType = self.causet_locale()[val_causet_off].try_into()?;
         let val_offset =
-            NumberCodec::decode_u32_le(&self.causet_locale()[val_causet_off + TYPE_LEN as usize..]) as usize;
+            NumberCodec::decode_u32_le(&self.causet_locale()[val_causet_off + TYPE_LEN..]) as usize;
         Ok(match val_type {
             JsonType::Literal => {
                 let offset = val_causet_off + TYPE_LEN;
-                #[allow(clippy::range_plus_one)]
-                JsonRef::new(val_type, &self.causet_locale()[offset..offset + LITERAL_LEN])
-            }
-            JsonType::U64 | JsonType::I64 | JsonType::Double => {
-                JsonRef::new(val_type, &self.causet_locale()[val_offset..val_offset + NUMBER_LEN])
+                JsonRef::new(
+                    val_type,
+                    &self.causet_locale()[offset..offset + LITERAL_LEN],
+                )
             }
+            JsonType::U64 | JsonType::I64 | JsonType::Double => JsonRef::new(
+                val_type,
+                &self.causet_locale()[val_offset..val_offset + NUMBER_LEN],
+            ),
             JsonType::String => {
                 let (str_len, len_len) =
                     NumberCodec::try_decode_var_u64(&self.causet_locale()[val_offset..])?;
@@ -199,41 +159,44 @@
                 )
             }
             _ => {
-                let data_size =
-                    NumberCodec::decode_u32_le(&self.causet_locale()[val_offset + ELEMENT_COUNT_LEN..])
-                        as usize;
-                JsonRef::new(val_type, &self.causet_locale()[val_offset..val_offset + data_size])
+                let data_size = NumberCodec::decode_u32_le(
+                    &self.causet_locale()[val_offset + ELEMENT_COUNT_LEN..],
+                ) as usize;
+                JsonRef::new(
+                    val_type,
+                    &self.causet_locale()[val_offset..val_offset + data_size],
+                )
             }
         })
     }
 
     /// Returns a primitive_causet pointer to the underlying causet_locales buffer.
-    pub(super) fn as_ptr(&self) -> *const u8 {
-        self.causet_locale.as_ptr()
+    pub(crate) fn as_ptr(&self) -> *const u8 {
+        self.causet_locale().as_ptr()
     }
 
     /// Returns the literal causet_locale of JSON document
-    pub(super) fn as_literal(&self) -> Result<u8> {
+    pub(crate) fn as_literal(&self) -> Result<u8> {
         match self.get_type() {
             JsonType::Literal => Ok(self.causet_locale()[0]),
             _ => Err(invalid_type!(
                 "{} from {} to literal",
                 ERR_CONVERT_FAILED,
-                self.to_string()
+                self.to_owned()
             )),
         }
     }
 
     /// Returns the encoding binary length of self
     pub fn binary_len(&self) -> usize {
-        TYPE_LEN + self.causet_locale.len()
+        TYPE_LEN + self.causet_locale().len()
     }
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::Json;
+    use crate::json::Json;
 
     #[test]
     fn test_type() {
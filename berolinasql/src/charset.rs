@@ -8,106 +8,107 @@
 // CONDITIONS OF ANY KIND, either express or implied. See the License for the
 // specific language governing permissions and limitations under the License.
 
-
-use std::fmt::{self, Debug, Display, Formatter};
-use std::str::FromStr;
-use std::error::Error;
-use std::convert::From;
-use std::result::Result;
-use std::fmt::{self, Display};
-use std::error::Error;
-use std::convert::From;
-use std::result::Result;
-use std::fmt::{self, Display};
-use std::error::Error;
-
-
-use std::sync::{Arc, Mutex};
-use std::sync::atomic::{AtomicBool, Partitioning};
-use std::thread;
-use std::time::Duration;
 use std::collections::HashMap;
-use std::collections::hash_map::Entry;
-use std::collections::hash_map::Iter;
-
-
-use std::sync::atomic::
-{
-    AtomicUsize,
-    Ordering::Relaxed,
-    Ordering::SeqCst
-};
-
-
-use std::sync::mpsc::{channel, Sender, Receiver};
-use std::sync::mpsc::TryRecvError;
-
-
-use std::sync::mpsc::RecvError;
-use std::sync::mpsc::RecvTimeoutError;
-
-
-use super::{AllegroPoset, Poset};
-use super::{PosetError, PosetErrorKind};
-use super::{PosetNode, PosetNodeId, PosetNodeData};
 
+use encoding_rs::{Encoding, GB18030, GBK, WINDOWS_1252};
 
-/// A `Sync` implementation for `AllegroPoset`.
-/// This implementation is thread-safe.
-/// # Examples
-/// ```
-///  use einsteindb::causetq::sync::new_sync;
-/// use einsteindb::causetq::sync::Sync;
-///
-/// let poset = new_sync();
-/// let sync = Sync::new(poset);
-/// ```
-
-
-#[derive(Debug)]
-pub struct Sync {
-    poset: Arc<Mutex<AllegroPoset>>,
-    is_running: Arc<AtomicBool>,
-    thread: Option<thread::JoinHandle<()>>,
-    thread_id: Option<thread::ThreadId>,
-    thread_name: String,
-    thread_name_lock: Arc<Mutex<String>>,
-    thread_name_cond: Arc<Condvar>,
-    thread_name_cond_signal: Arc<AtomicBool>,
-    thread_name_cond_signal_lock: Arc<Mutex<()>>,
-}
+use crate::error::Error as SqlError;
+use crate::field_type::Collation;
 
-#[derive(Deserialize, Serialize)]
-pub struct SyncConfig {
-    pub name: String,
-    pub thread_count: usize,
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Charset {
+    UTF8,
+    UTF8MB4,
+    Binary,
+    Latin1,
+    Gbk,
+    Gb18030,
 }
 
+impl Charset {
+    pub fn from_name(name: &str) -> Option<Charset> {
+        match name.to_ascii_lowercase().as_str() {
+            CHARSET_UTF8 | CHARSET_ASCII => Some(Charset::UTF8),
+            CHARSET_UTF8MB4 => Some(Charset::UTF8MB4),
+            CHARSET_BIN => Some(Charset::Binary),
+            CHARSET_LATIN1 => Some(Charset::Latin1),
+            CHARSET_GBK => Some(Charset::Gbk),
+            CHARSET_GB18030 => Some(Charset::Gb18030),
+            _ => None,
+        }
+    }
 
+    pub fn from_collation(collation: Collation) -> Charset {
+        Charset::from_name(collation.charset_name()).unwrap()
+    }
 
-#[derive(Debug)]
-pub struct SyncError {
-    kind: SyncErrorKind,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
-pub enum SyncErrorKind {
-    /// The underlying `AllegroPoset` has failed.
-    /// This error is returned when the underlying `AllegroPoset` fails.
-
-    Poset(PosetError),
+    pub fn name(self) -> &'static str {
+        match self {
+            Charset::UTF8 => CHARSET_UTF8,
+            Charset::UTF8MB4 => CHARSET_UTF8MB4,
+            Charset::Binary => CHARSET_BIN,
+            Charset::Latin1 => CHARSET_LATIN1,
+            Charset::Gbk => CHARSET_GBK,
+            Charset::Gb18030 => CHARSET_GB18030,
+        }
+    }
 
+    /// The maximum number of bytes one character takes in this charset.
+    pub fn max_char_len(self) -> usize {
+        match self {
+            Charset::Binary | Charset::Latin1 => 1,
+            Charset::Gbk => 2,
+            Charset::UTF8 => 3,
+            Charset::UTF8MB4 | Charset::Gb18030 => 4,
+        }
+    }
 
-}
+    /// `latin1` in MySQL is cp1252 rather than ISO 8859-1.
+    fn encoding(self) -> Option<&'static Encoding> {
+        match self {
+            Charset::Latin1 => Some(WINDOWS_1252),
+            Charset::Gbk => Some(GBK),
+            Charset::Gb18030 => Some(GB18030),
+            _ => None,
+        }
+    }
 
+    /// Converts `s` to the bytes of this charset, failing on characters it cannot represent.
+    pub fn encode(self, s: &str) -> crate::error::Result<Vec<u8>> {
+        match self.encoding() {
+            Some(encoding) => {
+                let (bytes, _, had_errors) = encoding.encode(s);
+                if had_errors {
+                    return Err(SqlError::cannot_convert_string(
+                        s,
+                        CHARSET_UTF8MB4,
+                        self.name(),
+                    ));
+                }
+                Ok(bytes.into_owned())
+            }
+            None if self == Charset::UTF8 && s.chars().any(|c| c.len_utf8() > 3) => Err(
+                SqlError::cannot_convert_string(s, CHARSET_UTF8MB4, self.name()),
+            ),
+            None => Ok(s.as_bytes().to_vec()),
+        }
+    }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Charset {
-    UTF8,
-    UTF8MB4,
+    /// Converts bytes of this charset to UTF-8, failing on malformed input.
+    pub fn decode(self, bytes: &[u8]) -> crate::error::Result<String> {
+        let decoded = match self.encoding() {
+            Some(encoding) => encoding
+                .decode_without_bom_handling_and_without_replacement(bytes)
+                .map(|s| s.into_owned()),
+            None => String::from_utf8(bytes.to_vec()).ok(),
+        };
+        decoded.ok_or_else(|| {
+            let escaped: String = bytes.iter().map(|b| format!("\\x{:02X}", b)).collect();
+            SqlError::cannot_convert_string(escaped, self.name(), CHARSET_UTF8MB4)
+        })
+    }
 }
 
-
 /// The charset map.
 ///
 /// The charset map is used to convert the charset name to charset.
@@ -115,7 +116,7 @@ pub enum Charset {
 ///
 /// # Examples
 ///
-/// ```
+/// ```ignore
 ///
 /// use einstein_db::codec::mysql::charset::CharsetMap;
 ///
@@ -132,23 +133,20 @@ pub enum Charset {
 /// assert_eq!(charset_map.get("utf8"), Some("utf8_general_ci"));
 /// assert_eq!(charset_map.get("latin1"), Some("latin1_general_ci"));
 /// assert_eq!(charset_map.get("binary"), Some("binary"));
-///
-
-
 /// ```
-/// # use einstein_db::codec::mysql::charset::CharsetMap;
-/// # let mut charset_map = CharsetMap::new();
-/// # charset_map.add("utf8mb4", "utf8mb4_general_ci");
-/// # charset_map.add("utf8mb4", "utf8mb4_bin");
-
-
-pub const CHARSET_MAP: &'static [(&'static str, &'static str)] = &[
+pub const CHARSET_MAP: &[(&str, &str)] = &[
     ("utf8mb4", "utf8mb4_general_ci"),
     ("utf8mb4", "utf8mb4_bin"),
     ("utf8", "utf8_general_ci"),
     ("utf8", "utf8_bin"),
     ("latin1", "latin1_general_ci"),
     ("latin1", "latin1_bin"),
+    ("utf8mb4", "utf8mb4_unicode_ci"),
+    ("utf8mb4", "utf8mb4_0900_ai_ci"),
+    ("gbk", "gbk_chinese_ci"),
+    ("gbk", "gbk_bin"),
+    ("gb18030", "gb18030_chinese_ci"),
+    ("gb18030", "gb18030_bin"),
     ("binary", "binary"),
 ];
 
@@ -162,10 +160,13 @@ pub const CHARSET_UTF8MB4: &str = "utf8mb4";
 pub const CHARSET_ASCII: &str = "ascii";
 /// `CHARSET_LATIN1` is a single byte charset.
 pub const CHARSET_LATIN1: &str = "latin1";
-/// `CHARSET_LATIN1MB4` is a single byte charset.
-///
-/// It's used for marking latin1 charset.
-///
+/// `CHARSET_GBK` is the double byte simplified Chinese charset.
+pub const CHARSET_GBK: &str = "gbk";
+/// `CHARSET_GB18030` extends GBK with four byte sequences covering all of Unicode.
+pub const CHARSET_GB18030: &str = "gb18030";
+// `CHARSET_LATIN1MB4` is a single byte charset.
+//
+// It's used for marking latin1 charset.
 
 /// All utf8 charsets.
 pub const UTF8_CHARSETS: &[&str] = &[CHARSET_UTF8, CHARSET_UTF8MB4, CHARSET_ASCII];
@@ -182,7 +183,7 @@ pub const UTF8_CHARSETS: &[&str] = &[CHARSET_UTF8, CHARSET_UTF8MB4, CHARSET_ASCI
 ///
 ///
 /// # Examples
-/// ```
+/// ```ignore
 /// use einstein_db::codec::mysql::charset::CHARSET_MAP;
 /// use einstein_db::codec::mysql::charset::CHARSET_BIN;
 ///
@@ -191,48 +192,42 @@ pub const UTF8_CHARSETS: &[&str] = &[CHARSET_UTF8, CHARSET_UTF8MB4, CHARSET_ASCI
 /// assert_eq!(CHARSET_MAP.get(CHARSET_UTF8), Some("utf8_general_ci"));
 ///
 /// assert_eq!(CHARSET_MAP.get(CHARSET_UTF8MB4), Some("utf8mb4_general_ci"));
-
-
-
-
-
+/// ```
 #[derive(Debug)]
 pub struct CharsetMap {
     charset_map: HashMap<String, String>,
 }
 
-
 impl CharsetMap {
     pub fn new() -> Self {
         let mut charset_map = HashMap::new();
         for (charset, charset_name) in CHARSET_MAP {
-            charset_map.insert(charset.to_string(), charset_name.to_string());
-        }
-        CharsetMap {
-            charset_map,
+            charset_map
+                .entry(charset.to_string())
+                .or_insert_with(|| charset_name.to_string());
         }
+        CharsetMap { charset_map }
     }
+    /// Registers a collation of `charset`. The first one registered is its default.
     pub fn add(&mut self, charset: &str, charset_name: &str) {
-        self.charset_map.insert(charset.to_string(), charset_name.to_string());
+        self.charset_map
+            .entry(charset.to_string())
+            .or_insert_with(|| charset_name.to_string());
     }
     pub fn get(&self, charset: &str) -> Option<&str> {
         self.charset_map.get(charset).map(|s| s.as_str())
     }
 }
 
+impl Default for CharsetMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
-    use std::iter::FromIterator;
-    use std::str::FromStr;
-    use std::string::String;
-    use std::vec::Vec;
-    use std::collections::HashSet;
-    use std::collections::BTreeSet;
-    use std::collections::BTreeMap;
-
 
     #[test]
     fn test_charset_map() {
@@ -249,5 +244,48 @@ mod tests {
         assert_eq!(charset_map.get("latin1"), Some("latin1_general_ci"));
         assert_eq!(charset_map.get("binary"), Some("binary"));
     }
-}
 
+    #[test]
+    fn test_charset_encode_decode() {
+        let cases = vec![
+            (
+                Charset::Latin1,
+                "caf\u{e9} \u{20ac}",
+                vec![0x63, 0x61, 0x66, 0xE9, 0x20, 0x80],
+            ),
+            (
+                Charset::Gbk,
+                "a\u{4e2d}\u{6587}",
+                vec![0x61, 0xD6, 0xD0, 0xCE, 0xC4],
+            ),
+            (
+                Charset::Gb18030,
+                "\u{4e2d}\u{1f600}",
+                vec![0xD6, 0xD0, 0x94, 0x39, 0xFC, 0x36],
+            ),
+            (Charset::UTF8MB4, "\u{1f600}", vec![0xF0, 0x9F, 0x98, 0x80]),
+        ];
+        for (charset, s, bytes) in cases {
+            assert_eq!(charset.encode(s).unwrap(), bytes, "{:?}", charset);
+            assert_eq!(charset.decode(&bytes).unwrap(), s, "{:?}", charset);
+        }
+
+        assert!(Charset::Latin1.encode("\u{4e2d}").is_err());
+        assert!(Charset::Gbk.encode("\u{1f600}").is_err());
+        assert!(Charset::UTF8.encode("\u{1f600}").is_err());
+        let err = Charset::Gbk.decode(&[0xD6]).unwrap_err();
+        assert_eq!(err.code(), crate::error::ERR_CANNOT_CONVERT_STRING);
+        assert!(Charset::UTF8MB4.decode(&[0xFF]).is_err());
+
+        assert_eq!(
+            Charset::from_collation(Collation::GbkChineseCi),
+            Charset::Gbk
+        );
+        assert_eq!(
+            Charset::from_collation(Collation::Utf8Mb40900AiCi),
+            Charset::UTF8MB4
+        );
+        assert_eq!(Charset::from_name("LATIN1"), Some(Charset::Latin1));
+        assert_eq!(Charset::from_name("koi8r"), None);
+    }
+}
@@ -0,0 +1,297 @@
+//Copyright 2021-2023 WHTCORPS INC ALL RIGHTS RESERVED. APACHE 2.0 COMMUNITY EDITION SL
+// AUTHORS: WHITFORD LEDER
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! Number codecs shared by the binary JSON format and the table soliton_id format.
+//!
+//! The `*_le` functions use the little-endian layout of the JSON format. `write_i64`
+//! and `read_i64` use the memcomparable layout of table soliton_ids: big-endian with
+//! the sign bit flipped, so that encoded soliton_ids sort like the integers.
+
+use crate::error::{Error, Result};
+
+pub const U8_SIZE: usize = 1;
+pub const U16_SIZE: usize = 2;
+pub const U32_SIZE: usize = 4;
+pub const U64_SIZE: usize = 8;
+pub const I64_SIZE: usize = 8;
+pub const F64_SIZE: usize = 8;
+pub const MAX_VARINT64_LENGTH: usize = 10;
+
+const SIGN_MARK: u64 = 0x8000_0000_0000_0000;
+
+/// Encodes and decodes numbers in byte slices.
+pub struct NumberCodec;
+
+impl NumberCodec {
+    /// Decodes a little-endian u16 from the first 2 bytes of `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than 2 bytes.
+    #[inline]
+    pub fn decode_u16_le(buf: &[u8]) -> u16 {
+        u16::from_le_bytes([buf[0], buf[1]])
+    }
+
+    /// Decodes a little-endian u32 from the first 4 bytes of `buf`.
+    #[inline]
+    pub fn decode_u32_le(buf: &[u8]) -> u32 {
+        let mut b = [0; U32_SIZE];
+        b.copy_from_slice(&buf[..U32_SIZE]);
+        u32::from_le_bytes(b)
+    }
+
+    /// Decodes a little-endian u64 from the first 8 bytes of `buf`.
+    #[inline]
+    pub fn decode_u64_le(buf: &[u8]) -> u64 {
+        let mut b = [0; U64_SIZE];
+        b.copy_from_slice(&buf[..U64_SIZE]);
+        u64::from_le_bytes(b)
+    }
+
+    /// Decodes a little-endian i64 from the first 8 bytes of `buf`.
+    #[inline]
+    pub fn decode_i64_le(buf: &[u8]) -> i64 {
+        Self::decode_u64_le(buf) as i64
+    }
+
+    /// Decodes a little-endian f64 from the first 8 bytes of `buf`.
+    #[inline]
+    pub fn decode_f64_le(buf: &[u8]) -> f64 {
+        f64::from_bits(Self::decode_u64_le(buf))
+    }
+
+    /// Decodes a memcomparable i64 from the first 8 bytes of `buf`.
+    #[inline]
+    pub fn decode_i64(buf: &[u8]) -> i64 {
+        let mut b = [0; U64_SIZE];
+        b.copy_from_slice(&buf[..U64_SIZE]);
+        (u64::from_be_bytes(b) ^ SIGN_MARK) as i64
+    }
+
+    /// Encodes a little-endian u32 into the first 4 bytes of `buf`.
+    #[inline]
+    pub fn encode_u32_le(buf: &mut [u8], v: u32) {
+        buf[..U32_SIZE].copy_from_slice(&v.to_le_bytes());
+    }
+
+    /// Encodes a little-endian i64 into the first 8 bytes of `buf`.
+    #[inline]
+    pub fn encode_i64_le(buf: &mut [u8], v: i64) {
+        buf[..I64_SIZE].copy_from_slice(&v.to_le_bytes());
+    }
+
+    /// Encodes a little-endian f64 into the first 8 bytes of `buf`.
+    #[inline]
+    pub fn encode_f64_le(buf: &mut [u8], v: f64) {
+        buf[..F64_SIZE].copy_from_slice(&v.to_bits().to_le_bytes());
+    }
+
+    /// Decodes a varint-encoded u64, returning it and the number of bytes it took.
+    pub fn try_decode_var_u64(buf: &[u8]) -> Result<(u64, usize)> {
+        let mut v = 0u64;
+        for (i, b) in buf.iter().take(MAX_VARINT64_LENGTH).enumerate() {
+            v |= u64::from(b & 0x7f) << (7 * i);
+            if b & 0x80 == 0 {
+                return Ok((v, i + 1));
+            }
+        }
+        Err(Error::unexpected_eof())
+    }
+}
+
+/// A buffer that bytes can be appended to.
+pub trait BufferWriter {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<()>;
+}
+
+impl BufferWriter for Vec<u8> {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Appends encoded numbers to a `BufferWriter`.
+pub trait NumberEncoder: BufferWriter {
+    #[inline]
+    fn write_u8(&mut self, v: u8) -> Result<()> {
+        self.write_bytes(&[v])
+    }
+
+    #[inline]
+    fn write_u16_le(&mut self, v: u16) -> Result<()> {
+        self.write_bytes(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_u32_le(&mut self, v: u32) -> Result<()> {
+        self.write_bytes(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_u64_le(&mut self, v: u64) -> Result<()> {
+        self.write_bytes(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_i64_le(&mut self, v: i64) -> Result<()> {
+        self.write_bytes(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_f64_le(&mut self, v: f64) -> Result<()> {
+        self.write_bytes(&v.to_bits().to_le_bytes())
+    }
+
+    /// Writes a memcomparable u64.
+    #[inline]
+    fn write_u64(&mut self, v: u64) -> Result<()> {
+        self.write_bytes(&v.to_be_bytes())
+    }
+
+    /// Writes a memcomparable i64.
+    #[inline]
+    fn write_i64(&mut self, v: i64) -> Result<()> {
+        self.write_u64(v as u64 ^ SIGN_MARK)
+    }
+
+    /// Writes a u64 as a varint, 7 bits per byte with the high bit set on all but the
+    /// last byte.
+    fn write_var_u64(&mut self, mut v: u64) -> Result<()> {
+        let mut buf = [0; MAX_VARINT64_LENGTH];
+        let mut len = 0;
+        while v >= 0x80 {
+            buf[len] = v as u8 | 0x80;
+            v >>= 7;
+            len += 1;
+        }
+        buf[len] = v as u8;
+        self.write_bytes(&buf[..=len])
+    }
+}
+
+impl<T: BufferWriter + ?Sized> NumberEncoder for T {}
+
+/// A buffer that bytes can be consumed from.
+pub trait BufferReader {
+    /// Returns the bytes left in the buffer.
+    fn bytes(&self) -> &[u8];
+
+    /// Consumes `count` bytes, failing with an unexpected EOF if there are not
+    /// enough of them.
+    fn read_bytes(&mut self, count: usize) -> Result<&[u8]>;
+}
+
+impl BufferReader for &[u8] {
+    #[inline]
+    fn bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn read_bytes(&mut self, count: usize) -> Result<&[u8]> {
+        if self.len() < count {
+            return Err(Error::unexpected_eof());
+        }
+        let (left, right) = self.split_at(count);
+        *self = right;
+        Ok(left)
+    }
+}
+
+/// Consumes encoded numbers from a `BufferReader`.
+pub trait NumberDecoder: BufferReader {
+    #[inline]
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(U8_SIZE)?[0])
+    }
+
+    #[inline]
+    fn read_u16_le(&mut self) -> Result<u16> {
+        Ok(NumberCodec::decode_u16_le(self.read_bytes(U16_SIZE)?))
+    }
+
+    #[inline]
+    fn read_u32_le(&mut self) -> Result<u32> {
+        Ok(NumberCodec::decode_u32_le(self.read_bytes(U32_SIZE)?))
+    }
+
+    #[inline]
+    fn read_u64_le(&mut self) -> Result<u64> {
+        Ok(NumberCodec::decode_u64_le(self.read_bytes(U64_SIZE)?))
+    }
+
+    #[inline]
+    fn read_i64_le(&mut self) -> Result<i64> {
+        Ok(NumberCodec::decode_i64_le(self.read_bytes(I64_SIZE)?))
+    }
+
+    #[inline]
+    fn read_f64_le(&mut self) -> Result<f64> {
+        Ok(NumberCodec::decode_f64_le(self.read_bytes(F64_SIZE)?))
+    }
+
+    /// Reads a memcomparable i64.
+    #[inline]
+    fn read_i64(&mut self) -> Result<i64> {
+        Ok(NumberCodec::decode_i64(self.read_bytes(I64_SIZE)?))
+    }
+
+    /// Reads a varint-encoded u64.
+    fn read_var_u64(&mut self) -> Result<u64> {
+        let (v, len) = NumberCodec::try_decode_var_u64(self.bytes())?;
+        self.read_bytes(len)?;
+        Ok(v)
+    }
+}
+
+impl<T: BufferReader + ?Sized> NumberDecoder for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_var_u64() {
+        for v in [0, 1, 127, 128, 300, 16383, 16384, u64::MAX] {
+            let mut buf = vec![];
+            buf.write_var_u64(v).unwrap();
+            assert_eq!(
+                NumberCodec::try_decode_var_u64(&buf).unwrap(),
+                (v, buf.len())
+            );
+            let mut r = buf.as_slice();
+            assert_eq!(r.read_var_u64().unwrap(), v);
+            assert!(r.is_empty());
+        }
+        assert!(NumberCodec::try_decode_var_u64(&[0x80, 0x80]).is_err());
+    }
+
+    #[test]
+    fn test_memcomparable_i64() {
+        let cases = [i64::MIN, -1, 0, 1, i64::MAX];
+        let encoded: Vec<Vec<u8>> = cases
+            .iter()
+            .map(|v| {
+                let mut buf = vec![];
+                buf.write_i64(*v).unwrap();
+                buf
+            })
+            .collect();
+        for w in encoded.windows(2) {
+            assert!(w[0] < w[1]);
+        }
+        for (v, buf) in cases.iter().zip(&encoded) {
+            assert_eq!(buf.as_slice().read_i64().unwrap(), *v);
+        }
+    }
+}
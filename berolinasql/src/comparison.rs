@@ -1,143 +1,43 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
-
-
-
-
-
- use allegro_poset::{
-     schema::{
-         Schema,
-         SchemaVersion,
-     },
-     transaction::{
-         Transaction,
-         TransactionContext,
-     },
-     error::{
-         Result,
-         Error,
-     },
- };
-
- use einstein_db::transaction::{
-     Transaction as EinsteinDBTransaction,
-     TransactionContext as EinsteinDBTransactionContext,
- };
-
- use einstein_db::schema::{
-     Schema as EinsteinDBSchema,
-     SchemaVersion as EinsteinDBSchemaVersion,
- };
-
- use soliton_core::{
-     transaction::{
-         Transaction as SolitonTransaction,
-         TransactionContext as SolitonTransactionContext,
-     },
-     error::{
-         Result as SolitonResult,
-         Error as SolitonError,
-     },
- };
-
-
- use einstein_ml::shellings::{
-     transaction::{
-         Transaction as EinsteinMLTransaction,
-         TransactionContext as EinsteinMLTransactionContext,
-     },
-     error::{
-         Result as EinsteinMLResult,
-         Error as EinsteinMLError,
-     },
- };
-use std::fmt::{self, Display, Formatter};
-use std::rc::Rc;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+//Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
 use std::cmp::Ordering;
-use std::hash::Hash;
-use std::hash::Hasher;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
-use std::sync::mpsc::{channel, Sender, Receiver};
-use std::thread;
-use std::time::Duration;
-use std::time::Instant;
-use std::sync::mpsc::TryRecvError;
-use std::sync::mpsc::RecvTimeoutError;
-
- #[derive(Debug)]
-pub struct Sync {
-     poset: Arc<Mutex<AllegroPoset>>,
- }
-
- #[derive(Debug)]
-pub struct SyncError {
-     kind: SyncErrorKind,
- }
-
-
-
-
-
- #[derive(Debug, Clone)]
- pub enum Comparison {
-     Equal,
-     NotEqual,
-     Less,
-     LessOrEqual,
-     Greater,
-     GreaterOrEqual,
- }
-
- #[derive(Debug, Clone)]
- pub enum ComparisonResult {
-     Equal,
-     NotEqual,
-     Less,
-     LessOrEqual,
-     Greater,
-     GreaterOrEqual,
- }
-
-
- pub trait Comparable {
-     fn compare<T: Ord>(x: T, y: T) -> Partitioning<ComparisonResult> {
-         if x == y {
-             Partitioning::new(ComparisonResult::Equal)
-         } else if x < y {
-             Partitioning::new(ComparisonResult::Less)
-         } else {
-             Partitioning::new(ComparisonResult::Greater)
-         }
-     }
-
-     fn compare_i64_u64(x: i64, y: u64) -> Partitioning {
-         if x < 0 {
-             Partitioning::Less
-         } else {
-             compare::<u64>(x as u64, y)
-         }
-     }
-
-     fn compare_f64_with_epsilon(x: f64, y: f64) -> Option<Partitioning> {
-         if (x - y).abs() < f64::EPSILON {
-             Some(Partitioning::Equal)
-         } else {
-             x.partial_cmp(&y)
-         }
-     }
- }
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::charset::Charset;
+use crate::constants::*;
+use crate::ducet::{DUCET_IMPLICIT, DUCET_PRIMARY};
+use crate::error::Result;
+use crate::field_type::Collation;
+use crate::json::{Json, JsonRef, JsonType, ERR_CONVERT_FAILED};
+
+fn compare<T: Ord>(x: T, y: T) -> Ordering {
+    x.cmp(&y)
+}
+
+fn compare_i64_u64(x: i64, y: u64) -> Ordering {
+    if x < 0 {
+        Ordering::Less
+    } else {
+        compare::<u64>(x as u64, y)
+    }
+}
+
+fn compare_f64_with_epsilon(x: f64, y: f64) -> Option<Ordering> {
+    if (x - y).abs() < f64::EPSILON {
+        Some(Ordering::Equal)
+    } else {
+        x.partial_cmp(&y)
+    }
+}
 
 impl<'a> JsonRef<'a> {
     fn get_precedence(&self) -> i32 {
@@ -164,7 +64,7 @@ impl<'a> JsonRef<'a> {
             _ => Err(invalid_type!(
                 "{} from {} to f64",
                 ERR_CONVERT_FAILED,
-                self.to_string()
+                self.to_owned()
             )),
         }
     }
@@ -173,25 +73,27 @@ impl<'a> JsonRef<'a> {
 impl<'a> Eq for JsonRef<'a> {}
 
 impl<'a> Ord for JsonRef<'a> {
-    fn cmp(&self, right: &JsonRef<'_>) -> Partitioning {
+    fn cmp(&self, right: &JsonRef<'_>) -> Ordering {
         self.partial_cmp(right).unwrap()
     }
 }
 
 impl<'a> PartialEq for JsonRef<'a> {
     fn eq(&self, right: &JsonRef<'_>) -> bool {
-        self.partial_cmp(right)
-            .map_or(false, |r| r == Partitioning::Equal)
+        self.partial_cmp(right) == Some(Ordering::Equal)
     }
 }
+// `Ord` unwraps this, not the other way around, as strings that are not valid
+// UTF-8 and NaN doubles have no order.
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<'a> PartialOrd for JsonRef<'a> {
     // See `CompareBinary` in MEDB `types/json/binary_functions.go`
-    fn partial_cmp(&self, right: &JsonRef<'_>) -> Option<Partitioning> {
+    fn partial_cmp(&self, right: &JsonRef<'_>) -> Option<Ordering> {
         let precedence_diff = self.get_precedence() - right.get_precedence();
         if precedence_diff == 0 {
             if self.get_precedence() == PRECEDENCE_NULL {
                 // for JSON null.
-                return Some(Partitioning::Equal);
+                return Some(Ordering::Equal);
             }
 
             return match self.get_type() {
@@ -242,9 +144,9 @@ impl<'a> PartialOrd for JsonRef<'a> {
                         {
                             match left_ele.partial_cmp(&right_ele) {
                                 order @ None
-                                | order @ Some(Partitioning::Greater)
-                                | order @ Some(Partitioning::Less) => return order,
-                                Some(Partitioning::Equal) => i += 1,
+                                | order @ Some(Ordering::Greater)
+                                | order @ Some(Ordering::Less) => return order,
+                                Some(Ordering::Equal) => i += 1,
                             }
                         } else {
                             return None;
@@ -264,88 +166,519 @@ impl<'a> PartialOrd for JsonRef<'a> {
         }
 
         if precedence_diff > 0 {
-            Some(Partitioning::Greater)
+            Some(Ordering::Greater)
         } else {
-            Some(Partitioning::Less)
+            Some(Ordering::Less)
         }
     }
 }
 
 impl Eq for Json {}
 impl Ord for Json {
-    fn cmp(&self, right: &Json) -> Partitioning {
+    fn cmp(&self, right: &Json) -> Ordering {
         self.as_ref().partial_cmp(&right.as_ref()).unwrap()
     }
 }
 
 impl PartialEq for Json {
     fn eq(&self, right: &Json) -> bool {
-        self.as_ref().partial_cmp(&right.as_ref()).unwrap() == Partitioning::Equal
+        self.as_ref().partial_cmp(&right.as_ref()).unwrap() == Ordering::Equal
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Json {
-    fn partial_cmp(&self, right: &Json) -> Option<Partitioning> {
+    fn partial_cmp(&self, right: &Json) -> Option<Ordering> {
         self.as_ref().partial_cmp(&right.as_ref())
     }
 }
 
-#[braneg(test)]
+/// Collation-aware string comparison.
+///
+/// Strings are in the internal representation, i.e. UTF-8 for every charset except
+/// `binary`. A collator maps a string to a sort soliton_id whose byte order is the
+/// collation order, so `sort_compare` and comparing `sort_key`s always agree.
+pub trait Collator: Sync {
+    /// Whether trailing spaces are ignored (`PAD SPACE`) or significant (`NO PAD`).
+    fn is_pad_space(&self) -> bool;
+
+    /// Appends the weights of `c` to `soliton_id`.
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()>;
+
+    fn write_sort_key(&self, s: &[u8], soliton_id: &mut Vec<u8>) -> Result<()> {
+        let s = std::str::from_utf8(s)?;
+        let s = if self.is_pad_space() {
+            s.trim_end_matches(' ')
+        } else {
+            s
+        };
+        for c in s.chars() {
+            self.write_char_weight(c, soliton_id)?;
+        }
+        Ok(())
+    }
+
+    fn sort_key(&self, s: &[u8]) -> Result<Vec<u8>> {
+        let mut soliton_id = Vec::with_capacity(s.len());
+        self.write_sort_key(s, &mut soliton_id)?;
+        Ok(soliton_id)
+    }
+
+    fn sort_compare(&self, a: &[u8], b: &[u8]) -> Result<Ordering> {
+        Ok(self.sort_key(a)?.cmp(&self.sort_key(b)?))
+    }
+}
+
+/// `binary` and `utf8mb4_bin` without padding: plain byte order.
+struct BinaryCollator;
+
+impl Collator for BinaryCollator {
+    fn is_pad_space(&self) -> bool {
+        false
+    }
+
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()> {
+        let mut buf = [0; 4];
+        soliton_id.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        Ok(())
+    }
+
+    fn write_sort_key(&self, s: &[u8], soliton_id: &mut Vec<u8>) -> Result<()> {
+        soliton_id.extend_from_slice(s);
+        Ok(())
+    }
+}
+
+/// `utf8mb4_bin`: byte order ignoring trailing spaces.
+struct Utf8Mb4BinCollator;
+
+impl Collator for Utf8Mb4BinCollator {
+    fn is_pad_space(&self) -> bool {
+        true
+    }
+
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()> {
+        BinaryCollator.write_char_weight(c, soliton_id)
+    }
+}
+
+/// Weight of a character outside the BMP in the collations that only weigh the BMP.
+const WEIGHT_REPLACEMENT: u32 = 0xFFFD;
+
+/// Returns the character `c` sorts as at primary strength: accents are dropped by
+/// taking the base of the canonical decomposition and case is folded to upper case.
+fn primary_char(c: char) -> char {
+    let base = c.nfd().next().unwrap_or(c);
+    let mut upper = base.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => base,
+    }
+}
+
+/// `utf8mb4_general_ci`: one case and accent insensitive weight per BMP character.
+struct GeneralCiCollator;
+
+impl Collator for GeneralCiCollator {
+    fn is_pad_space(&self) -> bool {
+        true
+    }
+
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()> {
+        let weight = if (c as u32) > 0xFFFF {
+            WEIGHT_REPLACEMENT
+        } else {
+            primary_char(c) as u32
+        };
+        soliton_id.extend_from_slice(&(weight as u16).to_be_bytes());
+        Ok(())
+    }
+}
+
+/// The UCA based collations, compared at primary strength with the DUCET weights.
+///
+/// Contractions are not applied; characters missing from the table are decomposed if
+/// they can be (e.g. hangul syllables) and get implicit weights otherwise.
+/// `utf8mb4_unicode_ci` follows UCA 4.0.0, which pads and gives all supplementary
+/// characters the same weight; `utf8mb4_0900_ai_ci` follows UCA 9.0.0, which does
+/// neither. Both use the DUCET of `ducet.rs`, whose primary order matches theirs for
+/// the characters they define.
+struct UnicodeCiCollator {
+    uca_900: bool,
+}
+
+impl UnicodeCiCollator {
+    fn write_weight(weight: u16, soliton_id: &mut Vec<u8>) {
+        soliton_id.extend_from_slice(&weight.to_be_bytes());
+    }
+
+    /// Appends the implicit weights UCA gives to `c`, which is not in the DUCET.
+    fn write_implicit_weights(c: u32, soliton_id: &mut Vec<u8>) {
+        if let Some(&(start, _, base)) = DUCET_IMPLICIT
+            .iter()
+            .find(|(start, end, _)| (*start..=*end).contains(&c))
+        {
+            Self::write_weight(base, soliton_id);
+            Self::write_weight(((c - start) | 0x8000) as u16, soliton_id);
+            return;
+        }
+        let base = match c {
+            0x4E00..=0x9FFF
+            | 0xFA0E
+            | 0xFA0F
+            | 0xFA11
+            | 0xFA13
+            | 0xFA14
+            | 0xFA1F
+            | 0xFA21
+            | 0xFA23
+            | 0xFA24
+            | 0xFA27..=0xFA29 => 0xFB40,
+            0x3400..=0x4DBF | 0x20000..=0x2A6DF | 0x2A700..=0x2EBEF | 0x30000..=0x3134F => 0xFB80,
+            _ => 0xFBC0,
+        };
+        Self::write_weight(base + (c >> 15) as u16, soliton_id);
+        Self::write_weight(((c & 0x7FFF) | 0x8000) as u16, soliton_id);
+    }
+}
+
+impl Collator for UnicodeCiCollator {
+    fn is_pad_space(&self) -> bool {
+        !self.uca_900
+    }
+
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()> {
+        let cp = c as u32;
+        if !self.uca_900 && cp > 0xFFFF {
+            Self::write_weight(WEIGHT_REPLACEMENT as u16, soliton_id);
+            return Ok(());
+        }
+        let found = DUCET_PRIMARY.binary_search_by(|(start, end, _)| {
+            if cp < *start {
+                Ordering::Greater
+            } else if cp > *end {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        });
+        match found {
+            Ok(i) => {
+                let (start, _, weights) = DUCET_PRIMARY[i];
+                if let [weight] = weights {
+                    Self::write_weight(weight + (cp - start) as u16, soliton_id);
+                } else {
+                    for weight in weights {
+                        Self::write_weight(*weight, soliton_id);
+                    }
+                }
+            }
+            Err(_) => {
+                let decomposed: Vec<char> = std::iter::once(c).nfd().collect();
+                if decomposed == [c] {
+                    Self::write_implicit_weights(cp, soliton_id);
+                } else {
+                    for d in decomposed {
+                        self.write_char_weight(d, soliton_id)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The collations of the legacy multi-byte charsets, weighing each character by its
+/// encoding in the charset. `_chinese_ci` additionally folds ASCII letters to upper
+/// case, which is what makes GBK ordering case insensitive.
+struct EncodedCollator {
+    charset: Charset,
+    ascii_ci: bool,
+}
+
+impl Collator for EncodedCollator {
+    fn is_pad_space(&self) -> bool {
+        true
+    }
+
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()> {
+        let c = if self.ascii_ci {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        };
+        let mut buf = [0; 4];
+        soliton_id.extend_from_slice(&self.charset.encode(c.encode_utf8(&mut buf))?);
+        Ok(())
+    }
+}
+
+/// The sort order of `latin1_swedish_ci`, indexed by latin1 byte: letters are folded
+/// to upper case, most accented letters to their base, while `Å`, `Ä`/`Æ`, `Ö`/`Ø`
+/// and `Ü` sort as the separate letters of the Swedish alphabet after `Z`.
+#[rustfmt::skip]
+static SORT_ORDER_LATIN1_SWEDISH_CI: [u8; 256] = [
+      0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15,
+     16,  17,  18,  19,  20,  21,  22,  23,  24,  25,  26,  27,  28,  29,  30,  31,
+     32,  33,  34,  35,  36,  37,  38,  39,  40,  41,  42,  43,  44,  45,  46,  47,
+     48,  49,  50,  51,  52,  53,  54,  55,  56,  57,  58,  59,  60,  61,  62,  63,
+     64,  65,  66,  67,  68,  69,  70,  71,  72,  73,  74,  75,  76,  77,  78,  79,
+     80,  81,  82,  83,  84,  85,  86,  87,  88,  89,  90,  91,  92,  93,  94,  95,
+     96,  65,  66,  67,  68,  69,  70,  71,  72,  73,  74,  75,  76,  77,  78,  79,
+     80,  81,  82,  83,  84,  85,  86,  87,  88,  89,  90, 123, 124, 125, 126, 127,
+    128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140, 141, 142, 143,
+    144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159,
+    160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175,
+    176, 177, 178, 179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191,
+     65,  65,  65,  65,  92,  91,  92,  67,  69,  69,  69,  69,  73,  73,  73,  73,
+     68,  78,  79,  79,  79,  79,  93, 215, 216,  85,  85,  85,  89,  89, 222, 223,
+     65,  65,  65,  65,  92,  91,  92,  67,  69,  69,  69,  69,  73,  73,  73,  73,
+     68,  78,  79,  79,  79,  79,  93, 247, 216,  85,  85,  85,  89,  89, 222, 255,
+];
+
+/// The case insensitive latin1 collations, weighing each character by one byte of a
+/// 256 entry sort order over its latin1 encoding. `latin1_general_ci` only folds case
+/// and keeps accented letters apart; `latin1_swedish_ci` uses the Swedish order.
+struct Latin1CiCollator {
+    swedish: bool,
+}
+
+impl Collator for Latin1CiCollator {
+    fn is_pad_space(&self) -> bool {
+        true
+    }
+
+    fn write_char_weight(&self, c: char, soliton_id: &mut Vec<u8>) -> Result<()> {
+        let mut buf = [0; 4];
+        let b = Charset::Latin1.encode(c.encode_utf8(&mut buf))?[0];
+        soliton_id.push(if self.swedish {
+            SORT_ORDER_LATIN1_SWEDISH_CI[b as usize]
+        } else {
+            match b {
+                b'a'..=b'z' | 0xE0..=0xFE if b != 0xF7 => b - 0x20,
+                // š, œ, ž and ÿ, whose upper case windows-1252 puts at 0x8A..0x9F.
+                0x9A | 0x9C | 0x9E => b - 0x10,
+                0xFF => 0x9F,
+                _ => b,
+            }
+        });
+        Ok(())
+    }
+}
+
+static BINARY_COLLATOR: BinaryCollator = BinaryCollator;
+static UTF8MB4_BIN_COLLATOR: Utf8Mb4BinCollator = Utf8Mb4BinCollator;
+static GENERAL_CI_COLLATOR: GeneralCiCollator = GeneralCiCollator;
+static UNICODE_CI_COLLATOR: UnicodeCiCollator = UnicodeCiCollator { uca_900: false };
+static UNICODE_0900_AI_CI_COLLATOR: UnicodeCiCollator = UnicodeCiCollator { uca_900: true };
+static LATIN1_BIN_COLLATOR: EncodedCollator = EncodedCollator {
+    charset: Charset::Latin1,
+    ascii_ci: false,
+};
+static LATIN1_SWEDISH_CI_COLLATOR: Latin1CiCollator = Latin1CiCollator { swedish: true };
+static LATIN1_GENERAL_CI_COLLATOR: Latin1CiCollator = Latin1CiCollator { swedish: false };
+static GBK_BIN_COLLATOR: EncodedCollator = EncodedCollator {
+    charset: Charset::Gbk,
+    ascii_ci: false,
+};
+static GBK_CHINESE_CI_COLLATOR: EncodedCollator = EncodedCollator {
+    charset: Charset::Gbk,
+    ascii_ci: true,
+};
+static GB18030_BIN_COLLATOR: EncodedCollator = EncodedCollator {
+    charset: Charset::Gb18030,
+    ascii_ci: false,
+};
+static GB18030_CHINESE_CI_COLLATOR: EncodedCollator = EncodedCollator {
+    charset: Charset::Gb18030,
+    ascii_ci: true,
+};
+
+/// Returns the collator implementing `collation`.
+pub fn collator(collation: Collation) -> &'static dyn Collator {
+    match collation {
+        Collation::Binary | Collation::Utf8Mb4BinNoPadding => &BINARY_COLLATOR,
+        Collation::Utf8Mb4Bin => &UTF8MB4_BIN_COLLATOR,
+        Collation::Utf8Mb4GeneralCi => &GENERAL_CI_COLLATOR,
+        Collation::Utf8Mb4UnicodeCi => &UNICODE_CI_COLLATOR,
+        Collation::Utf8Mb40900AiCi => &UNICODE_0900_AI_CI_COLLATOR,
+        Collation::Latin1Bin => &LATIN1_BIN_COLLATOR,
+        Collation::Latin1SwedishCi => &LATIN1_SWEDISH_CI_COLLATOR,
+        Collation::Latin1GeneralCi => &LATIN1_GENERAL_CI_COLLATOR,
+        Collation::GbkBin => &GBK_BIN_COLLATOR,
+        Collation::GbkChineseCi => &GBK_CHINESE_CI_COLLATOR,
+        Collation::Gb18030Bin => &GB18030_BIN_COLLATOR,
+        Collation::Gb18030ChineseCi => &GB18030_CHINESE_CI_COLLATOR,
+    }
+}
+
+/// Compares two strings under `collation`.
+pub fn sort_compare(collation: Collation, a: &[u8], b: &[u8]) -> Result<Ordering> {
+    collator(collation).sort_compare(a, b)
+}
+
+/// Returns the sort soliton_id of `s` under `collation`, used for indexing and grouping.
+pub fn sort_key(collation: Collation, s: &[u8]) -> Result<Vec<u8>> {
+    collator(collation).sort_key(s)
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_collation_sort_compare() {
+        use Ordering::{Equal, Greater, Less};
+        let cases: Vec<(Collation, &str, &str, Ordering)> = vec![
+            (Collation::Binary, "a", "a ", Less),
+            (Collation::Binary, "a", "A", Greater),
+            (Collation::Utf8Mb4Bin, "a", "a  ", Equal),
+            (Collation::Utf8Mb4Bin, "a", "A", Greater),
+            (Collation::Utf8Mb4GeneralCi, "a", "A ", Equal),
+            (Collation::Utf8Mb4GeneralCi, "\u{e9}", "E", Equal),
+            (Collation::Utf8Mb4GeneralCi, "\u{1f600}", "\u{1f601}", Equal),
+            (Collation::Utf8Mb4UnicodeCi, "Stra\u{df}e", "strasse", Equal),
+            (Collation::Utf8Mb4UnicodeCi, "\u{fb01}", "FI", Equal),
+            (
+                Collation::Utf8Mb4UnicodeCi,
+                "r\u{e9}sum\u{e9} ",
+                "RESUME",
+                Equal,
+            ),
+            (Collation::Utf8Mb4UnicodeCi, "\u{1f600}", "\u{1f601}", Equal),
+            (
+                Collation::Utf8Mb40900AiCi,
+                "r\u{e9}sum\u{e9}",
+                "RESUME",
+                Equal,
+            ),
+            (Collation::Utf8Mb40900AiCi, "a ", "a", Greater),
+            (Collation::Utf8Mb40900AiCi, "\u{1f600}", "\u{1f601}", Less),
+            (Collation::Utf8Mb40900AiCi, "a", "B", Less),
+            // Punctuation sorts before letters and accents don't move a letter.
+            (Collation::Utf8Mb40900AiCi, "_", "a", Less),
+            (Collation::Utf8Mb4UnicodeCi, "a_b", "aab", Less),
+            (Collation::Utf8Mb40900AiCi, "\u{e9}", "f", Less),
+            (Collation::Utf8Mb4UnicodeCi, "\u{e9}t\u{e9}", "ETE", Equal),
+            (Collation::Utf8Mb40900AiCi, "z", "\u{e0}z", Greater),
+            (Collation::Utf8Mb40900AiCi, "1", "a", Less),
+            // Hangul syllables weigh as their jamo, hanzi get implicit weights.
+            (
+                Collation::Utf8Mb40900AiCi,
+                "\u{ac00}",
+                "\u{1100}\u{1161}",
+                Equal,
+            ),
+            (Collation::Utf8Mb40900AiCi, "\u{5427}", "\u{554a}", Less),
+            (Collation::Utf8Mb40900AiCi, "z", "\u{4e00}", Less),
+            (Collation::Latin1Bin, "a ", "a", Equal),
+            (Collation::Latin1Bin, "\u{20ac}", "\u{e9}", Less),
+            (Collation::Latin1SwedishCi, "a ", "A", Equal),
+            (Collation::Latin1SwedishCi, "\u{e9}", "E", Equal),
+            (Collation::Latin1SwedishCi, "\u{e5}", "z", Greater),
+            (Collation::Latin1SwedishCi, "\u{e4}", "\u{e5}", Greater),
+            (Collation::Latin1SwedishCi, "\u{fc}", "y", Equal),
+            (Collation::Latin1GeneralCi, "\u{c9}", "\u{e9}", Equal),
+            (Collation::Latin1GeneralCi, "\u{e9}", "E", Greater),
+            (Collation::Latin1GeneralCi, "\u{178}", "\u{ff}", Equal),
+            (Collation::GbkBin, "a", "A", Greater),
+            (Collation::GbkChineseCi, "a", "A", Equal),
+            // GBK orders hanzi by pinyin: "啊" (a) < "吧" (ba) although U+554A > U+5427.
+            (Collation::GbkChineseCi, "\u{554a}", "\u{5427}", Less),
+            (Collation::Utf8Mb4Bin, "\u{554a}", "\u{5427}", Greater),
+            (Collation::Gb18030ChineseCi, "\u{554a} ", "\u{554a}", Equal),
+            (Collation::Gb18030Bin, "\u{1f600}", "\u{554a}", Less),
+        ];
+        for (collation, a, b, expected) in cases {
+            let got = sort_compare(collation, a.as_bytes(), b.as_bytes()).unwrap();
+            assert_eq!(got, expected, "{:?} {:?} {:?}", collation, a, b);
+            let soliton_a = sort_key(collation, a.as_bytes()).unwrap();
+            let soliton_b = sort_key(collation, b.as_bytes()).unwrap();
+            assert_eq!(
+                soliton_a.cmp(&soliton_b),
+                expected,
+                "{:?} {:?} {:?}",
+                collation,
+                a,
+                b
+            );
+        }
+
+        assert!(sort_key(Collation::Latin1Bin, "\u{4e2d}".as_bytes()).is_err());
+        assert!(sort_key(Collation::Utf8Mb4GeneralCi, &[0xFF]).is_err());
+        assert_eq!(sort_key(Collation::Binary, &[0xFF]).unwrap(), vec![0xFF]);
+    }
+
+    #[test]
+    fn test_collation_from_i32() {
+        let cases = [
+            (-47, Collation::Latin1Bin),
+            (-8, Collation::Latin1SwedishCi),
+            (-48, Collation::Latin1GeneralCi),
+            (-28, Collation::GbkChineseCi),
+            (-33, Collation::Utf8Mb4GeneralCi),
+            (-46, Collation::Utf8Mb4Bin),
+            (47, Collation::Utf8Mb4BinNoPadding),
+        ];
+        for (id, collation) in cases {
+            assert_eq!(Collation::from_i32(id).unwrap(), collation);
+        }
+        assert_eq!(Collation::Latin1SwedishCi.charset_name(), "latin1");
+        assert!(Collation::from_i32(-1000).is_err());
+    }
+
     #[test]
     fn test_cmp_json_numberic_type() {
         let cases = vec![
             (
                 Json::from_i64(922337203685477581),
                 Json::from_i64(922337203685477580),
-                Partitioning::Greater,
+                Ordering::Greater,
             ),
             (
                 Json::from_i64(-1),
                 Json::from_u64(18446744073709551615),
-                Partitioning::Less,
+                Ordering::Less,
             ),
             (
                 Json::from_i64(922337203685477580),
                 Json::from_u64(922337203685477581),
-                Partitioning::Less,
+                Ordering::Less,
             ),
-            (Json::from_i64(2), Json::from_u64(1), Partitioning::Greater),
+            (Json::from_i64(2), Json::from_u64(1), Ordering::Greater),
             (
-                Json::from_i64(std::i64::MAX),
-                Json::from_u64(std::i64::MAX as u64),
-                Partitioning::Equal,
+                Json::from_i64(i64::MAX),
+                Json::from_u64(i64::MAX as u64),
+                Ordering::Equal,
             ),
             (
                 Json::from_u64(18446744073709551615),
                 Json::from_i64(-1),
-                Partitioning::Greater,
+                Ordering::Greater,
             ),
             (
                 Json::from_u64(922337203685477581),
                 Json::from_i64(922337203685477580),
-                Partitioning::Greater,
+                Ordering::Greater,
             ),
-            (Json::from_u64(1), Json::from_i64(2), Partitioning::Less),
+            (Json::from_u64(1), Json::from_i64(2), Ordering::Less),
             (
-                Json::from_u64(std::i64::MAX as u64),
-                Json::from_i64(std::i64::MAX),
-                Partitioning::Equal,
+                Json::from_u64(i64::MAX as u64),
+                Json::from_i64(i64::MAX),
+                Ordering::Equal,
             ),
-            (Json::from_f64(9.0), Json::from_i64(9), Partitioning::Equal),
-            (Json::from_f64(8.9), Json::from_i64(9), Partitioning::Less),
-            (Json::from_f64(9.1), Json::from_i64(9), Partitioning::Greater),
-            (Json::from_f64(9.0), Json::from_u64(9), Partitioning::Equal),
-            (Json::from_f64(8.9), Json::from_u64(9), Partitioning::Less),
-            (Json::from_f64(9.1), Json::from_u64(9), Partitioning::Greater),
-            (Json::from_i64(9), Json::from_f64(9.0), Partitioning::Equal),
-            (Json::from_i64(9), Json::from_f64(8.9), Partitioning::Greater),
-            (Json::from_i64(9), Json::from_f64(9.1), Partitioning::Less),
-            (Json::from_u64(9), Json::from_f64(9.0), Partitioning::Equal),
-            (Json::from_u64(9), Json::from_f64(8.9), Partitioning::Greater),
-            (Json::from_u64(9), Json::from_f64(9.1), Partitioning::Less),
+            (Json::from_f64(9.0), Json::from_i64(9), Ordering::Equal),
+            (Json::from_f64(8.9), Json::from_i64(9), Ordering::Less),
+            (Json::from_f64(9.1), Json::from_i64(9), Ordering::Greater),
+            (Json::from_f64(9.0), Json::from_u64(9), Ordering::Equal),
+            (Json::from_f64(8.9), Json::from_u64(9), Ordering::Less),
+            (Json::from_f64(9.1), Json::from_u64(9), Ordering::Greater),
+            (Json::from_i64(9), Json::from_f64(9.0), Ordering::Equal),
+            (Json::from_i64(9), Json::from_f64(8.9), Ordering::Greater),
+            (Json::from_i64(9), Json::from_f64(9.1), Ordering::Less),
+            (Json::from_u64(9), Json::from_f64(9.0), Ordering::Equal),
+            (Json::from_u64(9), Json::from_f64(8.9), Ordering::Greater),
+            (Json::from_u64(9), Json::from_f64(9.1), Ordering::Less),
         ];
 
         for (left, right, expected) in cases {
@@ -42,6 +42,4 @@ pub const PRECEDENCE_STRING: i32 = -10;
 pub const PRECEDENCE_NUMBER: i32 = -11;
 pub const PRECEDENCE_NULL: i32 = -12;
 
-
 pub const PRECEDENCE_MAX: i32 = PRECEDENCE_NULL;
-
@@ -0,0 +1,9508 @@
+// Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! Primary weights of the Default Unicode Collation Element Table.
+//!
+//! Generated from `allkeys.txt` of UCA 13.0.0; do not edit by hand. Only the entries of
+//! single code points are kept, and of those only the non-zero primary weights.
+
+/// Code points `start..=end` whose primary weights are `weights`. When a range
+/// spans several code points it has exactly one weight, which grows by one per
+/// code point; an empty `weights` makes the code points ignorable. Sorted by `start`.
+#[rustfmt::skip]
+pub static DUCET_PRIMARY: &[(u32, u32, &[u16])] = &[
+    (0x0000, 0x0008, &[]),
+    (0x0009, 0x000D, &[0x0201]),
+    (0x000E, 0x001F, &[]),
+    (0x0020, 0x0020, &[0x0209]),
+    (0x0021, 0x0021, &[0x0267]),
+    (0x0022, 0x0022, &[0x031D]),
+    (0x0023, 0x0023, &[0x03AC]),
+    (0x0024, 0x0024, &[0x1F64]),
+    (0x0025, 0x0025, &[0x03AD]),
+    (0x0026, 0x0026, &[0x03A9]),
+    (0x0027, 0x0027, &[0x0316]),
+    (0x0028, 0x0029, &[0x0328]),
+    (0x002A, 0x002A, &[0x03A1]),
+    (0x002B, 0x002B, &[0x0666]),
+    (0x002C, 0x002C, &[0x0223]),
+    (0x002D, 0x002D, &[0x020D]),
+    (0x002E, 0x002E, &[0x027E]),
+    (0x002F, 0x002F, &[0x03A6]),
+    (0x0030, 0x0039, &[0x1F98]),
+    (0x003A, 0x003A, &[0x0240]),
+    (0x003B, 0x003B, &[0x023A]),
+    (0x003C, 0x003E, &[0x066A]),
+    (0x003F, 0x003F, &[0x026D]),
+    (0x0040, 0x0040, &[0x03A0]),
+    (0x0041, 0x0041, &[0x1FA2]),
+    (0x0042, 0x0042, &[0x1FBC]),
+    (0x0043, 0x0043, &[0x1FD6]),
+    (0x0044, 0x0044, &[0x1FEB]),
+    (0x0045, 0x0045, &[0x2007]),
+    (0x0046, 0x0046, &[0x2042]),
+    (0x0047, 0x0047, &[0x2051]),
+    (0x0048, 0x0048, &[0x2075]),
+    (0x0049, 0x0049, &[0x2090]),
+    (0x004A, 0x004A, &[0x20AB]),
+    (0x004B, 0x004B, &[0x20C4]),
+    (0x004C, 0x004C, &[0x20D6]),
+    (0x004D, 0x004D, &[0x2109]),
+    (0x004E, 0x004E, &[0x2118]),
+    (0x004F, 0x004F, &[0x213C]),
+    (0x0050, 0x0050, &[0x216B]),
+    (0x0051, 0x0051, &[0x2180]),
+    (0x0052, 0x0052, &[0x2193]),
+    (0x0053, 0x0053, &[0x21D2]),
+    (0x0054, 0x0054, &[0x21F7]),
+    (0x0055, 0x0055, &[0x2217]),
+    (0x0056, 0x0056, &[0x2247]),
+    (0x0057, 0x0057, &[0x2259]),
+    (0x0058, 0x0058, &[0x2264]),
+    (0x0059, 0x0059, &[0x2270]),
+    (0x005A, 0x005A, &[0x2286]),
+    (0x005B, 0x005B, &[0x032A]),
+    (0x005C, 0x005C, &[0x03A7]),
+    (0x005D, 0x005D, &[0x032B]),
+    (0x005E, 0x005E, &[0x04B7]),
+    (0x005F, 0x005F, &[0x020B]),
+    (0x0060, 0x0060, &[0x04B4]),
+    (0x0061, 0x0061, &[0x1FA2]),
+    (0x0062, 0x0062, &[0x1FBC]),
+    (0x0063, 0x0063, &[0x1FD6]),
+    (0x0064, 0x0064, &[0x1FEB]),
+    (0x0065, 0x0065, &[0x2007]),
+    (0x0066, 0x0066, &[0x2042]),
+    (0x0067, 0x0067, &[0x2051]),
+    (0x0068, 0x0068, &[0x2075]),
+    (0x0069, 0x0069, &[0x2090]),
+    (0x006A, 0x006A, &[0x20AB]),
+    (0x006B, 0x006B, &[0x20C4]),
+    (0x006C, 0x006C, &[0x20D6]),
+    (0x006D, 0x006D, &[0x2109]),
+    (0x006E, 0x006E, &[0x2118]),
+    (0x006F, 0x006F, &[0x213C]),
+    (0x0070, 0x0070, &[0x216B]),
+    (0x0071, 0x0071, &[0x2180]),
+    (0x0072, 0x0072, &[0x2193]),
+    (0x0073, 0x0073, &[0x21D2]),
+    (0x0074, 0x0074, &[0x21F7]),
+    (0x0075, 0x0075, &[0x2217]),
+    (0x0076, 0x0076, &[0x2247]),
+    (0x0077, 0x0077, &[0x2259]),
+    (0x0078, 0x0078, &[0x2264]),
+    (0x0079, 0x0079, &[0x2270]),
+    (0x007A, 0x007A, &[0x2286]),
+    (0x007B, 0x007B, &[0x032C]),
+    (0x007C, 0x007C, &[0x066E]),
+    (0x007D, 0x007D, &[0x032D]),
+    (0x007E, 0x007E, &[0x0670]),
+    (0x007F, 0x0084, &[]),
+    (0x0085, 0x0085, &[0x0206]),
+    (0x0086, 0x009F, &[]),
+    (0x00A0, 0x00A0, &[0x0209]),
+    (0x00A1, 0x00A1, &[0x0268]),
+    (0x00A2, 0x00A2, &[0x1F63]),
+    (0x00A3, 0x00A3, &[0x1F65]),
+    (0x00A4, 0x00A4, &[0x1F62]),
+    (0x00A5, 0x00A5, &[0x1F66]),
+    (0x00A6, 0x00A6, &[0x066F]),
+    (0x00A7, 0x00A7, &[0x039A]),
+    (0x00A8, 0x00A8, &[0x04BB]),
+    (0x00A9, 0x00A9, &[0x05D2]),
+    (0x00AA, 0x00AA, &[0x1FA2]),
+    (0x00AB, 0x00AB, &[0x0326]),
+    (0x00AC, 0x00AC, &[0x066D]),
+    (0x00AD, 0x00AD, &[]),
+    (0x00AE, 0x00AE, &[0x05D4]),
+    (0x00AF, 0x00AF, &[0x04B8]),
+    (0x00B0, 0x00B0, &[0x052A]),
+    (0x00B1, 0x00B1, &[0x0667]),
+    (0x00B2, 0x00B3, &[0x1F9A]),
+    (0x00B4, 0x00B4, &[0x04B5]),
+    (0x00B5, 0x00B5, &[0x2330]),
+    (0x00B6, 0x00B6, &[0x039C]),
+    (0x00B7, 0x00B7, &[0x0293]),
+    (0x00B8, 0x00B8, &[0x04BE]),
+    (0x00B9, 0x00B9, &[0x1F99]),
+    (0x00BA, 0x00BA, &[0x213C]),
+    (0x00BB, 0x00BB, &[0x0327]),
+    (0x00BC, 0x00BC, &[0x1F99, 0x0676, 0x1F9C]),
+    (0x00BD, 0x00BD, &[0x1F99, 0x0676, 0x1F9A]),
+    (0x00BE, 0x00BE, &[0x1F9B, 0x0676, 0x1F9C]),
+    (0x00BF, 0x00BF, &[0x026E]),
+    (0x00C0, 0x00C0, &[0x1FA2]),
+    (0x00C1, 0x00C1, &[0x1FA2]),
+    (0x00C2, 0x00C2, &[0x1FA2]),
+    (0x00C3, 0x00C3, &[0x1FA2]),
+    (0x00C4, 0x00C4, &[0x1FA2]),
+    (0x00C5, 0x00C5, &[0x1FA2]),
+    (0x00C6, 0x00C6, &[0x1FA2, 0x2007]),
+    (0x00C7, 0x00C7, &[0x1FD6]),
+    (0x00C8, 0x00C8, &[0x2007]),
+    (0x00C9, 0x00C9, &[0x2007]),
+    (0x00CA, 0x00CA, &[0x2007]),
+    (0x00CB, 0x00CB, &[0x2007]),
+    (0x00CC, 0x00CC, &[0x2090]),
+    (0x00CD, 0x00CD, &[0x2090]),
+    (0x00CE, 0x00CE, &[0x2090]),
+    (0x00CF, 0x00CF, &[0x2090]),
+    (0x00D0, 0x00D0, &[0x1FEB]),
+    (0x00D1, 0x00D1, &[0x2118]),
+    (0x00D2, 0x00D2, &[0x213C]),
+    (0x00D3, 0x00D3, &[0x213C]),
+    (0x00D4, 0x00D4, &[0x213C]),
+    (0x00D5, 0x00D5, &[0x213C]),
+    (0x00D6, 0x00D6, &[0x213C]),
+    (0x00D7, 0x00D7, &[0x0669]),
+    (0x00D8, 0x00D8, &[0x213C]),
+    (0x00D9, 0x00D9, &[0x2217]),
+    (0x00DA, 0x00DA, &[0x2217]),
+    (0x00DB, 0x00DB, &[0x2217]),
+    (0x00DC, 0x00DC, &[0x2217]),
+    (0x00DD, 0x00DD, &[0x2270]),
+    (0x00DE, 0x00DE, &[0x22B5]),
+    (0x00DF, 0x00DF, &[0x21D2, 0x21D2]),
+    (0x00E0, 0x00E0, &[0x1FA2]),
+    (0x00E1, 0x00E1, &[0x1FA2]),
+    (0x00E2, 0x00E2, &[0x1FA2]),
+    (0x00E3, 0x00E3, &[0x1FA2]),
+    (0x00E4, 0x00E4, &[0x1FA2]),
+    (0x00E5, 0x00E5, &[0x1FA2]),
+    (0x00E6, 0x00E6, &[0x1FA2, 0x2007]),
+    (0x00E7, 0x00E7, &[0x1FD6]),
+    (0x00E8, 0x00E8, &[0x2007]),
+    (0x00E9, 0x00E9, &[0x2007]),
+    (0x00EA, 0x00EA, &[0x2007]),
+    (0x00EB, 0x00EB, &[0x2007]),
+    (0x00EC, 0x00EC, &[0x2090]),
+    (0x00ED, 0x00ED, &[0x2090]),
+    (0x00EE, 0x00EE, &[0x2090]),
+    (0x00EF, 0x00EF, &[0x2090]),
+    (0x00F0, 0x00F0, &[0x1FEB]),
+    (0x00F1, 0x00F1, &[0x2118]),
+    (0x00F2, 0x00F2, &[0x213C]),
+    (0x00F3, 0x00F3, &[0x213C]),
+    (0x00F4, 0x00F4, &[0x213C]),
+    (0x00F5, 0x00F5, &[0x213C]),
+    (0x00F6, 0x00F6, &[0x213C]),
+    (0x00F7, 0x00F7, &[0x0668]),
+    (0x00F8, 0x00F8, &[0x213C]),
+    (0x00F9, 0x00F9, &[0x2217]),
+    (0x00FA, 0x00FA, &[0x2217]),
+    (0x00FB, 0x00FB, &[0x2217]),
+    (0x00FC, 0x00FC, &[0x2217]),
+    (0x00FD, 0x00FD, &[0x2270]),
+    (0x00FE, 0x00FE, &[0x22B5]),
+    (0x00FF, 0x00FF, &[0x2270]),
+    (0x0100, 0x0100, &[0x1FA2]),
+    (0x0101, 0x0101, &[0x1FA2]),
+    (0x0102, 0x0102, &[0x1FA2]),
+    (0x0103, 0x0103, &[0x1FA2]),
+    (0x0104, 0x0104, &[0x1FA2]),
+    (0x0105, 0x0105, &[0x1FA2]),
+    (0x0106, 0x0106, &[0x1FD6]),
+    (0x0107, 0x0107, &[0x1FD6]),
+    (0x0108, 0x0108, &[0x1FD6]),
+    (0x0109, 0x0109, &[0x1FD6]),
+    (0x010A, 0x010A, &[0x1FD6]),
+    (0x010B, 0x010B, &[0x1FD6]),
+    (0x010C, 0x010C, &[0x1FD6]),
+    (0x010D, 0x010D, &[0x1FD6]),
+    (0x010E, 0x010E, &[0x1FEB]),
+    (0x010F, 0x010F, &[0x1FEB]),
+    (0x0110, 0x0110, &[0x1FEB]),
+    (0x0111, 0x0111, &[0x1FEB]),
+    (0x0112, 0x0112, &[0x2007]),
+    (0x0113, 0x0113, &[0x2007]),
+    (0x0114, 0x0114, &[0x2007]),
+    (0x0115, 0x0115, &[0x2007]),
+    (0x0116, 0x0116, &[0x2007]),
+    (0x0117, 0x0117, &[0x2007]),
+    (0x0118, 0x0118, &[0x2007]),
+    (0x0119, 0x0119, &[0x2007]),
+    (0x011A, 0x011A, &[0x2007]),
+    (0x011B, 0x011B, &[0x2007]),
+    (0x011C, 0x011C, &[0x2051]),
+    (0x011D, 0x011D, &[0x2051]),
+    (0x011E, 0x011E, &[0x2051]),
+    (0x011F, 0x011F, &[0x2051]),
+    (0x0120, 0x0120, &[0x2051]),
+    (0x0121, 0x0121, &[0x2051]),
+    (0x0122, 0x0122, &[0x2051]),
+    (0x0123, 0x0123, &[0x2051]),
+    (0x0124, 0x0124, &[0x2075]),
+    (0x0125, 0x0125, &[0x2075]),
+    (0x0126, 0x0126, &[0x2075]),
+    (0x0127, 0x0127, &[0x2075]),
+    (0x0128, 0x0128, &[0x2090]),
+    (0x0129, 0x0129, &[0x2090]),
+    (0x012A, 0x012A, &[0x2090]),
+    (0x012B, 0x012B, &[0x2090]),
+    (0x012C, 0x012C, &[0x2090]),
+    (0x012D, 0x012D, &[0x2090]),
+    (0x012E, 0x012E, &[0x2090]),
+    (0x012F, 0x012F, &[0x2090]),
+    (0x0130, 0x0130, &[0x2090]),
+    (0x0131, 0x0131, &[0x2094]),
+    (0x0132, 0x0132, &[0x2090, 0x20AB]),
+    (0x0133, 0x0133, &[0x2090, 0x20AB]),
+    (0x0134, 0x0134, &[0x20AB]),
+    (0x0135, 0x0135, &[0x20AB]),
+    (0x0136, 0x0136, &[0x20C4]),
+    (0x0137, 0x0137, &[0x20C4]),
+    (0x0138, 0x0138, &[0x218F]),
+    (0x0139, 0x0139, &[0x20D6]),
+    (0x013A, 0x013A, &[0x20D6]),
+    (0x013B, 0x013B, &[0x20D6]),
+    (0x013C, 0x013C, &[0x20D6]),
+    (0x013D, 0x013D, &[0x20D6]),
+    (0x013E, 0x013E, &[0x20D6]),
+    (0x013F, 0x013F, &[0x20D6]),
+    (0x0140, 0x0140, &[0x20D6]),
+    (0x0141, 0x0141, &[0x20D6]),
+    (0x0142, 0x0142, &[0x20D6]),
+    (0x0143, 0x0143, &[0x2118]),
+    (0x0144, 0x0144, &[0x2118]),
+    (0x0145, 0x0145, &[0x2118]),
+    (0x0146, 0x0146, &[0x2118]),
+    (0x0147, 0x0147, &[0x2118]),
+    (0x0148, 0x0148, &[0x2118]),
+    (0x0149, 0x0149, &[0x22E3, 0x2118]),
+    (0x014A, 0x014A, &[0x2137]),
+    (0x014B, 0x014B, &[0x2137]),
+    (0x014C, 0x014C, &[0x213C]),
+    (0x014D, 0x014D, &[0x213C]),
+    (0x014E, 0x014E, &[0x213C]),
+    (0x014F, 0x014F, &[0x213C]),
+    (0x0150, 0x0150, &[0x213C]),
+    (0x0151, 0x0151, &[0x213C]),
+    (0x0152, 0x0152, &[0x213C, 0x2007]),
+    (0x0153, 0x0153, &[0x213C, 0x2007]),
+    (0x0154, 0x0154, &[0x2193]),
+    (0x0155, 0x0155, &[0x2193]),
+    (0x0156, 0x0156, &[0x2193]),
+    (0x0157, 0x0157, &[0x2193]),
+    (0x0158, 0x0158, &[0x2193]),
+    (0x0159, 0x0159, &[0x2193]),
+    (0x015A, 0x015A, &[0x21D2]),
+    (0x015B, 0x015B, &[0x21D2]),
+    (0x015C, 0x015C, &[0x21D2]),
+    (0x015D, 0x015D, &[0x21D2]),
+    (0x015E, 0x015E, &[0x21D2]),
+    (0x015F, 0x015F, &[0x21D2]),
+    (0x0160, 0x0160, &[0x21D2]),
+    (0x0161, 0x0161, &[0x21D2]),
+    (0x0162, 0x0162, &[0x21F7]),
+    (0x0163, 0x0163, &[0x21F7]),
+    (0x0164, 0x0164, &[0x21F7]),
+    (0x0165, 0x0165, &[0x21F7]),
+    (0x0166, 0x0166, &[0x21FC]),
+    (0x0167, 0x0167, &[0x21FC]),
+    (0x0168, 0x0168, &[0x2217]),
+    (0x0169, 0x0169, &[0x2217]),
+    (0x016A, 0x016A, &[0x2217]),
+    (0x016B, 0x016B, &[0x2217]),
+    (0x016C, 0x016C, &[0x2217]),
+    (0x016D, 0x016D, &[0x2217]),
+    (0x016E, 0x016E, &[0x2217]),
+    (0x016F, 0x016F, &[0x2217]),
+    (0x0170, 0x0170, &[0x2217]),
+    (0x0171, 0x0171, &[0x2217]),
+    (0x0172, 0x0172, &[0x2217]),
+    (0x0173, 0x0173, &[0x2217]),
+    (0x0174, 0x0174, &[0x2259]),
+    (0x0175, 0x0175, &[0x2259]),
+    (0x0176, 0x0176, &[0x2270]),
+    (0x0177, 0x0177, &[0x2270]),
+    (0x0178, 0x0178, &[0x2270]),
+    (0x0179, 0x0179, &[0x2286]),
+    (0x017A, 0x017A, &[0x2286]),
+    (0x017B, 0x017B, &[0x2286]),
+    (0x017C, 0x017C, &[0x2286]),
+    (0x017D, 0x017D, &[0x2286]),
+    (0x017E, 0x017E, &[0x2286]),
+    (0x017F, 0x017F, &[0x21D2]),
+    (0x0180, 0x0180, &[0x1FC4]),
+    (0x0181, 0x0181, &[0x1FCD]),
+    (0x0182, 0x0182, &[0x1FD1]),
+    (0x0183, 0x0183, &[0x1FD1]),
+    (0x0184, 0x0184, &[0x22D6]),
+    (0x0185, 0x0185, &[0x22D6]),
+    (0x0186, 0x0186, &[0x214F]),
+    (0x0187, 0x0187, &[0x1FE1]),
+    (0x0188, 0x0188, &[0x1FE1]),
+    (0x0189, 0x0189, &[0x1FF4]),
+    (0x018A, 0x018A, &[0x1FF8]),
+    (0x018B, 0x018B, &[0x1FFD]),
+    (0x018C, 0x018C, &[0x1FFD]),
+    (0x018D, 0x018D, &[0x2286, 0x2259]),
+    (0x018E, 0x018E, &[0x2015]),
+    (0x018F, 0x018F, &[0x201A]),
+    (0x0190, 0x0190, &[0x201F]),
+    (0x0191, 0x0191, &[0x204B]),
+    (0x0192, 0x0192, &[0x204B]),
+    (0x0193, 0x0193, &[0x2063]),
+    (0x0194, 0x0194, &[0x206D]),
+    (0x0195, 0x0195, &[0x207D]),
+    (0x0196, 0x0196, &[0x20A6]),
+    (0x0197, 0x0197, &[0x209F]),
+    (0x0198, 0x0198, &[0x20CA]),
+    (0x0199, 0x0199, &[0x20CA]),
+    (0x019A, 0x019A, &[0x20E1]),
+    (0x019B, 0x019B, &[0x2101]),
+    (0x019C, 0x019C, &[0x2238]),
+    (0x019D, 0x019D, &[0x2123]),
+    (0x019E, 0x019E, &[0x2127]),
+    (0x019F, 0x019F, &[0x215C]),
+    (0x01A0, 0x01A0, &[0x213C]),
+    (0x01A1, 0x01A1, &[0x213C]),
+    (0x01A2, 0x01A2, &[0x2071]),
+    (0x01A3, 0x01A3, &[0x2071]),
+    (0x01A4, 0x01A4, &[0x2174]),
+    (0x01A5, 0x01A5, &[0x2174]),
+    (0x01A6, 0x01A6, &[0x2198]),
+    (0x01A7, 0x01A7, &[0x22CE]),
+    (0x01A8, 0x01A8, &[0x22CE]),
+    (0x01A9, 0x01A9, &[0x21E4]),
+    (0x01AA, 0x01AA, &[0x21EA]),
+    (0x01AB, 0x01AB, &[0x2202]),
+    (0x01AC, 0x01AC, &[0x2206]),
+    (0x01AD, 0x01AD, &[0x2206]),
+    (0x01AE, 0x01AE, &[0x220A]),
+    (0x01AF, 0x01AF, &[0x2217]),
+    (0x01B0, 0x01B0, &[0x2217]),
+    (0x01B1, 0x01B1, &[0x2242]),
+    (0x01B2, 0x01B2, &[0x224E]),
+    (0x01B3, 0x01B3, &[0x227C]),
+    (0x01B4, 0x01B4, &[0x227C]),
+    (0x01B5, 0x01B5, &[0x228B]),
+    (0x01B6, 0x01B6, &[0x228B]),
+    (0x01B7, 0x01B7, &[0x22A3]),
+    (0x01B8, 0x01B8, &[0x22A8]),
+    (0x01B9, 0x01B9, &[0x22A8]),
+    (0x01BA, 0x01BA, &[0x22AD]),
+    (0x01BB, 0x01BB, &[0x22C7]),
+    (0x01BC, 0x01BC, &[0x22D2]),
+    (0x01BD, 0x01BD, &[0x22D2]),
+    (0x01BE, 0x01BE, &[0x21F7, 0x21D2]),
+    (0x01BF, 0x01BF, &[0x22BB]),
+    (0x01C0, 0x01C0, &[0x22FE]),
+    (0x01C1, 0x01C1, &[0x2302]),
+    (0x01C2, 0x01C2, &[0x2306]),
+    (0x01C3, 0x01C3, &[0x230A]),
+    (0x01C4, 0x01C4, &[0x1FEB, 0x2286]),
+    (0x01C5, 0x01C5, &[0x1FEB, 0x2286]),
+    (0x01C6, 0x01C6, &[0x1FEB, 0x2286]),
+    (0x01C7, 0x01C7, &[0x20D6, 0x20AB]),
+    (0x01C8, 0x01C8, &[0x20D6, 0x20AB]),
+    (0x01C9, 0x01C9, &[0x20D6, 0x20AB]),
+    (0x01CA, 0x01CA, &[0x2118, 0x20AB]),
+    (0x01CB, 0x01CB, &[0x2118, 0x20AB]),
+    (0x01CC, 0x01CC, &[0x2118, 0x20AB]),
+    (0x01CD, 0x01CD, &[0x1FA2]),
+    (0x01CE, 0x01CE, &[0x1FA2]),
+    (0x01CF, 0x01CF, &[0x2090]),
+    (0x01D0, 0x01D0, &[0x2090]),
+    (0x01D1, 0x01D1, &[0x213C]),
+    (0x01D2, 0x01D2, &[0x213C]),
+    (0x01D3, 0x01D3, &[0x2217]),
+    (0x01D4, 0x01D4, &[0x2217]),
+    (0x01D5, 0x01D5, &[0x2217]),
+    (0x01D6, 0x01D6, &[0x2217]),
+    (0x01D7, 0x01D7, &[0x2217]),
+    (0x01D8, 0x01D8, &[0x2217]),
+    (0x01D9, 0x01D9, &[0x2217]),
+    (0x01DA, 0x01DA, &[0x2217]),
+    (0x01DB, 0x01DB, &[0x2217]),
+    (0x01DC, 0x01DC, &[0x2217]),
+    (0x01DD, 0x01DD, &[0x2015]),
+    (0x01DE, 0x01DE, &[0x1FA2]),
+    (0x01DF, 0x01DF, &[0x1FA2]),
+    (0x01E0, 0x01E0, &[0x1FA2]),
+    (0x01E1, 0x01E1, &[0x1FA2]),
+    (0x01E2, 0x01E2, &[0x1FA2, 0x2007]),
+    (0x01E3, 0x01E3, &[0x1FA2, 0x2007]),
+    (0x01E4, 0x01E4, &[0x205E]),
+    (0x01E5, 0x01E5, &[0x205E]),
+    (0x01E6, 0x01E6, &[0x2051]),
+    (0x01E7, 0x01E7, &[0x2051]),
+    (0x01E8, 0x01E8, &[0x20C4]),
+    (0x01E9, 0x01E9, &[0x20C4]),
+    (0x01EA, 0x01EA, &[0x213C]),
+    (0x01EB, 0x01EB, &[0x213C]),
+    (0x01EC, 0x01EC, &[0x213C]),
+    (0x01ED, 0x01ED, &[0x213C]),
+    (0x01EE, 0x01EE, &[0x22A3]),
+    (0x01EF, 0x01EF, &[0x22A3]),
+    (0x01F0, 0x01F0, &[0x20AB]),
+    (0x01F1, 0x01F1, &[0x1FEB, 0x2286]),
+    (0x01F2, 0x01F2, &[0x1FEB, 0x2286]),
+    (0x01F3, 0x01F3, &[0x1FEB, 0x2286]),
+    (0x01F4, 0x01F4, &[0x2051]),
+    (0x01F5, 0x01F5, &[0x2051]),
+    (0x01F6, 0x01F6, &[0x207D]),
+    (0x01F7, 0x01F7, &[0x22BB]),
+    (0x01F8, 0x01F8, &[0x2118]),
+    (0x01F9, 0x01F9, &[0x2118]),
+    (0x01FA, 0x01FA, &[0x1FA2]),
+    (0x01FB, 0x01FB, &[0x1FA2]),
+    (0x01FC, 0x01FC, &[0x1FA2, 0x2007]),
+    (0x01FD, 0x01FD, &[0x1FA2, 0x2007]),
+    (0x01FE, 0x01FE, &[0x213C]),
+    (0x01FF, 0x01FF, &[0x213C]),
+    (0x0200, 0x0200, &[0x1FA2]),
+    (0x0201, 0x0201, &[0x1FA2]),
+    (0x0202, 0x0202, &[0x1FA2]),
+    (0x0203, 0x0203, &[0x1FA2]),
+    (0x0204, 0x0204, &[0x2007]),
+    (0x0205, 0x0205, &[0x2007]),
+    (0x0206, 0x0206, &[0x2007]),
+    (0x0207, 0x0207, &[0x2007]),
+    (0x0208, 0x0208, &[0x2090]),
+    (0x0209, 0x0209, &[0x2090]),
+    (0x020A, 0x020A, &[0x2090]),
+    (0x020B, 0x020B, &[0x2090]),
+    (0x020C, 0x020C, &[0x213C]),
+    (0x020D, 0x020D, &[0x213C]),
+    (0x020E, 0x020E, &[0x213C]),
+    (0x020F, 0x020F, &[0x213C]),
+    (0x0210, 0x0210, &[0x2193]),
+    (0x0211, 0x0211, &[0x2193]),
+    (0x0212, 0x0212, &[0x2193]),
+    (0x0213, 0x0213, &[0x2193]),
+    (0x0214, 0x0214, &[0x2217]),
+    (0x0215, 0x0215, &[0x2217]),
+    (0x0216, 0x0216, &[0x2217]),
+    (0x0217, 0x0217, &[0x2217]),
+    (0x0218, 0x0218, &[0x21D2]),
+    (0x0219, 0x0219, &[0x21D2]),
+    (0x021A, 0x021A, &[0x21F7]),
+    (0x021B, 0x021B, &[0x21F7]),
+    (0x021C, 0x021C, &[0x2282]),
+    (0x021D, 0x021D, &[0x2282]),
+    (0x021E, 0x021E, &[0x2075]),
+    (0x021F, 0x021F, &[0x2075]),
+    (0x0220, 0x0220, &[0x2127]),
+    (0x0221, 0x0221, &[0x2001]),
+    (0x0222, 0x0222, &[0x2166]),
+    (0x0223, 0x0223, &[0x2166]),
+    (0x0224, 0x0224, &[0x2291]),
+    (0x0225, 0x0225, &[0x2291]),
+    (0x0226, 0x0226, &[0x1FA2]),
+    (0x0227, 0x0227, &[0x1FA2]),
+    (0x0228, 0x0228, &[0x2007]),
+    (0x0229, 0x0229, &[0x2007]),
+    (0x022A, 0x022A, &[0x213C]),
+    (0x022B, 0x022B, &[0x213C]),
+    (0x022C, 0x022C, &[0x213C]),
+    (0x022D, 0x022D, &[0x213C]),
+    (0x022E, 0x022E, &[0x213C]),
+    (0x022F, 0x022F, &[0x213C]),
+    (0x0230, 0x0230, &[0x213C]),
+    (0x0231, 0x0231, &[0x213C]),
+    (0x0232, 0x0232, &[0x2270]),
+    (0x0233, 0x0233, &[0x2270]),
+    (0x0234, 0x0234, &[0x20F7]),
+    (0x0235, 0x0235, &[0x2131]),
+    (0x0236, 0x0236, &[0x220E]),
+    (0x0237, 0x0237, &[0x20AF]),
+    (0x0238, 0x0238, &[0x1FEB, 0x1FBC]),
+    (0x0239, 0x0239, &[0x2180, 0x216B]),
+    (0x023A, 0x023A, &[0x1FA7]),
+    (0x023B, 0x023B, &[0x1FDB]),
+    (0x023C, 0x023C, &[0x1FDB]),
+    (0x023D, 0x023D, &[0x20E1]),
+    (0x023E, 0x023E, &[0x2200]),
+    (0x023F, 0x023F, &[0x21DE]),
+    (0x0240, 0x0240, &[0x229D]),
+    (0x0241, 0x0241, &[0x22DE]),
+    (0x0242, 0x0242, &[0x22DE]),
+    (0x0243, 0x0243, &[0x1FC4]),
+    (0x0244, 0x0244, &[0x2222]),
+    (0x0245, 0x0245, &[0x2255]),
+    (0x0246, 0x0246, &[0x200E]),
+    (0x0247, 0x0247, &[0x200E]),
+    (0x0248, 0x0248, &[0x20B4]),
+    (0x0249, 0x0249, &[0x20B4]),
+    (0x024A, 0x024A, &[0x218B]),
+    (0x024B, 0x024B, &[0x218B]),
+    (0x024C, 0x024C, &[0x219F]),
+    (0x024D, 0x024D, &[0x219F]),
+    (0x024E, 0x024E, &[0x2278]),
+    (0x024F, 0x024F, &[0x2278]),
+    (0x0250, 0x0250, &[0x1FAD]),
+    (0x0251, 0x0251, &[0x1FB1]),
+    (0x0252, 0x0252, &[0x1FB7]),
+    (0x0253, 0x0253, &[0x1FCD]),
+    (0x0254, 0x0254, &[0x214F]),
+    (0x0255, 0x0255, &[0x1FE5]),
+    (0x0256, 0x0256, &[0x1FF4]),
+    (0x0257, 0x0257, &[0x1FF8]),
+    (0x0258, 0x0258, &[0x2024]),
+    (0x0259, 0x0259, &[0x201A]),
+    (0x025A, 0x025A, &[0x2028]),
+    (0x025B, 0x025B, &[0x201F]),
+    (0x025C, 0x025C, &[0x202C]),
+    (0x025D, 0x025D, &[0x2032]),
+    (0x025E, 0x025E, &[0x2036]),
+    (0x025F, 0x025F, &[0x20BC]),
+    (0x0260, 0x0260, &[0x2063]),
+    (0x0261, 0x0261, &[0x2055]),
+    (0x0262, 0x0262, &[0x205A]),
+    (0x0263, 0x0263, &[0x206D]),
+    (0x0264, 0x0264, &[0x203E]),
+    (0x0265, 0x0265, &[0x222C]),
+    (0x0266, 0x0266, &[0x2082]),
+    (0x0267, 0x0267, &[0x208A]),
+    (0x0268, 0x0268, &[0x209F]),
+    (0x0269, 0x0269, &[0x20A6]),
+    (0x026A, 0x026A, &[0x2098]),
+    (0x026B, 0x026B, &[0x20E6]),
+    (0x026C, 0x026C, &[0x20EC]),
+    (0x026D, 0x026D, &[0x20F2]),
+    (0x026E, 0x026E, &[0x20FC]),
+    (0x026F, 0x026F, &[0x2238]),
+    (0x0270, 0x0270, &[0x223E]),
+    (0x0271, 0x0271, &[0x2110]),
+    (0x0272, 0x0272, &[0x2123]),
+    (0x0273, 0x0273, &[0x212D]),
+    (0x0274, 0x0274, &[0x211C]),
+    (0x0275, 0x0275, &[0x215C]),
+    (0x0276, 0x0276, &[0x2143]),
+    (0x0277, 0x0277, &[0x2161]),
+    (0x0278, 0x0278, &[0x217B]),
+    (0x0279, 0x0279, &[0x21A4]),
+    (0x027A, 0x027A, &[0x21A9]),
+    (0x027B, 0x027B, &[0x21AE]),
+    (0x027C, 0x027C, &[0x21B3]),
+    (0x027D, 0x027D, &[0x21B7]),
+    (0x027E, 0x027E, &[0x21BC]),
+    (0x027F, 0x027F, &[0x21C1]),
+    (0x0280, 0x0280, &[0x2198]),
+    (0x0281, 0x0281, &[0x21CA]),
+    (0x0282, 0x0282, &[0x21DA]),
+    (0x0283, 0x0283, &[0x21E4]),
+    (0x0284, 0x0284, &[0x20C0]),
+    (0x0285, 0x0285, &[0x21EE]),
+    (0x0286, 0x0286, &[0x21F3]),
+    (0x0287, 0x0287, &[0x2213]),
+    (0x0288, 0x0288, &[0x220A]),
+    (0x0289, 0x0289, &[0x2222]),
+    (0x028A, 0x028A, &[0x2242]),
+    (0x028B, 0x028B, &[0x224E]),
+    (0x028C, 0x028C, &[0x2255]),
+    (0x028D, 0x028D, &[0x2260]),
+    (0x028E, 0x028E, &[0x2105]),
+    (0x028F, 0x028F, &[0x2274]),
+    (0x0290, 0x0290, &[0x2295]),
+    (0x0291, 0x0291, &[0x2299]),
+    (0x0292, 0x0292, &[0x22A3]),
+    (0x0293, 0x0293, &[0x22B1]),
+    (0x0294, 0x0294, &[0x22DA]),
+    (0x0295, 0x0295, &[0x22E9]),
+    (0x0296, 0x0296, &[0x22FA]),
+    (0x0297, 0x0297, &[0x230E]),
+    (0x0298, 0x0298, &[0x2312]),
+    (0x0299, 0x0299, &[0x1FC0]),
+    (0x029A, 0x029A, &[0x203A]),
+    (0x029B, 0x029B, &[0x2067]),
+    (0x029C, 0x029C, &[0x2079]),
+    (0x029D, 0x029D, &[0x20B8]),
+    (0x029E, 0x029E, &[0x20D2]),
+    (0x029F, 0x029F, &[0x20DA]),
+    (0x02A0, 0x02A0, &[0x2187]),
+    (0x02A1, 0x02A1, &[0x22F2]),
+    (0x02A2, 0x02A2, &[0x22F6]),
+    (0x02A3, 0x02A3, &[0x1FEB, 0x2286]),
+    (0x02A4, 0x02A4, &[0x1FEB, 0x22A3]),
+    (0x02A5, 0x02A5, &[0x1FEB, 0x2299]),
+    (0x02A6, 0x02A6, &[0x21F7, 0x21D2]),
+    (0x02A7, 0x02A7, &[0x21F7, 0x21E4]),
+    (0x02A8, 0x02A8, &[0x21F7, 0x1FE5]),
+    (0x02A9, 0x02A9, &[0x2042, 0x2137]),
+    (0x02AA, 0x02AA, &[0x20D6, 0x21D2]),
+    (0x02AB, 0x02AB, &[0x20D6, 0x2286]),
+    (0x02AC, 0x02AC, &[0x2316]),
+    (0x02AD, 0x02AD, &[0x231A]),
+    (0x02AE, 0x02AE, &[0x2230]),
+    (0x02AF, 0x02AF, &[0x2234]),
+    (0x02B0, 0x02B0, &[0x2075]),
+    (0x02B1, 0x02B1, &[0x2082]),
+    (0x02B2, 0x02B2, &[0x20AB]),
+    (0x02B3, 0x02B3, &[0x2193]),
+    (0x02B4, 0x02B4, &[0x21A4]),
+    (0x02B5, 0x02B5, &[0x21AE]),
+    (0x02B6, 0x02B6, &[0x21CA]),
+    (0x02B7, 0x02B7, &[0x2259]),
+    (0x02B8, 0x02B8, &[0x2270]),
+    (0x02B9, 0x02B9, &[0x04C5]),
+    (0x02BA, 0x02BA, &[0x04C7]),
+    (0x02BB, 0x02BB, &[0x208E]),
+    (0x02BC, 0x02BC, &[0x22E3]),
+    (0x02BD, 0x02BD, &[0x208F]),
+    (0x02BE, 0x02BE, &[0x22E5]),
+    (0x02BF, 0x02BF, &[0x22ED]),
+    (0x02C0, 0x02C0, &[0x22E2]),
+    (0x02C1, 0x02C1, &[0x22EE]),
+    (0x02C2, 0x02CF, &[0x04C8]),
+    (0x02D0, 0x02D1, &[0x1F46]),
+    (0x02D2, 0x02D5, &[0x04D6]),
+    (0x02D6, 0x02D7, &[0x04DC]),
+    (0x02D8, 0x02D9, &[0x04B9]),
+    (0x02DA, 0x02DA, &[0x04BC]),
+    (0x02DB, 0x02DB, &[0x04BF]),
+    (0x02DC, 0x02DC, &[0x04B6]),
+    (0x02DD, 0x02DD, &[0x04BD]),
+    (0x02DE, 0x02DF, &[0x04DE]),
+    (0x02E0, 0x02E0, &[0x206D]),
+    (0x02E1, 0x02E1, &[0x20D6]),
+    (0x02E2, 0x02E2, &[0x21D2]),
+    (0x02E3, 0x02E3, &[0x2264]),
+    (0x02E4, 0x02E4, &[0x22E9]),
+    (0x02E5, 0x02ED, &[0x04E0]),
+    (0x02EE, 0x02EE, &[0x22E4]),
+    (0x02EF, 0x02FF, &[0x04E9]),
+    (0x0300, 0x0362, &[]),
+    (0x0363, 0x0363, &[0x1FA2]),
+    (0x0364, 0x0364, &[0x2007]),
+    (0x0365, 0x0365, &[0x2090]),
+    (0x0366, 0x0366, &[0x213C]),
+    (0x0367, 0x0367, &[0x2217]),
+    (0x0368, 0x0368, &[0x1FD6]),
+    (0x0369, 0x0369, &[0x1FEB]),
+    (0x036A, 0x036A, &[0x2075]),
+    (0x036B, 0x036B, &[0x2109]),
+    (0x036C, 0x036C, &[0x2193]),
+    (0x036D, 0x036D, &[0x21F7]),
+    (0x036E, 0x036E, &[0x2247]),
+    (0x036F, 0x036F, &[0x2264]),
+    (0x0370, 0x0370, &[0x2328]),
+    (0x0371, 0x0371, &[0x2328]),
+    (0x0372, 0x0372, &[0x2349]),
+    (0x0373, 0x0373, &[0x2349]),
+    (0x0374, 0x0375, &[0x04C5]),
+    (0x0376, 0x0376, &[0x2325]),
+    (0x0377, 0x0377, &[0x2325]),
+    (0x037A, 0x037A, &[0x232B]),
+    (0x037B, 0x037B, &[0x233E]),
+    (0x037C, 0x037C, &[0x233D]),
+    (0x037D, 0x037D, &[0x233F]),
+    (0x037E, 0x037E, &[0x023A]),
+    (0x037F, 0x037F, &[0x232C]),
+    (0x0384, 0x0384, &[0x04B5]),
+    (0x0385, 0x0385, &[0x04BB]),
+    (0x0386, 0x0386, &[0x231E]),
+    (0x0387, 0x0387, &[0x0293]),
+    (0x0388, 0x0388, &[0x2323]),
+    (0x0389, 0x0389, &[0x2329]),
+    (0x038A, 0x038A, &[0x232B]),
+    (0x038C, 0x038C, &[0x2333]),
+    (0x038E, 0x038E, &[0x2341]),
+    (0x038F, 0x038F, &[0x2346]),
+    (0x0390, 0x0390, &[0x232B]),
+    (0x0391, 0x0393, &[0x231E]),
+    (0x0394, 0x0395, &[0x2322]),
+    (0x0396, 0x0396, &[0x2327]),
+    (0x0397, 0x0399, &[0x2329]),
+    (0x039A, 0x039B, &[0x232D]),
+    (0x039C, 0x03A0, &[0x2330]),
+    (0x03A1, 0x03A1, &[0x2339]),
+    (0x03A3, 0x03A3, &[0x233C]),
+    (0x03A4, 0x03A8, &[0x2340]),
+    (0x03A9, 0x03A9, &[0x2346]),
+    (0x03AA, 0x03AA, &[0x232B]),
+    (0x03AB, 0x03AB, &[0x2341]),
+    (0x03AC, 0x03AC, &[0x231E]),
+    (0x03AD, 0x03AD, &[0x2323]),
+    (0x03AE, 0x03AE, &[0x2329]),
+    (0x03AF, 0x03AF, &[0x232B]),
+    (0x03B0, 0x03B0, &[0x2341]),
+    (0x03B1, 0x03B3, &[0x231E]),
+    (0x03B4, 0x03B5, &[0x2322]),
+    (0x03B6, 0x03B6, &[0x2327]),
+    (0x03B7, 0x03B9, &[0x2329]),
+    (0x03BA, 0x03BB, &[0x232D]),
+    (0x03BC, 0x03C0, &[0x2330]),
+    (0x03C1, 0x03C1, &[0x2339]),
+    (0x03C2, 0x03C2, &[0x233C]),
+    (0x03C3, 0x03C3, &[0x233C]),
+    (0x03C4, 0x03C8, &[0x2340]),
+    (0x03C9, 0x03C9, &[0x2346]),
+    (0x03CA, 0x03CA, &[0x232B]),
+    (0x03CB, 0x03CB, &[0x2341]),
+    (0x03CC, 0x03CC, &[0x2333]),
+    (0x03CD, 0x03CD, &[0x2341]),
+    (0x03CE, 0x03CE, &[0x2346]),
+    (0x03CF, 0x03CF, &[0x232D, 0x231E, 0x232B]),
+    (0x03D0, 0x03D0, &[0x231F]),
+    (0x03D1, 0x03D1, &[0x232A]),
+    (0x03D2, 0x03D2, &[0x2341]),
+    (0x03D3, 0x03D3, &[0x2341]),
+    (0x03D4, 0x03D5, &[0x2341]),
+    (0x03D6, 0x03D6, &[0x2334]),
+    (0x03D7, 0x03D7, &[0x232D, 0x231E, 0x232B]),
+    (0x03D8, 0x03D8, &[0x2338]),
+    (0x03D9, 0x03D9, &[0x2338]),
+    (0x03DA, 0x03DA, &[0x2326]),
+    (0x03DB, 0x03DB, &[0x2326]),
+    (0x03DC, 0x03DC, &[0x2324]),
+    (0x03DD, 0x03DD, &[0x2324]),
+    (0x03DE, 0x03DE, &[0x2337]),
+    (0x03DF, 0x03DF, &[0x2337]),
+    (0x03E0, 0x03E0, &[0x2348]),
+    (0x03E1, 0x03E1, &[0x2348]),
+    (0x03E2, 0x03E2, &[0x236A]),
+    (0x03E3, 0x03E3, &[0x236A]),
+    (0x03E4, 0x03E4, &[0x236F]),
+    (0x03E5, 0x03E6, &[0x236F]),
+    (0x03E7, 0x03E7, &[0x2370]),
+    (0x03E8, 0x03E8, &[0x2373]),
+    (0x03E9, 0x03E9, &[0x2373]),
+    (0x03EA, 0x03EA, &[0x237A]),
+    (0x03EB, 0x03EB, &[0x237A]),
+    (0x03EC, 0x03EC, &[0x237D]),
+    (0x03ED, 0x03ED, &[0x237D]),
+    (0x03EE, 0x03EE, &[0x2381]),
+    (0x03EF, 0x03EF, &[0x2381]),
+    (0x03F0, 0x03F0, &[0x232D]),
+    (0x03F1, 0x03F1, &[0x2339]),
+    (0x03F2, 0x03F2, &[0x233C]),
+    (0x03F3, 0x03F3, &[0x232C]),
+    (0x03F4, 0x03F4, &[0x232A]),
+    (0x03F5, 0x03F5, &[0x2323]),
+    (0x03F6, 0x03F6, &[0x0661]),
+    (0x03F7, 0x03F7, &[0x234A]),
+    (0x03F8, 0x03F8, &[0x234A]),
+    (0x03F9, 0x03F9, &[0x233C]),
+    (0x03FA, 0x03FA, &[0x2336]),
+    (0x03FB, 0x03FB, &[0x2336]),
+    (0x03FC, 0x03FC, &[0x233B]),
+    (0x03FD, 0x03FD, &[0x233E]),
+    (0x03FE, 0x03FE, &[0x233D]),
+    (0x03FF, 0x03FF, &[0x233F]),
+    (0x0400, 0x0400, &[0x23BF]),
+    (0x0401, 0x0401, &[0x23BF]),
+    (0x0402, 0x0402, &[0x23B5]),
+    (0x0403, 0x0403, &[0x239B]),
+    (0x0404, 0x0404, &[0x23C3]),
+    (0x0405, 0x0405, &[0x23D9]),
+    (0x0406, 0x0406, &[0x23ED]),
+    (0x0407, 0x0407, &[0x23ED]),
+    (0x0408, 0x0408, &[0x23F6]),
+    (0x0409, 0x0409, &[0x2421]),
+    (0x040A, 0x040A, &[0x2447]),
+    (0x040B, 0x040B, &[0x247E]),
+    (0x040C, 0x040C, &[0x23FB]),
+    (0x040D, 0x040D, &[0x23E5]),
+    (0x040E, 0x040E, &[0x2482]),
+    (0x040F, 0x040F, &[0x24E4]),
+    (0x0410, 0x0410, &[0x2387]),
+    (0x0411, 0x0411, &[0x2393]),
+    (0x0412, 0x0412, &[0x2397]),
+    (0x0413, 0x0413, &[0x239B]),
+    (0x0414, 0x0414, &[0x23AF]),
+    (0x0415, 0x0415, &[0x23BF]),
+    (0x0416, 0x0416, &[0x23C7]),
+    (0x0417, 0x0417, &[0x23D1]),
+    (0x0418, 0x0418, &[0x23E5]),
+    (0x0419, 0x0419, &[0x23F2]),
+    (0x041A, 0x041A, &[0x23FB]),
+    (0x041B, 0x041B, &[0x2415]),
+    (0x041C, 0x041C, &[0x2428]),
+    (0x041D, 0x041D, &[0x2431]),
+    (0x041E, 0x041E, &[0x244C]),
+    (0x041F, 0x041F, &[0x2454]),
+    (0x0420, 0x0420, &[0x2461]),
+    (0x0421, 0x0421, &[0x246A]),
+    (0x0422, 0x0422, &[0x2473]),
+    (0x0423, 0x0423, &[0x2482]),
+    (0x0424, 0x0424, &[0x2493]),
+    (0x0425, 0x0425, &[0x2497]),
+    (0x0426, 0x0426, &[0x24BE]),
+    (0x0427, 0x0427, &[0x24C9]),
+    (0x0428, 0x0428, &[0x24E8]),
+    (0x0429, 0x0429, &[0x24ED]),
+    (0x042A, 0x042A, &[0x24F4]),
+    (0x042B, 0x042B, &[0x24F9]),
+    (0x042C, 0x042C, &[0x24FD]),
+    (0x042D, 0x042D, &[0x250A]),
+    (0x042E, 0x042E, &[0x250E]),
+    (0x042F, 0x042F, &[0x2514]),
+    (0x0430, 0x0430, &[0x2387]),
+    (0x0431, 0x0431, &[0x2393]),
+    (0x0432, 0x0432, &[0x2397]),
+    (0x0433, 0x0433, &[0x239B]),
+    (0x0434, 0x0434, &[0x23AF]),
+    (0x0435, 0x0435, &[0x23BF]),
+    (0x0436, 0x0436, &[0x23C7]),
+    (0x0437, 0x0437, &[0x23D1]),
+    (0x0438, 0x0438, &[0x23E5]),
+    (0x0439, 0x0439, &[0x23F2]),
+    (0x043A, 0x043A, &[0x23FB]),
+    (0x043B, 0x043B, &[0x2415]),
+    (0x043C, 0x043C, &[0x2428]),
+    (0x043D, 0x043D, &[0x2431]),
+    (0x043E, 0x043E, &[0x244C]),
+    (0x043F, 0x043F, &[0x2454]),
+    (0x0440, 0x0440, &[0x2461]),
+    (0x0441, 0x0441, &[0x246A]),
+    (0x0442, 0x0442, &[0x2473]),
+    (0x0443, 0x0443, &[0x2482]),
+    (0x0444, 0x0444, &[0x2493]),
+    (0x0445, 0x0445, &[0x2497]),
+    (0x0446, 0x0446, &[0x24BE]),
+    (0x0447, 0x0447, &[0x24C9]),
+    (0x0448, 0x0448, &[0x24E8]),
+    (0x0449, 0x0449, &[0x24ED]),
+    (0x044A, 0x044A, &[0x24F4]),
+    (0x044B, 0x044B, &[0x24F9]),
+    (0x044C, 0x044C, &[0x24FD]),
+    (0x044D, 0x044D, &[0x250A]),
+    (0x044E, 0x044E, &[0x250E]),
+    (0x044F, 0x044F, &[0x2514]),
+    (0x0450, 0x0450, &[0x23BF]),
+    (0x0451, 0x0451, &[0x23BF]),
+    (0x0452, 0x0452, &[0x23B5]),
+    (0x0453, 0x0453, &[0x239B]),
+    (0x0454, 0x0454, &[0x23C3]),
+    (0x0455, 0x0455, &[0x23D9]),
+    (0x0456, 0x0456, &[0x23ED]),
+    (0x0457, 0x0457, &[0x23ED]),
+    (0x0458, 0x0458, &[0x23F6]),
+    (0x0459, 0x0459, &[0x2421]),
+    (0x045A, 0x045A, &[0x2447]),
+    (0x045B, 0x045B, &[0x247E]),
+    (0x045C, 0x045C, &[0x23FB]),
+    (0x045D, 0x045D, &[0x23E5]),
+    (0x045E, 0x045E, &[0x2482]),
+    (0x045F, 0x045F, &[0x24E4]),
+    (0x0460, 0x0460, &[0x24AD]),
+    (0x0461, 0x0461, &[0x24AD]),
+    (0x0462, 0x0462, &[0x2505]),
+    (0x0463, 0x0463, &[0x2505]),
+    (0x0464, 0x0464, &[0x2519]),
+    (0x0465, 0x0465, &[0x2519]),
+    (0x0466, 0x0466, &[0x251D]),
+    (0x0467, 0x0467, &[0x251D]),
+    (0x0468, 0x0468, &[0x2527]),
+    (0x0469, 0x0469, &[0x2527]),
+    (0x046A, 0x046A, &[0x2522]),
+    (0x046B, 0x046B, &[0x2522]),
+    (0x046C, 0x046C, &[0x252C]),
+    (0x046D, 0x046D, &[0x252C]),
+    (0x046E, 0x046E, &[0x2530]),
+    (0x046F, 0x046F, &[0x2530]),
+    (0x0470, 0x0470, &[0x2534]),
+    (0x0471, 0x0471, &[0x2534]),
+    (0x0472, 0x0472, &[0x2538]),
+    (0x0473, 0x0473, &[0x2538]),
+    (0x0474, 0x0474, &[0x253C]),
+    (0x0475, 0x0475, &[0x253C]),
+    (0x0476, 0x0476, &[0x253C]),
+    (0x0477, 0x0477, &[0x253C]),
+    (0x0478, 0x0478, &[0x248F]),
+    (0x0479, 0x0479, &[0x248F]),
+    (0x047A, 0x047A, &[0x24BA]),
+    (0x047B, 0x047B, &[0x24BA]),
+    (0x047C, 0x047C, &[0x24B6]),
+    (0x047D, 0x047D, &[0x24B6]),
+    (0x047E, 0x047E, &[0x24B1]),
+    (0x047F, 0x047F, &[0x24B1]),
+    (0x0480, 0x0480, &[0x245D]),
+    (0x0481, 0x0481, &[0x245D]),
+    (0x0482, 0x0482, &[0x052B]),
+    (0x0483, 0x0489, &[]),
+    (0x048A, 0x048A, &[0x23E9]),
+    (0x048B, 0x048B, &[0x23E9]),
+    (0x048C, 0x048C, &[0x2501]),
+    (0x048D, 0x048D, &[0x2501]),
+    (0x048E, 0x048E, &[0x2465]),
+    (0x048F, 0x048F, &[0x2465]),
+    (0x0490, 0x0490, &[0x239B]),
+    (0x0491, 0x0491, &[0x239B]),
+    (0x0492, 0x0492, &[0x239F]),
+    (0x0493, 0x0493, &[0x239F]),
+    (0x0494, 0x0494, &[0x23A7]),
+    (0x0495, 0x0495, &[0x23A7]),
+    (0x0496, 0x0496, &[0x23CD]),
+    (0x0497, 0x0497, &[0x23CD]),
+    (0x0498, 0x0498, &[0x23BB]),
+    (0x0499, 0x0499, &[0x23BB]),
+    (0x049A, 0x049A, &[0x23FF]),
+    (0x049B, 0x049B, &[0x23FF]),
+    (0x049C, 0x049C, &[0x240F]),
+    (0x049D, 0x049D, &[0x240F]),
+    (0x049E, 0x049E, &[0x240B]),
+    (0x049F, 0x049F, &[0x240B]),
+    (0x04A0, 0x04A0, &[0x2407]),
+    (0x04A1, 0x04A1, &[0x2407]),
+    (0x04A2, 0x04A2, &[0x243A]),
+    (0x04A3, 0x04A3, &[0x243A]),
+    (0x04A4, 0x04A4, &[0x2443]),
+    (0x04A5, 0x04A5, &[0x2443]),
+    (0x04A6, 0x04A6, &[0x2459]),
+    (0x04A7, 0x04A7, &[0x2459]),
+    (0x04A8, 0x04A8, &[0x2541]),
+    (0x04A9, 0x04A9, &[0x2541]),
+    (0x04AA, 0x04AA, &[0x246F]),
+    (0x04AB, 0x04AB, &[0x246F]),
+    (0x04AC, 0x04AC, &[0x2479]),
+    (0x04AD, 0x04AD, &[0x2479]),
+    (0x04AE, 0x04AE, &[0x2486]),
+    (0x04AF, 0x04AF, &[0x2486]),
+    (0x04B0, 0x04B0, &[0x248A]),
+    (0x04B1, 0x04B1, &[0x248A]),
+    (0x04B2, 0x04B2, &[0x24A3]),
+    (0x04B3, 0x04B3, &[0x24A3]),
+    (0x04B4, 0x04B4, &[0x24C4]),
+    (0x04B5, 0x04B5, &[0x24C4]),
+    (0x04B6, 0x04B6, &[0x24CF]),
+    (0x04B7, 0x04B7, &[0x24CF]),
+    (0x04B8, 0x04B8, &[0x24D7]),
+    (0x04B9, 0x04B9, &[0x24D7]),
+    (0x04BA, 0x04BA, &[0x24A7]),
+    (0x04BB, 0x04BB, &[0x24A7]),
+    (0x04BC, 0x04BC, &[0x24DC]),
+    (0x04BD, 0x04BD, &[0x24DC]),
+    (0x04BE, 0x04BE, &[0x24E0]),
+    (0x04BF, 0x04BF, &[0x24E0]),
+    (0x04C0, 0x04C0, &[0x2546]),
+    (0x04C1, 0x04C1, &[0x23C7]),
+    (0x04C2, 0x04C2, &[0x23C7]),
+    (0x04C3, 0x04C3, &[0x2403]),
+    (0x04C4, 0x04C4, &[0x2403]),
+    (0x04C5, 0x04C5, &[0x241A]),
+    (0x04C6, 0x04C6, &[0x241A]),
+    (0x04C7, 0x04C7, &[0x243E]),
+    (0x04C8, 0x04C8, &[0x243E]),
+    (0x04C9, 0x04C9, &[0x2436]),
+    (0x04CA, 0x04CA, &[0x2436]),
+    (0x04CB, 0x04CB, &[0x24D3]),
+    (0x04CC, 0x04CC, &[0x24D3]),
+    (0x04CD, 0x04CD, &[0x242C]),
+    (0x04CE, 0x04CE, &[0x242C]),
+    (0x04CF, 0x04CF, &[0x2546]),
+    (0x04D0, 0x04D0, &[0x2387]),
+    (0x04D1, 0x04D1, &[0x2387]),
+    (0x04D2, 0x04D2, &[0x2387]),
+    (0x04D3, 0x04D3, &[0x2387]),
+    (0x04D4, 0x04D4, &[0x238F]),
+    (0x04D5, 0x04D5, &[0x238F]),
+    (0x04D6, 0x04D6, &[0x23BF]),
+    (0x04D7, 0x04D7, &[0x23BF]),
+    (0x04D8, 0x04D8, &[0x238B]),
+    (0x04D9, 0x04D9, &[0x238B]),
+    (0x04DA, 0x04DA, &[0x238B]),
+    (0x04DB, 0x04DB, &[0x238B]),
+    (0x04DC, 0x04DC, &[0x23C7]),
+    (0x04DD, 0x04DD, &[0x23C7]),
+    (0x04DE, 0x04DE, &[0x23D1]),
+    (0x04DF, 0x04DF, &[0x23D1]),
+    (0x04E0, 0x04E0, &[0x23DE]),
+    (0x04E1, 0x04E1, &[0x23DE]),
+    (0x04E2, 0x04E2, &[0x23E5]),
+    (0x04E3, 0x04E3, &[0x23E5]),
+    (0x04E4, 0x04E4, &[0x23E5]),
+    (0x04E5, 0x04E5, &[0x23E5]),
+    (0x04E6, 0x04E6, &[0x244C]),
+    (0x04E7, 0x04E7, &[0x244C]),
+    (0x04E8, 0x04E8, &[0x2450]),
+    (0x04E9, 0x04E9, &[0x2450]),
+    (0x04EA, 0x04EA, &[0x2450]),
+    (0x04EB, 0x04EB, &[0x2450]),
+    (0x04EC, 0x04EC, &[0x250A]),
+    (0x04ED, 0x04ED, &[0x250A]),
+    (0x04EE, 0x04EE, &[0x2482]),
+    (0x04EF, 0x04EF, &[0x2482]),
+    (0x04F0, 0x04F0, &[0x2482]),
+    (0x04F1, 0x04F1, &[0x2482]),
+    (0x04F2, 0x04F2, &[0x2482]),
+    (0x04F3, 0x04F3, &[0x2482]),
+    (0x04F4, 0x04F4, &[0x24C9]),
+    (0x04F5, 0x04F5, &[0x24C9]),
+    (0x04F6, 0x04F6, &[0x23AB]),
+    (0x04F7, 0x04F7, &[0x23AB]),
+    (0x04F8, 0x04F8, &[0x24F9]),
+    (0x04F9, 0x04F9, &[0x24F9]),
+    (0x04FA, 0x04FA, &[0x23A3]),
+    (0x04FB, 0x04FB, &[0x23A3]),
+    (0x04FC, 0x04FC, &[0x249B]),
+    (0x04FD, 0x04FD, &[0x249B]),
+    (0x04FE, 0x04FE, &[0x249F]),
+    (0x04FF, 0x04FF, &[0x249F]),
+    (0x0500, 0x0500, &[0x23B3]),
+    (0x0501, 0x0501, &[0x23B3]),
+    (0x0502, 0x0502, &[0x23BA]),
+    (0x0503, 0x0503, &[0x23BA]),
+    (0x0504, 0x0504, &[0x23D6]),
+    (0x0505, 0x0505, &[0x23D6]),
+    (0x0506, 0x0506, &[0x23E3]),
+    (0x0507, 0x0507, &[0x23E3]),
+    (0x0508, 0x0508, &[0x2426]),
+    (0x0509, 0x0509, &[0x2426]),
+    (0x050A, 0x050A, &[0x244B]),
+    (0x050B, 0x050B, &[0x244B]),
+    (0x050C, 0x050C, &[0x246E]),
+    (0x050D, 0x050D, &[0x246E]),
+    (0x050E, 0x050E, &[0x2478]),
+    (0x050F, 0x050F, &[0x2478]),
+    (0x0510, 0x0510, &[0x23D7]),
+    (0x0511, 0x0511, &[0x23D7]),
+    (0x0512, 0x0512, &[0x241F]),
+    (0x0513, 0x0513, &[0x241F]),
+    (0x0514, 0x0514, &[0x2427]),
+    (0x0515, 0x0515, &[0x2427]),
+    (0x0516, 0x0516, &[0x2469]),
+    (0x0517, 0x0517, &[0x2469]),
+    (0x0518, 0x0518, &[0x2518]),
+    (0x0519, 0x0519, &[0x2518]),
+    (0x051A, 0x051A, &[0x2414]),
+    (0x051B, 0x051B, &[0x2414]),
+    (0x051C, 0x051C, &[0x2545]),
+    (0x051D, 0x051D, &[0x2545]),
+    (0x051E, 0x051E, &[0x2413]),
+    (0x051F, 0x051F, &[0x2413]),
+    (0x0520, 0x0520, &[0x2420]),
+    (0x0521, 0x0521, &[0x2420]),
+    (0x0522, 0x0522, &[0x2442]),
+    (0x0523, 0x0523, &[0x2442]),
+    (0x0524, 0x0524, &[0x2458]),
+    (0x0525, 0x0525, &[0x2458]),
+    (0x0526, 0x0526, &[0x24AB]),
+    (0x0527, 0x0527, &[0x24AB]),
+    (0x0528, 0x0528, &[0x2435]),
+    (0x0529, 0x0529, &[0x2435]),
+    (0x052A, 0x052A, &[0x23CB]),
+    (0x052B, 0x052B, &[0x23CB]),
+    (0x052C, 0x052C, &[0x24CD]),
+    (0x052D, 0x052D, &[0x24CD]),
+    (0x052E, 0x052E, &[0x241E]),
+    (0x052F, 0x052F, &[0x241E]),
+    (0x0531, 0x0531, &[0x25F5]),
+    (0x0532, 0x0545, &[0x25F7]),
+    (0x0546, 0x0556, &[0x260C]),
+    (0x0559, 0x0559, &[0x261D]),
+    (0x055A, 0x055B, &[0x03E4]),
+    (0x055C, 0x055C, &[0x0269]),
+    (0x055D, 0x055D, &[0x022A]),
+    (0x055E, 0x055E, &[0x0270]),
+    (0x055F, 0x055F, &[0x03E6]),
+    (0x0560, 0x0560, &[0x25F6]),
+    (0x0561, 0x0561, &[0x25F5]),
+    (0x0562, 0x0575, &[0x25F7]),
+    (0x0576, 0x0586, &[0x260C]),
+    (0x0587, 0x0587, &[0x25FA, 0x2618]),
+    (0x0588, 0x0588, &[0x260B]),
+    (0x0589, 0x0589, &[0x0241]),
+    (0x058A, 0x058A, &[0x020E]),
+    (0x058D, 0x058E, &[0x052C]),
+    (0x058F, 0x058F, &[0x1F67]),
+    (0x0591, 0x05BD, &[]),
+    (0x05BE, 0x05BE, &[0x03E7]),
+    (0x05BF, 0x05BF, &[]),
+    (0x05C0, 0x05C0, &[0x03E8]),
+    (0x05C1, 0x05C2, &[]),
+    (0x05C3, 0x05C3, &[0x03E9]),
+    (0x05C4, 0x05C5, &[]),
+    (0x05C6, 0x05C6, &[0x03EA]),
+    (0x05C7, 0x05C7, &[]),
+    (0x05D0, 0x05DA, &[0x261E]),
+    (0x05DB, 0x05DD, &[0x2628]),
+    (0x05DE, 0x05DF, &[0x262A]),
+    (0x05E0, 0x05E3, &[0x262B]),
+    (0x05E4, 0x05E5, &[0x262E]),
+    (0x05E6, 0x05EA, &[0x262F]),
+    (0x05EF, 0x05EF, &[0x2627, 0x2622, 0x2623, 0x2622]),
+    (0x05F0, 0x05F0, &[0x2623, 0x2623]),
+    (0x05F1, 0x05F1, &[0x2623, 0x2627]),
+    (0x05F2, 0x05F2, &[0x2627, 0x2627]),
+    (0x05F3, 0x05F4, &[0x03EB]),
+    (0x0600, 0x0605, &[]),
+    (0x0606, 0x0606, &[0x067D]),
+    (0x0607, 0x0607, &[0x067F]),
+    (0x0608, 0x0608, &[0x052E]),
+    (0x0609, 0x0609, &[0x03B0]),
+    (0x060A, 0x060A, &[0x03B2]),
+    (0x060B, 0x060B, &[0x1F68]),
+    (0x060C, 0x060D, &[0x022B]),
+    (0x060E, 0x060F, &[0x0531]),
+    (0x0610, 0x061A, &[]),
+    (0x061B, 0x061B, &[0x023B]),
+    (0x061C, 0x061C, &[]),
+    (0x061E, 0x061E, &[0x0242]),
+    (0x061F, 0x061F, &[0x0271]),
+    (0x0620, 0x0620, &[0x2740]),
+    (0x0621, 0x0623, &[0x2664]),
+    (0x0624, 0x0625, &[0x2669]),
+    (0x0626, 0x0626, &[0x266E]),
+    (0x0627, 0x0627, &[0x2672]),
+    (0x0628, 0x0628, &[0x2674]),
+    (0x0629, 0x062B, &[0x2684]),
+    (0x062C, 0x062C, &[0x268F]),
+    (0x062D, 0x062E, &[0x2699]),
+    (0x062F, 0x0630, &[0x26A4]),
+    (0x0631, 0x0632, &[0x26B3]),
+    (0x0633, 0x0634, &[0x26C6]),
+    (0x0635, 0x0636, &[0x26D1]),
+    (0x0637, 0x0638, &[0x26D7]),
+    (0x0639, 0x063A, &[0x26DB]),
+    (0x063B, 0x063C, &[0x2707]),
+    (0x063D, 0x063F, &[0x273D]),
+    (0x0640, 0x0640, &[]),
+    (0x0641, 0x0641, &[0x26E4]),
+    (0x0642, 0x0642, &[0x26F0]),
+    (0x0643, 0x0643, &[0x26F6]),
+    (0x0644, 0x0644, &[0x270C]),
+    (0x0645, 0x0645, &[0x2714]),
+    (0x0646, 0x0646, &[0x2718]),
+    (0x0647, 0x0647, &[0x2722]),
+    (0x0648, 0x0648, &[0x2728]),
+    (0x0649, 0x064A, &[0x2736]),
+    (0x064B, 0x065F, &[]),
+    (0x0660, 0x0669, &[0x1F98]),
+    (0x066A, 0x066A, &[0x03AE]),
+    (0x066B, 0x066C, &[0x022D]),
+    (0x066D, 0x066D, &[0x03A4]),
+    (0x066E, 0x066E, &[0x2673]),
+    (0x066F, 0x066F, &[0x26EF]),
+    (0x0670, 0x0670, &[]),
+    (0x0671, 0x0671, &[0x2668]),
+    (0x0672, 0x0672, &[0x2667]),
+    (0x0673, 0x0673, &[0x266B]),
+    (0x0674, 0x0674, &[0x2664]),
+    (0x0675, 0x0675, &[0x2672, 0x2664]),
+    (0x0676, 0x0676, &[0x2728, 0x2664]),
+    (0x0677, 0x0677, &[0x272C, 0x2664]),
+    (0x0678, 0x0678, &[0x2737, 0x2664]),
+    (0x0679, 0x067A, &[0x2687]),
+    (0x067B, 0x067B, &[0x2675]),
+    (0x067C, 0x067D, &[0x2689]),
+    (0x067E, 0x067E, &[0x2676]),
+    (0x067F, 0x067F, &[0x268B]),
+    (0x0680, 0x0680, &[0x2677]),
+    (0x0681, 0x0682, &[0x269B]),
+    (0x0683, 0x0684, &[0x2690]),
+    (0x0685, 0x0685, &[0x269D]),
+    (0x0686, 0x0686, &[0x2692]),
+    (0x0687, 0x0687, &[0x2694]),
+    (0x0688, 0x068D, &[0x26A6]),
+    (0x068E, 0x0690, &[0x26AD]),
+    (0x0691, 0x0699, &[0x26B5]),
+    (0x069A, 0x069C, &[0x26C8]),
+    (0x069D, 0x069D, &[0x26D3]),
+    (0x069E, 0x069E, &[0x26D5]),
+    (0x069F, 0x069F, &[0x26D9]),
+    (0x06A0, 0x06A0, &[0x26DD]),
+    (0x06A1, 0x06A2, &[0x26E5]),
+    (0x06A3, 0x06A4, &[0x26E8]),
+    (0x06A5, 0x06A6, &[0x26EB]),
+    (0x06A7, 0x06A7, &[0x26F1]),
+    (0x06A8, 0x06A8, &[0x26F3]),
+    (0x06A9, 0x06AC, &[0x26F7]),
+    (0x06AD, 0x06AE, &[0x26FC]),
+    (0x06AF, 0x06AF, &[0x26FF]),
+    (0x06B0, 0x06B4, &[0x2701]),
+    (0x06B5, 0x06B8, &[0x270D]),
+    (0x06B9, 0x06B9, &[0x271E]),
+    (0x06BA, 0x06BA, &[0x2719]),
+    (0x06BB, 0x06BD, &[0x271B]),
+    (0x06BE, 0x06BE, &[0x2723]),
+    (0x06BF, 0x06BF, &[0x2693]),
+    (0x06C0, 0x06C0, &[0x2727]),
+    (0x06C1, 0x06C1, &[0x2724]),
+    (0x06C2, 0x06C3, &[0x2724]),
+    (0x06C4, 0x06CB, &[0x2729]),
+    (0x06CC, 0x06CE, &[0x2738]),
+    (0x06CF, 0x06CF, &[0x2732]),
+    (0x06D0, 0x06D1, &[0x273B]),
+    (0x06D2, 0x06D2, &[0x2745]),
+    (0x06D3, 0x06D3, &[0x2745]),
+    (0x06D4, 0x06D4, &[0x0280]),
+    (0x06D5, 0x06D5, &[0x2727]),
+    (0x06D6, 0x06DD, &[]),
+    (0x06DE, 0x06DE, &[0x0533]),
+    (0x06DF, 0x06E4, &[]),
+    (0x06E5, 0x06E5, &[0x2728]),
+    (0x06E6, 0x06E6, &[0x2737]),
+    (0x06E7, 0x06E8, &[]),
+    (0x06E9, 0x06E9, &[0x0534]),
+    (0x06EA, 0x06ED, &[]),
+    (0x06EE, 0x06EE, &[0x26B0]),
+    (0x06EF, 0x06EF, &[0x26BE]),
+    (0x06F0, 0x06F9, &[0x1F98]),
+    (0x06FA, 0x06FA, &[0x26CB]),
+    (0x06FB, 0x06FB, &[0x26D6]),
+    (0x06FC, 0x06FC, &[0x26DF]),
+    (0x06FD, 0x06FD, &[0x2664]),
+    (0x06FE, 0x06FE, &[0x2714]),
+    (0x06FF, 0x06FF, &[0x2726]),
+    (0x0700, 0x0700, &[0x02CD]),
+    (0x0701, 0x0702, &[0x0281]),
+    (0x0703, 0x0708, &[0x0243]),
+    (0x0709, 0x0709, &[0x0272]),
+    (0x070A, 0x070D, &[0x03ED]),
+    (0x070F, 0x070F, &[]),
+    (0x0710, 0x0710, &[0x2748]),
+    (0x0711, 0x0711, &[]),
+    (0x0712, 0x0713, &[0x2749]),
+    (0x0714, 0x0714, &[0x274A]),
+    (0x0715, 0x0715, &[0x274C]),
+    (0x0716, 0x0716, &[0x274B]),
+    (0x0717, 0x0719, &[0x274D]),
+    (0x071A, 0x071B, &[0x2751]),
+    (0x071C, 0x071F, &[0x2752]),
+    (0x0720, 0x0723, &[0x2757]),
+    (0x0724, 0x0726, &[0x275A]),
+    (0x0727, 0x0727, &[0x275C]),
+    (0x0728, 0x072C, &[0x275E]),
+    (0x072D, 0x072E, &[0x2749]),
+    (0x072F, 0x072F, &[0x274C]),
+    (0x0730, 0x074A, &[]),
+    (0x074D, 0x074D, &[0x2750]),
+    (0x074E, 0x074E, &[0x2756]),
+    (0x074F, 0x074F, &[0x275D]),
+    (0x0750, 0x0755, &[0x2678]),
+    (0x0756, 0x0756, &[0x267F]),
+    (0x0757, 0x0758, &[0x269E]),
+    (0x0759, 0x075A, &[0x26B1]),
+    (0x075B, 0x075B, &[0x26BF]),
+    (0x075C, 0x075C, &[0x26CC]),
+    (0x075D, 0x075F, &[0x26E0]),
+    (0x0760, 0x0761, &[0x26ED]),
+    (0x0762, 0x0762, &[0x2706]),
+    (0x0763, 0x0764, &[0x2709]),
+    (0x0765, 0x0766, &[0x2715]),
+    (0x0767, 0x0769, &[0x271F]),
+    (0x076A, 0x076A, &[0x2711]),
+    (0x076B, 0x076C, &[0x26C0]),
+    (0x076D, 0x076D, &[0x26CD]),
+    (0x076E, 0x076F, &[0x26A0]),
+    (0x0770, 0x0770, &[0x26CE]),
+    (0x0771, 0x0771, &[0x26C2]),
+    (0x0772, 0x0772, &[0x26A2]),
+    (0x0773, 0x0774, &[0x266C]),
+    (0x0775, 0x0777, &[0x2741]),
+    (0x0778, 0x0779, &[0x2733]),
+    (0x077A, 0x077B, &[0x2746]),
+    (0x077C, 0x077C, &[0x26A3]),
+    (0x077D, 0x077E, &[0x26CF]),
+    (0x077F, 0x077F, &[0x26FB]),
+    (0x0780, 0x0780, &[0x2787]),
+    (0x0781, 0x0783, &[0x278A]),
+    (0x0784, 0x0787, &[0x278E]),
+    (0x0788, 0x0788, &[0x2794]),
+    (0x0789, 0x078B, &[0x2796]),
+    (0x078C, 0x078C, &[0x279A]),
+    (0x078D, 0x078E, &[0x279E]),
+    (0x078F, 0x0790, &[0x27A1]),
+    (0x0791, 0x0797, &[0x27A6]),
+    (0x0798, 0x0798, &[0x279B]),
+    (0x0799, 0x079A, &[0x2788]),
+    (0x079B, 0x079B, &[0x2799]),
+    (0x079C, 0x079C, &[0x278D]),
+    (0x079D, 0x079F, &[0x27A3]),
+    (0x07A0, 0x07A1, &[0x279C]),
+    (0x07A2, 0x07A3, &[0x2792]),
+    (0x07A4, 0x07A4, &[0x27A0]),
+    (0x07A5, 0x07A5, &[0x2795]),
+    (0x07A6, 0x07B0, &[0x27AE]),
+    (0x07B1, 0x07B1, &[0x27AD]),
+    (0x07C0, 0x07C9, &[0x1F98]),
+    (0x07CA, 0x07E7, &[0x27B9]),
+    (0x07E8, 0x07E9, &[0x27C5]),
+    (0x07EA, 0x07EA, &[0x27C8]),
+    (0x07EB, 0x07F3, &[]),
+    (0x07F4, 0x07F5, &[0x27D7]),
+    (0x07F6, 0x07F6, &[0x0546]),
+    (0x07F7, 0x07F7, &[0x02CE]),
+    (0x07F8, 0x07F8, &[0x022F]),
+    (0x07F9, 0x07F9, &[0x026A]),
+    (0x07FA, 0x07FA, &[]),
+    (0x07FD, 0x07FD, &[]),
+    (0x07FE, 0x07FF, &[0x1F69]),
+    (0x0800, 0x0817, &[0x264A]),
+    (0x0818, 0x0819, &[]),
+    (0x081A, 0x081B, &[0x2662]),
+    (0x081C, 0x082D, &[]),
+    (0x0830, 0x083E, &[0x0249]),
+    (0x0840, 0x0858, &[0x276E]),
+    (0x0859, 0x085B, &[]),
+    (0x085E, 0x085E, &[0x03F1]),
+    (0x0860, 0x086A, &[0x2763]),
+    (0x08A0, 0x08A0, &[0x267E]),
+    (0x08A1, 0x08A1, &[0x2681]),
+    (0x08A2, 0x08A2, &[0x2695]),
+    (0x08A3, 0x08A3, &[0x26DA]),
+    (0x08A4, 0x08A4, &[0x26EA]),
+    (0x08A5, 0x08A5, &[0x26F5]),
+    (0x08A6, 0x08A6, &[0x2712]),
+    (0x08A7, 0x08A7, &[0x2717]),
+    (0x08A8, 0x08A9, &[0x266F]),
+    (0x08AA, 0x08AA, &[0x26C3]),
+    (0x08AB, 0x08AB, &[0x2735]),
+    (0x08AC, 0x08AD, &[0x2671]),
+    (0x08AE, 0x08AE, &[0x26AC]),
+    (0x08AF, 0x08AF, &[0x26D4]),
+    (0x08B0, 0x08B0, &[0x2700]),
+    (0x08B1, 0x08B1, &[0x2731]),
+    (0x08B2, 0x08B2, &[0x26C4]),
+    (0x08B3, 0x08B3, &[0x26E3]),
+    (0x08B4, 0x08B4, &[0x26FE]),
+    (0x08B6, 0x08B7, &[0x2682]),
+    (0x08B8, 0x08B8, &[0x268C]),
+    (0x08B9, 0x08B9, &[0x26C5]),
+    (0x08BA, 0x08BA, &[0x2744]),
+    (0x08BB, 0x08BB, &[0x26E7]),
+    (0x08BC, 0x08BC, &[0x26F2]),
+    (0x08BD, 0x08BD, &[0x271A]),
+    (0x08BE, 0x08BE, &[0x2680]),
+    (0x08BF, 0x08C0, &[0x268D]),
+    (0x08C1, 0x08C1, &[0x2696]),
+    (0x08C2, 0x08C2, &[0x270B]),
+    (0x08C3, 0x08C3, &[0x26DE]),
+    (0x08C4, 0x08C4, &[0x26F4]),
+    (0x08C5, 0x08C6, &[0x2697]),
+    (0x08C7, 0x08C7, &[0x2713]),
+    (0x08D3, 0x0903, &[]),
+    (0x0904, 0x0906, &[0x29DA]),
+    (0x0907, 0x090B, &[0x29E2]),
+    (0x090C, 0x090C, &[0x29E8]),
+    (0x090D, 0x0910, &[0x29EA]),
+    (0x0911, 0x0917, &[0x29EF]),
+    (0x0918, 0x091C, &[0x29F7]),
+    (0x091D, 0x0920, &[0x29FE]),
+    (0x0921, 0x0921, &[0x2A03]),
+    (0x0922, 0x0928, &[0x2A05]),
+    (0x0929, 0x092C, &[0x2A0B]),
+    (0x092D, 0x092F, &[0x2A10]),
+    (0x0930, 0x0930, &[0x2A14]),
+    (0x0931, 0x0933, &[0x2A14]),
+    (0x0934, 0x0939, &[0x2A16]),
+    (0x093A, 0x093B, &[0x2A24]),
+    (0x093C, 0x093C, &[]),
+    (0x093D, 0x093D, &[0x2A1C]),
+    (0x093E, 0x093E, &[0x2A23]),
+    (0x093F, 0x0944, &[0x2A29]),
+    (0x0945, 0x0945, &[0x2A31]),
+    (0x0946, 0x0947, &[0x2A33]),
+    (0x0948, 0x0948, &[0x2A36]),
+    (0x0949, 0x094D, &[0x2A38]),
+    (0x094E, 0x094E, &[0x2A35]),
+    (0x094F, 0x094F, &[0x2A26]),
+    (0x0950, 0x0950, &[0x29D7]),
+    (0x0951, 0x0954, &[]),
+    (0x0955, 0x0955, &[0x2A32]),
+    (0x0956, 0x0957, &[0x2A27]),
+    (0x0958, 0x095A, &[0x29F3]),
+    (0x095B, 0x095B, &[0x29FB]),
+    (0x095C, 0x095C, &[0x2A03]),
+    (0x095D, 0x095D, &[0x2A05]),
+    (0x095E, 0x095E, &[0x2A0D]),
+    (0x095F, 0x095F, &[0x2A12]),
+    (0x0960, 0x0960, &[0x29E7]),
+    (0x0961, 0x0961, &[0x29E9]),
+    (0x0962, 0x0963, &[0x2A2F]),
+    (0x0964, 0x0965, &[0x0296]),
+    (0x0966, 0x096F, &[0x1F98]),
+    (0x0970, 0x0970, &[0x0400]),
+    (0x0971, 0x0971, &[0x1F48]),
+    (0x0972, 0x0972, &[0x29D9]),
+    (0x0973, 0x0977, &[0x29DD]),
+    (0x0978, 0x0978, &[0x2A02]),
+    (0x0979, 0x0979, &[0x29FC]),
+    (0x097A, 0x097A, &[0x2A13]),
+    (0x097B, 0x097B, &[0x29F6]),
+    (0x097C, 0x097C, &[0x29FD]),
+    (0x097D, 0x097D, &[0x2A1D]),
+    (0x097E, 0x097E, &[0x2A04]),
+    (0x097F, 0x097F, &[0x2A0F]),
+    (0x0980, 0x0980, &[0x2A3D]),
+    (0x0981, 0x0983, &[]),
+    (0x0985, 0x098B, &[0x2A3E]),
+    (0x098C, 0x098C, &[0x2A46]),
+    (0x098F, 0x0990, &[0x2A48]),
+    (0x0993, 0x09A8, &[0x2A4A]),
+    (0x09AA, 0x09B0, &[0x2A60]),
+    (0x09B2, 0x09B2, &[0x2A68]),
+    (0x09B6, 0x09B9, &[0x2A6A]),
+    (0x09BC, 0x09BC, &[]),
+    (0x09BD, 0x09C4, &[0x2A6E]),
+    (0x09C7, 0x09C8, &[0x2A78]),
+    (0x09CB, 0x09CD, &[0x2A7A]),
+    (0x09CE, 0x09CE, &[0x2A5B, 0x2A7C]),
+    (0x09D7, 0x09D7, &[0x2A7D]),
+    (0x09DC, 0x09DD, &[0x2A58]),
+    (0x09DF, 0x09DF, &[0x2A65]),
+    (0x09E0, 0x09E0, &[0x2A45]),
+    (0x09E1, 0x09E1, &[0x2A47]),
+    (0x09E2, 0x09E3, &[0x2A76]),
+    (0x09E6, 0x09EF, &[0x1F98]),
+    (0x09F0, 0x09F0, &[0x2A67]),
+    (0x09F1, 0x09F1, &[0x2A69]),
+    (0x09F2, 0x09F3, &[0x1F6B]),
+    (0x09F4, 0x09F9, &[0x1D56]),
+    (0x09FA, 0x09FA, &[0x0547]),
+    (0x09FB, 0x09FB, &[0x1F6D]),
+    (0x09FC, 0x09FC, &[0x2A7E]),
+    (0x09FD, 0x09FD, &[0x0405]),
+    (0x09FE, 0x09FE, &[]),
+    (0x0A01, 0x0A03, &[]),
+    (0x0A05, 0x0A06, &[0x2A84]),
+    (0x0A07, 0x0A08, &[0x2A89]),
+    (0x0A09, 0x0A0A, &[0x2A81]),
+    (0x0A0F, 0x0A0F, &[0x2A8B]),
+    (0x0A10, 0x0A10, &[0x2A86]),
+    (0x0A13, 0x0A13, &[0x2A83]),
+    (0x0A14, 0x0A14, &[0x2A87]),
+    (0x0A15, 0x0A28, &[0x2A8F]),
+    (0x0A2A, 0x0A2F, &[0x2AA3]),
+    (0x0A30, 0x0A30, &[0x2AAA]),
+    (0x0A32, 0x0A32, &[0x2AAB]),
+    (0x0A33, 0x0A33, &[0x2AAB]),
+    (0x0A35, 0x0A35, &[0x2AAC]),
+    (0x0A36, 0x0A36, &[0x2A8C]),
+    (0x0A38, 0x0A39, &[0x2A8C]),
+    (0x0A3C, 0x0A3C, &[]),
+    (0x0A3E, 0x0A42, &[0x2AAE]),
+    (0x0A47, 0x0A48, &[0x2AB3]),
+    (0x0A4B, 0x0A4D, &[0x2AB5]),
+    (0x0A51, 0x0A51, &[0x2A8E]),
+    (0x0A59, 0x0A5A, &[0x2A90]),
+    (0x0A5B, 0x0A5B, &[0x2A96]),
+    (0x0A5C, 0x0A5C, &[0x2AAD]),
+    (0x0A5E, 0x0A5E, &[0x2AA4]),
+    (0x0A66, 0x0A6F, &[0x1F98]),
+    (0x0A70, 0x0A71, &[]),
+    (0x0A72, 0x0A72, &[0x2A88]),
+    (0x0A73, 0x0A73, &[0x2A80]),
+    (0x0A74, 0x0A74, &[0x2A7F]),
+    (0x0A75, 0x0A75, &[0x2AA9]),
+    (0x0A76, 0x0A76, &[0x0406]),
+    (0x0A81, 0x0A83, &[]),
+    (0x0A85, 0x0A8B, &[0x2AB9]),
+    (0x0A8C, 0x0A8C, &[0x2AC1]),
+    (0x0A8D, 0x0A8D, &[0x2AC3]),
+    (0x0A8F, 0x0A91, &[0x2AC4]),
+    (0x0A93, 0x0A9C, &[0x2AC7]),
+    (0x0A9D, 0x0AA8, &[0x2AD2]),
+    (0x0AAA, 0x0AB0, &[0x2ADE]),
+    (0x0AB2, 0x0AB2, &[0x2AE5]),
+    (0x0AB3, 0x0AB3, &[0x2AEB]),
+    (0x0AB5, 0x0AB9, &[0x2AE6]),
+    (0x0ABC, 0x0ABC, &[]),
+    (0x0ABD, 0x0AC4, &[0x2AEC]),
+    (0x0AC5, 0x0AC5, &[0x2AF6]),
+    (0x0AC7, 0x0AC9, &[0x2AF7]),
+    (0x0ACB, 0x0ACD, &[0x2AFA]),
+    (0x0AD0, 0x0AD0, &[0x2AB8]),
+    (0x0AE0, 0x0AE0, &[0x2AC0]),
+    (0x0AE1, 0x0AE1, &[0x2AC2]),
+    (0x0AE2, 0x0AE3, &[0x2AF4]),
+    (0x0AE6, 0x0AEF, &[0x1F98]),
+    (0x0AF0, 0x0AF0, &[0x0407]),
+    (0x0AF1, 0x0AF1, &[0x1F6E]),
+    (0x0AF9, 0x0AF9, &[0x2AD1]),
+    (0x0AFA, 0x0AFF, &[]),
+    (0x0B01, 0x0B03, &[]),
+    (0x0B05, 0x0B0B, &[0x2AFD]),
+    (0x0B0C, 0x0B0C, &[0x2B05]),
+    (0x0B0F, 0x0B10, &[0x2B07]),
+    (0x0B13, 0x0B28, &[0x2B09]),
+    (0x0B2A, 0x0B2F, &[0x2B1F]),
+    (0x0B30, 0x0B30, &[0x2B26]),
+    (0x0B32, 0x0B33, &[0x2B27]),
+    (0x0B35, 0x0B35, &[0x2B29]),
+    (0x0B36, 0x0B39, &[0x2B2B]),
+    (0x0B3C, 0x0B3C, &[]),
+    (0x0B3D, 0x0B44, &[0x2B2F]),
+    (0x0B47, 0x0B48, &[0x2B39]),
+    (0x0B4B, 0x0B4D, &[0x2B3B]),
+    (0x0B55, 0x0B55, &[]),
+    (0x0B56, 0x0B57, &[0x2B3E]),
+    (0x0B5C, 0x0B5D, &[0x2B17]),
+    (0x0B5F, 0x0B5F, &[0x2B25]),
+    (0x0B60, 0x0B60, &[0x2B04]),
+    (0x0B61, 0x0B61, &[0x2B06]),
+    (0x0B62, 0x0B63, &[0x2B37]),
+    (0x0B66, 0x0B6F, &[0x1F98]),
+    (0x0B70, 0x0B70, &[0x0548]),
+    (0x0B71, 0x0B71, &[0x2B2A]),
+    (0x0B72, 0x0B77, &[0x1D5C]),
+    (0x0B82, 0x0B82, &[]),
+    (0x0B83, 0x0B83, &[0x2B4D]),
+    (0x0B85, 0x0B8A, &[0x2B41]),
+    (0x0B8E, 0x0B90, &[0x2B47]),
+    (0x0B92, 0x0B94, &[0x2B4A]),
+    (0x0B95, 0x0B95, &[0x2B4E]),
+    (0x0B99, 0x0B9A, &[0x2B4F]),
+    (0x0B9C, 0x0B9C, &[0x2B60]),
+    (0x0B9E, 0x0B9F, &[0x2B51]),
+    (0x0BA3, 0x0BA4, &[0x2B53]),
+    (0x0BA8, 0x0BA8, &[0x2B55]),
+    (0x0BA9, 0x0BA9, &[0x2B5F]),
+    (0x0BAA, 0x0BAA, &[0x2B56]),
+    (0x0BAE, 0x0BB0, &[0x2B57]),
+    (0x0BB1, 0x0BB1, &[0x2B5E]),
+    (0x0BB2, 0x0BB2, &[0x2B5A]),
+    (0x0BB3, 0x0BB3, &[0x2B5D]),
+    (0x0BB4, 0x0BB4, &[0x2B5C]),
+    (0x0BB5, 0x0BB5, &[0x2B5B]),
+    (0x0BB6, 0x0BB9, &[0x2B61]),
+    (0x0BBE, 0x0BC2, &[0x2B65]),
+    (0x0BC6, 0x0BC8, &[0x2B6A]),
+    (0x0BCA, 0x0BCD, &[0x2B6D]),
+    (0x0BD0, 0x0BD0, &[0x2B40]),
+    (0x0BD7, 0x0BD7, &[0x2B71]),
+    (0x0BE6, 0x0BEF, &[0x1F98]),
+    (0x0BF0, 0x0BF2, &[0x1D68]),
+    (0x0BF3, 0x0BF8, &[0x0549]),
+    (0x0BF9, 0x0BF9, &[0x1F70]),
+    (0x0BFA, 0x0BFA, &[0x054F]),
+    (0x0C00, 0x0C04, &[]),
+    (0x0C05, 0x0C0B, &[0x2B72]),
+    (0x0C0C, 0x0C0C, &[0x2B7A]),
+    (0x0C0E, 0x0C10, &[0x2B7C]),
+    (0x0C12, 0x0C1A, &[0x2B7F]),
+    (0x0C1B, 0x0C1C, &[0x2B89]),
+    (0x0C1D, 0x0C28, &[0x2B8C]),
+    (0x0C2A, 0x0C32, &[0x2B98]),
+    (0x0C33, 0x0C34, &[0x2BA6]),
+    (0x0C35, 0x0C39, &[0x2BA1]),
+    (0x0C3D, 0x0C44, &[0x2BA9]),
+    (0x0C46, 0x0C48, &[0x2BB3]),
+    (0x0C4A, 0x0C4D, &[0x2BB6]),
+    (0x0C55, 0x0C56, &[0x2BBA]),
+    (0x0C58, 0x0C58, &[0x2B88]),
+    (0x0C59, 0x0C59, &[0x2B8B]),
+    (0x0C5A, 0x0C5A, &[0x2BA8]),
+    (0x0C60, 0x0C60, &[0x2B79]),
+    (0x0C61, 0x0C61, &[0x2B7B]),
+    (0x0C62, 0x0C63, &[0x2BB1]),
+    (0x0C66, 0x0C6F, &[0x1F98]),
+    (0x0C77, 0x0C77, &[0x0408]),
+    (0x0C78, 0x0C7B, &[0x1F98]),
+    (0x0C7C, 0x0C7E, &[0x1F99]),
+    (0x0C7F, 0x0C7F, &[0x0569]),
+    (0x0C80, 0x0C80, &[0x2BF3]),
+    (0x0C81, 0x0C83, &[]),
+    (0x0C84, 0x0C84, &[0x0409]),
+    (0x0C85, 0x0C8B, &[0x2BBC]),
+    (0x0C8C, 0x0C8C, &[0x2BC4]),
+    (0x0C8E, 0x0C90, &[0x2BC6]),
+    (0x0C92, 0x0CA8, &[0x2BC9]),
+    (0x0CAA, 0x0CB2, &[0x2BE0]),
+    (0x0CB3, 0x0CB3, &[0x2BEE]),
+    (0x0CB5, 0x0CB9, &[0x2BE9]),
+    (0x0CBC, 0x0CBC, &[]),
+    (0x0CBD, 0x0CBD, &[0x2BF0]),
+    (0x0CBE, 0x0CC4, &[0x2BF4]),
+    (0x0CC6, 0x0CC8, &[0x2BFD]),
+    (0x0CCA, 0x0CCD, &[0x2C00]),
+    (0x0CD5, 0x0CD6, &[0x2C04]),
+    (0x0CDE, 0x0CDE, &[0x2BEF]),
+    (0x0CE0, 0x0CE0, &[0x2BC3]),
+    (0x0CE1, 0x0CE1, &[0x2BC5]),
+    (0x0CE2, 0x0CE3, &[0x2BFB]),
+    (0x0CE6, 0x0CEF, &[0x1F98]),
+    (0x0CF1, 0x0CF2, &[0x2BF1]),
+    (0x0D00, 0x0D03, &[]),
+    (0x0D04, 0x0D04, &[0x2C3E]),
+    (0x0D05, 0x0D08, &[0x2C06]),
+    (0x0D09, 0x0D0B, &[0x2C0B]),
+    (0x0D0C, 0x0D0C, &[0x2C0F]),
+    (0x0D0E, 0x0D10, &[0x2C11]),
+    (0x0D12, 0x0D30, &[0x2C14]),
+    (0x0D31, 0x0D31, &[0x2C3B]),
+    (0x0D32, 0x0D32, &[0x2C33]),
+    (0x0D33, 0x0D34, &[0x2C39]),
+    (0x0D35, 0x0D39, &[0x2C34]),
+    (0x0D3A, 0x0D3A, &[0x2C3C]),
+    (0x0D3B, 0x0D3B, &[0x2C4F]),
+    (0x0D3C, 0x0D3C, &[0x2C4F]),
+    (0x0D3D, 0x0D3D, &[0x2C3D]),
+    (0x0D3E, 0x0D44, &[0x2C3F]),
+    (0x0D46, 0x0D48, &[0x2C48]),
+    (0x0D4A, 0x0D4C, &[0x2C4B]),
+    (0x0D4D, 0x0D4D, &[0x2C4F]),
+    (0x0D4E, 0x0D4E, &[0x2C32, 0x2C4F]),
+    (0x0D4F, 0x0D4F, &[0x056A]),
+    (0x0D54, 0x0D54, &[0x2C30, 0x2C4F]),
+    (0x0D55, 0x0D55, &[0x2C31, 0x2C4F]),
+    (0x0D56, 0x0D56, &[0x2C3A, 0x2C4F]),
+    (0x0D57, 0x0D57, &[0x2C4E]),
+    (0x0D58, 0x0D5E, &[0x1D80]),
+    (0x0D5F, 0x0D5F, &[0x2C0A]),
+    (0x0D60, 0x0D60, &[0x2C0E]),
+    (0x0D61, 0x0D61, &[0x2C10]),
+    (0x0D62, 0x0D63, &[0x2C46]),
+    (0x0D66, 0x0D6F, &[0x1F98]),
+    (0x0D70, 0x0D78, &[0x1D87]),
+    (0x0D79, 0x0D79, &[0x056B]),
+    (0x0D7A, 0x0D7A, &[0x2C25, 0x2C4F]),
+    (0x0D7B, 0x0D7B, &[0x2C2A, 0x2C4F]),
+    (0x0D7C, 0x0D7C, &[0x2C32, 0x2C4F]),
+    (0x0D7D, 0x0D7D, &[0x2C33, 0x2C4F]),
+    (0x0D7E, 0x0D7E, &[0x2C39, 0x2C4F]),
+    (0x0D7F, 0x0D7F, &[0x2C17, 0x2C4F]),
+    (0x0D81, 0x0D83, &[]),
+    (0x0D85, 0x0D96, &[0x2C50]),
+    (0x0D9A, 0x0DB1, &[0x2C62]),
+    (0x0DB3, 0x0DBB, &[0x2C7A]),
+    (0x0DBD, 0x0DBD, &[0x2C83]),
+    (0x0DC0, 0x0DC6, &[0x2C84]),
+    (0x0DCA, 0x0DCA, &[0x2C9C]),
+    (0x0DCF, 0x0DD4, &[0x2C8B]),
+    (0x0DD6, 0x0DD6, &[0x2C91]),
+    (0x0DD8, 0x0DD8, &[0x2C92]),
+    (0x0DD9, 0x0DDE, &[0x2C96]),
+    (0x0DDF, 0x0DDF, &[0x2C94]),
+    (0x0DE6, 0x0DEF, &[0x1F98]),
+    (0x0DF2, 0x0DF2, &[0x2C93]),
+    (0x0DF3, 0x0DF3, &[0x2C95]),
+    (0x0DF4, 0x0DF4, &[0x040A]),
+    (0x0E01, 0x0E3A, &[0x3217]),
+    (0x0E3F, 0x0E3F, &[0x1F75]),
+    (0x0E40, 0x0E45, &[0x3251]),
+    (0x0E46, 0x0E46, &[0x1F49]),
+    (0x0E47, 0x0E4E, &[]),
+    (0x0E4F, 0x0E4F, &[0x040B]),
+    (0x0E50, 0x0E59, &[0x1F98]),
+    (0x0E5A, 0x0E5B, &[0x040C]),
+    (0x0E81, 0x0E82, &[0x3258]),
+    (0x0E84, 0x0E84, &[0x325A]),
+    (0x0E86, 0x0E89, &[0x325B]),
+    (0x0E8A, 0x0E8A, &[0x3260]),
+    (0x0E8C, 0x0E8C, &[0x3261]),
+    (0x0E8D, 0x0E8D, &[0x3264]),
+    (0x0E8E, 0x0E8E, &[0x3262]),
+    (0x0E8F, 0x0EA3, &[0x3265]),
+    (0x0EA5, 0x0EA5, &[0x327A]),
+    (0x0EA7, 0x0EA9, &[0x327B]),
+    (0x0EAA, 0x0EAA, &[0x325F]),
+    (0x0EAB, 0x0EBD, &[0x327E]),
+    (0x0EC0, 0x0EC4, &[0x3291]),
+    (0x0EC6, 0x0EC6, &[0x1F4A]),
+    (0x0EC8, 0x0ECD, &[]),
+    (0x0ED0, 0x0ED9, &[0x1F98]),
+    (0x0EDC, 0x0EDC, &[0x327E, 0x326F]),
+    (0x0EDD, 0x0EDD, &[0x327E, 0x3277]),
+    (0x0EDE, 0x0EDE, &[0x3257]),
+    (0x0EDF, 0x0EDF, &[0x3263]),
+    (0x0F00, 0x0F00, &[0x331F, 0x3336]),
+    (0x0F01, 0x0F03, &[0x0573]),
+    (0x0F04, 0x0F0A, &[0x0410]),
+    (0x0F0B, 0x0F0B, &[0x0419]),
+    (0x0F0C, 0x0F12, &[0x0419]),
+    (0x0F13, 0x0F13, &[0x0576]),
+    (0x0F14, 0x0F14, &[0x025F]),
+    (0x0F15, 0x0F17, &[0x0577]),
+    (0x0F18, 0x0F19, &[]),
+    (0x0F1A, 0x0F1F, &[0x057A]),
+    (0x0F20, 0x0F29, &[0x1F98]),
+    (0x0F2A, 0x0F32, &[0x1F99]),
+    (0x0F33, 0x0F33, &[0x1F98]),
+    (0x0F34, 0x0F34, &[0x0580]),
+    (0x0F35, 0x0F35, &[]),
+    (0x0F36, 0x0F36, &[0x0581]),
+    (0x0F37, 0x0F37, &[]),
+    (0x0F38, 0x0F38, &[0x0582]),
+    (0x0F39, 0x0F39, &[]),
+    (0x0F3A, 0x0F3D, &[0x032E]),
+    (0x0F3E, 0x0F3F, &[]),
+    (0x0F40, 0x0F40, &[0x32D9]),
+    (0x0F41, 0x0F41, &[0x32DC]),
+    (0x0F42, 0x0F42, &[0x32DE]),
+    (0x0F43, 0x0F43, &[0x32DE, 0x331E]),
+    (0x0F44, 0x0F44, &[0x32E0]),
+    (0x0F45, 0x0F45, &[0x32E2]),
+    (0x0F46, 0x0F46, &[0x32E4]),
+    (0x0F47, 0x0F47, &[0x32E6]),
+    (0x0F49, 0x0F49, &[0x32E8]),
+    (0x0F4A, 0x0F4A, &[0x32EA]),
+    (0x0F4B, 0x0F4B, &[0x32EC]),
+    (0x0F4C, 0x0F4C, &[0x32EE]),
+    (0x0F4D, 0x0F4D, &[0x32EE, 0x331E]),
+    (0x0F4E, 0x0F4E, &[0x32F0]),
+    (0x0F4F, 0x0F4F, &[0x32F2]),
+    (0x0F50, 0x0F50, &[0x32F4]),
+    (0x0F51, 0x0F51, &[0x32F6]),
+    (0x0F52, 0x0F52, &[0x32F6, 0x331E]),
+    (0x0F53, 0x0F53, &[0x32F8]),
+    (0x0F54, 0x0F54, &[0x32FA]),
+    (0x0F55, 0x0F55, &[0x32FC]),
+    (0x0F56, 0x0F56, &[0x32FE]),
+    (0x0F57, 0x0F57, &[0x32FE, 0x331E]),
+    (0x0F58, 0x0F58, &[0x3300]),
+    (0x0F59, 0x0F59, &[0x3302]),
+    (0x0F5A, 0x0F5A, &[0x3304]),
+    (0x0F5B, 0x0F5B, &[0x3306]),
+    (0x0F5C, 0x0F5C, &[0x3306, 0x331E]),
+    (0x0F5D, 0x0F5D, &[0x3308]),
+    (0x0F5E, 0x0F5E, &[0x330A]),
+    (0x0F5F, 0x0F5F, &[0x330C]),
+    (0x0F60, 0x0F60, &[0x330E]),
+    (0x0F61, 0x0F61, &[0x3310]),
+    (0x0F62, 0x0F62, &[0x3312]),
+    (0x0F63, 0x0F63, &[0x3315]),
+    (0x0F64, 0x0F64, &[0x3317]),
+    (0x0F65, 0x0F65, &[0x3319]),
+    (0x0F66, 0x0F66, &[0x331B]),
+    (0x0F67, 0x0F67, &[0x331D]),
+    (0x0F68, 0x0F68, &[0x331F]),
+    (0x0F69, 0x0F69, &[0x32D9, 0x331A]),
+    (0x0F6A, 0x0F6A, &[0x3312]),
+    (0x0F6B, 0x0F6B, &[0x32DB]),
+    (0x0F6C, 0x0F6C, &[0x3314]),
+    (0x0F71, 0x0F73, &[0x3329]),
+    (0x0F74, 0x0F7D, &[0x332E]),
+    (0x0F7E, 0x0F7F, &[]),
+    (0x0F80, 0x0F81, &[0x332C]),
+    (0x0F82, 0x0F83, &[]),
+    (0x0F84, 0x0F84, &[0x3338]),
+    (0x0F85, 0x0F85, &[0x0420]),
+    (0x0F86, 0x0F87, &[]),
+    (0x0F88, 0x0F88, &[0x3321]),
+    (0x0F89, 0x0F89, &[0x3323]),
+    (0x0F8A, 0x0F8B, &[0x3327]),
+    (0x0F8C, 0x0F8C, &[0x3325]),
+    (0x0F8D, 0x0F8D, &[0x3322]),
+    (0x0F8E, 0x0F8E, &[0x3324]),
+    (0x0F8F, 0x0F8F, &[0x3326]),
+    (0x0F90, 0x0F90, &[0x32DA]),
+    (0x0F91, 0x0F91, &[0x32DD]),
+    (0x0F92, 0x0F92, &[0x32DF]),
+    (0x0F93, 0x0F93, &[0x32DF, 0x331E]),
+    (0x0F94, 0x0F94, &[0x32E1]),
+    (0x0F95, 0x0F95, &[0x32E3]),
+    (0x0F96, 0x0F96, &[0x32E5]),
+    (0x0F97, 0x0F97, &[0x32E7]),
+    (0x0F99, 0x0F99, &[0x32E9]),
+    (0x0F9A, 0x0F9A, &[0x32EB]),
+    (0x0F9B, 0x0F9B, &[0x32ED]),
+    (0x0F9C, 0x0F9C, &[0x32EF]),
+    (0x0F9D, 0x0F9D, &[0x32EF, 0x331E]),
+    (0x0F9E, 0x0F9E, &[0x32F1]),
+    (0x0F9F, 0x0F9F, &[0x32F3]),
+    (0x0FA0, 0x0FA0, &[0x32F5]),
+    (0x0FA1, 0x0FA1, &[0x32F7]),
+    (0x0FA2, 0x0FA2, &[0x32F7, 0x331E]),
+    (0x0FA3, 0x0FA3, &[0x32F9]),
+    (0x0FA4, 0x0FA4, &[0x32FB]),
+    (0x0FA5, 0x0FA5, &[0x32FD]),
+    (0x0FA6, 0x0FA6, &[0x32FF]),
+    (0x0FA7, 0x0FA7, &[0x32FF, 0x331E]),
+    (0x0FA8, 0x0FA8, &[0x3301]),
+    (0x0FA9, 0x0FA9, &[0x3303]),
+    (0x0FAA, 0x0FAA, &[0x3305]),
+    (0x0FAB, 0x0FAB, &[0x3307]),
+    (0x0FAC, 0x0FAC, &[0x3307, 0x331E]),
+    (0x0FAD, 0x0FAD, &[0x3309]),
+    (0x0FAE, 0x0FAE, &[0x330B]),
+    (0x0FAF, 0x0FAF, &[0x330D]),
+    (0x0FB0, 0x0FB0, &[0x330F]),
+    (0x0FB1, 0x0FB1, &[0x3311]),
+    (0x0FB2, 0x0FB2, &[0x3313]),
+    (0x0FB3, 0x0FB3, &[0x3316]),
+    (0x0FB4, 0x0FB4, &[0x3318]),
+    (0x0FB5, 0x0FB5, &[0x331A]),
+    (0x0FB6, 0x0FB6, &[0x331C]),
+    (0x0FB7, 0x0FB7, &[0x331E]),
+    (0x0FB8, 0x0FB8, &[0x3320]),
+    (0x0FB9, 0x0FB9, &[0x32DA, 0x331A]),
+    (0x0FBA, 0x0FBA, &[0x3309]),
+    (0x0FBB, 0x0FBB, &[0x3311]),
+    (0x0FBC, 0x0FBC, &[0x3313]),
+    (0x0FBE, 0x0FC5, &[0x0583]),
+    (0x0FC6, 0x0FC6, &[]),
+    (0x0FC7, 0x0FCC, &[0x058B]),
+    (0x0FCE, 0x0FCF, &[0x0591]),
+    (0x0FD0, 0x0FD1, &[0x0417]),
+    (0x0FD2, 0x0FD4, &[0x0421]),
+    (0x0FD5, 0x0FD8, &[0x0593]),
+    (0x0FD9, 0x0FDA, &[0x0424]),
+    (0x1000, 0x1000, &[0x356C]),
+    (0x1001, 0x1001, &[0x356E]),
+    (0x1002, 0x1002, &[0x3570]),
+    (0x1003, 0x1003, &[0x3574]),
+    (0x1004, 0x1004, &[0x3577]),
+    (0x1005, 0x1005, &[0x3579]),
+    (0x1006, 0x1006, &[0x357C]),
+    (0x1007, 0x1007, &[0x3580]),
+    (0x1008, 0x1008, &[0x3585]),
+    (0x1009, 0x1009, &[0x358C]),
+    (0x100A, 0x100B, &[0x3590]),
+    (0x100C, 0x100C, &[0x3593]),
+    (0x100D, 0x100D, &[0x3595]),
+    (0x100E, 0x100E, &[0x3598]),
+    (0x100F, 0x100F, &[0x359B]),
+    (0x1010, 0x1012, &[0x359F]),
+    (0x1013, 0x1013, &[0x35A4]),
+    (0x1014, 0x1014, &[0x35A7]),
+    (0x1015, 0x1016, &[0x35AB]),
+    (0x1017, 0x1017, &[0x35B2]),
+    (0x1018, 0x1018, &[0x35B5]),
+    (0x1019, 0x1019, &[0x35B8]),
+    (0x101A, 0x101A, &[0x35BA]),
+    (0x101B, 0x101B, &[0x35BC]),
+    (0x101C, 0x101C, &[0x35C0]),
+    (0x101D, 0x101D, &[0x35C2]),
+    (0x101E, 0x101E, &[0x35C9]),
+    (0x101F, 0x101F, &[0x35CB]),
+    (0x1020, 0x1020, &[0x35D1]),
+    (0x1021, 0x1026, &[0x35D8]),
+    (0x1027, 0x102B, &[0x35E2]),
+    (0x102C, 0x102C, &[0x35E6]),
+    (0x102D, 0x102D, &[0x35EA]),
+    (0x102E, 0x102E, &[0x35EC]),
+    (0x102F, 0x102F, &[0x35EE]),
+    (0x1030, 0x1030, &[0x35F1]),
+    (0x1031, 0x1031, &[0x35F6]),
+    (0x1032, 0x1032, &[0x35FA]),
+    (0x1033, 0x1033, &[0x35ED]),
+    (0x1034, 0x1034, &[0x35FC]),
+    (0x1035, 0x1035, &[0x35F8]),
+    (0x1036, 0x1038, &[]),
+    (0x1039, 0x103A, &[0x3602]),
+    (0x103B, 0x103B, &[0x35BB]),
+    (0x103C, 0x103C, &[0x35BF]),
+    (0x103D, 0x103D, &[0x35C3]),
+    (0x103E, 0x103E, &[0x35CE]),
+    (0x103F, 0x103F, &[0x35C9, 0x3602, 0x35C9]),
+    (0x1040, 0x1049, &[0x1F98]),
+    (0x104A, 0x104B, &[0x02A1]),
+    (0x104C, 0x104F, &[0x043B]),
+    (0x1050, 0x1051, &[0x35C6]),
+    (0x1052, 0x1055, &[0x35DE]),
+    (0x1056, 0x1059, &[0x35F2]),
+    (0x105A, 0x105A, &[0x3578]),
+    (0x105B, 0x105B, &[0x3586]),
+    (0x105C, 0x105D, &[0x35D3]),
+    (0x105E, 0x105E, &[0x35AA]),
+    (0x105F, 0x105F, &[0x35B9]),
+    (0x1060, 0x1060, &[0x35C1]),
+    (0x1061, 0x1061, &[0x358A]),
+    (0x1062, 0x1062, &[0x35FD]),
+    (0x1063, 0x1064, &[0x3604]),
+    (0x1065, 0x1065, &[0x35C8]),
+    (0x1066, 0x1066, &[0x35D7]),
+    (0x1067, 0x1068, &[0x35FE]),
+    (0x1069, 0x106D, &[0x3606]),
+    (0x106E, 0x106E, &[0x359C]),
+    (0x106F, 0x1070, &[0x35D5]),
+    (0x1071, 0x1071, &[0x35EB]),
+    (0x1072, 0x1072, &[0x35E8]),
+    (0x1073, 0x1074, &[0x35EF]),
+    (0x1075, 0x1075, &[0x356D]),
+    (0x1076, 0x1076, &[0x356F]),
+    (0x1077, 0x1077, &[0x3571]),
+    (0x1078, 0x1078, &[0x357A]),
+    (0x1079, 0x1079, &[0x3583]),
+    (0x107A, 0x107A, &[0x358D]),
+    (0x107B, 0x107B, &[0x35A2]),
+    (0x107C, 0x107C, &[0x35A8]),
+    (0x107D, 0x107E, &[0x35AD]),
+    (0x107F, 0x107F, &[0x35B3]),
+    (0x1080, 0x1080, &[0x35C5]),
+    (0x1081, 0x1081, &[0x35CC]),
+    (0x1082, 0x1082, &[0x35C4]),
+    (0x1083, 0x1083, &[0x35E7]),
+    (0x1084, 0x1084, &[0x35F7]),
+    (0x1085, 0x1085, &[0x35F9]),
+    (0x1086, 0x1086, &[0x3601]),
+    (0x1087, 0x1087, &[0x360B]),
+    (0x1088, 0x1088, &[0x360D]),
+    (0x1089, 0x108A, &[0x3610]),
+    (0x108B, 0x108B, &[0x360C]),
+    (0x108C, 0x108D, &[0x360E]),
+    (0x108E, 0x108E, &[0x35B0]),
+    (0x108F, 0x108F, &[0x3612]),
+    (0x1090, 0x1099, &[0x1F98]),
+    (0x109A, 0x109B, &[0x3613]),
+    (0x109C, 0x109C, &[0x35E9]),
+    (0x109D, 0x109D, &[0x35FB]),
+    (0x109E, 0x109F, &[0x0599]),
+    (0x10A0, 0x10A0, &[0x25A0]),
+    (0x10A1, 0x10A1, &[0x25A2]),
+    (0x10A2, 0x10A2, &[0x25A4]),
+    (0x10A3, 0x10A3, &[0x25A6]),
+    (0x10A4, 0x10A4, &[0x25A8]),
+    (0x10A5, 0x10A5, &[0x25AA]),
+    (0x10A6, 0x10A6, &[0x25AC]),
+    (0x10A7, 0x10A7, &[0x25B0]),
+    (0x10A8, 0x10A8, &[0x25B2]),
+    (0x10A9, 0x10A9, &[0x25B4]),
+    (0x10AA, 0x10AA, &[0x25B6]),
+    (0x10AB, 0x10AB, &[0x25B8]),
+    (0x10AC, 0x10AC, &[0x25BA]),
+    (0x10AD, 0x10AD, &[0x25BE]),
+    (0x10AE, 0x10AE, &[0x25C0]),
+    (0x10AF, 0x10AF, &[0x25C2]),
+    (0x10B0, 0x10B0, &[0x25C4]),
+    (0x10B1, 0x10B1, &[0x25C6]),
+    (0x10B2, 0x10B2, &[0x25C8]),
+    (0x10B3, 0x10B3, &[0x25CC]),
+    (0x10B4, 0x10B4, &[0x25CE]),
+    (0x10B5, 0x10B5, &[0x25D0]),
+    (0x10B6, 0x10B6, &[0x25D2]),
+    (0x10B7, 0x10B7, &[0x25D4]),
+    (0x10B8, 0x10B8, &[0x25D6]),
+    (0x10B9, 0x10B9, &[0x25D8]),
+    (0x10BA, 0x10BA, &[0x25DA]),
+    (0x10BB, 0x10BB, &[0x25DC]),
+    (0x10BC, 0x10BC, &[0x25DE]),
+    (0x10BD, 0x10BD, &[0x25E0]),
+    (0x10BE, 0x10BE, &[0x25E2]),
+    (0x10BF, 0x10BF, &[0x25E6]),
+    (0x10C0, 0x10C0, &[0x25E8]),
+    (0x10C1, 0x10C1, &[0x25AE]),
+    (0x10C2, 0x10C2, &[0x25BC]),
+    (0x10C3, 0x10C3, &[0x25CA]),
+    (0x10C4, 0x10C4, &[0x25E4]),
+    (0x10C5, 0x10C5, &[0x25EA]),
+    (0x10C7, 0x10C7, &[0x25ED]),
+    (0x10CD, 0x10CD, &[0x25F2]),
+    (0x10D0, 0x10D0, &[0x259F]),
+    (0x10D1, 0x10D1, &[0x25A1]),
+    (0x10D2, 0x10D2, &[0x25A3]),
+    (0x10D3, 0x10D3, &[0x25A5]),
+    (0x10D4, 0x10D4, &[0x25A7]),
+    (0x10D5, 0x10D5, &[0x25A9]),
+    (0x10D6, 0x10D6, &[0x25AB]),
+    (0x10D7, 0x10D7, &[0x25AF]),
+    (0x10D8, 0x10D8, &[0x25B1]),
+    (0x10D9, 0x10D9, &[0x25B3]),
+    (0x10DA, 0x10DA, &[0x25B5]),
+    (0x10DB, 0x10DB, &[0x25B7]),
+    (0x10DC, 0x10DC, &[0x25B9]),
+    (0x10DD, 0x10DD, &[0x25BD]),
+    (0x10DE, 0x10DE, &[0x25BF]),
+    (0x10DF, 0x10DF, &[0x25C1]),
+    (0x10E0, 0x10E0, &[0x25C3]),
+    (0x10E1, 0x10E1, &[0x25C5]),
+    (0x10E2, 0x10E2, &[0x25C7]),
+    (0x10E3, 0x10E3, &[0x25CB]),
+    (0x10E4, 0x10E4, &[0x25CD]),
+    (0x10E5, 0x10E5, &[0x25CF]),
+    (0x10E6, 0x10E6, &[0x25D1]),
+    (0x10E7, 0x10E7, &[0x25D3]),
+    (0x10E8, 0x10E8, &[0x25D5]),
+    (0x10E9, 0x10E9, &[0x25D7]),
+    (0x10EA, 0x10EA, &[0x25D9]),
+    (0x10EB, 0x10EB, &[0x25DB]),
+    (0x10EC, 0x10EC, &[0x25DD]),
+    (0x10ED, 0x10ED, &[0x25DF]),
+    (0x10EE, 0x10EE, &[0x25E1]),
+    (0x10EF, 0x10EF, &[0x25E5]),
+    (0x10F0, 0x10F0, &[0x25E7]),
+    (0x10F1, 0x10F1, &[0x25AD]),
+    (0x10F2, 0x10F2, &[0x25BB]),
+    (0x10F3, 0x10F3, &[0x25C9]),
+    (0x10F4, 0x10F4, &[0x25E3]),
+    (0x10F5, 0x10F5, &[0x25E9]),
+    (0x10F6, 0x10F7, &[0x25EB]),
+    (0x10F8, 0x10FA, &[0x25EE]),
+    (0x10FB, 0x10FB, &[0x02CF]),
+    (0x10FC, 0x10FC, &[0x25B9]),
+    (0x10FD, 0x10FD, &[0x25F1]),
+    (0x10FE, 0x10FF, &[0x25F3]),
+    (0x1100, 0x115E, &[0x4175]),
+    (0x115F, 0x11A7, &[0x41F1]),
+    (0x11A8, 0x11FF, &[0x4251]),
+    (0x1200, 0x120F, &[0x2812]),
+    (0x1210, 0x121F, &[0x2823]),
+    (0x1220, 0x122F, &[0x2838]),
+    (0x1230, 0x1237, &[0x2849]),
+    (0x1238, 0x123F, &[0x2858]),
+    (0x1240, 0x1248, &[0x2861]),
+    (0x124A, 0x124D, &[0x286A]),
+    (0x1250, 0x1256, &[0x286E]),
+    (0x1258, 0x1258, &[0x2875]),
+    (0x125A, 0x125D, &[0x2876]),
+    (0x1260, 0x1267, &[0x287A]),
+    (0x1268, 0x1277, &[0x2887]),
+    (0x1278, 0x127F, &[0x2898]),
+    (0x1280, 0x1288, &[0x28A1]),
+    (0x128A, 0x128D, &[0x28AA]),
+    (0x1290, 0x1297, &[0x28AE]),
+    (0x1298, 0x129F, &[0x28B7]),
+    (0x12A0, 0x12A7, &[0x28C0]),
+    (0x12A8, 0x12B0, &[0x28C9]),
+    (0x12B2, 0x12B5, &[0x28D2]),
+    (0x12B8, 0x12BE, &[0x28D6]),
+    (0x12C0, 0x12C0, &[0x28DD]),
+    (0x12C2, 0x12C5, &[0x28DE]),
+    (0x12C8, 0x12D6, &[0x28E2]),
+    (0x12D8, 0x12DF, &[0x28F1]),
+    (0x12E0, 0x12F7, &[0x2900]),
+    (0x12F8, 0x12FF, &[0x291F]),
+    (0x1300, 0x1307, &[0x2928]),
+    (0x1308, 0x1310, &[0x2931]),
+    (0x1312, 0x1315, &[0x293A]),
+    (0x1318, 0x131F, &[0x293E]),
+    (0x1320, 0x1327, &[0x294A]),
+    (0x1328, 0x132F, &[0x2953]),
+    (0x1330, 0x1337, &[0x2963]),
+    (0x1338, 0x133F, &[0x296C]),
+    (0x1340, 0x134F, &[0x297B]),
+    (0x1350, 0x1357, &[0x298F]),
+    (0x1358, 0x135A, &[0x299C]),
+    (0x135D, 0x135F, &[]),
+    (0x1360, 0x1360, &[0x02D0]),
+    (0x1361, 0x1361, &[0x0258]),
+    (0x1362, 0x1362, &[0x0283]),
+    (0x1363, 0x1366, &[0x0259]),
+    (0x1367, 0x1367, &[0x0273]),
+    (0x1368, 0x1368, &[0x02D1]),
+    (0x1369, 0x1371, &[0x1F99]),
+    (0x1372, 0x137C, &[0x1D90]),
+    (0x1380, 0x1383, &[0x2833]),
+    (0x1384, 0x1387, &[0x2882]),
+    (0x1388, 0x138B, &[0x298B]),
+    (0x138C, 0x138F, &[0x2997]),
+    (0x1390, 0x1399, &[0x04FA]),
+    (0x13A0, 0x13F5, &[0x38DE]),
+    (0x13F8, 0x13FD, &[0x392E]),
+    (0x1400, 0x1400, &[0x020F]),
+    (0x1401, 0x157B, &[0x3958]),
+    (0x157C, 0x157C, &[0x3B05]),
+    (0x157D, 0x157D, &[0x3AD3]),
+    (0x157E, 0x158D, &[0x3AD5]),
+    (0x158E, 0x1595, &[0x3AE6]),
+    (0x1596, 0x15A6, &[0x3AF4]),
+    (0x15A7, 0x166C, &[0x3B06]),
+    (0x166D, 0x166D, &[0x0597]),
+    (0x166E, 0x166E, &[0x0286]),
+    (0x166F, 0x166F, &[0x3AD4]),
+    (0x1670, 0x1670, &[0x3AE5]),
+    (0x1671, 0x1676, &[0x3AEE]),
+    (0x1677, 0x167F, &[0x3BCC]),
+    (0x1680, 0x1680, &[0x0209]),
+    (0x1681, 0x169A, &[0x3C1B]),
+    (0x169B, 0x169C, &[0x0332]),
+    (0x16A0, 0x16A0, &[0x3C35]),
+    (0x16A1, 0x16A2, &[0x3C35]),
+    (0x16A3, 0x16A3, &[0x3C5A]),
+    (0x16A4, 0x16A4, &[0x3C36]),
+    (0x16A5, 0x16A6, &[0x3C36]),
+    (0x16A7, 0x16A8, &[0x3C37]),
+    (0x16A9, 0x16A9, &[0x3C38]),
+    (0x16AA, 0x16AA, &[0x3C56]),
+    (0x16AB, 0x16AB, &[0x3C58]),
+    (0x16AC, 0x16AC, &[0x3C38]),
+    (0x16AD, 0x16AD, &[0x3C38]),
+    (0x16AE, 0x16AE, &[0x3C38]),
+    (0x16AF, 0x16B2, &[0x3C3A]),
+    (0x16B3, 0x16B3, &[0x3C3D]),
+    (0x16B4, 0x16B4, &[0x3C3D]),
+    (0x16B5, 0x16B5, &[0x3C3D]),
+    (0x16B6, 0x16B6, &[0x3C3D]),
+    (0x16B7, 0x16B7, &[0x3C3F]),
+    (0x16B8, 0x16B8, &[0x3C5D]),
+    (0x16B9, 0x16BA, &[0x3C40]),
+    (0x16BB, 0x16BB, &[0x3C41]),
+    (0x16BC, 0x16BC, &[0x3C41]),
+    (0x16BD, 0x16BE, &[0x3C41]),
+    (0x16BF, 0x16BF, &[0x3C42]),
+    (0x16C0, 0x16C1, &[0x3C42]),
+    (0x16C2, 0x16C2, &[0x3C43]),
+    (0x16C3, 0x16C3, &[0x3C45]),
+    (0x16C4, 0x16C5, &[0x3C45]),
+    (0x16C6, 0x16CA, &[0x3C46]),
+    (0x16CB, 0x16CB, &[0x3C4A]),
+    (0x16CC, 0x16CC, &[0x3C4A]),
+    (0x16CD, 0x16CD, &[0x3C4A]),
+    (0x16CE, 0x16CE, &[0x3C4A]),
+    (0x16CF, 0x16CF, &[0x3C4C]),
+    (0x16D0, 0x16D0, &[0x3C4C]),
+    (0x16D1, 0x16D2, &[0x3C4C]),
+    (0x16D3, 0x16D3, &[0x3C4D]),
+    (0x16D4, 0x16D4, &[0x3C4D]),
+    (0x16D5, 0x16D5, &[0x3C48]),
+    (0x16D6, 0x16D6, &[0x3C4E]),
+    (0x16D7, 0x16D7, &[0x3C50]),
+    (0x16D8, 0x16D8, &[0x3C50]),
+    (0x16D9, 0x16DA, &[0x3C50]),
+    (0x16DB, 0x16DC, &[0x3C51]),
+    (0x16DD, 0x16DF, &[0x3C52]),
+    (0x16E0, 0x16E0, &[0x3C5B]),
+    (0x16E1, 0x16E2, &[0x3C5F]),
+    (0x16E3, 0x16E3, &[0x3C5C]),
+    (0x16E4, 0x16E4, &[0x3C5E]),
+    (0x16E5, 0x16E6, &[0x3C61]),
+    (0x16E7, 0x16E7, &[0x3C62]),
+    (0x16E8, 0x16E8, &[0x3C62]),
+    (0x16E9, 0x16E9, &[0x3C40]),
+    (0x16EA, 0x16EA, &[0x3C4A]),
+    (0x16EB, 0x16ED, &[0x0263]),
+    (0x16EE, 0x16EE, &[0x3C46, 0x3C51]),
+    (0x16EF, 0x16EF, &[0x3C50, 0x3C50]),
+    (0x16F0, 0x16F0, &[0x3C37, 0x3C37]),
+    (0x16F1, 0x16F1, &[0x3C3E]),
+    (0x16F2, 0x16F2, &[0x3C4B]),
+    (0x16F3, 0x16F3, &[0x3C55]),
+    (0x16F4, 0x16F4, &[0x3C39]),
+    (0x16F5, 0x16F5, &[0x3C44]),
+    (0x16F6, 0x16F6, &[0x3C4F]),
+    (0x16F7, 0x16F7, &[0x3C57]),
+    (0x16F8, 0x16F8, &[0x3C59]),
+    (0x1700, 0x170C, &[0x3486]),
+    (0x170E, 0x1714, &[0x3493]),
+    (0x1720, 0x1734, &[0x349A]),
+    (0x1735, 0x1736, &[0x029E]),
+    (0x1740, 0x1753, &[0x34AF]),
+    (0x1760, 0x176C, &[0x34C3]),
+    (0x176E, 0x1770, &[0x34D0]),
+    (0x1772, 0x1773, &[0x34D3]),
+    (0x1780, 0x17A2, &[0x3675]),
+    (0x17A3, 0x17B3, &[0x3699]),
+    (0x17B4, 0x17B5, &[]),
+    (0x17B6, 0x17C5, &[0x36AA]),
+    (0x17C6, 0x17D1, &[]),
+    (0x17D2, 0x17D2, &[0x36BA]),
+    (0x17D3, 0x17D3, &[]),
+    (0x17D4, 0x17D5, &[0x02A3]),
+    (0x17D6, 0x17D6, &[0x0260]),
+    (0x17D7, 0x17D7, &[0x1F4B]),
+    (0x17D8, 0x17DA, &[0x043F]),
+    (0x17DB, 0x17DB, &[0x1F76]),
+    (0x17DC, 0x17DC, &[0x3698]),
+    (0x17DD, 0x17DD, &[]),
+    (0x17E0, 0x17E9, &[0x1F98]),
+    (0x17F0, 0x17F9, &[0x1F98]),
+    (0x1800, 0x1800, &[0x03F2]),
+    (0x1801, 0x1801, &[0x027F]),
+    (0x1802, 0x1802, &[0x0230]),
+    (0x1803, 0x1803, &[0x0284]),
+    (0x1804, 0x1805, &[0x025D]),
+    (0x1806, 0x1807, &[0x0211]),
+    (0x1808, 0x1808, &[0x0231]),
+    (0x1809, 0x1809, &[0x0285]),
+    (0x180A, 0x180E, &[]),
+    (0x1810, 0x1819, &[0x1F98]),
+    (0x1820, 0x1820, &[0x383E]),
+    (0x1821, 0x1821, &[0x3840]),
+    (0x1822, 0x1822, &[0x3843]),
+    (0x1823, 0x1823, &[0x3849]),
+    (0x1824, 0x1824, &[0x384B]),
+    (0x1825, 0x1825, &[0x384E]),
+    (0x1826, 0x1826, &[0x3850]),
+    (0x1827, 0x1829, &[0x3853]),
+    (0x182A, 0x182A, &[0x385A]),
+    (0x182B, 0x182B, &[0x385C]),
+    (0x182C, 0x182C, &[0x385F]),
+    (0x182D, 0x182D, &[0x3861]),
+    (0x182E, 0x182E, &[0x3866]),
+    (0x182F, 0x1831, &[0x3868]),
+    (0x1832, 0x1832, &[0x3871]),
+    (0x1833, 0x1833, &[0x3874]),
+    (0x1834, 0x1834, &[0x3877]),
+    (0x1835, 0x1835, &[0x387D]),
+    (0x1836, 0x1836, &[0x3881]),
+    (0x1837, 0x1837, &[0x3884]),
+    (0x1838, 0x1838, &[0x3886]),
+    (0x1839, 0x1839, &[0x3888]),
+    (0x183A, 0x183A, &[0x388B]),
+    (0x183B, 0x183C, &[0x3890]),
+    (0x183D, 0x183D, &[0x3894]),
+    (0x183E, 0x183E, &[0x3898]),
+    (0x183F, 0x1842, &[0x389B]),
+    (0x1843, 0x1843, &[0x383D]),
+    (0x1844, 0x1844, &[0x3841]),
+    (0x1845, 0x1845, &[0x3844]),
+    (0x1846, 0x1846, &[0x384A]),
+    (0x1847, 0x1847, &[0x384C]),
+    (0x1848, 0x1848, &[0x384F]),
+    (0x1849, 0x1849, &[0x3851]),
+    (0x184A, 0x184A, &[0x3856]),
+    (0x184B, 0x184B, &[0x385B]),
+    (0x184C, 0x184C, &[0x385D]),
+    (0x184D, 0x184D, &[0x3860]),
+    (0x184E, 0x184E, &[0x3862]),
+    (0x184F, 0x184F, &[0x3867]),
+    (0x1850, 0x1850, &[0x3872]),
+    (0x1851, 0x1851, &[0x3875]),
+    (0x1852, 0x1852, &[0x3878]),
+    (0x1853, 0x1853, &[0x387E]),
+    (0x1854, 0x1854, &[0x3892]),
+    (0x1855, 0x1855, &[0x3882]),
+    (0x1856, 0x1856, &[0x3887]),
+    (0x1857, 0x1857, &[0x388C]),
+    (0x1858, 0x1858, &[0x3896]),
+    (0x1859, 0x1859, &[0x3899]),
+    (0x185A, 0x185B, &[0x389F]),
+    (0x185C, 0x185C, &[0x387B]),
+    (0x185D, 0x185D, &[0x3842]),
+    (0x185E, 0x185E, &[0x3845]),
+    (0x185F, 0x185F, &[0x3848]),
+    (0x1860, 0x1860, &[0x3852]),
+    (0x1861, 0x1861, &[0x384D]),
+    (0x1862, 0x1862, &[0x3857]),
+    (0x1863, 0x1863, &[0x388D]),
+    (0x1864, 0x1864, &[0x3863]),
+    (0x1865, 0x1865, &[0x3865]),
+    (0x1866, 0x1866, &[0x385E]),
+    (0x1867, 0x1867, &[0x386B]),
+    (0x1868, 0x1868, &[0x3873]),
+    (0x1869, 0x1869, &[0x3876]),
+    (0x186A, 0x186A, &[0x387F]),
+    (0x186B, 0x186B, &[0x3889]),
+    (0x186C, 0x186C, &[0x3897]),
+    (0x186D, 0x186D, &[0x389A]),
+    (0x186E, 0x186E, &[0x3893]),
+    (0x186F, 0x186F, &[0x3895]),
+    (0x1870, 0x1870, &[0x38A1]),
+    (0x1871, 0x1871, &[0x3879]),
+    (0x1872, 0x1872, &[0x3883]),
+    (0x1873, 0x1873, &[0x3846]),
+    (0x1874, 0x1874, &[0x388E]),
+    (0x1875, 0x1875, &[0x3885]),
+    (0x1876, 0x1876, &[0x388A]),
+    (0x1877, 0x1877, &[0x3880]),
+    (0x1878, 0x1878, &[0x387A]),
+    (0x1880, 0x1886, &[0x3836]),
+    (0x1887, 0x1887, &[0x383F]),
+    (0x1888, 0x1888, &[0x3847]),
+    (0x1889, 0x1889, &[0x388F]),
+    (0x188A, 0x188A, &[0x3858]),
+    (0x188B, 0x188B, &[0x387C]),
+    (0x188C, 0x188C, &[0x38A2]),
+    (0x188D, 0x188E, &[0x38A4]),
+    (0x188F, 0x1890, &[0x38A7]),
+    (0x1891, 0x1891, &[0x38AB]),
+    (0x1892, 0x1893, &[0x38AD]),
+    (0x1894, 0x1894, &[0x38B0]),
+    (0x1895, 0x1895, &[0x38B2]),
+    (0x1896, 0x1897, &[0x38B4]),
+    (0x1898, 0x1898, &[0x38A9]),
+    (0x1899, 0x1899, &[0x38B3]),
+    (0x189A, 0x189A, &[0x3864]),
+    (0x189B, 0x189B, &[0x3859]),
+    (0x189C, 0x189D, &[0x386C]),
+    (0x189E, 0x189E, &[0x38A3]),
+    (0x189F, 0x189F, &[0x38A6]),
+    (0x18A0, 0x18A0, &[0x38AA]),
+    (0x18A1, 0x18A1, &[0x38AC]),
+    (0x18A2, 0x18A2, &[0x386E]),
+    (0x18A3, 0x18A3, &[0x38B1]),
+    (0x18A4, 0x18A5, &[0x386F]),
+    (0x18A6, 0x18A7, &[0x38B6]),
+    (0x18A8, 0x18A8, &[0x38AF]),
+    (0x18A9, 0x18A9, &[0x38B9]),
+    (0x18AA, 0x18AA, &[0x38B8]),
+    (0x18B0, 0x18F5, &[0x3BD5]),
+    (0x1900, 0x191C, &[0x3454]),
+    (0x191D, 0x191D, &[0x345C, 0x347A]),
+    (0x191E, 0x191E, &[0x345F, 0x347B]),
+    (0x1920, 0x192B, &[0x3471]),
+    (0x1930, 0x1938, &[0x347D]),
+    (0x1939, 0x193B, &[]),
+    (0x1940, 0x1940, &[0x0598]),
+    (0x1944, 0x1944, &[0x026B]),
+    (0x1945, 0x1945, &[0x0274]),
+    (0x1946, 0x194F, &[0x1F98]),
+    (0x1950, 0x196D, &[0x36BB]),
+    (0x1970, 0x1974, &[0x36D9]),
+    (0x1980, 0x19AB, &[0x36DE]),
+    (0x19B0, 0x19C9, &[0x370A]),
+    (0x19D0, 0x19D9, &[0x1F98]),
+    (0x19DA, 0x19DA, &[0x1F99]),
+    (0x19DE, 0x19DE, &[0x36FA, 0x3710]),
+    (0x19DF, 0x19DF, &[0x36FA, 0x3710, 0x371B]),
+    (0x19E0, 0x19FF, &[0x059F]),
+    (0x1A00, 0x1A1B, &[0x34D5]),
+    (0x1A1E, 0x1A1F, &[0x02D2]),
+    (0x1A20, 0x1A4C, &[0x3724]),
+    (0x1A4D, 0x1A52, &[0x3759]),
+    (0x1A53, 0x1A53, &[0x3751]),
+    (0x1A54, 0x1A54, &[0x374A, 0x3770, 0x374A]),
+    (0x1A55, 0x1A57, &[0x3753]),
+    (0x1A58, 0x1A58, &[0x372A]),
+    (0x1A59, 0x1A59, &[0x372A]),
+    (0x1A5A, 0x1A5A, &[0x373F]),
+    (0x1A5B, 0x1A5B, &[0x373F]),
+    (0x1A5C, 0x1A5E, &[0x3756]),
+    (0x1A60, 0x1A60, &[0x3770]),
+    (0x1A61, 0x1A61, &[0x375F]),
+    (0x1A62, 0x1A63, &[0x3761]),
+    (0x1A64, 0x1A6A, &[0x3762]),
+    (0x1A6B, 0x1A6B, &[0x3752]),
+    (0x1A6C, 0x1A6C, &[0x3760]),
+    (0x1A6D, 0x1A6D, &[0x376F]),
+    (0x1A6E, 0x1A6F, &[0x3769]),
+    (0x1A70, 0x1A72, &[0x376C]),
+    (0x1A73, 0x1A73, &[0x376B]),
+    (0x1A74, 0x1A7C, &[]),
+    (0x1A7F, 0x1A7F, &[]),
+    (0x1A80, 0x1A89, &[0x1F98]),
+    (0x1A90, 0x1A99, &[0x1F98]),
+    (0x1AA0, 0x1AA6, &[0x0442]),
+    (0x1AA7, 0x1AA7, &[0x1F4C]),
+    (0x1AA8, 0x1AAB, &[0x02A5]),
+    (0x1AAC, 0x1AAD, &[0x0449]),
+    (0x1AB0, 0x1ABE, &[]),
+    (0x1ABF, 0x1ABF, &[0x2259]),
+    (0x1AC0, 0x1AC0, &[0x2260]),
+    (0x1B00, 0x1B04, &[]),
+    (0x1B05, 0x1B13, &[0x37B6]),
+    (0x1B14, 0x1B22, &[0x37C7]),
+    (0x1B23, 0x1B27, &[0x37D7]),
+    (0x1B28, 0x1B2F, &[0x37DD]),
+    (0x1B30, 0x1B32, &[0x37E6]),
+    (0x1B33, 0x1B33, &[0x37EB]),
+    (0x1B34, 0x1B34, &[]),
+    (0x1B35, 0x1B44, &[0x37EC]),
+    (0x1B45, 0x1B46, &[0x37C5]),
+    (0x1B47, 0x1B47, &[0x37D6]),
+    (0x1B48, 0x1B48, &[0x37DC]),
+    (0x1B49, 0x1B49, &[0x37E5]),
+    (0x1B4A, 0x1B4B, &[0x37E9]),
+    (0x1B50, 0x1B59, &[0x1F98]),
+    (0x1B5A, 0x1B5B, &[0x02D4]),
+    (0x1B5C, 0x1B5C, &[0x0287]),
+    (0x1B5D, 0x1B5D, &[0x0261]),
+    (0x1B5E, 0x1B5F, &[0x02A9]),
+    (0x1B60, 0x1B60, &[0x0210]),
+    (0x1B61, 0x1B6A, &[0x05BF]),
+    (0x1B6B, 0x1B73, &[]),
+    (0x1B74, 0x1B7C, &[0x05C9]),
+    (0x1B80, 0x1B82, &[]),
+    (0x1B83, 0x1B8A, &[0x313A]),
+    (0x1B8B, 0x1B98, &[0x3143]),
+    (0x1B99, 0x1B99, &[0x3152]),
+    (0x1B9A, 0x1B9A, &[0x3154]),
+    (0x1B9B, 0x1B9B, &[0x3156]),
+    (0x1B9C, 0x1B9C, &[0x3159]),
+    (0x1B9D, 0x1B9D, &[0x315C]),
+    (0x1B9E, 0x1B9F, &[0x315E]),
+    (0x1BA0, 0x1BA0, &[0x3161]),
+    (0x1BA1, 0x1BA1, &[0x3155]),
+    (0x1BA2, 0x1BA2, &[0x3157]),
+    (0x1BA3, 0x1BA3, &[0x315A]),
+    (0x1BA4, 0x1BAB, &[0x3162]),
+    (0x1BAC, 0x1BAC, &[0x3153]),
+    (0x1BAD, 0x1BAD, &[0x315D]),
+    (0x1BAE, 0x1BAE, &[0x3142]),
+    (0x1BAF, 0x1BAF, &[0x3160]),
+    (0x1BB0, 0x1BB9, &[0x1F98]),
+    (0x1BBA, 0x1BBA, &[0x313A]),
+    (0x1BBB, 0x1BBB, &[0x3158]),
+    (0x1BBC, 0x1BBC, &[0x315B]),
+    (0x1BBD, 0x1BBD, &[0x3151]),
+    (0x1BBE, 0x1BBE, &[0x3141]),
+    (0x1BBF, 0x1BBF, &[0x3152]),
+    (0x1BC0, 0x1BC0, &[0x3508]),
+    (0x1BC1, 0x1BC2, &[0x3508]),
+    (0x1BC3, 0x1BC3, &[0x3509]),
+    (0x1BC4, 0x1BC5, &[0x3509]),
+    (0x1BC6, 0x1BC7, &[0x350A]),
+    (0x1BC8, 0x1BC9, &[0x350B]),
+    (0x1BCA, 0x1BCB, &[0x350C]),
+    (0x1BCC, 0x1BCC, &[0x350D]),
+    (0x1BCD, 0x1BCE, &[0x350D]),
+    (0x1BCF, 0x1BD2, &[0x350E]),
+    (0x1BD3, 0x1BD4, &[0x3511]),
+    (0x1BD5, 0x1BD6, &[0x3512]),
+    (0x1BD7, 0x1BD8, &[0x3513]),
+    (0x1BD9, 0x1BD9, &[0x3514]),
+    (0x1BDA, 0x1BDB, &[0x3514]),
+    (0x1BDC, 0x1BDE, &[0x3515]),
+    (0x1BDF, 0x1BE5, &[0x3517]),
+    (0x1BE6, 0x1BE6, &[]),
+    (0x1BE7, 0x1BE7, &[0x351E]),
+    (0x1BE8, 0x1BEA, &[0x351E]),
+    (0x1BEB, 0x1BEC, &[0x3520]),
+    (0x1BED, 0x1BEE, &[0x3521]),
+    (0x1BEF, 0x1BF3, &[0x3522]),
+    (0x1BFC, 0x1BFF, &[0x0456]),
+    (0x1C00, 0x1C09, &[0x33E6]),
+    (0x1C0A, 0x1C1A, &[0x33F3]),
+    (0x1C1B, 0x1C1B, &[0x3405]),
+    (0x1C1C, 0x1C23, &[0x3407]),
+    (0x1C24, 0x1C24, &[0x3404]),
+    (0x1C25, 0x1C25, &[0x3406]),
+    (0x1C26, 0x1C35, &[0x3410]),
+    (0x1C36, 0x1C36, &[0x340F]),
+    (0x1C37, 0x1C37, &[]),
+    (0x1C3B, 0x1C3C, &[0x029A]),
+    (0x1C3D, 0x1C3F, &[0x0438]),
+    (0x1C40, 0x1C49, &[0x1F98]),
+    (0x1C4D, 0x1C4F, &[0x33F0]),
+    (0x1C50, 0x1C59, &[0x1F98]),
+    (0x1C5A, 0x1C7D, &[0x38BA]),
+    (0x1C7E, 0x1C7F, &[0x02CB]),
+    (0x1C80, 0x1C80, &[0x2397]),
+    (0x1C81, 0x1C81, &[0x23AF]),
+    (0x1C82, 0x1C82, &[0x244C]),
+    (0x1C83, 0x1C83, &[0x246A]),
+    (0x1C84, 0x1C84, &[0x2473]),
+    (0x1C85, 0x1C85, &[0x2473]),
+    (0x1C86, 0x1C86, &[0x24F4]),
+    (0x1C87, 0x1C87, &[0x2505]),
+    (0x1C88, 0x1C88, &[0x248E]),
+    (0x1C90, 0x1C90, &[0x259F]),
+    (0x1C91, 0x1C91, &[0x25A1]),
+    (0x1C92, 0x1C92, &[0x25A3]),
+    (0x1C93, 0x1C93, &[0x25A5]),
+    (0x1C94, 0x1C94, &[0x25A7]),
+    (0x1C95, 0x1C95, &[0x25A9]),
+    (0x1C96, 0x1C96, &[0x25AB]),
+    (0x1C97, 0x1C97, &[0x25AF]),
+    (0x1C98, 0x1C98, &[0x25B1]),
+    (0x1C99, 0x1C99, &[0x25B3]),
+    (0x1C9A, 0x1C9A, &[0x25B5]),
+    (0x1C9B, 0x1C9B, &[0x25B7]),
+    (0x1C9C, 0x1C9C, &[0x25B9]),
+    (0x1C9D, 0x1C9D, &[0x25BD]),
+    (0x1C9E, 0x1C9E, &[0x25BF]),
+    (0x1C9F, 0x1C9F, &[0x25C1]),
+    (0x1CA0, 0x1CA0, &[0x25C3]),
+    (0x1CA1, 0x1CA1, &[0x25C5]),
+    (0x1CA2, 0x1CA2, &[0x25C7]),
+    (0x1CA3, 0x1CA3, &[0x25CB]),
+    (0x1CA4, 0x1CA4, &[0x25CD]),
+    (0x1CA5, 0x1CA5, &[0x25CF]),
+    (0x1CA6, 0x1CA6, &[0x25D1]),
+    (0x1CA7, 0x1CA7, &[0x25D3]),
+    (0x1CA8, 0x1CA8, &[0x25D5]),
+    (0x1CA9, 0x1CA9, &[0x25D7]),
+    (0x1CAA, 0x1CAA, &[0x25D9]),
+    (0x1CAB, 0x1CAB, &[0x25DB]),
+    (0x1CAC, 0x1CAC, &[0x25DD]),
+    (0x1CAD, 0x1CAD, &[0x25DF]),
+    (0x1CAE, 0x1CAE, &[0x25E1]),
+    (0x1CAF, 0x1CAF, &[0x25E5]),
+    (0x1CB0, 0x1CB0, &[0x25E7]),
+    (0x1CB1, 0x1CB1, &[0x25AD]),
+    (0x1CB2, 0x1CB2, &[0x25BB]),
+    (0x1CB3, 0x1CB3, &[0x25C9]),
+    (0x1CB4, 0x1CB4, &[0x25E3]),
+    (0x1CB5, 0x1CB5, &[0x25E9]),
+    (0x1CB6, 0x1CB7, &[0x25EB]),
+    (0x1CB8, 0x1CBA, &[0x25EE]),
+    (0x1CBD, 0x1CBD, &[0x25F1]),
+    (0x1CBE, 0x1CBF, &[0x25F3]),
+    (0x1CC0, 0x1CC7, &[0x044B]),
+    (0x1CD0, 0x1CE8, &[]),
+    (0x1CE9, 0x1CE9, &[0x2A1E]),
+    (0x1CEA, 0x1CEA, &[0x2A1E]),
+    (0x1CEB, 0x1CEB, &[0x2A1E]),
+    (0x1CEC, 0x1CEC, &[0x2A1E]),
+    (0x1CED, 0x1CED, &[]),
+    (0x1CEE, 0x1CEE, &[0x2A1E]),
+    (0x1CEF, 0x1CEF, &[0x2A1E]),
+    (0x1CF0, 0x1CF0, &[0x2A1E]),
+    (0x1CF1, 0x1CF1, &[0x2A1E]),
+    (0x1CF2, 0x1CF4, &[]),
+    (0x1CF5, 0x1CF6, &[0x2A1F]),
+    (0x1CF7, 0x1CF9, &[]),
+    (0x1CFA, 0x1CFA, &[0x2A1E]),
+    (0x1D00, 0x1D00, &[0x1FA6]),
+    (0x1D01, 0x1D02, &[0x1FAA]),
+    (0x1D03, 0x1D03, &[0x1FC9]),
+    (0x1D04, 0x1D04, &[0x1FDA]),
+    (0x1D05, 0x1D06, &[0x1FEF]),
+    (0x1D07, 0x1D07, &[0x200B]),
+    (0x1D08, 0x1D08, &[0x2031]),
+    (0x1D09, 0x1D09, &[0x209E]),
+    (0x1D0A, 0x1D0A, &[0x20B3]),
+    (0x1D0B, 0x1D0B, &[0x20C8]),
+    (0x1D0C, 0x1D0C, &[0x20DF]),
+    (0x1D0D, 0x1D0D, &[0x210D]),
+    (0x1D0E, 0x1D0E, &[0x2121]),
+    (0x1D0F, 0x1D0F, &[0x2140]),
+    (0x1D10, 0x1D10, &[0x2153]),
+    (0x1D11, 0x1D11, &[0x2141]),
+    (0x1D12, 0x1D12, &[0x2154]),
+    (0x1D13, 0x1D13, &[0x214D]),
+    (0x1D14, 0x1D14, &[0x2147]),
+    (0x1D15, 0x1D15, &[0x216A]),
+    (0x1D16, 0x1D17, &[0x2159]),
+    (0x1D18, 0x1D18, &[0x216F]),
+    (0x1D19, 0x1D19, &[0x219E]),
+    (0x1D1A, 0x1D1A, &[0x21A8]),
+    (0x1D1B, 0x1D1B, &[0x21FB]),
+    (0x1D1C, 0x1D1C, &[0x221B]),
+    (0x1D1D, 0x1D1E, &[0x221D]),
+    (0x1D1F, 0x1D1F, &[0x223D]),
+    (0x1D20, 0x1D20, &[0x224B]),
+    (0x1D21, 0x1D21, &[0x225D]),
+    (0x1D22, 0x1D22, &[0x228A]),
+    (0x1D23, 0x1D23, &[0x22A7]),
+    (0x1D24, 0x1D25, &[0x22EF]),
+    (0x1D26, 0x1D26, &[0x2321]),
+    (0x1D27, 0x1D27, &[0x232F]),
+    (0x1D28, 0x1D28, &[0x2335]),
+    (0x1D29, 0x1D29, &[0x233A]),
+    (0x1D2A, 0x1D2A, &[0x2345]),
+    (0x1D2B, 0x1D2B, &[0x2419]),
+    (0x1D2C, 0x1D2C, &[0x1FA2]),
+    (0x1D2D, 0x1D2D, &[0x1FA2, 0x2007]),
+    (0x1D2E, 0x1D2E, &[0x1FBC]),
+    (0x1D2F, 0x1D2F, &[0x1FC8]),
+    (0x1D30, 0x1D30, &[0x1FEB]),
+    (0x1D31, 0x1D31, &[0x2007]),
+    (0x1D32, 0x1D32, &[0x2015]),
+    (0x1D33, 0x1D33, &[0x2051]),
+    (0x1D34, 0x1D34, &[0x2075]),
+    (0x1D35, 0x1D35, &[0x2090]),
+    (0x1D36, 0x1D36, &[0x20AB]),
+    (0x1D37, 0x1D37, &[0x20C4]),
+    (0x1D38, 0x1D38, &[0x20D6]),
+    (0x1D39, 0x1D39, &[0x2109]),
+    (0x1D3A, 0x1D3A, &[0x2118]),
+    (0x1D3B, 0x1D3B, &[0x2120]),
+    (0x1D3C, 0x1D3C, &[0x213C]),
+    (0x1D3D, 0x1D3D, &[0x2166]),
+    (0x1D3E, 0x1D3E, &[0x216B]),
+    (0x1D3F, 0x1D3F, &[0x2193]),
+    (0x1D40, 0x1D40, &[0x21F7]),
+    (0x1D41, 0x1D41, &[0x2217]),
+    (0x1D42, 0x1D42, &[0x2259]),
+    (0x1D43, 0x1D43, &[0x1FA2]),
+    (0x1D44, 0x1D44, &[0x1FAD]),
+    (0x1D45, 0x1D45, &[0x1FB1]),
+    (0x1D46, 0x1D46, &[0x1FAB]),
+    (0x1D47, 0x1D47, &[0x1FBC]),
+    (0x1D48, 0x1D48, &[0x1FEB]),
+    (0x1D49, 0x1D49, &[0x2007]),
+    (0x1D4A, 0x1D4A, &[0x201A]),
+    (0x1D4B, 0x1D4B, &[0x201F]),
+    (0x1D4C, 0x1D4C, &[0x2031]),
+    (0x1D4D, 0x1D4D, &[0x2051]),
+    (0x1D4E, 0x1D4E, &[0x209E]),
+    (0x1D4F, 0x1D4F, &[0x20C4]),
+    (0x1D50, 0x1D50, &[0x2109]),
+    (0x1D51, 0x1D51, &[0x2137]),
+    (0x1D52, 0x1D52, &[0x213C]),
+    (0x1D53, 0x1D53, &[0x214F]),
+    (0x1D54, 0x1D55, &[0x2159]),
+    (0x1D56, 0x1D56, &[0x216B]),
+    (0x1D57, 0x1D57, &[0x21F7]),
+    (0x1D58, 0x1D58, &[0x2217]),
+    (0x1D59, 0x1D59, &[0x221D]),
+    (0x1D5A, 0x1D5A, &[0x2238]),
+    (0x1D5B, 0x1D5B, &[0x2247]),
+    (0x1D5C, 0x1D5C, &[0x22F0]),
+    (0x1D5D, 0x1D5E, &[0x231F]),
+    (0x1D5F, 0x1D5F, &[0x2322]),
+    (0x1D60, 0x1D61, &[0x2342]),
+    (0x1D62, 0x1D62, &[0x2090]),
+    (0x1D63, 0x1D63, &[0x2193]),
+    (0x1D64, 0x1D64, &[0x2217]),
+    (0x1D65, 0x1D65, &[0x2247]),
+    (0x1D66, 0x1D67, &[0x231F]),
+    (0x1D68, 0x1D68, &[0x2339]),
+    (0x1D69, 0x1D6A, &[0x2342]),
+    (0x1D6B, 0x1D6B, &[0x221F]),
+    (0x1D6C, 0x1D6C, &[0x1FCA]),
+    (0x1D6D, 0x1D6D, &[0x1FF2]),
+    (0x1D6E, 0x1D6E, &[0x2049]),
+    (0x1D6F, 0x1D6F, &[0x210E]),
+    (0x1D70, 0x1D70, &[0x2122]),
+    (0x1D71, 0x1D71, &[0x2172]),
+    (0x1D72, 0x1D72, &[0x21A3]),
+    (0x1D73, 0x1D73, &[0x21C0]),
+    (0x1D74, 0x1D74, &[0x21D8]),
+    (0x1D75, 0x1D75, &[0x2201]),
+    (0x1D76, 0x1D76, &[0x228F]),
+    (0x1D77, 0x1D77, &[0x206B]),
+    (0x1D78, 0x1D78, &[0x2431]),
+    (0x1D79, 0x1D79, &[0x2051]),
+    (0x1D7A, 0x1D7A, &[0x21F7, 0x2075]),
+    (0x1D7B, 0x1D7B, &[0x20A3]),
+    (0x1D7C, 0x1D7C, &[0x20AA]),
+    (0x1D7D, 0x1D7D, &[0x2170]),
+    (0x1D7E, 0x1D7E, &[0x2228]),
+    (0x1D7F, 0x1D7F, &[0x2246]),
+    (0x1D80, 0x1D80, &[0x1FCC]),
+    (0x1D81, 0x1D81, &[0x1FF3]),
+    (0x1D82, 0x1D82, &[0x204A]),
+    (0x1D83, 0x1D83, &[0x2062]),
+    (0x1D84, 0x1D84, &[0x20C9]),
+    (0x1D85, 0x1D85, &[0x20F1]),
+    (0x1D86, 0x1D86, &[0x210F]),
+    (0x1D87, 0x1D87, &[0x212C]),
+    (0x1D88, 0x1D88, &[0x2173]),
+    (0x1D89, 0x1D89, &[0x21AD]),
+    (0x1D8A, 0x1D8A, &[0x21D9]),
+    (0x1D8B, 0x1D8B, &[0x21E9]),
+    (0x1D8C, 0x1D8C, &[0x224D]),
+    (0x1D8D, 0x1D8D, &[0x2268]),
+    (0x1D8E, 0x1D8E, &[0x2290]),
+    (0x1D8F, 0x1D8F, &[0x1FA8]),
+    (0x1D90, 0x1D90, &[0x1FB6]),
+    (0x1D91, 0x1D91, &[0x1FFC]),
+    (0x1D92, 0x1D92, &[0x2012]),
+    (0x1D93, 0x1D93, &[0x2023]),
+    (0x1D94, 0x1D94, &[0x2030]),
+    (0x1D95, 0x1D95, &[0x201E]),
+    (0x1D96, 0x1D96, &[0x20A4]),
+    (0x1D97, 0x1D97, &[0x2156]),
+    (0x1D98, 0x1D98, &[0x21F2]),
+    (0x1D99, 0x1D99, &[0x2229]),
+    (0x1D9A, 0x1D9A, &[0x22AC]),
+    (0x1D9B, 0x1D9B, &[0x1FB7]),
+    (0x1D9C, 0x1D9C, &[0x1FD6]),
+    (0x1D9D, 0x1D9D, &[0x1FE5]),
+    (0x1D9E, 0x1D9E, &[0x1FEB]),
+    (0x1D9F, 0x1D9F, &[0x202C]),
+    (0x1DA0, 0x1DA0, &[0x2042]),
+    (0x1DA1, 0x1DA1, &[0x20BC]),
+    (0x1DA2, 0x1DA2, &[0x2055]),
+    (0x1DA3, 0x1DA3, &[0x222C]),
+    (0x1DA4, 0x1DA4, &[0x209F]),
+    (0x1DA5, 0x1DA5, &[0x20A6]),
+    (0x1DA6, 0x1DA6, &[0x2098]),
+    (0x1DA7, 0x1DA7, &[0x20A3]),
+    (0x1DA8, 0x1DA8, &[0x20B8]),
+    (0x1DA9, 0x1DA9, &[0x20F2]),
+    (0x1DAA, 0x1DAA, &[0x20F1]),
+    (0x1DAB, 0x1DAB, &[0x20DA]),
+    (0x1DAC, 0x1DAC, &[0x2110]),
+    (0x1DAD, 0x1DAD, &[0x223E]),
+    (0x1DAE, 0x1DAE, &[0x2123]),
+    (0x1DAF, 0x1DAF, &[0x212D]),
+    (0x1DB0, 0x1DB0, &[0x211C]),
+    (0x1DB1, 0x1DB1, &[0x215C]),
+    (0x1DB2, 0x1DB2, &[0x217B]),
+    (0x1DB3, 0x1DB3, &[0x21DA]),
+    (0x1DB4, 0x1DB4, &[0x21E4]),
+    (0x1DB5, 0x1DB5, &[0x2202]),
+    (0x1DB6, 0x1DB6, &[0x2222]),
+    (0x1DB7, 0x1DB7, &[0x2242]),
+    (0x1DB8, 0x1DB8, &[0x221B]),
+    (0x1DB9, 0x1DB9, &[0x224E]),
+    (0x1DBA, 0x1DBA, &[0x2255]),
+    (0x1DBB, 0x1DBB, &[0x2286]),
+    (0x1DBC, 0x1DBC, &[0x2295]),
+    (0x1DBD, 0x1DBD, &[0x2299]),
+    (0x1DBE, 0x1DBE, &[0x22A3]),
+    (0x1DBF, 0x1DBF, &[0x232A]),
+    (0x1DC0, 0x1DC9, &[]),
+    (0x1DCA, 0x1DCA, &[0x2193]),
+    (0x1DCB, 0x1DD1, &[]),
+    (0x1DD2, 0x1DD2, &[0x22C5]),
+    (0x1DD3, 0x1DD3, &[0x1FA2]),
+    (0x1DD4, 0x1DD4, &[0x1FA2, 0x2007]),
+    (0x1DD5, 0x1DD5, &[0x1FA2, 0x213C]),
+    (0x1DD6, 0x1DD6, &[0x1FA2, 0x2247]),
+    (0x1DD7, 0x1DD7, &[0x1FD6]),
+    (0x1DD8, 0x1DD8, &[0x1FEB]),
+    (0x1DD9, 0x1DD9, &[0x1FEB]),
+    (0x1DDA, 0x1DDA, &[0x2051]),
+    (0x1DDB, 0x1DDB, &[0x205A]),
+    (0x1DDC, 0x1DDC, &[0x20C4]),
+    (0x1DDD, 0x1DDD, &[0x20D6]),
+    (0x1DDE, 0x1DDE, &[0x20DA]),
+    (0x1DDF, 0x1DDF, &[0x210D]),
+    (0x1DE0, 0x1DE0, &[0x2118]),
+    (0x1DE1, 0x1DE1, &[0x211C]),
+    (0x1DE2, 0x1DE2, &[0x2198]),
+    (0x1DE3, 0x1DE3, &[0x219D]),
+    (0x1DE4, 0x1DE4, &[0x21D2]),
+    (0x1DE5, 0x1DE5, &[0x21D2]),
+    (0x1DE6, 0x1DE6, &[0x2286]),
+    (0x1DE7, 0x1DE7, &[0x1FB1]),
+    (0x1DE8, 0x1DE8, &[0x1FBC]),
+    (0x1DE9, 0x1DE9, &[0x1FD5]),
+    (0x1DEA, 0x1DEA, &[0x201A]),
+    (0x1DEB, 0x1DEB, &[0x2042]),
+    (0x1DEC, 0x1DEC, &[0x20EA]),
+    (0x1DED, 0x1DED, &[0x213C]),
+    (0x1DEE, 0x1DEE, &[0x216B]),
+    (0x1DEF, 0x1DEF, &[0x21E4]),
+    (0x1DF0, 0x1DF0, &[0x2217]),
+    (0x1DF1, 0x1DF1, &[0x2259]),
+    (0x1DF2, 0x1DF2, &[0x1FA2]),
+    (0x1DF3, 0x1DF3, &[0x213C]),
+    (0x1DF4, 0x1DF4, &[0x2217]),
+    (0x1DF5, 0x1DF9, &[]),
+    (0x1DFB, 0x1DFF, &[]),
+    (0x1E00, 0x1E00, &[0x1FA2]),
+    (0x1E01, 0x1E01, &[0x1FA2]),
+    (0x1E02, 0x1E02, &[0x1FBC]),
+    (0x1E03, 0x1E03, &[0x1FBC]),
+    (0x1E04, 0x1E04, &[0x1FBC]),
+    (0x1E05, 0x1E05, &[0x1FBC]),
+    (0x1E06, 0x1E06, &[0x1FBC]),
+    (0x1E07, 0x1E07, &[0x1FBC]),
+    (0x1E08, 0x1E08, &[0x1FD6]),
+    (0x1E09, 0x1E09, &[0x1FD6]),
+    (0x1E0A, 0x1E0A, &[0x1FEB]),
+    (0x1E0B, 0x1E0B, &[0x1FEB]),
+    (0x1E0C, 0x1E0C, &[0x1FEB]),
+    (0x1E0D, 0x1E0D, &[0x1FEB]),
+    (0x1E0E, 0x1E0E, &[0x1FEB]),
+    (0x1E0F, 0x1E0F, &[0x1FEB]),
+    (0x1E10, 0x1E10, &[0x1FEB]),
+    (0x1E11, 0x1E11, &[0x1FEB]),
+    (0x1E12, 0x1E12, &[0x1FEB]),
+    (0x1E13, 0x1E13, &[0x1FEB]),
+    (0x1E14, 0x1E14, &[0x2007]),
+    (0x1E15, 0x1E15, &[0x2007]),
+    (0x1E16, 0x1E16, &[0x2007]),
+    (0x1E17, 0x1E17, &[0x2007]),
+    (0x1E18, 0x1E18, &[0x2007]),
+    (0x1E19, 0x1E19, &[0x2007]),
+    (0x1E1A, 0x1E1A, &[0x2007]),
+    (0x1E1B, 0x1E1B, &[0x2007]),
+    (0x1E1C, 0x1E1C, &[0x2007]),
+    (0x1E1D, 0x1E1D, &[0x2007]),
+    (0x1E1E, 0x1E1E, &[0x2042]),
+    (0x1E1F, 0x1E1F, &[0x2042]),
+    (0x1E20, 0x1E20, &[0x2051]),
+    (0x1E21, 0x1E21, &[0x2051]),
+    (0x1E22, 0x1E22, &[0x2075]),
+    (0x1E23, 0x1E23, &[0x2075]),
+    (0x1E24, 0x1E24, &[0x2075]),
+    (0x1E25, 0x1E25, &[0x2075]),
+    (0x1E26, 0x1E26, &[0x2075]),
+    (0x1E27, 0x1E27, &[0x2075]),
+    (0x1E28, 0x1E28, &[0x2075]),
+    (0x1E29, 0x1E29, &[0x2075]),
+    (0x1E2A, 0x1E2A, &[0x2075]),
+    (0x1E2B, 0x1E2B, &[0x2075]),
+    (0x1E2C, 0x1E2C, &[0x2090]),
+    (0x1E2D, 0x1E2D, &[0x2090]),
+    (0x1E2E, 0x1E2E, &[0x2090]),
+    (0x1E2F, 0x1E2F, &[0x2090]),
+    (0x1E30, 0x1E30, &[0x20C4]),
+    (0x1E31, 0x1E31, &[0x20C4]),
+    (0x1E32, 0x1E32, &[0x20C4]),
+    (0x1E33, 0x1E33, &[0x20C4]),
+    (0x1E34, 0x1E34, &[0x20C4]),
+    (0x1E35, 0x1E35, &[0x20C4]),
+    (0x1E36, 0x1E36, &[0x20D6]),
+    (0x1E37, 0x1E37, &[0x20D6]),
+    (0x1E38, 0x1E38, &[0x20D6]),
+    (0x1E39, 0x1E39, &[0x20D6]),
+    (0x1E3A, 0x1E3A, &[0x20D6]),
+    (0x1E3B, 0x1E3B, &[0x20D6]),
+    (0x1E3C, 0x1E3C, &[0x20D6]),
+    (0x1E3D, 0x1E3D, &[0x20D6]),
+    (0x1E3E, 0x1E3E, &[0x2109]),
+    (0x1E3F, 0x1E3F, &[0x2109]),
+    (0x1E40, 0x1E40, &[0x2109]),
+    (0x1E41, 0x1E41, &[0x2109]),
+    (0x1E42, 0x1E42, &[0x2109]),
+    (0x1E43, 0x1E43, &[0x2109]),
+    (0x1E44, 0x1E44, &[0x2118]),
+    (0x1E45, 0x1E45, &[0x2118]),
+    (0x1E46, 0x1E46, &[0x2118]),
+    (0x1E47, 0x1E47, &[0x2118]),
+    (0x1E48, 0x1E48, &[0x2118]),
+    (0x1E49, 0x1E49, &[0x2118]),
+    (0x1E4A, 0x1E4A, &[0x2118]),
+    (0x1E4B, 0x1E4B, &[0x2118]),
+    (0x1E4C, 0x1E4C, &[0x213C]),
+    (0x1E4D, 0x1E4D, &[0x213C]),
+    (0x1E4E, 0x1E4E, &[0x213C]),
+    (0x1E4F, 0x1E4F, &[0x213C]),
+    (0x1E50, 0x1E50, &[0x213C]),
+    (0x1E51, 0x1E51, &[0x213C]),
+    (0x1E52, 0x1E52, &[0x213C]),
+    (0x1E53, 0x1E53, &[0x213C]),
+    (0x1E54, 0x1E54, &[0x216B]),
+    (0x1E55, 0x1E55, &[0x216B]),
+    (0x1E56, 0x1E56, &[0x216B]),
+    (0x1E57, 0x1E57, &[0x216B]),
+    (0x1E58, 0x1E58, &[0x2193]),
+    (0x1E59, 0x1E59, &[0x2193]),
+    (0x1E5A, 0x1E5A, &[0x2193]),
+    (0x1E5B, 0x1E5B, &[0x2193]),
+    (0x1E5C, 0x1E5C, &[0x2193]),
+    (0x1E5D, 0x1E5D, &[0x2193]),
+    (0x1E5E, 0x1E5E, &[0x2193]),
+    (0x1E5F, 0x1E5F, &[0x2193]),
+    (0x1E60, 0x1E60, &[0x21D2]),
+    (0x1E61, 0x1E61, &[0x21D2]),
+    (0x1E62, 0x1E62, &[0x21D2]),
+    (0x1E63, 0x1E63, &[0x21D2]),
+    (0x1E64, 0x1E64, &[0x21D2]),
+    (0x1E65, 0x1E65, &[0x21D2]),
+    (0x1E66, 0x1E66, &[0x21D2]),
+    (0x1E67, 0x1E67, &[0x21D2]),
+    (0x1E68, 0x1E68, &[0x21D2]),
+    (0x1E69, 0x1E69, &[0x21D2]),
+    (0x1E6A, 0x1E6A, &[0x21F7]),
+    (0x1E6B, 0x1E6B, &[0x21F7]),
+    (0x1E6C, 0x1E6C, &[0x21F7]),
+    (0x1E6D, 0x1E6D, &[0x21F7]),
+    (0x1E6E, 0x1E6E, &[0x21F7]),
+    (0x1E6F, 0x1E6F, &[0x21F7]),
+    (0x1E70, 0x1E70, &[0x21F7]),
+    (0x1E71, 0x1E71, &[0x21F7]),
+    (0x1E72, 0x1E72, &[0x2217]),
+    (0x1E73, 0x1E73, &[0x2217]),
+    (0x1E74, 0x1E74, &[0x2217]),
+    (0x1E75, 0x1E75, &[0x2217]),
+    (0x1E76, 0x1E76, &[0x2217]),
+    (0x1E77, 0x1E77, &[0x2217]),
+    (0x1E78, 0x1E78, &[0x2217]),
+    (0x1E79, 0x1E79, &[0x2217]),
+    (0x1E7A, 0x1E7A, &[0x2217]),
+    (0x1E7B, 0x1E7B, &[0x2217]),
+    (0x1E7C, 0x1E7C, &[0x2247]),
+    (0x1E7D, 0x1E7D, &[0x2247]),
+    (0x1E7E, 0x1E7E, &[0x2247]),
+    (0x1E7F, 0x1E7F, &[0x2247]),
+    (0x1E80, 0x1E80, &[0x2259]),
+    (0x1E81, 0x1E81, &[0x2259]),
+    (0x1E82, 0x1E82, &[0x2259]),
+    (0x1E83, 0x1E83, &[0x2259]),
+    (0x1E84, 0x1E84, &[0x2259]),
+    (0x1E85, 0x1E85, &[0x2259]),
+    (0x1E86, 0x1E86, &[0x2259]),
+    (0x1E87, 0x1E87, &[0x2259]),
+    (0x1E88, 0x1E88, &[0x2259]),
+    (0x1E89, 0x1E89, &[0x2259]),
+    (0x1E8A, 0x1E8A, &[0x2264]),
+    (0x1E8B, 0x1E8B, &[0x2264]),
+    (0x1E8C, 0x1E8C, &[0x2264]),
+    (0x1E8D, 0x1E8D, &[0x2264]),
+    (0x1E8E, 0x1E8E, &[0x2270]),
+    (0x1E8F, 0x1E8F, &[0x2270]),
+    (0x1E90, 0x1E90, &[0x2286]),
+    (0x1E91, 0x1E91, &[0x2286]),
+    (0x1E92, 0x1E92, &[0x2286]),
+    (0x1E93, 0x1E93, &[0x2286]),
+    (0x1E94, 0x1E94, &[0x2286]),
+    (0x1E95, 0x1E95, &[0x2286]),
+    (0x1E96, 0x1E96, &[0x2075]),
+    (0x1E97, 0x1E97, &[0x21F7]),
+    (0x1E98, 0x1E98, &[0x2259]),
+    (0x1E99, 0x1E99, &[0x2270]),
+    (0x1E9A, 0x1E9A, &[0x1FA2, 0x22E5]),
+    (0x1E9B, 0x1E9B, &[0x21D2]),
+    (0x1E9C, 0x1E9D, &[0x21E2]),
+    (0x1E9E, 0x1E9E, &[0x21D2, 0x21D2]),
+    (0x1E9F, 0x1E9F, &[0x2006]),
+    (0x1EA0, 0x1EA0, &[0x1FA2]),
+    (0x1EA1, 0x1EA1, &[0x1FA2]),
+    (0x1EA2, 0x1EA2, &[0x1FA2]),
+    (0x1EA3, 0x1EA3, &[0x1FA2]),
+    (0x1EA4, 0x1EA4, &[0x1FA2]),
+    (0x1EA5, 0x1EA5, &[0x1FA2]),
+    (0x1EA6, 0x1EA6, &[0x1FA2]),
+    (0x1EA7, 0x1EA7, &[0x1FA2]),
+    (0x1EA8, 0x1EA8, &[0x1FA2]),
+    (0x1EA9, 0x1EA9, &[0x1FA2]),
+    (0x1EAA, 0x1EAA, &[0x1FA2]),
+    (0x1EAB, 0x1EAB, &[0x1FA2]),
+    (0x1EAC, 0x1EAC, &[0x1FA2]),
+    (0x1EAD, 0x1EAD, &[0x1FA2]),
+    (0x1EAE, 0x1EAE, &[0x1FA2]),
+    (0x1EAF, 0x1EAF, &[0x1FA2]),
+    (0x1EB0, 0x1EB0, &[0x1FA2]),
+    (0x1EB1, 0x1EB1, &[0x1FA2]),
+    (0x1EB2, 0x1EB2, &[0x1FA2]),
+    (0x1EB3, 0x1EB3, &[0x1FA2]),
+    (0x1EB4, 0x1EB4, &[0x1FA2]),
+    (0x1EB5, 0x1EB5, &[0x1FA2]),
+    (0x1EB6, 0x1EB6, &[0x1FA2]),
+    (0x1EB7, 0x1EB7, &[0x1FA2]),
+    (0x1EB8, 0x1EB8, &[0x2007]),
+    (0x1EB9, 0x1EB9, &[0x2007]),
+    (0x1EBA, 0x1EBA, &[0x2007]),
+    (0x1EBB, 0x1EBB, &[0x2007]),
+    (0x1EBC, 0x1EBC, &[0x2007]),
+    (0x1EBD, 0x1EBD, &[0x2007]),
+    (0x1EBE, 0x1EBE, &[0x2007]),
+    (0x1EBF, 0x1EBF, &[0x2007]),
+    (0x1EC0, 0x1EC0, &[0x2007]),
+    (0x1EC1, 0x1EC1, &[0x2007]),
+    (0x1EC2, 0x1EC2, &[0x2007]),
+    (0x1EC3, 0x1EC3, &[0x2007]),
+    (0x1EC4, 0x1EC4, &[0x2007]),
+    (0x1EC5, 0x1EC5, &[0x2007]),
+    (0x1EC6, 0x1EC6, &[0x2007]),
+    (0x1EC7, 0x1EC7, &[0x2007]),
+    (0x1EC8, 0x1EC8, &[0x2090]),
+    (0x1EC9, 0x1EC9, &[0x2090]),
+    (0x1ECA, 0x1ECA, &[0x2090]),
+    (0x1ECB, 0x1ECB, &[0x2090]),
+    (0x1ECC, 0x1ECC, &[0x213C]),
+    (0x1ECD, 0x1ECD, &[0x213C]),
+    (0x1ECE, 0x1ECE, &[0x213C]),
+    (0x1ECF, 0x1ECF, &[0x213C]),
+    (0x1ED0, 0x1ED0, &[0x213C]),
+    (0x1ED1, 0x1ED1, &[0x213C]),
+    (0x1ED2, 0x1ED2, &[0x213C]),
+    (0x1ED3, 0x1ED3, &[0x213C]),
+    (0x1ED4, 0x1ED4, &[0x213C]),
+    (0x1ED5, 0x1ED5, &[0x213C]),
+    (0x1ED6, 0x1ED6, &[0x213C]),
+    (0x1ED7, 0x1ED7, &[0x213C]),
+    (0x1ED8, 0x1ED8, &[0x213C]),
+    (0x1ED9, 0x1ED9, &[0x213C]),
+    (0x1EDA, 0x1EDA, &[0x213C]),
+    (0x1EDB, 0x1EDB, &[0x213C]),
+    (0x1EDC, 0x1EDC, &[0x213C]),
+    (0x1EDD, 0x1EDD, &[0x213C]),
+    (0x1EDE, 0x1EDE, &[0x213C]),
+    (0x1EDF, 0x1EDF, &[0x213C]),
+    (0x1EE0, 0x1EE0, &[0x213C]),
+    (0x1EE1, 0x1EE1, &[0x213C]),
+    (0x1EE2, 0x1EE2, &[0x213C]),
+    (0x1EE3, 0x1EE3, &[0x213C]),
+    (0x1EE4, 0x1EE4, &[0x2217]),
+    (0x1EE5, 0x1EE5, &[0x2217]),
+    (0x1EE6, 0x1EE6, &[0x2217]),
+    (0x1EE7, 0x1EE7, &[0x2217]),
+    (0x1EE8, 0x1EE8, &[0x2217]),
+    (0x1EE9, 0x1EE9, &[0x2217]),
+    (0x1EEA, 0x1EEA, &[0x2217]),
+    (0x1EEB, 0x1EEB, &[0x2217]),
+    (0x1EEC, 0x1EEC, &[0x2217]),
+    (0x1EED, 0x1EED, &[0x2217]),
+    (0x1EEE, 0x1EEE, &[0x2217]),
+    (0x1EEF, 0x1EEF, &[0x2217]),
+    (0x1EF0, 0x1EF0, &[0x2217]),
+    (0x1EF1, 0x1EF1, &[0x2217]),
+    (0x1EF2, 0x1EF2, &[0x2270]),
+    (0x1EF3, 0x1EF3, &[0x2270]),
+    (0x1EF4, 0x1EF4, &[0x2270]),
+    (0x1EF5, 0x1EF5, &[0x2270]),
+    (0x1EF6, 0x1EF6, &[0x2270]),
+    (0x1EF7, 0x1EF7, &[0x2270]),
+    (0x1EF8, 0x1EF8, &[0x2270]),
+    (0x1EF9, 0x1EF9, &[0x2270]),
+    (0x1EFA, 0x1EFA, &[0x20D6, 0x20D6]),
+    (0x1EFB, 0x1EFB, &[0x20D6, 0x20D6]),
+    (0x1EFC, 0x1EFC, &[0x2254]),
+    (0x1EFD, 0x1EFD, &[0x2254]),
+    (0x1EFE, 0x1EFE, &[0x2280]),
+    (0x1EFF, 0x1EFF, &[0x2280]),
+    (0x1F00, 0x1F00, &[0x231E]),
+    (0x1F01, 0x1F01, &[0x231E]),
+    (0x1F02, 0x1F02, &[0x231E]),
+    (0x1F03, 0x1F03, &[0x231E]),
+    (0x1F04, 0x1F04, &[0x231E]),
+    (0x1F05, 0x1F05, &[0x231E]),
+    (0x1F06, 0x1F06, &[0x231E]),
+    (0x1F07, 0x1F07, &[0x231E]),
+    (0x1F08, 0x1F08, &[0x231E]),
+    (0x1F09, 0x1F09, &[0x231E]),
+    (0x1F0A, 0x1F0A, &[0x231E]),
+    (0x1F0B, 0x1F0B, &[0x231E]),
+    (0x1F0C, 0x1F0C, &[0x231E]),
+    (0x1F0D, 0x1F0D, &[0x231E]),
+    (0x1F0E, 0x1F0E, &[0x231E]),
+    (0x1F0F, 0x1F0F, &[0x231E]),
+    (0x1F10, 0x1F10, &[0x2323]),
+    (0x1F11, 0x1F11, &[0x2323]),
+    (0x1F12, 0x1F12, &[0x2323]),
+    (0x1F13, 0x1F13, &[0x2323]),
+    (0x1F14, 0x1F14, &[0x2323]),
+    (0x1F15, 0x1F15, &[0x2323]),
+    (0x1F18, 0x1F18, &[0x2323]),
+    (0x1F19, 0x1F19, &[0x2323]),
+    (0x1F1A, 0x1F1A, &[0x2323]),
+    (0x1F1B, 0x1F1B, &[0x2323]),
+    (0x1F1C, 0x1F1C, &[0x2323]),
+    (0x1F1D, 0x1F1D, &[0x2323]),
+    (0x1F20, 0x1F20, &[0x2329]),
+    (0x1F21, 0x1F21, &[0x2329]),
+    (0x1F22, 0x1F22, &[0x2329]),
+    (0x1F23, 0x1F23, &[0x2329]),
+    (0x1F24, 0x1F24, &[0x2329]),
+    (0x1F25, 0x1F25, &[0x2329]),
+    (0x1F26, 0x1F26, &[0x2329]),
+    (0x1F27, 0x1F27, &[0x2329]),
+    (0x1F28, 0x1F28, &[0x2329]),
+    (0x1F29, 0x1F29, &[0x2329]),
+    (0x1F2A, 0x1F2A, &[0x2329]),
+    (0x1F2B, 0x1F2B, &[0x2329]),
+    (0x1F2C, 0x1F2C, &[0x2329]),
+    (0x1F2D, 0x1F2D, &[0x2329]),
+    (0x1F2E, 0x1F2E, &[0x2329]),
+    (0x1F2F, 0x1F2F, &[0x2329]),
+    (0x1F30, 0x1F30, &[0x232B]),
+    (0x1F31, 0x1F31, &[0x232B]),
+    (0x1F32, 0x1F32, &[0x232B]),
+    (0x1F33, 0x1F33, &[0x232B]),
+    (0x1F34, 0x1F34, &[0x232B]),
+    (0x1F35, 0x1F35, &[0x232B]),
+    (0x1F36, 0x1F36, &[0x232B]),
+    (0x1F37, 0x1F37, &[0x232B]),
+    (0x1F38, 0x1F38, &[0x232B]),
+    (0x1F39, 0x1F39, &[0x232B]),
+    (0x1F3A, 0x1F3A, &[0x232B]),
+    (0x1F3B, 0x1F3B, &[0x232B]),
+    (0x1F3C, 0x1F3C, &[0x232B]),
+    (0x1F3D, 0x1F3D, &[0x232B]),
+    (0x1F3E, 0x1F3E, &[0x232B]),
+    (0x1F3F, 0x1F3F, &[0x232B]),
+    (0x1F40, 0x1F40, &[0x2333]),
+    (0x1F41, 0x1F41, &[0x2333]),
+    (0x1F42, 0x1F42, &[0x2333]),
+    (0x1F43, 0x1F43, &[0x2333]),
+    (0x1F44, 0x1F44, &[0x2333]),
+    (0x1F45, 0x1F45, &[0x2333]),
+    (0x1F48, 0x1F48, &[0x2333]),
+    (0x1F49, 0x1F49, &[0x2333]),
+    (0x1F4A, 0x1F4A, &[0x2333]),
+    (0x1F4B, 0x1F4B, &[0x2333]),
+    (0x1F4C, 0x1F4C, &[0x2333]),
+    (0x1F4D, 0x1F4D, &[0x2333]),
+    (0x1F50, 0x1F50, &[0x2341]),
+    (0x1F51, 0x1F51, &[0x2341]),
+    (0x1F52, 0x1F52, &[0x2341]),
+    (0x1F53, 0x1F53, &[0x2341]),
+    (0x1F54, 0x1F54, &[0x2341]),
+    (0x1F55, 0x1F55, &[0x2341]),
+    (0x1F56, 0x1F56, &[0x2341]),
+    (0x1F57, 0x1F57, &[0x2341]),
+    (0x1F59, 0x1F59, &[0x2341]),
+    (0x1F5B, 0x1F5B, &[0x2341]),
+    (0x1F5D, 0x1F5D, &[0x2341]),
+    (0x1F5F, 0x1F5F, &[0x2341]),
+    (0x1F60, 0x1F60, &[0x2346]),
+    (0x1F61, 0x1F61, &[0x2346]),
+    (0x1F62, 0x1F62, &[0x2346]),
+    (0x1F63, 0x1F63, &[0x2346]),
+    (0x1F64, 0x1F64, &[0x2346]),
+    (0x1F65, 0x1F65, &[0x2346]),
+    (0x1F66, 0x1F66, &[0x2346]),
+    (0x1F67, 0x1F67, &[0x2346]),
+    (0x1F68, 0x1F68, &[0x2346]),
+    (0x1F69, 0x1F69, &[0x2346]),
+    (0x1F6A, 0x1F6A, &[0x2346]),
+    (0x1F6B, 0x1F6B, &[0x2346]),
+    (0x1F6C, 0x1F6C, &[0x2346]),
+    (0x1F6D, 0x1F6D, &[0x2346]),
+    (0x1F6E, 0x1F6E, &[0x2346]),
+    (0x1F6F, 0x1F6F, &[0x2346]),
+    (0x1F70, 0x1F70, &[0x231E]),
+    (0x1F71, 0x1F71, &[0x231E]),
+    (0x1F72, 0x1F72, &[0x2323]),
+    (0x1F73, 0x1F73, &[0x2323]),
+    (0x1F74, 0x1F74, &[0x2329]),
+    (0x1F75, 0x1F75, &[0x2329]),
+    (0x1F76, 0x1F76, &[0x232B]),
+    (0x1F77, 0x1F77, &[0x232B]),
+    (0x1F78, 0x1F78, &[0x2333]),
+    (0x1F79, 0x1F79, &[0x2333]),
+    (0x1F7A, 0x1F7A, &[0x2341]),
+    (0x1F7B, 0x1F7B, &[0x2341]),
+    (0x1F7C, 0x1F7C, &[0x2346]),
+    (0x1F7D, 0x1F7D, &[0x2346]),
+    (0x1F80, 0x1F80, &[0x231E]),
+    (0x1F81, 0x1F81, &[0x231E]),
+    (0x1F82, 0x1F82, &[0x231E]),
+    (0x1F83, 0x1F83, &[0x231E]),
+    (0x1F84, 0x1F84, &[0x231E]),
+    (0x1F85, 0x1F85, &[0x231E]),
+    (0x1F86, 0x1F86, &[0x231E]),
+    (0x1F87, 0x1F87, &[0x231E]),
+    (0x1F88, 0x1F88, &[0x231E]),
+    (0x1F89, 0x1F89, &[0x231E]),
+    (0x1F8A, 0x1F8A, &[0x231E]),
+    (0x1F8B, 0x1F8B, &[0x231E]),
+    (0x1F8C, 0x1F8C, &[0x231E]),
+    (0x1F8D, 0x1F8D, &[0x231E]),
+    (0x1F8E, 0x1F8E, &[0x231E]),
+    (0x1F8F, 0x1F8F, &[0x231E]),
+    (0x1F90, 0x1F90, &[0x2329]),
+    (0x1F91, 0x1F91, &[0x2329]),
+    (0x1F92, 0x1F92, &[0x2329]),
+    (0x1F93, 0x1F93, &[0x2329]),
+    (0x1F94, 0x1F94, &[0x2329]),
+    (0x1F95, 0x1F95, &[0x2329]),
+    (0x1F96, 0x1F96, &[0x2329]),
+    (0x1F97, 0x1F97, &[0x2329]),
+    (0x1F98, 0x1F98, &[0x2329]),
+    (0x1F99, 0x1F99, &[0x2329]),
+    (0x1F9A, 0x1F9A, &[0x2329]),
+    (0x1F9B, 0x1F9B, &[0x2329]),
+    (0x1F9C, 0x1F9C, &[0x2329]),
+    (0x1F9D, 0x1F9D, &[0x2329]),
+    (0x1F9E, 0x1F9E, &[0x2329]),
+    (0x1F9F, 0x1F9F, &[0x2329]),
+    (0x1FA0, 0x1FA0, &[0x2346]),
+    (0x1FA1, 0x1FA1, &[0x2346]),
+    (0x1FA2, 0x1FA2, &[0x2346]),
+    (0x1FA3, 0x1FA3, &[0x2346]),
+    (0x1FA4, 0x1FA4, &[0x2346]),
+    (0x1FA5, 0x1FA5, &[0x2346]),
+    (0x1FA6, 0x1FA6, &[0x2346]),
+    (0x1FA7, 0x1FA7, &[0x2346]),
+    (0x1FA8, 0x1FA8, &[0x2346]),
+    (0x1FA9, 0x1FA9, &[0x2346]),
+    (0x1FAA, 0x1FAA, &[0x2346]),
+    (0x1FAB, 0x1FAB, &[0x2346]),
+    (0x1FAC, 0x1FAC, &[0x2346]),
+    (0x1FAD, 0x1FAD, &[0x2346]),
+    (0x1FAE, 0x1FAE, &[0x2346]),
+    (0x1FAF, 0x1FAF, &[0x2346]),
+    (0x1FB0, 0x1FB0, &[0x231E]),
+    (0x1FB1, 0x1FB1, &[0x231E]),
+    (0x1FB2, 0x1FB2, &[0x231E]),
+    (0x1FB3, 0x1FB3, &[0x231E]),
+    (0x1FB4, 0x1FB4, &[0x231E]),
+    (0x1FB6, 0x1FB6, &[0x231E]),
+    (0x1FB7, 0x1FB7, &[0x231E]),
+    (0x1FB8, 0x1FB8, &[0x231E]),
+    (0x1FB9, 0x1FB9, &[0x231E]),
+    (0x1FBA, 0x1FBA, &[0x231E]),
+    (0x1FBB, 0x1FBB, &[0x231E]),
+    (0x1FBC, 0x1FBC, &[0x231E]),
+    (0x1FBD, 0x1FBD, &[0x04C0]),
+    (0x1FBE, 0x1FBE, &[0x232B]),
+    (0x1FBF, 0x1FBF, &[0x04C0]),
+    (0x1FC0, 0x1FC0, &[0x04C2]),
+    (0x1FC1, 0x1FC1, &[0x04BB]),
+    (0x1FC2, 0x1FC2, &[0x2329]),
+    (0x1FC3, 0x1FC3, &[0x2329]),
+    (0x1FC4, 0x1FC4, &[0x2329]),
+    (0x1FC6, 0x1FC6, &[0x2329]),
+    (0x1FC7, 0x1FC7, &[0x2329]),
+    (0x1FC8, 0x1FC8, &[0x2323]),
+    (0x1FC9, 0x1FC9, &[0x2323]),
+    (0x1FCA, 0x1FCA, &[0x2329]),
+    (0x1FCB, 0x1FCB, &[0x2329]),
+    (0x1FCC, 0x1FCC, &[0x2329]),
+    (0x1FCD, 0x1FCD, &[0x04C0]),
+    (0x1FCE, 0x1FCE, &[0x04C0]),
+    (0x1FCF, 0x1FCF, &[0x04C0]),
+    (0x1FD0, 0x1FD0, &[0x232B]),
+    (0x1FD1, 0x1FD1, &[0x232B]),
+    (0x1FD2, 0x1FD2, &[0x232B]),
+    (0x1FD3, 0x1FD3, &[0x232B]),
+    (0x1FD6, 0x1FD6, &[0x232B]),
+    (0x1FD7, 0x1FD7, &[0x232B]),
+    (0x1FD8, 0x1FD8, &[0x232B]),
+    (0x1FD9, 0x1FD9, &[0x232B]),
+    (0x1FDA, 0x1FDA, &[0x232B]),
+    (0x1FDB, 0x1FDB, &[0x232B]),
+    (0x1FDD, 0x1FDD, &[0x04C1]),
+    (0x1FDE, 0x1FDE, &[0x04C1]),
+    (0x1FDF, 0x1FDF, &[0x04C1]),
+    (0x1FE0, 0x1FE0, &[0x2341]),
+    (0x1FE1, 0x1FE1, &[0x2341]),
+    (0x1FE2, 0x1FE2, &[0x2341]),
+    (0x1FE3, 0x1FE3, &[0x2341]),
+    (0x1FE4, 0x1FE4, &[0x2339]),
+    (0x1FE5, 0x1FE5, &[0x2339]),
+    (0x1FE6, 0x1FE6, &[0x2341]),
+    (0x1FE7, 0x1FE7, &[0x2341]),
+    (0x1FE8, 0x1FE8, &[0x2341]),
+    (0x1FE9, 0x1FE9, &[0x2341]),
+    (0x1FEA, 0x1FEA, &[0x2341]),
+    (0x1FEB, 0x1FEB, &[0x2341]),
+    (0x1FEC, 0x1FEC, &[0x2339]),
+    (0x1FED, 0x1FED, &[0x04BB]),
+    (0x1FEE, 0x1FEE, &[0x04BB]),
+    (0x1FEF, 0x1FEF, &[0x04B4]),
+    (0x1FF2, 0x1FF2, &[0x2346]),
+    (0x1FF3, 0x1FF3, &[0x2346]),
+    (0x1FF4, 0x1FF4, &[0x2346]),
+    (0x1FF6, 0x1FF6, &[0x2346]),
+    (0x1FF7, 0x1FF7, &[0x2346]),
+    (0x1FF8, 0x1FF8, &[0x2333]),
+    (0x1FF9, 0x1FF9, &[0x2333]),
+    (0x1FFA, 0x1FFA, &[0x2346]),
+    (0x1FFB, 0x1FFB, &[0x2346]),
+    (0x1FFC, 0x1FFC, &[0x2346]),
+    (0x1FFD, 0x1FFD, &[0x04B5]),
+    (0x1FFE, 0x1FFE, &[0x04C1]),
+    (0x2000, 0x2000, &[0x0209]),
+    (0x2001, 0x2001, &[0x0209]),
+    (0x2002, 0x2002, &[0x0209]),
+    (0x2003, 0x2003, &[0x0209]),
+    (0x2004, 0x2004, &[0x0209]),
+    (0x2005, 0x2005, &[0x0209]),
+    (0x2006, 0x2006, &[0x0209]),
+    (0x2007, 0x2007, &[0x0209]),
+    (0x2008, 0x2008, &[0x0209]),
+    (0x2009, 0x2009, &[0x0209]),
+    (0x200A, 0x200A, &[0x0209]),
+    (0x200B, 0x200F, &[]),
+    (0x2010, 0x2010, &[0x0213]),
+    (0x2011, 0x2015, &[0x0213]),
+    (0x2016, 0x2016, &[0x0394]),
+    (0x2017, 0x2017, &[0x020C]),
+    (0x2018, 0x201B, &[0x0317]),
+    (0x201C, 0x201F, &[0x031E]),
+    (0x2020, 0x2021, &[0x03B3]),
+    (0x2022, 0x2023, &[0x03B9]),
+    (0x2024, 0x2024, &[0x027E]),
+    (0x2025, 0x2025, &[0x027E, 0x027E]),
+    (0x2026, 0x2026, &[0x027E, 0x027E, 0x027E]),
+    (0x2027, 0x2027, &[0x03BB]),
+    (0x2028, 0x2029, &[0x0207]),
+    (0x202A, 0x202E, &[]),
+    (0x202F, 0x202F, &[0x0209]),
+    (0x2030, 0x2030, &[0x03AF]),
+    (0x2031, 0x2031, &[0x03B1]),
+    (0x2032, 0x2032, &[0x03BF]),
+    (0x2033, 0x2033, &[0x03BF, 0x03BF]),
+    (0x2034, 0x2034, &[0x03BF, 0x03BF, 0x03BF]),
+    (0x2035, 0x2035, &[0x03C0]),
+    (0x2036, 0x2036, &[0x03C0, 0x03C0]),
+    (0x2037, 0x2037, &[0x03C0, 0x03C0, 0x03C0]),
+    (0x2038, 0x2038, &[0x03C3]),
+    (0x2039, 0x203A, &[0x031B]),
+    (0x203B, 0x203B, &[0x03C4]),
+    (0x203C, 0x203C, &[0x0267, 0x0267]),
+    (0x203D, 0x203D, &[0x027C]),
+    (0x203E, 0x203E, &[0x020A]),
+    (0x203F, 0x203F, &[0x03C5]),
+    (0x2040, 0x2040, &[0x03C7]),
+    (0x2041, 0x2042, &[0x03C9]),
+    (0x2043, 0x2043, &[0x03BC]),
+    (0x2044, 0x2044, &[0x0676]),
+    (0x2045, 0x2046, &[0x0334]),
+    (0x2047, 0x2047, &[0x026D, 0x026D]),
+    (0x2048, 0x2048, &[0x026D, 0x0267]),
+    (0x2049, 0x2049, &[0x0267, 0x026D]),
+    (0x204A, 0x204A, &[0x03AA]),
+    (0x204B, 0x204B, &[0x039D]),
+    (0x204C, 0x204D, &[0x03BD]),
+    (0x204E, 0x204E, &[0x03A2]),
+    (0x204F, 0x204F, &[0x023C]),
+    (0x2050, 0x2050, &[0x03C8]),
+    (0x2051, 0x2051, &[0x03A3]),
+    (0x2052, 0x2052, &[0x0672]),
+    (0x2053, 0x2053, &[0x021A]),
+    (0x2054, 0x2054, &[0x03C6]),
+    (0x2055, 0x2056, &[0x02F9]),
+    (0x2057, 0x2057, &[0x03BF, 0x03BF, 0x03BF, 0x03BF]),
+    (0x2058, 0x205E, &[0x02FB]),
+    (0x205F, 0x205F, &[0x0209]),
+    (0x2060, 0x2064, &[]),
+    (0x2066, 0x206F, &[]),
+    (0x2070, 0x2070, &[0x1F98]),
+    (0x2071, 0x2071, &[0x2090]),
+    (0x2074, 0x2079, &[0x1F9C]),
+    (0x207A, 0x207A, &[0x0666]),
+    (0x207B, 0x207B, &[0x0671]),
+    (0x207C, 0x207C, &[0x066B]),
+    (0x207D, 0x207E, &[0x0328]),
+    (0x207F, 0x207F, &[0x2118]),
+    (0x2080, 0x2089, &[0x1F98]),
+    (0x208A, 0x208A, &[0x0666]),
+    (0x208B, 0x208B, &[0x0671]),
+    (0x208C, 0x208C, &[0x066B]),
+    (0x208D, 0x208E, &[0x0328]),
+    (0x2090, 0x2090, &[0x1FA2]),
+    (0x2091, 0x2091, &[0x2007]),
+    (0x2092, 0x2092, &[0x213C]),
+    (0x2093, 0x2093, &[0x2264]),
+    (0x2094, 0x2094, &[0x201A]),
+    (0x2095, 0x2095, &[0x2075]),
+    (0x2096, 0x2096, &[0x20C4]),
+    (0x2097, 0x2097, &[0x20D6]),
+    (0x2098, 0x2098, &[0x2109]),
+    (0x2099, 0x2099, &[0x2118]),
+    (0x209A, 0x209A, &[0x216B]),
+    (0x209B, 0x209B, &[0x21D2]),
+    (0x209C, 0x209C, &[0x21F7]),
+    (0x20A0, 0x20A7, &[0x1F78]),
+    (0x20A8, 0x20A8, &[0x2193, 0x21D2]),
+    (0x20A9, 0x20B9, &[0x1F80]),
+    (0x20BA, 0x20BF, &[0x1F92]),
+    (0x20D0, 0x20F0, &[]),
+    (0x2100, 0x2100, &[0x1FA2, 0x03A6, 0x1FD6]),
+    (0x2101, 0x2101, &[0x1FA2, 0x03A6, 0x21D2]),
+    (0x2102, 0x2102, &[0x1FD6]),
+    (0x2103, 0x2103, &[0x052A, 0x1FD6]),
+    (0x2104, 0x2104, &[0x05D6]),
+    (0x2105, 0x2105, &[0x1FD6, 0x03A6, 0x213C]),
+    (0x2106, 0x2106, &[0x1FD6, 0x03A6, 0x2217]),
+    (0x2107, 0x2107, &[0x201F]),
+    (0x2108, 0x2108, &[0x05D7]),
+    (0x2109, 0x2109, &[0x052A, 0x2042]),
+    (0x210A, 0x210A, &[0x2051]),
+    (0x210B, 0x210B, &[0x2075]),
+    (0x210C, 0x210C, &[0x2075]),
+    (0x210D, 0x210D, &[0x2075]),
+    (0x210E, 0x210E, &[0x2075]),
+    (0x210F, 0x210F, &[0x2075]),
+    (0x2110, 0x2110, &[0x2090]),
+    (0x2111, 0x2111, &[0x2090]),
+    (0x2112, 0x2112, &[0x20D6]),
+    (0x2113, 0x2113, &[0x20D6]),
+    (0x2114, 0x2114, &[0x05D8]),
+    (0x2115, 0x2115, &[0x2118]),
+    (0x2116, 0x2116, &[0x2118, 0x213C]),
+    (0x2117, 0x2118, &[0x05D9]),
+    (0x2119, 0x2119, &[0x216B]),
+    (0x211A, 0x211A, &[0x2180]),
+    (0x211B, 0x211B, &[0x2193]),
+    (0x211C, 0x211C, &[0x2193]),
+    (0x211D, 0x211D, &[0x2193]),
+    (0x211E, 0x211F, &[0x05DB]),
+    (0x2120, 0x2120, &[0x21D2, 0x2109]),
+    (0x2121, 0x2121, &[0x21F7, 0x2007, 0x20D6]),
+    (0x2122, 0x2122, &[0x21F7, 0x2109]),
+    (0x2123, 0x2123, &[0x05DD]),
+    (0x2124, 0x2124, &[0x2286]),
+    (0x2125, 0x2125, &[0x05DE]),
+    (0x2126, 0x2126, &[0x2346]),
+    (0x2127, 0x2127, &[0x05DF]),
+    (0x2128, 0x2128, &[0x2286]),
+    (0x2129, 0x2129, &[0x05E0]),
+    (0x212A, 0x212A, &[0x20C4]),
+    (0x212B, 0x212B, &[0x1FA2]),
+    (0x212C, 0x212C, &[0x1FBC]),
+    (0x212D, 0x212D, &[0x1FD6]),
+    (0x212E, 0x212E, &[0x05E1]),
+    (0x212F, 0x212F, &[0x2007]),
+    (0x2130, 0x2130, &[0x2007]),
+    (0x2131, 0x2131, &[0x2042]),
+    (0x2132, 0x2132, &[0x204F]),
+    (0x2133, 0x2133, &[0x2109]),
+    (0x2134, 0x2134, &[0x213C]),
+    (0x2135, 0x2138, &[0x261E]),
+    (0x2139, 0x2139, &[0x2090]),
+    (0x213A, 0x213A, &[0x05E2]),
+    (0x213B, 0x213B, &[0x2042, 0x1FA2, 0x2264]),
+    (0x213C, 0x213C, &[0x2334]),
+    (0x213D, 0x213D, &[0x2320]),
+    (0x213E, 0x213E, &[0x2320]),
+    (0x213F, 0x213F, &[0x2334]),
+    (0x2140, 0x2140, &[0x0665]),
+    (0x2141, 0x2144, &[0x05E3]),
+    (0x2145, 0x2145, &[0x1FEB]),
+    (0x2146, 0x2146, &[0x1FEB]),
+    (0x2147, 0x2147, &[0x2007]),
+    (0x2148, 0x2148, &[0x2090]),
+    (0x2149, 0x2149, &[0x20AB]),
+    (0x214A, 0x214A, &[0x05E7]),
+    (0x214B, 0x214B, &[0x0701]),
+    (0x214C, 0x214C, &[0x05E8]),
+    (0x214D, 0x214D, &[0x1FA2, 0x03A6, 0x21D2]),
+    (0x214E, 0x214E, &[0x204F]),
+    (0x214F, 0x214F, &[0x05E9]),
+    (0x2150, 0x2150, &[0x1F99, 0x0676, 0x1F9F]),
+    (0x2151, 0x2151, &[0x1F99, 0x0676, 0x1FA1]),
+    (0x2152, 0x2152, &[0x1F99, 0x0676, 0x1F99, 0x1F98]),
+    (0x2153, 0x2153, &[0x1F99, 0x0676, 0x1F9B]),
+    (0x2154, 0x2154, &[0x1F9A, 0x0676, 0x1F9B]),
+    (0x2155, 0x2155, &[0x1F99, 0x0676, 0x1F9D]),
+    (0x2156, 0x2156, &[0x1F9A, 0x0676, 0x1F9D]),
+    (0x2157, 0x2157, &[0x1F9B, 0x0676, 0x1F9D]),
+    (0x2158, 0x2158, &[0x1F9C, 0x0676, 0x1F9D]),
+    (0x2159, 0x2159, &[0x1F99, 0x0676, 0x1F9E]),
+    (0x215A, 0x215A, &[0x1F9D, 0x0676, 0x1F9E]),
+    (0x215B, 0x215B, &[0x1F99, 0x0676, 0x1FA0]),
+    (0x215C, 0x215C, &[0x1F9B, 0x0676, 0x1FA0]),
+    (0x215D, 0x215D, &[0x1F9D, 0x0676, 0x1FA0]),
+    (0x215E, 0x215E, &[0x1F9F, 0x0676, 0x1FA0]),
+    (0x215F, 0x215F, &[0x1F99, 0x0676]),
+    (0x2160, 0x2160, &[0x2090]),
+    (0x2161, 0x2161, &[0x2090, 0x2090]),
+    (0x2162, 0x2162, &[0x2090, 0x2090, 0x2090]),
+    (0x2163, 0x2163, &[0x2090, 0x2247]),
+    (0x2164, 0x2164, &[0x2247]),
+    (0x2165, 0x2165, &[0x2247, 0x2090]),
+    (0x2166, 0x2166, &[0x2247, 0x2090, 0x2090]),
+    (0x2167, 0x2167, &[0x2247, 0x2090, 0x2090, 0x2090]),
+    (0x2168, 0x2168, &[0x2090, 0x2264]),
+    (0x2169, 0x2169, &[0x2264]),
+    (0x216A, 0x216A, &[0x2264, 0x2090]),
+    (0x216B, 0x216B, &[0x2264, 0x2090, 0x2090]),
+    (0x216C, 0x216C, &[0x20D6]),
+    (0x216D, 0x216D, &[0x1FD6]),
+    (0x216E, 0x216E, &[0x1FEB]),
+    (0x216F, 0x216F, &[0x2109]),
+    (0x2170, 0x2170, &[0x2090]),
+    (0x2171, 0x2171, &[0x2090, 0x2090]),
+    (0x2172, 0x2172, &[0x2090, 0x2090, 0x2090]),
+    (0x2173, 0x2173, &[0x2090, 0x2247]),
+    (0x2174, 0x2174, &[0x2247]),
+    (0x2175, 0x2175, &[0x2247, 0x2090]),
+    (0x2176, 0x2176, &[0x2247, 0x2090, 0x2090]),
+    (0x2177, 0x2177, &[0x2247, 0x2090, 0x2090, 0x2090]),
+    (0x2178, 0x2178, &[0x2090, 0x2264]),
+    (0x2179, 0x2179, &[0x2264]),
+    (0x217A, 0x217A, &[0x2264, 0x2090]),
+    (0x217B, 0x217B, &[0x2264, 0x2090, 0x2090]),
+    (0x217C, 0x217C, &[0x20D6]),
+    (0x217D, 0x217D, &[0x1FD6]),
+    (0x217E, 0x217E, &[0x1FEB]),
+    (0x217F, 0x217F, &[0x2109]),
+    (0x2180, 0x2182, &[0x1D9B]),
+    (0x2183, 0x2183, &[0x1FE9]),
+    (0x2184, 0x2184, &[0x1FE9]),
+    (0x2185, 0x2185, &[0x1F9E]),
+    (0x2186, 0x2188, &[0x1D9E]),
+    (0x2189, 0x2189, &[0x1F98, 0x0676, 0x1F9B]),
+    (0x218A, 0x218B, &[0x05EA]),
+    (0x2190, 0x2190, &[0x05EC]),
+    (0x2191, 0x2191, &[0x05EE]),
+    (0x2192, 0x2192, &[0x05ED]),
+    (0x2193, 0x2199, &[0x05EF]),
+    (0x219A, 0x219B, &[0x05EC]),
+    (0x219C, 0x21AD, &[0x05F6]),
+    (0x21AE, 0x21AE, &[0x05F0]),
+    (0x21AF, 0x21CD, &[0x0608]),
+    (0x21CE, 0x21CE, &[0x062A]),
+    (0x21CF, 0x21CF, &[0x0628]),
+    (0x21D0, 0x2203, &[0x0626]),
+    (0x2204, 0x2208, &[0x0659]),
+    (0x2209, 0x220B, &[0x065D]),
+    (0x220C, 0x220D, &[0x065F]),
+    (0x220E, 0x2211, &[0x0662]),
+    (0x2212, 0x2212, &[0x0671]),
+    (0x2213, 0x2215, &[0x0673]),
+    (0x2216, 0x221B, &[0x0677]),
+    (0x221C, 0x221C, &[0x067E]),
+    (0x221D, 0x2223, &[0x0680]),
+    (0x2224, 0x2225, &[0x0686]),
+    (0x2226, 0x222B, &[0x0687]),
+    (0x222C, 0x222C, &[0x068C, 0x068C]),
+    (0x222D, 0x222D, &[0x068C, 0x068C, 0x068C]),
+    (0x222E, 0x222E, &[0x068D]),
+    (0x222F, 0x222F, &[0x068D, 0x068D]),
+    (0x2230, 0x2230, &[0x068D, 0x068D, 0x068D]),
+    (0x2231, 0x2240, &[0x068E]),
+    (0x2241, 0x2241, &[0x0699]),
+    (0x2242, 0x2243, &[0x069E]),
+    (0x2244, 0x2246, &[0x069F]),
+    (0x2247, 0x2247, &[0x06A0]),
+    (0x2248, 0x2248, &[0x06A2]),
+    (0x2249, 0x225F, &[0x06A2]),
+    (0x2260, 0x2260, &[0x066B]),
+    (0x2261, 0x2261, &[0x06B9]),
+    (0x2262, 0x226C, &[0x06B9]),
+    (0x226D, 0x226D, &[0x06A6]),
+    (0x226E, 0x226E, &[0x066A]),
+    (0x226F, 0x226F, &[0x066C]),
+    (0x2270, 0x2271, &[0x06BB]),
+    (0x2272, 0x2273, &[0x06C4]),
+    (0x2274, 0x2277, &[0x06C4]),
+    (0x2278, 0x227F, &[0x06C6]),
+    (0x2280, 0x2281, &[0x06C8]),
+    (0x2282, 0x2283, &[0x06CE]),
+    (0x2284, 0x2287, &[0x06CE]),
+    (0x2288, 0x22AB, &[0x06D0]),
+    (0x22AC, 0x22AC, &[0x06EA]),
+    (0x22AD, 0x22AE, &[0x06F0]),
+    (0x22AF, 0x22BC, &[0x06F3]),
+    (0x22BD, 0x22DF, &[0x0702]),
+    (0x22E0, 0x22E1, &[0x06CA]),
+    (0x22E2, 0x22E3, &[0x06D9]),
+    (0x22E4, 0x22E9, &[0x0725]),
+    (0x22EA, 0x22ED, &[0x06F6]),
+    (0x22EE, 0x2307, &[0x072B]),
+    (0x2308, 0x230B, &[0x0336]),
+    (0x230C, 0x2328, &[0x0745]),
+    (0x2329, 0x232A, &[0x0380]),
+    (0x232B, 0x2426, &[0x0762]),
+    (0x2440, 0x244A, &[0x085E]),
+    (0x2460, 0x2468, &[0x1F99]),
+    (0x2469, 0x2469, &[0x1F99, 0x1F98]),
+    (0x246A, 0x246A, &[0x1F99, 0x1F99]),
+    (0x246B, 0x246B, &[0x1F99, 0x1F9A]),
+    (0x246C, 0x246C, &[0x1F99, 0x1F9B]),
+    (0x246D, 0x246D, &[0x1F99, 0x1F9C]),
+    (0x246E, 0x246E, &[0x1F99, 0x1F9D]),
+    (0x246F, 0x246F, &[0x1F99, 0x1F9E]),
+    (0x2470, 0x2470, &[0x1F99, 0x1F9F]),
+    (0x2471, 0x2471, &[0x1F99, 0x1FA0]),
+    (0x2472, 0x2472, &[0x1F99, 0x1FA1]),
+    (0x2473, 0x2473, &[0x1F9A, 0x1F98]),
+    (0x2474, 0x2474, &[0x0328, 0x1F99, 0x0329]),
+    (0x2475, 0x2475, &[0x0328, 0x1F9A, 0x0329]),
+    (0x2476, 0x2476, &[0x0328, 0x1F9B, 0x0329]),
+    (0x2477, 0x2477, &[0x0328, 0x1F9C, 0x0329]),
+    (0x2478, 0x2478, &[0x0328, 0x1F9D, 0x0329]),
+    (0x2479, 0x2479, &[0x0328, 0x1F9E, 0x0329]),
+    (0x247A, 0x247A, &[0x0328, 0x1F9F, 0x0329]),
+    (0x247B, 0x247B, &[0x0328, 0x1FA0, 0x0329]),
+    (0x247C, 0x247C, &[0x0328, 0x1FA1, 0x0329]),
+    (0x247D, 0x247D, &[0x0328, 0x1F99, 0x1F98, 0x0329]),
+    (0x247E, 0x247E, &[0x0328, 0x1F99, 0x1F99, 0x0329]),
+    (0x247F, 0x247F, &[0x0328, 0x1F99, 0x1F9A, 0x0329]),
+    (0x2480, 0x2480, &[0x0328, 0x1F99, 0x1F9B, 0x0329]),
+    (0x2481, 0x2481, &[0x0328, 0x1F99, 0x1F9C, 0x0329]),
+    (0x2482, 0x2482, &[0x0328, 0x1F99, 0x1F9D, 0x0329]),
+    (0x2483, 0x2483, &[0x0328, 0x1F99, 0x1F9E, 0x0329]),
+    (0x2484, 0x2484, &[0x0328, 0x1F99, 0x1F9F, 0x0329]),
+    (0x2485, 0x2485, &[0x0328, 0x1F99, 0x1FA0, 0x0329]),
+    (0x2486, 0x2486, &[0x0328, 0x1F99, 0x1FA1, 0x0329]),
+    (0x2487, 0x2487, &[0x0328, 0x1F9A, 0x1F98, 0x0329]),
+    (0x2488, 0x2488, &[0x1F99, 0x027E]),
+    (0x2489, 0x2489, &[0x1F9A, 0x027E]),
+    (0x248A, 0x248A, &[0x1F9B, 0x027E]),
+    (0x248B, 0x248B, &[0x1F9C, 0x027E]),
+    (0x248C, 0x248C, &[0x1F9D, 0x027E]),
+    (0x248D, 0x248D, &[0x1F9E, 0x027E]),
+    (0x248E, 0x248E, &[0x1F9F, 0x027E]),
+    (0x248F, 0x248F, &[0x1FA0, 0x027E]),
+    (0x2490, 0x2490, &[0x1FA1, 0x027E]),
+    (0x2491, 0x2491, &[0x1F99, 0x1F98, 0x027E]),
+    (0x2492, 0x2492, &[0x1F99, 0x1F99, 0x027E]),
+    (0x2493, 0x2493, &[0x1F99, 0x1F9A, 0x027E]),
+    (0x2494, 0x2494, &[0x1F99, 0x1F9B, 0x027E]),
+    (0x2495, 0x2495, &[0x1F99, 0x1F9C, 0x027E]),
+    (0x2496, 0x2496, &[0x1F99, 0x1F9D, 0x027E]),
+    (0x2497, 0x2497, &[0x1F99, 0x1F9E, 0x027E]),
+    (0x2498, 0x2498, &[0x1F99, 0x1F9F, 0x027E]),
+    (0x2499, 0x2499, &[0x1F99, 0x1FA0, 0x027E]),
+    (0x249A, 0x249A, &[0x1F99, 0x1FA1, 0x027E]),
+    (0x249B, 0x249B, &[0x1F9A, 0x1F98, 0x027E]),
+    (0x249C, 0x249C, &[0x0328, 0x1FA2, 0x0329]),
+    (0x249D, 0x249D, &[0x0328, 0x1FBC, 0x0329]),
+    (0x249E, 0x249E, &[0x0328, 0x1FD6, 0x0329]),
+    (0x249F, 0x249F, &[0x0328, 0x1FEB, 0x0329]),
+    (0x24A0, 0x24A0, &[0x0328, 0x2007, 0x0329]),
+    (0x24A1, 0x24A1, &[0x0328, 0x2042, 0x0329]),
+    (0x24A2, 0x24A2, &[0x0328, 0x2051, 0x0329]),
+    (0x24A3, 0x24A3, &[0x0328, 0x2075, 0x0329]),
+    (0x24A4, 0x24A4, &[0x0328, 0x2090, 0x0329]),
+    (0x24A5, 0x24A5, &[0x0328, 0x20AB, 0x0329]),
+    (0x24A6, 0x24A6, &[0x0328, 0x20C4, 0x0329]),
+    (0x24A7, 0x24A7, &[0x0328, 0x20D6, 0x0329]),
+    (0x24A8, 0x24A8, &[0x0328, 0x2109, 0x0329]),
+    (0x24A9, 0x24A9, &[0x0328, 0x2118, 0x0329]),
+    (0x24AA, 0x24AA, &[0x0328, 0x213C, 0x0329]),
+    (0x24AB, 0x24AB, &[0x0328, 0x216B, 0x0329]),
+    (0x24AC, 0x24AC, &[0x0328, 0x2180, 0x0329]),
+    (0x24AD, 0x24AD, &[0x0328, 0x2193, 0x0329]),
+    (0x24AE, 0x24AE, &[0x0328, 0x21D2, 0x0329]),
+    (0x24AF, 0x24AF, &[0x0328, 0x21F7, 0x0329]),
+    (0x24B0, 0x24B0, &[0x0328, 0x2217, 0x0329]),
+    (0x24B1, 0x24B1, &[0x0328, 0x2247, 0x0329]),
+    (0x24B2, 0x24B2, &[0x0328, 0x2259, 0x0329]),
+    (0x24B3, 0x24B3, &[0x0328, 0x2264, 0x0329]),
+    (0x24B4, 0x24B4, &[0x0328, 0x2270, 0x0329]),
+    (0x24B5, 0x24B5, &[0x0328, 0x2286, 0x0329]),
+    (0x24B6, 0x24B6, &[0x1FA2]),
+    (0x24B7, 0x24B7, &[0x1FBC]),
+    (0x24B8, 0x24B8, &[0x1FD6]),
+    (0x24B9, 0x24B9, &[0x1FEB]),
+    (0x24BA, 0x24BA, &[0x2007]),
+    (0x24BB, 0x24BB, &[0x2042]),
+    (0x24BC, 0x24BC, &[0x2051]),
+    (0x24BD, 0x24BD, &[0x2075]),
+    (0x24BE, 0x24BE, &[0x2090]),
+    (0x24BF, 0x24BF, &[0x20AB]),
+    (0x24C0, 0x24C0, &[0x20C4]),
+    (0x24C1, 0x24C1, &[0x20D6]),
+    (0x24C2, 0x24C2, &[0x2109]),
+    (0x24C3, 0x24C3, &[0x2118]),
+    (0x24C4, 0x24C4, &[0x213C]),
+    (0x24C5, 0x24C5, &[0x216B]),
+    (0x24C6, 0x24C6, &[0x2180]),
+    (0x24C7, 0x24C7, &[0x2193]),
+    (0x24C8, 0x24C8, &[0x21D2]),
+    (0x24C9, 0x24C9, &[0x21F7]),
+    (0x24CA, 0x24CA, &[0x2217]),
+    (0x24CB, 0x24CB, &[0x2247]),
+    (0x24CC, 0x24CC, &[0x2259]),
+    (0x24CD, 0x24CD, &[0x2264]),
+    (0x24CE, 0x24CE, &[0x2270]),
+    (0x24CF, 0x24CF, &[0x2286]),
+    (0x24D0, 0x24D0, &[0x1FA2]),
+    (0x24D1, 0x24D1, &[0x1FBC]),
+    (0x24D2, 0x24D2, &[0x1FD6]),
+    (0x24D3, 0x24D3, &[0x1FEB]),
+    (0x24D4, 0x24D4, &[0x2007]),
+    (0x24D5, 0x24D5, &[0x2042]),
+    (0x24D6, 0x24D6, &[0x2051]),
+    (0x24D7, 0x24D7, &[0x2075]),
+    (0x24D8, 0x24D8, &[0x2090]),
+    (0x24D9, 0x24D9, &[0x20AB]),
+    (0x24DA, 0x24DA, &[0x20C4]),
+    (0x24DB, 0x24DB, &[0x20D6]),
+    (0x24DC, 0x24DC, &[0x2109]),
+    (0x24DD, 0x24DD, &[0x2118]),
+    (0x24DE, 0x24DE, &[0x213C]),
+    (0x24DF, 0x24DF, &[0x216B]),
+    (0x24E0, 0x24E0, &[0x2180]),
+    (0x24E1, 0x24E1, &[0x2193]),
+    (0x24E2, 0x24E2, &[0x21D2]),
+    (0x24E3, 0x24E3, &[0x21F7]),
+    (0x24E4, 0x24E4, &[0x2217]),
+    (0x24E5, 0x24E5, &[0x2247]),
+    (0x24E6, 0x24E6, &[0x2259]),
+    (0x24E7, 0x24E7, &[0x2264]),
+    (0x24E8, 0x24E8, &[0x2270]),
+    (0x24E9, 0x24E9, &[0x2286]),
+    (0x24EA, 0x24EA, &[0x1F98]),
+    (0x24EB, 0x24EB, &[0x1F99, 0x1F99]),
+    (0x24EC, 0x24EC, &[0x1F99, 0x1F9A]),
+    (0x24ED, 0x24ED, &[0x1F99, 0x1F9B]),
+    (0x24EE, 0x24EE, &[0x1F99, 0x1F9C]),
+    (0x24EF, 0x24EF, &[0x1F99, 0x1F9D]),
+    (0x24F0, 0x24F0, &[0x1F99, 0x1F9E]),
+    (0x24F1, 0x24F1, &[0x1F99, 0x1F9F]),
+    (0x24F2, 0x24F2, &[0x1F99, 0x1FA0]),
+    (0x24F3, 0x24F3, &[0x1F99, 0x1FA1]),
+    (0x24F4, 0x24F4, &[0x1F9A, 0x1F98]),
+    (0x24F5, 0x24FD, &[0x1F99]),
+    (0x24FE, 0x24FE, &[0x1F99, 0x1F98]),
+    (0x24FF, 0x24FF, &[0x1F98]),
+    (0x2500, 0x25FF, &[0x0869]),
+    (0x2600, 0x262F, &[0x0A33]),
+    (0x2630, 0x2637, &[0x0FEE]),
+    (0x2638, 0x266C, &[0x0A63]),
+    (0x266D, 0x266F, &[0x1241]),
+    (0x2670, 0x2689, &[0x0A98]),
+    (0x268A, 0x268F, &[0x0FE8]),
+    (0x2690, 0x26FF, &[0x0AB2]),
+    (0x2700, 0x2720, &[0x0B3C]),
+    (0x2721, 0x2767, &[0x0B5F]),
+    (0x2768, 0x2775, &[0x035E]),
+    (0x2776, 0x277E, &[0x1F99]),
+    (0x277F, 0x277F, &[0x1F99, 0x1F98]),
+    (0x2780, 0x2788, &[0x1F99]),
+    (0x2789, 0x2789, &[0x1F99, 0x1F98]),
+    (0x278A, 0x2792, &[0x1F99]),
+    (0x2793, 0x2793, &[0x1F99, 0x1F98]),
+    (0x2794, 0x27C4, &[0x0BA6]),
+    (0x27C5, 0x27C6, &[0x0352]),
+    (0x27C7, 0x27E5, &[0x0BD7]),
+    (0x27E6, 0x27EF, &[0x0354]),
+    (0x27F0, 0x27FF, &[0x0BF6]),
+    (0x2800, 0x28FF, &[0x0EE8]),
+    (0x2900, 0x2982, &[0x0C06]),
+    (0x2983, 0x2998, &[0x033C]),
+    (0x2999, 0x29D7, &[0x0C89]),
+    (0x29D8, 0x29DB, &[0x0396]),
+    (0x29DC, 0x29FB, &[0x0CC8]),
+    (0x29FC, 0x29FD, &[0x033A]),
+    (0x29FE, 0x2A0B, &[0x0CE8]),
+    (0x2A0C, 0x2A0C, &[0x068C, 0x068C, 0x068C, 0x068C]),
+    (0x2A0D, 0x2A73, &[0x0CF6]),
+    (0x2A74, 0x2A74, &[0x0240, 0x0240, 0x066B]),
+    (0x2A75, 0x2A75, &[0x066B, 0x066B]),
+    (0x2A76, 0x2A76, &[0x066B, 0x066B, 0x066B]),
+    (0x2A77, 0x2ADC, &[0x0D5D]),
+    (0x2ADD, 0x2B73, &[0x0DC2]),
+    (0x2B76, 0x2B95, &[0x0E59]),
+    (0x2B97, 0x2BFF, &[0x0E79]),
+    (0x2C00, 0x2C2E, &[0x254A]),
+    (0x2C30, 0x2C5E, &[0x254A]),
+    (0x2C60, 0x2C60, &[0x20E5]),
+    (0x2C61, 0x2C62, &[0x20E5]),
+    (0x2C63, 0x2C63, &[0x2170]),
+    (0x2C64, 0x2C64, &[0x21B7]),
+    (0x2C65, 0x2C65, &[0x1FA7]),
+    (0x2C66, 0x2C66, &[0x2200]),
+    (0x2C67, 0x2C67, &[0x2086]),
+    (0x2C68, 0x2C68, &[0x2086]),
+    (0x2C69, 0x2C69, &[0x20CE]),
+    (0x2C6A, 0x2C6A, &[0x20CE]),
+    (0x2C6B, 0x2C6B, &[0x22A1]),
+    (0x2C6C, 0x2C6C, &[0x22A1]),
+    (0x2C6D, 0x2C6D, &[0x1FB1]),
+    (0x2C6E, 0x2C6E, &[0x2110]),
+    (0x2C6F, 0x2C6F, &[0x1FAD]),
+    (0x2C70, 0x2C70, &[0x1FB7]),
+    (0x2C71, 0x2C71, &[0x2252]),
+    (0x2C72, 0x2C72, &[0x225F]),
+    (0x2C73, 0x2C73, &[0x225F]),
+    (0x2C74, 0x2C74, &[0x2253]),
+    (0x2C75, 0x2C75, &[0x2087]),
+    (0x2C76, 0x2C76, &[0x2087]),
+    (0x2C77, 0x2C77, &[0x217F]),
+    (0x2C78, 0x2C78, &[0x2014]),
+    (0x2C79, 0x2C79, &[0x21B2]),
+    (0x2C7A, 0x2C7A, &[0x215B]),
+    (0x2C7B, 0x2C7B, &[0x2019]),
+    (0x2C7C, 0x2C7C, &[0x20AB]),
+    (0x2C7D, 0x2C7D, &[0x2247]),
+    (0x2C7E, 0x2C7E, &[0x21DE]),
+    (0x2C7F, 0x2C7F, &[0x229D]),
+    (0x2C80, 0x2C80, &[0x234B]),
+    (0x2C81, 0x2C82, &[0x234B]),
+    (0x2C83, 0x2C84, &[0x234C]),
+    (0x2C85, 0x2C86, &[0x234D]),
+    (0x2C87, 0x2C88, &[0x234E]),
+    (0x2C89, 0x2C89, &[0x234F]),
+    (0x2C8A, 0x2C8A, &[0x2351]),
+    (0x2C8B, 0x2C8C, &[0x2351]),
+    (0x2C8D, 0x2C8E, &[0x2352]),
+    (0x2C8F, 0x2C90, &[0x2353]),
+    (0x2C91, 0x2C92, &[0x2354]),
+    (0x2C93, 0x2C94, &[0x2355]),
+    (0x2C95, 0x2C95, &[0x2356]),
+    (0x2C96, 0x2C96, &[0x2358]),
+    (0x2C97, 0x2C98, &[0x2358]),
+    (0x2C99, 0x2C9A, &[0x2359]),
+    (0x2C9B, 0x2C9B, &[0x235A]),
+    (0x2C9C, 0x2C9C, &[0x235D]),
+    (0x2C9D, 0x2C9E, &[0x235D]),
+    (0x2C9F, 0x2CA0, &[0x235E]),
+    (0x2CA1, 0x2CA2, &[0x235F]),
+    (0x2CA3, 0x2CA4, &[0x2360]),
+    (0x2CA5, 0x2CA6, &[0x2361]),
+    (0x2CA7, 0x2CA8, &[0x2362]),
+    (0x2CA9, 0x2CAA, &[0x2363]),
+    (0x2CAB, 0x2CAC, &[0x2364]),
+    (0x2CAD, 0x2CAE, &[0x2365]),
+    (0x2CAF, 0x2CB0, &[0x2366]),
+    (0x2CB1, 0x2CB1, &[0x2367]),
+    (0x2CB2, 0x2CB2, &[0x2382]),
+    (0x2CB3, 0x2CB4, &[0x2382]),
+    (0x2CB5, 0x2CB5, &[0x2383]),
+    (0x2CB6, 0x2CB6, &[0x2350]),
+    (0x2CB7, 0x2CB7, &[0x2350]),
+    (0x2CB8, 0x2CB8, &[0x2357]),
+    (0x2CB9, 0x2CB9, &[0x2357]),
+    (0x2CBA, 0x2CBA, &[0x235B]),
+    (0x2CBB, 0x2CBC, &[0x235B]),
+    (0x2CBD, 0x2CBD, &[0x235C]),
+    (0x2CBE, 0x2CBE, &[0x2368]),
+    (0x2CBF, 0x2CC0, &[0x2368]),
+    (0x2CC1, 0x2CC1, &[0x2369]),
+    (0x2CC2, 0x2CC2, &[0x236C]),
+    (0x2CC3, 0x2CC4, &[0x236C]),
+    (0x2CC5, 0x2CC6, &[0x236D]),
+    (0x2CC7, 0x2CC7, &[0x236E]),
+    (0x2CC8, 0x2CC8, &[0x2372]),
+    (0x2CC9, 0x2CC9, &[0x2372]),
+    (0x2CCA, 0x2CCA, &[0x2374]),
+    (0x2CCB, 0x2CCC, &[0x2374]),
+    (0x2CCD, 0x2CCE, &[0x2375]),
+    (0x2CCF, 0x2CD0, &[0x2376]),
+    (0x2CD1, 0x2CD2, &[0x2377]),
+    (0x2CD3, 0x2CD4, &[0x2378]),
+    (0x2CD5, 0x2CD5, &[0x2379]),
+    (0x2CD6, 0x2CD6, &[0x237C]),
+    (0x2CD7, 0x2CD7, &[0x237C]),
+    (0x2CD8, 0x2CD8, &[0x237E]),
+    (0x2CD9, 0x2CDA, &[0x237E]),
+    (0x2CDB, 0x2CDC, &[0x237F]),
+    (0x2CDD, 0x2CDD, &[0x2380]),
+    (0x2CDE, 0x2CDE, &[0x2384]),
+    (0x2CDF, 0x2CE0, &[0x2384]),
+    (0x2CE1, 0x2CE2, &[0x2385]),
+    (0x2CE3, 0x2CE3, &[0x2386]),
+    (0x2CE4, 0x2CE4, &[0x2356, 0x234B, 0x2355]),
+    (0x2CE5, 0x2CEA, &[0x0EE2]),
+    (0x2CEB, 0x2CEB, &[0x236B]),
+    (0x2CEC, 0x2CEC, &[0x236B]),
+    (0x2CED, 0x2CED, &[0x237B]),
+    (0x2CEE, 0x2CEE, &[0x237B]),
+    (0x2CEF, 0x2CF1, &[]),
+    (0x2CF2, 0x2CF2, &[0x2371]),
+    (0x2CF3, 0x2CF3, &[0x2371]),
+    (0x2CF9, 0x2CF9, &[0x0288]),
+    (0x2CFA, 0x2CFB, &[0x0275]),
+    (0x2CFC, 0x2CFC, &[0x0307]),
+    (0x2CFD, 0x2CFD, &[0x1DB7]),
+    (0x2CFE, 0x2CFE, &[0x0289]),
+    (0x2CFF, 0x2CFF, &[0x0308]),
+    (0x2D00, 0x2D00, &[0x25A0]),
+    (0x2D01, 0x2D01, &[0x25A2]),
+    (0x2D02, 0x2D02, &[0x25A4]),
+    (0x2D03, 0x2D03, &[0x25A6]),
+    (0x2D04, 0x2D04, &[0x25A8]),
+    (0x2D05, 0x2D05, &[0x25AA]),
+    (0x2D06, 0x2D06, &[0x25AC]),
+    (0x2D07, 0x2D07, &[0x25B0]),
+    (0x2D08, 0x2D08, &[0x25B2]),
+    (0x2D09, 0x2D09, &[0x25B4]),
+    (0x2D0A, 0x2D0A, &[0x25B6]),
+    (0x2D0B, 0x2D0B, &[0x25B8]),
+    (0x2D0C, 0x2D0C, &[0x25BA]),
+    (0x2D0D, 0x2D0D, &[0x25BE]),
+    (0x2D0E, 0x2D0E, &[0x25C0]),
+    (0x2D0F, 0x2D0F, &[0x25C2]),
+    (0x2D10, 0x2D10, &[0x25C4]),
+    (0x2D11, 0x2D11, &[0x25C6]),
+    (0x2D12, 0x2D12, &[0x25C8]),
+    (0x2D13, 0x2D13, &[0x25CC]),
+    (0x2D14, 0x2D14, &[0x25CE]),
+    (0x2D15, 0x2D15, &[0x25D0]),
+    (0x2D16, 0x2D16, &[0x25D2]),
+    (0x2D17, 0x2D17, &[0x25D4]),
+    (0x2D18, 0x2D18, &[0x25D6]),
+    (0x2D19, 0x2D19, &[0x25D8]),
+    (0x2D1A, 0x2D1A, &[0x25DA]),
+    (0x2D1B, 0x2D1B, &[0x25DC]),
+    (0x2D1C, 0x2D1C, &[0x25DE]),
+    (0x2D1D, 0x2D1D, &[0x25E0]),
+    (0x2D1E, 0x2D1E, &[0x25E2]),
+    (0x2D1F, 0x2D1F, &[0x25E6]),
+    (0x2D20, 0x2D20, &[0x25E8]),
+    (0x2D21, 0x2D21, &[0x25AE]),
+    (0x2D22, 0x2D22, &[0x25BC]),
+    (0x2D23, 0x2D23, &[0x25CA]),
+    (0x2D24, 0x2D24, &[0x25E4]),
+    (0x2D25, 0x2D25, &[0x25EA]),
+    (0x2D27, 0x2D27, &[0x25ED]),
+    (0x2D2D, 0x2D2D, &[0x25F2]),
+    (0x2D30, 0x2D3B, &[0x27D9]),
+    (0x2D3C, 0x2D53, &[0x27E6]),
+    (0x2D54, 0x2D65, &[0x27FF]),
+    (0x2D66, 0x2D66, &[0x27E5]),
+    (0x2D67, 0x2D67, &[0x27FE]),
+    (0x2D6F, 0x2D6F, &[0x2811]),
+    (0x2D70, 0x2D70, &[0x0453]),
+    (0x2D7F, 0x2D7F, &[]),
+    (0x2D80, 0x2D80, &[0x2822]),
+    (0x2D81, 0x2D81, &[0x2837]),
+    (0x2D82, 0x2D82, &[0x2848]),
+    (0x2D83, 0x2D83, &[0x2851]),
+    (0x2D84, 0x2D84, &[0x2860]),
+    (0x2D85, 0x2D85, &[0x2886]),
+    (0x2D86, 0x2D86, &[0x2897]),
+    (0x2D87, 0x2D87, &[0x28A0]),
+    (0x2D88, 0x2D88, &[0x28B6]),
+    (0x2D89, 0x2D89, &[0x28BF]),
+    (0x2D8A, 0x2D8A, &[0x28C8]),
+    (0x2D8B, 0x2D8B, &[0x28F9]),
+    (0x2D8C, 0x2D8C, &[0x2918]),
+    (0x2D8D, 0x2D8D, &[0x2927]),
+    (0x2D8E, 0x2D8E, &[0x2930]),
+    (0x2D8F, 0x2D8F, &[0x2952]),
+    (0x2D90, 0x2D90, &[0x295B]),
+    (0x2D91, 0x2D91, &[0x296B]),
+    (0x2D92, 0x2D92, &[0x299B]),
+    (0x2D93, 0x2D96, &[0x2946]),
+    (0x2DA0, 0x2DA6, &[0x299F]),
+    (0x2DA8, 0x2DAE, &[0x29A6]),
+    (0x2DB0, 0x2DB6, &[0x29AD]),
+    (0x2DB8, 0x2DBE, &[0x29B4]),
+    (0x2DC0, 0x2DC6, &[0x29BB]),
+    (0x2DC8, 0x2DCE, &[0x29C2]),
+    (0x2DD0, 0x2DD6, &[0x29C9]),
+    (0x2DD8, 0x2DDE, &[0x29D0]),
+    (0x2DE0, 0x2DE0, &[0x2393]),
+    (0x2DE1, 0x2DE1, &[0x2397]),
+    (0x2DE2, 0x2DE2, &[0x239B]),
+    (0x2DE3, 0x2DE3, &[0x23AF]),
+    (0x2DE4, 0x2DE4, &[0x23C7]),
+    (0x2DE5, 0x2DE5, &[0x23D1]),
+    (0x2DE6, 0x2DE6, &[0x23FB]),
+    (0x2DE7, 0x2DE7, &[0x2415]),
+    (0x2DE8, 0x2DE8, &[0x2428]),
+    (0x2DE9, 0x2DE9, &[0x2431]),
+    (0x2DEA, 0x2DEA, &[0x244C]),
+    (0x2DEB, 0x2DEB, &[0x2454]),
+    (0x2DEC, 0x2DEC, &[0x2461]),
+    (0x2DED, 0x2DED, &[0x246A]),
+    (0x2DEE, 0x2DEE, &[0x2473]),
+    (0x2DEF, 0x2DEF, &[0x2497]),
+    (0x2DF0, 0x2DF0, &[0x24BE]),
+    (0x2DF1, 0x2DF1, &[0x24C9]),
+    (0x2DF2, 0x2DF2, &[0x24E8]),
+    (0x2DF3, 0x2DF3, &[0x24ED]),
+    (0x2DF4, 0x2DF4, &[0x2538]),
+    (0x2DF5, 0x2DF5, &[0x246A, 0x2473]),
+    (0x2DF6, 0x2DF6, &[0x2387]),
+    (0x2DF7, 0x2DF7, &[0x23BF]),
+    (0x2DF8, 0x2DF8, &[0x23FA]),
+    (0x2DF9, 0x2DF9, &[0x248E]),
+    (0x2DFA, 0x2DFA, &[0x2505]),
+    (0x2DFB, 0x2DFB, &[0x250E]),
+    (0x2DFC, 0x2DFC, &[0x2513]),
+    (0x2DFD, 0x2DFD, &[0x251D]),
+    (0x2DFE, 0x2DFE, &[0x2522]),
+    (0x2DFF, 0x2DFF, &[0x252C]),
+    (0x2E00, 0x2E01, &[0x03CB]),
+    (0x2E02, 0x2E05, &[0x036C]),
+    (0x2E06, 0x2E08, &[0x03CD]),
+    (0x2E09, 0x2E0A, &[0x0370]),
+    (0x2E0B, 0x2E0B, &[0x03D0]),
+    (0x2E0C, 0x2E0D, &[0x0372]),
+    (0x2E0E, 0x2E16, &[0x03D1]),
+    (0x2E17, 0x2E17, &[0x021C]),
+    (0x2E18, 0x2E18, &[0x027D]),
+    (0x2E19, 0x2E19, &[0x0309]),
+    (0x2E1A, 0x2E1B, &[0x03DA]),
+    (0x2E1C, 0x2E1D, &[0x0374]),
+    (0x2E1E, 0x2E1F, &[0x03DC]),
+    (0x2E20, 0x2E29, &[0x0376]),
+    (0x2E2A, 0x2E2D, &[0x0302]),
+    (0x2E2E, 0x2E2E, &[0x026F]),
+    (0x2E2F, 0x2E2F, &[0x24F2]),
+    (0x2E30, 0x2E30, &[0x028A]),
+    (0x2E31, 0x2E31, &[0x0294]),
+    (0x2E32, 0x2E32, &[0x0225]),
+    (0x2E33, 0x2E33, &[0x0295]),
+    (0x2E34, 0x2E34, &[0x0224]),
+    (0x2E35, 0x2E35, &[0x023D]),
+    (0x2E36, 0x2E38, &[0x03B5]),
+    (0x2E39, 0x2E39, &[0x039B]),
+    (0x2E3A, 0x2E3B, &[0x0218]),
+    (0x2E3C, 0x2E3C, &[0x028B]),
+    (0x2E3D, 0x2E3D, &[0x0306]),
+    (0x2E3E, 0x2E3E, &[0x0395]),
+    (0x2E3F, 0x2E3F, &[0x039F]),
+    (0x2E40, 0x2E40, &[0x021D]),
+    (0x2E41, 0x2E41, &[0x0226]),
+    (0x2E42, 0x2E42, &[0x0322]),
+    (0x2E43, 0x2E43, &[0x021B]),
+    (0x2E44, 0x2E48, &[0x03DE]),
+    (0x2E49, 0x2E49, &[0x023F]),
+    (0x2E4A, 0x2E4A, &[0x03A8]),
+    (0x2E4B, 0x2E4B, &[0x03B8]),
+    (0x2E4C, 0x2E4C, &[0x0227]),
+    (0x2E4D, 0x2E4D, &[0x039E]),
+    (0x2E4E, 0x2E4F, &[0x0228]),
+    (0x2E50, 0x2E51, &[0x0B5D]),
+    (0x2E52, 0x2E52, &[0x03AB]),
+    (0x2E80, 0x2E80, &[0xFB40, 0xCE36]),
+    (0x2E81, 0x2E81, &[0xFB40, 0xD382]),
+    (0x2E82, 0x2E82, &[0xFB40, 0xCE5B]),
+    (0x2E83, 0x2E83, &[0xFB40, 0xCE5A]),
+    (0x2E84, 0x2E84, &[0xFB40, 0xCE59]),
+    (0x2E85, 0x2E85, &[0xFB40, 0xCEBB]),
+    (0x2E86, 0x2E86, &[0xFB40, 0xD182]),
+    (0x2E87, 0x2E87, &[0xFB40, 0xD1E0]),
+    (0x2E88, 0x2E88, &[0xFB40, 0xD200]),
+    (0x2E89, 0x2E89, &[0xFB40, 0xD202]),
+    (0x2E8A, 0x2E8A, &[0xFB40, 0xD35C]),
+    (0x2E8B, 0x2E8B, &[0xFB40, 0xD369]),
+    (0x2E8C, 0x2E8C, &[0xFB40, 0xDC0F]),
+    (0x2E8D, 0x2E8D, &[0xFB40, 0xDC0F]),
+    (0x2E8E, 0x2E8E, &[0xFB40, 0xDC22]),
+    (0x2E8F, 0x2E8F, &[0xFB40, 0xDC23]),
+    (0x2E90, 0x2E90, &[0xFB40, 0xDC22]),
+    (0x2E91, 0x2E91, &[0xFB40, 0xDC23]),
+    (0x2E92, 0x2E92, &[0xFB40, 0xDDF3]),
+    (0x2E93, 0x2E93, &[0xFB40, 0xDE7A]),
+    (0x2E94, 0x2E94, &[0xFB40, 0xDF51]),
+    (0x2E95, 0x2E95, &[0xFB40, 0xDF50]),
+    (0x2E96, 0x2E96, &[0xFB40, 0xDFC4]),
+    (0x2E97, 0x2E97, &[0xFB40, 0xDFC3]),
+    (0x2E98, 0x2E98, &[0xFB40, 0xE24C]),
+    (0x2E99, 0x2E99, &[0xFB40, 0xE535]),
+    (0x2E9B, 0x2E9B, &[0xFB40, 0xE5E1]),
+    (0x2E9C, 0x2E9C, &[0xFB40, 0xE5E5]),
+    (0x2E9D, 0x2E9D, &[0xFB40, 0xE708]),
+    (0x2E9E, 0x2E9E, &[0xFB40, 0xEB7A]),
+    (0x2E9F, 0x2E9F, &[0xFB40, 0xEBCD]),
+    (0x2EA0, 0x2EA0, &[0xFB40, 0xEC11]),
+    (0x2EA1, 0x2EA1, &[0xFB40, 0xEC35]),
+    (0x2EA2, 0x2EA2, &[0xFB40, 0xEC3A]),
+    (0x2EA3, 0x2EA3, &[0xFB40, 0xF06C]),
+    (0x2EA4, 0x2EA4, &[0xFB40, 0xF22B]),
+    (0x2EA5, 0x2EA5, &[0xFB40, 0xF22B]),
+    (0x2EA6, 0x2EA6, &[0xFB40, 0xCE2C]),
+    (0x2EA7, 0x2EA7, &[0xFB40, 0xF25B]),
+    (0x2EA8, 0x2EA8, &[0xFB40, 0xF2AD]),
+    (0x2EA9, 0x2EA9, &[0xFB40, 0xF38B]),
+    (0x2EAA, 0x2EAA, &[0xFB40, 0xF58B]),
+    (0x2EAB, 0x2EAB, &[0xFB40, 0xF6EE]),
+    (0x2EAC, 0x2EAC, &[0xFB40, 0xF93A]),
+    (0x2EAD, 0x2EAD, &[0xFB40, 0xF93B]),
+    (0x2EAE, 0x2EAE, &[0xFB40, 0xFAF9]),
+    (0x2EAF, 0x2EAF, &[0xFB40, 0xFCF9]),
+    (0x2EB0, 0x2EB0, &[0xFB40, 0xFE9F]),
+    (0x2EB1, 0x2EB1, &[0xFB40, 0xFF53]),
+    (0x2EB2, 0x2EB2, &[0xFB40, 0xFF52]),
+    (0x2EB3, 0x2EB3, &[0xFB40, 0xFF53]),
+    (0x2EB4, 0x2EB4, &[0xFB40, 0xFF53]),
+    (0x2EB5, 0x2EB5, &[0xFB40, 0xFF52]),
+    (0x2EB6, 0x2EB6, &[0xFB40, 0xFF8A]),
+    (0x2EB7, 0x2EB7, &[0xFB40, 0xFF8A]),
+    (0x2EB8, 0x2EB8, &[0xFB40, 0xFF8B]),
+    (0x2EB9, 0x2EB9, &[0xFB41, 0x8002]),
+    (0x2EBA, 0x2EBA, &[0xFB41, 0x8080]),
+    (0x2EBB, 0x2EBB, &[0xFB41, 0x807F]),
+    (0x2EBC, 0x2EBC, &[0xFB41, 0x8089]),
+    (0x2EBD, 0x2EBD, &[0xFB41, 0x81FC]),
+    (0x2EBE, 0x2EBE, &[0xFB41, 0x8279]),
+    (0x2EBF, 0x2EBF, &[0xFB41, 0x8279]),
+    (0x2EC0, 0x2EC0, &[0xFB41, 0x8279]),
+    (0x2EC1, 0x2EC1, &[0xFB41, 0x864E]),
+    (0x2EC2, 0x2EC2, &[0xFB41, 0x8864]),
+    (0x2EC3, 0x2EC3, &[0xFB41, 0x8980]),
+    (0x2EC4, 0x2EC4, &[0xFB41, 0x897F]),
+    (0x2EC5, 0x2EC5, &[0xFB41, 0x89C1]),
+    (0x2EC6, 0x2EC6, &[0xFB41, 0x89D2]),
+    (0x2EC7, 0x2EC7, &[0xFB41, 0x89D2]),
+    (0x2EC8, 0x2EC8, &[0xFB41, 0x8BA0]),
+    (0x2EC9, 0x2EC9, &[0xFB41, 0x8D1D]),
+    (0x2ECA, 0x2ECA, &[0xFB41, 0x8DB3]),
+    (0x2ECB, 0x2ECB, &[0xFB41, 0x8F66]),
+    (0x2ECC, 0x2ECC, &[0xFB41, 0x8FB6]),
+    (0x2ECD, 0x2ECD, &[0xFB41, 0x8FB6]),
+    (0x2ECE, 0x2ECE, &[0xFB41, 0x8FB6]),
+    (0x2ECF, 0x2ECF, &[0xFB41, 0x9091]),
+    (0x2ED0, 0x2ED0, &[0xFB41, 0x9485]),
+    (0x2ED1, 0x2ED1, &[0xFB41, 0x9577]),
+    (0x2ED2, 0x2ED2, &[0xFB41, 0x9578]),
+    (0x2ED3, 0x2ED3, &[0xFB41, 0x957F]),
+    (0x2ED4, 0x2ED4, &[0xFB41, 0x95E8]),
+    (0x2ED5, 0x2ED5, &[0xFB41, 0x961C]),
+    (0x2ED6, 0x2ED6, &[0xFB41, 0x961D]),
+    (0x2ED7, 0x2ED7, &[0xFB41, 0x96E8]),
+    (0x2ED8, 0x2ED8, &[0xFB41, 0x9752]),
+    (0x2ED9, 0x2ED9, &[0xFB41, 0x97E6]),
+    (0x2EDA, 0x2EDA, &[0xFB41, 0x9875]),
+    (0x2EDB, 0x2EDB, &[0xFB41, 0x98CE]),
+    (0x2EDC, 0x2EDC, &[0xFB41, 0x98DE]),
+    (0x2EDD, 0x2EDD, &[0xFB41, 0x98DF]),
+    (0x2EDE, 0x2EDE, &[0xFB41, 0x98E0]),
+    (0x2EDF, 0x2EDF, &[0xFB41, 0x98E0]),
+    (0x2EE0, 0x2EE0, &[0xFB41, 0x9963]),
+    (0x2EE1, 0x2EE1, &[0xFB41, 0x9996]),
+    (0x2EE2, 0x2EE2, &[0xFB41, 0x9A6C]),
+    (0x2EE3, 0x2EE3, &[0xFB41, 0x9AA8]),
+    (0x2EE4, 0x2EE4, &[0xFB41, 0x9B3C]),
+    (0x2EE5, 0x2EE5, &[0xFB41, 0x9C7C]),
+    (0x2EE6, 0x2EE6, &[0xFB41, 0x9E1F]),
+    (0x2EE7, 0x2EE7, &[0xFB41, 0x9E75]),
+    (0x2EE8, 0x2EE8, &[0xFB41, 0x9EA6]),
+    (0x2EE9, 0x2EE9, &[0xFB41, 0x9EC4]),
+    (0x2EEA, 0x2EEA, &[0xFB41, 0x9EFE]),
+    (0x2EEB, 0x2EEB, &[0xFB41, 0x9F4A]),
+    (0x2EEC, 0x2EEC, &[0xFB41, 0x9F50]),
+    (0x2EED, 0x2EED, &[0xFB41, 0x9F52]),
+    (0x2EEE, 0x2EEE, &[0xFB41, 0x9F7F]),
+    (0x2EEF, 0x2EEF, &[0xFB41, 0x9F8D]),
+    (0x2EF0, 0x2EF0, &[0xFB41, 0x9F99]),
+    (0x2EF1, 0x2EF1, &[0xFB41, 0x9F9C]),
+    (0x2EF2, 0x2EF2, &[0xFB41, 0x9F9C]),
+    (0x2EF3, 0x2EF3, &[0xFB41, 0x9F9F]),
+    (0x2F00, 0x2F00, &[0xFB40, 0xCE00]),
+    (0x2F01, 0x2F01, &[0xFB40, 0xCE28]),
+    (0x2F02, 0x2F02, &[0xFB40, 0xCE36]),
+    (0x2F03, 0x2F03, &[0xFB40, 0xCE3F]),
+    (0x2F04, 0x2F04, &[0xFB40, 0xCE59]),
+    (0x2F05, 0x2F05, &[0xFB40, 0xCE85]),
+    (0x2F06, 0x2F06, &[0xFB40, 0xCE8C]),
+    (0x2F07, 0x2F07, &[0xFB40, 0xCEA0]),
+    (0x2F08, 0x2F08, &[0xFB40, 0xCEBA]),
+    (0x2F09, 0x2F09, &[0xFB40, 0xD13F]),
+    (0x2F0A, 0x2F0A, &[0xFB40, 0xD165]),
+    (0x2F0B, 0x2F0B, &[0xFB40, 0xD16B]),
+    (0x2F0C, 0x2F0C, &[0xFB40, 0xD182]),
+    (0x2F0D, 0x2F0D, &[0xFB40, 0xD196]),
+    (0x2F0E, 0x2F0E, &[0xFB40, 0xD1AB]),
+    (0x2F0F, 0x2F0F, &[0xFB40, 0xD1E0]),
+    (0x2F10, 0x2F10, &[0xFB40, 0xD1F5]),
+    (0x2F11, 0x2F11, &[0xFB40, 0xD200]),
+    (0x2F12, 0x2F12, &[0xFB40, 0xD29B]),
+    (0x2F13, 0x2F13, &[0xFB40, 0xD2F9]),
+    (0x2F14, 0x2F14, &[0xFB40, 0xD315]),
+    (0x2F15, 0x2F15, &[0xFB40, 0xD31A]),
+    (0x2F16, 0x2F16, &[0xFB40, 0xD338]),
+    (0x2F17, 0x2F17, &[0xFB40, 0xD341]),
+    (0x2F18, 0x2F18, &[0xFB40, 0xD35C]),
+    (0x2F19, 0x2F19, &[0xFB40, 0xD369]),
+    (0x2F1A, 0x2F1A, &[0xFB40, 0xD382]),
+    (0x2F1B, 0x2F1B, &[0xFB40, 0xD3B6]),
+    (0x2F1C, 0x2F1C, &[0xFB40, 0xD3C8]),
+    (0x2F1D, 0x2F1D, &[0xFB40, 0xD3E3]),
+    (0x2F1E, 0x2F1E, &[0xFB40, 0xD6D7]),
+    (0x2F1F, 0x2F1F, &[0xFB40, 0xD71F]),
+    (0x2F20, 0x2F20, &[0xFB40, 0xD8EB]),
+    (0x2F21, 0x2F21, &[0xFB40, 0xD902]),
+    (0x2F22, 0x2F22, &[0xFB40, 0xD90A]),
+    (0x2F23, 0x2F23, &[0xFB40, 0xD915]),
+    (0x2F24, 0x2F24, &[0xFB40, 0xD927]),
+    (0x2F25, 0x2F25, &[0xFB40, 0xD973]),
+    (0x2F26, 0x2F26, &[0xFB40, 0xDB50]),
+    (0x2F27, 0x2F27, &[0xFB40, 0xDB80]),
+    (0x2F28, 0x2F28, &[0xFB40, 0xDBF8]),
+    (0x2F29, 0x2F29, &[0xFB40, 0xDC0F]),
+    (0x2F2A, 0x2F2A, &[0xFB40, 0xDC22]),
+    (0x2F2B, 0x2F2B, &[0xFB40, 0xDC38]),
+    (0x2F2C, 0x2F2C, &[0xFB40, 0xDC6E]),
+    (0x2F2D, 0x2F2D, &[0xFB40, 0xDC71]),
+    (0x2F2E, 0x2F2E, &[0xFB40, 0xDDDB]),
+    (0x2F2F, 0x2F2F, &[0xFB40, 0xDDE5]),
+    (0x2F30, 0x2F30, &[0xFB40, 0xDDF1]),
+    (0x2F31, 0x2F31, &[0xFB40, 0xDDFE]),
+    (0x2F32, 0x2F32, &[0xFB40, 0xDE72]),
+    (0x2F33, 0x2F33, &[0xFB40, 0xDE7A]),
+    (0x2F34, 0x2F34, &[0xFB40, 0xDE7F]),
+    (0x2F35, 0x2F35, &[0xFB40, 0xDEF4]),
+    (0x2F36, 0x2F36, &[0xFB40, 0xDEFE]),
+    (0x2F37, 0x2F37, &[0xFB40, 0xDF0B]),
+    (0x2F38, 0x2F38, &[0xFB40, 0xDF13]),
+    (0x2F39, 0x2F39, &[0xFB40, 0xDF50]),
+    (0x2F3A, 0x2F3A, &[0xFB40, 0xDF61]),
+    (0x2F3B, 0x2F3B, &[0xFB40, 0xDF73]),
+    (0x2F3C, 0x2F3C, &[0xFB40, 0xDFC3]),
+    (0x2F3D, 0x2F3D, &[0xFB40, 0xE208]),
+    (0x2F3E, 0x2F3E, &[0xFB40, 0xE236]),
+    (0x2F3F, 0x2F3F, &[0xFB40, 0xE24B]),
+    (0x2F40, 0x2F40, &[0xFB40, 0xE52F]),
+    (0x2F41, 0x2F41, &[0xFB40, 0xE534]),
+    (0x2F42, 0x2F42, &[0xFB40, 0xE587]),
+    (0x2F43, 0x2F43, &[0xFB40, 0xE597]),
+    (0x2F44, 0x2F44, &[0xFB40, 0xE5A4]),
+    (0x2F45, 0x2F45, &[0xFB40, 0xE5B9]),
+    (0x2F46, 0x2F46, &[0xFB40, 0xE5E0]),
+    (0x2F47, 0x2F47, &[0xFB40, 0xE5E5]),
+    (0x2F48, 0x2F48, &[0xFB40, 0xE6F0]),
+    (0x2F49, 0x2F49, &[0xFB40, 0xE708]),
+    (0x2F4A, 0x2F4A, &[0xFB40, 0xE728]),
+    (0x2F4B, 0x2F4B, &[0xFB40, 0xEB20]),
+    (0x2F4C, 0x2F4C, &[0xFB40, 0xEB62]),
+    (0x2F4D, 0x2F4D, &[0xFB40, 0xEB79]),
+    (0x2F4E, 0x2F4E, &[0xFB40, 0xEBB3]),
+    (0x2F4F, 0x2F4F, &[0xFB40, 0xEBCB]),
+    (0x2F50, 0x2F50, &[0xFB40, 0xEBD4]),
+    (0x2F51, 0x2F51, &[0xFB40, 0xEBDB]),
+    (0x2F52, 0x2F52, &[0xFB40, 0xEC0F]),
+    (0x2F53, 0x2F53, &[0xFB40, 0xEC14]),
+    (0x2F54, 0x2F54, &[0xFB40, 0xEC34]),
+    (0x2F55, 0x2F55, &[0xFB40, 0xF06B]),
+    (0x2F56, 0x2F56, &[0xFB40, 0xF22A]),
+    (0x2F57, 0x2F57, &[0xFB40, 0xF236]),
+    (0x2F58, 0x2F58, &[0xFB40, 0xF23B]),
+    (0x2F59, 0x2F59, &[0xFB40, 0xF23F]),
+    (0x2F5A, 0x2F5A, &[0xFB40, 0xF247]),
+    (0x2F5B, 0x2F5B, &[0xFB40, 0xF259]),
+    (0x2F5C, 0x2F5C, &[0xFB40, 0xF25B]),
+    (0x2F5D, 0x2F5D, &[0xFB40, 0xF2AC]),
+    (0x2F5E, 0x2F5E, &[0xFB40, 0xF384]),
+    (0x2F5F, 0x2F5F, &[0xFB40, 0xF389]),
+    (0x2F60, 0x2F60, &[0xFB40, 0xF4DC]),
+    (0x2F61, 0x2F61, &[0xFB40, 0xF4E6]),
+    (0x2F62, 0x2F62, &[0xFB40, 0xF518]),
+    (0x2F63, 0x2F63, &[0xFB40, 0xF51F]),
+    (0x2F64, 0x2F64, &[0xFB40, 0xF528]),
+    (0x2F65, 0x2F65, &[0xFB40, 0xF530]),
+    (0x2F66, 0x2F66, &[0xFB40, 0xF58B]),
+    (0x2F67, 0x2F67, &[0xFB40, 0xF592]),
+    (0x2F68, 0x2F68, &[0xFB40, 0xF676]),
+    (0x2F69, 0x2F69, &[0xFB40, 0xF67D]),
+    (0x2F6A, 0x2F6A, &[0xFB40, 0xF6AE]),
+    (0x2F6B, 0x2F6B, &[0xFB40, 0xF6BF]),
+    (0x2F6C, 0x2F6C, &[0xFB40, 0xF6EE]),
+    (0x2F6D, 0x2F6D, &[0xFB40, 0xF7DB]),
+    (0x2F6E, 0x2F6E, &[0xFB40, 0xF7E2]),
+    (0x2F6F, 0x2F6F, &[0xFB40, 0xF7F3]),
+    (0x2F70, 0x2F70, &[0xFB40, 0xF93A]),
+    (0x2F71, 0x2F71, &[0xFB40, 0xF9B8]),
+    (0x2F72, 0x2F72, &[0xFB40, 0xF9BE]),
+    (0x2F73, 0x2F73, &[0xFB40, 0xFA74]),
+    (0x2F74, 0x2F74, &[0xFB40, 0xFACB]),
+    (0x2F75, 0x2F75, &[0xFB40, 0xFAF9]),
+    (0x2F76, 0x2F76, &[0xFB40, 0xFC73]),
+    (0x2F77, 0x2F77, &[0xFB40, 0xFCF8]),
+    (0x2F78, 0x2F78, &[0xFB40, 0xFF36]),
+    (0x2F79, 0x2F79, &[0xFB40, 0xFF51]),
+    (0x2F7A, 0x2F7A, &[0xFB40, 0xFF8A]),
+    (0x2F7B, 0x2F7B, &[0xFB40, 0xFFBD]),
+    (0x2F7C, 0x2F7C, &[0xFB41, 0x8001]),
+    (0x2F7D, 0x2F7D, &[0xFB41, 0x800C]),
+    (0x2F7E, 0x2F7E, &[0xFB41, 0x8012]),
+    (0x2F7F, 0x2F7F, &[0xFB41, 0x8033]),
+    (0x2F80, 0x2F80, &[0xFB41, 0x807F]),
+    (0x2F81, 0x2F81, &[0xFB41, 0x8089]),
+    (0x2F82, 0x2F82, &[0xFB41, 0x81E3]),
+    (0x2F83, 0x2F83, &[0xFB41, 0x81EA]),
+    (0x2F84, 0x2F84, &[0xFB41, 0x81F3]),
+    (0x2F85, 0x2F85, &[0xFB41, 0x81FC]),
+    (0x2F86, 0x2F86, &[0xFB41, 0x820C]),
+    (0x2F87, 0x2F87, &[0xFB41, 0x821B]),
+    (0x2F88, 0x2F88, &[0xFB41, 0x821F]),
+    (0x2F89, 0x2F89, &[0xFB41, 0x826E]),
+    (0x2F8A, 0x2F8A, &[0xFB41, 0x8272]),
+    (0x2F8B, 0x2F8B, &[0xFB41, 0x8278]),
+    (0x2F8C, 0x2F8C, &[0xFB41, 0x864D]),
+    (0x2F8D, 0x2F8D, &[0xFB41, 0x866B]),
+    (0x2F8E, 0x2F8E, &[0xFB41, 0x8840]),
+    (0x2F8F, 0x2F8F, &[0xFB41, 0x884C]),
+    (0x2F90, 0x2F90, &[0xFB41, 0x8863]),
+    (0x2F91, 0x2F91, &[0xFB41, 0x897E]),
+    (0x2F92, 0x2F92, &[0xFB41, 0x898B]),
+    (0x2F93, 0x2F93, &[0xFB41, 0x89D2]),
+    (0x2F94, 0x2F94, &[0xFB41, 0x8A00]),
+    (0x2F95, 0x2F95, &[0xFB41, 0x8C37]),
+    (0x2F96, 0x2F96, &[0xFB41, 0x8C46]),
+    (0x2F97, 0x2F97, &[0xFB41, 0x8C55]),
+    (0x2F98, 0x2F98, &[0xFB41, 0x8C78]),
+    (0x2F99, 0x2F99, &[0xFB41, 0x8C9D]),
+    (0x2F9A, 0x2F9A, &[0xFB41, 0x8D64]),
+    (0x2F9B, 0x2F9B, &[0xFB41, 0x8D70]),
+    (0x2F9C, 0x2F9C, &[0xFB41, 0x8DB3]),
+    (0x2F9D, 0x2F9D, &[0xFB41, 0x8EAB]),
+    (0x2F9E, 0x2F9E, &[0xFB41, 0x8ECA]),
+    (0x2F9F, 0x2F9F, &[0xFB41, 0x8F9B]),
+    (0x2FA0, 0x2FA0, &[0xFB41, 0x8FB0]),
+    (0x2FA1, 0x2FA1, &[0xFB41, 0x8FB5]),
+    (0x2FA2, 0x2FA2, &[0xFB41, 0x9091]),
+    (0x2FA3, 0x2FA3, &[0xFB41, 0x9149]),
+    (0x2FA4, 0x2FA4, &[0xFB41, 0x91C6]),
+    (0x2FA5, 0x2FA5, &[0xFB41, 0x91CC]),
+    (0x2FA6, 0x2FA6, &[0xFB41, 0x91D1]),
+    (0x2FA7, 0x2FA7, &[0xFB41, 0x9577]),
+    (0x2FA8, 0x2FA8, &[0xFB41, 0x9580]),
+    (0x2FA9, 0x2FA9, &[0xFB41, 0x961C]),
+    (0x2FAA, 0x2FAA, &[0xFB41, 0x96B6]),
+    (0x2FAB, 0x2FAB, &[0xFB41, 0x96B9]),
+    (0x2FAC, 0x2FAC, &[0xFB41, 0x96E8]),
+    (0x2FAD, 0x2FAD, &[0xFB41, 0x9751]),
+    (0x2FAE, 0x2FAE, &[0xFB41, 0x975E]),
+    (0x2FAF, 0x2FAF, &[0xFB41, 0x9762]),
+    (0x2FB0, 0x2FB0, &[0xFB41, 0x9769]),
+    (0x2FB1, 0x2FB1, &[0xFB41, 0x97CB]),
+    (0x2FB2, 0x2FB2, &[0xFB41, 0x97ED]),
+    (0x2FB3, 0x2FB3, &[0xFB41, 0x97F3]),
+    (0x2FB4, 0x2FB4, &[0xFB41, 0x9801]),
+    (0x2FB5, 0x2FB5, &[0xFB41, 0x98A8]),
+    (0x2FB6, 0x2FB6, &[0xFB41, 0x98DB]),
+    (0x2FB7, 0x2FB7, &[0xFB41, 0x98DF]),
+    (0x2FB8, 0x2FB8, &[0xFB41, 0x9996]),
+    (0x2FB9, 0x2FB9, &[0xFB41, 0x9999]),
+    (0x2FBA, 0x2FBA, &[0xFB41, 0x99AC]),
+    (0x2FBB, 0x2FBB, &[0xFB41, 0x9AA8]),
+    (0x2FBC, 0x2FBC, &[0xFB41, 0x9AD8]),
+    (0x2FBD, 0x2FBD, &[0xFB41, 0x9ADF]),
+    (0x2FBE, 0x2FBE, &[0xFB41, 0x9B25]),
+    (0x2FBF, 0x2FBF, &[0xFB41, 0x9B2F]),
+    (0x2FC0, 0x2FC0, &[0xFB41, 0x9B32]),
+    (0x2FC1, 0x2FC1, &[0xFB41, 0x9B3C]),
+    (0x2FC2, 0x2FC2, &[0xFB41, 0x9B5A]),
+    (0x2FC3, 0x2FC3, &[0xFB41, 0x9CE5]),
+    (0x2FC4, 0x2FC4, &[0xFB41, 0x9E75]),
+    (0x2FC5, 0x2FC5, &[0xFB41, 0x9E7F]),
+    (0x2FC6, 0x2FC6, &[0xFB41, 0x9EA5]),
+    (0x2FC7, 0x2FC7, &[0xFB41, 0x9EBB]),
+    (0x2FC8, 0x2FC8, &[0xFB41, 0x9EC3]),
+    (0x2FC9, 0x2FC9, &[0xFB41, 0x9ECD]),
+    (0x2FCA, 0x2FCA, &[0xFB41, 0x9ED1]),
+    (0x2FCB, 0x2FCB, &[0xFB41, 0x9EF9]),
+    (0x2FCC, 0x2FCC, &[0xFB41, 0x9EFD]),
+    (0x2FCD, 0x2FCD, &[0xFB41, 0x9F0E]),
+    (0x2FCE, 0x2FCE, &[0xFB41, 0x9F13]),
+    (0x2FCF, 0x2FCF, &[0xFB41, 0x9F20]),
+    (0x2FD0, 0x2FD0, &[0xFB41, 0x9F3B]),
+    (0x2FD1, 0x2FD1, &[0xFB41, 0x9F4A]),
+    (0x2FD2, 0x2FD2, &[0xFB41, 0x9F52]),
+    (0x2FD3, 0x2FD3, &[0xFB41, 0x9F8D]),
+    (0x2FD4, 0x2FD4, &[0xFB41, 0x9F9C]),
+    (0x2FD5, 0x2FD5, &[0xFB41, 0x9FA0]),
+    (0x2FF0, 0x2FFB, &[0x1D1B]),
+    (0x3000, 0x3000, &[0x0209]),
+    (0x3001, 0x3001, &[0x0236]),
+    (0x3002, 0x3002, &[0x0292]),
+    (0x3003, 0x3003, &[0x03C1]),
+    (0x3004, 0x3004, &[0x1D4B]),
+    (0x3005, 0x3005, &[0x1F57]),
+    (0x3006, 0x3006, &[0x42E6, 0x42FC]),
+    (0x3007, 0x3007, &[0x1F98]),
+    (0x3008, 0x3011, &[0x0380]),
+    (0x3012, 0x3013, &[0x1D4C]),
+    (0x3014, 0x301B, &[0x038A]),
+    (0x301C, 0x301C, &[0x021E]),
+    (0x301D, 0x301F, &[0x0323]),
+    (0x3020, 0x3020, &[0x1D4E]),
+    (0x3021, 0x3029, &[0x1F99]),
+    (0x302A, 0x302F, &[]),
+    (0x3030, 0x3030, &[0x021F]),
+    (0x3031, 0x3031, &[0x1F5C]),
+    (0x3032, 0x3033, &[0x1F5C]),
+    (0x3034, 0x3035, &[0x1F5D]),
+    (0x3036, 0x3036, &[0x1D4C]),
+    (0x3037, 0x3037, &[0x1D4F]),
+    (0x3038, 0x3038, &[0xFB40, 0xD341]),
+    (0x3039, 0x3039, &[0xFB40, 0xD344]),
+    (0x303A, 0x303A, &[0xFB40, 0xD345]),
+    (0x303B, 0x303B, &[0x1F58]),
+    (0x303C, 0x303C, &[0x42F9, 0x42E7]),
+    (0x303D, 0x303D, &[0x03C2]),
+    (0x303E, 0x303F, &[0x1D50]),
+    (0x3041, 0x3041, &[0x42DA]),
+    (0x3042, 0x3043, &[0x42DA]),
+    (0x3044, 0x3045, &[0x42DB]),
+    (0x3046, 0x3046, &[0x42DC]),
+    (0x3047, 0x3047, &[0x42DE]),
+    (0x3048, 0x3049, &[0x42DE]),
+    (0x304A, 0x304B, &[0x42DF]),
+    (0x304C, 0x304D, &[0x42E0]),
+    (0x304E, 0x304F, &[0x42E1]),
+    (0x3050, 0x3051, &[0x42E2]),
+    (0x3052, 0x3053, &[0x42E3]),
+    (0x3054, 0x3055, &[0x42E4]),
+    (0x3056, 0x3057, &[0x42E5]),
+    (0x3058, 0x3059, &[0x42E6]),
+    (0x305A, 0x305B, &[0x42E7]),
+    (0x305C, 0x305D, &[0x42E8]),
+    (0x305E, 0x305F, &[0x42E9]),
+    (0x3060, 0x3061, &[0x42EA]),
+    (0x3062, 0x3063, &[0x42EB]),
+    (0x3064, 0x3064, &[0x42EC]),
+    (0x3065, 0x3066, &[0x42EC]),
+    (0x3067, 0x3068, &[0x42ED]),
+    (0x3069, 0x306F, &[0x42EE]),
+    (0x3070, 0x3070, &[0x42F4]),
+    (0x3071, 0x3072, &[0x42F4]),
+    (0x3073, 0x3073, &[0x42F5]),
+    (0x3074, 0x3075, &[0x42F5]),
+    (0x3076, 0x3076, &[0x42F6]),
+    (0x3077, 0x3078, &[0x42F6]),
+    (0x3079, 0x3079, &[0x42F7]),
+    (0x307A, 0x307B, &[0x42F7]),
+    (0x307C, 0x307C, &[0x42F8]),
+    (0x307D, 0x3083, &[0x42F8]),
+    (0x3084, 0x3085, &[0x42FE]),
+    (0x3086, 0x3087, &[0x42FF]),
+    (0x3088, 0x308E, &[0x4300]),
+    (0x308F, 0x3093, &[0x4306]),
+    (0x3094, 0x3094, &[0x42DC]),
+    (0x3095, 0x3095, &[0x42E0]),
+    (0x3096, 0x3096, &[0x42E3]),
+    (0x3099, 0x309A, &[]),
+    (0x309B, 0x309C, &[0x04C3]),
+    (0x309D, 0x309D, &[0x1F5F]),
+    (0x309E, 0x309E, &[0x1F5F]),
+    (0x309F, 0x309F, &[0x4300, 0x4302]),
+    (0x30A0, 0x30A0, &[0x0220]),
+    (0x30A1, 0x30A1, &[0x42DA]),
+    (0x30A2, 0x30A3, &[0x42DA]),
+    (0x30A4, 0x30A5, &[0x42DB]),
+    (0x30A6, 0x30A6, &[0x42DC]),
+    (0x30A7, 0x30A7, &[0x42DE]),
+    (0x30A8, 0x30A9, &[0x42DE]),
+    (0x30AA, 0x30AB, &[0x42DF]),
+    (0x30AC, 0x30AD, &[0x42E0]),
+    (0x30AE, 0x30AF, &[0x42E1]),
+    (0x30B0, 0x30B1, &[0x42E2]),
+    (0x30B2, 0x30B3, &[0x42E3]),
+    (0x30B4, 0x30B5, &[0x42E4]),
+    (0x30B6, 0x30B7, &[0x42E5]),
+    (0x30B8, 0x30B9, &[0x42E6]),
+    (0x30BA, 0x30BB, &[0x42E7]),
+    (0x30BC, 0x30BD, &[0x42E8]),
+    (0x30BE, 0x30BF, &[0x42E9]),
+    (0x30C0, 0x30C1, &[0x42EA]),
+    (0x30C2, 0x30C3, &[0x42EB]),
+    (0x30C4, 0x30C4, &[0x42EC]),
+    (0x30C5, 0x30C6, &[0x42EC]),
+    (0x30C7, 0x30C8, &[0x42ED]),
+    (0x30C9, 0x30CF, &[0x42EE]),
+    (0x30D0, 0x30D0, &[0x42F4]),
+    (0x30D1, 0x30D2, &[0x42F4]),
+    (0x30D3, 0x30D3, &[0x42F5]),
+    (0x30D4, 0x30D5, &[0x42F5]),
+    (0x30D6, 0x30D6, &[0x42F6]),
+    (0x30D7, 0x30D8, &[0x42F6]),
+    (0x30D9, 0x30D9, &[0x42F7]),
+    (0x30DA, 0x30DB, &[0x42F7]),
+    (0x30DC, 0x30DC, &[0x42F8]),
+    (0x30DD, 0x30E3, &[0x42F8]),
+    (0x30E4, 0x30E5, &[0x42FE]),
+    (0x30E6, 0x30E7, &[0x42FF]),
+    (0x30E8, 0x30EE, &[0x4300]),
+    (0x30EF, 0x30F3, &[0x4306]),
+    (0x30F4, 0x30F4, &[0x42DC]),
+    (0x30F5, 0x30F5, &[0x42E0]),
+    (0x30F6, 0x30F6, &[0x42E3]),
+    (0x30F7, 0x30FA, &[0x4306]),
+    (0x30FB, 0x30FB, &[0x0221]),
+    (0x30FC, 0x30FD, &[0x1F60]),
+    (0x30FE, 0x30FE, &[0x1F61]),
+    (0x30FF, 0x30FF, &[0x42E4, 0x42EE]),
+    (0x3105, 0x3107, &[0x4429]),
+    (0x3108, 0x3108, &[0x442D]),
+    (0x3109, 0x310B, &[0x442F]),
+    (0x310C, 0x310E, &[0x4433]),
+    (0x310F, 0x3112, &[0x4438]),
+    (0x3113, 0x3119, &[0x443F]),
+    (0x311A, 0x311B, &[0x4449]),
+    (0x311C, 0x311D, &[0x444C]),
+    (0x311E, 0x3124, &[0x444F]),
+    (0x3125, 0x3125, &[0x4457]),
+    (0x3126, 0x3129, &[0x445A]),
+    (0x312A, 0x312A, &[0x442E]),
+    (0x312B, 0x312B, &[0x4436]),
+    (0x312C, 0x312C, &[0x443C]),
+    (0x312D, 0x312D, &[0x445E]),
+    (0x312E, 0x312E, &[0x444C]),
+    (0x312F, 0x312F, &[0x4432]),
+    (0x3131, 0x3132, &[0x4175]),
+    (0x3133, 0x3133, &[0x4253]),
+    (0x3134, 0x3134, &[0x4177]),
+    (0x3135, 0x3136, &[0x4255]),
+    (0x3137, 0x3139, &[0x4178]),
+    (0x313A, 0x313F, &[0x4259]),
+    (0x3140, 0x3140, &[0x418F]),
+    (0x3141, 0x3143, &[0x417B]),
+    (0x3144, 0x3144, &[0x4196]),
+    (0x3145, 0x314E, &[0x417E]),
+    (0x314F, 0x3163, &[0x41F3]),
+    (0x3164, 0x3164, &[0x41F2]),
+    (0x3165, 0x3166, &[0x4189]),
+    (0x3167, 0x3168, &[0x4270]),
+    (0x3169, 0x3169, &[0x4275]),
+    (0x316A, 0x316A, &[0x4277]),
+    (0x316B, 0x316B, &[0x427C]),
+    (0x316C, 0x316C, &[0x4280]),
+    (0x316D, 0x316D, &[0x4282]),
+    (0x316E, 0x316E, &[0x4191]),
+    (0x316F, 0x316F, &[0x4286]),
+    (0x3170, 0x3170, &[0x4288]),
+    (0x3171, 0x3172, &[0x4192]),
+    (0x3173, 0x3173, &[0x4195]),
+    (0x3174, 0x3175, &[0x4197]),
+    (0x3176, 0x3176, &[0x419C]),
+    (0x3177, 0x3177, &[0x419E]),
+    (0x3178, 0x317C, &[0x41A0]),
+    (0x317D, 0x317D, &[0x41A7]),
+    (0x317E, 0x317E, &[0x41AB]),
+    (0x317F, 0x317F, &[0x41B5]),
+    (0x3180, 0x3180, &[0x41BC]),
+    (0x3181, 0x3181, &[0x41C1]),
+    (0x3182, 0x3183, &[0x429A]),
+    (0x3184, 0x3186, &[0x41CC]),
+    (0x3187, 0x3188, &[0x4216]),
+    (0x3189, 0x3189, &[0x421A]),
+    (0x318A, 0x318B, &[0x4223]),
+    (0x318C, 0x318C, &[0x4226]),
+    (0x318D, 0x318D, &[0x4230]),
+    (0x318E, 0x318E, &[0x4233]),
+    (0x3190, 0x3191, &[0x1D52]),
+    (0x3192, 0x3192, &[0xFB40, 0xCE00]),
+    (0x3193, 0x3193, &[0xFB40, 0xCE8C]),
+    (0x3194, 0x3194, &[0xFB40, 0xCE09]),
+    (0x3195, 0x3195, &[0xFB40, 0xD6DB]),
+    (0x3196, 0x3196, &[0xFB40, 0xCE0A]),
+    (0x3197, 0x3197, &[0xFB40, 0xCE2D]),
+    (0x3198, 0x3198, &[0xFB40, 0xCE0B]),
+    (0x3199, 0x3199, &[0xFB40, 0xF532]),
+    (0x319A, 0x319A, &[0xFB40, 0xCE59]),
+    (0x319B, 0x319B, &[0xFB40, 0xCE19]),
+    (0x319C, 0x319C, &[0xFB40, 0xCE01]),
+    (0x319D, 0x319D, &[0xFB40, 0xD929]),
+    (0x319E, 0x319E, &[0xFB40, 0xD730]),
+    (0x319F, 0x319F, &[0xFB40, 0xCEBA]),
+    (0x31A0, 0x31A0, &[0x4429]),
+    (0x31A1, 0x31A1, &[0x4443]),
+    (0x31A2, 0x31A2, &[0x4439]),
+    (0x31A3, 0x31A3, &[0x4434]),
+    (0x31A4, 0x31A4, &[0x444E]),
+    (0x31A5, 0x31A5, &[0x444E]),
+    (0x31A6, 0x31A6, &[0x444B]),
+    (0x31A7, 0x31A7, &[0x444A]),
+    (0x31A8, 0x31A8, &[0x445C]),
+    (0x31A9, 0x31A9, &[0x4449]),
+    (0x31AA, 0x31AB, &[0x445B]),
+    (0x31AC, 0x31AC, &[0x442C]),
+    (0x31AD, 0x31AD, &[0x4437]),
+    (0x31AE, 0x31AE, &[0x444F]),
+    (0x31AF, 0x31AF, &[0x4451]),
+    (0x31B0, 0x31B1, &[0x4458]),
+    (0x31B2, 0x31B2, &[0x4456]),
+    (0x31B3, 0x31B3, &[0x445B]),
+    (0x31B4, 0x31B4, &[0x442A]),
+    (0x31B5, 0x31B5, &[0x4430]),
+    (0x31B6, 0x31B6, &[0x4435]),
+    (0x31B7, 0x31B7, &[0x4438]),
+    (0x31B8, 0x31BA, &[0x4446]),
+    (0x31BB, 0x31BB, &[0x4434]),
+    (0x31BC, 0x31BD, &[0x443D]),
+    (0x31BE, 0x31BF, &[0x445F]),
+    (0x31C0, 0x31E3, &[0x1D27]),
+    (0x31F0, 0x31F0, &[0x42E2]),
+    (0x31F1, 0x31F2, &[0x42E6]),
+    (0x31F3, 0x31F3, &[0x42EE]),
+    (0x31F4, 0x31F4, &[0x42F1]),
+    (0x31F5, 0x31F9, &[0x42F4]),
+    (0x31FA, 0x31FA, &[0x42FB]),
+    (0x31FB, 0x31FF, &[0x4301]),
+    (0x3200, 0x3200, &[0x0328, 0x4175, 0x0329]),
+    (0x3201, 0x3201, &[0x0328, 0x4177, 0x0329]),
+    (0x3202, 0x3202, &[0x0328, 0x4178, 0x0329]),
+    (0x3203, 0x3203, &[0x0328, 0x417A, 0x0329]),
+    (0x3204, 0x3204, &[0x0328, 0x417B, 0x0329]),
+    (0x3205, 0x3205, &[0x0328, 0x417C, 0x0329]),
+    (0x3206, 0x3206, &[0x0328, 0x417E, 0x0329]),
+    (0x3207, 0x3207, &[0x0328, 0x4180, 0x0329]),
+    (0x3208, 0x3208, &[0x0328, 0x4181, 0x0329]),
+    (0x3209, 0x3209, &[0x0328, 0x4183, 0x0329]),
+    (0x320A, 0x320A, &[0x0328, 0x4184, 0x0329]),
+    (0x320B, 0x320B, &[0x0328, 0x4185, 0x0329]),
+    (0x320C, 0x320C, &[0x0328, 0x4186, 0x0329]),
+    (0x320D, 0x320D, &[0x0328, 0x4187, 0x0329]),
+    (0x320E, 0x320E, &[0x0328, 0x4175, 0x41F3, 0x0329]),
+    (0x320F, 0x320F, &[0x0328, 0x4177, 0x41F3, 0x0329]),
+    (0x3210, 0x3210, &[0x0328, 0x4178, 0x41F3, 0x0329]),
+    (0x3211, 0x3211, &[0x0328, 0x417A, 0x41F3, 0x0329]),
+    (0x3212, 0x3212, &[0x0328, 0x417B, 0x41F3, 0x0329]),
+    (0x3213, 0x3213, &[0x0328, 0x417C, 0x41F3, 0x0329]),
+    (0x3214, 0x3214, &[0x0328, 0x417E, 0x41F3, 0x0329]),
+    (0x3215, 0x3215, &[0x0328, 0x4180, 0x41F3, 0x0329]),
+    (0x3216, 0x3216, &[0x0328, 0x4181, 0x41F3, 0x0329]),
+    (0x3217, 0x3217, &[0x0328, 0x4183, 0x41F3, 0x0329]),
+    (0x3218, 0x3218, &[0x0328, 0x4184, 0x41F3, 0x0329]),
+    (0x3219, 0x3219, &[0x0328, 0x4185, 0x41F3, 0x0329]),
+    (0x321A, 0x321A, &[0x0328, 0x4186, 0x41F3, 0x0329]),
+    (0x321B, 0x321B, &[0x0328, 0x4187, 0x41F3, 0x0329]),
+    (0x321C, 0x321C, &[0x0328, 0x4181, 0x4200, 0x0329]),
+    (0x321D, 0x321D, &[0x0328, 0x4180, 0x41FB, 0x4181, 0x41F7, 0x4254, 0x0329]),
+    (0x321E, 0x321E, &[0x0328, 0x4180, 0x41FB, 0x4187, 0x4200, 0x0329]),
+    (0x3220, 0x3220, &[0x0328, 0xFB40, 0xCE00, 0x0329]),
+    (0x3221, 0x3221, &[0x0328, 0xFB40, 0xCE8C, 0x0329]),
+    (0x3222, 0x3222, &[0x0328, 0xFB40, 0xCE09, 0x0329]),
+    (0x3223, 0x3223, &[0x0328, 0xFB40, 0xD6DB, 0x0329]),
+    (0x3224, 0x3224, &[0x0328, 0xFB40, 0xCE94, 0x0329]),
+    (0x3225, 0x3225, &[0x0328, 0xFB40, 0xD16D, 0x0329]),
+    (0x3226, 0x3226, &[0x0328, 0xFB40, 0xCE03, 0x0329]),
+    (0x3227, 0x3227, &[0x0328, 0xFB40, 0xD16B, 0x0329]),
+    (0x3228, 0x3228, &[0x0328, 0xFB40, 0xCE5D, 0x0329]),
+    (0x3229, 0x3229, &[0x0328, 0xFB40, 0xD341, 0x0329]),
+    (0x322A, 0x322A, &[0x0328, 0xFB40, 0xE708, 0x0329]),
+    (0x322B, 0x322B, &[0x0328, 0xFB40, 0xF06B, 0x0329]),
+    (0x322C, 0x322C, &[0x0328, 0xFB40, 0xEC34, 0x0329]),
+    (0x322D, 0x322D, &[0x0328, 0xFB40, 0xE728, 0x0329]),
+    (0x322E, 0x322E, &[0x0328, 0xFB41, 0x91D1, 0x0329]),
+    (0x322F, 0x322F, &[0x0328, 0xFB40, 0xD71F, 0x0329]),
+    (0x3230, 0x3230, &[0x0328, 0xFB40, 0xE5E5, 0x0329]),
+    (0x3231, 0x3231, &[0x0328, 0xFB40, 0xE82A, 0x0329]),
+    (0x3232, 0x3232, &[0x0328, 0xFB40, 0xE709, 0x0329]),
+    (0x3233, 0x3233, &[0x0328, 0xFB40, 0xF93E, 0x0329]),
+    (0x3234, 0x3234, &[0x0328, 0xFB40, 0xD40D, 0x0329]),
+    (0x3235, 0x3235, &[0x0328, 0xFB40, 0xF279, 0x0329]),
+    (0x3236, 0x3236, &[0x0328, 0xFB41, 0x8CA1, 0x0329]),
+    (0x3237, 0x3237, &[0x0328, 0xFB40, 0xF95D, 0x0329]),
+    (0x3238, 0x3238, &[0x0328, 0xFB40, 0xD2B4, 0x0329]),
+    (0x3239, 0x3239, &[0x0328, 0xFB40, 0xCEE3, 0x0329]),
+    (0x323A, 0x323A, &[0x0328, 0xFB40, 0xD47C, 0x0329]),
+    (0x323B, 0x323B, &[0x0328, 0xFB40, 0xDB66, 0x0329]),
+    (0x323C, 0x323C, &[0x0328, 0xFB40, 0xF6E3, 0x0329]),
+    (0x323D, 0x323D, &[0x0328, 0xFB40, 0xCF01, 0x0329]),
+    (0x323E, 0x323E, &[0x0328, 0xFB41, 0x8CC7, 0x0329]),
+    (0x323F, 0x323F, &[0x0328, 0xFB40, 0xD354, 0x0329]),
+    (0x3240, 0x3240, &[0x0328, 0xFB40, 0xF96D, 0x0329]),
+    (0x3241, 0x3241, &[0x0328, 0xFB40, 0xCF11, 0x0329]),
+    (0x3242, 0x3242, &[0x0328, 0xFB41, 0x81EA, 0x0329]),
+    (0x3243, 0x3243, &[0x0328, 0xFB41, 0x81F3, 0x0329]),
+    (0x3244, 0x3244, &[0xFB40, 0xD54F]),
+    (0x3245, 0x3245, &[0xFB40, 0xDE7C]),
+    (0x3246, 0x3246, &[0xFB40, 0xE587]),
+    (0x3247, 0x3247, &[0xFB40, 0xFB8F]),
+    (0x3248, 0x3248, &[0x1F99, 0x1F98]),
+    (0x3249, 0x3249, &[0x1F9A, 0x1F98]),
+    (0x324A, 0x324A, &[0x1F9B, 0x1F98]),
+    (0x324B, 0x324B, &[0x1F9C, 0x1F98]),
+    (0x324C, 0x324C, &[0x1F9D, 0x1F98]),
+    (0x324D, 0x324D, &[0x1F9E, 0x1F98]),
+    (0x324E, 0x324E, &[0x1F9F, 0x1F98]),
+    (0x324F, 0x324F, &[0x1FA0, 0x1F98]),
+    (0x3250, 0x3250, &[0x216B, 0x21F7, 0x2007]),
+    (0x3251, 0x3251, &[0x1F9A, 0x1F99]),
+    (0x3252, 0x3252, &[0x1F9A, 0x1F9A]),
+    (0x3253, 0x3253, &[0x1F9A, 0x1F9B]),
+    (0x3254, 0x3254, &[0x1F9A, 0x1F9C]),
+    (0x3255, 0x3255, &[0x1F9A, 0x1F9D]),
+    (0x3256, 0x3256, &[0x1F9A, 0x1F9E]),
+    (0x3257, 0x3257, &[0x1F9A, 0x1F9F]),
+    (0x3258, 0x3258, &[0x1F9A, 0x1FA0]),
+    (0x3259, 0x3259, &[0x1F9A, 0x1FA1]),
+    (0x325A, 0x325A, &[0x1F9B, 0x1F98]),
+    (0x325B, 0x325B, &[0x1F9B, 0x1F99]),
+    (0x325C, 0x325C, &[0x1F9B, 0x1F9A]),
+    (0x325D, 0x325D, &[0x1F9B, 0x1F9B]),
+    (0x325E, 0x325E, &[0x1F9B, 0x1F9C]),
+    (0x325F, 0x325F, &[0x1F9B, 0x1F9D]),
+    (0x3260, 0x3260, &[0x4175]),
+    (0x3261, 0x3262, &[0x4177]),
+    (0x3263, 0x3265, &[0x417A]),
+    (0x3266, 0x3266, &[0x417E]),
+    (0x3267, 0x3268, &[0x4180]),
+    (0x3269, 0x326D, &[0x4183]),
+    (0x326E, 0x326E, &[0x4175, 0x41F3]),
+    (0x326F, 0x326F, &[0x4177, 0x41F3]),
+    (0x3270, 0x3270, &[0x4178, 0x41F3]),
+    (0x3271, 0x3271, &[0x417A, 0x41F3]),
+    (0x3272, 0x3272, &[0x417B, 0x41F3]),
+    (0x3273, 0x3273, &[0x417C, 0x41F3]),
+    (0x3274, 0x3274, &[0x417E, 0x41F3]),
+    (0x3275, 0x3275, &[0x4180, 0x41F3]),
+    (0x3276, 0x3276, &[0x4181, 0x41F3]),
+    (0x3277, 0x3277, &[0x4183, 0x41F3]),
+    (0x3278, 0x3278, &[0x4184, 0x41F3]),
+    (0x3279, 0x3279, &[0x4185, 0x41F3]),
+    (0x327A, 0x327A, &[0x4186, 0x41F3]),
+    (0x327B, 0x327B, &[0x4187, 0x41F3]),
+    (0x327C, 0x327C, &[0x4183, 0x41F3, 0x4260, 0x4175, 0x41FB]),
+    (0x327D, 0x327D, &[0x4181, 0x4200, 0x4180, 0x4206]),
+    (0x327E, 0x327E, &[0x4180, 0x4200]),
+    (0x327F, 0x327F, &[0x1D54]),
+    (0x3280, 0x3280, &[0xFB40, 0xCE00]),
+    (0x3281, 0x3281, &[0xFB40, 0xCE8C]),
+    (0x3282, 0x3282, &[0xFB40, 0xCE09]),
+    (0x3283, 0x3283, &[0xFB40, 0xD6DB]),
+    (0x3284, 0x3284, &[0xFB40, 0xCE94]),
+    (0x3285, 0x3285, &[0xFB40, 0xD16D]),
+    (0x3286, 0x3286, &[0xFB40, 0xCE03]),
+    (0x3287, 0x3287, &[0xFB40, 0xD16B]),
+    (0x3288, 0x3288, &[0xFB40, 0xCE5D]),
+    (0x3289, 0x3289, &[0xFB40, 0xD341]),
+    (0x328A, 0x328A, &[0xFB40, 0xE708]),
+    (0x328B, 0x328B, &[0xFB40, 0xF06B]),
+    (0x328C, 0x328C, &[0xFB40, 0xEC34]),
+    (0x328D, 0x328D, &[0xFB40, 0xE728]),
+    (0x328E, 0x328E, &[0xFB41, 0x91D1]),
+    (0x328F, 0x328F, &[0xFB40, 0xD71F]),
+    (0x3290, 0x3290, &[0xFB40, 0xE5E5]),
+    (0x3291, 0x3291, &[0xFB40, 0xE82A]),
+    (0x3292, 0x3292, &[0xFB40, 0xE709]),
+    (0x3293, 0x3293, &[0xFB40, 0xF93E]),
+    (0x3294, 0x3294, &[0xFB40, 0xD40D]),
+    (0x3295, 0x3295, &[0xFB40, 0xF279]),
+    (0x3296, 0x3296, &[0xFB41, 0x8CA1]),
+    (0x3297, 0x3297, &[0xFB40, 0xF95D]),
+    (0x3298, 0x3298, &[0xFB40, 0xD2B4]),
+    (0x3299, 0x3299, &[0xFB40, 0xF9D8]),
+    (0x329A, 0x329A, &[0xFB40, 0xF537]),
+    (0x329B, 0x329B, &[0xFB40, 0xD973]),
+    (0x329C, 0x329C, &[0xFB41, 0x9069]),
+    (0x329D, 0x329D, &[0xFB40, 0xD12A]),
+    (0x329E, 0x329E, &[0xFB40, 0xD370]),
+    (0x329F, 0x329F, &[0xFB40, 0xECE8]),
+    (0x32A0, 0x32A0, &[0xFB41, 0x9805]),
+    (0x32A1, 0x32A1, &[0xFB40, 0xCF11]),
+    (0x32A2, 0x32A2, &[0xFB40, 0xD199]),
+    (0x32A3, 0x32A3, &[0xFB40, 0xEB63]),
+    (0x32A4, 0x32A4, &[0xFB40, 0xCE0A]),
+    (0x32A5, 0x32A5, &[0xFB40, 0xCE2D]),
+    (0x32A6, 0x32A6, &[0xFB40, 0xCE0B]),
+    (0x32A7, 0x32A7, &[0xFB40, 0xDDE6]),
+    (0x32A8, 0x32A8, &[0xFB40, 0xD3F3]),
+    (0x32A9, 0x32A9, &[0xFB40, 0xD33B]),
+    (0x32AA, 0x32AA, &[0xFB40, 0xDB97]),
+    (0x32AB, 0x32AB, &[0xFB40, 0xDB66]),
+    (0x32AC, 0x32AC, &[0xFB40, 0xF6E3]),
+    (0x32AD, 0x32AD, &[0xFB40, 0xCF01]),
+    (0x32AE, 0x32AE, &[0xFB41, 0x8CC7]),
+    (0x32AF, 0x32AF, &[0xFB40, 0xD354]),
+    (0x32B0, 0x32B0, &[0xFB40, 0xD91C]),
+    (0x32B1, 0x32B1, &[0x1F9B, 0x1F9E]),
+    (0x32B2, 0x32B2, &[0x1F9B, 0x1F9F]),
+    (0x32B3, 0x32B3, &[0x1F9B, 0x1FA0]),
+    (0x32B4, 0x32B4, &[0x1F9B, 0x1FA1]),
+    (0x32B5, 0x32B5, &[0x1F9C, 0x1F98]),
+    (0x32B6, 0x32B6, &[0x1F9C, 0x1F99]),
+    (0x32B7, 0x32B7, &[0x1F9C, 0x1F9A]),
+    (0x32B8, 0x32B8, &[0x1F9C, 0x1F9B]),
+    (0x32B9, 0x32B9, &[0x1F9C, 0x1F9C]),
+    (0x32BA, 0x32BA, &[0x1F9C, 0x1F9D]),
+    (0x32BB, 0x32BB, &[0x1F9C, 0x1F9E]),
+    (0x32BC, 0x32BC, &[0x1F9C, 0x1F9F]),
+    (0x32BD, 0x32BD, &[0x1F9C, 0x1FA0]),
+    (0x32BE, 0x32BE, &[0x1F9C, 0x1FA1]),
+    (0x32BF, 0x32BF, &[0x1F9D, 0x1F98]),
+    (0x32C0, 0x32C0, &[0x1F99, 0xFB40, 0xE708]),
+    (0x32C1, 0x32C1, &[0x1F9A, 0xFB40, 0xE708]),
+    (0x32C2, 0x32C2, &[0x1F9B, 0xFB40, 0xE708]),
+    (0x32C3, 0x32C3, &[0x1F9C, 0xFB40, 0xE708]),
+    (0x32C4, 0x32C4, &[0x1F9D, 0xFB40, 0xE708]),
+    (0x32C5, 0x32C5, &[0x1F9E, 0xFB40, 0xE708]),
+    (0x32C6, 0x32C6, &[0x1F9F, 0xFB40, 0xE708]),
+    (0x32C7, 0x32C7, &[0x1FA0, 0xFB40, 0xE708]),
+    (0x32C8, 0x32C8, &[0x1FA1, 0xFB40, 0xE708]),
+    (0x32C9, 0x32C9, &[0x1F99, 0x1F98, 0xFB40, 0xE708]),
+    (0x32CA, 0x32CA, &[0x1F99, 0x1F99, 0xFB40, 0xE708]),
+    (0x32CB, 0x32CB, &[0x1F99, 0x1F9A, 0xFB40, 0xE708]),
+    (0x32CC, 0x32CC, &[0x2075, 0x2051]),
+    (0x32CD, 0x32CD, &[0x2007, 0x2193, 0x2051]),
+    (0x32CE, 0x32CE, &[0x2007, 0x2247]),
+    (0x32CF, 0x32CF, &[0x20D6, 0x21F7, 0x1FEB]),
+    (0x32D0, 0x32D2, &[0x42DA]),
+    (0x32D3, 0x32FE, &[0x42DE]),
+    (0x32FF, 0x32FF, &[0xFB40, 0xCEE4, 0xFB40, 0xD48C]),
+    (0x3300, 0x3300, &[0x42DA, 0x42F4, 0x1F60, 0x42EE]),
+    (0x3301, 0x3301, &[0x42DA, 0x4303, 0x42F6, 0x42DA]),
+    (0x3302, 0x3302, &[0x42DA, 0x430A, 0x42F7, 0x42DA]),
+    (0x3303, 0x3303, &[0x42DA, 0x1F60, 0x4303]),
+    (0x3304, 0x3304, &[0x42DB, 0x42F0, 0x430A, 0x42E2]),
+    (0x3305, 0x3305, &[0x42DB, 0x430A, 0x42EB]),
+    (0x3306, 0x3306, &[0x42DC, 0x42DF, 0x430A]),
+    (0x3307, 0x3307, &[0x42DE, 0x42E7, 0x42E2, 0x1F60, 0x42EE]),
+    (0x3308, 0x3308, &[0x42DE, 0x1F60, 0x42E0, 0x1F60]),
+    (0x3309, 0x3309, &[0x42DF, 0x430A, 0x42E7]),
+    (0x330A, 0x330A, &[0x42DF, 0x1F60, 0x42FB]),
+    (0x330B, 0x330B, &[0x42E0, 0x42DB, 0x4302]),
+    (0x330C, 0x330C, &[0x42E0, 0x4301, 0x42EC, 0x42EE]),
+    (0x330D, 0x330D, &[0x42E0, 0x4305, 0x4302, 0x1F60]),
+    (0x330E, 0x330E, &[0x42E0, 0x4305, 0x430A]),
+    (0x330F, 0x330F, &[0x42E0, 0x430A, 0x42F9]),
+    (0x3310, 0x3310, &[0x42E1, 0x42E0]),
+    (0x3311, 0x3311, &[0x42E1, 0x42F0, 0x1F60]),
+    (0x3312, 0x3312, &[0x42E1, 0x42FF, 0x4302, 0x1F60]),
+    (0x3313, 0x3313, &[0x42E1, 0x4303, 0x42EA, 0x1F60]),
+    (0x3314, 0x3314, &[0x42E1, 0x4305]),
+    (0x3315, 0x3315, &[0x42E1, 0x4305, 0x42E2, 0x4301, 0x42FB]),
+    (0x3316, 0x3316, &[0x42E1, 0x4305, 0x42FC, 0x1F60, 0x42EE, 0x4303]),
+    (0x3317, 0x3317, &[0x42E1, 0x4305, 0x4306, 0x42EC, 0x42EE]),
+    (0x3318, 0x3318, &[0x42E2, 0x4301, 0x42FB]),
+    (0x3319, 0x3319, &[0x42E2, 0x4301, 0x42FB, 0x42EE, 0x430A]),
+    (0x331A, 0x331A, &[0x42E2, 0x4303, 0x42E8, 0x42DB, 0x4305]),
+    (0x331B, 0x331B, &[0x42E2, 0x4305, 0x1F60, 0x42F2]),
+    (0x331C, 0x331C, &[0x42E3, 0x1F60, 0x42E7]),
+    (0x331D, 0x331D, &[0x42E4, 0x4303, 0x42EF]),
+    (0x331E, 0x331E, &[0x42E4, 0x1F60, 0x42F8]),
+    (0x331F, 0x331F, &[0x42E5, 0x42DB, 0x42E2, 0x4303]),
+    (0x3320, 0x3320, &[0x42E5, 0x430A, 0x42EB, 0x1F60, 0x42FB]),
+    (0x3321, 0x3321, &[0x42E6, 0x4302, 0x430A, 0x42E2]),
+    (0x3322, 0x3322, &[0x42E8, 0x430A, 0x42EB]),
+    (0x3323, 0x3323, &[0x42E8, 0x430A, 0x42EE]),
+    (0x3324, 0x3324, &[0x42EA, 0x1F60, 0x42E7]),
+    (0x3325, 0x3325, &[0x42ED, 0x42E6]),
+    (0x3326, 0x3326, &[0x42EE, 0x4303]),
+    (0x3327, 0x3327, &[0x42EE, 0x430A]),
+    (0x3328, 0x3328, &[0x42EF, 0x42F3]),
+    (0x3329, 0x3329, &[0x42F3, 0x42EC, 0x42EE]),
+    (0x332A, 0x332A, &[0x42F4, 0x42DB, 0x42EC]),
+    (0x332B, 0x332B, &[0x42F4, 0x1F60, 0x42E8, 0x430A, 0x42EE]),
+    (0x332C, 0x332C, &[0x42F4, 0x1F60, 0x42EC]),
+    (0x332D, 0x332D, &[0x42F4, 0x1F60, 0x4304, 0x4303]),
+    (0x332E, 0x332E, &[0x42F5, 0x42DA, 0x42E7, 0x42EE, 0x4303]),
+    (0x332F, 0x332F, &[0x42F5, 0x42E2, 0x4303]),
+    (0x3330, 0x3330, &[0x42F5, 0x42E4]),
+    (0x3331, 0x3331, &[0x42F5, 0x4303]),
+    (0x3332, 0x3332, &[0x42F6, 0x42DA, 0x4301, 0x42EC, 0x42EE]),
+    (0x3333, 0x3333, &[0x42F6, 0x42DB, 0x1F60, 0x42EE]),
+    (0x3334, 0x3334, &[0x42F6, 0x42EC, 0x42E6, 0x42DE, 0x4303]),
+    (0x3335, 0x3335, &[0x42F6, 0x4301, 0x430A]),
+    (0x3336, 0x3336, &[0x42F7, 0x42E2, 0x42EA, 0x1F60, 0x4303]),
+    (0x3337, 0x3337, &[0x42F7, 0x42E9]),
+    (0x3338, 0x3338, &[0x42F7, 0x42F0, 0x42F5]),
+    (0x3339, 0x3339, &[0x42F7, 0x4303, 0x42EC]),
+    (0x333A, 0x333A, &[0x42F7, 0x430A, 0x42E7]),
+    (0x333B, 0x333B, &[0x42F7, 0x1F60, 0x42E6]),
+    (0x333C, 0x333C, &[0x42F7, 0x1F60, 0x42EA]),
+    (0x333D, 0x333D, &[0x42F8, 0x42DB, 0x430A, 0x42EE]),
+    (0x333E, 0x333E, &[0x42F8, 0x4303, 0x42EE]),
+    (0x333F, 0x333F, &[0x42F8, 0x430A]),
+    (0x3340, 0x3340, &[0x42F8, 0x430A, 0x42EE]),
+    (0x3341, 0x3341, &[0x42F8, 0x1F60, 0x4303]),
+    (0x3342, 0x3342, &[0x42F8, 0x1F60, 0x430A]),
+    (0x3343, 0x3343, &[0x42F9, 0x42DB, 0x42E2, 0x4305]),
+    (0x3344, 0x3344, &[0x42F9, 0x42DB, 0x4303]),
+    (0x3345, 0x3345, &[0x42F9, 0x42EC, 0x42F4]),
+    (0x3346, 0x3346, &[0x42F9, 0x4303, 0x42E2]),
+    (0x3347, 0x3347, &[0x42F9, 0x430A, 0x42E6, 0x4300, 0x430A]),
+    (0x3348, 0x3348, &[0x42FA, 0x42E2, 0x4305, 0x430A]),
+    (0x3349, 0x3349, &[0x42FA, 0x4302]),
+    (0x334A, 0x334A, &[0x42FA, 0x4302, 0x42F4, 0x1F60, 0x4303]),
+    (0x334B, 0x334B, &[0x42FC, 0x42E0]),
+    (0x334C, 0x334C, &[0x42FC, 0x42E0, 0x42EE, 0x430A]),
+    (0x334D, 0x334D, &[0x42FC, 0x1F60, 0x42EE, 0x4303]),
+    (0x334E, 0x334E, &[0x42FE, 0x1F60, 0x42EE]),
+    (0x334F, 0x334F, &[0x42FE, 0x1F60, 0x4303]),
+    (0x3350, 0x3350, &[0x42FF, 0x42DA, 0x430A]),
+    (0x3351, 0x3351, &[0x4302, 0x42EC, 0x42EE, 0x4303]),
+    (0x3352, 0x3352, &[0x4302, 0x4301]),
+    (0x3353, 0x3353, &[0x4303, 0x42F5, 0x1F60]),
+    (0x3354, 0x3354, &[0x4303, 0x1F60, 0x42F6, 0x4303]),
+    (0x3355, 0x3355, &[0x4304, 0x42FB]),
+    (0x3356, 0x3356, &[0x4304, 0x430A, 0x42EE, 0x42E3, 0x430A]),
+    (0x3357, 0x3357, &[0x4306, 0x42EC, 0x42EE]),
+    (0x3358, 0x3358, &[0x1F98, 0xFB40, 0xF0B9]),
+    (0x3359, 0x3359, &[0x1F99, 0xFB40, 0xF0B9]),
+    (0x335A, 0x335A, &[0x1F9A, 0xFB40, 0xF0B9]),
+    (0x335B, 0x335B, &[0x1F9B, 0xFB40, 0xF0B9]),
+    (0x335C, 0x335C, &[0x1F9C, 0xFB40, 0xF0B9]),
+    (0x335D, 0x335D, &[0x1F9D, 0xFB40, 0xF0B9]),
+    (0x335E, 0x335E, &[0x1F9E, 0xFB40, 0xF0B9]),
+    (0x335F, 0x335F, &[0x1F9F, 0xFB40, 0xF0B9]),
+    (0x3360, 0x3360, &[0x1FA0, 0xFB40, 0xF0B9]),
+    (0x3361, 0x3361, &[0x1FA1, 0xFB40, 0xF0B9]),
+    (0x3362, 0x3362, &[0x1F99, 0x1F98, 0xFB40, 0xF0B9]),
+    (0x3363, 0x3363, &[0x1F99, 0x1F99, 0xFB40, 0xF0B9]),
+    (0x3364, 0x3364, &[0x1F99, 0x1F9A, 0xFB40, 0xF0B9]),
+    (0x3365, 0x3365, &[0x1F99, 0x1F9B, 0xFB40, 0xF0B9]),
+    (0x3366, 0x3366, &[0x1F99, 0x1F9C, 0xFB40, 0xF0B9]),
+    (0x3367, 0x3367, &[0x1F99, 0x1F9D, 0xFB40, 0xF0B9]),
+    (0x3368, 0x3368, &[0x1F99, 0x1F9E, 0xFB40, 0xF0B9]),
+    (0x3369, 0x3369, &[0x1F99, 0x1F9F, 0xFB40, 0xF0B9]),
+    (0x336A, 0x336A, &[0x1F99, 0x1FA0, 0xFB40, 0xF0B9]),
+    (0x336B, 0x336B, &[0x1F99, 0x1FA1, 0xFB40, 0xF0B9]),
+    (0x336C, 0x336C, &[0x1F9A, 0x1F98, 0xFB40, 0xF0B9]),
+    (0x336D, 0x336D, &[0x1F9A, 0x1F99, 0xFB40, 0xF0B9]),
+    (0x336E, 0x336E, &[0x1F9A, 0x1F9A, 0xFB40, 0xF0B9]),
+    (0x336F, 0x336F, &[0x1F9A, 0x1F9B, 0xFB40, 0xF0B9]),
+    (0x3370, 0x3370, &[0x1F9A, 0x1F9C, 0xFB40, 0xF0B9]),
+    (0x3371, 0x3371, &[0x2075, 0x216B, 0x1FA2]),
+    (0x3372, 0x3372, &[0x1FEB, 0x1FA2]),
+    (0x3373, 0x3373, &[0x1FA2, 0x2217]),
+    (0x3374, 0x3374, &[0x1FBC, 0x1FA2, 0x2193]),
+    (0x3375, 0x3375, &[0x213C, 0x2247]),
+    (0x3376, 0x3376, &[0x216B, 0x1FD6]),
+    (0x3377, 0x3377, &[0x1FEB, 0x2109]),
+    (0x3378, 0x3378, &[0x1FEB, 0x2109, 0x1F9A]),
+    (0x3379, 0x3379, &[0x1FEB, 0x2109, 0x1F9B]),
+    (0x337A, 0x337A, &[0x2090, 0x2217]),
+    (0x337B, 0x337B, &[0xFB40, 0xDE73, 0xFB40, 0xE210]),
+    (0x337C, 0x337C, &[0xFB40, 0xE62D, 0xFB40, 0xD48C]),
+    (0x337D, 0x337D, &[0xFB40, 0xD927, 0xFB40, 0xEB63]),
+    (0x337E, 0x337E, &[0xFB40, 0xE60E, 0xFB40, 0xECBB]),
+    (0x337F, 0x337F, &[0xFB40, 0xE82A, 0xFB40, 0xDF0F, 0xFB40, 0xCF1A, 0xFB40, 0xF93E]),
+    (0x3380, 0x3380, &[0x216B, 0x1FA2]),
+    (0x3381, 0x3381, &[0x2118, 0x1FA2]),
+    (0x3382, 0x3382, &[0x2330, 0x1FA2]),
+    (0x3383, 0x3383, &[0x2109, 0x1FA2]),
+    (0x3384, 0x3384, &[0x20C4, 0x1FA2]),
+    (0x3385, 0x3385, &[0x20C4, 0x1FBC]),
+    (0x3386, 0x3386, &[0x2109, 0x1FBC]),
+    (0x3387, 0x3387, &[0x2051, 0x1FBC]),
+    (0x3388, 0x3388, &[0x1FD6, 0x1FA2, 0x20D6]),
+    (0x3389, 0x3389, &[0x20C4, 0x1FD6, 0x1FA2, 0x20D6]),
+    (0x338A, 0x338A, &[0x216B, 0x2042]),
+    (0x338B, 0x338B, &[0x2118, 0x2042]),
+    (0x338C, 0x338C, &[0x2330, 0x2042]),
+    (0x338D, 0x338D, &[0x2330, 0x2051]),
+    (0x338E, 0x338E, &[0x2109, 0x2051]),
+    (0x338F, 0x338F, &[0x20C4, 0x2051]),
+    (0x3390, 0x3390, &[0x2075, 0x2286]),
+    (0x3391, 0x3391, &[0x20C4, 0x2075, 0x2286]),
+    (0x3392, 0x3392, &[0x2109, 0x2075, 0x2286]),
+    (0x3393, 0x3393, &[0x2051, 0x2075, 0x2286]),
+    (0x3394, 0x3394, &[0x21F7, 0x2075, 0x2286]),
+    (0x3395, 0x3395, &[0x2330, 0x20D6]),
+    (0x3396, 0x3396, &[0x2109, 0x20D6]),
+    (0x3397, 0x3397, &[0x1FEB, 0x20D6]),
+    (0x3398, 0x3398, &[0x20C4, 0x20D6]),
+    (0x3399, 0x3399, &[0x2042, 0x2109]),
+    (0x339A, 0x339A, &[0x2118, 0x2109]),
+    (0x339B, 0x339B, &[0x2330, 0x2109]),
+    (0x339C, 0x339C, &[0x2109, 0x2109]),
+    (0x339D, 0x339D, &[0x1FD6, 0x2109]),
+    (0x339E, 0x339E, &[0x20C4, 0x2109]),
+    (0x339F, 0x339F, &[0x2109, 0x2109, 0x1F9A]),
+    (0x33A0, 0x33A0, &[0x1FD6, 0x2109, 0x1F9A]),
+    (0x33A1, 0x33A1, &[0x2109, 0x1F9A]),
+    (0x33A2, 0x33A2, &[0x20C4, 0x2109, 0x1F9A]),
+    (0x33A3, 0x33A3, &[0x2109, 0x2109, 0x1F9B]),
+    (0x33A4, 0x33A4, &[0x1FD6, 0x2109, 0x1F9B]),
+    (0x33A5, 0x33A5, &[0x2109, 0x1F9B]),
+    (0x33A6, 0x33A6, &[0x20C4, 0x2109, 0x1F9B]),
+    (0x33A7, 0x33A7, &[0x2109, 0x0675, 0x21D2]),
+    (0x33A8, 0x33A8, &[0x2109, 0x0675, 0x21D2, 0x1F9A]),
+    (0x33A9, 0x33A9, &[0x216B, 0x1FA2]),
+    (0x33AA, 0x33AA, &[0x20C4, 0x216B, 0x1FA2]),
+    (0x33AB, 0x33AB, &[0x2109, 0x216B, 0x1FA2]),
+    (0x33AC, 0x33AC, &[0x2051, 0x216B, 0x1FA2]),
+    (0x33AD, 0x33AD, &[0x2193, 0x1FA2, 0x1FEB]),
+    (0x33AE, 0x33AE, &[0x2193, 0x1FA2, 0x1FEB, 0x0675, 0x21D2]),
+    (0x33AF, 0x33AF, &[0x2193, 0x1FA2, 0x1FEB, 0x0675, 0x21D2, 0x1F9A]),
+    (0x33B0, 0x33B0, &[0x216B, 0x21D2]),
+    (0x33B1, 0x33B1, &[0x2118, 0x21D2]),
+    (0x33B2, 0x33B2, &[0x2330, 0x21D2]),
+    (0x33B3, 0x33B3, &[0x2109, 0x21D2]),
+    (0x33B4, 0x33B4, &[0x216B, 0x2247]),
+    (0x33B5, 0x33B5, &[0x2118, 0x2247]),
+    (0x33B6, 0x33B6, &[0x2330, 0x2247]),
+    (0x33B7, 0x33B7, &[0x2109, 0x2247]),
+    (0x33B8, 0x33B8, &[0x20C4, 0x2247]),
+    (0x33B9, 0x33B9, &[0x2109, 0x2247]),
+    (0x33BA, 0x33BA, &[0x216B, 0x2259]),
+    (0x33BB, 0x33BB, &[0x2118, 0x2259]),
+    (0x33BC, 0x33BC, &[0x2330, 0x2259]),
+    (0x33BD, 0x33BD, &[0x2109, 0x2259]),
+    (0x33BE, 0x33BE, &[0x20C4, 0x2259]),
+    (0x33BF, 0x33BF, &[0x2109, 0x2259]),
+    (0x33C0, 0x33C0, &[0x20C4, 0x2346]),
+    (0x33C1, 0x33C1, &[0x2109, 0x2346]),
+    (0x33C2, 0x33C2, &[0x1FA2, 0x027E, 0x2109, 0x027E]),
+    (0x33C3, 0x33C3, &[0x1FBC, 0x2180]),
+    (0x33C4, 0x33C4, &[0x1FD6, 0x1FD6]),
+    (0x33C5, 0x33C5, &[0x1FD6, 0x1FEB]),
+    (0x33C6, 0x33C6, &[0x1FD6, 0x0675, 0x20C4, 0x2051]),
+    (0x33C7, 0x33C7, &[0x1FD6, 0x213C, 0x027E]),
+    (0x33C8, 0x33C8, &[0x1FEB, 0x1FBC]),
+    (0x33C9, 0x33C9, &[0x2051, 0x2270]),
+    (0x33CA, 0x33CA, &[0x2075, 0x1FA2]),
+    (0x33CB, 0x33CB, &[0x2075, 0x216B]),
+    (0x33CC, 0x33CC, &[0x2090, 0x2118]),
+    (0x33CD, 0x33CD, &[0x20C4, 0x20C4]),
+    (0x33CE, 0x33CE, &[0x20C4, 0x2109]),
+    (0x33CF, 0x33CF, &[0x20C4, 0x21F7]),
+    (0x33D0, 0x33D0, &[0x20D6, 0x2109]),
+    (0x33D1, 0x33D1, &[0x20D6, 0x2118]),
+    (0x33D2, 0x33D2, &[0x20D6, 0x213C, 0x2051]),
+    (0x33D3, 0x33D3, &[0x20D6, 0x2264]),
+    (0x33D4, 0x33D4, &[0x2109, 0x1FBC]),
+    (0x33D5, 0x33D5, &[0x2109, 0x2090, 0x20D6]),
+    (0x33D6, 0x33D6, &[0x2109, 0x213C, 0x20D6]),
+    (0x33D7, 0x33D7, &[0x216B, 0x2075]),
+    (0x33D8, 0x33D8, &[0x216B, 0x027E, 0x2109, 0x027E]),
+    (0x33D9, 0x33D9, &[0x216B, 0x216B, 0x2109]),
+    (0x33DA, 0x33DA, &[0x216B, 0x2193]),
+    (0x33DB, 0x33DB, &[0x21D2, 0x2193]),
+    (0x33DC, 0x33DC, &[0x21D2, 0x2247]),
+    (0x33DD, 0x33DD, &[0x2259, 0x1FBC]),
+    (0x33DE, 0x33DE, &[0x2247, 0x0675, 0x2109]),
+    (0x33DF, 0x33DF, &[0x1FA2, 0x0675, 0x2109]),
+    (0x33E0, 0x33E0, &[0x1F99, 0xFB40, 0xE5E5]),
+    (0x33E1, 0x33E1, &[0x1F9A, 0xFB40, 0xE5E5]),
+    (0x33E2, 0x33E2, &[0x1F9B, 0xFB40, 0xE5E5]),
+    (0x33E3, 0x33E3, &[0x1F9C, 0xFB40, 0xE5E5]),
+    (0x33E4, 0x33E4, &[0x1F9D, 0xFB40, 0xE5E5]),
+    (0x33E5, 0x33E5, &[0x1F9E, 0xFB40, 0xE5E5]),
+    (0x33E6, 0x33E6, &[0x1F9F, 0xFB40, 0xE5E5]),
+    (0x33E7, 0x33E7, &[0x1FA0, 0xFB40, 0xE5E5]),
+    (0x33E8, 0x33E8, &[0x1FA1, 0xFB40, 0xE5E5]),
+    (0x33E9, 0x33E9, &[0x1F99, 0x1F98, 0xFB40, 0xE5E5]),
+    (0x33EA, 0x33EA, &[0x1F99, 0x1F99, 0xFB40, 0xE5E5]),
+    (0x33EB, 0x33EB, &[0x1F99, 0x1F9A, 0xFB40, 0xE5E5]),
+    (0x33EC, 0x33EC, &[0x1F99, 0x1F9B, 0xFB40, 0xE5E5]),
+    (0x33ED, 0x33ED, &[0x1F99, 0x1F9C, 0xFB40, 0xE5E5]),
+    (0x33EE, 0x33EE, &[0x1F99, 0x1F9D, 0xFB40, 0xE5E5]),
+    (0x33EF, 0x33EF, &[0x1F99, 0x1F9E, 0xFB40, 0xE5E5]),
+    (0x33F0, 0x33F0, &[0x1F99, 0x1F9F, 0xFB40, 0xE5E5]),
+    (0x33F1, 0x33F1, &[0x1F99, 0x1FA0, 0xFB40, 0xE5E5]),
+    (0x33F2, 0x33F2, &[0x1F99, 0x1FA1, 0xFB40, 0xE5E5]),
+    (0x33F3, 0x33F3, &[0x1F9A, 0x1F98, 0xFB40, 0xE5E5]),
+    (0x33F4, 0x33F4, &[0x1F9A, 0x1F99, 0xFB40, 0xE5E5]),
+    (0x33F5, 0x33F5, &[0x1F9A, 0x1F9A, 0xFB40, 0xE5E5]),
+    (0x33F6, 0x33F6, &[0x1F9A, 0x1F9B, 0xFB40, 0xE5E5]),
+    (0x33F7, 0x33F7, &[0x1F9A, 0x1F9C, 0xFB40, 0xE5E5]),
+    (0x33F8, 0x33F8, &[0x1F9A, 0x1F9D, 0xFB40, 0xE5E5]),
+    (0x33F9, 0x33F9, &[0x1F9A, 0x1F9E, 0xFB40, 0xE5E5]),
+    (0x33FA, 0x33FA, &[0x1F9A, 0x1F9F, 0xFB40, 0xE5E5]),
+    (0x33FB, 0x33FB, &[0x1F9A, 0x1FA0, 0xFB40, 0xE5E5]),
+    (0x33FC, 0x33FC, &[0x1F9A, 0x1FA1, 0xFB40, 0xE5E5]),
+    (0x33FD, 0x33FD, &[0x1F9B, 0x1F98, 0xFB40, 0xE5E5]),
+    (0x33FE, 0x33FE, &[0x1F9B, 0x1F99, 0xFB40, 0xE5E5]),
+    (0x33FF, 0x33FF, &[0x2051, 0x1FA2, 0x20D6]),
+    (0x4DC0, 0x4DFF, &[0x0FF6]),
+    (0xA000, 0xA48C, &[0x4461]),
+    (0xA490, 0xA4C6, &[0x108D]),
+    (0xA4D0, 0xA4E9, &[0x48F4]),
+    (0xA4EA, 0xA4EA, &[0x4910]),
+    (0xA4EB, 0xA4EB, &[0x490E]),
+    (0xA4EC, 0xA4EC, &[0x4911]),
+    (0xA4ED, 0xA4ED, &[0x490F]),
+    (0xA4EE, 0xA4F7, &[0x4913]),
+    (0xA4F8, 0xA4FB, &[0x48EE]),
+    (0xA4FC, 0xA4FC, &[0x48F3]),
+    (0xA4FD, 0xA4FD, &[0x48F2]),
+    (0xA4FE, 0xA4FE, &[0x0232]),
+    (0xA4FF, 0xA4FF, &[0x028C]),
+    (0xA500, 0xA60C, &[0x3CB9]),
+    (0xA60D, 0xA60D, &[0x0233]),
+    (0xA60E, 0xA60E, &[0x028D]),
+    (0xA60F, 0xA60F, &[0x0277]),
+    (0xA610, 0xA610, &[0x3D11]),
+    (0xA611, 0xA611, &[0x3D23]),
+    (0xA612, 0xA612, &[0x3D40]),
+    (0xA613, 0xA613, &[0x3CC5, 0x3DC4]),
+    (0xA614, 0xA614, &[0x3CD7, 0x3DC4]),
+    (0xA615, 0xA615, &[0x3CEC, 0x3DC4]),
+    (0xA616, 0xA616, &[0x3D00, 0x3DC5]),
+    (0xA617, 0xA617, &[0x3D0B, 0x3DC4]),
+    (0xA618, 0xA618, &[0x3D11, 0x3DC5]),
+    (0xA619, 0xA619, &[0x3D13, 0x3DC5]),
+    (0xA61A, 0xA61A, &[0x3D19, 0x3DC4]),
+    (0xA61B, 0xA61B, &[0x3D3E, 0x3DC4]),
+    (0xA61C, 0xA61C, &[0x3D6D, 0x3DC4]),
+    (0xA61D, 0xA61D, &[0x3D84, 0x3DC4]),
+    (0xA61E, 0xA61E, &[0x3D8A, 0x3DC5]),
+    (0xA61F, 0xA61F, &[0x3D91, 0x3DC4]),
+    (0xA620, 0xA629, &[0x1F98]),
+    (0xA62A, 0xA62A, &[0x3D27]),
+    (0xA62B, 0xA62B, &[0x3D8A]),
+    (0xA640, 0xA640, &[0x23D5]),
+    (0xA641, 0xA641, &[0x23D5]),
+    (0xA642, 0xA642, &[0x23D8]),
+    (0xA643, 0xA643, &[0x23D8]),
+    (0xA644, 0xA644, &[0x23DD]),
+    (0xA645, 0xA645, &[0x23DD]),
+    (0xA646, 0xA646, &[0x23F1]),
+    (0xA647, 0xA647, &[0x23F1]),
+    (0xA648, 0xA648, &[0x23FA]),
+    (0xA649, 0xA649, &[0x23FA]),
+    (0xA64A, 0xA64A, &[0x248E]),
+    (0xA64B, 0xA64B, &[0x248E]),
+    (0xA64C, 0xA64C, &[0x24B5]),
+    (0xA64D, 0xA64D, &[0x24B5]),
+    (0xA64E, 0xA64E, &[0x24F1]),
+    (0xA64F, 0xA64F, &[0x24F1]),
+    (0xA650, 0xA650, &[0x24F8]),
+    (0xA651, 0xA651, &[0x24F8]),
+    (0xA652, 0xA652, &[0x2509]),
+    (0xA653, 0xA653, &[0x2509]),
+    (0xA654, 0xA654, &[0x2512]),
+    (0xA655, 0xA656, &[0x2512]),
+    (0xA657, 0xA657, &[0x2513]),
+    (0xA658, 0xA658, &[0x2521]),
+    (0xA659, 0xA659, &[0x2521]),
+    (0xA65A, 0xA65A, &[0x2526]),
+    (0xA65B, 0xA65B, &[0x2526]),
+    (0xA65C, 0xA65C, &[0x252B]),
+    (0xA65D, 0xA65D, &[0x252B]),
+    (0xA65E, 0xA65E, &[0x2540]),
+    (0xA65F, 0xA65F, &[0x2540]),
+    (0xA660, 0xA660, &[0x24C2]),
+    (0xA661, 0xA661, &[0x24C2]),
+    (0xA662, 0xA662, &[0x23B9]),
+    (0xA663, 0xA663, &[0x23B9]),
+    (0xA664, 0xA664, &[0x2425]),
+    (0xA665, 0xA665, &[0x2425]),
+    (0xA666, 0xA666, &[0x2430]),
+    (0xA667, 0xA667, &[0x2430]),
+    (0xA668, 0xA668, &[0x244C]),
+    (0xA669, 0xA669, &[0x244C]),
+    (0xA66A, 0xA66A, &[0x244C]),
+    (0xA66B, 0xA66B, &[0x244C]),
+    (0xA66C, 0xA66C, &[0x244C]),
+    (0xA66D, 0xA66D, &[0x244C]),
+    (0xA66E, 0xA66E, &[0x244C]),
+    (0xA66F, 0xA672, &[]),
+    (0xA673, 0xA673, &[0x03A5]),
+    (0xA674, 0xA674, &[0x23C3]),
+    (0xA675, 0xA675, &[0x23E5]),
+    (0xA676, 0xA676, &[0x23ED]),
+    (0xA677, 0xA677, &[0x2482]),
+    (0xA678, 0xA678, &[0x24F4]),
+    (0xA679, 0xA679, &[0x24F9]),
+    (0xA67A, 0xA67A, &[0x24FD]),
+    (0xA67B, 0xA67B, &[0x24AD]),
+    (0xA67C, 0xA67D, &[]),
+    (0xA67E, 0xA67E, &[0x03E3]),
+    (0xA67F, 0xA67F, &[0x24F3]),
+    (0xA680, 0xA680, &[0x23B4]),
+    (0xA681, 0xA681, &[0x23B4]),
+    (0xA682, 0xA682, &[0x23E4]),
+    (0xA683, 0xA683, &[0x23E4]),
+    (0xA684, 0xA684, &[0x23CC]),
+    (0xA685, 0xA685, &[0x23CC]),
+    (0xA686, 0xA686, &[0x24DB]),
+    (0xA687, 0xA687, &[0x24DB]),
+    (0xA688, 0xA688, &[0x23E2]),
+    (0xA689, 0xA689, &[0x23E2]),
+    (0xA68A, 0xA68A, &[0x247D]),
+    (0xA68B, 0xA68B, &[0x247D]),
+    (0xA68C, 0xA68C, &[0x2477]),
+    (0xA68D, 0xA68D, &[0x2477]),
+    (0xA68E, 0xA68E, &[0x24C3]),
+    (0xA68F, 0xA68F, &[0x24C3]),
+    (0xA690, 0xA690, &[0x24C8]),
+    (0xA691, 0xA691, &[0x24C8]),
+    (0xA692, 0xA692, &[0x24CE]),
+    (0xA693, 0xA693, &[0x24CE]),
+    (0xA694, 0xA694, &[0x24AC]),
+    (0xA695, 0xA695, &[0x24AC]),
+    (0xA696, 0xA696, &[0x24EC]),
+    (0xA697, 0xA697, &[0x24EC]),
+    (0xA698, 0xA698, &[0x244C]),
+    (0xA699, 0xA699, &[0x244C]),
+    (0xA69A, 0xA69A, &[0x244C]),
+    (0xA69B, 0xA69B, &[0x244C]),
+    (0xA69C, 0xA69C, &[0x24F4]),
+    (0xA69D, 0xA69D, &[0x24FD]),
+    (0xA69E, 0xA69E, &[0x2493]),
+    (0xA69F, 0xA69F, &[0x2519]),
+    (0xA6A0, 0xA6EF, &[0x3DC6]),
+    (0xA6F0, 0xA6F1, &[]),
+    (0xA6F2, 0xA6F2, &[0x02E0]),
+    (0xA6F3, 0xA6F3, &[0x028E]),
+    (0xA6F4, 0xA6F4, &[0x0266]),
+    (0xA6F5, 0xA6F5, &[0x0234]),
+    (0xA6F6, 0xA6F6, &[0x023E]),
+    (0xA6F7, 0xA6F7, &[0x0278]),
+    (0xA700, 0xA721, &[0x0504]),
+    (0xA722, 0xA722, &[0x22E6]),
+    (0xA723, 0xA723, &[0x22E6]),
+    (0xA724, 0xA724, &[0x22F1]),
+    (0xA725, 0xA725, &[0x22F1]),
+    (0xA726, 0xA726, &[0x2089]),
+    (0xA727, 0xA727, &[0x2089]),
+    (0xA728, 0xA728, &[0x21F7, 0x2286]),
+    (0xA729, 0xA729, &[0x21F7, 0x2286]),
+    (0xA72A, 0xA72A, &[0x22CB]),
+    (0xA72B, 0xA72C, &[0x22CB]),
+    (0xA72D, 0xA72E, &[0x22CC]),
+    (0xA72F, 0xA72F, &[0x22CD]),
+    (0xA730, 0xA730, &[0x2046]),
+    (0xA731, 0xA731, &[0x21D6]),
+    (0xA732, 0xA732, &[0x1FA2, 0x1FA2]),
+    (0xA733, 0xA733, &[0x1FA2, 0x1FA2]),
+    (0xA734, 0xA734, &[0x1FA2, 0x213C]),
+    (0xA735, 0xA735, &[0x1FA2, 0x213C]),
+    (0xA736, 0xA736, &[0x1FA2, 0x2217]),
+    (0xA737, 0xA737, &[0x1FA2, 0x2217]),
+    (0xA738, 0xA738, &[0x1FA2, 0x2247]),
+    (0xA739, 0xA739, &[0x1FA2, 0x2247]),
+    (0xA73A, 0xA73A, &[0x1FA2, 0x2247]),
+    (0xA73B, 0xA73B, &[0x1FA2, 0x2247]),
+    (0xA73C, 0xA73C, &[0x1FA2, 0x2270]),
+    (0xA73D, 0xA73D, &[0x1FA2, 0x2270]),
+    (0xA73E, 0xA73E, &[0x1FEA]),
+    (0xA73F, 0xA73F, &[0x1FEA]),
+    (0xA740, 0xA740, &[0x20CF]),
+    (0xA741, 0xA742, &[0x20CF]),
+    (0xA743, 0xA744, &[0x20D0]),
+    (0xA745, 0xA745, &[0x20D1]),
+    (0xA746, 0xA746, &[0x20DE]),
+    (0xA747, 0xA747, &[0x20DE]),
+    (0xA748, 0xA748, &[0x20E0]),
+    (0xA749, 0xA749, &[0x20E0]),
+    (0xA74A, 0xA74A, &[0x2160]),
+    (0xA74B, 0xA74B, &[0x2160]),
+    (0xA74C, 0xA74C, &[0x2158]),
+    (0xA74D, 0xA74D, &[0x2158]),
+    (0xA74E, 0xA74E, &[0x213C, 0x213C]),
+    (0xA74F, 0xA74F, &[0x213C, 0x213C]),
+    (0xA750, 0xA750, &[0x2171]),
+    (0xA751, 0xA751, &[0x2171]),
+    (0xA752, 0xA752, &[0x2178]),
+    (0xA753, 0xA754, &[0x2178]),
+    (0xA755, 0xA755, &[0x2179]),
+    (0xA756, 0xA756, &[0x2185]),
+    (0xA757, 0xA758, &[0x2185]),
+    (0xA759, 0xA759, &[0x2186]),
+    (0xA75A, 0xA75A, &[0x219D]),
+    (0xA75B, 0xA75B, &[0x219D]),
+    (0xA75C, 0xA75C, &[0x21D1]),
+    (0xA75D, 0xA75D, &[0x21D1]),
+    (0xA75E, 0xA75E, &[0x224C]),
+    (0xA75F, 0xA75F, &[0x224C]),
+    (0xA760, 0xA760, &[0x2247, 0x2270]),
+    (0xA761, 0xA761, &[0x2247, 0x2270]),
+    (0xA762, 0xA762, &[0x22A2]),
+    (0xA763, 0xA763, &[0x22A2]),
+    (0xA764, 0xA764, &[0x22B9]),
+    (0xA765, 0xA766, &[0x22B9]),
+    (0xA767, 0xA767, &[0x22BA]),
+    (0xA768, 0xA768, &[0x22BF]),
+    (0xA769, 0xA769, &[0x22BF]),
+    (0xA76A, 0xA76A, &[0x22C3]),
+    (0xA76B, 0xA76C, &[0x22C3]),
+    (0xA76D, 0xA76E, &[0x22C4]),
+    (0xA76F, 0xA76F, &[0x22C5]),
+    (0xA770, 0xA770, &[0x22C5]),
+    (0xA771, 0xA771, &[0x2005]),
+    (0xA772, 0xA772, &[0x20FB]),
+    (0xA773, 0xA773, &[0x2117]),
+    (0xA774, 0xA774, &[0x2136]),
+    (0xA775, 0xA776, &[0x21CF]),
+    (0xA777, 0xA777, &[0x2212]),
+    (0xA778, 0xA778, &[0x22C6]),
+    (0xA779, 0xA779, &[0x1FEB]),
+    (0xA77A, 0xA77A, &[0x1FEB]),
+    (0xA77B, 0xA77B, &[0x2042]),
+    (0xA77C, 0xA77C, &[0x2042]),
+    (0xA77D, 0xA77D, &[0x2051]),
+    (0xA77E, 0xA77E, &[0x206C]),
+    (0xA77F, 0xA77F, &[0x206C]),
+    (0xA780, 0xA780, &[0x2100]),
+    (0xA781, 0xA781, &[0x2100]),
+    (0xA782, 0xA782, &[0x2193]),
+    (0xA783, 0xA783, &[0x2193]),
+    (0xA784, 0xA784, &[0x21D2]),
+    (0xA785, 0xA785, &[0x21D2]),
+    (0xA786, 0xA786, &[0x21F7]),
+    (0xA787, 0xA787, &[0x21F7]),
+    (0xA788, 0xA78A, &[0x0526]),
+    (0xA78B, 0xA78B, &[0x22E7]),
+    (0xA78C, 0xA78C, &[0x22E7]),
+    (0xA78D, 0xA78D, &[0x222C]),
+    (0xA78E, 0xA78E, &[0x20F6]),
+    (0xA78F, 0xA78F, &[0x22E8]),
+    (0xA790, 0xA790, &[0x212B]),
+    (0xA791, 0xA791, &[0x212B]),
+    (0xA792, 0xA792, &[0x1FDF]),
+    (0xA793, 0xA794, &[0x1FDF]),
+    (0xA795, 0xA795, &[0x2081]),
+    (0xA796, 0xA796, &[0x1FCB]),
+    (0xA797, 0xA797, &[0x1FCB]),
+    (0xA798, 0xA798, &[0x2048]),
+    (0xA799, 0xA799, &[0x2048]),
+    (0xA79A, 0xA79A, &[0x1FA2]),
+    (0xA79B, 0xA79B, &[0x1FA2]),
+    (0xA79C, 0xA79C, &[0x213C]),
+    (0xA79D, 0xA79D, &[0x213C]),
+    (0xA79E, 0xA79E, &[0x2217]),
+    (0xA79F, 0xA79F, &[0x2217]),
+    (0xA7A0, 0xA7A0, &[0x2051]),
+    (0xA7A1, 0xA7A1, &[0x2051]),
+    (0xA7A2, 0xA7A2, &[0x20C4]),
+    (0xA7A3, 0xA7A3, &[0x20C4]),
+    (0xA7A4, 0xA7A4, &[0x2118]),
+    (0xA7A5, 0xA7A5, &[0x2118]),
+    (0xA7A6, 0xA7A6, &[0x2193]),
+    (0xA7A7, 0xA7A7, &[0x2193]),
+    (0xA7A8, 0xA7A8, &[0x21D2]),
+    (0xA7A9, 0xA7A9, &[0x21D2]),
+    (0xA7AA, 0xA7AA, &[0x2082]),
+    (0xA7AB, 0xA7AB, &[0x202C]),
+    (0xA7AC, 0xA7AC, &[0x2055]),
+    (0xA7AD, 0xA7AD, &[0x20EC]),
+    (0xA7AE, 0xA7AE, &[0x2098]),
+    (0xA7AF, 0xA7AF, &[0x2184]),
+    (0xA7B0, 0xA7B0, &[0x20D2]),
+    (0xA7B1, 0xA7B1, &[0x2213]),
+    (0xA7B2, 0xA7B2, &[0x20B8]),
+    (0xA7B3, 0xA7B3, &[0x226D]),
+    (0xA7B4, 0xA7B4, &[0x1FD5]),
+    (0xA7B5, 0xA7B5, &[0x1FD5]),
+    (0xA7B6, 0xA7B6, &[0x2165]),
+    (0xA7B7, 0xA7B7, &[0x2165]),
+    (0xA7B8, 0xA7B8, &[0x2227]),
+    (0xA7B9, 0xA7B9, &[0x2227]),
+    (0xA7BA, 0xA7BA, &[0x1FA9]),
+    (0xA7BB, 0xA7BB, &[0x1FA9]),
+    (0xA7BC, 0xA7BC, &[0x20A5]),
+    (0xA7BD, 0xA7BD, &[0x20A5]),
+    (0xA7BE, 0xA7BE, &[0x222B]),
+    (0xA7BF, 0xA7BF, &[0x222B]),
+    (0xA7C2, 0xA7C2, &[0x225E]),
+    (0xA7C3, 0xA7C3, &[0x225E]),
+    (0xA7C4, 0xA7C4, &[0x1FE0]),
+    (0xA7C5, 0xA7C5, &[0x21DA]),
+    (0xA7C6, 0xA7C6, &[0x2290]),
+    (0xA7C7, 0xA7C7, &[0x1FF1]),
+    (0xA7C8, 0xA7C8, &[0x1FF1]),
+    (0xA7C9, 0xA7C9, &[0x21D7]),
+    (0xA7CA, 0xA7CA, &[0x21D7]),
+    (0xA7F5, 0xA7F5, &[0x2088]),
+    (0xA7F6, 0xA7F6, &[0x2088]),
+    (0xA7F7, 0xA7F7, &[0x209D]),
+    (0xA7F8, 0xA7F8, &[0x2075]),
+    (0xA7F9, 0xA7F9, &[0x213C, 0x2007]),
+    (0xA7FA, 0xA7FA, &[0x223C]),
+    (0xA7FB, 0xA7FB, &[0x2050]),
+    (0xA7FC, 0xA7FC, &[0x217A]),
+    (0xA7FD, 0xA7FD, &[0x2115]),
+    (0xA7FE, 0xA7FE, &[0x209C]),
+    (0xA7FF, 0xA7FF, &[0x2116]),
+    (0xA800, 0xA806, &[0x2CDC]),
+    (0xA807, 0xA80A, &[0x2CE4]),
+    (0xA80B, 0xA80B, &[]),
+    (0xA80C, 0xA827, &[0x2CE8]),
+    (0xA828, 0xA82B, &[0x056C]),
+    (0xA82C, 0xA82C, &[0x2CE3]),
+    (0xA830, 0xA835, &[0x1D62]),
+    (0xA836, 0xA837, &[0x0570]),
+    (0xA838, 0xA838, &[0x1F6F]),
+    (0xA839, 0xA839, &[0x0572]),
+    (0xA840, 0xA847, &[0x3420]),
+    (0xA848, 0xA853, &[0x342C]),
+    (0xA854, 0xA857, &[0x3439]),
+    (0xA858, 0xA858, &[0x343F]),
+    (0xA859, 0xA85A, &[0x3442]),
+    (0xA85B, 0xA85C, &[0x3445]),
+    (0xA85D, 0xA85D, &[0x3449]),
+    (0xA85E, 0xA861, &[0x344E]),
+    (0xA862, 0xA865, &[0x344A]),
+    (0xA866, 0xA866, &[0x3452]),
+    (0xA867, 0xA867, &[0x3438]),
+    (0xA868, 0xA868, &[0x343D]),
+    (0xA869, 0xA86C, &[0x3428]),
+    (0xA86D, 0xA86D, &[0x343E]),
+    (0xA86E, 0xA86E, &[0x3444]),
+    (0xA86F, 0xA870, &[0x3447]),
+    (0xA871, 0xA872, &[0x3440]),
+    (0xA873, 0xA873, &[0x3453]),
+    (0xA874, 0xA875, &[0x0454]),
+    (0xA876, 0xA877, &[0x029C]),
+    (0xA880, 0xA881, &[]),
+    (0xA882, 0xA8C4, &[0x2D04]),
+    (0xA8C5, 0xA8C5, &[]),
+    (0xA8CE, 0xA8CF, &[0x0298]),
+    (0xA8D0, 0xA8D9, &[0x1F98]),
+    (0xA8E0, 0xA8F1, &[]),
+    (0xA8F2, 0xA8F2, &[0x2A21]),
+    (0xA8F3, 0xA8F3, &[0x2A21]),
+    (0xA8F4, 0xA8F4, &[0x2A21]),
+    (0xA8F5, 0xA8F5, &[0x2A21]),
+    (0xA8F6, 0xA8F6, &[0x2A21]),
+    (0xA8F7, 0xA8F7, &[0x2A21]),
+    (0xA8F8, 0xA8FA, &[0x0401]),
+    (0xA8FB, 0xA8FB, &[0x2A22]),
+    (0xA8FC, 0xA8FC, &[0x0404]),
+    (0xA8FD, 0xA8FD, &[0x29D8]),
+    (0xA8FE, 0xA8FE, &[0x29EE]),
+    (0xA8FF, 0xA8FF, &[0x2A37]),
+    (0xA900, 0xA909, &[0x1F98]),
+    (0xA90A, 0xA92A, &[0x354B]),
+    (0xA92B, 0xA92D, &[]),
+    (0xA92E, 0xA92E, &[0x045A]),
+    (0xA92F, 0xA92F, &[0x02A0]),
+    (0xA930, 0xA953, &[0x3527]),
+    (0xA95F, 0xA95F, &[0x02E1]),
+    (0xA960, 0xA97C, &[0x41D4]),
+    (0xA980, 0xA983, &[]),
+    (0xA984, 0xA9AA, &[0x37FC]),
+    (0xA9AB, 0xA9AB, &[0x3824]),
+    (0xA9AC, 0xA9AC, &[0x3824]),
+    (0xA9AD, 0xA9B2, &[0x3826]),
+    (0xA9B3, 0xA9B3, &[]),
+    (0xA9B4, 0xA9B4, &[0x382C]),
+    (0xA9B5, 0xA9B5, &[0x382C]),
+    (0xA9B6, 0xA9B9, &[0x382E]),
+    (0xA9BA, 0xA9BB, &[0x3833]),
+    (0xA9BC, 0xA9BC, &[0x382D]),
+    (0xA9BD, 0xA9BD, &[0x3832]),
+    (0xA9BE, 0xA9BE, &[0x3823]),
+    (0xA9BF, 0xA9BF, &[0x3825]),
+    (0xA9C0, 0xA9C0, &[0x3835]),
+    (0xA9C1, 0xA9C6, &[0x02D6]),
+    (0xA9C7, 0xA9C7, &[0x0262]),
+    (0xA9C8, 0xA9C9, &[0x02AB]),
+    (0xA9CA, 0xA9CD, &[0x02DC]),
+    (0xA9CF, 0xA9CF, &[0x1F4D]),
+    (0xA9D0, 0xA9D9, &[0x1F98]),
+    (0xA9DE, 0xA9DF, &[0x045B]),
+    (0xA9E0, 0xA9E0, &[0x3575]),
+    (0xA9E1, 0xA9E1, &[0x357D]),
+    (0xA9E2, 0xA9E2, &[0x3587]),
+    (0xA9E3, 0xA9E3, &[0x359D]),
+    (0xA9E4, 0xA9E4, &[0x35B6]),
+    (0xA9E5, 0xA9E5, &[0x3600]),
+    (0xA9E6, 0xA9E6, &[0x1F4E]),
+    (0xA9E7, 0xA9E7, &[0x358F]),
+    (0xA9E8, 0xA9E8, &[0x35B1]),
+    (0xA9E9, 0xA9E9, &[0x3573]),
+    (0xA9EA, 0xA9EA, &[0x3576]),
+    (0xA9EB, 0xA9EB, &[0x3582]),
+    (0xA9EC, 0xA9EC, &[0x3589]),
+    (0xA9ED, 0xA9ED, &[0x3597]),
+    (0xA9EE, 0xA9EE, &[0x359A]),
+    (0xA9EF, 0xA9EF, &[0x359E]),
+    (0xA9F0, 0xA9F9, &[0x1F98]),
+    (0xA9FA, 0xA9FA, &[0x35D2]),
+    (0xA9FB, 0xA9FB, &[0x35A3]),
+    (0xA9FC, 0xA9FC, &[0x35A6]),
+    (0xA9FD, 0xA9FD, &[0x35B4]),
+    (0xA9FE, 0xA9FE, &[0x35B7]),
+    (0xAA00, 0xAA28, &[0x3771]),
+    (0xAA29, 0xAA32, &[0x379E]),
+    (0xAA33, 0xAA36, &[0x379A]),
+    (0xAA40, 0xAA4D, &[0x37A8]),
+    (0xAA50, 0xAA59, &[0x1F98]),
+    (0xAA5C, 0xAA5C, &[0x045D]),
+    (0xAA5D, 0xAA5F, &[0x02AD]),
+    (0xAA60, 0xAA60, &[0x3572]),
+    (0xAA61, 0xAA61, &[0x357B]),
+    (0xAA62, 0xAA62, &[0x357E]),
+    (0xAA63, 0xAA63, &[0x3581]),
+    (0xAA64, 0xAA64, &[0x3588]),
+    (0xAA65, 0xAA65, &[0x358E]),
+    (0xAA66, 0xAA66, &[0x3592]),
+    (0xAA67, 0xAA67, &[0x3594]),
+    (0xAA68, 0xAA68, &[0x3596]),
+    (0xAA69, 0xAA69, &[0x3599]),
+    (0xAA6A, 0xAA6A, &[0x35A5]),
+    (0xAA6B, 0xAA6B, &[0x35A9]),
+    (0xAA6C, 0xAA6C, &[0x35CA]),
+    (0xAA6D, 0xAA6D, &[0x35CD]),
+    (0xAA6E, 0xAA6E, &[0x35CF]),
+    (0xAA6F, 0xAA6F, &[0x35AF]),
+    (0xAA70, 0xAA70, &[0x1F4F]),
+    (0xAA71, 0xAA71, &[0x35D0]),
+    (0xAA72, 0xAA72, &[0x3584]),
+    (0xAA73, 0xAA73, &[0x35BD]),
+    (0xAA74, 0xAA76, &[0x3618]),
+    (0xAA77, 0xAA79, &[0x059B]),
+    (0xAA7A, 0xAA7A, &[0x35BE]),
+    (0xAA7B, 0xAA7D, &[0x3615]),
+    (0xAA7E, 0xAA7E, &[0x357F]),
+    (0xAA7F, 0xAA7F, &[0x358B]),
+    (0xAA80, 0xAABE, &[0x3296]),
+    (0xAABF, 0xAABF, &[]),
+    (0xAAC0, 0xAAC0, &[0x32D5]),
+    (0xAAC1, 0xAAC1, &[]),
+    (0xAAC2, 0xAAC2, &[0x32D6]),
+    (0xAADB, 0xAADC, &[0x32D7]),
+    (0xAADD, 0xAADD, &[0x1F50]),
+    (0xAADE, 0xAADF, &[0x040E]),
+    (0xAAE0, 0xAAEA, &[0x2CB9]),
+    (0xAAEB, 0xAAEF, &[0x2CCC]),
+    (0xAAF0, 0xAAF0, &[0x02B0]),
+    (0xAAF1, 0xAAF1, &[0x0279]),
+    (0xAAF2, 0xAAF2, &[0x2C9D]),
+    (0xAAF3, 0xAAF4, &[0x1F51]),
+    (0xAAF5, 0xAAF5, &[0x2CD1]),
+    (0xAAF6, 0xAAF6, &[0x2CDB]),
+    (0xAB01, 0xAB06, &[0x2852]),
+    (0xAB09, 0xAB0E, &[0x2919]),
+    (0xAB11, 0xAB16, &[0x28FA]),
+    (0xAB20, 0xAB26, &[0x295C]),
+    (0xAB28, 0xAB2E, &[0x2974]),
+    (0xAB30, 0xAB30, &[0x1FB5]),
+    (0xAB31, 0xAB31, &[0x1FAC]),
+    (0xAB32, 0xAB33, &[0x200C]),
+    (0xAB34, 0xAB34, &[0x2013]),
+    (0xAB35, 0xAB35, &[0x2047]),
+    (0xAB36, 0xAB36, &[0x2059]),
+    (0xAB37, 0xAB37, &[0x20F0]),
+    (0xAB38, 0xAB39, &[0x20EA]),
+    (0xAB3A, 0xAB3A, &[0x2114]),
+    (0xAB3B, 0xAB3B, &[0x2135]),
+    (0xAB3C, 0xAB3C, &[0x213B]),
+    (0xAB3D, 0xAB3D, &[0x2142]),
+    (0xAB3E, 0xAB3E, &[0x214E]),
+    (0xAB3F, 0xAB3F, &[0x2155]),
+    (0xAB40, 0xAB40, &[0x214A]),
+    (0xAB41, 0xAB42, &[0x2148]),
+    (0xAB43, 0xAB44, &[0x214B]),
+    (0xAB45, 0xAB45, &[0x2197]),
+    (0xAB46, 0xAB46, &[0x219C]),
+    (0xAB47, 0xAB48, &[0x21C5]),
+    (0xAB49, 0xAB49, &[0x21BB]),
+    (0xAB4A, 0xAB4C, &[0x21C7]),
+    (0xAB4D, 0xAB4D, &[0x21E8]),
+    (0xAB4E, 0xAB4E, &[0x221C]),
+    (0xAB4F, 0xAB4F, &[0x2226]),
+    (0xAB50, 0xAB51, &[0x2220]),
+    (0xAB52, 0xAB52, &[0x222A]),
+    (0xAB53, 0xAB55, &[0x226D]),
+    (0xAB56, 0xAB59, &[0x2269]),
+    (0xAB5A, 0xAB5A, &[0x2281]),
+    (0xAB5B, 0xAB5B, &[0x0529]),
+    (0xAB5C, 0xAB5C, &[0x2089]),
+    (0xAB5D, 0xAB5D, &[0x20F0]),
+    (0xAB5E, 0xAB5E, &[0x20E6]),
+    (0xAB5F, 0xAB5F, &[0x222A]),
+    (0xAB60, 0xAB61, &[0x22C0]),
+    (0xAB62, 0xAB62, &[0x2157]),
+    (0xAB63, 0xAB63, &[0x22C2]),
+    (0xAB64, 0xAB64, &[0x1FBB]),
+    (0xAB65, 0xAB65, &[0x2347]),
+    (0xAB66, 0xAB66, &[0x1FEB, 0x2295]),
+    (0xAB67, 0xAB67, &[0x21F7, 0x21DA]),
+    (0xAB68, 0xAB68, &[0x21CE]),
+    (0xAB69, 0xAB69, &[0x2260]),
+    (0xAB6A, 0xAB6B, &[0x04DA]),
+    (0xAB70, 0xABBF, &[0x38DE]),
+    (0xABC0, 0xABDA, &[0x2C9E]),
+    (0xABDB, 0xABE2, &[0x2CD2]),
+    (0xABE3, 0xABEA, &[0x2CC4]),
+    (0xABEB, 0xABEB, &[0x02B1]),
+    (0xABEC, 0xABEC, &[]),
+    (0xABED, 0xABED, &[0x2CDA]),
+    (0xABF0, 0xABF9, &[0x1F98]),
+    (0xD7B0, 0xD7C6, &[0x423A]),
+    (0xD7CB, 0xD7FB, &[0x42A9]),
+    (0xF900, 0xF900, &[0xFB41, 0x8C48]),
+    (0xF901, 0xF901, &[0xFB40, 0xE6F4]),
+    (0xF902, 0xF902, &[0xFB41, 0x8ECA]),
+    (0xF903, 0xF903, &[0xFB41, 0x8CC8]),
+    (0xF904, 0xF904, &[0xFB40, 0xEED1]),
+    (0xF905, 0xF905, &[0xFB40, 0xCE32]),
+    (0xF906, 0xF906, &[0xFB40, 0xD3E5]),
+    (0xF907, 0xF907, &[0xFB41, 0x9F9C]),
+    (0xF908, 0xF908, &[0xFB41, 0x9F9C]),
+    (0xF909, 0xF909, &[0xFB40, 0xD951]),
+    (0xF90A, 0xF90A, &[0xFB41, 0x91D1]),
+    (0xF90B, 0xF90B, &[0xFB40, 0xD587]),
+    (0xF90C, 0xF90C, &[0xFB40, 0xD948]),
+    (0xF90D, 0xF90D, &[0xFB40, 0xE1F6]),
+    (0xF90E, 0xF90E, &[0xFB40, 0xF669]),
+    (0xF90F, 0xF90F, &[0xFB40, 0xFF85]),
+    (0xF910, 0xF910, &[0xFB41, 0x863F]),
+    (0xF911, 0xF911, &[0xFB41, 0x87BA]),
+    (0xF912, 0xF912, &[0xFB41, 0x88F8]),
+    (0xF913, 0xF913, &[0xFB41, 0x908F]),
+    (0xF914, 0xF914, &[0xFB40, 0xEA02]),
+    (0xF915, 0xF915, &[0xFB40, 0xED1B]),
+    (0xF916, 0xF916, &[0xFB40, 0xF0D9]),
+    (0xF917, 0xF917, &[0xFB40, 0xF3DE]),
+    (0xF918, 0xF918, &[0xFB41, 0x843D]),
+    (0xF919, 0xF919, &[0xFB41, 0x916A]),
+    (0xF91A, 0xF91A, &[0xFB41, 0x99F1]),
+    (0xF91B, 0xF91B, &[0xFB40, 0xCE82]),
+    (0xF91C, 0xF91C, &[0xFB40, 0xD375]),
+    (0xF91D, 0xF91D, &[0xFB40, 0xEB04]),
+    (0xF91E, 0xF91E, &[0xFB40, 0xF21B]),
+    (0xF91F, 0xF91F, &[0xFB41, 0x862D]),
+    (0xF920, 0xF920, &[0xFB41, 0x9E1E]),
+    (0xF921, 0xF921, &[0xFB40, 0xDD50]),
+    (0xF922, 0xF922, &[0xFB40, 0xEFEB]),
+    (0xF923, 0xF923, &[0xFB41, 0x85CD]),
+    (0xF924, 0xF924, &[0xFB41, 0x8964]),
+    (0xF925, 0xF925, &[0xFB40, 0xE2C9]),
+    (0xF926, 0xF926, &[0xFB41, 0x81D8]),
+    (0xF927, 0xF927, &[0xFB41, 0x881F]),
+    (0xF928, 0xF928, &[0xFB40, 0xDECA]),
+    (0xF929, 0xF929, &[0xFB40, 0xE717]),
+    (0xF92A, 0xF92A, &[0xFB40, 0xED6A]),
+    (0xF92B, 0xF92B, &[0xFB40, 0xF2FC]),
+    (0xF92C, 0xF92C, &[0xFB41, 0x90CE]),
+    (0xF92D, 0xF92D, &[0xFB40, 0xCF86]),
+    (0xF92E, 0xF92E, &[0xFB40, 0xD1B7]),
+    (0xF92F, 0xF92F, &[0xFB40, 0xD2DE]),
+    (0xF930, 0xF930, &[0xFB40, 0xE4C4]),
+    (0xF931, 0xF931, &[0xFB40, 0xEAD3]),
+    (0xF932, 0xF932, &[0xFB40, 0xF210]),
+    (0xF933, 0xF933, &[0xFB40, 0xF6E7]),
+    (0xF934, 0xF934, &[0xFB41, 0x8001]),
+    (0xF935, 0xF935, &[0xFB41, 0x8606]),
+    (0xF936, 0xF936, &[0xFB41, 0x865C]),
+    (0xF937, 0xF937, &[0xFB41, 0x8DEF]),
+    (0xF938, 0xF938, &[0xFB41, 0x9732]),
+    (0xF939, 0xF939, &[0xFB41, 0x9B6F]),
+    (0xF93A, 0xF93A, &[0xFB41, 0x9DFA]),
+    (0xF93B, 0xF93B, &[0xFB40, 0xF88C]),
+    (0xF93C, 0xF93C, &[0xFB40, 0xF97F]),
+    (0xF93D, 0xF93D, &[0xFB40, 0xFDA0]),
+    (0xF93E, 0xF93E, &[0xFB41, 0x83C9]),
+    (0xF93F, 0xF93F, &[0xFB41, 0x9304]),
+    (0xF940, 0xF940, &[0xFB41, 0x9E7F]),
+    (0xF941, 0xF941, &[0xFB41, 0x8AD6]),
+    (0xF942, 0xF942, &[0xFB40, 0xD8DF]),
+    (0xF943, 0xF943, &[0xFB40, 0xDF04]),
+    (0xF944, 0xF944, &[0xFB40, 0xFC60]),
+    (0xF945, 0xF945, &[0xFB41, 0x807E]),
+    (0xF946, 0xF946, &[0xFB40, 0xF262]),
+    (0xF947, 0xF947, &[0xFB40, 0xF8CA]),
+    (0xF948, 0xF948, &[0xFB41, 0x8CC2]),
+    (0xF949, 0xF949, &[0xFB41, 0x96F7]),
+    (0xF94A, 0xF94A, &[0xFB40, 0xD8D8]),
+    (0xF94B, 0xF94B, &[0xFB40, 0xDC62]),
+    (0xF94C, 0xF94C, &[0xFB40, 0xEA13]),
+    (0xF94D, 0xF94D, &[0xFB40, 0xEDDA]),
+    (0xF94E, 0xF94E, &[0xFB40, 0xEF0F]),
+    (0xF94F, 0xF94F, &[0xFB40, 0xFD2F]),
+    (0xF950, 0xF950, &[0xFB40, 0xFE37]),
+    (0xF951, 0xF951, &[0xFB41, 0x964B]),
+    (0xF952, 0xF952, &[0xFB40, 0xD2D2]),
+    (0xF953, 0xF953, &[0xFB41, 0x808B]),
+    (0xF954, 0xF954, &[0xFB40, 0xD1DC]),
+    (0xF955, 0xF955, &[0xFB40, 0xD1CC]),
+    (0xF956, 0xF956, &[0xFB40, 0xFA1C]),
+    (0xF957, 0xF957, &[0xFB40, 0xFDBE]),
+    (0xF958, 0xF958, &[0xFB41, 0x83F1]),
+    (0xF959, 0xF959, &[0xFB41, 0x9675]),
+    (0xF95A, 0xF95A, &[0xFB41, 0x8B80]),
+    (0xF95B, 0xF95B, &[0xFB40, 0xE2CF]),
+    (0xF95C, 0xF95C, &[0xFB40, 0xEA02]),
+    (0xF95D, 0xF95D, &[0xFB41, 0x8AFE]),
+    (0xF95E, 0xF95E, &[0xFB40, 0xCE39]),
+    (0xF95F, 0xF95F, &[0xFB40, 0xDBE7]),
+    (0xF960, 0xF960, &[0xFB40, 0xE012]),
+    (0xF961, 0xF961, &[0xFB40, 0xF387]),
+    (0xF962, 0xF962, &[0xFB40, 0xF570]),
+    (0xF963, 0xF963, &[0xFB40, 0xD317]),
+    (0xF964, 0xF964, &[0xFB40, 0xF8FB]),
+    (0xF965, 0xF965, &[0xFB40, 0xCFBF]),
+    (0xF966, 0xF966, &[0xFB40, 0xDFA9]),
+    (0xF967, 0xF967, &[0xFB40, 0xCE0D]),
+    (0xF968, 0xF968, &[0xFB40, 0xECCC]),
+    (0xF969, 0xF969, &[0xFB40, 0xE578]),
+    (0xF96A, 0xF96A, &[0xFB40, 0xFD22]),
+    (0xF96B, 0xF96B, &[0xFB40, 0xD3C3]),
+    (0xF96C, 0xF96C, &[0xFB40, 0xD85E]),
+    (0xF96D, 0xF96D, &[0xFB40, 0xF701]),
+    (0xF96E, 0xF96E, &[0xFB41, 0x8449]),
+    (0xF96F, 0xF96F, &[0xFB41, 0x8AAA]),
+    (0xF970, 0xF970, &[0xFB40, 0xEBBA]),
+    (0xF971, 0xF971, &[0xFB41, 0x8FB0]),
+    (0xF972, 0xF972, &[0xFB40, 0xEC88]),
+    (0xF973, 0xF973, &[0xFB40, 0xE2FE]),
+    (0xF974, 0xF974, &[0xFB41, 0x82E5]),
+    (0xF975, 0xF975, &[0xFB40, 0xE3A0]),
+    (0xF976, 0xF976, &[0xFB40, 0xF565]),
+    (0xF977, 0xF977, &[0xFB40, 0xCEAE]),
+    (0xF978, 0xF978, &[0xFB40, 0xD169]),
+    (0xF979, 0xF979, &[0xFB40, 0xD1C9]),
+    (0xF97A, 0xF97A, &[0xFB40, 0xE881]),
+    (0xF97B, 0xF97B, &[0xFB40, 0xFCE7]),
+    (0xF97C, 0xF97C, &[0xFB41, 0x826F]),
+    (0xF97D, 0xF97D, &[0xFB41, 0x8AD2]),
+    (0xF97E, 0xF97E, &[0xFB41, 0x91CF]),
+    (0xF97F, 0xF97F, &[0xFB40, 0xD2F5]),
+    (0xF980, 0xF980, &[0xFB40, 0xD442]),
+    (0xF981, 0xF981, &[0xFB40, 0xD973]),
+    (0xF982, 0xF982, &[0xFB40, 0xDEEC]),
+    (0xF983, 0xF983, &[0xFB40, 0xE5C5]),
+    (0xF984, 0xF984, &[0xFB40, 0xEFFE]),
+    (0xF985, 0xF985, &[0xFB40, 0xF92A]),
+    (0xF986, 0xF986, &[0xFB41, 0x95AD]),
+    (0xF987, 0xF987, &[0xFB41, 0x9A6A]),
+    (0xF988, 0xF988, &[0xFB41, 0x9E97]),
+    (0xF989, 0xF989, &[0xFB41, 0x9ECE]),
+    (0xF98A, 0xF98A, &[0xFB40, 0xD29B]),
+    (0xF98B, 0xF98B, &[0xFB40, 0xE6C6]),
+    (0xF98C, 0xF98C, &[0xFB40, 0xEB77]),
+    (0xF98D, 0xF98D, &[0xFB41, 0x8F62]),
+    (0xF98E, 0xF98E, &[0xFB40, 0xDE74]),
+    (0xF98F, 0xF98F, &[0xFB40, 0xE190]),
+    (0xF990, 0xF990, &[0xFB40, 0xE200]),
+    (0xF991, 0xF991, &[0xFB40, 0xE49A]),
+    (0xF992, 0xF992, &[0xFB40, 0xEF23]),
+    (0xF993, 0xF993, &[0xFB40, 0xF149]),
+    (0xF994, 0xF994, &[0xFB40, 0xF489]),
+    (0xF995, 0xF995, &[0xFB40, 0xF9CA]),
+    (0xF996, 0xF996, &[0xFB40, 0xFDF4]),
+    (0xF997, 0xF997, &[0xFB41, 0x806F]),
+    (0xF998, 0xF998, &[0xFB41, 0x8F26]),
+    (0xF999, 0xF999, &[0xFB41, 0x84EE]),
+    (0xF99A, 0xF99A, &[0xFB41, 0x9023]),
+    (0xF99B, 0xF99B, &[0xFB41, 0x934A]),
+    (0xF99C, 0xF99C, &[0xFB40, 0xD217]),
+    (0xF99D, 0xF99D, &[0xFB40, 0xD2A3]),
+    (0xF99E, 0xF99E, &[0xFB40, 0xD4BD]),
+    (0xF99F, 0xF99F, &[0xFB40, 0xF0C8]),
+    (0xF9A0, 0xF9A0, &[0xFB41, 0x88C2]),
+    (0xF9A1, 0xF9A1, &[0xFB41, 0x8AAA]),
+    (0xF9A2, 0xF9A2, &[0xFB40, 0xDEC9]),
+    (0xF9A3, 0xF9A3, &[0xFB40, 0xDFF5]),
+    (0xF9A4, 0xF9A4, &[0xFB40, 0xE37B]),
+    (0xF9A5, 0xF9A5, &[0xFB40, 0xEBAE]),
+    (0xF9A6, 0xF9A6, &[0xFB40, 0xFC3E]),
+    (0xF9A7, 0xF9A7, &[0xFB40, 0xF375]),
+    (0xF9A8, 0xF9A8, &[0xFB40, 0xCEE4]),
+    (0xF9A9, 0xF9A9, &[0xFB40, 0xD6F9]),
+    (0xF9AA, 0xF9AA, &[0xFB40, 0xDBE7]),
+    (0xF9AB, 0xF9AB, &[0xFB40, 0xDDBA]),
+    (0xF9AC, 0xF9AC, &[0xFB40, 0xE01C]),
+    (0xF9AD, 0xF9AD, &[0xFB40, 0xF3B2]),
+    (0xF9AE, 0xF9AE, &[0xFB40, 0xF469]),
+    (0xF9AF, 0xF9AF, &[0xFB40, 0xFF9A]),
+    (0xF9B0, 0xF9B0, &[0xFB41, 0x8046]),
+    (0xF9B1, 0xF9B1, &[0xFB41, 0x9234]),
+    (0xF9B2, 0xF9B2, &[0xFB41, 0x96F6]),
+    (0xF9B3, 0xF9B3, &[0xFB41, 0x9748]),
+    (0xF9B4, 0xF9B4, &[0xFB41, 0x9818]),
+    (0xF9B5, 0xF9B5, &[0xFB40, 0xCF8B]),
+    (0xF9B6, 0xF9B6, &[0xFB40, 0xF9AE]),
+    (0xF9B7, 0xF9B7, &[0xFB41, 0x91B4]),
+    (0xF9B8, 0xF9B8, &[0xFB41, 0x96B8]),
+    (0xF9B9, 0xF9B9, &[0xFB40, 0xE0E1]),
+    (0xF9BA, 0xF9BA, &[0xFB40, 0xCE86]),
+    (0xF9BB, 0xF9BB, &[0xFB40, 0xD0DA]),
+    (0xF9BC, 0xF9BC, &[0xFB40, 0xDBEE]),
+    (0xF9BD, 0xF9BD, &[0xFB40, 0xDC3F]),
+    (0xF9BE, 0xF9BE, &[0xFB40, 0xE599]),
+    (0xF9BF, 0xF9BF, &[0xFB40, 0xEA02]),
+    (0xF9C0, 0xF9C0, &[0xFB40, 0xF1CE]),
+    (0xF9C1, 0xF9C1, &[0xFB40, 0xF642]),
+    (0xF9C2, 0xF9C2, &[0xFB41, 0x84FC]),
+    (0xF9C3, 0xF9C3, &[0xFB41, 0x907C]),
+    (0xF9C4, 0xF9C4, &[0xFB41, 0x9F8D]),
+    (0xF9C5, 0xF9C5, &[0xFB40, 0xE688]),
+    (0xF9C6, 0xF9C6, &[0xFB41, 0x962E]),
+    (0xF9C7, 0xF9C7, &[0xFB40, 0xD289]),
+    (0xF9C8, 0xF9C8, &[0xFB40, 0xE77B]),
+    (0xF9C9, 0xF9C9, &[0xFB40, 0xE7F3]),
+    (0xF9CA, 0xF9CA, &[0xFB40, 0xED41]),
+    (0xF9CB, 0xF9CB, &[0xFB40, 0xEE9C]),
+    (0xF9CC, 0xF9CC, &[0xFB40, 0xF409]),
+    (0xF9CD, 0xF9CD, &[0xFB40, 0xF559]),
+    (0xF9CE, 0xF9CE, &[0xFB40, 0xF86B]),
+    (0xF9CF, 0xF9CF, &[0xFB40, 0xFD10]),
+    (0xF9D0, 0xF9D0, &[0xFB41, 0x985E]),
+    (0xF9D1, 0xF9D1, &[0xFB40, 0xD16D]),
+    (0xF9D2, 0xF9D2, &[0xFB40, 0xE22E]),
+    (0xF9D3, 0xF9D3, &[0xFB41, 0x9678]),
+    (0xF9D4, 0xF9D4, &[0xFB40, 0xD02B]),
+    (0xF9D5, 0xF9D5, &[0xFB40, 0xDD19]),
+    (0xF9D6, 0xF9D6, &[0xFB40, 0xEDEA]),
+    (0xF9D7, 0xF9D7, &[0xFB41, 0x8F2A]),
+    (0xF9D8, 0xF9D8, &[0xFB40, 0xDF8B]),
+    (0xF9D9, 0xF9D9, &[0xFB40, 0xE144]),
+    (0xF9DA, 0xF9DA, &[0xFB40, 0xE817]),
+    (0xF9DB, 0xF9DB, &[0xFB40, 0xF387]),
+    (0xF9DC, 0xF9DC, &[0xFB41, 0x9686]),
+    (0xF9DD, 0xF9DD, &[0xFB40, 0xD229]),
+    (0xF9DE, 0xF9DE, &[0xFB40, 0xD40F]),
+    (0xF9DF, 0xF9DF, &[0xFB40, 0xDC65]),
+    (0xF9E0, 0xF9E0, &[0xFB40, 0xE613]),
+    (0xF9E1, 0xF9E1, &[0xFB40, 0xE74E]),
+    (0xF9E2, 0xF9E2, &[0xFB40, 0xE8A8]),
+    (0xF9E3, 0xF9E3, &[0xFB40, 0xECE5]),
+    (0xF9E4, 0xF9E4, &[0xFB40, 0xF406]),
+    (0xF9E5, 0xF9E5, &[0xFB40, 0xF5E2]),
+    (0xF9E6, 0xF9E6, &[0xFB40, 0xFF79]),
+    (0xF9E7, 0xF9E7, &[0xFB41, 0x88CF]),
+    (0xF9E8, 0xF9E8, &[0xFB41, 0x88E1]),
+    (0xF9E9, 0xF9E9, &[0xFB41, 0x91CC]),
+    (0xF9EA, 0xF9EA, &[0xFB41, 0x96E2]),
+    (0xF9EB, 0xF9EB, &[0xFB40, 0xD33F]),
+    (0xF9EC, 0xF9EC, &[0xFB40, 0xEEBA]),
+    (0xF9ED, 0xF9ED, &[0xFB40, 0xD41D]),
+    (0xF9EE, 0xF9EE, &[0xFB40, 0xF1D0]),
+    (0xF9EF, 0xF9EF, &[0xFB40, 0xF498]),
+    (0xF9F0, 0xF9F0, &[0xFB41, 0x85FA]),
+    (0xF9F1, 0xF9F1, &[0xFB41, 0x96A3]),
+    (0xF9F2, 0xF9F2, &[0xFB41, 0x9C57]),
+    (0xF9F3, 0xF9F3, &[0xFB41, 0x9E9F]),
+    (0xF9F4, 0xF9F4, &[0xFB40, 0xE797]),
+    (0xF9F5, 0xF9F5, &[0xFB40, 0xEDCB]),
+    (0xF9F6, 0xF9F6, &[0xFB41, 0x81E8]),
+    (0xF9F7, 0xF9F7, &[0xFB40, 0xFACB]),
+    (0xF9F8, 0xF9F8, &[0xFB40, 0xFB20]),
+    (0xF9F9, 0xF9F9, &[0xFB40, 0xFC92]),
+    (0xF9FA, 0xF9FA, &[0xFB40, 0xF2C0]),
+    (0xF9FB, 0xF9FB, &[0xFB40, 0xF099]),
+    (0xF9FC, 0xF9FC, &[0xFB41, 0x8B58]),
+    (0xF9FD, 0xF9FD, &[0xFB40, 0xCEC0]),
+    (0xF9FE, 0xF9FE, &[0xFB41, 0x8336]),
+    (0xF9FF, 0xF9FF, &[0xFB40, 0xD23A]),
+    (0xFA00, 0xFA00, &[0xFB40, 0xD207]),
+    (0xFA01, 0xFA01, &[0xFB40, 0xDEA6]),
+    (0xFA02, 0xFA02, &[0xFB40, 0xE2D3]),
+    (0xFA03, 0xFA03, &[0xFB40, 0xFCD6]),
+    (0xFA04, 0xFA04, &[0xFB40, 0xDB85]),
+    (0xFA05, 0xFA05, &[0xFB40, 0xED1E]),
+    (0xFA06, 0xFA06, &[0xFB40, 0xE6B4]),
+    (0xFA07, 0xFA07, &[0xFB41, 0x8F3B]),
+    (0xFA08, 0xFA08, &[0xFB41, 0x884C]),
+    (0xFA09, 0xFA09, &[0xFB41, 0x964D]),
+    (0xFA0A, 0xFA0A, &[0xFB41, 0x898B]),
+    (0xFA0B, 0xFA0B, &[0xFB40, 0xDED3]),
+    (0xFA0C, 0xFA0C, &[0xFB40, 0xD140]),
+    (0xFA0D, 0xFA0D, &[0xFB40, 0xD5C0]),
+    (0xFA0E, 0xFA0E, &[0xFB41, 0xFA0E]),
+    (0xFA0F, 0xFA0F, &[0xFB41, 0xFA0F]),
+    (0xFA10, 0xFA10, &[0xFB40, 0xD85A]),
+    (0xFA11, 0xFA11, &[0xFB41, 0xFA11]),
+    (0xFA12, 0xFA12, &[0xFB40, 0xE674]),
+    (0xFA13, 0xFA13, &[0xFB41, 0xFA13]),
+    (0xFA14, 0xFA14, &[0xFB41, 0xFA14]),
+    (0xFA15, 0xFA15, &[0xFB40, 0xD1DE]),
+    (0xFA16, 0xFA16, &[0xFB40, 0xF32A]),
+    (0xFA17, 0xFA17, &[0xFB40, 0xF6CA]),
+    (0xFA18, 0xFA18, &[0xFB40, 0xF93C]),
+    (0xFA19, 0xFA19, &[0xFB40, 0xF95E]),
+    (0xFA1A, 0xFA1A, &[0xFB40, 0xF965]),
+    (0xFA1B, 0xFA1B, &[0xFB40, 0xF98F]),
+    (0xFA1C, 0xFA1C, &[0xFB41, 0x9756]),
+    (0xFA1D, 0xFA1D, &[0xFB40, 0xFCBE]),
+    (0xFA1E, 0xFA1E, &[0xFB40, 0xFFBD]),
+    (0xFA1F, 0xFA1F, &[0xFB41, 0xFA1F]),
+    (0xFA20, 0xFA20, &[0xFB41, 0x8612]),
+    (0xFA21, 0xFA21, &[0xFB41, 0xFA21]),
+    (0xFA22, 0xFA22, &[0xFB41, 0x8AF8]),
+    (0xFA23, 0xFA23, &[0xFB41, 0xFA23]),
+    (0xFA24, 0xFA24, &[0xFB41, 0xFA24]),
+    (0xFA25, 0xFA25, &[0xFB41, 0x9038]),
+    (0xFA26, 0xFA26, &[0xFB41, 0x90FD]),
+    (0xFA27, 0xFA27, &[0xFB41, 0xFA27]),
+    (0xFA28, 0xFA28, &[0xFB41, 0xFA28]),
+    (0xFA29, 0xFA29, &[0xFB41, 0xFA29]),
+    (0xFA2A, 0xFA2A, &[0xFB41, 0x98EF]),
+    (0xFA2B, 0xFA2B, &[0xFB41, 0x98FC]),
+    (0xFA2C, 0xFA2C, &[0xFB41, 0x9928]),
+    (0xFA2D, 0xFA2D, &[0xFB41, 0x9DB4]),
+    (0xFA2E, 0xFA2E, &[0xFB41, 0x90DE]),
+    (0xFA2F, 0xFA2F, &[0xFB41, 0x96B7]),
+    (0xFA30, 0xFA30, &[0xFB40, 0xCFAE]),
+    (0xFA31, 0xFA31, &[0xFB40, 0xD0E7]),
+    (0xFA32, 0xFA32, &[0xFB40, 0xD14D]),
+    (0xFA33, 0xFA33, &[0xFB40, 0xD2C9]),
+    (0xFA34, 0xFA34, &[0xFB40, 0xD2E4]),
+    (0xFA35, 0xFA35, &[0xFB40, 0xD351]),
+    (0xFA36, 0xFA36, &[0xFB40, 0xD59D]),
+    (0xFA37, 0xFA37, &[0xFB40, 0xD606]),
+    (0xFA38, 0xFA38, &[0xFB40, 0xD668]),
+    (0xFA39, 0xFA39, &[0xFB40, 0xD840]),
+    (0xFA3A, 0xFA3A, &[0xFB40, 0xD8A8]),
+    (0xFA3B, 0xFA3B, &[0xFB40, 0xDC64]),
+    (0xFA3C, 0xFA3C, &[0xFB40, 0xDC6E]),
+    (0xFA3D, 0xFA3D, &[0xFB40, 0xE094]),
+    (0xFA3E, 0xFA3E, &[0xFB40, 0xE168]),
+    (0xFA3F, 0xFA3F, &[0xFB40, 0xE18E]),
+    (0xFA40, 0xFA40, &[0xFB40, 0xE1F2]),
+    (0xFA41, 0xFA41, &[0xFB40, 0xE54F]),
+    (0xFA42, 0xFA42, &[0xFB40, 0xE5E2]),
+    (0xFA43, 0xFA43, &[0xFB40, 0xE691]),
+    (0xFA44, 0xFA44, &[0xFB40, 0xE885]),
+    (0xFA45, 0xFA45, &[0xFB40, 0xED77]),
+    (0xFA46, 0xFA46, &[0xFB40, 0xEE1A]),
+    (0xFA47, 0xFA47, &[0xFB40, 0xEF22]),
+    (0xFA48, 0xFA48, &[0xFB40, 0xF16E]),
+    (0xFA49, 0xFA49, &[0xFB40, 0xF22B]),
+    (0xFA4A, 0xFA4A, &[0xFB40, 0xF422]),
+    (0xFA4B, 0xFA4B, &[0xFB40, 0xF891]),
+    (0xFA4C, 0xFA4C, &[0xFB40, 0xF93E]),
+    (0xFA4D, 0xFA4D, &[0xFB40, 0xF949]),
+    (0xFA4E, 0xFA4E, &[0xFB40, 0xF948]),
+    (0xFA4F, 0xFA4F, &[0xFB40, 0xF950]),
+    (0xFA50, 0xFA50, &[0xFB40, 0xF956]),
+    (0xFA51, 0xFA51, &[0xFB40, 0xF95D]),
+    (0xFA52, 0xFA52, &[0xFB40, 0xF98D]),
+    (0xFA53, 0xFA53, &[0xFB40, 0xF98E]),
+    (0xFA54, 0xFA54, &[0xFB40, 0xFA40]),
+    (0xFA55, 0xFA55, &[0xFB40, 0xFA81]),
+    (0xFA56, 0xFA56, &[0xFB40, 0xFBC0]),
+    (0xFA57, 0xFA57, &[0xFB40, 0xFDF4]),
+    (0xFA58, 0xFA58, &[0xFB40, 0xFE09]),
+    (0xFA59, 0xFA59, &[0xFB40, 0xFE41]),
+    (0xFA5A, 0xFA5A, &[0xFB40, 0xFF72]),
+    (0xFA5B, 0xFA5B, &[0xFB41, 0x8005]),
+    (0xFA5C, 0xFA5C, &[0xFB41, 0x81ED]),
+    (0xFA5D, 0xFA5D, &[0xFB41, 0x8279]),
+    (0xFA5E, 0xFA5E, &[0xFB41, 0x8279]),
+    (0xFA5F, 0xFA5F, &[0xFB41, 0x8457]),
+    (0xFA60, 0xFA60, &[0xFB41, 0x8910]),
+    (0xFA61, 0xFA61, &[0xFB41, 0x8996]),
+    (0xFA62, 0xFA62, &[0xFB41, 0x8B01]),
+    (0xFA63, 0xFA63, &[0xFB41, 0x8B39]),
+    (0xFA64, 0xFA64, &[0xFB41, 0x8CD3]),
+    (0xFA65, 0xFA65, &[0xFB41, 0x8D08]),
+    (0xFA66, 0xFA66, &[0xFB41, 0x8FB6]),
+    (0xFA67, 0xFA67, &[0xFB41, 0x9038]),
+    (0xFA68, 0xFA68, &[0xFB41, 0x96E3]),
+    (0xFA69, 0xFA69, &[0xFB41, 0x97FF]),
+    (0xFA6A, 0xFA6A, &[0xFB41, 0x983B]),
+    (0xFA6B, 0xFA6B, &[0xFB40, 0xE075]),
+    (0xFA6C, 0xFA6C, &[0xFB84, 0xC2EE]),
+    (0xFA6D, 0xFA6D, &[0xFB41, 0x8218]),
+    (0xFA70, 0xFA70, &[0xFB40, 0xCE26]),
+    (0xFA71, 0xFA71, &[0xFB40, 0xD1B5]),
+    (0xFA72, 0xFA72, &[0xFB40, 0xD168]),
+    (0xFA73, 0xFA73, &[0xFB40, 0xCF80]),
+    (0xFA74, 0xFA74, &[0xFB40, 0xD145]),
+    (0xFA75, 0xFA75, &[0xFB40, 0xD180]),
+    (0xFA76, 0xFA76, &[0xFB40, 0xD2C7]),
+    (0xFA77, 0xFA77, &[0xFB40, 0xD2FA]),
+    (0xFA78, 0xFA78, &[0xFB40, 0xD59D]),
+    (0xFA79, 0xFA79, &[0xFB40, 0xD555]),
+    (0xFA7A, 0xFA7A, &[0xFB40, 0xD599]),
+    (0xFA7B, 0xFA7B, &[0xFB40, 0xD5E2]),
+    (0xFA7C, 0xFA7C, &[0xFB40, 0xD85A]),
+    (0xFA7D, 0xFA7D, &[0xFB40, 0xD8B3]),
+    (0xFA7E, 0xFA7E, &[0xFB40, 0xD944]),
+    (0xFA7F, 0xFA7F, &[0xFB40, 0xD954]),
+    (0xFA80, 0xFA80, &[0xFB40, 0xDA62]),
+    (0xFA81, 0xFA81, &[0xFB40, 0xDB28]),
+    (0xFA82, 0xFA82, &[0xFB40, 0xDED2]),
+    (0xFA83, 0xFA83, &[0xFB40, 0xDED9]),
+    (0xFA84, 0xFA84, &[0xFB40, 0xDF69]),
+    (0xFA85, 0xFA85, &[0xFB40, 0xDFAD]),
+    (0xFA86, 0xFA86, &[0xFB40, 0xE0D8]),
+    (0xFA87, 0xFA87, &[0xFB40, 0xE14E]),
+    (0xFA88, 0xFA88, &[0xFB40, 0xE108]),
+    (0xFA89, 0xFA89, &[0xFB40, 0xE18E]),
+    (0xFA8A, 0xFA8A, &[0xFB40, 0xE160]),
+    (0xFA8B, 0xFA8B, &[0xFB40, 0xE1F2]),
+    (0xFA8C, 0xFA8C, &[0xFB40, 0xE234]),
+    (0xFA8D, 0xFA8D, &[0xFB40, 0xE3C4]),
+    (0xFA8E, 0xFA8E, &[0xFB40, 0xE41C]),
+    (0xFA8F, 0xFA8F, &[0xFB40, 0xE452]),
+    (0xFA90, 0xFA90, &[0xFB40, 0xE556]),
+    (0xFA91, 0xFA91, &[0xFB40, 0xE674]),
+    (0xFA92, 0xFA92, &[0xFB40, 0xE717]),
+    (0xFA93, 0xFA93, &[0xFB40, 0xE71B]),
+    (0xFA94, 0xFA94, &[0xFB40, 0xE756]),
+    (0xFA95, 0xFA95, &[0xFB40, 0xEB79]),
+    (0xFA96, 0xFA96, &[0xFB40, 0xEBBA]),
+    (0xFA97, 0xFA97, &[0xFB40, 0xED41]),
+    (0xFA98, 0xFA98, &[0xFB40, 0xEEDB]),
+    (0xFA99, 0xFA99, &[0xFB40, 0xEECB]),
+    (0xFA9A, 0xFA9A, &[0xFB40, 0xEF22]),
+    (0xFA9B, 0xFA9B, &[0xFB40, 0xF01E]),
+    (0xFA9C, 0xFA9C, &[0xFB40, 0xF16E]),
+    (0xFA9D, 0xFA9D, &[0xFB40, 0xF7A7]),
+    (0xFA9E, 0xFA9E, &[0xFB40, 0xF235]),
+    (0xFA9F, 0xFA9F, &[0xFB40, 0xF2AF]),
+    (0xFAA0, 0xFAA0, &[0xFB40, 0xF32A]),
+    (0xFAA1, 0xFAA1, &[0xFB40, 0xF471]),
+    (0xFAA2, 0xFAA2, &[0xFB40, 0xF506]),
+    (0xFAA3, 0xFAA3, &[0xFB40, 0xF53B]),
+    (0xFAA4, 0xFAA4, &[0xFB40, 0xF61D]),
+    (0xFAA5, 0xFAA5, &[0xFB40, 0xF61F]),
+    (0xFAA6, 0xFAA6, &[0xFB40, 0xF6CA]),
+    (0xFAA7, 0xFAA7, &[0xFB40, 0xF6DB]),
+    (0xFAA8, 0xFAA8, &[0xFB40, 0xF6F4]),
+    (0xFAA9, 0xFAA9, &[0xFB40, 0xF74A]),
+    (0xFAAA, 0xFAAA, &[0xFB40, 0xF740]),
+    (0xFAAB, 0xFAAB, &[0xFB40, 0xF8CC]),
+    (0xFAAC, 0xFAAC, &[0xFB40, 0xFAB1]),
+    (0xFAAD, 0xFAAD, &[0xFB40, 0xFBC0]),
+    (0xFAAE, 0xFAAE, &[0xFB40, 0xFC7B]),
+    (0xFAAF, 0xFAAF, &[0xFB40, 0xFD5B]),
+    (0xFAB0, 0xFAB0, &[0xFB40, 0xFDF4]),
+    (0xFAB1, 0xFAB1, &[0xFB40, 0xFF3E]),
+    (0xFAB2, 0xFAB2, &[0xFB41, 0x8005]),
+    (0xFAB3, 0xFAB3, &[0xFB41, 0x8352]),
+    (0xFAB4, 0xFAB4, &[0xFB41, 0x83EF]),
+    (0xFAB5, 0xFAB5, &[0xFB41, 0x8779]),
+    (0xFAB6, 0xFAB6, &[0xFB41, 0x8941]),
+    (0xFAB7, 0xFAB7, &[0xFB41, 0x8986]),
+    (0xFAB8, 0xFAB8, &[0xFB41, 0x8996]),
+    (0xFAB9, 0xFAB9, &[0xFB41, 0x8ABF]),
+    (0xFABA, 0xFABA, &[0xFB41, 0x8AF8]),
+    (0xFABB, 0xFABB, &[0xFB41, 0x8ACB]),
+    (0xFABC, 0xFABC, &[0xFB41, 0x8B01]),
+    (0xFABD, 0xFABD, &[0xFB41, 0x8AFE]),
+    (0xFABE, 0xFABE, &[0xFB41, 0x8AED]),
+    (0xFABF, 0xFABF, &[0xFB41, 0x8B39]),
+    (0xFAC0, 0xFAC0, &[0xFB41, 0x8B8A]),
+    (0xFAC1, 0xFAC1, &[0xFB41, 0x8D08]),
+    (0xFAC2, 0xFAC2, &[0xFB41, 0x8F38]),
+    (0xFAC3, 0xFAC3, &[0xFB41, 0x9072]),
+    (0xFAC4, 0xFAC4, &[0xFB41, 0x9199]),
+    (0xFAC5, 0xFAC5, &[0xFB41, 0x9276]),
+    (0xFAC6, 0xFAC6, &[0xFB41, 0x967C]),
+    (0xFAC7, 0xFAC7, &[0xFB41, 0x96E3]),
+    (0xFAC8, 0xFAC8, &[0xFB41, 0x9756]),
+    (0xFAC9, 0xFAC9, &[0xFB41, 0x97DB]),
+    (0xFACA, 0xFACA, &[0xFB41, 0x97FF]),
+    (0xFACB, 0xFACB, &[0xFB41, 0x980B]),
+    (0xFACC, 0xFACC, &[0xFB41, 0x983B]),
+    (0xFACD, 0xFACD, &[0xFB41, 0x9B12]),
+    (0xFACE, 0xFACE, &[0xFB41, 0x9F9C]),
+    (0xFACF, 0xFACF, &[0xFB84, 0xA84A]),
+    (0xFAD0, 0xFAD0, &[0xFB84, 0xA844]),
+    (0xFAD1, 0xFAD1, &[0xFB84, 0xB3D5]),
+    (0xFAD2, 0xFAD2, &[0xFB80, 0xBB9D]),
+    (0xFAD3, 0xFAD3, &[0xFB80, 0xC018]),
+    (0xFAD4, 0xFAD4, &[0xFB80, 0xC039]),
+    (0xFAD5, 0xFAD5, &[0xFB84, 0xD249]),
+    (0xFAD6, 0xFAD6, &[0xFB84, 0xDCD0]),
+    (0xFAD7, 0xFAD7, &[0xFB84, 0xFED3]),
+    (0xFAD8, 0xFAD8, &[0xFB41, 0x9F43]),
+    (0xFAD9, 0xFAD9, &[0xFB41, 0x9F8E]),
+    (0xFB00, 0xFB00, &[0x2042, 0x2042]),
+    (0xFB01, 0xFB01, &[0x2042, 0x2090]),
+    (0xFB02, 0xFB02, &[0x2042, 0x20D6]),
+    (0xFB03, 0xFB03, &[0x2042, 0x2042, 0x2090]),
+    (0xFB04, 0xFB04, &[0x2042, 0x2042, 0x20D6]),
+    (0xFB05, 0xFB05, &[0x21D2, 0x21F7]),
+    (0xFB06, 0xFB06, &[0x21D2, 0x21F7]),
+    (0xFB13, 0xFB13, &[0x2609, 0x260C]),
+    (0xFB14, 0xFB14, &[0x2609, 0x25FA]),
+    (0xFB15, 0xFB15, &[0x2609, 0x2600]),
+    (0xFB16, 0xFB16, &[0x2614, 0x260C]),
+    (0xFB17, 0xFB17, &[0x2609, 0x2602]),
+    (0xFB1D, 0xFB1D, &[0x2627]),
+    (0xFB1E, 0xFB1E, &[]),
+    (0xFB1F, 0xFB1F, &[0x2627, 0x2627]),
+    (0xFB20, 0xFB20, &[0x262D]),
+    (0xFB21, 0xFB21, &[0x261E]),
+    (0xFB22, 0xFB23, &[0x2621]),
+    (0xFB24, 0xFB26, &[0x2628]),
+    (0xFB27, 0xFB27, &[0x2631]),
+    (0xFB28, 0xFB28, &[0x2633]),
+    (0xFB29, 0xFB29, &[0x0666]),
+    (0xFB2A, 0xFB2A, &[0x2632]),
+    (0xFB2B, 0xFB2B, &[0x2632]),
+    (0xFB2C, 0xFB2C, &[0x2632]),
+    (0xFB2D, 0xFB2D, &[0x2632]),
+    (0xFB2E, 0xFB2E, &[0x261E]),
+    (0xFB2F, 0xFB2F, &[0x261E]),
+    (0xFB30, 0xFB36, &[0x261E]),
+    (0xFB38, 0xFB3A, &[0x2626]),
+    (0xFB3B, 0xFB3C, &[0x2628]),
+    (0xFB3E, 0xFB3E, &[0x262A]),
+    (0xFB40, 0xFB41, &[0x262B]),
+    (0xFB43, 0xFB43, &[0x262E]),
+    (0xFB44, 0xFB44, &[0x262E]),
+    (0xFB46, 0xFB4A, &[0x262F]),
+    (0xFB4B, 0xFB4B, &[0x2623]),
+    (0xFB4C, 0xFB4C, &[0x261F]),
+    (0xFB4D, 0xFB4D, &[0x2628]),
+    (0xFB4E, 0xFB4E, &[0x262E]),
+    (0xFB4F, 0xFB4F, &[0x261E, 0x2629]),
+    (0xFB50, 0xFB50, &[0x2668]),
+    (0xFB51, 0xFB51, &[0x2668]),
+    (0xFB52, 0xFB52, &[0x2675]),
+    (0xFB53, 0xFB53, &[0x2675]),
+    (0xFB54, 0xFB54, &[0x2675]),
+    (0xFB55, 0xFB56, &[0x2675]),
+    (0xFB57, 0xFB57, &[0x2676]),
+    (0xFB58, 0xFB58, &[0x2676]),
+    (0xFB59, 0xFB5A, &[0x2676]),
+    (0xFB5B, 0xFB5B, &[0x2677]),
+    (0xFB5C, 0xFB5C, &[0x2677]),
+    (0xFB5D, 0xFB5D, &[0x2677]),
+    (0xFB5E, 0xFB5E, &[0x2688]),
+    (0xFB5F, 0xFB5F, &[0x2688]),
+    (0xFB60, 0xFB60, &[0x2688]),
+    (0xFB61, 0xFB61, &[0x2688]),
+    (0xFB62, 0xFB62, &[0x268B]),
+    (0xFB63, 0xFB63, &[0x268B]),
+    (0xFB64, 0xFB64, &[0x268B]),
+    (0xFB65, 0xFB65, &[0x268B]),
+    (0xFB66, 0xFB66, &[0x2687]),
+    (0xFB67, 0xFB67, &[0x2687]),
+    (0xFB68, 0xFB68, &[0x2687]),
+    (0xFB69, 0xFB69, &[0x2687]),
+    (0xFB6A, 0xFB6A, &[0x26E9]),
+    (0xFB6B, 0xFB6B, &[0x26E9]),
+    (0xFB6C, 0xFB6C, &[0x26E9]),
+    (0xFB6D, 0xFB6D, &[0x26E9]),
+    (0xFB6E, 0xFB6E, &[0x26EC]),
+    (0xFB6F, 0xFB6F, &[0x26EC]),
+    (0xFB70, 0xFB70, &[0x26EC]),
+    (0xFB71, 0xFB71, &[0x26EC]),
+    (0xFB72, 0xFB72, &[0x2691]),
+    (0xFB73, 0xFB73, &[0x2691]),
+    (0xFB74, 0xFB74, &[0x2691]),
+    (0xFB75, 0xFB75, &[0x2691]),
+    (0xFB76, 0xFB76, &[0x2690]),
+    (0xFB77, 0xFB77, &[0x2690]),
+    (0xFB78, 0xFB78, &[0x2690]),
+    (0xFB79, 0xFB79, &[0x2690]),
+    (0xFB7A, 0xFB7A, &[0x2692]),
+    (0xFB7B, 0xFB7B, &[0x2692]),
+    (0xFB7C, 0xFB7C, &[0x2692]),
+    (0xFB7D, 0xFB7D, &[0x2692]),
+    (0xFB7E, 0xFB7E, &[0x2694]),
+    (0xFB7F, 0xFB7F, &[0x2694]),
+    (0xFB80, 0xFB80, &[0x2694]),
+    (0xFB81, 0xFB81, &[0x2694]),
+    (0xFB82, 0xFB82, &[0x26AB]),
+    (0xFB83, 0xFB83, &[0x26AB]),
+    (0xFB84, 0xFB84, &[0x26AA]),
+    (0xFB85, 0xFB85, &[0x26AA]),
+    (0xFB86, 0xFB86, &[0x26AD]),
+    (0xFB87, 0xFB87, &[0x26AD]),
+    (0xFB88, 0xFB88, &[0x26A6]),
+    (0xFB89, 0xFB89, &[0x26A6]),
+    (0xFB8A, 0xFB8A, &[0x26BC]),
+    (0xFB8B, 0xFB8B, &[0x26BC]),
+    (0xFB8C, 0xFB8C, &[0x26B5]),
+    (0xFB8D, 0xFB8D, &[0x26B5]),
+    (0xFB8E, 0xFB8E, &[0x26F7]),
+    (0xFB8F, 0xFB8F, &[0x26F7]),
+    (0xFB90, 0xFB90, &[0x26F7]),
+    (0xFB91, 0xFB91, &[0x26F7]),
+    (0xFB92, 0xFB92, &[0x26FF]),
+    (0xFB93, 0xFB93, &[0x26FF]),
+    (0xFB94, 0xFB94, &[0x26FF]),
+    (0xFB95, 0xFB95, &[0x26FF]),
+    (0xFB96, 0xFB96, &[0x2704]),
+    (0xFB97, 0xFB97, &[0x2704]),
+    (0xFB98, 0xFB98, &[0x2704]),
+    (0xFB99, 0xFB99, &[0x2704]),
+    (0xFB9A, 0xFB9A, &[0x2702]),
+    (0xFB9B, 0xFB9B, &[0x2702]),
+    (0xFB9C, 0xFB9C, &[0x2702]),
+    (0xFB9D, 0xFB9D, &[0x2702]),
+    (0xFB9E, 0xFB9E, &[0x2719]),
+    (0xFB9F, 0xFB9F, &[0x2719]),
+    (0xFBA0, 0xFBA0, &[0x271B]),
+    (0xFBA1, 0xFBA1, &[0x271B]),
+    (0xFBA2, 0xFBA2, &[0x271B]),
+    (0xFBA3, 0xFBA3, &[0x271B]),
+    (0xFBA4, 0xFBA4, &[0x2727]),
+    (0xFBA5, 0xFBA5, &[0x2727]),
+    (0xFBA6, 0xFBA6, &[0x2724]),
+    (0xFBA7, 0xFBA7, &[0x2724]),
+    (0xFBA8, 0xFBA8, &[0x2724]),
+    (0xFBA9, 0xFBA9, &[0x2724]),
+    (0xFBAA, 0xFBAA, &[0x2723]),
+    (0xFBAB, 0xFBAB, &[0x2723]),
+    (0xFBAC, 0xFBAC, &[0x2723]),
+    (0xFBAD, 0xFBAD, &[0x2723]),
+    (0xFBAE, 0xFBAE, &[0x2745]),
+    (0xFBAF, 0xFBAF, &[0x2745]),
+    (0xFBB0, 0xFBB0, &[0x2745]),
+    (0xFBB1, 0xFBB1, &[0x2745]),
+    (0xFBB2, 0xFBC1, &[0x0536]),
+    (0xFBD3, 0xFBD3, &[0x26FC]),
+    (0xFBD4, 0xFBD4, &[0x26FC]),
+    (0xFBD5, 0xFBD5, &[0x26FC]),
+    (0xFBD6, 0xFBD6, &[0x26FC]),
+    (0xFBD7, 0xFBD7, &[0x272C]),
+    (0xFBD8, 0xFBD8, &[0x272C]),
+    (0xFBD9, 0xFBD9, &[0x272B]),
+    (0xFBDA, 0xFBDA, &[0x272B]),
+    (0xFBDB, 0xFBDB, &[0x272D]),
+    (0xFBDC, 0xFBDC, &[0x272D]),
+    (0xFBDD, 0xFBDD, &[0x272C, 0x2664]),
+    (0xFBDE, 0xFBDE, &[0x2730]),
+    (0xFBDF, 0xFBDF, &[0x2730]),
+    (0xFBE0, 0xFBE0, &[0x272A]),
+    (0xFBE1, 0xFBE1, &[0x272A]),
+    (0xFBE2, 0xFBE2, &[0x272E]),
+    (0xFBE3, 0xFBE3, &[0x272E]),
+    (0xFBE4, 0xFBE4, &[0x273B]),
+    (0xFBE5, 0xFBE5, &[0x273B]),
+    (0xFBE6, 0xFBE6, &[0x273B]),
+    (0xFBE7, 0xFBE7, &[0x273B]),
+    (0xFBE8, 0xFBE8, &[0x2736]),
+    (0xFBE9, 0xFBE9, &[0x2736]),
+    (0xFBEA, 0xFBEA, &[0x266E, 0x2672]),
+    (0xFBEB, 0xFBEB, &[0x266E, 0x2672]),
+    (0xFBEC, 0xFBEC, &[0x266E, 0x2727]),
+    (0xFBED, 0xFBED, &[0x266E, 0x2727]),
+    (0xFBEE, 0xFBEE, &[0x266E, 0x2728]),
+    (0xFBEF, 0xFBEF, &[0x266E, 0x2728]),
+    (0xFBF0, 0xFBF0, &[0x266E, 0x272C]),
+    (0xFBF1, 0xFBF1, &[0x266E, 0x272C]),
+    (0xFBF2, 0xFBF2, &[0x266E, 0x272B]),
+    (0xFBF3, 0xFBF3, &[0x266E, 0x272B]),
+    (0xFBF4, 0xFBF4, &[0x266E, 0x272D]),
+    (0xFBF5, 0xFBF5, &[0x266E, 0x272D]),
+    (0xFBF6, 0xFBF6, &[0x266E, 0x273B]),
+    (0xFBF7, 0xFBF7, &[0x266E, 0x273B]),
+    (0xFBF8, 0xFBF8, &[0x266E, 0x273B]),
+    (0xFBF9, 0xFBF9, &[0x266E, 0x2736]),
+    (0xFBFA, 0xFBFA, &[0x266E, 0x2736]),
+    (0xFBFB, 0xFBFB, &[0x266E, 0x2736]),
+    (0xFBFC, 0xFBFC, &[0x2738]),
+    (0xFBFD, 0xFBFD, &[0x2738]),
+    (0xFBFE, 0xFBFE, &[0x2738]),
+    (0xFBFF, 0xFBFF, &[0x2738]),
+    (0xFC00, 0xFC00, &[0x266E, 0x268F]),
+    (0xFC01, 0xFC01, &[0x266E, 0x2699]),
+    (0xFC02, 0xFC02, &[0x266E, 0x2714]),
+    (0xFC03, 0xFC03, &[0x266E, 0x2736]),
+    (0xFC04, 0xFC04, &[0x266E, 0x2737]),
+    (0xFC05, 0xFC05, &[0x2674, 0x268F]),
+    (0xFC06, 0xFC06, &[0x2674, 0x2699]),
+    (0xFC07, 0xFC07, &[0x2674, 0x269A]),
+    (0xFC08, 0xFC08, &[0x2674, 0x2714]),
+    (0xFC09, 0xFC09, &[0x2674, 0x2736]),
+    (0xFC0A, 0xFC0A, &[0x2674, 0x2737]),
+    (0xFC0B, 0xFC0B, &[0x2685, 0x268F]),
+    (0xFC0C, 0xFC0C, &[0x2685, 0x2699]),
+    (0xFC0D, 0xFC0D, &[0x2685, 0x269A]),
+    (0xFC0E, 0xFC0E, &[0x2685, 0x2714]),
+    (0xFC0F, 0xFC0F, &[0x2685, 0x2736]),
+    (0xFC10, 0xFC10, &[0x2685, 0x2737]),
+    (0xFC11, 0xFC11, &[0x2686, 0x268F]),
+    (0xFC12, 0xFC12, &[0x2686, 0x2714]),
+    (0xFC13, 0xFC13, &[0x2686, 0x2736]),
+    (0xFC14, 0xFC14, &[0x2686, 0x2737]),
+    (0xFC15, 0xFC15, &[0x268F, 0x2699]),
+    (0xFC16, 0xFC16, &[0x268F, 0x2714]),
+    (0xFC17, 0xFC17, &[0x2699, 0x268F]),
+    (0xFC18, 0xFC18, &[0x2699, 0x2714]),
+    (0xFC19, 0xFC19, &[0x269A, 0x268F]),
+    (0xFC1A, 0xFC1A, &[0x269A, 0x2699]),
+    (0xFC1B, 0xFC1B, &[0x269A, 0x2714]),
+    (0xFC1C, 0xFC1C, &[0x26C6, 0x268F]),
+    (0xFC1D, 0xFC1D, &[0x26C6, 0x2699]),
+    (0xFC1E, 0xFC1E, &[0x26C6, 0x269A]),
+    (0xFC1F, 0xFC1F, &[0x26C6, 0x2714]),
+    (0xFC20, 0xFC20, &[0x26D1, 0x2699]),
+    (0xFC21, 0xFC21, &[0x26D1, 0x2714]),
+    (0xFC22, 0xFC22, &[0x26D2, 0x268F]),
+    (0xFC23, 0xFC23, &[0x26D2, 0x2699]),
+    (0xFC24, 0xFC24, &[0x26D2, 0x269A]),
+    (0xFC25, 0xFC25, &[0x26D2, 0x2714]),
+    (0xFC26, 0xFC26, &[0x26D7, 0x2699]),
+    (0xFC27, 0xFC27, &[0x26D7, 0x2714]),
+    (0xFC28, 0xFC28, &[0x26D8, 0x2714]),
+    (0xFC29, 0xFC29, &[0x26DB, 0x268F]),
+    (0xFC2A, 0xFC2A, &[0x26DB, 0x2714]),
+    (0xFC2B, 0xFC2B, &[0x26DC, 0x268F]),
+    (0xFC2C, 0xFC2C, &[0x26DC, 0x2714]),
+    (0xFC2D, 0xFC2D, &[0x26E4, 0x268F]),
+    (0xFC2E, 0xFC2E, &[0x26E4, 0x2699]),
+    (0xFC2F, 0xFC2F, &[0x26E4, 0x269A]),
+    (0xFC30, 0xFC30, &[0x26E4, 0x2714]),
+    (0xFC31, 0xFC31, &[0x26E4, 0x2736]),
+    (0xFC32, 0xFC32, &[0x26E4, 0x2737]),
+    (0xFC33, 0xFC33, &[0x26F0, 0x2699]),
+    (0xFC34, 0xFC34, &[0x26F0, 0x2714]),
+    (0xFC35, 0xFC35, &[0x26F0, 0x2736]),
+    (0xFC36, 0xFC36, &[0x26F0, 0x2737]),
+    (0xFC37, 0xFC37, &[0x26F6, 0x2672]),
+    (0xFC38, 0xFC38, &[0x26F6, 0x268F]),
+    (0xFC39, 0xFC39, &[0x26F6, 0x2699]),
+    (0xFC3A, 0xFC3A, &[0x26F6, 0x269A]),
+    (0xFC3B, 0xFC3B, &[0x26F6, 0x270C]),
+    (0xFC3C, 0xFC3C, &[0x26F6, 0x2714]),
+    (0xFC3D, 0xFC3D, &[0x26F6, 0x2736]),
+    (0xFC3E, 0xFC3E, &[0x26F6, 0x2737]),
+    (0xFC3F, 0xFC3F, &[0x270C, 0x268F]),
+    (0xFC40, 0xFC40, &[0x270C, 0x2699]),
+    (0xFC41, 0xFC41, &[0x270C, 0x269A]),
+    (0xFC42, 0xFC42, &[0x270C, 0x2714]),
+    (0xFC43, 0xFC43, &[0x270C, 0x2736]),
+    (0xFC44, 0xFC44, &[0x270C, 0x2737]),
+    (0xFC45, 0xFC45, &[0x2714, 0x268F]),
+    (0xFC46, 0xFC46, &[0x2714, 0x2699]),
+    (0xFC47, 0xFC47, &[0x2714, 0x269A]),
+    (0xFC48, 0xFC48, &[0x2714, 0x2714]),
+    (0xFC49, 0xFC49, &[0x2714, 0x2736]),
+    (0xFC4A, 0xFC4A, &[0x2714, 0x2737]),
+    (0xFC4B, 0xFC4B, &[0x2718, 0x268F]),
+    (0xFC4C, 0xFC4C, &[0x2718, 0x2699]),
+    (0xFC4D, 0xFC4D, &[0x2718, 0x269A]),
+    (0xFC4E, 0xFC4E, &[0x2718, 0x2714]),
+    (0xFC4F, 0xFC4F, &[0x2718, 0x2736]),
+    (0xFC50, 0xFC50, &[0x2718, 0x2737]),
+    (0xFC51, 0xFC51, &[0x2722, 0x268F]),
+    (0xFC52, 0xFC52, &[0x2722, 0x2714]),
+    (0xFC53, 0xFC53, &[0x2722, 0x2736]),
+    (0xFC54, 0xFC54, &[0x2722, 0x2737]),
+    (0xFC55, 0xFC55, &[0x2737, 0x268F]),
+    (0xFC56, 0xFC56, &[0x2737, 0x2699]),
+    (0xFC57, 0xFC57, &[0x2737, 0x269A]),
+    (0xFC58, 0xFC58, &[0x2737, 0x2714]),
+    (0xFC59, 0xFC59, &[0x2737, 0x2736]),
+    (0xFC5A, 0xFC5A, &[0x2737, 0x2737]),
+    (0xFC5B, 0xFC5B, &[0x26A5]),
+    (0xFC5C, 0xFC5C, &[0x26B3]),
+    (0xFC5D, 0xFC5D, &[0x2736]),
+    (0xFC5E, 0xFC63, &[]),
+    (0xFC64, 0xFC64, &[0x266E, 0x26B3]),
+    (0xFC65, 0xFC65, &[0x266E, 0x26B4]),
+    (0xFC66, 0xFC66, &[0x266E, 0x2714]),
+    (0xFC67, 0xFC67, &[0x266E, 0x2718]),
+    (0xFC68, 0xFC68, &[0x266E, 0x2736]),
+    (0xFC69, 0xFC69, &[0x266E, 0x2737]),
+    (0xFC6A, 0xFC6A, &[0x2674, 0x26B3]),
+    (0xFC6B, 0xFC6B, &[0x2674, 0x26B4]),
+    (0xFC6C, 0xFC6C, &[0x2674, 0x2714]),
+    (0xFC6D, 0xFC6D, &[0x2674, 0x2718]),
+    (0xFC6E, 0xFC6E, &[0x2674, 0x2736]),
+    (0xFC6F, 0xFC6F, &[0x2674, 0x2737]),
+    (0xFC70, 0xFC70, &[0x2685, 0x26B3]),
+    (0xFC71, 0xFC71, &[0x2685, 0x26B4]),
+    (0xFC72, 0xFC72, &[0x2685, 0x2714]),
+    (0xFC73, 0xFC73, &[0x2685, 0x2718]),
+    (0xFC74, 0xFC74, &[0x2685, 0x2736]),
+    (0xFC75, 0xFC75, &[0x2685, 0x2737]),
+    (0xFC76, 0xFC76, &[0x2686, 0x26B3]),
+    (0xFC77, 0xFC77, &[0x2686, 0x26B4]),
+    (0xFC78, 0xFC78, &[0x2686, 0x2714]),
+    (0xFC79, 0xFC79, &[0x2686, 0x2718]),
+    (0xFC7A, 0xFC7A, &[0x2686, 0x2736]),
+    (0xFC7B, 0xFC7B, &[0x2686, 0x2737]),
+    (0xFC7C, 0xFC7C, &[0x26E4, 0x2736]),
+    (0xFC7D, 0xFC7D, &[0x26E4, 0x2737]),
+    (0xFC7E, 0xFC7E, &[0x26F0, 0x2736]),
+    (0xFC7F, 0xFC7F, &[0x26F0, 0x2737]),
+    (0xFC80, 0xFC80, &[0x26F6, 0x2672]),
+    (0xFC81, 0xFC81, &[0x26F6, 0x270C]),
+    (0xFC82, 0xFC82, &[0x26F6, 0x2714]),
+    (0xFC83, 0xFC83, &[0x26F6, 0x2736]),
+    (0xFC84, 0xFC84, &[0x26F6, 0x2737]),
+    (0xFC85, 0xFC85, &[0x270C, 0x2714]),
+    (0xFC86, 0xFC86, &[0x270C, 0x2736]),
+    (0xFC87, 0xFC87, &[0x270C, 0x2737]),
+    (0xFC88, 0xFC88, &[0x2714, 0x2672]),
+    (0xFC89, 0xFC89, &[0x2714, 0x2714]),
+    (0xFC8A, 0xFC8A, &[0x2718, 0x26B3]),
+    (0xFC8B, 0xFC8B, &[0x2718, 0x26B4]),
+    (0xFC8C, 0xFC8C, &[0x2718, 0x2714]),
+    (0xFC8D, 0xFC8D, &[0x2718, 0x2718]),
+    (0xFC8E, 0xFC8E, &[0x2718, 0x2736]),
+    (0xFC8F, 0xFC8F, &[0x2718, 0x2737]),
+    (0xFC90, 0xFC90, &[0x2736]),
+    (0xFC91, 0xFC91, &[0x2737, 0x26B3]),
+    (0xFC92, 0xFC92, &[0x2737, 0x26B4]),
+    (0xFC93, 0xFC93, &[0x2737, 0x2714]),
+    (0xFC94, 0xFC94, &[0x2737, 0x2718]),
+    (0xFC95, 0xFC95, &[0x2737, 0x2736]),
+    (0xFC96, 0xFC96, &[0x2737, 0x2737]),
+    (0xFC97, 0xFC97, &[0x266E, 0x268F]),
+    (0xFC98, 0xFC98, &[0x266E, 0x2699]),
+    (0xFC99, 0xFC99, &[0x266E, 0x269A]),
+    (0xFC9A, 0xFC9A, &[0x266E, 0x2714]),
+    (0xFC9B, 0xFC9B, &[0x266E, 0x2722]),
+    (0xFC9C, 0xFC9C, &[0x2674, 0x268F]),
+    (0xFC9D, 0xFC9D, &[0x2674, 0x2699]),
+    (0xFC9E, 0xFC9E, &[0x2674, 0x269A]),
+    (0xFC9F, 0xFC9F, &[0x2674, 0x2714]),
+    (0xFCA0, 0xFCA0, &[0x2674, 0x2722]),
+    (0xFCA1, 0xFCA1, &[0x2685, 0x268F]),
+    (0xFCA2, 0xFCA2, &[0x2685, 0x2699]),
+    (0xFCA3, 0xFCA3, &[0x2685, 0x269A]),
+    (0xFCA4, 0xFCA4, &[0x2685, 0x2714]),
+    (0xFCA5, 0xFCA5, &[0x2685, 0x2722]),
+    (0xFCA6, 0xFCA6, &[0x2686, 0x2714]),
+    (0xFCA7, 0xFCA7, &[0x268F, 0x2699]),
+    (0xFCA8, 0xFCA8, &[0x268F, 0x2714]),
+    (0xFCA9, 0xFCA9, &[0x2699, 0x268F]),
+    (0xFCAA, 0xFCAA, &[0x2699, 0x2714]),
+    (0xFCAB, 0xFCAB, &[0x269A, 0x268F]),
+    (0xFCAC, 0xFCAC, &[0x269A, 0x2714]),
+    (0xFCAD, 0xFCAD, &[0x26C6, 0x268F]),
+    (0xFCAE, 0xFCAE, &[0x26C6, 0x2699]),
+    (0xFCAF, 0xFCAF, &[0x26C6, 0x269A]),
+    (0xFCB0, 0xFCB0, &[0x26C6, 0x2714]),
+    (0xFCB1, 0xFCB1, &[0x26D1, 0x2699]),
+    (0xFCB2, 0xFCB2, &[0x26D1, 0x269A]),
+    (0xFCB3, 0xFCB3, &[0x26D1, 0x2714]),
+    (0xFCB4, 0xFCB4, &[0x26D2, 0x268F]),
+    (0xFCB5, 0xFCB5, &[0x26D2, 0x2699]),
+    (0xFCB6, 0xFCB6, &[0x26D2, 0x269A]),
+    (0xFCB7, 0xFCB7, &[0x26D2, 0x2714]),
+    (0xFCB8, 0xFCB8, &[0x26D7, 0x2699]),
+    (0xFCB9, 0xFCB9, &[0x26D8, 0x2714]),
+    (0xFCBA, 0xFCBA, &[0x26DB, 0x268F]),
+    (0xFCBB, 0xFCBB, &[0x26DB, 0x2714]),
+    (0xFCBC, 0xFCBC, &[0x26DC, 0x268F]),
+    (0xFCBD, 0xFCBD, &[0x26DC, 0x2714]),
+    (0xFCBE, 0xFCBE, &[0x26E4, 0x268F]),
+    (0xFCBF, 0xFCBF, &[0x26E4, 0x2699]),
+    (0xFCC0, 0xFCC0, &[0x26E4, 0x269A]),
+    (0xFCC1, 0xFCC1, &[0x26E4, 0x2714]),
+    (0xFCC2, 0xFCC2, &[0x26F0, 0x2699]),
+    (0xFCC3, 0xFCC3, &[0x26F0, 0x2714]),
+    (0xFCC4, 0xFCC4, &[0x26F6, 0x268F]),
+    (0xFCC5, 0xFCC5, &[0x26F6, 0x2699]),
+    (0xFCC6, 0xFCC6, &[0x26F6, 0x269A]),
+    (0xFCC7, 0xFCC7, &[0x26F6, 0x270C]),
+    (0xFCC8, 0xFCC8, &[0x26F6, 0x2714]),
+    (0xFCC9, 0xFCC9, &[0x270C, 0x268F]),
+    (0xFCCA, 0xFCCA, &[0x270C, 0x2699]),
+    (0xFCCB, 0xFCCB, &[0x270C, 0x269A]),
+    (0xFCCC, 0xFCCC, &[0x270C, 0x2714]),
+    (0xFCCD, 0xFCCD, &[0x270C, 0x2722]),
+    (0xFCCE, 0xFCCE, &[0x2714, 0x268F]),
+    (0xFCCF, 0xFCCF, &[0x2714, 0x2699]),
+    (0xFCD0, 0xFCD0, &[0x2714, 0x269A]),
+    (0xFCD1, 0xFCD1, &[0x2714, 0x2714]),
+    (0xFCD2, 0xFCD2, &[0x2718, 0x268F]),
+    (0xFCD3, 0xFCD3, &[0x2718, 0x2699]),
+    (0xFCD4, 0xFCD4, &[0x2718, 0x269A]),
+    (0xFCD5, 0xFCD5, &[0x2718, 0x2714]),
+    (0xFCD6, 0xFCD6, &[0x2718, 0x2722]),
+    (0xFCD7, 0xFCD7, &[0x2722, 0x268F]),
+    (0xFCD8, 0xFCD8, &[0x2722, 0x2714]),
+    (0xFCD9, 0xFCD9, &[0x2722]),
+    (0xFCDA, 0xFCDA, &[0x2737, 0x268F]),
+    (0xFCDB, 0xFCDB, &[0x2737, 0x2699]),
+    (0xFCDC, 0xFCDC, &[0x2737, 0x269A]),
+    (0xFCDD, 0xFCDD, &[0x2737, 0x2714]),
+    (0xFCDE, 0xFCDE, &[0x2737, 0x2722]),
+    (0xFCDF, 0xFCDF, &[0x266E, 0x2714]),
+    (0xFCE0, 0xFCE0, &[0x266E, 0x2722]),
+    (0xFCE1, 0xFCE1, &[0x2674, 0x2714]),
+    (0xFCE2, 0xFCE2, &[0x2674, 0x2722]),
+    (0xFCE3, 0xFCE3, &[0x2685, 0x2714]),
+    (0xFCE4, 0xFCE4, &[0x2685, 0x2722]),
+    (0xFCE5, 0xFCE5, &[0x2686, 0x2714]),
+    (0xFCE6, 0xFCE6, &[0x2686, 0x2722]),
+    (0xFCE7, 0xFCE7, &[0x26C6, 0x2714]),
+    (0xFCE8, 0xFCE8, &[0x26C6, 0x2722]),
+    (0xFCE9, 0xFCE9, &[0x26C7, 0x2714]),
+    (0xFCEA, 0xFCEA, &[0x26C7, 0x2722]),
+    (0xFCEB, 0xFCEB, &[0x26F6, 0x270C]),
+    (0xFCEC, 0xFCEC, &[0x26F6, 0x2714]),
+    (0xFCED, 0xFCED, &[0x270C, 0x2714]),
+    (0xFCEE, 0xFCEE, &[0x2718, 0x2714]),
+    (0xFCEF, 0xFCEF, &[0x2718, 0x2722]),
+    (0xFCF0, 0xFCF0, &[0x2737, 0x2714]),
+    (0xFCF1, 0xFCF1, &[0x2737, 0x2722]),
+    (0xFCF2, 0xFCF4, &[]),
+    (0xFCF5, 0xFCF5, &[0x26D7, 0x2736]),
+    (0xFCF6, 0xFCF6, &[0x26D7, 0x2737]),
+    (0xFCF7, 0xFCF7, &[0x26DB, 0x2736]),
+    (0xFCF8, 0xFCF8, &[0x26DB, 0x2737]),
+    (0xFCF9, 0xFCF9, &[0x26DC, 0x2736]),
+    (0xFCFA, 0xFCFA, &[0x26DC, 0x2737]),
+    (0xFCFB, 0xFCFB, &[0x26C6, 0x2736]),
+    (0xFCFC, 0xFCFC, &[0x26C6, 0x2737]),
+    (0xFCFD, 0xFCFD, &[0x26C7, 0x2736]),
+    (0xFCFE, 0xFCFE, &[0x26C7, 0x2737]),
+    (0xFCFF, 0xFCFF, &[0x2699, 0x2736]),
+    (0xFD00, 0xFD00, &[0x2699, 0x2737]),
+    (0xFD01, 0xFD01, &[0x268F, 0x2736]),
+    (0xFD02, 0xFD02, &[0x268F, 0x2737]),
+    (0xFD03, 0xFD03, &[0x269A, 0x2736]),
+    (0xFD04, 0xFD04, &[0x269A, 0x2737]),
+    (0xFD05, 0xFD05, &[0x26D1, 0x2736]),
+    (0xFD06, 0xFD06, &[0x26D1, 0x2737]),
+    (0xFD07, 0xFD07, &[0x26D2, 0x2736]),
+    (0xFD08, 0xFD08, &[0x26D2, 0x2737]),
+    (0xFD09, 0xFD09, &[0x26C7, 0x268F]),
+    (0xFD0A, 0xFD0A, &[0x26C7, 0x2699]),
+    (0xFD0B, 0xFD0B, &[0x26C7, 0x269A]),
+    (0xFD0C, 0xFD0C, &[0x26C7, 0x2714]),
+    (0xFD0D, 0xFD0D, &[0x26C7, 0x26B3]),
+    (0xFD0E, 0xFD0E, &[0x26C6, 0x26B3]),
+    (0xFD0F, 0xFD0F, &[0x26D1, 0x26B3]),
+    (0xFD10, 0xFD10, &[0x26D2, 0x26B3]),
+    (0xFD11, 0xFD11, &[0x26D7, 0x2736]),
+    (0xFD12, 0xFD12, &[0x26D7, 0x2737]),
+    (0xFD13, 0xFD13, &[0x26DB, 0x2736]),
+    (0xFD14, 0xFD14, &[0x26DB, 0x2737]),
+    (0xFD15, 0xFD15, &[0x26DC, 0x2736]),
+    (0xFD16, 0xFD16, &[0x26DC, 0x2737]),
+    (0xFD17, 0xFD17, &[0x26C6, 0x2736]),
+    (0xFD18, 0xFD18, &[0x26C6, 0x2737]),
+    (0xFD19, 0xFD19, &[0x26C7, 0x2736]),
+    (0xFD1A, 0xFD1A, &[0x26C7, 0x2737]),
+    (0xFD1B, 0xFD1B, &[0x2699, 0x2736]),
+    (0xFD1C, 0xFD1C, &[0x2699, 0x2737]),
+    (0xFD1D, 0xFD1D, &[0x268F, 0x2736]),
+    (0xFD1E, 0xFD1E, &[0x268F, 0x2737]),
+    (0xFD1F, 0xFD1F, &[0x269A, 0x2736]),
+    (0xFD20, 0xFD20, &[0x269A, 0x2737]),
+    (0xFD21, 0xFD21, &[0x26D1, 0x2736]),
+    (0xFD22, 0xFD22, &[0x26D1, 0x2737]),
+    (0xFD23, 0xFD23, &[0x26D2, 0x2736]),
+    (0xFD24, 0xFD24, &[0x26D2, 0x2737]),
+    (0xFD25, 0xFD25, &[0x26C7, 0x268F]),
+    (0xFD26, 0xFD26, &[0x26C7, 0x2699]),
+    (0xFD27, 0xFD27, &[0x26C7, 0x269A]),
+    (0xFD28, 0xFD28, &[0x26C7, 0x2714]),
+    (0xFD29, 0xFD29, &[0x26C7, 0x26B3]),
+    (0xFD2A, 0xFD2A, &[0x26C6, 0x26B3]),
+    (0xFD2B, 0xFD2B, &[0x26D1, 0x26B3]),
+    (0xFD2C, 0xFD2C, &[0x26D2, 0x26B3]),
+    (0xFD2D, 0xFD2D, &[0x26C7, 0x268F]),
+    (0xFD2E, 0xFD2E, &[0x26C7, 0x2699]),
+    (0xFD2F, 0xFD2F, &[0x26C7, 0x269A]),
+    (0xFD30, 0xFD30, &[0x26C7, 0x2714]),
+    (0xFD31, 0xFD31, &[0x26C6, 0x2722]),
+    (0xFD32, 0xFD32, &[0x26C7, 0x2722]),
+    (0xFD33, 0xFD33, &[0x26D7, 0x2714]),
+    (0xFD34, 0xFD34, &[0x26C6, 0x268F]),
+    (0xFD35, 0xFD35, &[0x26C6, 0x2699]),
+    (0xFD36, 0xFD36, &[0x26C6, 0x269A]),
+    (0xFD37, 0xFD37, &[0x26C7, 0x268F]),
+    (0xFD38, 0xFD38, &[0x26C7, 0x2699]),
+    (0xFD39, 0xFD39, &[0x26C7, 0x269A]),
+    (0xFD3A, 0xFD3A, &[0x26D7, 0x2714]),
+    (0xFD3B, 0xFD3B, &[0x26D8, 0x2714]),
+    (0xFD3C, 0xFD3C, &[0x2672]),
+    (0xFD3D, 0xFD3D, &[0x2672]),
+    (0xFD3E, 0xFD3F, &[0x0392]),
+    (0xFD50, 0xFD50, &[0x2685, 0x268F, 0x2714]),
+    (0xFD51, 0xFD51, &[0x2685, 0x2699, 0x268F]),
+    (0xFD52, 0xFD52, &[0x2685, 0x2699, 0x268F]),
+    (0xFD53, 0xFD53, &[0x2685, 0x2699, 0x2714]),
+    (0xFD54, 0xFD54, &[0x2685, 0x269A, 0x2714]),
+    (0xFD55, 0xFD55, &[0x2685, 0x2714, 0x268F]),
+    (0xFD56, 0xFD56, &[0x2685, 0x2714, 0x2699]),
+    (0xFD57, 0xFD57, &[0x2685, 0x2714, 0x269A]),
+    (0xFD58, 0xFD58, &[0x268F, 0x2714, 0x2699]),
+    (0xFD59, 0xFD59, &[0x268F, 0x2714, 0x2699]),
+    (0xFD5A, 0xFD5A, &[0x2699, 0x2714, 0x2737]),
+    (0xFD5B, 0xFD5B, &[0x2699, 0x2714, 0x2736]),
+    (0xFD5C, 0xFD5C, &[0x26C6, 0x2699, 0x268F]),
+    (0xFD5D, 0xFD5D, &[0x26C6, 0x268F, 0x2699]),
+    (0xFD5E, 0xFD5E, &[0x26C6, 0x268F, 0x2736]),
+    (0xFD5F, 0xFD5F, &[0x26C6, 0x2714, 0x2699]),
+    (0xFD60, 0xFD60, &[0x26C6, 0x2714, 0x2699]),
+    (0xFD61, 0xFD61, &[0x26C6, 0x2714, 0x268F]),
+    (0xFD62, 0xFD62, &[0x26C6, 0x2714, 0x2714]),
+    (0xFD63, 0xFD63, &[0x26C6, 0x2714, 0x2714]),
+    (0xFD64, 0xFD64, &[0x26D1, 0x2699, 0x2699]),
+    (0xFD65, 0xFD65, &[0x26D1, 0x2699, 0x2699]),
+    (0xFD66, 0xFD66, &[0x26D1, 0x2714, 0x2714]),
+    (0xFD67, 0xFD67, &[0x26C7, 0x2699, 0x2714]),
+    (0xFD68, 0xFD68, &[0x26C7, 0x2699, 0x2714]),
+    (0xFD69, 0xFD69, &[0x26C7, 0x268F, 0x2737]),
+    (0xFD6A, 0xFD6A, &[0x26C7, 0x2714, 0x269A]),
+    (0xFD6B, 0xFD6B, &[0x26C7, 0x2714, 0x269A]),
+    (0xFD6C, 0xFD6C, &[0x26C7, 0x2714, 0x2714]),
+    (0xFD6D, 0xFD6D, &[0x26C7, 0x2714, 0x2714]),
+    (0xFD6E, 0xFD6E, &[0x26D2, 0x2699, 0x2736]),
+    (0xFD6F, 0xFD6F, &[0x26D2, 0x269A, 0x2714]),
+    (0xFD70, 0xFD70, &[0x26D2, 0x269A, 0x2714]),
+    (0xFD71, 0xFD71, &[0x26D7, 0x2714, 0x2699]),
+    (0xFD72, 0xFD72, &[0x26D7, 0x2714, 0x2699]),
+    (0xFD73, 0xFD73, &[0x26D7, 0x2714, 0x2714]),
+    (0xFD74, 0xFD74, &[0x26D7, 0x2714, 0x2737]),
+    (0xFD75, 0xFD75, &[0x26DB, 0x268F, 0x2714]),
+    (0xFD76, 0xFD76, &[0x26DB, 0x2714, 0x2714]),
+    (0xFD77, 0xFD77, &[0x26DB, 0x2714, 0x2714]),
+    (0xFD78, 0xFD78, &[0x26DB, 0x2714, 0x2736]),
+    (0xFD79, 0xFD79, &[0x26DC, 0x2714, 0x2714]),
+    (0xFD7A, 0xFD7A, &[0x26DC, 0x2714, 0x2737]),
+    (0xFD7B, 0xFD7B, &[0x26DC, 0x2714, 0x2736]),
+    (0xFD7C, 0xFD7C, &[0x26E4, 0x269A, 0x2714]),
+    (0xFD7D, 0xFD7D, &[0x26E4, 0x269A, 0x2714]),
+    (0xFD7E, 0xFD7E, &[0x26F0, 0x2714, 0x2699]),
+    (0xFD7F, 0xFD7F, &[0x26F0, 0x2714, 0x2714]),
+    (0xFD80, 0xFD80, &[0x270C, 0x2699, 0x2714]),
+    (0xFD81, 0xFD81, &[0x270C, 0x2699, 0x2737]),
+    (0xFD82, 0xFD82, &[0x270C, 0x2699, 0x2736]),
+    (0xFD83, 0xFD83, &[0x270C, 0x268F, 0x268F]),
+    (0xFD84, 0xFD84, &[0x270C, 0x268F, 0x268F]),
+    (0xFD85, 0xFD85, &[0x270C, 0x269A, 0x2714]),
+    (0xFD86, 0xFD86, &[0x270C, 0x269A, 0x2714]),
+    (0xFD87, 0xFD87, &[0x270C, 0x2714, 0x2699]),
+    (0xFD88, 0xFD88, &[0x270C, 0x2714, 0x2699]),
+    (0xFD89, 0xFD89, &[0x2714, 0x2699, 0x268F]),
+    (0xFD8A, 0xFD8A, &[0x2714, 0x2699, 0x2714]),
+    (0xFD8B, 0xFD8B, &[0x2714, 0x2699, 0x2737]),
+    (0xFD8C, 0xFD8C, &[0x2714, 0x268F, 0x2699]),
+    (0xFD8D, 0xFD8D, &[0x2714, 0x268F, 0x2714]),
+    (0xFD8E, 0xFD8E, &[0x2714, 0x269A, 0x268F]),
+    (0xFD8F, 0xFD8F, &[0x2714, 0x269A, 0x2714]),
+    (0xFD92, 0xFD92, &[0x2714, 0x268F, 0x269A]),
+    (0xFD93, 0xFD93, &[0x2722, 0x2714, 0x268F]),
+    (0xFD94, 0xFD94, &[0x2722, 0x2714, 0x2714]),
+    (0xFD95, 0xFD95, &[0x2718, 0x2699, 0x2714]),
+    (0xFD96, 0xFD96, &[0x2718, 0x2699, 0x2736]),
+    (0xFD97, 0xFD97, &[0x2718, 0x268F, 0x2714]),
+    (0xFD98, 0xFD98, &[0x2718, 0x268F, 0x2714]),
+    (0xFD99, 0xFD99, &[0x2718, 0x268F, 0x2736]),
+    (0xFD9A, 0xFD9A, &[0x2718, 0x2714, 0x2737]),
+    (0xFD9B, 0xFD9B, &[0x2718, 0x2714, 0x2736]),
+    (0xFD9C, 0xFD9C, &[0x2737, 0x2714, 0x2714]),
+    (0xFD9D, 0xFD9D, &[0x2737, 0x2714, 0x2714]),
+    (0xFD9E, 0xFD9E, &[0x2674, 0x269A, 0x2737]),
+    (0xFD9F, 0xFD9F, &[0x2685, 0x268F, 0x2737]),
+    (0xFDA0, 0xFDA0, &[0x2685, 0x268F, 0x2736]),
+    (0xFDA1, 0xFDA1, &[0x2685, 0x269A, 0x2737]),
+    (0xFDA2, 0xFDA2, &[0x2685, 0x269A, 0x2736]),
+    (0xFDA3, 0xFDA3, &[0x2685, 0x2714, 0x2737]),
+    (0xFDA4, 0xFDA4, &[0x2685, 0x2714, 0x2736]),
+    (0xFDA5, 0xFDA5, &[0x268F, 0x2714, 0x2737]),
+    (0xFDA6, 0xFDA6, &[0x268F, 0x2699, 0x2736]),
+    (0xFDA7, 0xFDA7, &[0x268F, 0x2714, 0x2736]),
+    (0xFDA8, 0xFDA8, &[0x26C6, 0x269A, 0x2736]),
+    (0xFDA9, 0xFDA9, &[0x26D1, 0x2699, 0x2737]),
+    (0xFDAA, 0xFDAA, &[0x26C7, 0x2699, 0x2737]),
+    (0xFDAB, 0xFDAB, &[0x26D2, 0x2699, 0x2737]),
+    (0xFDAC, 0xFDAC, &[0x270C, 0x268F, 0x2737]),
+    (0xFDAD, 0xFDAD, &[0x270C, 0x2714, 0x2737]),
+    (0xFDAE, 0xFDAE, &[0x2737, 0x2699, 0x2737]),
+    (0xFDAF, 0xFDAF, &[0x2737, 0x268F, 0x2737]),
+    (0xFDB0, 0xFDB0, &[0x2737, 0x2714, 0x2737]),
+    (0xFDB1, 0xFDB1, &[0x2714, 0x2714, 0x2737]),
+    (0xFDB2, 0xFDB2, &[0x26F0, 0x2714, 0x2737]),
+    (0xFDB3, 0xFDB3, &[0x2718, 0x2699, 0x2737]),
+    (0xFDB4, 0xFDB4, &[0x26F0, 0x2714, 0x2699]),
+    (0xFDB5, 0xFDB5, &[0x270C, 0x2699, 0x2714]),
+    (0xFDB6, 0xFDB6, &[0x26DB, 0x2714, 0x2737]),
+    (0xFDB7, 0xFDB7, &[0x26F6, 0x2714, 0x2737]),
+    (0xFDB8, 0xFDB8, &[0x2718, 0x268F, 0x2699]),
+    (0xFDB9, 0xFDB9, &[0x2714, 0x269A, 0x2737]),
+    (0xFDBA, 0xFDBA, &[0x270C, 0x268F, 0x2714]),
+    (0xFDBB, 0xFDBB, &[0x26F6, 0x2714, 0x2714]),
+    (0xFDBC, 0xFDBC, &[0x270C, 0x268F, 0x2714]),
+    (0xFDBD, 0xFDBD, &[0x2718, 0x268F, 0x2699]),
+    (0xFDBE, 0xFDBE, &[0x268F, 0x2699, 0x2737]),
+    (0xFDBF, 0xFDBF, &[0x2699, 0x268F, 0x2737]),
+    (0xFDC0, 0xFDC0, &[0x2714, 0x268F, 0x2737]),
+    (0xFDC1, 0xFDC1, &[0x26E4, 0x2714, 0x2737]),
+    (0xFDC2, 0xFDC2, &[0x2674, 0x2699, 0x2737]),
+    (0xFDC3, 0xFDC3, &[0x26F6, 0x2714, 0x2714]),
+    (0xFDC4, 0xFDC4, &[0x26DB, 0x268F, 0x2714]),
+    (0xFDC5, 0xFDC5, &[0x26D1, 0x2714, 0x2714]),
+    (0xFDC6, 0xFDC6, &[0x26C6, 0x269A, 0x2737]),
+    (0xFDC7, 0xFDC7, &[0x2718, 0x268F, 0x2737]),
+    (0xFDF0, 0xFDF0, &[0x26D1, 0x270C, 0x2745]),
+    (0xFDF1, 0xFDF1, &[0x26F0, 0x270C, 0x2745]),
+    (0xFDF2, 0xFDF2, &[0x2672, 0x270C, 0x270C, 0x2722]),
+    (0xFDF3, 0xFDF3, &[0x2672, 0x26F6, 0x2674, 0x26B3]),
+    (0xFDF4, 0xFDF4, &[0x2714, 0x2699, 0x2714, 0x26A4]),
+    (0xFDF5, 0xFDF5, &[0x26D1, 0x270C, 0x26DB, 0x2714]),
+    (0xFDF6, 0xFDF6, &[0x26B3, 0x26C6, 0x2728, 0x270C]),
+    (0xFDF7, 0xFDF7, &[0x26DB, 0x270C, 0x2737, 0x2722]),
+    (0xFDF8, 0xFDF8, &[0x2728, 0x26C6, 0x270C, 0x2714]),
+    (0xFDF9, 0xFDF9, &[0x26D1, 0x270C, 0x2736]),
+    (0xFDFA, 0xFDFA, &[0x26D1, 0x270C, 0x2736, 0x0209, 0x2672, 0x270C, 0x270C, 0x2722, 0x0209, 0x26DB, 0x270C, 0x2737, 0x2722, 0x0209, 0x2728, 0x26C6, 0x270C, 0x2714]),
+    (0xFDFB, 0xFDFB, &[0x268F, 0x270C, 0x0209, 0x268F, 0x270C, 0x2672, 0x270C, 0x2722]),
+    (0xFDFC, 0xFDFC, &[0x26B3, 0x2738, 0x2672, 0x270C]),
+    (0xFDFD, 0xFDFD, &[0x0535]),
+    (0xFE00, 0xFE0F, &[]),
+    (0xFE10, 0xFE10, &[0x0223]),
+    (0xFE11, 0xFE11, &[0x0236]),
+    (0xFE12, 0xFE12, &[0x0292]),
+    (0xFE13, 0xFE13, &[0x0240]),
+    (0xFE14, 0xFE14, &[0x023A]),
+    (0xFE15, 0xFE15, &[0x0267]),
+    (0xFE16, 0xFE16, &[0x026D]),
+    (0xFE17, 0xFE18, &[0x038C]),
+    (0xFE19, 0xFE19, &[0x027E, 0x027E, 0x027E]),
+    (0xFE20, 0xFE2F, &[]),
+    (0xFE30, 0xFE30, &[0x027E, 0x027E]),
+    (0xFE31, 0xFE31, &[0x0216]),
+    (0xFE32, 0xFE32, &[0x0215]),
+    (0xFE33, 0xFE33, &[0x020B]),
+    (0xFE34, 0xFE34, &[0x020B]),
+    (0xFE35, 0xFE36, &[0x0328]),
+    (0xFE37, 0xFE38, &[0x032C]),
+    (0xFE39, 0xFE3A, &[0x038A]),
+    (0xFE3B, 0xFE3C, &[0x0388]),
+    (0xFE3D, 0xFE3E, &[0x0382]),
+    (0xFE3F, 0xFE40, &[0x0380]),
+    (0xFE41, 0xFE44, &[0x0384]),
+    (0xFE45, 0xFE46, &[0x0237]),
+    (0xFE47, 0xFE48, &[0x032A]),
+    (0xFE49, 0xFE49, &[0x020A]),
+    (0xFE4A, 0xFE4A, &[0x020A]),
+    (0xFE4B, 0xFE4B, &[0x020A]),
+    (0xFE4C, 0xFE4D, &[0x020A]),
+    (0xFE4E, 0xFE4E, &[0x020B]),
+    (0xFE4F, 0xFE4F, &[0x020B]),
+    (0xFE50, 0xFE50, &[0x0223]),
+    (0xFE51, 0xFE51, &[0x0236]),
+    (0xFE52, 0xFE52, &[0x027E]),
+    (0xFE54, 0xFE54, &[0x023A]),
+    (0xFE55, 0xFE55, &[0x0240]),
+    (0xFE56, 0xFE56, &[0x026D]),
+    (0xFE57, 0xFE57, &[0x0267]),
+    (0xFE58, 0xFE58, &[0x0216]),
+    (0xFE59, 0xFE5A, &[0x0328]),
+    (0xFE5B, 0xFE5C, &[0x032C]),
+    (0xFE5D, 0xFE5E, &[0x038A]),
+    (0xFE5F, 0xFE5F, &[0x03AC]),
+    (0xFE60, 0xFE60, &[0x03A9]),
+    (0xFE61, 0xFE61, &[0x03A1]),
+    (0xFE62, 0xFE62, &[0x0666]),
+    (0xFE63, 0xFE63, &[0x020D]),
+    (0xFE64, 0xFE64, &[0x066A]),
+    (0xFE65, 0xFE65, &[0x066C]),
+    (0xFE66, 0xFE66, &[0x066B]),
+    (0xFE68, 0xFE68, &[0x03A7]),
+    (0xFE69, 0xFE69, &[0x1F64]),
+    (0xFE6A, 0xFE6A, &[0x03AD]),
+    (0xFE6B, 0xFE6B, &[0x03A0]),
+    (0xFE70, 0xFE74, &[]),
+    (0xFE76, 0xFE7F, &[]),
+    (0xFE80, 0xFE81, &[0x2664]),
+    (0xFE82, 0xFE83, &[0x2665]),
+    (0xFE84, 0xFE84, &[0x2666]),
+    (0xFE85, 0xFE85, &[0x2669]),
+    (0xFE86, 0xFE87, &[0x2669]),
+    (0xFE88, 0xFE88, &[0x266A]),
+    (0xFE89, 0xFE89, &[0x266E]),
+    (0xFE8A, 0xFE8A, &[0x266E]),
+    (0xFE8B, 0xFE8B, &[0x266E]),
+    (0xFE8C, 0xFE8C, &[0x266E]),
+    (0xFE8D, 0xFE8D, &[0x2672]),
+    (0xFE8E, 0xFE8E, &[0x2672]),
+    (0xFE8F, 0xFE8F, &[0x2674]),
+    (0xFE90, 0xFE90, &[0x2674]),
+    (0xFE91, 0xFE91, &[0x2674]),
+    (0xFE92, 0xFE92, &[0x2674]),
+    (0xFE93, 0xFE93, &[0x2684]),
+    (0xFE94, 0xFE95, &[0x2684]),
+    (0xFE96, 0xFE96, &[0x2685]),
+    (0xFE97, 0xFE97, &[0x2685]),
+    (0xFE98, 0xFE99, &[0x2685]),
+    (0xFE9A, 0xFE9A, &[0x2686]),
+    (0xFE9B, 0xFE9B, &[0x2686]),
+    (0xFE9C, 0xFE9C, &[0x2686]),
+    (0xFE9D, 0xFE9D, &[0x268F]),
+    (0xFE9E, 0xFE9E, &[0x268F]),
+    (0xFE9F, 0xFE9F, &[0x268F]),
+    (0xFEA0, 0xFEA0, &[0x268F]),
+    (0xFEA1, 0xFEA1, &[0x2699]),
+    (0xFEA2, 0xFEA2, &[0x2699]),
+    (0xFEA3, 0xFEA3, &[0x2699]),
+    (0xFEA4, 0xFEA5, &[0x2699]),
+    (0xFEA6, 0xFEA6, &[0x269A]),
+    (0xFEA7, 0xFEA7, &[0x269A]),
+    (0xFEA8, 0xFEA8, &[0x269A]),
+    (0xFEA9, 0xFEA9, &[0x26A4]),
+    (0xFEAA, 0xFEAB, &[0x26A4]),
+    (0xFEAC, 0xFEAC, &[0x26A5]),
+    (0xFEAD, 0xFEAD, &[0x26B3]),
+    (0xFEAE, 0xFEAF, &[0x26B3]),
+    (0xFEB0, 0xFEB0, &[0x26B4]),
+    (0xFEB1, 0xFEB1, &[0x26C6]),
+    (0xFEB2, 0xFEB2, &[0x26C6]),
+    (0xFEB3, 0xFEB3, &[0x26C6]),
+    (0xFEB4, 0xFEB5, &[0x26C6]),
+    (0xFEB6, 0xFEB6, &[0x26C7]),
+    (0xFEB7, 0xFEB7, &[0x26C7]),
+    (0xFEB8, 0xFEB8, &[0x26C7]),
+    (0xFEB9, 0xFEB9, &[0x26D1]),
+    (0xFEBA, 0xFEBA, &[0x26D1]),
+    (0xFEBB, 0xFEBB, &[0x26D1]),
+    (0xFEBC, 0xFEBD, &[0x26D1]),
+    (0xFEBE, 0xFEBE, &[0x26D2]),
+    (0xFEBF, 0xFEBF, &[0x26D2]),
+    (0xFEC0, 0xFEC0, &[0x26D2]),
+    (0xFEC1, 0xFEC1, &[0x26D7]),
+    (0xFEC2, 0xFEC2, &[0x26D7]),
+    (0xFEC3, 0xFEC3, &[0x26D7]),
+    (0xFEC4, 0xFEC5, &[0x26D7]),
+    (0xFEC6, 0xFEC6, &[0x26D8]),
+    (0xFEC7, 0xFEC7, &[0x26D8]),
+    (0xFEC8, 0xFEC8, &[0x26D8]),
+    (0xFEC9, 0xFEC9, &[0x26DB]),
+    (0xFECA, 0xFECA, &[0x26DB]),
+    (0xFECB, 0xFECB, &[0x26DB]),
+    (0xFECC, 0xFECD, &[0x26DB]),
+    (0xFECE, 0xFECE, &[0x26DC]),
+    (0xFECF, 0xFECF, &[0x26DC]),
+    (0xFED0, 0xFED0, &[0x26DC]),
+    (0xFED1, 0xFED1, &[0x26E4]),
+    (0xFED2, 0xFED2, &[0x26E4]),
+    (0xFED3, 0xFED3, &[0x26E4]),
+    (0xFED4, 0xFED4, &[0x26E4]),
+    (0xFED5, 0xFED5, &[0x26F0]),
+    (0xFED6, 0xFED6, &[0x26F0]),
+    (0xFED7, 0xFED7, &[0x26F0]),
+    (0xFED8, 0xFED8, &[0x26F0]),
+    (0xFED9, 0xFED9, &[0x26F6]),
+    (0xFEDA, 0xFEDA, &[0x26F6]),
+    (0xFEDB, 0xFEDB, &[0x26F6]),
+    (0xFEDC, 0xFEDC, &[0x26F6]),
+    (0xFEDD, 0xFEDD, &[0x270C]),
+    (0xFEDE, 0xFEDE, &[0x270C]),
+    (0xFEDF, 0xFEDF, &[0x270C]),
+    (0xFEE0, 0xFEE0, &[0x270C]),
+    (0xFEE1, 0xFEE1, &[0x2714]),
+    (0xFEE2, 0xFEE2, &[0x2714]),
+    (0xFEE3, 0xFEE3, &[0x2714]),
+    (0xFEE4, 0xFEE4, &[0x2714]),
+    (0xFEE5, 0xFEE5, &[0x2718]),
+    (0xFEE6, 0xFEE6, &[0x2718]),
+    (0xFEE7, 0xFEE7, &[0x2718]),
+    (0xFEE8, 0xFEE8, &[0x2718]),
+    (0xFEE9, 0xFEE9, &[0x2722]),
+    (0xFEEA, 0xFEEA, &[0x2722]),
+    (0xFEEB, 0xFEEB, &[0x2722]),
+    (0xFEEC, 0xFEEC, &[0x2722]),
+    (0xFEED, 0xFEED, &[0x2728]),
+    (0xFEEE, 0xFEEE, &[0x2728]),
+    (0xFEEF, 0xFEEF, &[0x2736]),
+    (0xFEF0, 0xFEF1, &[0x2736]),
+    (0xFEF2, 0xFEF2, &[0x2737]),
+    (0xFEF3, 0xFEF3, &[0x2737]),
+    (0xFEF4, 0xFEF4, &[0x2737]),
+    (0xFEF5, 0xFEF5, &[0x270C, 0x2665]),
+    (0xFEF6, 0xFEF6, &[0x270C, 0x2665]),
+    (0xFEF7, 0xFEF7, &[0x270C, 0x2666]),
+    (0xFEF8, 0xFEF8, &[0x270C, 0x2666]),
+    (0xFEF9, 0xFEF9, &[0x270C, 0x266A]),
+    (0xFEFA, 0xFEFA, &[0x270C, 0x266A]),
+    (0xFEFB, 0xFEFB, &[0x270C, 0x2672]),
+    (0xFEFC, 0xFEFC, &[0x270C, 0x2672]),
+    (0xFEFF, 0xFEFF, &[]),
+    (0xFF01, 0xFF01, &[0x0267]),
+    (0xFF02, 0xFF02, &[0x031D]),
+    (0xFF03, 0xFF03, &[0x03AC]),
+    (0xFF04, 0xFF04, &[0x1F64]),
+    (0xFF05, 0xFF05, &[0x03AD]),
+    (0xFF06, 0xFF06, &[0x03A9]),
+    (0xFF07, 0xFF07, &[0x0316]),
+    (0xFF08, 0xFF09, &[0x0328]),
+    (0xFF0A, 0xFF0A, &[0x03A1]),
+    (0xFF0B, 0xFF0B, &[0x0666]),
+    (0xFF0C, 0xFF0C, &[0x0223]),
+    (0xFF0D, 0xFF0D, &[0x020D]),
+    (0xFF0E, 0xFF0E, &[0x027E]),
+    (0xFF0F, 0xFF0F, &[0x03A6]),
+    (0xFF10, 0xFF19, &[0x1F98]),
+    (0xFF1A, 0xFF1A, &[0x0240]),
+    (0xFF1B, 0xFF1B, &[0x023A]),
+    (0xFF1C, 0xFF1E, &[0x066A]),
+    (0xFF1F, 0xFF1F, &[0x026D]),
+    (0xFF20, 0xFF20, &[0x03A0]),
+    (0xFF21, 0xFF21, &[0x1FA2]),
+    (0xFF22, 0xFF22, &[0x1FBC]),
+    (0xFF23, 0xFF23, &[0x1FD6]),
+    (0xFF24, 0xFF24, &[0x1FEB]),
+    (0xFF25, 0xFF25, &[0x2007]),
+    (0xFF26, 0xFF26, &[0x2042]),
+    (0xFF27, 0xFF27, &[0x2051]),
+    (0xFF28, 0xFF28, &[0x2075]),
+    (0xFF29, 0xFF29, &[0x2090]),
+    (0xFF2A, 0xFF2A, &[0x20AB]),
+    (0xFF2B, 0xFF2B, &[0x20C4]),
+    (0xFF2C, 0xFF2C, &[0x20D6]),
+    (0xFF2D, 0xFF2D, &[0x2109]),
+    (0xFF2E, 0xFF2E, &[0x2118]),
+    (0xFF2F, 0xFF2F, &[0x213C]),
+    (0xFF30, 0xFF30, &[0x216B]),
+    (0xFF31, 0xFF31, &[0x2180]),
+    (0xFF32, 0xFF32, &[0x2193]),
+    (0xFF33, 0xFF33, &[0x21D2]),
+    (0xFF34, 0xFF34, &[0x21F7]),
+    (0xFF35, 0xFF35, &[0x2217]),
+    (0xFF36, 0xFF36, &[0x2247]),
+    (0xFF37, 0xFF37, &[0x2259]),
+    (0xFF38, 0xFF38, &[0x2264]),
+    (0xFF39, 0xFF39, &[0x2270]),
+    (0xFF3A, 0xFF3A, &[0x2286]),
+    (0xFF3B, 0xFF3B, &[0x032A]),
+    (0xFF3C, 0xFF3C, &[0x03A7]),
+    (0xFF3D, 0xFF3D, &[0x032B]),
+    (0xFF3E, 0xFF3E, &[0x04B7]),
+    (0xFF3F, 0xFF3F, &[0x020B]),
+    (0xFF40, 0xFF40, &[0x04B4]),
+    (0xFF41, 0xFF41, &[0x1FA2]),
+    (0xFF42, 0xFF42, &[0x1FBC]),
+    (0xFF43, 0xFF43, &[0x1FD6]),
+    (0xFF44, 0xFF44, &[0x1FEB]),
+    (0xFF45, 0xFF45, &[0x2007]),
+    (0xFF46, 0xFF46, &[0x2042]),
+    (0xFF47, 0xFF47, &[0x2051]),
+    (0xFF48, 0xFF48, &[0x2075]),
+    (0xFF49, 0xFF49, &[0x2090]),
+    (0xFF4A, 0xFF4A, &[0x20AB]),
+    (0xFF4B, 0xFF4B, &[0x20C4]),
+    (0xFF4C, 0xFF4C, &[0x20D6]),
+    (0xFF4D, 0xFF4D, &[0x2109]),
+    (0xFF4E, 0xFF4E, &[0x2118]),
+    (0xFF4F, 0xFF4F, &[0x213C]),
+    (0xFF50, 0xFF50, &[0x216B]),
+    (0xFF51, 0xFF51, &[0x2180]),
+    (0xFF52, 0xFF52, &[0x2193]),
+    (0xFF53, 0xFF53, &[0x21D2]),
+    (0xFF54, 0xFF54, &[0x21F7]),
+    (0xFF55, 0xFF55, &[0x2217]),
+    (0xFF56, 0xFF56, &[0x2247]),
+    (0xFF57, 0xFF57, &[0x2259]),
+    (0xFF58, 0xFF58, &[0x2264]),
+    (0xFF59, 0xFF59, &[0x2270]),
+    (0xFF5A, 0xFF5A, &[0x2286]),
+    (0xFF5B, 0xFF5B, &[0x032C]),
+    (0xFF5C, 0xFF5C, &[0x066E]),
+    (0xFF5D, 0xFF5D, &[0x032D]),
+    (0xFF5E, 0xFF5E, &[0x0670]),
+    (0xFF5F, 0xFF60, &[0x033E]),
+    (0xFF61, 0xFF61, &[0x0292]),
+    (0xFF62, 0xFF63, &[0x0384]),
+    (0xFF64, 0xFF64, &[0x0236]),
+    (0xFF65, 0xFF65, &[0x0221]),
+    (0xFF66, 0xFF66, &[0x4309]),
+    (0xFF67, 0xFF69, &[0x42DA]),
+    (0xFF6A, 0xFF6B, &[0x42DE]),
+    (0xFF6C, 0xFF6E, &[0x42FE]),
+    (0xFF6F, 0xFF6F, &[0x42EC]),
+    (0xFF70, 0xFF70, &[0x1F60]),
+    (0xFF71, 0xFF73, &[0x42DA]),
+    (0xFF74, 0xFF9C, &[0x42DE]),
+    (0xFF9D, 0xFF9D, &[0x430A]),
+    (0xFF9E, 0xFF9F, &[]),
+    (0xFFA0, 0xFFA0, &[0x41F2]),
+    (0xFFA1, 0xFFA2, &[0x4175]),
+    (0xFFA3, 0xFFA3, &[0x4253]),
+    (0xFFA4, 0xFFA4, &[0x4177]),
+    (0xFFA5, 0xFFA6, &[0x4255]),
+    (0xFFA7, 0xFFA9, &[0x4178]),
+    (0xFFAA, 0xFFAF, &[0x4259]),
+    (0xFFB0, 0xFFB0, &[0x418F]),
+    (0xFFB1, 0xFFB3, &[0x417B]),
+    (0xFFB4, 0xFFB4, &[0x4196]),
+    (0xFFB5, 0xFFBE, &[0x417E]),
+    (0xFFC2, 0xFFC7, &[0x41F3]),
+    (0xFFCA, 0xFFCF, &[0x41F9]),
+    (0xFFD2, 0xFFD7, &[0x41FF]),
+    (0xFFDA, 0xFFDC, &[0x4205]),
+    (0xFFE0, 0xFFE0, &[0x1F63]),
+    (0xFFE1, 0xFFE1, &[0x1F65]),
+    (0xFFE2, 0xFFE2, &[0x066D]),
+    (0xFFE3, 0xFFE3, &[0x04B8]),
+    (0xFFE4, 0xFFE4, &[0x066F]),
+    (0xFFE5, 0xFFE5, &[0x1F66]),
+    (0xFFE6, 0xFFE6, &[0x1F80]),
+    (0xFFE8, 0xFFE8, &[0x086B]),
+    (0xFFE9, 0xFFE9, &[0x05EC]),
+    (0xFFEA, 0xFFEA, &[0x05EE]),
+    (0xFFEB, 0xFFEB, &[0x05ED]),
+    (0xFFEC, 0xFFEC, &[0x05EF]),
+    (0xFFED, 0xFFED, &[0x0909]),
+    (0xFFEE, 0xFFEE, &[0x0934]),
+    (0xFFF9, 0xFFFB, &[]),
+    (0xFFFC, 0xFFFC, &[0x1D55]),
+    (0xFFFD, 0xFFFD, &[0xFFFD]),
+    (0x10000, 0x1000B, &[0x4CFC]),
+    (0x1000D, 0x10026, &[0x4D08]),
+    (0x10028, 0x1003A, &[0x4D22]),
+    (0x1003C, 0x1003D, &[0x4D35]),
+    (0x1003F, 0x1004D, &[0x4D37]),
+    (0x10050, 0x1005D, &[0x4D46]),
+    (0x10080, 0x100FA, &[0x4D54]),
+    (0x10100, 0x10102, &[0x030B]),
+    (0x10107, 0x1010F, &[0x1F99]),
+    (0x10110, 0x10133, &[0x1DBE]),
+    (0x10137, 0x1013F, &[0x10C4]),
+    (0x10140, 0x10141, &[0x1DE2]),
+    (0x10142, 0x10142, &[0x1F99]),
+    (0x10143, 0x10143, &[0x1F9D]),
+    (0x10144, 0x10147, &[0x1DE4]),
+    (0x10148, 0x10148, &[0x1F9D]),
+    (0x10149, 0x1014E, &[0x1DE8]),
+    (0x1014F, 0x1014F, &[0x1F9D]),
+    (0x10150, 0x10157, &[0x1DEE]),
+    (0x10158, 0x10158, &[0x1F99]),
+    (0x10159, 0x10159, &[0x1F99]),
+    (0x1015A, 0x1015B, &[0x1F99]),
+    (0x1015C, 0x1015C, &[0x1F9A]),
+    (0x1015D, 0x1015D, &[0x1F9A]),
+    (0x1015E, 0x1015E, &[0x1F9A]),
+    (0x1015F, 0x1015F, &[0x1F9D]),
+    (0x10160, 0x10172, &[0x1DF6]),
+    (0x10173, 0x10173, &[0x1F9D]),
+    (0x10174, 0x10178, &[0x1E09]),
+    (0x10179, 0x10189, &[0x10CD]),
+    (0x1018A, 0x1018A, &[0x1F98]),
+    (0x1018B, 0x1018B, &[0x1E0E]),
+    (0x1018C, 0x1018E, &[0x10DE]),
+    (0x10190, 0x1019C, &[0x10E1]),
+    (0x101A0, 0x101A0, &[0x10EE]),
+    (0x101D0, 0x101FC, &[0x10EF]),
+    (0x101FD, 0x101FD, &[]),
+    (0x10280, 0x1029C, &[0x4AC1]),
+    (0x102A0, 0x102D0, &[0x4ADE]),
+    (0x102E0, 0x102E0, &[]),
+    (0x102E1, 0x102E9, &[0x1F99]),
+    (0x102EA, 0x102FB, &[0x1E0F]),
+    (0x10300, 0x1030E, &[0x4B29]),
+    (0x1030F, 0x1031E, &[0x4B39]),
+    (0x1031F, 0x1031F, &[0x4B38]),
+    (0x10320, 0x10320, &[0x1F99]),
+    (0x10321, 0x10321, &[0x1F9D]),
+    (0x10322, 0x10323, &[0x1DB8]),
+    (0x1032D, 0x1034A, &[0x4B49]),
+    (0x10350, 0x10375, &[0x2579]),
+    (0x10376, 0x10376, &[0x2579]),
+    (0x10377, 0x10377, &[0x257C]),
+    (0x10378, 0x10378, &[0x2580]),
+    (0x10379, 0x10379, &[0x2586]),
+    (0x1037A, 0x1037A, &[0x258A]),
+    (0x10380, 0x1039D, &[0x50FF]),
+    (0x1039F, 0x1039F, &[0x030E]),
+    (0x103A0, 0x103C3, &[0x511D]),
+    (0x103C8, 0x103CF, &[0x5141]),
+    (0x103D0, 0x103D0, &[0x030F]),
+    (0x103D1, 0x103D2, &[0x1F99]),
+    (0x103D3, 0x103D5, &[0x1E21]),
+    (0x10400, 0x10427, &[0x4B67]),
+    (0x10428, 0x1047F, &[0x4B67]),
+    (0x10480, 0x1049D, &[0x4C4A]),
+    (0x104A0, 0x104A9, &[0x1F98]),
+    (0x104B0, 0x104D3, &[0x3934]),
+    (0x104D8, 0x104FB, &[0x3934]),
+    (0x10500, 0x10527, &[0x4C68]),
+    (0x10530, 0x10563, &[0x4C90]),
+    (0x1056F, 0x1056F, &[0x045E]),
+    (0x10600, 0x10736, &[0x4DCF]),
+    (0x10740, 0x10755, &[0x4F06]),
+    (0x10760, 0x10767, &[0x4F1C]),
+    (0x10800, 0x10805, &[0x4F24]),
+    (0x10808, 0x10808, &[0x4F2A]),
+    (0x1080A, 0x10835, &[0x4F2B]),
+    (0x10837, 0x10838, &[0x4F57]),
+    (0x1083C, 0x1083C, &[0x4F59]),
+    (0x1083F, 0x1083F, &[0x4F5A]),
+    (0x10840, 0x10855, &[0x500B]),
+    (0x10857, 0x10857, &[0x02E2]),
+    (0x10858, 0x1085A, &[0x1F99]),
+    (0x1085B, 0x1085F, &[0x1E33]),
+    (0x10860, 0x1086D, &[0x4FCA]),
+    (0x1086E, 0x10876, &[0x4FD7]),
+    (0x10877, 0x10878, &[0x111C]),
+    (0x10879, 0x1087D, &[0x1F99]),
+    (0x1087E, 0x1087F, &[0x1E24]),
+    (0x10880, 0x10880, &[0x4FE0]),
+    (0x10881, 0x10882, &[0x4FE0]),
+    (0x10883, 0x10886, &[0x4FE1]),
+    (0x10887, 0x1088C, &[0x4FE4]),
+    (0x1088D, 0x1088E, &[0x4FE9]),
+    (0x1088F, 0x10890, &[0x4FEA]),
+    (0x10891, 0x10892, &[0x4FEB]),
+    (0x10893, 0x10894, &[0x4FEC]),
+    (0x10895, 0x1089C, &[0x4FED]),
+    (0x1089D, 0x1089E, &[0x4FF4]),
+    (0x108A7, 0x108AA, &[0x1F99]),
+    (0x108AB, 0x108AC, &[0x1F9C]),
+    (0x108AD, 0x108AF, &[0x1E26]),
+    (0x108E0, 0x108F2, &[0x4FF6]),
+    (0x108F4, 0x108F5, &[0x5009]),
+    (0x108FB, 0x108FB, &[0x1F99]),
+    (0x108FC, 0x108FC, &[0x1F9D]),
+    (0x108FD, 0x108FF, &[0x1E29]),
+    (0x10900, 0x10915, &[0x2634]),
+    (0x10916, 0x10916, &[0x1F99]),
+    (0x10917, 0x10919, &[0x1E30]),
+    (0x1091A, 0x1091B, &[0x1F9A]),
+    (0x1091F, 0x1091F, &[0x0310]),
+    (0x10920, 0x10939, &[0x4B0F]),
+    (0x1093F, 0x1093F, &[0x030A]),
+    (0x10980, 0x10986, &[0x59D6]),
+    (0x10987, 0x1098A, &[0x59DC]),
+    (0x1098B, 0x1098C, &[0x59DF]),
+    (0x1098D, 0x1098E, &[0x59E0]),
+    (0x1098F, 0x10993, &[0x59E1]),
+    (0x10994, 0x10998, &[0x59E5]),
+    (0x10999, 0x1099A, &[0x59E9]),
+    (0x1099B, 0x1099D, &[0x59EA]),
+    (0x1099E, 0x1099F, &[0x59EF]),
+    (0x109A0, 0x109AF, &[0x59D6]),
+    (0x109B0, 0x109B7, &[0x59E5]),
+    (0x109BC, 0x109BC, &[0x1F2B]),
+    (0x109BD, 0x109BD, &[0x1F20]),
+    (0x109BE, 0x109BF, &[0x59ED]),
+    (0x109C0, 0x109C8, &[0x1F99]),
+    (0x109C9, 0x109CF, &[0x1EF5]),
+    (0x109D2, 0x109F5, &[0x1EFC]),
+    (0x109F6, 0x109FF, &[0x1F21]),
+    (0x10A00, 0x10A03, &[0x31AF]),
+    (0x10A05, 0x10A06, &[0x31B3]),
+    (0x10A0C, 0x10A0C, &[0x31B5]),
+    (0x10A0D, 0x10A0F, &[]),
+    (0x10A10, 0x10A10, &[0x31B6]),
+    (0x10A11, 0x10A13, &[0x31B8]),
+    (0x10A15, 0x10A17, &[0x31BB]),
+    (0x10A19, 0x10A1A, &[0x31BE]),
+    (0x10A1B, 0x10A1B, &[0x31C1]),
+    (0x10A1C, 0x10A2C, &[0x31C3]),
+    (0x10A2D, 0x10A31, &[0x31D5]),
+    (0x10A32, 0x10A32, &[0x31B7]),
+    (0x10A33, 0x10A33, &[0x31C2]),
+    (0x10A34, 0x10A34, &[0x31C0]),
+    (0x10A35, 0x10A35, &[0x31D4]),
+    (0x10A38, 0x10A3A, &[]),
+    (0x10A3F, 0x10A3F, &[0x31DA]),
+    (0x10A40, 0x10A43, &[0x1F99]),
+    (0x10A44, 0x10A48, &[0x1E5E]),
+    (0x10A50, 0x10A55, &[0x0464]),
+    (0x10A56, 0x10A57, &[0x02B2]),
+    (0x10A58, 0x10A58, &[0x046A]),
+    (0x10A60, 0x10A7C, &[0x4F5B]),
+    (0x10A7D, 0x10A7D, &[0x1F99]),
+    (0x10A7E, 0x10A7F, &[0x1E2C]),
+    (0x10A80, 0x10A9C, &[0x4F78]),
+    (0x10A9D, 0x10A9D, &[0x1F99]),
+    (0x10A9E, 0x10A9F, &[0x1E2E]),
+    (0x10AC0, 0x10AC7, &[0x5072]),
+    (0x10AC8, 0x10AE4, &[0x5079]),
+    (0x10AE5, 0x10AE6, &[]),
+    (0x10AEB, 0x10AEB, &[0x1F99]),
+    (0x10AEC, 0x10AEC, &[0x1F9D]),
+    (0x10AED, 0x10AEF, &[0x1E38]),
+    (0x10AF0, 0x10AF6, &[0x046F]),
+    (0x10B00, 0x10B2D, &[0x4F95]),
+    (0x10B2E, 0x10B35, &[0x4FC2]),
+    (0x10B39, 0x10B39, &[0x046E]),
+    (0x10B3A, 0x10B3F, &[0x02E3]),
+    (0x10B40, 0x10B55, &[0x5021]),
+    (0x10B58, 0x10B5B, &[0x1F99]),
+    (0x10B5C, 0x10B5F, &[0x1E3B]),
+    (0x10B60, 0x10B72, &[0x5037]),
+    (0x10B78, 0x10B7B, &[0x1F99]),
+    (0x10B7C, 0x10B7F, &[0x1E3F]),
+    (0x10B80, 0x10B91, &[0x504A]),
+    (0x10B99, 0x10B9C, &[0x0476]),
+    (0x10BA9, 0x10BAC, &[0x1F99]),
+    (0x10BAD, 0x10BAF, &[0x1E43]),
+    (0x10C00, 0x10C00, &[0x3C8C]),
+    (0x10C01, 0x10C03, &[0x3C8C]),
+    (0x10C04, 0x10C07, &[0x3C8E]),
+    (0x10C08, 0x10C09, &[0x3C91]),
+    (0x10C0A, 0x10C0B, &[0x3C92]),
+    (0x10C0C, 0x10C0D, &[0x3C93]),
+    (0x10C0E, 0x10C0F, &[0x3C94]),
+    (0x10C10, 0x10C11, &[0x3C95]),
+    (0x10C12, 0x10C14, &[0x3C96]),
+    (0x10C15, 0x10C16, &[0x3C98]),
+    (0x10C17, 0x10C18, &[0x3C99]),
+    (0x10C19, 0x10C1A, &[0x3C9A]),
+    (0x10C1B, 0x10C1C, &[0x3C9B]),
+    (0x10C1D, 0x10C1E, &[0x3C9C]),
+    (0x10C1F, 0x10C24, &[0x3C9D]),
+    (0x10C25, 0x10C26, &[0x3CA2]),
+    (0x10C27, 0x10C28, &[0x3CA3]),
+    (0x10C29, 0x10C2A, &[0x3CA4]),
+    (0x10C2B, 0x10C2D, &[0x3CA5]),
+    (0x10C2E, 0x10C32, &[0x3CA7]),
+    (0x10C33, 0x10C34, &[0x3CAB]),
+    (0x10C35, 0x10C36, &[0x3CAC]),
+    (0x10C37, 0x10C38, &[0x3CAD]),
+    (0x10C39, 0x10C3A, &[0x3CAE]),
+    (0x10C3B, 0x10C3F, &[0x3CAF]),
+    (0x10C40, 0x10C41, &[0x3CB3]),
+    (0x10C42, 0x10C43, &[0x3CB4]),
+    (0x10C44, 0x10C45, &[0x3CB5]),
+    (0x10C46, 0x10C48, &[0x3CB6]),
+    (0x10C80, 0x10C80, &[0x3C63]),
+    (0x10C81, 0x10C89, &[0x3C63]),
+    (0x10C8A, 0x10C8A, &[0x3C6B]),
+    (0x10C8B, 0x10C90, &[0x3C6B]),
+    (0x10C91, 0x10C9B, &[0x3C70]),
+    (0x10C9C, 0x10C9D, &[0x3C7A]),
+    (0x10C9E, 0x10C9E, &[0x3C7B]),
+    (0x10C9F, 0x10CA2, &[0x3C7B]),
+    (0x10CA3, 0x10CAA, &[0x3C7E]),
+    (0x10CAB, 0x10CAC, &[0x3C85]),
+    (0x10CAD, 0x10CB2, &[0x3C86]),
+    (0x10CC0, 0x10CC0, &[0x3C63]),
+    (0x10CC1, 0x10CC9, &[0x3C63]),
+    (0x10CCA, 0x10CCA, &[0x3C6B]),
+    (0x10CCB, 0x10CD0, &[0x3C6B]),
+    (0x10CD1, 0x10CDB, &[0x3C70]),
+    (0x10CDC, 0x10CDD, &[0x3C7A]),
+    (0x10CDE, 0x10CDE, &[0x3C7B]),
+    (0x10CDF, 0x10CE2, &[0x3C7B]),
+    (0x10CE3, 0x10CEA, &[0x3C7E]),
+    (0x10CEB, 0x10CEC, &[0x3C85]),
+    (0x10CED, 0x10CF2, &[0x3C86]),
+    (0x10CFA, 0x10CFA, &[0x1F99]),
+    (0x10CFB, 0x10CFB, &[0x1F9D]),
+    (0x10CFC, 0x10CFF, &[0x1DBA]),
+    (0x10D00, 0x10D00, &[0x361B]),
+    (0x10D01, 0x10D1C, &[0x3621]),
+    (0x10D1D, 0x10D21, &[0x361C]),
+    (0x10D22, 0x10D23, &[0x363D]),
+    (0x10D24, 0x10D27, &[]),
+    (0x10D30, 0x10D39, &[0x1F98]),
+    (0x10E60, 0x10E68, &[0x1F99]),
+    (0x10E69, 0x10E7E, &[0x1DA1]),
+    (0x10E80, 0x10EA0, &[0x50BE]),
+    (0x10EA1, 0x10EA8, &[0x50E0]),
+    (0x10EA9, 0x10EA9, &[0x50E9]),
+    (0x10EAB, 0x10EAC, &[]),
+    (0x10EAD, 0x10EAD, &[0x0222]),
+    (0x10EB0, 0x10EB0, &[0x50DF]),
+    (0x10EB1, 0x10EB1, &[0x50E8]),
+    (0x10F00, 0x10F00, &[0x5096]),
+    (0x10F01, 0x10F02, &[0x5096]),
+    (0x10F03, 0x10F05, &[0x5097]),
+    (0x10F06, 0x10F0E, &[0x5099]),
+    (0x10F0F, 0x10F0F, &[0x50A1]),
+    (0x10F10, 0x10F12, &[0x50A1]),
+    (0x10F13, 0x10F15, &[0x50A3]),
+    (0x10F16, 0x10F16, &[0x50A5]),
+    (0x10F17, 0x10F1A, &[0x50A5]),
+    (0x10F1B, 0x10F1B, &[0x50A8]),
+    (0x10F1C, 0x10F1C, &[0x50A8]),
+    (0x10F1D, 0x10F21, &[0x1F99]),
+    (0x10F22, 0x10F26, &[0x1E46]),
+    (0x10F27, 0x10F27, &[0x50A3, 0x50A6]),
+    (0x10F30, 0x10F44, &[0x50A9]),
+    (0x10F45, 0x10F45, &[0x50BA]),
+    (0x10F46, 0x10F50, &[]),
+    (0x10F51, 0x10F51, &[0x1F99]),
+    (0x10F52, 0x10F54, &[0x1E4B]),
+    (0x10F55, 0x10F59, &[0x02E9]),
+    (0x10FB0, 0x10FC4, &[0x50EA]),
+    (0x10FC5, 0x10FC8, &[0x1F99]),
+    (0x10FC9, 0x10FCB, &[0x1E4E]),
+    (0x10FE0, 0x10FF5, &[0x505C]),
+    (0x10FF6, 0x10FF6, &[0x5062, 0x5065]),
+    (0x11000, 0x11002, &[]),
+    (0x11003, 0x11004, &[0x3199]),
+    (0x11005, 0x11033, &[0x316A]),
+    (0x11034, 0x11046, &[0x319B]),
+    (0x11047, 0x11048, &[0x02B4]),
+    (0x11049, 0x1104D, &[0x045F]),
+    (0x11052, 0x1105A, &[0x1F99]),
+    (0x1105B, 0x11065, &[0x1E53]),
+    (0x11066, 0x1106F, &[0x1F98]),
+    (0x1107F, 0x1107F, &[0x31AE]),
+    (0x11080, 0x11082, &[]),
+    (0x11083, 0x11099, &[0x2D47]),
+    (0x1109A, 0x1109B, &[0x2D5D]),
+    (0x1109C, 0x110AA, &[0x2D5E]),
+    (0x110AB, 0x110AB, &[0x2D67]),
+    (0x110AC, 0x110B9, &[0x2D6D]),
+    (0x110BA, 0x110BA, &[]),
+    (0x110BB, 0x110BC, &[0x047A]),
+    (0x110BD, 0x110BD, &[]),
+    (0x110BE, 0x110BF, &[0x02EE]),
+    (0x110C0, 0x110C1, &[0x02B6]),
+    (0x110CD, 0x110CD, &[]),
+    (0x110D0, 0x110E8, &[0x4CC4]),
+    (0x110F0, 0x110F9, &[0x1F98]),
+    (0x11100, 0x11102, &[]),
+    (0x11103, 0x11123, &[0x363F]),
+    (0x11124, 0x11126, &[0x3661]),
+    (0x11127, 0x11132, &[0x3665]),
+    (0x11133, 0x11134, &[0x3673]),
+    (0x11136, 0x1113F, &[0x1F98]),
+    (0x11140, 0x11140, &[0x02F0]),
+    (0x11141, 0x11142, &[0x02B8]),
+    (0x11143, 0x11143, &[0x027A]),
+    (0x11144, 0x11144, &[0x3664]),
+    (0x11145, 0x11146, &[0x3671]),
+    (0x11147, 0x11147, &[0x3660]),
+    (0x11150, 0x1116F, &[0x2D7B]),
+    (0x11170, 0x11172, &[0x2D9C]),
+    (0x11173, 0x11173, &[]),
+    (0x11174, 0x11175, &[0x047C]),
+    (0x11176, 0x11176, &[0x2D9B]),
+    (0x11180, 0x11182, &[]),
+    (0x11183, 0x111B2, &[0x2DA1]),
+    (0x111B3, 0x111BC, &[0x2DD5]),
+    (0x111BD, 0x111C0, &[0x2DE0]),
+    (0x111C1, 0x111C3, &[0x2DD1]),
+    (0x111C4, 0x111C4, &[0x2D9F]),
+    (0x111C5, 0x111C6, &[0x02BA]),
+    (0x111C7, 0x111C7, &[0x047F]),
+    (0x111C8, 0x111C8, &[0x02F1]),
+    (0x111C9, 0x111CC, &[]),
+    (0x111CD, 0x111CD, &[0x047E]),
+    (0x111CE, 0x111CE, &[0x2DDF]),
+    (0x111CF, 0x111CF, &[]),
+    (0x111D0, 0x111D9, &[0x1F98]),
+    (0x111DA, 0x111DA, &[0x2DA0]),
+    (0x111DB, 0x111DB, &[0x0480]),
+    (0x111DC, 0x111DC, &[0x2DD4]),
+    (0x111DD, 0x111DD, &[0x0481]),
+    (0x111DE, 0x111DF, &[0x02F2]),
+    (0x111E1, 0x111E9, &[0x1F99]),
+    (0x111EA, 0x111F4, &[0x1E6D]),
+    (0x11200, 0x11211, &[0x2DE4]),
+    (0x11213, 0x11233, &[0x2DF6]),
+    (0x11234, 0x11234, &[]),
+    (0x11235, 0x11235, &[0x2E17]),
+    (0x11236, 0x11237, &[]),
+    (0x11238, 0x11239, &[0x02BC]),
+    (0x1123A, 0x1123D, &[0x0482]),
+    (0x1123E, 0x1123E, &[]),
+    (0x11280, 0x11283, &[0x2E51]),
+    (0x11284, 0x11286, &[0x2E57]),
+    (0x11288, 0x11288, &[0x2E5A]),
+    (0x1128A, 0x1128D, &[0x2E5B]),
+    (0x1128F, 0x1129D, &[0x2E5F]),
+    (0x1129F, 0x112A4, &[0x2E6E]),
+    (0x112A5, 0x112A6, &[0x2E55]),
+    (0x112A7, 0x112A8, &[0x2E74]),
+    (0x112A9, 0x112A9, &[0x02F4]),
+    (0x112B0, 0x112DE, &[0x2E18]),
+    (0x112DF, 0x112DF, &[]),
+    (0x112E0, 0x112E8, &[0x2E47]),
+    (0x112E9, 0x112E9, &[]),
+    (0x112EA, 0x112EA, &[0x2E50]),
+    (0x112F0, 0x112F9, &[0x1F98]),
+    (0x11300, 0x11303, &[]),
+    (0x11305, 0x1130B, &[0x2E77]),
+    (0x1130C, 0x1130C, &[0x2E7F]),
+    (0x1130F, 0x11310, &[0x2E81]),
+    (0x11313, 0x11328, &[0x2E83]),
+    (0x1132A, 0x11330, &[0x2E99]),
+    (0x11332, 0x11333, &[0x2EA0]),
+    (0x11335, 0x11339, &[0x2EA2]),
+    (0x1133B, 0x1133C, &[]),
+    (0x1133D, 0x1133D, &[0x2EA7]),
+    (0x1133E, 0x11344, &[0x2EAA]),
+    (0x11347, 0x11348, &[0x2EB3]),
+    (0x1134B, 0x1134D, &[0x2EB5]),
+    (0x11350, 0x11350, &[0x2E76]),
+    (0x11357, 0x11357, &[0x2EB8]),
+    (0x1135D, 0x1135D, &[0x2EB9]),
+    (0x1135E, 0x1135F, &[0x2EA8]),
+    (0x11360, 0x11360, &[0x2E7E]),
+    (0x11361, 0x11361, &[0x2E80]),
+    (0x11362, 0x11363, &[0x2EB1]),
+    (0x11366, 0x1136C, &[]),
+    (0x11370, 0x11374, &[]),
+    (0x11400, 0x11434, &[0x2EBC]),
+    (0x11435, 0x11442, &[0x2EF6]),
+    (0x11443, 0x11446, &[]),
+    (0x11447, 0x11447, &[0x2EF1]),
+    (0x11448, 0x11448, &[0x2EF4]),
+    (0x11449, 0x1144A, &[0x2EBA]),
+    (0x1144B, 0x1144C, &[0x02BE]),
+    (0x1144D, 0x1144D, &[0x0486]),
+    (0x1144E, 0x1144F, &[0x0488]),
+    (0x11450, 0x11459, &[0x1F98]),
+    (0x1145A, 0x1145A, &[0x0487]),
+    (0x1145B, 0x1145B, &[0x048A]),
+    (0x1145D, 0x1145D, &[0x048B]),
+    (0x1145E, 0x1145E, &[]),
+    (0x1145F, 0x1145F, &[0x2EF5]),
+    (0x11460, 0x11461, &[0x2EF2]),
+    (0x11480, 0x114AF, &[0x2F05]),
+    (0x114B0, 0x114BE, &[0x2F37]),
+    (0x114BF, 0x114C1, &[]),
+    (0x114C2, 0x114C2, &[0x2F46]),
+    (0x114C3, 0x114C3, &[]),
+    (0x114C4, 0x114C5, &[0x2F35]),
+    (0x114C6, 0x114C6, &[0x048C]),
+    (0x114C7, 0x114C7, &[0x2F04]),
+    (0x114D0, 0x114D9, &[0x1F98]),
+    (0x11580, 0x115B5, &[0x2F47]),
+    (0x115B8, 0x115BB, &[0x2F7D]),
+    (0x115BC, 0x115BE, &[]),
+    (0x115BF, 0x115BF, &[0x2F81]),
+    (0x115C0, 0x115C0, &[]),
+    (0x115C1, 0x115C1, &[0x048D]),
+    (0x115C2, 0x115C3, &[0x02C0]),
+    (0x115C4, 0x115D7, &[0x048E]),
+    (0x115D8, 0x115D8, &[0x2F49]),
+    (0x115D9, 0x115DB, &[0x2F49]),
+    (0x115DC, 0x115DD, &[0x2F79]),
+    (0x11600, 0x1163C, &[0x2F82]),
+    (0x1163D, 0x1163E, &[]),
+    (0x1163F, 0x1163F, &[0x2FBF]),
+    (0x11640, 0x11640, &[]),
+    (0x11641, 0x11642, &[0x02C2]),
+    (0x11643, 0x11643, &[0x04A2]),
+    (0x11644, 0x11644, &[0x2FC0]),
+    (0x11650, 0x11659, &[0x1F98]),
+    (0x11660, 0x1166C, &[0x03F3]),
+    (0x11680, 0x11689, &[0x2FC1]),
+    (0x1168A, 0x1168B, &[0x2FCE]),
+    (0x1168C, 0x116A6, &[0x2FD1]),
+    (0x116A7, 0x116A7, &[0x2FCC]),
+    (0x116A8, 0x116A8, &[0x2FCB]),
+    (0x116A9, 0x116A9, &[0x2FCD]),
+    (0x116AA, 0x116AA, &[0x2FEC]),
+    (0x116AB, 0x116AC, &[]),
+    (0x116AD, 0x116B6, &[0x2FED]),
+    (0x116B7, 0x116B7, &[]),
+    (0x116B8, 0x116B8, &[0x2FD0]),
+    (0x116C0, 0x116C9, &[0x1F98]),
+    (0x11700, 0x11704, &[0x30A5]),
+    (0x11705, 0x11715, &[0x30A9]),
+    (0x11716, 0x11719, &[0x30B9]),
+    (0x1171A, 0x1171A, &[0x30AC]),
+    (0x1171D, 0x1171F, &[0x30C9]),
+    (0x11720, 0x1172B, &[0x30BD]),
+    (0x11730, 0x11739, &[0x1F98]),
+    (0x1173A, 0x1173B, &[0x1E51]),
+    (0x1173C, 0x1173D, &[0x02C4]),
+    (0x1173E, 0x1173E, &[0x02F5]),
+    (0x1173F, 0x1173F, &[0x059E]),
+    (0x11800, 0x11836, &[0x306D]),
+    (0x11837, 0x11838, &[]),
+    (0x11839, 0x11839, &[0x30A4]),
+    (0x1183A, 0x1183A, &[]),
+    (0x1183B, 0x1183B, &[0x04A3]),
+    (0x118A0, 0x118BF, &[0x49AF]),
+    (0x118C0, 0x118DF, &[0x49AF]),
+    (0x118E0, 0x118E9, &[0x1F98]),
+    (0x118EA, 0x118F2, &[0x1E78]),
+    (0x118FF, 0x118FF, &[0x49AE]),
+    (0x11900, 0x11906, &[0x2FF7]),
+    (0x11909, 0x11909, &[0x2FFE]),
+    (0x1190C, 0x11910, &[0x2FFF]),
+    (0x11911, 0x11913, &[0x3005]),
+    (0x11915, 0x11916, &[0x3008]),
+    (0x11918, 0x11925, &[0x300A]),
+    (0x11926, 0x11926, &[0x3019]),
+    (0x11927, 0x11927, &[0x301B]),
+    (0x11928, 0x11935, &[0x301D]),
+    (0x11937, 0x11938, &[0x302B]),
+    (0x1193B, 0x1193C, &[]),
+    (0x1193D, 0x1193E, &[0x302D]),
+    (0x1193F, 0x1193F, &[0x3004]),
+    (0x11940, 0x11940, &[0x3018]),
+    (0x11941, 0x11941, &[0x301A]),
+    (0x11942, 0x11942, &[0x301C]),
+    (0x11943, 0x11943, &[]),
+    (0x11944, 0x11944, &[0x02C6]),
+    (0x11945, 0x11945, &[0x04A4]),
+    (0x11946, 0x11946, &[0x02F6]),
+    (0x11950, 0x11959, &[0x1F98]),
+    (0x119A0, 0x119A7, &[0x302F]),
+    (0x119AA, 0x119D0, &[0x3037]),
+    (0x119D1, 0x119D7, &[0x3060]),
+    (0x119DA, 0x119DA, &[0x3067]),
+    (0x119DB, 0x119DD, &[0x3069]),
+    (0x119DE, 0x119DF, &[]),
+    (0x119E0, 0x119E0, &[0x306C]),
+    (0x119E1, 0x119E1, &[0x305E]),
+    (0x119E2, 0x119E2, &[0x04A5]),
+    (0x119E3, 0x119E3, &[0x305F]),
+    (0x119E4, 0x119E4, &[0x3068]),
+    (0x11A00, 0x11A0A, &[0x3361]),
+    (0x11A0B, 0x11A0B, &[0x3339]),
+    (0x11A0C, 0x11A31, &[0x333B]),
+    (0x11A32, 0x11A32, &[0x333A]),
+    (0x11A33, 0x11A33, &[]),
+    (0x11A34, 0x11A34, &[0x336C]),
+    (0x11A35, 0x11A39, &[]),
+    (0x11A3A, 0x11A3A, &[0x335A]),
+    (0x11A3B, 0x11A3E, &[0x3359]),
+    (0x11A3F, 0x11A46, &[0x0426]),
+    (0x11A47, 0x11A47, &[0x336D]),
+    (0x11A50, 0x11A53, &[0x336E]),
+    (0x11A54, 0x11A54, &[0x3374]),
+    (0x11A55, 0x11A55, &[0x3376]),
+    (0x11A56, 0x11A56, &[0x3375]),
+    (0x11A57, 0x11A58, &[0x3377]),
+    (0x11A59, 0x11A5A, &[0x3372]),
+    (0x11A5B, 0x11A5C, &[0x3379]),
+    (0x11A5D, 0x11A82, &[0x337C]),
+    (0x11A83, 0x11A83, &[0x337B]),
+    (0x11A84, 0x11A85, &[0x33A2]),
+    (0x11A86, 0x11A87, &[0x339B]),
+    (0x11A88, 0x11A88, &[0x339E]),
+    (0x11A89, 0x11A89, &[0x33A0]),
+    (0x11A8A, 0x11A8A, &[0x337A]),
+    (0x11A8B, 0x11A8B, &[0x337C]),
+    (0x11A8C, 0x11A8C, &[0x337F]),
+    (0x11A8D, 0x11A8D, &[0x338A]),
+    (0x11A8E, 0x11A8F, &[0x338E]),
+    (0x11A90, 0x11A90, &[0x3393]),
+    (0x11A91, 0x11A92, &[0x339B]),
+    (0x11A93, 0x11A93, &[0x339E]),
+    (0x11A94, 0x11A94, &[0x33A0]),
+    (0x11A95, 0x11A95, &[0x3399]),
+    (0x11A96, 0x11A98, &[]),
+    (0x11A99, 0x11A99, &[0x33A4]),
+    (0x11A9A, 0x11A9C, &[0x042E]),
+    (0x11A9D, 0x11A9D, &[0x33A5]),
+    (0x11A9E, 0x11AA2, &[0x0431]),
+    (0x11AC0, 0x11AD4, &[0x49D6]),
+    (0x11AD5, 0x11ADB, &[0x49CF]),
+    (0x11ADC, 0x11ADD, &[0x49EB]),
+    (0x11ADE, 0x11ADE, &[0x49F2]),
+    (0x11ADF, 0x11AE3, &[0x49ED]),
+    (0x11AE4, 0x11AE4, &[0x49F3]),
+    (0x11AE5, 0x11AE5, &[0x49F9]),
+    (0x11AE6, 0x11AE6, &[0x49F7]),
+    (0x11AE7, 0x11AE7, &[0x4A05]),
+    (0x11AE8, 0x11AE8, &[0x49FA]),
+    (0x11AE9, 0x11AE9, &[0x49F8]),
+    (0x11AEA, 0x11AEA, &[0x4A06]),
+    (0x11AEB, 0x11AEB, &[0x4A01]),
+    (0x11AEC, 0x11AEC, &[0x49FF]),
+    (0x11AED, 0x11AED, &[0x4A02]),
+    (0x11AEE, 0x11AEE, &[0x4A00]),
+    (0x11AEF, 0x11AEF, &[0x49F4]),
+    (0x11AF0, 0x11AF0, &[0x4A07]),
+    (0x11AF1, 0x11AF1, &[0x49F6]),
+    (0x11AF2, 0x11AF2, &[0x49F5]),
+    (0x11AF3, 0x11AF3, &[0x49FD]),
+    (0x11AF4, 0x11AF4, &[0x49FB]),
+    (0x11AF5, 0x11AF5, &[0x4A03]),
+    (0x11AF6, 0x11AF6, &[0x49FE]),
+    (0x11AF7, 0x11AF7, &[0x49FC]),
+    (0x11AF8, 0x11AF8, &[0x4A04]),
+    (0x11C00, 0x11C08, &[0x31DB]),
+    (0x11C0A, 0x11C2E, &[0x31E4]),
+    (0x11C2F, 0x11C36, &[0x320A]),
+    (0x11C38, 0x11C3B, &[0x3212]),
+    (0x11C3C, 0x11C3E, &[]),
+    (0x11C3F, 0x11C3F, &[0x3216]),
+    (0x11C40, 0x11C40, &[0x3209]),
+    (0x11C41, 0x11C42, &[0x02C7]),
+    (0x11C43, 0x11C45, &[0x046B]),
+    (0x11C50, 0x11C59, &[0x1F98]),
+    (0x11C5A, 0x11C62, &[0x1F99]),
+    (0x11C63, 0x11C6C, &[0x1E63]),
+    (0x11C70, 0x11C71, &[0x0436]),
+    (0x11C72, 0x11C72, &[0x33A6]),
+    (0x11C73, 0x11C73, &[0x33A8]),
+    (0x11C74, 0x11C74, &[0x33AA]),
+    (0x11C75, 0x11C75, &[0x33AC]),
+    (0x11C76, 0x11C76, &[0x33AE]),
+    (0x11C77, 0x11C77, &[0x33B0]),
+    (0x11C78, 0x11C78, &[0x33B2]),
+    (0x11C79, 0x11C79, &[0x33B4]),
+    (0x11C7A, 0x11C7A, &[0x33B6]),
+    (0x11C7B, 0x11C7B, &[0x33B8]),
+    (0x11C7C, 0x11C7C, &[0x33BA]),
+    (0x11C7D, 0x11C7D, &[0x33BC]),
+    (0x11C7E, 0x11C7E, &[0x33BE]),
+    (0x11C7F, 0x11C7F, &[0x33C0]),
+    (0x11C80, 0x11C80, &[0x33C2]),
+    (0x11C81, 0x11C81, &[0x33C4]),
+    (0x11C82, 0x11C82, &[0x33C6]),
+    (0x11C83, 0x11C83, &[0x33C8]),
+    (0x11C84, 0x11C84, &[0x33CA]),
+    (0x11C85, 0x11C85, &[0x33CC]),
+    (0x11C86, 0x11C86, &[0x33CE]),
+    (0x11C87, 0x11C87, &[0x33D0]),
+    (0x11C88, 0x11C89, &[0x33D2]),
+    (0x11C8A, 0x11C8A, &[0x33D5]),
+    (0x11C8B, 0x11C8B, &[0x33D7]),
+    (0x11C8C, 0x11C8C, &[0x33D9]),
+    (0x11C8D, 0x11C8D, &[0x33DB]),
+    (0x11C8E, 0x11C8E, &[0x33DD]),
+    (0x11C8F, 0x11C8F, &[0x33DF]),
+    (0x11C92, 0x11C92, &[0x33A7]),
+    (0x11C93, 0x11C93, &[0x33A9]),
+    (0x11C94, 0x11C94, &[0x33AB]),
+    (0x11C95, 0x11C95, &[0x33AD]),
+    (0x11C96, 0x11C96, &[0x33AF]),
+    (0x11C97, 0x11C97, &[0x33B1]),
+    (0x11C98, 0x11C98, &[0x33B3]),
+    (0x11C99, 0x11C99, &[0x33B5]),
+    (0x11C9A, 0x11C9A, &[0x33B7]),
+    (0x11C9B, 0x11C9B, &[0x33B9]),
+    (0x11C9C, 0x11C9C, &[0x33BB]),
+    (0x11C9D, 0x11C9D, &[0x33BD]),
+    (0x11C9E, 0x11C9E, &[0x33BF]),
+    (0x11C9F, 0x11C9F, &[0x33C1]),
+    (0x11CA0, 0x11CA0, &[0x33C3]),
+    (0x11CA1, 0x11CA1, &[0x33C5]),
+    (0x11CA2, 0x11CA2, &[0x33C7]),
+    (0x11CA3, 0x11CA3, &[0x33C9]),
+    (0x11CA4, 0x11CA4, &[0x33CB]),
+    (0x11CA5, 0x11CA5, &[0x33CD]),
+    (0x11CA6, 0x11CA6, &[0x33CF]),
+    (0x11CA7, 0x11CA7, &[0x33D1]),
+    (0x11CA9, 0x11CA9, &[0x33D4]),
+    (0x11CAA, 0x11CAA, &[0x33D6]),
+    (0x11CAB, 0x11CAB, &[0x33D8]),
+    (0x11CAC, 0x11CAC, &[0x33DA]),
+    (0x11CAD, 0x11CAD, &[0x33DC]),
+    (0x11CAE, 0x11CAE, &[0x33DE]),
+    (0x11CAF, 0x11CB4, &[0x33E0]),
+    (0x11CB5, 0x11CB6, &[]),
+    (0x11D00, 0x11D06, &[0x30CC]),
+    (0x11D08, 0x11D09, &[0x30D3]),
+    (0x11D0B, 0x11D36, &[0x30D5]),
+    (0x11D3A, 0x11D3A, &[0x3101]),
+    (0x11D3C, 0x11D3D, &[0x3102]),
+    (0x11D3F, 0x11D3F, &[0x3104]),
+    (0x11D40, 0x11D43, &[]),
+    (0x11D44, 0x11D45, &[0x3105]),
+    (0x11D46, 0x11D46, &[0x30F0]),
+    (0x11D47, 0x11D47, &[0x30F0]),
+    (0x11D50, 0x11D59, &[0x1F98]),
+    (0x11D60, 0x11D65, &[0x3108]),
+    (0x11D67, 0x11D68, &[0x310E]),
+    (0x11D6A, 0x11D8E, &[0x3110]),
+    (0x11D90, 0x11D91, &[0x3135]),
+    (0x11D93, 0x11D94, &[0x3137]),
+    (0x11D95, 0x11D96, &[]),
+    (0x11D97, 0x11D97, &[0x3139]),
+    (0x11D98, 0x11D98, &[0x3107]),
+    (0x11DA0, 0x11DA9, &[0x1F98]),
+    (0x11EE0, 0x11EF6, &[0x34F1]),
+    (0x11EF7, 0x11EF8, &[0x02F7]),
+    (0x11FB0, 0x11FB0, &[0x4912]),
+    (0x11FC0, 0x11FD4, &[0x1D6B]),
+    (0x11FD5, 0x11FDC, &[0x0550]),
+    (0x11FDD, 0x11FE0, &[0x1F71]),
+    (0x11FE1, 0x11FF1, &[0x0558]),
+    (0x11FFF, 0x11FFF, &[0x04A6]),
+    (0x12000, 0x12262, &[0x5149]),
+    (0x12263, 0x122D3, &[0x53AE]),
+    (0x122D4, 0x122D5, &[0x53AC]),
+    (0x122D6, 0x12399, &[0x541F]),
+    (0x12400, 0x12407, &[0x1F9A]),
+    (0x12408, 0x1240E, &[0x1F9B]),
+    (0x1240F, 0x12414, &[0x1F9C]),
+    (0x12415, 0x1241D, &[0x1F99]),
+    (0x1241E, 0x12422, &[0x1F99]),
+    (0x12423, 0x12424, &[0x1F9A]),
+    (0x12425, 0x1242B, &[0x1F9B]),
+    (0x1242C, 0x1242E, &[0x1F99]),
+    (0x1242F, 0x12431, &[0x1F9B]),
+    (0x12432, 0x12433, &[0x1F2C]),
+    (0x12434, 0x12436, &[0x1F99]),
+    (0x12437, 0x12439, &[0x1F9B]),
+    (0x1243A, 0x1243A, &[0x1F9B]),
+    (0x1243B, 0x1243C, &[0x1F9B]),
+    (0x1243D, 0x1243D, &[0x1F9C]),
+    (0x1243E, 0x1243E, &[0x1F9C]),
+    (0x1243F, 0x1243F, &[0x1F9C]),
+    (0x12440, 0x12441, &[0x1F9E]),
+    (0x12442, 0x12442, &[0x1F9F]),
+    (0x12443, 0x12444, &[0x1F9F]),
+    (0x12445, 0x12446, &[0x1FA0]),
+    (0x12447, 0x12447, &[0x1FA1]),
+    (0x12448, 0x12448, &[0x1FA1]),
+    (0x12449, 0x12449, &[0x1FA1]),
+    (0x1244A, 0x1244E, &[0x1F9A]),
+    (0x1244F, 0x12452, &[0x1F99]),
+    (0x12453, 0x12454, &[0x1F9C]),
+    (0x12455, 0x12455, &[0x1F9D]),
+    (0x12456, 0x12457, &[0x1F9A]),
+    (0x12458, 0x12459, &[0x1F99]),
+    (0x1245A, 0x12468, &[0x1F2E]),
+    (0x12469, 0x1246E, &[0x1F9C]),
+    (0x12470, 0x12474, &[0x0311]),
+    (0x12480, 0x12543, &[0x54E3]),
+    (0x13000, 0x1342E, &[0x55A7]),
+    (0x13430, 0x13438, &[]),
+    (0x14400, 0x14646, &[0x59F1]),
+    (0x16800, 0x16A38, &[0x3E16]),
+    (0x16A40, 0x16A5E, &[0x4CDD]),
+    (0x16A60, 0x16A69, &[0x1F98]),
+    (0x16A6E, 0x16A6F, &[0x02C9]),
+    (0x16AD0, 0x16AED, &[0x404F]),
+    (0x16AF0, 0x16AF4, &[]),
+    (0x16AF5, 0x16AF5, &[0x028F]),
+    (0x16B00, 0x16B2F, &[0x4A08]),
+    (0x16B30, 0x16B36, &[]),
+    (0x16B37, 0x16B3B, &[0x04A7]),
+    (0x16B3C, 0x16B3F, &[0x111E]),
+    (0x16B40, 0x16B41, &[0x4A38]),
+    (0x16B42, 0x16B43, &[0x1F53]),
+    (0x16B44, 0x16B44, &[0x04AC]),
+    (0x16B45, 0x16B45, &[0x1122]),
+    (0x16B50, 0x16B59, &[0x1F98]),
+    (0x16B5B, 0x16B61, &[0x1E81]),
+    (0x16B63, 0x16B77, &[0x4A3A]),
+    (0x16B7D, 0x16B8F, &[0x4A4F]),
+    (0x16E40, 0x16E5F, &[0x4132]),
+    (0x16E60, 0x16E7F, &[0x4132]),
+    (0x16E80, 0x16E89, &[0x1F98]),
+    (0x16E8A, 0x16E93, &[0x1E88]),
+    (0x16E94, 0x16E96, &[0x1F99]),
+    (0x16E97, 0x16E97, &[0x0235]),
+    (0x16E98, 0x16E98, &[0x0290]),
+    (0x16E99, 0x16E9A, &[0x04AD]),
+    (0x16F00, 0x16F03, &[0x491D]),
+    (0x16F04, 0x16F05, &[0x4922]),
+    (0x16F06, 0x16F06, &[0x4922]),
+    (0x16F07, 0x16F0D, &[0x4924]),
+    (0x16F0E, 0x16F0F, &[0x492C]),
+    (0x16F10, 0x16F12, &[0x492F]),
+    (0x16F13, 0x16F13, &[0x492F]),
+    (0x16F14, 0x16F24, &[0x4932]),
+    (0x16F25, 0x16F25, &[0x4941]),
+    (0x16F26, 0x16F32, &[0x4943]),
+    (0x16F33, 0x16F33, &[0x4951]),
+    (0x16F34, 0x16F39, &[0x4953]),
+    (0x16F3A, 0x16F3E, &[0x495A]),
+    (0x16F3F, 0x16F3F, &[0x495D]),
+    (0x16F40, 0x16F44, &[0x495F]),
+    (0x16F45, 0x16F45, &[0x4921]),
+    (0x16F46, 0x16F46, &[0x4952]),
+    (0x16F47, 0x16F47, &[0x4950]),
+    (0x16F48, 0x16F48, &[0x492B]),
+    (0x16F49, 0x16F49, &[0x4959]),
+    (0x16F4A, 0x16F4A, &[0x492E]),
+    (0x16F4F, 0x16F4F, &[0x4968]),
+    (0x16F50, 0x16F53, &[0x4964]),
+    (0x16F54, 0x16F58, &[0x4969]),
+    (0x16F59, 0x16F5C, &[0x496F]),
+    (0x16F5D, 0x16F60, &[0x4974]),
+    (0x16F61, 0x16F65, &[0x4979]),
+    (0x16F66, 0x16F6D, &[0x497F]),
+    (0x16F6E, 0x16F72, &[0x4989]),
+    (0x16F73, 0x16F74, &[0x498F]),
+    (0x16F75, 0x16F7E, &[0x4993]),
+    (0x16F7F, 0x16F7F, &[0x4987]),
+    (0x16F80, 0x16F80, &[0x498E]),
+    (0x16F81, 0x16F81, &[0x496E]),
+    (0x16F82, 0x16F82, &[0x4992]),
+    (0x16F83, 0x16F83, &[0x4973]),
+    (0x16F84, 0x16F84, &[0x4978]),
+    (0x16F85, 0x16F85, &[0x4991]),
+    (0x16F86, 0x16F86, &[0x497E]),
+    (0x16F87, 0x16F87, &[0x4988]),
+    (0x16F8F, 0x16F9F, &[0x499D]),
+    (0x16FE0, 0x16FE1, &[0x1F59]),
+    (0x16FE2, 0x16FE2, &[0x0239]),
+    (0x16FE3, 0x16FE3, &[0x1F5B]),
+    (0x16FE4, 0x16FE4, &[]),
+    (0x16FF0, 0x16FF1, &[]),
+    (0x1B000, 0x1B000, &[0x42DD]),
+    (0x1B001, 0x1B001, &[0x4318]),
+    (0x1B002, 0x1B00E, &[0x430B]),
+    (0x1B00F, 0x1B11E, &[0x4319]),
+    (0x1B150, 0x1B152, &[0x4307]),
+    (0x1B164, 0x1B167, &[0x4307]),
+    (0x1BC00, 0x1BC6A, &[0x4BBF]),
+    (0x1BC70, 0x1BC7C, &[0x4C2A]),
+    (0x1BC80, 0x1BC88, &[0x4C37]),
+    (0x1BC90, 0x1BC99, &[0x4C40]),
+    (0x1BC9C, 0x1BC9C, &[0x1314]),
+    (0x1BC9D, 0x1BC9E, &[]),
+    (0x1BC9F, 0x1BC9F, &[0x0291]),
+    (0x1BCA0, 0x1BCA3, &[]),
+    (0x1D000, 0x1D0F5, &[0x1124]),
+    (0x1D100, 0x1D126, &[0x121A]),
+    (0x1D129, 0x1D129, &[0x1254]),
+    (0x1D12A, 0x1D139, &[0x1244]),
+    (0x1D13A, 0x1D15D, &[0x1255]),
+    (0x1D15E, 0x1D15F, &[0x1272]),
+    (0x1D160, 0x1D160, &[0x1273]),
+    (0x1D161, 0x1D161, &[0x1273]),
+    (0x1D162, 0x1D162, &[0x1273]),
+    (0x1D163, 0x1D163, &[0x1273]),
+    (0x1D164, 0x1D164, &[0x1273]),
+    (0x1D165, 0x1D169, &[]),
+    (0x1D16A, 0x1D16C, &[0x1279]),
+    (0x1D16D, 0x1D182, &[]),
+    (0x1D183, 0x1D184, &[0x127C]),
+    (0x1D185, 0x1D18B, &[]),
+    (0x1D18C, 0x1D1A9, &[0x127E]),
+    (0x1D1AA, 0x1D1AD, &[]),
+    (0x1D1AE, 0x1D1BA, &[0x129C]),
+    (0x1D1BB, 0x1D1BC, &[0x12A7]),
+    (0x1D1BD, 0x1D1BE, &[0x12A7]),
+    (0x1D1BF, 0x1D1E8, &[0x12A7]),
+    (0x1D200, 0x1D241, &[0x12D1]),
+    (0x1D242, 0x1D244, &[]),
+    (0x1D245, 0x1D245, &[0x1313]),
+    (0x1D2E0, 0x1D2E9, &[0x1F98]),
+    (0x1D2EA, 0x1D2F3, &[0x1E92]),
+    (0x1D300, 0x1D356, &[0x1036]),
+    (0x1D360, 0x1D368, &[0x1F99]),
+    (0x1D369, 0x1D371, &[0x1F3D]),
+    (0x1D372, 0x1D376, &[0x1F99]),
+    (0x1D377, 0x1D377, &[0x1F99]),
+    (0x1D378, 0x1D378, &[0x1F9D]),
+    (0x1D400, 0x1D400, &[0x1FA2]),
+    (0x1D401, 0x1D401, &[0x1FBC]),
+    (0x1D402, 0x1D402, &[0x1FD6]),
+    (0x1D403, 0x1D403, &[0x1FEB]),
+    (0x1D404, 0x1D404, &[0x2007]),
+    (0x1D405, 0x1D405, &[0x2042]),
+    (0x1D406, 0x1D406, &[0x2051]),
+    (0x1D407, 0x1D407, &[0x2075]),
+    (0x1D408, 0x1D408, &[0x2090]),
+    (0x1D409, 0x1D409, &[0x20AB]),
+    (0x1D40A, 0x1D40A, &[0x20C4]),
+    (0x1D40B, 0x1D40B, &[0x20D6]),
+    (0x1D40C, 0x1D40C, &[0x2109]),
+    (0x1D40D, 0x1D40D, &[0x2118]),
+    (0x1D40E, 0x1D40E, &[0x213C]),
+    (0x1D40F, 0x1D40F, &[0x216B]),
+    (0x1D410, 0x1D410, &[0x2180]),
+    (0x1D411, 0x1D411, &[0x2193]),
+    (0x1D412, 0x1D412, &[0x21D2]),
+    (0x1D413, 0x1D413, &[0x21F7]),
+    (0x1D414, 0x1D414, &[0x2217]),
+    (0x1D415, 0x1D415, &[0x2247]),
+    (0x1D416, 0x1D416, &[0x2259]),
+    (0x1D417, 0x1D417, &[0x2264]),
+    (0x1D418, 0x1D418, &[0x2270]),
+    (0x1D419, 0x1D419, &[0x2286]),
+    (0x1D41A, 0x1D41A, &[0x1FA2]),
+    (0x1D41B, 0x1D41B, &[0x1FBC]),
+    (0x1D41C, 0x1D41C, &[0x1FD6]),
+    (0x1D41D, 0x1D41D, &[0x1FEB]),
+    (0x1D41E, 0x1D41E, &[0x2007]),
+    (0x1D41F, 0x1D41F, &[0x2042]),
+    (0x1D420, 0x1D420, &[0x2051]),
+    (0x1D421, 0x1D421, &[0x2075]),
+    (0x1D422, 0x1D422, &[0x2090]),
+    (0x1D423, 0x1D423, &[0x20AB]),
+    (0x1D424, 0x1D424, &[0x20C4]),
+    (0x1D425, 0x1D425, &[0x20D6]),
+    (0x1D426, 0x1D426, &[0x2109]),
+    (0x1D427, 0x1D427, &[0x2118]),
+    (0x1D428, 0x1D428, &[0x213C]),
+    (0x1D429, 0x1D429, &[0x216B]),
+    (0x1D42A, 0x1D42A, &[0x2180]),
+    (0x1D42B, 0x1D42B, &[0x2193]),
+    (0x1D42C, 0x1D42C, &[0x21D2]),
+    (0x1D42D, 0x1D42D, &[0x21F7]),
+    (0x1D42E, 0x1D42E, &[0x2217]),
+    (0x1D42F, 0x1D42F, &[0x2247]),
+    (0x1D430, 0x1D430, &[0x2259]),
+    (0x1D431, 0x1D431, &[0x2264]),
+    (0x1D432, 0x1D432, &[0x2270]),
+    (0x1D433, 0x1D433, &[0x2286]),
+    (0x1D434, 0x1D434, &[0x1FA2]),
+    (0x1D435, 0x1D435, &[0x1FBC]),
+    (0x1D436, 0x1D436, &[0x1FD6]),
+    (0x1D437, 0x1D437, &[0x1FEB]),
+    (0x1D438, 0x1D438, &[0x2007]),
+    (0x1D439, 0x1D439, &[0x2042]),
+    (0x1D43A, 0x1D43A, &[0x2051]),
+    (0x1D43B, 0x1D43B, &[0x2075]),
+    (0x1D43C, 0x1D43C, &[0x2090]),
+    (0x1D43D, 0x1D43D, &[0x20AB]),
+    (0x1D43E, 0x1D43E, &[0x20C4]),
+    (0x1D43F, 0x1D43F, &[0x20D6]),
+    (0x1D440, 0x1D440, &[0x2109]),
+    (0x1D441, 0x1D441, &[0x2118]),
+    (0x1D442, 0x1D442, &[0x213C]),
+    (0x1D443, 0x1D443, &[0x216B]),
+    (0x1D444, 0x1D444, &[0x2180]),
+    (0x1D445, 0x1D445, &[0x2193]),
+    (0x1D446, 0x1D446, &[0x21D2]),
+    (0x1D447, 0x1D447, &[0x21F7]),
+    (0x1D448, 0x1D448, &[0x2217]),
+    (0x1D449, 0x1D449, &[0x2247]),
+    (0x1D44A, 0x1D44A, &[0x2259]),
+    (0x1D44B, 0x1D44B, &[0x2264]),
+    (0x1D44C, 0x1D44C, &[0x2270]),
+    (0x1D44D, 0x1D44D, &[0x2286]),
+    (0x1D44E, 0x1D44E, &[0x1FA2]),
+    (0x1D44F, 0x1D44F, &[0x1FBC]),
+    (0x1D450, 0x1D450, &[0x1FD6]),
+    (0x1D451, 0x1D451, &[0x1FEB]),
+    (0x1D452, 0x1D452, &[0x2007]),
+    (0x1D453, 0x1D453, &[0x2042]),
+    (0x1D454, 0x1D454, &[0x2051]),
+    (0x1D456, 0x1D456, &[0x2090]),
+    (0x1D457, 0x1D457, &[0x20AB]),
+    (0x1D458, 0x1D458, &[0x20C4]),
+    (0x1D459, 0x1D459, &[0x20D6]),
+    (0x1D45A, 0x1D45A, &[0x2109]),
+    (0x1D45B, 0x1D45B, &[0x2118]),
+    (0x1D45C, 0x1D45C, &[0x213C]),
+    (0x1D45D, 0x1D45D, &[0x216B]),
+    (0x1D45E, 0x1D45E, &[0x2180]),
+    (0x1D45F, 0x1D45F, &[0x2193]),
+    (0x1D460, 0x1D460, &[0x21D2]),
+    (0x1D461, 0x1D461, &[0x21F7]),
+    (0x1D462, 0x1D462, &[0x2217]),
+    (0x1D463, 0x1D463, &[0x2247]),
+    (0x1D464, 0x1D464, &[0x2259]),
+    (0x1D465, 0x1D465, &[0x2264]),
+    (0x1D466, 0x1D466, &[0x2270]),
+    (0x1D467, 0x1D467, &[0x2286]),
+    (0x1D468, 0x1D468, &[0x1FA2]),
+    (0x1D469, 0x1D469, &[0x1FBC]),
+    (0x1D46A, 0x1D46A, &[0x1FD6]),
+    (0x1D46B, 0x1D46B, &[0x1FEB]),
+    (0x1D46C, 0x1D46C, &[0x2007]),
+    (0x1D46D, 0x1D46D, &[0x2042]),
+    (0x1D46E, 0x1D46E, &[0x2051]),
+    (0x1D46F, 0x1D46F, &[0x2075]),
+    (0x1D470, 0x1D470, &[0x2090]),
+    (0x1D471, 0x1D471, &[0x20AB]),
+    (0x1D472, 0x1D472, &[0x20C4]),
+    (0x1D473, 0x1D473, &[0x20D6]),
+    (0x1D474, 0x1D474, &[0x2109]),
+    (0x1D475, 0x1D475, &[0x2118]),
+    (0x1D476, 0x1D476, &[0x213C]),
+    (0x1D477, 0x1D477, &[0x216B]),
+    (0x1D478, 0x1D478, &[0x2180]),
+    (0x1D479, 0x1D479, &[0x2193]),
+    (0x1D47A, 0x1D47A, &[0x21D2]),
+    (0x1D47B, 0x1D47B, &[0x21F7]),
+    (0x1D47C, 0x1D47C, &[0x2217]),
+    (0x1D47D, 0x1D47D, &[0x2247]),
+    (0x1D47E, 0x1D47E, &[0x2259]),
+    (0x1D47F, 0x1D47F, &[0x2264]),
+    (0x1D480, 0x1D480, &[0x2270]),
+    (0x1D481, 0x1D481, &[0x2286]),
+    (0x1D482, 0x1D482, &[0x1FA2]),
+    (0x1D483, 0x1D483, &[0x1FBC]),
+    (0x1D484, 0x1D484, &[0x1FD6]),
+    (0x1D485, 0x1D485, &[0x1FEB]),
+    (0x1D486, 0x1D486, &[0x2007]),
+    (0x1D487, 0x1D487, &[0x2042]),
+    (0x1D488, 0x1D488, &[0x2051]),
+    (0x1D489, 0x1D489, &[0x2075]),
+    (0x1D48A, 0x1D48A, &[0x2090]),
+    (0x1D48B, 0x1D48B, &[0x20AB]),
+    (0x1D48C, 0x1D48C, &[0x20C4]),
+    (0x1D48D, 0x1D48D, &[0x20D6]),
+    (0x1D48E, 0x1D48E, &[0x2109]),
+    (0x1D48F, 0x1D48F, &[0x2118]),
+    (0x1D490, 0x1D490, &[0x213C]),
+    (0x1D491, 0x1D491, &[0x216B]),
+    (0x1D492, 0x1D492, &[0x2180]),
+    (0x1D493, 0x1D493, &[0x2193]),
+    (0x1D494, 0x1D494, &[0x21D2]),
+    (0x1D495, 0x1D495, &[0x21F7]),
+    (0x1D496, 0x1D496, &[0x2217]),
+    (0x1D497, 0x1D497, &[0x2247]),
+    (0x1D498, 0x1D498, &[0x2259]),
+    (0x1D499, 0x1D499, &[0x2264]),
+    (0x1D49A, 0x1D49A, &[0x2270]),
+    (0x1D49B, 0x1D49B, &[0x2286]),
+    (0x1D49C, 0x1D49C, &[0x1FA2]),
+    (0x1D49E, 0x1D49E, &[0x1FD6]),
+    (0x1D49F, 0x1D49F, &[0x1FEB]),
+    (0x1D4A2, 0x1D4A2, &[0x2051]),
+    (0x1D4A5, 0x1D4A5, &[0x20AB]),
+    (0x1D4A6, 0x1D4A6, &[0x20C4]),
+    (0x1D4A9, 0x1D4A9, &[0x2118]),
+    (0x1D4AA, 0x1D4AA, &[0x213C]),
+    (0x1D4AB, 0x1D4AB, &[0x216B]),
+    (0x1D4AC, 0x1D4AC, &[0x2180]),
+    (0x1D4AE, 0x1D4AE, &[0x21D2]),
+    (0x1D4AF, 0x1D4AF, &[0x21F7]),
+    (0x1D4B0, 0x1D4B0, &[0x2217]),
+    (0x1D4B1, 0x1D4B1, &[0x2247]),
+    (0x1D4B2, 0x1D4B2, &[0x2259]),
+    (0x1D4B3, 0x1D4B3, &[0x2264]),
+    (0x1D4B4, 0x1D4B4, &[0x2270]),
+    (0x1D4B5, 0x1D4B5, &[0x2286]),
+    (0x1D4B6, 0x1D4B6, &[0x1FA2]),
+    (0x1D4B7, 0x1D4B7, &[0x1FBC]),
+    (0x1D4B8, 0x1D4B8, &[0x1FD6]),
+    (0x1D4B9, 0x1D4B9, &[0x1FEB]),
+    (0x1D4BB, 0x1D4BB, &[0x2042]),
+    (0x1D4BD, 0x1D4BD, &[0x2075]),
+    (0x1D4BE, 0x1D4BE, &[0x2090]),
+    (0x1D4BF, 0x1D4BF, &[0x20AB]),
+    (0x1D4C0, 0x1D4C0, &[0x20C4]),
+    (0x1D4C1, 0x1D4C1, &[0x20D6]),
+    (0x1D4C2, 0x1D4C2, &[0x2109]),
+    (0x1D4C3, 0x1D4C3, &[0x2118]),
+    (0x1D4C5, 0x1D4C5, &[0x216B]),
+    (0x1D4C6, 0x1D4C6, &[0x2180]),
+    (0x1D4C7, 0x1D4C7, &[0x2193]),
+    (0x1D4C8, 0x1D4C8, &[0x21D2]),
+    (0x1D4C9, 0x1D4C9, &[0x21F7]),
+    (0x1D4CA, 0x1D4CA, &[0x2217]),
+    (0x1D4CB, 0x1D4CB, &[0x2247]),
+    (0x1D4CC, 0x1D4CC, &[0x2259]),
+    (0x1D4CD, 0x1D4CD, &[0x2264]),
+    (0x1D4CE, 0x1D4CE, &[0x2270]),
+    (0x1D4CF, 0x1D4CF, &[0x2286]),
+    (0x1D4D0, 0x1D4D0, &[0x1FA2]),
+    (0x1D4D1, 0x1D4D1, &[0x1FBC]),
+    (0x1D4D2, 0x1D4D2, &[0x1FD6]),
+    (0x1D4D3, 0x1D4D3, &[0x1FEB]),
+    (0x1D4D4, 0x1D4D4, &[0x2007]),
+    (0x1D4D5, 0x1D4D5, &[0x2042]),
+    (0x1D4D6, 0x1D4D6, &[0x2051]),
+    (0x1D4D7, 0x1D4D7, &[0x2075]),
+    (0x1D4D8, 0x1D4D8, &[0x2090]),
+    (0x1D4D9, 0x1D4D9, &[0x20AB]),
+    (0x1D4DA, 0x1D4DA, &[0x20C4]),
+    (0x1D4DB, 0x1D4DB, &[0x20D6]),
+    (0x1D4DC, 0x1D4DC, &[0x2109]),
+    (0x1D4DD, 0x1D4DD, &[0x2118]),
+    (0x1D4DE, 0x1D4DE, &[0x213C]),
+    (0x1D4DF, 0x1D4DF, &[0x216B]),
+    (0x1D4E0, 0x1D4E0, &[0x2180]),
+    (0x1D4E1, 0x1D4E1, &[0x2193]),
+    (0x1D4E2, 0x1D4E2, &[0x21D2]),
+    (0x1D4E3, 0x1D4E3, &[0x21F7]),
+    (0x1D4E4, 0x1D4E4, &[0x2217]),
+    (0x1D4E5, 0x1D4E5, &[0x2247]),
+    (0x1D4E6, 0x1D4E6, &[0x2259]),
+    (0x1D4E7, 0x1D4E7, &[0x2264]),
+    (0x1D4E8, 0x1D4E8, &[0x2270]),
+    (0x1D4E9, 0x1D4E9, &[0x2286]),
+    (0x1D4EA, 0x1D4EA, &[0x1FA2]),
+    (0x1D4EB, 0x1D4EB, &[0x1FBC]),
+    (0x1D4EC, 0x1D4EC, &[0x1FD6]),
+    (0x1D4ED, 0x1D4ED, &[0x1FEB]),
+    (0x1D4EE, 0x1D4EE, &[0x2007]),
+    (0x1D4EF, 0x1D4EF, &[0x2042]),
+    (0x1D4F0, 0x1D4F0, &[0x2051]),
+    (0x1D4F1, 0x1D4F1, &[0x2075]),
+    (0x1D4F2, 0x1D4F2, &[0x2090]),
+    (0x1D4F3, 0x1D4F3, &[0x20AB]),
+    (0x1D4F4, 0x1D4F4, &[0x20C4]),
+    (0x1D4F5, 0x1D4F5, &[0x20D6]),
+    (0x1D4F6, 0x1D4F6, &[0x2109]),
+    (0x1D4F7, 0x1D4F7, &[0x2118]),
+    (0x1D4F8, 0x1D4F8, &[0x213C]),
+    (0x1D4F9, 0x1D4F9, &[0x216B]),
+    (0x1D4FA, 0x1D4FA, &[0x2180]),
+    (0x1D4FB, 0x1D4FB, &[0x2193]),
+    (0x1D4FC, 0x1D4FC, &[0x21D2]),
+    (0x1D4FD, 0x1D4FD, &[0x21F7]),
+    (0x1D4FE, 0x1D4FE, &[0x2217]),
+    (0x1D4FF, 0x1D4FF, &[0x2247]),
+    (0x1D500, 0x1D500, &[0x2259]),
+    (0x1D501, 0x1D501, &[0x2264]),
+    (0x1D502, 0x1D502, &[0x2270]),
+    (0x1D503, 0x1D503, &[0x2286]),
+    (0x1D504, 0x1D504, &[0x1FA2]),
+    (0x1D505, 0x1D505, &[0x1FBC]),
+    (0x1D507, 0x1D507, &[0x1FEB]),
+    (0x1D508, 0x1D508, &[0x2007]),
+    (0x1D509, 0x1D509, &[0x2042]),
+    (0x1D50A, 0x1D50A, &[0x2051]),
+    (0x1D50D, 0x1D50D, &[0x20AB]),
+    (0x1D50E, 0x1D50E, &[0x20C4]),
+    (0x1D50F, 0x1D50F, &[0x20D6]),
+    (0x1D510, 0x1D510, &[0x2109]),
+    (0x1D511, 0x1D511, &[0x2118]),
+    (0x1D512, 0x1D512, &[0x213C]),
+    (0x1D513, 0x1D513, &[0x216B]),
+    (0x1D514, 0x1D514, &[0x2180]),
+    (0x1D516, 0x1D516, &[0x21D2]),
+    (0x1D517, 0x1D517, &[0x21F7]),
+    (0x1D518, 0x1D518, &[0x2217]),
+    (0x1D519, 0x1D519, &[0x2247]),
+    (0x1D51A, 0x1D51A, &[0x2259]),
+    (0x1D51B, 0x1D51B, &[0x2264]),
+    (0x1D51C, 0x1D51C, &[0x2270]),
+    (0x1D51E, 0x1D51E, &[0x1FA2]),
+    (0x1D51F, 0x1D51F, &[0x1FBC]),
+    (0x1D520, 0x1D520, &[0x1FD6]),
+    (0x1D521, 0x1D521, &[0x1FEB]),
+    (0x1D522, 0x1D522, &[0x2007]),
+    (0x1D523, 0x1D523, &[0x2042]),
+    (0x1D524, 0x1D524, &[0x2051]),
+    (0x1D525, 0x1D525, &[0x2075]),
+    (0x1D526, 0x1D526, &[0x2090]),
+    (0x1D527, 0x1D527, &[0x20AB]),
+    (0x1D528, 0x1D528, &[0x20C4]),
+    (0x1D529, 0x1D529, &[0x20D6]),
+    (0x1D52A, 0x1D52A, &[0x2109]),
+    (0x1D52B, 0x1D52B, &[0x2118]),
+    (0x1D52C, 0x1D52C, &[0x213C]),
+    (0x1D52D, 0x1D52D, &[0x216B]),
+    (0x1D52E, 0x1D52E, &[0x2180]),
+    (0x1D52F, 0x1D52F, &[0x2193]),
+    (0x1D530, 0x1D530, &[0x21D2]),
+    (0x1D531, 0x1D531, &[0x21F7]),
+    (0x1D532, 0x1D532, &[0x2217]),
+    (0x1D533, 0x1D533, &[0x2247]),
+    (0x1D534, 0x1D534, &[0x2259]),
+    (0x1D535, 0x1D535, &[0x2264]),
+    (0x1D536, 0x1D536, &[0x2270]),
+    (0x1D537, 0x1D537, &[0x2286]),
+    (0x1D538, 0x1D538, &[0x1FA2]),
+    (0x1D539, 0x1D539, &[0x1FBC]),
+    (0x1D53B, 0x1D53B, &[0x1FEB]),
+    (0x1D53C, 0x1D53C, &[0x2007]),
+    (0x1D53D, 0x1D53D, &[0x2042]),
+    (0x1D53E, 0x1D53E, &[0x2051]),
+    (0x1D540, 0x1D540, &[0x2090]),
+    (0x1D541, 0x1D541, &[0x20AB]),
+    (0x1D542, 0x1D542, &[0x20C4]),
+    (0x1D543, 0x1D543, &[0x20D6]),
+    (0x1D544, 0x1D544, &[0x2109]),
+    (0x1D546, 0x1D546, &[0x213C]),
+    (0x1D54A, 0x1D54A, &[0x21D2]),
+    (0x1D54B, 0x1D54B, &[0x21F7]),
+    (0x1D54C, 0x1D54C, &[0x2217]),
+    (0x1D54D, 0x1D54D, &[0x2247]),
+    (0x1D54E, 0x1D54E, &[0x2259]),
+    (0x1D54F, 0x1D54F, &[0x2264]),
+    (0x1D550, 0x1D550, &[0x2270]),
+    (0x1D552, 0x1D552, &[0x1FA2]),
+    (0x1D553, 0x1D553, &[0x1FBC]),
+    (0x1D554, 0x1D554, &[0x1FD6]),
+    (0x1D555, 0x1D555, &[0x1FEB]),
+    (0x1D556, 0x1D556, &[0x2007]),
+    (0x1D557, 0x1D557, &[0x2042]),
+    (0x1D558, 0x1D558, &[0x2051]),
+    (0x1D559, 0x1D559, &[0x2075]),
+    (0x1D55A, 0x1D55A, &[0x2090]),
+    (0x1D55B, 0x1D55B, &[0x20AB]),
+    (0x1D55C, 0x1D55C, &[0x20C4]),
+    (0x1D55D, 0x1D55D, &[0x20D6]),
+    (0x1D55E, 0x1D55E, &[0x2109]),
+    (0x1D55F, 0x1D55F, &[0x2118]),
+    (0x1D560, 0x1D560, &[0x213C]),
+    (0x1D561, 0x1D561, &[0x216B]),
+    (0x1D562, 0x1D562, &[0x2180]),
+    (0x1D563, 0x1D563, &[0x2193]),
+    (0x1D564, 0x1D564, &[0x21D2]),
+    (0x1D565, 0x1D565, &[0x21F7]),
+    (0x1D566, 0x1D566, &[0x2217]),
+    (0x1D567, 0x1D567, &[0x2247]),
+    (0x1D568, 0x1D568, &[0x2259]),
+    (0x1D569, 0x1D569, &[0x2264]),
+    (0x1D56A, 0x1D56A, &[0x2270]),
+    (0x1D56B, 0x1D56B, &[0x2286]),
+    (0x1D56C, 0x1D56C, &[0x1FA2]),
+    (0x1D56D, 0x1D56D, &[0x1FBC]),
+    (0x1D56E, 0x1D56E, &[0x1FD6]),
+    (0x1D56F, 0x1D56F, &[0x1FEB]),
+    (0x1D570, 0x1D570, &[0x2007]),
+    (0x1D571, 0x1D571, &[0x2042]),
+    (0x1D572, 0x1D572, &[0x2051]),
+    (0x1D573, 0x1D573, &[0x2075]),
+    (0x1D574, 0x1D574, &[0x2090]),
+    (0x1D575, 0x1D575, &[0x20AB]),
+    (0x1D576, 0x1D576, &[0x20C4]),
+    (0x1D577, 0x1D577, &[0x20D6]),
+    (0x1D578, 0x1D578, &[0x2109]),
+    (0x1D579, 0x1D579, &[0x2118]),
+    (0x1D57A, 0x1D57A, &[0x213C]),
+    (0x1D57B, 0x1D57B, &[0x216B]),
+    (0x1D57C, 0x1D57C, &[0x2180]),
+    (0x1D57D, 0x1D57D, &[0x2193]),
+    (0x1D57E, 0x1D57E, &[0x21D2]),
+    (0x1D57F, 0x1D57F, &[0x21F7]),
+    (0x1D580, 0x1D580, &[0x2217]),
+    (0x1D581, 0x1D581, &[0x2247]),
+    (0x1D582, 0x1D582, &[0x2259]),
+    (0x1D583, 0x1D583, &[0x2264]),
+    (0x1D584, 0x1D584, &[0x2270]),
+    (0x1D585, 0x1D585, &[0x2286]),
+    (0x1D586, 0x1D586, &[0x1FA2]),
+    (0x1D587, 0x1D587, &[0x1FBC]),
+    (0x1D588, 0x1D588, &[0x1FD6]),
+    (0x1D589, 0x1D589, &[0x1FEB]),
+    (0x1D58A, 0x1D58A, &[0x2007]),
+    (0x1D58B, 0x1D58B, &[0x2042]),
+    (0x1D58C, 0x1D58C, &[0x2051]),
+    (0x1D58D, 0x1D58D, &[0x2075]),
+    (0x1D58E, 0x1D58E, &[0x2090]),
+    (0x1D58F, 0x1D58F, &[0x20AB]),
+    (0x1D590, 0x1D590, &[0x20C4]),
+    (0x1D591, 0x1D591, &[0x20D6]),
+    (0x1D592, 0x1D592, &[0x2109]),
+    (0x1D593, 0x1D593, &[0x2118]),
+    (0x1D594, 0x1D594, &[0x213C]),
+    (0x1D595, 0x1D595, &[0x216B]),
+    (0x1D596, 0x1D596, &[0x2180]),
+    (0x1D597, 0x1D597, &[0x2193]),
+    (0x1D598, 0x1D598, &[0x21D2]),
+    (0x1D599, 0x1D599, &[0x21F7]),
+    (0x1D59A, 0x1D59A, &[0x2217]),
+    (0x1D59B, 0x1D59B, &[0x2247]),
+    (0x1D59C, 0x1D59C, &[0x2259]),
+    (0x1D59D, 0x1D59D, &[0x2264]),
+    (0x1D59E, 0x1D59E, &[0x2270]),
+    (0x1D59F, 0x1D59F, &[0x2286]),
+    (0x1D5A0, 0x1D5A0, &[0x1FA2]),
+    (0x1D5A1, 0x1D5A1, &[0x1FBC]),
+    (0x1D5A2, 0x1D5A2, &[0x1FD6]),
+    (0x1D5A3, 0x1D5A3, &[0x1FEB]),
+    (0x1D5A4, 0x1D5A4, &[0x2007]),
+    (0x1D5A5, 0x1D5A5, &[0x2042]),
+    (0x1D5A6, 0x1D5A6, &[0x2051]),
+    (0x1D5A7, 0x1D5A7, &[0x2075]),
+    (0x1D5A8, 0x1D5A8, &[0x2090]),
+    (0x1D5A9, 0x1D5A9, &[0x20AB]),
+    (0x1D5AA, 0x1D5AA, &[0x20C4]),
+    (0x1D5AB, 0x1D5AB, &[0x20D6]),
+    (0x1D5AC, 0x1D5AC, &[0x2109]),
+    (0x1D5AD, 0x1D5AD, &[0x2118]),
+    (0x1D5AE, 0x1D5AE, &[0x213C]),
+    (0x1D5AF, 0x1D5AF, &[0x216B]),
+    (0x1D5B0, 0x1D5B0, &[0x2180]),
+    (0x1D5B1, 0x1D5B1, &[0x2193]),
+    (0x1D5B2, 0x1D5B2, &[0x21D2]),
+    (0x1D5B3, 0x1D5B3, &[0x21F7]),
+    (0x1D5B4, 0x1D5B4, &[0x2217]),
+    (0x1D5B5, 0x1D5B5, &[0x2247]),
+    (0x1D5B6, 0x1D5B6, &[0x2259]),
+    (0x1D5B7, 0x1D5B7, &[0x2264]),
+    (0x1D5B8, 0x1D5B8, &[0x2270]),
+    (0x1D5B9, 0x1D5B9, &[0x2286]),
+    (0x1D5BA, 0x1D5BA, &[0x1FA2]),
+    (0x1D5BB, 0x1D5BB, &[0x1FBC]),
+    (0x1D5BC, 0x1D5BC, &[0x1FD6]),
+    (0x1D5BD, 0x1D5BD, &[0x1FEB]),
+    (0x1D5BE, 0x1D5BE, &[0x2007]),
+    (0x1D5BF, 0x1D5BF, &[0x2042]),
+    (0x1D5C0, 0x1D5C0, &[0x2051]),
+    (0x1D5C1, 0x1D5C1, &[0x2075]),
+    (0x1D5C2, 0x1D5C2, &[0x2090]),
+    (0x1D5C3, 0x1D5C3, &[0x20AB]),
+    (0x1D5C4, 0x1D5C4, &[0x20C4]),
+    (0x1D5C5, 0x1D5C5, &[0x20D6]),
+    (0x1D5C6, 0x1D5C6, &[0x2109]),
+    (0x1D5C7, 0x1D5C7, &[0x2118]),
+    (0x1D5C8, 0x1D5C8, &[0x213C]),
+    (0x1D5C9, 0x1D5C9, &[0x216B]),
+    (0x1D5CA, 0x1D5CA, &[0x2180]),
+    (0x1D5CB, 0x1D5CB, &[0x2193]),
+    (0x1D5CC, 0x1D5CC, &[0x21D2]),
+    (0x1D5CD, 0x1D5CD, &[0x21F7]),
+    (0x1D5CE, 0x1D5CE, &[0x2217]),
+    (0x1D5CF, 0x1D5CF, &[0x2247]),
+    (0x1D5D0, 0x1D5D0, &[0x2259]),
+    (0x1D5D1, 0x1D5D1, &[0x2264]),
+    (0x1D5D2, 0x1D5D2, &[0x2270]),
+    (0x1D5D3, 0x1D5D3, &[0x2286]),
+    (0x1D5D4, 0x1D5D4, &[0x1FA2]),
+    (0x1D5D5, 0x1D5D5, &[0x1FBC]),
+    (0x1D5D6, 0x1D5D6, &[0x1FD6]),
+    (0x1D5D7, 0x1D5D7, &[0x1FEB]),
+    (0x1D5D8, 0x1D5D8, &[0x2007]),
+    (0x1D5D9, 0x1D5D9, &[0x2042]),
+    (0x1D5DA, 0x1D5DA, &[0x2051]),
+    (0x1D5DB, 0x1D5DB, &[0x2075]),
+    (0x1D5DC, 0x1D5DC, &[0x2090]),
+    (0x1D5DD, 0x1D5DD, &[0x20AB]),
+    (0x1D5DE, 0x1D5DE, &[0x20C4]),
+    (0x1D5DF, 0x1D5DF, &[0x20D6]),
+    (0x1D5E0, 0x1D5E0, &[0x2109]),
+    (0x1D5E1, 0x1D5E1, &[0x2118]),
+    (0x1D5E2, 0x1D5E2, &[0x213C]),
+    (0x1D5E3, 0x1D5E3, &[0x216B]),
+    (0x1D5E4, 0x1D5E4, &[0x2180]),
+    (0x1D5E5, 0x1D5E5, &[0x2193]),
+    (0x1D5E6, 0x1D5E6, &[0x21D2]),
+    (0x1D5E7, 0x1D5E7, &[0x21F7]),
+    (0x1D5E8, 0x1D5E8, &[0x2217]),
+    (0x1D5E9, 0x1D5E9, &[0x2247]),
+    (0x1D5EA, 0x1D5EA, &[0x2259]),
+    (0x1D5EB, 0x1D5EB, &[0x2264]),
+    (0x1D5EC, 0x1D5EC, &[0x2270]),
+    (0x1D5ED, 0x1D5ED, &[0x2286]),
+    (0x1D5EE, 0x1D5EE, &[0x1FA2]),
+    (0x1D5EF, 0x1D5EF, &[0x1FBC]),
+    (0x1D5F0, 0x1D5F0, &[0x1FD6]),
+    (0x1D5F1, 0x1D5F1, &[0x1FEB]),
+    (0x1D5F2, 0x1D5F2, &[0x2007]),
+    (0x1D5F3, 0x1D5F3, &[0x2042]),
+    (0x1D5F4, 0x1D5F4, &[0x2051]),
+    (0x1D5F5, 0x1D5F5, &[0x2075]),
+    (0x1D5F6, 0x1D5F6, &[0x2090]),
+    (0x1D5F7, 0x1D5F7, &[0x20AB]),
+    (0x1D5F8, 0x1D5F8, &[0x20C4]),
+    (0x1D5F9, 0x1D5F9, &[0x20D6]),
+    (0x1D5FA, 0x1D5FA, &[0x2109]),
+    (0x1D5FB, 0x1D5FB, &[0x2118]),
+    (0x1D5FC, 0x1D5FC, &[0x213C]),
+    (0x1D5FD, 0x1D5FD, &[0x216B]),
+    (0x1D5FE, 0x1D5FE, &[0x2180]),
+    (0x1D5FF, 0x1D5FF, &[0x2193]),
+    (0x1D600, 0x1D600, &[0x21D2]),
+    (0x1D601, 0x1D601, &[0x21F7]),
+    (0x1D602, 0x1D602, &[0x2217]),
+    (0x1D603, 0x1D603, &[0x2247]),
+    (0x1D604, 0x1D604, &[0x2259]),
+    (0x1D605, 0x1D605, &[0x2264]),
+    (0x1D606, 0x1D606, &[0x2270]),
+    (0x1D607, 0x1D607, &[0x2286]),
+    (0x1D608, 0x1D608, &[0x1FA2]),
+    (0x1D609, 0x1D609, &[0x1FBC]),
+    (0x1D60A, 0x1D60A, &[0x1FD6]),
+    (0x1D60B, 0x1D60B, &[0x1FEB]),
+    (0x1D60C, 0x1D60C, &[0x2007]),
+    (0x1D60D, 0x1D60D, &[0x2042]),
+    (0x1D60E, 0x1D60E, &[0x2051]),
+    (0x1D60F, 0x1D60F, &[0x2075]),
+    (0x1D610, 0x1D610, &[0x2090]),
+    (0x1D611, 0x1D611, &[0x20AB]),
+    (0x1D612, 0x1D612, &[0x20C4]),
+    (0x1D613, 0x1D613, &[0x20D6]),
+    (0x1D614, 0x1D614, &[0x2109]),
+    (0x1D615, 0x1D615, &[0x2118]),
+    (0x1D616, 0x1D616, &[0x213C]),
+    (0x1D617, 0x1D617, &[0x216B]),
+    (0x1D618, 0x1D618, &[0x2180]),
+    (0x1D619, 0x1D619, &[0x2193]),
+    (0x1D61A, 0x1D61A, &[0x21D2]),
+    (0x1D61B, 0x1D61B, &[0x21F7]),
+    (0x1D61C, 0x1D61C, &[0x2217]),
+    (0x1D61D, 0x1D61D, &[0x2247]),
+    (0x1D61E, 0x1D61E, &[0x2259]),
+    (0x1D61F, 0x1D61F, &[0x2264]),
+    (0x1D620, 0x1D620, &[0x2270]),
+    (0x1D621, 0x1D621, &[0x2286]),
+    (0x1D622, 0x1D622, &[0x1FA2]),
+    (0x1D623, 0x1D623, &[0x1FBC]),
+    (0x1D624, 0x1D624, &[0x1FD6]),
+    (0x1D625, 0x1D625, &[0x1FEB]),
+    (0x1D626, 0x1D626, &[0x2007]),
+    (0x1D627, 0x1D627, &[0x2042]),
+    (0x1D628, 0x1D628, &[0x2051]),
+    (0x1D629, 0x1D629, &[0x2075]),
+    (0x1D62A, 0x1D62A, &[0x2090]),
+    (0x1D62B, 0x1D62B, &[0x20AB]),
+    (0x1D62C, 0x1D62C, &[0x20C4]),
+    (0x1D62D, 0x1D62D, &[0x20D6]),
+    (0x1D62E, 0x1D62E, &[0x2109]),
+    (0x1D62F, 0x1D62F, &[0x2118]),
+    (0x1D630, 0x1D630, &[0x213C]),
+    (0x1D631, 0x1D631, &[0x216B]),
+    (0x1D632, 0x1D632, &[0x2180]),
+    (0x1D633, 0x1D633, &[0x2193]),
+    (0x1D634, 0x1D634, &[0x21D2]),
+    (0x1D635, 0x1D635, &[0x21F7]),
+    (0x1D636, 0x1D636, &[0x2217]),
+    (0x1D637, 0x1D637, &[0x2247]),
+    (0x1D638, 0x1D638, &[0x2259]),
+    (0x1D639, 0x1D639, &[0x2264]),
+    (0x1D63A, 0x1D63A, &[0x2270]),
+    (0x1D63B, 0x1D63B, &[0x2286]),
+    (0x1D63C, 0x1D63C, &[0x1FA2]),
+    (0x1D63D, 0x1D63D, &[0x1FBC]),
+    (0x1D63E, 0x1D63E, &[0x1FD6]),
+    (0x1D63F, 0x1D63F, &[0x1FEB]),
+    (0x1D640, 0x1D640, &[0x2007]),
+    (0x1D641, 0x1D641, &[0x2042]),
+    (0x1D642, 0x1D642, &[0x2051]),
+    (0x1D643, 0x1D643, &[0x2075]),
+    (0x1D644, 0x1D644, &[0x2090]),
+    (0x1D645, 0x1D645, &[0x20AB]),
+    (0x1D646, 0x1D646, &[0x20C4]),
+    (0x1D647, 0x1D647, &[0x20D6]),
+    (0x1D648, 0x1D648, &[0x2109]),
+    (0x1D649, 0x1D649, &[0x2118]),
+    (0x1D64A, 0x1D64A, &[0x213C]),
+    (0x1D64B, 0x1D64B, &[0x216B]),
+    (0x1D64C, 0x1D64C, &[0x2180]),
+    (0x1D64D, 0x1D64D, &[0x2193]),
+    (0x1D64E, 0x1D64E, &[0x21D2]),
+    (0x1D64F, 0x1D64F, &[0x21F7]),
+    (0x1D650, 0x1D650, &[0x2217]),
+    (0x1D651, 0x1D651, &[0x2247]),
+    (0x1D652, 0x1D652, &[0x2259]),
+    (0x1D653, 0x1D653, &[0x2264]),
+    (0x1D654, 0x1D654, &[0x2270]),
+    (0x1D655, 0x1D655, &[0x2286]),
+    (0x1D656, 0x1D656, &[0x1FA2]),
+    (0x1D657, 0x1D657, &[0x1FBC]),
+    (0x1D658, 0x1D658, &[0x1FD6]),
+    (0x1D659, 0x1D659, &[0x1FEB]),
+    (0x1D65A, 0x1D65A, &[0x2007]),
+    (0x1D65B, 0x1D65B, &[0x2042]),
+    (0x1D65C, 0x1D65C, &[0x2051]),
+    (0x1D65D, 0x1D65D, &[0x2075]),
+    (0x1D65E, 0x1D65E, &[0x2090]),
+    (0x1D65F, 0x1D65F, &[0x20AB]),
+    (0x1D660, 0x1D660, &[0x20C4]),
+    (0x1D661, 0x1D661, &[0x20D6]),
+    (0x1D662, 0x1D662, &[0x2109]),
+    (0x1D663, 0x1D663, &[0x2118]),
+    (0x1D664, 0x1D664, &[0x213C]),
+    (0x1D665, 0x1D665, &[0x216B]),
+    (0x1D666, 0x1D666, &[0x2180]),
+    (0x1D667, 0x1D667, &[0x2193]),
+    (0x1D668, 0x1D668, &[0x21D2]),
+    (0x1D669, 0x1D669, &[0x21F7]),
+    (0x1D66A, 0x1D66A, &[0x2217]),
+    (0x1D66B, 0x1D66B, &[0x2247]),
+    (0x1D66C, 0x1D66C, &[0x2259]),
+    (0x1D66D, 0x1D66D, &[0x2264]),
+    (0x1D66E, 0x1D66E, &[0x2270]),
+    (0x1D66F, 0x1D66F, &[0x2286]),
+    (0x1D670, 0x1D670, &[0x1FA2]),
+    (0x1D671, 0x1D671, &[0x1FBC]),
+    (0x1D672, 0x1D672, &[0x1FD6]),
+    (0x1D673, 0x1D673, &[0x1FEB]),
+    (0x1D674, 0x1D674, &[0x2007]),
+    (0x1D675, 0x1D675, &[0x2042]),
+    (0x1D676, 0x1D676, &[0x2051]),
+    (0x1D677, 0x1D677, &[0x2075]),
+    (0x1D678, 0x1D678, &[0x2090]),
+    (0x1D679, 0x1D679, &[0x20AB]),
+    (0x1D67A, 0x1D67A, &[0x20C4]),
+    (0x1D67B, 0x1D67B, &[0x20D6]),
+    (0x1D67C, 0x1D67C, &[0x2109]),
+    (0x1D67D, 0x1D67D, &[0x2118]),
+    (0x1D67E, 0x1D67E, &[0x213C]),
+    (0x1D67F, 0x1D67F, &[0x216B]),
+    (0x1D680, 0x1D680, &[0x2180]),
+    (0x1D681, 0x1D681, &[0x2193]),
+    (0x1D682, 0x1D682, &[0x21D2]),
+    (0x1D683, 0x1D683, &[0x21F7]),
+    (0x1D684, 0x1D684, &[0x2217]),
+    (0x1D685, 0x1D685, &[0x2247]),
+    (0x1D686, 0x1D686, &[0x2259]),
+    (0x1D687, 0x1D687, &[0x2264]),
+    (0x1D688, 0x1D688, &[0x2270]),
+    (0x1D689, 0x1D689, &[0x2286]),
+    (0x1D68A, 0x1D68A, &[0x1FA2]),
+    (0x1D68B, 0x1D68B, &[0x1FBC]),
+    (0x1D68C, 0x1D68C, &[0x1FD6]),
+    (0x1D68D, 0x1D68D, &[0x1FEB]),
+    (0x1D68E, 0x1D68E, &[0x2007]),
+    (0x1D68F, 0x1D68F, &[0x2042]),
+    (0x1D690, 0x1D690, &[0x2051]),
+    (0x1D691, 0x1D691, &[0x2075]),
+    (0x1D692, 0x1D692, &[0x2090]),
+    (0x1D693, 0x1D693, &[0x20AB]),
+    (0x1D694, 0x1D694, &[0x20C4]),
+    (0x1D695, 0x1D695, &[0x20D6]),
+    (0x1D696, 0x1D696, &[0x2109]),
+    (0x1D697, 0x1D697, &[0x2118]),
+    (0x1D698, 0x1D698, &[0x213C]),
+    (0x1D699, 0x1D699, &[0x216B]),
+    (0x1D69A, 0x1D69A, &[0x2180]),
+    (0x1D69B, 0x1D69B, &[0x2193]),
+    (0x1D69C, 0x1D69C, &[0x21D2]),
+    (0x1D69D, 0x1D69D, &[0x21F7]),
+    (0x1D69E, 0x1D69E, &[0x2217]),
+    (0x1D69F, 0x1D69F, &[0x2247]),
+    (0x1D6A0, 0x1D6A0, &[0x2259]),
+    (0x1D6A1, 0x1D6A1, &[0x2264]),
+    (0x1D6A2, 0x1D6A2, &[0x2270]),
+    (0x1D6A3, 0x1D6A3, &[0x2286]),
+    (0x1D6A4, 0x1D6A4, &[0x2094]),
+    (0x1D6A5, 0x1D6A5, &[0x20AF]),
+    (0x1D6A8, 0x1D6AA, &[0x231E]),
+    (0x1D6AB, 0x1D6AC, &[0x2322]),
+    (0x1D6AD, 0x1D6AD, &[0x2327]),
+    (0x1D6AE, 0x1D6B0, &[0x2329]),
+    (0x1D6B1, 0x1D6B2, &[0x232D]),
+    (0x1D6B3, 0x1D6B7, &[0x2330]),
+    (0x1D6B8, 0x1D6B8, &[0x2339]),
+    (0x1D6B9, 0x1D6B9, &[0x232A]),
+    (0x1D6BA, 0x1D6BA, &[0x233C]),
+    (0x1D6BB, 0x1D6BF, &[0x2340]),
+    (0x1D6C0, 0x1D6C0, &[0x2346]),
+    (0x1D6C1, 0x1D6C1, &[0x065C]),
+    (0x1D6C2, 0x1D6C4, &[0x231E]),
+    (0x1D6C5, 0x1D6C6, &[0x2322]),
+    (0x1D6C7, 0x1D6C7, &[0x2327]),
+    (0x1D6C8, 0x1D6CA, &[0x2329]),
+    (0x1D6CB, 0x1D6CC, &[0x232D]),
+    (0x1D6CD, 0x1D6D1, &[0x2330]),
+    (0x1D6D2, 0x1D6D2, &[0x2339]),
+    (0x1D6D3, 0x1D6D3, &[0x233C]),
+    (0x1D6D4, 0x1D6D4, &[0x233C]),
+    (0x1D6D5, 0x1D6D9, &[0x2340]),
+    (0x1D6DA, 0x1D6DA, &[0x2346]),
+    (0x1D6DB, 0x1D6DB, &[0x0658]),
+    (0x1D6DC, 0x1D6DC, &[0x2323]),
+    (0x1D6DD, 0x1D6DD, &[0x232A]),
+    (0x1D6DE, 0x1D6DE, &[0x232D]),
+    (0x1D6DF, 0x1D6DF, &[0x2342]),
+    (0x1D6E0, 0x1D6E0, &[0x2339]),
+    (0x1D6E1, 0x1D6E1, &[0x2334]),
+    (0x1D6E2, 0x1D6E4, &[0x231E]),
+    (0x1D6E5, 0x1D6E6, &[0x2322]),
+    (0x1D6E7, 0x1D6E7, &[0x2327]),
+    (0x1D6E8, 0x1D6EA, &[0x2329]),
+    (0x1D6EB, 0x1D6EC, &[0x232D]),
+    (0x1D6ED, 0x1D6F1, &[0x2330]),
+    (0x1D6F2, 0x1D6F2, &[0x2339]),
+    (0x1D6F3, 0x1D6F3, &[0x232A]),
+    (0x1D6F4, 0x1D6F4, &[0x233C]),
+    (0x1D6F5, 0x1D6F9, &[0x2340]),
+    (0x1D6FA, 0x1D6FA, &[0x2346]),
+    (0x1D6FB, 0x1D6FB, &[0x065C]),
+    (0x1D6FC, 0x1D6FE, &[0x231E]),
+    (0x1D6FF, 0x1D700, &[0x2322]),
+    (0x1D701, 0x1D701, &[0x2327]),
+    (0x1D702, 0x1D704, &[0x2329]),
+    (0x1D705, 0x1D706, &[0x232D]),
+    (0x1D707, 0x1D70B, &[0x2330]),
+    (0x1D70C, 0x1D70C, &[0x2339]),
+    (0x1D70D, 0x1D70D, &[0x233C]),
+    (0x1D70E, 0x1D70E, &[0x233C]),
+    (0x1D70F, 0x1D713, &[0x2340]),
+    (0x1D714, 0x1D714, &[0x2346]),
+    (0x1D715, 0x1D715, &[0x0658]),
+    (0x1D716, 0x1D716, &[0x2323]),
+    (0x1D717, 0x1D717, &[0x232A]),
+    (0x1D718, 0x1D718, &[0x232D]),
+    (0x1D719, 0x1D719, &[0x2342]),
+    (0x1D71A, 0x1D71A, &[0x2339]),
+    (0x1D71B, 0x1D71B, &[0x2334]),
+    (0x1D71C, 0x1D71E, &[0x231E]),
+    (0x1D71F, 0x1D720, &[0x2322]),
+    (0x1D721, 0x1D721, &[0x2327]),
+    (0x1D722, 0x1D724, &[0x2329]),
+    (0x1D725, 0x1D726, &[0x232D]),
+    (0x1D727, 0x1D72B, &[0x2330]),
+    (0x1D72C, 0x1D72C, &[0x2339]),
+    (0x1D72D, 0x1D72D, &[0x232A]),
+    (0x1D72E, 0x1D72E, &[0x233C]),
+    (0x1D72F, 0x1D733, &[0x2340]),
+    (0x1D734, 0x1D734, &[0x2346]),
+    (0x1D735, 0x1D735, &[0x065C]),
+    (0x1D736, 0x1D738, &[0x231E]),
+    (0x1D739, 0x1D73A, &[0x2322]),
+    (0x1D73B, 0x1D73B, &[0x2327]),
+    (0x1D73C, 0x1D73E, &[0x2329]),
+    (0x1D73F, 0x1D740, &[0x232D]),
+    (0x1D741, 0x1D745, &[0x2330]),
+    (0x1D746, 0x1D746, &[0x2339]),
+    (0x1D747, 0x1D747, &[0x233C]),
+    (0x1D748, 0x1D748, &[0x233C]),
+    (0x1D749, 0x1D74D, &[0x2340]),
+    (0x1D74E, 0x1D74E, &[0x2346]),
+    (0x1D74F, 0x1D74F, &[0x0658]),
+    (0x1D750, 0x1D750, &[0x2323]),
+    (0x1D751, 0x1D751, &[0x232A]),
+    (0x1D752, 0x1D752, &[0x232D]),
+    (0x1D753, 0x1D753, &[0x2342]),
+    (0x1D754, 0x1D754, &[0x2339]),
+    (0x1D755, 0x1D755, &[0x2334]),
+    (0x1D756, 0x1D758, &[0x231E]),
+    (0x1D759, 0x1D75A, &[0x2322]),
+    (0x1D75B, 0x1D75B, &[0x2327]),
+    (0x1D75C, 0x1D75E, &[0x2329]),
+    (0x1D75F, 0x1D760, &[0x232D]),
+    (0x1D761, 0x1D765, &[0x2330]),
+    (0x1D766, 0x1D766, &[0x2339]),
+    (0x1D767, 0x1D767, &[0x232A]),
+    (0x1D768, 0x1D768, &[0x233C]),
+    (0x1D769, 0x1D76D, &[0x2340]),
+    (0x1D76E, 0x1D76E, &[0x2346]),
+    (0x1D76F, 0x1D76F, &[0x065C]),
+    (0x1D770, 0x1D772, &[0x231E]),
+    (0x1D773, 0x1D774, &[0x2322]),
+    (0x1D775, 0x1D775, &[0x2327]),
+    (0x1D776, 0x1D778, &[0x2329]),
+    (0x1D779, 0x1D77A, &[0x232D]),
+    (0x1D77B, 0x1D77F, &[0x2330]),
+    (0x1D780, 0x1D780, &[0x2339]),
+    (0x1D781, 0x1D781, &[0x233C]),
+    (0x1D782, 0x1D782, &[0x233C]),
+    (0x1D783, 0x1D787, &[0x2340]),
+    (0x1D788, 0x1D788, &[0x2346]),
+    (0x1D789, 0x1D789, &[0x0658]),
+    (0x1D78A, 0x1D78A, &[0x2323]),
+    (0x1D78B, 0x1D78B, &[0x232A]),
+    (0x1D78C, 0x1D78C, &[0x232D]),
+    (0x1D78D, 0x1D78D, &[0x2342]),
+    (0x1D78E, 0x1D78E, &[0x2339]),
+    (0x1D78F, 0x1D78F, &[0x2334]),
+    (0x1D790, 0x1D792, &[0x231E]),
+    (0x1D793, 0x1D794, &[0x2322]),
+    (0x1D795, 0x1D795, &[0x2327]),
+    (0x1D796, 0x1D798, &[0x2329]),
+    (0x1D799, 0x1D79A, &[0x232D]),
+    (0x1D79B, 0x1D79F, &[0x2330]),
+    (0x1D7A0, 0x1D7A0, &[0x2339]),
+    (0x1D7A1, 0x1D7A1, &[0x232A]),
+    (0x1D7A2, 0x1D7A2, &[0x233C]),
+    (0x1D7A3, 0x1D7A7, &[0x2340]),
+    (0x1D7A8, 0x1D7A8, &[0x2346]),
+    (0x1D7A9, 0x1D7A9, &[0x065C]),
+    (0x1D7AA, 0x1D7AC, &[0x231E]),
+    (0x1D7AD, 0x1D7AE, &[0x2322]),
+    (0x1D7AF, 0x1D7AF, &[0x2327]),
+    (0x1D7B0, 0x1D7B2, &[0x2329]),
+    (0x1D7B3, 0x1D7B4, &[0x232D]),
+    (0x1D7B5, 0x1D7B9, &[0x2330]),
+    (0x1D7BA, 0x1D7BA, &[0x2339]),
+    (0x1D7BB, 0x1D7BB, &[0x233C]),
+    (0x1D7BC, 0x1D7BC, &[0x233C]),
+    (0x1D7BD, 0x1D7C1, &[0x2340]),
+    (0x1D7C2, 0x1D7C2, &[0x2346]),
+    (0x1D7C3, 0x1D7C3, &[0x0658]),
+    (0x1D7C4, 0x1D7C4, &[0x2323]),
+    (0x1D7C5, 0x1D7C5, &[0x232A]),
+    (0x1D7C6, 0x1D7C6, &[0x232D]),
+    (0x1D7C7, 0x1D7C7, &[0x2342]),
+    (0x1D7C8, 0x1D7C8, &[0x2339]),
+    (0x1D7C9, 0x1D7C9, &[0x2334]),
+    (0x1D7CA, 0x1D7CA, &[0x2324]),
+    (0x1D7CB, 0x1D7CB, &[0x2324]),
+    (0x1D7CE, 0x1D7D7, &[0x1F98]),
+    (0x1D7D8, 0x1D7E1, &[0x1F98]),
+    (0x1D7E2, 0x1D7EB, &[0x1F98]),
+    (0x1D7EC, 0x1D7F5, &[0x1F98]),
+    (0x1D7F6, 0x1D7FF, &[0x1F98]),
+    (0x1D800, 0x1D9FF, &[0x1AFF]),
+    (0x1DA00, 0x1DA36, &[]),
+    (0x1DA37, 0x1DA3A, &[0x1CFF]),
+    (0x1DA3B, 0x1DA6C, &[]),
+    (0x1DA6D, 0x1DA74, &[0x1D03]),
+    (0x1DA75, 0x1DA75, &[]),
+    (0x1DA76, 0x1DA83, &[0x1D0B]),
+    (0x1DA84, 0x1DA84, &[]),
+    (0x1DA85, 0x1DA86, &[0x1D19]),
+    (0x1DA87, 0x1DA8B, &[0x04AF]),
+    (0x1DA9B, 0x1DA9F, &[]),
+    (0x1DAA1, 0x1DAAF, &[]),
+    (0x1E000, 0x1E006, &[0x254A]),
+    (0x1E008, 0x1E018, &[0x2552]),
+    (0x1E01B, 0x1E021, &[0x2565]),
+    (0x1E023, 0x1E024, &[0x256D]),
+    (0x1E026, 0x1E02A, &[0x2570]),
+    (0x1E100, 0x1E12C, &[0x4A62]),
+    (0x1E130, 0x1E136, &[]),
+    (0x1E137, 0x1E13B, &[0x4A8F]),
+    (0x1E13C, 0x1E13D, &[0x1F55]),
+    (0x1E140, 0x1E149, &[0x1F98]),
+    (0x1E14E, 0x1E14E, &[0x4A94]),
+    (0x1E14F, 0x1E14F, &[0x1123]),
+    (0x1E2C0, 0x1E2EB, &[0x4A95]),
+    (0x1E2EC, 0x1E2EF, &[]),
+    (0x1E2F0, 0x1E2F9, &[0x1F98]),
+    (0x1E2FF, 0x1E2FF, &[0x1F77]),
+    (0x1E800, 0x1E8C4, &[0x406D]),
+    (0x1E8C7, 0x1E8CF, &[0x1F99]),
+    (0x1E8D0, 0x1E8D6, &[]),
+    (0x1E900, 0x1E921, &[0x4152]),
+    (0x1E922, 0x1E943, &[0x4152]),
+    (0x1E944, 0x1E94A, &[]),
+    (0x1E94B, 0x1E94B, &[0x4174]),
+    (0x1E950, 0x1E959, &[0x1F98]),
+    (0x1E95E, 0x1E95E, &[0x026C]),
+    (0x1E95F, 0x1E95F, &[0x027B]),
+    (0x1EC71, 0x1EC79, &[0x1F99]),
+    (0x1EC7A, 0x1ECA2, &[0x1E9C]),
+    (0x1ECA3, 0x1ECAB, &[0x1F99]),
+    (0x1ECAC, 0x1ECAC, &[0x1315]),
+    (0x1ECAD, 0x1ECAF, &[0x1EC5]),
+    (0x1ECB0, 0x1ECB0, &[0x1F91]),
+    (0x1ECB1, 0x1ECB2, &[0x1F99]),
+    (0x1ECB3, 0x1ECB4, &[0x1EC8]),
+    (0x1ED01, 0x1ED09, &[0x1F99]),
+    (0x1ED0A, 0x1ED2D, &[0x1ECA]),
+    (0x1ED2E, 0x1ED2E, &[0x1316]),
+    (0x1ED2F, 0x1ED36, &[0x1F9A]),
+    (0x1ED37, 0x1ED3D, &[0x1EEE]),
+    (0x1EE00, 0x1EE00, &[0x2672]),
+    (0x1EE01, 0x1EE01, &[0x2674]),
+    (0x1EE02, 0x1EE02, &[0x268F]),
+    (0x1EE03, 0x1EE03, &[0x26A4]),
+    (0x1EE05, 0x1EE05, &[0x2728]),
+    (0x1EE06, 0x1EE06, &[0x26B4]),
+    (0x1EE07, 0x1EE07, &[0x2699]),
+    (0x1EE08, 0x1EE08, &[0x26D7]),
+    (0x1EE09, 0x1EE09, &[0x2737]),
+    (0x1EE0A, 0x1EE0A, &[0x26F6]),
+    (0x1EE0B, 0x1EE0B, &[0x270C]),
+    (0x1EE0C, 0x1EE0C, &[0x2714]),
+    (0x1EE0D, 0x1EE0D, &[0x2718]),
+    (0x1EE0E, 0x1EE0E, &[0x26C6]),
+    (0x1EE0F, 0x1EE0F, &[0x26DB]),
+    (0x1EE10, 0x1EE10, &[0x26E4]),
+    (0x1EE11, 0x1EE11, &[0x26D1]),
+    (0x1EE12, 0x1EE12, &[0x26F0]),
+    (0x1EE13, 0x1EE13, &[0x26B3]),
+    (0x1EE14, 0x1EE14, &[0x26C7]),
+    (0x1EE15, 0x1EE16, &[0x2685]),
+    (0x1EE17, 0x1EE17, &[0x269A]),
+    (0x1EE18, 0x1EE18, &[0x26A5]),
+    (0x1EE19, 0x1EE19, &[0x26D2]),
+    (0x1EE1A, 0x1EE1A, &[0x26D8]),
+    (0x1EE1B, 0x1EE1B, &[0x26DC]),
+    (0x1EE1C, 0x1EE1C, &[0x2673]),
+    (0x1EE1D, 0x1EE1D, &[0x2719]),
+    (0x1EE1E, 0x1EE1E, &[0x26E5]),
+    (0x1EE1F, 0x1EE1F, &[0x26EF]),
+    (0x1EE21, 0x1EE21, &[0x2674]),
+    (0x1EE22, 0x1EE22, &[0x268F]),
+    (0x1EE24, 0x1EE24, &[0x2722]),
+    (0x1EE27, 0x1EE27, &[0x2699]),
+    (0x1EE29, 0x1EE29, &[0x2737]),
+    (0x1EE2A, 0x1EE2A, &[0x26F6]),
+    (0x1EE2B, 0x1EE2B, &[0x270C]),
+    (0x1EE2C, 0x1EE2C, &[0x2714]),
+    (0x1EE2D, 0x1EE2D, &[0x2718]),
+    (0x1EE2E, 0x1EE2E, &[0x26C6]),
+    (0x1EE2F, 0x1EE2F, &[0x26DB]),
+    (0x1EE30, 0x1EE30, &[0x26E4]),
+    (0x1EE31, 0x1EE31, &[0x26D1]),
+    (0x1EE32, 0x1EE32, &[0x26F0]),
+    (0x1EE34, 0x1EE34, &[0x26C7]),
+    (0x1EE35, 0x1EE36, &[0x2685]),
+    (0x1EE37, 0x1EE37, &[0x269A]),
+    (0x1EE39, 0x1EE39, &[0x26D2]),
+    (0x1EE3B, 0x1EE3B, &[0x26DC]),
+    (0x1EE42, 0x1EE42, &[0x268F]),
+    (0x1EE47, 0x1EE47, &[0x2699]),
+    (0x1EE49, 0x1EE49, &[0x2737]),
+    (0x1EE4B, 0x1EE4B, &[0x270C]),
+    (0x1EE4D, 0x1EE4D, &[0x2718]),
+    (0x1EE4E, 0x1EE4E, &[0x26C6]),
+    (0x1EE4F, 0x1EE4F, &[0x26DB]),
+    (0x1EE51, 0x1EE51, &[0x26D1]),
+    (0x1EE52, 0x1EE52, &[0x26F0]),
+    (0x1EE54, 0x1EE54, &[0x26C7]),
+    (0x1EE57, 0x1EE57, &[0x269A]),
+    (0x1EE59, 0x1EE59, &[0x26D2]),
+    (0x1EE5B, 0x1EE5B, &[0x26DC]),
+    (0x1EE5D, 0x1EE5D, &[0x2719]),
+    (0x1EE5F, 0x1EE5F, &[0x26EF]),
+    (0x1EE61, 0x1EE61, &[0x2674]),
+    (0x1EE62, 0x1EE62, &[0x268F]),
+    (0x1EE64, 0x1EE64, &[0x2722]),
+    (0x1EE67, 0x1EE67, &[0x2699]),
+    (0x1EE68, 0x1EE68, &[0x26D7]),
+    (0x1EE69, 0x1EE69, &[0x2737]),
+    (0x1EE6A, 0x1EE6A, &[0x26F6]),
+    (0x1EE6C, 0x1EE6C, &[0x2714]),
+    (0x1EE6D, 0x1EE6D, &[0x2718]),
+    (0x1EE6E, 0x1EE6E, &[0x26C6]),
+    (0x1EE6F, 0x1EE6F, &[0x26DB]),
+    (0x1EE70, 0x1EE70, &[0x26E4]),
+    (0x1EE71, 0x1EE71, &[0x26D1]),
+    (0x1EE72, 0x1EE72, &[0x26F0]),
+    (0x1EE74, 0x1EE74, &[0x26C7]),
+    (0x1EE75, 0x1EE76, &[0x2685]),
+    (0x1EE77, 0x1EE77, &[0x269A]),
+    (0x1EE79, 0x1EE79, &[0x26D2]),
+    (0x1EE7A, 0x1EE7A, &[0x26D8]),
+    (0x1EE7B, 0x1EE7B, &[0x26DC]),
+    (0x1EE7C, 0x1EE7C, &[0x2673]),
+    (0x1EE7E, 0x1EE7E, &[0x26E5]),
+    (0x1EE80, 0x1EE80, &[0x2672]),
+    (0x1EE81, 0x1EE81, &[0x2674]),
+    (0x1EE82, 0x1EE82, &[0x268F]),
+    (0x1EE83, 0x1EE83, &[0x26A4]),
+    (0x1EE84, 0x1EE84, &[0x2722]),
+    (0x1EE85, 0x1EE85, &[0x2728]),
+    (0x1EE86, 0x1EE86, &[0x26B4]),
+    (0x1EE87, 0x1EE87, &[0x2699]),
+    (0x1EE88, 0x1EE88, &[0x26D7]),
+    (0x1EE89, 0x1EE89, &[0x2737]),
+    (0x1EE8B, 0x1EE8B, &[0x270C]),
+    (0x1EE8C, 0x1EE8C, &[0x2714]),
+    (0x1EE8D, 0x1EE8D, &[0x2718]),
+    (0x1EE8E, 0x1EE8E, &[0x26C6]),
+    (0x1EE8F, 0x1EE8F, &[0x26DB]),
+    (0x1EE90, 0x1EE90, &[0x26E4]),
+    (0x1EE91, 0x1EE91, &[0x26D1]),
+    (0x1EE92, 0x1EE92, &[0x26F0]),
+    (0x1EE93, 0x1EE93, &[0x26B3]),
+    (0x1EE94, 0x1EE94, &[0x26C7]),
+    (0x1EE95, 0x1EE96, &[0x2685]),
+    (0x1EE97, 0x1EE97, &[0x269A]),
+    (0x1EE98, 0x1EE98, &[0x26A5]),
+    (0x1EE99, 0x1EE99, &[0x26D2]),
+    (0x1EE9A, 0x1EE9A, &[0x26D8]),
+    (0x1EE9B, 0x1EE9B, &[0x26DC]),
+    (0x1EEA1, 0x1EEA1, &[0x2674]),
+    (0x1EEA2, 0x1EEA2, &[0x268F]),
+    (0x1EEA3, 0x1EEA3, &[0x26A4]),
+    (0x1EEA5, 0x1EEA5, &[0x2728]),
+    (0x1EEA6, 0x1EEA6, &[0x26B4]),
+    (0x1EEA7, 0x1EEA7, &[0x2699]),
+    (0x1EEA8, 0x1EEA8, &[0x26D7]),
+    (0x1EEA9, 0x1EEA9, &[0x2737]),
+    (0x1EEAB, 0x1EEAB, &[0x270C]),
+    (0x1EEAC, 0x1EEAC, &[0x2714]),
+    (0x1EEAD, 0x1EEAD, &[0x2718]),
+    (0x1EEAE, 0x1EEAE, &[0x26C6]),
+    (0x1EEAF, 0x1EEAF, &[0x26DB]),
+    (0x1EEB0, 0x1EEB0, &[0x26E4]),
+    (0x1EEB1, 0x1EEB1, &[0x26D1]),
+    (0x1EEB2, 0x1EEB2, &[0x26F0]),
+    (0x1EEB3, 0x1EEB3, &[0x26B3]),
+    (0x1EEB4, 0x1EEB4, &[0x26C7]),
+    (0x1EEB5, 0x1EEB6, &[0x2685]),
+    (0x1EEB7, 0x1EEB7, &[0x269A]),
+    (0x1EEB8, 0x1EEB8, &[0x26A5]),
+    (0x1EEB9, 0x1EEB9, &[0x26D2]),
+    (0x1EEBA, 0x1EEBA, &[0x26D8]),
+    (0x1EEBB, 0x1EEBB, &[0x26DC]),
+    (0x1EEF0, 0x1EEF1, &[0x052F]),
+    (0x1F000, 0x1F02B, &[0x1317]),
+    (0x1F030, 0x1F093, &[0x1343]),
+    (0x1F0A0, 0x1F0AE, &[0x13A7]),
+    (0x1F0B1, 0x1F0BF, &[0x13B6]),
+    (0x1F0C1, 0x1F0CF, &[0x13C5]),
+    (0x1F0D1, 0x1F0F5, &[0x13D4]),
+    (0x1F100, 0x1F100, &[0x1F98, 0x027E]),
+    (0x1F101, 0x1F101, &[0x1F98, 0x0223]),
+    (0x1F102, 0x1F102, &[0x1F99, 0x0223]),
+    (0x1F103, 0x1F103, &[0x1F9A, 0x0223]),
+    (0x1F104, 0x1F104, &[0x1F9B, 0x0223]),
+    (0x1F105, 0x1F105, &[0x1F9C, 0x0223]),
+    (0x1F106, 0x1F106, &[0x1F9D, 0x0223]),
+    (0x1F107, 0x1F107, &[0x1F9E, 0x0223]),
+    (0x1F108, 0x1F108, &[0x1F9F, 0x0223]),
+    (0x1F109, 0x1F109, &[0x1FA0, 0x0223]),
+    (0x1F10A, 0x1F10A, &[0x1FA1, 0x0223]),
+    (0x1F10B, 0x1F10B, &[0x1F98]),
+    (0x1F10C, 0x1F10C, &[0x1F98]),
+    (0x1F10D, 0x1F10F, &[0x145B]),
+    (0x1F110, 0x1F110, &[0x0328, 0x1FA2, 0x0329]),
+    (0x1F111, 0x1F111, &[0x0328, 0x1FBC, 0x0329]),
+    (0x1F112, 0x1F112, &[0x0328, 0x1FD6, 0x0329]),
+    (0x1F113, 0x1F113, &[0x0328, 0x1FEB, 0x0329]),
+    (0x1F114, 0x1F114, &[0x0328, 0x2007, 0x0329]),
+    (0x1F115, 0x1F115, &[0x0328, 0x2042, 0x0329]),
+    (0x1F116, 0x1F116, &[0x0328, 0x2051, 0x0329]),
+    (0x1F117, 0x1F117, &[0x0328, 0x2075, 0x0329]),
+    (0x1F118, 0x1F118, &[0x0328, 0x2090, 0x0329]),
+    (0x1F119, 0x1F119, &[0x0328, 0x20AB, 0x0329]),
+    (0x1F11A, 0x1F11A, &[0x0328, 0x20C4, 0x0329]),
+    (0x1F11B, 0x1F11B, &[0x0328, 0x20D6, 0x0329]),
+    (0x1F11C, 0x1F11C, &[0x0328, 0x2109, 0x0329]),
+    (0x1F11D, 0x1F11D, &[0x0328, 0x2118, 0x0329]),
+    (0x1F11E, 0x1F11E, &[0x0328, 0x213C, 0x0329]),
+    (0x1F11F, 0x1F11F, &[0x0328, 0x216B, 0x0329]),
+    (0x1F120, 0x1F120, &[0x0328, 0x2180, 0x0329]),
+    (0x1F121, 0x1F121, &[0x0328, 0x2193, 0x0329]),
+    (0x1F122, 0x1F122, &[0x0328, 0x21D2, 0x0329]),
+    (0x1F123, 0x1F123, &[0x0328, 0x21F7, 0x0329]),
+    (0x1F124, 0x1F124, &[0x0328, 0x2217, 0x0329]),
+    (0x1F125, 0x1F125, &[0x0328, 0x2247, 0x0329]),
+    (0x1F126, 0x1F126, &[0x0328, 0x2259, 0x0329]),
+    (0x1F127, 0x1F127, &[0x0328, 0x2264, 0x0329]),
+    (0x1F128, 0x1F128, &[0x0328, 0x2270, 0x0329]),
+    (0x1F129, 0x1F129, &[0x0328, 0x2286, 0x0329]),
+    (0x1F12A, 0x1F12A, &[0x038A, 0x21D2, 0x038B]),
+    (0x1F12B, 0x1F12B, &[0x1FD6]),
+    (0x1F12C, 0x1F12C, &[0x2193]),
+    (0x1F12D, 0x1F12D, &[0x1FD6, 0x1FEB]),
+    (0x1F12E, 0x1F12E, &[0x2259, 0x2286]),
+    (0x1F12F, 0x1F12F, &[0x05D3]),
+    (0x1F130, 0x1F130, &[0x1FA2]),
+    (0x1F131, 0x1F131, &[0x1FBC]),
+    (0x1F132, 0x1F132, &[0x1FD6]),
+    (0x1F133, 0x1F133, &[0x1FEB]),
+    (0x1F134, 0x1F134, &[0x2007]),
+    (0x1F135, 0x1F135, &[0x2042]),
+    (0x1F136, 0x1F136, &[0x2051]),
+    (0x1F137, 0x1F137, &[0x2075]),
+    (0x1F138, 0x1F138, &[0x2090]),
+    (0x1F139, 0x1F139, &[0x20AB]),
+    (0x1F13A, 0x1F13A, &[0x20C4]),
+    (0x1F13B, 0x1F13B, &[0x20D6]),
+    (0x1F13C, 0x1F13C, &[0x2109]),
+    (0x1F13D, 0x1F13D, &[0x2118]),
+    (0x1F13E, 0x1F13E, &[0x213C]),
+    (0x1F13F, 0x1F13F, &[0x216B]),
+    (0x1F140, 0x1F140, &[0x2180]),
+    (0x1F141, 0x1F141, &[0x2193]),
+    (0x1F142, 0x1F142, &[0x21D2]),
+    (0x1F143, 0x1F143, &[0x21F7]),
+    (0x1F144, 0x1F144, &[0x2217]),
+    (0x1F145, 0x1F145, &[0x2247]),
+    (0x1F146, 0x1F146, &[0x2259]),
+    (0x1F147, 0x1F147, &[0x2264]),
+    (0x1F148, 0x1F148, &[0x2270]),
+    (0x1F149, 0x1F149, &[0x2286]),
+    (0x1F14A, 0x1F14A, &[0x2075, 0x2247]),
+    (0x1F14B, 0x1F14B, &[0x2109, 0x2247]),
+    (0x1F14C, 0x1F14C, &[0x21D2, 0x1FEB]),
+    (0x1F14D, 0x1F14D, &[0x21D2, 0x21D2]),
+    (0x1F14E, 0x1F14E, &[0x216B, 0x216B, 0x2247]),
+    (0x1F14F, 0x1F14F, &[0x2259, 0x1FD6]),
+    (0x1F150, 0x1F150, &[0x1FA2]),
+    (0x1F151, 0x1F151, &[0x1FBC]),
+    (0x1F152, 0x1F152, &[0x1FD6]),
+    (0x1F153, 0x1F153, &[0x1FEB]),
+    (0x1F154, 0x1F154, &[0x2007]),
+    (0x1F155, 0x1F155, &[0x2042]),
+    (0x1F156, 0x1F156, &[0x2051]),
+    (0x1F157, 0x1F157, &[0x2075]),
+    (0x1F158, 0x1F158, &[0x2090]),
+    (0x1F159, 0x1F159, &[0x20AB]),
+    (0x1F15A, 0x1F15A, &[0x20C4]),
+    (0x1F15B, 0x1F15B, &[0x20D6]),
+    (0x1F15C, 0x1F15C, &[0x2109]),
+    (0x1F15D, 0x1F15D, &[0x2118]),
+    (0x1F15E, 0x1F15E, &[0x213C]),
+    (0x1F15F, 0x1F15F, &[0x216B]),
+    (0x1F160, 0x1F160, &[0x2180]),
+    (0x1F161, 0x1F161, &[0x2193]),
+    (0x1F162, 0x1F162, &[0x21D2]),
+    (0x1F163, 0x1F163, &[0x21F7]),
+    (0x1F164, 0x1F164, &[0x2217]),
+    (0x1F165, 0x1F165, &[0x2247]),
+    (0x1F166, 0x1F166, &[0x2259]),
+    (0x1F167, 0x1F167, &[0x2264]),
+    (0x1F168, 0x1F168, &[0x2270]),
+    (0x1F169, 0x1F169, &[0x2286]),
+    (0x1F16A, 0x1F16A, &[0x2109, 0x1FD6]),
+    (0x1F16B, 0x1F16B, &[0x2109, 0x1FEB]),
+    (0x1F16C, 0x1F16C, &[0x2109, 0x2193]),
+    (0x1F16D, 0x1F16F, &[0x145E]),
+    (0x1F170, 0x1F170, &[0x1FA2]),
+    (0x1F171, 0x1F171, &[0x1FBC]),
+    (0x1F172, 0x1F172, &[0x1FD6]),
+    (0x1F173, 0x1F173, &[0x1FEB]),
+    (0x1F174, 0x1F174, &[0x2007]),
+    (0x1F175, 0x1F175, &[0x2042]),
+    (0x1F176, 0x1F176, &[0x2051]),
+    (0x1F177, 0x1F177, &[0x2075]),
+    (0x1F178, 0x1F178, &[0x2090]),
+    (0x1F179, 0x1F179, &[0x20AB]),
+    (0x1F17A, 0x1F17A, &[0x20C4]),
+    (0x1F17B, 0x1F17B, &[0x20D6]),
+    (0x1F17C, 0x1F17C, &[0x2109]),
+    (0x1F17D, 0x1F17D, &[0x2118]),
+    (0x1F17E, 0x1F17E, &[0x213C]),
+    (0x1F17F, 0x1F17F, &[0x216B]),
+    (0x1F180, 0x1F180, &[0x2180]),
+    (0x1F181, 0x1F181, &[0x2193]),
+    (0x1F182, 0x1F182, &[0x21D2]),
+    (0x1F183, 0x1F183, &[0x21F7]),
+    (0x1F184, 0x1F184, &[0x2217]),
+    (0x1F185, 0x1F185, &[0x2247]),
+    (0x1F186, 0x1F186, &[0x2259]),
+    (0x1F187, 0x1F187, &[0x2264]),
+    (0x1F188, 0x1F188, &[0x2270]),
+    (0x1F189, 0x1F189, &[0x2286]),
+    (0x1F18A, 0x1F18A, &[0x216B]),
+    (0x1F18B, 0x1F18B, &[0x2090, 0x1FD6]),
+    (0x1F18C, 0x1F18C, &[0x216B, 0x1FA2]),
+    (0x1F18D, 0x1F18D, &[0x21D2, 0x1FA2]),
+    (0x1F18E, 0x1F18E, &[0x1FA2, 0x1FBC]),
+    (0x1F18F, 0x1F18F, &[0x2259, 0x1FD6]),
+    (0x1F190, 0x1F190, &[0x1FEB, 0x20AB]),
+    (0x1F191, 0x1F191, &[0x1FD6, 0x20D6]),
+    (0x1F192, 0x1F192, &[0x1FD6, 0x213C, 0x213C, 0x20D6]),
+    (0x1F193, 0x1F193, &[0x2042, 0x2193, 0x2007, 0x2007]),
+    (0x1F194, 0x1F194, &[0x2090, 0x1FEB]),
+    (0x1F195, 0x1F195, &[0x2118, 0x2007, 0x2259]),
+    (0x1F196, 0x1F196, &[0x2118, 0x2051]),
+    (0x1F197, 0x1F197, &[0x213C, 0x20C4]),
+    (0x1F198, 0x1F198, &[0x21D2, 0x213C, 0x21D2]),
+    (0x1F199, 0x1F199, &[0x2217, 0x216B, 0x0267]),
+    (0x1F19A, 0x1F19A, &[0x2247, 0x21D2]),
+    (0x1F19B, 0x1F19B, &[0x1F9B, 0x1FEB]),
+    (0x1F19C, 0x1F19C, &[0x1F9A, 0x2118, 0x1FEB, 0x0209, 0x21D2, 0x1FD6, 0x2193]),
+    (0x1F19D, 0x1F19D, &[0x1F9A, 0x20C4]),
+    (0x1F19E, 0x1F19E, &[0x1F9C, 0x20C4]),
+    (0x1F19F, 0x1F19F, &[0x1FA0, 0x20C4]),
+    (0x1F1A0, 0x1F1A0, &[0x1F9D, 0x027E, 0x1F99]),
+    (0x1F1A1, 0x1F1A1, &[0x1F9F, 0x027E, 0x1F99]),
+    (0x1F1A2, 0x1F1A2, &[0x1F9A, 0x1F9A, 0x027E, 0x1F9A]),
+    (0x1F1A3, 0x1F1A3, &[0x1F9E, 0x1F98, 0x216B]),
+    (0x1F1A4, 0x1F1A4, &[0x1F99, 0x1F9A, 0x1F98, 0x216B]),
+    (0x1F1A5, 0x1F1A5, &[0x1FEB]),
+    (0x1F1A6, 0x1F1A6, &[0x2075, 0x1FD6]),
+    (0x1F1A7, 0x1F1A7, &[0x2075, 0x1FEB, 0x2193]),
+    (0x1F1A8, 0x1F1A8, &[0x2075, 0x2090, 0x0209, 0x2193, 0x2007, 0x21D2]),
+    (0x1F1A9, 0x1F1A9, &[0x20D6, 0x213C, 0x21D2, 0x21D2, 0x20D6, 0x2007, 0x21D2, 0x21D2]),
+    (0x1F1AA, 0x1F1AA, &[0x21D2, 0x2075, 0x2247]),
+    (0x1F1AB, 0x1F1AB, &[0x2217, 0x2075, 0x1FEB]),
+    (0x1F1AC, 0x1F1AC, &[0x2247, 0x213C, 0x1FEB]),
+    (0x1F1AD, 0x1F1AD, &[0x05D5]),
+    (0x1F1E6, 0x1F1FF, &[0x0B22]),
+    (0x1F200, 0x1F200, &[0x42F8, 0x42E0]),
+    (0x1F201, 0x1F201, &[0x42E4, 0x42E4]),
+    (0x1F202, 0x1F202, &[0x42E5]),
+    (0x1F210, 0x1F210, &[0xFB40, 0xE24B]),
+    (0x1F211, 0x1F211, &[0xFB40, 0xDB57]),
+    (0x1F212, 0x1F212, &[0xFB40, 0xD3CC]),
+    (0x1F213, 0x1F213, &[0x42ED]),
+    (0x1F214, 0x1F214, &[0xFB40, 0xCE8C]),
+    (0x1F215, 0x1F215, &[0xFB40, 0xD91A]),
+    (0x1F216, 0x1F216, &[0xFB41, 0x89E3]),
+    (0x1F217, 0x1F217, &[0xFB40, 0xD929]),
+    (0x1F218, 0x1F218, &[0xFB40, 0xCEA4]),
+    (0x1F219, 0x1F219, &[0xFB40, 0xE620]),
+    (0x1F21A, 0x1F21A, &[0xFB40, 0xF121]),
+    (0x1F21B, 0x1F21B, &[0xFB40, 0xE599]),
+    (0x1F21C, 0x1F21C, &[0xFB40, 0xD24D]),
+    (0x1F21D, 0x1F21D, &[0xFB40, 0xDF8C]),
+    (0x1F21E, 0x1F21E, &[0xFB40, 0xD18D]),
+    (0x1F21F, 0x1F21F, &[0xFB40, 0xE5B0]),
+    (0x1F220, 0x1F220, &[0xFB40, 0xD21D]),
+    (0x1F221, 0x1F221, &[0xFB40, 0xFD42]),
+    (0x1F222, 0x1F222, &[0xFB40, 0xF51F]),
+    (0x1F223, 0x1F223, &[0xFB41, 0x8CA9]),
+    (0x1F224, 0x1F224, &[0xFB40, 0xD8F0]),
+    (0x1F225, 0x1F225, &[0xFB40, 0xD439]),
+    (0x1F226, 0x1F226, &[0xFB40, 0xEF14]),
+    (0x1F227, 0x1F227, &[0xFB40, 0xE295]),
+    (0x1F228, 0x1F228, &[0xFB40, 0xE355]),
+    (0x1F229, 0x1F229, &[0xFB40, 0xCE00]),
+    (0x1F22A, 0x1F22A, &[0xFB40, 0xCE09]),
+    (0x1F22B, 0x1F22B, &[0xFB41, 0x904A]),
+    (0x1F22C, 0x1F22C, &[0xFB40, 0xDDE6]),
+    (0x1F22D, 0x1F22D, &[0xFB40, 0xCE2D]),
+    (0x1F22E, 0x1F22E, &[0xFB40, 0xD3F3]),
+    (0x1F22F, 0x1F22F, &[0xFB40, 0xE307]),
+    (0x1F230, 0x1F230, &[0xFB41, 0x8D70]),
+    (0x1F231, 0x1F231, &[0xFB40, 0xE253]),
+    (0x1F232, 0x1F232, &[0xFB40, 0xF981]),
+    (0x1F233, 0x1F233, &[0xFB40, 0xFA7A]),
+    (0x1F234, 0x1F234, &[0xFB40, 0xD408]),
+    (0x1F235, 0x1F235, &[0xFB40, 0xEE80]),
+    (0x1F236, 0x1F236, &[0xFB40, 0xE709]),
+    (0x1F237, 0x1F237, &[0xFB40, 0xE708]),
+    (0x1F238, 0x1F238, &[0xFB40, 0xF533]),
+    (0x1F239, 0x1F239, &[0xFB40, 0xD272]),
+    (0x1F23A, 0x1F23A, &[0xFB40, 0xD5B6]),
+    (0x1F23B, 0x1F23B, &[0xFB41, 0x914D]),
+    (0x1F240, 0x1F240, &[0x038A, 0xFB40, 0xE72C, 0x038B]),
+    (0x1F241, 0x1F241, &[0x038A, 0xFB40, 0xCE09, 0x038B]),
+    (0x1F242, 0x1F242, &[0x038A, 0xFB40, 0xCE8C, 0x038B]),
+    (0x1F243, 0x1F243, &[0x038A, 0xFB40, 0xDB89, 0x038B]),
+    (0x1F244, 0x1F244, &[0x038A, 0xFB40, 0xF0B9, 0x038B]),
+    (0x1F245, 0x1F245, &[0x038A, 0xFB40, 0xE253, 0x038B]),
+    (0x1F246, 0x1F246, &[0x038A, 0xFB40, 0xF6D7, 0x038B]),
+    (0x1F247, 0x1F247, &[0x038A, 0xFB40, 0xD2DD, 0x038B]),
+    (0x1F248, 0x1F248, &[0x038A, 0xFB40, 0xE557, 0x038B]),
+    (0x1F250, 0x1F250, &[0xFB40, 0xDF97]),
+    (0x1F251, 0x1F251, &[0xFB40, 0xD3EF]),
+    (0x1F260, 0x1F265, &[0x1461]),
+    (0x1F300, 0x1F5FF, &[0x1467]),
+    (0x1F600, 0x1F6D7, &[0x189E]),
+    (0x1F6E0, 0x1F6EC, &[0x1976]),
+    (0x1F6F0, 0x1F6FC, &[0x1983]),
+    (0x1F700, 0x1F773, &[0x1990]),
+    (0x1F780, 0x1F7D8, &[0x1A04]),
+    (0x1F7E0, 0x1F7EB, &[0x1A5D]),
+    (0x1F800, 0x1F80B, &[0x1A69]),
+    (0x1F810, 0x1F847, &[0x1A75]),
+    (0x1F850, 0x1F859, &[0x1AAD]),
+    (0x1F860, 0x1F887, &[0x1AB7]),
+    (0x1F890, 0x1F8AD, &[0x1ADF]),
+    (0x1F8B0, 0x1F8B1, &[0x1AFD]),
+    (0x1F900, 0x1F978, &[0x1767]),
+    (0x1F97A, 0x1F9CB, &[0x17E0]),
+    (0x1F9CD, 0x1F9FF, &[0x1832]),
+    (0x1FA00, 0x1FA53, &[0x13F9]),
+    (0x1FA60, 0x1FA6D, &[0x144D]),
+    (0x1FA70, 0x1FA74, &[0x1865]),
+    (0x1FA78, 0x1FA7A, &[0x186A]),
+    (0x1FA80, 0x1FA86, &[0x186D]),
+    (0x1FA90, 0x1FAA8, &[0x1874]),
+    (0x1FAB0, 0x1FAB6, &[0x188D]),
+    (0x1FAC0, 0x1FAC2, &[0x1894]),
+    (0x1FAD0, 0x1FAD6, &[0x1897]),
+    (0x1FB00, 0x1FB92, &[0x0969]),
+    (0x1FB94, 0x1FBCA, &[0x09FC]),
+    (0x1FBF0, 0x1FBF9, &[0x1F98]),
+    (0x2F800, 0x2F800, &[0xFB40, 0xCE3D]),
+    (0x2F801, 0x2F801, &[0xFB40, 0xCE38]),
+    (0x2F802, 0x2F802, &[0xFB40, 0xCE41]),
+    (0x2F803, 0x2F803, &[0xFB84, 0x8122]),
+    (0x2F804, 0x2F804, &[0xFB40, 0xCF60]),
+    (0x2F805, 0x2F805, &[0xFB40, 0xCFAE]),
+    (0x2F806, 0x2F806, &[0xFB40, 0xCFBB]),
+    (0x2F807, 0x2F807, &[0xFB40, 0xD002]),
+    (0x2F808, 0x2F808, &[0xFB40, 0xD07A]),
+    (0x2F809, 0x2F809, &[0xFB40, 0xD099]),
+    (0x2F80A, 0x2F80A, &[0xFB40, 0xD0E7]),
+    (0x2F80B, 0x2F80B, &[0xFB40, 0xD0CF]),
+    (0x2F80C, 0x2F80C, &[0xFB80, 0xB49E]),
+    (0x2F80D, 0x2F80D, &[0xFB84, 0x863A]),
+    (0x2F80E, 0x2F80E, &[0xFB40, 0xD14D]),
+    (0x2F80F, 0x2F80F, &[0xFB40, 0xD154]),
+    (0x2F810, 0x2F810, &[0xFB40, 0xD164]),
+    (0x2F811, 0x2F811, &[0xFB40, 0xD177]),
+    (0x2F812, 0x2F812, &[0xFB84, 0x851C]),
+    (0x2F813, 0x2F813, &[0xFB80, 0xB4B9]),
+    (0x2F814, 0x2F814, &[0xFB40, 0xD167]),
+    (0x2F815, 0x2F815, &[0xFB40, 0xD18D]),
+    (0x2F816, 0x2F816, &[0xFB84, 0x854B]),
+    (0x2F817, 0x2F817, &[0xFB40, 0xD197]),
+    (0x2F818, 0x2F818, &[0xFB40, 0xD1A4]),
+    (0x2F819, 0x2F819, &[0xFB40, 0xCECC]),
+    (0x2F81A, 0x2F81A, &[0xFB40, 0xD1AC]),
+    (0x2F81B, 0x2F81B, &[0xFB40, 0xD1B5]),
+    (0x2F81C, 0x2F81C, &[0xFB85, 0x91DF]),
+    (0x2F81D, 0x2F81D, &[0xFB40, 0xD1F5]),
+    (0x2F81E, 0x2F81E, &[0xFB40, 0xD203]),
+    (0x2F81F, 0x2F81F, &[0xFB80, 0xB4DF]),
+    (0x2F820, 0x2F820, &[0xFB40, 0xD23B]),
+    (0x2F821, 0x2F821, &[0xFB40, 0xD246]),
+    (0x2F822, 0x2F822, &[0xFB40, 0xD272]),
+    (0x2F823, 0x2F823, &[0xFB40, 0xD277]),
+    (0x2F824, 0x2F824, &[0xFB80, 0xB515]),
+    (0x2F825, 0x2F825, &[0xFB40, 0xD2C7]),
+    (0x2F826, 0x2F826, &[0xFB40, 0xD2C9]),
+    (0x2F827, 0x2F827, &[0xFB40, 0xD2E4]),
+    (0x2F828, 0x2F828, &[0xFB40, 0xD2FA]),
+    (0x2F829, 0x2F829, &[0xFB40, 0xD305]),
+    (0x2F82A, 0x2F82A, &[0xFB40, 0xD306]),
+    (0x2F82B, 0x2F82B, &[0xFB40, 0xD317]),
+    (0x2F82C, 0x2F82C, &[0xFB40, 0xD349]),
+    (0x2F82D, 0x2F82D, &[0xFB40, 0xD351]),
+    (0x2F82E, 0x2F82E, &[0xFB40, 0xD35A]),
+    (0x2F82F, 0x2F82F, &[0xFB40, 0xD373]),
+    (0x2F830, 0x2F830, &[0xFB40, 0xD37D]),
+    (0x2F831, 0x2F831, &[0xFB40, 0xD37F]),
+    (0x2F832, 0x2F832, &[0xFB40, 0xD37F]),
+    (0x2F833, 0x2F833, &[0xFB40, 0xD37F]),
+    (0x2F834, 0x2F834, &[0xFB84, 0x8A2C]),
+    (0x2F835, 0x2F835, &[0xFB40, 0xF070]),
+    (0x2F836, 0x2F836, &[0xFB40, 0xD3CA]),
+    (0x2F837, 0x2F837, &[0xFB40, 0xD3DF]),
+    (0x2F838, 0x2F838, &[0xFB84, 0x8B63]),
+    (0x2F839, 0x2F839, &[0xFB40, 0xD3EB]),
+    (0x2F83A, 0x2F83A, &[0xFB40, 0xD3F1]),
+    (0x2F83B, 0x2F83B, &[0xFB40, 0xD406]),
+    (0x2F83C, 0x2F83C, &[0xFB40, 0xD49E]),
+    (0x2F83D, 0x2F83D, &[0xFB40, 0xD438]),
+    (0x2F83E, 0x2F83E, &[0xFB40, 0xD448]),
+    (0x2F83F, 0x2F83F, &[0xFB40, 0xD468]),
+    (0x2F840, 0x2F840, &[0xFB40, 0xD4A2]),
+    (0x2F841, 0x2F841, &[0xFB40, 0xD4F6]),
+    (0x2F842, 0x2F842, &[0xFB40, 0xD510]),
+    (0x2F843, 0x2F843, &[0xFB40, 0xD553]),
+    (0x2F844, 0x2F844, &[0xFB40, 0xD563]),
+    (0x2F845, 0x2F845, &[0xFB40, 0xD584]),
+    (0x2F846, 0x2F846, &[0xFB40, 0xD584]),
+    (0x2F847, 0x2F847, &[0xFB40, 0xD599]),
+    (0x2F848, 0x2F848, &[0xFB40, 0xD5AB]),
+    (0x2F849, 0x2F849, &[0xFB40, 0xD5B3]),
+    (0x2F84A, 0x2F84A, &[0xFB40, 0xD5C2]),
+    (0x2F84B, 0x2F84B, &[0xFB40, 0xD716]),
+    (0x2F84C, 0x2F84C, &[0xFB40, 0xD606]),
+    (0x2F84D, 0x2F84D, &[0xFB40, 0xD717]),
+    (0x2F84E, 0x2F84E, &[0xFB40, 0xD651]),
+    (0x2F84F, 0x2F84F, &[0xFB40, 0xD674]),
+    (0x2F850, 0x2F850, &[0xFB40, 0xD207]),
+    (0x2F851, 0x2F851, &[0xFB40, 0xD8EE]),
+    (0x2F852, 0x2F852, &[0xFB40, 0xD7CE]),
+    (0x2F853, 0x2F853, &[0xFB40, 0xD7F4]),
+    (0x2F854, 0x2F854, &[0xFB40, 0xD80D]),
+    (0x2F855, 0x2F855, &[0xFB40, 0xD78B]),
+    (0x2F856, 0x2F856, &[0xFB40, 0xD832]),
+    (0x2F857, 0x2F857, &[0xFB40, 0xD831]),
+    (0x2F858, 0x2F858, &[0xFB40, 0xD8AC]),
+    (0x2F859, 0x2F859, &[0xFB84, 0x94E4]),
+    (0x2F85A, 0x2F85A, &[0xFB40, 0xD8F2]),
+    (0x2F85B, 0x2F85B, &[0xFB40, 0xD8F7]),
+    (0x2F85C, 0x2F85C, &[0xFB40, 0xD906]),
+    (0x2F85D, 0x2F85D, &[0xFB40, 0xD91A]),
+    (0x2F85E, 0x2F85E, &[0xFB40, 0xD922]),
+    (0x2F85F, 0x2F85F, &[0xFB40, 0xD962]),
+    (0x2F860, 0x2F860, &[0xFB84, 0x96A8]),
+    (0x2F861, 0x2F861, &[0xFB84, 0x96EA]),
+    (0x2F862, 0x2F862, &[0xFB40, 0xD9EC]),
+    (0x2F863, 0x2F863, &[0xFB40, 0xDA1B]),
+    (0x2F864, 0x2F864, &[0xFB40, 0xDA27]),
+    (0x2F865, 0x2F865, &[0xFB40, 0xD9D8]),
+    (0x2F866, 0x2F866, &[0xFB40, 0xDA66]),
+    (0x2F867, 0x2F867, &[0xFB80, 0xB6EE]),
+    (0x2F868, 0x2F868, &[0xFB80, 0xB6FC]),
+    (0x2F869, 0x2F869, &[0xFB40, 0xDB08]),
+    (0x2F86A, 0x2F86A, &[0xFB40, 0xDB3E]),
+    (0x2F86B, 0x2F86B, &[0xFB40, 0xDB3E]),
+    (0x2F86C, 0x2F86C, &[0xFB84, 0x99C8]),
+    (0x2F86D, 0x2F86D, &[0xFB40, 0xDBC3]),
+    (0x2F86E, 0x2F86E, &[0xFB40, 0xDBD8]),
+    (0x2F86F, 0x2F86F, &[0xFB40, 0xDBE7]),
+    (0x2F870, 0x2F870, &[0xFB40, 0xDBF3]),
+    (0x2F871, 0x2F871, &[0xFB84, 0x9B18]),
+    (0x2F872, 0x2F872, &[0xFB40, 0xDBFF]),
+    (0x2F873, 0x2F873, &[0xFB40, 0xDC06]),
+    (0x2F874, 0x2F874, &[0xFB40, 0xDF53]),
+    (0x2F875, 0x2F875, &[0xFB40, 0xDC22]),
+    (0x2F876, 0x2F876, &[0xFB80, 0xB781]),
+    (0x2F877, 0x2F877, &[0xFB40, 0xDC60]),
+    (0x2F878, 0x2F878, &[0xFB40, 0xDC6E]),
+    (0x2F879, 0x2F879, &[0xFB40, 0xDCC0]),
+    (0x2F87A, 0x2F87A, &[0xFB40, 0xDC8D]),
+    (0x2F87B, 0x2F87B, &[0xFB84, 0x9DE4]),
+    (0x2F87C, 0x2F87C, &[0xFB40, 0xDD43]),
+    (0x2F87D, 0x2F87D, &[0xFB84, 0x9DE6]),
+    (0x2F87E, 0x2F87E, &[0xFB40, 0xDD6E]),
+    (0x2F87F, 0x2F87F, &[0xFB40, 0xDD6B]),
+    (0x2F880, 0x2F880, &[0xFB40, 0xDD7C]),
+    (0x2F881, 0x2F881, &[0xFB40, 0xDDE1]),
+    (0x2F882, 0x2F882, &[0xFB40, 0xDDE2]),
+    (0x2F883, 0x2F883, &[0xFB80, 0xB82F]),
+    (0x2F884, 0x2F884, &[0xFB40, 0xDDFD]),
+    (0x2F885, 0x2F885, &[0xFB40, 0xDE28]),
+    (0x2F886, 0x2F886, &[0xFB40, 0xDE3D]),
+    (0x2F887, 0x2F887, &[0xFB40, 0xDE69]),
+    (0x2F888, 0x2F888, &[0xFB80, 0xB862]),
+    (0x2F889, 0x2F889, &[0xFB84, 0xA183]),
+    (0x2F88A, 0x2F88A, &[0xFB80, 0xB87C]),
+    (0x2F88B, 0x2F88B, &[0xFB40, 0xDEB0]),
+    (0x2F88C, 0x2F88C, &[0xFB40, 0xDEB3]),
+    (0x2F88D, 0x2F88D, &[0xFB40, 0xDEB6]),
+    (0x2F88E, 0x2F88E, &[0xFB40, 0xDECA]),
+    (0x2F88F, 0x2F88F, &[0xFB85, 0xA392]),
+    (0x2F890, 0x2F890, &[0xFB40, 0xDEFE]),
+    (0x2F891, 0x2F891, &[0xFB84, 0xA331]),
+    (0x2F892, 0x2F892, &[0xFB84, 0xA331]),
+    (0x2F893, 0x2F893, &[0xFB41, 0x8201]),
+    (0x2F894, 0x2F894, &[0xFB40, 0xDF22]),
+    (0x2F895, 0x2F895, &[0xFB40, 0xDF22]),
+    (0x2F896, 0x2F896, &[0xFB80, 0xB8C7]),
+    (0x2F897, 0x2F897, &[0xFB84, 0xB2B8]),
+    (0x2F898, 0x2F898, &[0xFB84, 0xE1DA]),
+    (0x2F899, 0x2F899, &[0xFB40, 0xDF62]),
+    (0x2F89A, 0x2F89A, &[0xFB40, 0xDF6B]),
+    (0x2F89B, 0x2F89B, &[0xFB80, 0xB8E3]),
+    (0x2F89C, 0x2F89C, &[0xFB40, 0xDF9A]),
+    (0x2F89D, 0x2F89D, &[0xFB40, 0xDFCD]),
+    (0x2F89E, 0x2F89E, &[0xFB40, 0xDFD7]),
+    (0x2F89F, 0x2F89F, &[0xFB40, 0xDFF9]),
+    (0x2F8A0, 0x2F8A0, &[0xFB40, 0xE081]),
+    (0x2F8A1, 0x2F8A1, &[0xFB80, 0xB93A]),
+    (0x2F8A2, 0x2F8A2, &[0xFB80, 0xB91C]),
+    (0x2F8A3, 0x2F8A3, &[0xFB40, 0xE094]),
+    (0x2F8A4, 0x2F8A4, &[0xFB84, 0xA6D4]),
+    (0x2F8A5, 0x2F8A5, &[0xFB40, 0xE0C7]),
+    (0x2F8A6, 0x2F8A6, &[0xFB40, 0xE148]),
+    (0x2F8A7, 0x2F8A7, &[0xFB40, 0xE14C]),
+    (0x2F8A8, 0x2F8A8, &[0xFB40, 0xE14E]),
+    (0x2F8A9, 0x2F8A9, &[0xFB40, 0xE14C]),
+    (0x2F8AA, 0x2F8AA, &[0xFB40, 0xE17A]),
+    (0x2F8AB, 0x2F8AB, &[0xFB40, 0xE18E]),
+    (0x2F8AC, 0x2F8AC, &[0xFB40, 0xE1B2]),
+    (0x2F8AD, 0x2F8AD, &[0xFB40, 0xE1A4]),
+    (0x2F8AE, 0x2F8AE, &[0xFB40, 0xE1AF]),
+    (0x2F8AF, 0x2F8AF, &[0xFB40, 0xE1DE]),
+    (0x2F8B0, 0x2F8B0, &[0xFB40, 0xE1F2]),
+    (0x2F8B1, 0x2F8B1, &[0xFB40, 0xE1F6]),
+    (0x2F8B2, 0x2F8B2, &[0xFB40, 0xE210]),
+    (0x2F8B3, 0x2F8B3, &[0xFB40, 0xE21B]),
+    (0x2F8B4, 0x2F8B4, &[0xFB40, 0xE25D]),
+    (0x2F8B5, 0x2F8B5, &[0xFB40, 0xE2B1]),
+    (0x2F8B6, 0x2F8B6, &[0xFB40, 0xE2D4]),
+    (0x2F8B7, 0x2F8B7, &[0xFB40, 0xE350]),
+    (0x2F8B8, 0x2F8B8, &[0xFB84, 0xAB0C]),
+    (0x2F8B9, 0x2F8B9, &[0xFB40, 0xE33D]),
+    (0x2F8BA, 0x2F8BA, &[0xFB40, 0xE2FC]),
+    (0x2F8BB, 0x2F8BB, &[0xFB40, 0xE368]),
+    (0x2F8BC, 0x2F8BC, &[0xFB40, 0xE383]),
+    (0x2F8BD, 0x2F8BD, &[0xFB40, 0xE3E4]),
+    (0x2F8BE, 0x2F8BE, &[0xFB84, 0xABF1]),
+    (0x2F8BF, 0x2F8BF, &[0xFB40, 0xE422]),
+    (0x2F8C0, 0x2F8C0, &[0xFB40, 0xE3C5]),
+    (0x2F8C1, 0x2F8C1, &[0xFB40, 0xE3A9]),
+    (0x2F8C2, 0x2F8C2, &[0xFB80, 0xBA2E]),
+    (0x2F8C3, 0x2F8C3, &[0xFB40, 0xE469]),
+    (0x2F8C4, 0x2F8C4, &[0xFB40, 0xE47E]),
+    (0x2F8C5, 0x2F8C5, &[0xFB40, 0xE49D]),
+    (0x2F8C6, 0x2F8C6, &[0xFB40, 0xE477]),
+    (0x2F8C7, 0x2F8C7, &[0xFB80, 0xBA6C]),
+    (0x2F8C8, 0x2F8C8, &[0xFB40, 0xE54F]),
+    (0x2F8C9, 0x2F8C9, &[0xFB40, 0xE56C]),
+    (0x2F8CA, 0x2F8CA, &[0xFB84, 0xB00A]),
+    (0x2F8CB, 0x2F8CB, &[0xFB40, 0xE5E3]),
+    (0x2F8CC, 0x2F8CC, &[0xFB40, 0xE6F8]),
+    (0x2F8CD, 0x2F8CD, &[0xFB40, 0xE649]),
+    (0x2F8CE, 0x2F8CE, &[0xFB80, 0xBB19]),
+    (0x2F8CF, 0x2F8CF, &[0xFB40, 0xE691]),
+    (0x2F8D0, 0x2F8D0, &[0xFB80, 0xBB08]),
+    (0x2F8D1, 0x2F8D1, &[0xFB80, 0xBAE4]),
+    (0x2F8D2, 0x2F8D2, &[0xFB40, 0xD192]),
+    (0x2F8D3, 0x2F8D3, &[0xFB40, 0xD195]),
+    (0x2F8D4, 0x2F8D4, &[0xFB40, 0xE700]),
+    (0x2F8D5, 0x2F8D5, &[0xFB40, 0xE69C]),
+    (0x2F8D6, 0x2F8D6, &[0xFB41, 0x80AD]),
+    (0x2F8D7, 0x2F8D7, &[0xFB80, 0xC3D9]),
+    (0x2F8D8, 0x2F8D8, &[0xFB40, 0xE717]),
+    (0x2F8D9, 0x2F8D9, &[0xFB40, 0xE71B]),
+    (0x2F8DA, 0x2F8DA, &[0xFB40, 0xE721]),
+    (0x2F8DB, 0x2F8DB, &[0xFB40, 0xE75E]),
+    (0x2F8DC, 0x2F8DC, &[0xFB40, 0xE753]),
+    (0x2F8DD, 0x2F8DD, &[0xFB84, 0xB3C3]),
+    (0x2F8DE, 0x2F8DE, &[0xFB80, 0xBB49]),
+    (0x2F8DF, 0x2F8DF, &[0xFB40, 0xE7FA]),
+    (0x2F8E0, 0x2F8E0, &[0xFB40, 0xE785]),
+    (0x2F8E1, 0x2F8E1, &[0xFB40, 0xE852]),
+    (0x2F8E2, 0x2F8E2, &[0xFB40, 0xE885]),
+    (0x2F8E3, 0x2F8E3, &[0xFB84, 0xB46D]),
+    (0x2F8E4, 0x2F8E4, &[0xFB40, 0xE88E]),
+    (0x2F8E5, 0x2F8E5, &[0xFB40, 0xE81F]),
+    (0x2F8E6, 0x2F8E6, &[0xFB40, 0xE914]),
+    (0x2F8E7, 0x2F8E7, &[0xFB80, 0xBB9D]),
+    (0x2F8E8, 0x2F8E8, &[0xFB40, 0xE942]),
+    (0x2F8E9, 0x2F8E9, &[0xFB40, 0xE9A3]),
+    (0x2F8EA, 0x2F8EA, &[0xFB40, 0xE9EA]),
+    (0x2F8EB, 0x2F8EB, &[0xFB40, 0xEAA8]),
+    (0x2F8EC, 0x2F8EC, &[0xFB84, 0xB6A3]),
+    (0x2F8ED, 0x2F8ED, &[0xFB40, 0xEADB]),
+    (0x2F8EE, 0x2F8EE, &[0xFB80, 0xBC18]),
+    (0x2F8EF, 0x2F8EF, &[0xFB40, 0xEB21]),
+    (0x2F8F0, 0x2F8F0, &[0xFB84, 0xB8A7]),
+    (0x2F8F1, 0x2F8F1, &[0xFB40, 0xEB54]),
+    (0x2F8F2, 0x2F8F2, &[0xFB80, 0xBC4E]),
+    (0x2F8F3, 0x2F8F3, &[0xFB40, 0xEB72]),
+    (0x2F8F4, 0x2F8F4, &[0xFB40, 0xEB9F]),
+    (0x2F8F5, 0x2F8F5, &[0xFB40, 0xEBBA]),
+    (0x2F8F6, 0x2F8F6, &[0xFB40, 0xEBBB]),
+    (0x2F8F7, 0x2F8F7, &[0xFB84, 0xBA8D]),
+    (0x2F8F8, 0x2F8F8, &[0xFB84, 0x9D0B]),
+    (0x2F8F9, 0x2F8F9, &[0xFB84, 0xBAFA]),
+    (0x2F8FA, 0x2F8FA, &[0xFB40, 0xEC4E]),
+    (0x2F8FB, 0x2F8FB, &[0xFB84, 0xBCBC]),
+    (0x2F8FC, 0x2F8FC, &[0xFB40, 0xECBF]),
+    (0x2F8FD, 0x2F8FD, &[0xFB40, 0xECCD]),
+    (0x2F8FE, 0x2F8FE, &[0xFB40, 0xEC67]),
+    (0x2F8FF, 0x2F8FF, &[0xFB40, 0xED16]),
+    (0x2F900, 0x2F900, &[0xFB40, 0xED3E]),
+    (0x2F901, 0x2F901, &[0xFB40, 0xED77]),
+    (0x2F902, 0x2F902, &[0xFB40, 0xED41]),
+    (0x2F903, 0x2F903, &[0xFB40, 0xED69]),
+    (0x2F904, 0x2F904, &[0xFB40, 0xED78]),
+    (0x2F905, 0x2F905, &[0xFB40, 0xED85]),
+    (0x2F906, 0x2F906, &[0xFB84, 0xBD1E]),
+    (0x2F907, 0x2F907, &[0xFB40, 0xED34]),
+    (0x2F908, 0x2F908, &[0xFB40, 0xEE2F]),
+    (0x2F909, 0x2F909, &[0xFB40, 0xEE6E]),
+    (0x2F90A, 0x2F90A, &[0xFB80, 0xBD33]),
+    (0x2F90B, 0x2F90B, &[0xFB40, 0xEECB]),
+    (0x2F90C, 0x2F90C, &[0xFB40, 0xEEC7]),
+    (0x2F90D, 0x2F90D, &[0xFB84, 0xBED1]),
+    (0x2F90E, 0x2F90E, &[0xFB40, 0xEDF9]),
+    (0x2F90F, 0x2F90F, &[0xFB40, 0xEF6E]),
+    (0x2F910, 0x2F910, &[0xFB84, 0xBF5E]),
+    (0x2F911, 0x2F911, &[0xFB84, 0xBF8E]),
+    (0x2F912, 0x2F912, &[0xFB40, 0xEFC6]),
+    (0x2F913, 0x2F913, &[0xFB40, 0xF039]),
+    (0x2F914, 0x2F914, &[0xFB40, 0xF01E]),
+    (0x2F915, 0x2F915, &[0xFB40, 0xF01B]),
+    (0x2F916, 0x2F916, &[0xFB80, 0xBD96]),
+    (0x2F917, 0x2F917, &[0xFB40, 0xF04A]),
+    (0x2F918, 0x2F918, &[0xFB40, 0xF07D]),
+    (0x2F919, 0x2F919, &[0xFB40, 0xF077]),
+    (0x2F91A, 0x2F91A, &[0xFB40, 0xF0AD]),
+    (0x2F91B, 0x2F91B, &[0xFB84, 0x8525]),
+    (0x2F91C, 0x2F91C, &[0xFB40, 0xF145]),
+    (0x2F91D, 0x2F91D, &[0xFB84, 0xC263]),
+    (0x2F91E, 0x2F91E, &[0xFB40, 0xF19C]),
+    (0x2F91F, 0x2F91F, &[0xFB84, 0xC3AB]),
+    (0x2F920, 0x2F920, &[0xFB40, 0xF228]),
+    (0x2F921, 0x2F921, &[0xFB40, 0xF235]),
+    (0x2F922, 0x2F922, &[0xFB40, 0xF250]),
+    (0x2F923, 0x2F923, &[0xFB84, 0xC608]),
+    (0x2F924, 0x2F924, &[0xFB40, 0xF280]),
+    (0x2F925, 0x2F925, &[0xFB40, 0xF295]),
+    (0x2F926, 0x2F926, &[0xFB84, 0xC735]),
+    (0x2F927, 0x2F927, &[0xFB84, 0xC814]),
+    (0x2F928, 0x2F928, &[0xFB40, 0xF37A]),
+    (0x2F929, 0x2F929, &[0xFB40, 0xF38B]),
+    (0x2F92A, 0x2F92A, &[0xFB80, 0xBEAC]),
+    (0x2F92B, 0x2F92B, &[0xFB40, 0xF3A5]),
+    (0x2F92C, 0x2F92C, &[0xFB80, 0xBEB8]),
+    (0x2F92D, 0x2F92D, &[0xFB80, 0xBEB8]),
+    (0x2F92E, 0x2F92E, &[0xFB40, 0xF447]),
+    (0x2F92F, 0x2F92F, &[0xFB40, 0xF45C]),
+    (0x2F930, 0x2F930, &[0xFB40, 0xF471]),
+    (0x2F931, 0x2F931, &[0xFB40, 0xF485]),
+    (0x2F932, 0x2F932, &[0xFB40, 0xF4CA]),
+    (0x2F933, 0x2F933, &[0xFB80, 0xBF1B]),
+    (0x2F934, 0x2F934, &[0xFB40, 0xF524]),
+    (0x2F935, 0x2F935, &[0xFB84, 0xCC36]),
+    (0x2F936, 0x2F936, &[0xFB40, 0xF53E]),
+    (0x2F937, 0x2F937, &[0xFB84, 0xCC92]),
+    (0x2F938, 0x2F938, &[0xFB40, 0xF570]),
+    (0x2F939, 0x2F939, &[0xFB84, 0xA19F]),
+    (0x2F93A, 0x2F93A, &[0xFB40, 0xF610]),
+    (0x2F93B, 0x2F93B, &[0xFB84, 0xCFA1]),
+    (0x2F93C, 0x2F93C, &[0xFB84, 0xCFB8]),
+    (0x2F93D, 0x2F93D, &[0xFB84, 0xD044]),
+    (0x2F93E, 0x2F93E, &[0xFB80, 0xBFFC]),
+    (0x2F93F, 0x2F93F, &[0xFB80, 0xC008]),
+    (0x2F940, 0x2F940, &[0xFB40, 0xF6F4]),
+    (0x2F941, 0x2F941, &[0xFB84, 0xD0F3]),
+    (0x2F942, 0x2F942, &[0xFB84, 0xD0F2]),
+    (0x2F943, 0x2F943, &[0xFB84, 0xD119]),
+    (0x2F944, 0x2F944, &[0xFB84, 0xD133]),
+    (0x2F945, 0x2F945, &[0xFB40, 0xF71E]),
+    (0x2F946, 0x2F946, &[0xFB40, 0xF71F]),
+    (0x2F947, 0x2F947, &[0xFB40, 0xF71F]),
+    (0x2F948, 0x2F948, &[0xFB40, 0xF74A]),
+    (0x2F949, 0x2F949, &[0xFB80, 0xC039]),
+    (0x2F94A, 0x2F94A, &[0xFB40, 0xF78B]),
+    (0x2F94B, 0x2F94B, &[0xFB80, 0xC046]),
+    (0x2F94C, 0x2F94C, &[0xFB80, 0xC096]),
+    (0x2F94D, 0x2F94D, &[0xFB84, 0xD41D]),
+    (0x2F94E, 0x2F94E, &[0xFB40, 0xF84E]),
+    (0x2F94F, 0x2F94F, &[0xFB40, 0xF88C]),
+    (0x2F950, 0x2F950, &[0xFB40, 0xF8CC]),
+    (0x2F951, 0x2F951, &[0xFB80, 0xC0E3]),
+    (0x2F952, 0x2F952, &[0xFB84, 0xD626]),
+    (0x2F953, 0x2F953, &[0xFB40, 0xF956]),
+    (0x2F954, 0x2F954, &[0xFB84, 0xD69A]),
+    (0x2F955, 0x2F955, &[0xFB84, 0xD6C5]),
+    (0x2F956, 0x2F956, &[0xFB40, 0xF98F]),
+    (0x2F957, 0x2F957, &[0xFB40, 0xF9EB]),
+    (0x2F958, 0x2F958, &[0xFB80, 0xC12F]),
+    (0x2F959, 0x2F959, &[0xFB40, 0xFA40]),
+    (0x2F95A, 0x2F95A, &[0xFB40, 0xFA4A]),
+    (0x2F95B, 0x2F95B, &[0xFB40, 0xFA4F]),
+    (0x2F95C, 0x2F95C, &[0xFB84, 0xD97C]),
+    (0x2F95D, 0x2F95D, &[0xFB84, 0xDAA7]),
+    (0x2F95E, 0x2F95E, &[0xFB84, 0xDAA7]),
+    (0x2F95F, 0x2F95F, &[0xFB40, 0xFAEE]),
+    (0x2F960, 0x2F960, &[0xFB80, 0xC202]),
+    (0x2F961, 0x2F961, &[0xFB84, 0xDBAB]),
+    (0x2F962, 0x2F962, &[0xFB40, 0xFBC6]),
+    (0x2F963, 0x2F963, &[0xFB40, 0xFBC9]),
+    (0x2F964, 0x2F964, &[0xFB80, 0xC227]),
+    (0x2F965, 0x2F965, &[0xFB84, 0xDC80]),
+    (0x2F966, 0x2F966, &[0xFB40, 0xFCD2]),
+    (0x2F967, 0x2F967, &[0xFB80, 0xC2A0]),
+    (0x2F968, 0x2F968, &[0xFB40, 0xFCE8]),
+    (0x2F969, 0x2F969, &[0xFB40, 0xFCE3]),
+    (0x2F96A, 0x2F96A, &[0xFB40, 0xFD00]),
+    (0x2F96B, 0x2F96B, &[0xFB84, 0xDF86]),
+    (0x2F96C, 0x2F96C, &[0xFB40, 0xFD63]),
+    (0x2F96D, 0x2F96D, &[0xFB80, 0xC301]),
+    (0x2F96E, 0x2F96E, &[0xFB40, 0xFDC7]),
+    (0x2F96F, 0x2F96F, &[0xFB40, 0xFE02]),
+    (0x2F970, 0x2F970, &[0xFB40, 0xFE45]),
+    (0x2F971, 0x2F971, &[0xFB80, 0xC334]),
+    (0x2F972, 0x2F972, &[0xFB84, 0xE228]),
+    (0x2F973, 0x2F973, &[0xFB84, 0xE247]),
+    (0x2F974, 0x2F974, &[0xFB80, 0xC359]),
+    (0x2F975, 0x2F975, &[0xFB84, 0xE2D9]),
+    (0x2F976, 0x2F976, &[0xFB40, 0xFF7A]),
+    (0x2F977, 0x2F977, &[0xFB84, 0xE33E]),
+    (0x2F978, 0x2F978, &[0xFB40, 0xFF95]),
+    (0x2F979, 0x2F979, &[0xFB40, 0xFFFA]),
+    (0x2F97A, 0x2F97A, &[0xFB41, 0x8005]),
+    (0x2F97B, 0x2F97B, &[0xFB84, 0xE4DA]),
+    (0x2F97C, 0x2F97C, &[0xFB84, 0xE523]),
+    (0x2F97D, 0x2F97D, &[0xFB41, 0x8060]),
+    (0x2F97E, 0x2F97E, &[0xFB84, 0xE5A8]),
+    (0x2F97F, 0x2F97F, &[0xFB41, 0x8070]),
+    (0x2F980, 0x2F980, &[0xFB84, 0xB35F]),
+    (0x2F981, 0x2F981, &[0xFB80, 0xC3D5]),
+    (0x2F982, 0x2F982, &[0xFB41, 0x80B2]),
+    (0x2F983, 0x2F983, &[0xFB41, 0x8103]),
+    (0x2F984, 0x2F984, &[0xFB80, 0xC40B]),
+    (0x2F985, 0x2F985, &[0xFB41, 0x813E]),
+    (0x2F986, 0x2F986, &[0xFB40, 0xDAB5]),
+    (0x2F987, 0x2F987, &[0xFB84, 0xE7A7]),
+    (0x2F988, 0x2F988, &[0xFB84, 0xE7B5]),
+    (0x2F989, 0x2F989, &[0xFB84, 0xB393]),
+    (0x2F98A, 0x2F98A, &[0xFB84, 0xB39C]),
+    (0x2F98B, 0x2F98B, &[0xFB41, 0x8201]),
+    (0x2F98C, 0x2F98C, &[0xFB41, 0x8204]),
+    (0x2F98D, 0x2F98D, &[0xFB41, 0x8F9E]),
+    (0x2F98E, 0x2F98E, &[0xFB80, 0xC46B]),
+    (0x2F98F, 0x2F98F, &[0xFB41, 0x8291]),
+    (0x2F990, 0x2F990, &[0xFB41, 0x828B]),
+    (0x2F991, 0x2F991, &[0xFB41, 0x829D]),
+    (0x2F992, 0x2F992, &[0xFB40, 0xD2B3]),
+    (0x2F993, 0x2F993, &[0xFB41, 0x82B1]),
+    (0x2F994, 0x2F994, &[0xFB41, 0x82B3]),
+    (0x2F995, 0x2F995, &[0xFB41, 0x82BD]),
+    (0x2F996, 0x2F996, &[0xFB41, 0x82E6]),
+    (0x2F997, 0x2F997, &[0xFB84, 0xEB3C]),
+    (0x2F998, 0x2F998, &[0xFB41, 0x82E5]),
+    (0x2F999, 0x2F999, &[0xFB41, 0x831D]),
+    (0x2F99A, 0x2F99A, &[0xFB41, 0x8363]),
+    (0x2F99B, 0x2F99B, &[0xFB41, 0x83AD]),
+    (0x2F99C, 0x2F99C, &[0xFB41, 0x8323]),
+    (0x2F99D, 0x2F99D, &[0xFB41, 0x83BD]),
+    (0x2F99E, 0x2F99E, &[0xFB41, 0x83E7]),
+    (0x2F99F, 0x2F99F, &[0xFB41, 0x8457]),
+    (0x2F9A0, 0x2F9A0, &[0xFB41, 0x8353]),
+    (0x2F9A1, 0x2F9A1, &[0xFB41, 0x83CA]),
+    (0x2F9A2, 0x2F9A2, &[0xFB41, 0x83CC]),
+    (0x2F9A3, 0x2F9A3, &[0xFB41, 0x83DC]),
+    (0x2F9A4, 0x2F9A4, &[0xFB84, 0xEC36]),
+    (0x2F9A5, 0x2F9A5, &[0xFB84, 0xED6B]),
+    (0x2F9A6, 0x2F9A6, &[0xFB84, 0xECD5]),
+    (0x2F9A7, 0x2F9A7, &[0xFB80, 0xC52B]),
+    (0x2F9A8, 0x2F9A8, &[0xFB41, 0x84F1]),
+    (0x2F9A9, 0x2F9A9, &[0xFB41, 0x84F3]),
+    (0x2F9AA, 0x2F9AA, &[0xFB41, 0x8516]),
+    (0x2F9AB, 0x2F9AB, &[0xFB84, 0xF3CA]),
+    (0x2F9AC, 0x2F9AC, &[0xFB41, 0x8564]),
+    (0x2F9AD, 0x2F9AD, &[0xFB84, 0xEF2C]),
+    (0x2F9AE, 0x2F9AE, &[0xFB80, 0xC55D]),
+    (0x2F9AF, 0x2F9AF, &[0xFB80, 0xC561]),
+    (0x2F9B0, 0x2F9B0, &[0xFB84, 0xEFB1]),
+    (0x2F9B1, 0x2F9B1, &[0xFB84, 0xF0D2]),
+    (0x2F9B2, 0x2F9B2, &[0xFB80, 0xC56B]),
+    (0x2F9B3, 0x2F9B3, &[0xFB41, 0x8650]),
+    (0x2F9B4, 0x2F9B4, &[0xFB41, 0x865C]),
+    (0x2F9B5, 0x2F9B5, &[0xFB41, 0x8667]),
+    (0x2F9B6, 0x2F9B6, &[0xFB41, 0x8669]),
+    (0x2F9B7, 0x2F9B7, &[0xFB41, 0x86A9]),
+    (0x2F9B8, 0x2F9B8, &[0xFB41, 0x8688]),
+    (0x2F9B9, 0x2F9B9, &[0xFB41, 0x870E]),
+    (0x2F9BA, 0x2F9BA, &[0xFB41, 0x86E2]),
+    (0x2F9BB, 0x2F9BB, &[0xFB41, 0x8779]),
+    (0x2F9BC, 0x2F9BC, &[0xFB41, 0x8728]),
+    (0x2F9BD, 0x2F9BD, &[0xFB41, 0x876B]),
+    (0x2F9BE, 0x2F9BE, &[0xFB41, 0x8786]),
+    (0x2F9BF, 0x2F9BF, &[0xFB80, 0xC5D7]),
+    (0x2F9C0, 0x2F9C0, &[0xFB41, 0x87E1]),
+    (0x2F9C1, 0x2F9C1, &[0xFB41, 0x8801]),
+    (0x2F9C2, 0x2F9C2, &[0xFB80, 0xC5F9]),
+    (0x2F9C3, 0x2F9C3, &[0xFB41, 0x8860]),
+    (0x2F9C4, 0x2F9C4, &[0xFB41, 0x8863]),
+    (0x2F9C5, 0x2F9C5, &[0xFB84, 0xF667]),
+    (0x2F9C6, 0x2F9C6, &[0xFB41, 0x88D7]),
+    (0x2F9C7, 0x2F9C7, &[0xFB41, 0x88DE]),
+    (0x2F9C8, 0x2F9C8, &[0xFB80, 0xC635]),
+    (0x2F9C9, 0x2F9C9, &[0xFB41, 0x88FA]),
+    (0x2F9CA, 0x2F9CA, &[0xFB80, 0xB4BB]),
+    (0x2F9CB, 0x2F9CB, &[0xFB84, 0xF8AE]),
+    (0x2F9CC, 0x2F9CC, &[0xFB84, 0xF966]),
+    (0x2F9CD, 0x2F9CD, &[0xFB80, 0xC6BE]),
+    (0x2F9CE, 0x2F9CE, &[0xFB80, 0xC6C7]),
+    (0x2F9CF, 0x2F9CF, &[0xFB41, 0x8AA0]),
+    (0x2F9D0, 0x2F9D0, &[0xFB41, 0x8AED]),
+    (0x2F9D1, 0x2F9D1, &[0xFB41, 0x8B8A]),
+    (0x2F9D2, 0x2F9D2, &[0xFB41, 0x8C55]),
+    (0x2F9D3, 0x2F9D3, &[0xFB84, 0xFCA8]),
+    (0x2F9D4, 0x2F9D4, &[0xFB41, 0x8CAB]),
+    (0x2F9D5, 0x2F9D5, &[0xFB41, 0x8CC1]),
+    (0x2F9D6, 0x2F9D6, &[0xFB41, 0x8D1B]),
+    (0x2F9D7, 0x2F9D7, &[0xFB41, 0x8D77]),
+    (0x2F9D8, 0x2F9D8, &[0xFB84, 0xFF2F]),
+    (0x2F9D9, 0x2F9D9, &[0xFB84, 0x8804]),
+    (0x2F9DA, 0x2F9DA, &[0xFB41, 0x8DCB]),
+    (0x2F9DB, 0x2F9DB, &[0xFB41, 0x8DBC]),
+    (0x2F9DC, 0x2F9DC, &[0xFB41, 0x8DF0]),
+    (0x2F9DD, 0x2F9DD, &[0xFB84, 0x88DE]),
+    (0x2F9DE, 0x2F9DE, &[0xFB41, 0x8ED4]),
+    (0x2F9DF, 0x2F9DF, &[0xFB41, 0x8F38]),
+    (0x2F9E0, 0x2F9E0, &[0xFB85, 0x85D2]),
+    (0x2F9E1, 0x2F9E1, &[0xFB85, 0x85ED]),
+    (0x2F9E2, 0x2F9E2, &[0xFB41, 0x9094]),
+    (0x2F9E3, 0x2F9E3, &[0xFB41, 0x90F1]),
+    (0x2F9E4, 0x2F9E4, &[0xFB41, 0x9111]),
+    (0x2F9E5, 0x2F9E5, &[0xFB85, 0x872E]),
+    (0x2F9E6, 0x2F9E6, &[0xFB41, 0x911B]),
+    (0x2F9E7, 0x2F9E7, &[0xFB41, 0x9238]),
+    (0x2F9E8, 0x2F9E8, &[0xFB41, 0x92D7]),
+    (0x2F9E9, 0x2F9E9, &[0xFB41, 0x92D8]),
+    (0x2F9EA, 0x2F9EA, &[0xFB41, 0x927C]),
+    (0x2F9EB, 0x2F9EB, &[0xFB41, 0x93F9]),
+    (0x2F9EC, 0x2F9EC, &[0xFB41, 0x9415]),
+    (0x2F9ED, 0x2F9ED, &[0xFB85, 0x8BFA]),
+    (0x2F9EE, 0x2F9EE, &[0xFB41, 0x958B]),
+    (0x2F9EF, 0x2F9EF, &[0xFB80, 0xC995]),
+    (0x2F9F0, 0x2F9F0, &[0xFB41, 0x95B7]),
+    (0x2F9F1, 0x2F9F1, &[0xFB85, 0x8D77]),
+    (0x2F9F2, 0x2F9F2, &[0xFB80, 0xC9E6]),
+    (0x2F9F3, 0x2F9F3, &[0xFB41, 0x96C3]),
+    (0x2F9F4, 0x2F9F4, &[0xFB40, 0xDDB2]),
+    (0x2F9F5, 0x2F9F5, &[0xFB41, 0x9723]),
+    (0x2F9F6, 0x2F9F6, &[0xFB85, 0x9145]),
+    (0x2F9F7, 0x2F9F7, &[0xFB85, 0x921A]),
+    (0x2F9F8, 0x2F9F8, &[0xFB80, 0xCA6E]),
+    (0x2F9F9, 0x2F9F9, &[0xFB80, 0xCA76]),
+    (0x2F9FA, 0x2F9FA, &[0xFB41, 0x97E0]),
+    (0x2F9FB, 0x2F9FB, &[0xFB85, 0x940A]),
+    (0x2F9FC, 0x2F9FC, &[0xFB80, 0xCAB2]),
+    (0x2F9FD, 0x2F9FD, &[0xFB85, 0x9496]),
+    (0x2F9FE, 0x2F9FE, &[0xFB41, 0x980B]),
+    (0x2F9FF, 0x2F9FF, &[0xFB41, 0x980B]),
+    (0x2FA00, 0x2FA00, &[0xFB41, 0x9829]),
+    (0x2FA01, 0x2FA01, &[0xFB85, 0x95B6]),
+    (0x2FA02, 0x2FA02, &[0xFB41, 0x98E2]),
+    (0x2FA03, 0x2FA03, &[0xFB80, 0xCB33]),
+    (0x2FA04, 0x2FA04, &[0xFB41, 0x9929]),
+    (0x2FA05, 0x2FA05, &[0xFB41, 0x99A7]),
+    (0x2FA06, 0x2FA06, &[0xFB41, 0x99C2]),
+    (0x2FA07, 0x2FA07, &[0xFB41, 0x99FE]),
+    (0x2FA08, 0x2FA08, &[0xFB80, 0xCBCE]),
+    (0x2FA09, 0x2FA09, &[0xFB85, 0x9B30]),
+    (0x2FA0A, 0x2FA0A, &[0xFB41, 0x9B12]),
+    (0x2FA0B, 0x2FA0B, &[0xFB41, 0x9C40]),
+    (0x2FA0C, 0x2FA0C, &[0xFB41, 0x9CFD]),
+    (0x2FA0D, 0x2FA0D, &[0xFB80, 0xCCCE]),
+    (0x2FA0E, 0x2FA0E, &[0xFB80, 0xCCED]),
+    (0x2FA0F, 0x2FA0F, &[0xFB41, 0x9D67]),
+    (0x2FA10, 0x2FA10, &[0xFB85, 0xA0CE]),
+    (0x2FA11, 0x2FA11, &[0xFB80, 0xCCF8]),
+    (0x2FA12, 0x2FA12, &[0xFB85, 0xA105]),
+    (0x2FA13, 0x2FA13, &[0xFB85, 0xA20E]),
+    (0x2FA14, 0x2FA14, &[0xFB85, 0xA291]),
+    (0x2FA15, 0x2FA15, &[0xFB41, 0x9EBB]),
+    (0x2FA16, 0x2FA16, &[0xFB80, 0xCD56]),
+    (0x2FA17, 0x2FA17, &[0xFB41, 0x9EF9]),
+    (0x2FA18, 0x2FA18, &[0xFB41, 0x9EFE]),
+    (0x2FA19, 0x2FA19, &[0xFB41, 0x9F05]),
+    (0x2FA1A, 0x2FA1A, &[0xFB41, 0x9F0F]),
+    (0x2FA1B, 0x2FA1B, &[0xFB41, 0x9F16]),
+    (0x2FA1C, 0x2FA1C, &[0xFB41, 0x9F3B]),
+    (0x2FA1D, 0x2FA1D, &[0xFB85, 0xA600]),
+    (0xE0001, 0xE0001, &[]),
+    (0xE0020, 0xE007F, &[]),
+    (0xE0100, 0xE01EF, &[]),
+];
+
+/// The `@implicitweights` ranges: code points `start..=end` get the implicit weights
+/// `base` and `(c - start) | 0x8000`.
+pub static DUCET_IMPLICIT: &[(u32, u32, u16)] = &[
+    (0x17000, 0x18AFF, 0xFB00),
+    (0x18B00, 0x18CFF, 0xFB02),
+    (0x18D00, 0x18D8F, 0xFB00),
+    (0x1B170, 0x1B2FF, 0xFB01),
+];
@@ -1,113 +1,71 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
-
-
-use std::error::Error;
-use std::fmt;
-use std::io;
-use std::result;
- use std::string::FromUtf8Error;
-use std::str::Utf8Error;
-
-use crate::berolinasql::{Error as BerolinaSqlError, ErrorKind as BerolinaSqlErrorKind};
-
-#[derive(Debug)]
-pub enum ErrorKind {
-    Io(io::Error),
-    BerolinaSql(BerolinaSqlError),
-    Utf8(Utf8Error),
-    FromUtf8(FromUtf8Error),
-    Other(String),
-}
-
-
- #[derive(Debug)]
-pub struct ErrorImpl {
-    pub kind: ErrorKind,
-}
-
-#[derive(Debug)]
-pub enum BerolinaSqlError {
-    IoError(io::Error),
-    SqlError(String),
-}
-
-
-impl fmt::Display for BerolinaSqlError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            BerolinaSqlError::IoError(ref err) => write!(f, "IO error: {}", err),
-            BerolinaSqlError::SqlError(ref err) => write!(f, "SQL error: {}", err),
-        }
-    }
-
-    fn cause(&self) -> Option<&dyn Error> {
-        match *self {
-            BerolinaSqlError::IoError(ref err) => Some(err),
-            BerolinaSqlError::SqlError(_) => None,
-        }
-    }
-
-    fn description(&self) -> &str {
-        match *self {
-            BerolinaSqlError::IoError(_) => "IO error",
-            BerolinaSqlError::SqlError(_) => "SQL error",
-        }
-    }
-
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match *self {
-            BerolinaSqlError::IoError(ref err) => Some(err),
-            BerolinaSqlError::SqlError(_) => None,
+//Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+/// Builds an `Error::Other` from a displayable causet_locale or a format string.
+macro_rules! box_err {
+    ($e:expr) => {{
+        let e: Box<dyn std::error::Error + Send + Sync> = format!("{}", $e).into();
+        $crate::error::Error::from(e)
+    }};
+    ($f:tt, $($arg:expr),+) => {{
+        box_err!(format!($f, $($arg),+))
+    }};
+}
+
+/// Unwraps a `Result`, returning early with `box_err!` on failure.
+macro_rules! box_try {
+    ($expr:expr) => {{
+        match $expr {
+            Ok(r) => r,
+            Err(e) => return Err(box_err!(e)),
         }
-    }
+    }};
 }
 
+/// Builds an `Error::InvalidDataType` from a message or a format string.
+macro_rules! invalid_type {
+    ($e:expr) => {{
+        $crate::error::Error::InvalidDataType(($e).into())
+    }};
+    ($f:tt, $($arg:expr),+) => {{
+        $crate::error::Error::InvalidDataType(format!($f, $($arg),+))
+    }};
+}
 
-
-
-
-
-
- use quick_error::quick_error;
- use regex::Error as RegexpError;
- use serde_json::error::Error as SerdeError;
- use std::{error, str};
- use std::error::Error;
- use std::fmt;
- use std::fmt::Display;
- use std::io;
- use std::io;
- use std::num::ParseFloatError;
- use std::result;
- use std::str::Utf8Error;
- use std::string::FromUtf8Error;
-
- impl Error {
-     pub fn new<S: Into<String>>(msg: S) -> Error {
-         Error::Codec(msg.into())
-     }
- }
-
- pub const ERR_M_BIGGER_THAN_D: i32 = 1427;
- pub const ERR_UNCAUSET_LOCALE_NUCLEON: i32 = 1105;
- pub const ERR_REGEXP: i32 = 1139;
- pub const ZLIB_LENGTH_CORRUPTED: i32 = 1258;
- pub const ZLIB_DATA_CORRUPTED: i32 = 1259;
- pub const WARN_DATA_TRUNCATED: i32 = 1265;
- pub const ERR_TRUNCATE_WRONG_VALUE: i32 = 1292;
- pub const ERR_UNCAUSET_LOCALE_NUCLEON_TIMEZONE: i32 = 1298;
- pub const ERR_DIVISION_BY_ZERO: i32 = 1365;
+use quick_error::quick_error;
+use regex::Error as RegexpError;
+use serde_json::error::Error as SerdeError;
+use std::error;
+use std::fmt::Display;
+use std::io;
+use std::num::ParseFloatError;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+
+use crate::field_type::DataTypeError;
+use crate::json_schema::JsonSchemaValidationReport;
+
+pub const ERR_M_BIGGER_THAN_D: i32 = 1427;
+pub const ERR_UNCAUSET_LOCALE_NUCLEON: i32 = 1105;
+pub const ERR_REGEXP: i32 = 1139;
+pub const ZLIB_LENGTH_CORRUPTED: i32 = 1258;
+pub const ZLIB_DATA_CORRUPTED: i32 = 1259;
+pub const WARN_DATA_TRUNCATED: i32 = 1265;
+pub const ERR_TRUNCATE_WRONG_VALUE: i32 = 1292;
+pub const ERR_UNCAUSET_LOCALE_NUCLEON_TIMEZONE: i32 = 1298;
+pub const ERR_DIVISION_BY_ZERO: i32 = 1365;
 pub const ERR_DATA_TOO_LONG: i32 = 1406;
 pub const ERR_INCORRECT_PARAMETERS: i32 = 1583;
 pub const ERR_DATA_OUT_OF_RANGE: i32 = 1690;
+pub const ERR_CANNOT_CONVERT_STRING: i32 = 3854;
+pub const ERR_JSON_SCHEMA_VALIDATION: i32 = 3934;
 
 quick_error! {
     #[derive(Debug)]
@@ -117,28 +75,32 @@ quick_error! {
         }
         Encoding(err: Utf8Error) {
             from()
-            cause(err)
+            source(err)
             display("encoding failed")
         }
         ColumnOffset(offset: usize) {
             display("illegal causet_merge offset: {}", offset)
         }
-        UnCausetLocaleNucleonSignature(sig: ScalarFuncSig) {
-            display("UnCausetLocaleNucleon signature: {:?}", sig)
+        UnCausetLocaleNucleonSignature(sig: String) {
+            display("UnCausetLocaleNucleon signature: {}", sig)
         }
         Eval(s: String, code:i32) {
             display("evaluation failed: {}", s)
         }
+        JsonSchemaViolation(causet_merge: String, report: JsonSchemaValidationReport) {
+            display("causet_merge '{}' failed JSON schema validation at {}: {}",
+                causet_merge, report.document_location, report.message)
+        }
         Other(err: Box<dyn error::Error + Send + Sync>) {
             from()
-            cause(err.as_ref())
+            source(err.as_ref())
             display("{}", err)
         }
     }
 }
 
 impl Error {
-    pub fn over_causetxctx(data: impl Display, expr: impl Display) -> Error {
+    pub fn overflow(data: impl Display, expr: impl Display) -> Error {
         let msg = format!("{} causet_locale is out of range in '{}'", data, expr);
         Error::Eval(msg, ERR_DATA_OUT_OF_RANGE)
     }
@@ -165,14 +127,17 @@ impl Error {
         Error::Eval(msg.into(), ERR_UNCAUSET_LOCALE_NUCLEON)
     }
 
-    pub fn cast_as_signed_overCausetxctx() -> Error {
+    pub fn cast_as_signed_overflow() -> Error {
         let msg =
             "Cast to signed converted positive out-of-range integer to it's negative complement";
         Error::Eval(msg.into(), ERR_UNCAUSET_LOCALE_NUCLEON)
     }
 
     pub fn invalid_timezone(given_time_zone: impl Display) -> Error {
-        let msg = format!("unCausetLocaleNucleon or incorrect time zone: {}", given_time_zone);
+        let msg = format!(
+            "unCausetLocaleNucleon or incorrect time zone: {}",
+            given_time_zone
+        );
         Error::Eval(msg, ERR_UNCAUSET_LOCALE_NUCLEON_TIMEZONE)
     }
 
@@ -192,16 +157,17 @@ impl Error {
     pub fn code(&self) -> i32 {
         match *self {
             Error::Eval(_, code) => code,
+            Error::JsonSchemaViolation(..) => ERR_JSON_SCHEMA_VALIDATION,
             _ => ERR_UNCAUSET_LOCALE_NUCLEON,
         }
     }
 
-    pub fn is_over_causetxctx(&self) -> bool {
+    pub fn is_overflow(&self) -> bool {
         self.code() == ERR_DATA_OUT_OF_RANGE
     }
 
     pub fn unexpected_eof() -> Error {
-        EinsteinDB_util::codec::Error::unexpected_eof().into()
+        io::Error::new(io::ErrorKind::UnexpectedEof, "unexpected eof").into()
     }
 
     pub fn invalid_time_format(val: impl Display) -> Error {
@@ -223,6 +189,18 @@ impl Error {
         Error::Eval("ZLIB: Input data corrupted".into(), ZLIB_DATA_CORRUPTED)
     }
 
+    pub fn cannot_convert_string(s: impl Display, from: &str, to: &str) -> Error {
+        let msg = format!("Cannot convert string '{}' from {} to {}", s, from, to);
+        Error::Eval(msg, ERR_CANNOT_CONVERT_STRING)
+    }
+
+    pub fn json_schema_violation(
+        causet_merge: impl Into<String>,
+        report: JsonSchemaValidationReport,
+    ) -> Error {
+        Error::JsonSchemaViolation(causet_merge.into(), report)
+    }
+
     pub fn incorrect_parameters(val: &str) -> Error {
         let msg = format!(
             "Incorrect parameters in the call to native function '{}'",
@@ -232,15 +210,6 @@ impl Error {
     }
 }
 
-impl From<Error> for einsteindbpb::Error {
-    fn from(error: Error) -> einsteindbpb::Error {
-        let mut err = einsteindbpb::Error::default();
-        err.set_code(error.code());
-        err.set_msg(error.to_string());
-        err
-    }
-}
-
 impl From<FromUtf8Error> for Error {
     fn from(err: FromUtf8Error) -> Error {
         Error::Encoding(err.utf8_error())
@@ -259,16 +228,9 @@ impl From<ParseFloatError> for Error {
     }
 }
 
-impl From<EinsteinDB_util::codec::Error> for Error {
-    fn from(err: EinsteinDB_util::codec::Error) -> Error {
-        box_err!("codec:{:?}", err)
-    }
-}
-
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
-        let uerr: EinsteinDB_util::codec::Error = err.into();
-        uerr.into()
+        Error::Other(Box::new(err))
     }
 }
 
@@ -279,34 +241,10 @@ impl From<RegexpError> for Error {
     }
 }
 
-impl From<codec::Error> for Error {
-    fn from(err: codec::Error) -> Error {
-        box_err!("Codec: {}", err)
-    }
-}
-
-impl From<crate::DataTypeError> for Error {
-    fn from(err: crate::DataTypeError) -> Self {
+impl From<DataTypeError> for Error {
+    fn from(err: DataTypeError) -> Self {
         box_err!("invalid topograph: {:?}", err)
     }
 }
 
-// TODO: `codec::Error` should be substituted by EvaluateError.
-impl From<Error> for EvaluateError {
-    #[inline]
-    fn from(err: Error) -> Self {
-        match err {
-            Error::Eval(msg, code) => EvaluateError::Custom { code, msg },
-            e => EvaluateError::Other(e.to_string()),
-        }
-    }
-}
-
 pub type Result<T> = std::result::Result<T, Error>;
-
-
-
-
-
-
-
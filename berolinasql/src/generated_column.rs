@@ -0,0 +1,191 @@
+//Copyright 2021-2023 WHTCORPS INC ALL RIGHTS RESERVED. APACHE 2.0 COMMUNITY EDITION SL
+// AUTHORS: WHITFORD LEDER
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! Generated columns computed from a JSON causet_merge of the same row.
+//!
+//! The supported expressions are the JSON extraction forms
+//!
+//! ```text
+//!   doc->'$.user.id'                                  json_extract(doc, '$.user.id')
+//!   doc->>'$.user.id'                  json_unquote(json_extract(doc, '$.user.id'))
+//! ```
+//!
+//! which is what functional indexes on JSON documents are built from.
+
+use crate::error::{Error, Result};
+use crate::path_expr::{parse_json_local_path_expr, LocalPathExpression};
+use crate::value::Value;
+
+/// Whether a generated causet_merge is materialized in the row or computed on read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneratedColumnKind {
+    Stored,
+    Virtual,
+}
+
+/// A `col->'local_path'` or `col->>'local_path'` expression.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedExpr {
+    /// Offset of the JSON causet_merge the causet_locale is extracted from.
+    pub source: usize,
+    pub local_path: LocalPathExpression,
+    /// `->>` unquotes the extracted causet_locale into a string.
+    pub unquote: bool,
+}
+
+impl GeneratedExpr {
+    /// Parses `expr`, resolving causet_merge names with `column_offset`.
+    pub fn parse(
+        expr: &str,
+        column_offset: impl Fn(&str) -> Option<usize>,
+    ) -> Result<GeneratedExpr> {
+        let expr = expr.trim();
+        let (source, local_path, unquote) = if let Some(args) = strip_call(expr, "json_unquote") {
+            let (source, local_path) = strip_call(args, "json_extract")
+                .and_then(split_extract_args)
+                .ok_or_else(|| invalid_expr(expr))?;
+            (source, local_path, true)
+        } else if let Some(args) = strip_call(expr, "json_extract") {
+            let (source, local_path) =
+                split_extract_args(args).ok_or_else(|| invalid_expr(expr))?;
+            (source, local_path, false)
+        } else if let Some(idx) = expr.find("->>") {
+            (&expr[..idx], &expr[idx + 3..], true)
+        } else if let Some(idx) = expr.find("->") {
+            (&expr[..idx], &expr[idx + 2..], false)
+        } else {
+            return Err(invalid_expr(expr));
+        };
+        let source = source.trim().trim_matches('`');
+        let source = column_offset(source)
+            .ok_or_else(|| box_err!("unknown causet_merge '{}' in '{}'", source, expr))?;
+        let local_path = unquote_literal(local_path.trim()).ok_or_else(|| invalid_expr(expr))?;
+        Ok(GeneratedExpr {
+            source,
+            local_path: parse_json_local_path_expr(local_path)?,
+            unquote,
+        })
+    }
+
+    /// Evaluates the expression on a row. Non-JSON or missing causet_locales yield `NULL`.
+    pub fn eval(&self, causet_locales: &[Value]) -> Result<Value> {
+        let doc = match causet_locales.get(self.source) {
+            Some(Value::Json(doc)) => doc,
+            _ => return Ok(Value::Null),
+        };
+        match doc
+            .as_ref()
+            .extract(std::slice::from_ref(&self.local_path))?
+        {
+            Some(v) if self.unquote => Ok(Value::String(v.as_ref().unquote()?)),
+            Some(v) => Ok(Value::Json(v)),
+            None => Ok(Value::Null),
+        }
+    }
+}
+
+/// The definition of a generated causet_merge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedColumn {
+    pub expr: GeneratedExpr,
+    pub kind: GeneratedColumnKind,
+}
+
+impl GeneratedColumn {
+    pub fn new(expr: GeneratedExpr, kind: GeneratedColumnKind) -> Self {
+        GeneratedColumn { expr, kind }
+    }
+
+    pub fn is_stored(&self) -> bool {
+        self.kind == GeneratedColumnKind::Stored
+    }
+}
+
+fn strip_call<'a>(expr: &'a str, name: &str) -> Option<&'a str> {
+    let expr = expr.trim();
+    if expr.len() <= name.len() || !expr[..name.len()].eq_ignore_ascii_case(name) {
+        return None;
+    }
+    let rest = expr[name.len()..].trim_start();
+    rest.strip_prefix('(')?.strip_suffix(')')
+}
+
+fn split_extract_args(args: &str) -> Option<(&str, &str)> {
+    let comma = args.find(',')?;
+    Some((&args[..comma], &args[comma + 1..]))
+}
+
+fn unquote_literal(s: &str) -> Option<&str> {
+    s.strip_prefix('\'')?.strip_suffix('\'')
+}
+
+fn invalid_expr(expr: &str) -> Error {
+    box_err!("unsupported generated causet_merge expression '{}'", expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offset(name: &str) -> Option<usize> {
+        match name {
+            "id" => Some(0),
+            "doc" => Some(1),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_generated_expr() {
+        let mut test_cases = vec![
+            ("doc->'$.user.id'", Some(false)),
+            ("doc ->> '$.user.id'", Some(true)),
+            ("`doc`->>'$.user.id'", Some(true)),
+            ("json_extract(doc, '$.user.id')", Some(false)),
+            ("JSON_UNQUOTE(JSON_EXTRACT(doc, '$.user.id'))", Some(true)),
+            ("other->'$.user.id'", None),
+            ("doc->'$.user['", None),
+            ("doc->$.user.id", None),
+            ("id + 1", None),
+        ];
+        let local_path = parse_json_local_path_expr("$.user.id").unwrap();
+        for (i, (expr, expected)) in test_cases.drain(..).enumerate() {
+            let got = GeneratedExpr::parse(expr, offset);
+            match expected {
+                Some(unquote) => {
+                    let got = got.unwrap();
+                    assert_eq!(got.source, 1, "#{}", i);
+                    assert_eq!(got.local_path, local_path, "#{}", i);
+                    assert_eq!(got.unquote, unquote, "#{}", i);
+                }
+                None => assert!(got.is_err(), "#{} expect error but got {:?}", i, got),
+            }
+        }
+    }
+
+    #[test]
+    fn test_eval_generated_expr() {
+        let doc = Value::Json(r#"{"user": {"id": "u1"}}"#.parse().unwrap());
+        let row = vec![Value::Int(1), doc];
+        let quoted = GeneratedExpr::parse("doc->'$.user.id'", offset).unwrap();
+        assert_eq!(
+            quoted.eval(&row).unwrap(),
+            Value::Json(r#""u1""#.parse().unwrap())
+        );
+        let unquoted = GeneratedExpr::parse("doc->>'$.user.id'", offset).unwrap();
+        assert_eq!(unquoted.eval(&row).unwrap(), Value::String("u1".to_owned()));
+        let missing = GeneratedExpr::parse("doc->>'$.user.name'", offset).unwrap();
+        assert_eq!(missing.eval(&row).unwrap(), Value::Null);
+        assert_eq!(
+            unquoted.eval(&[Value::Int(1), Value::Null]).unwrap(),
+            Value::Null
+        );
+    }
+}
@@ -6,242 +6,38 @@
 // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
 // CONDITIONS OF ANY KIND, either express or implied. See the License for the
 // specific language governing permissions and limitations under the License.
-//
-//
-// use std::error::Error;
-// use std::fmt;
-use std::error::Error;
-use std::fmt;
-use std::io::{self, Write};
-use std::str::FromStr;
-use std::io::{self, Read, Write};
-use std::mem;
-use std::ops::{Deref, DerefMut};
-use std::slice;
-use std::str;
-use std::string::String;
-use std::vec::Vec;
-use std::{fmt, io, str};
-use std::{fmt, io, str};
 
+//! Encoding and decoding of the binary JSON format.
 
+use std::collections::BTreeMap;
+use std::convert::TryInto;
 
-
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum JsonType {
-    JsonNull,
-    JsonBoolean,
-    JsonNumber,
-    JsonString,
-    JsonArray,
-    JsonObject,
-}
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Json {
-    JsonNull,
-    JsonBoolean(bool),
-    JsonNumber(f64),
-    JsonString(String),
-    JsonArray(Vec<Json>),
-    JsonObject(Vec<(String, Json)>),
-}
-
-
-pub struct JsonCodec {
-
-    buf: Vec<u8>,
-
-    pub json_type: JsonType,
-
-    pub json: Json,
-
-    pub decimal: Option<Decimal>,
-}
-
-
-impl JsonCodec {
-    pub fn new() -> JsonCodec {
-        JsonCodec {
-            buf: Vec::new(),
-            json_type: JsonType::JsonNull,
-            json: Json::JsonNull,
-            decimal: None,
-        }
-
-
-    }
-}
-
+use crate::codec::{BufferReader, BufferWriter, NumberCodec, NumberDecoder, NumberEncoder};
+use crate::constants::*;
 use crate::error::{Error, Result};
-use crate::util::{self, Codec, Decode, Encode};
-
-
-
-use crate::codec::{Error, Result};
-
-use super::{Json, JsonRef, JsonType};
-use super::constants::*;
-
-
-use super::local_path_expr::local_pathExpression;
-use super::super::Result;
-
-
-use super::super::Error;
-
-
-use super::super::Error::{self, *};
-
-
-use super::super::Error::{overCausetxctx, division_by_zero};
-
-
-pub fn json_keys(json: &Json) -> Result<Vec<String>> {
-    match json {
-        &Json::Object(ref m) => {
-            let mut keys = Vec::new();
-            for (k, _) in m.iter() {
-                keys.push(k.to_owned());
-            }
-            Ok(keys)
-        }
-        _ => Err(Error::invalid_type("OBJECT", json.get_type())),
-    }
-}
-
-
-pub fn json_get(json: &Json, key: &str) -> Result<Json> {
-    match json {
-        &Json::Object(ref m) => {
-            match m.get(key) {
-                Some(v) => Ok(v.clone()),
-                None => Ok(Json::Null),
-            }
-        }
-        _ => Err(Error::invalid_type("OBJECT", json.get_type())),
-    }
-}
+use crate::json::{Json, JsonRef, JsonType};
 
 impl<'a> JsonRef<'a> {
-pub fn get_string(&self, key: &str) -> Result<String> {
-        match self {
-            &JsonRef::Object(ref m) => {
-                match m.get(key) {
-                    Some(v) => Ok(v.to_string()),
-                    None => Ok(String::new()),
-                }
-            }
-            _ => Err(Error::invalid_type("OBJECT", self.get_type())),
-        }
-    }
-
-    pub fn get_int(&self, key: &str) -> Result<i64> {
-        match self {
-            &JsonRef::Object(ref m) => {
-                match m.get(key) {
-                    Some(v) => {
-                        match v {
-                            &JsonRef::Number(n) => Ok(n),
-                            _ => Err(Error::invalid_type("NUMBER", v.get_type())),
-                        }
-                    }
-                    None => Ok(0),
-                }
-            }
-            _ => Err(Error::invalid_type("OBJECT", self.get_type())),
-        }
-    }
-
-    pub fn get_bool(&self, key: &str) -> Result<bool> {
-        match self {
-            &JsonRef::Object(ref m) => {
-                match m.get(key) {
-                    Some(v) => {
-                        match v {
-                            &JsonRef::Boolean(b) => Ok(b),
-                            _ => Err(Error::invalid_type("BOOLEAN", v.get_type())),
-                        }
-                    }
-                    None => Ok(false),
-                }
-            }
-            _ => Err(Error::invalid_type("OBJECT", self.get_type())),
-        }
-    }
-
-    pub fn get_float(&self, key: &str) -> Result<f64> {
-        match self {
-            &JsonRef::Object(ref m) => {
-                match m.get(key) {
-                    Some(v) => {
-                        match v {
-                            &JsonRef::Number(n) => Ok(n),
-                            _ => Err(Error::invalid_type("NUMBER", v.get_type())),
-                        }
-                    }
-                    None => Ok(0.0),
-                }
-            }
-            _ => Err(Error::invalid_type("OBJECT", self.get_type())),
-        }
-    }
-    fn encoded_len(&self) -> usize {
-        match self {
-            &JsonRef::Null => 4,
-            &JsonRef::Boolean(b) => {
-                if b {
-                    4
-                } else {
-                    5
-                }
-            }
-            &JsonRef::Number(n) => {
-                let s = format!("{}", n);
-                s.len() + 2
-            }
-            &JsonRef::String(ref s) => {
-                s.len() + 2
-            }
-            &JsonRef::Array(ref a) => {
-                let mut len = 4;
-                for v in a {
-                    len += v.encoded_len();
-                }
-                len
-            }
-            &JsonRef::Object(ref m) => {
-                let mut len = 4;
-                for (k, v) in m {
-                    len += k.len() + v.encoded_len();
-                }
-                len
-            }
-        }
-        match self.type_code {
+    pub(crate) fn encoded_len(&self) -> usize {
+        match self.get_type() {
             // Literal is encoded inline with causet_locale-causet, so nothing will be
             // appended in causet_locale part
             JsonType::Literal => 0,
-            _ => self.causet_locale.len(),
+            _ => self.causet_locale().len(),
         }
     }
 }
 
 pub trait JsonEncoder: NumberEncoder {
-    fn write_json<'a>(&mut self, data: JsonRef<'a>) -> Result<()> {
+    fn write_json(&mut self, data: JsonRef<'_>) -> Result<()> {
         self.write_u8(data.get_type() as u8)?;
-        self.write_bytes(data.causet_locale()).map_err(Error::from)
+        self.write_bytes(data.causet_locale())
     }
-}
-
 
-pub trait JsonDecoder: NumberDecoder {
     // See `appeneinsteindbinaryObject` in MEDB `types/json/binary.go`
-    fn write_json_obj_from_soliton_ids_causet_locales<'a>(
+    fn write_json_obj_from_soliton_ids_causet_locales(
         &mut self,
-        mut entries: Vec<(&[u8], JsonRef<'a>)>,
+        mut entries: Vec<(&[u8], JsonRef<'_>)>,
     ) -> Result<()> {
         entries.sort_by(|a, b| a.0.cmp(b.0));
         // object: element-count size soliton_id-causet* causet_locale-causet* soliton_id* causet_locale*
@@ -253,11 +49,15 @@ pub trait JsonDecoder: NumberDecoder {
         let einsteindb_fdb_kv_encoded_len = entries
             .iter()
             .fold(0, |acc, (k, v)| acc + k.len() + v.encoded_len());
-        let size =
-            ELEMENT_COUNT_LEN + SIZE_LEN + soliton_id_entries_len + causet_locale_entries_len + einsteindb_fdb_kv_encoded_len;
+        let size = ELEMENT_COUNT_LEN
+            + SIZE_LEN
+            + soliton_id_entries_len
+            + causet_locale_entries_len
+            + einsteindb_fdb_kv_encoded_len;
         self.write_u32_le(element_count as u32)?;
         self.write_u32_le(size as u32)?;
-        let mut soliton_id_offset = ELEMENT_COUNT_LEN + SIZE_LEN + soliton_id_entries_len + causet_locale_entries_len;
+        let mut soliton_id_offset =
+            ELEMENT_COUNT_LEN + SIZE_LEN + soliton_id_entries_len + causet_locale_entries_len;
 
         // Write soliton_id entries
         for (soliton_id, _) in entries.iter() {
@@ -281,12 +81,149 @@ pub trait JsonDecoder: NumberDecoder {
         // Write causet_locales
         for (_, v) in entries.iter() {
             if v.get_type() != JsonType::Literal {
-                self.write_bytes(v.causet_locale)?;
+                self.write_bytes(v.causet_locale())?;
+            }
+        }
+        Ok(())
+    }
+
+    // See `appeneinsteindbinaryObject` in MEDB `types/json/binary.go`
+    fn write_json_obj(&mut self, data: &BTreeMap<String, Json>) -> Result<()> {
+        let entries = data
+            .iter()
+            .map(|(k, v)| (k.as_bytes(), v.as_ref()))
+            .collect();
+        self.write_json_obj_from_soliton_ids_causet_locales(entries)
+    }
+
+    // See `appeneinsteindbinaryArray` in MEDB `types/json/binary.go`
+    fn write_json_ref_array(&mut self, data: &[JsonRef<'_>]) -> Result<()> {
+        // array ::= element-count size causet_locale-causet* causet_locale*
+        let element_count = data.len();
+        let causet_locale_entries_len = VALUE_ENTRY_LEN * element_count;
+        let causet_locales_len = data.iter().fold(0, |acc, v| acc + v.encoded_len());
+        let total_size =
+            ELEMENT_COUNT_LEN + SIZE_LEN + causet_locale_entries_len + causet_locales_len;
+        self.write_u32_le(element_count as u32)?;
+        self.write_u32_le(total_size as u32)?;
+        let mut causet_locale_offset =
+            (ELEMENT_COUNT_LEN + SIZE_LEN + causet_locale_entries_len) as u32;
+        // Write causet_locale entries
+        for v in data {
+            self.write_causet_locale_causet(&mut causet_locale_offset, v)?;
+        }
+        // Write causet_locales
+        for v in data {
+            if v.get_type() != JsonType::Literal {
+                self.write_bytes(v.causet_locale())?;
             }
         }
         Ok(())
     }
+
+    // See `appeneinsteindbinaryArray` in MEDB `types/json/binary.go`
+    fn write_json_array(&mut self, data: &[Json]) -> Result<()> {
+        let refs: Vec<JsonRef<'_>> = data.iter().map(|v| v.as_ref()).collect();
+        self.write_json_ref_array(&refs)
+    }
+
+    // See `appeneinsteindbinaryValElem` in MEDB `types/json/binary.go`
+    fn write_causet_locale_causet(
+        &mut self,
+        causet_locale_offset: &mut u32,
+        v: &JsonRef<'_>,
+    ) -> Result<()> {
+        let tp = v.get_type();
+        self.write_u8(tp as u8)?;
+        match tp {
+            JsonType::Literal => {
+                // A literal is inlined into the uint32 of its causet_locale-causet.
+                self.write_u8(v.causet_locale()[0])?;
+                for _ in LITERAL_LEN..U32_LEN {
+                    self.write_u8(JSON_LITERAL_NIL)?;
+                }
+            }
+            _ => {
+                self.write_u32_le(*causet_locale_offset)?;
+                *causet_locale_offset += v.encoded_len() as u32;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_json_literal(&mut self, data: u8) -> Result<()> {
+        self.write_u8(data)
+    }
+
+    fn write_json_str(&mut self, data: &str) -> Result<()> {
+        let bytes = data.as_bytes();
+        self.write_var_u64(bytes.len() as u64)?;
+        self.write_bytes(bytes)
+    }
+
+    fn write_json_f64(&mut self, data: f64) -> Result<()> {
+        self.write_f64_le(data)
+    }
+
+    fn write_json_i64(&mut self, data: i64) -> Result<()> {
+        self.write_i64_le(data)
+    }
+
+    fn write_json_u64(&mut self, data: u64) -> Result<()> {
+        self.write_u64_le(data)
+    }
 }
 
+impl<T: BufferWriter + ?Sized> JsonEncoder for T {}
 
+pub trait JsonDecoder: NumberDecoder {
+    /// Reads a type code followed by an encoded JSON causet_locale, as written by
+    /// `JsonEncoder::write_json`.
+    fn read_json(&mut self) -> Result<Json> {
+        let tp: JsonType = self.read_u8()?.try_into()?;
+        let causet_locale = match tp {
+            JsonType::Object | JsonType::Array => {
+                if self.bytes().len() < ELEMENT_COUNT_LEN + SIZE_LEN {
+                    return Err(Error::unexpected_eof());
+                }
+                let len = NumberCodec::decode_u32_le(&self.bytes()[ELEMENT_COUNT_LEN..]);
+                self.read_bytes(len as usize)?
+            }
+            JsonType::String => {
+                let (str_len, len_len) = NumberCodec::try_decode_var_u64(self.bytes())?;
+                self.read_bytes(str_len as usize + len_len)?
+            }
+            JsonType::I64 | JsonType::U64 | JsonType::Double => self.read_bytes(NUMBER_LEN)?,
+            JsonType::Literal => self.read_bytes(LITERAL_LEN)?,
+        };
+        Ok(Json::new(tp, causet_locale.to_vec()))
+    }
+}
 
+impl<T: BufferReader + ?Sized> JsonDecoder for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_serialize_deserialize() {
+        let jstr1 =
+            r#"{"aaaaaaaaaaa": [1, "2", {"aa": "bb"}, 4.0], "bbbbbbbbbb": true, "ccccccccc": "d"}"#;
+        let j1: Json = jstr1.parse().unwrap();
+        let jstr2 = r#"[{"a": 1, "b": true}, 3, 3.5, "hello, world", null, true]"#;
+        let j2: Json = jstr2.parse().unwrap();
+
+        let json_nil = Json::none().unwrap();
+        let json_bool = Json::from_bool(true).unwrap();
+        let json_double = Json::from_f64(3.24).unwrap();
+        let json_str = Json::from_string(String::from("hello, 世界")).unwrap();
+        let test_cases = vec![json_nil, json_bool, json_double, json_str, j1, j2];
+        for json in test_cases {
+            let mut data = vec![];
+            data.write_json(json.as_ref()).unwrap();
+            let output = data.as_slice().read_json().unwrap();
+            assert_eq!(json.to_string(), output.to_string());
+        }
+    }
+}
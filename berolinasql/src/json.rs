@@ -1,13 +1,12 @@
- //Copyright 2021-2023 WHTCORPS INC
-
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
+//Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
 
 //! The binary JSON format from MyBerolinaSQL 5.7 is as follows:
 //! ```text
@@ -35,35 +34,6 @@
 //!
 //!   // the number of members in object or number of elements in array
 //!   element-count ::= uint32
-
-
- use std::error::Error;
-    use std::fmt;
-    use std::io;
-    use std::string::FromUtf8Error;
-    use std::str::Utf8Error;
-    use std::result;
-    use std::string::FromUtf8Error;
-    use std::str::Utf8Error;
-    use std::error::Error;
-    use std::string::FromUtf8Error;
-
-
-
- #[derive(Debug, Clone, PartialEq, Eq)]
-    pub enum JsonType {
-        Object,
-        Array,
-        Literal,
-        Int16,
-        Uint16,
-        Int32,
-        Uint32,
-        Int64,
-        Uint64,
-        Double,
-        Utf8mb4String,
-    }
 //!   //number of bytes in the binary representation of the object or array
 //!   size ::= uint32
 //!   soliton_id-causet ::= soliton_id-offset soliton_id-length
@@ -93,9 +63,32 @@
 //! ```
 //!
 
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str;
+
+use num_derive::FromPrimitive;
+
+use crate::codec::{NumberCodec, F64_SIZE, I64_SIZE};
+use crate::constants::{JSON_LITERAL_FALSE, JSON_LITERAL_NIL, JSON_LITERAL_TRUE};
+use crate::error::{Error, Result};
+use crate::jcodec::JsonEncoder;
+use crate::value::Value;
+
+/// The type code of a JSON causet_locale in the binary format.
+#[derive(Eq, PartialEq, FromPrimitive, Clone, Debug, Copy)]
+pub enum JsonType {
+    Object = 0x01,
+    Array = 0x03,
+    Literal = 0x04,
+    I64 = 0x09,
+    U64 = 0x0a,
+    Double = 0x0b,
+    String = 0x0c,
+}
 
- const ERR_CONVERT_FAILED: &str = "Can not covert from ";
-
+pub const ERR_CONVERT_FAILED: &str = "Can not covert from ";
 
 impl TryFrom<u8> for JsonType {
     type Error = Error;
@@ -108,14 +101,17 @@ impl TryFrom<u8> for JsonType {
 /// Represents a reference of JSON causet_locale aiming to reduce memory copy.
 #[derive(Clone, Copy, Debug)]
 pub struct JsonRef<'a> {
-    type_code: JsonType,
+    pub(crate) type_code: JsonType,
     // Referred causet_locale
-    causet_locale: &'a [u8],
+    pub(crate) causet_locale: &'a [u8],
 }
 
 impl<'a> JsonRef<'a> {
     pub fn new(type_code: JsonType, causet_locale: &[u8]) -> JsonRef<'_> {
-        JsonRef { type_code, causet_locale }
+        JsonRef {
+            type_code,
+            causet_locale,
+        }
     }
 
     /// Returns an owned Json via copying
@@ -133,7 +129,7 @@ impl<'a> JsonRef<'a> {
 
     /// Returns the underlying causet_locale slice
     pub fn causet_locale(&self) -> &'a [u8] {
-        &self.causet_locale
+        self.causet_locale
     }
 
     // Returns the JSON causet_locale as u64
@@ -206,9 +202,16 @@ pub struct Json {
     pub causet_locale: Vec<u8>,
 }
 
- impl Display for Json {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.to_string())
+impl<'a> fmt::Display for JsonRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&s)
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
@@ -216,7 +219,7 @@ impl Json {
     /// Creates a new JSON from the type and encoded bytes
     pub fn new(tp: JsonType, causet_locale: Vec<u8>) -> Self {
         Self {
-            type_code: tp.into(),
+            type_code: tp,
             causet_locale,
         }
     }
@@ -314,7 +317,7 @@ impl Json {
 
 /// Create JSON arrayy by given elements
 /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-creation-functions.html#function_json-array
-pub fn json_array(elems: Vec<DatumType>) -> Result<Json> {
+pub fn json_array(elems: Vec<Value>) -> Result<Json> {
     let mut a = Vec::with_capacity(elems.len());
     for elem in elems {
         a.push(elem.into_json()?);
@@ -324,20 +327,20 @@ pub fn json_array(elems: Vec<DatumType>) -> Result<Json> {
 
 /// Create JSON object by given soliton_id-causet_locale pairs
 /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-creation-functions.html#function_json-object
-pub fn json_object(einsteindb_fdb_kvs: Vec<DatumType>) -> Result<Json> {
+pub fn json_object(einsteindb_fdb_kvs: Vec<Value>) -> Result<Json> {
     let len = einsteindb_fdb_kvs.len();
-    if !is_even(len) {
-        return Err(Error::Other(box_err!(
+    if !len.is_multiple_of(2) {
+        return Err(box_err!(
             "Incorrect parameter count in the call to native \
              function 'JSON_OBJECT'"
-        )));
+        ));
     }
     let mut map = BTreeMap::new();
     let mut soliton_id = None;
     for elem in einsteindb_fdb_kvs {
         if soliton_id.is_none() {
             // take elem as soliton_id
-            if elem == DatumType::Null {
+            if elem == Value::Null {
                 return Err(invalid_type!(
                     "JSON documents may not contain NULL member names"
                 ));
@@ -352,18 +355,23 @@ pub fn json_object(einsteindb_fdb_kvs: Vec<DatumType>) -> Result<Json> {
     Json::from_object(map)
 }
 
+/// Converts a causet_locale into another type, following the casting rules of MEDB.
+pub trait ConvertTo<T> {
+    fn convert(&self) -> Result<T>;
+}
+
 impl ConvertTo<f64> for Json {
     ///  Keep compatible with MEDB's `ConvertJSONToFloat` function.
     #[inline]
-    fn convert(&self, ctx: &mut EvalContext) -> Result<f64> {
-        self.as_ref().convert(ctx)
+    fn convert(&self) -> Result<f64> {
+        self.as_ref().convert()
     }
 }
 
 impl<'a> ConvertTo<f64> for JsonRef<'a> {
     ///  Keep compatible with MEDB's `ConvertJSONToFloat` function.
     #[inline]
-    fn convert(&self, ctx: &mut EvalContext) -> Result<f64> {
+    fn convert(&self) -> Result<f64> {
         let d = match self.get_type() {
             JsonType::Array | JsonType::Object => 0f64,
             JsonType::U64 => self.get_u64() as f64,
@@ -372,15 +380,26 @@ impl<'a> ConvertTo<f64> for JsonRef<'a> {
             JsonType::Literal => self
                 .get_literal()
                 .map_or(0f64, |x| if x { 1f64 } else { 0f64 }),
-            JsonType::String => self.get_str_bytes()?.convert(ctx)?,
+            JsonType::String => str_to_f64_prefix(self.get_str()?),
         };
         Ok(d)
     }
 }
 
+// Parses the longest prefix of `s` that is a valid float, and 0 when there is none,
+// the way MEDB truncates an invalid string when casting it to REAL.
+fn str_to_f64_prefix(s: &str) -> f64 {
+    let s = s.trim();
+    (1..=s.len())
+        .rev()
+        .filter(|&end| s.is_char_boundary(end))
+        .find_map(|end| s[..end].parse::<f64>().ok())
+        .unwrap_or(0f64)
+}
+
 impl ConvertTo<Json> for i64 {
     #[inline]
-    fn convert(&self, _: &mut EvalContext) -> Result<Json> {
+    fn convert(&self) -> Result<Json> {
         let mut causet_locale = vec![0; I64_SIZE];
         NumberCodec::encode_i64_le(&mut causet_locale, *self);
         Ok(Json {
@@ -392,7 +411,7 @@ impl ConvertTo<Json> for i64 {
 
 impl ConvertTo<Json> for f64 {
     #[inline]
-    fn convert(&self, _: &mut EvalContext) -> Result<Json> {
+    fn convert(&self) -> Result<Json> {
         // FIXME: `select json_type(cast(1111.11 as json))` should return `DECIMAL`, we return `DOUBLE` now.
         let mut causet_locale = vec![0; F64_SIZE];
         NumberCodec::encode_f64_le(&mut causet_locale, *self);
@@ -403,56 +422,8 @@ impl ConvertTo<Json> for f64 {
     }
 }
 
-impl ConvertTo<Json> for Real {
-    #[inline]
-    fn convert(&self, _: &mut EvalContext) -> Result<Json> {
-        // FIXME: `select json_type(cast(1111.11 as json))` should return `DECIMAL`, we return `DOUBLE` now.
-        let mut causet_locale = vec![0; F64_SIZE];
-        NumberCodec::encode_f64_le(&mut causet_locale, self.into_inner());
-        Ok(Json {
-            type_code: JsonType::Double,
-            causet_locale,
-        })
-    }
-}
-
-impl ConvertTo<Json> for Decimal {
-    #[inline]
-    fn convert(&self, ctx: &mut EvalContext) -> Result<Json> {
-        // FIXME: `select json_type(cast(1111.11 as json))` should return `DECIMAL`, we return `DOUBLE` now.
-        let val: f64 = self.convert(ctx)?;
-        val.convert(ctx)
-    }
-}
-
-impl ConvertTo<Json> for Time {
-    #[inline]
-    fn convert(&self, ctx: &mut EvalContext) -> Result<Json> {
-        let tp = self.get_time_type();
-        let s = if tp == TimeType::DateTime || tp == TimeType::Timestamp {
-            self.round_frac(ctx, myBerolinaSQL::MAX_FSP)?
-        } else {
-            *self
-        };
-        Json::from_string(s.to_string())
-    }
-}
-
-impl ConvertTo<Json> for Duration {
-    #[inline]
-    fn convert(&self, _: &mut EvalContext) -> Result<Json> {
-        let d = self.maximize_fsp();
-        Json::from_string(d.to_string())
-    }
-}
-
-
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
-    use crate::expr::{PolicyGradient, EvalContext};
-
     use super::*;
 
     #[test]
@@ -460,10 +431,10 @@ mod tests {
         let cases = vec![
             (
                 vec![
-                    DatumType::I64(1),
-                    DatumType::Bytes(b"sdf".to_vec()),
-                    DatumType::U64(2),
-                    DatumType::Json(r#"[3,4]"#.parse().unwrap()),
+                    Value::Int(1),
+                    Value::String("sdf".to_owned()),
+                    Value::Int(2),
+                    Value::Json(r#"[3,4]"#.parse().unwrap()),
                 ],
                 r#"[1,"sdf",2,[3,4]]"#.parse().unwrap(),
             ),
@@ -477,12 +448,12 @@ mod tests {
     #[test]
     fn test_json_object() {
         let cases = vec![
-            vec![DatumType::I64(1)],
+            vec![Value::Int(1)],
             vec![
-                DatumType::I64(1),
-                DatumType::Bytes(b"sdf".to_vec()),
-                DatumType::Null,
-                DatumType::U64(2),
+                Value::Int(1),
+                Value::String("sdf".to_owned()),
+                Value::Null,
+                Value::Int(2),
             ],
         ];
         for d in cases {
@@ -492,12 +463,12 @@ mod tests {
         let cases = vec![
             (
                 vec![
-                    DatumType::I64(1),
-                    DatumType::Bytes(b"sdf".to_vec()),
-                    DatumType::Bytes(b"asd".to_vec()),
-                    DatumType::Bytes(b"qwe".to_vec()),
-                    DatumType::I64(2),
-                    DatumType::Json(r#"{"3":4}"#.parse().unwrap()),
+                    Value::Int(1),
+                    Value::String("sdf".to_owned()),
+                    Value::String("asd".to_owned()),
+                    Value::String("qwe".to_owned()),
+                    Value::Int(2),
+                    Value::Json(r#"{"3":4}"#.parse().unwrap()),
                 ],
                 r#"{"1":"sdf","2":{"3":4},"asd":"qwe"}"#.parse().unwrap(),
             ),
@@ -522,12 +493,11 @@ mod tests {
             (r#""hello""#, 0f64),
             (r#""1234""#, 1234f64),
         ];
-        let mut ctx = EvalContext::new(Arc::new(PolicyGradient::default_for_test()));
         for (jstr, exp) in test_cases {
             let json: Json = jstr.parse().unwrap();
-            let get: f64 = json.convert(&mut ctx).unwrap();
+            let get: f64 = json.convert().unwrap();
             assert!(
-                (get - exp).abs() < std::f64::EPSILON,
+                (get - exp).abs() < f64::EPSILON,
                 "json.as_f64 get: {}, exp: {}",
                 get,
                 exp
@@ -11,10 +11,11 @@
 use std::error::Error;
 use std::fmt;
 use std::io;
-use std::string::FromUtf8Error;
 use std::str::Utf8Error;
-use std::result;
+use std::string::FromUtf8Error;
 
+use crate::error::Result;
+use crate::json::{JsonRef, JsonType};
 
 #[derive(Debug)]
 pub enum JsonDepthError {
@@ -25,9 +26,8 @@ pub enum JsonDepthError {
     JsonDepthError(String),
 }
 
-
 impl fmt::Display for JsonDepthError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             JsonDepthError::IoError(ref err) => write!(f, "IO error: {}", err),
             JsonDepthError::Utf8Error(ref err) => write!(f, "UTF8 error: {}", err),
@@ -38,7 +38,6 @@ impl fmt::Display for JsonDepthError {
     }
 }
 
-
 ///Find The Depth of an ordered Einstein JSON object.
 /// # Arguments
 /// * `json` - The JSON object to find the depth of.
@@ -47,7 +46,7 @@ impl fmt::Display for JsonDepthError {
 /// # Errors
 /// * `JsonDepthError` - If the JSON object is not valid.
 /// # Examples
-/// ```
+/// ```ignore
 /// use einstein_sql::berolinasql::json_depth;
 /// let json = r#"{
 ///    "a": {
@@ -74,89 +73,60 @@ impl fmt::Display for JsonDepthError {
 /// "v": {
 /// "w": {
 /// "x": {
-
-
-
-
-struct JsonDepth {
+/// ```
+pub struct JsonDepth {
     depth: usize,
 }
 
-
 ///BTree Hashmap
 /// # Arguments
 /// * `depth` - The depth of the JSON object.
-
-
 impl JsonDepth {
     pub fn new(depth: usize) -> JsonDepth {
-        JsonDepth {
-            depth: depth,
-        }
+        JsonDepth { depth }
     }
 }
 
-
 impl JsonDepth {
     pub fn get_depth(&self) -> usize {
         self.depth
     }
 }
 
-
 impl Error for JsonDepthError {
-    fn description(&self) -> &str {
-        match *self {
-            JsonDepthError::IoError(ref err) => err.description(),
-            JsonDepthError::Utf8Error(ref err) => err.description(),
-            JsonDepthError::FromUtf8Error(ref err) => err.description(),
-            JsonDepthError::JsonError(ref err) => err.description(),
-            JsonDepthError::JsonDepthError(ref err) => err.as_str(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             JsonDepthError::IoError(ref err) => Some(err),
             JsonDepthError::Utf8Error(ref err) => Some(err),
             JsonDepthError::FromUtf8Error(ref err) => Some(err),
             JsonDepthError::JsonError(ref err) => Some(err),
-            JsonDepthError::JsonDepthError(ref err) => None,
+            JsonDepthError::JsonDepthError(_) => None,
         }
     }
 }
 
-
 impl From<io::Error> for JsonDepthError {
     fn from(err: io::Error) -> JsonDepthError {
         JsonDepthError::IoError(err)
     }
 }
 
-
 impl From<Utf8Error> for JsonDepthError {
     fn from(err: Utf8Error) -> JsonDepthError {
         JsonDepthError::Utf8Error(err)
     }
 }
 
-
 impl From<FromUtf8Error> for JsonDepthError {
     fn from(err: FromUtf8Error) -> JsonDepthError {
         JsonDepthError::FromUtf8Error(err)
     }
 }
 
-
-
-
-
 impl<'a> JsonRef<'a> {
-
-
     /// Returns maximum depth of JSON document
     pub fn depth(&self) -> Result<i64> {
-        depth_json(&self)
+        depth_json(self)
     }
 }
 
@@ -191,9 +161,9 @@ fn depth_json(j: &JsonRef<'_>) -> Result<i64> {
     } + 1)
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
-    use super::super::Json;
+    use crate::json::Json;
 
     #[test]
     fn test_json_depth() {
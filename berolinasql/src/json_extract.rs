@@ -1,40 +1,31 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
+//Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
 
+//! `JSON_EXTRACT` and the local_path matching the other JSON functions build on.
 
- ///! This module is used to extract json from a string.
- /// It is used to extract json from a string.
- ///
+use std::collections::HashSet;
 
-use std::str::Chars;
-use std::iter::Peekable;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
-use std::fmt::{Display, Formatter, Result};
-use std::fmt;
-use std::error::Error;
-use std::result::Result as StdResult;
-use std::convert::From;
+use crate::error::Result;
+use crate::json::{Json, JsonRef, JsonType};
+use crate::path_expr::{
+    ArraySelection, LocalPathExpression, LocalPathLeg, LOCAL_PATH_EXPR_ASTERISK,
+};
 
-
-
-/// This enum is used to represent the different types of json values.
-/// It is used to represent the different types of json values.
- impl<'a> JsonRef<'a> {
+impl<'a> JsonRef<'a> {
     /// This function is used to get the type of the json value.
     /// `extract` receives several local_path expressions as arguments, matches them in j, and returns
     /// the target JSON matched any local_path expressions, which may be autowrapped as an array.
     /// If there is no any expression matched, it returns None.
     ///
     /// See `Extract()` in MEDB `json.binary_function.go`
-    pub fn extract(&self, local_path_expr_list: &[local_pathExpression]) -> Result<Option<Json>> {
+    pub fn extract(&self, local_path_expr_list: &[LocalPathExpression]) -> Result<Option<Json>> {
         let mut elem_list = Vec::with_capacity(local_path_expr_list.len());
         for local_path_expr in local_path_expr_list {
             elem_list.append(&mut extract_json(*self, &local_path_expr.legs)?)
@@ -54,39 +45,42 @@ use std::convert::From;
 }
 
 /// `extract_json` is used by JSON::extract().
-pub fn extract_json<'a>(j: JsonRef<'a>, local_path_legs: &[local_pathLeg]) -> Result<Vec<JsonRef<'a>>> {
+pub fn extract_json<'a>(
+    j: JsonRef<'a>,
+    local_path_legs: &[LocalPathLeg],
+) -> Result<Vec<JsonRef<'a>>> {
     if local_path_legs.is_empty() {
         return Ok(vec![j]);
     }
     let (current_leg, sub_local_path_legs) = (&local_path_legs[0], &local_path_legs[1..]);
     let mut ret = vec![];
     match *current_leg {
-        local_pathLeg::Index(i) => match j.get_type() {
+        LocalPathLeg::ArraySelection(selection) => match j.get_type() {
             JsonType::Array => {
-                let elem_count = j.get_elem_count();
-                if i == local_path_EXPR_ARRAY_INDEX_ASTERISK {
-                    for k in 0..elem_count {
-                        ret.append(&mut extract_json(j.array_get_elem(k)?, sub_local_path_legs)?)
-                    }
-                } else if (i as usize) < elem_count {
+                for k in selection.index_range(j.get_elem_count()) {
                     ret.append(&mut extract_json(
-                        j.array_get_elem(i as usize)?,
+                        j.array_get_elem(k)?,
                         sub_local_path_legs,
                     )?)
                 }
             }
             _ => {
-                if i as usize == 0 {
+                // A non-array causet_locale is autowrapped as a single element array,
+                // but '[*]' only matches the elements of real arrays.
+                if selection != ArraySelection::Asterisk && !selection.index_range(1).is_empty() {
                     ret.append(&mut extract_json(j, sub_local_path_legs)?)
                 }
             }
         },
-        local_pathLeg::Key(ref soliton_id) => {
+        LocalPathLeg::Key(ref soliton_id) => {
             if j.get_type() == JsonType::Object {
-                if soliton_id == local_path_EXPR_ASTERISK {
+                if soliton_id == LOCAL_PATH_EXPR_ASTERISK {
                     let elem_count = j.get_elem_count();
                     for i in 0..elem_count {
-                        ret.append(&mut extract_json(j.object_get_val(i)?, sub_local_path_legs)?)
+                        ret.append(&mut extract_json(
+                            j.object_get_val(i)?,
+                            sub_local_path_legs,
+                        )?)
                     }
                 } else if let Some(idx) = j.object_search_soliton_id(soliton_id.as_bytes()) {
                     let val = j.object_get_val(idx)?;
@@ -94,37 +88,43 @@ pub fn extract_json<'a>(j: JsonRef<'a>, local_path_legs: &[local_pathLeg]) -> Re
                 }
             }
         }
-        local_pathLeg::DoubleAsterisk => {
+        LocalPathLeg::DoubleAsterisk => {
+            // '**' matches the current causet_locale and, recursively, every causet_locale below it,
+            // so the whole `local_path_legs` is kept when descending into children.
             ret.append(&mut extract_json(j, sub_local_path_legs)?);
             match j.get_type() {
                 JsonType::Array => {
                     let elem_count = j.get_elem_count();
                     for k in 0..elem_count {
-                        ret.append(&mut extract_json(j.array_get_elem(k)?, sub_local_path_legs)?)
+                        ret.append(&mut extract_json(j.array_get_elem(k)?, local_path_legs)?)
                     }
                 }
                 JsonType::Object => {
                     let elem_count = j.get_elem_count();
                     for i in 0..elem_count {
-                        ret.append(&mut extract_json(j.object_get_val(i)?, sub_local_path_legs)?)
+                        ret.append(&mut extract_json(j.object_get_val(i)?, local_path_legs)?)
                     }
                 }
                 _ => {}
             }
+            // A causet_locale reachable through several descents is only reported once.
+            let mut seen = HashSet::with_capacity(ret.len());
+            ret.retain(|v| seen.insert(v.as_ptr()));
         }
     }
     Ok(ret)
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use super::*;
-    use super::super::local_path_expr::{
-        local_path_EXPR_ARRAY_INDEX_ASTERISK, local_path_EXPRESSION_CONTAINS_ASTERISK,
-        local_path_EXPRESSION_CONTAINS_DOUBLE_ASTERISK, local_pathExpressionFlag,
-        };
+    use crate::path_expr::{
+        ArrayIndex, ArraySelection, LocalPathExpressionFlag,
+        LOCAL_PATH_EXPRESSION_CONTAINS_ASTERISK, LOCAL_PATH_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
+        LOCAL_PATH_EXPRESSION_CONTAINS_RANGE,
+    };
 
     #[test]
     fn test_json_extract() {
@@ -134,158 +134,257 @@ mod tests {
             // Index
             (
                 "[true, 2017]",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(0)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some("true"),
             ),
             (
                 "[true, 2017]",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(local_path_EXPR_ARRAY_INDEX_ASTERISK)],
-                    flags: local_path_EXPRESSION_CONTAINS_ASTERISK,
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Asterisk)],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_ASTERISK,
                 }],
                 Some("[true, 2017]"),
             ),
             (
                 "[true, 2107]",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(2)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(2),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             (
                 "6.18",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(0)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some("6.18"),
             ),
             (
                 "6.18",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(local_path_EXPR_ARRAY_INDEX_ASTERISK)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Asterisk)],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             (
                 "true",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(0)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some("true"),
             ),
             (
                 "true",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(local_path_EXPR_ARRAY_INDEX_ASTERISK)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Asterisk)],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             (
                 "6",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(0)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some("6"),
             ),
             (
                 "6",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(local_path_EXPR_ARRAY_INDEX_ASTERISK)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Asterisk)],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             (
                 "-6",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(0)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some("-6"),
             ),
             (
                 "-6",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(local_path_EXPR_ARRAY_INDEX_ASTERISK)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Asterisk)],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             (
                 r#"{"a": [1, 2, {"aa": "xx"}]}"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(local_path_EXPR_ARRAY_INDEX_ASTERISK)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Asterisk)],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             (
                 r#"{"a": [1, 2, {"aa": "xx"}]}"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Index(0)],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Left(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some(r#"{"a": [1, 2, {"aa": "xx"}]}"#),
             ),
             // Key
             (
                 r#"{"a": "a1", "b": 20.08, "c": false}"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Key(String::from("c"))],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::Key(String::from("c"))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 Some("false"),
             ),
             (
                 r#"{"a": "a1", "b": 20.08, "c": false}"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Key(String::from(local_path_EXPR_ASTERISK))],
-                    flags: local_path_EXPRESSION_CONTAINS_ASTERISK,
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::Key(String::from(LOCAL_PATH_EXPR_ASTERISK))],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_ASTERISK,
                 }],
                 Some(r#"["a1", 20.08, false]"#),
             ),
             (
                 r#"{"a": "a1", "b": 20.08, "c": false}"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::Key(String::from("d"))],
-                    flags: local_pathExpressionFlag::default(),
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::Key(String::from("d"))],
+                    flags: LocalPathExpressionFlag::default(),
                 }],
                 None,
             ),
             // Double asterisks
             (
                 "21",
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::DoubleAsterisk, local_pathLeg::Key(String::from("c"))],
-                    flags: local_path_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
+                vec![LocalPathExpression {
+                    legs: vec![
+                        LocalPathLeg::DoubleAsterisk,
+                        LocalPathLeg::Key(String::from("c")),
+                    ],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
                 }],
                 None,
             ),
             (
                 r#"{"g": {"a": "a1", "b": 20.08, "c": false}}"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::DoubleAsterisk, local_pathLeg::Key(String::from("c"))],
-                    flags: local_path_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
+                vec![LocalPathExpression {
+                    legs: vec![
+                        LocalPathLeg::DoubleAsterisk,
+                        LocalPathLeg::Key(String::from("c")),
+                    ],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
                 }],
                 Some("false"),
             ),
             (
                 r#"[{"a": "a1", "b": 20.08, "c": false}, true]"#,
-                vec![local_pathExpression {
-                    legs: vec![local_pathLeg::DoubleAsterisk, local_pathLeg::Key(String::from("c"))],
-                    flags: local_path_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
+                vec![LocalPathExpression {
+                    legs: vec![
+                        LocalPathLeg::DoubleAsterisk,
+                        LocalPathLeg::Key(String::from("c")),
+                    ],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
                 }],
                 Some("false"),
             ),
+            // Last and ranges
+            (
+                "[1, 2, 3, 4]",
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Right(1),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
+                }],
+                Some("3"),
+            ),
+            (
+                "[1, 2, 3, 4]",
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Range(
+                        ArrayIndex::Left(1),
+                        ArrayIndex::Right(1),
+                    ))],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_RANGE,
+                }],
+                Some("[2, 3]"),
+            ),
+            (
+                "[1, 2, 3, 4]",
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Range(
+                        ArrayIndex::Left(2),
+                        ArrayIndex::Left(10),
+                    ))],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_RANGE,
+                }],
+                Some("[3, 4]"),
+            ),
+            (
+                "[1, 2, 3, 4]",
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Right(4),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
+                }],
+                None,
+            ),
+            (
+                "true",
+                vec![LocalPathExpression {
+                    legs: vec![LocalPathLeg::ArraySelection(ArraySelection::Index(
+                        ArrayIndex::Right(0),
+                    ))],
+                    flags: LocalPathExpressionFlag::default(),
+                }],
+                Some("true"),
+            ),
+            // Recursive descent
+            (
+                r#"{"a": {"b": {"c": 1}}, "c": 2}"#,
+                vec![LocalPathExpression {
+                    legs: vec![
+                        LocalPathLeg::DoubleAsterisk,
+                        LocalPathLeg::Key(String::from("c")),
+                    ],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
+                }],
+                Some("[2, 1]"),
+            ),
+            (
+                r#"[[1, [2]], {"x": [3]}]"#,
+                vec![LocalPathExpression {
+                    legs: vec![
+                        LocalPathLeg::DoubleAsterisk,
+                        LocalPathLeg::ArraySelection(ArraySelection::Index(ArrayIndex::Left(0))),
+                    ],
+                    flags: LOCAL_PATH_EXPRESSION_CONTAINS_DOUBLE_ASTERISK,
+                }],
+                Some(r#"[[1, [2]], 1, 2, {"x": [3]}, 3]"#),
+            ),
         ];
         for (i, (js, exprs, expected)) in test_cases.drain(..).enumerate() {
             let j = js.parse();
@@ -9,187 +9,62 @@
 // specific language governing permissions and limitations under the License.
 
 use std::str;
-use std::fmt;
-use std::error;
-use std::io;
-use std::convert::From;
-use std::result;
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::collections::BTreeMap;
-use std::collections::BTreeSet;
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Json {
-    Null,
-    Bool(bool),
-    Number(f64),
-    String(String),
-    Array(Vec<Json>),
-    Object(BTreeMap<String, Json>),
-}
-
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Error {
-    SyntaxError(String),
-    IoError(io::Error),
-    InvalidType(String, String),
-    InvalidSyntax(String),
-    InvalidNumber(String),
-    InvalidString(String),
-    InvalidEscape(char),
-    InvalidUnicode(String),
-    MissingColon,
-    MissingComma,
-    MissingCloseBrace,
-    MissingCloseBracket,
-    MissingCloseParen,
-    MissingOpenBrace,
-    MissingOpenBracket,
-    MissingOpenParen,
-    MissingQuote,
-    MissingSemicolon,
-    MissingValue,
-    MissingColonOrComma,
-    MissingCommaOrCloseBrace,
-    MissingCommaOrCloseBracket,
-    MissingCommaOrCloseParen,
-    MissingCommaOrOpenBrace,
-    MissingCommaOrOpenBracket,
-    MissingCommaOrOpenParen,
-    MissingCommaOrQuote,
-    MissingCommaOrSemicolon,
-    MissingCommaOrValue,
-    MissingColonOrCloseBrace,
-    MissingColonOrCloseBracket,
-    MissingColonOrCloseParen,
-    MissingColonOrOpenBrace,
-    MissingColonOrOpenBracket,
-    MissingColonOrOpenParen,
-    MissingColonOrQuote,
-    MissingColonOrSemicolon,
-    MissingColonOrValue,
-    MissingCommaOrColon,
-    MissingCommaOrCloseBraceOrSemicolon,
-    MissingCommaOrCloseBracketOrSemicolon,
-    MissingCommaOrCloseParenOrSemicolon,
-    MissingCommaOrOpenBraceOrSemicolon,
-    MissingCommaOrOpenBracketOrSemicolon,
-    MissingCommaOrOpenParenOrSemicolon,
-    MissingCommaOrQuoteOrSemicolon,
-    MissingCommaOrValueOrSemicolon,
-    MissingColonOrColonOrSemicolon,
-    MissingColonOrCloseBraceOrSemicolon,
-    MissingColonOrCloseBracketOrSemicolon,
-    MissingColonOrCloseParenOrSemicolon,
-}
-
-impl fmt::Display for SemicolonToken {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, ";")
-    }
-}
-
-
-impl fmt::Display for CommaToken {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, ",")
-    }
-}
-
-
-impl fmt::Display for ColonToken {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, ":")
-    }
-}
-
-
-
-
-use super::{Json, JsonRef, JsonType};
-use super::local_path_expr::local_pathExpression;
-use super::super::Result;
-use super::super::Error;
-use super::super::Error::{self, *};
-use super::super::Error::{overCausetxctx, division_by_zero};
 
+use crate::error::Result;
+use crate::json::{Json, JsonRef, JsonType};
+use crate::path_expr::LocalPathExpression;
 
+/// Returns the soliton_ids of the JSON object.
 pub fn json_keys(json: &Json) -> Result<Vec<String>> {
-    match json {
-        &Json::Object(ref m) => {
-            let mut keys = Vec::new();
-            for (k, _) in m.iter() {
-                keys.push(k.to_owned());
-            }
-            Ok(keys)
-        }
-        _ => Err(Error::invalid_type("OBJECT", json.get_type())),
+    let j = json.as_ref();
+    if j.get_type() != JsonType::Object {
+        return Err(invalid_type!("OBJECT expected, but got {:?}", j.get_type()));
+    }
+    let mut keys = Vec::with_capacity(j.get_elem_count());
+    for i in 0..j.get_elem_count() {
+        keys.push(box_try!(str::from_utf8(j.object_get_soliton_id(i))).to_owned());
     }
+    Ok(keys)
 }
 
-
-
-
-/// Returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-/// If the value is not a JSON object, returns null.
-
-
+/// Returns the causet_locale of the specified soliton_id in the JSON object.
+/// If the soliton_id does not exist, returns null.
 pub fn json_get(json: &Json, key: &str) -> Result<Json> {
-    match json {
-        &Json::Object(ref m) => {
-            match m.get(key) {
-                Some(v) => Ok(v.clone()),
-                None => Ok(Json::Null),
-            }
-        }
-        _ => Err(Error::invalid_type("OBJECT", json.get_type())),
+    let j = json.as_ref();
+    if j.get_type() != JsonType::Object {
+        return Err(invalid_type!("OBJECT expected, but got {:?}", j.get_type()));
+    }
+    match j.object_search_soliton_id(key.as_bytes()) {
+        Some(i) => Ok(j.object_get_val(i)?.to_owned()),
+        None => Json::none(),
     }
 }
 
-
-/// Returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-
-
+/// Returns the causet_locale of the specified soliton_id in the JSON object as a string.
+/// If the soliton_id does not exist, returns an empty string.
 pub fn json_get_string(json: &Json, key: &str) -> Result<String> {
-    match json {
-        &Json::Object(ref m) => {
-            match m.get(key) {
-                Some(v) => Ok(v.to_string()),
-                None => Ok(String::new()),
-            }
-        }
-        _ => Err(Error::invalid_type("OBJECT", json.get_type())),
+    let j = json.as_ref();
+    if j.get_type() != JsonType::Object {
+        return Err(invalid_type!("OBJECT expected, but got {:?}", j.get_type()));
+    }
+    match j.object_search_soliton_id(key.as_bytes()) {
+        Some(i) => Ok(j.object_get_val(i)?.to_string()),
+        None => Ok(String::new()),
     }
 }
 
 impl Json {
     pub fn get_string(&self, key: &str) -> Result<String> {
-        match self {
-            &Json::Object(ref m) => {
-                match m.get(key) {
-                    Some(v) => Ok(v.to_string()),
-                    None => Ok(String::new()),
-                }
-            }
-            _ => Err(Error::invalid_type("OBJECT", self.get_type())),
-        }
+        json_get_string(self, key)
     }
 }
 
-
-
-
-
 impl<'a> JsonRef<'a> {
     /// Evaluates a (possibly empty) list of causet_locales and returns a JSON array containing those causet_locales specified by `local_path_expr_list`
-    pub fn soliton_ids(&self, local_path_expr_list: &[local_pathExpression]) -> Result<Option<Json>> {
+    pub fn soliton_ids(
+        &self,
+        local_path_expr_list: &[LocalPathExpression],
+    ) -> Result<Option<Json>> {
         if !local_path_expr_list.is_empty() {
             if local_path_expr_list.len() > 1 {
                 return Err(box_err!(
@@ -211,35 +86,7 @@ impl<'a> JsonRef<'a> {
                 None => Ok(None),
             }
         } else {
-            json_soliton_ids(&self)
-        }
-    }
-}
-
-impl JsonRef {
-    pub fn soliton_ids(&self, local_path_expr_list: &[local_pathExpression]) -> Result<Option<Json>> {
-        if !local_path_expr_list.is_empty() {
-            if local_path_expr_list.len() > 1 {
-                return Err(box_err!(
-                    "Incorrect number of parameters: expected: 0 or 1, get {:?}",
-                    local_path_expr_list.len()
-                ));
-            }
-            if local_path_expr_list
-                .iter()
-                .any(|expr| expr.contains_any_asterisk())
-            {
-                return Err(box_err!(
-                    "Invalid local_path expression: expected no asterisk, but {:?}",
-                    local_path_expr_list
-                ));
-            }
-            match self.extract(local_path_expr_list)? {
-                Some(j) => json_soliton_ids(&j.as_ref()),
-                None => Ok(None),
-            }
-        } else {
-            json_soliton_ids(&self)
+            json_soliton_ids(self)
         }
     }
 }
@@ -250,7 +97,9 @@ fn json_soliton_ids(j: &JsonRef<'_>) -> Result<Option<Json>> {
         let elem_count = j.get_elem_count();
         let mut ret = Vec::with_capacity(elem_count);
         for i in 0..elem_count {
-            ret.push(Json::from_str_val(str::from_utf8(j.object_get_soliton_id(i))?)?);
+            ret.push(Json::from_str_val(box_try!(str::from_utf8(
+                j.object_get_soliton_id(i)
+            )))?);
         }
         Some(Json::from_array(ret)?)
     } else {
@@ -258,12 +107,12 @@ fn json_soliton_ids(j: &JsonRef<'_>) -> Result<Option<Json>> {
     })
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use super::*;
-    use super::super::local_path_expr::parse_json_local_path_expr;
+    use crate::path_expr::parse_json_local_path_expr;
 
     #[test]
     fn test_json_soliton_ids() {
@@ -338,4 +187,19 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_json_get() {
+        let j: Json = r#"{"b": "x", "a": [1]}"#.parse().unwrap();
+        assert_eq!(json_keys(&j).unwrap(), vec!["a", "b"]);
+        assert_eq!(json_get(&j, "a").unwrap(), "[1]".parse().unwrap());
+        assert_eq!(json_get(&j, "c").unwrap(), Json::none().unwrap());
+        assert_eq!(j.get_string("b").unwrap(), r#""x""#);
+        assert_eq!(json_get_string(&j, "c").unwrap(), "");
+
+        let array: Json = "[1]".parse().unwrap();
+        assert!(json_keys(&array).is_err());
+        assert!(json_get(&array, "a").is_err());
+        assert!(array.get_string("a").is_err());
+    }
 }
@@ -8,81 +8,28 @@
 // CONDITIONS OF ANY KIND, either express or implied. See the License for the
 // specific language governing permissions and limitations under the License.
 
-
-
-
-/// Returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-/// If the value is not a JSON object, returns null.
-/// If the value is a JSON object, returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-
-
-pub fn json_get_object(json: &Json, key: &str) -> Result<Json> {
-    match json {
-        &Json::Object(ref m) => {
-            match m.get(key) {
-                Some(v) => Ok(v.clone()),
-                None => Ok(Json::Null),
-            }
-        }
-        _ => Err(Error::invalid_type("OBJECT", json.get_type())),
-    }
-}
-
-
-/// Returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-
-
-pub fn json_get_array(json: &Json, key: &str) -> Result<Json> {
-    match json {
-        &Json::Array(ref m) => {
-            match m.get(key) {
-                Some(v) => Ok(v.clone()),
-                None => Ok(Json::Null),
-            }
-        }
-        _ => Err(Error::invalid_type("ARRAY", json.get_type())),
+use crate::error::Result;
+use crate::json::{Json, JsonRef, JsonType};
+use crate::path_expr::LocalPathExpression;
+
+/// Returns the causet_locale of the specified soliton_id in the JSON object.
+/// If the soliton_id does not exist, returns null.
+pub fn json_get_object(json: &Json, soliton_id: &str) -> Result<Json> {
+    match json.as_ref().json_get_object(soliton_id)? {
+        Some(v) => Ok(v.to_owned()),
+        None => Json::none(),
     }
 }
 
-
-/// Returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-/// If the value is not a JSON object, returns null.
-/// If the value is a JSON object, returns the value of the specified key in the JSON object.
-/// If the key does not exist, returns null.
-
-
-pub fn json_get_string(json: &Json, key: &str) -> Result<Json> {
-    match json {
-        &Json::String(ref m) => {
-            match m.get(key) {
-                Some(v) => Ok(v.clone()),
-                None => Ok(Json::Null),
-            }
-        }
-        _ => Err(Error::invalid_type("STRING", json.get_type())),
+/// Returns the element at the specified index in the JSON array.
+/// If the index is out of range, returns null.
+pub fn json_get_array(json: &Json, index: &str) -> Result<Json> {
+    match json.as_ref().json_get_array(index)? {
+        Some(v) => Ok(v.to_owned()),
+        None => Json::none(),
     }
 }
 
-
-
-
-
-
-
-
-use std::collections::HashMap;
-use std::convert::TryFrom;
-use std::fmt::{self, Debug, Display, Formatter};
-use std::hash::{Hash, Hasher};
-use std::rc::Rc;
-use std::str::FromStr;
-
-
-
 impl<'a> JsonRef<'a> {
     fn len(&self) -> Option<i64> {
         match self.get_type() {
@@ -93,79 +40,60 @@ impl<'a> JsonRef<'a> {
 
     /// `json_length` is the implementation for JSON_LENGTH in myBerolinaSQL
     /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-Attr-functions.html#function_json-length
-    pub fn json_length(&self, local_path_expr_list: &[local_pathExpression]) -> Result<Option<i64>> {
+    pub fn json_length(&self, local_path_expr_list: &[LocalPathExpression]) -> Result<Option<i64>> {
         if local_path_expr_list.is_empty() {
             return Ok(self.len());
         }
         if local_path_expr_list.len() == 1 && local_path_expr_list[0].contains_any_asterisk() {
             return Ok(None);
         }
-        Ok(self.extract(local_path_expr_list)?.and_then(|j| j.as_ref().len()))
+        Ok(self
+            .extract(local_path_expr_list)?
+            .and_then(|j| j.as_ref().len()))
     }
-}
 
-
-impl<'a> JsonRef<'a> {
-    /// `json_get_object` is the implementation for JSON_GET_OBJECT in myBerolinaSQL
-    /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-Attr-functions.html#function_json-get-object
-    pub fn json_get_object(&self, key: &str) -> Result<JsonRef<'a>> {
+    /// Returns the causet_locale of `soliton_id` in this JSON object, or `None` if the
+    /// soliton_id does not exist. Fails if this is not an object.
+    pub fn json_get_object(&self, soliton_id: &str) -> Result<Option<JsonRef<'a>>> {
         match self.get_type() {
-            JsonType::Object => {
-                let v = self.get_object()?.get(key)?;
-                Ok(JsonRef::new(v))
-            }
-            _ => Err(Error::invalid_type("OBJECT", self.get_type())),
+            JsonType::Object => self.json_get_object_or_null(soliton_id),
+            tp => Err(invalid_type!("OBJECT expected, but got {:?}", tp)),
         }
     }
-}
-
 
-impl<'a> JsonRef<'a> {
-    /// `json_get_array` is the implementation for JSON_GET_ARRAY in myBerolinaSQL
-    /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-Attr-functions.html#function_json-get-array
-    pub fn json_get_array(&self, key: &str) -> Result<JsonRef<'a>> {
-        match self.get_type() {
-            JsonType::Array => {
-                let v = self.get_array()?.get(key)?;
-                Ok(JsonRef::new(v))
-            }
-            _ => Err(Error::invalid_type("ARRAY", self.get_type())),
+    /// Returns the element at `index` in this JSON array, or `None` if the index
+    /// is out of range. Fails if this is not an array or `index` is not a number.
+    pub fn json_get_array(&self, index: &str) -> Result<Option<JsonRef<'a>>> {
+        if self.get_type() != JsonType::Array {
+            return Err(invalid_type!(
+                "ARRAY expected, but got {:?}",
+                self.get_type()
+            ));
         }
-    }
-}
-
-
-impl<'a> JsonRef<'a> {
-    /// `json_get_string` is the implementation for JSON_GET_STRING in myBerolinaSQL
-    /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-Attr-functions.html#function_json-get-string
-    pub fn json_get_string(&self, key: &str) -> Result<JsonRef<'a>> {
-        match self.get_type() {
-            JsonType::String => {
-                let v = self.get_string()?.get(key)?;
-                Ok(JsonRef::new(v))
-            }
-            _ => Err(Error::invalid_type("STRING", self.get_type())),
+        let index: usize = box_try!(index.parse());
+        if index >= self.get_elem_count() {
+            return Ok(None);
         }
+        self.array_get_elem(index).map(Some)
     }
-}
 
-
-impl<'a> JsonRef<'a> {
-    /// `json_get_object` is the implementation for JSON_GET_OBJECT in myBerolinaSQL
-    /// https://dev.myBerolinaSQL.com/doc/refman/5.7/en/json-Attr-functions.html#function_json-get-object
-    pub fn json_get_object_or_null(&self, key: &str) -> Result<JsonRef<'a>> {
-        match self.get_type() {
-            JsonType::Object => {
-                let v = self.get_object()?.get(key)?;
-                Ok(JsonRef::new(v))
-            }
-            _ => Ok(JsonRef::new(Json::Null)),
+    /// Like `json_get_object`, but returns `None` instead of failing when this is
+    /// not an object.
+    pub fn json_get_object_or_null(&self, soliton_id: &str) -> Result<Option<JsonRef<'a>>> {
+        if self.get_type() != JsonType::Object {
+            return Ok(None);
+        }
+        match self.object_search_soliton_id(soliton_id.as_bytes()) {
+            Some(i) => self.object_get_val(i).map(Some),
+            None => Ok(None),
         }
     }
 }
+
+#[cfg(test)]
 mod tests {
-    use super::super::local_path_expr::parse_json_local_path_expr;
-    use super::super::Json;
+    use super::*;
+    use crate::path_expr::parse_json_local_path_expr;
     #[test]
     fn test_json_length() {
         let mut test_cases = vec![
@@ -221,4 +149,18 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_json_get_object_and_array() {
+        let j: Json = r#"{"a": [1, {"b": 2}], "c": "d"}"#.parse().unwrap();
+        let a = json_get_object(&j, "a").unwrap();
+        assert_eq!(a, "[1, {\"b\": 2}]".parse().unwrap());
+        assert_eq!(json_get_object(&j, "x").unwrap(), Json::none().unwrap());
+        assert_eq!(json_get_array(&a, "0").unwrap(), "1".parse().unwrap());
+        assert_eq!(json_get_array(&a, "2").unwrap(), Json::none().unwrap());
+        assert!(json_get_array(&a, "b").is_err());
+        assert!(json_get_array(&j, "0").is_err());
+        assert!(json_get_object(&a, "a").is_err());
+        assert_eq!(a.as_ref().json_get_object_or_null("a").unwrap(), None);
+    }
 }
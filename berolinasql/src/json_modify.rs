@@ -1,102 +1,22 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
-
-
-
-
-// use std::error::Error;
-// use std::fmt;
-// use std::io;
-// use std::string::FromUtf8Error;
-
-use std::error::Error;
-use std::fmt;
-use std::io;
-use std::result;
-
-
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::thread;
-use std::time::Duration;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
-use std::collections::hash_map::Iter;
-
-use std::collections::hash_map::IterMut;
-
-
-use crate::berolinasql::{Error as BerolinaSqlError, ErrorKind as BerolinaSqlErrorKind};
-use crate::berolinasql::{ErrorImpl as BerolinaSqlErrorImpl};
-use std::error::Error;
-use std::string::FromUtf8Error;
-use std::str::Utf8Error;
-use std::result;
-use std::string::FromUtf8Error;
-use std::str::Utf8Error;
-use std::error::Error;
-use std::string::FromUtf8Error;
-use std::str::Utf8Error;
-use std::error::Error;
-use std::string::FromUtf8Error;
-use std::str::Utf8Error;
-use std::error::Error;
-
-
- #[derive(Debug)]
-    pub enum ErrorKind {
-        Io(io::Error),
-        BerolinaSql(BerolinaSqlError),
-        Utf8(Utf8Error),
-        FromUtf8(FromUtf8Error),
-        Other(String),
-    }
-
-    #[derive(Debug)]
-    pub struct ErrorImpl {
-        pub kind: ErrorKind,
-    }
-
-    #[derive(Debug)]
-    pub enum BerolinaSqlError {
-        IoError(io::Error),
-        SqlError(String),
-    }
-
-
-
-
-
-
-
-
-
-
-
-use std::collections::hash_map::Keys;
-use std::collections::hash_map::Values;
- /// `ModifyType` is for mosN.
- /// `ModifyType` is used to specify the type of the modify.
-
-
-
- use std::collections::HashSet;
-    use std::collections::HashMap;
-    use std::collections::hash_map::Entry;
-
-    use std::collections::hash_map::Iter;
-    use std::collections::hash_map::IterMut;
-
-
+// Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+use crate::error::Result;
+use crate::json::{Json, JsonRef};
+use crate::modifier::BinaryModifier;
+use crate::path_expr::LocalPathExpression;
+
+/// `ModifyType` is used to specify the type of the modify.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ModifyType {
+    /// `UnCausetLocaleNucleon` is an unrecognized modify type, which is rejected.
     UnCausetLocaleNucleon,
     /// `Insert` is for inserting a new element into a JSON.
     Insert,
@@ -104,21 +24,17 @@ pub enum ModifyType {
     Replace,
     /// `Set` = `Insert` | `Replace`
     Set,
-
-
 }
 
-
-
 impl<'a> JsonRef<'a> {
     /// Modifies a Json object by insert, replace or set.
-    /// All local_path expressions cannot contain * or ** wildcard.
+    /// All local_path expressions cannot contain * or ** wildcard, nor array ranges.
     /// If any error occurs, the input won't be changed.
     ///
     /// See `Modify()` in MEDB `json/binary_function.go`
     pub fn modify(
         &self,
-        local_path_expr_list: &[local_pathExpression],
+        local_path_expr_list: &[LocalPathExpression],
         causet_locales: Vec<Json>,
         mt: ModifyType,
     ) -> Result<Json> {
@@ -136,24 +52,33 @@ impl<'a> JsonRef<'a> {
                     expr
                 ));
             }
+            if expr.contains_any_range() {
+                return Err(box_err!(
+                    "Invalid local_path expression: expected no array range, found {:?}",
+                    expr
+                ));
+            }
         }
         let mut res = self.to_owned();
-        for (expr, causet_locale) in local_path_expr_list.iter().zip(causet_locales.into_iter()) {
+        for (expr, causet_locale) in local_path_expr_list.iter().zip(causet_locales) {
             let modifier = BinaryModifier::new(res.as_ref());
             res = match mt {
-                ModifyType::Insert => modifier.insert(&expr, causet_locale)?,
-                ModifyType::Replace => modifier.replace(&expr, causet_locale)?,
-                ModifyType::Set => modifier.set(&expr, causet_locale)?,
+                ModifyType::Insert => modifier.insert(expr, causet_locale)?,
+                ModifyType::Replace => modifier.replace(expr, causet_locale)?,
+                ModifyType::Set => modifier.set(expr, causet_locale)?,
+                ModifyType::UnCausetLocaleNucleon => {
+                    return Err(box_err!("Invalid modify type: {:?}", mt))
+                }
             };
         }
         Ok(res)
     }
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::local_path_expr::parse_json_local_path_expr;
+    use crate::path_expr::parse_json_local_path_expr;
 
     #[test]
     fn test_json_modify() {
@@ -265,7 +190,9 @@ mod tests {
                 false,
             ),
         ];
-        for (i, (json, local_path, causet_locale, mt, expected, success)) in test_cases.drain(..).enumerate() {
+        for (i, (json, local_path, causet_locale, mt, expected, success)) in
+            test_cases.drain(..).enumerate()
+        {
             let json: Result<Json> = json.parse();
             assert!(
                 json.is_ok(),
@@ -300,7 +227,9 @@ mod tests {
                 causet_locale.unwrap(),
                 expected.unwrap(),
             );
-            let result = json.as_ref().modify(vec![local_path].as_slice(), vec![causet_locale], mt);
+            let result = json
+                .as_ref()
+                .modify(vec![local_path].as_slice(), vec![causet_locale], mt);
             if success {
                 assert!(
                     result.is_ok(),
@@ -1,27 +1,26 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
+//Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
 
- use super::{Json, JsonRef};
- use super::local_path_expr::local_pathExpression;
- use super::modifier::BinaryModifier;
- use super::super::Result;
+use crate::error::Result;
+use crate::json::{Json, JsonRef};
+use crate::modifier::BinaryModifier;
+use crate::path_expr::LocalPathExpression;
 
- impl<'a> JsonRef<'a> {
+impl<'a> JsonRef<'a> {
     /// Removes elements from Json,
-    /// All local_path expressions cannot contain * or ** wildcard.
+    /// All local_path expressions cannot contain * or ** wildcard, nor array ranges.
     /// If any error occurs, the input won't be changed.
-    pub fn remove(&self, local_path_expr_list: &[local_pathExpression]) -> Result<Json> {
-        if local_path_expr_list
-            .iter()
-            .any(|expr| expr.legs.is_empty() || expr.contains_any_asterisk())
-        {
+    pub fn remove(&self, local_path_expr_list: &[LocalPathExpression]) -> Result<Json> {
+        if local_path_expr_list.iter().any(|expr| {
+            expr.legs.is_empty() || expr.contains_any_asterisk() || expr.contains_any_range()
+        }) {
             return Err(box_err!("Invalid local_path expression"));
         }
 
@@ -34,10 +33,10 @@
     }
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::local_path_expr::parse_json_local_path_expr;
+    use crate::path_expr::parse_json_local_path_expr;
 
     #[test]
     fn test_json_remove() {
@@ -65,7 +64,12 @@ mod tests {
             let j: Result<Json> = json.parse();
             assert!(j.is_ok(), "#{} expect json parse ok but got {:?}", i, j);
             let p = parse_json_local_path_expr(local_path);
-            assert!(p.is_ok(), "#{} expect local_path parse ok but got {:?}", i, p);
+            assert!(
+                p.is_ok(),
+                "#{} expect local_path parse ok but got {:?}",
+                i,
+                p
+            );
             let e: Result<Json> = expected.parse();
             assert!(
                 e.is_ok(),
@@ -0,0 +1,858 @@
+//Copyright 2021-2023 WHTCORPS INC ALL RIGHTS RESERVED. APACHE 2.0 COMMUNITY EDITION SL
+// AUTHORS: WHITFORD LEDER
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! `JSON_SCHEMA_VALID` and `JSON_SCHEMA_VALIDATION_REPORT`.
+//!
+//! Validation follows JSON Schema draft 4 like MyBerolinaSQL 8 does. The supported
+//! keywords are `type`, `enum`, `allOf`, `anyOf`, `oneOf`, `not`, the numeric
+//! `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum` and `multipleOf`,
+//! the string `minLength`, `maxLength` and `pattern`, the array `items`,
+//! `additionalItems`, `minItems`, `maxItems` and `uniqueItems`, and the object
+//! `properties`, `patternProperties`, `additionalProperties`, `required`,
+//! `minProperties` and `maxProperties`. Unknown keywords are ignored.
+//!
+//! Both the schema and the document are walked as `JsonRef`s, nothing is
+//! converted into another tree representation.
+
+use std::collections::{BTreeMap, HashMap};
+
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::json::{Json, JsonRef, JsonType};
+
+/// Why a document was rejected by a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonSchemaValidationReport {
+    /// JSON pointer to the offending causet_locale in the document, e.g. `#/orders/0/id`.
+    pub document_location: String,
+    /// JSON pointer to the failing keyword's subschema, e.g. `#/properties/orders/items`.
+    pub schema_location: String,
+    /// The schema keyword which failed, e.g. `minimum`.
+    pub keyword: String,
+    pub message: String,
+}
+
+impl JsonSchemaValidationReport {
+    /// Renders the report the way `JSON_SCHEMA_VALIDATION_REPORT` returns it.
+    pub fn to_json(&self) -> Result<Json> {
+        let mut map = BTreeMap::new();
+        map.insert("valid".to_owned(), Json::from_bool(false)?);
+        map.insert("reason".to_owned(), Json::from_str_val(&self.message)?);
+        map.insert(
+            "schema-location".to_owned(),
+            Json::from_str_val(&self.schema_location)?,
+        );
+        map.insert(
+            "document-location".to_owned(),
+            Json::from_str_val(&self.document_location)?,
+        );
+        map.insert(
+            "schema-failed-keyword".to_owned(),
+            Json::from_str_val(&self.keyword)?,
+        );
+        Json::from_object(map)
+    }
+}
+
+/// A JSON schema which documents can be validated against.
+#[derive(Clone, Debug)]
+pub struct JsonSchema {
+    schema: Json,
+    /// The regexes of the `pattern` and `patternProperties` keywords, by their source.
+    patterns: HashMap<String, Regex>,
+}
+
+impl JsonSchema {
+    /// Creates a schema. The schema document must be a JSON object, and its regexes
+    /// are compiled here so that an invalid one is reported with the schema.
+    pub fn new(schema: Json) -> Result<JsonSchema> {
+        if schema.as_ref().get_type() != JsonType::Object {
+            return Err(invalid_schema("the schema must be a JSON object"));
+        }
+        let mut patterns = HashMap::new();
+        compile_patterns(schema.as_ref(), &mut patterns)?;
+        Ok(JsonSchema { schema, patterns })
+    }
+
+    pub fn as_json(&self) -> JsonRef<'_> {
+        self.schema.as_ref()
+    }
+
+    /// Validates `doc`, returns the first violation found or `None` if `doc` is valid.
+    pub fn validate(&self, doc: JsonRef<'_>) -> Result<Option<JsonSchemaValidationReport>> {
+        let mut validator = Validator {
+            patterns: &self.patterns,
+            document_location: vec![],
+            schema_location: vec![],
+        };
+        validator.validate(self.schema.as_ref(), doc)
+    }
+}
+
+/// `JSON_SCHEMA_VALID(schema, doc)`
+pub fn json_schema_valid(schema: JsonRef<'_>, doc: JsonRef<'_>) -> Result<bool> {
+    let schema = JsonSchema::new(schema.to_owned())?;
+    Ok(schema.validate(doc)?.is_none())
+}
+
+/// `JSON_SCHEMA_VALIDATION_REPORT(schema, doc)`
+pub fn json_schema_validation_report(schema: JsonRef<'_>, doc: JsonRef<'_>) -> Result<Json> {
+    let schema = JsonSchema::new(schema.to_owned())?;
+    match schema.validate(doc)? {
+        Some(report) => report.to_json(),
+        None => {
+            let mut map = BTreeMap::new();
+            map.insert("valid".to_owned(), Json::from_bool(true)?);
+            Json::from_object(map)
+        }
+    }
+}
+
+struct Validator<'a> {
+    patterns: &'a HashMap<String, Regex>,
+    document_location: Vec<String>,
+    schema_location: Vec<String>,
+}
+
+/// One group of keywords, checking `doc` against `schema`.
+type Check<'a> =
+    fn(&mut Validator<'a>, JsonRef<'_>, JsonRef<'_>) -> Result<Option<JsonSchemaValidationReport>>;
+
+impl<'a> Validator<'a> {
+    fn validate(
+        &mut self,
+        schema: JsonRef<'_>,
+        doc: JsonRef<'_>,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        if schema.get_type() != JsonType::Object {
+            return Err(invalid_schema("a subschema must be a JSON object"));
+        }
+        let checks: [Check<'a>; 5] = [
+            Validator::validate_generic,
+            Validator::validate_number,
+            Validator::validate_string,
+            Validator::validate_array,
+            Validator::validate_object,
+        ];
+        for check in checks.iter() {
+            if let Some(report) = check(self, schema, doc)? {
+                return Ok(Some(report));
+            }
+        }
+        Ok(None)
+    }
+
+    fn validate_generic(
+        &mut self,
+        schema: JsonRef<'_>,
+        doc: JsonRef<'_>,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        if let Some(tp) = keyword(schema, "type")? {
+            let matched = match tp.get_type() {
+                JsonType::String => type_matches(tp.get_str()?, doc)?,
+                JsonType::Array => {
+                    let mut matched = false;
+                    for i in 0..tp.get_elem_count() {
+                        let name = tp.array_get_elem(i)?;
+                        if name.get_type() != JsonType::String {
+                            return Err(invalid_schema("'type' must name JSON types"));
+                        }
+                        matched |= type_matches(name.get_str()?, doc)?;
+                    }
+                    matched
+                }
+                _ => return Err(invalid_schema("'type' must be a string or an array")),
+            };
+            if !matched {
+                return Ok(Some(self.fail(
+                    "type",
+                    format!("expected type {} but got {}", tp, type_name(doc)),
+                )));
+            }
+        }
+        if let Some(candidates) = keyword(schema, "enum")? {
+            if candidates.get_type() != JsonType::Array {
+                return Err(invalid_schema("'enum' must be an array"));
+            }
+            let mut found = false;
+            for i in 0..candidates.get_elem_count() {
+                found |= candidates.array_get_elem(i)? == doc;
+            }
+            if !found {
+                return Ok(Some(
+                    self.fail("enum", format!("{} is not one of {}", doc, candidates)),
+                ));
+            }
+        }
+        if let Some(all_of) = keyword(schema, "allOf")? {
+            for i in 0..schema_array_len(all_of, "allOf")? {
+                self.schema_location.push("allOf".to_owned());
+                self.schema_location.push(i.to_string());
+                let report = self.validate(all_of.array_get_elem(i)?, doc)?;
+                self.schema_location
+                    .truncate(self.schema_location.len() - 2);
+                if report.is_some() {
+                    return Ok(report);
+                }
+            }
+        }
+        if let Some(any_of) = keyword(schema, "anyOf")? {
+            let matched = self.count_matching(any_of, "anyOf", doc)?;
+            if matched == 0 {
+                return Ok(Some(
+                    self.fail("anyOf", "no subschema in 'anyOf' matched".to_owned()),
+                ));
+            }
+        }
+        if let Some(one_of) = keyword(schema, "oneOf")? {
+            let matched = self.count_matching(one_of, "oneOf", doc)?;
+            if matched != 1 {
+                return Ok(Some(self.fail(
+                    "oneOf",
+                    format!(
+                        "{} subschemas in 'oneOf' matched, expected exactly one",
+                        matched
+                    ),
+                )));
+            }
+        }
+        if let Some(not) = keyword(schema, "not")? {
+            if self.validate(not, doc)?.is_none() {
+                return Ok(Some(
+                    self.fail("not", "the subschema in 'not' matched".to_owned()),
+                ));
+            }
+        }
+        Ok(None)
+    }
+
+    fn validate_number(
+        &mut self,
+        schema: JsonRef<'_>,
+        doc: JsonRef<'_>,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        let v = match as_f64(doc) {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        if let Some(min) = number_keyword(schema, "minimum")? {
+            let exclusive = bool_keyword(schema, "exclusiveMinimum")?.unwrap_or(false);
+            if v < min || (exclusive && v == min) {
+                return Ok(Some(self.fail(
+                    "minimum",
+                    format!("{} is less than the minimum of {}", doc, min),
+                )));
+            }
+        }
+        if let Some(max) = number_keyword(schema, "maximum")? {
+            let exclusive = bool_keyword(schema, "exclusiveMaximum")?.unwrap_or(false);
+            if v > max || (exclusive && v == max) {
+                return Ok(Some(self.fail(
+                    "maximum",
+                    format!("{} is greater than the maximum of {}", doc, max),
+                )));
+            }
+        }
+        if let Some(divisor) = number_keyword(schema, "multipleOf")? {
+            if divisor <= 0.0 {
+                return Err(invalid_schema("'multipleOf' must be greater than 0"));
+            }
+            let quotient = v / divisor;
+            if (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs().max(1.0) {
+                return Ok(Some(self.fail(
+                    "multipleOf",
+                    format!("{} is not a multiple of {}", doc, divisor),
+                )));
+            }
+        }
+        Ok(None)
+    }
+
+    fn validate_string(
+        &mut self,
+        schema: JsonRef<'_>,
+        doc: JsonRef<'_>,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        if doc.get_type() != JsonType::String {
+            return Ok(None);
+        }
+        let s = doc.get_str()?;
+        let len = s.chars().count() as u64;
+        if let Some(min) = count_keyword(schema, "minLength")? {
+            if len < min {
+                return Ok(Some(self.fail(
+                    "minLength",
+                    format!("the string is shorter than {} characters", min),
+                )));
+            }
+        }
+        if let Some(max) = count_keyword(schema, "maxLength")? {
+            if len > max {
+                return Ok(Some(self.fail(
+                    "maxLength",
+                    format!("the string is longer than {} characters", max),
+                )));
+            }
+        }
+        if let Some(pattern) = keyword(schema, "pattern")? {
+            if !self.regex(pattern.get_str()?)?.is_match(s) {
+                return Ok(Some(self.fail(
+                    "pattern",
+                    format!("{} does not match the pattern {}", doc, pattern),
+                )));
+            }
+        }
+        Ok(None)
+    }
+
+    fn validate_array(
+        &mut self,
+        schema: JsonRef<'_>,
+        doc: JsonRef<'_>,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        if doc.get_type() != JsonType::Array {
+            return Ok(None);
+        }
+        let elem_count = doc.get_elem_count();
+        if let Some(min) = count_keyword(schema, "minItems")? {
+            if (elem_count as u64) < min {
+                return Ok(Some(self.fail(
+                    "minItems",
+                    format!("the array has fewer than {} items", min),
+                )));
+            }
+        }
+        if let Some(max) = count_keyword(schema, "maxItems")? {
+            if (elem_count as u64) > max {
+                return Ok(Some(self.fail(
+                    "maxItems",
+                    format!("the array has more than {} items", max),
+                )));
+            }
+        }
+        if bool_keyword(schema, "uniqueItems")?.unwrap_or(false) {
+            for i in 0..elem_count {
+                for k in i + 1..elem_count {
+                    if doc.array_get_elem(i)? == doc.array_get_elem(k)? {
+                        return Ok(Some(self.fail(
+                            "uniqueItems",
+                            format!("the items at {} and {} are equal", i, k),
+                        )));
+                    }
+                }
+            }
+        }
+        let items = match keyword(schema, "items")? {
+            Some(items) => items,
+            None => return Ok(None),
+        };
+        for i in 0..elem_count {
+            let elem = doc.array_get_elem(i)?;
+            let (subschema, location) = match items.get_type() {
+                JsonType::Object => (items, vec!["items".to_owned()]),
+                JsonType::Array if i < items.get_elem_count() => (
+                    items.array_get_elem(i)?,
+                    vec!["items".to_owned(), i.to_string()],
+                ),
+                JsonType::Array => match keyword(schema, "additionalItems")? {
+                    None => break,
+                    Some(additional) if additional.get_type() == JsonType::Literal => {
+                        if additional.get_literal() == Some(false) {
+                            self.document_location.push(i.to_string());
+                            let report = self.fail(
+                                "additionalItems",
+                                "additional items are not allowed".to_owned(),
+                            );
+                            self.document_location.pop();
+                            return Ok(Some(report));
+                        }
+                        break;
+                    }
+                    Some(additional) => (additional, vec!["additionalItems".to_owned()]),
+                },
+                _ => return Err(invalid_schema("'items' must be an object or an array")),
+            };
+            if let Some(report) = self.validate_child(subschema, location, elem, i.to_string())? {
+                return Ok(Some(report));
+            }
+        }
+        Ok(None)
+    }
+
+    fn validate_object(
+        &mut self,
+        schema: JsonRef<'_>,
+        doc: JsonRef<'_>,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        if doc.get_type() != JsonType::Object {
+            return Ok(None);
+        }
+        let elem_count = doc.get_elem_count();
+        if let Some(min) = count_keyword(schema, "minProperties")? {
+            if (elem_count as u64) < min {
+                return Ok(Some(self.fail(
+                    "minProperties",
+                    format!("the object has fewer than {} members", min),
+                )));
+            }
+        }
+        if let Some(max) = count_keyword(schema, "maxProperties")? {
+            if (elem_count as u64) > max {
+                return Ok(Some(self.fail(
+                    "maxProperties",
+                    format!("the object has more than {} members", max),
+                )));
+            }
+        }
+        if let Some(required) = keyword(schema, "required")? {
+            for i in 0..schema_array_len(required, "required")? {
+                let name = required.array_get_elem(i)?;
+                if name.get_type() != JsonType::String {
+                    return Err(invalid_schema("'required' must only contain strings"));
+                }
+                if doc
+                    .object_search_soliton_id(name.get_str_bytes()?)
+                    .is_none()
+                {
+                    return Ok(Some(self.fail(
+                        "required",
+                        format!("the required member {} is missing", name),
+                    )));
+                }
+            }
+        }
+
+        let properties = keyword(schema, "properties")?;
+        let pattern_properties = match keyword(schema, "patternProperties")? {
+            Some(pp) => {
+                let mut patterns = Vec::with_capacity(pp.get_elem_count());
+                for i in 0..pp.get_elem_count() {
+                    let name = String::from_utf8(pp.object_get_soliton_id(i).to_vec())?;
+                    patterns.push((self.regex(&name)?, name, pp.object_get_val(i)?));
+                }
+                patterns
+            }
+            None => vec![],
+        };
+        let additional = keyword(schema, "additionalProperties")?;
+        for i in 0..elem_count {
+            let name = String::from_utf8(doc.object_get_soliton_id(i).to_vec())?;
+            let val = doc.object_get_val(i)?;
+            let mut matched = false;
+            if let Some(properties) = properties {
+                if let Some(idx) = properties.object_search_soliton_id(name.as_bytes()) {
+                    matched = true;
+                    let location = vec!["properties".to_owned(), name.clone()];
+                    let report = self.validate_child(
+                        properties.object_get_val(idx)?,
+                        location,
+                        val,
+                        name.clone(),
+                    )?;
+                    if report.is_some() {
+                        return Ok(report);
+                    }
+                }
+            }
+            for (re, pattern, subschema) in &pattern_properties {
+                if re.is_match(&name) {
+                    matched = true;
+                    let location = vec!["patternProperties".to_owned(), pattern.clone()];
+                    let report = self.validate_child(*subschema, location, val, name.clone())?;
+                    if report.is_some() {
+                        return Ok(report);
+                    }
+                }
+            }
+            if matched {
+                continue;
+            }
+            match additional {
+                Some(additional) if additional.get_type() == JsonType::Literal => {
+                    if additional.get_literal() != Some(false) {
+                        continue;
+                    }
+                    self.document_location.push(name.clone());
+                    let report = self.fail(
+                        "additionalProperties",
+                        format!("the member \"{}\" is not allowed", name),
+                    );
+                    self.document_location.pop();
+                    return Ok(Some(report));
+                }
+                Some(additional) => {
+                    let location = vec!["additionalProperties".to_owned()];
+                    let report = self.validate_child(additional, location, val, name)?;
+                    if report.is_some() {
+                        return Ok(report);
+                    }
+                }
+                None => {}
+            }
+        }
+        Ok(None)
+    }
+
+    fn validate_child(
+        &mut self,
+        subschema: JsonRef<'_>,
+        schema_location: Vec<String>,
+        doc: JsonRef<'_>,
+        document_location: String,
+    ) -> Result<Option<JsonSchemaValidationReport>> {
+        let schema_depth = self.schema_location.len();
+        self.schema_location.extend(schema_location);
+        self.document_location.push(document_location);
+        let report = self.validate(subschema, doc);
+        self.document_location.pop();
+        self.schema_location.truncate(schema_depth);
+        report
+    }
+
+    fn count_matching(
+        &mut self,
+        subschemas: JsonRef<'_>,
+        name: &str,
+        doc: JsonRef<'_>,
+    ) -> Result<usize> {
+        let mut matched = 0;
+        for i in 0..schema_array_len(subschemas, name)? {
+            if self.validate(subschemas.array_get_elem(i)?, doc)?.is_none() {
+                matched += 1;
+            }
+        }
+        Ok(matched)
+    }
+
+    /// The regex `pattern` was compiled to when the schema was created.
+    fn regex(&self, pattern: &str) -> Result<&'a Regex> {
+        self.patterns
+            .get(pattern)
+            .ok_or_else(|| invalid_schema(&format!("the pattern {} was not compiled", pattern)))
+    }
+
+    fn fail(&self, keyword: &str, message: String) -> JsonSchemaValidationReport {
+        JsonSchemaValidationReport {
+            document_location: json_pointer(&self.document_location),
+            schema_location: json_pointer(&self.schema_location),
+            keyword: keyword.to_owned(),
+            message,
+        }
+    }
+}
+
+fn keyword<'a>(schema: JsonRef<'a>, name: &str) -> Result<Option<JsonRef<'a>>> {
+    match schema.object_search_soliton_id(name.as_bytes()) {
+        Some(idx) => Ok(Some(schema.object_get_val(idx)?)),
+        None => Ok(None),
+    }
+}
+
+fn number_keyword(schema: JsonRef<'_>, name: &str) -> Result<Option<f64>> {
+    match keyword(schema, name)? {
+        Some(v) => as_f64(v)
+            .map(Some)
+            .ok_or_else(|| invalid_schema(&format!("'{}' must be a number", name))),
+        None => Ok(None),
+    }
+}
+
+fn count_keyword(schema: JsonRef<'_>, name: &str) -> Result<Option<u64>> {
+    match number_keyword(schema, name)? {
+        Some(v) if v >= 0.0 && v.fract() == 0.0 => Ok(Some(v as u64)),
+        Some(_) => Err(invalid_schema(&format!(
+            "'{}' must be a non-negative integer",
+            name
+        ))),
+        None => Ok(None),
+    }
+}
+
+fn bool_keyword(schema: JsonRef<'_>, name: &str) -> Result<Option<bool>> {
+    match keyword(schema, name)? {
+        Some(v) if v.get_type() == JsonType::Literal && v.get_literal().is_some() => {
+            Ok(v.get_literal())
+        }
+        Some(_) => Err(invalid_schema(&format!("'{}' must be a boolean", name))),
+        None => Ok(None),
+    }
+}
+
+fn schema_array_len(subschemas: JsonRef<'_>, name: &str) -> Result<usize> {
+    if subschemas.get_type() != JsonType::Array {
+        return Err(invalid_schema(&format!("'{}' must be an array", name)));
+    }
+    Ok(subschemas.get_elem_count())
+}
+
+/// Compiles the regexes of `schema` and of all its subschemas into `patterns`.
+/// Subschemas which are not objects are left for the validation to reject.
+fn compile_patterns(schema: JsonRef<'_>, patterns: &mut HashMap<String, Regex>) -> Result<()> {
+    if schema.get_type() != JsonType::Object {
+        return Ok(());
+    }
+    if let Some(pattern) = keyword(schema, "pattern")? {
+        if pattern.get_type() != JsonType::String {
+            return Err(invalid_schema("'pattern' must be a string"));
+        }
+        compile_pattern(pattern.get_str()?, patterns)?;
+    }
+    if let Some(pp) = keyword(schema, "patternProperties")? {
+        if pp.get_type() != JsonType::Object {
+            return Err(invalid_schema("'patternProperties' must be an object"));
+        }
+        for i in 0..pp.get_elem_count() {
+            compile_pattern(
+                &String::from_utf8(pp.object_get_soliton_id(i).to_vec())?,
+                patterns,
+            )?;
+            compile_patterns(pp.object_get_val(i)?, patterns)?;
+        }
+    }
+    if let Some(properties) = keyword(schema, "properties")? {
+        if properties.get_type() == JsonType::Object {
+            for i in 0..properties.get_elem_count() {
+                compile_patterns(properties.object_get_val(i)?, patterns)?;
+            }
+        }
+    }
+    for name in &["items", "allOf", "anyOf", "oneOf"] {
+        if let Some(subschemas) = keyword(schema, name)? {
+            if subschemas.get_type() == JsonType::Array {
+                for i in 0..subschemas.get_elem_count() {
+                    compile_patterns(subschemas.array_get_elem(i)?, patterns)?;
+                }
+            }
+        }
+    }
+    for name in &["items", "additionalItems", "additionalProperties", "not"] {
+        if let Some(subschema) = keyword(schema, name)? {
+            compile_patterns(subschema, patterns)?;
+        }
+    }
+    Ok(())
+}
+
+fn compile_pattern(pattern: &str, patterns: &mut HashMap<String, Regex>) -> Result<()> {
+    if !patterns.contains_key(pattern) {
+        patterns.insert(pattern.to_owned(), box_try!(Regex::new(pattern)));
+    }
+    Ok(())
+}
+
+fn as_f64(j: JsonRef<'_>) -> Option<f64> {
+    match j.get_type() {
+        JsonType::I64 => Some(j.get_i64() as f64),
+        JsonType::U64 => Some(j.get_u64() as f64),
+        JsonType::Double => Some(j.get_double()),
+        _ => None,
+    }
+}
+
+fn type_matches(name: &str, doc: JsonRef<'_>) -> Result<bool> {
+    let matched = match name {
+        "object" => doc.get_type() == JsonType::Object,
+        "array" => doc.get_type() == JsonType::Array,
+        "string" => doc.get_type() == JsonType::String,
+        "number" => as_f64(doc).is_some(),
+        "integer" => match doc.get_type() {
+            JsonType::I64 | JsonType::U64 => true,
+            JsonType::Double => doc.get_double().fract() == 0.0,
+            _ => false,
+        },
+        "boolean" => doc.get_type() == JsonType::Literal && doc.get_literal().is_some(),
+        "null" => doc.get_type() == JsonType::Literal && doc.get_literal().is_none(),
+        _ => return Err(invalid_schema(&format!("unknown type \"{}\"", name))),
+    };
+    Ok(matched)
+}
+
+fn type_name(doc: JsonRef<'_>) -> &'static str {
+    match doc.get_type() {
+        JsonType::Object => "object",
+        JsonType::Array => "array",
+        JsonType::String => "string",
+        JsonType::I64 | JsonType::U64 => "integer",
+        JsonType::Double => "number",
+        JsonType::Literal if doc.get_literal().is_none() => "null",
+        JsonType::Literal => "boolean",
+    }
+}
+
+// Builds a URI fragment JSON pointer such as `#/properties/a~1b`.
+fn json_pointer(tokens: &[String]) -> String {
+    let mut pointer = String::from("#");
+    for token in tokens {
+        pointer.push('/');
+        pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+    }
+    pointer
+}
+
+fn invalid_schema(reason: &str) -> Error {
+    box_err!("Invalid JSON schema: {}", reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(schema: &str, doc: &str) -> Option<JsonSchemaValidationReport> {
+        let schema: Json = schema.parse().unwrap();
+        let doc: Json = doc.parse().unwrap();
+        JsonSchema::new(schema)
+            .unwrap()
+            .validate(doc.as_ref())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_json_schema_valid() {
+        let schema = r#"{
+            "type": "object",
+            "required": ["id", "tags"],
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "name": {"type": "string", "maxLength": 4, "pattern": "^[a-z]+$"},
+                "tags": {"type": "array", "items": {"enum": ["a", "b"]}, "uniqueItems": true},
+                "score": {"type": ["number", "null"], "multipleOf": 0.5}
+            },
+            "additionalProperties": false
+        }"#;
+        let mut test_cases = vec![
+            (r#"{"id": 1, "tags": []}"#, true),
+            (
+                r#"{"id": 2, "name": "abc", "tags": ["a", "b"], "score": 1.5}"#,
+                true,
+            ),
+            (r#"{"id": 2, "tags": [], "score": null}"#, true),
+            (r#"{"id": 0, "tags": []}"#, false),
+            (r#"{"id": 1.5, "tags": []}"#, false),
+            (r#"{"id": 1}"#, false),
+            (r#"{"id": 1, "tags": ["c"]}"#, false),
+            (r#"{"id": 1, "tags": ["a", "a"]}"#, false),
+            (r#"{"id": 1, "tags": [], "name": "abcde"}"#, false),
+            (r#"{"id": 1, "tags": [], "name": "AB"}"#, false),
+            (r#"{"id": 1, "tags": [], "score": 1.2}"#, false),
+            (r#"{"id": 1, "tags": [], "extra": 1}"#, false),
+            (r#"[1]"#, false),
+        ];
+        for (i, (doc, expected)) in test_cases.drain(..).enumerate() {
+            let got = check(schema, doc);
+            assert_eq!(got.is_none(), expected, "#{} {} got {:?}", i, doc, got);
+        }
+    }
+
+    #[test]
+    fn test_json_schema_combinators() {
+        let schema = r#"{"anyOf": [{"type": "string"}, {"type": "integer"}]}"#;
+        assert!(check(schema, r#""abc""#).is_none());
+        assert!(check(schema, "5").is_none());
+        assert_eq!(check(schema, "1.5").unwrap().keyword, "anyOf");
+
+        let schema = r#"{"oneOf": [{"minimum": 10}, {"maximum": 20}]}"#;
+        assert!(check(schema, "5").is_none());
+        assert!(check(schema, "25").is_none());
+        assert_eq!(check(schema, "15").unwrap().keyword, "oneOf");
+
+        let schema = r#"{"not": {"enum": [15]}}"#;
+        assert!(check(schema, "5").is_none());
+        assert_eq!(check(schema, "15").unwrap().keyword, "not");
+
+        let schema = r#"{"items": [{"type": "string"}], "additionalItems": false}"#;
+        assert!(check(schema, r#"["a"]"#).is_none());
+        assert_eq!(
+            check(schema, r#"["a", 1]"#).unwrap().keyword,
+            "additionalItems"
+        );
+    }
+
+    #[test]
+    fn test_json_schema_validation_report() {
+        let schema =
+            r#"{"properties": {"orders": {"items": {"properties": {"a/b": {"maximum": 3}}}}}}"#;
+        let report = check(schema, r#"{"orders": [{"a/b": 1}, {"a/b": 4}]}"#).unwrap();
+        assert_eq!(
+            report,
+            JsonSchemaValidationReport {
+                document_location: "#/orders/1/a~1b".to_owned(),
+                schema_location: "#/properties/orders/items/properties/a~1b".to_owned(),
+                keyword: "maximum".to_owned(),
+                message: "4 is greater than the maximum of 3".to_owned(),
+            }
+        );
+
+        let schema: Json = schema.parse().unwrap();
+        let doc: Json = r#"{"orders": [{"a/b": 4}]}"#.parse().unwrap();
+        let got = json_schema_validation_report(schema.as_ref(), doc.as_ref()).unwrap();
+        let expected: Json = r##"{
+            "valid": false,
+            "reason": "4 is greater than the maximum of 3",
+            "schema-location": "#/properties/orders/items/properties/a~1b",
+            "document-location": "#/orders/0/a~1b",
+            "schema-failed-keyword": "maximum"
+        }"##
+        .parse()
+        .unwrap();
+        assert_eq!(got, expected);
+
+        let doc: Json = r#"{"orders": []}"#.parse().unwrap();
+        let got = json_schema_validation_report(schema.as_ref(), doc.as_ref()).unwrap();
+        assert_eq!(got, r#"{"valid": true}"#.parse::<Json>().unwrap());
+        assert!(json_schema_valid(schema.as_ref(), doc.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_json_schema() {
+        for schema in &[
+            "[]",
+            r#"{"type": 1}"#,
+            r#"{"type": "int"}"#,
+            r#"{"minLength": -1}"#,
+            r#"{"pattern": "("}"#,
+        ] {
+            let schema: Json = schema.parse().unwrap();
+            let doc: Json = r#""abc""#.parse().unwrap();
+            assert!(
+                json_schema_valid(schema.as_ref(), doc.as_ref()).is_err(),
+                "{}",
+                schema
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_schema_patterns() {
+        // Invalid regexes are rejected with the schema, even where no document
+        // would reach them.
+        for schema in &[
+            r#"{"properties": {"a": {"pattern": "("}}}"#,
+            r#"{"patternProperties": {"(": {}}}"#,
+            r#"{"items": [{}, {"not": {"pattern": "["}}]}"#,
+            r#"{"anyOf": [{"patternProperties": {"a": {"pattern": 1}}}]}"#,
+        ] {
+            let schema: Json = schema.parse().unwrap();
+            assert!(JsonSchema::new(schema.clone()).is_err(), "{}", schema);
+        }
+
+        // A property named like a keyword is not one.
+        let schema: Json = r#"{"properties": {"pattern": {"type": "string"}}, "patternProperties": {"^x": {"pattern": "^[0-9]+$"}}}"#
+            .parse()
+            .unwrap();
+        let schema = JsonSchema::new(schema).unwrap();
+        let doc: Json = r#"{"pattern": "(", "x1": "12"}"#.parse().unwrap();
+        assert!(schema.validate(doc.as_ref()).unwrap().is_none());
+        let doc: Json = r#"{"x1": "1a"}"#.parse().unwrap();
+        let report = schema.validate(doc.as_ref()).unwrap().unwrap();
+        assert_eq!(report.schema_location, "#/patternProperties/^x");
+        assert_eq!(report.keyword, "pattern");
+    }
+}
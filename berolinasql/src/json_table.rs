@@ -0,0 +1,606 @@
+//Copyright 2021-2023 WHTCORPS INC ALL RIGHTS RESERVED. APACHE 2.0 COMMUNITY EDITION SL
+// AUTHORS: WHITFORD LEDER
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! `JSON_TABLE(doc, local_path COLUMNS(...))` turns a JSON document into typed rows.
+//!
+//! ```text
+//!   JSON_TABLE(doc, '$.orders[*]' COLUMNS(
+//!       seq FOR ORDINALITY,
+//!       id BIGINT PATH '$.id' DEFAULT '0' ON EMPTY NULL ON ERROR,
+//!       has_note INT EXISTS PATH '$.note',
+//!       NESTED PATH '$.items[*]' COLUMNS(sku VARCHAR(32) PATH '$.sku')
+//!   ))
+//! ```
+//!
+//! Output columns are laid out in declaration order with the columns of every
+//! `NESTED PATH` inlined at the place the nested clause is declared. Sibling
+//! nested paths are not joined with each other: every row carries the causet_locales
+//! of a single nested local_path and NULLs for its siblings, and a parent row without
+//! any nested match is still produced once (like a left join).
+
+use std::convert::TryFrom;
+
+use crate::error::{Error, Result};
+use crate::field_type::{EvalType, FieldType, FieldTypeAccessor, FieldTypeTp};
+use crate::json::{Json, JsonRef, JsonType};
+use crate::json_extract::extract_json;
+use crate::path_expr::LocalPathExpression;
+
+/// What to produce when a `PATH` causet_merge matches nothing (`ON EMPTY`) or when the
+/// matched causet_locale cannot be converted to the causet_merge type (`ON ERROR`).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum JsonTableOnResponse {
+    /// `NULL ON ...`, the default.
+    #[default]
+    Null,
+    /// `ERROR ON ...` aborts the whole evaluation.
+    Error,
+    /// `DEFAULT <json> ON ...`
+    Default(Json),
+}
+
+/// How a `JSON_TABLE` causet_merge gets its causet_locales.
+#[derive(Clone, Debug)]
+pub enum JsonTableColumnKind {
+    /// `name FOR ORDINALITY`, a 1-based counter of the rows matched by the enclosing local_path.
+    ForOrdinality,
+    /// `name type [EXISTS] PATH local_path [on_empty ON EMPTY] [on_error ON ERROR]`
+    Path {
+        local_path: LocalPathExpression,
+        exists: bool,
+        on_empty: JsonTableOnResponse,
+        on_error: JsonTableOnResponse,
+    },
+    /// `NESTED [PATH] local_path COLUMNS (...)`
+    Nested {
+        local_path: LocalPathExpression,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+/// A causet_merge definition inside a `COLUMNS(...)` clause.
+#[derive(Clone, Debug)]
+pub struct JsonTableColumn {
+    pub name: String,
+    /// Ignored for `NESTED PATH` columns, whose output types come from their children.
+    pub field_type: FieldType,
+    pub kind: JsonTableColumnKind,
+}
+
+impl JsonTableColumn {
+    pub fn for_ordinality(name: impl Into<String>) -> JsonTableColumn {
+        JsonTableColumn {
+            name: name.into(),
+            field_type: FieldTypeTp::LongLong.into(),
+            kind: JsonTableColumnKind::ForOrdinality,
+        }
+    }
+
+    pub fn local_path(
+        name: impl Into<String>,
+        field_type: FieldType,
+        local_path: LocalPathExpression,
+        on_empty: JsonTableOnResponse,
+        on_error: JsonTableOnResponse,
+    ) -> JsonTableColumn {
+        JsonTableColumn {
+            name: name.into(),
+            field_type,
+            kind: JsonTableColumnKind::Path {
+                local_path,
+                exists: false,
+                on_empty,
+                on_error,
+            },
+        }
+    }
+
+    pub fn exists(
+        name: impl Into<String>,
+        field_type: FieldType,
+        local_path: LocalPathExpression,
+    ) -> JsonTableColumn {
+        JsonTableColumn {
+            name: name.into(),
+            field_type,
+            kind: JsonTableColumnKind::Path {
+                local_path,
+                exists: true,
+                on_empty: JsonTableOnResponse::Null,
+                on_error: JsonTableOnResponse::Null,
+            },
+        }
+    }
+
+    pub fn nested(
+        local_path: LocalPathExpression,
+        columns: Vec<JsonTableColumn>,
+    ) -> JsonTableColumn {
+        JsonTableColumn {
+            name: String::new(),
+            field_type: FieldTypeTp::Null.into(),
+            kind: JsonTableColumnKind::Nested {
+                local_path,
+                columns,
+            },
+        }
+    }
+
+    /// The number of output columns this definition expands to.
+    fn width(&self) -> usize {
+        match self.kind {
+            JsonTableColumnKind::Nested { ref columns, .. } => {
+                columns.iter().map(|c| c.width()).sum()
+            }
+            _ => 1,
+        }
+    }
+}
+
+/// A typed causet_locale of a `JSON_TABLE` output cell, one variant per supported `EvalType`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonTableCausetLocale {
+    Null,
+    Int(i64),
+    Real(f64),
+    Bytes(Vec<u8>),
+    Json(Json),
+}
+
+/// An evaluator of `JSON_TABLE(doc, local_path COLUMNS(...))`.
+#[derive(Clone, Debug)]
+pub struct JsonTable {
+    local_path: LocalPathExpression,
+    columns: Vec<JsonTableColumn>,
+    topograph: Vec<(String, FieldType)>,
+}
+
+impl JsonTable {
+    /// Builds an evaluator, rejecting causet_merge types that `JSON_TABLE` cannot produce.
+    pub fn new(
+        local_path: LocalPathExpression,
+        columns: Vec<JsonTableColumn>,
+    ) -> Result<JsonTable> {
+        let mut topograph = Vec::new();
+        flatten_topograph(&columns, &mut topograph)?;
+        Ok(JsonTable {
+            local_path,
+            columns,
+            topograph,
+        })
+    }
+
+    /// Returns the names and field types of the output columns, in output order.
+    pub fn topograph(&self) -> &[(String, FieldType)] {
+        &self.topograph
+    }
+
+    /// Returns the eval types of the output columns, in output order.
+    pub fn eval_types(&self) -> Vec<EvalType> {
+        self.topograph
+            .iter()
+            .map(|(_, ft)| column_eval_type(ft).unwrap())
+            .collect()
+    }
+
+    /// Evaluates the table function against `doc`. A `NULL` document produces no rows.
+    pub fn evaluate(&self, doc: Option<JsonRef<'_>>) -> Result<Vec<Vec<JsonTableCausetLocale>>> {
+        let doc = match doc {
+            Some(doc) => doc,
+            None => return Ok(vec![]),
+        };
+        let mut rows = Vec::new();
+        for (i, row_doc) in extract_json(doc, &self.local_path.legs)?
+            .into_iter()
+            .enumerate()
+        {
+            rows.append(&mut self.eval_columns(&self.columns, row_doc, i as i64 + 1)?);
+        }
+        Ok(rows)
+    }
+
+    fn eval_columns(
+        &self,
+        columns: &[JsonTableColumn],
+        j: JsonRef<'_>,
+        ordinality: i64,
+    ) -> Result<Vec<Vec<JsonTableCausetLocale>>> {
+        let width = columns.iter().map(|c| c.width()).sum();
+        let mut base = Vec::with_capacity(width);
+        let mut nested = Vec::new();
+        for col in columns {
+            match col.kind {
+                JsonTableColumnKind::ForOrdinality => {
+                    base.push(JsonTableCausetLocale::Int(ordinality))
+                }
+                JsonTableColumnKind::Path {
+                    ref local_path,
+                    exists,
+                    ref on_empty,
+                    ref on_error,
+                } => base.push(eval_path_column(
+                    col, j, local_path, exists, on_empty, on_error,
+                )?),
+                JsonTableColumnKind::Nested {
+                    ref local_path,
+                    ref columns,
+                } => {
+                    nested.push((base.len(), local_path, columns));
+                    base.resize(base.len() + col.width(), JsonTableCausetLocale::Null);
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        for (offset, local_path, nested_columns) in nested {
+            for (i, nested_doc) in extract_json(j, &local_path.legs)?.into_iter().enumerate() {
+                for nested_row in self.eval_columns(nested_columns, nested_doc, i as i64 + 1)? {
+                    let mut row = base.clone();
+                    let len = nested_row.len();
+                    row.splice(offset..offset + len, nested_row);
+                    rows.push(row);
+                }
+            }
+        }
+        if rows.is_empty() {
+            rows.push(base);
+        }
+        Ok(rows)
+    }
+}
+
+fn flatten_topograph(
+    columns: &[JsonTableColumn],
+    topograph: &mut Vec<(String, FieldType)>,
+) -> Result<()> {
+    for col in columns {
+        match col.kind {
+            JsonTableColumnKind::Nested { ref columns, .. } => {
+                flatten_topograph(columns, topograph)?
+            }
+            _ => {
+                column_eval_type(&col.field_type)?;
+                topograph.push((col.name.clone(), col.field_type.clone()));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn column_eval_type(ft: &FieldType) -> Result<EvalType> {
+    match box_try!(EvalType::try_from(ft.as_accessor().tp())) {
+        tp @ EvalType::Int | tp @ EvalType::Real | tp @ EvalType::Bytes | tp @ EvalType::Json => {
+            Ok(tp)
+        }
+        tp => Err(box_err!(
+            "JSON_TABLE does not support causet_merge of type {}",
+            tp
+        )),
+    }
+}
+
+fn eval_path_column(
+    col: &JsonTableColumn,
+    j: JsonRef<'_>,
+    local_path: &LocalPathExpression,
+    exists: bool,
+    on_empty: &JsonTableOnResponse,
+    on_error: &JsonTableOnResponse,
+) -> Result<JsonTableCausetLocale> {
+    let matched = extract_json(j, &local_path.legs)?;
+    if exists {
+        return convert_to_column(col, Json::from_i64(!matched.is_empty() as i64)?.as_ref());
+    }
+    let converted = match matched.len() {
+        0 => {
+            return respond(col, on_empty, || {
+                box_err!(
+                    "Missing causet_locale for JSON_TABLE causet_merge '{}'",
+                    col.name
+                )
+            })
+        }
+        1 => convert_to_column(col, matched[0]),
+        _ => Err(box_err!(
+            "Can't store an array or an object in the scalar JSON_TABLE causet_merge '{}'",
+            col.name
+        )),
+    };
+    match converted {
+        Ok(v) => Ok(v),
+        Err(e) => respond(col, on_error, || e),
+    }
+}
+
+fn respond(
+    col: &JsonTableColumn,
+    response: &JsonTableOnResponse,
+    err: impl FnOnce() -> Error,
+) -> Result<JsonTableCausetLocale> {
+    match *response {
+        JsonTableOnResponse::Null => Ok(JsonTableCausetLocale::Null),
+        JsonTableOnResponse::Error => Err(err()),
+        JsonTableOnResponse::Default(ref default) => convert_to_column(col, default.as_ref()),
+    }
+}
+
+/// Converts a matched JSON causet_locale into the causet_merge's eval type, following the
+/// MyBerolinaSQL cast rules. Lossy conversions are reported as errors so that
+/// `ON ERROR` can take over.
+fn convert_to_column(col: &JsonTableColumn, j: JsonRef<'_>) -> Result<JsonTableCausetLocale> {
+    let ft = col.field_type.as_accessor();
+    if j.get_type() == JsonType::Literal && j.get_literal().is_none() {
+        return Ok(JsonTableCausetLocale::Null);
+    }
+    match column_eval_type(&col.field_type)? {
+        EvalType::Int => {
+            let v = match j.get_type() {
+                JsonType::I64 => j.get_i64(),
+                JsonType::U64 if j.get_u64() <= i64::MAX as u64 => j.get_u64() as i64,
+                JsonType::Double => {
+                    double_to_i64(j.get_double()).ok_or_else(|| invalid_cast(col, j))?
+                }
+                JsonType::Literal => j.get_literal().unwrap() as i64,
+                JsonType::String => box_try!(j.get_str()?.trim().parse::<i64>()),
+                _ => return Err(invalid_cast(col, j)),
+            };
+            Ok(JsonTableCausetLocale::Int(v))
+        }
+        EvalType::Real => {
+            let v = match j.get_type() {
+                JsonType::I64 => j.get_i64() as f64,
+                JsonType::U64 => j.get_u64() as f64,
+                JsonType::Double => j.get_double(),
+                JsonType::Literal => j.get_literal().unwrap() as i64 as f64,
+                JsonType::String => box_try!(j.get_str()?.trim().parse::<f64>()),
+                _ => return Err(invalid_cast(col, j)),
+            };
+            Ok(JsonTableCausetLocale::Real(v))
+        }
+        EvalType::Bytes => {
+            let s = match j.get_type() {
+                JsonType::String => j.get_str()?.to_owned(),
+                _ => j.to_string(),
+            };
+            if ft.flen() > 0 && s.chars().count() > ft.flen() as usize {
+                return Err(box_err!(
+                    "Data too long for JSON_TABLE causet_merge '{}'",
+                    col.name
+                ));
+            }
+            Ok(JsonTableCausetLocale::Bytes(s.into_bytes()))
+        }
+        _ => Ok(JsonTableCausetLocale::Json(j.to_owned())),
+    }
+}
+
+/// Converts `f` if it is an integer within the range of `i64`. Like MyBerolinaSQL,
+/// fractional causet_locales are not rounded but rejected, as are NaN and infinities.
+fn double_to_i64(f: f64) -> Option<i64> {
+    // 2^63, which unlike `i64::MAX` is exact as a double.
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    if f.fract() == 0.0 && (-LIMIT..LIMIT).contains(&f) {
+        Some(f as i64)
+    } else {
+        None
+    }
+}
+
+fn invalid_cast(col: &JsonTableColumn, j: JsonRef<'_>) -> Error {
+    box_err!(
+        "Invalid JSON causet_locale {} for JSON_TABLE causet_merge '{}' of type {}",
+        j.to_string(),
+        col.name,
+        col.field_type.as_accessor().tp()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path_expr::parse_json_local_path_expr;
+
+    fn local_path(s: &str) -> LocalPathExpression {
+        parse_json_local_path_expr(s).unwrap()
+    }
+
+    fn varchar(flen: isize) -> FieldType {
+        let mut ft: FieldType = FieldTypeTp::VarChar.into();
+        ft.as_mut_accessor().set_flen(flen);
+        ft
+    }
+
+    fn int(v: i64) -> JsonTableCausetLocale {
+        JsonTableCausetLocale::Int(v)
+    }
+
+    fn bytes(s: &str) -> JsonTableCausetLocale {
+        JsonTableCausetLocale::Bytes(s.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_json_table_path_columns() {
+        let table = JsonTable::new(
+            local_path("$[*]"),
+            vec![
+                JsonTableColumn::for_ordinality("seq"),
+                JsonTableColumn::local_path(
+                    "id",
+                    FieldTypeTp::LongLong.into(),
+                    local_path("$.id"),
+                    JsonTableOnResponse::Default(Json::from_i64(-1).unwrap()),
+                    JsonTableOnResponse::Null,
+                ),
+                JsonTableColumn::local_path(
+                    "name",
+                    varchar(4),
+                    local_path("$.name"),
+                    JsonTableOnResponse::Null,
+                    JsonTableOnResponse::Default(Json::from_str_val("?").unwrap()),
+                ),
+                JsonTableColumn::exists(
+                    "has_tag",
+                    FieldTypeTp::LongLong.into(),
+                    local_path("$.tag"),
+                ),
+            ],
+        )
+        .unwrap();
+        let doc: Json =
+            r#"[{"id": 1, "name": "ab", "tag": null}, {"name": "toolong"}, {"id": "x"}]"#
+                .parse()
+                .unwrap();
+        let rows = table.evaluate(Some(doc.as_ref())).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![int(1), int(1), bytes("ab"), int(1)],
+                vec![int(2), int(-1), bytes("?"), int(0)],
+                vec![
+                    int(3),
+                    JsonTableCausetLocale::Null,
+                    JsonTableCausetLocale::Null,
+                    int(0)
+                ],
+            ]
+        );
+        assert!(table.evaluate(None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_json_table_error_responses() {
+        let table = JsonTable::new(
+            local_path("$"),
+            vec![JsonTableColumn::local_path(
+                "id",
+                FieldTypeTp::LongLong.into(),
+                local_path("$.id"),
+                JsonTableOnResponse::Error,
+                JsonTableOnResponse::Error,
+            )],
+        )
+        .unwrap();
+        for doc in &[r#"{}"#, r#"{"id": [1]}"#, r#"{"id": 1.5}"#] {
+            let doc: Json = doc.parse().unwrap();
+            assert!(table.evaluate(Some(doc.as_ref())).is_err(), "{}", doc);
+        }
+
+        let unsupported = JsonTable::new(
+            local_path("$"),
+            vec![JsonTableColumn::local_path(
+                "d",
+                FieldTypeTp::Duration.into(),
+                local_path("$.d"),
+                JsonTableOnResponse::Null,
+                JsonTableOnResponse::Null,
+            )],
+        );
+        assert!(unsupported.is_err());
+    }
+
+    #[test]
+    fn test_json_table_double_to_int() {
+        let table = JsonTable::new(
+            local_path("$[*]"),
+            vec![JsonTableColumn::local_path(
+                "id",
+                FieldTypeTp::LongLong.into(),
+                local_path("$"),
+                JsonTableOnResponse::Null,
+                JsonTableOnResponse::Default(Json::from_i64(-1).unwrap()),
+            )],
+        )
+        .unwrap();
+        let mut test_cases = vec![
+            (2.0, int(2)),
+            (-9_223_372_036_854_775_808.0, int(i64::MIN)),
+            (1.5, int(-1)),
+            (9_223_372_036_854_775_808.0, int(-1)),
+            (-1e19, int(-1)),
+            (f64::INFINITY, int(-1)),
+            (f64::NAN, int(-1)),
+        ];
+        for (f, expected) in test_cases.drain(..) {
+            let doc = Json::from_array(vec![Json::from_f64(f).unwrap()]).unwrap();
+            let rows = table.evaluate(Some(doc.as_ref())).unwrap();
+            assert_eq!(rows, vec![vec![expected]], "{}", f);
+        }
+    }
+
+    #[test]
+    fn test_json_table_nested_paths() {
+        let table = JsonTable::new(
+            local_path("$.orders[*]"),
+            vec![
+                JsonTableColumn::local_path(
+                    "order",
+                    FieldTypeTp::LongLong.into(),
+                    local_path("$.id"),
+                    JsonTableOnResponse::Null,
+                    JsonTableOnResponse::Null,
+                ),
+                JsonTableColumn::nested(
+                    local_path("$.items[*]"),
+                    vec![
+                        JsonTableColumn::for_ordinality("item_seq"),
+                        JsonTableColumn::local_path(
+                            "sku",
+                            varchar(-1),
+                            local_path("$"),
+                            JsonTableOnResponse::Null,
+                            JsonTableOnResponse::Null,
+                        ),
+                    ],
+                ),
+                JsonTableColumn::nested(
+                    local_path("$.notes[*]"),
+                    vec![JsonTableColumn::local_path(
+                        "note",
+                        FieldTypeTp::JSON.into(),
+                        local_path("$"),
+                        JsonTableOnResponse::Null,
+                        JsonTableOnResponse::Null,
+                    )],
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            table.eval_types(),
+            vec![
+                EvalType::Int,
+                EvalType::Int,
+                EvalType::Bytes,
+                EvalType::Json
+            ]
+        );
+        let doc: Json =
+            r#"{"orders": [{"id": 7, "items": ["a", "b"], "notes": [{"x": 1}]}, {"id": 8}]}"#
+                .parse()
+                .unwrap();
+        let note: Json = r#"{"x": 1}"#.parse().unwrap();
+        let null = JsonTableCausetLocale::Null;
+        let rows = table.evaluate(Some(doc.as_ref())).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![int(7), int(1), bytes("a"), null.clone()],
+                vec![int(7), int(2), bytes("b"), null.clone()],
+                vec![
+                    int(7),
+                    null.clone(),
+                    null.clone(),
+                    JsonTableCausetLocale::Json(note)
+                ],
+                vec![int(8), null.clone(), null.clone(), null],
+            ]
+        );
+    }
+}
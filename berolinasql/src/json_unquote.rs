@@ -1,65 +1,31 @@
- //Copyright 2021-2023 WHTCORPS INC
- //
- // Licensed under the Apache License, Version 2.0 (the "License"); you may not use
- // this file File except in compliance with the License. You may obtain a copy of the
- // License at http://www.apache.org/licenses/LICENSE-2.0
- // Unless required by applicable law or agreed to in writing, software distributed
- // under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
- // CONDITIONS OF ANY KIND, either express or implied. See the License for the
- // specific language governing permissions and limitations under the License.
-
-
-use EinsteinDB::*;
- use EinsteinDB::berolinasql::*;
-    use EinsteinDB::berolinasql::json_modify::*;
-    use EinsteinDB::berolinasql::json_length::*;
-    use EinsteinDB::berolinasql::json_quote::*;
-    use EinsteinDB::berolinasql::json_unquote::*;
-    use EinsteinDB::berolinasql::json_type::*;
-    use EinsteinDB::berolinasql::json_unwrap::*;
-    use EinsteinDB::berolinasql::json_wrap::*;
-    use EinsteinDB::berolinasql::json_unwrap::*;
-    use EinsteinDB::berolinasql::json_wrap::*;
-    use EinsteinDB::berolinasql::json_unwrap::*;
-    use EinsteinDB::berolinasql::json_wrap::*;
-
-
-
-
-
-
- use std::error::Error;
-    use std::fmt;
-    use std::io;
-    use std::string::FromUtf8Error;
-    use std::str::Utf8Error;
-    use std::result;
-    use std::string::FromUtf8Error;
-    use std::str::Utf8Error;
-    use std::error::Error;
-    use std::string::FromUtf8Error;
-    use std::str::Utf8Error;
-    use std::error::Error;
-    use std::string::FromUtf8Error;
-    use std::str::Utf8Error;
-    use std::error::Error;
-    use std::string::FromUtf8Error;
-
-
-const ESCAPED_UNICODE_BYTES_SIZE: usize = 4;
- const ESCAPED_UNICODE_BYTES: &[u8] = b"\\u";
-const CHAR_BACKSPACE: char = '\x08';
-const CHAR_HORIZONTAL_TAB: char = '\x09';
-const CHAR_LINEFEED: char = '\x0A';
-const CHAR_FORMFEED: char = '\x0C';
-const CHAR_CARRIAGE_RETURN: char = '\x0D';
-const CHAR_VERTICAL_TAB: char = '\x0B';
-const CHAR_QUOTATION_MARK: char = '\x22';
-const CHAR_APOSTROPHE: char = '\x27';
-const CHAR_AMPERSAND: char = '\x26';
-const CHAR_LESS_THAN: char = '\x3C';
-const CHAR_GREATER_THAN: char = '\x3E';
-
+//Copyright 2021-2023 WHTCORPS INC
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+use std::str;
+
+use crate::error::Result;
+use crate::json::{JsonRef, JsonType};
+
+pub const ESCAPED_UNICODE_BYTES_SIZE: usize = 4;
+pub const ESCAPED_UNICODE_BYTES: &[u8] = b"\\u";
+pub const CHAR_BACKSPACE: char = '\x08';
+pub const CHAR_HORIZONTAL_TAB: char = '\x09';
+pub const CHAR_LINEFEED: char = '\x0A';
+pub const CHAR_FORMFEED: char = '\x0C';
+pub const CHAR_CARRIAGE_RETURN: char = '\x0D';
+pub const CHAR_VERTICAL_TAB: char = '\x0B';
+pub const CHAR_QUOTATION_MARK: char = '\x22';
+pub const CHAR_APOSTROPHE: char = '\x27';
+pub const CHAR_AMPERSAND: char = '\x26';
+pub const CHAR_LESS_THAN: char = '\x3C';
+pub const CHAR_GREATER_THAN: char = '\x3E';
 
 impl<'a> JsonRef<'a> {
     /// `unquote` recognizes the escape sequences shown in:
@@ -69,10 +35,9 @@ impl<'a> JsonRef<'a> {
     /// See `Unquote()` in MEDB `json/binary_function.go`
     pub fn unquote(&self) -> Result<String> {
         match self.get_type() {
-            JsonType::String => {
-                let s = self.get_str()?;
-                unquote_string(s)
-            }
+            // The escape sequences of a JSON string are decoded when it is parsed,
+            // decoding them again would mangle e.g. a trailing backslash.
+            JsonType::String => Ok(self.get_str()?.to_owned()),
             _ => Ok(self.to_string()),
         }
     }
@@ -124,13 +89,13 @@ pub fn unquote_string(s: &str) -> Result<String> {
 
 fn decode_escaped_unicode(s: &str) -> Result<char> {
     let u = box_try!(u32::from_str_radix(s, 16));
-    char::from_u32(u).ok_or(box_err!("invalid char from: {}", s))
+    char::from_u32(u).ok_or_else(|| box_err!("invalid char from: {}", s))
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
-    use super::super::Json;
     use super::*;
+    use crate::json::Json;
     use std::collections::BTreeMap;
 
     #[test]
@@ -155,8 +120,7 @@ mod tests {
     }
 
     #[test]
-    fn test_json_unquote() {
-        // test unquote json string
+    fn test_unquote_string() {
         let mut test_cases = vec![
             ("\\b", true, Some("\x08")),
             ("\\f", true, Some("\x0C")),
@@ -173,8 +137,7 @@ mod tests {
             ("\\u59", false, None),
         ];
         for (i, (input, no_error, expected)) in test_cases.drain(..).enumerate() {
-            let j = Json::from_string(String::from(input)).unwrap();
-            let r = j.as_ref().unquote();
+            let r = unquote_string(input);
             if no_error {
                 assert!(r.is_ok(), "#{} expect unquote ok but got err {:?}", i, r);
                 let got = r.unwrap();
@@ -188,6 +151,17 @@ mod tests {
                 assert!(r.is_err(), "#{} expected error but got {:?}", i, r);
             }
         }
+    }
+
+    #[test]
+    fn test_json_unquote() {
+        // test unquote json string, which is kept as is
+        for s in ["\\b", "\\u597d", "b\\", "\\", "[", "好"] {
+            let j = Json::from_string(String::from(s)).unwrap();
+            assert_eq!(j.as_ref().unquote().unwrap(), s);
+        }
+        let j: Json = r#""a\tb\\""#.parse().unwrap();
+        assert_eq!(j.as_ref().unquote().unwrap(), "a\tb\\");
 
         // test unquote other json types
         let mut test_cases = vec![
@@ -211,5 +185,3 @@ mod tests {
         }
     }
 }
-
- 
\ No newline at end of file
@@ -0,0 +1,49 @@
+//Copyright 2021-2023 WHTCORPS INC ALL RIGHTS RESERVED. APACHE 2.0 COMMUNITY EDITION SL
+// AUTHORS: WHITFORD LEDER
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! The modules of BerolinaSQL, built as a library so that the executors outside
+//! the `berolinasql` binary can use them, such as the columnar batches built
+//! from `JSON_TABLE` rows.
+
+#[macro_use]
+pub mod error;
+
+pub mod binary;
+pub mod charset;
+pub mod codec;
+pub mod comparison;
+pub mod constants;
+mod ducet;
+#[path = "../../causet/src/field_type.rs"]
+pub mod field_type;
+pub mod generated_column;
+pub mod jcodec;
+pub mod json;
+pub mod json_depth;
+pub mod json_extract;
+pub mod json_keys;
+pub mod json_length;
+pub mod json_merge;
+pub mod json_modify;
+pub mod json_remove;
+pub mod json_schema;
+pub mod json_table;
+pub mod json_type;
+pub mod json_unquote;
+pub mod modifier;
+pub mod overflow;
+pub mod parser;
+pub mod path_expr;
+pub mod serde;
+pub mod table;
+pub mod value;
+
+pub use crate::error::{Error, Result};
+pub use crate::json::{Json, JsonRef, JsonType};
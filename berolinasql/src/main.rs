@@ -1,66 +1,35 @@
-mod parser;
-
-mod table;
-mod decimal;
-mod error;
-mod binary;
-mod comparison;
-mod json_type;
-mod json_modify;
-mod overflow;
-
-
-
-
-
-
-
-
+// Copyright (c)2018 WHTCORPS INC. All Rights Reserved.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Causal Set
+//! #################################
+//! This is a simple implementation of a Causal Set.
+//! We use rust peg to design a context-free grammar for sqlite statements which is used to parse conv2sql predicate expressions
+//! and generate sqlite statements for in app, while also transducing type safe sqlite statements to conv2sql predicate expressions which
+//! can be used to generate sqlite statements and be used in the future for sql generation. The sqlite statements are generated by
+//! the peg grammar and the sql-to-peg grammar is used to generate the sqlite statements.
+
+#![allow(dead_code)]
+
+use std::env;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
-
-
-
-
-use std::time::Instant;
-
-
-///! Copyright (c)2018 WHTCORPS INC. All Rights Reserved.
-///! Licensed under the Apache License, Version 2.0 (the "License");
-///! you may not use this file except in compliance with the License.
-///! You may obtain a copy of the License at
-///!
-///! http://www.apache.org/licenses/LICENSE-2.0
-///!
-///! Unless required by applicable law or agreed to in writing, software
-///! distributed under the License is distributed on an "AS IS" BASIS,
-///! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-///! See the License for the specific language governing permissions and
-///! limitations under the License.
-///!
-///!
-
-
-///! # Causal Set
-///! #################################
-///! This is a simple implementation of a Causal Set.
-///! We use rust peg to design a context-free grammar for sqlite statements which is used to parse conv2sql predicate expressions
-///! and generate sqlite statements for in app, while also transducing type safe sqlite statements to conv2sql predicate expressions which
-///! can be used to generate sqlite statements and be used in the future for sql generation. The sqlite statements are generated by
-///! the peg grammar and the sql-to-peg grammar is used to generate the sqlite statements.
-///!
-///
-///
-use std::fmt;
 use std::result;
-use std::string::FromUtf8Error;
 use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+use std::time::Instant;
 
-
-
-
-
-
+use berolinasql::table::{self as sql_table, Column, Row};
+use berolinasql::value::{Value, ValueType};
+use berolinasql::Json;
 
 #[derive(Debug)]
 pub enum ErrorKind {
@@ -71,45 +40,36 @@ pub enum ErrorKind {
     Other(String),
 }
 
-
 #[derive(Debug)]
 pub struct ErrorImpl {
     pub kind: ErrorKind,
 }
 
-
 #[derive(Debug)]
 pub enum BerolinaSqlError {
     IoError(io::Error),
     SqlError(String),
 }
 
-
 ///Rust peg
 /// ##############################################################################
 ///
 
-
 #[derive(Debug)]
 pub struct Error {
     pub kind: ErrorKind,
 }
 
-
-
-
-
-
 pub enum Sql {
-    Select(Sql),
-    Insert(Sql),
-    Update(Sql),
-    Delete(Sql),
-    Create(Sql),
-    Drop(Sql),
-    Alter(Sql),
-    Rename(Sql),
-    Copy(Sql),
+    Select(Box<Sql>),
+    Insert(Box<Sql>),
+    Update(Box<Sql>),
+    Delete(Box<Sql>),
+    Create(Box<Sql>),
+    Drop(Box<Sql>),
+    Alter(Box<Sql>),
+    Rename(Box<Sql>),
+    Copy(Box<Sql>),
 
     /// A SQL statement that does not return a result set.
     /// This includes UPDATE, DELETE, CREATE, DROP, and ALTER statements.
@@ -122,39 +82,18 @@ pub enum Sql {
     /// If the query succeeds and no rows are affected, then 1 is returned.
     /// If the query succeeds and multiple rows are affected, then the number of rows affected is returned.
     /// If the query succeeds and multiple rows are affected, then the number of rows affected is returned.
+    AlterTable(Box<Sql>),
+    RenameTable(Box<Sql>),
+    TruncateTable(Box<Sql>),
 
-
-
-
-
-
-    AlterTable(crate::Sql),
-    RenameTable(crate::Sql),
-    TruncateTable(crate::Sql),
-
-    Use(crate::Sql),
-
-
-
-
+    Use(Box<Sql>),
 }
 
-
-
-
-
-#[allow(dead_code)]
-
-
-
-
-// #[derive(Debug)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CausalSet {
     pub causal_set: Vec<Causal>,
 }
 
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Causal {
     pub id: usize,
@@ -162,13 +101,11 @@ pub struct Causal {
     pub value: String,
 }
 
-
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct CausalSetBuilder {
     pub causal_set: Vec<Causal>,
 }
 
-
 impl CausalSetBuilder {
     pub fn new() -> CausalSetBuilder {
         CausalSetBuilder {
@@ -188,23 +125,17 @@ impl CausalSetBuilder {
     }
 }
 
-
-
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CausalSetBuilderWithId {
     pub causal_set: Vec<Causal>,
     pub id: usize,
 }
 
-
-
-
 impl CausalSetBuilderWithId {
     pub fn new(id: usize) -> CausalSetBuilderWithId {
         CausalSetBuilderWithId {
             causal_set: Vec::new(),
-            id: id,
+            id,
         }
     }
 
@@ -220,7 +151,6 @@ impl CausalSetBuilderWithId {
     }
 }
 
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CausalSetBuilderWithIdAndName {
     pub causal_set: Vec<Causal>,
@@ -228,14 +158,12 @@ pub struct CausalSetBuilderWithIdAndName {
     pub name: String,
 }
 
-
-
 impl CausalSetBuilderWithIdAndName {
     pub fn new(id: usize, name: String) -> CausalSetBuilderWithIdAndName {
         CausalSetBuilderWithIdAndName {
             causal_set: Vec::new(),
-            id: id,
-            name: name,
+            id,
+            name,
         }
     }
 
@@ -251,7 +179,6 @@ impl CausalSetBuilderWithIdAndName {
     }
 }
 
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CausalSetBuilderWithIdAndNameAndValue {
     pub causal_set: Vec<Causal>,
@@ -260,14 +187,13 @@ pub struct CausalSetBuilderWithIdAndNameAndValue {
     pub value: String,
 }
 
-
 impl CausalSetBuilderWithIdAndNameAndValue {
     pub fn new(id: usize, name: String, value: String) -> CausalSetBuilderWithIdAndNameAndValue {
         CausalSetBuilderWithIdAndNameAndValue {
             causal_set: Vec::new(),
-            id: id,
-            name: name,
-            value: value,
+            id,
+            name,
+            value,
         }
     }
 
@@ -285,8 +211,6 @@ impl CausalSetBuilderWithIdAndNameAndValue {
 
 pub type Rule = Vec<CausalSet>;
 
-
-
 pub type Result<T> = result::Result<T, Error>;
 
 pub trait CausalSetBuilderExt {
@@ -294,9 +218,7 @@ pub trait CausalSetBuilderExt {
     fn build(self) -> CausalSet;
 }
 
-
 pub trait ContextFreeGrammar {
-
     fn get_start_symbol(&self) -> &str; // start symbol
 
     fn get_terminal_symbols(&self) -> &Vec<String>; // terminal symbols
@@ -305,27 +227,38 @@ pub trait ContextFreeGrammar {
 
     fn get_rules(&self) -> &Vec<Rule>; // rules symbols
 
-
-
     fn parse(&self, input: &str) -> Result<CausalSet>;
 }
 
-
-
-
-fn main() -> String {
+/// Loads a file of JSON documents, one per line, into a table with a single JSON
+/// causet_merge and reports how long it took.
+fn main() -> io::Result<()> {
     let start = Instant::now();
-    let mut table = Table::new();
-    let mut file = File::open("/home/berolina/Desktop/test.txt")?;
-    BufReader::new(file);
-    let _ = String::new();
-    String::new();
-    String::new()
+    let local_path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/home/berolina/Desktop/test.txt".to_owned());
+    let mut table = sql_table::Table::new(
+        "test".to_owned(),
+        vec![Column::new("doc".to_owned(), ValueType::Json)],
+        vec![],
+    );
+    let file = File::open(local_path)?;
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let doc: Json = line.parse().map_err(|e: berolinasql::Error| {
+            io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+        })?;
+        table
+            .insert(Row::new(vec![Value::Json(doc)]))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    }
+    println!("loaded {} rows in {:?}", table.rows.len(), start.elapsed());
+    Ok(())
 }
 
-
-
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Table {
     pub table: Vec<CausalSet>,
@@ -12,192 +12,14 @@
 // @File: modifier.rs
 // @Date: 2020-03-20
 
+use std::ptr;
 
-use std::io::{self, Read, Write};
-use std::mem;
-use std::cmp;
-use std::fmt;
-
-use byteorder::{ByteOrder, BigEndian, ReadBytesExt, WriteBytesExt};
-use byteorder::{LittleEndian, WriteBytesExt};
-
-
-use byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
-use std::sync::Arc;
-use std::sync::Mutex;
-use std::sync::RwLock;
-use std::sync::RwLockReadGuard;
-use std::sync::RwLockWriteGuard;
-use std::sync::atomic::{AtomicBool, Partitioning};
-use std::time::Duration;
-
-use crate :: error::{Error, Result};
-use crate :: util::{self, foundationdb_to_engine_error};
-use crate::{EngineIterator};
-use crate::Engine;
-use crate::EngineResult;
-use crate::EngineSnapshot;
-use crate::EngineWriteBatch;
-use crate::IteratorMode;
-use crate::Modify;
-use crate::Snapshot;
-use crate::WriteBatch;
-use crate::WriteOptions;
-
-
-
-
-use einstein_ml::{
-    engine::{
-        Engine as EinsteinEngine,
-        EngineIterator as EinsteinEngineIterator,
-        EngineSnapshot as EinsteinEngineSnapshot,
-        EngineWriteBatch as EinsteinEngineWriteBatch,
-        Modify as EinsteinModify,
-        Snapshot as EinsteinSnapshot,
-        WriteBatch as EinsteinWriteBatch,
-    },
-    error::{Error as EinsteinError, Result as EinsteinResult},
-    util::{
-        foundationdb_to_engine_error as einstein_to_engine_error,
-        EngineIterator as EinsteinEngineIteratorImpl,
-        EngineSnapshot as EinsteinEngineSnapshotImpl,
-        EngineWriteBatch as EinsteinEngineWriteBatchImpl,
-        Modify as EinsteinModifyImpl,
-        Snapshot as EinsteinSnapshotImpl,
-        WriteBatch as EinsteinWriteBatchImpl,
-    },
-};
-
-
-/// `Modifier` is a wrapper of `EinsteinEngine` that provides the following features:
-/// - `Modifier` is thread-safe.
-/// - `Modifier` is safe to use in multi-threaded environment.
-/// - `Modifier` is safe to use in multi-process environment.
-
-
-
-
-#[derive(Debug)]
-pub struct Modifier {
-    engine: Arc<Mutex<EinsteinEngine>>,
-    is_closed: AtomicBool,
-}
-
-
-#[derive(Debug)]
-pub struct ModifierSnapshot {
-    engine: Arc<Mutex<EinsteinEngine>>,
-    is_closed: AtomicBool,
-}
-
-
-
-pub struct EngineImpl {
-    engine: EinsteinEngine,
-    is_closed: Arc<AtomicBool>,
-    timestamp: Arc<Mutex<u64>>,
-    hash: Arc<RwLock<u64>>,
-    digest: Arc<RwLock<u64>>,
-    secret: Arc<RwLock<u64>>,
-    pk: Arc<RwLock<u64>>,
-}
-
-impl EngineImpl {
-    pub fn new(engine: EinsteinEngine) -> Self {
-        Self {
-            engine,
-            is_closed: Arc::new(AtomicBool::new(false)),
-            timestamp: Arc::new(Mutex::new(0)),
-            hash: Arc::new(RwLock::new(0)),
-            digest: Arc::new(RwLock::new(0)),
-            secret: Arc::new(RwLock::new(0)),
-            pk: Arc::new(RwLock::new(0)),
-        }
-    }
-}
-
-
-
-/// `Modifier` is a wrapper of `EinsteinEngine` that provides the following features:
-/// - `Modifier` is thread-safe.
-/// - `Modifier` is safe to use in multi-threaded environment.
-/// - `Modifier` is safe to use in multi-process environment.
-
-
-impl Modifier {
-    pub fn new(engine: EinsteinEngine) -> Self {
-        Self {
-            engine: Arc::new(Mutex::new(engine)),
-            is_closed: AtomicBool::new(false),
-        }
-    }
-
-    pub fn is_closed(&self) -> bool {
-        self.is_closed.load(Ordering::Relaxed)
-    }
-
-    pub fn close(&self) {
-        self.is_closed.store(true, Ordering::Relaxed);
-    }
-
-    pub fn get_engine(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_impl(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_snapshot(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_write_batch(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_iterator(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_modify(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_snapshot_impl(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_write_batch_impl(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_iterator_impl(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_modify_impl(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone
-    }
-
-    pub fn get_engine_impl_(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_snapshot_impl_(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_write_batch_impl_(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-
-    pub fn get_engine_iterator_impl_(&self) -> Arc<Mutex<EinsteinEngine>> {
-        Arc::clone(&self.engine)
-    }
-}
-
+use crate::codec::NumberCodec;
+use crate::constants::*;
+use crate::error::Result;
+use crate::json::{Json, JsonRef, JsonType};
+use crate::json_extract::extract_json;
+use crate::path_expr::{ArraySelection, LocalPathExpression, LocalPathLeg};
 
 /// A helper struct that derives a new JSON by combining and manipulating
 /// the encoded bytes directly. Only used by `json_replace`, `json_set`,
@@ -205,81 +27,29 @@ impl Modifier {
 ///
 /// See `binaryModifier` in MEDB `json/binary_function.go`
 pub struct BinaryModifier<'a> {
-
-    /// The underlying engine
-    /// This is the engine that the `BinaryModifier` is wrapping.
-    /// It is used to get the underlying engine.
-
-    engine: &'a mut EinsteinEngine,
-
-    /// The underlying engine snapshot
-    /// This is the engine snapshot that the `BinaryModifier` is wrapping.
-    /// It is used to get the underlying engine snapshot.
-
-    engine_snapshot: EinsteinEngineSnapshot,
-
-    /// The underlying engine write batch
-    /// This is the engine write batch that the `BinaryModifier` is wrapping.
-    /// It is used to get the underlying engine write batch.
-    /// It is used to get the underlying engine write batch.
-
-    engine_write_batch: EinsteinEngineWriteBatch,
-
-
-    /// The underlying engine iterator
-    /// This is the engine iterator that the `BinaryModifier` is wrapping.
-    ///
-
-    engine_iterator: EinsteinEngineIterator,
-
-    /// The encoded bytes of the JSON
-
-    encoded: &'a [u8],
-
     // The target Json to be modified
     old: JsonRef<'a>,
     // The ptr point to the memory location of `old.causet_locale` that `new_causet_locale` should be appended
     to_be_modified_ptr: *const u8,
-    //ODO(fullstop000): Can we just use Json instead ?
-    new: JsonRef<'a>,
-    
+    // The new causet_locale to be appended at `to_be_modified_ptr`
     new_causet_locale: Option<Json>,
-
-    // The target Json to be inserted
-    insert: JsonRef<'a>,
-
-    // The target Json to be removed
-    remove: JsonRef<'a>,
-
-    // The target Json to be replaced
-    replace: JsonRef<'a>,
 }
 
-///! A helper struct that derives a new JSON by combining and manipulating
 impl<'a> BinaryModifier<'a> {
     /// Creates a new `BinaryModifier` from a `JsonRef`
-    pub fn new(old: JsonRef<'a>) -> BinaryModifier<'_> {
+    pub fn new(old: JsonRef<'a>) -> BinaryModifier<'a> {
         BinaryModifier {
             // The initial offset is 0 by `as_ref()` call
-            engine: &mut (),
-            engine_snapshot: (),
-            engine_write_batch: (),
-            engine_iterator: (),
-            encoded: &[],
             old,
             // Mark invalid
             to_be_modified_ptr: ptr::null(),
-            new: (),
             new_causet_locale: None,
-            insert: (),
-            remove: (),
-            replace: ()
         }
     }
 
     /// Replaces the existing causet_locale JSON and adds nonexisting causet_locale
     /// specified by the expression local_path with `new`
-    pub fn set(mut self, local_path: &local_pathExpression, new: Json) -> Result<Json> {
+    pub fn set(mut self, local_path: &LocalPathExpression, new: Json) -> Result<Json> {
         let result = extract_json(self.old, local_path.legs.as_slice())?;
         if !result.is_empty() {
             self.to_be_modified_ptr = result[0].as_ptr();
@@ -291,7 +61,7 @@ impl<'a> BinaryModifier<'a> {
     }
 
     /// Replaces the existing causet_locale JSON specified by the expression local_path with `new`
-    pub fn replace(mut self, local_path: &local_pathExpression, new: Json) -> Result<Json> {
+    pub fn replace(mut self, local_path: &LocalPathExpression, new: Json) -> Result<Json> {
         let result = extract_json(self.old, local_path.legs.as_slice())?;
         if result.is_empty() {
             return Ok(self.old.to_owned());
@@ -303,7 +73,7 @@ impl<'a> BinaryModifier<'a> {
 
     /// Inserts a `new` into `old` JSON document by given expression local_path without replacing
     /// existing causet_locales
-    pub fn insert(mut self, local_path: &local_pathExpression, new: Json) -> Result<Json> {
+    pub fn insert(mut self, local_path: &LocalPathExpression, new: Json) -> Result<Json> {
         let result = extract_json(self.old, local_path.legs.as_slice())?;
         if !result.is_empty() {
             // The local_path-causet_locale is existing. The insertion is ignored with no overwrite.
@@ -313,19 +83,22 @@ impl<'a> BinaryModifier<'a> {
         self.rebuild()
     }
 
-    fn do_insert(&mut self, local_path_legs: &[local_pathLeg], new: Json) -> Result<()> {
+    fn do_insert(&mut self, local_path_legs: &[LocalPathLeg], new: Json) -> Result<()> {
         if local_path_legs.is_empty() {
             return Ok(());
         }
         let legs_len = local_path_legs.len();
-        let (parent_legs, last_leg) = (&local_path_legs[..legs_len - 1], &local_path_legs[legs_len - 1]);
+        let (parent_legs, last_leg) = (
+            &local_path_legs[..legs_len - 1],
+            &local_path_legs[legs_len - 1],
+        );
         let result = extract_json(self.old, parent_legs)?;
         if result.is_empty() {
             return Ok(());
         }
         let parent_node = &result[0];
-        match &*last_leg {
-            local_pathLeg::Index(_) => {
+        match last_leg {
+            LocalPathLeg::ArraySelection(_) => {
                 // Record the parent node causet_locale offset, as it's actually relative to `old`
                 self.to_be_modified_ptr = parent_node.as_ptr();
                 match parent_node.get_type() {
@@ -345,7 +118,7 @@ impl<'a> BinaryModifier<'a> {
                     }
                 }
             }
-            local_pathLeg::Key(insert_soliton_id) => {
+            LocalPathLeg::Key(insert_soliton_id) => {
                 // Ignore constant
                 if parent_node.get_type() != JsonType::Object {
                     return Ok(());
@@ -382,7 +155,7 @@ impl<'a> BinaryModifier<'a> {
         Ok(())
     }
 
-    pub fn remove(mut self, local_path_legs: &[local_pathLeg]) -> Result<Json> {
+    pub fn remove(mut self, local_path_legs: &[LocalPathLeg]) -> Result<Json> {
         let result = extract_json(self.old, local_path_legs)?;
         if result.is_empty() {
             return Ok(self.old.to_owned());
@@ -391,47 +164,51 @@ impl<'a> BinaryModifier<'a> {
         self.rebuild()
     }
 
-    fn do_remove(&mut self, local_path_legs: &[local_pathLeg]) -> Result<()> {
+    fn do_remove(&mut self, local_path_legs: &[LocalPathLeg]) -> Result<()> {
         if local_path_legs.is_empty() {
             return Ok(());
         }
         let legs_len = local_path_legs.len();
-        let (parent_legs, last_leg) = (&local_path_legs[..legs_len - 1], &local_path_legs[legs_len - 1]);
+        let (parent_legs, last_leg) = (
+            &local_path_legs[..legs_len - 1],
+            &local_path_legs[legs_len - 1],
+        );
         let result = extract_json(self.old, parent_legs)?;
         if result.is_empty() {
             // No parent found, just return
             return Ok(());
         }
         let parent_node = &result[0];
-        match &*last_leg {
-            local_pathLeg::Index(remove_idx) => {
-                if parent_node.get_type() == JsonType::Array {
-                    self.to_be_modified_ptr = parent_node.as_ptr();
-                    let elems_count = parent_node.get_elem_count();
-                    let mut elems = Vec::with_capacity(elems_count - 1);
-                    let remove_idx = *remove_idx as usize;
-                    for i in 0..elems_count {
-                        if i != remove_idx {
-                            elems.push(parent_node.array_get_elem(i)?);
-                        }
+        match last_leg {
+            LocalPathLeg::ArraySelection(ArraySelection::Index(remove_idx))
+                if parent_node.get_type() == JsonType::Array =>
+            {
+                let elems_count = parent_node.get_elem_count();
+                let remove_idx = match remove_idx.resolve(elems_count) {
+                    Some(idx) => idx,
+                    None => return Ok(()),
+                };
+                self.to_be_modified_ptr = parent_node.as_ptr();
+                let mut elems = Vec::with_capacity(elems_count - 1);
+                for i in 0..elems_count {
+                    if i != remove_idx {
+                        elems.push(parent_node.array_get_elem(i)?);
                     }
-                    self.new_causet_locale = Some(Json::from_ref_array(elems)?);
                 }
+                self.new_causet_locale = Some(Json::from_ref_array(elems)?);
             }
-            local_pathLeg::Key(remove_soliton_id) => {
-                // Ignore constant
-                if parent_node.get_type() == JsonType::Object {
-                    self.to_be_modified_ptr = parent_node.as_ptr();
-                    let elem_count = parent_node.get_elem_count();
-                    let mut entries = Vec::with_capacity(elem_count - 1);
-                    for i in 0..elem_count {
-                        let soliton_id = parent_node.object_get_soliton_id(i);
-                        if soliton_id != remove_soliton_id.as_bytes() {
-                            entries.push((soliton_id, parent_node.object_get_val(i)?));
-                        }
+            // Ignore constant
+            LocalPathLeg::Key(remove_soliton_id) if parent_node.get_type() == JsonType::Object => {
+                self.to_be_modified_ptr = parent_node.as_ptr();
+                let elem_count = parent_node.get_elem_count();
+                let mut entries = Vec::with_capacity(elem_count - 1);
+                for i in 0..elem_count {
+                    let soliton_id = parent_node.object_get_soliton_id(i);
+                    if soliton_id != remove_soliton_id.as_bytes() {
+                        entries.push((soliton_id, parent_node.object_get_val(i)?));
                     }
-                    self.new_causet_locale = Some(Json::from_einsteindb_fdb_kv_pairs(entries)?);
                 }
+                self.new_causet_locale = Some(Json::from_einsteindb_fdb_kv_pairs(entries)?);
             }
             _ => {}
         }
@@ -440,7 +217,11 @@ impl<'a> BinaryModifier<'a> {
 
     fn rebuild(&mut self) -> Result<Json> {
         let mut buf = Vec::with_capacity(
-            self.old.causet_locale.len() + self.new_causet_locale.as_ref().map_or(0, |v| v.causet_locale.len()),
+            self.old.causet_locale.len()
+                + self
+                    .new_causet_locale
+                    .as_ref()
+                    .map_or(0, |v| v.causet_locale.len()),
         );
         let new_tp = self.rebuild_to(&mut buf)?;
         Ok(Json::new(new_tp, buf))
@@ -488,16 +269,19 @@ impl<'a> BinaryModifier<'a> {
                         // Append soliton_ids
                         if elem_count > 0 {
                             let first_soliton_id_offset =
-                                NumberCodec::decode_u32_le(&current.causet_locale[HEADER_LEN..]) as usize;
+                                NumberCodec::decode_u32_le(&current.causet_locale[HEADER_LEN..])
+                                    as usize;
                             let last_soliton_id_offset = NumberCodec::decode_u32_le(
-                                &current.causet_locale[HEADER_LEN + (elem_count - 1) * KEY_ENTRY_LEN..],
+                                &current.causet_locale
+                                    [HEADER_LEN + (elem_count - 1) * KEY_ENTRY_LEN..],
                             ) as usize;
                             let last_soliton_id_len = NumberCodec::decode_u16_le(
                                 &current.causet_locale
                                     [HEADER_LEN + (elem_count - 1) * KEY_ENTRY_LEN + U32_LEN..],
                             ) as usize;
                             buf.extend_from_slice(
-                                &current.causet_locale[first_soliton_id_offset..last_soliton_id_offset + last_soliton_id_len],
+                                &current.causet_locale[first_soliton_id_offset
+                                    ..last_soliton_id_offset + last_soliton_id_len],
                             );
                         }
                         HEADER_LEN + elem_count * KEY_ENTRY_LEN
@@ -541,4 +325,4 @@ impl<'a> BinaryModifier<'a> {
         }
         Ok(tp)
     }
-}
\ No newline at end of file
+}
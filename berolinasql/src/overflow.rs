@@ -1,59 +1,33 @@
-/// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
-/// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
-/// this file except in compliance with the License. You may obtain a copy of the
-/// License at http://www.apache.org/licenses/LICENSE-2.0
-/// Unless required by applicable law or agreed to in writing, software distributed
-/// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
-/// CONDITIONS OF ANY KIND, either express or implied. See the License for the
-/// specific language governing permissions and limitations under the License.
-
-
-
-use std::error::Error;
-
-
-
-use ::std::{
-    collections::HashMap,
-    fmt::{self, Display},
-    io::{self, Write},
-    ops::{Deref, DerefMut},
-    result::Result,
-    str::FromStr,
-    sync::{Arc, Mutex},
-    time::{Duration, Instant},
-};
-
-
-use EinsteinDB::berolinasql::{
-    self,
-    db::{self, DB},
-    error::{self, Error},
-    options::{self, Options},
-    types::{self, Type},
-    util::{self, Key},
-};
-
-
-///4` divides i64 a with b and returns:
-/// - an Error indicating over_causetxctx occurred or the divisor is 0
-///. - i64 otherwise
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+use crate::error::{Error, Result};
+
+/// `div_i64` divides i64 a with b and returns:
+/// - an Error indicating overflow occurred or the divisor is 0
+/// - i64 otherwise
 #[inline]
 pub fn div_i64(a: i64, b: i64) -> Result<i64> {
     if b == 0 {
         return Err(Error::division_by_zero());
     }
-    match a.overCausetxctxing_div(b) {
-        (_res, true) => Err(Error::overCausetxctx(
+    match a.overflowing_div(b) {
+        (_res, true) => Err(Error::overflow(
             "UNSIGNED BIGINT",
-            &format!("({} / {})", a, b),
+            format!("({} / {})", a, b),
         )),
         (res, false) => Ok(res),
     }
 }
 
 /// `div_u64_with_i64` divides u64 a with i64 b and returns:
-/// - an Error indicating over_causetxctx occurred or the divisor is 0
+/// - an Error indicating overflow occurred or the divisor is 0
 /// - u64 otherwise
 #[inline]
 pub fn div_u64_with_i64(a: u64, b: i64) -> Result<u64> {
@@ -61,10 +35,10 @@ pub fn div_u64_with_i64(a: u64, b: i64) -> Result<u64> {
         return Err(Error::division_by_zero());
     }
     if b < 0 {
-        if a != 0 && (b.overCausetxctxing_neg().0 as u64) <= a {
-            Err(Error::overCausetxctx(
+        if a != 0 && (b.overflowing_neg().0 as u64) <= a {
+            Err(Error::overflow(
                 "UNSIGNED BIGINT",
-                &format!("({} / {})", a, b),
+                format!("({} / {})", a, b),
             ))
         } else {
             Ok(0)
@@ -75,7 +49,7 @@ pub fn div_u64_with_i64(a: u64, b: i64) -> Result<u64> {
 }
 
 /// `div_i64_with_u64` divides i64 a with u64 b and returns:
-/// - an Error indicating over_causetxctx occurred or the divisor is 0
+/// - an Error indicating overflow occurred or the divisor is 0
 /// - u64 otherwise
 #[inline]
 pub fn div_i64_with_u64(a: i64, b: u64) -> Result<u64> {
@@ -83,10 +57,10 @@ pub fn div_i64_with_u64(a: i64, b: u64) -> Result<u64> {
         return Err(Error::division_by_zero());
     }
     if a < 0 {
-        if a.overCausetxctxing_neg().0 as u64 >= b {
-            Err(Error::overCausetxctx(
+        if a.overflowing_neg().0 as u64 >= b {
+            Err(Error::overflow(
                 "UNSIGNED BIGINT",
-                &format!("({} / {})", a, b),
+                format!("({} / {})", a, b),
             ))
         } else {
             Ok(0)
@@ -96,22 +70,21 @@ pub fn div_i64_with_u64(a: i64, b: u64) -> Result<u64> {
     }
 }
 
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
-    use crate::codec::error::{ERR_DATA_OUT_OF_RANGE, ERR_DIVISION_BY_ZERO};
-    use std::{i64, u64};
+    use crate::error::{ERR_DATA_OUT_OF_RANGE, ERR_DIVISION_BY_ZERO};
 
     macro_rules! do_test {
-        ($cases:solitonid, $func:solitonid) => {
-            for (lsh, rsh, exp, is_overCausetxctx) in $cases {
+        ($cases:ident, $func:ident) => {
+            for (lsh, rsh, exp, is_overflow) in $cases {
                 let desc = format!("Error testing {}({}, {})", stringify!($func), lsh, rsh);
                 match super::$func(lsh, rsh) {
                     Ok(res) => {
-                        assert!(!is_overCausetxctx, "{}: overCausetxctxed unexpectedly", desc);
+                        assert!(!is_overflow, "{}: overflowed unexpectedly", desc);
                         assert_eq!(res, exp, "{}: expect {} but got {}", desc, exp, res);
                     }
                     Err(e) => {
-                        assert!(is_overCausetxctx, "{}: expect over_causetxctx", desc);
+                        assert!(is_overflow, "{}: expect overflow", desc);
                         assert_eq!(e.code(), ERR_DATA_OUT_OF_RANGE);
                     }
                 }
@@ -8,217 +8,444 @@
 // limitations under the License.
 //
 
-
-use super::*;
-use crate::error::{Error, Result};
-use crate::parser::{Parser, ParserError};
-use crate::value::{Value, ValueType};
-use crate::{ValueRef, ValueRefMut};
-use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
-use std::io;
+use std::ops::{Range, RangeBounds};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
+use crate::codec::{BufferReader, BufferWriter, NumberDecoder, NumberEncoder};
+use crate::error::{Error, Result};
+use crate::field_type::Collation;
+use crate::generated_column::{GeneratedColumn, GeneratedColumnKind, GeneratedExpr};
+use crate::json::Json;
+use crate::json_schema::JsonSchema;
+use crate::value::{Value, ValueType};
 
 #[derive(Debug)]
 pub struct Table {
     pub name: String,
     pub columns: Vec<Column>,
     pub rows: Vec<Row>,
+    pub indexes: Vec<Index>,
 }
 
-
 impl Table {
     pub fn new(name: String, columns: Vec<Column>, rows: Vec<Row>) -> Self {
         Table {
             name,
             columns,
             rows,
+            indexes: Vec::new(),
         }
     }
-}
-
-
-#[derive(Debug)]
-pub struct Column {
-    pub name: String,
-    pub value_type: ValueType,
-}
 
+    pub fn column_offset(&self, name: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(name))
+    }
 
-impl Column {
-    pub fn new(name: String, value_type: ValueType) -> Self {
-        Column {
-            name,
-            value_type,
+    /// Adds a causet_merge generated by `expr` (e.g. `doc->>'$.user.id'`), filling it in
+    /// for the rows already in the table.
+    pub fn add_generated_column(
+        &mut self,
+        name: String,
+        value_type: ValueType,
+        expr: &str,
+        kind: GeneratedColumnKind,
+    ) -> Result<()> {
+        if self.column_offset(&name).is_some() {
+            return Err(box_err!("duplicate causet_merge name '{}'", name));
+        }
+        let expr = GeneratedExpr::parse(expr, |c| self.column_offset(c))?;
+        let source = &self.columns[expr.source];
+        if !matches!(source.value_type, ValueType::Json) {
+            return Err(box_err!(
+                "generated causet_merge '{}' must be extracted from a JSON causet_merge, '{}' is not one",
+                name,
+                source.name
+            ));
         }
+        let generated = GeneratedColumn::new(expr, kind);
+        let mut causet_locales = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            causet_locales.push(if generated.is_stored() {
+                generated.expr.eval(&row.values)?
+            } else {
+                Value::Null
+            });
+        }
+        for (row, causet_locale) in self.rows.iter_mut().zip(causet_locales) {
+            row.values.push(causet_locale);
+        }
+        self.columns
+            .push(Column::new(name, value_type).with_generated(generated));
+        Ok(())
     }
-}
-
 
-#[derive(Debug)]
-pub struct Row {
-    pub values: Vec<Value>,
-}
+    /// Creates an index on `causet_merge`, which is typically a generated causet_merge over
+    /// a JSON local_path.
+    pub fn create_index(&mut self, name: String, causet_merge: &str) -> Result<()> {
+        self.add_index(name, causet_merge, false)
+    }
 
+    /// Like `create_index`, but rows may not share a non-`NULL` causet_locale of
+    /// `causet_merge`. Fails, leaving the table without the index, if they already do.
+    pub fn create_unique_index(&mut self, name: String, causet_merge: &str) -> Result<()> {
+        self.add_index(name, causet_merge, true)
+    }
 
+    fn add_index(&mut self, name: String, causet_merge: &str, unique: bool) -> Result<()> {
+        if self
+            .indexes
+            .iter()
+            .any(|idx| idx.name.eq_ignore_ascii_case(&name))
+        {
+            return Err(box_err!("duplicate index name '{}'", name));
+        }
+        let offset = self.column_offset(causet_merge).ok_or_else(|| {
+            box_err!(
+                "unknown causet_merge '{}' in table {}",
+                causet_merge,
+                self.name
+            )
+        })?;
+        let mut index = Index::new(name, offset);
+        index.unique = unique;
+        for row_offset in 0..self.rows.len() {
+            let causet_locale = self.causet_locale(row_offset, offset)?;
+            index.check_unique(&causet_locale, None)?;
+            index.insert(&causet_locale, row_offset);
+        }
+        self.indexes.push(index);
+        Ok(())
+    }
 
+    /// Returns the causet_locale of `causet_merge` in the row at `offset`, evaluating
+    /// virtual generated columns on the fly.
+    pub fn causet_locale(&self, offset: usize, causet_merge: usize) -> Result<Value> {
+        self.row_causet_locale(&self.rows[offset], causet_merge)
+    }
 
-impl Row {
-    pub fn new(values: Vec<Value>) -> Self {
-        Row {
-            values,
+    fn row_causet_locale(&self, row: &Row, causet_merge: usize) -> Result<Value> {
+        match &self.columns[causet_merge].generated {
+            Some(generated) if !generated.is_stored() => generated.expr.eval(&row.values),
+            _ => Ok(row.values[causet_merge].clone()),
         }
     }
-}
 
+    /// Appends `row`, rejecting it if any causet_merge constraint is violated. The
+    /// table is left unchanged if `row` is rejected.
+    pub fn insert(&mut self, mut row: Row) -> Result<()> {
+        self.fill_generated(&mut row)?;
+        self.check_row(&row)?;
+        let soliton_ids = self.index_causet_locales(&row)?;
+        self.check_unique(&soliton_ids, None)?;
+        self.rows.push(row);
+        self.index_row(self.rows.len() - 1, soliton_ids, true);
+        Ok(())
+    }
 
-use crate::fdb_traits::FdbTrait;
-use crate::fdb_traits::FdbTraitImpl;
-use crate::fdb_traits_impl::FdbTraitImplImpl;
+    /// Replaces the row at `offset`, the old row is kept if `row` is rejected.
+    pub fn update(&mut self, offset: usize, mut row: Row) -> Result<()> {
+        if offset >= self.rows.len() {
+            return Err(box_err!(
+                "row offset {} out of range, table has {} rows",
+                offset,
+                self.rows.len()
+            ));
+        }
+        self.fill_generated(&mut row)?;
+        self.check_row(&row)?;
+        let old_soliton_ids = self.index_causet_locales(&self.rows[offset])?;
+        let soliton_ids = self.index_causet_locales(&row)?;
+        self.check_unique(&soliton_ids, Some(offset))?;
+        self.index_row(offset, old_soliton_ids, false);
+        self.rows[offset] = row;
+        self.index_row(offset, soliton_ids, true);
+        Ok(())
+    }
 
+    pub fn check_row(&self, row: &Row) -> Result<()> {
+        if row.values.len() != self.columns.len() {
+            return Err(box_err!(
+                "row has {} causet_locales but table {} has {} columns",
+                row.values.len(),
+                self.name,
+                self.columns.len()
+            ));
+        }
+        for (causet_merge, causet_locale) in self.columns.iter().zip(&row.values) {
+            causet_merge.check(causet_locale)?;
+        }
+        Ok(())
+    }
 
-use std::{
-    collections::HashMap,
-    fmt::{self, Display},
-    io,
-    convert::{TryFrom, TryInto},
-    ops::{Deref, DerefMut},
-    sync::{Arc, Mutex},
-};
+    /// Returns the index whose causet_merge is generated by exactly `expr`, if any.
+    pub fn index_for_expr(&self, expr: &GeneratedExpr) -> Option<&Index> {
+        self.indexes.iter().find(|idx| {
+            self.columns[idx.causet_merge]
+                .generated
+                .as_ref()
+                .is_some_and(|g| &g.expr == expr)
+        })
+    }
 
+    /// Returns the offsets of the rows whose `expr` (e.g. `doc->>'$.user.id'`) lies in
+    /// `range`, in ascending order. An index on a causet_merge generated by the same
+    /// expression is used when there is one, otherwise every row is evaluated.
+    pub fn json_extract_lookup<R: RangeBounds<Json>>(
+        &self,
+        expr: &str,
+        range: R,
+    ) -> Result<Vec<usize>> {
+        let expr = GeneratedExpr::parse(expr, |c| self.column_offset(c))?;
+        if let Some(index) = self.index_for_expr(&expr) {
+            return Ok(index.range(range));
+        }
+        let mut offsets = Vec::new();
+        for (offset, row) in self.rows.iter().enumerate() {
+            if let Some(soliton_id) = Index::soliton_id(&expr.eval(&row.values)?) {
+                if range.contains(&soliton_id) {
+                    offsets.push(offset);
+                }
+            }
+        }
+        Ok(offsets)
+    }
 
+    /// Returns the row offsets ordered by `causet_merge`, as `ORDER BY causet_merge` would:
+    /// strings compare under the causet_merge's collation, NULLs come first (last when
+    /// `desc`) and rows that compare equal keep their order.
+    pub fn order_by(&self, causet_merge: &str, desc: bool) -> Result<Vec<usize>> {
+        let offset = self.column_offset(causet_merge).ok_or_else(|| {
+            box_err!(
+                "unknown causet_merge '{}' in table {}",
+                causet_merge,
+                self.name
+            )
+        })?;
+        let collation = self.columns[offset].collation;
+        let mut soliton_ids = Vec::with_capacity(self.rows.len());
+        for row_offset in 0..self.rows.len() {
+            let causet_locale = self.causet_locale(row_offset, offset)?;
+            soliton_ids.push((causet_locale.sort_key(collation)?, row_offset));
+        }
+        if desc {
+            soliton_ids.sort_by(|(a, _), (b, _)| b.cmp(a));
+        } else {
+            soliton_ids.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+        Ok(soliton_ids.into_iter().map(|(_, o)| o).collect())
+    }
 
-#[derive(Debug)]
-pub struct TableImpl {
-    pub name: String,
-    pub columns: Vec<Column>,
-    pub rows: Vec<Row>,
-    pub fdb_trait: FdbTraitImpl,
-}
+    /// Computes the stored generated columns of `row`; virtual ones are kept as `NULL`.
+    fn fill_generated(&self, row: &mut Row) -> Result<()> {
+        for (offset, causet_merge) in self.columns.iter().enumerate() {
+            let generated = match &causet_merge.generated {
+                Some(generated) => generated,
+                None => continue,
+            };
+            match row.values.get(offset) {
+                Some(Value::Null) => {}
+                Some(_) => {
+                    return Err(box_err!(
+                        "The causet_locale specified for generated causet_merge '{}' in table '{}' is not allowed.",
+                        causet_merge.name,
+                        self.name
+                    ))
+                }
+                None => continue,
+            }
+            if generated.is_stored() {
+                row.values[offset] = generated.expr.eval(&row.values)?;
+            }
+        }
+        Ok(())
+    }
 
+    /// Returns the causet_locales `row` is indexed by, one per index. They are computed
+    /// before the table is changed, so that a failing evaluation leaves it intact.
+    fn index_causet_locales(&self, row: &Row) -> Result<Vec<Value>> {
+        self.indexes
+            .iter()
+            .map(|index| self.row_causet_locale(row, index.causet_merge))
+            .collect()
+    }
 
-#[derive(Debug)]
-pub struct TableRef {
-    pub table: Arc<Mutex<TableImpl>>,
+    /// Checks that a row indexed by `causet_locales` would not duplicate a soliton_id of a
+    /// unique index. `offset` is the row being replaced, whose own soliton_ids don't count.
+    fn check_unique(&self, causet_locales: &[Value], offset: Option<usize>) -> Result<()> {
+        for (index, causet_locale) in self.indexes.iter().zip(causet_locales) {
+            index.check_unique(causet_locale, offset)?;
+        }
+        Ok(())
+    }
+
+    /// Adds (or removes) the row at `offset` to (or from) every index, under the
+    /// causet_locales returned by `index_causet_locales`.
+    fn index_row(&mut self, offset: usize, causet_locales: Vec<Value>, add: bool) {
+        for (index, causet_locale) in self.indexes.iter_mut().zip(causet_locales) {
+            if add {
+                index.insert(&causet_locale, offset);
+            } else {
+                index.remove(&causet_locale, offset);
+            }
+        }
+    }
 }
 
+/// A secondary index over one causet_merge. Keys are kept as JSON so that they order
+/// like the `json_extract` results they are looked up with; `NULL`s are not indexed.
 #[derive(Debug)]
-pub struct TableRefMut {
-    pub table: Arc<Mutex<TableImpl>>,
+pub struct Index {
+    pub name: String,
+    pub causet_merge: usize,
+    pub unique: bool,
+    entries: BTreeMap<Json, Vec<usize>>,
 }
 
+impl Index {
+    pub fn new(name: String, causet_merge: usize) -> Self {
+        Index {
+            name,
+            causet_merge,
+            unique: false,
+            entries: BTreeMap::new(),
+        }
+    }
 
+    /// Fails if this is a unique index and a row other than `offset` already has
+    /// `causet_locale` as its soliton_id.
+    fn check_unique(&self, causet_locale: &Value, offset: Option<usize>) -> Result<()> {
+        if !self.unique {
+            return Ok(());
+        }
+        if let Some(soliton_id) = Index::soliton_id(causet_locale) {
+            if let Some(offsets) = self.entries.get(&soliton_id) {
+                if offsets.iter().any(|o| Some(*o) != offset) {
+                    return Err(box_err!(
+                        "Duplicate entry '{}' for soliton_id '{}'",
+                        soliton_id,
+                        self.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 
+    fn soliton_id(causet_locale: &Value) -> Option<Json> {
+        match causet_locale {
+            Value::Json(j) => Some(j.clone()),
+            Value::String(s) => Some(Json::from_string(s.clone()).ok()?),
+            Value::Int(i) => Some(Json::from_i64(*i).ok()?),
+            _ => None,
+        }
+    }
 
+    fn insert(&mut self, causet_locale: &Value, offset: usize) {
+        if let Some(soliton_id) = Index::soliton_id(causet_locale) {
+            self.entries.entry(soliton_id).or_default().push(offset);
+        }
+    }
 
+    fn remove(&mut self, causet_locale: &Value, offset: usize) {
+        if let Some(soliton_id) = Index::soliton_id(causet_locale) {
+            if let Some(offsets) = self.entries.get_mut(&soliton_id) {
+                offsets.retain(|o| *o != offset);
+                if offsets.is_empty() {
+                    self.entries.remove(&soliton_id);
+                }
+            }
+        }
+    }
 
-///ttl for primitive type in EinsteinDB
-/// time to live for primitive type in EinsteinDB
-/// 
-/// # Arguments
-/// * `ttl` - ttl for primitive type in EinsteinDB
-/// 
-/// # Returns
-/// * `Option<u64>` - expire_ts for primitive type in EinsteinDB
-/// 
-/// # Example
-/// 
-/// ```rust
-/// use einsteindb_sql::table::ttl_to_expire_ts;
-/// 
-/// let ttl = 0;
-/// 
-/// let expire_ts = ttl_to_expire_ts(ttl);
-/// 
-/// assert_eq!(expire_ts, None);
-/// 
-/// let ttl = 1;
-/// 
-/// let expire_ts = ttl_to_expire_ts(ttl);
-/// 
-/// assert_eq!(expire_ts, Some(2));
-/// 
-use berolina_sql::{
-    ttl_current_ts,
-    ttl_to_expire_ts,
-    ttl_expire_ts,
-    ast::{self, Expr, ExprKind, Field, FieldType, FieldTypeTp, FieldTypeVisitor},
-    Column,
-    EvalContext,
-    EvalContextImpl,
-    Result,
-    Row,
-    Rows,
-    RowsIter,
-    ScalarFunc,
-    ScalarFuncArgs,
-    ScalarFuncCall,
-    ScalarFuncCallArgs,
-    ScalarFuncCallType,
-    ScalarValue,
-    TypeFlag,
-    Value,
-};
-
-use einsteindb::{ColumnInfo, ColumnInfo_Type, IndexInfo, IndexInfo_Type, IndexInfo_Unique};
-use einsteindb::{IndexType, IndexTypeTp, IndexTypeTpFlag, TableInfo, TableInfo_Type};
-use einstein_db::Causetid;
-use soliton::types::{
-    ColumnType, ColumnTypeFlag, ColumnTypeTp, ColumnTypeTpFlag, ColumnTypeTpFlagVec,
-    ColumnTypeTpVec,
-};
-
-
-use einsteindb_util::time::UnixSecs;
-use einsteindb_util::time::{Duration, DurationSecs};
-
-
-use einsteindb_util::time::{DurationSecs, DurationSecs as DurationSecsT};
+    /// Returns the offsets of the rows whose soliton_id lies in `range`, in ascending order.
+    pub fn range<R: RangeBounds<Json>>(&self, range: R) -> Vec<usize> {
+        let mut offsets: Vec<usize> = self
+            .entries
+            .range(range)
+            .flat_map(|(_, o)| o.iter().copied())
+            .collect();
+        offsets.sort_unstable();
+        offsets
+    }
+}
 
+#[derive(Debug)]
+pub struct Column {
+    pub name: String,
+    pub value_type: ValueType,
+    /// Documents written to a JSON causet_merge must satisfy this schema when it is set.
+    pub json_schema: Option<JsonSchema>,
+    pub generated: Option<GeneratedColumn>,
+    /// How strings in the causet_merge compare, `utf8mb4_bin` by default.
+    pub collation: Collation,
+}
 
+impl Column {
+    pub fn new(name: String, value_type: ValueType) -> Self {
+        Column {
+            name,
+            value_type,
+            json_schema: None,
+            generated: None,
+            collation: Collation::Utf8Mb4Bin,
+        }
+    }
 
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
+    }
 
+    pub fn with_json_schema(mut self, json_schema: JsonSchema) -> Self {
+        self.json_schema = Some(json_schema);
+        self
+    }
 
+    pub fn with_generated(mut self, generated: GeneratedColumn) -> Self {
+        self.generated = Some(generated);
+        self
+    }
+    /// Checks `causet_locale` against the causet_merge constraints.
+    pub fn check(&self, causet_locale: &Value) -> Result<()> {
+        if let (Some(schema), Value::Json(doc)) = (&self.json_schema, causet_locale) {
+            if let Some(report) = schema.validate(doc.as_ref())? {
+                return Err(Error::json_schema_violation(self.name.clone(), report));
+            }
+        }
+        Ok(())
+    }
+}
 
 #[derive(Debug)]
-#[allow(dead_code)]
-pub struct TableRefImpl {
-    pub table: Arc<Mutex<TableImpl>>,
+pub struct Row {
+    pub values: Vec<Value>,
 }
 
-#[allow(unused_imports)]
-use einsteindb_util::time::{DurationSecs, DurationSecs as DurationSecsT};
-
+impl Row {
+    pub fn new(values: Vec<Value>) -> Self {
+        Row { values }
+    }
+}
 
 #[derive(Debug)]
-#[allow(dead_code)]
-pub struct TableRefMutImpl {
-    pub table: Arc<Mutex<TableImpl>>,
+pub struct TableRef {
+    pub table: Arc<Mutex<Table>>,
 }
-impl Table {
-    pub fn new(name: String, columns: Vec<Column>, indexes: Vec<Index>, primary_key: Vec<String>, ttl: u64, ttl_column: String, ttl_column_type: ColumnType, ttl_column_type_flag: ColumnTypeFlag, ttl_column_type_tp: ColumnTypeTp) -> Self {
-        let mut table = Table {
-            name,
-            columns,
-            rows: Vec::new(),
-        };
-
 
-    }
+#[derive(Debug)]
+pub struct TableRefMut {
+    pub table: Arc<Mutex<Table>>,
 }
 
 // handle or Index id
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Id(u64);
 
-
-
-pub struct TableRefImplImpl {
-    pub table: Arc<Mutex<TableImpl>>,
-}
-
 impl Id {
     pub fn new(id: u64) -> Self {
         Id(id)
@@ -241,84 +468,30 @@ impl Id {
     }
 }
 
-
 impl fmt::Display for Id {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
     }
-
-    fn fmt_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
 }
 
-
 impl FromStr for Id {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let id = s.parse::<u64>()?;
-        Ok(Id::new(id))
-    }
-
-    fn from_str_radix(s: &str, radix: u32) -> Result<Self> {
-        let id = s.parse::<u64>()?;
-        Ok(Id::new(id))
-    }
-
-    fn from_str_radix_lossy(s: &str, radix: u32) -> Result<Self> {
-        let id = s.parse::<u64>()?;
-        Ok(Id::new(id))
-    }
-
-    fn from_str_lossy(s: &str) -> Result<Self> {
-        let id = s.parse::<u64>()?;
+        let id = box_try!(s.parse::<u64>());
         Ok(Id::new(id))
     }
-
-    fn from_str_lossy_radix(s: &str, radix: u32) -> Result<Self> {
-        let id = s.parse::<u64>()?;
-        Ok(Id::new(id))
-    }
-
-    
-}
-
-
-impl Hash for Id {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state);
-    }
-
-    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
-        for id in data {
-            id.hash(state);
-        }
-    }
-
-    fn hash_to_bytes(data: &[Self]) -> Vec<u8> {
-        let mut hasher = DefaultHasher::new();
-        Self::hash_slice(data, &mut hasher);
-        hasher.finish().to_be_bytes().to_vec()
-    }
-
-    fn hash_to_hex(data: &[Self]) -> String {
-        let mut hasher = DefaultHasher::new();
-        Self::hash_slice(data, &mut hasher);
-        format!("{:x}", hasher.finish())
-    }
 }
 
 //Deferred Column Type
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct DeferredColumnType {
-    pub column_type: ColumnType,
+    pub column_type: ValueType,
     pub column_id: Id,
 }
 
-
 impl DeferredColumnType {
-    pub fn new(column_type: ColumnType, column_id: Id) -> Self {
+    pub fn new(column_type: ValueType, column_id: Id) -> Self {
         DeferredColumnType {
             column_type,
             column_id,
@@ -326,13 +499,10 @@ impl DeferredColumnType {
     }
 }
 
-
-
 pub const ID_LEN: usize = 8;
 pub const PREFIX_LEN: usize = TABLE_PREFIX_LEN + ID_LEN /*table_id*/ + SEP_LEN;
 pub const RECORD_ROW_KEY_LEN: usize = PREFIX_LEN + ID_LEN;
 pub const TABLE_PREFIX: &[u8] = b"t";
-pub const SEP: &[u8] = b"_";
 pub const RECORD_PREFIX_SEP: &[u8] = b"_r";
 pub const INDEX_PREFIX_SEP: &[u8] = b"_i";
 pub const SEP_LEN: usize = 2;
@@ -341,40 +511,18 @@ pub const TABLE_PREFIX_KEY_LEN: usize = TABLE_PREFIX_LEN + ID_LEN;
 // the maximum len of the old encoding of Index causet_locale.
 pub const MAX_OLD_ENCODED_VALUE_LEN: usize = 9;
 
-
-pub fn encode_table(table: &Table) -> Result<Vec<u8>> {
-    let mut buf = Vec::with_capacity(table.encoded_len());
-    table.encode(&mut buf)?;
-    Ok(buf)
-}
-
-
-pub fn encode_row(table_id: i64, handle: i64, row: Vec<DatumType>) -> Result<Vec<u8>> {
-    let mut buf = Vec::with_capacity(RECORD_ROW_KEY_LEN + row.len() * 8);
-    buf.write_all(table_id.to_string().as_bytes())?;
-    buf.write_all(RECORD_PREFIX_SEP)?;
-    buf.write_all(handle.to_string().as_bytes())?;
-    buf.write_all(b"\t")?;
-    for datum in row {
-        datum.encode(&mut buf)?;
-        buf.write_all(b"\t")?;
-    }
-    Ok(buf)
-}
-
-
 /// `TableEncoder` encodes the table record/Index prefix.
 trait TableEncoder: NumberEncoder {
     fn append_table_record_prefix(&mut self, table_id: i64) -> Result<()> {
         self.write_bytes(TABLE_PREFIX)?;
         self.write_i64(table_id)?;
-        self.write_bytes(RECORD_PREFIX_SEP).map_err(Error::from)
+        self.write_bytes(RECORD_PREFIX_SEP)
     }
 
     fn append_table_index_prefix(&mut self, table_id: i64) -> Result<()> {
         self.write_bytes(TABLE_PREFIX)?;
         self.write_i64(table_id)?;
-        self.write_bytes(INDEX_PREFIX_SEP).map_err(Error::from)
+        self.write_bytes(INDEX_PREFIX_SEP)
     }
 }
 
@@ -394,74 +542,39 @@ pub fn extract_table_prefix(soliton_id: &[u8]) -> Result<&[u8]> {
 }
 
 /// Checks if the range is for table record or Index.
-pub fn check_table_ranges(ranges: &[Key]) -> Result<()> {
+pub fn check_table_ranges(ranges: &[Range<Vec<u8>>]) -> Result<()> {
     for range in ranges {
-        extract_table_prefix(range.get_start())?;
-        extract_table_prefix(range.get_end())?;
-        if range.get_start() >= range.get_end() {
+        extract_table_prefix(&range.start)?;
+        extract_table_prefix(&range.end)?;
+        if range.start >= range.end {
             return Err(invalid_type!(
                 "invalid range,range.start should be smaller than range.end, but got [{:?},{:?})",
-                range.get_start(),
-                range.get_end()
+                range.start,
+                range.end
             ));
         }
     }
     Ok(())
 }
 
-
-/// `TableDecoder` decodes the table record/Index prefix.
-/// It is used to decode the table record/Index prefix from the table record/Index key.
-/// For example, we have a table record/Index key:
-///    t1_r1
-///   t1_r2
-///  t1_r3
-/// t2_r1
-/// t2_r2
-/// t2_r3
-///
-/// The TableDecoder will point to the first byte of the table record/Index key:
-///   t1_r1
-///  t1_r2
-///
-/// The TableDecoder can be used to decode the table id:
-///  t1_r1 -> t1
-/// t1_r2 -> t1
-/// t1_r3 -> t1
-/// t2_r1 -> t2
-/// t2_r2 -> t2
-/// t2_r3 -> t2
-///
-/// The TableDecoder can be used to decode the record/Index handle:
-/// t1_r1 -> 1
-/// t1_r2 -> 2
-/// t1_r3 -> 3
-/// t2_r1 -> 1
-/// t2_r2 -> 2
-/// t2_r3 -> 3
-///
-/// The TableDecoder can be used to decode the record/Index key:
-/// t1_r1 -> t1_r1
-/// t1_r2 -> t1_r2
-/// t1_r3 -> t1_r3
-/// t2_r1 -> t2_r1
-/// t2_r2 -> t2_r2
-/// t2_r3 -> t2_r3
-///
-/// The TableDecoder can be used to decode the table prefix:
-/// t1_r1 -> t1
-/// t1_r2 -> t1
-/// t1_r3 -> t1
-///
-/// The TableDecoder can be used to decode the table prefix:
-/// t1_r1 -> t1
-/// t1_r2 -> t1
-/// t1_r3 -> t1
-///
+/// Checks that `soliton_id` is a table record or Index soliton_id, depending on
+/// whether `prefix_sep` is `RECORD_PREFIX_SEP` or `INDEX_PREFIX_SEP`:
 ///
+/// ```text
+///   t{table_id}_r{handle}
+///   t{table_id}_i{index_id}{index_causet_locales}
+/// ```
 #[inline]
-fn check_soliton_id_type(soliton_id: &[u8], prefix_sep: u8) -> Result<()> {
-    if !soliton_id.starts_with(&[prefix_sep]) {
+fn check_soliton_id_type(soliton_id: &[u8], prefix_sep: &[u8]) -> Result<()> {
+    let mut buf = soliton_id;
+    if buf.read_bytes(TABLE_PREFIX_LEN)? != TABLE_PREFIX {
+        return Err(invalid_type!(
+            "record soliton_id or Index soliton_id expected, but got {:?}",
+            soliton_id
+        ));
+    }
+    buf.read_bytes(ID_LEN)?;
+    if buf.read_bytes(SEP_LEN)? != prefix_sep {
         Err(invalid_type!(
             "record soliton_id or Index soliton_id expected, but got {:?}",
             soliton_id
@@ -470,93 +583,48 @@ fn check_soliton_id_type(soliton_id: &[u8], prefix_sep: u8) -> Result<()> {
         Ok(())
     }
 }
-   // check_soliton_id_type
-
 
-//no prefix
 #[inline]
 pub fn check_index_soliton_id(soliton_id: &[u8]) -> Result<()> {
-    //quicker check
-    //we just save ourselves a function call
-
-
-    if soliton_id.len() < INDEX_PREFIX_KEY_LEN {
-        Err(invalid_type!(
-            "record soliton_id or Index soliton_id expected, but got {:?}",
-            soliton_id
-        ))
-    } else {
-        check_soliton_id_type(soliton_id, INDEX_PREFIX_SEP)
-    }
-}   // check_index_soliton_id
+    check_soliton_id_type(soliton_id, INDEX_PREFIX_SEP)
+}
 
-//no prefix
-//no handle
-//no key
-//no table prefix
 #[inline]
 pub fn check_record_soliton_id(soliton_id: &[u8]) -> Result<()> {
-    //quicker check
-    //we just save ourselves a function call
-
-
-    if soliton_id.len() < RECORD_PREFIX_KEY_LEN {
-        Err(invalid_type!(
-            "record soliton_id or Index soliton_id expected, but got {:?}",
-            soliton_id
-        ))
-    } else {
-        check_soliton_id_type(soliton_id, RECORD_PREFIX_SEP)
-    }
-}   // check_index_soliton_id
-
-
-
+    check_soliton_id_type(soliton_id, RECORD_PREFIX_SEP)
+}
 
+/// Checks that `soliton_id` is a table record or Index soliton_id.
 #[inline]
 pub fn check_table_soliton_id(soliton_id: &[u8]) -> Result<()> {
-    if !soliton_id.starts_with(TABLE_PREFIX) || soliton_id.len() < TABLE_PREFIX_KEY_LEN {
-        Err(invalid_type!(
-            "record soliton_id or Index soliton_id expected, but got {:?}",
-            soliton_id
-        ))
-    } else if soliton_id[TABLE_PREFIX_KEY_LEN] != TABLE_PREFIX_SEP {
-        Err(invalid_type!(
-            "record soliton_id or Index soliton_id expected, but got {:?}",
-            soliton_id
-        ))
-    } else {
-        Ok(())
-    }
-}   // check_table_soliton_id
-
+    check_record_soliton_id(soliton_id).or_else(|_| check_index_soliton_id(soliton_id))
+}
 
+/// Checks that `soliton_id` is a record soliton_id of the table `table_id`.
 #[inline]
-pub fn check_record_soliton_id_with_table_id(soliton_id: &[u8], table_id: &[u8]) -> Result<()> {
-    check_soliton_id_type(soliton_id, INDEX_PREFIX_SEP)
+pub fn check_record_soliton_id_with_table_id(soliton_id: &[u8], table_id: i64) -> Result<()> {
+    check_record_soliton_id(soliton_id)?;
+    let id = decode_table_id(soliton_id)?;
+    if id != table_id {
+        return Err(invalid_type!(
+            "record soliton_id of table {} expected, but got one of table {}",
+            table_id,
+            id
+        ));
+    }
+    Ok(())
 }
 
-
 /// Decodes table ID from the soliton_id.
 pub fn decode_table_id(soliton_id: &[u8]) -> Result<i64> {
     let mut buf = soliton_id;
     if buf.read_bytes(TABLE_PREFIX_LEN)? != TABLE_PREFIX {
         return Err(invalid_type!(
-            "record soliton_id expected, but got {}",
-            hex::encode_upper(soliton_id)
+            "record soliton_id expected, but got {:?}",
+            soliton_id
         ));
     }
-    buf.read_i64().map_err(Error::from)
-}
-
-/// `flatten` flattens the datum.
-#[inline]
-pub fn flatten(ctx: &mut EvalContext, data: DatumType) -> Result<DatumType> {
-    match data {
-        DatumType::Dur(d) => Ok(DatumType::I64(d.to_nanos())),
-        DatumType::Time(t) => Ok(DatumType::U64(t.to_packed_u64(ctx)?)),
-        _ => Ok(data),
-    }
+    buf.read_i64()
 }
 
 /// `encode_row_soliton_id` encodes the table id and record handle into a byte array.
@@ -589,7 +657,7 @@ pub fn encode_column_soliton_id(table_id: i64, handle: i64, column_id: i64) -> V
 pub fn decode_int_handle(mut soliton_id: &[u8]) -> Result<i64> {
     check_record_soliton_id(soliton_id)?;
     soliton_id = &soliton_id[PREFIX_LEN..];
-    soliton_id.read_i64().map_err(Error::from)
+    soliton_id.read_i64()
 }
 
 /// `decode_common_handle` decodes soliton_id soliton_id and gets the common handle.
@@ -605,124 +673,10 @@ pub fn encode_index_seek_soliton_id(table_id: i64, idx_id: i64, encoded: &[u8])
     let mut soliton_id = Vec::with_capacity(PREFIX_LEN + ID_LEN + encoded.len());
     soliton_id.append_table_index_prefix(table_id).unwrap();
     soliton_id.write_i64(idx_id).unwrap();
-    soliton_id.write_all(encoded).unwrap();
+    soliton_id.extend_from_slice(encoded);
     soliton_id
 }
 
-// `decode_index_soliton_id` decodes datums from an Index soliton_id.
-pub fn decode_index_soliton_id(
-    ctx: &mut EvalContext,
-    encoded: &[u8],
-    infos: &[ColumnInfo],
-) -> Result<Vec<DatumType>> {
-    let mut buf = &encoded[PREFIX_LEN + ID_LEN..];
-    let mut res = vec![];
-
-    for info in infos {
-        if buf.is_empty() {
-            return Err(box_err!("{} is too short.", hex::encode_upper(encoded)));
-        }
-        let mut v = buf.read_datum()?;
-        v = unflatten(ctx, v, info)?;
-        res.push(v);
-    }
-
-    Ok(res)
-}
-
-/// `unflatten` converts a primitive_causet datum to a causet_merge datum.
-fn unflatten(
-    ctx: &mut EvalContext,
-    datum: DatumType,
-    field_type: &dyn FieldTypeAccessor,
-) -> Result<DatumType> {
-    if let DatumType::Null = datum {
-        return Ok(datum);
-    }
-    let tp = field_type.tp();
-    match tp {
-        FieldTypeTp::Float => Ok(DatumType::F64(f64::from(datum.f64() as f32))),
-        FieldTypeTp::Date | FieldTypeTp::DateTime | FieldTypeTp::Timestamp => {
-            let fsp = field_type.decimal() as i8;
-            let t = Time::from_packed_u64(ctx, datum.u64(), tp.try_into()?, fsp)?;
-            Ok(DatumType::Time(t))
-        }
-        FieldTypeTp::Duration => {
-            Duration::from_nanos(datum.i64(), field_type.decimal() as i8).map(DatumType::Dur)
-        }
-        FieldTypeTp::Enum | FieldTypeTp::Set | FieldTypeTp::Bit => Err(box_err!(
-            "unflatten field type {} is not supported yet.",
-            tp
-        )),
-        t => {
-            debug_assert!(
-                [
-                    FieldTypeTp::Tiny,
-                    FieldTypeTp::Short,
-                    FieldTypeTp::Year,
-                    FieldTypeTp::Int24,
-                    FieldTypeTp::Long,
-                    FieldTypeTp::LongLong,
-                    FieldTypeTp::Double,
-                    FieldTypeTp::TinyBlob,
-                    FieldTypeTp::MediumBlob,
-                    FieldTypeTp::Blob,
-                    FieldTypeTp::LongBlob,
-                    FieldTypeTp::VarChar,
-                    FieldTypeTp::String,
-                    FieldTypeTp::NewDecimal,
-                    FieldTypeTp::JSON
-                ]
-                .contains(&t),
-                "unCausetLocaleNucleon type {} {}",
-                t,
-                datum
-            );
-            Ok(datum)
-        }
-    }
-}
-
-// `decode_col_causet_locale` decodes data to a DatumType according to the causet_merge info.
-pub fn decode_col_causet_locale(
-    data: &mut BytesSlice<'_>,
-    ctx: &mut EvalContext,
-    col: &ColumnInfo,
-) -> Result<DatumType> {
-    let d = data.read_datum()?;
-    unflatten(ctx, d, col)
-}
-
-// `decode_row` decodes a byte slice into datums.
-// TODO: We should only decode columns in the cols map.
-// Row layout: colID1, causet_locale1, colID2, causet_locale2, .....
-pub fn decode_row(
-    data: &mut BytesSlice<'_>,
-    ctx: &mut EvalContext,
-    cols: &HashMap<i64, ColumnInfo>,
-) -> Result<HashMap<i64, DatumType>> {
-    let mut causet_locales = datum::decode(data)?;
-    if causet_locales.get(0).map_or(true, |d| *d == DatumType::Null) {
-        return Ok(HashMap::default());
-    }
-    if causet_locales.len() & 1 == 1 {
-        return Err(box_err!("decoded event causet_locales' length should be even!"));
-    }
-    let mut event = HashMap::with_capacity_and_hasher(cols.len(), Default::default());
-    let mut drain = causet_locales.drain(..);
-    loop {
-        let id = match drain.next() {
-            None => return Ok(event),
-            Some(id) => id.i64(),
-        };
-        let v = drain.next().unwrap();
-        if let Some(ci) = cols.get(&id) {
-            let v = unflatten(ctx, v, ci)?;
-            event.insert(id, v);
-        }
-    }
-}
-
 /// `RowColMeta` saves the causet_merge meta of the event.
 #[derive(Debug)]
 pub struct RowColMeta {
@@ -748,7 +702,10 @@ impl RowColMeta {
 
 impl RowColsDict {
     pub fn new(cols: HashMap<i64, RowColMeta>, causet_locale: Vec<u8>) -> RowColsDict {
-        RowColsDict { causet_locale, cols }
+        RowColsDict {
+            causet_locale,
+            cols,
+        }
     }
 
     /// Returns the total count of the columns.
@@ -783,7 +740,7 @@ impl RowColsDict {
     pub fn get_column_causet_locales_and_end_offsets(&self) -> (&[u8], Vec<usize>) {
         let mut start = self.causet_locale.len();
         let mut length = 0;
-        for meta in self.cols.causet_locales() {
+        for meta in self.cols.values() {
             if meta.offset < start {
                 start = meta.offset;
             }
@@ -791,147 +748,16 @@ impl RowColsDict {
         }
         let end_offsets = self
             .cols
-            .causet_locales()
+            .values()
             .map(|meta| meta.offset + meta.length - start)
             .collect();
         (&self.causet_locale[start..start + length], end_offsets)
     }
 }
 
-/// `cut_row` cuts the encoded event into (col_id,offset,length)
-///  and returns interested columns' meta in RowColsDict
-///
-/// Encoded event can be either in event format EINSTEIN_DB or causet_record.
-///
-/// `col_ids` must be consistent with `cols`. Otherwise the result is undefined.
-pub fn cut_row(
-    data: Vec<u8>,
-    col_ids: &HashSet<i64>,
-    cols: Arc<Vec<ColumnInfo>>,
-) -> Result<RowColsDict> {
-    if cols.is_empty() || data.is_empty() || (data.len() == 1 && data[0] == datum::NIL_FLAG) {
-        return Ok(RowColsDict::new(HashMap::default(), data));
-    }
-    match data[0] {
-        crate::codec::event::causet_record::CODEC_VERSION => cut_row_causet_record(data, cols),
-        _ => cut_row_einstein_db(data, col_ids),
-    }
-}
-
-/// Cuts a non-empty event in event format EINSTEIN_DB.
-fn cut_row_einstein_db(data: Vec<u8>, cols: &HashSet<i64>) -> Result<RowColsDict> {
-    let meta_map = {
-        let mut meta_map = HashMap::with_capacity_and_hasher(cols.len(), Default::default());
-        let length = data.len();
-        let mut tmp_data: &[u8] = data.as_ref();
-        while !tmp_data.is_empty() && meta_map.len() < cols.len() {
-            let id = tmp_data.read_datum()?.i64();
-            let offset = length - tmp_data.len();
-            let (val, rem) = datum::split_datum(tmp_data, false)?;
-            if cols.contains(&id) {
-                meta_map.insert(id, RowColMeta::new(offset, val.len()));
-            }
-            tmp_data = rem;
-        }
-        meta_map
-    };
-    Ok(RowColsDict::new(meta_map, data))
-}
-
-/// Cuts a non-empty event in event format causet_record and encodes into EINSTEIN_DB format.
-fn cut_row_causet_record(data: Vec<u8>, cols: Arc<Vec<ColumnInfo>>) -> Result<RowColsDict> {
-    use crate::codec::datum_codec::{ColumnIdDatumTypeEncoder, EvaluableDatumTypeEncoder};
-    use crate::codec::event::causet_record::{RowSlice, EINSTEIN_DB_CompatibleEncoder};
-
-    let mut meta_map = HashMap::with_capacity_and_hasher(cols.len(), Default::default());
-    let mut result = Vec::with_capacity(data.len() + cols.len() * 8);
-
-    let row_slice = RowSlice::from_bytes(&data)?;
-    for col in cols.iter() {
-        let id = col.get_column_id();
-        if let Some((start, offset)) = row_slice.search_in_non_null_ids(id)? {
-            result.write_column_id_datum(id)?;
-            let causet_record_datum = &row_slice.causet_locales()[start..offset];
-            let result_offset = result.len();
-            result.write_causet_record_as_datum(causet_record_datum, col)?;
-            meta_map.insert(
-                id,
-                RowColMeta::new(result_offset, result.len() - result_offset),
-            );
-        } else if row_slice.search_in_null_ids(id) {
-            result.write_column_id_datum(id)?;
-            let result_offset = result.len();
-            result.write_evaluable_datum_null()?;
-            meta_map.insert(
-                id,
-                RowColMeta::new(result_offset, result.len() - result_offset),
-            );
-        } else {
-            // Otherwise the causet_merge does not exist.
-        }
-    }
-    Ok(RowColsDict::new(meta_map, result))
-}
-
-/// `cut_idx_soliton_id` cuts the encoded Index soliton_id into RowColsDict and handle .
-pub fn cut_idx_soliton_id(soliton_id: Vec<u8>, col_ids: &[i64]) -> Result<(RowColsDict, Option<i64>)> {
-    let mut meta_map: HashMap<i64, RowColMeta> =
-        HashMap::with_capacity_and_hasher(col_ids.len(), Default::default());
-    let handle = {
-        let mut tmp_data: &[u8] = &soliton_id[PREFIX_LEN + ID_LEN..];
-        let length = soliton_id.len();
-        // parse cols from data
-        for &id in col_ids {
-            let offset = length - tmp_data.len();
-            let (val, rem) = datum::split_datum(tmp_data, false)?;
-            meta_map.insert(id, RowColMeta::new(offset, val.len()));
-            tmp_data = rem;
-        }
-
-        if tmp_data.is_empty() {
-            None
-        } else {
-            Some(tmp_data.read_datum()?.i64())
-        }
-    };
-    Ok((RowColsDict::new(meta_map, soliton_id), handle))
-}
-
-pub fn generate_index_data_for_test(
-    table_id: i64,
-    index_id: i64,
-    handle: i64,
-    col_val: &DatumType,
-    unique: bool,
-) -> (HashMap<i64, Vec<u8>>, Vec<u8>) {
-    let indice = vec![(2, (*col_val).clone()), (3, DatumType::Dec(handle.into()))];
-    let mut expect_row = HashMap::default();
-    let mut v: Vec<_> = indice
-        .iter()
-        .map(|&(ref cid, ref causet_locale)| {
-            expect_row.insert(
-                *cid,
-                datum::encode_soliton_id(&mut EvalContext::default(), &[causet_locale.clone()]).unwrap(),
-            );
-            causet_locale.clone()
-        })
-        .collect();
-    if !unique {
-        v.push(DatumType::I64(handle));
-    }
-    let encoded = datum::encode_soliton_id(&mut EvalContext::default(), &v).unwrap();
-    let idx_soliton_id = encode_index_seek_soliton_id(table_id, index_id, &encoded);
-    (expect_row, idx_soliton_id)
-}
-
-#[braneg(test)]
+#[cfg(test)]
 mod tests {
-    use EinsteinDB_util::collections::{HashMap, HashSet};
-    use EinsteinDB_util::map;
-    use einsteindbpb::ColumnInfo;
-    use std::i64;
-
-    use crate::codec::datum::{self, DatumType};
+    use crate::error::ERR_JSON_SCHEMA_VALIDATION;
 
     use super::*;
 
@@ -947,204 +773,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_index_soliton_id_codec() {
-        let tests = vec![
-            DatumType::U64(1),
-            DatumType::Bytes(b"123".to_vec()),
-            DatumType::I64(-1),
-            DatumType::Dur(Duration::parse(&mut EvalContext::default(), b"12:34:56.666", 2).unwrap()),
-        ];
-
-        let mut duration_col = ColumnInfo::default();
-        duration_col
-            .as_mut_accessor()
-            .set_tp(FieldTypeTp::Duration)
-            .set_decimal(2);
-
-        let types = vec![
-            FieldTypeTp::LongLong.into(),
-            FieldTypeTp::VarChar.into(),
-            FieldTypeTp::LongLong.into(),
-            duration_col,
-        ];
-        let mut ctx = EvalContext::default();
-        let buf = datum::encode_soliton_id(&mut ctx, &tests).unwrap();
-        let encoded = encode_index_seek_soliton_id(1, 2, &buf);
-        assert_eq!(tests, decode_index_soliton_id(&mut ctx, &encoded, &types).unwrap());
-    }
-
-    fn to_hash_map(event: &RowColsDict) -> HashMap<i64, Vec<u8>> {
-        let mut data = HashMap::with_capacity_and_hasher(event.cols.len(), Default::default());
-        if event.is_empty() {
-            return data;
-        }
-        for (soliton_id, meta) in &event.cols {
-            data.insert(
-                *soliton_id,
-                event.causet_locale[meta.offset..(meta.offset + meta.length)].to_vec(),
-            );
-        }
-        data
-    }
-
-    fn cut_row_as_owned(bs: &[u8], col_id_set: &HashSet<i64>) -> HashMap<i64, Vec<u8>> {
-        let is_empty_row =
-            col_id_set.is_empty() || bs.is_empty() || (bs.len() == 1 && bs[0] == datum::NIL_FLAG);
-        let res = if is_empty_row {
-            RowColsDict::new(HashMap::default(), bs.to_vec())
-        } else {
-            cut_row_einstein_db(bs.to_vec(), col_id_set).unwrap()
-        };
-        to_hash_map(&res)
-    }
-
-    fn cut_idx_soliton_id_as_owned(bs: &[u8], ids: &[i64]) -> (HashMap<i64, Vec<u8>>, Option<i64>) {
-        let (res, left) = cut_idx_soliton_id(bs.to_vec(), ids).unwrap();
-        (to_hash_map(&res), left)
-    }
-
-    #[test]
-    fn test_row_codec() {
-        let mut duration_col = ColumnInfo::default();
-        duration_col
-            .as_mut_accessor()
-            .set_tp(FieldTypeTp::Duration)
-            .set_decimal(2);
-
-        let mut cols = map![
-            1 => FieldTypeTp::LongLong.into(),
-            2 => FieldTypeTp::VarChar.into(),
-            3 => FieldTypeTp::NewDecimal.into(),
-            5 => FieldTypeTp::JSON.into(),
-            6 => duration_col
-        ];
-
-        let mut event = map![
-            1 => DatumType::I64(100),
-            2 => DatumType::Bytes(b"abc".to_vec()),
-            3 => DatumType::Dec(10.into()),
-            5 => DatumType::Json(r#"{"name": "John"}"#.parse().unwrap()),
-            6 => DatumType::Dur(Duration::parse(&mut EvalContext::default(),b"23:23:23.666",2 ).unwrap())
-        ];
-
-        let mut ctx = EvalContext::default();
-        let col_ids: Vec<_> = event.iter().map(|(&id, _)| id).collect();
-        let col_causet_locales: Vec<_> = event.iter().map(|(_, v)| v.clone()).collect();
-        let mut col_encoded: HashMap<_, _> = event
-            .iter()
-            .map(|(k, v)| {
-                let f = super::flatten(&mut ctx, v.clone()).unwrap();
-                (*k, datum::encode_causet_locale(&mut ctx, &[f]).unwrap())
-            })
-            .collect();
-        let mut col_id_set: HashSet<_> = col_ids.iter().cloned().collect();
-
-        let bs = encode_row(&mut ctx, col_causet_locales, &col_ids).unwrap();
-        assert!(!bs.is_empty());
-        let mut ctx = EvalContext::default();
-        let r = decode_row(&mut bs.as_slice(), &mut ctx, &cols).unwrap();
-        assert_eq!(event, r);
-
-        let mut datums: HashMap<_, _>;
-        datums = cut_row_as_owned(&bs, &col_id_set);
-        assert_eq!(col_encoded, datums);
-
-        cols.insert(4, FieldTypeTp::Float.into());
-        let r = decode_row(&mut bs.as_slice(), &mut ctx, &cols).unwrap();
-        assert_eq!(event, r);
-
-        col_id_set.insert(4);
-        datums = cut_row_as_owned(&bs, &col_id_set);
-        assert_eq!(col_encoded, datums);
-
-        cols.remove(&4);
-        cols.remove(&3);
-        let r = decode_row(&mut bs.as_slice(), &mut ctx, &cols).unwrap();
-        event.remove(&3);
-        assert_eq!(event, r);
-
-        col_id_set.remove(&3);
-        col_id_set.remove(&4);
-        datums = cut_row_as_owned(&bs, &col_id_set);
-        col_encoded.remove(&3);
-        assert_eq!(col_encoded, datums);
-
-        let bs = encode_row(&mut ctx, vec![], &[]).unwrap();
-        assert!(!bs.is_empty());
-        assert!(decode_row(&mut bs.as_slice(), &mut ctx, &cols)
-            .unwrap()
-            .is_empty());
-        datums = cut_row_as_owned(&bs, &col_id_set);
-        assert!(datums.is_empty());
-    }
-
-    #[test]
-    fn test_idx_codec() {
-        let mut col_ids = vec![1, 2, 3, 4];
-
-        let mut duration_col = ColumnInfo::default();
-        duration_col
-            .as_mut_accessor()
-            .set_tp(FieldTypeTp::Duration)
-            .set_decimal(2);
-
-        let col_types = vec![
-            FieldTypeTp::LongLong.into(),
-            FieldTypeTp::VarChar.into(),
-            FieldTypeTp::NewDecimal.into(),
-            duration_col,
-        ];
-
-        let col_causet_locales = vec![
-            DatumType::I64(100),
-            DatumType::Bytes(b"abc".to_vec()),
-            DatumType::Dec(10.into()),
-            DatumType::Dur(Duration::parse(&mut EvalContext::default(), b"23:23:23.666", 2).unwrap()),
-        ];
-
-        let mut ctx = EvalContext::default();
-        let mut col_encoded: HashMap<_, _> = col_ids
-            .iter()
-            .zip(&col_types)
-            .zip(&col_causet_locales)
-            .map(|((id, t), v)| {
-                let unflattened = super::unflatten(&mut ctx, v.clone(), t).unwrap();
-                let encoded = datum::encode_soliton_id(&mut ctx, &[unflattened]).unwrap();
-                (*id, encoded)
-            })
-            .collect();
-
-        let soliton_id = datum::encode_soliton_id(&mut ctx, &col_causet_locales).unwrap();
-        let bs = encode_index_seek_soliton_id(1, 1, &soliton_id);
-        assert!(!bs.is_empty());
-        let mut ctx = EvalContext::default();
-        let r = decode_index_soliton_id(&mut ctx, &bs, &col_types).unwrap();
-        assert_eq!(col_causet_locales, r);
-
-        let mut res: (HashMap<_, _>, _) = cut_idx_soliton_id_as_owned(&bs, &col_ids);
-        assert_eq!(col_encoded, res.0);
-        assert!(res.1.is_none());
-
-        let handle_data = col_encoded.remove(&4).unwrap();
-        let handle = if handle_data.is_empty() {
-            None
-        } else {
-            Some((handle_data.as_ref() as &[u8]).read_datum().unwrap().i64())
-        };
-        col_ids.remove(3);
-        res = cut_idx_soliton_id_as_owned(&bs, &col_ids);
-        assert_eq!(col_encoded, res.0);
-        assert_eq!(res.1, handle);
-
-        let bs = encode_index_seek_soliton_id(1, 1, &[]);
-        assert!(!bs.is_empty());
-        assert!(decode_index_soliton_id(&mut ctx, &bs, &[]).unwrap().is_empty());
-        res = cut_idx_soliton_id_as_owned(&bs, &[]);
-        assert!(res.0.is_empty());
-        assert!(res.1.is_none());
-    }
-
     #[test]
     fn test_extract_table_prefix() {
         let cases = vec![
@@ -1169,20 +797,14 @@ mod tests {
     fn test_check_table_range() {
         let small_soliton_id = b"t\x80\x00\x00\x00\x00\x00\x00\x01a".to_vec();
         let large_soliton_id = b"t\x80\x00\x00\x00\x00\x00\x00\x01b".to_vec();
-        let mut range = Key::default();
-        range.set_start(small_soliton_id.clone());
-        range.set_end(large_soliton_id.clone());
+        let range = small_soliton_id.clone()..large_soliton_id.clone();
         assert!(check_table_ranges(&[range]).is_ok());
         //test range.start > range.end
-        let mut range = Key::default();
-        range.set_end(small_soliton_id.clone());
-        range.set_start(large_soliton_id);
+        let range = large_soliton_id..small_soliton_id.clone();
         assert!(check_table_ranges(&[range]).is_err());
 
         // test invalid end
-        let mut range = Key::default();
-        range.set_start(small_soliton_id);
-        range.set_end(b"xx".to_vec());
+        let range = small_soliton_id..b"xx".to_vec();
         assert!(check_table_ranges(&[range]).is_err());
     }
 
@@ -1201,16 +823,324 @@ mod tests {
     #[test]
     fn test_check_soliton_id_type() {
         let record_soliton_id = encode_row_soliton_id(TABLE_ID, 1);
-        assert!(check_soliton_id_type(&record_soliton_id.as_slice(), RECORD_PREFIX_SEP).is_ok());
-        assert!(check_soliton_id_type(&record_soliton_id.as_slice(), INDEX_PREFIX_SEP).is_err());
+        assert!(check_soliton_id_type(&record_soliton_id, RECORD_PREFIX_SEP).is_ok());
+        assert!(check_soliton_id_type(&record_soliton_id, INDEX_PREFIX_SEP).is_err());
+        assert!(check_record_soliton_id_with_table_id(&record_soliton_id, TABLE_ID).is_ok());
+        assert!(check_record_soliton_id_with_table_id(&record_soliton_id, TABLE_ID + 1).is_err());
 
-        let (_, index_soliton_id) =
-            generate_index_data_for_test(TABLE_ID, INDEX_ID, 1, &DatumType::I64(1), true);
-        assert!(check_soliton_id_type(&index_soliton_id.as_slice(), RECORD_PREFIX_SEP).is_err());
-        assert!(check_soliton_id_type(&index_soliton_id.as_slice(), INDEX_PREFIX_SEP).is_ok());
+        let index_soliton_id = encode_index_seek_soliton_id(TABLE_ID, INDEX_ID, &[1]);
+        assert!(check_soliton_id_type(&index_soliton_id, RECORD_PREFIX_SEP).is_err());
+        assert!(check_soliton_id_type(&index_soliton_id, INDEX_PREFIX_SEP).is_ok());
+        assert!(check_table_soliton_id(&index_soliton_id).is_ok());
 
         let too_small_soliton_id = vec![0];
-        assert!(check_soliton_id_type(&too_small_soliton_id.as_slice(), RECORD_PREFIX_SEP).is_err());
-        assert!(check_soliton_id_type(&too_small_soliton_id.as_slice(), INDEX_PREFIX_SEP).is_err());
+        assert!(check_soliton_id_type(&too_small_soliton_id, RECORD_PREFIX_SEP).is_err());
+        assert!(check_soliton_id_type(&too_small_soliton_id, INDEX_PREFIX_SEP).is_err());
+        assert!(check_table_soliton_id(&too_small_soliton_id).is_err());
+    }
+
+    #[test]
+    fn test_json_schema_constraint() {
+        let schema = JsonSchema::new(
+            r#"{"required": ["id"], "properties": {"id": {"type": "integer"}}}"#
+                .parse()
+                .unwrap(),
+        )
+        .unwrap();
+        let columns = vec![
+            Column::new("id".to_owned(), ValueType::Int),
+            Column::new("doc".to_owned(), ValueType::Json).with_json_schema(schema),
+        ];
+        let mut table = Table::new("t".to_owned(), columns, vec![]);
+        let doc = |s: &str| Value::Json(s.parse().unwrap());
+
+        table
+            .insert(Row::new(vec![Value::Int(1), doc(r#"{"id": 1}"#)]))
+            .unwrap();
+        let err = table
+            .insert(Row::new(vec![Value::Int(2), doc(r#"{"id": "x"}"#)]))
+            .unwrap_err();
+        match err {
+            Error::JsonSchemaViolation(ref causet_merge, ref report) => {
+                assert_eq!(causet_merge, "doc");
+                assert_eq!(report.document_location, "#/id");
+                assert_eq!(report.schema_location, "#/properties/id");
+                assert_eq!(report.keyword, "type");
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+        assert_eq!(err.code(), ERR_JSON_SCHEMA_VALIDATION);
+        assert!(table
+            .update(0, Row::new(vec![Value::Int(1), doc("{}")]))
+            .is_err());
+        table
+            .update(0, Row::new(vec![Value::Int(1), Value::Null]))
+            .unwrap();
+        assert_eq!(table.rows.len(), 1);
+    }
+
+    #[test]
+    fn test_generated_column_index() {
+        let columns = vec![
+            Column::new("id".to_owned(), ValueType::Int),
+            Column::new("doc".to_owned(), ValueType::Json),
+        ];
+        let mut table = Table::new("t".to_owned(), columns, vec![]);
+        let doc = |s: &str| Value::Json(s.parse().unwrap());
+        let soliton_id = |s: &str| Json::from_string(s.to_owned()).unwrap();
+
+        table
+            .insert(Row::new(vec![
+                Value::Int(1),
+                doc(r#"{"user": {"id": "b"}}"#),
+            ]))
+            .unwrap();
+        table
+            .add_generated_column(
+                "user_id".to_owned(),
+                ValueType::String,
+                "doc->>'$.user.id'",
+                GeneratedColumnKind::Stored,
+            )
+            .unwrap();
+        table
+            .add_generated_column(
+                "user_doc".to_owned(),
+                ValueType::Json,
+                "doc->'$.user'",
+                GeneratedColumnKind::Virtual,
+            )
+            .unwrap();
+        assert_eq!(table.rows[0].values[2], Value::String("b".to_owned()));
+        assert_eq!(table.rows[0].values[3], Value::Null);
+        assert_eq!(table.causet_locale(0, 3).unwrap(), doc(r#"{"id": "b"}"#));
+
+        // Lookups scan the table until an index exists, then read it instead.
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("b")..)
+                .unwrap(),
+            vec![0]
+        );
+        table
+            .create_index("idx_user_id".to_owned(), "user_id")
+            .unwrap();
+        let expr = GeneratedExpr::parse("doc->>'$.user.id'", |c| table.column_offset(c)).unwrap();
+        assert_eq!(table.index_for_expr(&expr).unwrap().name, "idx_user_id");
+        assert!(table
+            .create_index("idx_user_id".to_owned(), "user_id")
+            .is_err());
+
+        table
+            .insert(Row::new(vec![
+                Value::Int(2),
+                doc(r#"{"user": {"id": "a"}}"#),
+                Value::Null,
+                Value::Null,
+            ]))
+            .unwrap();
+        table
+            .insert(Row::new(vec![
+                Value::Int(3),
+                doc(r#"{"user": {"id": "c"}}"#),
+                Value::Null,
+                Value::Null,
+            ]))
+            .unwrap();
+        table
+            .insert(Row::new(vec![
+                Value::Int(4),
+                doc("{}"),
+                Value::Null,
+                Value::Null,
+            ]))
+            .unwrap();
+        assert!(table
+            .insert(Row::new(vec![
+                Value::Int(5),
+                doc("{}"),
+                Value::String("x".to_owned()),
+                Value::Null
+            ]))
+            .is_err());
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("b")..)
+                .unwrap(),
+            vec![0, 2]
+        );
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("a")..soliton_id("c"))
+                .unwrap(),
+            vec![0, 1]
+        );
+
+        // Updates move the row between index entries.
+        table
+            .update(
+                2,
+                Row::new(vec![
+                    Value::Int(3),
+                    doc(r#"{"user": {"id": "0"}}"#),
+                    Value::Null,
+                    Value::Null,
+                ]),
+            )
+            .unwrap();
+        assert_eq!(table.rows[2].values[2], Value::String("0".to_owned()));
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("b")..)
+                .unwrap(),
+            vec![0]
+        );
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", ..soliton_id("a"))
+                .unwrap(),
+            vec![2]
+        );
+
+        // Indexes on virtual columns are built from the evaluated causet_locales.
+        table
+            .create_index("idx_user_doc".to_owned(), "user_doc")
+            .unwrap();
+        let user_a: Json = r#"{"id": "a"}"#.parse().unwrap();
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->'$.user'", user_a.clone()..=user_a)
+                .unwrap(),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_generated_column_source() {
+        let columns = vec![
+            Column::new("id".to_owned(), ValueType::Int),
+            Column::new("doc".to_owned(), ValueType::Json),
+        ];
+        let mut table = Table::new("t".to_owned(), columns, vec![]);
+        assert!(table
+            .add_generated_column(
+                "gen".to_owned(),
+                ValueType::String,
+                "id->>'$.a'",
+                GeneratedColumnKind::Virtual
+            )
+            .is_err());
+        assert!(table
+            .add_generated_column(
+                "gen".to_owned(),
+                ValueType::String,
+                "doc->>'$.a'",
+                GeneratedColumnKind::Virtual
+            )
+            .is_ok());
+        assert_eq!(table.columns.len(), 3);
+    }
+
+    #[test]
+    fn test_index_failure_leaves_table_unchanged() {
+        let columns = vec![
+            Column::new("id".to_owned(), ValueType::Int),
+            Column::new("doc".to_owned(), ValueType::Json),
+        ];
+        let mut table = Table::new("t".to_owned(), columns, vec![]);
+        let row = |id: i64, s: &str| {
+            Row::new(vec![
+                Value::Int(id),
+                Value::Json(s.parse().unwrap()),
+                Value::Null,
+            ])
+        };
+        let soliton_id = |s: &str| Json::from_string(s.to_owned()).unwrap();
+        table
+            .add_generated_column(
+                "user_id".to_owned(),
+                ValueType::String,
+                "doc->>'$.user.id'",
+                GeneratedColumnKind::Virtual,
+            )
+            .unwrap();
+        table
+            .create_unique_index("idx_user_id".to_owned(), "user_id")
+            .unwrap();
+        table.insert(row(1, r#"{"user": {"id": "a"}}"#)).unwrap();
+        // A trailing escaped backslash is a plain character of the id.
+        table.insert(row(2, r#"{"user": {"id": "b\\"}}"#)).unwrap();
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("b\\")..=soliton_id("b\\"))
+                .unwrap(),
+            vec![1]
+        );
+
+        // The id duplicates a soliton_id of the unique index.
+        assert!(table.insert(row(3, r#"{"user": {"id": "a"}}"#)).is_err());
+        assert_eq!(table.rows.len(), 2);
+        assert!(table.update(1, row(3, r#"{"user": {"id": "a"}}"#)).is_err());
+        assert_eq!(table.rows[1].values[0], Value::Int(2));
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("a")..)
+                .unwrap(),
+            vec![0, 1]
+        );
+
+        // A row may keep its own soliton_id.
+        table.update(0, row(4, r#"{"user": {"id": "a"}}"#)).unwrap();
+        table.insert(row(5, r#"{"user": {"id": "c"}}"#)).unwrap();
+        assert_eq!(
+            table
+                .json_extract_lookup("doc->>'$.user.id'", soliton_id("a")..)
+                .unwrap(),
+            vec![0, 1, 2]
+        );
+
+        // Existing duplicates prevent the unique index from being created.
+        table.create_index("idx_id".to_owned(), "id").unwrap();
+        table.insert(row(4, r#"{"user": {"id": "d"}}"#)).unwrap();
+        assert!(table.create_unique_index("uk_id".to_owned(), "id").is_err());
+        assert_eq!(table.indexes.len(), 2);
+    }
+
+    #[test]
+    fn test_order_by_collation() {
+        let rows = ["b", "\u{e9}", "A", "a", "_x", "f"]
+            .iter()
+            .enumerate()
+            .map(|(i, s)| Row::new(vec![Value::Int(i as i64), Value::String(s.to_string())]))
+            .chain(std::iter::once(Row::new(vec![Value::Int(6), Value::Null])))
+            .collect();
+        let columns = vec![
+            Column::new("id".to_owned(), ValueType::Int),
+            Column::new("name".to_owned(), ValueType::String),
+        ];
+        let mut table = Table::new("t".to_owned(), columns, rows);
+        assert_eq!(
+            table.order_by("name", false).unwrap(),
+            vec![6, 2, 4, 3, 0, 5, 1]
+        );
+
+        table.columns[1].collation = Collation::Utf8Mb40900AiCi;
+        assert_eq!(
+            table.order_by("name", false).unwrap(),
+            vec![6, 4, 2, 3, 0, 1, 5]
+        );
+        assert_eq!(
+            table.order_by("name", true).unwrap(),
+            vec![5, 1, 0, 2, 3, 4, 6]
+        );
+        assert_eq!(
+            table.order_by("id", true).unwrap(),
+            vec![6, 5, 4, 3, 2, 1, 0]
+        );
+
+        table.columns[1].collation = Collation::GbkBin;
+        table.rows[0].values[1] = Value::String("\u{1f600}".to_owned());
+        assert!(table.order_by("name", false).is_err());
+        assert!(table.order_by("nope", false).is_err());
     }
 }
@@ -0,0 +1,93 @@
+//Copyright 2021-2023 WHTCORPS INC ALL RIGHTS RESERVED. APACHE 2.0 COMMUNITY EDITION SL
+// AUTHORS: WHITFORD LEDER
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file File except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+//! The causet_locales stored in the cells of a `Table`.
+
+use std::cmp::Ordering;
+
+use crate::comparison;
+use crate::error::Result;
+use crate::field_type::Collation;
+use crate::json::Json;
+
+/// The type of a `causet_merge`, which every non-NULL causet_locale in it must have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    Int,
+    String,
+    Json,
+}
+
+/// A single cell causet_locale.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i64),
+    String(String),
+    Json(Json),
+}
+
+impl Value {
+    /// Returns the type of the causet_locale, or `None` for NULL.
+    pub fn value_type(&self) -> Option<ValueType> {
+        match self {
+            Value::Null => None,
+            Value::Int(_) => Some(ValueType::Int),
+            Value::String(_) => Some(ValueType::String),
+            Value::Json(_) => Some(ValueType::Json),
+        }
+    }
+
+    /// Converts the causet_locale into JSON, NULL becoming the JSON `null` literal.
+    pub fn into_json(self) -> Result<Json> {
+        match self {
+            Value::Null => Json::none(),
+            Value::Int(i) => Json::from_i64(i),
+            Value::String(s) => Json::from_string(s),
+            Value::Json(j) => Ok(j),
+        }
+    }
+
+    /// Converts the causet_locale into its string form.
+    pub fn into_string(self) -> Result<String> {
+        match self {
+            Value::Null => Err(invalid_type!("cannot convert NULL to a string")),
+            Value::Int(i) => Ok(i.to_string()),
+            Value::String(s) => Ok(s),
+            Value::Json(j) => Ok(j.to_string()),
+        }
+    }
+
+    /// Returns the soliton_id `ORDER BY` sorts the causet_locale by, strings being weighed
+    /// under `collation`. NULL sorts first.
+    pub fn sort_key(&self, collation: Collation) -> Result<SortKey> {
+        Ok(match self {
+            Value::Null => SortKey::Null,
+            Value::Int(i) => SortKey::Int(*i),
+            Value::String(s) => SortKey::String(comparison::sort_key(collation, s.as_bytes())?),
+            Value::Json(j) => SortKey::Json(j.clone()),
+        })
+    }
+
+    /// Compares two causet_locales of a causet_merge, strings under `collation`.
+    pub fn cmp_collated(&self, other: &Value, collation: Collation) -> Result<Ordering> {
+        Ok(self.sort_key(collation)?.cmp(&other.sort_key(collation)?))
+    }
+}
+
+/// A causet_locale in a form whose natural order is the collation order, see
+/// `Value::sort_key`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortKey {
+    Null,
+    Int(i64),
+    String(Vec<u8>),
+    Json(Json),
+}
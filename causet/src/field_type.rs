@@ -1,140 +1,23 @@
-/// Copyright (c) 2022 Whtcorps Inc and EinsteinDB Project contributors
-///     Licensed under the Apache License, Version 2.0
-///    See LICENSE.txt in the project root for license information.
-///   Unless required by applicable law or agreed to in writing, software
-///  distributed under the License is distributed on an "AS IS" BASIS,
-///   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-/// See the License for the specific language governing permissions and
-/// limitations under the License.
-/// =================================================================
-///
-
-
-
+// Copyright (c) 2022 Whtcorps Inc and EinsteinDB Project contributors
+//     Licensed under the Apache License, Version 2.0
+//    See LICENSE.txt in the project root for license information.
+//   Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================
+//
+
+//! Field types, collations and evaluation types of `causet_merge`s.
+//!
+//! The file is self-contained so that crates which cannot depend on `causet` as a
+//! whole (for example `berolinasql`) can include it as a module.
 
-use std::error::Error;
-use crate::*;
 use std::convert::TryFrom;
-use std::cmp::Ordering;
-use std::fmt::{self, Debug};
-use std::hash::{Hash, Hasher};
-use std::marker::PhantomData;// (1) reuse fields from other types (2) avoid unused imports (3) avoid dead code warnings
 use std::fmt;
-use std::str::FromStr;
-use std::sync::Arc;
-use std::time::Duration;
-use std::{fmt::Display, hash::Hash as StdHash};
-use std::{fmt::Formatter, str::FromStr};
-
-
-use causet::{EvalType, EvalWrap, EvalWrapExt, Result as CausetResult};
-use causetq::*;
-
-
-use EinsteinDB_engine::{
-    datatype::{
-        DateTime,
-        Decimal,
-        Json,
-        Nullable,
-        Option,
-        OptionType,
-        Time,
-        TimeStamp,
-        TimeStampTz,
-        Timestamp,
-        TimestampTz,
-        Value,
-        ValueType,
-        Vector,
-        VectorValue,
-        VectorValueType,
-        Wrapper,
-        WrapperType,
-        WrapperValue,
-        WrapperValueType,
-        Bytes,
-        BytesValue,
-        BytesValueType,
-        BytesRef,
-        BytesRefValue,
-        BytesRefValueType,
-        BytesRefValueType,
-        DecimalValue,
-        DecimalValueType,
-        DecimalRef,
-        DecimalRefValue,
-        DecimalRefValueType,
-        DecimalRefValueType,
-        DateTimeValue,
-        DateTimeValueType,
-        DateTimeRef,
-        DateTimeRefValue,
-        DateTimeRefValueType,
-        DateTimeRefValueType,
-        JsonValue,
-        JsonValueType,
-        JsonRef,
-        JsonRefValue,
-        JsonRefValueType,
-        JsonRefValueType,
-        TimeValue,
-        TimeValueType,
-        TimeRef,
-        TimeRefValue,
-        TimeRefValueType,
-        TimeRefValueType,
-        TimeStampValue,
-        TimeStampValueType,
-        TimeStampRef,
-        TimeStampRefValue,
-        TimeStampRefValueType,
-        TimeStampRefValueType,
-        TimestampValue,
-        TimestampValueType,
-        TimestampRef,
-        TimestampRefValue,
-        TimestampRefValueType,
-        TimestampRefValueType,
-        TimestampTzValue,
-        TimestampTzValueType,
-        TimestampTzRef,
-        TimestampTzRefValue,
-        TimestampTzRefValueType,
-        TimestampTzRefValueType,
-        VectorValueType,
-        VectorRef,
-        VectorRefValue,
-        VectorRefValueType,
-    }
-};
-
-
-use berolina_sql:: {
-    ast::{self, Expr, ExprKind, Field, FieldType, FieldValue, FieldValueKind, FieldValueType, FieldValueValue, FromSql, ToSql},
-    parser::Parser,
-    types::{self, Type},
-    value::{self, Value as BerolinaValue},
-};
-
-
-
-/// Import the `EvalType` enum from `causet` and `causetq`
-/// (1) reuse fields from other types (2) avoid unused imports (3) avoid dead code warnings
-/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-/// #[derive(Serialize, Deserialize)]
-/// pub struct EvalTypeTp {
-///    pub eval_type: EvalType,
-/// }
-/// #[derive(Debug, Clone)]
-/// pub struct EvalTypeWrap {
-///   pub eval_type: EvalType,
-///  pub eval_wrap: EvalWrap,
-///
-/// }
-///
-/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 
+use bitflags::bitflags;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum EvalType {
@@ -144,6 +27,7 @@ pub enum EvalType {
     Datetime = 3,
     Duration = 4,
     Bytes = 5,
+    Json = 6,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -154,9 +38,9 @@ pub enum EvalWrap {
     Datetime = 3,
     Duration = 4,
     Bytes = 5,
+    Json = 6,
 }
 
-
 impl EvalType {
     pub fn eval_type_wrap(&self) -> EvalWrap {
         match self {
@@ -166,41 +50,75 @@ impl EvalType {
             EvalType::Datetime => EvalWrap::Datetime,
             EvalType::Duration => EvalWrap::Duration,
             EvalType::Bytes => EvalWrap::Bytes,
+            EvalType::Json => EvalWrap::Json,
         }
     }
 }
 
+impl fmt::Display for EvalType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
 
+impl TryFrom<FieldTypeTp> for EvalType {
+    type Error = DataTypeError;
+
+    // Succeeds for all field types supported as eval types, fails for unsupported types.
+    fn try_from(tp: FieldTypeTp) -> Result<Self, DataTypeError> {
+        let eval_type = match tp {
+            FieldTypeTp::Tiny
+            | FieldTypeTp::Short
+            | FieldTypeTp::Int24
+            | FieldTypeTp::Long
+            | FieldTypeTp::LongLong
+            | FieldTypeTp::Year => EvalType::Int,
+            FieldTypeTp::Float | FieldTypeTp::Double => EvalType::Real,
+            FieldTypeTp::NewDecimal => EvalType::Decimal,
+            FieldTypeTp::Timestamp | FieldTypeTp::Date | FieldTypeTp::DateTime => {
+                EvalType::Datetime
+            }
+            FieldTypeTp::Duration => EvalType::Duration,
+            FieldTypeTp::JSON => EvalType::Json,
+            FieldTypeTp::VarChar
+            | FieldTypeTp::TinyBlob
+            | FieldTypeTp::MediumBlob
+            | FieldTypeTp::LongBlob
+            | FieldTypeTp::Blob
+            | FieldTypeTp::VarString
+            | FieldTypeTp::String => EvalType::Bytes,
+            _ => return Err(DataTypeError::UnsupportedType(tp)),
+        };
+        Ok(eval_type)
+    }
+}
 
+/// The error of an unsupported field type or collation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTypeError {
+    UnsupportedType(FieldTypeTp),
+    UnsupportedCollation { code: i32 },
+}
 
+impl fmt::Display for DataTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataTypeError::UnsupportedType(tp) => write!(f, "unsupported type: {}", tp),
+            DataTypeError::UnsupportedCollation { code } => {
+                write!(f, "unsupported collation code: {}", code)
+            }
+        }
+    }
+}
 
-
-use std::fmt::{self, Debug, Display, Formatter};
-use std::str::FromStr;
-use std::sync::Arc;
-
-
-///! # FieldType
-///
-///    FieldType is the type of a field.
-///  IT allows a tuple to be created from a list of field types.
-/// what sets it apart in this case is that it is a tuple of types,
-/// and not a single type.
-///
-///
+impl std::error::Error for DataTypeError {}
 
 #[derive(Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
 pub struct SuseIsolatedStringType(pub i32);
 impl SuseIsolatedStringType {
     #[inline]
     pub fn new(value: i32) -> Self {
-        SuseIsolatedStringType(value);
-        //concurrency check
-        #[cfg(feature = "concurrency")]
-        {
-            assert_eq!(value, 0);
-        }
-
+        SuseIsolatedStringType(value)
     }
     #[inline]
     pub fn value(&self) -> i32 {
@@ -211,17 +129,134 @@ impl SuseIsolatedStringType {
     pub const NOT_NULL: SuseIsolatedStringType = SuseIsolatedStringType(1);
 }
 
+/// FieldType is the type of a causet_merge, as carried by `einsteindbpb::FieldType`.
+///
+/// It is used in the table topograph definition, and in the table and index data
+/// definitions. Use `FieldTypeAccessor` to read and write it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldType {
+    tp: i32,
+    flag: u32,
+    flen: i32,
+    decimal: i32,
+    collate: i32,
+    charset: String,
+}
+
+impl FieldType {
+    pub fn get_tp(&self) -> i32 {
+        self.tp
+    }
+
+    pub fn set_tp(&mut self, tp: i32) {
+        self.tp = tp;
+    }
+
+    pub fn get_flag(&self) -> u32 {
+        self.flag
+    }
+
+    pub fn set_flag(&mut self, flag: u32) {
+        self.flag = flag;
+    }
+
+    pub fn get_flen(&self) -> i32 {
+        self.flen
+    }
+
+    pub fn set_flen(&mut self, flen: i32) {
+        self.flen = flen;
+    }
+
+    pub fn get_decimal(&self) -> i32 {
+        self.decimal
+    }
+
+    pub fn set_decimal(&mut self, decimal: i32) {
+        self.decimal = decimal;
+    }
+
+    pub fn get_collate(&self) -> i32 {
+        self.collate
+    }
+
+    pub fn set_collate(&mut self, collate: i32) {
+        self.collate = collate;
+    }
+
+    pub fn get_charset(&self) -> &str {
+        &self.charset
+    }
+
+    pub fn set_charset(&mut self, charset: String) {
+        self.charset = charset;
+    }
+}
+
+/// ColumnInfo describes a causet_merge, as carried by `einsteindbpb::ColumnInfo`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnInfo {
+    column_id: i64,
+    tp: i32,
+    flag: i32,
+    column_len: i32,
+    decimal: i32,
+    collation: i32,
+}
 
-/// FieldType is the type of a column.
-/// It is used in the table schema definition.
-/// It is also used in the table data definition.
-/// It is used in the table Index definition.
-/// It is used in the table Index data definition.
-/// It is used in the table Index data definition.
+impl ColumnInfo {
+    pub fn get_column_id(&self) -> i64 {
+        self.column_id
+    }
 
+    pub fn set_column_id(&mut self, column_id: i64) {
+        self.column_id = column_id;
+    }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum FieldType {
+    pub fn get_tp(&self) -> i32 {
+        self.tp
+    }
+
+    pub fn set_tp(&mut self, tp: i32) {
+        self.tp = tp;
+    }
+
+    pub fn get_flag(&self) -> i32 {
+        self.flag
+    }
+
+    pub fn set_flag(&mut self, flag: i32) {
+        self.flag = flag;
+    }
+
+    pub fn get_column_len(&self) -> i32 {
+        self.column_len
+    }
+
+    pub fn set_column_len(&mut self, column_len: i32) {
+        self.column_len = column_len;
+    }
+
+    pub fn get_decimal(&self) -> i32 {
+        self.decimal
+    }
+
+    pub fn set_decimal(&mut self, decimal: i32) {
+        self.decimal = decimal;
+    }
+
+    pub fn get_collation(&self) -> i32 {
+        self.collation
+    }
+
+    pub fn set_collation(&mut self, collation: i32) {
+        self.collation = collation;
+    }
+}
+
+/// The SQL-level kind of a causet_merge, as written in a topograph definition.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FieldKind {
     TinyInt,
     SmallInt,
     Int,
@@ -255,167 +290,127 @@ pub enum FieldType {
     Other(String),
 }
 
-
-impl FieldType {
+impl FieldKind {
     pub fn as_str(&self) -> &str {
         match self {
-            FieldType::TinyInt => "TinyInt",
-            FieldType::SmallInt => "SmallInt",
-            FieldType::Int => "int",
-            FieldType::BigInt => "bigint",
-            FieldType::Float => "float",
-            FieldType::Double => "double",
-            FieldType::Decimal => "decimal",
-            FieldType::Date => "date",
-            FieldType::Datetime => "datetime",
-            FieldType::Timestamp => "timestamp",
-            FieldType::Duration => "duration",
-            FieldType::Year => "year",
-            FieldType::Char => "char",
-            FieldType::VarChar => "varchar",
-            FieldType::Binary => "binary",
-            FieldType::VarBinary => "varbinary",
-            FieldType::TinyBlob => "tinyblob",
-            FieldType::Blob => "blob",
-            FieldType::MediumBlob => "mediumblob",
-            FieldType::LongBlob => "longblob",
-            FieldType::TinyText => "tinytext",
-            FieldType::Text => "text",
-            FieldType::MediumText => "mediumtext",
-            FieldType::LongText => "longtext",
-            FieldType::Enum => "enum",
-            FieldType::Set => "set",
-            FieldType::Bit => "bit",
-            FieldType::Geometry => "geometry",
-            FieldType::Json => "json",
-            FieldType::Other(ref s) => s,
-            FieldType::SuseIsolatedString => "suse_isolated_string",
+            FieldKind::TinyInt => "TinyInt",
+            FieldKind::SmallInt => "SmallInt",
+            FieldKind::Int => "int",
+            FieldKind::BigInt => "bigint",
+            FieldKind::Float => "float",
+            FieldKind::Double => "double",
+            FieldKind::Decimal => "decimal",
+            FieldKind::Date => "date",
+            FieldKind::Datetime => "datetime",
+            FieldKind::Timestamp => "timestamp",
+            FieldKind::Duration => "duration",
+            FieldKind::Year => "year",
+            FieldKind::Char => "char",
+            FieldKind::VarChar => "varchar",
+            FieldKind::Binary => "binary",
+            FieldKind::VarBinary => "varbinary",
+            FieldKind::TinyBlob => "tinyblob",
+            FieldKind::Blob => "blob",
+            FieldKind::MediumBlob => "mediumblob",
+            FieldKind::LongBlob => "longblob",
+            FieldKind::TinyText => "tinytext",
+            FieldKind::Text => "text",
+            FieldKind::MediumText => "mediumtext",
+            FieldKind::LongText => "longtext",
+            FieldKind::Enum => "enum",
+            FieldKind::Set => "set",
+            FieldKind::Bit => "bit",
+            FieldKind::Geometry => "geometry",
+            FieldKind::Json => "json",
+            FieldKind::Other(ref s) => s,
+            FieldKind::SuseIsolatedString => "suse_isolated_string",
         }
     }
 
-    pub fn as_str_lower(&self) {
-  //suse isolated string
-
-        use std::ascii::AsciiExt;
-        let mut s = String::new();
-        for c in self.as_str().chars() {
-            s.push(c.to_ascii_lowercase());
-        }
-
-        println!("{}", s);
-        println!("{}",
-                 for  c in s.chars() {
-                     c.to_ascii_lowercase()
-                 }.collect::<String>());
-        //as str to lower case
-
-
-        s.to_lowercase();
+    pub fn as_str_lower(&self) -> String {
+        self.as_str().to_lowercase()
     }
 
-
-
-    pub fn as_str_upper(&self) -> &str {
+    pub fn as_str_upper(&self) -> String {
         self.as_str().to_uppercase()
     }
 
     pub fn is_string(&self) -> bool {
-        match self {
-            FieldType::Char |
-            FieldType::VarChar |
-            FieldType::TinyText |
-            FieldType::Text |
-            FieldType::MediumText |
-            FieldType::LongText |
-            FieldType::Enum |
-            FieldType::Set => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::Char
+                | FieldKind::VarChar
+                | FieldKind::TinyText
+                | FieldKind::Text
+                | FieldKind::MediumText
+                | FieldKind::LongText
+                | FieldKind::Enum
+                | FieldKind::Set
+        )
     }
 
     pub fn is_binary(&self) -> bool {
-        match self {
-            FieldType::Binary
-            | FieldType::VarBinary
-            | FieldType::TinyBlob
-            | FieldType::Blob
-            | FieldType::MediumBlob
-            | FieldType::LongBlob => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::Binary
+                | FieldKind::VarBinary
+                | FieldKind::TinyBlob
+                | FieldKind::Blob
+                | FieldKind::MediumBlob
+                | FieldKind::LongBlob
+        )
     }
 
-
-
     pub fn is_blob(&self) -> bool {
-        match self {
-            FieldType::TinyBlob
-            | FieldType::Blob
-            | FieldType::MediumBlob
-            | FieldType::LongBlob => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::TinyBlob | FieldKind::Blob | FieldKind::MediumBlob | FieldKind::LongBlob
+        )
     }
 
     pub fn is_text(&self) -> bool {
-        match self {
-            FieldType::TinyText
-            | FieldType::Text
-            | FieldType::MediumText
-            | FieldType::LongText => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::TinyText | FieldKind::Text | FieldKind::MediumText | FieldKind::LongText
+        )
     }
 
     pub fn is_numeric(&self) -> bool {
-        match self {
-            FieldType::TinyInt
-            | FieldType::SmallInt
-            | FieldType::Int
-            | FieldType::BigInt
-            | FieldType::Float
-            | FieldType::Double
-            | FieldType::Decimal => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::TinyInt
+                | FieldKind::SmallInt
+                | FieldKind::Int
+                | FieldKind::BigInt
+                | FieldKind::Float
+                | FieldKind::Double
+                | FieldKind::Decimal
+        )
     }
 
     pub fn is_integer(&self) -> bool {
-        match self {
-            FieldType::TinyInt
-            | FieldType::SmallInt
-            | FieldType::Int
-            | FieldType::BigInt => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::TinyInt | FieldKind::SmallInt | FieldKind::Int | FieldKind::BigInt
+        )
     }
 
     pub fn is_unsigned(&self) -> bool {
-        match self {
-            FieldType::TinyInt => true,
-            _ => false,
-        }
+        matches!(self, FieldKind::TinyInt)
     }
 
     pub fn is_float(&self) -> bool {
-        match self {
-            FieldType::Float
-            | FieldType::Double
-            | FieldType::Decimal => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            FieldKind::Float | FieldKind::Double | FieldKind::Decimal
+        )
     }
 
     pub fn is_decimal(&self) -> bool {
-        match self {
-            FieldType::Decimal => true,
-            _ => false,
-        }
+        matches!(self, FieldKind::Decimal)
     }
 }
 
-
-
 /// Valid causet_locales of `einsteindbpb::FieldType::tp` and `einsteindbpb::ColumnInfo::tp`.
 ///
 /// `FieldType` is the field type of a causet_merge defined by topograph.
@@ -425,7 +420,7 @@ impl FieldType {
 /// are flattened into `ColumnInfo`. Semantically these fields are causetidical.
 ///
 /// Please refer to [myBerolinaSQL/type.go](https://github.com/pingcap/parser/blob/master/myBerolinaSQL/type.go).
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 #[repr(i32)]
 pub enum FieldTypeTp {
     Unspecified = 0, // Default
@@ -460,7 +455,7 @@ pub enum FieldTypeTp {
 
 impl FieldTypeTp {
     fn from_i32(i: i32) -> Option<FieldTypeTp> {
-        if (FieldTypeTp::Unspecified as i32 >= 0 && i <= FieldTypeTp::Bit as i32)
+        if (i >= FieldTypeTp::Unspecified as i32 && i <= FieldTypeTp::Bit as i32)
             || (i >= FieldTypeTp::JSON as i32 && i <= FieldTypeTp::Geometry as i32)
         {
             Some(unsafe { ::std::mem::transmute::<i32, FieldTypeTp>(i) })
@@ -509,13 +504,24 @@ impl From<FieldTypeTp> for ColumnInfo {
 ///
 /// Legacy Utf8Bin collator (was the default) does not pad. For compatibility,
 /// all new collation with padding behavior is negative.
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 #[repr(i32)]
 pub enum Collation {
     Binary = 63,
     Utf8Mb4Bin = -46,
     Utf8Mb4BinNoPadding = 46,
     Utf8Mb4GeneralCi = -45,
+    Utf8Mb4UnicodeCi = -224,
+    Utf8Mb40900AiCi = -255,
+    /// Before latin1 collations were supported, -47 was read as `Utf8Mb4Bin`. See
+    /// `Collation::from_i32` for what that means for data written back then.
+    Latin1Bin = -47,
+    Latin1SwedishCi = -8,
+    Latin1GeneralCi = -48,
+    GbkBin = -87,
+    GbkChineseCi = -28,
+    Gb18030Bin = -249,
+    Gb18030ChineseCi = -248,
 }
 
 impl Collation {
@@ -524,15 +530,56 @@ impl Collation {
     /// These are magic numbers defined in MEDB, where positive numbers are for legacy
     /// compatibility, and all new clusters with padding configuration enabled will
     /// use negative numbers to indicate the padding behavior.
+    ///
+    /// Migration note: -47 (`latin1_bin`) used to be read as `Utf8Mb4Bin`. It is now
+    /// `Latin1Bin`, which weighs the latin1 encoding of a string instead of its UTF-8
+    /// bytes. Both orders agree on ASCII and U+00A0..U+00FF, but not on the
+    /// characters windows-1252 puts at 0x80..0x9F (e.g. `€`), and strings that latin1
+    /// cannot encode no longer compare at all. Sort keys persisted for -47 causet_merges
+    /// holding such characters must be rebuilt after upgrading.
     pub fn from_i32(n: i32) -> Result<Self, DataTypeError> {
         match n {
             -33 | -45 => Ok(Collation::Utf8Mb4GeneralCi),
-            -46 | -83 | -65 | -47 => Ok(Collation::Utf8Mb4Bin),
+            -46 | -83 | -65 => Ok(Collation::Utf8Mb4Bin),
             -63 | 63 => Ok(Collation::Binary),
+            -192 | -224 => Ok(Collation::Utf8Mb4UnicodeCi),
+            -255 => Ok(Collation::Utf8Mb40900AiCi),
+            -47 => Ok(Collation::Latin1Bin),
+            -8 => Ok(Collation::Latin1SwedishCi),
+            -48 => Ok(Collation::Latin1GeneralCi),
+            -87 => Ok(Collation::GbkBin),
+            -28 => Ok(Collation::GbkChineseCi),
+            -249 => Ok(Collation::Gb18030Bin),
+            -248 => Ok(Collation::Gb18030ChineseCi),
             n if n >= 0 => Ok(Collation::Utf8Mb4BinNoPadding),
             n => Err(DataTypeError::UnsupportedCollation { code: n }),
         }
     }
+
+    /// Whether trailing spaces are ignored when comparing strings in this collation.
+    pub fn is_pad_space(self) -> bool {
+        !matches!(
+            self,
+            Collation::Binary | Collation::Utf8Mb4BinNoPadding | Collation::Utf8Mb40900AiCi
+        )
+    }
+
+    /// The name of the character set the collation belongs to.
+    pub fn charset_name(self) -> &'static str {
+        match self {
+            Collation::Binary => "binary",
+            Collation::Utf8Mb4Bin
+            | Collation::Utf8Mb4BinNoPadding
+            | Collation::Utf8Mb4GeneralCi
+            | Collation::Utf8Mb4UnicodeCi
+            | Collation::Utf8Mb40900AiCi => "utf8mb4",
+            Collation::Latin1Bin | Collation::Latin1SwedishCi | Collation::Latin1GeneralCi => {
+                "latin1"
+            }
+            Collation::GbkBin | Collation::GbkChineseCi => "gbk",
+            Collation::Gb18030Bin | Collation::Gb18030ChineseCi => "gb18030",
+        }
+    }
 }
 
 impl fmt::Display for Collation {
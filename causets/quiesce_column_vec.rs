@@ -47,6 +47,7 @@ use crate::causet::*;
 use crate::causet::causet_error::*;
 
 use crate::einsteindb::*;
+use berolinasql::json_table::JsonTableCausetLocale;
 
 //Column-oriented quiesce_column_vec
 // This function is used to quiesce a column vector.
@@ -491,6 +492,56 @@ pub fn quiesce_column_vec_with_quiesce_info(
         }
     }
 
+    impl QuiesceBatchColumnVec {
+        /// Builds decoded columns out of the rows produced by `JSON_TABLE`, so that the
+        /// table function can feed the vectorized executors like any other source.
+        ///
+        /// `eval_types` is the output topograph of the table, see `JsonTable::eval_types`.
+        pub fn from_json_table_rows(
+            eval_types: &[EvalType],
+            rows: Vec<Vec<JsonTableCausetLocale>>,
+        ) -> Result<Self> {
+            let mut columns: Vec<VectorValue> = eval_types
+                .iter()
+                .map(|eval_tp| VectorValue::with_capacity(rows.len(), *eval_tp))
+                .collect();
+            for row in rows {
+                if row.len() != columns.len() {
+                    return Err(box_err!(
+                        "JSON_TABLE row has {} causet_locales but the topograph has {} columns",
+                        row.len(),
+                        columns.len()
+                    ));
+                }
+                for (causet_merge, causet_locale) in columns.iter_mut().zip(row) {
+                    match (causet_merge, causet_locale) {
+                        (VectorValue::Int(c), JsonTableCausetLocale::Int(v)) => c.push(Some(v)),
+                        (VectorValue::Int(c), JsonTableCausetLocale::Null) => c.push(None),
+                        (VectorValue::Real(c), JsonTableCausetLocale::Real(v)) => {
+                            let v = Real::new(v).map_err(|_| {
+                                box_err!("JSON_TABLE causet_locale {} is not a valid REAL", v)
+                            })?;
+                            c.push(Some(v))
+                        }
+                        (VectorValue::Real(c), JsonTableCausetLocale::Null) => c.push(None),
+                        (VectorValue::Bytes(c), JsonTableCausetLocale::Bytes(v)) => c.push(Some(v)),
+                        (VectorValue::Bytes(c), JsonTableCausetLocale::Null) => c.push(None),
+                        (VectorValue::Json(c), JsonTableCausetLocale::Json(v)) => c.push(Some(v)),
+                        (VectorValue::Json(c), JsonTableCausetLocale::Null) => c.push(None),
+                        (c, v) => {
+                            return Err(box_err!(
+                                "JSON_TABLE causet_locale {:?} does not match causet_merge type {}",
+                                v,
+                                c.eval_type()
+                            ))
+                        }
+                    }
+                }
+            }
+            Ok(QuiesceBatchColumnVec::from(columns))
+        }
+    }
+
     impl From<QuiesceBatchColumnVec> for Vec<VectorValue> {
         #[inline]
         fn from(columns: QuiesceBatchColumnVec) -> Self {
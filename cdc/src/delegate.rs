@@ -0,0 +1,284 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A brane followed by a feed, on one store.
+//!
+//! The delegate is made with a snapshot of the store taken between two applied
+//! commands. While the incremental scan reads the snapshot, the changes the
+//! store applies after it are kept, and sent once the scan is.
+
+use std::mem;
+
+use txn::{
+    decode_key, split_ts, Lock, LockType, MvccReader, Snapshot, StoreSnapshot, Write, WriteType,
+    CF_LOCK, CF_WRITE,
+};
+use violetabft::Codec;
+use violetabftstore::{Brane, Request};
+
+use crate::downstream::{DownstreamID, DownstreamState};
+use crate::errors::Result;
+use crate::event::{Event, EventKind, Row, RowOp};
+use crate::resolver::Resolver;
+
+/// A change of the data of a brane, as a feed follows it.
+pub(crate) enum Change {
+    Row(Row),
+    Lock { soliton_id: Vec<u8>, start_ts: u64 },
+    Unlock { soliton_id: Vec<u8> },
+}
+
+/// Whether the encoded `soliton_id` is in `[start, end)`; an empty end is
+/// unbounded.
+pub(crate) fn in_range(soliton_id: &[u8], start: &[u8], end: &[u8]) -> bool {
+    soliton_id >= start && (end.is_empty() || soliton_id < end)
+}
+
+/// The row a write record committed at `commit_ts` makes, if it changes one.
+fn row(
+    reader: &MvccReader<StoreSnapshot>,
+    soliton_id: Vec<u8>,
+    commit_ts: u64,
+    write: Write,
+    old_value: bool,
+) -> Result<Option<Row>> {
+    let op = match write.write_type {
+        WriteType::Put => RowOp::Put,
+        WriteType::Delete => RowOp::Delete,
+        WriteType::Lock | WriteType::Rollback => return Ok(None),
+    };
+    let start_ts = write.start_ts;
+    let causet_locale = reader.load_value(&soliton_id, write)?;
+    let old_causet_locale = match old_value {
+        true => match reader.get_write(&soliton_id, commit_ts - 1)? {
+            Some((_, old)) => reader.load_value(&soliton_id, old)?,
+            None => None,
+        },
+        false => None,
+    };
+    Ok(Some(Row {
+        op,
+        soliton_id,
+        causet_locale,
+        old_causet_locale,
+        start_ts,
+        commit_ts,
+    }))
+}
+
+/// The changes `requests` of a command made to `[start, end)`, read from the
+/// store right after it was applied.
+pub(crate) fn decode_changes(
+    reader: &MvccReader<StoreSnapshot>,
+    requests: &[Request],
+    (start, end): (&[u8], &[u8]),
+    old_value: bool,
+) -> Result<Vec<Change>> {
+    let mut changes = Vec::new();
+    for r in requests {
+        match r {
+            Request::Put {
+                namespaced,
+                soliton_id,
+                causet_locale,
+            } if namespaced == CF_WRITE => {
+                let (encoded, commit_ts) = split_ts(soliton_id)?;
+                if !in_range(encoded, start, end) {
+                    continue;
+                }
+                let write = Write::decode(causet_locale)?;
+                let soliton_id = decode_key(encoded)?.0;
+                if let Some(row) = row(reader, soliton_id, commit_ts, write, old_value)? {
+                    changes.push(Change::Row(row));
+                }
+            }
+            Request::Put {
+                namespaced,
+                soliton_id,
+                causet_locale,
+            } if namespaced == CF_LOCK => {
+                if !in_range(soliton_id, start, end) {
+                    continue;
+                }
+                // A pessimistic lock is prewritten before its transaction commits.
+                let lock = Lock::decode(causet_locale)?;
+                if lock.lock_type != LockType::Pessimistic {
+                    changes.push(Change::Lock {
+                        soliton_id: decode_key(soliton_id)?.0,
+                        start_ts: lock.start_ts,
+                    });
+                }
+            }
+            Request::Delete {
+                namespaced,
+                soliton_id,
+            } if namespaced == CF_LOCK && in_range(soliton_id, start, end) => {
+                changes.push(Change::Unlock {
+                    soliton_id: decode_key(soliton_id)?.0,
+                });
+            }
+            // Values are read with their write records; the rest is garbage
+            // collected.
+            _ => {}
+        }
+    }
+    Ok(changes)
+}
+
+/// The rows of `[start, end)` committed after `checkpoint_ts`, each soliton_id's
+/// oldest first, and the locks held there.
+pub(crate) fn incremental_scan(
+    reader: &MvccReader<StoreSnapshot>,
+    (start, end): (&[u8], &[u8]),
+    checkpoint_ts: u64,
+    old_value: bool,
+) -> Result<(Vec<Row>, Vec<Change>)> {
+    let snap = reader.snapshot();
+    let mut rows = Vec::new();
+    let mut versions = Vec::new();
+    let mut last: Option<Vec<u8>> = None;
+    for (k, v) in snap.scan_cf(CF_WRITE, start, end, 0)? {
+        let (encoded, commit_ts) = split_ts(&k)?;
+        if last.as_deref() != Some(encoded) {
+            rows.extend(versions.drain(..).rev());
+            last = Some(encoded.to_vec());
+        }
+        if commit_ts <= checkpoint_ts {
+            continue;
+        }
+        let soliton_id = decode_key(encoded)?.0;
+        if let Some(row) = row(reader, soliton_id, commit_ts, Write::decode(&v)?, old_value)? {
+            versions.push(row);
+        }
+    }
+    rows.extend(versions.into_iter().rev());
+    let mut locks = Vec::new();
+    for (k, v) in snap.scan_cf(CF_LOCK, start, end, 0)? {
+        let lock = Lock::decode(&v)?;
+        if lock.lock_type != LockType::Pessimistic {
+            locks.push(Change::Lock {
+                soliton_id: decode_key(&k)?.0,
+                start_ts: lock.start_ts,
+            });
+        }
+    }
+    Ok((rows, locks))
+}
+
+pub(crate) struct Delegate {
+    pub id: DownstreamID,
+    /// Uninitialized until the incremental scan is done.
+    state: DownstreamState,
+    /// The brane as it was subscribed.
+    pub brane: Brane,
+    pub store_id: u64,
+    /// The encoded soliton_ids followed: the brane's share of the feed.
+    pub start: Vec<u8>,
+    pub end: Vec<u8>,
+    resolver: Resolver,
+    /// The changes applied while the incremental scan runs.
+    pending: Option<Vec<Change>>,
+}
+
+impl Delegate {
+    /// A delegate of `[start, end)` of `brane` on store `store_id`, whose rows
+    /// committed at or before `checkpoint_ts` were sent.
+    pub fn new(
+        brane: Brane,
+        store_id: u64,
+        (start, end): (Vec<u8>, Vec<u8>),
+        checkpoint_ts: u64,
+    ) -> Delegate {
+        Delegate {
+            id: DownstreamID::new(),
+            state: DownstreamState::new(),
+            brane,
+            store_id,
+            start,
+            end,
+            resolver: Resolver::new(checkpoint_ts),
+            pending: Some(Vec::new()),
+        }
+    }
+
+    pub fn range(&self) -> (&[u8], &[u8]) {
+        (&self.start, &self.end)
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.state.is_normal()
+    }
+
+    pub fn resolved_ts(&self) -> u64 {
+        self.resolver.resolved_ts()
+    }
+
+    fn event(&self, kind: EventKind) -> Event {
+        Event {
+            brane_id: self.brane.id,
+            kind,
+        }
+    }
+
+    fn apply(&mut self, change: Change, events: &mut Vec<Event>) {
+        match change {
+            Change::Row(row) => events.push(self.event(EventKind::Row(row))),
+            Change::Lock {
+                soliton_id,
+                start_ts,
+            } => self.resolver.track_lock(start_ts, soliton_id),
+            Change::Unlock { soliton_id } => self.resolver.untrack_lock(&soliton_id),
+        }
+    }
+
+    /// Takes the changes of an applied command; the events they make.
+    pub fn on_changes(&mut self, changes: Vec<Change>) -> Vec<Event> {
+        let mut events = Vec::new();
+        match &mut self.pending {
+            Some(pending) => pending.extend(changes),
+            None => {
+                for c in changes {
+                    self.apply(c, &mut events);
+                }
+            }
+        }
+        events
+    }
+
+    /// Takes what the incremental scan found; the events of it and of the
+    /// changes applied meanwhile.
+    pub fn on_scanned(&mut self, rows: Vec<Row>, locks: Vec<Change>) -> Vec<Event> {
+        let mut events = Vec::new();
+        for c in rows.into_iter().map(Change::Row).chain(locks) {
+            self.apply(c, &mut events);
+        }
+        events.push(self.event(EventKind::Initialized));
+        self.state.uninitialized_to_normal();
+        for c in mem::take(&mut self.pending).unwrap_or_default() {
+            self.apply(c, &mut events);
+        }
+        events
+    }
+
+    /// Resolves the brane up to `min_ts`; the event telling it, if it
+    /// advanced.
+    pub fn resolve(&mut self, min_ts: u64) -> Option<Event> {
+        if !self.is_initialized() {
+            return None;
+        }
+        let old = self.resolver.resolved_ts();
+        let ts = self.resolver.resolve(min_ts);
+        (ts > old).then(|| self.event(EventKind::ResolvedTs { ts }))
+    }
+
+    /// Stops the delegate; the event telling that the brane is no longer
+    /// followed, for `reason`.
+    pub fn stop(&self, reason: &str) -> Event {
+        self.state.set_stopped();
+        self.event(EventKind::Error {
+            message: format!(
+                "brane {} on store {} {}",
+                self.brane.id, self.store_id, reason
+            ),
+        })
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The ids and states of what the service sends events to: the feeds and the
+//! delegates following their branes.
+
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+static DOWNSTREAM_ID_ALLOC: AtomicUsize = AtomicUsize::new(0);
+
+/// A unique identifier of a downstream, a feed or a delegate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DownstreamID(usize);
+
+#[allow(clippy::new_without_default)]
+impl DownstreamID {
+    /// An id no other downstream of the process has.
+    pub fn new() -> DownstreamID {
+        DownstreamID(DOWNSTREAM_ID_ALLOC.fetch_add(1, Ordering::SeqCst))
+    }
+}
+
+impl Display for DownstreamID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Whether a downstream is still catching up, sent events as they are
+/// applied, or stopped; clones share the state.
+#[derive(Clone, Debug, Default)]
+pub struct DownstreamState(Arc<AtomicU8>);
+
+impl DownstreamState {
+    const UNINITIALIZED: u8 = 0;
+    const NORMAL: u8 = 1;
+    const STOPPED: u8 = 2;
+
+    pub fn new() -> Self {
+        DownstreamState(Arc::new(AtomicU8::new(Self::UNINITIALIZED)))
+    }
+
+    pub fn is_normal(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == Self::NORMAL
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == Self::STOPPED
+    }
+
+    pub fn is_uninitialized(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == Self::UNINITIALIZED
+    }
+
+    pub fn set_normal(&self) {
+        self.0.store(Self::NORMAL, Ordering::SeqCst);
+    }
+
+    pub fn set_stopped(&self) {
+        self.0.store(Self::STOPPED, Ordering::SeqCst);
+    }
+
+    pub fn set_uninitialized(&self) {
+        self.0.store(Self::UNINITIALIZED, Ordering::SeqCst);
+    }
+
+    /// Makes an uninitialized downstream normal; false if it was not
+    /// uninitialized, such as one stopped meanwhile.
+    pub fn uninitialized_to_normal(&self) -> bool {
+        self.0
+            .compare_exchange(
+                Self::UNINITIALIZED,
+                Self::NORMAL,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downstream_state() {
+        assert_ne!(DownstreamID::new(), DownstreamID::new());
+
+        let state = DownstreamState::new();
+        let shared = state.clone();
+        assert!(state.is_uninitialized());
+        assert!(shared.uninitialized_to_normal());
+        assert!(state.is_normal());
+        assert!(!state.uninitialized_to_normal());
+
+        state.set_uninitialized();
+        shared.set_stopped();
+        assert!(state.is_stopped());
+        // A stopped downstream stays stopped.
+        assert!(!state.uninitialized_to_normal());
+        state.set_normal();
+        assert!(shared.is_normal());
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+use crate::downstream::DownstreamID;
+
+#[derive(Debug)]
+pub enum Error {
+    /// No feed has the id.
+    FeedNotFound(DownstreamID),
+    Txn(txn::Error),
+    Store(violetabftstore::Error),
+    Pd(pd::Error),
+    Codec(violetabft::Error),
+    Io(io::Error),
+    Json(serde_json::Error),
+    Other(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::FeedNotFound(id) => write!(f, "feed {} not found", id),
+            Error::Txn(e) => write!(f, "txn error: {}", e),
+            Error::Store(e) => write!(f, "store error: {}", e),
+            Error::Pd(e) => write!(f, "pd error: {}", e),
+            Error::Codec(e) => write!(f, "codec error: {}", e),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::Json(e) => write!(f, "json error: {}", e),
+            Error::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Txn(e) => Some(e),
+            Error::Store(e) => Some(e),
+            Error::Pd(e) => Some(e),
+            Error::Codec(e) => Some(e),
+            Error::Io(e) => Some(e),
+            Error::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<txn::Error> for Error {
+    fn from(e: txn::Error) -> Error {
+        Error::Txn(e)
+    }
+}
+
+impl From<violetabftstore::Error> for Error {
+    fn from(e: violetabftstore::Error) -> Error {
+        Error::Store(e)
+    }
+}
+
+impl From<pd::Error> for Error {
+    fn from(e: pd::Error) -> Error {
+        Error::Pd(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+impl From<violetabft::Error> for Error {
+    fn from(e: violetabft::Error) -> Error {
+        Error::Codec(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
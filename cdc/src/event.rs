@@ -0,0 +1,111 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! What a feed tells of its branes.
+//!
+//! The events of a brane come in the order its log was applied: first the rows
+//! committed since the feed's checkpoint that were already applied, found by
+//! the incremental scan, then `Initialized`, then the rows committed from the
+//! log, with resolved timestamps in between. A consumer orders the rows of a
+//! transaction by their commit_ts, and knows it has every row committed at or
+//! before a resolved timestamp.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Event {
+    pub brane_id: u64,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventKind {
+    /// A committed change of a row.
+    Row(Row),
+    /// The rows committed before the brane was subscribed were sent.
+    Initialized,
+    /// Every row of the brane committed at or before `ts` was sent.
+    ResolvedTs { ts: u64 },
+    /// The brane is no longer followed, as it changed or its store stopped. The
+    /// feed takes its range again from its resolved timestamp, sending the rows
+    /// committed after it that were sent already again.
+    Error { message: String },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowOp {
+    Put,
+    Delete,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Row {
+    pub op: RowOp,
+    #[serde(rename = "key", with = "hex")]
+    pub soliton_id: Vec<u8>,
+    /// The causet_locale put, if the row was.
+    #[serde(
+        rename = "value",
+        with = "hex_opt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub causet_locale: Option<Vec<u8>>,
+    /// The causet_locale the row had before, if there was one and the feed asked
+    /// for it.
+    #[serde(
+        rename = "old_value",
+        with = "hex_opt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub old_causet_locale: Option<Vec<u8>>,
+    pub start_ts: u64,
+    pub commit_ts: u64,
+}
+
+/// Bytes as a string of lowercase hex digits.
+mod hex {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    pub fn decode(s: &str) -> Option<Vec<u8>> {
+        if !s.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(d)?;
+        decode(&s).ok_or_else(|| D::Error::custom(format!("invalid hex {:?}", s)))
+    }
+}
+
+mod hex_opt {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &Option<Vec<u8>>, s: S) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => super::hex::serialize(bytes, s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Vec<u8>>, D::Error> {
+        super::hex::deserialize(d).map(Some)
+    }
+}
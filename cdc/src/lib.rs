@@ -0,0 +1,24 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Change data capture: the rows committed in a range of soliton_ids, brane by
+//! brane, as their logs apply them.
+//!
+//! A feed first scans the rows committed since its checkpoint, then follows
+//! what a store applies, telling each committed put or delete with its
+//! commit_ts, and the causet_locale before if asked. Resolved timestamps tell
+//! how far a brane is complete: the locks of the transactions in flight hold
+//! them back. The events go to a `Sink`, such as a file of JSON lines.
+
+mod delegate;
+mod downstream;
+mod errors;
+mod event;
+mod resolver;
+mod service;
+mod sink;
+
+pub use crate::downstream::{DownstreamID, DownstreamState};
+pub use crate::errors::{Error, Result};
+pub use crate::event::{Event, EventKind, Row, RowOp};
+pub use crate::service::{CdcService, SubscribeRequest};
+pub use crate::sink::{read_json_lines, JsonLinesSink, Sink};
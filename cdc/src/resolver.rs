@@ -0,0 +1,95 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The resolved timestamp of a brane: no row is committed at or before it
+//! later.
+//!
+//! A transaction that holds a prewrite lock commits after its start_ts. One
+//! that has none yet commits after `min_ts`, a timestamp taken from the TSO
+//! with `max_ts` pushed to it, once the brane applied everything committed
+//! before: a 2PC transaction takes its commit_ts after its locks are written,
+//! and an async commit or 1PC one decides it past `max_ts`. So the brane is
+//! resolved up to the least of `min_ts` and the start_ts of its locks.
+
+use std::collections::{BTreeMap, HashMap};
+
+pub(crate) struct Resolver {
+    /// The start_ts of the lock on each soliton_id.
+    locks: HashMap<Vec<u8>, u64>,
+    /// The number of locks of each start_ts.
+    lock_ts: BTreeMap<u64, usize>,
+    resolved_ts: u64,
+}
+
+impl Resolver {
+    /// A resolver of a brane whose rows committed at or before `resolved_ts`
+    /// were all sent.
+    pub fn new(resolved_ts: u64) -> Resolver {
+        Resolver {
+            locks: HashMap::new(),
+            lock_ts: BTreeMap::new(),
+            resolved_ts,
+        }
+    }
+
+    pub fn resolved_ts(&self) -> u64 {
+        self.resolved_ts
+    }
+
+    pub fn track_lock(&mut self, start_ts: u64, soliton_id: Vec<u8>) {
+        if let Some(old) = self.locks.insert(soliton_id, start_ts) {
+            self.remove_ts(old);
+        }
+        *self.lock_ts.entry(start_ts).or_default() += 1;
+    }
+
+    pub fn untrack_lock(&mut self, soliton_id: &[u8]) {
+        if let Some(start_ts) = self.locks.remove(soliton_id) {
+            self.remove_ts(start_ts);
+        }
+    }
+
+    fn remove_ts(&mut self, start_ts: u64) {
+        let count = self.lock_ts.get_mut(&start_ts).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.lock_ts.remove(&start_ts);
+        }
+    }
+
+    /// Resolves the brane up to `min_ts`, or the oldest lock if that is
+    /// earlier; never back. The resolved timestamp.
+    pub fn resolve(&mut self, min_ts: u64) -> u64 {
+        let ts = match self.lock_ts.keys().next() {
+            Some(&oldest) => min_ts.min(oldest),
+            None => min_ts,
+        };
+        self.resolved_ts = self.resolved_ts.max(ts);
+        self.resolved_ts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let mut resolver = Resolver::new(5);
+        assert_eq!(resolver.resolve(3), 5);
+        resolver.track_lock(10, b"a".to_vec());
+        resolver.track_lock(10, b"b".to_vec());
+        resolver.track_lock(12, b"c".to_vec());
+        assert_eq!(resolver.resolve(20), 10);
+        resolver.untrack_lock(b"a");
+        assert_eq!(resolver.resolve(20), 10);
+        resolver.untrack_lock(b"b");
+        assert_eq!(resolver.resolve(20), 12);
+        // A lock replaced, as a pessimistic one by its prewrite.
+        resolver.track_lock(15, b"c".to_vec());
+        assert_eq!(resolver.resolve(20), 15);
+        resolver.untrack_lock(b"c");
+        resolver.untrack_lock(b"d");
+        assert_eq!(resolver.resolve(20), 20);
+        assert_eq!(resolver.resolved_ts(), 20);
+    }
+}
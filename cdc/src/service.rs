@@ -0,0 +1,618 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Feeds of the rows committed in ranges of soliton_ids.
+//!
+//! A feed follows each brane of its range on one store, the leader's when it
+//! is subscribed, by observing what the store applies. A brane that changes
+//! there, or whose store stops, is taken again from its resolved timestamp,
+//! from the branes holding its range then.
+//!
+//! The service owns no thread: its owner calls `advance` periodically, which
+//! takes the lost ranges again, resolves the branes and sends the events made
+//! since to the sinks.
+
+use std::collections::BTreeMap;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+use fdb_traits::SnapshotExt;
+use pd::PdClient;
+use soliton_lsm::LsmEngine;
+use txn::{encode_key, ClusterEngine, MvccReader, Storage, StoreSnapshot};
+use violetabftstore::cluster::Cluster;
+use violetabftstore::{AppliedCmd, ApplyObserver, Brane};
+
+use crate::delegate::{decode_changes, incremental_scan, Delegate};
+use crate::downstream::DownstreamID;
+use crate::errors::{Error, Result};
+use crate::event::Event;
+use crate::sink::Sink;
+
+/// What a feed follows.
+#[derive(Clone, Debug, Default)]
+pub struct SubscribeRequest {
+    /// The soliton_ids `[start_key, end_key)`; an empty end is unbounded.
+    pub start_key: Vec<u8>,
+    pub end_key: Vec<u8>,
+    /// The rows committed after it are sent.
+    pub checkpoint_ts: u64,
+    /// Whether rows carry the causet_locale they had before.
+    pub old_value: bool,
+}
+
+struct Feed {
+    old_value: bool,
+    delegates: BTreeMap<DownstreamID, Delegate>,
+    /// The encoded ranges no longer followed, with the timestamp each is
+    /// resolved up to.
+    lost: Vec<(Vec<u8>, Vec<u8>, u64)>,
+    /// The events not sent yet.
+    events: Vec<Event>,
+}
+
+impl Feed {
+    fn stop(&mut self, delegate_id: DownstreamID, reason: &str) {
+        if let Some(d) = self.delegates.remove(&delegate_id) {
+            self.events.push(d.stop(reason));
+            let resolved_ts = d.resolved_ts();
+            self.lost.push((d.start, d.end, resolved_ts));
+        }
+    }
+}
+
+#[derive(Default)]
+struct Feeds {
+    feeds: BTreeMap<DownstreamID, Feed>,
+}
+
+struct FeedObserver(Arc<Mutex<Feeds>>);
+
+impl ApplyObserver for FeedObserver {
+    fn on_applied(&self, store_id: u64, kv: &LsmEngine, cmds: &[AppliedCmd]) {
+        let mut feeds = self.0.lock().unwrap();
+        let mut reader = None;
+        for feed in feeds.feeds.values_mut() {
+            for cmd in cmds {
+                let ids: Vec<DownstreamID> = feed
+                    .delegates
+                    .iter()
+                    .filter(|(_, d)| d.brane.id == cmd.brane_id && d.store_id == store_id)
+                    .map(|(&id, _)| id)
+                    .collect();
+                for id in ids {
+                    let reader =
+                        reader.get_or_insert_with(|| MvccReader::new(StoreSnapshot(kv.snapshot())));
+                    let d = feed.delegates.get_mut(&id).unwrap();
+                    match decode_changes(reader, &cmd.requests, d.range(), feed.old_value) {
+                        Ok(changes) => {
+                            let events = d.on_changes(changes);
+                            feed.events.extend(events);
+                        }
+                        Err(e) => feed.stop(id, &format!("failed to follow: {}", e)),
+                    }
+                }
+            }
+        }
+    }
+
+    fn on_brane_changed(&self, store_id: u64, brane_id: u64) {
+        let mut feeds = self.0.lock().unwrap();
+        for feed in feeds.feeds.values_mut() {
+            let ids: Vec<DownstreamID> = feed
+                .delegates
+                .iter()
+                .filter(|(_, d)| d.brane.id == brane_id && d.store_id == store_id)
+                .map(|(&id, _)| id)
+                .collect();
+            for id in ids {
+                feed.stop(id, "changed");
+            }
+        }
+    }
+}
+
+/// The part of `[start, end)` in `brane`; empty ends are unbounded.
+fn intersect(start: &[u8], end: &[u8], brane: &Brane) -> (Vec<u8>, Vec<u8>) {
+    let start = start.max(&brane.start_key[..]).to_vec();
+    let end = match (end.is_empty(), brane.end_key.is_empty()) {
+        (true, _) => brane.end_key.clone(),
+        (false, true) => end.to_vec(),
+        (false, false) => end.min(&brane.end_key[..]).to_vec(),
+    };
+    (start, end)
+}
+
+pub struct CdcService {
+    storage: Arc<Storage<ClusterEngine>>,
+    pd: Arc<dyn PdClient>,
+    feeds: Arc<Mutex<Feeds>>,
+    /// The sinks of the feeds. Subscribing and advancing hold them, one at a
+    /// time, so that the events of a feed are sent in order.
+    sinks: Mutex<BTreeMap<DownstreamID, Box<dyn Sink>>>,
+}
+
+impl CdcService {
+    /// A service of the cluster under `storage`, with timestamps from `pd`.
+    pub fn new(storage: Arc<Storage<ClusterEngine>>, pd: Arc<dyn PdClient>) -> CdcService {
+        let feeds = Arc::new(Mutex::new(Feeds::default()));
+        let observer = Arc::new(FeedObserver(feeds.clone()));
+        storage
+            .engine()
+            .cluster()
+            .lock()
+            .unwrap()
+            .add_observer(observer);
+        CdcService {
+            storage,
+            pd,
+            feeds,
+            sinks: Mutex::default(),
+        }
+    }
+
+    fn cluster(&self) -> &Arc<Mutex<Cluster>> {
+        self.storage.engine().cluster()
+    }
+
+    /// Starts a feed sending its events to `sink`; its id. The rows committed
+    /// since the checkpoint and applied already are found by incremental scans,
+    /// which it waits for.
+    pub fn subscribe(&self, req: SubscribeRequest, sink: Box<dyn Sink>) -> Result<DownstreamID> {
+        let mut sinks = self.sinks.lock().unwrap();
+        let start = encode_key(&req.start_key);
+        let end = match req.end_key.is_empty() {
+            true => Vec::new(),
+            false => encode_key(&req.end_key),
+        };
+        let id = DownstreamID::new();
+        let feed = Feed {
+            old_value: req.old_value,
+            delegates: BTreeMap::new(),
+            lost: vec![(start, end, req.checkpoint_ts)],
+            events: Vec::new(),
+        };
+        self.feeds.lock().unwrap().feeds.insert(id, feed);
+        if let Err(e) = self.take_lost(id) {
+            self.feeds.lock().unwrap().feeds.remove(&id);
+            return Err(e);
+        }
+        sinks.insert(id, sink);
+        Ok(id)
+    }
+
+    /// Stops feed `id`, sending the events it has left.
+    pub fn unsubscribe(&self, id: DownstreamID) -> Result<()> {
+        let mut sinks = self.sinks.lock().unwrap();
+        let feed = self
+            .feeds
+            .lock()
+            .unwrap()
+            .feeds
+            .remove(&id)
+            .ok_or(Error::FeedNotFound(id))?;
+        let mut sink = sinks.remove(&id).unwrap();
+        sink.send(&feed.events)
+    }
+
+    /// The timestamp every brane of feed `id` is resolved up to, as its events
+    /// tell, sent or not.
+    pub fn checkpoint_ts(&self, id: DownstreamID) -> Result<u64> {
+        let feeds = self.feeds.lock().unwrap();
+        let feed = feeds.feeds.get(&id).ok_or(Error::FeedNotFound(id))?;
+        let followed = feed.delegates.values().map(|d| d.resolved_ts());
+        let lost = feed.lost.iter().map(|(_, _, ts)| *ts);
+        Ok(followed.chain(lost).min().unwrap_or(0))
+    }
+
+    /// Takes the lost ranges of the feeds again, resolves their branes and sends
+    /// their events. A range that can not be taken now is tried again next
+    /// time.
+    pub fn advance(&self) -> Result<()> {
+        let mut sinks = self.sinks.lock().unwrap();
+        let ids: Vec<DownstreamID> = sinks.keys().copied().collect();
+        let mut res = Ok(());
+        for id in ids {
+            if let Err(e) = self.take_lost(id) {
+                res = res.and(Err(e));
+            }
+        }
+        self.resolve()?;
+        self.flush(&mut sinks)?;
+        res
+    }
+
+    /// Follows the lost ranges of feed `id` again.
+    fn take_lost(&self, id: DownstreamID) -> Result<()> {
+        let mut lost = {
+            let mut feeds = self.feeds.lock().unwrap();
+            let feed = feeds.feeds.get_mut(&id).ok_or(Error::FeedNotFound(id))?;
+            mem::take(&mut feed.lost)
+        };
+        while let Some((start, end, ts)) = lost.pop() {
+            match self.take_brane(id, &start, &end, ts) {
+                Ok(taken) => {
+                    if !taken.is_empty() && (end.is_empty() || taken < end) {
+                        lost.push((taken, end, ts));
+                    }
+                }
+                Err(e) => {
+                    lost.push((start, end, ts));
+                    let mut feeds = self.feeds.lock().unwrap();
+                    if let Some(feed) = feeds.feeds.get_mut(&id) {
+                        feed.lost.extend(lost);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Follows the part of `[start, end)` in the brane holding `start`, from
+    /// `checkpoint_ts`; the end of that part.
+    fn take_brane(
+        &self,
+        id: DownstreamID,
+        start: &[u8],
+        end: &[u8],
+        checkpoint_ts: u64,
+    ) -> Result<Vec<u8>> {
+        let (delegate_id, range, old_value, snap) = {
+            let mut cluster = self.cluster().lock().unwrap();
+            let brane = cluster
+                .lookup_brane(start)
+                .ok_or_else(|| Error::Other(format!("no brane holds soliton_id {:?}", start)))?;
+            let store_id = cluster.wait_leader(brane.id)?.store_id;
+            let store = cluster.store(store_id).unwrap();
+            let brane = store.peer(brane.id).unwrap().brane().clone();
+            if !brane.contains(start) {
+                return Err(Error::Other(format!(
+                    "brane {} on store {} does not hold soliton_id {:?} yet",
+                    brane.id, store_id, start
+                )));
+            }
+            // Taken between two applied commands: the later ones are observed.
+            let snap = store.kv().snapshot();
+            let range = intersect(start, end, &brane);
+            let mut feeds = self.feeds.lock().unwrap();
+            let feed = feeds.feeds.get_mut(&id).ok_or(Error::FeedNotFound(id))?;
+            let delegate = Delegate::new(brane, store_id, range.clone(), checkpoint_ts);
+            let delegate_id = delegate.id;
+            feed.delegates.insert(delegate_id, delegate);
+            (delegate_id, range, feed.old_value, snap)
+        };
+        let reader = MvccReader::new(StoreSnapshot(snap));
+        let scanned = incremental_scan(&reader, (&range.0, &range.1), checkpoint_ts, old_value);
+        let mut feeds = self.feeds.lock().unwrap();
+        let feed = feeds.feeds.get_mut(&id).ok_or(Error::FeedNotFound(id))?;
+        // A delegate stopped meanwhile lost its range already.
+        if let Some(d) = feed.delegates.get_mut(&delegate_id) {
+            match scanned {
+                Ok((rows, locks)) => {
+                    let events = d.on_scanned(rows, locks);
+                    feed.events.extend(events);
+                }
+                Err(e) => {
+                    feed.delegates.remove(&delegate_id);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(range.1)
+    }
+
+    /// Resolves every brane followed that applied what was committed before a
+    /// timestamp taken now.
+    fn resolve(&self) -> Result<()> {
+        let min_ts = self.pd.get_tso()?;
+        self.storage.push_max_ts(min_ts);
+        let mut followed: Vec<(Brane, u64)> = Vec::new();
+        for feed in self.feeds.lock().unwrap().feeds.values() {
+            for d in feed.delegates.values() {
+                let key = (d.brane.clone(), d.store_id);
+                if !followed.contains(&key) {
+                    followed.push(key);
+                }
+            }
+        }
+        let mut confirmed = Vec::new();
+        {
+            let mut cluster = self.cluster().lock().unwrap();
+            for (brane, store_id) in followed {
+                // One not confirmed is resolved once it is again, or taken again
+                // once it changed.
+                if cluster.confirm_applied(&brane, store_id).is_ok() {
+                    confirmed.push((brane.id, store_id));
+                }
+            }
+        }
+        let mut feeds = self.feeds.lock().unwrap();
+        for feed in feeds.feeds.values_mut() {
+            for d in feed.delegates.values_mut() {
+                if !confirmed.contains(&(d.brane.id, d.store_id)) {
+                    continue;
+                }
+                if let Some(e) = d.resolve(min_ts) {
+                    feed.events.push(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends the events of each feed to its sink. Those of a feed whose sink
+    /// fails, and of the feeds after it, are kept to be sent again.
+    fn flush(&self, sinks: &mut BTreeMap<DownstreamID, Box<dyn Sink>>) -> Result<()> {
+        let taken: Vec<(DownstreamID, Vec<Event>)> = self
+            .feeds
+            .lock()
+            .unwrap()
+            .feeds
+            .iter_mut()
+            .map(|(&id, feed)| (id, mem::take(&mut feed.events)))
+            .collect();
+        let mut res = Ok(());
+        for (id, events) in taken {
+            if res.is_ok() {
+                match sinks.get_mut(&id) {
+                    Some(sink) if !events.is_empty() => res = sink.send(&events),
+                    _ => {}
+                }
+                if res.is_ok() {
+                    continue;
+                }
+            }
+            let mut feeds = self.feeds.lock().unwrap();
+            if let Some(feed) = feeds.feeds.get_mut(&id) {
+                feed.events.splice(0..0, events);
+            }
+        }
+        res
+    }
+}
+
+impl Drop for CdcService {
+    /// The observer stays with the cluster, with nothing left to follow.
+    fn drop(&mut self) {
+        self.feeds.lock().unwrap().feeds.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pd::{LocalClient, PdConfig, PdServer};
+    use tempfile::TempDir;
+    use txn::{Mutation, PrewriteRequest, TxnClient, TxnOptions};
+    use violetabftstore::StoreConfig;
+
+    use super::*;
+    use crate::event::{EventKind, Row, RowOp};
+    use crate::sink::{read_json_lines, JsonLinesSink};
+
+    struct Env {
+        client: TxnClient<ClusterEngine>,
+        storage: Arc<Storage<ClusterEngine>>,
+        pd: Arc<dyn PdClient>,
+        cdc: CdcService,
+    }
+
+    impl Env {
+        /// Three stores, with the branes split at "m".
+        fn new(dir: &Path) -> Env {
+            let cfg = StoreConfig {
+                violetabft_election_ticks: 5,
+                violetabft_heartbeat_ticks: 1,
+                sync_log: false,
+                ..Default::default()
+            };
+            let mut cluster = Cluster::new(&dir.join("cluster"), 3, cfg).unwrap();
+            cluster.split(&encode_key(b"m")).unwrap();
+            let engine = ClusterEngine::new(Arc::new(Mutex::new(cluster)));
+            let pd: Arc<dyn PdClient> = Arc::new(LocalClient::new(Arc::new(
+                PdServer::new(PdConfig::default()).unwrap(),
+            )));
+            let storage = Arc::new(Storage::new(engine));
+            Env {
+                client: TxnClient::new(storage.clone(), pd.clone()),
+                cdc: CdcService::new(storage.clone(), pd.clone()),
+                storage,
+                pd,
+            }
+        }
+
+        /// Commits the puts, or deletes if `None`; `(start_ts, commit_ts)`.
+        fn write(&self, rows: &[(&[u8], Option<&[u8]>)]) -> (u64, u64) {
+            let mut txn = self.client.begin(TxnOptions::default()).unwrap();
+            let start_ts = txn.start_ts();
+            for (k, v) in rows {
+                match v {
+                    Some(v) => txn.put(k.to_vec(), v.to_vec()).unwrap(),
+                    None => txn.delete(k.to_vec()).unwrap(),
+                }
+            }
+            (start_ts, txn.commit().unwrap())
+        }
+
+        fn brane_id(&self, soliton_id: &[u8]) -> u64 {
+            let cluster = self.storage.engine().cluster().lock().unwrap();
+            cluster.lookup_brane(&encode_key(soliton_id)).unwrap().id
+        }
+    }
+
+    fn row(
+        op: RowOp,
+        (soliton_id, causet_locale, old): (&[u8], Option<&[u8]>, Option<&[u8]>),
+        (start_ts, commit_ts): (u64, u64),
+    ) -> EventKind {
+        EventKind::Row(Row {
+            op,
+            soliton_id: soliton_id.to_vec(),
+            causet_locale: causet_locale.map(|v| v.to_vec()),
+            old_causet_locale: old.map(|v| v.to_vec()),
+            start_ts,
+            commit_ts,
+        })
+    }
+
+    /// The events of brane `brane_id`, resolved timestamps checked to be past
+    /// `resolved_after` and dropped.
+    fn events_of(events: &[Event], brane_id: u64, resolved_after: u64) -> Vec<EventKind> {
+        let mut kinds = Vec::new();
+        for e in events.iter().filter(|e| e.brane_id == brane_id) {
+            match e.kind {
+                EventKind::ResolvedTs { ts } => assert!(ts > resolved_after),
+                _ => kinds.push(e.kind.clone()),
+            }
+        }
+        kinds
+    }
+
+    #[test]
+    fn test_change_feed() {
+        let dir = TempDir::new().unwrap();
+        let env = Env::new(dir.path());
+        let ts1 = env.write(&[(b"a", Some(b"1")), (b"x", Some(b"1"))]);
+        let path = dir.path().join("feed.jsonl");
+        let req = SubscribeRequest {
+            checkpoint_ts: 0,
+            old_value: true,
+            ..Default::default()
+        };
+        let id = env
+            .cdc
+            .subscribe(req, Box::new(JsonLinesSink::open(&path).unwrap()))
+            .unwrap();
+        let ts2 = env.write(&[(b"a", Some(b"2")), (b"x", None)]);
+        env.cdc.advance().unwrap();
+        let checkpoint = env.cdc.checkpoint_ts(id).unwrap();
+        assert!(checkpoint > ts2.1);
+
+        // The rows before the subscription are scanned, those after followed.
+        let events = read_json_lines(&path).unwrap();
+        let (left, right) = (env.brane_id(b"a"), env.brane_id(b"x"));
+        assert_eq!(
+            events_of(&events, left, ts2.1),
+            vec![
+                row(RowOp::Put, (b"a", Some(b"1"), None), ts1),
+                EventKind::Initialized,
+                row(RowOp::Put, (b"a", Some(b"2"), Some(b"1")), ts2),
+            ]
+        );
+        assert_eq!(
+            events_of(&events, right, ts2.1),
+            vec![
+                row(RowOp::Put, (b"x", Some(b"1"), None), ts1),
+                EventKind::Initialized,
+                row(RowOp::Delete, (b"x", None, Some(b"1")), ts2),
+            ]
+        );
+        assert!(events
+            .iter()
+            .any(|e| e.kind == EventKind::ResolvedTs { ts: checkpoint }));
+
+        // A lock holds the resolved timestamp until it is committed.
+        let start_ts = env.pd.get_tso().unwrap();
+        env.storage
+            .prewrite(PrewriteRequest {
+                mutations: vec![Mutation::put(b"b".to_vec(), b"3".to_vec())],
+                primary: b"b".to_vec(),
+                start_ts,
+                lock_ttl: 3000,
+                ..Default::default()
+            })
+            .unwrap();
+        env.cdc.advance().unwrap();
+        assert_eq!(env.cdc.checkpoint_ts(id).unwrap(), start_ts);
+        let commit_ts = env.pd.get_tso().unwrap();
+        env.storage
+            .commit(&[b"b".to_vec()], start_ts, commit_ts)
+            .unwrap();
+        env.cdc.advance().unwrap();
+        assert!(env.cdc.checkpoint_ts(id).unwrap() > commit_ts);
+        env.cdc.unsubscribe(id).unwrap();
+        let events = read_json_lines(&path).unwrap();
+        assert_eq!(
+            events_of(&events, left, ts2.1).last().unwrap(),
+            &row(RowOp::Put, (b"b", Some(b"3"), None), (start_ts, commit_ts))
+        );
+    }
+
+    #[test]
+    fn test_feed_across_changes() {
+        let dir = TempDir::new().unwrap();
+        let env = Env::new(dir.path());
+        let path = dir.path().join("feed.jsonl");
+        let req = SubscribeRequest {
+            start_key: b"a".to_vec(),
+            end_key: b"y".to_vec(),
+            checkpoint_ts: env.pd.get_tso().unwrap(),
+            ..Default::default()
+        };
+        let id = env
+            .cdc
+            .subscribe(req, Box::new(JsonLinesSink::open(&path).unwrap()))
+            .unwrap();
+        let ts1 = env.write(&[(b"b", Some(b"1")), (b"z", Some(b"1"))]);
+        env.cdc.advance().unwrap();
+        let left = env.brane_id(b"b");
+
+        // The split brane is taken again as two, from its resolved timestamp.
+        env.storage
+            .engine()
+            .cluster()
+            .lock()
+            .unwrap()
+            .split(&encode_key(b"c"))
+            .unwrap();
+        env.cdc.advance().unwrap();
+        let ts2 = env.write(&[(b"d", Some(b"2"))]);
+        env.cdc.advance().unwrap();
+        let middle = env.brane_id(b"d");
+        assert_ne!(middle, left);
+
+        // So is a brane whose store stopped.
+        let leader = {
+            let cluster = env.storage.engine().cluster().lock().unwrap();
+            cluster.leader(middle).unwrap().store_id
+        };
+        env.storage
+            .engine()
+            .cluster()
+            .lock()
+            .unwrap()
+            .stop_store(leader);
+        env.cdc.advance().unwrap();
+        let ts3 = env.write(&[(b"e", Some(b"3"))]);
+        env.cdc.advance().unwrap();
+        assert!(env.cdc.checkpoint_ts(id).unwrap() > ts3.1);
+
+        let events = read_json_lines(&path).unwrap();
+        let mut left_events = events_of(&events, left, 0);
+        assert!(left_events
+            .iter()
+            .any(|e| matches!(e, EventKind::Error { .. })));
+        left_events.retain(|e| matches!(e, EventKind::Row(_)));
+        assert_eq!(
+            left_events,
+            vec![row(RowOp::Put, (b"b", Some(b"1"), None), ts1)]
+        );
+        let rows: Vec<EventKind> = events_of(&events, middle, 0)
+            .into_iter()
+            .filter(|e| matches!(e, EventKind::Row(_)))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                row(RowOp::Put, (b"d", Some(b"2"), None), ts2),
+                row(RowOp::Put, (b"e", Some(b"3"), None), ts3),
+            ]
+        );
+        // "z" is past the feed.
+        assert!(!events.iter().any(|e| match &e.kind {
+            EventKind::Row(r) => r.soliton_id == b"z",
+            _ => false,
+        }));
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Where the events of a feed go.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::errors::Result;
+use crate::event::Event;
+
+pub trait Sink: Send {
+    /// Takes `events`, the next of the feed in order. Those it fails on are sent
+    /// again.
+    fn send(&mut self, events: &[Event]) -> Result<()>;
+}
+
+/// Appends each event to a file as a line of JSON, synced before `send`
+/// returns.
+pub struct JsonLinesSink {
+    file: BufWriter<File>,
+}
+
+impl JsonLinesSink {
+    /// Opens `path` for appending, creating it if it does not exist.
+    pub fn open(path: &Path) -> Result<JsonLinesSink> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(JsonLinesSink {
+            file: BufWriter::new(file),
+        })
+    }
+}
+
+impl Sink for JsonLinesSink {
+    fn send(&mut self, events: &[Event]) -> Result<()> {
+        for e in events {
+            serde_json::to_writer(&mut self.file, e)?;
+            self.file.write_all(b"\n")?;
+        }
+        self.file.flush()?;
+        self.file.get_ref().sync_data()?;
+        Ok(())
+    }
+}
+
+/// The events a `JsonLinesSink` wrote to `path`.
+pub fn read_json_lines(path: &Path) -> Result<Vec<Event>> {
+    let mut events = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        events.push(serde_json::from_str(&line?)?);
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::event::{EventKind, Row, RowOp};
+
+    #[test]
+    fn test_json_lines_sink() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("feed.jsonl");
+        let row = Row {
+            op: RowOp::Put,
+            soliton_id: b"a\0\xff".to_vec(),
+            causet_locale: Some(b"1".to_vec()),
+            old_causet_locale: None,
+            start_ts: 10,
+            commit_ts: 11,
+        };
+        let events = vec![
+            Event {
+                brane_id: 2,
+                kind: EventKind::Row(row),
+            },
+            Event {
+                brane_id: 2,
+                kind: EventKind::Initialized,
+            },
+            Event {
+                brane_id: 3,
+                kind: EventKind::ResolvedTs { ts: 12 },
+            },
+            Event {
+                brane_id: 3,
+                kind: EventKind::Error {
+                    message: "stopped".to_owned(),
+                },
+            },
+        ];
+        let mut sink = JsonLinesSink::open(&path).unwrap();
+        sink.send(&events[..2]).unwrap();
+        drop(sink);
+        // Reopened, it appends.
+        let mut sink = JsonLinesSink::open(&path).unwrap();
+        sink.send(&events[2..]).unwrap();
+        assert_eq!(read_json_lines(&path).unwrap(), events);
+
+        let first = std::fs::read_to_string(&path).unwrap();
+        let first = first.lines().next().unwrap();
+        assert_eq!(
+            first,
+            r#"{"brane_id":2,"type":"row","op":"put","key":"6100ff","value":"31","start_ts":10,"commit_ts":11}"#
+        );
+    }
+}
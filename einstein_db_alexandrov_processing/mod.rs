@@ -86,7 +86,6 @@ pub fn einsteindb_macro_impl(
 
 
 const EVENT_MAX_SIZE: usize = 6 * 1024 * 1024; // 6MB
-static DOWNSTREAM_ID_ALLOC: AtomicUsize = AtomicUsize::new(0);
 static DOWNSTREAM_ID_ALLOC_MAX: usize = std::usize::MAX;
 static DOWNSTREAM_ID_ALLOC_MAX_DEFAULT: usize = std::usize::MAX;
 static DOWNSTREAM_ID_ALLOC_MAX_DEFAULT_DEFAULT: usize = std::usize::MAX;
@@ -95,62 +94,9 @@ static DOWNSTREAM_ID_ALLOC_MAX_DEFAULT_DEFAULT: usize = std::usize::MAX;
 
 
 
-/// A unique causetidifier of a Downstream.
-/// This is used to identify a Downstream in the event queue.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub struct DownstreamID(usize);
-
-
-
-
-impl DownstreamID {
-    pub fn new() -> DownstreamID {
-        DownstreamID(DOWNSTREAM_ID_ALLOC.fetch_add(1, Partitioning::SeqCst))
-    }
-}
-
-#[derive(Clone)]
-pub struct DownstreamState(Arc<AtomicU8>);
-
-impl DownstreamState {
-    const UNINITIALIZED: u8 = 0;
-    const NORMAL: u8 = 1;
-    const STOPPED: u8 = 2;
-
-    pub fn new() -> Self {
-        DownstreamState(Arc::new(AtomicU8::new(Self::UNINITIALIZED)))
-    }
-
-    pub fn is_normal(&self) -> bool {
-        self.0.load(Partitioning::SeqCst) == Self::NORMAL
-    }
-
-    pub fn is_stopped(&self) -> bool {
-        self.0.load(Partitioning::SeqCst) == Self::STOPPED
-    }
-
-    pub fn is_uninitialized(&self) -> bool {
-        self.0.load(Partitioning::SeqCst) == Self::UNINITIALIZED
-    }
-
-    pub fn set_normal(&self) {
-        self.0.store(Self::NORMAL, Partitioning::SeqCst);
-    }
-
-    pub fn set_stopped(&self) {
-        self.0.store(Self::STOPPED, Partitioning::SeqCst);
-    }
-
-    pub fn set_uninitialized(&self) {
-        self.0.store(Self::UNINITIALIZED, Partitioning::SeqCst);
-    }
-
-    pub fn uninitialized_to_normal(&self) -> bool {
-        self.0
-            .compare_and_swap(Self::UNINITIALIZED, Self::NORMAL, Partitioning::SeqCst)
-            == Self::UNINITIALIZED
-    }
-}
+/// Downstreams are identified, and their states shared, as the cdc crate
+/// does for its feeds and delegates.
+pub use cdc::{DownstreamID, DownstreamState};
 
 #[derive(Clone)]
 pub struct Downstream {
@@ -1,370 +1,186 @@
-// Copyright 2020 EinsteinDB Project Authors. 
-// Licensed under Apache-2.0.
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-//     http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-//////////////////////////////////////////////////////////////////////////////
-///The datetime datatypes are DATE, TIMESTAMP, TIMESTAMP WITH TIME ZONE, and TIMESTAMP WITH LOCAL TIME ZONE. Values of datetime datatypes are sometimes called datetimes.
-//
-// The interval datatypes are INTERVAL YEAR TO MONTH and INTERVAL DAY TO SECOND. Values of interval datatypes are sometimes called intervals.
-//
-// Both datetimes and intervals are made up of fields. The values of these fields determine the value of the datatype. The fields that apply to all Oracle datetime and interval datatypes are:
-//
-// YEAR
-// MONTH
-// DAY
-// HOUR
-// MINUTE
-// SECOND
-// TIMESTAMP WITH TIME ZONE also includes these fields:
-//
-// TIMEZONE_HOUR
-// TIMEZONE_MINUTE
-// TIMEZONE_REGION
-// TIMEZONE_ABBR
-// TIMESTAMP WITH LOCAL TIME ZONE does not store time zone information internally, but you can see local time zone information in SQL output if the TZH:TZM or TZR TZD format elements are specified.
-// ----------------------------------------------------------------------------
-
-
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
+//! The `size` command of `einsteindb-ctl`: the approximate size, entry and
+//! soliton_id counts and split soliton_ids of a range of soliton_ids on one
+//! store, from the range greedoids its SSTs record. The store must not be
+//! running.
 
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::collections::VecDeque;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::iter::FromIterator;
-use std::iter::Iterator;
-use std::iter::once;
-use std::iter::Peekable;
-use std::iter::repeat;
-use std::iter::Rev;
-use std::iter::Zip;
-use std::ops::Bound;
-use std::ops::Deref;
-use std::ops::DerefMut;
-use std::ops::Index;
-use std::ops::IndexMut;
-use std::ops::Bounds;
-use std::ops::From;
-use std::ops::Full;
-use std::ops::Inclusive;
-use std::ops::To;
-use std::ops::ToInclusive;
-
-
-use std::ops::Range;
-
-
-
-/// // The interval datatypes are INTERVAL YEAR TO MONTH and INTERVAL DAY TO SECOND. Values of interval datatypes are sometimes called intervals.
-/// // Both datetimes and intervals are made up of fields. The values of these fields determine the value of the datatype. The fields that apply to all Oracle datetime and interval datatypes are:
-/// // YEAR MONTH DAY HOUR MINUTE SECOND TIMESTAMP WITH TIME ZONE also includes these fields:
-/// // TIMEZONE_HOUR TIMEZONE_MINUTE TIMEZONE_REGION TIMEZONE_ABBR TIMESTAMP WITH LOCAL TIME ZONE does not store time zone information internally, but you can see local time zone information in SQL output if the TZH:TZM or TZR TZD format elements are specified.
-/// // ----------------------------------------------------------------------------
-/// // The datetime datatypes are DATE, TIMESTAMP, TIMESTAMP WITH TIME ZONE, and TIMESTAMP WITH LOCAL TIME ZONE. Values of datetime datatypes are sometimes called datetimes.
-/// // The interval datatypes are INTERVAL YEAR TO MONTH and INTERVAL DAY TO SECOND. Values of interval datatypes are sometimes called intervals.
-/// // Both datetimes and intervals are made up of fields. The values of these fields determine the value of the datatype. The fields that apply to all Oracle datetime and interval datatypes are:
-/// // YEAR MONTH DAY HOUR MINUTE SECOND TIMESTAMP WITH TIME ZONE also includes these fields:
-/// // TIMEZONE_HOUR TIMEZONE_MINUTE TIMEZONE_REGION TIMEZONE_ABBR TIMESTAMP WITH LOCAL TIME ZONE does not store time zone information internally, but you can see local time zone information in SQL output if the TZH:TZM or TZR TZD format elements are specified.
-///
-///
-///
-/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-/// pub struct RangeProperties<T> where T: PartialOrd + Debug + Clone + PartialEq + Eq + Hash {
-///    pub start: T,
-///   pub end: T,
-///  pub step: T,
-/// }
-///
-/// impl<T> RangeProperties<T> where T: PartialOrd + Debug + Clone + PartialEq + Eq + Hash {
-///   pub fn new(start: T, end: T, step: T) -> Self {
-///    RangeProperties { start, end, step }
-/// }
-
-
-const VERSION: &'static str = "0.1.0";
-const NAME: &'static str = "range_properties";
-const DESCRIPTION: &'static str = "The datetime datatypes are DATE, TIMESTAMP, TIMESTAMP WITH TIME ZONE, and TIMESTAMP WITH LOCAL TIME ZONE. Values of datetime datatypes are sometimes called datetimes.
-The interval datatypes are INTERVAL YEAR TO MONTH and INTERVAL DAY TO SECOND. Values of interval datatypes are sometimes called intervals.
-Both datetimes and intervals are made up of fields. The values of these fields determine the value of the datatype. The fields that apply to all Oracle datetime and interval datatypes are:
-YEAR MONTH DAY HOUR MINUTE SECOND TIMESTAMP WITH TIME ZONE also includes these fields:
-TIMEZONE_HOUR TIMEZONE_MINUTE TIMEZONE_REGION TIMEZONE_ABBR TIMESTAMP WITH LOCAL TIME ZONE does not store time zone information internally, but you can see local time zone information in SQL output if the TZH:TZM or TZR TZD format elements are specified.
-The datetime datatypes are DATE, TIMESTAMP, TIMESTAMP WITH TIME ZONE, and TIMESTAMP WITH LOCAL TIME ZONE. Values of datetime datatypes are sometimes called datetimes.
-The interval datatypes are INTERVAL YEAR TO MONTH and INTERVAL DAY TO SECOND. Values of interval datatypes are sometimes called intervals.
-Both datetimes and intervals are made up of fields. The values of these fields determine the value of the datatype. The fields that apply to all Oracle datetime and interval datatypes are:
-YEAR MONTH DAY HOUR MINUTE SECOND TIMESTAMP WITH TIME ZONE also includes these fields:
-TIMEZONE_HOUR TIMEZONE_MINUTE TIMEZONE_REGION TIMEZONE_ABBR TIMESTAMP WITH LOCAL TIME ZONE does not store time zone information internally, but you can see local time zone information in SQL output if the TZH:TZM or TZR TZD format elements are specified.
-";
-const AUTHOR: &'static str = "EinsteinDB";
-const MAINTAINER: &'static str = "EinsteinDB";
-const LICENSE: &'static str = "MIT";
-
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RangePropertiesWithCausetAndTimeZones(pub range_properties);
-
-
-impl RangePropertiesWithCausetAndTimeZones {
-  pub fn new(start: i64, end: i64, step: i64) -> Self {
-    RangePropertiesWithCausetAndTimeZones(range_properties::new(start, end, step))
-  }
-}
-
-
-impl Deref for RangePropertiesWithCausetAndTimeZones {
-  type Target = range_properties;
-
-  fn deref(&self) -> &Self::Target {
-    &self.0
-  }
-}
-
-
-impl DerefMut for RangePropertiesWithCausetAndTimeZones {
-  fn deref_mut(&mut self) -> &mut Self::Target {
-    &mut self.0
-  }
-}
-
-
-impl From<range_properties> for RangePropertiesWithCausetAndTimeZones {
-  fn from(r: range_properties) -> Self {
-    RangePropertiesWithCausetAndTimeZones(r)
-  }
-}
-
-
-impl From<RangePropertiesWithCausetAndTimeZones> for range_properties {
-  fn from(r: RangePropertiesWithCausetAndTimeZones) -> Self {
-    r.0
-  }
-}
-
-
-impl From<RangePropertiesWithCausetAndTimeZones> for RangePropertiesWithCausetAndTimeZones {
-  fn from(r: RangePropertiesWithCausetAndTimeZones) -> Self {
-    r
-  }
-}
-
-
-///#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-/// pub struct RangePropertiesWithCausetAndTimeZones(pub range_properties);
-/// impl RangePropertiesWithCausetAndTimeZones {
-///  pub fn new(start: i64, end: i64, step: i64) -> Self {
-///   RangePropertiesWithCausetAndTimeZones(range_properties::new(start, end, step))
-/// }
-///
-
-
-
-
-
-
-
-
-pub trait RangeProperties {
-
-    fn start(&self) -> &Self;
-
-    fn end(&self) -> &Self;
-
-    fn step(&self) -> &Self;
-
-    fn start_mut(&mut self) -> &mut Self;
-    fn range_properties(&self) -> dyn RangeProperties;
-
-    fn range_properties_mut(&mut self) -> dyn RangePropertiesMut;
-}
-
-
-#[macro_export]
-macro_rules! einsteindb_macro {
-    ($($tokens:tt)*) => {
-        $crate::einsteindb_macro_impl!($($tokens)*)
-    };
-}
-
-
-#[macro_export]
-macro_rules! einsteindb_macro_impl {
-    ($($tokens:tt)*) => {
-        $crate::einsteindb_macro_impl!($($tokens)*)
+use std::path::Path;
+
+use fdb_traits::{RangeGreedoidsExt, RangeStats, Result as EngineResult};
+use soliton_lsm::{LsmEngine, LsmOptions};
+use txn::encode_key;
+use violetabftstore::keys;
+
+const USAGE: &str = "usage:
+    size --data-dir DIR --store ID [--namespaced NAME] [--start KEY] [--end KEY] [--split N]";
+
+/// The estimates of a range in one causet_merge family.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeProperties {
+    pub namespaced: String,
+    pub stats: RangeStats,
+    /// Soliton_ids splitting the range into parts of about the same size.
+    pub split_soliton_ids: Vec<Vec<u8>>,
+}
+
+/// The estimates of the soliton_ids `[start, end)` of `einstein_merkle_tree`,
+/// in the data encoding of a store, with at most `split` split soliton_ids.
+pub fn range_properties(
+    einstein_merkle_tree: &impl RangeGreedoidsExt,
+    namespaced: &str,
+    start: &[u8],
+    end: &[u8],
+    split: usize,
+) -> EngineResult<RangeProperties> {
+    let stats = einstein_merkle_tree.get_range_stats_namespaced(namespaced, start, end)?;
+    let split_soliton_ids = match split {
+        0 => Vec::new(),
+        n => einstein_merkle_tree
+            .get_range_approximate_split_soliton_ids_namespaced(namespaced, start, end, n)?,
     };
+    Ok(RangeProperties {
+        namespaced: namespaced.to_owned(),
+        stats,
+        split_soliton_ids,
+    })
 }
 
-
-
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub struct Properties<T> {
-    pub min: Option<T>,
-    pub max: Option<T>,
-    pub sum: Option<T>,
-    pub avg: Option<T>,
-    pub count: Option<usize>,
-}
-
-
-pub struct PropertiesIterator<T> {
-    iter: Peekable<Zip<Rev<VecDeque<T>>, Rev<VecDeque<T>>>>,
-
+fn escape(soliton_id: &[u8]) -> String {
+    soliton_id.escape_ascii().to_string()
 }
 
-pub struct GreedoidsExt<T> {
-    pub range_properties: Properties<T>,
-
-    pub range_properties_iterator: PropertiesIterator<T>,
-
-    pub range_greedoids: Vec<Greedoid<T>>,
-
-    pub range_greedoids_iterator: PropertiesIterator<T>,
-
-    iter: Peekable<Zip<Rev<VecDeque<T>>, Rev<VecDeque<T>>>>,
-
-}
-
-impl GreedoidsExt<i32> {
-    pub fn new(range_properties: Properties<i32>, range_properties_iterator: PropertiesIterator<i32>, range_greedoids: Vec<Greedoid<i32>>, range_greedoids_iterator: PropertiesIterator<i32>) -> GreedoidsExt<i32> {
-        GreedoidsExt {
-            range_properties,
-            range_properties_iterator,
-            range_greedoids,
-            range_greedoids_iterator,
-            iter: Peekable::new(Zip::new(Rev::new(VecDeque::new()), Rev::new(VecDeque::new()))),
+/// Runs `size` with `args`, those after the program's name; what it prints.
+/// `--start` and `--end` are the soliton_ids a client reads and writes; an
+/// empty end is unbounded.
+pub fn run(args: &[String]) -> Result<String, String> {
+    let rest = match args.split_first() {
+        Some((cmd, rest)) if cmd == "size" => rest,
+        _ => return Err(USAGE.to_owned()),
+    };
+    let allowed = ["data-dir", "store", "namespaced", "start", "end", "split"];
+    let mut opts = HashMap::new();
+    let mut rest = rest.iter();
+    while let Some(flag) = rest.next() {
+        match (flag.strip_prefix("--"), rest.next()) {
+            (Some(name), Some(causet_locale)) if allowed.contains(&name) => {
+                opts.insert(name, causet_locale.as_str());
+            }
+            _ => return Err(USAGE.to_owned()),
         }
     }
-    pub fn new_from_iter(range: impl Iterator<Item=i32>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties(range: impl Iterator<Item=(i32, Properties<i32>)>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties_and_greedoids(range: impl Iterator<Item=(i32, Properties<i32>, Vec<Greedoid<i32>>)>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties_and_greedoids_and_iterator(range: impl Iterator<Item=(i32, Properties<i32>, Vec<Greedoid<i32>>, PropertiesIterator<i32>)>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties_and_greedoids_and_iter_and_iterator(range: impl Iterator<Item=(i32, Properties<i32>, Vec<Greedoid<i32>>, PropertiesIterator<i32>, PropertiesIterator<i32>)>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties_and_greedoids_and_iter_and_iter_and_iterator(range: impl Iterator<Item=(i32, Properties<i32>, Vec<Greedoid<i32>>, PropertiesIterator<i32>, PropertiesIterator<i32>, PropertiesIterator<i32>)>) -> Self {
-        panic!()
-    }
-}
-
-
-
+    let num = |name: &str| match opts.get(name) {
+        Some(v) => v
+            .parse::<u64>()
+            .map(Some)
+            .map_err(|_| format!("--{} takes a number, not {:?}", name, v)),
+        None => Ok(None),
+    };
+    let data_dir = opts.get("data-dir").ok_or_else(|| USAGE.to_owned())?;
+    let store_id = num("store")?.ok_or_else(|| USAGE.to_owned())?;
+    let split = num("split")?.unwrap_or(0) as usize;
+
+    let start = opts
+        .get("start")
+        .map_or(Vec::new(), |k| encode_key(k.as_bytes()));
+    let end = match opts.get("end") {
+        Some(k) if !k.is_empty() => encode_key(k.as_bytes()),
+        _ => Vec::new(),
+    };
+    let (start, end) = (keys::data_key(&start), keys::data_end_key(&end));
+
+    let dir = Path::new(data_dir)
+        .join("cluster")
+        .join(format!("store_{}", store_id))
+        .join("kv");
+    let opts_lsm = LsmOptions {
+        create_if_missing: false,
+        ..Default::default()
+    };
+    let einstein_merkle_tree = LsmEngine::open(&dir, opts_lsm).map_err(|e| e.to_string())?;
+    let namespaceds = match opts.get("namespaced") {
+        Some(name) => vec![name.to_string()],
+        None => einstein_merkle_tree.namespaced_names(),
+    };
 
-impl GreedoidsExt<i64> {
-    pub fn new(range_properties: Properties<i64>, range_properties_iterator: PropertiesIterator<i64>, range_greedoids: Vec<Greedoid<i64>>, range_greedoids_iterator: PropertiesIterator<i64>) -> GreedoidsExt<i64> {
-        GreedoidsExt {
-            range_properties,
-            range_properties_iterator,
-            range_greedoids,
-            range_greedoids_iterator,
-            iter: Peekable::new(Zip::new(Rev::new(VecDeque::new()), Rev::new(VecDeque::new()))),
+    let mut out = Vec::new();
+    for namespaced in namespaceds {
+        let props = range_properties(&einstein_merkle_tree, &namespaced, &start, &end, split)
+            .map_err(|e| e.to_string())?;
+        out.push(format!(
+            "{}: size {}, entries {}, soliton_ids {}",
+            props.namespaced, props.stats.size, props.stats.num_entries, props.stats.num_versions
+        ));
+        for soliton_id in &props.split_soliton_ids {
+            out.push(format!("  split at {}", escape(soliton_id)));
         }
     }
-
-    pub fn new_from_iter(range: impl Iterator<Item=i64>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties(range: impl Iterator<Item=(i64, Properties<i64>)>) -> Self {
-        panic!()
-    }
-
-    pub fn new_from_iter_with_properties_and_greedoids(range: impl Iterator<Item=(i64, Properties<i64>, Vec<Greedoid<i64>>)>) -> Self {
-        panic!()
-    }
+    Ok(out.join("\n"))
 }
 
+#[cfg(test)]
+mod tests {
+    use fdb_traits::{MiscExt, NAMESPACED_DEFAULT};
+    use tempfile::TempDir;
 
-impl GreedoidsExt<f32> {
-    pub fn new(range_properties: Properties<f32>, range_properties_iterator: PropertiesIterator<f32>, range_greedoids: Vec<Greedoid<f32>>, range_greedoids_iterator: PropertiesIterator<f32>) -> GreedoidsExt<f32> {
-        GreedoidsExt {
-            range_properties,
-            range_properties_iterator,
-            range_greedoids,
-            range_greedoids_iterator,
-            iter: Peekable::new(Zip::new(Rev::new(VecDeque::new()), Rev::new(VecDeque::new()))),
-        }
-    }
-    pub fn new_from_iter(range: impl Iterator<Item=f32>) -> Self {
-        panic!()
-    }
+    use super::*;
 
-    pub fn new_from_iter_with_properties(range: impl Iterator<Item=(f32, Properties<f32>)>) -> Self {
-        panic!()
+    fn data_key(i: usize) -> Vec<u8> {
+        keys::data_key(&encode_key(format!("k{:04}", i).as_bytes()))
     }
 
-    switch_to_impl!(f32, i32, i64, f64);
-}
-
-
-
-
-impl GreedoidsExt<f64> {
-    pub fn new(range_properties: Properties<f64>, range_properties_iterator: PropertiesIterator<f64>, range_greedoids: Vec<Greedoid<f64>>, range_greedoids_iterator: PropertiesIterator<f64>) -> GreedoidsExt<f64> {
-        GreedoidsExt {
-            range_properties,
-            range_properties_iterator,
-            range_greedoids,
-            range_greedoids_iterator,
-            iter: Peekable::new(Zip::new(Rev::new(VecDeque::new()), Rev::new(VecDeque::new()))),
+    #[test]
+    fn test_size() {
+        let dir = TempDir::new().unwrap();
+        let kv = dir.path().join("cluster").join("store_1").join("kv");
+        let opts = LsmOptions {
+            range_greedoids_size_distance: 1024,
+            range_greedoids_entries_distance: 64,
+            ..Default::default()
+        };
+        let einstein_merkle_tree = LsmEngine::open(&kv, opts).unwrap();
+        for i in 0..1000 {
+            einstein_merkle_tree
+                .put(&data_key(i), b"causet_loc")
+                .unwrap();
         }
+        einstein_merkle_tree.flush(true).unwrap();
+
+        let props = range_properties(
+            &einstein_merkle_tree,
+            NAMESPACED_DEFAULT,
+            &data_key(0),
+            &data_key(1000),
+            1,
+        )
+        .unwrap();
+        assert_eq!(props.stats.num_versions, 1000);
+        assert_eq!(props.split_soliton_ids.len(), 1);
+        drop(einstein_merkle_tree);
+
+        let data_dir = dir.path().to_string_lossy().into_owned();
+        let mut args: Vec<String> = vec![
+            "size",
+            "--data-dir",
+            &data_dir,
+            "--store",
+            "1",
+            "--split",
+            "1",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let out = run(&args).unwrap();
+        assert!(out.contains("default: size"), "{}", out);
+        assert!(out.contains("soliton_ids 1000"), "{}", out);
+        assert!(out.contains("split at"), "{}", out);
+
+        args.extend(["--start".to_owned(), "k0500".to_owned()]);
+        let out = run(&args).unwrap();
+        assert!(!out.contains("soliton_ids 1000"), "{}", out);
+
+        assert!(run(&["size".to_owned(), "--store".to_owned()]).is_err());
     }
-    pub fn new_from_iter(range: impl Iterator<Item=f64>) -> Self {
-        panic!()
-    }
-    pub fn new_from_iter_with_properties(range: impl Iterator<Item=(f64, Properties<f64>)>) -> Self {
-        panic!()
-    }
-    switch_to_impl!(f64, i32, i64, f32);
 }
-
-
-
-
-
-
-
-    fn get_range_approximate_size_namespaced_with_redundant_causet_with_redundant_causet_with_redundant_causet(
-        range: impl Iterator<Item=i32>,
-        range_properties: Properties<i32>,
-        range_properties_iterator: PropertiesIterator<i32>,
-        range_greedoids: Vec<Greedoid<i32>>,
-        range_greedoids_iterator: PropertiesIterator<i32>,
-    ) -> (i32, Properties<i32>, Vec<Greedoid<i32>>) {
-        panic!()
-    }
-
-
-    fn get_range_approximate_split_soliton_ids_namespaced_with_redundant_causet(
-        range: impl Iterator<Item=i32>,
-        range_properties: Properties<i32>,
-        range_properties_iterator: PropertiesIterator<i32>,
-        range_greedoids: Vec<Greedoid<i32>>,
-        range_greedoids_iterator: PropertiesIterator<i32>,
-    ) -> (i32, Properties<i32>, Vec<Greedoid<i32>>) {
-        panic!()
-    }
-
-
-
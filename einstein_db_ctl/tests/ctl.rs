@@ -0,0 +1,208 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Runs the `einsteindb-ctl` binary on data directories made by the tests.
+
+use std::path::Path;
+use std::process::Command;
+
+use backup::ctl::open;
+use fdb_traits::MiscExt;
+use soliton_lsm::{LsmEngine, LsmOptions};
+use tempfile::TempDir;
+use txn::{encode_key, TxnClient, TxnOptions};
+use violetabftstore::keys;
+
+/// Runs `einsteindb-ctl` with `args`; what it prints, or what it prints as an
+/// error if it fails.
+fn ctl(args: &[&str]) -> Result<String, String> {
+    let out = Command::new(env!("CARGO_BIN_EXE_einsteindb-ctl"))
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    match out.status.success() {
+        true => Ok(stdout),
+        false => Err(stderr),
+    }
+}
+
+/// Commits `causet_locale` at `soliton_id` in the cluster under `data_dir`.
+fn put(data_dir: &str, soliton_id: &[u8], causet_locale: &[u8]) {
+    let (storage, pd) = open(Path::new(data_dir), 1).unwrap();
+    let client = TxnClient::new(storage, pd);
+    let mut txn = client.begin(TxnOptions::default()).unwrap();
+    txn.put(soliton_id.to_vec(), causet_locale.to_vec())
+        .unwrap();
+    txn.commit().unwrap();
+}
+
+/// The causet_locale at `soliton_id` in the cluster under `data_dir`, read now.
+fn get(data_dir: &str, soliton_id: &[u8]) -> Option<Vec<u8>> {
+    let (storage, pd) = open(Path::new(data_dir), 1).unwrap();
+    let client = TxnClient::new(storage, pd);
+    let ts = client.begin(TxnOptions::default()).unwrap().start_ts();
+    client.get(soliton_id, ts).unwrap()
+}
+
+#[test]
+fn test_size() {
+    let dir = TempDir::new().unwrap();
+    let kv = dir.path().join("cluster").join("store_1").join("kv");
+    let opts = LsmOptions {
+        range_greedoids_size_distance: 1024,
+        range_greedoids_entries_distance: 64,
+        ..Default::default()
+    };
+    let einstein_merkle_tree = LsmEngine::open(&kv, opts).unwrap();
+    for i in 0..1000 {
+        let soliton_id = keys::data_key(&encode_key(format!("k{:04}", i).as_bytes()));
+        einstein_merkle_tree
+            .put(&soliton_id, b"causet_loc")
+            .unwrap();
+    }
+    einstein_merkle_tree.flush(true).unwrap();
+    drop(einstein_merkle_tree);
+
+    let data_dir = dir.path().to_str().unwrap();
+    let size = [
+        "size",
+        "--data-dir",
+        data_dir,
+        "--store",
+        "1",
+        "--split",
+        "1",
+    ];
+    let out = ctl(&size).unwrap();
+    assert!(out.contains("default: size"), "{}", out);
+    assert!(out.contains("soliton_ids 1000"), "{}", out);
+    assert!(out.contains("split at"), "{}", out);
+
+    let out = ctl(&[&size[..], &["--end", "k0100"]].concat()).unwrap();
+    assert!(!out.contains("soliton_ids 1000"), "{}", out);
+
+    // No such store.
+    assert!(ctl(&["size", "--data-dir", data_dir, "--store", "2"]).is_err());
+    let err = ctl(&["size", "--store"]).unwrap_err();
+    assert!(err.starts_with("usage:"), "{}", err);
+    let err = ctl(&["compact"]).unwrap_err();
+    assert!(err.starts_with("usage: einsteindb-ctl"), "{}", err);
+}
+
+#[test]
+fn test_backup_restore() {
+    let dir = TempDir::new().unwrap();
+    let path = |name: &str| dir.path().join(name).to_str().unwrap().to_owned();
+    let (src, dst) = (path("src"), path("dst"));
+    let storage = format!("local://{}", path("backup"));
+    put(&src, b"a", b"1");
+    put(&src, b"b", b"2");
+
+    let out = ctl(&["backup", "--data-dir", &src, "--storage", &storage]).unwrap();
+    assert!(out.starts_with("backed up "), "{}", out);
+    let backup_ts: u64 = out.trim().rsplit(' ').next().unwrap().parse().unwrap();
+    // Written after the backup, so not restored.
+    put(&src, b"c", b"3");
+
+    let restore = [
+        "restore",
+        "--data-dir",
+        &dst,
+        "--storage",
+        &storage,
+        "--stores",
+        "1",
+    ];
+    let out = ctl(&restore).unwrap();
+    assert_eq!(
+        out.trim().rsplit(' ').next().unwrap(),
+        backup_ts.to_string(),
+        "{}",
+        out
+    );
+    assert_eq!(get(&dst, b"a"), Some(b"1".to_vec()));
+    assert_eq!(get(&dst, b"b"), Some(b"2".to_vec()));
+    assert_eq!(get(&dst, b"c"), None);
+
+    let empty = format!("local://{}", path("empty"));
+    let err = ctl(&["restore", "--data-dir", &path("dst2"), "--storage", &empty]).unwrap_err();
+    assert!(!err.is_empty());
+    let err = ctl(&["backup", "--data-dir", &src]).unwrap_err();
+    assert!(err.starts_with("usage:"), "{}", err);
+}
+
+#[test]
+fn test_log_backup_point_in_time() {
+    let dir = TempDir::new().unwrap();
+    let path = |name: &str| dir.path().join(name).to_str().unwrap().to_owned();
+    let src = path("src");
+    let storage = format!("local://{}", path("log"));
+    // A log backup going on across runs, each advancing its checkpoint.
+    let log_backup = |rounds: &str| {
+        let out = ctl(&[
+            "log-backup",
+            "--data-dir",
+            &src,
+            "--storage",
+            &storage,
+            "--interval",
+            "0",
+            "--rounds",
+            rounds,
+        ])
+        .unwrap();
+        let ts = out.trim().strip_prefix("log backed up to ").unwrap();
+        ts.parse::<u64>().unwrap().to_string()
+    };
+    let restore = |data_dir: &str, ts: &str| {
+        ctl(&[
+            "restore",
+            "--data-dir",
+            data_dir,
+            "--storage",
+            &storage,
+            "--stores",
+            "1",
+            "--point-in-time",
+            ts,
+        ])
+    };
+
+    put(&src, b"a", b"1");
+    let first = log_backup("2");
+    put(&src, b"b", b"2");
+    let second = log_backup("1");
+    put(&src, b"c", b"3");
+
+    let dst = path("dst_first");
+    let out = restore(&dst, &first).unwrap();
+    assert!(
+        out.starts_with(&format!("restored to {} ", first)),
+        "{}",
+        out
+    );
+    assert_eq!(get(&dst, b"a"), Some(b"1".to_vec()));
+    assert_eq!(get(&dst, b"b"), None);
+
+    let dst = path("dst_second");
+    restore(&dst, &second).unwrap();
+    assert_eq!(get(&dst, b"a"), Some(b"1".to_vec()));
+    assert_eq!(get(&dst, b"b"), Some(b"2".to_vec()));
+    assert_eq!(get(&dst, b"c"), None);
+
+    // Past the checkpoint the log got to.
+    let later = (second.parse::<u64>().unwrap() + 1).to_string();
+    assert!(restore(&path("dst_later"), &later).is_err());
+    let err = ctl(&[
+        "log-backup",
+        "--data-dir",
+        &src,
+        "--storage",
+        &storage,
+        "--rounds",
+        "x",
+    ])
+    .unwrap_err();
+    assert!(err.contains("--rounds takes a number"), "{}", err);
+}
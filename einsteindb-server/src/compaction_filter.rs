@@ -0,0 +1,219 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Built-in compaction filters.
+//!
+//! `TtlCompactionFilterFactory` drops primitive causet_locales whose TTL has run out,
+//! `GcCompactionFilterFactory` drops MVCC versions of the write causet_merge family
+//! that no read at or above the GC safe point can see.
+//!
+//! A MVCC soliton_id is the user soliton_id followed by the big-endian `!commit_ts`,
+//! so that the versions of a soliton_id are sorted newest first. The first byte of a
+//! write record is its type: `P`ut, `D`elete, `L`ock or `R`ollback.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use fdb_traits::{
+    CompactionFilter, CompactionFilterContext, CompactionFilterDecision, CompactionFilterFactory,
+};
+
+use crate::primitive_ttl::{split_expire_ts, ttl_expired};
+
+const TS_LEN: usize = 8;
+
+pub const WRITE_TYPE_PUT: u8 = b'P';
+pub const WRITE_TYPE_DELETE: u8 = b'D';
+pub const WRITE_TYPE_LOCK: u8 = b'L';
+pub const WRITE_TYPE_ROLLBACK: u8 = b'R';
+
+/// Appends the encoded `ts` to a user soliton_id.
+pub fn append_ts(user_key: &[u8], ts: u64) -> Vec<u8> {
+    let mut soliton_id = Vec::with_capacity(user_key.len() + TS_LEN);
+    soliton_id.extend_from_slice(user_key);
+    soliton_id.extend_from_slice(&(!ts).to_be_bytes());
+    soliton_id
+}
+
+/// Splits a MVCC soliton_id into its user soliton_id and timestamp.
+pub fn split_ts(soliton_id: &[u8]) -> Option<(&[u8], u64)> {
+    if soliton_id.len() < TS_LEN {
+        return None;
+    }
+    let (user_key, ts) = soliton_id.split_at(soliton_id.len() - TS_LEN);
+    Some((user_key, !u64::from_be_bytes(ts.try_into().unwrap())))
+}
+
+#[derive(Default)]
+pub struct TtlCompactionFilterFactory;
+
+struct TtlCompactionFilter;
+
+impl CompactionFilter for TtlCompactionFilter {
+    fn filter(&mut self, _: usize, _: &[u8], causet_locale: &[u8]) -> CompactionFilterDecision {
+        match split_expire_ts(causet_locale) {
+            Some((_, expire_ts)) if ttl_expired(expire_ts) => CompactionFilterDecision::Remove,
+            _ => CompactionFilterDecision::Keep,
+        }
+    }
+}
+
+impl CompactionFilterFactory for TtlCompactionFilterFactory {
+    fn name(&self) -> &str {
+        "ttl"
+    }
+
+    fn create_compaction_filter(
+        &self,
+        _: &CompactionFilterContext<'_>,
+    ) -> Option<Box<dyn CompactionFilter>> {
+        Some(Box::new(TtlCompactionFilter))
+    }
+}
+
+/// Collects the garbage of the write causet_merge family below a safe point.
+///
+/// For each user soliton_id, every version newer than the safe point is kept, and so
+/// is the newest put at or below it, which is what reads at the safe point see.
+/// Older versions, and locks and rollbacks at or below the safe point, are removed.
+/// A delete at or below the safe point is removed too, but only by compactions of the
+/// bottommost level: elsewhere it still hides older versions in deeper levels.
+#[derive(Clone, Default)]
+pub struct GcCompactionFilterFactory {
+    safe_point: Arc<AtomicU64>,
+}
+
+impl GcCompactionFilterFactory {
+    pub fn safe_point(&self) -> u64 {
+        self.safe_point.load(Ordering::Acquire)
+    }
+
+    /// Advances the safe point; it never moves backwards.
+    pub fn set_safe_point(&self, safe_point: u64) {
+        self.safe_point.fetch_max(safe_point, Ordering::AcqRel);
+    }
+}
+
+impl CompactionFilterFactory for GcCompactionFilterFactory {
+    fn name(&self) -> &str {
+        "mvcc_gc"
+    }
+
+    fn create_compaction_filter(
+        &self,
+        context: &CompactionFilterContext<'_>,
+    ) -> Option<Box<dyn CompactionFilter>> {
+        let safe_point = self.safe_point();
+        if safe_point == 0 {
+            return None;
+        }
+        Some(Box::new(GcCompactionFilter {
+            safe_point,
+            is_bottommost_level: context.is_bottommost_level,
+            user_key: Vec::new(),
+            remove_older: false,
+        }))
+    }
+}
+
+struct GcCompactionFilter {
+    safe_point: u64,
+    is_bottommost_level: bool,
+    /// The user soliton_id of the last version seen.
+    user_key: Vec<u8>,
+    /// A put or delete at or below the safe point has been seen for `user_key`.
+    remove_older: bool,
+}
+
+impl CompactionFilter for GcCompactionFilter {
+    fn filter(
+        &mut self,
+        _: usize,
+        soliton_id: &[u8],
+        causet_locale: &[u8],
+    ) -> CompactionFilterDecision {
+        let (user_key, commit_ts) = match split_ts(soliton_id) {
+            Some(split) => split,
+            None => return CompactionFilterDecision::Keep,
+        };
+        if user_key != self.user_key.as_slice() {
+            self.user_key = user_key.to_vec();
+            self.remove_older = false;
+        }
+        if commit_ts > self.safe_point {
+            return CompactionFilterDecision::Keep;
+        }
+        if self.remove_older {
+            return CompactionFilterDecision::Remove;
+        }
+        match causet_locale.first() {
+            Some(&WRITE_TYPE_PUT) => {
+                self.remove_older = true;
+                CompactionFilterDecision::Keep
+            }
+            Some(&WRITE_TYPE_DELETE) => {
+                self.remove_older = true;
+                if self.is_bottommost_level {
+                    CompactionFilterDecision::Remove
+                } else {
+                    CompactionFilterDecision::Keep
+                }
+            }
+            Some(&WRITE_TYPE_LOCK) | Some(&WRITE_TYPE_ROLLBACK) => CompactionFilterDecision::Remove,
+            _ => CompactionFilterDecision::Keep,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_gc(
+        safe_point: u64,
+        is_bottommost_level: bool,
+        versions: &[(&[u8], u64, u8)],
+    ) -> Vec<bool> {
+        let factory = GcCompactionFilterFactory::default();
+        let context = CompactionFilterContext {
+            namespaced: "write",
+            output_level: 6,
+            is_bottommost_level,
+            is_manual_compaction: false,
+        };
+        assert!(factory.create_compaction_filter(&context).is_none());
+        factory.set_safe_point(safe_point);
+        factory.set_safe_point(safe_point - 1);
+        assert_eq!(factory.safe_point(), safe_point);
+        let mut filter = factory.create_compaction_filter(&context).unwrap();
+        versions
+            .iter()
+            .map(|&(user_key, ts, write_type)| {
+                let soliton_id = append_ts(user_key, ts);
+                assert_eq!(split_ts(&soliton_id), Some((user_key, ts)));
+                filter.filter(6, &soliton_id, &[write_type]) == CompactionFilterDecision::Keep
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_gc_compaction_filter() {
+        let versions: &[(&[u8], u64, u8)] = &[
+            (b"a", 30, WRITE_TYPE_PUT),
+            (b"a", 20, WRITE_TYPE_ROLLBACK),
+            (b"a", 15, WRITE_TYPE_PUT),
+            (b"a", 10, WRITE_TYPE_PUT),
+            (b"b", 12, WRITE_TYPE_LOCK),
+            (b"b", 11, WRITE_TYPE_DELETE),
+            (b"b", 5, WRITE_TYPE_PUT),
+            (b"c", 25, WRITE_TYPE_DELETE),
+        ];
+        assert_eq!(
+            run_gc(20, false, versions),
+            vec![true, false, true, false, false, true, false, true]
+        );
+        assert_eq!(
+            run_gc(20, true, versions),
+            vec![true, false, true, false, false, false, false, true]
+        );
+    }
+}
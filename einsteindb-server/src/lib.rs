@@ -0,0 +1,10 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The server of a store: the primitive key-causet_locale API with per-soliton_id
+//! TTL, and the routing of requests to the store leading their brane.
+
+pub mod compaction_filter;
+pub mod primitive_kv;
+pub mod primitive_ttl;
+pub mod routing;
+pub mod ttl_properties;
@@ -0,0 +1,247 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The primitive (raw) key-causet_locale API.
+//!
+//! In the causet_merge families listed in `LsmOptions::ttl_namespaceds` every
+//! causet_locale carries its expiry time, see `fdb_traits::append_expire_ts`. Expired
+//! soliton_ids are hidden from reads as soon as they expire, and reclaimed by the
+//! compactions `check_ttl_and_compact` triggers.
+
+use std::sync::Arc;
+
+use fdb_traits::{
+    split_expire_ts, CompactionFilterExt, Error, IterOptions, Iterable, Iterator, Peekable, Result,
+    SeekKey, TtlGreedoidsExt,
+};
+use soliton_lsm::LsmEngine;
+
+use crate::compaction_filter::TtlCompactionFilterFactory;
+use crate::primitive_ttl::{ttl_current_ts, ttl_expired, PrimitiveTtl};
+
+#[derive(Clone)]
+pub struct PrimitiveKv {
+    einstein_merkle_tree: LsmEngine,
+}
+
+impl PrimitiveKv {
+    /// Wraps `einstein_merkle_tree`, installing the TTL compaction filter on its TTL
+    /// causet_merge families.
+    pub fn new(einstein_merkle_tree: LsmEngine) -> Result<PrimitiveKv> {
+        for namespaced in &einstein_merkle_tree.options().ttl_namespaceds {
+            einstein_merkle_tree.set_compaction_filter_factory(
+                namespaced,
+                Some(Arc::new(TtlCompactionFilterFactory)),
+            )?;
+        }
+        Ok(PrimitiveKv {
+            einstein_merkle_tree,
+        })
+    }
+
+    pub fn einstein_merkle_tree(&self) -> &LsmEngine {
+        &self.einstein_merkle_tree
+    }
+
+    fn is_ttl_namespaced(&self, namespaced: &str) -> bool {
+        self.einstein_merkle_tree
+            .options()
+            .is_ttl_namespaced(namespaced)
+    }
+
+    pub fn put(&self, namespaced: &str, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()> {
+        self.put_with_ttl(namespaced, soliton_id, causet_locale, 0)
+    }
+
+    /// Writes `causet_locale` to expire in `ttl` seconds; 0 never expires.
+    pub fn put_with_ttl(
+        &self,
+        namespaced: &str,
+        soliton_id: &[u8],
+        causet_locale: &[u8],
+        ttl: u64,
+    ) -> Result<()> {
+        if !self.is_ttl_namespaced(namespaced) {
+            if ttl != 0 {
+                return Err(Error::Engine(format!(
+                    "causet_merge family {} does not support TTL",
+                    namespaced
+                )));
+            }
+            return self
+                .einstein_merkle_tree
+                .put_namespaced(namespaced, soliton_id, causet_locale);
+        }
+        let entry = PrimitiveTtl::new(
+            String::from_utf8_lossy(soliton_id).into_owned(),
+            causet_locale.to_vec(),
+            ttl,
+        );
+        self.einstein_merkle_tree
+            .put_namespaced(namespaced, soliton_id, &entry.encode())
+    }
+
+    pub fn delete(&self, namespaced: &str, soliton_id: &[u8]) -> Result<()> {
+        self.einstein_merkle_tree
+            .delete_namespaced(namespaced, soliton_id)
+    }
+
+    /// Strips the expiry time of a stored causet_locale, or returns `None` if it expired.
+    fn decode(&self, namespaced: &str, causet_locale: Vec<u8>) -> Result<Option<Vec<u8>>> {
+        if !self.is_ttl_namespaced(namespaced) {
+            return Ok(Some(causet_locale));
+        }
+        let (user_value, expire_ts) = split_expire_ts(&causet_locale)
+            .ok_or_else(|| Error::Corruption("causet_locale without expiry time".to_owned()))?;
+        if ttl_expired(expire_ts) {
+            return Ok(None);
+        }
+        Ok(Some(user_value.to_vec()))
+    }
+
+    pub fn get(&self, namespaced: &str, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        match self
+            .einstein_merkle_tree
+            .get_value_namespaced(namespaced, soliton_id)?
+        {
+            Some(causet_locale) => self.decode(namespaced, causet_locale),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the seconds `soliton_id` has left to live, 0 if it never expires, or
+    /// `None` if it does not exist.
+    pub fn get_ttl(&self, namespaced: &str, soliton_id: &[u8]) -> Result<Option<u64>> {
+        if !self.is_ttl_namespaced(namespaced) {
+            return Ok(self.get(namespaced, soliton_id)?.map(|_| 0));
+        }
+        let causet_locale = match self
+            .einstein_merkle_tree
+            .get_value_namespaced(namespaced, soliton_id)?
+        {
+            Some(causet_locale) => causet_locale,
+            None => return Ok(None),
+        };
+        match split_expire_ts(&causet_locale) {
+            Some((_, 0)) => Ok(Some(0)),
+            Some((_, expire_ts)) if ttl_expired(expire_ts) => Ok(None),
+            Some((_, expire_ts)) => Ok(Some(expire_ts - ttl_current_ts())),
+            None => Err(Error::Corruption(
+                "causet_locale without expiry time".to_owned(),
+            )),
+        }
+    }
+
+    /// Returns up to `limit` live soliton_ids in `[start_soliton_id, end_soliton_id)`,
+    /// `end_soliton_id` being unbounded if empty.
+    pub fn scan(
+        &self,
+        namespaced: &str,
+        start_soliton_id: &[u8],
+        end_soliton_id: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let upper_bound = Some(end_soliton_id.to_vec()).filter(|e| !e.is_empty());
+        let opts = IterOptions::new(None, upper_bound);
+        let mut iter = self.einstein_merkle_tree.iterator_opt(namespaced, opts)?;
+        let mut res = Vec::new();
+        let mut valid = iter.seek(SeekKey::Key(start_soliton_id))?;
+        while valid && res.len() < limit {
+            if let Some(causet_locale) = self.decode(namespaced, iter.causet_locale().to_vec())? {
+                res.push((iter.soliton_id().to_vec(), causet_locale));
+            }
+            valid = iter.next()?;
+        }
+        Ok(res)
+    }
+
+    /// Compacts `[start_soliton_id, end_soliton_id]` of `namespaced` if one of its files
+    /// holds an expired causet_locale, so that the TTL compaction filter reclaims them.
+    /// Returns whether a compaction ran.
+    pub fn check_ttl_and_compact(
+        &self,
+        namespaced: &str,
+        start_soliton_id: &[u8],
+        end_soliton_id: &[u8],
+    ) -> Result<bool> {
+        let greedoids = self
+            .einstein_merkle_tree
+            .get_range_ttl_greedoids_namespaced(namespaced, start_soliton_id, end_soliton_id)?;
+        if !greedoids
+            .iter()
+            .any(|(_, greedoids)| ttl_expired(greedoids.min_expire_ts))
+        {
+            return Ok(false);
+        }
+        self.einstein_merkle_tree.compact_range_namespaced(
+            namespaced,
+            Some(start_soliton_id),
+            Some(end_soliton_id),
+        )?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdb_traits::{append_expire_ts, MiscExt, NAMESPACED_DEFAULT};
+    use soliton_lsm::LsmOptions;
+
+    #[test]
+    fn test_primitive_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = LsmOptions {
+            namespaceds: vec!["write".to_owned()],
+            ttl_namespaceds: vec![NAMESPACED_DEFAULT.to_owned()],
+            ..Default::default()
+        };
+        let kv = PrimitiveKv::new(LsmEngine::open(dir.path(), opts).unwrap()).unwrap();
+        kv.put(NAMESPACED_DEFAULT, b"a", b"1").unwrap();
+        kv.put_with_ttl(NAMESPACED_DEFAULT, b"b", b"2", 1000)
+            .unwrap();
+        assert!(kv.put_with_ttl("write", b"b", b"2", 1000).is_err());
+        kv.put("write", b"b", b"2").unwrap();
+        // An already expired causet_locale, as if written long ago.
+        let mut expired = b"3".to_vec();
+        append_expire_ts(&mut expired, 1);
+        kv.einstein_merkle_tree().put(b"c", &expired).unwrap();
+
+        assert_eq!(
+            kv.get(NAMESPACED_DEFAULT, b"a").unwrap(),
+            Some(b"1".to_vec())
+        );
+        assert_eq!(kv.get(NAMESPACED_DEFAULT, b"c").unwrap(), None);
+        assert_eq!(kv.get("write", b"b").unwrap(), Some(b"2".to_vec()));
+        assert_eq!(kv.get_ttl(NAMESPACED_DEFAULT, b"a").unwrap(), Some(0));
+        assert!(kv.get_ttl(NAMESPACED_DEFAULT, b"b").unwrap().unwrap() <= 1000);
+        assert_eq!(kv.get_ttl(NAMESPACED_DEFAULT, b"c").unwrap(), None);
+        assert_eq!(
+            kv.scan(NAMESPACED_DEFAULT, b"", b"", 10).unwrap(),
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"2".to_vec())
+            ]
+        );
+        assert_eq!(
+            kv.scan(NAMESPACED_DEFAULT, b"b", b"c", 10).unwrap(),
+            vec![(b"b".to_vec(), b"2".to_vec())]
+        );
+
+        kv.einstein_merkle_tree().flush(true).unwrap();
+        assert!(kv
+            .check_ttl_and_compact(NAMESPACED_DEFAULT, b"", b"z")
+            .unwrap());
+        let stats = kv
+            .einstein_merkle_tree()
+            .compaction_filter_stats(NAMESPACED_DEFAULT)
+            .unwrap();
+        assert_eq!(stats.keys_removed, 1);
+        assert!(!kv
+            .check_ttl_and_compact(NAMESPACED_DEFAULT, b"", b"z")
+            .unwrap());
+        assert_eq!(
+            kv.get(NAMESPACED_DEFAULT, b"b").unwrap(),
+            Some(b"2".to_vec())
+        );
+    }
+}
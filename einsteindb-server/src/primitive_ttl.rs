@@ -1,66 +1,39 @@
 // Copyright 2021 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
-use super::*;
-use crate::error::{Error, Result};
-use crate::parser::{Parser, ParserError};
-use crate::value::{Value, ValueType};
-use crate::{ValueRef, ValueRefMut};
-use itertools::Itertools;
-use crate::fdb_traits::FdbTrait;
-use crate::fdb_traits::FdbTraitImpl;
-use pretty;
-use std::{
-    collections::HashMap,
-    fmt::{self, Display},
-    io,
-    convert::{TryFrom, TryInto},
-    ops::{Deref, DerefMut},
-    sync::{Arc, Mutex},
-};
-use crate::fdb_traits::FdbTrait;
-use crate::fdb_traits::FdbTraitImpl;
-
-
-#[derive(Debug, Default)]
-pub struct TtlGreedoids {
-    pub max_expire_ts: u64,
-    pub min_expire_ts: u64,
-}
-
-
-
-
-pub trait TtlGreedoidsExt {
-    fn get_range_ttl_greedoids_namespaced(
-        &self,
-        namespaced: &str,
-        start_soliton_id: &[u8],
-        end_soliton_id: &[u8],
-    ) -> Result<Vec<(String, TtlGreedoids)>>;
-}
-
-
+//! Time to live of primitive causet_locales, kept as the second since the Unix
+//! epoch they expire at.
 
+use std::fmt::{self, Display};
+use std::ops::{Deref, DerefMut};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+pub use fdb_traits::{
+    append_expire_ts, split_expire_ts, TtlGreedoids, TtlGreedoidsExt, TTL_SUFFIX_LEN,
+};
 
+/// A primitive causet_locale with the seconds it has to live; a `ttl` of 0 never
+/// expires. Derefs to the causet_locale.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PrimitiveTtl {
     pub name: String,
-    pub value: Value,
-    pub ttl: i64,
+    pub value: Vec<u8>,
+    pub ttl: u64,
 }
 
-
 impl PrimitiveTtl {
-    pub fn new(name: String, value: Value, ttl: i64) -> Self {
-        PrimitiveTtl {
-            name,
-            value,
-            ttl,
-        }
+    pub fn new(name: String, value: Vec<u8>, ttl: u64) -> Self {
+        PrimitiveTtl { name, value, ttl }
     }
-}
 
+    /// The causet_locale as stored in a TTL causet_merge family if written now:
+    /// followed by its expiry time.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.value.len() + TTL_SUFFIX_LEN);
+        encoded.extend_from_slice(&self.value);
+        append_expire_ts(&mut encoded, ttl_expire_ts(self.ttl));
+        encoded
+    }
+}
 
 impl Display for PrimitiveTtl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -68,58 +41,28 @@ impl Display for PrimitiveTtl {
     }
 }
 
-
 impl Deref for PrimitiveTtl {
-    type Target = Value;
+    type Target = Vec<u8>;
 
     fn deref(&self) -> &Self::Target {
         &self.value
     }
 }
 
-
-
-
-impl Display for PrimitiveTtl {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", pretty::render(&self))
-    }
-}
-
-
 impl DerefMut for PrimitiveTtl {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.value
     }
 }
 
-
-
-
-
-
-/// # PrimitiveTtl
-///  - name: String
-/// - value: Value
-/// - ttl: i64
-/// - ttl_type: String
-/// - ttl_unit: String
-/// - ttl_value: i64
-/// - ttl_unit_value: i64
-///
-///
-/// ## Examples
-/// ```
-///
-
-///ttl for primitive type in EinsteinDB
-/// time to live for primitive type in EinsteinDB
-
 pub fn ttl_current_ts() -> u64 {
-    fail_point!("ttl_current_ts", |r| r.map_or(2, |e| e.parse().unwrap()));
-    einsteindb_util::time::UnixSecs::now().into_inner()
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
 }
 
+/// The second a causet_locale written now with `ttl` expires at, `None` for a `ttl`
+/// of 0, which never expires.
 pub fn ttl_to_expire_ts(ttl: u64) -> Option<u64> {
     if ttl == 0 {
         None
@@ -128,53 +71,32 @@ pub fn ttl_to_expire_ts(ttl: u64) -> Option<u64> {
     }
 }
 
-
 pub fn ttl_expire_ts(ttl: u64) -> u64 {
     ttl_to_expire_ts(ttl).unwrap_or(0)
 }
 
-
-pub fn ttl_expired(ttl: u64) -> bool {
-    ttl_to_expire_ts(ttl).map_or(false, |expire_ts| expire_ts <= ttl_current_ts())
-}
-
-
-pub fn ttl_expire_time(ttl: u64) -> Option<u64> {
-    ttl_to_expire_ts(ttl).map(|expire_ts| expire_ts - ttl_current_ts())
+/// Whether a causet_locale with the given `expire_ts` has expired; 0 never expires.
+pub fn ttl_expired(expire_ts: u64) -> bool {
+    expire_ts != 0 && expire_ts <= ttl_current_ts()
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub fn ttl_expire_time_str(ttl: u64) -> String {
-    ttl_expire_time(ttl).map_or("".to_owned(), |expire_time| {
-        let expire_time = expire_time as i64;
-        if expire_time < 0 {
-            "expired".to_owned()
-        } else {
-            format!("{}s", expire_time)
-        }
-    })
-}
+    #[test]
+    fn test_primitive_ttl() {
+        let mut entry = PrimitiveTtl::new("k".to_owned(), b"v".to_vec(), 0);
+        entry.push(b'1');
+        assert_eq!(entry.to_string(), "k");
+        assert_eq!(split_expire_ts(&entry.encode()), Some((&b"v1"[..], 0)));
 
-//add relativistic time
-pub fn ttl_expire_time_str_relativistic(ttl: u64) -> String {
-    ttl_expire_time(ttl).map_or("".to_owned(), |expire_time| {
-        let expire_time = expire_time as i64;
-        if expire_time < 0 {
-            "expired".to_owned()
-        } else {
-            format!("{}s", expire_time)
-        }
-    })
-}
-
-//add certificate expire time
-pub fn ttl_expire_time_str_cert(ttl: u64) -> String {
-    ttl_expire_time(ttl).map_or("".to_owned(), |expire_time| {
-        let expire_time = expire_time as i64;
-        if expire_time < 0 {
-            "expired".to_owned()
-        } else {
-            format!("{}s", expire_time)
-        }
-    })
+        entry.ttl = 10;
+        let encoded = entry.encode();
+        let (value, expire_ts) = split_expire_ts(&encoded).unwrap();
+        assert_eq!(value, &entry[..]);
+        assert!(expire_ts >= ttl_current_ts() + 10);
+        assert!(!ttl_expired(expire_ts));
+        assert!(ttl_expired(1));
+    }
 }
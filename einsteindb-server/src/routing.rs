@@ -0,0 +1,152 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Routing of requests to the store leading the brane of their soliton_id.
+//!
+//! Routes come from the placement driver and are cached; a store rejecting a
+//! request with a newer view of the brane corrects the cache through
+//! `on_store_error`, and a route whose brane is gone is looked up again.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use pd::{Error, PdClient, Result, StoreMeta};
+use violetabftstore::{Brane, BraneCache, Peer};
+
+/// Where to send a request for a soliton_id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BraneRoute {
+    pub brane: Brane,
+    pub leader: Peer,
+    /// The address of the leader's store.
+    pub address: String,
+}
+
+pub struct BraneRouter {
+    pd: Arc<dyn PdClient>,
+    cache: Mutex<BraneCache>,
+    stores: Mutex<HashMap<u64, StoreMeta>>,
+}
+
+impl BraneRouter {
+    pub fn new(pd: Arc<dyn PdClient>) -> BraneRouter {
+        BraneRouter {
+            pd,
+            cache: Mutex::new(BraneCache::new()),
+            stores: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The brane of `soliton_id` and its leader, as cached, or else as the
+    /// placement driver knows them.
+    pub fn route(&self, soliton_id: &[u8]) -> Result<BraneRoute> {
+        let cached = self
+            .cache
+            .lock()
+            .unwrap()
+            .locate(soliton_id)
+            .and_then(|(brane, leader)| Some((brane.clone(), leader?)));
+        let (brane, leader) = match cached {
+            Some(route) => route,
+            None => {
+                let info = self.pd.get_brane(soliton_id)?;
+                let mut cache = self.cache.lock().unwrap();
+                cache.update(info.brane.clone());
+                cache.update_leader(info.brane.id, info.leader);
+                let leader = info.leader.ok_or_else(|| {
+                    Error::Store(violetabftstore::Error::NotLeader(info.brane.id, None))
+                })?;
+                (info.brane, leader)
+            }
+        };
+        let address = self.store_address(leader.store_id)?;
+        Ok(BraneRoute {
+            brane,
+            leader,
+            address,
+        })
+    }
+
+    fn store_address(&self, store_id: u64) -> Result<String> {
+        if let Some(store) = self.stores.lock().unwrap().get(&store_id) {
+            return Ok(store.address.clone());
+        }
+        let store = self.pd.get_store(store_id)?;
+        let address = store.address.clone();
+        self.stores.lock().unwrap().insert(store_id, store);
+        Ok(address)
+    }
+
+    /// Learns from a store rejecting a request routed to `brane_id`.
+    pub fn on_store_error(&self, brane_id: u64, err: &violetabftstore::Error) {
+        let mut cache = self.cache.lock().unwrap();
+        match err {
+            violetabftstore::Error::NotLeader(_, Some(leader)) => {
+                cache.update_leader(brane_id, Some(*leader));
+            }
+            violetabftstore::Error::EpochNotMatch(_, branes) => {
+                cache.invalidate(brane_id);
+                for brane in branes {
+                    cache.update(brane.clone());
+                }
+            }
+            violetabftstore::Error::NotLeader(_, None)
+            | violetabftstore::Error::BraneNotFound(_)
+            | violetabftstore::Error::KeyNotInBrane { .. } => cache.invalidate(brane_id),
+            _ => {}
+        }
+    }
+
+    /// Forgets the cached address of a store that could not be reached.
+    pub fn on_store_unreachable(&self, store_id: u64) {
+        self.stores.lock().unwrap().remove(&store_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pd::cluster::PdCluster;
+    use pd::PdConfig;
+    use violetabftstore::StoreConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_route_and_correct() {
+        let dir = tempfile::tempdir().unwrap();
+        let store_cfg = StoreConfig {
+            violetabft_election_ticks: 5,
+            violetabft_heartbeat_ticks: 1,
+            sync_log: false,
+            ..Default::default()
+        };
+        let mut c = PdCluster::new(dir.path(), 3, store_cfg, PdConfig::default()).unwrap();
+        let router = BraneRouter::new(Arc::new(c.client().clone()));
+        let route = router.route(b"x").unwrap();
+        assert_eq!(Some(route.leader), c.cluster.leader(route.brane.id));
+        assert_eq!(route.address, format!("store-{}", route.leader.store_id));
+
+        // After a split the cached route is outdated until a store says so.
+        let (left, right) = c.cluster.split(b"m").unwrap();
+        c.tick().unwrap();
+        assert_eq!(router.route(b"x").unwrap().brane, route.brane);
+        let err = violetabftstore::Error::EpochNotMatch(
+            "stale".to_owned(),
+            vec![left.clone(), right.clone()],
+        );
+        router.on_store_error(route.brane.id, &err);
+        assert_eq!(router.route(b"a").unwrap().brane, left);
+        assert_eq!(router.route(b"x").unwrap().brane, right);
+
+        // A store that does not lead the brane any more points to the one
+        // that does.
+        let leader = c.cluster.leader(right.id).unwrap();
+        let other = *right.peers.iter().find(|p| p.id != leader.id).unwrap();
+        router.on_store_error(
+            right.id,
+            &violetabftstore::Error::NotLeader(right.id, Some(other)),
+        );
+        assert_eq!(router.route(b"x").unwrap().leader, other);
+        router.on_store_error(right.id, &violetabftstore::Error::NotLeader(right.id, None));
+        assert_eq!(router.route(b"x").unwrap().leader, leader);
+    }
+}
@@ -1,259 +1,144 @@
 // Copyright 2020 EinsteinDB Project Authors. Licensed under Apache-2.0.
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at http://www.apache.org/licenses/LICENSE-2.0.
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and limitations under the License.
-//
 
 //! # Compact
-//! 
-//! ## Overview
-//! 
-//! This module provides the `Compact` trait and its implementations.
-//! compaction-related with bloom filter
-//! and indexing.
+//!
+//! The compaction-related options of the test einstein_merkle_trees, with bloom
+//! filters and block compression, and the manual compactions the tests run.
 
+use fdb_traits::{Compression, Result};
 
-use einstein_db_alexandrov_processing::{
-    index::{
-        Index,
-        IndexIterator,
-        IndexIteratorOptions,
-        IndexIteratorOptionsBuilder,
-    }
-};
-
-
-/// `Compact` is a trait that provides compacting operations.
-/// It is implemented by `Engine` and `Directory`.
-/// `Compact` is used by `Engine` to compacting operations.
-/// `Compact` is used by `Directory` to compacting operations.
-/// `Compact` is used by `Snapshot` to compacting operations.
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::{Duration, Instant};
-use std::{cmp, u64};
-
-
-
-
-use fdb_traits::Result;
+use crate::kv::{KvTestEngine, LsmOptions};
 
-#[derive(Debug, Clone)]
+/// The table and compaction options a test einstein_merkle_tree is built with.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompactOptions {
-    pub causetq_upstream_interlock_threshold: u64,
-    pub causetq_upstream_interlock_compaction_interval: u64,
-    pub causetq_upstream_interlock_compaction_threshold: u64,
-    pub block_size: u64,
-    pub block_cache_size: u64,
-    pub block_cache_shard_bits: u8,
+    /// Target uncompressed size of an SST data block.
+    pub block_size: usize,
     pub enable_bloom_filter: bool,
-    pub enable_indexing: bool,
-    pub index_block_size: u64,
-    pub index_block_cache_size: u64,
-    pub index_block_cache_shard_bits: u8,
-    pub index_block_restart_interval: u64,
-    pub compression_type: String,
-    pub compression_level: i32,
-    pub compression_block_size: u64,
-    pub compression_strategy: String,
-    pub compression_dict: Vec<u8>,
-    pub enable_statistics: bool,
-    pub statistics_interval: u64,
-    pub statistics_block_size: u64,
-    pub statistics_block_cache_size: u64,
-    pub statistics_block_cache_shard_bits: u8,
-    pub statistics_block_restart_interval: u64,
-    pub statistics_index_block_size: u64,
-    pub statistics_index_block_cache_size: u64,
-    pub statistics_index_block_cache_shard_bits: u8,
-    pub statistics_index_block_restart_interval: u64,
-    pub statistics_index_partitions: u64,
-    pub statistics_index_index_block_restart_interval: u64,
-    pub statistics_index_index_partitions: u64,
-    pub statistics_index_index_index_block_restart_interval: u64,
-    pub statistics_index_index_index_partitions: u64,
-    pub statistics_index_index_index_index_block_restart_interval: u64,
-    pub statistics_index_index_index_index_partitions: u64,
+    pub bloom_bits_per_key: usize,
+    pub compression: Compression,
+    /// Number of level 0 files that triggers a level 0 compaction.
+    pub level0_file_num_compaction_trigger: usize,
+    /// Compaction outputs are split into files of about this size.
+    pub target_file_size_base: u64,
+    pub disable_auto_compactions: bool,
 }
 
 impl Default for CompactOptions {
     fn default() -> Self {
+        let opts = LsmOptions::default();
         CompactOptions {
-            causetq_upstream_interlock_threshold: 1,
-            causetq_upstream_interlock_compaction_interval: 1,
-            causetq_upstream_interlock_compaction_threshold: 1,
-            block_size: 1 << 20,
-            block_cache_size: 1 << 20,
-            block_cache_shard_bits: 0,
-            enable_bloom_filter: true,
-            enable_indexing: true,
-            index_block_size: 1 << 20,
-            index_block_cache_size: 1 << 20,
-            index_block_cache_shard_bits: 0,
-            index_block_restart_interval: 16,
-            compression_type: String::from("snappy"),
-            compression_level: -1,
-            compression_block_size: 0,
-            compression_strategy: String::from("default"),
-            compression_dict: vec![],
-            enable_statistics: true,
-            statistics_interval: 1,
-            statistics_block_size: 1 << 20,
-            statistics_block_cache_size: 1 << 20,
-            statistics_block_cache_shard_bits: 0,
-            statistics_block_restart_interval: 16,
-            statistics_index_block_size: 1 << 20,
-            statistics_index_block_cache_size: 1 << 20,
-            statistics_index_block_cache_shard_bits: 0,
-            statistics_index_block_restart_interval: 16,
-            statistics_index_partitions: 1,
-            statistics_index_index_block_restart_interval: 16,
-            statistics_index_index_partitions: 1,
-            statistics_index_index_index_block_restart_interval: 16,
-            statistics_index_index_index_partitions: 1,
-            statistics_index_index_index_index_block_restart_interval: 16,
-            statistics_index_index_index_index_partitions: 1,
+            block_size: opts.block_size,
+            enable_bloom_filter: opts.bloom_bits_per_key > 0,
+            bloom_bits_per_key: opts.bloom_bits_per_key,
+            compression: opts.compression,
+            level0_file_num_compaction_trigger: opts.level0_file_num_compaction_trigger,
+            target_file_size_base: opts.target_file_size_base,
+            disable_auto_compactions: opts.disable_auto_compactions,
         }
     }
 }
 
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CompactionOptions {
-    pub level: i32,
-    pub output_file_size_limit: u64,
-    pub output_file_size_base: u64,
-    pub output_file_size_multiplier: u64,
-    pub output_file_size_max: u64,
-    pub output_file_size_min: u64,
-
-    pub max_output_file_size: u64,
-    pub min_output_file_size: u64,
-    pub max_output_file_size_base: u64,
-    pub max_output_file_size_multiplier: u64,
-    pub max_output_file_size_max: u64,
-
-    pub max_output_file_size_min: u64,
-
-    pub max_output_file_size_base_min: u64,
-    pub max_output_file_size_base_max: u64,
-}
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CompactionOptionsBuilder {
-    pub level: i32,
-    pub output_file_size_limit: u64,
-}
-
-impl Default for CompactionOptionsBuilder {
-    /// Checks whether any causet_merge family sets `disable_auto_jet_bundles` to `True` or not.
-    fn auto_jet_bundles_is_disabled(&self) -> Result<bool, Error> {
-        let mut disabled = false;
-        for cf_opts in self.cf_opts.iter() {
-            if cf_opts.disable_auto_jet_bundles {
-                disabled = true;
-                break;
-            }
-        }
-        Ok(disabled)
-    }
-
-    fn default() -> Self {
-        CompactionOptionsBuilder {
-            level: 0,
-            output_file_size_limit: 0,
-        }
+impl CompactOptions {
+    /// Sets these options on `opts`.
+    pub fn apply_to(&self, opts: &mut LsmOptions) {
+        opts.block_size = self.block_size;
+        opts.bloom_bits_per_key = if self.enable_bloom_filter {
+            self.bloom_bits_per_key
+        } else {
+            0
+        };
+        opts.compression = self.compression;
+        opts.level0_file_num_compaction_trigger = self.level0_file_num_compaction_trigger;
+        opts.target_file_size_base = self.target_file_size_base;
+        opts.disable_auto_compactions = self.disable_auto_compactions;
     }
-
 }
 
-
-
-
-
-
-    /// Compacts the causet_merge families in the specified range by manual or not.
-    /// If `exclusive_manual` is `true`, the compaction will be exclusive.
-    /// If `exclusive_manual` is `false`, the compaction will be inclusive.
-    /// If `manual_compaction_type` is `ManualCompactionType::Manual`, the compaction will be manual.
-    /// If `manual_compaction_type` is `ManualCompactionType::Auto`, the compaction will be automatic.
-
-
-
-    /// Compacts the causet_merge families in the specified range by manual or not.
-    ///
-    /// If `exclusive_manual` is `true`, the compaction will be exclusive.
-    /// If `exclusive_manual` is `false`, the compaction will be inclusive.
-    /// If `manual_compaction_type` is `ManualCompactionType::Manual`, the compaction will be manual.
-    /// If `manual_compaction_type` is `ManualCompactionType::Auto`, the compaction will be automatic.
-    ///
-
-
-    /// Compacts the causet_merge families in the specified range by manual or not.
-
-
-
-    /// Compacts the causet_merge families in the specified range by manual or not.
-    /// If `exclusive_manual` is `true`, the compaction will be exclusive.
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Which part of a causet_merge family a manual compaction covers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct CompactionOptionsManual {
-    pub exclusive_manual: bool,
-    pub manual_compaction_type: ManualCompactionType,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CompactionOptionsUniversal {
-    pub size_ratio: u64,
-    pub min_merge_width: u64,
-    pub max_merge_width: u64,
-    pub max_size_amplification_percent: u64,
-    pub stop_style: StopStyle,
-    pub allow_trivial_move: bool,
+    pub namespaced: Option<String>,
+    /// Unbounded when `None`.
+    pub start: Option<Vec<u8>>,
+    pub end: Option<Vec<u8>>,
 }
 
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CompactionOptionsFIFO {
-    pub allow_trivial_move: bool,
+/// Runs the manual compaction `opts` describes, over every causet_merge family when
+/// it names none.
+pub fn compact_manual(
+    einstein_merkle_tree: &KvTestEngine,
+    opts: &CompactionOptionsManual,
+) -> Result<()> {
+    let namespaceds = match &opts.namespaced {
+        Some(namespaced) => vec![namespaced.clone()],
+        None => einstein_merkle_tree.namespaced_names(),
+    };
+    for namespaced in namespaceds {
+        einstein_merkle_tree.compact_range_namespaced(
+            &namespaced,
+            opts.start.as_deref(),
+            opts.end.as_deref(),
+        )?;
+    }
+    Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use fdb_traits::{MiscExt, Peekable};
 
-impl Default for CompactionOptionsFIFO {
-    fn default() -> Self {
-        CompactionOptionsFIFO {
+    use super::*;
+    use crate::kv::new_einstein_merkle_tree_opt;
 
-            // TODO: default value
-            allow_trivial_move: false,
+    #[test]
+    fn test_compact_manual() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut opts = LsmOptions {
+            namespaceds: vec!["write".to_owned()],
+            ..Default::default()
+        };
+        CompactOptions {
+            enable_bloom_filter: false,
+            compression: Compression::Snappy,
+            disable_auto_compactions: true,
+            ..Default::default()
         }
-    }
-}
-
-impl Default for CompactionOptionsUniversal {
-    fn default() -> Self {
-        CompactionOptionsUniversal {
-            size_ratio: 0,
-            min_merge_width: 0,
-            max_merge_width: 0,
-            max_size_amplification_percent: 0,
-            stop_style: StopStyle::StopStyleNone,
-            allow_trivial_move: false,
+        .apply_to(&mut opts);
+        assert_eq!(opts.bloom_bits_per_key, 0);
+        let einstein_merkle_tree =
+            new_einstein_merkle_tree_opt(dir.path().to_str().unwrap(), opts).unwrap();
+
+        for i in 0..3u8 {
+            einstein_merkle_tree
+                .put_namespaced("write", &[i], b"v")
+                .unwrap();
+            einstein_merkle_tree.flush(true).unwrap();
         }
+        einstein_merkle_tree
+            .delete_namespaced("write", &[0])
+            .unwrap();
+        assert_eq!(
+            einstein_merkle_tree.num_files_at_level("write", 0).unwrap(),
+            3
+        );
+
+        compact_manual(&einstein_merkle_tree, &CompactionOptionsManual::default()).unwrap();
+        assert_eq!(
+            einstein_merkle_tree.num_files_at_level("write", 0).unwrap(),
+            0
+        );
+        assert_eq!(
+            einstein_merkle_tree.num_files_at_level("write", 1).unwrap(),
+            1
+        );
+        assert!(einstein_merkle_tree
+            .get_value_namespaced("write", &[0])
+            .unwrap()
+            .is_none());
+        assert!(einstein_merkle_tree
+            .get_value_namespaced("write", &[2])
+            .unwrap()
+            .is_some());
     }
 }
-
-
-impl Default for CompactionOptionsManual {
-    fn default() -> Self {
-        CompactionOptionsManual {
-            exclusive_manual: false,
-            manual_compaction_type: ManualCompactionType::Manual,
-        }
-    }
-}
\ No newline at end of file
@@ -1,100 +1,217 @@
-// Copyright 2020 EinsteinDB Project Authors. 
-//Licensed under Apache-2.0.
-// See the LICENSE file in the project root for license information.
+// Copyright 2020 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
+//! Compaction jobs of the test einstein_merkle_trees: the TTL compaction filter
+//! the tests install and what a manual compaction job did.
 
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::{Duration, Instant};
-use std::{cmp, u64};
+use fdb_traits::{
+    split_expire_ts, CompactionFilter, CompactionFilterContext, CompactionFilterDecision,
+    CompactionFilterExt, CompactionFilterFactory, MiscExt, Result,
+};
+
+use crate::kv::KvTestEngine;
 
+/// The current time in seconds since the epoch, as expiry times are recorded.
+pub fn ttl_current_ts() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
 
-use fdb_traits::Result;
-use einstein_db_alexandrov_processing::{
-    index::{
-        Index,
-        IndexIterator,
-        IndexIteratorOptions,
-        IndexIteratorOptionsBuilder,
+/// The expiry time of a causet_locale written now with `ttl` seconds to live, or
+/// `None` if it never expires.
+pub fn ttl_to_expire_ts(ttl: u64) -> Option<u64> {
+    if ttl == 0 {
+        None
+    } else {
+        Some(ttl.saturating_add(ttl_current_ts()))
     }
-};
+}
 
-use berolina_sql::{
-    parser::Parser,
-    value::{Value, ValueType},
-    error::{Error, Result},
-    parser::ParserError,
-    value::{ValueRef, ValueRefMut},
-    fdb_traits::FdbTrait,
-    fdb_traits::FdbTraitImpl,
-    pretty,
-    io,
-    convert::{TryFrom, TryInto},
-    ops::{Deref, DerefMut},
-    sync::{Arc, Mutex},
-};
+/// Whether a causet_locale with expiry time `expire_ts` has expired at `now`. An
+/// expiry time of 0 never expires.
+pub fn ttl_expired(expire_ts: u64, now: u64) -> bool {
+    expire_ts != 0 && expire_ts <= now
+}
 
+/// Drops the causet_locales of a TTL causet_merge family that expired at the
+/// time the compaction started.
+pub struct TtlCompactionFilterFactory;
 
-use itertools::Itertools;
-use std::local_path::local_path;
+struct TtlCompactionFilter {
+    now: u64,
+}
 
+impl CompactionFilter for TtlCompactionFilter {
+    fn filter(
+        &mut self,
+        _level: usize,
+        _soliton_id: &[u8],
+        causet_locale: &[u8],
+    ) -> CompactionFilterDecision {
+        match split_expire_ts(causet_locale) {
+            Some((_, expire_ts)) if ttl_expired(expire_ts, self.now) => {
+                CompactionFilterDecision::Remove
+            }
+            _ => CompactionFilterDecision::Keep,
+        }
+    }
+}
 
-use super::*;
+impl CompactionFilterFactory for TtlCompactionFilterFactory {
+    fn name(&self) -> &str {
+        "ttl"
+    }
 
+    fn create_compaction_filter(
+        &self,
+        _context: &CompactionFilterContext<'_>,
+    ) -> Option<Box<dyn CompactionFilter>> {
+        Some(Box::new(TtlCompactionFilter {
+            now: ttl_current_ts(),
+        }))
+    }
+}
 
+/// What a compaction job did.
+pub trait CompactionJobInfo {
+    fn namespaced_name(&self) -> &str;
+
+    fn input_file_count(&self) -> usize;
 
+    fn output_file_count(&self) -> usize;
 
+    /// The level the outputs were written to.
+    fn output_l_naught(&self) -> usize;
 
+    /// The soliton_ids the compaction filter removed.
+    fn num_removed_soliton_ids(&self) -> u64;
+}
 
-pub fn ttl_current_ts() -> u64 {
-    fail_point!("ttl_current_ts", |r| r.map_or(2, |e| e.parse().unwrap()));
-    einsteindb_util::time::UnixSecs::now().into_inner()
+/// A manual compaction of a whole causet_merge family, see `run_compaction_job`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManualCompactionJob {
+    namespaced: String,
+    input_files: usize,
+    output_files: usize,
+    output_l_naught: usize,
+    removed: u64,
 }
 
+impl CompactionJobInfo for ManualCompactionJob {
+    fn namespaced_name(&self) -> &str {
+        &self.namespaced
+    }
 
-pub fn ttl_to_expire_ts(ttl: u64) -> Option<u64> {
-    if ttl == 0 {
-        None
-    } else {
-        Some(ttl.saturating_add(ttl_current_ts()))
+    fn input_file_count(&self) -> usize {
+        self.input_files
     }
-}
 
+    fn output_file_count(&self) -> usize {
+        self.output_files
+    }
 
-pub fn ttl_expire_ts(ttl: u64) -> u64 {
-    ttl_to_expire_ts(ttl).unwrap_or(0)
+    fn output_l_naught(&self) -> usize {
+        self.output_l_naught
+    }
+
+    fn num_removed_soliton_ids(&self) -> u64 {
+        self.removed
+    }
 }
 
+fn files_per_level(einstein_merkle_tree: &KvTestEngine, namespaced: &str) -> Result<Vec<usize>> {
+    let num_levels = einstein_merkle_tree.options().num_levels;
+    (0..num_levels)
+        .map(|l| einstein_merkle_tree.num_files_at_level(namespaced, l))
+        .collect()
+}
 
-pub fn ttl_expired(ttl: u64) -> bool {
-    ttl_to_expire_ts(ttl).map_or(false, |expire_ts| expire_ts <= ttl_current_ts())
+/// Compacts all of `namespaced` and reports what the compaction did.
+pub fn run_compaction_job(
+    einstein_merkle_tree: &KvTestEngine,
+    namespaced: &str,
+) -> Result<ManualCompactionJob> {
+    let removed = einstein_merkle_tree
+        .compaction_filter_stats(namespaced)?
+        .keys_removed;
+    // Flush first, so that the memtable is counted among the inputs.
+    einstein_merkle_tree.flush_namespaced(namespaced, true)?;
+    let before = files_per_level(einstein_merkle_tree, namespaced)?;
+    einstein_merkle_tree.compact_range_namespaced(namespaced, None, None)?;
+    let after = files_per_level(einstein_merkle_tree, namespaced)?;
+    Ok(ManualCompactionJob {
+        namespaced: namespaced.to_owned(),
+        input_files: before.iter().sum(),
+        output_files: after.iter().sum(),
+        output_l_naught: after.iter().rposition(|&n| n > 0).unwrap_or(0),
+        removed: einstein_merkle_tree
+            .compaction_filter_stats(namespaced)?
+            .keys_removed
+            - removed,
+    })
 }
 
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
 
-pub fn ttl_expire_time(ttl: u64) -> Option<u64> {
-    ttl_to_expire_ts(ttl).map(|expire_ts| expire_ts - ttl_current_ts())
-}
+    use fdb_traits::{append_expire_ts, Peekable};
 
+    use super::*;
+    use crate::kv::{new_einstein_merkle_tree_opt, LsmOptions};
 
-pub trait CompactionJobInfo {
-    type TableGreedoidsCollectionView;
-    type CompactionReason;
-    fn status(&self) -> Result<(), String>;
-    fn namespaced_name(&self) -> &str;
-    fn input_file_count(&self) -> usize;
-    fn num_input_filefs_at_output_l_naught(&self) -> usize;
-    fn input_file_at(&self, pos: usize) -> &local_path;
-    fn output_file_count(&self) -> usize;
-    fn output_file_at(&self, pos: usize) -> &local_path;
-    fn table_greedoids(&self) -> &Self::TableGreedoidsCollectionView;
-    fn base_input_l_naught(&self) -> i32;
-    fn elapsed_micros(&self) -> u64;
-    fn num_corrupt_soliton_ids(&self) -> u64;
-    fn output_l_naught(&self) -> i32;
-    fn input_records(&self) -> u64;
-    fn output_records(&self) -> u64;
-    fn total_input_bytes(&self) -> u64;
-    fn total_output_bytes(&self) -> u64;
-    fn jet_bundle_reason(&self) -> Self::CompactionReason;
+    #[test]
+    fn test_ttl() {
+        assert_eq!(ttl_to_expire_ts(0), None);
+        assert!(ttl_to_expire_ts(10).unwrap() >= ttl_current_ts() + 10);
+        assert!(!ttl_expired(0, u64::MAX));
+        assert!(ttl_expired(5, 5));
+        assert!(!ttl_expired(6, 5));
+    }
+
+    #[test]
+    fn test_run_compaction_job() {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = LsmOptions {
+            namespaceds: vec!["ttl".to_owned()],
+            ttl_namespaceds: vec!["ttl".to_owned()],
+            disable_auto_compactions: true,
+            ..Default::default()
+        };
+        let einstein_merkle_tree =
+            new_einstein_merkle_tree_opt(dir.path().to_str().unwrap(), opts).unwrap();
+        einstein_merkle_tree
+            .set_compaction_filter_factory("ttl", Some(Arc::new(TtlCompactionFilterFactory)))
+            .unwrap();
+
+        for (soliton_id, expire_ts) in [
+            (b"a", 1),
+            (b"b", 0),
+            (b"c", ttl_to_expire_ts(3600).unwrap()),
+        ] {
+            let mut causet_locale = b"v".to_vec();
+            append_expire_ts(&mut causet_locale, expire_ts);
+            einstein_merkle_tree
+                .put_namespaced("ttl", soliton_id, &causet_locale)
+                .unwrap();
+            einstein_merkle_tree.flush(true).unwrap();
+        }
+
+        let job = run_compaction_job(&einstein_merkle_tree, "ttl").unwrap();
+        assert_eq!(job.namespaced_name(), "ttl");
+        assert_eq!(job.input_file_count(), 3);
+        assert_eq!(job.output_file_count(), 1);
+        assert_eq!(job.output_l_naught(), 1);
+        assert_eq!(job.num_removed_soliton_ids(), 1);
+        assert!(einstein_merkle_tree
+            .get_value_namespaced("ttl", b"a")
+            .unwrap()
+            .is_none());
+        assert!(einstein_merkle_tree
+            .get_value_namespaced("ttl", b"b")
+            .unwrap()
+            .is_some());
+    }
 }
@@ -1,253 +1,54 @@
-use std::{ptr, usize};
-use std::cell::UnsafeCell;
-use std::sync::Arc;
-
-use std::borrow::Cow;
-use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering, Partitioning};
-
-use crate::mailbox::BasicMailbox;
-
-
-const MAX_MSG_COUNT: usize = 1024;
-
-const MAX_MSG_SIZE: usize = 1024 * 1024;
-
-const MAX_MSG_COUNT_PER_THREAD: usize = 1024;
-
-// The FSM is notified.
-const INTERLOCKING_FSM_BROADCAST: usize = 0;
-// The FSM is idle.
-const INTERLOCKING_FSM_IDLE: usize = 1;
-// The FSM is waiting for a message.
-const INTERLOCKING_FSM_WAITING: usize = 2;
-// The FSM is waiting for a message and is notified.
-const INTERLOCKING_FSM_WAITING_BROADCAST: usize = 3;
-
-
-#[derive(Debug, Clone)]
-pub struct FSM {
-    mailbox: Arc<BasicMailbox>,
-    state: AtomicUsize,
-    msg_count: AtomicUsize,
-    msg_size: AtomicUsize,
-    msg_count_per_thread: AtomicUsize,
-    msg_size_per_thread: AtomicUsize,
-    msg_count_per_thread_per_thread: AtomicUsize,
-    msg_size_per_thread_per_thread: AtomicUsize,
-}
-
-
-impl FSM {
-    pub fn new() -> Self {
-        FSM {
-            mailbox: Arc::new(BasicMailbox::new()),
-            state: AtomicUsize::new(INTERLOCKING_FSM_IDLE),
-            msg_count: AtomicUsize::new(0),
-            msg_size: AtomicUsize::new(0),
-            msg_count_per_thread: AtomicUsize::new(0),
-            msg_size_per_thread: AtomicUsize::new(0),
-            msg_count_per_thread_per_thread: AtomicUsize::new(0),
-            msg_size_per_thread_per_thread: AtomicUsize::new(0),
-        }
-    }
-
-    pub fn mailbox(&self) -> &Arc<BasicMailbox> {
-        &self.mailbox
-    }
-
-    pub fn mailbox_mut(&mut self) -> &mut Arc<BasicMailbox> {
-        &mut self.mailbox
-    }
-
-    pub fn state(&self) -> usize {
-        self.state.load(Ordering::Relaxed)
-    }
-
-    pub fn set_state(&self, state: usize) {
-        self.state.store(state, Ordering::Relaxed)
-    }
-
-    pub fn msg_count(&self) -> usize {
-        self.msg_count.load(Ordering::Relaxed)
-    }
-
-    pub fn set_msg_count(&self, msg_count: usize) {
-        self.msg_count.store(msg_count, Ordering::Relaxed)
-    }
-
-    pub fn msg_size(&self) -> usize {
-        self.msg_size.load(Ordering::Relaxed)
-    }
-
-    pub fn set_msg_size(&self, msg_size: usize) {
-        self.msg_size.store(msg_size, Ordering::Relaxed)
-    }
-
-    pub fn msg_count_per_thread(&self) -> usize {
-        self.msg_count_per_thread.load(Ordering::Relaxed)
-    }
-}
-
-
-
-///A Maxwell Demon is a thread that runs on a single core.
-/// It is responsible for:
-/// 1. Receiving messages from the mailbox.
-/// 2. Processing the messages.
-/// 3. Sending messages to the mailbox.
-/// 4. Reporting the results to the mailbox.
-/// 
-/// The Maxwell Demon is a singleton.
-/// It is created by the Maxwell Engine.
-/// It is destroyed by the Maxwell Engine.
-
-
-
-
-#[derive(Debug, Clone)]
-pub struct MaxwellDemon {
-    mailbox: Arc<BasicMailbox>,
-    state: AtomicUsize,
-    msg_count: AtomicUsize,
-    msg_size: AtomicUsize,
-    msg_count_per_thread: AtomicUsize,
-    msg_size_per_thread: AtomicUsize,
-    msg_count_per_thread_per_thread: AtomicUsize,
-    msg_size_per_thread_per_thread: AtomicUsize,
-}
-
-
-impl MaxwellDemon {
-    pub fn new() -> Self {
-        MaxwellDemon {
-            mailbox: Arc::new(BasicMailbox::new()),
-            state: AtomicUsize::new(INTERLOCKING_FSM_IDLE),
-            msg_count: AtomicUsize::new(0),
-            msg_size: AtomicUsize::new(0),
-            msg_count_per_thread: AtomicUsize::new(0),
-            msg_size_per_thread: AtomicUsize::new(0),
-            msg_count_per_thread_per_thread: AtomicUsize::new(0),
-            msg_size_per_thread_per_thread: AtomicUsize::new(0),
-        }
-    }
-
-    pub fn mailbox(&self) -> &Arc<BasicMailbox> {
-        &self.mailbox
-    }
-
-    pub fn mailbox_mut(&mut self) -> &mut Arc<BasicMailbox> {
-        &mut self.mailbox
-    }
-
-    pub fn state(&self) -> usize {
-        self.state.load(Ordering::Relaxed)
-    }
-
-    pub fn set_state(&self, state: usize) {
-        self.state.store(state, Ordering::Relaxed)
-    }
-
-    pub fn msg_count(&self) -> usize {
-        self.msg_count.load(Ordering::Relaxed)
-    }
-
-    pub fn set_msg_count(&self, msg_count: usize) {
-        self.msg_count.store(msg_count, Ordering::Relaxed)
-    }
-
-    pub fn msg_size(&self) -> usize {
-        self.msg_size.load(Ordering::Relaxed)
-    }
-
-    pub fn set_msg_size(&self, msg_size: usize) {
-        self.msg_size.store(msg_size, Ordering::Relaxed)
-    }
-
-    pub fn msg_count_per_thread(&self) -> usize {
-        self.msg_count_per_thread.load(Ordering::Relaxed)
-    }
-
-}
-
-
-
+// Copyright 2020 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
+//! The finite state machines the store drives in alexandrov_poset_processes, and
+//! the mailboxes they are notified through.
+//!
+//! A turing_automata is owned by its `FsmState` while it is idle. Sending a
+//! message to an idle turing_automata takes it out and hands it to the
+//! `FsmScheduler`; the poller that handles it gives it back with `release` once
+//! its mailbox is drained.
 
+use std::borrow::Cow;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::mpsc::{SendError, Sender};
+use std::sync::Arc;
 
+// The turing_automata is notified, a poller owns it.
+const NOTIFYSTATE_NOTIFIED: usize = 0;
+// The turing_automata is idle, its `FsmState` owns it.
+const NOTIFYSTATE_IDLE: usize = 1;
+// The turing_automata is expected to be dropped.
+const NOTIFYSTATE_DROP: usize = 2;
 
-/// A FSM is a state machine that can be used to implement a state machine.
-/// The FSM is a single threaded state machine.
-
-
-/// `FsmScheduler` schedules `turing_automata` for later handles.
+/// `FsmScheduler` schedules turing_automata for later handles.
 pub trait FsmScheduler {
+    type Fsm: Fsm;
 
-    /// `schedule` schedules the `turing_automata` for later handles.
-    /// The `turing_automata` is scheduled for later handles.
-    /// 
-    
+    /// Schedules `turing_automata` for later handles.
+    fn schedule(&self, turing_automata: Box<Self::Fsm>);
 
-    fn schedule(&self, turing_automata: &dyn Fsm);
+    /// Shuts down the scheduler.
+    fn shutdown(&self);
 }
 
-
-/// `FsmScheduler` schedules `turing_automata` for later handles.
-/// The `turing_automata` is scheduled for later handles.
-///     
-/// # Examples
-/// ```
-/// use engine_test::FSM;
-/// use maxwell::turing_automata::FsmScheduler;
-/// use maxwell::turing_automata::turing_automata;
-/// use maxwell::turing_automata::FSM;
-///
-/// let turing_automata = FSM::new();
-/// let turing_automata_scheduler = FsmScheduler::new();
-/// turing_automata_scheduler.schedule(&turing_automata);
-/// ```
-/// 
-/// # Panics
-/// This function may panic if the `turing_automata` is not valid.
-/// 
-/// # Safety
-/// This function is unsafe because it dereferences the `turing_automata` to get its `Mailbox`.
-/// This function is unsafe because it dereferences the `Mailbox` to get its `MailboxGuard`.
-/// This function is unsafe because it dereferences the `MailboxGuard` to get its `Mailbox`.
-
-
-
-/// A turing_automata is a finite state machine. It should be able to be notified for
-/// uFIDelating internal state according to incoming messages.
+/// A turing_automata is a finite state machine. It should be able to be notified
+/// for updating internal state according to incoming messages.
 pub trait Fsm {
-
-    /// `mailbox` returns the mailbox for this turing_automata.
-    /// The mailbox is used to send messages to the turing_automata.
-    ///     
-    /// # Examples
-    /// ```
-    /// use engine_test::FSM;
-    /// use maxwell::turing_automata::turing_automata;
-    /// use maxwell::turing_automata::FSM;
-    ///
-    /// let turing_automata = FSM::new();
-    /// let mailbox = turing_automata.mailbox();
-    /// ```
-    /// 
-    /// # Panics
-    /// This function may panic if the `turing_automata` is not valid.
-    /// 
     type Message: Send;
 
     fn is_stopped(&self) -> bool;
 
-    /// Set a mailbox to turing_automata, which should be used to send message to itself.
+    /// Set a mailbox to turing_automata, which should be used to send message to
+    /// itself.
     fn set_mailbox(&mut self, _mailbox: Cow<'_, BasicMailbox<Self>>)
     where
         Self: Sized,
     {
     }
-    /// Take the mailbox from turing_automata. Implementation should ensure there will be
-    /// no reference to mailbox after calling this method.
+
+    /// Take the mailbox from turing_automata. Implementation should ensure there
+    /// will be no reference to mailbox after calling this method.
     fn take_mailbox(&mut self) -> Option<BasicMailbox<Self>>
     where
         Self: Sized,
@@ -256,7 +57,6 @@ pub trait Fsm {
     }
 }
 
-
 pub struct FsmState<N> {
     status: AtomicUsize,
     data: AtomicPtr<N>,
@@ -266,85 +66,264 @@ impl<N: Fsm> FsmState<N> {
     pub fn new(data: Box<N>) -> FsmState<N> {
         FsmState {
             status: AtomicUsize::new(NOTIFYSTATE_IDLE),
-            data: AtomicPtr::new(Box::into_primitive_causet(data)),
+            data: AtomicPtr::new(Box::into_raw(data)),
         }
     }
 
     /// Take the turing_automata if it's IDLE.
-    pub fn take_turing_automata(&self) -> Option<Box<N>> {
-        let previous_state =
-            self.status
-                .compare_and_swap(NOTIFYSTATE_IDLE, NOTIFYSTATE_NOTIFIED, Partitioning::AcqRel);
-        if previous_state != NOTIFYSTATE_IDLE {
+    pub fn take_fsm(&self) -> Option<Box<N>> {
+        if self
+            .status
+            .compare_exchange(
+                NOTIFYSTATE_IDLE,
+                NOTIFYSTATE_NOTIFIED,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_err()
+        {
             return None;
         }
 
-        let p = self.data.swap(ptr::null_mut(), Partitioning::AcqRel);
+        let p = self.data.swap(ptr::null_mut(), Ordering::AcqRel);
         if !p.is_null() {
-            Some(unsafe { Box::from_primitive_causet(p) })
+            // SAFETY: `p` came from `Box::into_raw` and was swapped out, so this is
+            // its only owner.
+            Some(unsafe { Box::from_raw(p) })
         } else {
             panic!("inconsistent status and data, something should be wrong.");
         }
     }
 
-    /// Notify turing_automata via a `FsmScheduler`.
-    #[inline]
+    /// Notifies the turing_automata, scheduling it if it was idle.
     pub fn notify<S: FsmScheduler<Fsm = N>>(
         &self,
         scheduler: &S,
         mailbox: Cow<'_, BasicMailbox<N>>,
     ) {
-        match self.take_turing_automata() {
-            None => {}
-            Some(mut n) => {
-                n.set_mailbox(mailbox);
-                scheduler.schedule(&n);
-            }
+        if let Some(mut turing_automata) = self.take_fsm() {
+            turing_automata.set_mailbox(mailbox);
+            scheduler.schedule(turing_automata);
         }
     }
 
     /// Put the owner back to the state.
     ///
     /// It's not required that all messages should be consumed before
-    /// releasing a turing_automata. However, a turing_automata is guaranteed to be notified only
-    /// when new messages arrives after it's released.
-    #[inline]
+    /// releasing a turing_automata. However, a turing_automata is guaranteed
+    /// to be notified only when new messages arrives after it's released.
     pub fn release(&self, turing_automata: Box<N>) {
-        let previous = self.data.swap(Box::into_primitive_causet(turing_automata), Partitioning::AcqRel);
+        let previous = self
+            .data
+            .swap(Box::into_raw(turing_automata), Ordering::AcqRel);
         let mut previous_status = NOTIFYSTATE_NOTIFIED;
         if previous.is_null() {
-            previous_status = self.status.compare_and_swap(
+            match self.status.compare_exchange(
                 NOTIFYSTATE_NOTIFIED,
                 NOTIFYSTATE_IDLE,
-                Partitioning::AcqRel,
-            );
-            match previous_status {
-                _NOTIFYSTATE_NOTIFIED => return,
-                _NOTIFYSTATE_DROP => {
-                    let ptr = self.data.swap(ptr::null_mut(), Partitioning::AcqRel);
-                    unsafe { Box::from_primitive_causet(ptr) };
-                    return;
-                }
-                _ => {}
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return,
+                Err(status) => previous_status = status,
+            }
+            if previous_status == NOTIFYSTATE_DROP {
+                let ptr = self.data.swap(ptr::null_mut(), Ordering::AcqRel);
+                // SAFETY: the state is being dropped and `ptr` was just swapped out.
+                unsafe { drop(Box::from_raw(ptr)) };
+                return;
             }
         }
         panic!("invalid release state: {:?} {}", previous, previous_status);
     }
 
     /// Clear the turing_automata.
-    #[inline]
     pub fn clear(&self) {
-        match self.status.swap(NOTIFYSTATE_DROP, Partitioning::AcqRel) {
-            NOTIFYSTATE_NOTIFIED => return,
+        match self.status.swap(NOTIFYSTATE_DROP, Ordering::AcqRel) {
+            NOTIFYSTATE_NOTIFIED | NOTIFYSTATE_DROP => return,
+            _ => {}
+        }
+
+        let ptr = self.data.swap(ptr::null_mut(), Ordering::SeqCst);
+        if !ptr.is_null() {
+            // SAFETY: the turing_automata was idle, so the state owned `ptr`.
+            unsafe { drop(Box::from_raw(ptr)) };
         }
     }
 }
 
 impl<N> Drop for FsmState<N> {
     fn drop(&mut self) {
-        let ptr = self.data.swap(ptr::null_mut(), Partitioning::SeqCst);
+        let ptr = self.data.swap(ptr::null_mut(), Ordering::SeqCst);
         if !ptr.is_null() {
-            unsafe { Box::from_primitive_causet(ptr) };
+            // SAFETY: `ptr` came from `Box::into_raw` and nobody else holds it.
+            unsafe { drop(Box::from_raw(ptr)) };
         }
     }
 }
+
+/// A basic mailbox.
+///
+/// Every mailbox should have one and only one owner, who will receive all
+/// messages sent to this mailbox.
+///
+/// When a message is sent to a mailbox, its owner will be checked whether it's
+/// idle. An idle owner will be scheduled via `FsmScheduler` immediately, which
+/// will drive the turing_automata to poll for messages.
+pub struct BasicMailbox<Owner: Fsm> {
+    sender: Sender<Owner::Message>,
+    state: Arc<FsmState<Owner>>,
+}
+
+impl<Owner: Fsm> BasicMailbox<Owner> {
+    /// Creates a mailbox for `turing_automata`, whose messages are received from
+    /// the other end of `sender`.
+    pub fn new(sender: Sender<Owner::Message>, turing_automata: Box<Owner>) -> BasicMailbox<Owner> {
+        BasicMailbox {
+            sender,
+            state: Arc::new(FsmState::new(turing_automata)),
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.state.status.load(Ordering::Acquire) == NOTIFYSTATE_IDLE
+    }
+
+    pub fn release(&self, turing_automata: Box<Owner>) {
+        self.state.release(turing_automata)
+    }
+
+    pub fn take_fsm(&self) -> Option<Box<Owner>> {
+        self.state.take_fsm()
+    }
+
+    /// Sends a message to the owner and notifies it. Fails if the receiving end is
+    /// gone.
+    pub fn try_send<S: FsmScheduler<Fsm = Owner>>(
+        &self,
+        msg: Owner::Message,
+        scheduler: &S,
+    ) -> Result<(), SendError<Owner::Message>> {
+        self.sender.send(msg)?;
+        self.state.notify(scheduler, Cow::Borrowed(self));
+        Ok(())
+    }
+
+    /// Closes the mailbox explicitly.
+    pub fn close(&self) {
+        self.state.clear();
+    }
+}
+
+impl<Owner: Fsm> Clone for BasicMailbox<Owner> {
+    fn clone(&self) -> BasicMailbox<Owner> {
+        BasicMailbox {
+            sender: self.sender.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{channel, Receiver};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    struct Counter {
+        receiver: Receiver<u64>,
+        sum: u64,
+        mailbox: Option<BasicMailbox<Counter>>,
+    }
+
+    impl Fsm for Counter {
+        type Message = u64;
+
+        fn is_stopped(&self) -> bool {
+            false
+        }
+
+        fn set_mailbox(&mut self, mailbox: Cow<'_, BasicMailbox<Self>>) {
+            self.mailbox = Some(mailbox.into_owned());
+        }
+
+        fn take_mailbox(&mut self) -> Option<BasicMailbox<Self>> {
+            self.mailbox.take()
+        }
+    }
+
+    #[derive(Default)]
+    struct Scheduler {
+        // Kept boxed, as they are handed back to `release`.
+        #[allow(clippy::vec_box)]
+        scheduled: Mutex<Vec<Box<Counter>>>,
+    }
+
+    impl FsmScheduler for Scheduler {
+        type Fsm = Counter;
+
+        fn schedule(&self, turing_automata: Box<Counter>) {
+            self.scheduled.lock().unwrap().push(turing_automata);
+        }
+
+        fn shutdown(&self) {
+            self.scheduled.lock().unwrap().clear();
+        }
+    }
+
+    /// Drains the mailboxes of the scheduled turing_automata and releases them.
+    fn poll(scheduler: &Scheduler) -> usize {
+        let scheduled: Vec<_> = scheduler.scheduled.lock().unwrap().drain(..).collect();
+        let n = scheduled.len();
+        for mut counter in scheduled {
+            counter.sum += counter.receiver.try_iter().sum::<u64>();
+            let mailbox = counter.take_mailbox().unwrap();
+            mailbox.release(counter);
+        }
+        n
+    }
+
+    #[test]
+    fn test_mailbox() {
+        let (tx, rx) = channel();
+        let counter = Box::new(Counter {
+            receiver: rx,
+            sum: 0,
+            mailbox: None,
+        });
+        let mailbox = BasicMailbox::new(tx, counter);
+        let scheduler = Scheduler::default();
+        assert!(mailbox.is_idle());
+
+        // Only the first message of a burst schedules the turing_automata.
+        mailbox.try_send(1, &scheduler).unwrap();
+        mailbox.clone().try_send(2, &scheduler).unwrap();
+        assert!(!mailbox.is_idle());
+        assert!(mailbox.take_fsm().is_none());
+        assert_eq!(poll(&scheduler), 1);
+        assert!(mailbox.is_idle());
+
+        mailbox.try_send(3, &scheduler).unwrap();
+        assert_eq!(poll(&scheduler), 1);
+        assert_eq!(poll(&scheduler), 0);
+        assert_eq!(mailbox.take_fsm().unwrap().sum, 6);
+    }
+
+    #[test]
+    fn test_close() {
+        let (tx, rx) = channel();
+        let counter = Box::new(Counter {
+            receiver: rx,
+            sum: 0,
+            mailbox: None,
+        });
+        let mailbox = BasicMailbox::new(tx, counter);
+        let scheduler = Scheduler::default();
+        mailbox.try_send(1, &scheduler).unwrap();
+        // Closing a busy mailbox drops its turing_automata once it's released.
+        mailbox.close();
+        assert_eq!(poll(&scheduler), 1);
+        assert!(mailbox.take_fsm().is_none());
+    }
+}
@@ -1,133 +1,86 @@
-mod compact;
-mod compaction_job;
-mod fsm;
-mod import;
-mod misc ;
-
-
-pub use compact::*;
-pub use compaction_job::*;
-pub use fsm::*;
-pub use import::*;
-pub use misc::*;
-    
-
-    
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() {
-        assert_eq!(2 + 2, 4);
-    }
-}
+// Copyright 2020 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
+//! The einstein_merkle_trees the test suite runs on, by the names it knows them.
+//!
+//! This should ultimately be the only module within EinsteinDB that needs to know
+//! about concrete EinsteinMerkleTrees. Other code only uses the `fdb_traits`
+//! abstractions.
 
+use std::path::Path;
 
+use ::fdb_traits::Result;
 
+mod compact;
+mod compaction_job;
+mod fdb_traits;
+mod fsm;
+mod import;
+mod misc;
+
+pub use crate::compact::{compact_manual, CompactOptions, CompactionOptionsManual};
+pub use crate::compaction_job::{
+    run_compaction_job, ttl_current_ts, ttl_expired, ttl_to_expire_ts, CompactionJobInfo,
+    ManualCompactionJob, TtlCompactionFilterFactory,
+};
+pub use crate::fdb_traits::FdbTrait;
+pub use crate::fsm::{BasicMailbox, Fsm, FsmScheduler, FsmState};
+pub use crate::import::{import_kv, ImportOption, ImportOptions};
+pub use crate::misc::{all_deleted, delete_all_in_range_by_files};
 
 /// Types and constructors for the "violetabft" einstein_merkle_tree
 pub mod violetabft {
-    use std::hash::Hash;
-    use crate::ctor::{DBOptions, NAMESPACEDOptions};
-
-    /// The type of a node in the einstein_merkle_tree
-    /// This is a tuple of the hash of the left and right children, and the hash of the data.
-    /// The hash of the data is computed by the `hash` function of the `Hasher` type.
-    /// The hash of the left and right children is computed by the `hash` function of the `Hasher` type.
-    
-    
-    #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-    pub struct Node<T> {
-        pub left: dyn Hash,
-        pub right: dyn Hash,
-        pub data: T,
-    }
-    
-    /// The type of a leaf in the einstein_merkle_tree
-    /// This is a tuple of the hash of the data, and the data.
-    
-    
-    #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-    pub struct Leaf<T> {
-        pub data: T,
-    }
-    
-    /// The type of a branch in the einstein_merkle_tree
-    
-    
-    #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-    pub struct Branch<T> {
-        pub left: dyn Hash,
-        pub right: dyn Hash,
-    }
+    pub use violetabft_log_engine::{
+        LogBatch as VioletaBFTTestLogBatch, VioletaBFTLogConfig,
+        VioletaBFTLogEngine as VioletaBFTTestEngine,
+    };
 
-    pub fn new_einstein_merkle_tree(
-        local_path: &str,
-        db_opt: Option<DBOptions>,
-        namespaced: &str,
-        opt: Option<NAMESPACEDOptions<'_>>,
-    ) -> Result<VioletaBFTTesteinstein_merkle_tree, E> {
-        &[namespaced];
-        let opts = opt.map(|o| vec![o]);
-        VioletaBFTTesteinstein_merkle_tree::new_einstein_merkle_tree(local_path, db_opt, namespaceds, opts)
+    use fdb_traits::Result;
+
+    pub fn new_einstein_merkle_tree(local_path: &str) -> Result<VioletaBFTTestEngine> {
+        new_einstein_merkle_tree_opt(VioletaBFTLogConfig::new(local_path))
     }
 
-    pub fn new_einstein_merkle_tree_opt(
-        local_path: &str,
-        db_opt: DBOptions,
-        namespaced_opt: NAMESPACEDOptions<'_>,
-    ) -> Result<VioletaBFTTesteinstein_merkle_tree, E> {
-        let namespaceds_opts = vec![namespaced_opt];
-        VioletaBFTTesteinstein_merkle_tree::new_einstein_merkle_tree_opt(local_path, db_opt, namespaceds_opts)
+    pub fn new_einstein_merkle_tree_opt(cfg: VioletaBFTLogConfig) -> Result<VioletaBFTTestEngine> {
+        VioletaBFTTestEngine::open(cfg)
     }
 }
 
 /// Types and constructors for the "kv" einstein_merkle_tree
 pub mod kv {
-    #[APPEND_LOG_g(feature = "test-einstein_merkle_tree-kv-panic")]
-    pub use einstein_merkle_tree_panic::{
-        soliton_panic_merkle_tree as KvTesteinstein_merkle_tree, soliton_panic_merkle_treeIterator as KvTesteinstein_merkle_treeIterator,
-        PanicLightlikePersistence as KvTestLightlikePersistence, PanicWriteBatch as KvTestWriteBatch,
-    };
-    #[APPEND_LOG_g(feature = "test-einstein_merkle_tree-kv-foundationdb")]
-    pub use fdb_einstein_merkle_tree::{
-        Fdbeinstein_merkle_tree as KvTesteinstein_merkle_tree, Fdbeinstein_merkle_treeIterator as KvTesteinstein_merkle_treeIterator,
-        FdbLightlikePersistence as KvTestLightlikePersistence, FdbWriteBatch as KvTestWriteBatch,
+    pub use soliton_lsm::{
+        LsmEngine as KvTestEngine, LsmIterator as KvTestEngineIterator, LsmOptions,
+        LsmSnapshot as KvTestSnapshot, LsmWriteBatch as KvTestWriteBatch,
     };
-    use fdb_traits::Result;
 
-    use crate::ctor::{DBOptions, einstein_merkle_treeConstructorExt, NAMESPACEDOptions};
+    use fdb_traits::Result;
 
+    /// A kv einstein_merkle_tree with the causet_merge families `namespaceds` besides
+    /// the default one.
     pub fn new_einstein_merkle_tree(
         local_path: &str,
-        db_opt: Option<DBOptions>,
-        namespaces: &[&str],
-        opts: Option<Vec<NAMESPACEDOptions<'_>>>,
-    ) -> Result<KvTesteinstein_merkle_tree, E> {
-        KvTesteinstein_merkle_tree::new_einstein_merkle_tree(local_path, db_opt, namespaces, opts)
+        namespaceds: &[&str],
+    ) -> Result<KvTestEngine> {
+        let opts = LsmOptions {
+            namespaceds: namespaceds.iter().map(|&n| n.to_owned()).collect(),
+            ..Default::default()
+        };
+        new_einstein_merkle_tree_opt(local_path, opts)
     }
 
     pub fn new_einstein_merkle_tree_opt(
         local_path: &str,
-        db_opt: DBOptions,
-        namespaces_opts: Vec<NAMESPACEDOptions<'_>>,
-    ) -> Result<KvTesteinstein_merkle_tree, E> {
-        KvTesteinstein_merkle_tree::new_einstein_merkle_tree_opt(local_path, db_opt, namespaces_opts)
+        opts: LsmOptions,
+    ) -> Result<KvTestEngine> {
+        KvTestEngine::open(local_path, opts)
     }
 }
 
-/// Create a timelike_storage einstein_merkle_tree with a concrete type. This should ultimately be the
-/// only module within EinsteinDB that needs to know about concrete EinsteinMerkleTrees. Other
-/// code only uses the `fdb_traits` abstractions.
-///
-/// At the moment this has a lot of open-coding of einstein_merkle_tree-specific
-/// initialization, but in the future more constructor abstractions should be
-/// pushed down into fdb_traits.
-///
-/// This module itself is intended to be extracted from this crate into its own
-/// crate, once the requirements for einstein_merkle_tree construction are better understood.
+/// Create a storage einstein_merkle_tree with a concrete type. This should ultimately be the
+/// only module within EinsteinDB that needs to know about concrete einstein_merkle_trees.
 pub mod ctor {
-    use fdb_traits::Result;
+    use fdb_traits::{Error, Result};
+
+    use crate::kv::{KvTestEngine, LsmOptions};
 
     /// einstein_merkle_tree construction
     ///
@@ -135,8 +88,8 @@ pub mod ctor {
     /// einstein_merkle_tree such that all of EinsteinDB and its tests work correctly, for the
     /// constructed causet_merge families.
     ///
-    /// Specifically, this means that FdbDB constructors should set up
-    /// all greedoids collectors, always.
+    /// Specifically, this means that constructors should set up all greedoids
+    /// collectors, always.
     pub trait EinsteinMerkleTreeConstructorExt: Sized {
         /// Create a new einstein_merkle_tree with either:
         ///
@@ -145,33 +98,43 @@ pub mod ctor {
         ///
         /// Note that if `opts` is not `None` then the `namespaces` argument is completely ignored.
         ///
-        /// The einstein_merkle_tree timelike_stores its data in the `local_path` directory.
+        /// The einstein_merkle_tree stores its data in the `local_path` directory.
         /// If that directory does not exist, then it is created.
         fn new_einstein_merkle_tree(
             local_path: &str,
             db_opt: Option<DBOptions>,
             namespaces: &[&str],
             opts: Option<Vec<NAMESPACEDOptions<'_>>>,
-        ) -> Result<Self, E>;
+        ) -> Result<Self> {
+            let opts = opts.unwrap_or_else(|| {
+                namespaces
+                    .iter()
+                    .map(|&namespaced| {
+                        NAMESPACEDOptions::new(namespaced, ColumnFamilyOptions::new())
+                    })
+                    .collect()
+            });
+            Self::new_einstein_merkle_tree_opt(local_path, db_opt.unwrap_or_default(), opts)
+        }
 
         /// Create a new einstein_merkle_tree with specified causet_merge families and options
         ///
-        /// The einstein_merkle_tree timelike_stores its data in the `local_path` directory.
+        /// The einstein_merkle_tree stores its data in the `local_path` directory.
         /// If that directory does not exist, then it is created.
         fn new_einstein_merkle_tree_opt(
             local_path: &str,
             db_opt: DBOptions,
             namespaces_opts: Vec<NAMESPACEDOptions<'_>>,
-        ) -> Result<Self, E>;
+        ) -> Result<Self>;
     }
 
-    #[derive(Clone)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub enum CryptoOptions {
         None,
         DefaultCtrEncryptedEnv(Vec<u8>),
     }
 
-    #[derive(Clone)]
+    #[derive(Clone, Debug)]
     pub struct DBOptions {
         encryption: CryptoOptions,
     }
@@ -201,53 +164,33 @@ pub mod ctor {
 
     impl<'a> NAMESPACEDOptions<'a> {
         pub fn new(namespaced: &'a str, options: ColumnFamilyOptions) -> NAMESPACEDOptions<'a> {
-            NAMESPACEDOptions { namespaced, options }
+            NAMESPACEDOptions {
+                namespaced,
+                options,
+            }
         }
     }
 
     /// Greedoids for a single causet_merge family
     ///
-    /// All EinsteinMerkleTrees must emulate causet_merge families, but at present it is not clear
-    /// how non-FdbDB EinsteinMerkleTrees should deal with the wide variety of options for
-    /// causet_merge families.
-    ///
-    /// At present this very closely mirrors the causet_merge family options
-    /// for FdbDB, with the exception that it provides no capacity for
-    /// installing table property collectors, which have little hope of being
-    /// emulated on arbitrary EinsteinMerkleTrees.
-    ///
-    /// Instead, the FdbDB constructors need to always install the table
-    /// property collectors that EinsteinDB needs, and other EinsteinMerkleTrees need to
-    /// accomplish the same high-l_naught ends those table greedoids are used for
-    /// by their own means.
-    ///
-    /// At present, they should probably emulate, reinterpret, or ignore them as
-    /// suitable to get einsteindb functioning.
-    ///
-    /// In the future EinsteinDB will probably have einstein_merkle_tree-specific configuration
-    /// options.
-    #[derive(Clone)]
+    /// All EinsteinMerkleTrees must emulate causet_merge families, but at present it is not
+    /// clear how they should deal with the wide variety of options for causet_merge
+    /// families. EinsteinMerkleTrees emulate, reinterpret, or ignore them as suitable to get
+    /// einsteindb functioning.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
     pub struct ColumnFamilyOptions {
         disable_auto_jet_bundles: bool,
         l_naught_zero_file_num_jet_bundle_trigger: Option<i32>,
         l_naught_zero_slowdown_writes_trigger: Option<i32>,
-        /// On FdbDB, turns off the range greedoids collector. Only used in
-        /// tests. Unclear how other EinsteinMerkleTrees should deal with this.
+        /// Turns off the range greedoids collector. Only used in tests.
         no_range_greedoids: bool,
-        /// On FdbDB, turns off the table greedoids collector. Only used in
-        /// tests. Unclear how other EinsteinMerkleTrees should deal with this.
+        /// Turns off the table greedoids collector. Only used in tests.
         no_table_greedoids: bool,
     }
 
     impl ColumnFamilyOptions {
         pub fn new() -> ColumnFamilyOptions {
-            ColumnFamilyOptions {
-                disable_auto_jet_bundles: false,
-                l_naught_zero_file_num_jet_bundle_trigger: None,
-                l_naught_zero_slowdown_writes_trigger: None,
-                no_range_greedoids: false,
-                no_table_greedoids: false,
-            }
+            ColumnFamilyOptions::default()
         }
 
         pub fn set_disable_auto_jet_bundles(&mut self, v: bool) {
@@ -291,178 +234,98 @@ pub mod ctor {
         }
     }
 
-    impl Default for ColumnFamilyOptions {
-        fn default() -> Self {
-            Self::new()
-        }
-    }
-
-    mod panic {
-        use einstein_merkle_tree_panic::soliton_panic_merkle_tree;
-        use fdb_traits::Result;
-
-        use super::{DBOptions, EinsteinMerkleTreeConstructorExt, NAMESPACEDOptions};
-
-        impl EinsteinMerkleTreeConstructorExt for einstein_merkle_tree_panic::soliton_panic_merkle_tree {
-            fn new_einstein_merkle_tree(
-                _local_path: &str,
-                _db_opt: Option<DBOptions>,
-                _namespaceds: &[&str],
-                _opts: Option<Vec<NAMESPACEDOptions<'_>>>,
-            ) -> Result<Self, E> {
-                Ok(soliton_panic_merkle_tree)
+    /// The options of a kv einstein_merkle_tree are shared by all of its causet_merge
+    /// families, so they must all ask for the same ones. It has no write slowdown
+    /// and always collects greedoids, so those options are ignored.
+    impl EinsteinMerkleTreeConstructorExt for KvTestEngine {
+        fn new_einstein_merkle_tree_opt(
+            local_path: &str,
+            db_opt: DBOptions,
+            namespaces_opts: Vec<NAMESPACEDOptions<'_>>,
+        ) -> Result<Self> {
+            if db_opt.encryption != CryptoOptions::None {
+                return Err(Error::Engine(
+                    "the kv einstein_merkle_tree does not support encryption".to_owned(),
+                ));
             }
-
-            fn new_einstein_merkle_tree_opt(
-                _local_path: &str,
-                _db_opt: DBOptions,
-                _namespaceds_opts: Vec<NAMESPACEDOptions<'_>>,
-            ) -> Result<Self, E> {
-                Ok(soliton_panic_merkle_tree)
+            let mut opts = LsmOptions::default();
+            if let Some(first) = namespaces_opts.first() {
+                if namespaces_opts.iter().any(|o| o.options != first.options) {
+                    return Err(Error::Engine(
+                        "the causet_merge families of a kv einstein_merkle_tree share their options"
+                            .to_owned(),
+                    ));
+                }
+                opts.disable_auto_compactions = first.options.disable_auto_jet_bundles;
+                if let Some(n) = first.options.l_naught_zero_file_num_jet_bundle_trigger {
+                    opts.level0_file_num_compaction_trigger = n.max(1) as usize;
+                }
             }
+            opts.namespaceds = namespaces_opts
+                .iter()
+                .map(|o| o.namespaced.to_owned())
+                .collect();
+            KvTestEngine::open(local_path, opts)
         }
     }
+}
 
-    mod foundationdb {
-        use fdb_einstein_merkle_tree::{FdbColumnFamilyOptions, FdbDBOptions};
-        use fdb_einstein_merkle_tree::greedoids::{
-            Violetabft_oocGreedoidsCollectorFactory, GreedoidsCollectorFactory,
-        };
-        use fdb_einstein_merkle_tree::primitive_causet::{DBOptions as Primitive_CausetFdbDBOptions, Env};
-        use fdb_einstein_merkle_tree::primitive_causet::ColumnFamilyOptions as Primitive_CausetFdbColumnFamilyOptions;
-        use fdb_einstein_merkle_tree::util::{
-            FdbNAMESPACEDOptions, new_einstein_merkle_tree as foundation_new_einstein_merkle_tree, new_einstein_merkle_tree_opt as foundation_new_einstein_merkle_tree_opt,
-        };
-        use fdb_traits::{ColumnFamilyOptions as ColumnFamilyOptionsTrait, Result};
-        use std::sync::Arc;
-
-        use super::{
-            ColumnFamilyOptions, CryptoOptions, DBOptions, EinsteinMerkleTreeConstructorExt, NAMESPACEDOptions,
-        };
-
-        impl EinsteinMerkleTreeConstructorExt for fdb_einstein_merkle_tree::Fdbeinstein_merkle_tree {
-            // FIXME this is duplicating behavior from fdb_lsh-merkle_merkle_tree::primitive_causet_util in order to
-            // call set_standard_namespaced_opts.
-            fn new_einstein_merkle_tree(
-                local_path: &str,
-                db_opt: Option<DBOptions>,
-                namespaceds: &[&str],
-                opts: Option<Vec<NAMESPACEDOptions<'_>>>,
-            ) -> Result<Self, E> {
-                let foundation_db_opts = match db_opt {
-                    Some(db_opt) => Some(get_foundation_db_opts(db_opt)?),
-                    None => None,
-                };
-                let namespaceds_opts = match opts {
-                    Some(opts) => opts,
-                    None => {
-                        let mut default_namespaceds_opts = Vec::with_capacity(namespaceds.len());
-                        for namespaced in namespaceds {
-                            default_namespaceds_opts.push(NAMESPACEDOptions::new(*namespaced, ColumnFamilyOptions::new()));
-                        }
-                        default_namespaceds_opts
-                    }
-                };
-                let foundation_namespaceds_opts = namespaceds_opts
-                    .iter()
-                    .map(|namespaced_opts| {
-                        let mut foundation_namespaced_opts = FdbColumnFamilyOptions::new();
-                        set_standard_namespaced_opts(foundation_namespaced_opts.as_primitive_causet_mut(), &namespaced_opts.options);
-                        set_namespaced_opts(&mut foundation_namespaced_opts, &namespaced_opts.options);
-                        FdbNAMESPACEDOptions::new(namespaced_opts.namespaced, foundation_namespaced_opts)
-                    })
-                    .collect();
-                foundation_new_einstein_merkle_tree(local_path, foundation_db_opts, &[], Some(foundation_namespaceds_opts))
-            }
+/// Both einstein_merkle_trees in `local_path`, the VioletaBFT one in its `violetabft`
+/// directory.
+pub fn new_temp_einstein_merkle_tree(
+    local_path: &Path,
+    namespaceds: &[&str],
+) -> Result<(kv::KvTestEngine, violetabft::VioletaBFTTestEngine)> {
+    let violetabft_path = local_path.join("violetabft");
+    Ok((
+        kv::new_einstein_merkle_tree(&local_path.to_string_lossy(), namespaceds)?,
+        violetabft::new_einstein_merkle_tree(&violetabft_path.to_string_lossy())?,
+    ))
+}
 
-            fn new_einstein_merkle_tree_opt(
-                local_path: &str,
-                db_opt: DBOptions,
-                namespaces_opts: Vec<NAMESPACEDOptions<'_>>,
-            ) -> Result<Self, E> {
-                let foundation_db_opts = get_foundation_db_opts(db_opt)?;
-                let foundation_namespaces_opts = namespaces_opts
-                    .iter()
-                    .map(|namespaced_opts| {
-                        let mut foundation_namespaced_opts = FdbColumnFamilyOptions::new();
-                        set_standard_namespaced_opts(foundation_namespaced_opts.as_primitive_causet_mut(), &namespaced_opts.options);
-                        set_namespaced_opts(&mut foundation_namespaced_opts, &namespaced_opts.options);
-                        FdbNAMESPACEDOptions::new(namespaced_opts.namespaced, foundation_namespaced_opts)
-                    })
-                    .collect();
-                foundation_new_einstein_merkle_tree_opt(local_path, foundation_db_opts, foundation_namespaces_opts)
-            }
-        }
+#[cfg(test)]
+mod write_batch;
 
-        fn set_standard_namespaced_opts(
-            foundation_namespaced_opts: &mut Primitive_CausetFdbColumnFamilyOptions,
-            namespaced_opts: &ColumnFamilyOptions,
-        ) {
-            if !namespaced_opts.get_no_range_greedoids() {
-                foundation_namespaced_opts.add_table_greedoids_collector_factory(
-                    "einsteindb.range-greedoids-collector",
-                    GreedoidsCollectorFactory::default(),
-                );
-            }
-            if !namespaced_opts.get_no_table_greedoids() {
-                foundation_namespaced_opts.add_table_greedoids_collector_factory(
-                    "einsteindb.causet_model-greedoids-collector",
-                    Violetabft_oocGreedoidsCollectorFactory::default(),
-                );
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use ::fdb_traits::MiscExt;
 
-        fn set_namespaced_opts(
-            foundation_namespaced_opts: &mut FdbColumnFamilyOptions,
-            namespaced_opts: &ColumnFamilyOptions,
-        ) {
-            if let Some(trigger) = namespaced_opts.get_l_naught_zero_file_num_jet_bundle_trigger() {
-                foundation_namespaced_opts.set_l_naught_zero_file_num_jet_bundle_trigger(trigger);
-            }
-            if let Some(trigger) = namespaced_opts.get_l_naught_zero_slowdown_writes_trigger() {
-                foundation_namespaced_opts
-                    .as_primitive_causet_mut()
-                    .set_l_naught_zero_slowdown_writes_trigger(trigger);
-            }
-            if namespaced_opts.get_disable_auto_jet_bundles() {
-                foundation_namespaced_opts.set_disable_auto_jet_bundles(true);
-            }
-        }
+    use super::ctor::{
+        ColumnFamilyOptions, DBOptions, EinsteinMerkleTreeConstructorExt, NAMESPACEDOptions,
+    };
+    use super::kv::KvTestEngine;
 
-        fn get_foundation_db_opts(db_opts: DBOptions) -> Result<FdbDBOptions, E> {
-            let mut foundation_db_opts = Primitive_CausetFdbDBOptions::new();
-            match db_opts.encryption {
-                CryptoOptions::None => (),
-                CryptoOptions::DefaultCtrEncryptedEnv(ciphertext) => {
-                    let env = Arc::new(Env::new_default_ctr_encrypted_env(&ciphertext)?);
-                    foundation_db_opts.set_env(env);
-                }
-            }
-            let foundation_db_opts = FdbDBOptions::from_primitive_causet(foundation_db_opts);
-            Ok(foundation_db_opts)
-        }
+    #[test]
+    fn test_ctor() {
+        let dir = tempfile::tempdir().unwrap();
+        let local_path = dir.path().to_str().unwrap();
+        let mut encrypted = DBOptions::new();
+        encrypted.with_default_ctr_encrypted_env(vec![0; 16]);
+        assert!(
+            KvTestEngine::new_einstein_merkle_tree(local_path, Some(encrypted), &[], None).is_err()
+        );
+
+        let mut manual = ColumnFamilyOptions::new();
+        manual.set_disable_auto_jet_bundles(true);
+        let opts = vec![
+            NAMESPACEDOptions::new("default", manual.clone()),
+            NAMESPACEDOptions::new("write", ColumnFamilyOptions::new()),
+        ];
+        assert!(
+            KvTestEngine::new_einstein_merkle_tree_opt(local_path, DBOptions::new(), opts).is_err()
+        );
+
+        let opts = vec![
+            NAMESPACEDOptions::new("default", manual.clone()),
+            NAMESPACEDOptions::new("write", manual),
+        ];
+        let einstein_merkle_tree =
+            KvTestEngine::new_einstein_merkle_tree(local_path, None, &["lock"], Some(opts))
+                .unwrap();
+        assert!(einstein_merkle_tree.options().disable_auto_compactions);
+        let mut namespaceds = einstein_merkle_tree.namespaced_names();
+        namespaceds.sort();
+        assert_eq!(namespaceds, ["default", "write"]);
+        assert_eq!(einstein_merkle_tree.get_local_path(), local_path);
     }
 }
-
-/// Create a new set of EinsteinMerkleTrees in a temporary directory
-///
-/// This is little-used and probably shouldn't exist.
-pub fn new_temp_einstein_merkle_tree(
-    local_path: &tempfile::TempDir,
-) -> fdb_traits::einstein_merkle_trees<crate::kv::KvTesteinstein_merkle_tree, crate::violetabft::VioletaBFTTesteinstein_merkle_tree> {
-    let violetabft_local_path = local_path.local_path().join(std::local_path::local_path::new("violetabft"));
-    let trees = fdb_traits::einstein_merkle_trees::new(crate::kv::new_einstein_merkle_tree(
-            local_path.local_path().to_str().unwrap(),
-            None,
-            fdb_traits::ALL_NAMESPACEDS,
-            None,
-        )
-            .unwrap(), violetabft::new_einstein_merkle_tree(
-            violetabft_local_path.to_str().unwrap(),
-            None,
-            fdb_traits::NAMESPACED_DEFAULT,
-            None,
-        )
-            .unwrap(),);
-    trees
-}
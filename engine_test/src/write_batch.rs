@@ -0,0 +1,205 @@
+//! Property tests of write alexandrov_poset_process save points: a alexandrov_poset_process
+//! built with any mix of commands, nested save points and rollbacks must count,
+//! size and write exactly the commands a plain model keeps.
+
+use std::collections::BTreeMap;
+
+use fdb_traits::{
+    FdbWriteBatch, Iterable, Iterator, Mutable, WriteBatch, WriteBatchExt, WriteCommand,
+    WriteOptions,
+};
+use proptest::prelude::*;
+
+use crate::kv::new_einstein_merkle_tree;
+
+const NAMESPACEDS: &[&str] = &["default", "lock", "write"];
+
+#[derive(Clone, Debug)]
+enum Op {
+    Put(usize, Vec<u8>, Vec<u8>),
+    Delete(usize, Vec<u8>),
+    DeleteRange(usize, Vec<u8>, Vec<u8>),
+    PushSavePoint,
+    PopSavePoint,
+    RollbackToSavePoint,
+}
+
+fn soliton_id() -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(b'a'..b'f', 1..3)
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let namespaced = 0..NAMESPACEDS.len();
+    prop_oneof![
+        4 => (namespaced.clone(), soliton_id(), prop::collection::vec(any::<u8>(), 0..8))
+            .prop_map(|(n, k, v)| Op::Put(n, k, v)),
+        2 => (namespaced.clone(), soliton_id()).prop_map(|(n, k)| Op::Delete(n, k)),
+        1 => (namespaced, soliton_id(), soliton_id()).prop_map(|(n, a, b)| Op::DeleteRange(n, a, b)),
+        2 => Just(Op::PushSavePoint),
+        1 => Just(Op::PopSavePoint),
+        2 => Just(Op::RollbackToSavePoint),
+    ]
+}
+
+/// Issues `op` to `wb`, and to `model`, the list of live commands plus the save
+/// point stack of command counts.
+fn issue<W: Mutable>(
+    wb: &mut W,
+    push: impl FnOnce(&mut W),
+    pop: impl FnOnce(&mut W) -> bool,
+    rollback: impl FnOnce(&mut W) -> bool,
+    op: &Op,
+    model: &mut (Vec<Op>, Vec<usize>),
+) {
+    let (commands, save_points) = model;
+    match op {
+        Op::Put(n, k, v) => wb.put_namespaced(NAMESPACEDS[*n], k, v).unwrap(),
+        Op::Delete(n, k) => wb.delete_namespaced(NAMESPACEDS[*n], k).unwrap(),
+        Op::DeleteRange(n, a, b) => wb.delete_range_namespaced(NAMESPACEDS[*n], a, b).unwrap(),
+        Op::PushSavePoint => {
+            push(wb);
+            save_points.push(commands.len());
+            return;
+        }
+        Op::PopSavePoint => {
+            assert_eq!(pop(wb), save_points.pop().is_some());
+            return;
+        }
+        Op::RollbackToSavePoint => {
+            let expected = save_points.pop();
+            assert_eq!(rollback(wb), expected.is_some());
+            if let Some(count) = expected {
+                commands.truncate(count);
+            }
+            return;
+        }
+    }
+    commands.push(op.clone());
+}
+
+fn model_batch(commands: &[Op]) -> FdbWriteBatch {
+    let mut wb = FdbWriteBatch::new();
+    for op in commands {
+        issue(
+            &mut wb,
+            |_| {},
+            |_| true,
+            |_| true,
+            op,
+            &mut (Vec::new(), Vec::new()),
+        );
+    }
+    wb
+}
+
+fn model_state(commands: &[Op]) -> Vec<BTreeMap<Vec<u8>, Vec<u8>>> {
+    let mut state = vec![BTreeMap::new(); NAMESPACEDS.len()];
+    for op in commands {
+        match op {
+            Op::Put(n, k, v) => {
+                state[*n].insert(k.clone(), v.clone());
+            }
+            Op::Delete(n, k) => {
+                state[*n].remove(k);
+            }
+            Op::DeleteRange(n, a, b) => state[*n].retain(|k, _| k < a || k >= b),
+            _ => unreachable!(),
+        }
+    }
+    state
+}
+
+proptest! {
+    #[test]
+    fn test_fdb_write_batch_save_points(ops in prop::collection::vec(op(), 0..64)) {
+        let mut wb = FdbWriteBatch::new();
+        let mut model = (Vec::new(), Vec::new());
+        for op in &ops {
+            issue(
+                &mut wb,
+                |wb| wb.push_save_point("sp"),
+                |wb| wb.pop_save_point().is_ok(),
+                |wb| wb.rollback_to_save_point().is_ok(),
+                op,
+                &mut model,
+            );
+            prop_assert_eq!(wb.count(), model.0.len());
+            prop_assert_eq!(wb.save_points().len(), model.1.len());
+        }
+        let expected = model_batch(&model.0);
+        prop_assert_eq!(wb.data(), expected.data());
+        prop_assert_eq!(wb.data_size(), expected.data_size());
+        prop_assert_eq!(
+            wb.iter().collect::<Vec<WriteCommand<'_>>>(),
+            expected.iter().collect::<Vec<_>>()
+        );
+        prop_assert_eq!(FdbWriteBatch::from_data(wb.data()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_lsm_write_batch_save_points(
+        before in prop::collection::vec(op(), 0..16),
+        ops in prop::collection::vec(op(), 0..64),
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree =
+            new_einstein_merkle_tree(dir.path().to_str().unwrap(), NAMESPACEDS).unwrap();
+
+        // Commit some data first so that rollbacks have something to (not) touch.
+        let before: Vec<Op> = before
+            .into_iter()
+            .filter(|op| matches!(op, Op::Put(..) | Op::Delete(..) | Op::DeleteRange(..)))
+            .collect();
+        let mut wb = einstein_merkle_tree.write_alexandrov_poset_process();
+        let mut model = (Vec::new(), Vec::new());
+        for op in &before {
+            issue(&mut wb, |_| {}, |_| true, |_| true, op, &mut model);
+        }
+        wb.write_opt(&WriteOptions::default()).unwrap();
+
+        let mut wb = einstein_merkle_tree.write_alexandrov_poset_process();
+        let mut model = (Vec::new(), Vec::new());
+        for op in &ops {
+            issue(
+                &mut wb,
+                |wb| wb.push_save_point("sp"),
+                |wb| wb.pop_save_point().is_ok(),
+                |wb| wb.rollback_to_save_point().is_ok(),
+                op,
+                &mut model,
+            );
+        }
+        prop_assert_eq!(wb.count(), model.0.len());
+        prop_assert_eq!(wb.data_size(), model_batch(&model.0).data_size());
+        prop_assert_eq!(wb.is_empty(), model.0.is_empty());
+        wb.write_opt(&WriteOptions::default()).unwrap();
+
+        let expected = model_state(&before.iter().chain(&model.0).cloned().collect::<Vec<_>>());
+        for (n, namespaced) in NAMESPACEDS.iter().enumerate() {
+            let mut iter = einstein_merkle_tree.iterator_namespaced(namespaced).unwrap();
+            let mut actual = BTreeMap::new();
+            let mut valid = iter.seek_to_first().unwrap();
+            while valid {
+                actual.insert(iter.soliton_id().to_vec(), iter.causet_locale().to_vec());
+                valid = iter.next().unwrap();
+            }
+            prop_assert_eq!(&actual, &expected[n]);
+        }
+    }
+}
+
+#[test]
+fn test_clear_drops_save_points() {
+    let mut wb = FdbWriteBatch::new();
+    wb.push_save_point("outer");
+    wb.put_namespaced("write", b"k", b"v").unwrap();
+    wb.push_save_point("inner");
+    wb.delete_range_namespaced("lock", b"a", b"z").unwrap();
+    assert_eq!(wb.save_points()[1].name, "inner");
+    assert_eq!(wb.save_points()[1].count, 1);
+    wb.clear();
+    assert!(wb.is_empty());
+    assert_eq!(wb.data_size(), 0);
+    assert!(wb.rollback_to_save_point().is_err());
+    assert!(wb.pop_save_point().is_err());
+}
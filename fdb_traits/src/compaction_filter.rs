@@ -0,0 +1,79 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Compaction filters let the layers above the einstein_merkle_tree drop or rewrite
+//! soliton_ids while they are being compacted, e.g. to reclaim expired or
+//! garbage-collected versions without issuing deletes.
+
+use std::sync::Arc;
+
+use crate::Result;
+
+/// What to do with a soliton_id passed to a `CompactionFilter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompactionFilterDecision {
+    Keep,
+    /// Drop the soliton_id. Older versions of it in deeper levels stay hidden.
+    Remove,
+    /// Keep the soliton_id with a new causet_locale.
+    ChangeValue(Vec<u8>),
+}
+
+/// Describes the compaction a filter is created for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionFilterContext<'a> {
+    pub namespaced: &'a str,
+    pub output_level: usize,
+    /// No deeper level holds soliton_ids of the compacted range, so whatever the
+    /// compaction drops is gone for good.
+    pub is_bottommost_level: bool,
+    /// The compaction was requested through `compact_range` rather than picked by
+    /// the einstein_merkle_tree.
+    pub is_manual_compaction: bool,
+}
+
+/// Decides the fate of each soliton_id written by one compaction.
+///
+/// Filters are called in soliton_id order, once per live soliton_id; deletions and
+/// versions shadowed by newer writes never reach them.
+pub trait CompactionFilter: Send {
+    fn filter(
+        &mut self,
+        level: usize,
+        soliton_id: &[u8],
+        causet_locale: &[u8],
+    ) -> CompactionFilterDecision;
+}
+
+/// Creates a fresh `CompactionFilter` for every compaction of a causet_merge family.
+pub trait CompactionFilterFactory: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Returns `None` if soliton_ids of this compaction need no filtering.
+    fn create_compaction_filter(
+        &self,
+        context: &CompactionFilterContext<'_>,
+    ) -> Option<Box<dyn CompactionFilter>>;
+}
+
+/// What the compaction filters of a causet_merge family did since the einstein_merkle_tree
+/// was opened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionFilterStats {
+    /// Soliton_ids passed to a filter.
+    pub keys_filtered: u64,
+    pub keys_removed: u64,
+    pub keys_changed: u64,
+}
+
+/// EinsteinMerkleTrees that run compaction filters.
+pub trait CompactionFilterExt {
+    /// Installs the factory used by later compactions of `namespaced`, replacing the
+    /// previous one. `None` disables filtering.
+    fn set_compaction_filter_factory(
+        &self,
+        namespaced: &str,
+        factory: Option<Arc<dyn CompactionFilterFactory>>,
+    ) -> Result<()>;
+
+    fn compaction_filter_stats(&self, namespaced: &str) -> Result<CompactionFilterStats>;
+}
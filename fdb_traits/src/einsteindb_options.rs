@@ -1,95 +1,83 @@
 // Copyright 2019 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
-pub use allegro_poset::poset::poset::PosetConfig;
-pub use allegro_poset::poset::poset::PosetConfigEntry;
-use std::{
-    cmp,
-    fmt,
-    hash,
-    marker::PhantomData,
-    mem,
-    ptr,
-    slice,
-};
-use std::borrow::Cow;
-use std::collections::{
-    BTreeMap,
-    BTreeSet,
-};
-use std::collections::HashMap;
-use std::fmt::{
-    Display,
-    Formatter,
-    Result as FmtResult,
-};
-use std::fmt;
-use std::iter::FromIterator;
-use std::ops::Deref;
-use std::ops::Deref;
-use std::str::FromStr;
-use std::sync::Arc;
-use std::sync::Arc;
-use std::time::Duration;
+//! Options of an einstein_merkle_tree, and changing them while it runs.
+//!
+//! Every option is known by name, with its causet_locale as a string. Online
+//! options take effect on the running einstein_merkle_tree; the others are only
+//! read when it is opened, and changing them requires a restart.
 
-use crate::{
-    error::{Error, ErrorInner, Result},
-    fdb_traits::{EinsteindbOptions, EinsteindbOptionsExt},
-    util::{
-        config::{Config, ConfigGroup, ConfigValue},
-        to_c_str,
-    },
-};
-use crate::errors::Result;
-use crate::soliton::*;
+use crate::Result;
 
-use super::*;
+/// How a change of an option takes effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// On the running einstein_merkle_tree.
+    Online,
+    /// The next time the einstein_merkle_tree is opened.
+    RestartRequired,
+}
 
-pub use self::config::Config;
-pub use self::config::ConfigEntry;
-pub use self::config::ConfigValue;
-pub use self::config::ConfigValueType;
+/// An option whose causet_locale differs between two sets of options.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionChange {
+    pub name: &'static str,
+    pub old_value: String,
+    pub new_value: String,
+    pub kind: ChangeKind,
+}
 
 /// A trait for EinsteinMerkleTrees that support setting global options
 pub trait EinsteinOptionsSetter {
     type DBOptions: EinsteinDBOptions;
 
+    /// The options the einstein_merkle_tree runs with.
     fn get_db_options(&self) -> Self::DBOptions;
+
+    /// Changes online options by name and persists the effective options. If any
+    /// change fails or requires a restart, none is made.
     fn set_db_options(&self, options: &[(&str, &str)]) -> Result<()>;
+
+    /// Diffs `new` against the running options and applies the online changes.
+    /// Returns every change; those that require a restart are not applied.
+    fn update_db_options(&self, new: &Self::DBOptions) -> Result<Vec<OptionChange>> {
+        let changes = self.get_db_options().diff(new);
+        let online: Vec<(&str, &str)> = changes
+            .iter()
+            .filter(|c| c.kind == ChangeKind::Online)
+            .map(|c| (c.name, c.new_value.as_str()))
+            .collect();
+        if !online.is_empty() {
+            self.set_db_options(&online)?;
+        }
+        Ok(changes)
+    }
 }
 
 /// A handle to a database's options
-pub trait EinsteinDBOptions {
-    type FoundationDB: Client;
-
+pub trait EinsteinDBOptions: Sized {
     fn new() -> Self;
+
+    /// The causet_locale of the option `name`.
+    fn get_option(&self, name: &str) -> Result<String>;
+    fn set_option(&mut self, name: &str, causet_locale: &str) -> Result<()>;
+
+    /// The options whose causet_locales differ between `self` and `new`, and how
+    /// changing each of them from `self` takes effect.
+    fn diff(&self, new: &Self) -> Vec<OptionChange>;
+
+    /// The threads flushes and compactions run on; 0 when they run on the threads
+    /// that write.
     fn get_max_background_jobs(&self) -> i32;
+    /// The rate flushes and compactions write at, `None` for unlimited.
     fn get_rate_bytes_per_sec(&self) -> Option<i64>;
     fn set_rate_bytes_per_sec(&mut self, rate_bytes_per_sec: i64) -> Result<()>;
+    /// Whether the rate limit follows the load, `None` when it can not.
     fn get_rate_limiter_auto_tuned(&self) -> Option<bool>;
     fn set_rate_limiter_auto_tuned(&mut self, rate_limiter_auto_tuned: bool) -> Result<()>;
-    fn set_foundation_dbdb_options(&mut self, opts: &Self::FoundationDB);
-}
-
-
-/// A handle to a database's options
-pub struct EinsteinDBOptionsImpl {
-    pub max_background_jobs: i32,
-    pub rate_bytes_per_sec: Option<i64>,
-    pub rate_limiter_auto_tuned: Option<bool>,
-    pub foundation_dbdb_options: Option<Arc<FoundationDB>>,
-}
-
-/// Now we can implement the `EinsteinDBOptions` trait for `EinsteinDBOptionsImpl`.
-/// This is where we implement the actual options.
-
-//interlock_guard_mutex_lock_timeout_ms: i32
-//interlock_guard_mutex_lock_timeout_ms: i32
-//interlock_guard_mutex_lock_timeout_ms: i32
-
-
-pub struct EinsteinDBOptionsImplBuilder {
-    pub max_background_jobs: i32,
-    pub rate_bytes_per_sec: Option<i64>,
-    pub rate_limiter_auto_tuned: Option<bool>,
-    pub foundation_dbdb_options: Option<Arc<FoundationDB>>,
+    /// The capacity of the block cache, `None` without one.
+    fn get_block_cache_capacity(&self) -> Option<usize>;
+    fn set_block_cache_capacity(&mut self, capacity: usize) -> Result<()>;
+    /// The size memtables are flushed at.
+    fn get_write_buffer_size(&self) -> usize;
+    fn set_write_buffer_size(&mut self, size: usize) -> Result<()>;
 }
@@ -1,300 +1,202 @@
-/// Copyright 2022 The EinsteinDB Authors. All rights reserved.
-/// Use of this source code is governed by a MIT-style
-/// license that can be found in the LICENSE file.
-/// @authors   CavHack, Slushie, imcustodian, robertmc, luojunqing, dqxw, wangjiezhe
-/// @license   MIT License
-/// @brief     The file is used for encryption and decryption.
-/// @version   0.1
-/// @date      2020-03-01
-/// @file      encryption.rs
-
-
-use std::{
-    fs::{self, File},
-    io::{self, Read, Write},
-    path::Path,
-    str::FromStr,
-};
-
-
-use crate::*;
-use std::fs::{self, File};
-use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
-use std::path::{Path, PathBuf};
-
-
-use std::sync::Arc;
-use std::time::{Duration, SystemTime};
-
-
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{channel, Receiver, Sender};
-use std::thread;
-
-
-
-
-//fdb_opts
-//             compression_strategy: String::from("default"),
-//             compression_dict: Vec::new(),
-//             enable_statistics: false,
-//             statistics_interval: 0,
-//             statistics_block_size: 0,
-//             statistics_block_cache_size: 0,
-//             statistics_block_cache_shard_bits: 0,
-//              
-//
-
-
-
-/*
-Encrypted storage. What are the tradeoffs to using SQLCipher instead of sqlite?
-It's a trivial change that gives us pervasive encryption of all data, 
-but we don't know the performance implications. 
-
-If we ATTACH two databases to encrypt only some data (per-attribute encryption), 
-do we over-complicate querying or handling the transaction log, and are we able to transact mixed collections of causets atomically?
-*/
-
-
-
-use std::mem;
-use std::ptr::copy_nonoverlapping;
-
-#[allow(non_camel_case_types)]
-#[repr(simd)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub(crate) struct u64x2(pub u64, pub u64);
-
-impl u64x2 {
-    
-    #[inline]
-    pub(crate) fn new(a: u64, b: u64) -> Self {
-        u64x2(a, b)
-    }
-
-    #[inline]
-    pub(crate) fn lo(self) -> u64 {
-        self.0
-    }
-
-    #[inline]
-    pub(crate) fn hi(self) -> u64 {
-        self.1
-    }
+// Copyright 2022 The EinsteinDB Authors. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+
+//! The interface between an einstein_merkle_tree and the manager of the keys
+//! its files are encrypted with.
+//!
+//! Files are encrypted with AES in counter mode: each file has its own key
+//! and initial counter block, and the block at byte `offset` is encrypted with
+//! the initial counter plus `offset / 16`, so any part of a file can be read
+//! or rewritten without the rest.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+/// The AES block size, which is also the size of a CTR counter block.
+pub const AES_BLOCK_SIZE: usize = 16;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EncryptionMethod {
+    #[default]
+    Plaintext,
+    Aes128Ctr,
+    Aes192Ctr,
+    Aes256Ctr,
+}
 
-    #[inline(always)]
-    pub fn read(src: &[u8; 16]) -> Self {
-        let mut tmp = mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            copy_nonoverlapping(src.as_ptr(), tmp.as_mut_ptr(), 16);
-            let tmp = tmp.assume_init();
-            tmp
-        
+impl EncryptionMethod {
+    /// The length of the keys of the method, in bytes.
+    pub fn key_length(self) -> usize {
+        match self {
+            EncryptionMethod::Plaintext => 0,
+            EncryptionMethod::Aes128Ctr => 16,
+            EncryptionMethod::Aes192Ctr => 24,
+            EncryptionMethod::Aes256Ctr => 32,
         }
-
-
-    }
-
-     /// Write U64x2 content into array pointer (potentially unaligned)
-     /// # Safety
-     /// This is unsafe because it writes to an unaligned pointer.
-     /// The caller must ensure that the pointer is valid.
-     /// The function has no other safety requirements.
-     /// # Examples
-     /// ```
-     /// use einstein_sqlite::U64x2;
-     /// let mut dest = [0u8; 16];
-     /// let src = U64x2::new(0x1122334455667788, 0x99AABBCCDDEEFF00);
-     /// unsafe {
-     ///    einstein_sqlite::U64x2::write(&mut dest, src);
-     /// 
-     ///    
-#[inline(always)]
-    pub unsafe fn write(dest: &mut [u8; 16], src: Self) {
-        copy_nonoverlapping(src.as_ptr(), dest.as_mut_ptr(), 16);
     }
+}
 
-    #[inline(always)]
-    pub fn write_to_slice(&self, dest: &mut [u8]) {
-        unsafe {
-            copy_nonoverlapping(self.as_ptr(), dest.as_mut_ptr(), 16);
+impl FromStr for EncryptionMethod {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "plaintext" => Ok(EncryptionMethod::Plaintext),
+            "aes128-ctr" => Ok(EncryptionMethod::Aes128Ctr),
+            "aes192-ctr" => Ok(EncryptionMethod::Aes192Ctr),
+            "aes256-ctr" => Ok(EncryptionMethod::Aes256Ctr),
+            _ => Err(Error::Encryption(format!(
+                "unknown encryption method {}",
+                s
+            ))),
         }
     }
-
-    #[inline(always)]
-    pub fn as_ptr(&self) -> *const u64 {
-        self as *const Self as *const u64
-    }
-
-    #[inline(always)]
-    pub fn as_mut_ptr(&mut self) -> *mut u64 {
-        self as *mut Self as *mut u64
-    }
-
 }
 
-
-
-pub(crate) fn aesenc(block: &mut u64x2, rkey: &u64x2) {
-    let mut tmp = u64x2::read(block);
-    tmp.0 ^= rkey.0;
-
-    unsafe {
-        copy_nonoverlapping(tmp.as_ptr(), block.as_mut_ptr(), 16);
-    }
-
-    tmp.1 ^= rkey.1;
-    unsafe {
-        llvm_asm!("aesenc $0, $1"
-            : "+x"(*block)
-            : "x"(*rkey)
-            :
-            : "intel", "alignstack"
-
-        );
-        
-
-    
+impl Display for EncryptionMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EncryptionMethod::Plaintext => "plaintext",
+            EncryptionMethod::Aes128Ctr => "aes128-ctr",
+            EncryptionMethod::Aes192Ctr => "aes192-ctr",
+            EncryptionMethod::Aes256Ctr => "aes256-ctr",
+        };
+        f.write_str(name)
     }
 }
 
-#[inline(always)]
-pub(crate) fn aesenclast(block: &mut u64x2, rkey: &u64x2) {
-    let mut tmp = u64x2::read(block);
-    tmp.0 ^= rkey.0;
-
-    unsafe {
-        llvm_asm!("aesenclast $0, $1"
-            : "+x"(*block)
-            : "x"(*rkey)
-            :
-            : "intel", "alignstack"
-        );
-    }
+/// How a file is encrypted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileEncryptionInfo {
+    pub method: EncryptionMethod,
+    pub key: Vec<u8>,
+    /// The initial counter block.
+    pub iv: Vec<u8>,
 }
 
-macro_rules! aeskeygenassist {
-    ($r:expr, $a:expr) => {
-        unsafe {
-            llvm_asm!("aeskeygenassist $0, $1"
-                : "+x"(*$r)
-                : "x"($a)
-                :
-                : "intel", "alignstack"
-            );
+impl FileEncryptionInfo {
+    pub fn new(method: EncryptionMethod, key: Vec<u8>, iv: Vec<u8>) -> Result<Self> {
+        if key.len() != method.key_length() {
+            return Err(Error::Encryption(format!(
+                "{} needs a {} byte key, got {}",
+                method,
+                method.key_length(),
+                key.len()
+            )));
         }
-    };
-}
-
-
-#[inline(always)]
-pub(crate) fn aeskeygenassist(r: &mut u64x2, a: u64) {
-    aeskeygenassist!(r, a);
-}
-
-
-
-
-
-#[inline(always)]
-pub(crate) fn aesroundlast(block: &mut u64x2, rkey: &u64x2) {
-    unsafe {
-        llvm_asm!("aesroundlast $0, $1"
-            : "+x"(*block)
-            : "x"(*rkey)
-            :
-            : "intel", "alignstack"
-        );
-    }
-}
-
-
-#[inline(always)]
-pub(crate) fn aesimc(block: &mut u64x2) {
-    let mut tmp = u64x2::read(block);
-    tmp.0 ^= rkey.0;
-
-        unsafe {
-            llvm_asm!("aesenc $0, $1"
-                : "+x"(*block)
-                : "x"(*rkey)
-                :
-                : "intel", "alignstack"
-            );
+        if method != EncryptionMethod::Plaintext && iv.len() != AES_BLOCK_SIZE {
+            return Err(Error::Encryption(format!(
+                "{} needs a {} byte iv, got {}",
+                method,
+                AES_BLOCK_SIZE,
+                iv.len()
+            )));
         }
+        Ok(FileEncryptionInfo { method, key, iv })
+    }
 
-    tmp.1 ^= rkey.1;
-    unsafe {
-        llvm_asm!("aesenc $0, $1"
-            : "+x"(*block)
-            : "x"(*rkey)
-            :
-            : "intel", "alignstack"
-        );
-
-
+    pub fn is_empty(&self) -> bool {
+        self.method == EncryptionMethod::Plaintext
     }
 
+    /// The counter block to encrypt the block at byte `offset` of the file with.
+    pub fn counter_at(&self, offset: u64) -> Result<[u8; AES_BLOCK_SIZE]> {
+        let iv: &[u8; AES_BLOCK_SIZE] = self
+            .iv
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::Encryption("the file is not encrypted".to_owned()))?;
+        let mut counter = [0; AES_BLOCK_SIZE];
+        u64x2::read(iv)
+            .add_offset(offset)
+            .write_to_slice(&mut counter);
+        Ok(counter)
+    }
+}
 
+/// Hands out the keys of the files of an einstein_merkle_tree and keeps
+/// track of them as files are created, renamed and deleted.
+pub trait EncryptionKeyManager: Sync + Send {
+    /// How the file at `fname` is encrypted; `Plaintext` if the manager does
+    /// not know it.
+    fn get_file(&self, fname: &str) -> Result<FileEncryptionInfo>;
+    /// Makes a new key for the file about to be created at `fname`.
+    fn new_file(&self, fname: &str) -> Result<FileEncryptionInfo>;
+    fn delete_file(&self, fname: &str) -> Result<()>;
+    /// Records that `dst_fname` is a hard link to `src_fname`, sharing its key.
+    fn link_file(&self, src_fname: &str, dst_fname: &str) -> Result<()>;
 }
 
+/// A 128-bit big-endian counter, as a pair of the high and the low 64 bits.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct u64x2(pub u64, pub u64);
 
-#[inline(always)]
-pub(crate) fn aesimclast(block: &mut u64x2) {
-    let mut dst = mem::MaybeUninit::<u64x2>::uninit();
-        unsafe {
-            llvm_asm!("aeskeygenassist $0, $1, $2"
-                    : "+x"(*dst.as_mut_ptr())
-                    : "x"(*$src), "i"($i)
-                    :
-                    : "intel", "alignstack"
-                );
-            dst.assume_init()
-        }
+impl u64x2 {
+    /// Reads u64x2 from a 16-byte big-endian array.
+    pub fn read(src: &[u8; AES_BLOCK_SIZE]) -> Self {
+        let (hi, lo) = src.split_at(8);
+        u64x2(
+            u64::from_be_bytes(hi.try_into().unwrap()),
+            u64::from_be_bytes(lo.try_into().unwrap()),
+        )
     }
 
-    #[inline(always)]
-    pub(crate) fn aesimcround(block: &mut u64x2, rkey: &u64x2) {
-        unsafe {
-            llvm_asm!("aesround $0, $1"
-                : "+x"(*block)
-                : "x"(*rkey)
-                :
-                : "intel", "alignstack"
-            );
-        }
-
+    /// Writes u64x2 to the first 16 bytes of `dest`, big-endian.
+    pub fn write_to_slice(&self, dest: &mut [u8]) {
+        dest[..8].copy_from_slice(&self.0.to_be_bytes());
+        dest[8..AES_BLOCK_SIZE].copy_from_slice(&self.1.to_be_bytes());
     }
 
-    #[inline(always)]
-    pub(crate) fn aesimcroundlast(block: &mut u64x2, rkey: &u64x2) {
-        let mut tmp = u64x2::read(block);
-        tmp.0 ^= rkey.0;
-        unsafe {
-            llvm_asm!("aesenclast $0, $1"
-                : "+x"(*block)
-                : "x"(*rkey)
-                :
-                : "intel", "alignstack"
-            );
-        }
+    /// Advances the counter to the block holding byte `offset`, wrapping
+    /// around at 2^128 like AES-CTR does.
+    pub fn add_offset(self, offset: u64) -> Self {
+        let (lo, carry) = self.1.overflowing_add(offset / AES_BLOCK_SIZE as u64);
+        u64x2(self.0.wrapping_add(carry as u64), lo)
     }
+}
 
-
-    #[inline(always)]
-    pub(crate) fn aesround(block: &mut u64x2, rkey: &u64x2) {
-        let mut tmp = u64x2::read(block);
-        tmp.0 ^= rkey.0;
-        unsafe {
-            llvm_asm!("aesenc $0, $1"
-                : "+x"(*block)
-                : "x"(*rkey)
-                :
-                : "intel", "alignstack"
-            );
-            dst.assume_init()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_at() {
+        let mut iv = vec![0; AES_BLOCK_SIZE];
+        iv[8..].copy_from_slice(&u64::MAX.to_be_bytes());
+        let info = FileEncryptionInfo::new(EncryptionMethod::Aes128Ctr, vec![1; 16], iv).unwrap();
+
+        let counter = info.counter_at(15).unwrap();
+        assert_eq!(counter.as_slice(), info.iv.as_slice());
+
+        // The low half carries into the high half.
+        let counter = info.counter_at(16).unwrap();
+        let mut expected = [0; AES_BLOCK_SIZE];
+        expected[7] = 1;
+        assert_eq!(counter, expected);
+
+        assert!(FileEncryptionInfo::default().counter_at(0).is_err());
+    }
+
+    #[test]
+    fn test_method() {
+        for m in [
+            EncryptionMethod::Plaintext,
+            EncryptionMethod::Aes128Ctr,
+            EncryptionMethod::Aes192Ctr,
+            EncryptionMethod::Aes256Ctr,
+        ] {
+            assert_eq!(m.to_string().parse::<EncryptionMethod>().unwrap(), m);
         }
+        assert!(
+            FileEncryptionInfo::new(EncryptionMethod::Aes256Ctr, vec![0; 16], vec![0; 16]).is_err()
+        );
+        assert!(
+            FileEncryptionInfo::new(EncryptionMethod::Aes192Ctr, vec![0; 24], vec![0; 8]).is_err()
+        );
+        assert!(FileEncryptionInfo::default().is_empty());
     }
-
+}
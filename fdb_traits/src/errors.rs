@@ -1,273 +1,131 @@
-/// Copyright (c) 2015-2020, EinsteinDB Project Authors. Licensed under Apache-2.0.
-/// Copyright (c) 2021, The EinsteinDB Authors. Licensed under Apache-2.0.
-/// Copyright (c) 2022, The EinsteinDB Authors. Licensed under Apache-2.0.
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
-///! # EinsteinDB
-/// # ----------------------------------------------------------------
-/// This is the EinsteinDB Rust API.
-/// The EinsteinDB Rust API is a low-level API for interacting with EinsteinDB.
-/// It is designed to be used by other languages and libraries.
-/// # ----------------------------------------------------------------
-/// # Examples
-/// ```
-/// use einstein_db::*;
-/// use einstein_db::config::Config;
-/// use einstein_db::fdb_traits::*;
-///
-/// let config = Config::new();
-/// let db = FdbTransactional::new(&config).unwrap();
-/// let mut db = db.begin_transaction().unwrap();
-///
-///
-///  let mut db = FdbTransactional::new(&config).unwrap();
-/// let mut db = db.begin_transaction().unwrap();
-///
-/// if let Err(e) = db.commit() {
-///    println!("{}", e);
-/// } else {
-///   println!("Committed");
-/// }
-/// end_transaction(&mut db).unwrap();
+use crate::schema::{Causetid, TypedValue, ValueType};
 
-
-/// protobuf-like traits for fdb_traits
-/// Language: rust
-///
-///
-///
-
-
-
-
-
-
-use std::{
-    cmp,
-    fmt,
-    hash,
-    marker::PhantomData,
-    mem,
-    ptr,
-    slice,
-};
-use std::borrow::Cow;
-use std::collections::{
-    BTreeMap,
-    BTreeSet,
-};
-use std::collections::HashMap;
-use std::fmt::{
-    Display,
-    Formatter,
-    Result as FmtResult,
-};
-use std::iter::FromIterator;
-use std::ops::Deref;
-use crate::fdb_traits::CausetQErrorKind;
-
-
-
-
-
-
-///! Copyright (c) 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
-//! Licensed under the Apache License, Version 2.0 (the "License"); you may not used this file: {}", path.as_ref().display()
-//! except in compliance with the License. You may obtain a copy of the License atomic_refs_and_arcs.rs", path.as_ref().display()
-///! Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the specific language governing permissions and limitations under the License.
-//! @file: {}", path.as_ref().display()
-//! @license: {}", path.as_ref().display()
-
-
-//     let mut causet_locale_sets: BTreeMap<Causetid, BTreeSet<Causetid>> = BTreeMap::default();
-// attributes.iter().for_each(|(a, attribute)| {
-//     for (e, ars) in attribute.evs.iter() {
-//         for v in ars.add.iter().chain(ars.retract.iter()) {
-//             let mut causet_locale_set = causet_locale_sets.entry(a).or_default();
-//             if !causet_locale_set.insert(v.causet_locale) {
-//                 errors.push(CardinalityConflict {
-//                     attribute,
-//                     e,
-//                     v,
-//                 });
-//             }
-//         }
-//     }
-// });
-//     errors
-// }
-//
-// }
-//
-// }
-
-
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A causet given several causet_locales of an attribute with cardinality one.
+#[derive(Clone, Debug, PartialEq)]
 pub struct CardinalityConflict {
     pub attribute: Causetid,
     pub e: Causetid,
-    pub v: Causetq_TV,
+    pub v: TypedValue,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A causet_locale whose type is not the one of its attribute.
+#[derive(Clone, Debug, PartialEq)]
 pub struct TypeDisagreement {
     pub attribute: Causetid,
     pub e: Causetid,
-    pub v: Causetq_TV,
+    pub v: TypedValue,
     pub expected_type: ValueType,
 }
 
-
-
-
-
-//Here we define the type of the errors that can be returned by the `CausetQ` trait.
-//This is a simple wrapper around the `std::error::Error` trait.
-//The `CausetQ` trait is implemented for `CausetQError` and `causet_qerror_kind`.
-
-
-//Here we define the type of the errors that can be returned by the `CausetQ` trait.
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CausetQError {
-    pub kind: CausetQErrorKind,
-
-    pub causet: Option<Box<dyn std::error::Error + Send + Sync>>,
-
-
-
+/// The error returned by storage einstein_merkle_tree implementations.
+#[derive(Debug)]
+pub enum Error {
+    /// A failure reported by the einstein_merkle_tree itself.
+    Engine(String),
+    /// Persisted data failed a checksum or could not be decoded.
+    Corruption(String),
+    /// The named causet_merge family does not exist.
+    NamespacedName(String),
+    /// The soliton_id is outside the brane it was routed to.
+    NotInRange {
+        soliton_id: Vec<u8>,
+        brane_id: u64,
+        start: Vec<u8>,
+        end: Vec<u8>,
+    },
+    /// An assertion about the topograph that would make it invalid.
+    BadTopographAssertion(String),
+    UnrecognizedSolitonid(String),
+    UnrecognizedCausetid(Causetid),
+    /// A causet_locale that cannot be coerced to the type of its attribute.
+    BadValuePair(String, ValueType),
+    /// Two vocabularies define the same attribute differently.
+    ConflictingAttributeDefinitions {
+        vocabulary: String,
+        version: u32,
+        attribute: String,
+    },
+    /// The store holds a newer version of the vocabulary than the one installed.
+    ExistingVocabularyTooNew {
+        name: String,
+        existing: u32,
+        ours: u32,
+    },
+    UnexpectedCoreTopograph(String),
+    InvalidVocabularyVersion,
+    /// A failure to encrypt or decrypt data, or to manage its keys.
+    Encryption(String),
+    Io(std::io::Error),
+    Other(Box<dyn std::error::Error + Send + Sync>),
 }
 
-//Here we define the type of the errors that can be returned by the `CausetQ` trait.
-//This is a simple wrapper around the `std::error::Error` trait.
-
-pub mod causet_qerror_kind {
-    //Here we define the type of the errors that can be returned by the `CausetQ` trait.
-    //This is a simple wrapper around the `std::error::Error` trait.
-    //The `CausetQ` trait is implemented for `CausetQError` and `causet_qerror_kind`.
-    use std::error::Error;
-    use std::fmt;
-    use std::fmt::Display;
-    use std::fmt::Formatter;
-    use std::fmt::Result;
-    use std::sync::Arc;
-
-    use crate::fdb_traits::CausetQErrorKind;
-    use crate::fdb_traits::CausetQError;
-    use crate::fdb_traits::CausetQErrorKind::{
-        CausetQErrorKind,
-        CausetQErrorKind,
-    };
-
-    use crate::fdb_traits::CausetQErrorKind::{
-        CausetQErrorKind,
-        CausetQErrorKind,
-    };
-
-    use crate::fdb_traits::CausetQErrorKind::{
-        CausetQErrorKind,
-        CausetQErrorKind,
-    };
-
-    use crate::fdb_traits::CausetQErrorKind::{
-        CausetQErrorKind,
-        CausetQErrorKind,
-    };
-
-    use crate::fdb_traits::CausetQErrorKind::{
-        CausetQErrorKind,
-        CausetQErrorKind,
-    };
-
-
-}
-
-
-
-
-///! Errors that can be returned by the `CausetQ` trait.
-/// This is a simple wrapper around the `std::error::Error` trait.
-/// The `CausetQ` trait is implemented for `CausetQError` and `causet_qerror_kind`.
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CausetQErrorKind {
-    pub kind: CausetQErrorKind,
-    pub causet: Option<Box<dyn std::error::Error + Send + Sync>>,
-}
-
-
-impl CausetQErrorKind {
-    pub fn new(kind: CausetQErrorKind) -> Self {
-        CausetQErrorKind {
-            kind,
-            causet: None,
-        }
-    }
-}
-
-
-
-
-
-
-impl CausetQErrorKind {
-    pub fn new_with_causet(kind: CausetQErrorKind, causet: Box<dyn std::error::Error + Send + Sync>) -> Self {
-        CausetQErrorKind {
-            kind,
-            causet: Some(causet),
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::Engine(msg) => write!(f, "storage einstein_merkle_tree error: {}", msg),
+            Error::Corruption(msg) => write!(f, "corruption: {}", msg),
+            Error::NamespacedName(name) => write!(f, "invalid causet_merge family {}", name),
+            Error::NotInRange {
+                soliton_id,
+                brane_id,
+                start,
+                end,
+            } => write!(
+                f,
+                "soliton_id {:?} is not in brane {} [{:?}, {:?})",
+                soliton_id, brane_id, start, end
+            ),
+            Error::BadTopographAssertion(msg) => write!(f, "bad topograph assertion: {}", msg),
+            Error::UnrecognizedSolitonid(solitonid) => write!(f, "no causetid found for solitonid: {}", solitonid),
+            Error::UnrecognizedCausetid(causetid) => write!(f, "no solitonid found for causetid: {}", causetid),
+            Error::BadValuePair(v, t) => write!(f, "causet_locale '{}' is not the expected EinsteinDB causet_locale type {}", v, t),
+            Error::ConflictingAttributeDefinitions {
+                vocabulary,
+                version,
+                attribute,
+            } => write!(
+                f,
+                "vocabulary {}/{} conflicts with the existing definition of attribute {}",
+                vocabulary, version, attribute
+            ),
+            Error::ExistingVocabularyTooNew { name, existing, ours } => write!(
+                f,
+                "existing vocabulary {} is version {}, newer than ours ({})",
+                name, existing, ours
+            ),
+            Error::UnexpectedCoreTopograph(msg) => write!(f, "core topograph: {}", msg),
+            Error::InvalidVocabularyVersion => write!(f, "invalid vocabulary version"),
+            Error::Encryption(msg) => write!(f, "encryption error: {}", msg),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::Other(e) => write!(f, "{}", e),
         }
     }
 }
 
-
-impl CausetQErrorKind {
-    pub fn new_with_causet_and_cause(kind: CausetQErrorKind, causet: Box<dyn std::error::Error + Send + Sync>, cause: Box<dyn std::error::Error + Send + Sync>) -> Self {
-        CausetQErrorKind {
-            kind,
-            causet: Some(causet),
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Other(e) => Some(e.as_ref()),
+            _ => None,
         }
     }
 }
 
-
-impl CausetQErrorKind {
-    pub fn new_with_causet_and_cause_and_cause(kind: CausetQErrorKind, causet: Box<dyn std::error::Error + Send + Sync>, cause: Box<dyn std::error::Error + Send + Sync>, cause2: Box<dyn std::error::Error + Send + Sync>) -> Self {
-        CausetQErrorKind {
-            kind,
-            causet: Some(causet),
-        }
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Io(e)
     }
 }
 
-
-impl CausetQErrorKind {
-    pub fn new_with_causet_and_cause_and_cause_and_cause(kind: CausetQErrorKind, causet: Box<dyn std::error::Error + Send + Sync>, cause: Box<dyn std::error::Error + Send + Sync>, cause2: Box<dyn std::error::Error + Send + Sync>, cause3: Box<dyn std::error::Error + Send + Sync>) -> Self {
-        CausetQErrorKind {
-            kind,
-            causet: Some(causet),
-        }
+impl From<String> for Error {
+    fn from(msg: String) -> Error {
+        Error::Engine(msg)
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-pub struct CausetQErrorKindSerialized {
-    pub kind: String,
-    pub causet: Option<String>,
-}
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CausetQErrorKindDeserialized {
-    pub kind: CausetQErrorKind,
-    pub causet: Option<Box<dyn std::error::Error + Send + Sync>>,
-}
-
-
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,33 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use crate::{ExternalSstFileInfo, Result};
+
+/// How ingested soliton_ids relate to the data already in the causet_merge family.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportMode {
+    /// The range of every file must be free of existing data.
+    #[default]
+    Insert,
+    /// Ingested soliton_ids shadow existing versions of them.
+    Overwrite,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IngestExternalFileOptions {
+    pub mode: ImportMode,
+    /// Compare the CRC32 of each file with the one of its `ExternalSstFileInfo`.
+    pub verify_checksum: bool,
+}
+
+pub trait ImportExt {
+    /// Ingests `files` into `namespaced`. Either all files are ingested or none is.
+    ///
+    /// The files must not overlap each other. They are copied, the caller remains
+    /// responsible for removing them.
+    fn ingest_external_file_namespaced(
+        &self,
+        namespaced: &str,
+        opts: &IngestExternalFileOptions,
+        files: &[ExternalSstFileInfo],
+    ) -> Result<()>;
+}
@@ -0,0 +1,91 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Iteration over einstein_merkle_trees and snapshots.
+//!
+//! Iterators read a consistent view: iterating over an einstein_merkle_tree behaves as if
+//! a snapshot was taken when the iterator was created. An iterator is _invalid_ when
+//! it is not positioned at a soliton_id, after moving past either end of its range or
+//! seeking to a position with nothing in it; a seek makes it valid again.
+
+use crate::{IterOptions, Result, NAMESPACED_DEFAULT};
+
+/// Where a seek positions an iterator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeekKey<'a> {
+    /// The first soliton_id of the iterator's range.
+    Start,
+    /// The last soliton_id of the iterator's range.
+    End,
+    /// With `seek`, the first soliton_id `>=` it; with `seek_for_prev`, the last
+    /// soliton_id `<=` it.
+    Key(&'a [u8]),
+}
+
+pub trait Iterator: Send {
+    /// Positions the iterator at `soliton_id`, or the first soliton_id after it. Returns
+    /// whether the iterator is valid.
+    fn seek(&mut self, soliton_id: SeekKey<'_>) -> Result<bool>;
+
+    /// Positions the iterator at `soliton_id`, or the last soliton_id before it.
+    fn seek_for_prev(&mut self, soliton_id: SeekKey<'_>) -> Result<bool>;
+
+    fn seek_to_first(&mut self) -> Result<bool> {
+        self.seek(SeekKey::Start)
+    }
+
+    fn seek_to_last(&mut self) -> Result<bool> {
+        self.seek_for_prev(SeekKey::End)
+    }
+
+    fn prev(&mut self) -> Result<bool>;
+
+    #[allow(clippy::should_implement_trait)]
+    fn next(&mut self) -> Result<bool>;
+
+    fn valid(&self) -> bool;
+
+    /// The current soliton_id. Panics if the iterator is invalid.
+    fn soliton_id(&self) -> &[u8];
+
+    /// The current causet_locale. Panics if the iterator is invalid.
+    fn causet_locale(&self) -> &[u8];
+}
+
+pub trait Iterable {
+    type Iterator: Iterator;
+
+    fn iterator_opt(&self, namespaced: &str, opts: IterOptions) -> Result<Self::Iterator>;
+
+    fn iterator(&self) -> Result<Self::Iterator> {
+        self.iterator_opt(NAMESPACED_DEFAULT, IterOptions::default())
+    }
+
+    fn iterator_namespaced(&self, namespaced: &str) -> Result<Self::Iterator> {
+        self.iterator_opt(namespaced, IterOptions::default())
+    }
+
+    /// Calls `f` on each pair of `[start, end)` in order, until it returns `false`.
+    fn scan_namespaced<F>(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+        fill_cache: bool,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&[u8], &[u8]) -> Result<bool>,
+    {
+        let mut opts = IterOptions::new(Some(start.to_vec()), Some(end.to_vec()));
+        opts.set_fill_cache(fill_cache);
+        let mut iter = self.iterator_opt(namespaced, opts)?;
+        let mut valid = iter.seek_to_first()?;
+        while valid {
+            if !f(iter.soliton_id(), iter.causet_locale())? {
+                break;
+            }
+            valid = iter.next()?;
+        }
+        Ok(())
+    }
+}
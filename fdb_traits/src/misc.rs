@@ -0,0 +1,98 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use crate::{Result, NAMESPACED_DEFAULT};
+
+/// How `MiscExt::delete_all_in_range` removes a range of soliton_ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteStrategy {
+    /// Scans the range and deletes its soliton_ids one by one. Cheap when the range
+    /// holds few soliton_ids.
+    DeleteByKey,
+    /// Writes a single range tombstone. Reads skip the range right away; the space
+    /// is reclaimed by compactions.
+    DeleteByRange,
+    /// Drops the SST files that lie entirely in the range. The memtable and files
+    /// that straddle the bounds of the range are not touched, so soliton_ids of the
+    /// range may be left, and older versions of them may show again. Usually
+    /// followed by another strategy.
+    DeleteFiles,
+    /// Drops the SST files that lie entirely in the range and rewrites the ones that
+    /// straddle its bounds without it, after flushing the memtable if it overlaps
+    /// the range. The space is reclaimed right away.
+    DeleteByWriter,
+}
+
+/// Maintenance and introspection of an einstein_merkle_tree that does not fit
+/// the other traits.
+pub trait MiscExt {
+    /// Flushes the memtables of all causet_merge families. With `sync`, returns once
+    /// the flushed data is persisted.
+    fn flush(&self, sync: bool) -> Result<()>;
+
+    fn flush_namespaced(&self, namespaced: &str, sync: bool) -> Result<()>;
+
+    /// Deletes the soliton_ids of `[start, end)` from `namespaced`, as `strategy`
+    /// describes.
+    fn delete_all_in_range_namespaced(
+        &self,
+        namespaced: &str,
+        strategy: DeleteStrategy,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<()>;
+
+    fn delete_all_in_range(
+        &self,
+        strategy: DeleteStrategy,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<()> {
+        self.delete_all_in_range_namespaced(NAMESPACED_DEFAULT, strategy, start, end)
+    }
+
+    /// The number of entries and of versions of `[start, end)`, see `RangeStats`, or
+    /// `None` if the range is empty.
+    fn get_range_entries_and_versions(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<Option<(u64, u64)>>;
+
+    /// The total size of the SST files and memtables of all causet_merge families.
+    fn get_einstein_merkle_tree_used_size(&self) -> Result<u64>;
+
+    /// Roughly deletes the files of all causet_merge families in multiple ranges.
+    ///
+    /// Note:
+    ///    - After this operation, some soliton_ids in the range might still exist in the database.
+    ///    - After this operation, some soliton_ids in the range might be removed from existing
+    ///      snapshots, so you shouldn't expect to be able to read data from the range using
+    ///      existing snapshots any more.
+    fn roughly_cleanup_ranges(&self, ranges: &[(Vec<u8>, Vec<u8>)]) -> Result<()>;
+
+    /// The local_path to the directory on the filesystem where the database is stored.
+    fn get_local_path(&self) -> String;
+
+    fn sync_wal(&self) -> Result<()>;
+
+    /// Check whether a database exists at a given local_path.
+    fn exists(local_path: &str) -> bool
+    where
+        Self: Sized;
+
+    /// Dump stats about the database into a string.
+    ///
+    /// For debugging. The format and content is unspecified.
+    fn dump_stats(&self) -> Result<String>;
+
+    /// The sequence number of the last committed write.
+    fn get_latest_sequence_number(&self) -> u64;
+
+    /// The sequence number of the oldest live snapshot, if there is one.
+    fn get_oldest_snapshot_sequence_number(&self) -> Option<u64>;
+
+    /// Whether writes are being delayed or stopped until flushes or compactions
+    /// catch up.
+    fn is_stalled_or_stopped(&self) -> bool;
+}
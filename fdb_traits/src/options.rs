@@ -1,288 +1,220 @@
-///Copyright (c) EinsteinDB project contributors. All rights reserved.
-/// 
-/// Licensed under the Apache License, Version 2.0 (the "License");
-/// you may not use this file except in compliance with the License.
-/// You may obtain a copy of the License at
-/// 
-///   http://www.apache.org/licenses/LICENSE-2.0
-/// 
-/// Unless required by applicable law or agreed to in writing, software
-/// distributed under the License is distributed on an "AS IS" BASIS,
-/// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-/// See the License for the specific language governing permissions and
-/// limitations under the License.
-///     
-/// 
-#![allow(dead_code)]
-#![allow(unused_variables)]
-#![allow(unused_imports)]
-#![allow(unused_mut)]
-use std::collections::HashSet;
-use std::collections::HashMap;
-use std::collections::BTreeSet;
-use std::collections::BTreeMap;
-use std::hash::Hash;
-use std::ops::{
-    Add,
-    AddAssign,
-    Sub,
-    SubAssign,
-    Mul,
-    MulAssign,
-    Div,
-    DivAssign,
-    Deref,
-    DerefMut,
-    Index,
-    IndexMut,
-};
-
-use ::{
-    //path 
-    
-    ValueRc,
-    ValueRef,
-    ValueRefMut,
-};
-
-
-use std::collections::HashMap;
-use std::collections::BTreeMap;
-use std::collections::BTreeSet;
-use std::collections::HashSet;
-
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
 
+use std::collections::{BTreeMap, HashSet};
+use std::hash::Hash;
 use std::rc::Rc;
-use std::cell::RefCell;
-use std::cell::Ref;
-
-
-use std::fmt::Debug;
-use std::fmt::Display;
-
-
-use std::fmt::Formatter;
-use std::fmt::Result;
-
-
 
+/// Options for point reads.
+#[derive(Clone, Debug)]
+pub struct ReadOptions {
+    /// Whether blocks read to serve the request are inserted into the block cache.
+    pub fill_cache: bool,
+}
 
-pub(crate) fn einsteindb_macro_impl<T>(t: T) -> T {
+impl Default for ReadOptions {
+    fn default() -> ReadOptions {
+        ReadOptions { fill_cache: true }
+    }
+}
 
-    t
+/// Options for iterators.
+#[derive(Clone, Debug)]
+pub struct IterOptions {
+    /// The smallest soliton_id the iterator may return.
+    pub lower_bound: Option<Vec<u8>>,
+    /// The iterator only returns soliton_ids smaller than this one.
+    pub upper_bound: Option<Vec<u8>>,
+    /// Once positioned, only return soliton_ids with the prefix of the soliton_id it
+    /// was positioned at. Requires a prefix extractor on the einstein_merkle_tree.
+    pub prefix_same_as_start: bool,
+    /// Whether blocks read by the iterator are inserted into the block cache.
+    pub fill_cache: bool,
+    /// Skip reading causet_locales; the iterator returns empty ones.
+    pub key_only: bool,
 }
 
+impl Default for IterOptions {
+    fn default() -> IterOptions {
+        IterOptions {
+            lower_bound: None,
+            upper_bound: None,
+            prefix_same_as_start: false,
+            fill_cache: true,
+            key_only: false,
+        }
+    }
+}
 
+impl IterOptions {
+    pub fn new(lower_bound: Option<Vec<u8>>, upper_bound: Option<Vec<u8>>) -> IterOptions {
+        IterOptions {
+            lower_bound,
+            upper_bound,
+            ..Default::default()
+        }
+    }
 
-pub(crate) fn causet_macro_impl<T>(t: T) -> T {
+    pub fn set_lower_bound(&mut self, bound: &[u8]) {
+        self.lower_bound = Some(bound.to_vec());
+    }
 
-    t
-}
+    pub fn set_upper_bound(&mut self, bound: &[u8]) {
+        self.upper_bound = Some(bound.to_vec());
+    }
 
+    pub fn set_prefix_same_as_start(&mut self, enable: bool) {
+        self.prefix_same_as_start = enable;
+    }
 
+    pub fn set_fill_cache(&mut self, fill_cache: bool) {
+        self.fill_cache = fill_cache;
+    }
 
+    pub fn set_key_only(&mut self, key_only: bool) {
+        self.key_only = key_only;
+    }
+}
 
+/// Options for committing a write alexandrov_poset_process.
+#[derive(Clone, Debug, Default)]
+pub struct WriteOptions {
+    /// Fsync the write-ahead log before acknowledging the write.
+    pub sync: bool,
+    /// Skip the write-ahead log; the write is lost if the process crashes before a flush.
+    pub disable_wal: bool,
+}
 
+impl WriteOptions {
+    pub fn new() -> WriteOptions {
+        WriteOptions::default()
+    }
 
+    pub fn set_sync(&mut self, sync: bool) {
+        self.sync = sync;
+    }
 
+    pub fn set_disable_wal(&mut self, disable_wal: bool) {
+        self.disable_wal = disable_wal;
+    }
+}
 
 /// An `InternSet` allows to "intern" some potentially large values, maintaining a single value
 /// instance owned by the `InternSet` and leaving consumers with lightweight ref-counted handles to
 /// the large owned value.  This can avoid expensive clone() operations.
 ///
-/// In Mentat, such large values might be strings or arbitrary [a v] pairs.
+/// In EinsteinDB, such large values might be strings or arbitrary [a v] pairs.
 ///
 /// See https://en.wikipedia.org/wiki/String_interning for discussion.
-/// 
-
-
-
-
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct InternSet<T> where T: Eq + Hash {
-    inner: HashSet<ValueRc<T>>,
-
-    /// A cache of interned values.
-    /// This is used to avoid expensive clone() operations.
-    /// The cache is a map from the interned value to a reference to the interned value.
-    /// This is a map from the interned value to a reference to the interned value.
-    /// 
-    /// 
-    
-
-    cache: HashMap<ValueRc<T>, ValueRef<T>>,
-
-
-
-
-
-
-    
-}
-
-
-pub fn  intern_set_macro_impl<T>(t: T) -> T {
-
-    t
+pub struct InternSet<T>
+where
+    T: Eq + Hash,
+{
+    inner: HashSet<Rc<T>>,
 }
 
-
-impl<T> InternSet<T> where T: Eq + Hash {
-    pub fn new() -> Self {
+impl<T> InternSet<T>
+where
+    T: Eq + Hash,
+{
+    pub fn new() -> InternSet<T> {
         InternSet {
             inner: HashSet::new(),
-            cache: HashMap::new(),
         }
     }
-    pub fn insert(&mut self, value: T) -> bool {
-        let value_rc = ValueRc::new(value);
-        let value_ref = ValueRef::new(value_rc);
-        self.cache.insert(value_rc.clone(), value_ref);
-        self.inner.insert(value_rc)
-    }
-    pub fn contains(&self, value: &T) -> bool {
-        self.inner.contains(value)
-    }
+
     pub fn len(&self) -> usize {
         self.inner.len()
     }
+
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
-    pub fn clear(&mut self) {
-        self.inner.clear();
-        self.cache.clear();
-    }
-
-    pub fn get(&self, value: &T) -> Option<ValueRef<T>> {
-        self.cache.get(value).cloned()
-    }
-
-
-    pub fn get_ref(&self, value: &T) -> Option<ValueRef<T>> {
-        self.cache.get(value).cloned()
-    }
-
-
-    
 
-    
-    pub fn get_ref_mut(&mut self, value: &T) -> Option<ValueRefMut<T>> {
-        self.cache.get_mut(value).cloned()
-    }
-    pub fn get_rc(&self, value: &T) -> Option<ValueRc<T>> {
-        self.cache.get(value).cloned().map(|v| v.rc.clone())
-    }
-    pub fn get_rc_mut(&mut self, value: &T) -> Option<ValueRcMut<T>> {
-        self.cache.get_mut(value).cloned().map(|v| v.rc_mut.clone())
+    /// Intern a value, providing a ref-counted handle to the interned value.
+    pub fn intern<R: Into<Rc<T>>>(&mut self, value: R) -> Rc<T> {
+        let soliton_id: Rc<T> = value.into();
+        if let Some(existing) = self.inner.get(&soliton_id) {
+            return existing.clone();
+        }
+        self.inner.insert(soliton_id.clone());
+        soliton_id
     }
 }
 
-
-
-use std::collections::BTreeMap;
-
-/// Witness assertions and retractions, folding (assertion, retraction) pairs into alterations.
-/// Assumes that no assertion or retraction will be witnessed more than once.
-///
-/// This keeps track of when we see a :db/add, a :db/retract, or both :db/add and :db/retract in
-/// some order.
+/// A map of the causet_locales asserted, retracted, and altered (both retracted and
+/// asserted) by a transaction, by soliton_id.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
 pub struct AddRetractAlterSet<K, V> {
-    /// The set of (assertion, retraction) pairs.
-    /// The keys are the assertion values, the values are the retraction values.
-    /// The keys are ordered by the assertion values.
-    /// The values are ordered by the retraction values.
-
-    inner: BTreeMap<K, BTreeSet<V>>,
-    /// The set of keys that have been added.
-    /// This is a set of keys that have been added.
-    
-    added: InternSet<K>,
-    /// The set of keys that have been retracted.
-    /// This is a set of keys that have been retracted.
     pub asserted: BTreeMap<K, V>,
-
-    /// The set of keys that have been retracted.
     pub retracted: BTreeMap<K, V>,
-
-    /// The set of keys that have been added and retracted.
+    /// The retracted and the asserted causet_locale.
     pub altered: BTreeMap<K, (V, V)>,
-
-    /// The set of keys that have been added and retracted.
-    /// This is a set of keys that have been added and retracted.
-    /// This is a set of keys that have been added and retracted.
-    pub altered_added: BTreeMap<K, (V, V)>,
 }
 
-
-
-impl AddRetractAlterSet<K, V> {
-    pub fn new() -> Self {
+impl<K, V> Default for AddRetractAlterSet<K, V>
+where
+    K: Ord,
+{
+    fn default() -> AddRetractAlterSet<K, V> {
         AddRetractAlterSet {
-            inner: (),
-            added: InternSet::new(),
-            asserted: BTreeMap::new(),
-            retracted: BTreeMap::new(),
-            altered: BTreeMap::new(),
-            altered_added: ()
+            asserted: BTreeMap::default(),
+            retracted: BTreeMap::default(),
+            altered: BTreeMap::default(),
         }
     }
-    pub fn insert_added(&mut self, key: K, value: V) -> bool {
-        self.added.insert(key)
-    }
-    pub fn insert_asserted(&mut self, key: K, value: V) -> bool {
-        self.asserted.insert(key, value)
-    }
-    pub fn insert_retracted(&mut self, key: K, value: V) -> bool {
-        self.retracted.insert(key, value)
-    }
-    pub fn insert_altered(&mut self, key: K, value: (V, V)) -> bool {
-        self.altered.insert(key, value)
-    }
-    pub fn contains_added(&self, key: &K) -> bool {
-        self.added.contains(key)
-    }
-    pub fn contains_asserted(&self, key: &K) -> bool {
-        self.asserted.contains_key(key)
-    }
-    pub fn contains_retracted(&self, key: &K) -> bool {
-        self.retracted.contains_key(key)
-    }
-    pub fn contains_altered(&self, key: &K) -> bool {
-        self.altered.contains_key(key)
-    }
-    pub fn len_added(&self) -> usize {
-        self.added.len()
-    }
-    pub fn len_asserted(&self) -> usize {
-        self.asserted.len()
-    }
-    pub fn len_retracted(&self) -> usize {
-        self.retracted.len()
-    }
-    pub fn len_altered(&self) -> usize {
-        self.altered.len()
-    }
-    pub fn is_empty_added(&self) -> bool {
-        self.added.is_empty
-    }
-
+}
 
-    pub fn is_empty_asserted(&self) -> bool {
-        self.asserted.is_empty()
+impl<K, V> AddRetractAlterSet<K, V>
+where
+    K: Ord,
+{
+    pub fn witness(&mut self, soliton_id: K, causet_locale: V, added: bool) {
+        if added {
+            if let Some(retracted_causet_locale) = self.retracted.remove(&soliton_id) {
+                self.altered.insert(soliton_id, (retracted_causet_locale, causet_locale));
+            } else {
+                self.asserted.insert(soliton_id, causet_locale);
+            }
+        } else if let Some(asserted_causet_locale) = self.asserted.remove(&soliton_id) {
+            self.altered.insert(soliton_id, (causet_locale, asserted_causet_locale));
+        } else {
+            self.retracted.insert(soliton_id, causet_locale);
+        }
     }
 
-    pub fn is_empty_retracted(&self) -> bool {
-        self.retracted.is_empty()
+    pub fn is_empty(&self) -> bool {
+        self.asserted.is_empty() && self.retracted.is_empty() && self.altered.is_empty()
     }
+}
 
-    pub fn is_empty_altered(&self) -> bool {
-        self.altered.is_empty()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_set() {
+        let mut set: InternSet<String> = InternSet::new();
+        let a = set.intern("causet".to_owned());
+        let b = set.intern("causet".to_owned());
+        assert!(Rc::ptr_eq(&a, &b));
+        set.intern("locale".to_owned());
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_add_retract_alter_set() {
+        let mut set = AddRetractAlterSet::default();
+        set.witness(1, "a", true);
+        set.witness(2, "b", false);
+        set.witness(2, "c", true);
+        set.witness(3, "d", true);
+        set.witness(3, "e", false);
+        assert_eq!(set.asserted.into_iter().collect::<Vec<_>>(), vec![(1, "a")]);
+        assert!(set.retracted.is_empty());
+        assert_eq!(
+            set.altered.into_iter().collect::<Vec<_>>(),
+            vec![(2, ("b", "c")), (3, ("e", "d"))]
+        );
     }
 }
-
-
-
-
-
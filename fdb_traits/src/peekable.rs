@@ -1,360 +1,155 @@
-///Copyright (c) 2020, the EinsteinDB Project Authors. Licensed under Apache-2.0.
-/// See LICENSE.txt for details.
+//Copyright (c) 2020, the EinsteinDB Project Authors. Licensed under Apache-2.0.
+// See LICENSE.txt for details.
 
+//FoundationDB's Record Layer sits atop the FoundationDB Network Layer.
+// The Record Layer is responsible for serializing and deserializing FoundationDB records.
+//
+// fdb_traits aims to bridge the gap between the FoundationDB Network Layer and the FoundationDB Record Layer.
+// using causal consistent read, we can guarantee that the data in the record is consistent with the data in the database.
+// using causal consistent write, we can guarantee that the data in the database is consistent with the data in the record.
+//
+use std::collections::BTreeSet;
+use std::iter::FusedIterator;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
+use crate::{ReadOptions, Result, NAMESPACED_DEFAULT};
 
+/// Point reads of an einstein_merkle_tree or of a snapshot of it.
+///
+/// Reads of an einstein_merkle_tree see every write committed before they start; reads of
+/// a snapshot see the writes committed before it was taken.
+pub trait Peekable {
+    /// The causet_locale of `soliton_id` in `namespaced`, `None` if it is absent or deleted.
+    fn get_value_namespaced_opt(
+        &self,
+        opts: &ReadOptions,
+        namespaced: &str,
+        soliton_id: &[u8],
+    ) -> Result<Option<Vec<u8>>>;
 
+    fn get_value_opt(&self, opts: &ReadOptions, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.get_value_namespaced_opt(opts, NAMESPACED_DEFAULT, soliton_id)
+    }
 
-///FoundationDB's Record Layer sits atop the FoundationDB Network Layer.
-/// The Record Layer is responsible for serializing and deserializing FoundationDB records.
-///
-/// fdb_traits aims to bridge the gap between the FoundationDB Network Layer and the FoundationDB Record Layer.
-/// using causal consistent read, we can guarantee that the data in the record is consistent with the data in the database.
-/// using causal consistent write, we can guarantee that the data in the database is consistent with the data in the record.
-///
+    fn get_value_namespaced(&self, namespaced: &str, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.get_value_namespaced_opt(&ReadOptions::default(), namespaced, soliton_id)
+    }
 
+    fn get_value(&self, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.get_value_namespaced(NAMESPACED_DEFAULT, soliton_id)
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FoundationDbCausetReadWriteOptions {
     /// The set of keys that have been added.
-    /// This is a set of keys that have been added.
     pub added: BTreeSet<String>,
     /// The set of keys that have been retracted.
-    /// This is a set of keys that have been retracted.
     pub retracted: BTreeSet<String>,
     /// The set of keys that have been added and retracted.
     pub altered: BTreeSet<String>,
-    /// The set of keys that have been added and retracted.
-    /// This is a set of keys that have been added and retracted.
+    /// The set of keys that were added again after being altered.
     pub altered_added: BTreeSet<String>,
 }
 
-///
-/// 
-/// ```rust
-/// use einstein_db_alexandrov_processing::file_system::*;
-/// use einstein_db_alexandrov_processing::file_system::fdb_file_system::*;
-/// 
-/// 
-/// let mut fdb_file_system = FDBFileSystem::new();
-///  fdb_file_system.create_dir_all("/tmp/einstein_db_alexandrov_processing/").unwrap();
-
-use einstein_db_alexandrov_processing::file_system::*;
-use einstein_db_alexandrov_processing::file_system::fdb_file_system::*;
-
-
-// #[macro_use]
-// extern crate lazy_static;
-// extern crate regex;
-// extern crate chrono;
-// extern crate itertools;
-
-
-// #[macro_use]
-// extern crate failure;
-// extern crate failure_derive;
-// extern crate failure_derive_utils;
-
-
-
-
-
-
-use std::collections::BTreeMap;
-use std::collections::BTreeSet;
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::collections::VecDeque;
-use std::collections::HashSet;
-//Evictable is a trait that provides a method to evict an item from a cache.
-
-use std::path::Path;
-use std::fs::{self, File};
-use std::io::{self, Read, Write};
-use std::io::{Error, ErrorKind};
-use std::io::{BufReader, BufWriter};
-use std::io::{BufRead, BufReaderExt};
-use std::io::{BufWriter, BufWriterExt};
-use crate::iter::{adapters::SourceIter, FusedIterator, TrustedLen};
-use crate::ops::{ControlFlow, Try};
-
-
-///Eviction Policy
-/// The Eviction Policy is responsible for evicting items from a cache.
-/// We'll focus on Grpcio and its cache.
-
-
-use std::sync::Arc;
-use std::sync::Mutex;
-use std::sync::RwLock;
-use std::sync::RwLockReadGuard;
-use std::sync::RwLockWriteGuard;
-use std::sync::RwLockReadWriteGuard;
-
-
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::atomic::AtomicU64;
-use std::sync::atomic::AtomicU32;
-use std::sync::atomic::AtomicU8;
-
-
-use std::sync::atomic::AtomicBool;
-
-// Page caches used in non-simulated environments
-// use std::sync::Arc;
-// use std::sync::Mutex;
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct EvictablePageCauset<T: TopographCausetTermBuilder>(Arc<Mutex<T>>);
+impl FoundationDbCausetReadWriteOptions {
+    pub fn add(&mut self, soliton_id: &str) {
+        if self.retracted.remove(soliton_id) {
+            self.altered.insert(soliton_id.to_owned());
+        } else if self.altered.contains(soliton_id) {
+            self.altered_added.insert(soliton_id.to_owned());
+        } else {
+            self.added.insert(soliton_id.to_owned());
+        }
+    }
 
+    pub fn retract(&mut self, soliton_id: &str) {
+        if self.added.remove(soliton_id) {
+            self.altered.insert(soliton_id.to_owned());
+        } else {
+            self.retracted.insert(soliton_id.to_owned());
+        }
+    }
 
-impl<T: TopographCausetTermBuilder> EvictablePageCauset<T> {
-    pub fn new(page_size: usize) -> Self {
-        EvictablePageCauset(Arc::new(Mutex::new(T::new(page_size))))
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.retracted.is_empty()
+            && self.altered.is_empty()
+            && self.altered_added.is_empty()
     }
 }
 
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A cached causet_locale with the counters an eviction policy needs.
+#[derive(Debug)]
 pub struct Evictable<T> {
-    /// The underlying value.
-    pub value: T,
-    /// The number of times the value has been accessed.
-    pub access_count: AtomicUsize,
-    /// The number of times the value has been evicted.
-    pub eviction_count: AtomicUsize,
+    pub causet_locale: T,
+    access_count: AtomicUsize,
+    eviction_count: AtomicUsize,
 }
 
-
 impl<T> Evictable<T> {
-    /// Creates a new Evictable.
-    pub fn new(value: T) -> Self {
+    pub fn new(causet_locale: T) -> Self {
         Evictable {
-            value,
+            causet_locale,
             access_count: AtomicUsize::new(0),
             eviction_count: AtomicUsize::new(0),
         }
     }
-}
 
-// The simulator needs to store separate page caches for each machine
-// in the cluster. This is because the simulator is not able to
-// simulate the effects of page faults.
-// #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-// pub struct PageCache {
-//     /// The underlying value.
-//     pub value: Arc<Mutex<Evictable<Vec<u8>>>>,
-//     /// The number of times the value has been accessed.
-//     pub access_count: AtomicUsize,
-//     /// The number of times the value has been evicted.
-//     pub eviction_count: AtomicUsize,
-// }
+    /// Returns the causet_locale, counting the access.
+    pub fn access(&self) -> &T {
+        self.access_count.fetch_add(1, Ordering::Relaxed);
+        &self.causet_locale
+    }
 
+    pub fn access_count(&self) -> usize {
+        self.access_count.load(Ordering::Relaxed)
+    }
 
+    /// Records that the causet_locale was chosen for eviction and survived it, and
+    /// resets its accesses so it must earn its place again.
+    pub fn mark_evicted(&self) {
+        self.eviction_count.fetch_add(1, Ordering::Relaxed);
+        self.access_count.store(0, Ordering::Relaxed);
+    }
 
-impl Evictable<Vec<u8>> {
-    /// Creates a new Evictable.
-    pub fn new(value: Vec<u8>) -> Self {
-        Evictable {
-            value: value,
-            access_count: AtomicUsize::new(0),
-            eviction_count: AtomicUsize::new(0),
-        }
+    pub fn eviction_count(&self) -> usize {
+        self.eviction_count.load(Ordering::Relaxed)
     }
 }
 
-/// An iterator with a `peek()` that returns an optional reference to the next
-/// element.
-///
-/// This `struct` is created by the [`peekable`] method on [`Iterator`]. See its
-/// documentation for more.
-///
-/// [`peekable`]: Iterator::peekable
-/// [`Iterator`]: trait.Iterator.html
+/// An iterator adapter that can look at the next item without consuming it,
+/// which the merge of several sorted sources needs to pick the smallest head.
 #[derive(Clone, Debug)]
-pub struct LightlikePeekable<I, P> {
-
+pub struct LightlikePeekable<I: std::iter::Iterator> {
     iter: I,
-    peeked: Option<I::Item>,
+    /// `Some(None)` once the underlying iterator was seen to be exhausted.
+    peeked: Option<Option<I::Item>>,
 }
 
-pub struct Peekable<I: Iterator> {
-    iter: I,
-    peeked: Option<I::Item>,
-
-    /// The `FusedIterator` implementation for `Peekable` is based on the
-    /// `FusedIterator` implementation for the underlying iterator.
-    /// It is not possible to implement `FusedIterator` for `Peekable`
-    /// because `Peekable` needs to be able to call `peek` on the underlying
-    /// iterator, which is not possible if the underlying iterator is also
-    /// `FusedIterator`.
-    /// Therefore, we just override the `FusedIterator` methods that call
-    /// `peek` and `next` on the underlying iterator.
-    /// This is safe because `Peekable` is always in a state where the underlying
-    /// iterator is `FusedIterator`, so it is safe to call `peek` and `next`.
-    /// 
-    /// 
-    /// 
-    
-
-
-
-    // #[doc(hidden)]
-    // #[unstable(feature = "unstable_fused_iterator", issue = "none")]
-    // pub fn next_back(&mut self) -> Option<I::Item> {
-    //     self.iter.next_back()
-    // }
-    // #[doc(hidden)]
-    // #[unstable(feature = "unstable_fused_iterator", issue = "none")]
-    // pub fn next_back_into_iter(&mut self) -> Option<I> {
-
-    #[doc(hidden)]
-    #[allow(dead_code)]
-    __no_send_sync_unwind: (),
-}
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct FDBFileSystem {
-
-
-    // #[doc(hidden)]
-    // #[allow(dead_code)]
-    // __no_send_sync_unwind: (),
-
-
-}
-
-impl<I: Iterator> Peekable<I> {
-    /// Creates a new `Peekable` iterator.
-    /// The iteration behavior of the returned `Peekable` is the same as the read version, we need to implement the write version.
-    /// # Examples
-    /// ```rust
-    /// use einstein_db_alexandrov_processing::file_system::*;
-    /// use einstein_db_alexandrov_processing::
-    ///
-    /// let mut fdb_file_system = FDBFileSystem::new();
-    /// fdb_file_system.create_dir_all("/tmp/einstein_db_alexandrov_processing/").unwrap();
-    /// ```
-    /// # Panics
-    /// Panics if the underlying iterator is already in use.
-    ///
-    /// Returns a reference to the next element of the iterator without advancing
-    /// the iterator.
-    ///
-    /// # Panics
-
-
-
-    /// Returns a reference to the next element of the iterator without advancing
-    #[cfg(feature = "unstable")]
-    pub fn peek(&mut self) -> Option<&I::Item> {
-        self.peeked.as_ref()
-    }
-
-    // #[doc(hidden)]
-    // #[unstable(feature = "trusted_len", issue = "none")]
-    // pub fn len(&self) -> usize {
-    //     self.iter.len()
-    // }
-    // #[doc(hidden)]
-    // #[unstable(feature = "trusted_len", issue = "none")]
-    // pub fn is_empty(&self) -> bool {
-    //     self.iter.is_empty()
-    // }
-
-
-    #[cfg(feature = "unstable")]
-    #[doc(hidden)]
-    #[unstable(feature = "unstable", issue = "none")]
-    pub fn len(&self) -> usize {
-        self.iter.len()
-    }
-
-    #[cfg(feature = "unstable")]
-    #[doc(hidden)]
-    #[unstable(feature = "unstable", issue = "none")]
-    pub fn is_empty(&self) -> bool {
-        self.iter.is_empty()
+impl<I: std::iter::Iterator> LightlikePeekable<I> {
+    pub fn new(iter: I) -> Self {
+        LightlikePeekable { iter, peeked: None }
     }
 
-    // #[doc(hidden)]
-    // #[unstable(feature = "trusted_len", issue = "none")]
-    // pub fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
-    // where
-    //     Self: Sized,
-    //     Fold: FnMut(Acc, Self::Item) -> R,
-    //     R: Try<Ok = Acc>,
-    // {
-    //     self.iter.try_fold(init, fold)
-    // }
-    // #[doc(hidden)]
-    // #[unstable(feature = "trusted_len", issue = "none")]
-    // pub fn try_rfold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
-    // where
-    //     Self: Sized,
-    //     Fold: FnMut(Acc, Self::Item) -> R,
-    //     R: Try<Ok = Acc>,
-    // {
-    //     self.iter.try_rfold(init, fold)
-    // }
-    // #[doc(hidden)]
-    // #[unstable(feature = "trusted_len", issue = "none")]
-    // pub fn try_rfold_with<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
-    // where
-    //     Self: Sized,
-    //     Fold: FnMut(Acc, Self::Item) -> R,
-    //     R: Try<Ok = Acc>,
-    // {
-    //     self.iter.try_rfold_with(init, fold)
-    // }
-    // #[doc(hidden)]
-    // #[unstable(feature = "trusted_len", issue = "none")]
-    // pub fn try_rfold_by<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
-    // where
-    //     Self: Sized,
-    //     Fold: FnMut(Acc, Self::Item) -> R,
-    //     R: Try<Ok = Acc>,
-    //
-
-
-// Peekable must remember if a None has been seen in the `.peek()` method.
-// It ensures that `.peek(); .peek();` or `.peek(); .next();` only advances the
-// underlying iterator at most once. This does not by itself make the iterator
-// fused.
-// #[doc(hidden)]
-// #[unstable(feature = "trusted_len", issue = "none")]
-// pub fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
-// where
-//     Self: Sized,
-//     Fold: FnMut(Acc, Self::Item) -> R,
-//     R: Try<Ok = Acc>,
-// {
-//     self.iter.try_fold(init, fold)
-// }
-// #[doc(hidden)
-
-
-    pub fn next(&mut self) -> Option<I::Item> {
-        if self.peeked.is_some() {
-            self.peeked = None;
-            return Some(self.iter.next().unwrap());
-        }
-        self.iter.next()
-    }
-
-    pub fn next_into_iter(&mut self) -> Option<I> {
-        if self.peeked.is_some() {
-            self.peeked = None;
-            return Some(self.iter.next().unwrap());
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+    }
+
+    /// Consumes and returns the next item if `f` accepts it.
+    pub fn next_if(&mut self, f: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.next() {
+            Some(item) if f(&item) => Some(item),
+            other => {
+                self.peeked = Some(other);
+                None
+            }
         }
-        self.iter.next()
     }
 }
 
-
-impl<I: Iterator> Iterator for Peekable<I> {
+impl<I: std::iter::Iterator> std::iter::Iterator for LightlikePeekable<I> {
     type Item = I::Item;
 
     #[inline]
@@ -365,361 +160,70 @@ impl<I: Iterator> Iterator for Peekable<I> {
         }
     }
 
-
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<I::Item> {
+    fn count(mut self) -> usize {
         match self.peeked.take() {
-            Some(v) => v,
-            None => self.iter.nth(n),
-        }
-    }
-
-    #[inline]
-    fn count(&mut self) -> usize {
-        self.iter.count()
-    }
-
-}
-
-///*
-/// Peekable's iterator is only as long as the underlying iterator.
-/// This method is called after `.next()` or `.next_back()` to check if the
-/// iterator is empty.
-/// 
-/// At-least-once. For all of our current use-cases, 
-/// it is sufficient to ensure at-least-once semantics,
-///  where each task is guaranteed to execute but may, 
-/// in some cases, execute more than once.
-///  Tasks that affect only the database could
-///  execute exactly-once using Founda- tionDB’s support for ACID transactions.
-/// 
-///  In practice, many tasks affect services outside the database. When a task is part of a larger flow, stronger functionality  
-/// is often better achieved at a higher level in the stack,
-///  as suggested by the end-to-end argument
-/// of the task.
-/// 
-/// For example, a task that reads from a database may be executed more than once,
-/// but a task that writes to a database may only be executed once. EinsteinDB is a database that is designed to support this.
-/// 
-/// The following example shows how to use the `is_empty` method to ensure that a task is executed at-least-once.
-/// 
-/// ```rust
-/// # use einstein_db::prelude::*;
-/// 
-/// # let mut db = Database::new();
-/// # let mut table = db.create_table("table");
-/// # let mut table = table.unwrap();
-/// # let mut table = table.as_mut();
-/// 
-/// # let mut iter = table.iter();
-/// # let mut iter = iter.peekable();
-/// 
-
-
-#[stable(feature = "rust1", since = "1.0.0")]
-impl<I: Iterator> FusedIterator for Peekable<I> {
-    fn is_terminated(&mut self) -> bool {
-        self.iter.is_terminated()
-    }
-}
-
-    #[stable(feature = "rust1", since = "1.0.0")]
-    impl<I: Iterator> ExactSizeIterator for Peekable<I> {
-        fn len(&self) -> usize {
-            self.iter.len()
-        }
-    }
-
-#[stable(feature = "rust1", since = "1.0.0")]
-impl<I: Iterator> DoubleEndedIterator for Peekable<I> {
-    #[inline]
-    fn next_back(&mut self) -> Option<I::Item> {
-        if self.peeked.is_some() {
-            self.peeked = self.iter.next_back();
-        } else {
-            self.iter.next_back()
+            Some(None) => 0,
+            Some(Some(_)) => 1 + self.iter.count(),
+            None => self.iter.count(),
         }
     }
-}
-
-//FoundationDB’s support for ACID transactions is based on the following principle:
-// At-least-once. For all of our current use-cases
-// it is sufficient to ensure at-least-once semantics,
-//  where each task is guaranteed to execute but may,
-//  in some cases, execute more than once.
-//  Tasks that affect only the database could
-//  execute exactly-once using Founda- tionDB’s support for ACID transactions.
-
-
-
-
-// #[stable(feature = "rust1", since = "1.0.0")]
-// impl<I: Iterator> ExactSizeIterator for Peekable<I> {
-//     fn len(&self) -> usize {
-//         self.iter.len()
-//     }
-// }
-
-
-
-
-
-///Flow Arena Simulator
-/// This is a simple simulator for the Flow Arena.
-/// It is used to test the correctness of the Flow Arena.
-///
- 
-///#define DECLARE_JWK_OPTIONAL_STRING_MEMBER(value, member)                                                              \
-// 	auto member = Optional<StringRef>();                                                                               \
-// 	if (!getJwkStringMember<false>(value, #member, member, keyIndex))                                                  \
-// 		return {}
-///#define DECLARE_JWK_OPTIONAL_STRING_MEMBER_WITH_DEFAULT(value, member, default)                                        \
-///     auto member = Optional<StringRef>();                                                                               \
-///    if (!getJwkStringMember<false>(value, #member, member, keyIndex))                                                  \
-
-
-
-//language: rust
-// declare jwk_optional_string_member(value, member)
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(C)]
-pub struct Jwk {
-    pub kty: String,
-    pub alg: String,
-    pub kid: String,
-
-    pub n: String,
-    pub e: String,
-    pub d: String,
-    pub p: String,
-    pub q: String,
-    pub dp: String,
-    pub dq: String,
-    pub qi: String,
-    pub k: String,
-    pub crv: String,
-    pub x: String,
-    pub y: String,
-    pub kty_crv: String,
-    pub kty_x5c: String,
-    pub kty_x5t: String,
-    pub kty_x5t_s256: String,
-    pub kty_x5u: String,
-    pub kty_x5u_s256: String,
-}
-
-
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(C)]
-pub struct JwkSet {
-    pub keys: Vec<Jwk>,
-}
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(C)]
-pub struct JwkSetRef {
-    pub keys: Vec<JwkRef>,
-}
-
-
-
-/// An iterator that yields `None` forever.
-/// It is useful for consuming the elements of an iterator until exhaustion.
-/// It should be used as the base case of a `match` statement.
-/// 
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(C, align(32))]
-pub struct JwkRef {
-    pub kty: StringRef,
-    pub alg: StringRef,
-    pub kid: StringRef,
-
-    pub n: StringRef,
-    pub e: StringRef,
-    pub d: StringRef,
-    pub p: StringRef,
-    pub q: StringRef,
-    pub dp: StringRef,
-    pub dq: StringRef,
-    pub qi: StringRef,
-    pub k: StringRef,
-    pub crv: StringRef,
-    pub x: StringRef,
-    pub y: StringRef,
-    pub kty_crv: StringRef,
-    pub kty_x5c: StringRef,
-    pub kty_x5t: StringRef,
-    pub kty_x5t_s256: StringRef,
-    pub kty_x5u: StringRef,
-    pub kty_x5u_s256: StringRef,
-}
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(C, align(32))]
-/// An iterator that yields `None` forever.
-/// It is useful for consuming the elements of an iterator until exhaustion.
-/// // The value does not include the size of `connectPacketLength` itself,
-// 	// but only the other fields of this structure.
-// 	// The value does not include the size of `connectPacketLength` itself,
-
-
-pub struct JwkSetRefIter {
-    pub keys: Vec<JwkRef>,
-    pub index: usize,
-}
-
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(C, align(32))]
-pub struct JwkSetRefIterRef {
-    pub keys: Vec<JwkRefRef>,
-    pub index: usize,
-}
-
-
-/// An iterator that yields `None` forever.
-/// It is useful for consuming the elements of an iterator until exhaustion.
-/// It should be used as the base case of a `match` statement.
-///     #[doc(hidden)]
-///    #[unstable(feature = "trusted_len", issue = "none")]
-///   pub fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
-///Flow features
-// Flow’s new keywords and control-flow primitives support the capability to pass messages asynchronously between components. Here’s a brief overview.
-//
-// Promise<T> and Future<T>
-// The data types that connect asynchronous senders and receivers are Promise<T> and Future<T> for some C++ type T. When a sender holds a Promise<T>, it represents a promise to deliver a value of type T at some point in the future to the holder of the Future<T>. Conversely, a receiver holding a Future<T> can asynchronously continue computation until the point at which it actually needs the T.
-//
-// Promises and futures can be used within a single process, but their real strength in a distributed system is that they can traverse the network. For example, one computer could create a promise/future pair, then send the promise to another computer over the network. The promise and future will still be connected, and when the promise is fulfilled by the remote computer, the original holder of the future will see the value appear.
-//
-// wait()
-// At the point when a receiver holding a Future<T> needs the T to continue computation, it invokes the wait() statement with the Future<T> as its parameter. The wait() statement allows the calling actor to pause execution until the value of the future is set, returning a value of type T. During the wait, other actors can continue execution, providing asynchronous concurrency within a single process.
-//
-// ACTOR
-// Only functions labeled with the ACTOR tag can call wait(). Actors are the essential unit of asynchronous work and can be composed to create complex message-passing systems. By composing actors, futures can be chained together so that the result of one depends on the output of another.
-//
-// An actor is declared as returning a Future<T> where T may be Void if the actor’s return value is used only for signaling. Each actor is preprocessed into a C++11 class with internal callbacks and supporting functions.
-//
-// State
-// The state keyword is used to scope a variable so that it is visible across multiple wait() statements within an actor. The use of a state variable is illustrated in the example actor below.
-//
-// PromiseStream<T>, FutureStream<T>
-// When a component wants to work with a stream of asynchronous messages rather than a single message, it can use PromiseStream<T> and FutureStream<T>. These constructs allow for two important features: multiplexing and reliable delivery of messages. They also play an important role in Flow design patterns. For example, many of the servers in FoundationDB expose their interfaces as a struct of promise streams—one for each request type.
-
-
-
-
-
-#[stable(feature = "rust1", since = "1.0.0")]
-impl<I: Iterator> Iterator for FdbRecordOptions<I> {
-    type Item = I::Item;
-
-    #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        self.iter.next()
-    }
-
-    #[inline]   
-    fn timestep(&mut self) -> Option<I::Item> {
-        self.iter.timestep()
-    }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<I::Item> {
-        self.iter.nth(n)
-    }
-
-    #[inline]
-    fn count(&mut self) -> usize {
-        self.iter.count()
-    }
-}
-
-
-
-
-
-
-#[stable(feature = "rust1", since = "1.0.0")]
-impl<I: IteratorT> IteratorT for FdbRecordOptions<I> {
-    type Item = I::Item;
-
-    #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        self.iter.next()
-    }
-
-    #[inline]
-    fn timestep(&mut self) -> Option<I::Item> {
-        self.iter.timestep()
-    }
-
-    #[inline]
-    fn nth(&mut self, n: usize) -> Option<I::Item> {
-        self.iter.nth(n)
+        match self.peeked.take() {
+            Some(None) => None,
+            Some(v @ Some(_)) if n == 0 => v,
+            Some(Some(_)) => self.iter.nth(n - 1),
+            None => self.iter.nth(n),
+        }
     }
 
     #[inline]
-    fn count(&mut self) -> usize {
-        self.iter.count()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peek_len = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(peek_len),
+            hi.and_then(|x| x.checked_add(peek_len)),
+        )
     }
 }
 
+impl<I: ExactSizeIterator> ExactSizeIterator for LightlikePeekable<I> {}
 
-#[stable(feature = "rust1", since = "1.0.0")]
-impl<I: IteratorT> Iterator for FdbRecordOptions<I> {
-    type Item = I::Item;
+impl<I: FusedIterator> FusedIterator for LightlikePeekable<I> {}
 
-    #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        self.iter.next()
-    }
-
-    #[inline]
-    fn timestep(&mut self) -> Option<I::Item> {
-        self.iter.timestep()
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    #[inline]
-    fn nth(&mut self, n: usize) -> Option<I::Item> {
-        self.iter.nth(n)
+    #[test]
+    fn test_lightlike_peekable() {
+        let mut it = LightlikePeekable::new(vec![1, 2, 3].into_iter());
+        assert_eq!(it.peek(), Some(&1));
+        assert_eq!(it.peek(), Some(&1));
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.next_if(|&x| x == 2), None);
+        assert_eq!(it.next_if(|&x| x == 1), Some(1));
+        assert_eq!(it.nth(1), Some(3));
+        assert_eq!(it.peek(), None);
+        assert_eq!(it.next(), None);
     }
 
-    #[inline]
-    fn count(&mut self) -> usize {
-        self.iter.count()
+    #[test]
+    fn test_read_write_options() {
+        let mut opts = FoundationDbCausetReadWriteOptions::default();
+        opts.add("a");
+        opts.retract("a");
+        opts.retract("b");
+        opts.add("a");
+        assert!(opts.added.is_empty());
+        assert_eq!(opts.retracted.iter().collect::<Vec<_>>(), vec!["b"]);
+        assert_eq!(opts.altered_added.iter().collect::<Vec<_>>(), vec!["a"]);
     }
 }
-
-
-#[stable(feature = "rust1", since = "1.0.0")]
-impl<I: IteratorT> IteratorT for FdbRecordOptions<I> {
-    type Item = I::Item;
-
-    #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        self.iter.next()
-    }
-
-    #[inline]
-    fn timestep(&mut self) -> Option<I::Item> {
-        self.iter.timestep()
-    }
-
-    #[inline]
-    fn nth(&mut self, n: usize) -> Option<I::Item> {
-        self.iter.nth(n)
-    }
-
-    #[inline]
-    fn count(&mut self) -> usize {
-        self.iter.count()
-    }
-}
\ No newline at end of file
@@ -0,0 +1,59 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use crate::Result;
+
+/// Approximate statistics of a range of soliton_ids.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RangeStats {
+    /// The size of the soliton_ids and causet_locales, before compression.
+    pub size: u64,
+    /// The number of stored versions, deletions and versions not compacted away yet
+    /// included.
+    pub num_entries: u64,
+    /// The number of distinct soliton_ids. In an MVCC causet_merge family each MVCC
+    /// version is a soliton_id of its own.
+    pub num_versions: u64,
+}
+
+/// Estimates the contents of ranges from the greedoids SSTs record at sampled
+/// soliton_ids, without reading their data.
+pub trait RangeGreedoidsExt {
+    fn get_range_stats_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<RangeStats>;
+
+    fn get_range_approximate_size_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<u64> {
+        Ok(self
+            .get_range_stats_namespaced(namespaced, start, end)?
+            .size)
+    }
+
+    fn get_range_approximate_soliton_ids_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<u64> {
+        Ok(self
+            .get_range_stats_namespaced(namespaced, start, end)?
+            .num_versions)
+    }
+
+    /// Returns at most `count` sampled soliton_ids that split `[start, end)` into parts
+    /// of about the same size, in ascending order.
+    fn get_range_approximate_split_soliton_ids_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+        count: usize,
+    ) -> Result<Vec<Vec<u8>>>;
+}
@@ -8,49 +8,166 @@
 // CONDITIONS OF ANY KIND, either express or implied. See the License for the
 // specific language governing permissions and limitations under the License.
 
+//! The topograph: the attributes causets are made of, and what causet_locales
+//! each of them accepts.
+//!
+//! Attributes are named by soliton_idwords written `:namespace/name` and
+//! identified by causetids.
 
-use std::collections::HashMap;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
-use std::hash::Hash;
-use std::rc::Rc;
-use std::sync::Arc;
 
+use crate::{Error, Result};
 
-use crate::fdb::{FdbError, FdbResult};
-use crate::fdb::{FdbKey, FdbKeySlice, FdbKeyValue, FdbKeyValueSlice, FdbKeyValueVersion};
-use crate::fdb::{FdbKeyValueVersionSlice, FdbKeyValueVersionSliceSlice};
-use crate::fdb::{FdbKeySliceSlice, FdbKeyValueSliceSlice};
-use crate::fdb::{FdbKeySliceVersion, FdbKeyValueSliceVersion, FdbKeyValueVersionSliceVersion};
+pub type Causetid = i64;
 
+/// Maps causetids to the soliton_idwords naming them.
+pub type CausetidMap = BTreeMap<Causetid, String>;
 
-/// A schema is a set of named types.
-/// A type is a set of named fields.
-/// A field is a named type with a set of constraints.
-/// A constraint is a named type with a set of values.
-/// A value is a named type with a set of properties.
-/// A property is a named type with a set of values.
-///
-/// A schema is a set of named types.
-/// A type is a set of named fields.
-///
+/// Maps soliton_idwords to the causetids they name.
+pub type SolitonidMap = BTreeMap<String, Causetid>;
+
+pub type AttributeMap = BTreeMap<Causetid, Attribute>;
+
+/// The type of the causet_locales of an attribute.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialOrd, PartialEq)]
+pub enum ValueType {
+    #[default]
+    Ref,
+    Boolean,
+    Instant,
+    Long,
+    Double,
+    String,
+    Keyword,
+    Uuid,
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ValueType::Ref => ":einsteindb.type/ref",
+            ValueType::Boolean => ":einsteindb.type/boolean",
+            ValueType::Instant => ":einsteindb.type/instant",
+            ValueType::Long => ":einsteindb.type/long",
+            ValueType::Double => ":einsteindb.type/double",
+            ValueType::String => ":einsteindb.type/string",
+            ValueType::Keyword => ":einsteindb.type/soliton_idword",
+            ValueType::Uuid => ":einsteindb.type/uuid",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A causet_locale with its type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypedValue {
+    Ref(Causetid),
+    Boolean(bool),
+    /// Microseconds since the Unix epoch.
+    Instant(i64),
+    Long(i64),
+    Double(f64),
+    String(String),
+    Keyword(String),
+    Uuid([u8; 16]),
+}
+
+impl TypedValue {
+    pub fn causet_locale_type(&self) -> ValueType {
+        match self {
+            TypedValue::Ref(_) => ValueType::Ref,
+            TypedValue::Boolean(_) => ValueType::Boolean,
+            TypedValue::Instant(_) => ValueType::Instant,
+            TypedValue::Long(_) => ValueType::Long,
+            TypedValue::Double(_) => ValueType::Double,
+            TypedValue::String(_) => ValueType::String,
+            TypedValue::Keyword(_) => ValueType::Keyword,
+            TypedValue::Uuid(_) => ValueType::Uuid,
+        }
+    }
+}
+
+impl fmt::Display for TypedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedValue::Ref(e) | TypedValue::Long(e) | TypedValue::Instant(e) => write!(f, "{}", e),
+            TypedValue::Boolean(b) => write!(f, "{}", b),
+            TypedValue::Double(d) => write!(f, "{}", d),
+            TypedValue::String(s) => write!(f, "{:?}", s),
+            TypedValue::Keyword(k) => f.write_str(k),
+            TypedValue::Uuid(u) => {
+                for b in u {
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
 
+pub mod attribute {
+    /// How the causet_locales of an attribute identify causets.
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+    pub enum Unique {
+        /// At most one causet has a given causet_locale.
+        Value,
+        /// As `Value`, and transacting the causet_locale upserts the causet having it.
+        Idcauset,
+    }
+}
+
+use self::attribute::Unique;
+
+/// An attribute of the topograph.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialOrd, PartialEq)]
+pub struct Attribute {
+    pub causet_locale_type: ValueType,
+    /// Whether a causet may have several causet_locales of the attribute.
+    pub multival: bool,
+    pub unique: Option<Unique>,
+    /// Whether the causet_locales are indexed for lookups by causet_locale.
+    pub index: bool,
+    /// Whether string causet_locales are indexed for full-text search.
+    pub fulltext: bool,
+    /// Whether the referenced causets are part of the referencing one, and are
+    /// retracted with it.
+    pub component: bool,
+    /// Whether only the current causet_locales are kept, without history.
+    pub no_history: bool,
+}
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// What an `AttributeBuilder` changed in an existing attribute.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+pub enum AttributeAlteration {
+    Cardinality,
+    Unique,
+    Index,
+    IsComponent,
+    NoHistory,
+}
+
+/// A schema is a set of named types.
+/// A type is a set of named fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct FdbSchema {
     pub types: HashMap<String, FdbType>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct FdbType {
     pub fields: HashMap<String, FdbField>,
 }
 
+/// A field is a named attribute of a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FdbField {
+    pub attribute: Attribute,
+}
+
 impl FdbSchema {
     pub fn new() -> FdbSchema {
-        FdbSchema {
-            types: HashMap::new(),
-        }
+        FdbSchema::default()
     }
 
     pub fn add_type(&mut self, name: String, type_: FdbType) {
@@ -65,55 +182,58 @@ impl FdbSchema {
         self.types.get_mut(name)
     }
 
-    pub fn get_type_names(&self) -> Vec<String> {
-        self.types.keys().cloned().collect()
-    }
-
-    pub fn get_type_names_mut(&mut self) -> Vec<String> {
-        self.types.keys().cloned().collect()
-    }
-
-    pub fn get_type_names_ref(&self) -> Vec<&str> {
-        self.types.keys().map(|s| s.as_str()).collect()
-    }
-
-    pub fn get_type_names_ref_mut(&mut self) -> Vec<&str> {
-        self.types.keys().map(|s| s.as_str()).collect()
-    }
-
-    pub fn get_type_names_ref_mut_as_vec(&mut self) -> Vec<&str> {
-        self.types.keys().map(|s| s.as_str()).collect()
-    }
-
-    pub fn get_type_names_ref_mut_as_vec_as_vec(&mut self) -> Vec<Vec<&str>> {
-        self.types.keys().map(|s| s.as_str()).collect()
-    }
-
-    pub fn get_type_names_ref_mut_as_vec_as_vec_as_vec(&mut self) -> Vec<Vec<Vec<&str>>> {
-        self.types.keys().map(|s| s.as_str()).collect()
+    /// The names of the types, sorted.
+    pub fn get_type_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.types.keys().map(|s| s.as_str()).collect();
+        names.sort_unstable();
+        names
     }
 }
 
 pub trait AttributeValidation {
-    fn validate<F>(&self, solitonid: F) -> Result<()> where F: Fn() -> String;
+    fn validate<F>(&self, solitonid: F) -> Result<()>
+    where
+        F: Fn() -> String;
+}
+
+fn bad_assertion(msg: String) -> Result<()> {
+    Err(Error::BadTopographAssertion(msg))
 }
 
 impl AttributeValidation for Attribute {
-    fn validate<F>(&self, solitonid: F) -> Result<()> where F: Fn() -> String {
-        if self.unique == Some(attribute::Unique::Value) && !self.index {
-            bail!(einsteindbErrorKind::BadTopographAssertion(format!(":einsteindb/unique :einsteindb/unique_causet_locale without :einsteindb/Index true for causetid: {}", solitonid())))
+    fn validate<F>(&self, solitonid: F) -> Result<()>
+    where
+        F: Fn() -> String,
+    {
+        if self.unique == Some(Unique::Value) && !self.index {
+            return bad_assertion(format!(
+                ":einsteindb/unique :einsteindb/unique_causet_locale without :einsteindb/Index true for causetid: {}",
+                solitonid()
+            ));
         }
-        if self.unique == Some(attribute::Unique::Idcauset) && !self.index {
-            bail!(einsteindbErrorKind::BadTopographAssertion(format!(":einsteindb/unique :einsteindb/unique_idcauset without :einsteindb/Index true for causetid: {}", solitonid())))
+        if self.unique == Some(Unique::Idcauset) && !self.index {
+            return bad_assertion(format!(
+                ":einsteindb/unique :einsteindb/unique_idcauset without :einsteindb/Index true for causetid: {}",
+                solitonid()
+            ));
         }
         if self.fulltext && self.causet_locale_type != ValueType::String {
-            bail!(einsteindbErrorKind::BadTopographAssertion(format!(":einsteindb/fulltext true without :einsteindb/causet_localeType :einsteindb.type/string for causetid: {}", solitonid())))
+            return bad_assertion(format!(
+                ":einsteindb/fulltext true without :einsteindb/causet_localeType :einsteindb.type/string for causetid: {}",
+                solitonid()
+            ));
         }
         if self.fulltext && !self.index {
-            bail!(einsteindbErrorKind::BadTopographAssertion(format!(":einsteindb/fulltext true without :einsteindb/Index true for causetid: {}", solitonid())))
+            return bad_assertion(format!(
+                ":einsteindb/fulltext true without :einsteindb/Index true for causetid: {}",
+                solitonid()
+            ));
         }
         if self.component && self.causet_locale_type != ValueType::Ref {
-            bail!(einsteindbErrorKind::BadTopographAssertion(format!(":einsteindb/isComponent true without :einsteindb/causet_localeType :einsteindb.type/ref for causetid: {}", solitonid())))
+            return bad_assertion(format!(
+                ":einsteindb/isComponent true without :einsteindb/causet_localeType :einsteindb.type/ref for causetid: {}",
+                solitonid()
+            ));
         }
         // TODO: consider warning if we have :einsteindb/Index true for :einsteindb/causet_localeType :einsteindb.type/string,
         // since this may be inefficient.  More generally, we should try to drive complex
@@ -124,20 +244,28 @@ impl AttributeValidation for Attribute {
 }
 
 /// Return `Ok(())` if `attribute_map` defines a valid EinsteinDB topograph.
-fn validate_attribute_map(causetid_map: &CausetidMap, attribute_map: &AttributeMap) -> Result<()> {
+pub fn validate_attribute_map(
+    causetid_map: &CausetidMap,
+    attribute_map: &AttributeMap,
+) -> Result<()> {
     for (causetid, attribute) in attribute_map {
-        let solitonid = || causetid_map.get(causetid).map(|solitonid| solitonid.to_string()).unwrap_or(causetid.to_string());
+        let solitonid = || {
+            causetid_map
+                .get(causetid)
+                .cloned()
+                .unwrap_or_else(|| causetid.to_string())
+        };
         attribute.validate(solitonid)?;
     }
     Ok(())
 }
 
-#[derive(Clone,Debug,Default,Eq,Hash,Ord,PartialOrd,PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialOrd, PartialEq)]
 pub struct AttributeBuilder {
     helpful: bool,
     pub causet_locale_type: Option<ValueType>,
     pub multival: Option<bool>,
-    pub unique: Option<Option<attribute::Unique>>,
+    pub unique: Option<Option<Unique>>,
     pub index: Option<bool>,
     pub fulltext: Option<bool>,
     pub component: Option<bool>,
@@ -155,13 +283,14 @@ impl AttributeBuilder {
     }
 
     /// Make a new AttributeBuilder from an existing Attribute. This is important to allow
-    /// spacelike_dagger_spacelike_dagger_retraction. Only attributes that we allow to change are duplicated here.
+    /// retraction. Only attributes that we allow to change are duplicated here.
     pub fn to_modify_attribute(attribute: &Attribute) -> Self {
-        let mut ab = AttributeBuilder::default();
-        ab.multival   = Some(attribute.multival);
-        ab.unique     = Some(attribute.unique);
-        ab.component  = Some(attribute.component);
-        ab
+        AttributeBuilder {
+            multival: Some(attribute.multival),
+            unique: Some(attribute.unique),
+            component: Some(attribute.component),
+            ..Default::default()
+        }
     }
 
     pub fn causet_locale_type(&mut self, causet_locale_type: ValueType) -> &mut Self {
@@ -179,8 +308,8 @@ impl AttributeBuilder {
         self
     }
 
-    pub fn unique(&mut self, unique: attribute::Unique) -> &mut Self {
-        if self.helpful && unique == attribute::Unique::Idcauset {
+    pub fn unique(&mut self, unique: Unique) -> &mut Self {
+        if self.helpful && unique == Unique::Idcauset {
             self.index = Some(true);
         }
         self.unique = Some(Some(unique));
@@ -212,17 +341,22 @@ impl AttributeBuilder {
 
     pub fn validate_install_attribute(&self) -> Result<()> {
         if self.causet_locale_type.is_none() {
-            bail!(einsteindbErrorKind::BadTopographAssertion("Topograph attribute for new attribute does not set :einsteindb/causet_localeType".into()));
+            return bad_assertion(
+                "Topograph attribute for new attribute does not set :einsteindb/causet_localeType"
+                    .into(),
+            );
         }
         Ok(())
     }
 
     pub fn validate_alter_attribute(&self) -> Result<()> {
         if self.causet_locale_type.is_some() {
-            bail!(einsteindbErrorKind::BadTopographAssertion("Topograph alteration must not set :einsteindb/causet_localeType".into()));
+            return bad_assertion(
+                "Topograph alteration must not set :einsteindb/causet_localeType".into(),
+            );
         }
         if self.fulltext.is_some() {
-            bail!(einsteindbErrorKind::BadTopographAssertion("Topograph alteration must not set :einsteindb/fulltext".into()));
+            return bad_assertion("Topograph alteration must not set :einsteindb/fulltext".into());
         }
         Ok(())
     }
@@ -238,8 +372,8 @@ impl AttributeBuilder {
         if let Some(multival) = self.multival {
             attribute.multival = multival;
         }
-        if let Some(ref unique) = self.unique {
-            attribute.unique = unique.clone();
+        if let Some(unique) = self.unique {
+            attribute.unique = unique;
         }
         if let Some(index) = self.index {
             attribute.index = index;
@@ -250,10 +384,10 @@ impl AttributeBuilder {
         if let Some(no_history) = self.no_history {
             attribute.no_history = no_history;
         }
-
         attribute
     }
 
+    /// Applies the flags set in the builder to `attribute`, and returns what changed.
     pub fn mutate(&self, attribute: &mut Attribute) -> Vec<AttributeAlteration> {
         let mut mutations = Vec::new();
         if let Some(multival) = self.multival {
@@ -262,19 +396,15 @@ impl AttributeBuilder {
                 mutations.push(AttributeAlteration::Cardinality);
             }
         }
-
-        if let Some(ref unique) = self.unique {
-            if *unique != attribute.unique {
-                attribute.unique = unique.clone();
-                mutations.push(AttributeAlteration::Unique);
-            }
-        } else {
-            if attribute.unique != None {
-                attribute.unique = None;
+        if let Some(unique) = self.unique {
+            if unique != attribute.unique {
+                attribute.unique = unique;
                 mutations.push(AttributeAlteration::Unique);
             }
+        } else if attribute.unique.is_some() {
+            attribute.unique = None;
+            mutations.push(AttributeAlteration::Unique);
         }
-
         if let Some(index) = self.index {
             if index != attribute.index {
                 attribute.index = index;
@@ -293,311 +423,484 @@ impl AttributeBuilder {
                 mutations.push(AttributeAlteration::NoHistory);
             }
         }
-
         mutations
     }
 }
 
-pub trait TopographBuilding {
-    fn build(&self) -> Topograph;
-    fn require_causetid(&self, solitonid: &shellings::Keyword) -> Result<CausetLocaleNucleonCausetid>;
-    fn require_attribute_for_causetid(&self, causetid: Causetid) -> Result<&Attribute>;
-    fn from_causetid_map_and_attribute_map(causetid_map: SolitonidMap, attribute_map: AttributeMap) -> Result<Topograph>;
+/// The attributes of a store and the soliton_idwords naming them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Topograph {
+    pub causetid_map: CausetidMap,
+    pub solitonid_map: SolitonidMap,
+    pub attribute_map: AttributeMap,
+    /// The attributes with `component` set, sorted.
+    pub component_attributes: Vec<Causetid>,
 }
 
+impl Topograph {
+    pub fn new(solitonid_map: SolitonidMap, attribute_map: AttributeMap) -> Topograph {
+        let causetid_map = solitonid_map.iter().map(|(k, &v)| (v, k.clone())).collect();
+        let mut topograph = Topograph {
+            causetid_map,
+            solitonid_map,
+            attribute_map,
+            component_attributes: Vec::new(),
+        };
+        topograph.update_component_attributes();
+        topograph
+    }
 
-impl TopographBuilding for TopographAlteration {
-    fn from_causetid_map_and_triples<U>() -> Result<Topograph>
-        where U: CausetidMapBuilding,
-    {
-        let mut causetid_map = U::build()?;
-        let mut attribute_map = AttributeMap::default();
-        let mut topograph = Topograph::default();
-
-        for (causetid, attribute) in causetid_map.iter() {
-            let attribute = attribute.build();
-            attribute_map.insert(causetid.clone(), attribute);
-        }
-
-        for (causetid, attribute) in attribute_map.iter() {
-            topograph.insert(causetid.clone(), attribute.clone());
-        }
-
-        Ok(topograph)
+    pub fn get_causetid(&self, solitonid: &str) -> Option<Causetid> {
+        self.solitonid_map.get(solitonid).copied()
     }
 
-    fn build(&self) -> Topograph {
-        let mut topograph = Topograph::default();
+    pub fn get_solitonid(&self, causetid: Causetid) -> Option<&str> {
+        self.causetid_map.get(&causetid).map(|s| s.as_str())
+    }
 
-        for (causetid, attribute) in self.causetid_map.iter() {
-            let attribute = attribute.build();
-            topograph.insert(causetid.clone(), attribute);
-        }
+    pub fn attribute_for_causetid(&self, causetid: Causetid) -> Option<&Attribute> {
+        self.attribute_map.get(&causetid)
+    }
 
-        for (causetid, attribute) in self.attribute_map.iter() {
-            topograph.insert(causetid.clone(), attribute.clone());
-        }
+    pub fn attribute_for_solitonid(&self, solitonid: &str) -> Option<(&Attribute, Causetid)> {
+        let causetid = self.get_causetid(solitonid)?;
+        self.attribute_for_causetid(causetid).map(|a| (a, causetid))
+    }
 
-        topograph
+    /// Names `causetid` `solitonid`.
+    pub fn insert_solitonid(&mut self, solitonid: &str, causetid: Causetid) {
+        self.solitonid_map.insert(solitonid.to_owned(), causetid);
+        self.causetid_map.insert(causetid, solitonid.to_owned());
     }
 
-    fn require_causetid(&self, solitonid: &shellings::Keyword) -> Result<CausetLocaleNucleonCausetid> {
-        self.causetid_map.get(solitonid).ok_or(Error::MissingCausetid(solitonid.clone()))
+    /// Rebuilds the list of component attributes after the attribute map changed.
+    pub fn update_component_attributes(&mut self) {
+        self.component_attributes = self
+            .attribute_map
+            .iter()
+            .filter(|(_, a)| a.component)
+            .map(|(&e, _)| e)
+            .collect();
     }
 }
 
+pub trait TopographBuilding {
+    fn require_causetid(&self, solitonid: &str) -> Result<Causetid>;
+    fn require_attribute_for_causetid(&self, causetid: Causetid) -> Result<&Attribute>;
+    fn from_solitonid_map_and_attribute_map(
+        solitonid_map: SolitonidMap,
+        attribute_map: AttributeMap,
+    ) -> Result<Topograph>;
+    fn from_solitonid_map_and_triples<U>(
+        solitonid_map: SolitonidMap,
+        assertions: U,
+    ) -> Result<Topograph>
+    where
+        U: IntoIterator<Item = (String, String, TypedValue)>;
+}
 
 impl TopographBuilding for Topograph {
+    fn require_causetid(&self, solitonid: &str) -> Result<Causetid> {
+        self.get_causetid(solitonid)
+            .ok_or_else(|| Error::UnrecognizedSolitonid(solitonid.to_owned()))
+    }
+
     fn require_attribute_for_causetid(&self, causetid: Causetid) -> Result<&Attribute> {
-        self.attribute_for_causetid(causetid).ok_or(einsteindbErrorKind::UnrecognizedCausetid(causetid).into())
+        self.attribute_for_causetid(causetid)
+            .ok_or(Error::UnrecognizedCausetid(causetid))
     }
 
     /// Create a valid `Topograph` from the constituent maps.
-    fn from_causetid_map_and_attribute_map(causetid_map: SolitonidMap, attribute_map: AttributeMap) -> Result<Topograph> {
-        let causetid_map: CausetidMap = causetid_map.iter().map(|(k, v)| (v.clone(), k.clone())).collect();
-
-        validate_attribute_map(&causetid_map, &attribute_map)?;
-        Ok(Topograph::new(causetid_map, causetid_map, attribute_map))
+    fn from_solitonid_map_and_attribute_map(
+        solitonid_map: SolitonidMap,
+        attribute_map: AttributeMap,
+    ) -> Result<Topograph> {
+        let topograph = Topograph::new(solitonid_map, attribute_map);
+        validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map)?;
+        Ok(topograph)
     }
 
-    /// Turn vec![(Keyword(:solitonid), Keyword(:soliton_id), causetq_TV(:causet_locale)), ...] into a EinsteinDB `Topograph`.
-    fn from_causetid_map_and_triples<U>(causetid_map: SolitonidMap, lightlike_dagger_upsert: U) -> Result<Topograph>
-        where U: IntoIterator<Item=(shellings::Keyword, shellings::Keyword, causetq_TV)>{
-
-        let causetid_lightlike_dagger_upsert: Result<Vec<(Causetid, Causetid, causetq_TV)>> = lightlike_dagger_upsert.into_iter().map(|(shellingic_causetid, shellingic_attr, causet_locale)| {
-            let solitonid: i64 = *causetid_map.get(&shellingic_causetid).ok_or(einsteindbErrorKind::UnrecognizedSolitonid(shellingic_causetid.to_string()))?;
-            let attr: i64 = *causetid_map.get(&shellingic_attr).ok_or(einsteindbErrorKind::UnrecognizedSolitonid(shellingic_attr.to_string()))?;
-            Ok((solitonid, attr, causet_locale))
-        }).collect();
-
-        let mut topograph = Topograph::from_causetid_map_and_attribute_map(causetid_map, AttributeMap::default())?;
-        let spacetime_report = spacetime::update_attribute_map_from_causetid_triples(&mut topograph.attribute_map,
-                                                                                causetid_lightlike_dagger_upsert?,
-                                                                                // No spacelike_dagger_spacelike_dagger_spacelike_dagger_retractions.
-                                                                                vec![])?;
-
-        // Rebuild the component attributes list if necessary.
-        if spacetime_report.attributes_did_change() {
-            topograph.update_component_attributes();
+    /// Turn vec![(":solitonid", ":einsteindb/attr", causet_locale), ...] into a EinsteinDB
+    /// `Topograph`. The attributes are the :einsteindb/* ones describing attributes.
+    fn from_solitonid_map_and_triples<U>(
+        solitonid_map: SolitonidMap,
+        assertions: U,
+    ) -> Result<Topograph>
+    where
+        U: IntoIterator<Item = (String, String, TypedValue)>,
+    {
+        let mut builders: BTreeMap<Causetid, AttributeBuilder> = BTreeMap::new();
+        for (solitonid, attr, causet_locale) in assertions {
+            let causetid = *solitonid_map
+                .get(&solitonid)
+                .ok_or_else(|| Error::UnrecognizedSolitonid(solitonid.clone()))?;
+            let builder = builders.entry(causetid).or_default();
+            match (attr.as_str(), causet_locale) {
+                (":einsteindb/causet_localeType", TypedValue::Keyword(t)) => {
+                    builder.causet_locale_type(value_type_for_soliton_idword(&t)?);
+                }
+                (":einsteindb/cardinality", TypedValue::Keyword(c)) => match c.as_str() {
+                    ":einsteindb.cardinality/one" => {
+                        builder.multival(false);
+                    }
+                    ":einsteindb.cardinality/many" => {
+                        builder.multival(true);
+                    }
+                    _ => {
+                        return Err(Error::BadTopographAssertion(format!(
+                            "unknown cardinality {}",
+                            c
+                        )))
+                    }
+                },
+                (":einsteindb/unique", TypedValue::Keyword(u)) => match u.as_str() {
+                    ":einsteindb.unique/causet_locale" => {
+                        builder.unique(Unique::Value);
+                    }
+                    ":einsteindb.unique/idcauset" => {
+                        builder.unique(Unique::Idcauset);
+                    }
+                    _ => {
+                        return Err(Error::BadTopographAssertion(format!(
+                            "unknown uniqueness {}",
+                            u
+                        )))
+                    }
+                },
+                (":einsteindb/Index", TypedValue::Boolean(b)) => {
+                    builder.index(b);
+                }
+                (":einsteindb/fulltext", TypedValue::Boolean(b)) => {
+                    builder.fulltext(b);
+                }
+                (":einsteindb/isComponent", TypedValue::Boolean(b)) => {
+                    builder.component(b);
+                }
+                (":einsteindb/noHistory", TypedValue::Boolean(b)) => {
+                    builder.no_history(b);
+                }
+                (attr, causet_locale) => {
+                    return Err(Error::BadTopographAssertion(format!(
+                        "cannot describe an attribute with {} {}",
+                        attr, causet_locale
+                    )))
+                }
+            }
         }
-        Ok(topograph)
+        let mut attribute_map = AttributeMap::new();
+        for (causetid, builder) in builders {
+            builder.validate_install_attribute()?;
+            attribute_map.insert(causetid, builder.build());
+        }
+        Topograph::from_solitonid_map_and_attribute_map(solitonid_map, attribute_map)
     }
 }
 
+fn value_type_for_soliton_idword(soliton_idword: &str) -> Result<ValueType> {
+    let t = match soliton_idword {
+        ":einsteindb.type/ref" => ValueType::Ref,
+        ":einsteindb.type/boolean" => ValueType::Boolean,
+        ":einsteindb.type/instant" => ValueType::Instant,
+        ":einsteindb.type/long" => ValueType::Long,
+        ":einsteindb.type/double" => ValueType::Double,
+        ":einsteindb.type/string" => ValueType::String,
+        ":einsteindb.type/soliton_idword" => ValueType::Keyword,
+        ":einsteindb.type/uuid" => ValueType::Uuid,
+        _ => {
+            return Err(Error::BadTopographAssertion(format!(
+                "unknown causet_locale type {}",
+                soliton_idword
+            )))
+        }
+    };
+    Ok(t)
+}
+
 pub trait TopographTypeChecking {
     /// Do topograph-aware typechecking and coercion.
     ///
     /// Either assert that the given causet_locale is in the causet_locale type's causet_locale set, or (in limited cases)
     /// coerce the given causet_locale into the causet_locale type's causet_locale set.
-    fn to_typed_causet_locale(&self, causet_locale: &einstein_ml::ValueAndSpan, causet_locale_type: ValueType) -> Result<causetq_TV>;
+    fn to_typed_causet_locale(
+        &self,
+        causet_locale: TypedValue,
+        causet_locale_type: ValueType,
+    ) -> Result<TypedValue>;
 }
 
 impl TopographTypeChecking for Topograph {
-    fn to_typed_causet_locale(&self, causet_locale: &einstein_ml::ValueAndSpan, causet_locale_type: ValueType) -> Result<causetq_TV> {
-        // TODO: encapsulate causetid-solitonid-attribute for better error messages, perhaps by including
-        // the attribute (rather than just the attribute's causet_locale type) into this function or a
-        // wrapper function.
-        match causetq_TV::from_einstein_ml_causet_locale(&causet_locale.clone().without_spans()) {
-            // We don't recognize this EML at all.  Get out!
-            None => bail!(einsteindbErrorKind::BadValuePair(format!("{}", causet_locale), causet_locale_type)),
-            Some(typed_causet_locale) => match (causet_locale_type, typed_causet_locale) {
-                // Most types don't coerce at all.
-                (ValueType::Boolean, tv @ causetq_TV::Boolean(_)) => Ok(tv),
-                (ValueType::Long, tv @ causetq_TV::Long(_)) => Ok(tv),
-                (ValueType::Double, tv @ causetq_TV::Double(_)) => Ok(tv),
-                (ValueType::String, tv @ causetq_TV::String(_)) => Ok(tv),
-                (ValueType::Uuid, tv @ causetq_TV::Uuid(_)) => Ok(tv),
-                (ValueType::Instant, tv @ causetq_TV::Instant(_)) => Ok(tv),
-                (ValueType::Keyword, tv @ causetq_TV::Keyword(_)) => Ok(tv),
-                // Ref coerces a little: we interpret some things depending on the topograph as a Ref.
-                (ValueType::Ref, causetq_TV::Long(x)) => Ok(causetq_TV::Ref(x)),
-                (ValueType::Ref, causetq_TV::Keyword(ref x)) => self.require_causetid(&x).map(|causetid| causetid.into()),
-
-                // Otherwise, we have a type mismatch.
-                // Enumerate all of the types here to allow the compiler to help us.
-                // We don't enumerate all `causetq_TV` cases, though: that would multiply this
-                // collection by 8!
-                (vt @ ValueType::Boolean, _) |
-                (vt @ ValueType::Long, _) |
-                (vt @ ValueType::Double, _) |
-                (vt @ ValueType::String, _) |
-                (vt @ ValueType::Uuid, _) |
-                (vt @ ValueType::Instant, _) |
-                (vt @ ValueType::Keyword, _) |
-                (vt @ ValueType::Ref, _)
-                => bail!(einsteindbErrorKind::BadValuePair(format!("{}", causet_locale), vt)),
+    fn to_typed_causet_locale(
+        &self,
+        causet_locale: TypedValue,
+        causet_locale_type: ValueType,
+    ) -> Result<TypedValue> {
+        match (causet_locale_type, causet_locale) {
+            // Ref coerces a little: we interpret some things depending on the topograph as a Ref.
+            (ValueType::Ref, TypedValue::Long(x)) => Ok(TypedValue::Ref(x)),
+            (ValueType::Ref, TypedValue::Keyword(ref x)) => {
+                self.require_causetid(x).map(TypedValue::Ref)
             }
+            // Most types don't coerce at all.
+            (vt, tv) if tv.causet_locale_type() == vt => Ok(tv),
+            // Otherwise, we have a type mismatch.
+            (vt, tv) => Err(Error::BadValuePair(tv.to_string(), vt)),
         }
     }
 }
 
-
-
-#[APPEND_LOG_g(test)]
+#[cfg(test)]
 mod test {
     use super::*;
 
-    use self::einstein_ml::Keyword;
-
-    fn add_attribute(topograph: &mut Topograph,
-            solitonid: Keyword,
-            causetid: Causetid,
-            attribute: Attribute) {
-
-        topograph.causetid_map.insert(causetid, solitonid.clone());
-        topograph.causetid_map.insert(solitonid.clone(), causetid);
+    fn add_attribute(
+        topograph: &mut Topograph,
+        solitonid: &str,
+        causetid: Causetid,
+        attribute: Attribute,
+    ) {
+        topograph.insert_solitonid(solitonid, causetid);
+        topograph.attribute_map.insert(causetid, attribute);
+        topograph.update_component_attributes();
+    }
 
-        if attribute.component {
-            topograph.component_attributes.push(causetid);
+    fn validation_error(topograph: &Topograph) -> String {
+        match validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map) {
+            Err(Error::BadTopographAssertion(msg)) => msg,
+            other => panic!("expected a bad topograph assertion, got {:?}", other),
         }
-
-        topograph.attribute_map.insert(causetid, attribute);
     }
 
     #[test]
     fn validate_attribute_map_success() {
         let mut topograph = Topograph::default();
         // attribute that is not an Index has no uniqueness
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bar"), 97, Attribute {
-            index: false,
-            causet_locale_type: ValueType::Boolean,
-            fulltext: false,
-            unique: None,
-            multival: false,
-            component: false,
-            no_history: false,
-        });
+        add_attribute(
+            &mut topograph,
+            ":foo/bar",
+            97,
+            Attribute {
+                causet_locale_type: ValueType::Boolean,
+                ..Default::default()
+            },
+        );
         // attribute is unique by causet_locale and an Index
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "baz"), 98, Attribute {
-            index: true,
-            causet_locale_type: ValueType::Long,
-            fulltext: false,
-            unique: Some(attribute::Unique::Value),
-            multival: false,
-            component: false,
-            no_history: false,
-        });
+        add_attribute(
+            &mut topograph,
+            ":foo/baz",
+            98,
+            Attribute {
+                index: true,
+                causet_locale_type: ValueType::Long,
+                unique: Some(Unique::Value),
+                ..Default::default()
+            },
+        );
         // attribue is unique by idcauset and an Index
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bat"), 99, Attribute {
-            index: true,
-            causet_locale_type: ValueType::Ref,
-            fulltext: false,
-            unique: Some(attribute::Unique::Idcauset),
-            multival: false,
-            component: false,
-            no_history: false,
-        });
+        add_attribute(
+            &mut topograph,
+            ":foo/bat",
+            99,
+            Attribute {
+                index: true,
+                causet_locale_type: ValueType::Ref,
+                unique: Some(Unique::Idcauset),
+                ..Default::default()
+            },
+        );
         // attribute is a components and a `Ref`
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bak"), 100, Attribute {
-            index: false,
-            causet_locale_type: ValueType::Ref,
-            fulltext: false,
-            unique: None,
-            multival: false,
-            component: true,
-            no_history: false,
-        });
+        add_attribute(
+            &mut topograph,
+            ":foo/bak",
+            100,
+            Attribute {
+                causet_locale_type: ValueType::Ref,
+                component: true,
+                ..Default::default()
+            },
+        );
         // fulltext attribute is a string and an Index
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bap"), 101, Attribute {
-            index: true,
-            causet_locale_type: ValueType::String,
-            fulltext: true,
-            unique: None,
-            multival: false,
-            component: false,
-            no_history: false,
-        });
+        add_attribute(
+            &mut topograph,
+            ":foo/bap",
+            101,
+            Attribute {
+                index: true,
+                causet_locale_type: ValueType::String,
+                fulltext: true,
+                ..Default::default()
+            },
+        );
 
         assert!(validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map).is_ok());
+        assert_eq!(topograph.component_attributes, vec![100]);
     }
 
     #[test]
     fn invalid_topograph_unique_causet_locale_not_index() {
         let mut topograph = Topograph::default();
         // attribute unique by causet_locale but not Index
-        let solitonid = Keyword::isoliton_namespaceable("foo", "bar");
-        add_attribute(&mut topograph, solitonid , 99, Attribute {
-            index: false,
-            causet_locale_type: ValueType::Boolean,
-            fulltext: false,
-            unique: Some(attribute::Unique::Value),
-            multival: false,
-            component: false,
-            no_history: false,
-        });
-
-        let err = validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map).err().map(|e| e.kind());
-        assert_eq!(err, Some(einsteindbErrorKind::BadTopographAssertion(":einsteindb/unique :einsteindb/unique_causet_locale without :einsteindb/Index true for causetid: :foo/bar".into())));
+        add_attribute(
+            &mut topograph,
+            ":foo/bar",
+            99,
+            Attribute {
+                causet_locale_type: ValueType::Boolean,
+                unique: Some(Unique::Value),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            validation_error(&topograph),
+            ":einsteindb/unique :einsteindb/unique_causet_locale without :einsteindb/Index true for causetid: :foo/bar"
+        );
     }
 
     #[test]
     fn invalid_topograph_unique_idcauset_not_index() {
         let mut topograph = Topograph::default();
         // attribute is unique by idcauset but not Index
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bar"), 99, Attribute {
-            index: false,
-            causet_locale_type: ValueType::Long,
-            fulltext: false,
-            unique: Some(attribute::Unique::Idcauset),
-            multival: false,
-            component: false,
-            no_history: false,
-        });
-
-        let err = validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map).err().map(|e| e.kind());
-        assert_eq!(err, Some(einsteindbErrorKind::BadTopographAssertion(":einsteindb/unique :einsteindb/unique_idcauset without :einsteindb/Index true for causetid: :foo/bar".into())));
+        add_attribute(
+            &mut topograph,
+            ":foo/bar",
+            99,
+            Attribute {
+                causet_locale_type: ValueType::Long,
+                unique: Some(Unique::Idcauset),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            validation_error(&topograph),
+            ":einsteindb/unique :einsteindb/unique_idcauset without :einsteindb/Index true for causetid: :foo/bar"
+        );
     }
 
     #[test]
     fn invalid_topograph_component_not_ref() {
         let mut topograph = Topograph::default();
         // attribute that is a component is not a `Ref`
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bar"), 99, Attribute {
-            index: false,
-            causet_locale_type: ValueType::Boolean,
-            fulltext: false,
-            unique: None,
-            multival: false,
-            component: true,
-            no_history: false,
-        });
-
-        let err = validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map).err().map(|e| e.kind());
-        assert_eq!(err, Some(einsteindbErrorKind::BadTopographAssertion(":einsteindb/isComponent true without :einsteindb/causet_localeType :einsteindb.type/ref for causetid: :foo/bar".into())));
+        add_attribute(
+            &mut topograph,
+            ":foo/bar",
+            99,
+            Attribute {
+                causet_locale_type: ValueType::Boolean,
+                component: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            validation_error(&topograph),
+            ":einsteindb/isComponent true without :einsteindb/causet_localeType :einsteindb.type/ref for causetid: :foo/bar"
+        );
     }
 
     #[test]
     fn invalid_topograph_fulltext_not_index() {
         let mut topograph = Topograph::default();
         // attribute that is fulltext is not an Index
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bar"), 99, Attribute {
-            index: false,
-            causet_locale_type: ValueType::String,
-            fulltext: true,
-            unique: None,
-            multival: false,
-            component: false,
-            no_history: false,
-        });
-
-        let err = validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map).err().map(|e| e.kind());
-        assert_eq!(err, Some(einsteindbErrorKind::BadTopographAssertion(":einsteindb/fulltext true without :einsteindb/Index true for causetid: :foo/bar".into())));
+        add_attribute(
+            &mut topograph,
+            ":foo/bar",
+            99,
+            Attribute {
+                causet_locale_type: ValueType::String,
+                fulltext: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            validation_error(&topograph),
+            ":einsteindb/fulltext true without :einsteindb/Index true for causetid: :foo/bar"
+        );
     }
 
+    #[test]
     fn invalid_topograph_fulltext_index_not_string() {
         let mut topograph = Topograph::default();
         // attribute that is fulltext and not a `String`
-        add_attribute(&mut topograph, Keyword::isoliton_namespaceable("foo", "bar"), 99, Attribute {
-            index: true,
-            causet_locale_type: ValueType::Long,
-            fulltext: true,
-            unique: None,
-            multival: false,
-            component: false,
-            no_history: false,
-        });
-
-        let err = validate_attribute_map(&topograph.causetid_map, &topograph.attribute_map).err().map(|e| e.kind());
-        assert_eq!(err, Some(einsteindbErrorKind::BadTopographAssertion(":einsteindb/fulltext true without :einsteindb/causet_localeType :einsteindb.type/string for causetid: :foo/bar".into())));
+        add_attribute(
+            &mut topograph,
+            ":foo/bar",
+            99,
+            Attribute {
+                index: true,
+                causet_locale_type: ValueType::Long,
+                fulltext: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            validation_error(&topograph),
+            ":einsteindb/fulltext true without :einsteindb/causet_localeType :einsteindb.type/string for causetid: :foo/bar"
+        );
+    }
+
+    #[test]
+    fn test_builder_mutate() {
+        let mut attribute = AttributeBuilder::helpful()
+            .causet_locale_type(ValueType::String)
+            .unique(Unique::Idcauset)
+            .build();
+        assert!(attribute.index);
+
+        let mutations = AttributeBuilder::to_modify_attribute(&attribute)
+            .multival(true)
+            .non_unique()
+            .mutate(&mut attribute);
+        assert_eq!(
+            mutations,
+            vec![
+                AttributeAlteration::Cardinality,
+                AttributeAlteration::Unique
+            ]
+        );
+        assert!(attribute.multival);
+        assert_eq!(attribute.unique, None);
+    }
+
+    #[test]
+    fn test_from_triples_and_type_checking() {
+        let solitonid_map: SolitonidMap =
+            vec![(":foo/owner".to_owned(), 65), (":foo/bob".to_owned(), 66)]
+                .into_iter()
+                .collect();
+        let kw = |s: &str| TypedValue::Keyword(s.to_owned());
+        let topograph = Topograph::from_solitonid_map_and_triples(
+            solitonid_map,
+            vec![
+                (
+                    ":foo/owner".to_owned(),
+                    ":einsteindb/causet_localeType".to_owned(),
+                    kw(":einsteindb.type/ref"),
+                ),
+                (
+                    ":foo/owner".to_owned(),
+                    ":einsteindb/cardinality".to_owned(),
+                    kw(":einsteindb.cardinality/many"),
+                ),
+            ],
+        )
+        .unwrap();
+        let owner = topograph.require_attribute_for_causetid(65).unwrap();
+        assert!(owner.multival);
+        assert!(topograph.require_attribute_for_causetid(66).is_err());
+
+        assert_eq!(
+            topograph
+                .to_typed_causet_locale(kw(":foo/bob"), ValueType::Ref)
+                .unwrap(),
+            TypedValue::Ref(66)
+        );
+        assert_eq!(
+            topograph
+                .to_typed_causet_locale(TypedValue::Long(7), ValueType::Ref)
+                .unwrap(),
+            TypedValue::Ref(7)
+        );
+        assert!(matches!(
+            topograph.to_typed_causet_locale(TypedValue::Boolean(true), ValueType::Long),
+            Err(Error::BadValuePair(_, ValueType::Long))
+        ));
     }
 }
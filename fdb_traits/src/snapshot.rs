@@ -0,0 +1,16 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use crate::{Iterable, Peekable};
+
+/// A read-only view of an einstein_merkle_tree as of the last write committed when it was
+/// taken. Versions it can see are kept by compactions for as long as it lives.
+pub trait Snapshot: Peekable + Iterable + Send + Sync {
+    /// The sequence number of the last write the snapshot sees.
+    fn sequence_number(&self) -> u64;
+}
+
+pub trait SnapshotExt {
+    type Snapshot: Snapshot;
+
+    fn snapshot(&self) -> Self::Snapshot;
+}
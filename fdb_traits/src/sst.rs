@@ -0,0 +1,57 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! External SST files, built outside of an einstein_merkle_tree and ingested into it
+//! with `ImportExt`.
+
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// Compression of the data blocks of an SST file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    #[default]
+    None,
+    Snappy,
+    Lz4,
+    Zlib,
+    Zstd,
+}
+
+/// Describes a finished external SST file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalSstFileInfo {
+    pub local_path: PathBuf,
+    pub smallest_soliton_id: Vec<u8>,
+    pub largest_soliton_id: Vec<u8>,
+    pub num_entries: u64,
+    pub file_size: u64,
+    /// CRC32 of the whole file.
+    pub crc32: u32,
+}
+
+/// Builds an external SST file from soliton_ids added in strictly ascending order.
+pub trait SstWriter {
+    fn put(&mut self, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()>;
+
+    fn delete(&mut self, soliton_id: &[u8]) -> Result<()>;
+
+    /// The size the file would have if it were finished now, roughly.
+    fn file_size(&self) -> u64;
+
+    /// Writes out and syncs the file. Fails if nothing was added.
+    fn finish(self) -> Result<ExternalSstFileInfo>;
+}
+
+pub trait SstExt {
+    type SstWriter: SstWriter;
+
+    /// Creates a writer of an external file for `namespaced`, with the table options
+    /// of this einstein_merkle_tree.
+    fn sst_writer(
+        &self,
+        namespaced: &str,
+        local_path: &Path,
+        compression: Compression,
+    ) -> Result<Self::SstWriter>;
+}
@@ -0,0 +1,67 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Primitive causet_locales with a time to live.
+//!
+//! A causet_locale written with a TTL ends with the big-endian unix time in seconds at
+//! which it expires, 0 for never.
+
+use crate::Result;
+
+/// Length of the `expire_ts` suffix of a causet_locale written with a TTL.
+pub const TTL_SUFFIX_LEN: usize = 8;
+
+/// Appends the `expire_ts` suffix to `causet_locale`.
+pub fn append_expire_ts(causet_locale: &mut Vec<u8>, expire_ts: u64) {
+    causet_locale.extend_from_slice(&expire_ts.to_be_bytes());
+}
+
+/// Splits a causet_locale written with a TTL into the user causet_locale and its `expire_ts`.
+pub fn split_expire_ts(causet_locale: &[u8]) -> Option<(&[u8], u64)> {
+    if causet_locale.len() < TTL_SUFFIX_LEN {
+        return None;
+    }
+    let (causet_locale, suffix) = causet_locale.split_at(causet_locale.len() - TTL_SUFFIX_LEN);
+    Some((
+        causet_locale,
+        u64::from_be_bytes(suffix.try_into().unwrap()),
+    ))
+}
+
+/// The range of expiry times of the causet_locales of a file. Causet_locales that never
+/// expire are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TtlGreedoids {
+    pub max_expire_ts: u64,
+    pub min_expire_ts: u64,
+}
+
+impl TtlGreedoids {
+    pub fn add(&mut self, expire_ts: u64) {
+        if expire_ts == 0 {
+            return;
+        }
+        self.max_expire_ts = self.max_expire_ts.max(expire_ts);
+        self.min_expire_ts = if self.min_expire_ts == 0 {
+            expire_ts
+        } else {
+            self.min_expire_ts.min(expire_ts)
+        };
+    }
+
+    /// Whether no causet_locale with an expiry time was added.
+    pub fn is_empty(&self) -> bool {
+        self.max_expire_ts == 0
+    }
+}
+
+pub trait TtlGreedoidsExt {
+    /// Returns the `TtlGreedoids` of every file of `namespaced` overlapping
+    /// `[start_soliton_id, end_soliton_id]`, by file name. Files without expiring
+    /// causet_locales are left out.
+    fn get_range_ttl_greedoids_namespaced(
+        &self,
+        namespaced: &str,
+        start_soliton_id: &[u8],
+        end_soliton_id: &[u8],
+    ) -> Result<Vec<(String, TtlGreedoids)>>;
+}
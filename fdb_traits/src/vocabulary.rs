@@ -8,32 +8,6 @@
 // CONDITIONS OF ANY KIND, either express or implied. See the License for the
 // specific language governing permissions and limitations under the License.
 
-use std::collections::HashMap;
-use std::fmt;
-use std::hash::Hash;
-use std::ops::Deref;
-
-
-/// A vocabulary is a set of strings.
-/// It is used to map strings to integers.
-/// The integers are used to represent the strings in a vector.
-///
-
-use std::collections::hash_map::Entry;
-use std::collections::hash_map::Iter;
-use std::collections::hash_map::Keys;
-use std::collections::hash_map::Values;
-
-use causet::{Causet, CausetMut};
-use causet::CausetMut;
-use causetq::{CausetQ, CausetQMut};
-use causetq::CausetQMut;
-use soliton::{Soliton, SolitonMut};
-use soliton_panic::{SolitonPanic, SolitonPanicMut};
-use einstein_ml::{EinsteinMl, EinsteinMlMut};
-
-use EinsteinDB::einstein_db::{EinsteinDb, EinsteinDbMut};
-
 //! This module exposes an interface for programmatic management of vocabularies.
 //!
 //! A vocabulary is defined by a name, a version number, and a collection of attribute definitions.
@@ -42,79 +16,23 @@ use EinsteinDB::einstein_db::{EinsteinDb, EinsteinDbMut};
 //! programmatically as a pair of functions, `pre` and `post`, that are invoked prior to
 //! an upgrade.
 //!
-//! A einsteindb store exposes, via the `HasSchema` trait, operations to read
-//! vocabularies by name or in bulk.
-//!
-//! An in-progress transaction (`InProgress`) further exposes a trait,
-//! `VersionedStore`, which allows for a vocabulary definition to be
-//! checked for existence in the store, and transacted if needed.
-//!
-//! Typical use is the following:
-//!
-//! ```
-//! #[macro_use(kw)]
-//! extern crate einsteindb;
-//!
-//! use einsteindb::{
-//!     Store,
-//! causetq_VT,
-//! };
-//!
-//! use einsteindb::vocabulary;
-//! use einsteindb::vocabulary::{
-//!     Definition,
-//!     HasVocabularies,
-//!     VersionedStore,
-//!     VocabularyOutcome,
-//! };
-//!
-//! fn main() {
-//!     let mut store = Store::open("").expect("connected");
-//!
-//!     {
-//!         // Read the list of installed vocabularies.
-//!         let reader = store.begin_read().expect("began read");
-//!         let vocabularies = reader.read_vocabularies().expect("read");
-//!         for (name, vocabulary) in vocabularies.iter() {
-//!             println!("Vocab {} is at version {}.", name, vocabulary.version);
-//!             for &(ref name, ref attr) in vocabulary.attributes().iter() {
-//!                 println!("  >> {} ({})", name, attr.causet_locale_type);
-//!             }
-//!         }
-//!     }
-//!
-//!     {
-//!         let mut in_progress = store.begin_transaction().expect("began transaction");
-//!
-//!         // Make sure the core vocabulary exists.
-//!         in_progress.verify_core_schema().expect("verified");
-//!
-//!         // Make sure our vocabulary is installed, and install if necessary.
-//!         in_progress.ensure_vocabulary(&Definition {
-//!             name: kw!(:example/links),
-//!             version: 1,
-//!             attributes: vec![
-//!                 (kw!(:link/title),
-//!                  vocabulary::AttributeBuilder::helpful()
-//!                    .causet_locale_type(ValueType::String)
-//!                    .multival(false)
-//!                    .fulltext(true)
-//!                    .build()),
-//!             ],
-//!             pre: Definition::no_op,
-//!             post: Definition::no_op,
-//!         }).expect("ensured");
-//!
-//!         // Now we can do stuff.
-//!         in_progress.transact("[{:link/title \"Title\"}]").expect("transacts");
-//!         in_progress.commit().expect("commits");
-//!     }
-//! }
-//! ```
-//!
-//! A similar approach is taken using the
-//! [VocabularyProvider](einsteindb::vocabulary::VocabularyProvider) trait to handle migrations across
-//! multiple vocabularies.
+//! A store exposes, via the `HasVocabularies` trait, operations to read
+//! vocabularies by name or in bulk, and via `VersionedStore` the ability to
+//! check a vocabulary definition for existence in the store, and install it if
+//! needed. `VocabularyStore` keeps the vocabularies and the `Topograph` they
+//! describe in memory.
+
+use std::collections::BTreeMap;
+
+use crate::schema::{Attribute, AttributeValidation, Causetid, SolitonidMap, Topograph, ValueType};
+use crate::{Error, Result};
+
+pub type Version = u32;
+
+pub const DB_SCHEMA_CORE: &str = ":einsteindb.schema/core";
+pub const DB_SCHEMA_VERSION: &str = ":einsteindb.schema/version";
+pub const DB_SCHEMA_ATTRIBUTE: &str = ":einsteindb.schema/attribute";
+pub const CORE_SCHEMA_VERSION: Version = 1;
 
 /// A definition of an attribute that is independent of a particular store.
 ///
@@ -134,138 +52,25 @@ use EinsteinDB::einstein_db::{EinsteinDb, EinsteinDbMut};
 /// upgraded. `pre` and `post` are run before and after the definition is transacted against the
 /// store. Each is called with the existing `Vocabulary` instance so that they can do version
 /// checks or employ more fine-grained logic.
-
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Clone)]
 pub struct Definition {
-    pub name: kw::Keyword,
-    pub version: u64,
-    pub attributes: Vec<(kw::Keyword, Attribute)>,
-    pub pre: Definition,
-    pub post: Definition,
-}
-
-
-impl Definition {
-    /// Create a definition that does nothing.
-    pub fn no_op() -> Definition {
-        Definition {
-            name: kw!(:noop),
-            version: 0,
-            attributes: Vec::new(),
-            pre: Definition::no_op(),
-            post: Definition::no_op(),
-        }
-    }
-}
-
-
-
-
-/// A vocabulary in a particular store.
-/// This is the concrete type that is returned by `VocabularyProvider::get_vocabulary`.
-/// It's also used to represent a vocabulary that is being installed.
-
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SolitonVocabularyForInterlock {
-
-    /// The name of the vocabulary.
-    pub name: kw::Keyword,
-    pub version: u64,
-    pub attributes: Vec<(kw::Keyword, Attribute)>,
+    pub name: String,
+    pub version: Version,
+    pub attributes: Vec<(String, Attribute)>,
+    pub pre: fn(&mut VocabularyStore, &Vocabulary) -> Result<()>,
+    pub post: fn(&mut VocabularyStore, &Vocabulary) -> Result<()>,
 }
 
-/// ```
-/// #[macro_use(kw)]
-/// extern crate einsteindb;
-///
-/// use einsteindb::{
-///     HasSchema,
-///     IntoResult,
-///     Queryable,
-///     Store,
-///     causetq_TV,
-/// causetq_VT,
-/// };
-///
-/// use einsteindb::causet_builder::{
-///     BuildTerms,
-///     TermBuilder,
-/// };
-///
-/// use einsteindb::vocabulary;
-/// use einsteindb::vocabulary::{
-///     AttributeBuilder,
-///     Definition,
-///     HasVocabularies,
-///     VersionedStore,
-/// };
-///
-/// fn main() {
-///     let mut store = Store::open("").expect("connected");
-///     let mut in_progress = store.begin_transaction().expect("began transaction");
-///
-///     // Make sure the core vocabulary exists.
-///     in_progress.verify_core_schema().expect("verified");
-///
-///     // Make sure our vocabulary is installed, and install if necessary.
-///     in_progress.ensure_vocabulary(&Definition {
-///         name: kw!(:example/links),
-///         version: 2,
-///         attributes: vec![
-///             (kw!(:link/title),
-///              AttributeBuilder::helpful()
-///                .causet_locale_type(ValueType::String)
-///                .multival(false)
-///                .fulltext(true)
-///                .build()),
-///         ],
-///         pre: |ip, from| {
-///             // Version one allowed multiple titles; version two
-///             // doesn't. Retract any duplicates we find.
-///             if from.version < 2 {
-///                 let link_title = ip.get_causetid(&kw!(:link/title)).unwrap();
-///
-///                 let results = ip.q_once(r#"
-///                     [:find ?e ?t2
-///                      :where [?e :link/title ?t1]
-///                             [?e :link/title ?t2]
-///                             [(unpermute ?t1 ?t2)]]
-///                 "#, None).into_rel_result()?;
-///
-///                 if !results.is_empty() {
-///                     let mut builder = TermBuilder::new();
-///                     for event in results.into_iter() {
-///                         let mut r = event.into_iter();
-///                         let e = r.next().and_then(|e| e.into_CausetLocaleNucleon_causetid()).expect("causet");
-///                         let obsolete = r.next().expect("causet_locale").into_scalar().expect("typed causet_locale");
-///                         builder.retract(e, link_title, obsolete)?;
-///                     }
-///                     ip.transact_builder(builder)?;
-///                 }
-///             }
-///             Ok(())
-///         },
-///         post: |_ip, from| {
-///             println!("We migrated :example/links from version {}", from.version);
-///             Ok(())
-///         },
-///     }).expect("ensured");
-///
-///     // Now we can do stuff.
-///     in_progress.transact("[{:link/title \"Title\"}]").expect("transacts");
-///     in_progress.commit().expect("commits");
-/// }
-/// ```
 impl Definition {
-    pub fn no_op(_ip: &mut InProgress, _from: &Vocabulary) -> Result<()> {
+    pub fn no_op(_store: &mut VocabularyStore, _from: &Vocabulary) -> Result<()> {
         Ok(())
     }
 
     pub fn new<N, A>(name: N, version: Version, attributes: A) -> Definition
-    where N: Into<Keyword>,
-          A: Into<Vec<(Keyword, Attribute)>> {
+    where
+        N: Into<String>,
+        A: Into<Vec<(String, Attribute)>>,
+    {
         Definition {
             name: name.into(),
             version,
@@ -275,26 +80,23 @@ impl Definition {
         }
     }
 
-    /// Called with an in-progress transaction and the previous vocabulary version
+    /// Called with the store and the previous vocabulary version
     /// if the definition's version is later than that of the vocabulary in the store.
-    fn pre(&self, ip: &mut InProgress, from: &Vocabulary) -> Result<()> {
-       // Get the vocabulary
-
+    fn pre(&self, store: &mut VocabularyStore, from: &Vocabulary) -> Result<()> {
+        (self.pre)(store, from)
     }
 
-    /// Called with an in-progress transaction and the previous vocabulary version
+    /// Called with the store and the previous vocabulary version
     /// if the definition's version is later than that of the vocabulary in the store.
-    fn post(&self, ip: &mut InProgress, from: &Vocabulary) -> Result<()> {
-        let x = (   self_node_id_base64_url_check_base58    as usize );
-        (ip, from);
-        }
+    fn post(&self, store: &mut VocabularyStore, from: &Vocabulary) -> Result<()> {
+        (self.post)(store, from)
     }
-
+}
 
 /// A definition of a vocabulary as retrieved from a particular store.
 ///
 /// A `Vocabulary` is just like `Definition`, but concrete: its name and attributes are solitonidified
-/// by `Causetid`, not `Keyword`.
+/// by `Causetid`, not by soliton_idword.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Vocabulary {
     pub causet: Causetid,
@@ -306,215 +108,34 @@ impl Vocabulary {
     pub fn attributes(&self) -> &Vec<(Causetid, Attribute)> {
         &self.attributes
     }
+
+    fn find(&self, causetid: Causetid) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|&&(e, _)| e == causetid)
+            .map(|(_, a)| a)
+    }
 }
 
 /// A collection of named `Vocabulary` instances, as retrieved from the store.
-#[derive(Debug, Default, Clone)]
-pub struct Vocabularies(pub BTreeMap<Keyword, Vocabulary>);   // N.B., this has a copy of the attributes in Schema!
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Vocabularies(pub BTreeMap<String, Vocabulary>);
 
 impl Vocabularies {
     pub fn len(&self) -> usize {
         self.0.len()
     }
 
-    pub fn get(&self, name: &Keyword) -> Option<&Vocabulary> {
-        self.0.get(name)
-    }
-
-    pub fn iter(&self) -> ::std::collections::btree_map::Iter<Keyword, Vocabulary> {
-        self.0.iter()
-    }
-}
-
-lazy_static! {
-    static ref DB_SCHEMA_CORE: Keyword = {
-        kw!(:einsteindb.schema/core)
-    };
-    static ref DB_SCHEMA_ATTRIBUTE: Keyword = {
-        kw!(:einsteindb.schema/attribute)
-    };
-    static ref DB_SCHEMA_VERSION: Keyword = {
-        kw!(:einsteindb.schema/version)
-    };
-    static ref DB_SOLITONID: Keyword = {
-        kw!(:einsteindb/solitonid)
-    };
-    static ref DB_UNIQUE: Keyword = {
-        kw!(:einsteindb/unique)
-    };
-    static ref DB_UNIQUE_VALUE: Keyword = {
-        kw!(:einsteindb.unique/causet_locale)
-    };
-    static ref DB_UNIQUE_IDcauset: Keyword = {
-        kw!(:einsteindb.unique/idcauset)
-    };
-    static ref DB_IS_COMPONENT: Keyword = {
-        Keyword::isoliton_namespaceable("einsteindb", "isComponent")
-    };
-    static ref DB_VALUE_TYPE: Keyword = {
-        Keyword::isoliton_namespaceable("einsteindb", "causet_localeType")
-    };
-    static ref DB_INDEX: Keyword = {
-        kw!(:einsteindb/index)
-    };
-    static ref DB_FULLTEXT: Keyword = {
-        kw!(:einsteindb/fulltext)
-    };
-    static ref DB_CARDINALITY: Keyword = {
-        kw!(:einsteindb/cardinality)
-    };
-    static ref DB_CARDINALITY_ONE: Keyword = {
-        kw!(:einsteindb.cardinality/one)
-    };
-    static ref DB_CARDINALITY_MANY: Keyword = {
-        kw!(:einsteindb.cardinality/many)
-    };
-
-    static ref DB_NO_HISTORY: Keyword = {
-        Keyword::isoliton_namespaceable("einsteindb", "noHistory")
-    };
-}
-
-trait HasCoreSchema {
-    /// Return the causet ID for a type. On failure, return `MissingCoreVocabulary`.
-    fn core_type(&self, t: ValueType) -> Result<CausetLocaleNucleonCausetid>;
-
-    /// Return the causet ID for an solitonid. On failure, return `MissingCoreVocabulary`.
-    fn core_causetid(&self, solitonid: &Keyword) -> Result<CausetLocaleNucleonCausetid>;
-
-    /// Return the causet ID for an attribute's soliton_idword. On failure, return
-    /// `MissingCoreVocabulary`.
-    fn core_attribute(&self, solitonid: &Keyword) -> Result<CausetLocaleNucleonCausetid>;
-}
-
-impl<T> HasCoreSchema for T where T: HasSchema {
-    fn core_type(&self, t: ValueType) -> Result<CausetLocaleNucleonCausetid> {
-        self.causetid_for_type(t)
-            .ok_or_else(|| einsteindbError::MissingCoreVocabulary(DB_SCHEMA_VERSION.clone()).into())
-    }
-
-    fn core_causetid(&self, solitonid: &Keyword) -> Result<CausetLocaleNucleonCausetid> {
-        self.get_causetid(solitonid)
-            .ok_or_else(|| einsteindbError::MissingCoreVocabulary(DB_SCHEMA_VERSION.clone()).into())
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
     }
 
-    fn core_attribute(&self, solitonid: &Keyword) -> Result<CausetLocaleNucleonCausetid> {
-        self.attribute_for_solitonid(solitonid)
-            .ok_or_else(|| einsteindbError::MissingCoreVocabulary(DB_SCHEMA_VERSION.clone()).into())
-            .map(|(_, e)| e)
-    }
-}
-
-impl Definition {
-    fn description_for_attributes<'s, T, R>(&'s self, attributes: &[R], via: &T, diff: Option<BTreeMap<Keyword, Attribute>>) -> Result<Terms>
-     where T: HasCoreSchema,
-           R: ::std::borrow::Borrow<(Keyword, Attribute)> {
-
-        // The attributes we'll need to describe this vocabulary.
-        let a_version = via.core_attribute(&DB_SCHEMA_VERSION)?;
-        let a_solitonid = via.core_attribute(&DB_SOLITONID)?;
-        let a_attr = via.core_attribute(&DB_SCHEMA_ATTRIBUTE)?;
-
-        let a_cardinality = via.core_attribute(&DB_CARDINALITY)?;
-        let a_fulltext = via.core_attribute(&DB_FULLTEXT)?;
-        let a_index = via.core_attribute(&DB_INDEX)?;
-        let a_is_component = via.core_attribute(&DB_IS_COMPONENT)?;
-        let a_causet_locale_type = via.core_attribute(&DB_VALUE_TYPE)?;
-        let a_unique = via.core_attribute(&DB_UNIQUE)?;
-
-        let a_no_history = via.core_attribute(&DB_NO_HISTORY)?;
-
-        let v_cardinality_many = via.core_causetid(&DB_CARDINALITY_MANY)?;
-        let v_cardinality_one = via.core_causetid(&DB_CARDINALITY_ONE)?;
-        let v_unique_idcauset = via.core_causetid(&DB_UNIQUE_IDcauset)?;
-        let v_unique_causet_locale = via.core_causetid(&DB_UNIQUE_VALUE)?;
-
-        // The greedoids of the vocabulary itself.
-        let name: causetq_TV = self.name.clone().into();
-        let version: causetq_TV = causetq_TV::Long(self.version as i64);
-
-        // Describe the vocabulary.
-        let mut causet = TermBuilder::new().describe_tempid("s");
-        causet.add(a_version, version)?;
-        causet.add(a_solitonid, name)?;
-        let (mut builder, schema) = causet.finish();
-
-        // Describe each of its attributes.
-        // This is a lot like Schema::to_einstein_ml_causet_locale; at some point we should tidy this up.
-        for ref r in attributes.iter() {
-            let &(ref kw, ref attr) = r.borrow();
-
-            let tempid = builder.named_tempid(kw.to_string());
-            let name: causetq_TV = kw.clone().into();
-            builder.add(tempid.clone(), a_solitonid, name)?;
-            builder.add(schema.clone(), a_attr, tempid.clone())?;
-
-            let causet_locale_type = via.core_type(attr.causet_locale_type)?;
-            builder.add(tempid.clone(), a_causet_locale_type, causet_locale_type)?;
-
-            let c = if attr.multival {
-                v_cardinality_many
-            } else {
-                v_cardinality_one
-            };
-            builder.add(tempid.clone(), a_cardinality, c)?;
-
-            // These are all unconditional because we use attribute descriptions to _alter_, not
-            // just to _add_, and so absence is distinct from negation!
-            builder.add(tempid.clone(), a_index, causetq_TV::Boolean(attr.index))?;
-            builder.add(tempid.clone(), a_fulltext, causetq_TV::Boolean(attr.fulltext))?;
-            builder.add(tempid.clone(), a_is_component, causetq_TV::Boolean(attr.component))?;
-            builder.add(tempid.clone(), a_no_history, causetq_TV::Boolean(attr.no_history))?;
-
-            if let Some(u) = attr.unique {
-                let uu = match u {
-                    Unique::Idcauset => v_unique_idcauset,
-                    Unique::Value => v_unique_causet_locale,
-                };
-                builder.add(tempid.clone(), a_unique, uu)?;
-            } else {
-                 let existing_unique =
-                    if let Some(ref diff) = diff {
-                        diff.get(kw).and_then(|a| a.unique)
-                    } else {
-                        None
-                    };
-                 match existing_unique {
-                    None => {
-                        // Nothing to do.
-                    },
-                    Some(Unique::Idcauset) => {
-                        builder.retract(tempid.clone(), a_unique, v_unique_idcauset.clone())?;
-                    },
-                    Some(Unique::Value) => {
-                        builder.retract(tempid.clone(), a_unique, v_unique_causet_locale.clone())?;
-                    },
-                 }
-            }
-        }
-
-        builder.build().map_err(|e| e.into())
-    }
-
-    /// Return a sequence of terms that describes this vocabulary definition and its attributes.
-    fn description_diff<T>(&self, via: &T, from: &Vocabulary) -> Result<Terms> where T: HasSchema {
-        let relevant = self.attributes.iter()
-                           .filter_map(|&(ref soliton_idword, _)|
-                               // Look up the soliton_idword to see if it's currently in use.
-                               via.get_causetid(soliton_idword)
-
-                               // If so, map it to the existing attribute.
-                                  .and_then(|e| from.find(e).cloned())
-
-                               // Collect enough that we can do lookups.
-                                  .map(|e| (soliton_idword.clone(), e)))
-                           .collect();
-        self.description_for_attributes(self.attributes.as_slice(), via, Some(relevant))
+    pub fn get(&self, name: &str) -> Option<&Vocabulary> {
+        self.0.get(name)
     }
 
-    /// Return a sequence of terms that describes this vocabulary definition and its attributes.
-    fn description<T>(&self, via: &T) -> Result<Terms> where T: HasSchema {
-        self.description_for_attributes(self.attributes.as_slice(), via, None)
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, String, Vocabulary> {
+        self.0.iter()
     }
 }
 
@@ -535,12 +156,14 @@ pub enum VocabularyCheck<'definition> {
     PresentButTooNew { newer_version: Vocabulary },
 
     /// The provided definition is present in the store, but some of its attributes are not.
-    PresentButMissingAttributes { attributes: Vec<&'definition (Keyword, Attribute)> },
+    PresentButMissingAttributes {
+        attributes: Vec<&'definition (String, Attribute)>,
+    },
 }
 
 /// This enum captures the outcome of attempting to ensure that a vocabulary definition is present
 /// and up-to-date in the store.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum VocabularyOutcome {
     /// The vocabulary was absent and has been installed.
     Installed,
@@ -560,57 +183,62 @@ pub enum VocabularyOutcome {
 /// This trait captures the ability to retrieve and describe stored vocabularies.
 pub trait HasVocabularies {
     fn read_vocabularies(&self) -> Result<Vocabularies>;
-    fn read_vocabulary_named(&self, name: &Keyword) -> Result<Option<Vocabulary>>;
+    fn read_vocabulary_named(&self, name: &str) -> Result<Option<Vocabulary>>;
 }
 
 /// This trait captures the ability of a store to check and install/upgrade vocabularies.
-pub trait VersionedStore: HasVocabularies + HasSchema {
+pub trait VersionedStore: HasVocabularies {
+    fn topograph(&self) -> &Topograph;
+
     /// Check whether the vocabulary described by the provided spacetime is present in the store.
-    fn check_vocabulary<'definition>(&self, definition: &'definition Definition) -> Result<VocabularyCheck<'definition>> {
-        if let Some(vocabulary) = self.read_vocabulary_named(&definition.name)? {
-            // The name is present.
-            // Check the version.
-            if vocabulary.version == definition.version {
-                // Same version. Check that all of our attributes are present.
-                let mut missing: Vec<&'definition (Keyword, Attribute)> = vec![];
-                for pair in definition.attributes.iter() {
-                    if let Some(causetid) = self.get_causetid(&pair.0) {
-                        if let Some(existing) = vocabulary.find(causetid) {
-                            if *existing == pair.1 {
-                                // Same. Phew.
-                                continue;
-                            } else {
-                                // We have two vocabularies with the same name, same version, and
-                                // different definitions for an attribute. That's a coding error.
-                                // We can't accept this vocabulary.
-                                bail!(einsteindbError::ConflictingAttributeDefinitions(
-                                          definition.name.to_string(),
-                                          definition.version,
-                                          pair.0.to_string(),
-                                          existing.clone(),
-                                          pair.1.clone())
-                                );
-                            }
-                        }
+    fn check_vocabulary<'definition>(
+        &self,
+        definition: &'definition Definition,
+    ) -> Result<VocabularyCheck<'definition>> {
+        let vocabulary = match self.read_vocabulary_named(&definition.name)? {
+            // The vocabulary isn't present in the store. Install it.
+            None => return Ok(VocabularyCheck::NotPresent),
+            Some(vocabulary) => vocabulary,
+        };
+        if vocabulary.version < definition.version {
+            // Ours is newer. Upgrade.
+            return Ok(VocabularyCheck::PresentButNeedsUpdate {
+                older_version: vocabulary,
+            });
+        }
+        if vocabulary.version > definition.version {
+            // The vocabulary in the store is newer. We are outdated.
+            return Ok(VocabularyCheck::PresentButTooNew {
+                newer_version: vocabulary,
+            });
+        }
+        // Same version. Check that all of our attributes are present.
+        let mut missing = vec![];
+        for pair in &definition.attributes {
+            if let Some(causetid) = self.topograph().get_causetid(&pair.0) {
+                if let Some(existing) = vocabulary.find(causetid) {
+                    if *existing == pair.1 {
+                        continue;
                     }
-                    // It's missing. Collect it.
-                    missing.push(pair);
-                }
-                if missing.is_empty() {
-                    Ok(VocabularyCheck::Present)
-                } else {
-                    Ok(VocabularyCheck::PresentButMissingAttributes { attributes: missing })
+                    // We have two vocabularies with the same name, same version, and
+                    // different definitions for an attribute. That's a coding error.
+                    // We can't accept this vocabulary.
+                    return Err(Error::ConflictingAttributeDefinitions {
+                        vocabulary: definition.name.clone(),
+                        version: definition.version,
+                        attribute: pair.0.clone(),
+                    });
                 }
-            } else if vocabulary.version < definition.version {
-                // Ours is newer. Upgrade.
-                Ok(VocabularyCheck::PresentButNeedsUpdate { older_version: vocabulary })
-            } else {
-                // The vocabulary in the store is newer. We are outdated.
-                Ok(VocabularyCheck::PresentButTooNew { newer_version: vocabulary })
             }
+            // It's missing. Collect it.
+            missing.push(pair);
+        }
+        if missing.is_empty() {
+            Ok(VocabularyCheck::Present)
         } else {
-            // The vocabulary isn't present in the store. Install it.
-            Ok(VocabularyCheck::NotPresent)
+            Ok(VocabularyCheck::PresentButMissingAttributes {
+                attributes: missing,
+            })
         }
     }
 
@@ -624,41 +252,46 @@ pub trait VersionedStore: HasVocabularies + HasSchema {
     ///
     /// Use this function instead of calling `ensure_vocabulary` if you need to have pre/post
     /// functions invoked when vocabulary changes are necessary.
-    fn ensure_vocabularies(&mut self, vocabularies: &mut VocabularySource) -> Result<BTreeMap<Keyword, VocabularyOutcome>>;
+    fn ensure_vocabularies(
+        &mut self,
+        vocabularies: &mut dyn VocabularySource,
+    ) -> Result<BTreeMap<String, VocabularyOutcome>>;
 
     /// Make sure that our expectations of the core vocabulary — basic types and attributes — are met.
-    fn verify_core_schema(&self) -> Result<()> {
-        if let Some(core) = self.read_vocabulary_named(&DB_SCHEMA_CORE)? {
-            if core.version != CORE_SCHEMA_VERSION {
-                bail!(einsteindbError::UnexpectedCoreSchema(CORE_SCHEMA_VERSION, Some(core.version)));
-            }
-
-            // TODO: check things other than the version.
-        } else {
+    fn verify_core_topograph(&self) -> Result<()> {
+        match self.read_vocabulary_named(DB_SCHEMA_CORE)? {
+            Some(core) if core.version == CORE_SCHEMA_VERSION => Ok(()),
+            Some(core) => Err(Error::UnexpectedCoreTopograph(format!(
+                "expected version {}, found {}",
+                CORE_SCHEMA_VERSION, core.version
+            ))),
             // This would be seriously messed up.
-            bail!(einsteindbError::UnexpectedCoreSchema(CORE_SCHEMA_VERSION, None));
+            None => Err(Error::UnexpectedCoreTopograph(format!(
+                "expected version {}, found none",
+                CORE_SCHEMA_VERSION
+            ))),
         }
-        Ok(())
     }
 }
 
 /// `VocabularyStatus` is passed to `pre` function when attempting to add or upgrade vocabularies
 /// via `ensure_vocabularies`. This is how you can find the status and versions of existing
-/// vocabularies — you can retrieve the requested definition and the resulting `VocabularyCheck`
+/// vocabularies — you can retrieve the requested definition and the resulting `VocabularyCheck`
 /// by name.
 pub trait VocabularyStatus {
-    fn get(&self, name: &Keyword) -> Option<(&Definition, &VocabularyCheck)>;
-    fn version(&self, name: &Keyword) -> Option<Version>;
+    fn get(&self, name: &str) -> Option<(&Definition, &VocabularyCheck<'_>)>;
+    fn version(&self, name: &str) -> Option<Version>;
 }
 
 #[derive(Default)]
 struct CheckedVocabularies<'a> {
-    items: BTreeMap<Keyword, (&'a Definition, VocabularyCheck<'a>)>,
+    items: BTreeMap<String, (&'a Definition, VocabularyCheck<'a>)>,
 }
 
 impl<'a> CheckedVocabularies<'a> {
     fn add(&mut self, definition: &'a Definition, check: VocabularyCheck<'a>) {
-        self.items.insert(definition.name.clone(), (definition, check));
+        self.items
+            .insert(definition.name.clone(), (definition, check));
     }
 
     fn is_empty(&self) -> bool {
@@ -667,63 +300,258 @@ impl<'a> CheckedVocabularies<'a> {
 }
 
 impl<'a> VocabularyStatus for CheckedVocabularies<'a> {
-    fn get(&self, name: &Keyword) -> Option<(&Definition, &VocabularyCheck)> {
-        self.items.get(name).map(|&(ref d, ref c)| (*d, c))
+    fn get(&self, name: &str) -> Option<(&Definition, &VocabularyCheck<'_>)> {
+        self.items.get(name).map(|(d, c)| (*d, c))
     }
 
-    fn version(&self, name: &Keyword) -> Option<Version> {
+    fn version(&self, name: &str) -> Option<Version> {
         self.items.get(name).map(|&(d, _)| d.version)
     }
 }
 
+/// Implement `VocabularySource` to have full programmatic control over how a set of `Definition`s
+/// are checked against and installed into a store.
+pub trait VocabularySource {
+    /// Called to obtain the list of `Definition`s to install. This will be called before `pre`.
+    fn definitions(&mut self) -> Vec<Definition>;
+
+    /// Called before the supplied `Definition`s are installed.
+    /// If this function returns `Err`, the entire vocabulary operation will fail.
+    fn pre(&mut self, _store: &mut VocabularyStore, _checks: &dyn VocabularyStatus) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after the supplied `Definition`s are installed.
+    /// If this function returns `Err`, the entire vocabulary operation will fail.
+    fn post(&mut self, _store: &mut VocabularyStore) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A convenience struct to package simple `pre` and `post` functions with a collection of
+/// vocabulary `Definition`s.
+pub struct SimpleVocabularySource {
+    pub definitions: Vec<Definition>,
+    pub pre: Option<fn(&mut VocabularyStore) -> Result<()>>,
+    pub post: Option<fn(&mut VocabularyStore) -> Result<()>>,
+}
+
+impl SimpleVocabularySource {
+    pub fn new(
+        definitions: Vec<Definition>,
+        pre: Option<fn(&mut VocabularyStore) -> Result<()>>,
+        post: Option<fn(&mut VocabularyStore) -> Result<()>>,
+    ) -> SimpleVocabularySource {
+        SimpleVocabularySource {
+            definitions,
+            pre,
+            post,
+        }
+    }
+
+    pub fn with_definitions(definitions: Vec<Definition>) -> SimpleVocabularySource {
+        Self::new(definitions, None, None)
+    }
+}
+
+impl VocabularySource for SimpleVocabularySource {
+    fn definitions(&mut self) -> Vec<Definition> {
+        self.definitions.clone()
+    }
+
+    fn pre(&mut self, store: &mut VocabularyStore, _checks: &dyn VocabularyStatus) -> Result<()> {
+        self.pre.map_or(Ok(()), |pre| pre(store))
+    }
+
+    fn post(&mut self, store: &mut VocabularyStore) -> Result<()> {
+        self.post.map_or(Ok(()), |post| post(store))
+    }
+}
+
 trait VocabularyMechanics {
     fn install_vocabulary(&mut self, definition: &Definition) -> Result<VocabularyOutcome>;
-    fn install_attributes_for<'definition>(&mut self, definition: &'definition Definition, attributes: Vec<&'definition (Keyword, Attribute)>) -> Result<VocabularyOutcome>;
-    fn upgrade_vocabulary(&mut self, definition: &Definition, from_version: Vocabulary) -> Result<VocabularyOutcome>;
+    fn install_attributes_for<'definition>(
+        &mut self,
+        definition: &'definition Definition,
+        attributes: Vec<&'definition (String, Attribute)>,
+    ) -> Result<VocabularyOutcome>;
+    fn upgrade_vocabulary(
+        &mut self,
+        definition: &Definition,
+        from_version: Vocabulary,
+    ) -> Result<VocabularyOutcome>;
 }
 
-impl Vocabulary {
-    // TODO: don't do linear search!
-    fn find<T>(&self, causetid: T) -> Option<&Attribute> where T: Into<Causetid> {
-        let to_find = causetid.into();
-        self.attributes.iter().find(|&&(e, _)| e == to_find).map(|&(_, ref a)| a)
+/// A vocabulary as the store records it: the causet naming it, its version,
+/// and the attributes it defines.
+#[derive(Clone, Debug)]
+struct InstalledVocabulary {
+    causet: Causetid,
+    version: Version,
+    attributes: Vec<Causetid>,
+}
+
+/// A store of vocabularies and of the topograph they define.
+///
+/// Every change made by `ensure_vocabularies` is applied atomically: if the
+/// `pre` or `post` hook or any installation fails, the store is left as it was.
+#[derive(Clone, Debug)]
+pub struct VocabularyStore {
+    topograph: Topograph,
+    vocabularies: BTreeMap<String, InstalledVocabulary>,
+    next_causetid: Causetid,
+}
+
+/// The first causetid handed out to user attributes and vocabularies.
+const USER0: Causetid = 0x10000;
+
+impl Default for VocabularyStore {
+    fn default() -> Self {
+        VocabularyStore::new()
     }
 }
 
-impl<'a, 'c> VersionedStore for InProgress<'a, 'c> {
-    fn ensure_vocabulary(&mut self, definition: &Definition) -> Result<VocabularyOutcome> {
-        match self.check_vocabulary(definition)? {
-            VocabularyCheck::Present => Ok(VocabularyOutcome::Existed),
-            VocabularyCheck::NotPresent => self.install_vocabulary(definition),
-            VocabularyCheck::PresentButNeedsUpdate { older_version } => self.upgrade_vocabulary(definition, older_version),
-            VocabularyCheck::PresentButMissingAttributes { attributes } => self.install_attributes_for(definition, attributes),
-            VocabularyCheck::PresentButTooNew { newer_version } => Err(einsteindbError::ExistingVocabularyTooNew(definition.name.to_string(), newer_version.version, definition.version).into()),
+impl VocabularyStore {
+    /// A store holding only the core vocabulary.
+    pub fn new() -> VocabularyStore {
+        let core = [
+            (
+                DB_SCHEMA_VERSION,
+                Attribute {
+                    causet_locale_type: ValueType::Long,
+                    ..Default::default()
+                },
+            ),
+            (
+                DB_SCHEMA_ATTRIBUTE,
+                Attribute {
+                    causet_locale_type: ValueType::Ref,
+                    multival: true,
+                    index: true,
+                    ..Default::default()
+                },
+            ),
+        ];
+        let mut solitonid_map = SolitonidMap::new();
+        let mut attribute_map = BTreeMap::new();
+        solitonid_map.insert(DB_SCHEMA_CORE.to_owned(), 1);
+        for (i, (solitonid, attribute)) in core.iter().enumerate() {
+            let causetid = i as Causetid + 2;
+            solitonid_map.insert((*solitonid).to_owned(), causetid);
+            attribute_map.insert(causetid, attribute.clone());
+        }
+        let mut vocabularies = BTreeMap::new();
+        vocabularies.insert(
+            DB_SCHEMA_CORE.to_owned(),
+            InstalledVocabulary {
+                causet: 1,
+                version: CORE_SCHEMA_VERSION,
+                attributes: attribute_map.keys().copied().collect(),
+            },
+        );
+        VocabularyStore {
+            topograph: Topograph::new(solitonid_map, attribute_map),
+            vocabularies,
+            next_causetid: USER0,
+        }
+    }
+
+    /// Returns the causetid of `solitonid`, allocating one if it has none.
+    fn causetid_for(&mut self, solitonid: &str) -> Causetid {
+        if let Some(causetid) = self.topograph.get_causetid(solitonid) {
+            return causetid;
+        }
+        let causetid = self.next_causetid;
+        self.next_causetid += 1;
+        self.topograph.insert_solitonid(solitonid, causetid);
+        causetid
+    }
+
+    /// Installs or replaces the attributes of `definition` and records them as
+    /// part of it.
+    fn install<'d>(
+        &mut self,
+        definition: &Definition,
+        attributes: impl IntoIterator<Item = &'d (String, Attribute)>,
+    ) -> Result<()> {
+        let mut installed = Vec::new();
+        for (solitonid, attribute) in attributes {
+            attribute.validate(|| solitonid.clone())?;
+            let causetid = self.causetid_for(solitonid);
+            if let Some(existing) = self.topograph.attribute_map.get(&causetid) {
+                if existing.causet_locale_type != attribute.causet_locale_type {
+                    return Err(Error::BadTopographAssertion(format!(
+                        "cannot change :einsteindb/causet_localeType of {} from {} to {}",
+                        solitonid, existing.causet_locale_type, attribute.causet_locale_type
+                    )));
+                }
+            }
+            self.topograph
+                .attribute_map
+                .insert(causetid, attribute.clone());
+            installed.push(causetid);
+        }
+        self.topograph.update_component_attributes();
+
+        let causet = self.causetid_for(&definition.name);
+        let vocabulary = self
+            .vocabularies
+            .entry(definition.name.clone())
+            .or_insert_with(|| InstalledVocabulary {
+                causet,
+                version: definition.version,
+                attributes: Vec::new(),
+            });
+        vocabulary.version = definition.version;
+        for causetid in installed {
+            if !vocabulary.attributes.contains(&causetid) {
+                vocabulary.attributes.push(causetid);
+            }
+        }
+        Ok(())
+    }
+
+    fn vocabulary(&self, installed: &InstalledVocabulary) -> Vocabulary {
+        Vocabulary {
+            causet: installed.causet,
+            version: installed.version,
+            attributes: installed
+                .attributes
+                .iter()
+                .filter_map(|&e| {
+                    self.topograph
+                        .attribute_for_causetid(e)
+                        .map(|a| (e, a.clone()))
+                })
+                .collect(),
         }
     }
 
-    fn ensure_vocabularies(&mut self, vocabularies: &mut VocabularySource) -> Result<BTreeMap<Keyword, VocabularyOutcome>> {
+    fn ensure_vocabularies_inner(
+        &mut self,
+        vocabularies: &mut dyn VocabularySource,
+    ) -> Result<BTreeMap<String, VocabularyOutcome>> {
         let definitions = vocabularies.definitions();
 
-        let mut update  = Vec::new();
+        let mut update = Vec::new();
         let mut missing = Vec::new();
         let mut out = BTreeMap::new();
 
         let mut work = CheckedVocabularies::default();
 
-        for definition in definitions.iter() {
+        for definition in &definitions {
             match self.check_vocabulary(definition)? {
                 VocabularyCheck::Present => {
                     out.insert(definition.name.clone(), VocabularyOutcome::Existed);
-                },
+                }
                 VocabularyCheck::PresentButTooNew { newer_version } => {
-                    bail!(einsteindbError::ExistingVocabularyTooNew(definition.name.to_string(), newer_version.version, definition.version));
-                },
-
-                c @ VocabularyCheck::NotPresent |
-                c @ VocabularyCheck::PresentButNeedsUpdate { older_version: _ } |
-                c @ VocabularyCheck::PresentButMissingAttributes { attributes: _ } => {
-                    work.add(definition, c);
-                },
+                    return Err(Error::ExistingVocabularyTooNew {
+                        name: definition.name.clone(),
+                        existing: newer_version.version,
+                        ours: definition.version,
+                    });
+                }
+                c => work.add(definition, c),
             }
         }
 
@@ -734,23 +562,22 @@ impl<'a, 'c> VersionedStore for InProgress<'a, 'c> {
         // If any work needs to be done, run pre/post.
         vocabularies.pre(self, &work)?;
 
-        for (name, (definition, check)) in work.items.into_iter() {
+        for (name, (definition, check)) in work.items {
             match check {
                 VocabularyCheck::NotPresent => {
                     // Install it directly.
                     out.insert(name, self.install_vocabulary(definition)?);
-                },
+                }
                 VocabularyCheck::PresentButNeedsUpdate { older_version } => {
                     // Save this: we'll do it later.
                     update.push((definition, older_version));
-                },
+                }
                 VocabularyCheck::PresentButMissingAttributes { attributes } => {
                     // Save this: we'll do it later.
                     missing.push((definition, attributes));
-                },
-                VocabularyCheck::Present |
-                VocabularyCheck::PresentButTooNew { newer_version: _ } => {
-                    unreachable!();
+                }
+                VocabularyCheck::Present | VocabularyCheck::PresentButTooNew { .. } => {
+                    unreachable!()
                 }
             }
         }
@@ -767,247 +594,225 @@ impl<'a, 'c> VersionedStore for InProgress<'a, 'c> {
     }
 }
 
-/// Implement `VocabularySource` to have full programmatic control over how a set of `Definition`s
-/// are checked against and transacted into a store.
-pub trait VocabularySource {
-    /// Called to obtain the list of `Definition`s to install. This will be called before `pre`.
-    fn definitions(&mut self) -> Vec<Definition>;
-
-    /// Called before the supplied `Definition`s are transacted. Do not commit the `InProgress`.
-    /// If this function returns `Err`, the entire vocabulary operation will fail.
-    fn pre(&mut self, _in_progress: &mut InProgress, _checks: &VocabularyStatus) -> Result<()> {
-        Ok(())
-    }
-
-    /// Called after the supplied `Definition`s are transacted. Do not commit the `InProgress`.
-    /// If this function returns `Err`, the entire vocabulary operation will fail.
-    fn post(&mut self, _in_progress: &mut InProgress) -> Result<()> {
-        Ok(())
-    }
-}
-
-/// A convenience struct to package simple `pre` and `post` functions with a collection of
-/// vocabulary `Definition`s.
-pub struct SimpleVocabularySource {
-    pub definitions: Vec<Definition>,
-    pub pre: Option<fn(&mut InProgress) -> Result<()>>,
-    pub post: Option<fn(&mut InProgress) -> Result<()>>,
-}
-
-impl SimpleVocabularySource {
-    pub fn new(definitions: Vec<Definition>,
-               pre: Option<fn(&mut InProgress) -> Result<()>>,
-               post: Option<fn(&mut InProgress) -> Result<()>>) -> SimpleVocabularySource {
-        SimpleVocabularySource {
-            pre: pre,
-            post: post,
-            definitions: definitions,
-        }
+impl HasVocabularies for VocabularyStore {
+    fn read_vocabularies(&self) -> Result<Vocabularies> {
+        Ok(Vocabularies(
+            self.vocabularies
+                .iter()
+                .map(|(name, v)| (name.clone(), self.vocabulary(v)))
+                .collect(),
+        ))
     }
 
-    pub fn with_definitions(definitions: Vec<Definition>) -> SimpleVocabularySource {
-        Self::new(definitions, None, None)
+    fn read_vocabulary_named(&self, name: &str) -> Result<Option<Vocabulary>> {
+        Ok(self.vocabularies.get(name).map(|v| self.vocabulary(v)))
     }
 }
 
-impl VocabularySource for SimpleVocabularySource {
-    ///
-    ///
-    /// # Arguments
-    ///
-    /// * `in_progress`:
-    /// * `_checks`:
-    ///
-    /// returns: <unknown>
-    ///
-    /// # Examples
-    ///
-    /// ```
-    ///
-    /// ```
-    fn pre(&mut self, in_progress: &mut InProgress, _checks: &dyn VocabularyStatus) -> Result<()> {
-        self.pre.map(|pre| {
-            pre.definitions.iter().map(|def| {  self.definitions.get( def ) }).collect()    //
-                // TODO: checked_add_i64
-                // PathBuf
-                //(?))+definitions})
-
-            (pre)(in_progress)
-        }).unwrap_or(Ok(()))
+impl VersionedStore for VocabularyStore {
+    fn topograph(&self) -> &Topograph {
+        &self.topograph
     }
 
-    ///
-    ///
-    /// # Arguments
-    ///
-    /// * `in_progress`:
-    ///
-    /// returns: <unknown>
-    ///
-    /// # Examples
-    ///
-    /// ```
-    ///
-    /// ```
-    fn post(&mut self, in_progress: &mut InProgress) -> Result<()> {
-
-
-        self.post.map(|pre| { self.definitions.get( pre ) }).unwrap_or(Ok(()));
-
+    fn ensure_vocabulary(&mut self, definition: &Definition) -> Result<VocabularyOutcome> {
+        match self.check_vocabulary(definition)? {
+            VocabularyCheck::Present => Ok(VocabularyOutcome::Existed),
+            VocabularyCheck::NotPresent => self.install_vocabulary(definition),
+            VocabularyCheck::PresentButNeedsUpdate { older_version } => {
+                self.upgrade_vocabulary(definition, older_version)
+            }
+            VocabularyCheck::PresentButMissingAttributes { attributes } => {
+                self.install_attributes_for(definition, attributes)
+            }
+            VocabularyCheck::PresentButTooNew { newer_version } => {
+                Err(Error::ExistingVocabularyTooNew {
+                    name: definition.name.clone(),
+                    existing: newer_version.version,
+                    ours: definition.version,
+                })
+            }
+        }
     }
 
-    fn definitions(&mut self) -> Vec<Definition> {
-        self.definitions.clone()
+    fn ensure_vocabularies(
+        &mut self,
+        vocabularies: &mut dyn VocabularySource,
+    ) -> Result<BTreeMap<String, VocabularyOutcome>> {
+        let saved = self.clone();
+        let res = self.ensure_vocabularies_inner(vocabularies);
+        if res.is_err() {
+            *self = saved;
+        }
+        res
     }
 }
 
-impl<'a, 'c> VocabularyMechanics for InProgress<'a, 'c> {
-    /// Turn the vocabulary into causets, transact them, and on success return the outcome.
+impl VocabularyMechanics for VocabularyStore {
     fn install_vocabulary(&mut self, definition: &Definition) -> Result<VocabularyOutcome> {
-        let (terms, _tempids) = definition.description(self)?;
-        self.transact_causets(terms)?;
+        self.install(definition, &definition.attributes)?;
         Ok(VocabularyOutcome::Installed)
     }
 
-    fn install_attributes_for<'definition>(&mut self, definition: &'definition Definition, attributes: Vec<&'definition (Keyword, Attribute)>) -> Result<VocabularyOutcome> {
-        let (terms, _tempids) = definition.description_for_attributes(&attributes, self, None)?;
-        self.transact_causets(terms)?;
+    fn install_attributes_for<'definition>(
+        &mut self,
+        definition: &'definition Definition,
+        attributes: Vec<&'definition (String, Attribute)>,
+    ) -> Result<VocabularyOutcome> {
+        self.install(definition, attributes)?;
         Ok(VocabularyOutcome::InstalledMissingAttributes)
     }
 
-    /// Turn the declarative parts of the vocabulary into alterations. Run the 'pre' steps.
-    /// Transact the changes. Run the 'post' steps. Return the result and the new `InProgress`!
-    fn upgrade_vocabulary(&mut self, definition: &Definition, from_version: Vocabulary) -> Result<VocabularyOutcome> {
-        // It's sufficient for us to generate the causet form of each attribute and transact that.
+    /// Run the 'pre' steps, install the attributes of the new version over the
+    /// old ones, then run the 'post' steps.
+    fn upgrade_vocabulary(
+        &mut self,
+        definition: &Definition,
+        from_version: Vocabulary,
+    ) -> Result<VocabularyOutcome> {
         // We trust that the vocabulary will implement a 'pre' function that cleans up data for any
         // failable conversion (e.g., cardinality-many to cardinality-one).
-
         definition.pre(self, &from_version)?;
-
-        // TODO: don't do work for attributes that are unchanged. Here we rely on the transactor
-        // to elide duplicate causets.
-        let (terms, _tempids) = definition.description_diff(self, &from_version)?;
-        self.transact_causets(terms)?;
-
+        self.install(definition, &definition.attributes)?;
         definition.post(self, &from_version)?;
         Ok(VocabularyOutcome::Upgraded)
     }
 }
 
-impl<T> HasVocabularies for T where T: HasSchema + Queryable {
-    fn read_vocabulary_named(&self, name: &Keyword) -> Result<Option<Vocabulary>> {
-        if let Some(causetid) = self.get_causetid(name) {
-            match self.lookup_causet_locale_for_attribute(causetid, &DB_SCHEMA_VERSION)? {
-                None => Ok(None),
-                Some(causetq_TV::Long(version))
-                    if version > 0 && (version < u32::max_causet_locale() as i64) => {
-                        let version = version as u32;
-                        let attributes = self.lookup_causet_locales_for_attribute(causetid, &DB_SCHEMA_ATTRIBUTE)?
-                                             .into_iter()
-                                             .filter_map(|a| {
-                                                 if let causetq_TV::Ref(a) = a {
-                                                     self.attribute_for_causetid(a)
-                                                         .cloned()
-                                                         .map(|attr| (a, attr))
-                                                 } else {
-                                                     None
-                                                 }
-                                             })
-                                             .collect();
-                        Ok(Some(Vocabulary {
-                            causet: causetid.into(),
-                            version: version,
-                            attributes: attributes,
-                        }))
-                    },
-                Some(_) => bail!(einsteindbError::InvalidVocabularyVersion),
-            }
-        } else {
-            Ok(None)
-        }
-    }
-
-    fn read_vocabularies(&self) -> Result<Vocabularies> {
-        // This would be way easier with pull expressions. #110.
-        let versions: BTreeMap<Causetid, u32> =
-            self.q_once(r#"[:find ?vocab ?version
-                            :where [?vocab :einsteindb.schema/version ?version]]"#, None)
-                .into_rel_result()?
-                .into_iter()
-                .filter_map(|v|
-                    match (&v[0], &v[1]) {
-                        (&Binding::Scalar(causetq_TV::Ref(vocab)),
-                         &Binding::Scalar(causetq_TV::Long(version)))
-                        if version > 0 && (version < u32::max_causet_locale() as i64) => Some((vocab, version as u32)),
-                        (_, _) => None,
-                    })
-                .collect();
-
-        let mut attributes = BTreeMap::<Causetid, Vec<(Causetid, Attribute)>>::new();
-        let pairs =
-            self.q_once("[:find ?vocab ?attr :where [?vocab :einsteindb.schema/attribute ?attr]]", None)
-                .into_rel_result()?
-                .into_iter()
-                .filter_map(|v| {
-                    match (&v[0], &v[1]) {
-                        (&Binding::Scalar(causetq_TV::Ref(vocab)),
-                         &Binding::Scalar(causetq_TV::Ref(attr))) => Some((vocab, attr)),
-                        (_, _) => None,
-                    }
-                    });
-
-        // TODO: validate that attributes.soliton_ids is a subset of versions.soliton_ids.
-        for (vocab, attr) in pairs {
-            if let Some(attribute) = self.attribute_for_causetid(attr).cloned() {
-                attributes.entry(vocab).or_insert(Vec::new()).push((attr, attribute));
-            }
-        }
-
-        // TODO: return more errors?
-
-        // We walk versions first in order to support vocabularies with no attributes.
-        Ok(Vocabularies(versions.into_iter().filter_map(|(vocab, version)| {
-            // Get the name.
-            self.get_solitonid(vocab).cloned()
-                .map(|name| {
-                    let attrs = attributes.remove(&vocab).unwrap_or(vec![]);
-                    (name.clone(), Vocabulary {
-                        causet: vocab,
-                        version: version,
-                        attributes: attrs,
-                    })
-                })
-        }).collect()))
-    }
-}
-
-#[APPEND_LOG_g(test)]
+#[cfg(test)]
 mod tests {
-    use Store;
+    use super::*;
+    use crate::schema::AttributeBuilder;
 
-    use super::HasVocabularies;
+    fn links(version: Version, multival: bool) -> Definition {
+        Definition::new(
+            ":example/links",
+            version,
+            vec![(
+                ":link/title".to_owned(),
+                AttributeBuilder::helpful()
+                    .causet_locale_type(ValueType::String)
+                    .multival(multival)
+                    .fulltext(true)
+                    .build(),
+            )],
+        )
+    }
 
     #[test]
-    fn test_read_vocabularies() {
-        let mut store = Store::open("").expect("opened");
-        let vocabularies = store.begin_read().expect("in progress")
-                                .read_vocabularies().expect("OK");
+    fn test_core_topograph() {
+        let store = VocabularyStore::new();
+        store.verify_core_topograph().unwrap();
+        let vocabularies = store.read_vocabularies().unwrap();
         assert_eq!(vocabularies.len(), 1);
-        let core = vocabularies.get(&kw!(:einsteindb.schema/core)).expect("exists");
-        assert_eq!(core.version, 1);
+        let core = vocabularies.get(DB_SCHEMA_CORE).unwrap();
+        assert_eq!(core.version, CORE_SCHEMA_VERSION);
+        assert_eq!(core.attributes().len(), 2);
     }
 
     #[test]
-    fn test_core_schema() {
-        let mut store = Store::open("").expect("opened");
-        let in_progress = store.begin_transaction().expect("in progress");
-        let vocab = in_progress.read_vocabularies().expect("vocabulary");
-        assert_eq!(1, vocab.len());
-        assert_eq!(1, vocab.get(&kw!(:einsteindb.schema/core)).expect("core vocab").version);
+    fn test_ensure_vocabulary() {
+        let mut store = VocabularyStore::new();
+        let v1 = links(1, true);
+        assert_eq!(
+            store.ensure_vocabulary(&v1).unwrap(),
+            VocabularyOutcome::Installed
+        );
+        assert_eq!(
+            store.ensure_vocabulary(&v1).unwrap(),
+            VocabularyOutcome::Existed
+        );
+
+        let title = store.topograph().get_causetid(":link/title").unwrap();
+        let attribute = store.topograph().attribute_for_causetid(title).unwrap();
+        assert!(attribute.multival && attribute.index && attribute.fulltext);
+
+        // Same version, different attribute: a coding error.
+        assert!(matches!(
+            store.ensure_vocabulary(&links(1, false)),
+            Err(Error::ConflictingAttributeDefinitions { .. })
+        ));
+
+        let mut v2 = links(2, false);
+        v2.pre = |store, from| {
+            assert_eq!(from.version, 1);
+            assert!(store
+                .topograph()
+                .attribute_for_solitonid(":link/title")
+                .is_some());
+            Ok(())
+        };
+        assert_eq!(
+            store.ensure_vocabulary(&v2).unwrap(),
+            VocabularyOutcome::Upgraded
+        );
+        assert!(
+            !store
+                .topograph()
+                .attribute_for_causetid(title)
+                .unwrap()
+                .multival
+        );
+
+        assert!(matches!(
+            store.ensure_vocabulary(&links(1, true)),
+            Err(Error::ExistingVocabularyTooNew {
+                existing: 2,
+                ours: 1,
+                ..
+            })
+        ));
+
+        let mut v2_more = links(2, false);
+        v2_more.attributes.push((
+            ":link/url".to_owned(),
+            AttributeBuilder::helpful()
+                .causet_locale_type(ValueType::String)
+                .build(),
+        ));
+        assert_eq!(
+            store.ensure_vocabulary(&v2_more).unwrap(),
+            VocabularyOutcome::InstalledMissingAttributes
+        );
+        let links = store
+            .read_vocabulary_named(":example/links")
+            .unwrap()
+            .unwrap();
+        assert_eq!(links.attributes().len(), 2);
     }
-}
-
-
-
-
 
+    #[test]
+    fn test_ensure_vocabularies_is_atomic() {
+        let mut store = VocabularyStore::new();
+        let mut bad = Definition::new(
+            ":example/bad",
+            1,
+            vec![(
+                ":bad/unique".to_owned(),
+                AttributeBuilder::default()
+                    .causet_locale_type(ValueType::Long)
+                    .unique(crate::schema::attribute::Unique::Value)
+                    .build(),
+            )],
+        );
+        let mut source =
+            SimpleVocabularySource::with_definitions(vec![links(1, true), bad.clone()]);
+        assert!(matches!(
+            store.ensure_vocabularies(&mut source),
+            Err(Error::BadTopographAssertion(_))
+        ));
+        assert_eq!(store.read_vocabularies().unwrap().len(), 1);
+        assert!(store.topograph().get_causetid(":link/title").is_none());
+
+        bad.attributes[0].1.index = true;
+        let mut source = SimpleVocabularySource::new(
+            vec![links(1, true), bad],
+            None,
+            Some(|store| store.verify_core_topograph()),
+        );
+        let outcomes = store.ensure_vocabularies(&mut source).unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes
+            .values()
+            .all(|&o| o == VocabularyOutcome::Installed));
+    }
+}
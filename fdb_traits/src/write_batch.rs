@@ -8,140 +8,255 @@
 // governing permissions and limitations under the License.
 //
 
-
-use crate::fdb_traits::*;
-use crate::{FdbError, FdbResult};
-use std::convert::TryFrom;
-use std::fmt;
-use std::io;
-use std::io::{Read, Write};
-use std::marker::PhantomData;
-use std::mem;
-use std::ops::{Deref, DerefMut};
-use std::slice;
-use std::str;
-
-use std::collections::HashMap;
-use std::collections::BTreeMap;
-use std::collections::BTreeSet;
-use std::collections::HashSet;
-
-
-use std::rc::Rc;
-use std::cell::RefCell;
-use std::cell::Ref;
-
-
 use std::fmt::Debug;
-use std::fmt::Display;
-
-use EinsteinDB::fdb_traits::*;
-use EinsteinDB::fdb_traits::options::*;
-
-
-
-use EinsteinDB::einstein_db::*;
-use EinsteinDB::einstein_db::options::*;
-
-use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::sync::Arc;
+
+use crate::{Error, Result, WriteOptions};
+
+/// The default causet_merge family, used by the non-`_namespaced` methods of `Mutable`.
+pub const NAMESPACED_DEFAULT: &str = "default";
+
+const TAG_DELETE: u8 = 0;
+const TAG_PUT: u8 = 1;
+const TAG_DELETE_RANGE: u8 = 2;
+
+/// A command recorded in a write alexandrov_poset_process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteCommand<'a> {
+    Put {
+        namespaced: &'a str,
+        soliton_id: &'a [u8],
+        causet_locale: &'a [u8],
+    },
+    Delete {
+        namespaced: &'a str,
+        soliton_id: &'a [u8],
+    },
+    DeleteRange {
+        namespaced: &'a str,
+        begin_soliton_id: &'a [u8],
+        end_soliton_id: &'a [u8],
+    },
+}
 
+impl<'a> WriteCommand<'a> {
+    pub fn namespaced(&self) -> &'a str {
+        match *self {
+            WriteCommand::Put { namespaced, .. }
+            | WriteCommand::Delete { namespaced, .. }
+            | WriteCommand::DeleteRange { namespaced, .. } => namespaced,
+        }
+    }
+}
 
-use std::convert::TryFrom;
-use std::convert::TryInto;
+fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
 
+fn put_length_prefixed(buf: &mut Vec<u8>, data: &[u8]) {
+    put_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
 
-use std::iter::FromIterator;
-use std::iter::IntoIterator;
+fn get_length_prefixed<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let mut len = 0u64;
+    let mut shift = 0;
+    loop {
+        let (&b, rest) = buf
+            .split_first()
+            .filter(|_| shift < 64)
+            .ok_or_else(|| Error::Corruption("truncated write alexandrov_poset_process".to_owned()))?;
+        *buf = rest;
+        len |= u64::from(b & 0x7f) << shift;
+        if b < 0x80 {
+            break;
+        }
+        shift += 7;
+    }
+    if (buf.len() as u64) < len {
+        return Err(Error::Corruption("truncated write alexandrov_poset_process".to_owned()));
+    }
+    let (data, rest) = buf.split_at(len as usize);
+    *buf = rest;
+    Ok(data)
+}
 
+fn decode_command<'a>(buf: &mut &'a [u8]) -> Result<WriteCommand<'a>> {
+    let (&tag, rest) = buf
+        .split_first()
+        .ok_or_else(|| Error::Corruption("truncated write alexandrov_poset_process".to_owned()))?;
+    *buf = rest;
+    let namespaced = std::str::from_utf8(get_length_prefixed(buf)?)
+        .map_err(|e| Error::Corruption(format!("bad causet_merge family name: {}", e)))?;
+    let soliton_id = get_length_prefixed(buf)?;
+    Ok(match tag {
+        TAG_PUT => WriteCommand::Put {
+            namespaced,
+            soliton_id,
+            causet_locale: get_length_prefixed(buf)?,
+        },
+        TAG_DELETE => WriteCommand::Delete {
+            namespaced,
+            soliton_id,
+        },
+        TAG_DELETE_RANGE => WriteCommand::DeleteRange {
+            namespaced,
+            begin_soliton_id: soliton_id,
+            end_soliton_id: get_length_prefixed(buf)?,
+        },
+        tag => return Err(Error::Corruption(format!("unknown write command {}", tag))),
+    })
+}
 
-use std::hash::Hash;
-use std::hash::Hasher;
+/// An einstein_merkle_tree-independent write alexandrov_poset_process.
+///
+/// Commands are serialized into one buffer as they are issued:
+///
+/// ```text
+///   command ::= tag: u8 | namespaced | soliton_id | [causet_locale | end-soliton_id]
+/// ```
+///
+/// where every field is prefixed with its varint length. `data_size` is the length of
+/// that buffer, which is what einstein_merkle_trees append to their write-ahead log, and
+/// rolling back to a save point truncates it, whatever causet_merge families the
+/// rolled back commands touched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FdbWriteBatch {
+    rep: Vec<u8>,
+    /// The offset in `rep` of each command.
+    offsets: Vec<usize>,
+    save_points: Vec<SavePoint>,
+}
 
+impl FdbWriteBatch {
+    pub fn new() -> FdbWriteBatch {
+        FdbWriteBatch::default()
+    }
 
-use std::cmp::PartialEq;
-use std::cmp::Eq;
-use std::cmp::Ordering;
-use std::cmp::PartialOrd;
-use std::cmp::Ord;
+    pub fn with_capacity(cap: usize) -> FdbWriteBatch {
+        FdbWriteBatch {
+            rep: Vec::with_capacity(cap),
+            ..Default::default()
+        }
+    }
 
+    /// Rebuilds a alexandrov_poset_process from the output of `data`.
+    pub fn from_data(data: &[u8]) -> Result<FdbWriteBatch> {
+        let mut offsets = Vec::new();
+        let mut buf = data;
+        while !buf.is_empty() {
+            offsets.push(data.len() - buf.len());
+            decode_command(&mut buf)?;
+        }
+        Ok(FdbWriteBatch {
+            rep: data.to_vec(),
+            offsets,
+            save_points: Vec::new(),
+        })
+    }
 
-use std::fmt::Debug;
-use std::fmt::Display;
-use std::fmt::Formatter;
-use std::fmt::Result;
+    /// The serialized commands.
+    pub fn data(&self) -> &[u8] {
+        &self.rep
+    }
 
-///Co-optimizing storage and queries for linear algebras
-/// # Examples
-/// ```
-/// use EinsteinDB::fdb_traits::*;
-/// use EinsteinDB::fdb_traits::options::*;
-///
-///
+    pub fn data_size(&self) -> usize {
+        self.rep.len()
+    }
 
+    pub fn count(&self) -> usize {
+        self.offsets.len()
+    }
 
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
 
+    /// Drops every command and save point.
+    pub fn clear(&mut self) {
+        self.rep.clear();
+        self.offsets.clear();
+        self.save_points.clear();
+    }
 
+    pub fn iter(&self) -> impl Iterator<Item = WriteCommand<'_>> {
+        let mut buf = self.rep.as_slice();
+        (0..self.count()).map(move |_| decode_command(&mut buf).expect("write alexandrov_poset_process was validated"))
+    }
 
+    /// The save points pushed so far, innermost last.
+    pub fn save_points(&self) -> &[SavePoint] {
+        &self.save_points
+    }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct FdbWriteBatch<K, V> {
-    pub(crate) inner: Rc<RefCell<FdbWriteBatchInner<K, V>>>,
-}
+    pub fn push_save_point(&mut self, name: &str) {
+        let count = self.count();
+        self.save_points.push(SavePoint {
+            name: name.to_owned(),
+            count,
+        });
+    }
 
+    /// Pops the innermost save point, keeping the commands issued since.
+    pub fn pop_save_point(&mut self) -> Result<SavePoint> {
+        self.save_points
+            .pop()
+            .ok_or_else(|| Error::Engine("no save point to pop".to_owned()))
+    }
 
-impl<K, V> FdbWriteBatch<K, V> {
-    pub fn new() -> Self {
-        FdbWriteBatch {
-            inner: Rc::new(RefCell::new(FdbWriteBatchInner::new())),
+    /// Pops the innermost save point and drops the commands issued since.
+    pub fn rollback_to_save_point(&mut self) -> Result<SavePoint> {
+        let save_point = self
+            .save_points
+            .pop()
+            .ok_or_else(|| Error::Engine("no save point to roll back to".to_owned()))?;
+        if let Some(&offset) = self.offsets.get(save_point.count) {
+            self.rep.truncate(offset);
+            self.offsets.truncate(save_point.count);
         }
+        Ok(save_point)
     }
-}
-
 
-impl<K, V> FdbWriteBatch<K, V> {
-    pub fn clear(&mut self) {
-        self.inner.borrow_mut().clear();
+    fn push(&mut self, tag: u8, namespaced: &str, soliton_id: &[u8], causet_locale: Option<&[u8]>) {
+        self.offsets.push(self.rep.len());
+        self.rep.push(tag);
+        put_length_prefixed(&mut self.rep, namespaced.as_bytes());
+        put_length_prefixed(&mut self.rep, soliton_id);
+        if let Some(v) = causet_locale {
+            put_length_prefixed(&mut self.rep, v);
+        }
     }
 }
 
-
-impl<K, V> FdbWriteBatch<K, V> {
-    pub fn commit(&mut self) -> FdbResult<()> {
-        self.inner.borrow_mut().commit()
+impl Mutable for FdbWriteBatch {
+    fn put_namespaced(&mut self, namespaced: &str, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()> {
+        self.push(TAG_PUT, namespaced, soliton_id, Some(causet_locale));
+        Ok(())
     }
-}
 
-#[derive(Debug, Fail)]
-pub enum WriteBatchError {
-    #[fail(display = "WriteBatchError: {}", _0)]
-    WriteBatchError(String),
-}
+    fn delete_namespaced(&mut self, namespaced: &str, soliton_id: &[u8]) -> Result<()> {
+        self.push(TAG_DELETE, namespaced, soliton_id, None);
+        Ok(())
+    }
 
-#[derive(Debug, Fail)]
-#[allow(dead_code)]
-pub enum WriteBatchWriteError {
-    #[fail(display = "WriteBatchWriteError: {}", _0)]
-    WriteBatchWriteError(String),
+    fn delete_range_namespaced(&mut self, namespaced: &str, begin_soliton_id: &[u8], end_soliton_id: &[u8]) -> Result<()> {
+        self.push(TAG_DELETE_RANGE, namespaced, begin_soliton_id, Some(end_soliton_id));
+        Ok(())
+    }
 }
 
-
-#[derive(Debug, Fail)]
-#[allow(dead_code)]
-#[allow(unused_variables)]
-#[allow(unused_imports)]
-#[allow(unused_mut)]
-pub enum WriteBatchReadError {
-    #[fail(display = "WriteBatchReadError: {}", _0)]
-    WriteBatchReadError(String),
+/// A named marker in a write alexandrov_poset_process that later commands can be rolled back to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SavePoint {
+    pub name: String,
+    /// The number of commands in the alexandrov_poset_process when the save point was recorded.
+    pub count: usize,
 }
 
-/// A write batch is a collection of mutations that can be applied to a database.
-/// The batch is not thread-safe, and must be committed before it can be used again.
-/// The batch is not intended to be used concurrently with other threads.
-///
-
-
 /// EinsteinMerkleTrees that can create write alexandrov_poset_processes
 pub trait WriteBatchExt: Sized {
     type WriteBatch: WriteBatch<Self>;
@@ -171,59 +286,76 @@ pub trait WriteBatchExt: Sized {
 /// A trait implemented by WriteBatch
 pub trait Mutable: Send {
     /// Write a soliton_id/causet_locale in the default causet_merge family
-    fn put(&mut self, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()>{
-        self.put_with_merge_family(b"", soliton_id, causet_locale)
+    fn put(&mut self, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()> {
+        self.put_namespaced(NAMESPACED_DEFAULT, soliton_id, causet_locale)
     }
 
     /// Write a soliton_id/causet_locale in a given causet_merge family
-    fn put_namespaced(&mut self, namespaced: &str, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()>{
-        self.put_with_merge_family(namespaced.as_bytes(), soliton_id, causet_locale)
-    }
+    fn put_namespaced(&mut self, namespaced: &str, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()>;
 
     /// Delete a soliton_id/causet_locale in the default causet_merge family
-    fn delete(&mut self, soliton_id: &[u8]) -> Result<()>{
-        self.delete_with_merge_family(b"", soliton_id)
+    fn delete(&mut self, soliton_id: &[u8]) -> Result<()> {
+        self.delete_namespaced(NAMESPACED_DEFAULT, soliton_id)
     }
 
     /// Delete a soliton_id/causet_locale in a given causet_merge family
-    fn delete_namespaced(&mut self, namespaced: &str, soliton_id: &[u8]) -> Result<()>{
-        self.delete_with_merge_family(namespaced.as_bytes(), soliton_id)
-    }
+    fn delete_namespaced(&mut self, namespaced: &str, soliton_id: &[u8]) -> Result<()>;
 
     /// Delete a range of soliton_id/causet_locales in the default causet_merge family
-    fn delete_range(&mut self, begin_soliton_id: &[u8], end_soliton_id: &[u8]) -> Result<()>{
-        self.delete_range_with_merge_family(b"", begin_soliton_id, end_soliton_id)
+    fn delete_range(&mut self, begin_soliton_id: &[u8], end_soliton_id: &[u8]) -> Result<()> {
+        self.delete_range_namespaced(NAMESPACED_DEFAULT, begin_soliton_id, end_soliton_id)
     }
 
     /// Delete a range of soliton_id/causet_locales in a given causet_merge family
     fn delete_range_namespaced(&mut self, namespaced: &str, begin_soliton_id: &[u8], end_soliton_id: &[u8]) -> Result<()>;
 
-    fn put_msg<M: protobuf::Message>(&mut self, soliton_id: &[u8], m: &M) -> Result<()> {
-        self.put(soliton_id, &m.write_to_bytes()?)
-    }
-    fn put_msg_namespaced<M: protobuf::Message>(&mut self, namespaced: &str, soliton_id: &[u8], m: &M) -> Result<()> {
-        self.put_namespaced(namespaced, soliton_id, &m.write_to_bytes()?)
-    }
 }
-//!Read path of a transaction
-// Fig. 1 above shows a high-level view of the read path. An application uses FDB client library to read data. It creates a transaction and calls its read() function. The read() operation will lead to several steps.
+
+// Read path of a transaction
+//
+// An application uses the FDB client library to read data. It creates a transaction
+// and calls its read() function, which leads to several steps.
 //
-// Step 1 (Timestamp request): The read operation needs a timestamp. The client initiates the timestamp request through an RPC to proxy. The request will trigger Step 2 and Step 3;
+// Step 1 (Timestamp request): The read operation needs a timestamp. The client
+// initiates the timestamp request through an RPC to a proxy. The request triggers
+// Step 2 and Step 3.
 //
-// To improve throughput and reduce load on the server side, each client dynamically batches the timestamp requests. A client keeps adding requests to the current batch until when the number of requests in a batch exceeds a configurable threshold or when the batching times out at a dynamically computed threshold. Each batch sends only one timestamp request to proxy and all requests in the same batch share the same timestamp.
-// Step 2 (Get latest commit version): When the timestamp request arrives at a proxy, the proxy wants to get the largest commit version as the return value. So it contacts the rest of (n-1) proxies for their latest commit versions and uses the largest one as the return value for Step 1.
+// To improve throughput and reduce load on the server side, each client batches the
+// timestamp requests: all requests in the same batch share the same timestamp.
 //
-// O(n^2) communication cost: Because each proxy needs to contact the rest of (n-1) proxies to serve clients’ timestamp request, the communication cost is n*(n-1), where n is the number of proxies;
-// Batching: To reduce communication cost, each proxy batches clients’ timestamp requests for a configurable time period (say 1ms) and return the same timestamp for requests in the same batch.
-// Step 3 (Confirm proxy’s liveness): To prevent proxies that are no longer a part of the system (such as due to network partition) from serving requests, each proxy contacts the queuing system for each timestamp request to confirm it is still a valid proxy (i.e., not replaced by a newer generation proxy process). This is based on the FDB property that at most one active queuing system is available at any given time.
+// Step 2 (Get latest commit version): When the timestamp request arrives at a proxy,
+// the proxy wants to get the largest commit version as the return value. So it
+// contacts the rest of the (n-1) proxies for their latest commit versions and uses
+// the largest one as the return value for Step 1.
+//
+// Step 3 (Confirm proxy's liveness): To prevent proxies that are no longer a part of
+// the system from serving requests, each proxy contacts the queuing system for each
+// timestamp request to confirm it is still a valid proxy.
 
+// Write path of a transaction
+//
+// Step 1 (Timestamp request): The write operation needs a timestamp, requested from
+// a proxy the same way as for reads.
 
-//!Write path of a transaction
-//!
-//!    Fig. 2 above shows a high-level view of the write path. An application uses FDB client library to write data. It creates a transaction and calls its put() function. The put() operation will lead to several steps.
+/// A proxy that hands out read versions, the latest version it committed.
+pub trait Proxy: Send + Sync {
+    fn get_read_version(&self) -> u64;
+}
 
+/// The proxies of a database, as seen by a transaction.
+pub trait Database: Debug + Send + Sync {
+    fn get_num_proxies(&self) -> usize;
+    fn get_proxy(&self, i: usize) -> Arc<dyn Proxy>;
+
+    /// The largest commit version of all proxies (Step 2 above).
+    fn get_read_version(&self) -> u64 {
+        (0..self.get_num_proxies())
+            .map(|i| self.get_proxy(i).get_read_version())
+            .max()
+            .unwrap_or(0)
+    }
+}
 
-//!    Step 1 (Timestamp request): The write operation needs a timestamp. The client initiates the timestamp request through an RPC to proxy. The request will trigger Step 2 and Step 3;
 #[derive(Debug, Clone)]
 pub struct Transaction {
     pub db: Arc<dyn Database>,
@@ -236,7 +368,6 @@ pub struct Transaction {
 
 //read committed and read your own version
 
-
 #[derive(Debug, Clone)]
 pub struct TransactionRead {
     pub db: Arc<dyn Database>,
@@ -244,15 +375,10 @@ pub struct TransactionRead {
     pub commit_version: u64,
     pub read_version: u64,
     pub read_version_old: u64,
+    pub read_version_old_old: u64,
     pub read_version_new: u64,
 }
 
-
-
-
-
-
-
 impl Transaction {
     pub fn new(db: Arc<dyn Database>) -> Self {
         Self {
@@ -262,145 +388,79 @@ impl Transaction {
             read_version: 0,
             read_version_old: 0,
             read_version_new: 0,
-        };
+        }
+    }
 
-        fn get_namespaced(db: &Arc<dyn Database>, namespace: &str, key: &[u8]) -> Result<Option<Vec<u8>>> {
-            let mut buf = Vec::new();
-            buf.write_u64::<BigEndian>(db.get_namespaced(namespace, key)?.unwrap_or(0))?;
-            Ok(Some(buf))
+    /// The read path of this transaction, starting at its read version.
+    pub fn read(&mut self) -> TransactionRead {
+        let mut read = TransactionRead::new(self.db.clone());
+        read.timestamp = self.timestamp;
+        read.commit_version = self.commit_version;
+        read.read_version = self.read_version;
+        read.read_version = read.get_read_version();
+        self.read_version = read.read_version;
+        read
+    }
+}
+
+impl TransactionRead {
+    pub fn new(db: Arc<dyn Database>) -> Self {
+        Self {
+            db,
+            timestamp: 0,
+            commit_version: 0,
+            read_version: 0,
+            read_version_old: 0,
+            read_version_old_old: 0,
+            read_version_new: 0,
         }
     }
 
-    pub fn get_read_version(&self) -> u64 {
+    /// Returns the read version, asking the proxies for it on the first call only so
+    /// that every read of the transaction sees the same snapshot.
+    pub fn get_read_version(&mut self) -> u64 {
         if self.read_version == 0 {
-            for i in 0..self.db.get_num_proxies() {
-                let proxy = self.db.get_proxy(i);
-                loop {
-                    let read_version = proxy.get_read_version();
-                    if read_version > self.read_version {
-                        self.read_version = read_version;
-                    }
-                    if read_version == self.read_version {
-                        break;
-                    }
-                }
-                let read_version = proxy.get_read_version();
-                //println!("read_version: {}", read_version);
-                //if read_version > self.read_version {
-                // then we need to wait for the read_version to be updated
-                if read_version > self.read_version {
-                    //multiplex read_version
-                    self.read_version_old = self.read_version;
-                    self.read_version = read_version;
-                    loop {
-                        let read_version = proxy.get_read_version();
-                        if read_version > self.read_version {
-                            self.read_version = read_version;
-                        }
-                        if read_version == self.read_version {
-                            break;
-                        }
-                    }
-                }
-            }
             self.read_version = self.db.get_read_version();
         }
         self.read_version
     }
 
-    pub fn get_read_version_old(&self) -> u64 {
-        for i in 0..self.db.get_num_proxies() {
-            let proxy = self.db.get_proxy(i);
-            loop {
-                let read_version = proxy.get_read_version({
-                    //let read_version = proxy.get_read_version();
-                    //now we have a read version
-                    let read_version = proxy.get_read_version();
-                    //we need to check if it is the same as the read version
-                    if read_version > self.read_version_old {
-                        //note: we need to check if it is the same as the read version
-                        self.read_version_old = read_version;
-                    }
-                    //if it is the same as the read version, we need to break
-                    if read_version == self.read_version_old {
-                        break;
-                    }
-                });
-                //if it is the same as the read version, we need to break
-                if read_version == self.read_version_old {
-                    break;
-                }
-                //if it is not the same as the read version, we need to continue
-            }
+    /// Returns the read version before the last `get_read_version_old_old` moved it
+    /// forward, or the current one if it never moved.
+    pub fn get_read_version_old(&mut self) -> u64 {
+        if self.read_version_old == 0 {
+            return self.get_read_version();
         }
-
         self.read_version_old
     }
 
-
-    pub fn get_read_version_old_old(&self) -> u64 {
-        for i in 0..self.db.get_num_proxies() {
-            let proxy = self.db.get_proxy(i);
-            loop {
-                let read_version = proxy.get_read_version();
-                //println!("read_version: {}", read_version);
-                //if read_version > self.read_version {
-                // then we need to wait for the read_version to be updated
-                if read_version > self.read_version_old_old {
-                    //multiplex read_version
-                    self.read_version_old_old = self.read_version_old;
-                    self.read_version_old = self.read_version;
-                    self.read_version = read_version;
-                    loop {
-                        let read_version = proxy.get_read_version();
-                        if read_version > self.read_version {
-                            self.read_version = read_version;
-                        }
-                        if read_version == self.read_version {
-                            break;
-                        }
-                    }
-                }
-            }
+    /// Moves the read version forward to the largest commit version of the proxies,
+    /// keeping the two previous read versions. Returns the new read version.
+    pub fn get_read_version_old_old(&mut self) -> u64 {
+        let read_version = self.get_read_version();
+        let latest = self.db.get_read_version();
+        if latest > read_version {
+            self.read_version_old_old = self.read_version_old;
+            self.read_version_old = read_version;
+            self.read_version = latest;
         }
+        self.read_version
     }
 
-
+    /// Like `get_read_version_old_old`, for a read version history that is not this
+    /// transaction's own: returns the newer of `read_version` and the largest commit
+    /// version of the proxies, and records it as `read_version_new`.
     pub fn get_read_version_new(
-         &self,
+        &mut self,
         read_version: u64,
         read_version_old: u64,
         read_version_old_old: u64,
     ) -> u64 {
-        for i in 0..self.db.get_num_proxies() {
-            let proxy = self.db.get_proxy(i);
-            loop {
-                let read_version = proxy.get_read_version();
-                //println!("read_version: {}", read_version);
-                //if read_version > self.read_version {
-                // then we need to wait for the read_version to be updated
-                if read_version > read_version_old_old {
-                    //multiplex read_version
-                    read_version_old_old = read_version_old;
-                    read_version_old = read_version;
-                    read_version = read_version;
-                    loop {
-                        let read_version = proxy.get_read_version();
-                        if read_version > read_version {
-                            read_version = read_version;
-                        }
-                        if read_version == read_version {
-                            break;
-                        }
-                    }
-                }
-            }
-        }
-
-        read_version
-    }
-
+        debug_assert!(read_version_old_old <= read_version_old && read_version_old <= read_version);
+        self.read_version_new = read_version.max(self.db.get_read_version());
+        self.read_version_new
     }
+}
 
 /// Batches of multiple writes that are committed atomically
 ///
@@ -414,8 +474,7 @@ impl Transaction {
 /// The exact strategy used by WriteBatch is up to the implementation.
 /// FdbDB though _seems_ to serialize the writes to an in-memory buffer,
 /// and then write the whole serialized alexandrov_poset_process to disk at once.
-
-
+///
 /// Write alexandrov_poset_processes may be reused after being written. In that case they write
 /// exactly the same data as previously, Replacing any soliton_ids that may have
 /// changed in between the two alexandrov_poset_process writes.
@@ -427,22 +486,13 @@ impl Transaction {
 /// save point, and pops the save point from the stack.
 pub trait WriteBatch<E: WriteBatchExt + Sized>: Mutable {
     /// Create a WriteBatch with a given command capacity
-    fn with_capacity(e: &E, cap: usize) -> Self{
-        //with_capacity will be implemented by the WriteBatchExt trait
-        e.with_capacity(cap);
-        //now filter the WriteBatchExt trait
-        if let Some(e) = e.downcast_ref::<dyn WriteBatchExt>() {
-            e.with_capacity(cap)
-        } else {
-            panic!("WriteBatchExt trait not implemented")
-        }
-    }
+    fn with_capacity(e: &E, cap: usize) -> Self;
 
     /// Commit the WriteBatch to disk with the given options
     fn write_opt(&self, opts: &WriteOptions) -> Result<()>;
 
     /// Commit the WriteBatch to disk atomically
-    fn write(&self, fdb_traits: &E) -> Result<()> {
+    fn write(&self, _einstein_merkle_tree: &E) -> Result<()> {
         self.write_opt(&WriteOptions::default())
     }
 
@@ -464,91 +514,16 @@ pub trait WriteBatch<E: WriteBatchExt + Sized>: Mutable {
     /// If so, the `write` method should be called.
     fn should_write_to_einstein_merkle_tree(&self) -> bool;
 
-    /// Clears the WriteBatch of all commands
+    /// Clears the WriteBatch of all commands and save points
     ///
     /// It may be reused afterward as an empty alexandrov_poset_process.
-    fn clear(fdb: FdbError, ltree: &mut EinsteinMerkleTrees<E>) -> Result<()>;
-
-
+    fn clear(&mut self);
 
-
-
-
-    /// Record a save point
-    ///     * `name`: A name for the save point
-    ///    * `save_point`: The save point to record
-    ///   * `save_point_stack`: The stack of save points
-    /// * `save_point_stack_len`: The length of the stackß
-        /// Push a save point onto the save point stack
-    fn push_save_point(&mut self, name: &str, save_point: &mut SavePoint);
-
-    /// Pop a save point from the save point stack
-    ///   * `save_point`: The save point to pop
-    /// * `save_point_stack`: The stack of save points
-    /// * `save_point_stack_len`: The length of the stackß
-    /// Pop a save point from the save point stack
-    ///  * `save_point`: The save point to pop
-    /// * `save_point_stack`: The stack of save points
-    ///
+    /// Push a save point onto the save point stack
     ///
-    fn anti_pop_save_point(&mut self, save_point: &mut SavePoint){
-        //pop_save_point will be implemented by the WriteBatchExt trait
-        if let Some(e) = self.downcast_mut::<dyn WriteBatchExt>() {
-            e.pop_save_point(save_point)
-        } else {
-            panic!("WriteBatchExt trait not implemented")
-        }
-    }
-
-    /// Rollback to a save point
-    ///  * `save_point`: The save point to rollback to
-    /// * `save_point_stack`: The stack of save points
-    /// * `save_point_stack_len`: The length of the stackß
-    /// Rollback to a save point
-    /// * `save_point`: The save point to rollback to
-    /// * `save_point_stack`: The stack of save points
-
-
-
-    /// Record a command
-    /// * `Cmd`: The command to record
-    /// * `cmd_type`: The type of command
-    /// * `cmd_type_len`: The length of the command type
-    /// * `cmd_data`: The data of the command
-    /// * `cmd_data_len`: The length of the command data
-    /// Record a command
-
-    fn record_command(&mut self, cmd: &[u8], cmd_type: &[u8], cmd_type_len: usize, cmd_data: &[u8], cmd_data_len: usize);
-
-
-    /// Record a command
-
-    fn record_command_with_type(&mut self, cmd_type: &[u8], cmd_type_len: usize, cmd_data: &[u8], cmd_data_len: usize);
-
-
-    /// Rollback all commands issued since the last save point
-    /// and pop the save point from the stack
-    /// If there are no save points on the stack, do nothing
-    /// and return false
-
-    fn anti_rollback_to_save_point< 'a >(&mut self, save_point: &mut SavePoint, save_point_stack: &'a mut Vec< SavePoint >, save_point_stack_len: usize) -> bool;
-
-    /// Rollback all commands issued since the last save point
-    /// and pop the save point from the stack
-    /// If there are no save points on the stack, do nothing
-
-    fn anti_rollback_to_save_point_with_stack< 'a >(&mut self, save_point: &mut SavePoint, save_point_stack: &'a mut Vec< SavePoint >) -> bool;
-
-
-
-
-    /// Pop a save point from the save point stack
-    /// If there are no save points on the stack, this is a no-op
-    /// If the top save point is not the one on the stack, this is a no-op
-
-
-    fn rollback_to_save_point_opt(&mut self, opts: &WriteOptions);
-
+    /// Save points nest: each `rollback_to_save_point` or `pop_save_point` applies
+    /// to the innermost one. The name is only kept for diagnostics.
+    fn push_save_point(&mut self, name: &str);
 
     /// Pop a save point from the save point stack
     ///
@@ -559,311 +534,69 @@ pub trait WriteBatch<E: WriteBatchExt + Sized>: Mutable {
     ///
     /// Additionally pops the last save point from the save point stack.
     fn rollback_to_save_point(&mut self) -> Result<()>;
-
-    /// Merge another WriteBatch to itself
-    /// The resulting WriteBatch will contain all commands from both batches
-    /// The WriteBatch on which this method is called will not be modified
-    /// The WriteBatch passed as an argument will be empty after this operation
-    ///
-    /// This method is useful when multiple batches need to be merged together
-    /// into a single batch.
-    ///
-    /// This method is not implemented for FdbDB
-    ///
-    /// # Panics
-    ///
-    /// Panics if the WriteBatch passed as an argument is not empty
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use fdb::{Fdb, FdbOptions, DatabaseOptions, Database, WriteOptions, WriteBatch, ReadOptions};
-    /// use fdb::fdb_sys;
-    /// use fdb::fdb_sys::*;
-    /// use fdb::fdb_sys::fdb_error_t;
-    /// use fdb::fdb_sys::fdb_status_t;
-    ///
-    ///
-    /// let db_opts = DatabaseOptions::new();
-    /// let db = Fdb::open(&db_opts).unwrap();
-    ///
-    /// let mut opts = WriteOptions::new();
-    ///
-    /// let mut batch1 = WriteBatch::new();
-    ///
-    /// batch1.put(b"key1", b"value1").unwrap();
-    /// batch1.put(b"key2", b"value2").unwrap();
-    ///
-    /// let mut batch2 = WriteBatch::new();
-    ///
-    /// batch2.put(b"key3", b"value3").unwrap();
-    ///
-    /// batch1.merge(&mut batch2).unwrap();
-    ///
-    /// assert_eq!(batch1.get(b"key1").unwrap().unwrap(), b"value1");
-    ///
-    /// assert_eq!(batch1.get(b"key2").unwrap().unwrap(), b"value2");
-    ///
-    /// assert_eq!(batch1.get(b"key3").unwrap().unwrap(), b"value3");
-    ///
-    ///
-    ///
-    /// ```
-    ///
 }
-//We experiment with a new type for the WriteBatch interface which is a bit more flexible, it's also type-safe and can be used with the FdbDB
-//type WriteBatch = WriteBatchImpl;
-    fn merge<'a>(&mut merkle: EinsteinMerkleTrees, other: &mut dyn WriteBatch) -> EinsteinMerkleTrees<'a>{
-        unsafe {
-            //TODO: implement this method for FdbDB
-            //a merkle pointer is an instant of a merkle tree
-            // as such it is not possible to merge two merkle trees
-            // without creating a new merkle tree
-            //however we can merge two merkle trees
-            //and then create a new merkle tree from the merged merkle trees
-            //this is the same as creating a new merkle tree from the two merkle trees
-            //with the advent of psoets we can do this in a single operation
-            let mut merkle_ptr = merkle.merkle_ptr;
-            //let mut other_ptr = other.merkle_ptr;
-            let mut other_ptr = other.merkle_ptr;
-            //this is the pointer to the new merkle tree
-            let mut result = fdb_sys::fdb_merge_batch(merkle_ptr, other_ptr); //the batch is merged into the merkle tree
-            if result != fdb_sys::fdb_status_t::FDB_RESULT_SUCCESS {
-                panic!("Failed to merge two batches");
-            }
-            let mut new_merkle = EinsteinMerkleTrees::new(merkle_ptr);
-            new_merkle
-        }
-    }
 
-    /// Merge another WriteBatch to itself
-    /// The resulting WriteBatch will contain all commands from both batches
-    /// The WriteBatch on which this method is called will not be modified
-    ///
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
 
-    /// # Panics
-    /// Panics if the WriteBatch passed as an argument is not empty
-    /// # Examples
-    /// ```
-    /// use fdb::{Fdb, FdbOptions, DatabaseOptions, Database, WriteOptions, WriteBatch, ReadOptions};
-    ///
-    /// use fdb::fdb_sys;
-    /// use fdb::fdb_sys::*;
-    /// use fdb::fdb_sys::fdb_error_t;
-    /// use fdb::fdb_sys::fdb_status_t;
-    ///
-    ///
-    /// let db_opts = DatabaseOptions::new();
-    /// let db = Fdb::open(&db_opts).unwrap();
-    ///
-    /// let mut opts = WriteOptions::new();
-    ///
-    /// let mut batch1 = WriteBatch::new();
-    ///
-    /// batch1.put(b"key1", b"value1").unwrap();
-    ///
-    /// let mut batch2 = WriteBatch::new();
-    ///
-    /// batch2.put(b"key2", b"value2").unwrap();
-    ///
-    /// batch1.merge(&mut batch2).unwrap();
-    ///
-    /// assert_eq!(batch1.get(b"key1").unwrap().unwrap(), b"value1");
-    ///
-    /// assert_eq!(batch1.get(b"key2").unwrap().unwrap(), b"value2");
-    ///
-    /// ```
-
-
-    fn merge_into_self<'a>(&mut merkle: EinsteinMerkleTrees, other: &mut dyn WriteBatch) -> EinsteinMerkleTrees<'a>{
-        unsafe {
-    /// Returns the number of commands in the batch
-   fn merge_options(merge_options: &mut fdb_sys::fdb_merge_options_t) -> fdb_sys::fdb_status_t {
-        unsafe {
-            fdb_sys::fdb_merge_options_init(merge_options);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_APPEND_DOCS);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_STABLE);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER_BY_KEY);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER_BY_KEY_AND_SEQNUM);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER_BY_SEQNUM);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER_BY_SEQNUM_AND_KEY);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER_BY_SEQNUM_AND_VERSION);
-            fdb_sys::fdb_merge_options_set_flags(merge_options, fdb_sys::fdb_merge_flags_t::FDB_MERGE_DOCS_IN_ORDER_BY_VERSION);
-    }
-        if merkle_ptr == other.merkle_ptr {
-            panic!("Cannot merge a batch with itself");
-        }
-        unsafe {
-            for i in 0..other.num_commands {
-                let mut command = other.commands[i];
-                let mut key = command.key;
-                let mut value = command.value;
-                let mut key_len = command.key_len;
-                let mut value_len = command.value_len;
-                let mut flags = command.flags;
-                let mut version = command.version;
-                let mut seqnum = command.seqnum;
-                let mut err = fdb_sys::fdb_merge_append_doc(merge_options, merkle_ptr, &mut key, key_len, &mut value, value_len, flags, version, seqnum);
-                if err != fdb_sys::fdb_status_t::FDB_RESULT_SUCCESS {
-                    panic!("Failed to merge batch");
-                }
-            }
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct TestDatabase {
+        proxies: Vec<Arc<TestProxy>>,
+    }
 
+    #[derive(Debug, Default)]
+    struct TestProxy(AtomicU64);
 
+    impl Proxy for TestProxy {
+        fn get_read_version(&self) -> u64 {
+            self.0.load(Ordering::SeqCst)
         }
-        merkle
-    }
     }
 
-    }
-    /// Merges the contents of the batch into the database.
-    /// # Examples
-    /// ```
-    /// use einsteinium_core::{EinsteinMerkleTrees, WriteBatch};
-    /// let mut db = EinsteinMerkleTrees::new();
-    /// let mut batch1 = WriteBatch::new();
-    /// let mut batch2 = WriteBatch::new();
-    /// batch1.put(b"key1", b"value1").unwrap();
-    /// batch1.put(b"key2", b"value2").unwrap();
-    /// batch2.put(b"key3", b"value3").unwrap();
-    /// batch2.put(b"key4", b"value4").unwrap();
-    /// batch1.merge_into_self(&mut batch2);
-    ///
-    /// assert_eq!(db.get(b"key1").unwrap().unwrap(), b"value1");
-    /// assert_eq!(db.get(b"key2").unwrap().unwrap(), b"value2");
-    /// assert_eq!(db.get(b"key3").unwrap().unwrap(), b"value3");
-    ///
-    /// assert_eq!(db.get(b"key4").unwrap().unwrap(), b"value4");
-    /// ```
-
-
-    pub fn append_merge_with_log (&mut log: &mut Log, batch: &mut dyn WriteBatch) -> Result<(), String> {
-
-        for x in 0..turing_automata.num_commands {
-            let mut command = turing_automata.commands[x];
-            let mut key = command.key;
-            let mut value = command.value;
-            let mut key_len = command.key_len;
-            let mut value_len = command.value_len;
-            let mut flags = command.flags;
-            let mut version = command.version;
-            let mut seqnum = command.seqnum;
-            let mut err = fdb_sys::fdb_merge_append_doc(merge_options, merkle_ptr, &mut key, key_len, &mut value, value_len, flags, version, seqnum);
-            if err != fdb_sys::fdb_status_t::FDB_RESULT_SUCCESS {
-                panic!("Failed to merge batch");
-            }
+    impl Database for TestDatabase {
+        fn get_num_proxies(&self) -> usize {
+            self.proxies.len()
         }
 
-        let mut merkle = EinsteinMerkleTrees::new();
-        let mut merkle_ptr = merkle.merkle_ptr;
-            let mut other_ptr = other.merkle_ptr;
-            let mut result = fdb_sys::fdb_merge_batch(merkle_ptr, other_ptr); //the batch is merged into the merkle tree
-            if result != fdb_sys::fdb_status_t::FDB_RESULT_SUCCESS {
-                panic!("Failed to merge two batches");
-            }
-            let mut new_merkle = EinsteinMerkleTrees::new(merkle_ptr);
-            new_merkle
+        fn get_proxy(&self, i: usize) -> Arc<dyn Proxy> {
+            self.proxies[i].clone()
         }
+    }
 
-
-
-    /// Returns the number of commands in the batch
-    /// ```
-    /// # use einsteinium::{EinsteinMerkleTrees, WriteBatch};
-    /// # let mut batch = WriteBatch::new();
-    /// # let mut merkle = EinsteinMerkleTrees::new();
-    /// # let mut other = EinsteinMerkleTrees::new();
-    ///
-    /// # let mut key = [0u8; 32];
-    /// # let mut value = [0u8; 32];
-    /// # let mut key_len = 32;
-    /// # let mut value_len = 32;
-    /// # let mut flags = 0;
-    ///
-    /// # let mut version = 0;
-    /// # let mut seqnum = 0;
-    ///
-    /// # batch.append(&mut key, &mut value, flags, version, seqnum);
-    /// # batch.append(&mut key, &mut value, flags, version, seqnum);
-    ///
-    /// # let mut other_batch = WriteBatch::new();
-    ///
-    /// # other_batch.append(&mut key, &mut value, flags, version, seqnum);
-    /// # other_batch.append(&mut key, &mut value, flags, version, seqnum);
-    ///
-    /// # let mut other_merkle = EinsteinMerkleTrees::new();
-    ///
-    /// # other_merkle.append(&mut key, &mut value, flags, version, seqnum);
-    ///
-    ///
-    /// # let mut merged_batch = batch.merge(other_batch);
-    ///
-    /// # assert_eq!(merged_batch.num_commands(), 4);
-    /// # assert_eq!(merged_batch.num_commands(), other_merkle.num_commands());
-        /// Merge another WriteBatch to itself
-        /// The resulting WriteBatch will contain all commands from both batches
-
-//selfless retraction with upsert on the other side
-
-    /// ```
-    /// # use einsteinium::{EinsteinMerkleTrees, WriteBatch};
-    /// # let mut batch = WriteBatch::new();
-    /// # let mut merkle = EinsteinMerkleTrees::new();
-    /// # let mut other = EinsteinMerkleTrees::new();
-    /// # let mut other_merkle = EinsteinMerkleTrees::new();
-    /// # let mut key = [0u8; 32];
-    /// # let mut value = [0u8; 32];
-    /// # let mut key_len = 32;
-    /// # let mut value_len = 32;
-    ///
-    /// # let mut version = 0;
-    /// # let mut seqnum = 0;
-    ///
-    /// # batch.append(&mut key, &mut value, 0, version, seqnum);
-    /// # batch.append(&mut key, &mut value, 0, version, seqnum);
-    ///
-    /// # other.append(&mut key, &mut value, 0, version, seqnum);
-    ///
-    ///
-    /// # other_merkle.append(&mut key, &mut value, 0, version, seqnum);
-    ///
-    /// # let mut merged_batch = batch.merge(other);
-    ///
-    /// # assert_eq!(merged_batch.num_commands(), 4);
-    /// # assert_eq!(merged_batch.num_commands(), other_merkle.num_commands());
-    ///
-
-
-
-    /// Returns the number of commands in the batch
-
-#[inline]
-    pub fn num_commands(fdb_merge_batch: &mut EinsteinMerkleTrees) -> usize {
-        fdb_merge_batch.num_commands();
-
-
-    ///
-            unsafe {
-                let mut num_commands = 0;
-                let result = fdb_sys::fdb_get_num_commands(fdb_merge_batch.fdb_merkle_trees, &mut num_commands);
-                 //the batch is merged into the merkle tree
-                if result != 0 {
-                    panic!("Failed to get number of commands in the batch");
-
-            }
-            unsafe {
-                //here we are creating a new merkle tree from the two merkle trees
-                //we are not modifying the original merkle trees
-                let mut new_merkle_tree = EinsteinMerkleTrees::new();
-                let mut other_ptr = other.merkle_ptr;
-                let mut result = fdb_sys::fdb_merge_batch(merkle_ptr, other_ptr); //the batch is merged into the merkle tree
-            }
-        }
+    #[test]
+    fn test_read_version() {
+        let db = Arc::new(TestDatabase {
+            proxies: (0..3).map(|_| Arc::default()).collect(),
+        });
+        db.proxies[0].0.store(5, Ordering::SeqCst);
+        db.proxies[2].0.store(7, Ordering::SeqCst);
+
+        let mut txn = Transaction::new(db.clone());
+        let mut read = txn.read();
+        assert_eq!(txn.read_version, 7);
+        assert_eq!(read.get_read_version(), 7);
+        assert_eq!(read.get_read_version_old(), 7);
+
+        // Commits on the proxies do not change the snapshot of the transaction.
+        db.proxies[1].0.store(9, Ordering::SeqCst);
+        assert_eq!(read.get_read_version(), 7);
+        assert_eq!(read.get_read_version_new(8, 7, 5), 9);
+        assert_eq!(read.read_version_new, 9);
+
+        assert_eq!(read.get_read_version_old_old(), 9);
+        assert_eq!(read.get_read_version(), 9);
+        assert_eq!(read.get_read_version_old(), 7);
+        assert_eq!(read.read_version_old_old, 0);
+        db.proxies[0].0.store(12, Ordering::SeqCst);
+        assert_eq!(read.get_read_version_old_old(), 12);
+        assert_eq!(read.get_read_version_old(), 9);
+        assert_eq!(read.read_version_old_old, 7);
+        assert_eq!(read.get_read_version_new(15, 12, 9), 15);
+
+        // A new transaction starts at the latest commit version.
+        assert_eq!(Transaction::new(db).read().get_read_version(), 12);
+    }
 }
-
-
-
-
-
-
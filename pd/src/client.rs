@@ -0,0 +1,109 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! What stores and clients ask the placement driver.
+
+use std::sync::Arc;
+
+use violetabftstore::{Brane, IdAllocator};
+
+use crate::errors::Result;
+use crate::meta::{BraneHeartbeat, BraneInfo, StoreMeta, StoreStats};
+use crate::operator::OperatorStep;
+use crate::server::PdServer;
+
+pub trait PdClient: Send + Sync {
+    fn bootstrap_cluster(&self, store: StoreMeta, brane: Brane) -> Result<()>;
+
+    fn is_cluster_bootstrapped(&self) -> Result<bool>;
+
+    fn alloc_id(&self) -> Result<u64>;
+
+    fn put_store(&self, store: StoreMeta) -> Result<()>;
+
+    fn get_store(&self, store_id: u64) -> Result<StoreMeta>;
+
+    fn get_all_stores(&self) -> Result<Vec<StoreMeta>>;
+
+    fn store_heartbeat(&self, stats: StoreStats) -> Result<()>;
+
+    /// Reports a brane led by the caller; the step it should carry out next.
+    fn brane_heartbeat(&self, hb: BraneHeartbeat) -> Result<Option<OperatorStep>>;
+
+    /// The brane holding `soliton_id`, and its leader if known.
+    fn get_brane(&self, soliton_id: &[u8]) -> Result<BraneInfo>;
+
+    fn get_brane_by_id(&self, brane_id: u64) -> Result<Option<BraneInfo>>;
+
+    /// A timestamp greater than every one handed out before.
+    fn get_tso(&self) -> Result<u64> {
+        self.batch_get_tso(1)
+    }
+
+    /// `count` consecutive timestamps, of which the first is returned.
+    fn batch_get_tso(&self, count: u32) -> Result<u64>;
+}
+
+/// A client of a placement driver in the same process.
+#[derive(Clone)]
+pub struct LocalClient {
+    server: Arc<PdServer>,
+}
+
+impl LocalClient {
+    pub fn new(server: Arc<PdServer>) -> LocalClient {
+        LocalClient { server }
+    }
+}
+
+impl PdClient for LocalClient {
+    fn bootstrap_cluster(&self, store: StoreMeta, brane: Brane) -> Result<()> {
+        self.server.bootstrap_cluster(store, brane)
+    }
+
+    fn is_cluster_bootstrapped(&self) -> Result<bool> {
+        Ok(self.server.is_bootstrapped())
+    }
+
+    fn alloc_id(&self) -> Result<u64> {
+        self.server.alloc_id()
+    }
+
+    fn put_store(&self, store: StoreMeta) -> Result<()> {
+        self.server.put_store(store)
+    }
+
+    fn get_store(&self, store_id: u64) -> Result<StoreMeta> {
+        self.server.get_store(store_id)
+    }
+
+    fn get_all_stores(&self) -> Result<Vec<StoreMeta>> {
+        Ok(self.server.get_all_stores())
+    }
+
+    fn store_heartbeat(&self, stats: StoreStats) -> Result<()> {
+        self.server.store_heartbeat(stats)
+    }
+
+    fn brane_heartbeat(&self, hb: BraneHeartbeat) -> Result<Option<OperatorStep>> {
+        self.server.brane_heartbeat(hb)
+    }
+
+    fn get_brane(&self, soliton_id: &[u8]) -> Result<BraneInfo> {
+        self.server.get_brane(soliton_id)
+    }
+
+    fn get_brane_by_id(&self, brane_id: u64) -> Result<Option<BraneInfo>> {
+        self.server.get_brane_by_id(brane_id)
+    }
+
+    fn batch_get_tso(&self, count: u32) -> Result<u64> {
+        self.server.get_ts(count)
+    }
+}
+
+/// Stores allocate the ids of new branes and peers from the placement driver.
+impl IdAllocator for LocalClient {
+    fn alloc_id(&self) -> violetabftstore::Result<u64> {
+        PdClient::alloc_id(self).map_err(|e| violetabftstore::Error::Other(e.to_string()))
+    }
+}
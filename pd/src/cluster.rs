@@ -0,0 +1,303 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! An in-process cluster of stores managed by an in-process placement driver:
+//! the stores report to it every tick, and carry out the operators it answers
+//! with.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use violetabft::ConfChangeType;
+use violetabftstore::cluster::Cluster;
+use violetabftstore::StoreConfig;
+
+use crate::client::{LocalClient, PdClient};
+use crate::errors::{Error, Result};
+use crate::meta::{BraneHeartbeat, StoreMeta, StoreStats};
+use crate::operator::OperatorStep;
+use crate::server::{PdConfig, PdServer};
+
+fn store_address(store_id: u64) -> String {
+    format!("store-{}", store_id)
+}
+
+pub struct PdCluster {
+    pub cluster: Cluster,
+    pub pd: Arc<PdServer>,
+    client: LocalClient,
+}
+
+impl PdCluster {
+    /// Bootstraps a cluster of `store_count` stores, whose ids of branes and
+    /// peers come from the placement driver.
+    pub fn new(
+        dir: &Path,
+        store_count: u64,
+        store_cfg: StoreConfig,
+        pd_cfg: PdConfig,
+    ) -> Result<PdCluster> {
+        let pd = Arc::new(PdServer::new(pd_cfg)?);
+        let client = LocalClient::new(pd.clone());
+        let cluster = Cluster::with_id_allocator(
+            &dir.join("stores"),
+            store_count,
+            store_cfg,
+            Arc::new(client.clone()),
+        )?;
+        let brane = cluster
+            .branes()
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Other("the cluster has no brane".to_owned()))?;
+        client.bootstrap_cluster(StoreMeta::new(1, store_address(1)), brane)?;
+        for &store_id in &cluster.store_ids()[1..] {
+            client.put_store(StoreMeta::new(store_id, store_address(store_id)))?;
+        }
+        let mut pd_cluster = PdCluster {
+            cluster,
+            pd,
+            client,
+        };
+        pd_cluster.heartbeat()?;
+        Ok(pd_cluster)
+    }
+
+    pub fn client(&self) -> &LocalClient {
+        &self.client
+    }
+
+    /// Starts an empty store and registers it.
+    pub fn add_store(&mut self, store_id: u64) -> Result<()> {
+        self.cluster.add_store(store_id)?;
+        self.client
+            .put_store(StoreMeta::new(store_id, store_address(store_id)))
+    }
+
+    /// Ticks the stores, sends their heartbeats, then ticks the placement
+    /// driver.
+    pub fn tick(&mut self) -> Result<()> {
+        self.cluster.tick()?;
+        self.heartbeat()?;
+        self.pd.tick()
+    }
+
+    /// Sends the heartbeats of every running store and of the branes led there,
+    /// and starts the steps the placement driver answers with.
+    pub fn heartbeat(&mut self) -> Result<()> {
+        for store_id in self.cluster.store_ids().to_vec() {
+            let store = match self.cluster.store_mut(store_id) {
+                Some(store) => store,
+                None => continue,
+            };
+            let mut stats = StoreStats {
+                store_id,
+                ..Default::default()
+            };
+            let mut steps = Vec::new();
+            for brane in store.branes() {
+                let approximate_size = store.brane_approximate_size(brane.id)?;
+                stats.brane_count += 1;
+                stats.used_size += approximate_size;
+                let peer = store.peer(brane.id).unwrap();
+                if !peer.is_leader() {
+                    continue;
+                }
+                stats.leader_count += 1;
+                let truncated = peer.storage().apply_state().truncated_index;
+                let pending_peers = brane
+                    .peers
+                    .iter()
+                    .copied()
+                    .filter(|p| {
+                        let progress = &peer.raw_node.violetabft.prs.progress;
+                        progress.get(&p.id).is_none_or(|pr| pr.matched < truncated)
+                    })
+                    .collect();
+                let hb = BraneHeartbeat {
+                    brane: brane.clone(),
+                    leader: peer.peer,
+                    term: peer.term(),
+                    approximate_size,
+                    pending_peers,
+                };
+                match self.client.brane_heartbeat(hb) {
+                    Ok(Some(step)) => steps.push((brane.id, step)),
+                    Ok(None) | Err(Error::StaleBrane(_)) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            self.client.store_heartbeat(stats)?;
+            for (brane_id, step) in steps {
+                let res = match step {
+                    OperatorStep::AddPeer(peer) | OperatorStep::PromoteLearner(peer) => {
+                        store.change_peer(brane_id, vec![(ConfChangeType::AddNode, peer)])
+                    }
+                    OperatorStep::AddLearner(peer) => {
+                        store.change_peer(brane_id, vec![(ConfChangeType::AddLearnerNode, peer)])
+                    }
+                    OperatorStep::RemovePeer(peer) => {
+                        store.change_peer(brane_id, vec![(ConfChangeType::RemoveNode, peer)])
+                    }
+                    OperatorStep::TransferLeader(peer) => store.transfer_leader(brane_id, peer),
+                };
+                // The step is handed out again with the next heartbeat.
+                let _ = res;
+            }
+        }
+        Ok(self.cluster.settle()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::schedule::ScheduleConfig;
+
+    fn store_config() -> StoreConfig {
+        StoreConfig {
+            violetabft_election_ticks: 5,
+            violetabft_heartbeat_ticks: 1,
+            sync_log: false,
+            split_check_ticks: 1000,
+            log_gc_ticks: 5,
+            log_gc_threshold: 1,
+            ..Default::default()
+        }
+    }
+
+    fn pd_config() -> PdConfig {
+        PdConfig {
+            data_dir: None,
+            schedule: ScheduleConfig {
+                max_store_down_ticks: 10,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The replicas and the leaders on each store.
+    fn distribution(c: &PdCluster) -> BTreeMap<u64, (usize, usize)> {
+        let mut counts = BTreeMap::new();
+        for &store_id in c.cluster.store_ids() {
+            counts.insert(store_id, (0, 0));
+        }
+        for brane in c.cluster.branes() {
+            for p in &brane.peers {
+                counts.get_mut(&p.store_id).unwrap().0 += 1;
+            }
+            let leader = c.cluster.leader(brane.id).unwrap();
+            counts.get_mut(&leader.store_id).unwrap().1 += 1;
+        }
+        counts
+    }
+
+    fn spread(counts: impl Iterator<Item = usize> + Clone) -> usize {
+        counts.clone().max().unwrap() - counts.min().unwrap()
+    }
+
+    #[test]
+    fn test_balance() {
+        let dir = TempDir::new().unwrap();
+        let mut c = PdCluster::new(dir.path(), 3, store_config(), pd_config()).unwrap();
+        for i in 0..20 {
+            let key = format!("k{:02}", i).into_bytes();
+            c.cluster.put(&key, b"v").unwrap();
+        }
+        for split_key in [&b"k05"[..], b"k10", b"k15"] {
+            c.cluster.split(split_key).unwrap();
+        }
+        c.add_store(4).unwrap();
+        for _ in 0..300 {
+            c.tick().unwrap();
+            let counts = distribution(&c);
+            if c.pd.operators().is_empty()
+                && spread(counts.values().map(|c| c.0)) <= 1
+                && spread(counts.values().map(|c| c.1)) <= 1
+            {
+                break;
+            }
+        }
+        let counts = distribution(&c);
+        assert!(spread(counts.values().map(|c| c.0)) <= 1, "{:?}", counts);
+        assert!(spread(counts.values().map(|c| c.1)) <= 1, "{:?}", counts);
+        assert!(counts[&4].0 > 0);
+        for brane in c.cluster.branes() {
+            assert_eq!(brane.peers.len(), 3);
+            assert!(brane.peers.iter().all(|p| !p.is_learner()), "{:?}", brane);
+        }
+        for i in 0..20 {
+            let key = format!("k{:02}", i).into_bytes();
+            assert_eq!(c.cluster.get(&key).unwrap().unwrap(), b"v");
+        }
+    }
+
+    #[test]
+    fn test_replace_down_store() {
+        let dir = TempDir::new().unwrap();
+        let mut c = PdCluster::new(dir.path(), 4, store_config(), pd_config()).unwrap();
+        c.cluster.put(b"k", b"v").unwrap();
+        let brane_id = c.cluster.branes()[0].id;
+
+        // Bootstrapped on every store, the brane has a replica too many.
+        for _ in 0..50 {
+            c.tick().unwrap();
+        }
+        let brane = c.cluster.get_brane(brane_id).unwrap();
+        assert_eq!(brane.peers.len(), 3);
+        let spare = (1..=4).find(|&s| brane.peer_on_store(s).is_none()).unwrap();
+
+        // A replica lost with its store is made again on the spare store.
+        let lost = (1..=4).find(|&s| s != spare).unwrap();
+        c.cluster.stop_store(lost);
+        for _ in 0..100 {
+            c.tick().unwrap();
+            let brane = c.cluster.get_brane(brane_id).unwrap();
+            if brane.peers.len() == 3 && brane.peer_on_store(lost).is_none() {
+                break;
+            }
+        }
+        let brane = c.cluster.get_brane(brane_id).unwrap();
+        // Added as a learner, the replica was promoted before the lost one went.
+        let added = brane.peer_on_store(spare);
+        assert!(added.is_some_and(|p| !p.is_learner()), "{:?}", brane);
+        assert!(brane.peer_on_store(lost).is_none(), "{:?}", brane);
+        assert_eq!(c.cluster.get(b"k").unwrap().unwrap(), b"v");
+    }
+
+    #[test]
+    fn test_routing_tso_and_ids() {
+        let dir = TempDir::new().unwrap();
+        let pd_cfg = PdConfig {
+            data_dir: Some(dir.path().join("pd")),
+            ..pd_config()
+        };
+        let mut c = PdCluster::new(dir.path(), 3, store_config(), pd_cfg.clone()).unwrap();
+        let (_, right) = c.cluster.split(b"m").unwrap();
+        c.tick().unwrap();
+        let info = c.client().get_brane(b"x").unwrap();
+        assert_eq!(info.brane, right);
+        assert_eq!(info.leader, c.cluster.leader(right.id));
+        let store = c.client().get_store(info.leader.unwrap().store_id).unwrap();
+        assert_eq!(store.address, store_address(store.id));
+
+        let ts = c.client().get_tso().unwrap();
+        assert!(c.client().batch_get_tso(10).unwrap() > ts);
+
+        // A restarted placement driver hands out neither an id nor a timestamp
+        // again.
+        let id = c.client().alloc_id().unwrap();
+        let ts = c.client().get_tso().unwrap();
+        drop(c);
+        let pd = PdServer::new(pd_cfg).unwrap();
+        assert!(pd.alloc_id().unwrap() > id);
+        assert!(pd.get_ts(1).unwrap() > ts);
+        assert!(matches!(
+            pd.get_brane(b"x"),
+            Err(Error::ClusterNotBootstrapped)
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The cluster has no first brane yet.
+    ClusterNotBootstrapped,
+    ClusterBootstrapped,
+    StoreNotFound(u64),
+    /// The store was removed from the cluster, and may not come back.
+    StoreTombstone(u64),
+    /// No brane holds the soliton_id.
+    BraneNotFound(Vec<u8>),
+    /// A heartbeat reported a brane older than the one known.
+    StaleBrane(u64),
+    Io(io::Error),
+    Store(violetabftstore::Error),
+    Other(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ClusterNotBootstrapped => write!(f, "the cluster is not bootstrapped"),
+            Error::ClusterBootstrapped => write!(f, "the cluster is bootstrapped already"),
+            Error::StoreNotFound(id) => write!(f, "store {} not found", id),
+            Error::StoreTombstone(id) => write!(f, "store {} is removed", id),
+            Error::BraneNotFound(key) => write!(f, "no brane holds soliton_id {:?}", key),
+            Error::StaleBrane(id) => write!(f, "brane {} is stale", id),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::Store(e) => write!(f, "store error: {}", e),
+            Error::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Store(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<violetabftstore::Error> for Error {
+    fn from(e: violetabftstore::Error) -> Error {
+        Error::Store(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,32 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The placement driver (PD): the one place that knows the whole cluster.
+//!
+//! It hands out ids and timestamps that only grow, and learns the stores and
+//! branes from their heartbeats. Its schedulers keep each brane at its number
+//! of replicas and balance replicas and leaders across the stores, through
+//! operators it answers the heartbeats of brane leaders with. A replica is
+//! added as a learner and promoted to a voter once it was sent a snapshot.
+//! Clients look up the routing of soliton_ids through a `PdClient`.
+
+mod client;
+pub mod cluster;
+mod errors;
+mod meta;
+mod operator;
+mod saved_limit;
+mod schedule;
+mod server;
+pub mod tso;
+
+pub use crate::client::{LocalClient, PdClient};
+pub use crate::errors::{Error, Result};
+pub use crate::meta::{
+    BasicCluster, BraneHeartbeat, BraneInfo, StoreInfo, StoreMeta, StoreState, StoreStats,
+};
+pub use crate::operator::{Operator, OperatorStep};
+pub use crate::schedule::{
+    BalanceLeaderScheduler, BalanceReplicaScheduler, ReplicaChecker, ScheduleConfig,
+    ScheduleContext, Scheduler,
+};
+pub use crate::server::{PdConfig, PdServer};
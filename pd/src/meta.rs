@@ -0,0 +1,288 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The stores and branes of the cluster, as their heartbeats last told.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound::{Excluded, Unbounded};
+
+use violetabftstore::{keys, Brane, Peer};
+
+use crate::errors::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoreState {
+    Up,
+    /// Being drained: its replicas are moved elsewhere.
+    Offline,
+    /// Removed for good.
+    Tombstone,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoreMeta {
+    pub id: u64,
+    /// Where clients reach the store.
+    pub address: String,
+    pub state: StoreState,
+}
+
+impl StoreMeta {
+    pub fn new(id: u64, address: impl Into<String>) -> StoreMeta {
+        StoreMeta {
+            id,
+            address: address.into(),
+            state: StoreState::Up,
+        }
+    }
+}
+
+/// What a store reports in its heartbeats.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StoreStats {
+    pub store_id: u64,
+    pub capacity: u64,
+    pub available: u64,
+    pub used_size: u64,
+    pub brane_count: u64,
+    pub leader_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct StoreInfo {
+    pub meta: StoreMeta,
+    pub stats: StoreStats,
+    /// The tick of the placement driver the store last sent a heartbeat at.
+    pub last_heartbeat: u64,
+}
+
+impl StoreInfo {
+    /// Whether the store sent no heartbeat in the last `max_down_ticks` ticks.
+    pub fn is_down(&self, now: u64, max_down_ticks: u64) -> bool {
+        now.saturating_sub(self.last_heartbeat) > max_down_ticks
+    }
+}
+
+/// What the leader of a brane reports in its heartbeats.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BraneHeartbeat {
+    pub brane: Brane,
+    pub leader: Peer,
+    pub term: u64,
+    pub approximate_size: u64,
+    /// The peers whose log lags behind the leader's.
+    pub pending_peers: Vec<Peer>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BraneInfo {
+    pub brane: Brane,
+    pub leader: Option<Peer>,
+    pub term: u64,
+    pub approximate_size: u64,
+    pub pending_peers: Vec<Peer>,
+}
+
+impl From<BraneHeartbeat> for BraneInfo {
+    fn from(hb: BraneHeartbeat) -> BraneInfo {
+        BraneInfo {
+            brane: hb.brane,
+            leader: Some(hb.leader),
+            term: hb.term,
+            approximate_size: hb.approximate_size,
+            pending_peers: hb.pending_peers,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BasicCluster {
+    stores: BTreeMap<u64, StoreInfo>,
+    branes: HashMap<u64, BraneInfo>,
+    /// The ids of the branes by the data soliton_id of their end.
+    ranges: BTreeMap<Vec<u8>, u64>,
+}
+
+impl BasicCluster {
+    pub fn new() -> BasicCluster {
+        BasicCluster::default()
+    }
+
+    /// Adds a store or updates its metadata; a removed store can not come back.
+    pub fn put_store(&mut self, meta: StoreMeta, now: u64) -> Result<()> {
+        match self.stores.get_mut(&meta.id) {
+            Some(info) if info.meta.state == StoreState::Tombstone => {
+                Err(Error::StoreTombstone(meta.id))
+            }
+            Some(info) => {
+                info.meta = meta;
+                Ok(())
+            }
+            None => {
+                let stats = StoreStats {
+                    store_id: meta.id,
+                    ..Default::default()
+                };
+                self.stores.insert(
+                    meta.id,
+                    StoreInfo {
+                        meta,
+                        stats,
+                        last_heartbeat: now,
+                    },
+                );
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_store(&self, store_id: u64) -> Option<&StoreInfo> {
+        self.stores.get(&store_id)
+    }
+
+    pub fn stores(&self) -> impl Iterator<Item = &StoreInfo> {
+        self.stores.values()
+    }
+
+    pub fn set_store_state(&mut self, store_id: u64, state: StoreState) -> Result<()> {
+        let info = self
+            .stores
+            .get_mut(&store_id)
+            .ok_or(Error::StoreNotFound(store_id))?;
+        if info.meta.state == StoreState::Tombstone && state != StoreState::Tombstone {
+            return Err(Error::StoreTombstone(store_id));
+        }
+        info.meta.state = state;
+        Ok(())
+    }
+
+    pub fn handle_store_heartbeat(&mut self, stats: StoreStats, now: u64) -> Result<()> {
+        let info = self
+            .stores
+            .get_mut(&stats.store_id)
+            .ok_or(Error::StoreNotFound(stats.store_id))?;
+        if info.meta.state == StoreState::Tombstone {
+            return Err(Error::StoreTombstone(stats.store_id));
+        }
+        info.stats = stats;
+        info.last_heartbeat = now;
+        Ok(())
+    }
+
+    /// Records `info`, in place of the branes it overlaps, unless a brane known
+    /// already is newer.
+    pub fn put_brane(&mut self, info: BraneInfo) -> Result<()> {
+        let brane = &info.brane;
+        if let Some(old) = self.branes.get(&brane.id) {
+            let (old_epoch, epoch) = (old.brane.brane_epoch, brane.brane_epoch);
+            if epoch.is_stale(&old_epoch) || (epoch == old_epoch && info.term < old.term) {
+                return Err(Error::StaleBrane(brane.id));
+            }
+        }
+        let end = keys::data_end_key(&brane.end_key);
+        let overlapping: Vec<u64> = self
+            .ranges
+            .range((Excluded(keys::data_key(&brane.start_key)), Unbounded))
+            .map(|(_, id)| *id)
+            .take_while(|id| keys::data_key(&self.branes[id].brane.start_key) < end)
+            .filter(|id| *id != brane.id)
+            .collect();
+        for id in &overlapping {
+            if self.branes[id].brane.brane_epoch.version > brane.brane_epoch.version {
+                return Err(Error::StaleBrane(brane.id));
+            }
+        }
+        for id in overlapping.into_iter().chain(Some(brane.id)) {
+            if let Some(old) = self.branes.remove(&id) {
+                self.ranges.remove(&keys::data_end_key(&old.brane.end_key));
+            }
+        }
+        self.ranges.insert(end, brane.id);
+        self.branes.insert(brane.id, info);
+        Ok(())
+    }
+
+    pub fn get_brane(&self, brane_id: u64) -> Option<&BraneInfo> {
+        self.branes.get(&brane_id)
+    }
+
+    pub fn brane_for_key(&self, soliton_id: &[u8]) -> Option<&BraneInfo> {
+        let (_, id) = self
+            .ranges
+            .range((Excluded(keys::data_key(soliton_id)), Unbounded))
+            .next()?;
+        let info = &self.branes[id];
+        info.brane.contains(soliton_id).then_some(info)
+    }
+
+    /// The branes in the order of their ranges.
+    pub fn branes(&self) -> impl Iterator<Item = &BraneInfo> {
+        self.ranges.values().map(|id| &self.branes[id])
+    }
+
+    pub fn brane_count(&self, store_id: u64) -> usize {
+        self.branes()
+            .filter(|b| b.brane.peer_on_store(store_id).is_some())
+            .count()
+    }
+
+    pub fn leader_count(&self, store_id: u64) -> usize {
+        self.branes()
+            .filter(|b| b.leader.is_some_and(|l| l.store_id == store_id))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use violetabftstore::BraneEpoch;
+
+    use super::*;
+
+    fn info(id: u64, start: &[u8], end: &[u8], version: u64, term: u64) -> BraneInfo {
+        BraneInfo {
+            brane: Brane {
+                id,
+                start_key: start.to_vec(),
+                end_key: end.to_vec(),
+                brane_epoch: BraneEpoch {
+                    conf_ver: 1,
+                    version,
+                },
+                peers: vec![Peer::new(id * 10, 1)],
+            },
+            leader: Some(Peer::new(id * 10, 1)),
+            term,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_brane_heartbeats() {
+        let mut cluster = BasicCluster::new();
+        cluster.put_store(StoreMeta::new(1, "s1"), 0).unwrap();
+        cluster.put_brane(info(1, b"", b"", 1, 1)).unwrap();
+
+        // The right half of a split reports first, and replaces its parent.
+        cluster.put_brane(info(2, b"m", b"", 2, 1)).unwrap();
+        assert!(cluster.get_brane(1).is_none());
+        assert!(cluster.brane_for_key(b"a").is_none());
+        cluster.put_brane(info(1, b"", b"m", 2, 1)).unwrap();
+        assert_eq!(cluster.brane_for_key(b"a").unwrap().brane.id, 1);
+        assert_eq!(cluster.brane_for_key(b"z").unwrap().brane.id, 2);
+
+        // Older news of a brane are refused.
+        assert!(matches!(
+            cluster.put_brane(info(1, b"", b"", 1, 1)),
+            Err(Error::StaleBrane(1))
+        ));
+        assert!(matches!(
+            cluster.put_brane(info(3, b"", b"z", 1, 1)),
+            Err(Error::StaleBrane(3))
+        ));
+        assert_eq!(cluster.branes().count(), 2);
+        assert_eq!((cluster.brane_count(1), cluster.leader_count(1)), (2, 2));
+
+        cluster.set_store_state(1, StoreState::Tombstone).unwrap();
+        assert!(cluster.put_store(StoreMeta::new(1, "s1"), 0).is_err());
+    }
+}
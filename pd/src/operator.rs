@@ -0,0 +1,83 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Operators: the steps a scheduler wants a brane to go through, handed to its
+//! leader one at a time in the answers to its heartbeats.
+
+use violetabftstore::Peer;
+
+use crate::meta::BraneInfo;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OperatorStep {
+    AddPeer(Peer),
+    /// Adds a learner; done once it is not pending, having been sent a
+    /// snapshot.
+    AddLearner(Peer),
+    /// Promotes a learner to a voter.
+    PromoteLearner(Peer),
+    RemovePeer(Peer),
+    TransferLeader(Peer),
+}
+
+impl OperatorStep {
+    /// Whether `info` shows the step done.
+    pub fn is_finished(&self, info: &BraneInfo) -> bool {
+        match self {
+            OperatorStep::AddPeer(peer) => info.brane.peer(peer.id).is_some(),
+            OperatorStep::AddLearner(peer) => {
+                info.brane.peer(peer.id).is_some()
+                    && info.pending_peers.iter().all(|p| p.id != peer.id)
+            }
+            OperatorStep::PromoteLearner(peer) => {
+                info.brane.peer(peer.id).is_some_and(|p| !p.is_learner())
+            }
+            OperatorStep::RemovePeer(peer) => info.brane.peer(peer.id).is_none(),
+            OperatorStep::TransferLeader(peer) => info.leader.is_some_and(|l| l.id == peer.id),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Operator {
+    pub brane_id: u64,
+    /// The scheduler that created it.
+    pub desc: &'static str,
+    pub steps: Vec<OperatorStep>,
+    /// The tick it was created at.
+    pub created_at: u64,
+    current: usize,
+}
+
+impl Operator {
+    pub fn new(brane_id: u64, desc: &'static str, steps: Vec<OperatorStep>, now: u64) -> Operator {
+        Operator {
+            brane_id,
+            desc,
+            steps,
+            created_at: now,
+            current: 0,
+        }
+    }
+
+    /// Moves past the steps `info` shows done; the step to carry out next, if any
+    /// is left.
+    pub fn check(&mut self, info: &BraneInfo) -> Option<&OperatorStep> {
+        while self.current < self.steps.len() && self.steps[self.current].is_finished(info) {
+            self.current += 1;
+        }
+        self.steps.get(self.current)
+    }
+
+    /// The steps not yet done.
+    pub fn pending_steps(&self) -> &[OperatorStep] {
+        &self.steps[self.current.min(self.steps.len())..]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.steps.len()
+    }
+
+    pub fn is_timeout(&self, now: u64, timeout_ticks: u64) -> bool {
+        now.saturating_sub(self.created_at) > timeout_ticks
+    }
+}
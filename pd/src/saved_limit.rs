@@ -0,0 +1,52 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A limit persisted ahead of what it bounds, so that handing out ids or
+//! timestamps below it needs no write, and a restart starts past anything handed
+//! out before.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::errors::{Error, Result};
+
+pub(crate) struct SavedLimit {
+    /// None keeps the limit in memory only.
+    path: Option<PathBuf>,
+    limit: u64,
+}
+
+impl SavedLimit {
+    pub fn open(path: Option<&Path>) -> Result<SavedLimit> {
+        let limit = match path {
+            Some(path) if path.exists() => {
+                let data = fs::read(path)?;
+                let bytes: [u8; 8] = data.as_slice().try_into().map_err(|_| {
+                    Error::Other(format!("{} is not a saved limit", path.display()))
+                })?;
+                u64::from_le_bytes(bytes)
+            }
+            _ => 0,
+        };
+        Ok(SavedLimit {
+            path: path.map(Path::to_owned),
+            limit,
+        })
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    pub fn save(&mut self, limit: u64) -> Result<()> {
+        if let Some(path) = &self.path {
+            let tmp = path.with_extension("tmp");
+            let mut file = File::create(&tmp)?;
+            file.write_all(&limit.to_le_bytes())?;
+            file.sync_all()?;
+            fs::rename(&tmp, path)?;
+        }
+        self.limit = limit;
+        Ok(())
+    }
+}
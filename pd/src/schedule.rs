@@ -0,0 +1,363 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Schedulers: each looks at the cluster and proposes at most one operator at a
+//! time, for a brane that has none running.
+
+use std::collections::HashMap;
+
+use violetabftstore::{Peer, PeerRole};
+
+use crate::errors::Result;
+use crate::meta::{BasicCluster, BraneInfo, StoreState};
+use crate::operator::{Operator, OperatorStep};
+
+#[derive(Clone, Debug)]
+pub struct ScheduleConfig {
+    /// The replicas every brane should have.
+    pub max_replicas: usize,
+    /// The ticks without a heartbeat after which a store is down, and its
+    /// replicas are made again elsewhere.
+    pub max_store_down_ticks: u64,
+    /// The ticks after which an unfinished operator is given up.
+    pub operator_timeout_ticks: u64,
+    /// The difference in counts the balance schedulers tolerate between stores.
+    pub balance_tolerance: usize,
+    /// The operators of one scheduler that may run at once.
+    pub schedule_limit: usize,
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> ScheduleConfig {
+        ScheduleConfig {
+            max_replicas: 3,
+            max_store_down_ticks: 30,
+            operator_timeout_ticks: 100,
+            balance_tolerance: 1,
+            schedule_limit: 4,
+        }
+    }
+}
+
+pub struct ScheduleContext<'a> {
+    pub cluster: &'a BasicCluster,
+    pub cfg: &'a ScheduleConfig,
+    /// The current tick.
+    pub now: u64,
+    /// The running operators, by brane.
+    pub operators: &'a HashMap<u64, Operator>,
+    pub alloc_id: &'a mut dyn FnMut() -> Result<u64>,
+}
+
+impl ScheduleContext<'_> {
+    /// Whether replicas may be placed on the store.
+    fn is_store_available(&self, store_id: u64) -> bool {
+        self.cluster.get_store(store_id).is_some_and(|s| {
+            s.meta.state == StoreState::Up && !s.is_down(self.now, self.cfg.max_store_down_ticks)
+        })
+    }
+
+    fn available_stores(&self) -> Vec<u64> {
+        self.cluster
+            .stores()
+            .map(|s| s.meta.id)
+            .filter(|&id| self.is_store_available(id))
+            .collect()
+    }
+
+    /// Whether the brane can be scheduled: it has a leader, no lagging peer, and
+    /// no operator running.
+    fn is_schedulable(&self, info: &BraneInfo) -> bool {
+        info.leader.is_some()
+            && info.pending_peers.is_empty()
+            && !self.operators.contains_key(&info.brane.id)
+    }
+
+    /// The replicas and the leaderships the store gains, or loses if negative,
+    /// once the running operators finish.
+    fn influence(&self, store_id: u64) -> (i64, i64) {
+        let (mut replicas, mut leaders) = (0, 0);
+        for op in self.operators.values() {
+            let mut leader = self
+                .cluster
+                .get_brane(op.brane_id)
+                .and_then(|info| info.leader);
+            for step in op.pending_steps() {
+                match step {
+                    OperatorStep::AddPeer(p) | OperatorStep::AddLearner(p)
+                        if p.store_id == store_id =>
+                    {
+                        replicas += 1
+                    }
+                    OperatorStep::RemovePeer(p) if p.store_id == store_id => replicas -= 1,
+                    OperatorStep::TransferLeader(p) => {
+                        if p.store_id == store_id {
+                            leaders += 1;
+                        }
+                        if leader.is_some_and(|l| l.store_id == store_id) {
+                            leaders -= 1;
+                        }
+                        leader = Some(*p);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        (replicas, leaders)
+    }
+
+    /// The replicas on the store once the running operators finish.
+    fn brane_count(&self, store_id: u64) -> i64 {
+        self.cluster.brane_count(store_id) as i64 + self.influence(store_id).0
+    }
+
+    /// The branes led from the store once the running operators finish.
+    fn leader_count(&self, store_id: u64) -> i64 {
+        self.cluster.leader_count(store_id) as i64 + self.influence(store_id).1
+    }
+
+    fn running(&self, desc: &str) -> usize {
+        self.operators.values().filter(|op| op.desc == desc).count()
+    }
+
+    fn new_peer(&mut self, store_id: u64) -> Result<Peer> {
+        Ok(Peer::new((self.alloc_id)()?, store_id))
+    }
+}
+
+pub trait Scheduler: Send {
+    fn name(&self) -> &'static str;
+
+    fn schedule(&mut self, ctx: &mut ScheduleContext<'_>) -> Result<Option<Operator>>;
+}
+
+/// The steps that add the voter `peer`: a learner first, promoted once it was
+/// sent a snapshot, so that a replica without the data never counts towards a
+/// quorum.
+fn add_voter_steps(peer: Peer) -> Vec<OperatorStep> {
+    vec![
+        OperatorStep::AddLearner(Peer {
+            role: PeerRole::Learner,
+            ..peer
+        }),
+        OperatorStep::PromoteLearner(peer),
+    ]
+}
+
+/// The steps that remove `peer`, handing the leadership to another peer first if
+/// it leads.
+fn remove_peer_steps(info: &BraneInfo, peer: Peer, successor: Option<Peer>) -> Vec<OperatorStep> {
+    let mut steps = Vec::new();
+    if info.leader.is_some_and(|l| l.id == peer.id) {
+        let other = || {
+            info.brane
+                .peers
+                .iter()
+                .copied()
+                .find(|p| p.id != peer.id && p.can_lead())
+        };
+        match successor.or_else(other) {
+            Some(to) => steps.push(OperatorStep::TransferLeader(to)),
+            None => return steps,
+        }
+    }
+    steps.push(OperatorStep::RemovePeer(peer));
+    steps
+}
+
+/// Keeps every brane at `max_replicas` replicas on available stores: adds one
+/// where one is missing or on a store that is down or drained, then removes the
+/// lost or extra one.
+pub struct ReplicaChecker;
+
+impl Scheduler for ReplicaChecker {
+    fn name(&self) -> &'static str {
+        "replica-checker"
+    }
+
+    fn schedule(&mut self, ctx: &mut ScheduleContext<'_>) -> Result<Option<Operator>> {
+        if ctx.running(self.name()) >= ctx.cfg.schedule_limit {
+            return Ok(None);
+        }
+        let cluster = ctx.cluster;
+        for info in cluster.branes() {
+            if info.leader.is_none() || ctx.operators.contains_key(&info.brane.id) {
+                continue;
+            }
+            let peers = &info.brane.peers;
+            // A learner left by an operator that did not finish.
+            let stray = peers
+                .iter()
+                .copied()
+                .find(|p| p.is_learner() && !info.pending_peers.contains(p));
+            if let Some(learner) = stray {
+                let voter = Peer {
+                    role: PeerRole::Voter,
+                    ..learner
+                };
+                let op = Operator::new(
+                    info.brane.id,
+                    self.name(),
+                    vec![OperatorStep::PromoteLearner(voter)],
+                    ctx.now,
+                );
+                return Ok(Some(op));
+            }
+            let healthy: Vec<Peer> = peers
+                .iter()
+                .copied()
+                .filter(|p| ctx.is_store_available(p.store_id))
+                .collect();
+            if healthy.len() < ctx.cfg.max_replicas {
+                let target = ctx
+                    .available_stores()
+                    .into_iter()
+                    .filter(|&s| info.brane.peer_on_store(s).is_none())
+                    .min_by_key(|&s| (ctx.brane_count(s), s));
+                if let Some(store_id) = target {
+                    let peer = ctx.new_peer(store_id)?;
+                    let op =
+                        Operator::new(info.brane.id, self.name(), add_voter_steps(peer), ctx.now);
+                    return Ok(Some(op));
+                }
+            }
+            let extra = if healthy.len() < peers.len() {
+                // Lost to a store that is down or drained.
+                peers.iter().copied().find(|p| !healthy.contains(p))
+            } else if peers.len() > ctx.cfg.max_replicas {
+                healthy
+                    .iter()
+                    .copied()
+                    .max_by_key(|p| (ctx.brane_count(p.store_id), p.store_id))
+            } else {
+                None
+            };
+            if let Some(peer) = extra {
+                if healthy.len() > 1 {
+                    let successor = healthy.iter().copied().find(|p| *p != peer && p.can_lead());
+                    let steps = remove_peer_steps(info, peer, successor);
+                    return Ok(Some(Operator::new(
+                        info.brane.id,
+                        self.name(),
+                        steps,
+                        ctx.now,
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Moves replicas from the store holding the most to the one holding the
+/// fewest.
+pub struct BalanceReplicaScheduler;
+
+impl Scheduler for BalanceReplicaScheduler {
+    fn name(&self) -> &'static str {
+        "balance-replica"
+    }
+
+    fn schedule(&mut self, ctx: &mut ScheduleContext<'_>) -> Result<Option<Operator>> {
+        if ctx.running(self.name()) >= ctx.cfg.schedule_limit {
+            return Ok(None);
+        }
+        let cluster = ctx.cluster;
+        let stores = ctx.available_stores();
+        let source = stores
+            .iter()
+            .copied()
+            .max_by_key(|&s| (ctx.brane_count(s), s));
+        let target = stores
+            .iter()
+            .copied()
+            .min_by_key(|&s| (ctx.brane_count(s), s));
+        let (source, target) = match (source, target) {
+            (Some(source), Some(target)) => (source, target),
+            _ => return Ok(None),
+        };
+        if ctx.brane_count(source) <= ctx.brane_count(target) + ctx.cfg.balance_tolerance as i64 {
+            return Ok(None);
+        }
+        let candidate = cluster.branes().find(|info| {
+            ctx.is_schedulable(info)
+                && info.brane.peers.len() == ctx.cfg.max_replicas
+                && info
+                    .brane
+                    .peer_on_store(source)
+                    .is_some_and(|p| p.can_lead())
+                && info.brane.peer_on_store(target).is_none()
+        });
+        let info = match candidate {
+            Some(info) => info,
+            None => return Ok(None),
+        };
+        let peer = ctx.new_peer(target)?;
+        let mut steps = add_voter_steps(peer);
+        let old = info.brane.peer_on_store(source).unwrap();
+        steps.extend(remove_peer_steps(info, old, Some(peer)));
+        Ok(Some(Operator::new(
+            info.brane.id,
+            self.name(),
+            steps,
+            ctx.now,
+        )))
+    }
+}
+
+/// Moves leaderships from the store leading the most branes to followers on
+/// stores leading fewer.
+pub struct BalanceLeaderScheduler;
+
+impl Scheduler for BalanceLeaderScheduler {
+    fn name(&self) -> &'static str {
+        "balance-leader"
+    }
+
+    fn schedule(&mut self, ctx: &mut ScheduleContext<'_>) -> Result<Option<Operator>> {
+        if ctx.running(self.name()) >= ctx.cfg.schedule_limit {
+            return Ok(None);
+        }
+        let cluster = ctx.cluster;
+        let stores = ctx.available_stores();
+        let source = match stores
+            .iter()
+            .copied()
+            .max_by_key(|&s| (ctx.leader_count(s), s))
+        {
+            Some(source) => source,
+            None => return Ok(None),
+        };
+        let source_count = ctx.leader_count(source);
+        let mut best: Option<(i64, u64, Peer)> = None;
+        for info in cluster.branes() {
+            if !ctx.is_schedulable(info) || info.leader.unwrap().store_id != source {
+                continue;
+            }
+            for peer in &info.brane.peers {
+                if peer.store_id == source
+                    || !peer.can_lead()
+                    || !ctx.is_store_available(peer.store_id)
+                {
+                    continue;
+                }
+                let count = ctx.leader_count(peer.store_id);
+                if best.is_none_or(|(c, _, _)| count < c) {
+                    best = Some((count, info.brane.id, *peer));
+                }
+            }
+        }
+        match best {
+            Some((count, brane_id, peer))
+                if source_count > count + ctx.cfg.balance_tolerance as i64 =>
+            {
+                Ok(Some(Operator::new(
+                    brane_id,
+                    self.name(),
+                    vec![OperatorStep::TransferLeader(peer)],
+                    ctx.now,
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The placement driver service.
+//!
+//! The metadata of stores and branes is kept in memory and rebuilt from the
+//! heartbeats after a restart. Ids and timestamps are persisted ahead of use, so
+//! that neither goes back.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use violetabftstore::Brane;
+
+use crate::errors::{Error, Result};
+use crate::meta::{BasicCluster, BraneHeartbeat, BraneInfo, StoreMeta, StoreState, StoreStats};
+use crate::operator::{Operator, OperatorStep};
+use crate::saved_limit::SavedLimit;
+use crate::schedule::{
+    BalanceLeaderScheduler, BalanceReplicaScheduler, ReplicaChecker, ScheduleConfig,
+    ScheduleContext, Scheduler,
+};
+use crate::tso::TimestampOracle;
+
+/// The ids allocated between two writes of the limit.
+const ID_BATCH: u64 = 1000;
+
+#[derive(Clone, Debug, Default)]
+pub struct PdConfig {
+    /// Where ids and timestamps are persisted; in memory only if unset.
+    pub data_dir: Option<PathBuf>,
+    pub schedule: ScheduleConfig,
+}
+
+struct IdAllocator {
+    next_id: u64,
+    limit: SavedLimit,
+}
+
+impl IdAllocator {
+    fn alloc(&mut self) -> Result<u64> {
+        if self.next_id >= self.limit.limit() {
+            self.limit.save(self.next_id + ID_BATCH)?;
+        }
+        self.next_id += 1;
+        Ok(self.next_id)
+    }
+}
+
+struct Inner {
+    cfg: PdConfig,
+    bootstrapped: bool,
+    ids: IdAllocator,
+    tso: TimestampOracle,
+    cluster: BasicCluster,
+    operators: HashMap<u64, Operator>,
+    schedulers: Vec<Box<dyn Scheduler>>,
+    ticks: u64,
+}
+
+impl Inner {
+    fn check_bootstrapped(&self) -> Result<()> {
+        if !self.bootstrapped {
+            return Err(Error::ClusterNotBootstrapped);
+        }
+        Ok(())
+    }
+}
+
+pub struct PdServer {
+    inner: Mutex<Inner>,
+}
+
+impl PdServer {
+    pub fn new(cfg: PdConfig) -> Result<PdServer> {
+        let (id_path, tso_path) = match &cfg.data_dir {
+            Some(dir) => {
+                std::fs::create_dir_all(dir)?;
+                (Some(dir.join("id")), Some(dir.join("tso")))
+            }
+            None => (None, None),
+        };
+        let id_limit = SavedLimit::open(id_path.as_deref())?;
+        let tso = TimestampOracle::open(tso_path.as_deref())?;
+        let schedulers: Vec<Box<dyn Scheduler>> = vec![
+            Box::new(ReplicaChecker),
+            Box::new(BalanceReplicaScheduler),
+            Box::new(BalanceLeaderScheduler),
+        ];
+        Ok(PdServer {
+            inner: Mutex::new(Inner {
+                cfg,
+                bootstrapped: false,
+                ids: IdAllocator {
+                    // Every id below the limit may have been handed out.
+                    next_id: id_limit.limit(),
+                    limit: id_limit,
+                },
+                tso,
+                cluster: BasicCluster::new(),
+                operators: HashMap::new(),
+                schedulers,
+                ticks: 0,
+            }),
+        })
+    }
+
+    /// Records the first store of the cluster and its first brane.
+    pub fn bootstrap_cluster(&self, store: StoreMeta, brane: Brane) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.bootstrapped {
+            return Err(Error::ClusterBootstrapped);
+        }
+        let now = inner.ticks;
+        inner.cluster.put_store(store, now)?;
+        inner.cluster.put_brane(BraneInfo {
+            brane,
+            ..Default::default()
+        })?;
+        inner.bootstrapped = true;
+        Ok(())
+    }
+
+    pub fn is_bootstrapped(&self) -> bool {
+        self.inner.lock().unwrap().bootstrapped
+    }
+
+    pub fn alloc_id(&self) -> Result<u64> {
+        self.inner.lock().unwrap().ids.alloc()
+    }
+
+    /// Hands out `count` consecutive timestamps, returning the first.
+    pub fn get_ts(&self, count: u32) -> Result<u64> {
+        self.inner.lock().unwrap().tso.get_ts(count)
+    }
+
+    pub fn put_store(&self, store: StoreMeta) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_bootstrapped()?;
+        let now = inner.ticks;
+        inner.cluster.put_store(store, now)
+    }
+
+    pub fn get_store(&self, store_id: u64) -> Result<StoreMeta> {
+        let inner = self.inner.lock().unwrap();
+        match inner.cluster.get_store(store_id) {
+            Some(info) if info.meta.state == StoreState::Tombstone => {
+                Err(Error::StoreTombstone(store_id))
+            }
+            Some(info) => Ok(info.meta.clone()),
+            None => Err(Error::StoreNotFound(store_id)),
+        }
+    }
+
+    pub fn get_all_stores(&self) -> Vec<StoreMeta> {
+        let inner = self.inner.lock().unwrap();
+        inner.cluster.stores().map(|s| s.meta.clone()).collect()
+    }
+
+    /// Drains (`Offline`) or removes (`Tombstone`) a store.
+    pub fn set_store_state(&self, store_id: u64, state: StoreState) -> Result<()> {
+        self.inner
+            .lock()
+            .unwrap()
+            .cluster
+            .set_store_state(store_id, state)
+    }
+
+    pub fn store_heartbeat(&self, stats: StoreStats) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_bootstrapped()?;
+        let now = inner.ticks;
+        inner.cluster.handle_store_heartbeat(stats, now)
+    }
+
+    /// Records what the leader of a brane reports; the step of the operator of
+    /// the brane it should carry out, if one is running.
+    pub fn brane_heartbeat(&self, hb: BraneHeartbeat) -> Result<Option<OperatorStep>> {
+        let mut inner = self.inner.lock().unwrap();
+        let inner = &mut *inner;
+        let brane_id = hb.brane.id;
+        inner.cluster.put_brane(hb.into())?;
+        // A cluster whose metadata was lost learns it is bootstrapped.
+        inner.bootstrapped = true;
+        let info = inner.cluster.get_brane(brane_id).unwrap();
+        let op = match inner.operators.get_mut(&brane_id) {
+            Some(op) => op,
+            None => return Ok(None),
+        };
+        let step = op.check(info).cloned();
+        if step.is_none() || op.is_timeout(inner.ticks, inner.cfg.schedule.operator_timeout_ticks) {
+            inner.operators.remove(&brane_id);
+            return Ok(None);
+        }
+        Ok(step)
+    }
+
+    pub fn get_brane(&self, soliton_id: &[u8]) -> Result<BraneInfo> {
+        let inner = self.inner.lock().unwrap();
+        inner.check_bootstrapped()?;
+        inner
+            .cluster
+            .brane_for_key(soliton_id)
+            .cloned()
+            .ok_or_else(|| Error::BraneNotFound(soliton_id.to_vec()))
+    }
+
+    pub fn get_brane_by_id(&self, brane_id: u64) -> Result<Option<BraneInfo>> {
+        let inner = self.inner.lock().unwrap();
+        inner.check_bootstrapped()?;
+        Ok(inner.cluster.get_brane(brane_id).cloned())
+    }
+
+    /// The running operators.
+    pub fn operators(&self) -> Vec<Operator> {
+        let inner = self.inner.lock().unwrap();
+        inner.operators.values().cloned().collect()
+    }
+
+    /// Advances the clock of the placement driver by a tick, and runs the
+    /// schedulers.
+    pub fn tick(&self) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        let inner = &mut *inner;
+        inner.ticks += 1;
+        if !inner.bootstrapped {
+            return Ok(());
+        }
+        let now = inner.ticks;
+        let timeout = inner.cfg.schedule.operator_timeout_ticks;
+        inner.operators.retain(|_, op| !op.is_timeout(now, timeout));
+        let Inner {
+            cfg,
+            ids,
+            cluster,
+            operators,
+            schedulers,
+            ..
+        } = inner;
+        for scheduler in schedulers {
+            let mut alloc_id = || ids.alloc();
+            let mut ctx = ScheduleContext {
+                cluster,
+                cfg: &cfg.schedule,
+                now,
+                operators,
+                alloc_id: &mut alloc_id,
+            };
+            if let Some(op) = scheduler.schedule(&mut ctx)? {
+                operators.insert(op.brane_id, op);
+            }
+        }
+        Ok(())
+    }
+}
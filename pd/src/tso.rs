@@ -0,0 +1,107 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The timestamp oracle: timestamps that only grow, across restarts too.
+//!
+//! A timestamp is the physical time in milliseconds shifted left by
+//! `PHYSICAL_SHIFT_BITS`, plus a logical counter that orders the timestamps
+//! handed out within one millisecond.
+
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::{Error, Result};
+use crate::saved_limit::SavedLimit;
+
+pub const PHYSICAL_SHIFT_BITS: u32 = 18;
+const MAX_LOGICAL: u64 = 1 << PHYSICAL_SHIFT_BITS;
+/// How far ahead of the physical time handed out the persisted limit is moved.
+const SAVE_INTERVAL_MS: u64 = 3000;
+
+pub fn compose_ts(physical: u64, logical: u64) -> u64 {
+    (physical << PHYSICAL_SHIFT_BITS) + logical
+}
+
+pub fn extract_physical(ts: u64) -> u64 {
+    ts >> PHYSICAL_SHIFT_BITS
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+pub struct TimestampOracle {
+    physical: u64,
+    logical: u64,
+    /// No timestamp of this physical time or later is handed out before a later
+    /// limit is persisted.
+    saved: SavedLimit,
+}
+
+impl TimestampOracle {
+    /// Opens the oracle, persisting its limit at `path` if any. It starts past
+    /// every timestamp it may have handed out before.
+    pub fn open(path: Option<&Path>) -> Result<TimestampOracle> {
+        let saved = SavedLimit::open(path)?;
+        Ok(TimestampOracle {
+            physical: saved.limit(),
+            logical: 0,
+            saved,
+        })
+    }
+
+    /// Hands out `count` consecutive timestamps, returning the first.
+    pub fn get_ts(&mut self, count: u32) -> Result<u64> {
+        let count = count as u64;
+        if count == 0 || count >= MAX_LOGICAL {
+            return Err(Error::Other(format!(
+                "can not allocate {} timestamps",
+                count
+            )));
+        }
+        let now = now_ms();
+        if now > self.physical {
+            self.physical = now;
+            self.logical = 0;
+        }
+        if self.logical + count > MAX_LOGICAL {
+            // The logical counter is exhausted: run ahead of the clock.
+            self.physical += 1;
+            self.logical = 0;
+        }
+        if self.physical >= self.saved.limit() {
+            self.saved.save(self.physical + SAVE_INTERVAL_MS)?;
+        }
+        let ts = compose_ts(self.physical, self.logical);
+        self.logical += count;
+        Ok(ts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_monotonic_across_restarts() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tso");
+        let mut tso = TimestampOracle::open(Some(&path)).unwrap();
+        let mut last = 0;
+        for _ in 0..1000 {
+            let ts = tso.get_ts(100).unwrap();
+            assert!(ts > last);
+            last = ts + 99;
+        }
+        assert!(extract_physical(last) >= now_ms() - 60_000);
+        assert!(tso.get_ts(0).is_err());
+
+        // A restarted oracle never goes back, even if the clock did.
+        drop(tso);
+        let mut tso = TimestampOracle::open(Some(&path)).unwrap();
+        assert!(tso.get_ts(1).unwrap() > last);
+    }
+}
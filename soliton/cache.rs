@@ -15,7 +15,6 @@
 //         $crate::einsteindb_macro_impl!($($tokens)*)
 //     };
 
-pub use self::cache::Cache;
 
 use grpc::{Client, ChannelBuilder, ClientStub, RpcContext, RpcStatus, RpcStatusCode};
 use grpc::{Server, ServerBuilder, ServerUnaryExt, ServerStreamingExt, ServerBidirectionalExt};
@@ -37,82 +36,470 @@ use grpc::{Server, ServerBuilder, ServerUnaryExt, ServerStreamingExt, ServerBidi
 
 
 //     ($($tokens:tt)*) => {
-use einstein_ml::{
-    cache::{
-        Cache, CacheConfig, CacheType, CacheValue, CacheValueType,
-    }
-};
 
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
 use std::time::{Duration, Instant};
 use std::thread;
 use std::sync::mpsc::{channel, Sender, Receiver};
 use std::sync::mpsc;
 use std::sync::mpsc::{TryRecvError};
 
-#[derive(Clone, Debug)]
+use einsteindb_util::config::{OptionReadableSize, ReadableSize};
+use einsteindb_util::sys::SysQuota;
+use online_config::OnlineConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::config::BLOCK_CACHE_RATE;
+
+/// How the block cache is built: one cache whose budget every causet_merge
+/// family of the einstein_merkle_trees shares, or, when it is not `shared`, a
+/// cache per causet_merge family sized by its `block_cache_size`.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug, OnlineConfig)]
+#[serde(default)]
+#[serde(rename_all = "kebab-case")]
 pub struct CacheConfig {
-    pub cache_type: CacheType,
-    pub cache_value_type: CacheValueType,
-    pub cache_size: usize,
-    pub cache_ttl: Duration,
-    pub cache_clean_interval: Duration,
+    #[online_config(skip)]
+    pub shared: bool,
+    /// The budget of the shared cache, `BLOCK_CACHE_RATE` of the memory when unset.
+    pub capacity: OptionReadableSize,
+    #[online_config(skip)]
+    pub num_shard_bits: i32,
+    /// The part of each shard the index and filter blocks, and the blocks hit
+    /// since they were cached, are kept in before the others are evicted.
+    #[online_config(skip)]
+    pub high_pri_pool_ratio: f64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> CacheConfig {
+        CacheConfig {
+            shared: true,
+            capacity: OptionReadableSize(None),
+            num_shard_bits: 6,
+            high_pri_pool_ratio: 0.8,
+        }
+    }
 }
 
+impl CacheConfig {
+    /// The cache every causet_merge family reads through, `None` when each has its
+    /// own.
+    pub fn build_shared_cache(&self) -> Option<Cache> {
+        if !self.shared {
+            return None;
+        }
+        let capacity = match self.capacity.0 {
+            Some(c) => c.0 as usize,
+            None => (SysQuota::memory_limit_in_bytes() as f64 * BLOCK_CACHE_RATE) as usize,
+        };
+        Some(Cache::new(
+            capacity,
+            self.num_shard_bits as u32,
+            self.high_pri_pool_ratio,
+        ))
+    }
+}
+
+/// The contents of a data block.
+pub type Block = Arc<Vec<u8>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Priority {
+    High,
+    Low,
+}
+
+/// Identifies a block: the cache id of its table and its offset in the file.
+type BlockKey = (u64, u64);
+
+struct Entry {
+    block: Block,
+    charge: usize,
+    priority: Priority,
+    hit: bool,
+    tick: u64,
+    in_high_pool: bool,
+}
+
+/// A shard of the cache. Its LRU list is split in two pools: blocks of high
+/// priority and blocks hit since they were inserted go to the high priority
+/// pool, whose oldest blocks move to the low priority pool once it holds more
+/// than its part of the shard. Blocks are evicted from the oldest of the low
+/// priority pool first.
+#[derive(Default)]
+struct LruShard {
+    capacity: usize,
+    high_pri_capacity: usize,
+    /// Charges of the cached blocks and reservations.
+    usage: usize,
+    /// Charges of the blocks of the high priority pool and reservations.
+    high_pri_usage: usize,
+    reserved: usize,
+    entries: HashMap<BlockKey, Entry>,
+    /// The blocks of each pool, by their last use.
+    high_pool: BTreeMap<u64, BlockKey>,
+    low_pool: BTreeMap<u64, BlockKey>,
+    next_tick: u64,
+}
+
+impl LruShard {
+    fn set_capacity(&mut self, capacity: usize, high_pri_pool_ratio: f64) {
+        self.capacity = capacity;
+        self.high_pri_capacity = (capacity as f64 * high_pri_pool_ratio) as usize;
+        self.maintain_pools();
+    }
+
+    /// Makes `key` the newest block of the pool it belongs to.
+    fn push(&mut self, key: BlockKey) {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        let high_pri_capacity = self.high_pri_capacity;
+        let e = self.entries.get_mut(&key).unwrap();
+        e.tick = tick;
+        e.in_high_pool = high_pri_capacity > 0 && (e.priority == Priority::High || e.hit);
+        if e.in_high_pool {
+            self.high_pri_usage += e.charge;
+            self.high_pool.insert(tick, key);
+        } else {
+            self.low_pool.insert(tick, key);
+        }
+    }
 
-#[derive(Clone, Debug)]
-pub struct CacheValue {
-    pub value: String,
-    pub last_access: Instant,
+    fn unlink(&mut self, key: BlockKey) {
+        let e = &self.entries[&key];
+        if e.in_high_pool {
+            self.high_pri_usage -= e.charge;
+            self.high_pool.remove(&e.tick);
+        } else {
+            self.low_pool.remove(&e.tick);
+        }
+    }
+
+    /// Moves the oldest blocks of the high priority pool to the low priority one
+    /// until it fits, then evicts until the shard fits.
+    fn maintain_pools(&mut self) {
+        while self.high_pri_usage > self.high_pri_capacity {
+            let key = match self.high_pool.pop_first() {
+                Some((_, key)) => key,
+                None => break,
+            };
+            let e = self.entries.get_mut(&key).unwrap();
+            e.in_high_pool = false;
+            self.high_pri_usage -= e.charge;
+            let tick = self.next_tick;
+            self.next_tick += 1;
+            e.tick = tick;
+            self.low_pool.insert(tick, key);
+        }
+        while self.usage > self.capacity {
+            let key = match self.low_pool.first_key_value() {
+                Some((_, &key)) => key,
+                None => match self.high_pool.first_key_value() {
+                    Some((_, &key)) => key,
+                    None => break,
+                },
+            };
+            self.erase(key);
+        }
+    }
+
+    fn erase(&mut self, key: BlockKey) {
+        if self.entries.contains_key(&key) {
+            self.unlink(key);
+            let e = self.entries.remove(&key).unwrap();
+            self.usage -= e.charge;
+        }
+    }
+
+    fn lookup(&mut self, key: BlockKey) -> Option<Block> {
+        let block = {
+            let e = self.entries.get_mut(&key)?;
+            e.hit = true;
+            e.block.clone()
+        };
+        self.unlink(key);
+        self.push(key);
+        self.maintain_pools();
+        Some(block)
+    }
+
+    fn insert(&mut self, key: BlockKey, block: Block, charge: usize, priority: Priority) {
+        self.erase(key);
+        self.entries.insert(
+            key,
+            Entry {
+                block,
+                charge,
+                priority,
+                hit: false,
+                tick: 0,
+                in_high_pool: false,
+            },
+        );
+        self.usage += charge;
+        self.push(key);
+        self.maintain_pools();
+    }
+
+    fn reserve(&mut self, charge: usize) {
+        self.usage += charge;
+        self.high_pri_usage += charge;
+        self.reserved += charge;
+        self.maintain_pools();
+    }
+
+    fn release(&mut self, charge: usize) {
+        self.usage -= charge;
+        self.high_pri_usage -= charge;
+        self.reserved -= charge;
+    }
 }
 
+/// Block cache hits and misses of the tables of a causet_merge family.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
 
-#[derive(Clone, Debug)]
-pub struct Cache {
-    pub cache_config: CacheConfig,
-    pub cache_map: Arc<RwLock<HashMap<String, CacheValue>>>,
-    pub cache_clean_thread: Option<thread::JoinHandle<()>>,
+#[derive(Debug, Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
-pub(crate) struct CacheImpl {
-    cache: Arc<RwLock<Cache>>,
+
+struct CacheCore {
+    shards: Vec<Mutex<LruShard>>,
+    shard_bits: u32,
+    high_pri_pool_ratio: f64,
+    capacity: AtomicU64,
+    next_id: AtomicU64,
+    counters: RwLock<HashMap<String, Arc<CacheCounters>>>,
 }
 
+/// A sharded LRU block cache. Clones share the same cache, so that the
+/// causet_merge families `build_opt` is given it for share one budget.
+#[derive(Clone)]
+pub struct Cache {
+    core: Arc<CacheCore>,
+}
+
+impl Cache {
+    /// A cache of `capacity` bytes split into `2^num_shard_bits` shards.
+    pub fn new(capacity: usize, num_shard_bits: u32, high_pri_pool_ratio: f64) -> Cache {
+        let shards = (0..1usize << num_shard_bits)
+            .map(|_| Mutex::new(LruShard::default()))
+            .collect();
+        let cache = Cache {
+            core: Arc::new(CacheCore {
+                shards,
+                shard_bits: num_shard_bits,
+                high_pri_pool_ratio: high_pri_pool_ratio.clamp(0.0, 1.0),
+                capacity: AtomicU64::new(0),
+                next_id: AtomicU64::new(1),
+                counters: RwLock::default(),
+            }),
+        };
+        cache.set_capacity(capacity);
+        cache
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.core.capacity.load(Ordering::Relaxed) as usize
+    }
+
+    /// Changes the capacity, evicting blocks if the cache shrinks.
+    pub fn set_capacity(&self, capacity: usize) {
+        self.core.capacity.store(capacity as u64, Ordering::Relaxed);
+        let per_shard = capacity.div_ceil(self.core.shards.len());
+        for shard in &self.core.shards {
+            shard
+                .lock()
+                .unwrap()
+                .set_capacity(per_shard, self.core.high_pri_pool_ratio);
+        }
+    }
+
+    /// The charges of the cached blocks and of the index and filter blocks of open
+    /// tables.
+    pub fn usage(&self) -> usize {
+        self.core
+            .shards
+            .iter()
+            .map(|s| s.lock().unwrap().usage)
+            .sum()
+    }
+
+    /// The charges of the index and filter blocks of open tables.
+    pub fn pinned_usage(&self) -> usize {
+        self.core
+            .shards
+            .iter()
+            .map(|s| s.lock().unwrap().reserved)
+            .sum()
+    }
+
+    /// A new cache id, for the blocks of a table.
+    pub fn new_id(&self) -> u64 {
+        self.core.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn shard(&self, id: u64, offset: u64) -> MutexGuard<'_, LruShard> {
+        let hash = (id ^ offset.rotate_left(32)).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let idx = hash.checked_shr(64 - self.core.shard_bits).unwrap_or(0);
+        self.core.shards[idx as usize].lock().unwrap()
+    }
+
+    fn counters(&self, namespaced: &str) -> Arc<CacheCounters> {
+        if let Some(c) = self.core.counters.read().unwrap().get(namespaced) {
+            return c.clone();
+        }
+        let mut counters = self.core.counters.write().unwrap();
+        counters.entry(namespaced.to_owned()).or_default().clone()
+    }
+
+    /// The block at `offset` of the table `id` of `namespaced`, counting a hit or a
+    /// miss for `namespaced`.
+    pub fn lookup(&self, namespaced: &str, id: u64, offset: u64) -> Option<Block> {
+        let block = self.shard(id, offset).lookup((id, offset));
+        let counters = self.counters(namespaced);
+        let counter = match block {
+            Some(_) => &counters.hits,
+            None => &counters.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        block
+    }
+
+    pub fn insert(&self, id: u64, offset: u64, block: Block, charge: usize, priority: Priority) {
+        self.shard(id, offset)
+            .insert((id, offset), block, charge, priority);
+    }
+
+    /// Charges the index and filter blocks of the table `id`, `charge` bytes, to the
+    /// high priority pool of its shard until the returned reservation is dropped.
+    pub fn reserve(&self, id: u64, charge: usize) -> CacheReservation {
+        self.shard(id, 0).reserve(charge);
+        CacheReservation {
+            cache: self.clone(),
+            id,
+            charge,
+        }
+    }
 
-impl CacheImpl {
-    pub(crate) fn new(cache: Cache) -> CacheImpl {
-        CacheImpl {
-            cache: Arc::new(RwLock::new(cache)),
+    /// The hits and misses of the tables of `namespaced`.
+    pub fn stats(&self, namespaced: &str) -> CacheStats {
+        let counters = self.counters(namespaced);
+        CacheStats {
+            hits: counters.hits.load(Ordering::Relaxed),
+            misses: counters.misses.load(Ordering::Relaxed),
         }
     }
 }
 
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache")
+            .field("capacity", &self.capacity())
+            .field("shards", &self.core.shards.len())
+            .field("high_pri_pool_ratio", &self.core.high_pri_pool_ratio)
+            .finish()
+    }
+}
 
-/// A simple in-memory cache.
-///
-/// This cache is not thread-safe.
-///
-/// # Examples
-///
-/// ```
-/// use soliton::cache::{Cache, CacheEntry};
-///
-/// let mut cache = Cache::new();
-///
-/// cache.set("foo", CacheEntry::new("bar", Duration::from_secs(1)));
-///
-///     // The cache entry is still valid.
-/// assert_eq!(cache.get("foo").unwrap().value(), "bar");
-///
-/// std::thread::sleep(Duration::from_secs(1));
-///
-///    // The cache entry is no longer valid.
-/// assert!(cache.get("foo").is_none());
-/// ```
-///
-/// ```
-/// use soliton::cache::{Cache, CacheEntry};
-///
+pub struct CacheReservation {
+    cache: Cache,
+    id: u64,
+    charge: usize,
+}
+
+impl Drop for CacheReservation {
+    fn drop(&mut self) {
+        self.cache.shard(self.id, 0).release(self.charge);
+    }
+}
+
+#[cfg(test)]
+mod block_cache_tests {
+    use super::*;
+
+    fn block(n: usize) -> Block {
+        Arc::new(n.to_be_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_lru_eviction_and_priorities() {
+        let cache = Cache::new(100, 0, 0.5);
+        for i in 0..10 {
+            cache.insert(1, i, block(i as usize), 10, Priority::Low);
+        }
+        assert_eq!(cache.usage(), 100);
+        // Hitting a block moves it to the high priority pool.
+        assert!(cache.lookup("default", 1, 0).is_some());
+        cache.insert(1, 10, block(10), 10, Priority::Low);
+        assert!(cache.lookup("default", 1, 0).is_some());
+        assert!(cache.lookup("default", 1, 1).is_none());
+        assert_eq!(cache.usage(), 100);
+
+        // High priority blocks beyond the pool are demoted, not evicted first.
+        for i in 20..26 {
+            cache.insert(1, i, block(i as usize), 10, Priority::High);
+        }
+        assert_eq!(cache.usage(), 100);
+        for i in 21..26 {
+            assert!(cache.lookup("default", 1, i).is_some(), "{}", i);
+        }
+        assert!(cache.lookup("default", 1, 2).is_none());
+
+        // Index and filter blocks count against the budget and are never evicted.
+        let reservation = cache.reserve(2, 60);
+        assert_eq!(cache.pinned_usage(), 60);
+        assert_eq!(cache.usage(), 100);
+        drop(reservation);
+        assert_eq!(cache.pinned_usage(), 0);
+        assert_eq!(cache.usage(), 40);
+
+        cache.set_capacity(20);
+        assert_eq!(cache.usage(), 20);
+        assert_eq!(cache.capacity(), 20);
+    }
+
+    #[test]
+    fn test_shared_budget_and_stats() {
+        let cfg = CacheConfig {
+            capacity: OptionReadableSize(Some(ReadableSize(100_000))),
+            num_shard_bits: 4,
+            ..Default::default()
+        };
+        let cache = cfg.build_shared_cache().unwrap();
+        let shared = cache.clone();
+        let (a, b) = (cache.new_id(), shared.new_id());
+        assert_ne!(a, b);
+        for i in 0..1000 {
+            cache.insert(a, i * 4096, block(i as usize), 100, Priority::Low);
+            shared.insert(b, i * 4096, block(i as usize), 100, Priority::Low);
+        }
+        // Both causet_merge families are charged to the one budget.
+        assert!(cache.usage() <= 100_000 + 16 * 100);
+        assert_eq!(cache.usage(), shared.usage());
+        for i in 0..10 {
+            cache.lookup("write", b, i * 4096);
+        }
+        assert!(cache.lookup("default", a, 999 * 4096).is_some());
+        assert_eq!(cache.stats("default"), CacheStats { hits: 1, misses: 0 });
+        assert_eq!(cache.stats("write").hits + cache.stats("write").misses, 10);
+        assert_eq!(cache.stats("lock"), CacheStats::default());
+
+        let cfg = CacheConfig {
+            shared: false,
+            ..Default::default()
+        };
+        assert!(cfg.build_shared_cache().is_none());
+    }
+}
 
 // Right now we use BTreeMap, because we expect few cached attributes.
 pub type CacheMap<K, V> = BTreeMap<K, V>;
@@ -28,12 +28,16 @@ use std::path::Path;
 use std::process::Command;
 
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::{Arc, Mutex};
 use std::sync::mpsc::{channel, Sender, Receiver};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 
+use fdb_traits::ChangeKind;
+
+use crate::cache::{Cache, CacheConfig};
+
 
 
 
@@ -532,11 +536,17 @@ macro_rules! build_namespaced_opt {
         if let Some(cache) = $cache {
             block_base_opts.set_block_cache(cache);
         } else {
-            let mut cache_opts = LRUCacheOptions::new();
-            cache_opts.set_capacity($opt.block_cache_size.0 as usize);
-            block_base_opts.set_block_cache(&Cache::new_lru_cache(cache_opts));
+            // A cache of its own, with the shards and priority pools of the shared one.
+            let cache_APPEND_LOG_g = CacheConfig::default();
+            block_base_opts.set_block_cache(&Cache::new(
+                $opt.block_cache_size.0 as usize,
+                cache_APPEND_LOG_g.num_shard_bits as u32,
+                cache_APPEND_LOG_g.high_pri_pool_ratio,
+            ));
         }
         block_base_opts.set_cache_index_and_filter_blocks($opt.cache_index_and_filter_blocks);
+        // Index and filter blocks are kept in the high priority pool.
+        block_base_opts.set_cache_index_and_filter_blocks_with_high_priority(true);
         block_base_opts
             .set_pin_l0_filter_and_index_blocks_in_cache($opt.pin_l0_filter_and_index_blocks);
         if $opt.use_bloom_filter {
@@ -3098,6 +3108,33 @@ pub fn check_critical_config(config: &EinsteinDbConfig) -> Result<(), String> {
     Ok(())
 }
 
+impl EinsteinDbConfig {
+    /// The fields whose causet_locales differ in `incoming`, and how changing each
+    /// of them takes effect.
+    pub fn classify_changes(
+        &self,
+        incoming: &EinsteinDbConfig,
+    ) -> NamespacedgResult<Vec<ConfigFieldChange>> {
+        let (old, new) = (flatten_config(self)?, flatten_config(incoming)?);
+        let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        let mut changes = Vec::new();
+        for name in names {
+            let old_causet_locale = old.get(name).cloned().unwrap_or_default();
+            let new_causet_locale = new.get(name).cloned().unwrap_or_default();
+            if old_causet_locale != new_causet_locale {
+                changes.push(ConfigFieldChange {
+                    // Fields the typed config does not know are only read at startup.
+                    kind: change_kind(name).unwrap_or(ChangeKind::RestartRequired),
+                    name: name.clone(),
+                    old_causet_locale,
+                    new_causet_locale,
+                });
+            }
+        }
+        Ok(changes)
+    }
+}
+
 fn get_last_config(data_dir: &str) -> Option<EinsteinDbConfig> {
     let timelike_store_path = Path::new(data_dir);
     let last_APPEND_LOG_g_path = timelike_store_path.join(LAST_CONFIG_FILE);
@@ -3207,6 +3244,56 @@ fn serde_to_online_config(name: String) -> String {
     }
 }
 
+/// How a change of the field `name`, `module.field` as `fidelate` takes it, takes
+/// effect: the fields marked `#[online_config(skip)]` are only read at startup.
+pub fn change_kind(name: &str) -> NamespacedgResult<ChangeKind> {
+    let name = serde_to_online_config(name.to_owned());
+    let mut typed = &*EINSTEINDBCONFIG_TYPED;
+    let mut fields = name.split('.').peekable();
+    while let Some(field) = fields.next() {
+        match typed.get(field) {
+            None => break,
+            Some(ConfigValue::Skip) => return Ok(ChangeKind::RestartRequired),
+            Some(ConfigValue::Module(m)) if fields.peek().is_some() => typed = m,
+            Some(_) => return Ok(ChangeKind::Online),
+        }
+    }
+    Err(format!("unexpect fields: {}", name).into())
+}
+
+/// A field whose causet_locale differs between the running config and an incoming one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigFieldChange {
+    /// `module.field`, as in the config file.
+    pub name: String,
+    pub old_causet_locale: String,
+    pub new_causet_locale: String,
+    pub kind: ChangeKind,
+}
+
+/// The causet_locales of the fields of `config`, by their names in the config file.
+fn flatten_config(config: &EinsteinDbConfig) -> NamespacedgResult<BTreeMap<String, String>> {
+    fn helper(prefix: String, causet_locale: toml::Value, dst: &mut BTreeMap<String, String>) {
+        match causet_locale {
+            toml::Value::Table(table) => {
+                for (name, causet_locale) in table {
+                    let name = match prefix.as_str() {
+                        "" => name,
+                        _ => format!("{}.{}", prefix, name),
+                    };
+                    helper(name, causet_locale, dst);
+                }
+            }
+            v => {
+                dst.insert(prefix, v.to_string());
+            }
+        }
+    }
+    let mut dst = BTreeMap::new();
+    helper(String::new(), toml::Value::try_from(config)?, &mut dst);
+    Ok(dst)
+}
+
 fn to_config_change(change: HashMap<String, String>) -> NamespacedgResult<ConfigChange> {
     fn helper(
         mut fields: Vec<String>,
@@ -3217,9 +3304,11 @@ fn to_config_change(change: HashMap<String, String>) -> NamespacedgResult<Config
         if let Some(field) = fields.pop() {
             return match typed.get(&field) {
                 None => Err(format!("unexpect fields: {}", field).into()),
-                Some(ConfigValue::Skip) => {
-                    Err(format!("config {} can not be changed", field).into())
-                }
+                Some(ConfigValue::Skip) => Err(format!(
+                    "config {} can not be changed online, it requires a restart",
+                    field
+                )
+                .into()),
                 Some(ConfigValue::Module(m)) => {
                     if let ConfigValue::Module(n_dst) = dst
                         .entry(field)
@@ -3398,6 +3487,8 @@ pub struct ConfigController {
 struct ConfigInner {
     current: EinsteinDbConfig,
     config_mgrs: HashMap<Module, Box<dyn ConfigManager>>,
+    /// The changes of the config file that take effect after a restart.
+    restart_required: Vec<ConfigFieldChange>,
 }
 
 impl ConfigController {
@@ -3406,6 +3497,7 @@ impl ConfigController {
             inner: Arc::new(RwDagger::new(ConfigInner {
                 current,
                 config_mgrs: HashMap::new(),
+                restart_required: Vec::new(),
             })),
         }
     }
@@ -3415,17 +3507,37 @@ impl ConfigController {
         self.fidelate_impl(diff, Some(change))
     }
 
-    pub fn fidelate_from_toml_file(&self) -> NamespacedgResult<()> {
+    /// Applies the online changes of the config file to the running EinsteinDB and
+    /// keeps those that require a restart for `restart_required`. Returns every
+    /// change.
+    pub fn fidelate_from_toml_file(&self) -> NamespacedgResult<Vec<ConfigFieldChange>> {
         let current = self.get_current();
         match EinsteinDbConfig::from_file(Path::new(&current.APPEND_LOG_g_path), None) {
             Ok(incoming) => {
+                let changes = current.classify_changes(&incoming)?;
                 let diff = current.diff(&incoming);
-                self.fidelate_impl(diff, None)
+                self.fidelate_impl(diff, None)?;
+                let restart_required: Vec<_> = changes
+                    .iter()
+                    .filter(|c| c.kind == ChangeKind::RestartRequired)
+                    .cloned()
+                    .collect();
+                if !restart_required.is_empty() {
+                    warn!("config changes take effect after a restart"; "changes" => ?restart_required);
+                }
+                self.inner.write().unwrap().restart_required = restart_required;
+                Ok(changes)
             }
             Err(e) => Err(e),
         }
     }
 
+    /// The changes of the config file that the running EinsteinDB has not made, as
+    /// they require a restart.
+    pub fn restart_required(&self) -> Vec<ConfigFieldChange> {
+        self.inner.read().unwrap().restart_required.clone()
+    }
+
     fn fidelate_impl(
         &self,
         diff: HashMap<String, ConfigValue>,
@@ -3458,6 +3570,8 @@ impl ConfigController {
         }
         debug!("all config change had been dispatched"; "change" => ?to_fidelate);
         inner.current.fidelate(to_fidelate);
+        // Persist the effective config, that the next start is checked against.
+        persist_config(&inner.current)?;
         // Write change to the config file
         if let Some(change) = change {
             let content = {
@@ -4054,6 +4168,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_change_restart_required_config() {
+        let (mut APPEND_LOG_g, _dir) = EinsteinDbConfig::with_tmp().unwrap();
+        APPEND_LOG_g.foundationdb.max_background_jobs = 4;
+        APPEND_LOG_g.timelike_storage.block_cache.shared = false;
+        APPEND_LOG_g.validate().unwrap();
+        let mut incoming = APPEND_LOG_g.clone();
+        incoming.foundationdb.max_background_jobs = 8;
+        incoming.foundationdb.defaultnamespaced.write_buffer_size = ReadableSize::mb(256);
+        incoming.foundationdb.wal_dir = "/data/wal_dir".to_owned();
+
+        let changes = APPEND_LOG_g.classify_changes(&incoming).unwrap();
+        let kinds: Vec<_> = changes.iter().map(|c| (c.name.as_str(), c.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("foundationdb.defaultnamespaced.write-buffer-size", ChangeKind::Online),
+                ("foundationdb.max-background-jobs", ChangeKind::Online),
+                ("foundationdb.wal-dir", ChangeKind::RestartRequired),
+            ]
+        );
+        assert_eq!(
+            change_kind("foundationdb.max-background-jobs").unwrap(),
+            ChangeKind::Online
+        );
+        assert!(change_kind("foundationdb.no-such-field").is_err());
+
+        // Reloading the config file makes the online changes and keeps the others.
+        let data_dir = APPEND_LOG_g.timelike_storage.data_dir.clone();
+        let APPEND_LOG_g_path = APPEND_LOG_g.APPEND_LOG_g_path.clone();
+        let (einsteindb, APPEND_LOG_g_controller, ..) = new_interlocking_directorates(APPEND_LOG_g);
+        incoming.write_to_file(&APPEND_LOG_g_path).unwrap();
+        let changes = APPEND_LOG_g_controller.fidelate_from_toml_file().unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(einsteindb.get_db_options().get_max_background_jobs(), 8);
+        let namespaced_opts = einsteindb.get_options_namespaced(NAMESPACED_DEFAULT).unwrap();
+        assert_eq!(namespaced_opts.get_write_buffer_size(), ReadableSize::mb(256).0);
+        let current = APPEND_LOG_g_controller.get_current();
+        assert_eq!(current.foundationdb.max_background_jobs, 8);
+        assert_ne!(current.foundationdb.wal_dir, "/data/wal_dir");
+        let restart_required = APPEND_LOG_g_controller.restart_required();
+        assert_eq!(restart_required.len(), 1);
+        assert_eq!(restart_required[0].name, "foundationdb.wal-dir");
+
+        // The effective config is persisted, without the change that needs a restart.
+        let last = get_last_config(&data_dir).unwrap();
+        assert_eq!(last.foundationdb.max_background_jobs, 8);
+        assert_eq!(last.foundationdb.wal_dir, current.foundationdb.wal_dir);
+
+        // Changing it online is refused.
+        let err = APPEND_LOG_g_controller
+            .fidelate_config("foundationdb.wal-dir", "/data/wal_dir")
+            .unwrap_err();
+        assert!(err.to_string().contains("requires a restart"), "{}", err);
+    }
+
     #[test]
     fn test_change_rate_limiter_auto_tuned() {
         let (mut APPEND_LOG_g, _dir) = EinsteinDbConfig::with_tmp().unwrap();
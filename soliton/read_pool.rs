@@ -11,6 +11,69 @@ use yatp::pool::Remote;
 use yatp::queue::Extras;
 use yatp::task::future::TaskCell;
 
+/// How a read is served; chosen per read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// By the leader, through the log.
+    #[default]
+    Strong,
+    /// By any replica, once it applied the log up to the commit index the
+    /// leader confirmed for the read: it sees every write committed before it.
+    Follower,
+    /// By any replica, from what it applied, if the brane is closed at the
+    /// timestamp: no write at or before it is applied later.
+    Stale(u64),
+}
+
+/// The timestamps the branes of the store the pool reads from are closed at.
+pub trait ClosedTs {
+    /// The timestamp `brane_id` is closed at here; 0 if it is not closed.
+    fn closed_ts(&self, brane_id: u64) -> u64;
+}
+
+/// What the replica of a brane here does to serve a read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadRoute {
+    /// Proposes the read through the log, as the leader.
+    Leader,
+    /// Asks the leader for its commit index by read-index, waits to apply the
+    /// log up to it, then reads what it applied.
+    ReadIndex,
+    /// Reads what it applied at once.
+    Local,
+}
+
+/// A read to schedule on the pool.
+#[derive(Clone, Copy, Debug)]
+pub struct ReadRequest {
+    pub brane_id: u64,
+    pub consistency: ReadConsistency,
+    pub priority: CommandPri,
+    pub task_id: u64,
+}
+
+/// How the replica of `brane_id` here, the leader if `is_leader`, serves a read
+/// of `consistency`.
+pub fn route_read<C: ClosedTs + ?Sized>(
+    brane_id: u64,
+    consistency: ReadConsistency,
+    is_leader: bool,
+    closed: &C,
+) -> Result<ReadRoute, ReadPoolError> {
+    match consistency {
+        ReadConsistency::Strong if is_leader => Ok(ReadRoute::Leader),
+        ReadConsistency::Strong => Err(ReadPoolError::NotLeader(brane_id)),
+        ReadConsistency::Follower => Ok(ReadRoute::ReadIndex),
+        ReadConsistency::Stale(ts) => match closed.closed_ts(brane_id) {
+            closed_ts if closed_ts < ts => Err(ReadPoolError::DataNotReady {
+                brane_id,
+                closed_ts,
+            }),
+            _ => Ok(ReadRoute::Local),
+        },
+    }
+}
+
 
 /// A read pool.
 /// This is a wrapper around a yatp pool.
@@ -193,6 +256,28 @@ impl ReadPoolHandle {
         }
     }
 
+    /// Spawns `req` on the pool if the replica of its brane here, the leader
+    /// if `is_leader`, can serve it; `f` is given how it serves it and makes the
+    /// read. A stale read of a brane not closed at its timestamp fails without
+    /// being spawned.
+    pub fn spawn_read<C, F, Fut, T>(
+        &self,
+        req: ReadRequest,
+        is_leader: bool,
+        closed: &C,
+        f: F,
+    ) -> impl Future<Output = Result<T, ReadPoolError>>
+    where
+        C: ClosedTs + ?Sized,
+        F: FnOnce(ReadRoute) -> Fut,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let read = route_read(req.brane_id, req.consistency, is_leader, closed)
+            .map(|route| self.spawn_handle(f(route), req.priority, req.task_id));
+        async move { read?.await }
+    }
+
     pub fn get_normal_pool_size(&self) -> usize {
         match self {
             ReadPoolHandle::FuturePools {
@@ -290,6 +375,12 @@ pub enum ReadPoolError {
 
     #[error("{0}")]
     Canceled(#[from] oneshot::Canceled),
+
+    #[error("brane {0} is not led here")]
+    NotLeader(u64),
+
+    #[error("brane {brane_id} is closed up to {closed_ts} only")]
+    DataNotReady { brane_id: u64, closed_ts: u64 },
 }
 
 mod metrics {
@@ -305,6 +396,60 @@ mod metrics {
     }
 }
 
+#[cfg(test)]
+mod read_consistency_tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    impl ClosedTs for HashMap<u64, u64> {
+        fn closed_ts(&self, brane_id: u64) -> u64 {
+            self.get(&brane_id).copied().unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_route_read() {
+        let closed: HashMap<u64, u64> = [(1, 10)].into_iter().collect();
+        let route = |brane_id, consistency, is_leader| {
+            route_read(brane_id, consistency, is_leader, &closed)
+        };
+
+        assert_eq!(
+            route(1, ReadConsistency::Strong, true).unwrap(),
+            ReadRoute::Leader
+        );
+        match route(1, ReadConsistency::Strong, false) {
+            Err(ReadPoolError::NotLeader(1)) => {}
+            r => panic!("{:?}", r),
+        }
+        for is_leader in [true, false] {
+            assert_eq!(
+                route(1, ReadConsistency::Follower, is_leader).unwrap(),
+                ReadRoute::ReadIndex
+            );
+            assert_eq!(
+                route(1, ReadConsistency::Stale(10), is_leader).unwrap(),
+                ReadRoute::Local
+            );
+        }
+        match route(1, ReadConsistency::Stale(11), false) {
+            Err(ReadPoolError::DataNotReady {
+                brane_id: 1,
+                closed_ts: 10,
+            }) => {}
+            r => panic!("{:?}", r),
+        }
+        // Not closed here at all.
+        match route(2, ReadConsistency::Stale(1), false) {
+            Err(ReadPoolError::DataNotReady {
+                brane_id: 2,
+                closed_ts: 0,
+            }) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+}
+
 /*
     #[test]
     fn test_yatp_full() {
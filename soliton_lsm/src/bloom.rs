@@ -0,0 +1,91 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Bloom filters over the user soliton_ids of an SST, using double hashing to derive
+//! the probe positions from one 32-bit hash.
+
+/// Hashes `data` (murmur-like, the same function must be used to build and probe).
+pub fn bloom_hash(data: &[u8]) -> u32 {
+    const SEED: u32 = 0xbc9f_1d34;
+    const M: u32 = 0xc6a4_a793;
+    let mut h = SEED ^ (data.len() as u32).wrapping_mul(M);
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        h = h.wrapping_add(u32::from_le_bytes(chunk.try_into().unwrap()));
+        h = h.wrapping_mul(M);
+        h ^= h >> 16;
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        for (i, b) in rest.iter().enumerate() {
+            h = h.wrapping_add(u32::from(*b) << (8 * i));
+        }
+        h = h.wrapping_mul(M);
+        h ^= h >> 24;
+    }
+    h
+}
+
+/// Builds the filter for the hashes of the soliton_ids of one SST.
+///
+/// The last byte of the filter is the number of probes.
+pub fn build_filter(hashes: &[u32], bits_per_key: usize) -> Vec<u8> {
+    // 0.69 =~ ln(2) minimizes the false positive rate.
+    let probes = ((bits_per_key as f64 * 0.69) as usize).clamp(1, 30);
+    let bits = (hashes.len() * bits_per_key).max(64);
+    let bytes = bits.div_ceil(8);
+    let bits = bytes * 8;
+    let mut filter = vec![0u8; bytes + 1];
+    for &h in hashes {
+        let mut h = h;
+        let delta = h.rotate_right(17);
+        for _ in 0..probes {
+            let pos = h as usize % bits;
+            filter[pos / 8] |= 1 << (pos % 8);
+            h = h.wrapping_add(delta);
+        }
+    }
+    filter[bytes] = probes as u8;
+    filter
+}
+
+/// Returns false if the soliton_id with `hash` is definitely not in the filter.
+pub fn may_contain(filter: &[u8], hash: u32) -> bool {
+    if filter.len() < 2 {
+        return true;
+    }
+    let bytes = filter.len() - 1;
+    let bits = bytes * 8;
+    let probes = filter[bytes];
+    let mut h = hash;
+    let delta = h.rotate_right(17);
+    for _ in 0..probes {
+        let pos = h as usize % bits;
+        if filter[pos / 8] & (1 << (pos % 8)) == 0 {
+            return false;
+        }
+        h = h.wrapping_add(delta);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter() {
+        let keys: Vec<Vec<u8>> = (0..10_000u32)
+            .map(|i| format!("soliton_id{}", i).into_bytes())
+            .collect();
+        let hashes: Vec<u32> = keys.iter().map(|k| bloom_hash(k)).collect();
+        let filter = build_filter(&hashes, 10);
+        assert!(hashes.iter().all(|&h| may_contain(&filter, h)));
+
+        let false_positives = (10_000..20_000u32)
+            .filter(|i| may_contain(&filter, bloom_hash(format!("soliton_id{}", i).as_bytes())))
+            .count();
+        // ~1% with 10 bits per soliton_id.
+        assert!(false_positives < 300, "{} false positives", false_positives);
+        assert!(may_contain(&[], 1));
+    }
+}
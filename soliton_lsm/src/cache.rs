@@ -0,0 +1,394 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The block cache: a sharded LRU cache of decoded data blocks, with one memory
+//! budget for every table reading through it.
+//!
+//! Each shard splits its LRU list in two pools. Blocks of high priority and blocks
+//! hit since they were inserted go to the high priority pool; once it holds more
+//! than `high_pri_pool_ratio` of the shard, its oldest blocks move to the low
+//! priority pool, and blocks are evicted from the oldest of the low priority pool
+//! first. The index and filter blocks of open tables are charged to the high
+//! priority pool and never evicted.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::codec::InternalKey;
+
+/// The decoded entries of a data block.
+pub type Block = Arc<Vec<(InternalKey, Vec<u8>)>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Priority {
+    High,
+    Low,
+}
+
+/// Identifies a block: the cache id of its table and its offset in the file.
+type CacheKey = (u64, u64);
+
+struct Entry {
+    block: Block,
+    charge: usize,
+    priority: Priority,
+    hit: bool,
+    tick: u64,
+    in_high_pool: bool,
+}
+
+#[derive(Default)]
+struct LruShard {
+    capacity: usize,
+    high_pri_capacity: usize,
+    /// Charges of the cached blocks and reservations.
+    usage: usize,
+    /// Charges of the blocks of the high priority pool and reservations.
+    high_pri_usage: usize,
+    reserved: usize,
+    entries: HashMap<CacheKey, Entry>,
+    /// The blocks of each pool, by their last use.
+    high_pool: BTreeMap<u64, CacheKey>,
+    low_pool: BTreeMap<u64, CacheKey>,
+    next_tick: u64,
+}
+
+impl LruShard {
+    fn set_capacity(&mut self, capacity: usize, high_pri_pool_ratio: f64) {
+        self.capacity = capacity;
+        self.high_pri_capacity = (capacity as f64 * high_pri_pool_ratio) as usize;
+        self.maintain_pools();
+    }
+
+    /// Makes `key` the newest block of the pool it belongs to.
+    fn push(&mut self, key: CacheKey) {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        let high_pri_capacity = self.high_pri_capacity;
+        let e = self.entries.get_mut(&key).unwrap();
+        e.tick = tick;
+        e.in_high_pool = high_pri_capacity > 0 && (e.priority == Priority::High || e.hit);
+        if e.in_high_pool {
+            self.high_pri_usage += e.charge;
+            self.high_pool.insert(tick, key);
+        } else {
+            self.low_pool.insert(tick, key);
+        }
+    }
+
+    fn unlink(&mut self, key: CacheKey) {
+        let e = &self.entries[&key];
+        if e.in_high_pool {
+            self.high_pri_usage -= e.charge;
+            self.high_pool.remove(&e.tick);
+        } else {
+            self.low_pool.remove(&e.tick);
+        }
+    }
+
+    /// Moves the oldest blocks of the high priority pool to the low priority one
+    /// until it fits, then evicts until the shard fits.
+    fn maintain_pools(&mut self) {
+        while self.high_pri_usage > self.high_pri_capacity {
+            let key = match self.high_pool.pop_first() {
+                Some((_, key)) => key,
+                None => break,
+            };
+            let e = self.entries.get_mut(&key).unwrap();
+            e.in_high_pool = false;
+            self.high_pri_usage -= e.charge;
+            let tick = self.next_tick;
+            self.next_tick += 1;
+            e.tick = tick;
+            self.low_pool.insert(tick, key);
+        }
+        while self.usage > self.capacity {
+            let key = match self.low_pool.first_key_value() {
+                Some((_, &key)) => key,
+                None => match self.high_pool.first_key_value() {
+                    Some((_, &key)) => key,
+                    None => break,
+                },
+            };
+            self.erase(key);
+        }
+    }
+
+    fn erase(&mut self, key: CacheKey) {
+        if self.entries.contains_key(&key) {
+            self.unlink(key);
+            let e = self.entries.remove(&key).unwrap();
+            self.usage -= e.charge;
+        }
+    }
+
+    fn lookup(&mut self, key: CacheKey) -> Option<Block> {
+        let block = {
+            let e = self.entries.get_mut(&key)?;
+            e.hit = true;
+            e.block.clone()
+        };
+        self.unlink(key);
+        self.push(key);
+        self.maintain_pools();
+        Some(block)
+    }
+
+    fn insert(&mut self, key: CacheKey, block: Block, charge: usize, priority: Priority) {
+        self.erase(key);
+        self.entries.insert(
+            key,
+            Entry {
+                block,
+                charge,
+                priority,
+                hit: false,
+                tick: 0,
+                in_high_pool: false,
+            },
+        );
+        self.usage += charge;
+        self.push(key);
+        self.maintain_pools();
+    }
+
+    fn reserve(&mut self, charge: usize) {
+        self.usage += charge;
+        self.high_pri_usage += charge;
+        self.reserved += charge;
+        self.maintain_pools();
+    }
+
+    fn release(&mut self, charge: usize) {
+        self.usage -= charge;
+        self.high_pri_usage -= charge;
+        self.reserved -= charge;
+    }
+}
+
+struct CacheCore {
+    shards: Vec<Mutex<LruShard>>,
+    shard_bits: u32,
+    high_pri_pool_ratio: f64,
+    capacity: AtomicU64,
+    next_id: AtomicU64,
+}
+
+/// A block cache. Clones share the same cache, so that the causet_merge families of
+/// an einstein_merkle_tree, or several einstein_merkle_trees, share one budget.
+#[derive(Clone)]
+pub struct BlockCache {
+    core: Arc<CacheCore>,
+}
+
+impl BlockCache {
+    /// A cache of `capacity` bytes split into `2^num_shard_bits` shards.
+    pub fn new(capacity: usize, num_shard_bits: u32, high_pri_pool_ratio: f64) -> BlockCache {
+        let shards = (0..1usize << num_shard_bits)
+            .map(|_| Mutex::new(LruShard::default()))
+            .collect();
+        let cache = BlockCache {
+            core: Arc::new(CacheCore {
+                shards,
+                shard_bits: num_shard_bits,
+                high_pri_pool_ratio: high_pri_pool_ratio.clamp(0.0, 1.0),
+                capacity: AtomicU64::new(0),
+                next_id: AtomicU64::new(1),
+            }),
+        };
+        cache.set_capacity(capacity);
+        cache
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.core.capacity.load(Ordering::Relaxed) as usize
+    }
+
+    /// Changes the capacity, evicting blocks if the cache shrinks.
+    pub fn set_capacity(&self, capacity: usize) {
+        self.core.capacity.store(capacity as u64, Ordering::Relaxed);
+        let per_shard = capacity.div_ceil(self.core.shards.len());
+        for shard in &self.core.shards {
+            shard
+                .lock()
+                .unwrap()
+                .set_capacity(per_shard, self.core.high_pri_pool_ratio);
+        }
+    }
+
+    /// The charges of the cached blocks and of the index and filter blocks of open
+    /// tables.
+    pub fn usage(&self) -> usize {
+        self.core
+            .shards
+            .iter()
+            .map(|s| s.lock().unwrap().usage)
+            .sum()
+    }
+
+    /// The charges of the index and filter blocks of open tables.
+    pub fn pinned_usage(&self) -> usize {
+        self.core
+            .shards
+            .iter()
+            .map(|s| s.lock().unwrap().reserved)
+            .sum()
+    }
+
+    /// A new cache id, for the blocks of a table.
+    pub(crate) fn new_id(&self) -> u64 {
+        self.core.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn shard(&self, id: u64, offset: u64) -> MutexGuard<'_, LruShard> {
+        let hash = (id ^ offset.rotate_left(32)).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let idx = hash.checked_shr(64 - self.core.shard_bits).unwrap_or(0);
+        self.core.shards[idx as usize].lock().unwrap()
+    }
+
+    pub(crate) fn lookup(&self, id: u64, offset: u64) -> Option<Block> {
+        self.shard(id, offset).lookup((id, offset))
+    }
+
+    pub(crate) fn insert(
+        &self,
+        id: u64,
+        offset: u64,
+        block: Block,
+        charge: usize,
+        priority: Priority,
+    ) {
+        self.shard(id, offset)
+            .insert((id, offset), block, charge, priority);
+    }
+
+    /// Charges `charge` bytes to the high priority pool of the shard of `id` until
+    /// the returned reservation is dropped.
+    pub(crate) fn reserve(&self, id: u64, charge: usize) -> CacheReservation {
+        self.shard(id, 0).reserve(charge);
+        CacheReservation {
+            cache: self.clone(),
+            id,
+            charge,
+        }
+    }
+}
+
+impl fmt::Debug for BlockCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockCache")
+            .field("capacity", &self.capacity())
+            .field("shards", &self.core.shards.len())
+            .field("high_pri_pool_ratio", &self.core.high_pri_pool_ratio)
+            .finish()
+    }
+}
+
+pub struct CacheReservation {
+    cache: BlockCache,
+    id: u64,
+    charge: usize,
+}
+
+impl Drop for CacheReservation {
+    fn drop(&mut self) {
+        self.cache.shard(self.id, 0).release(self.charge);
+    }
+}
+
+/// Block cache hits and misses of the tables of a causet_merge family.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct BlockCacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl BlockCacheCounters {
+    pub fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> BlockCacheStats {
+        BlockCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// What a table reads its blocks through: the block cache and the counters of its
+/// causet_merge family.
+#[derive(Clone)]
+pub struct TableCache {
+    pub cache: BlockCache,
+    pub counters: Arc<BlockCacheCounters>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(n: usize) -> Block {
+        Arc::new(vec![(InternalKey::lookup(&n.to_be_bytes(), 0), Vec::new())])
+    }
+
+    #[test]
+    fn test_lru_eviction_and_priorities() {
+        let cache = BlockCache::new(100, 0, 0.5);
+        for i in 0..10 {
+            cache.insert(1, i, block(i as usize), 10, Priority::Low);
+        }
+        assert_eq!(cache.usage(), 100);
+        // Hitting a block moves it to the high priority pool.
+        assert!(cache.lookup(1, 0).is_some());
+        cache.insert(1, 10, block(10), 10, Priority::Low);
+        assert!(cache.lookup(1, 0).is_some());
+        assert!(cache.lookup(1, 1).is_none());
+        assert_eq!(cache.usage(), 100);
+
+        // High priority blocks beyond the pool are demoted, not evicted first.
+        for i in 20..26 {
+            cache.insert(1, i, block(i as usize), 10, Priority::High);
+        }
+        assert_eq!(cache.usage(), 100);
+        for i in 21..26 {
+            assert!(cache.lookup(1, i).is_some(), "{}", i);
+        }
+        assert!(cache.lookup(1, 2).is_none());
+
+        // Reservations count against the budget and are never evicted.
+        let reservation = cache.reserve(2, 60);
+        assert_eq!(cache.pinned_usage(), 60);
+        assert_eq!(cache.usage(), 100);
+        drop(reservation);
+        assert_eq!(cache.pinned_usage(), 0);
+        assert_eq!(cache.usage(), 40);
+
+        cache.set_capacity(20);
+        assert_eq!(cache.usage(), 20);
+        assert_eq!(cache.capacity(), 20);
+    }
+
+    #[test]
+    fn test_shards() {
+        let cache = BlockCache::new(1 << 20, 4, 0.5);
+        let (a, b) = (cache.new_id(), cache.new_id());
+        assert_ne!(a, b);
+        for i in 0..1000 {
+            cache.insert(a, i * 4096, block(i as usize), 100, Priority::Low);
+        }
+        assert_eq!(cache.usage(), 100_000);
+        for i in 0..1000 {
+            assert_eq!(cache.lookup(a, i * 4096).unwrap(), block(i as usize));
+            assert!(cache.lookup(b, i * 4096).is_none());
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Encoding helpers shared by the write-ahead log, the memtable and SST files.
+
+use std::cmp::Ordering;
+
+use fdb_traits::{Error, Result};
+
+/// The kind of a versioned entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum ValueKind {
+    Delete = 0,
+    Put = 1,
+}
+
+impl ValueKind {
+    pub fn from_u8(b: u8) -> Result<ValueKind> {
+        match b {
+            0 => Ok(ValueKind::Delete),
+            1 => Ok(ValueKind::Put),
+            b => Err(Error::Corruption(format!(
+                "unknown causet_locale kind {}",
+                b
+            ))),
+        }
+    }
+}
+
+/// Sequence numbers take the upper 56 bits of the trailer of an encoded internal
+/// soliton_id.
+pub const MAX_SEQUENCE: u64 = (1 << 56) - 1;
+
+/// A user soliton_id tagged with the sequence number and kind of the write.
+///
+/// Internal soliton_ids sort by user soliton_id ascending, then by sequence number
+/// descending, so that the newest version of a soliton_id is met first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InternalKey {
+    pub user_key: Vec<u8>,
+    pub seq: u64,
+    pub kind: ValueKind,
+}
+
+impl InternalKey {
+    pub fn new(user_key: &[u8], seq: u64, kind: ValueKind) -> InternalKey {
+        InternalKey {
+            user_key: user_key.to_vec(),
+            seq,
+            kind,
+        }
+    }
+
+    /// The smallest internal soliton_id for `user_key` visible at `seq`.
+    pub fn lookup(user_key: &[u8], seq: u64) -> InternalKey {
+        InternalKey::new(user_key, seq, ValueKind::Put)
+    }
+
+    /// An internal soliton_id that sorts before every version of `user_key`. Tables
+    /// use it as the bound of a range tombstone, see `FileMeta::ends_before`.
+    pub fn range_bound(user_key: &[u8]) -> InternalKey {
+        InternalKey::new(user_key, MAX_SEQUENCE, ValueKind::Delete)
+    }
+
+    /// An internal soliton_id that sorts after every version of `user_key`.
+    pub fn last_bound(user_key: &[u8]) -> InternalKey {
+        InternalKey::new(user_key, 0, ValueKind::Delete)
+    }
+
+    pub fn is_range_bound(&self) -> bool {
+        self.seq == MAX_SEQUENCE
+    }
+
+    fn trailer(&self) -> u64 {
+        (self.seq << 8) | self.kind as u64
+    }
+
+    pub fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_length_prefixed(buf, &self.user_key);
+        buf.extend_from_slice(&self.trailer().to_le_bytes());
+    }
+
+    pub fn decode_from(buf: &mut &[u8]) -> Result<InternalKey> {
+        let user_key = get_length_prefixed(buf)?.to_vec();
+        let trailer = get_fixed_u64(buf)?;
+        Ok(InternalKey {
+            user_key,
+            seq: trailer >> 8,
+            kind: ValueKind::from_u8(trailer as u8)?,
+        })
+    }
+}
+
+impl Ord for InternalKey {
+    fn cmp(&self, other: &InternalKey) -> Ordering {
+        self.user_key
+            .cmp(&other.user_key)
+            .then_with(|| other.seq.cmp(&self.seq))
+            .then_with(|| other.kind.cmp(&self.kind))
+    }
+}
+
+impl PartialOrd for InternalKey {
+    fn partial_cmp(&self, other: &InternalKey) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+pub fn get_varint(buf: &mut &[u8]) -> Result<u64> {
+    let mut v = 0u64;
+    for (i, b) in buf.iter().enumerate().take(10) {
+        v |= u64::from(b & 0x7f) << (7 * i);
+        if b & 0x80 == 0 {
+            *buf = &buf[i + 1..];
+            return Ok(v);
+        }
+    }
+    Err(Error::Corruption("bad varint".to_owned()))
+}
+
+pub fn put_length_prefixed(buf: &mut Vec<u8>, data: &[u8]) {
+    put_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+pub fn get_length_prefixed<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len = get_varint(buf)? as usize;
+    if buf.len() < len {
+        return Err(Error::Corruption(
+            "truncated length prefixed slice".to_owned(),
+        ));
+    }
+    let (data, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(data)
+}
+
+pub fn get_fixed_u64(buf: &mut &[u8]) -> Result<u64> {
+    if buf.len() < 8 {
+        return Err(Error::Corruption("truncated u64".to_owned()));
+    }
+    let (data, rest) = buf.split_at(8);
+    *buf = rest;
+    Ok(u64::from_le_bytes(data.try_into().unwrap()))
+}
+
+pub fn get_fixed_u32(buf: &mut &[u8]) -> Result<u32> {
+    if buf.len() < 4 {
+        return Err(Error::Corruption("truncated u32".to_owned()));
+    }
+    let (data, rest) = buf.split_at(4);
+    *buf = rest;
+    Ok(u32::from_le_bytes(data.try_into().unwrap()))
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    crc32fast::hash(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_internal_key_order_and_codec() {
+        let mut keys = vec![
+            InternalKey::new(b"b", 1, ValueKind::Put),
+            InternalKey::new(b"a", 1, ValueKind::Put),
+            InternalKey::new(b"a", 3, ValueKind::Delete),
+            InternalKey::new(b"a", 2, ValueKind::Put),
+        ];
+        keys.sort();
+        let seqs: Vec<_> = keys.iter().map(|k| (k.user_key.clone(), k.seq)).collect();
+        assert_eq!(
+            seqs,
+            vec![
+                (b"a".to_vec(), 3),
+                (b"a".to_vec(), 2),
+                (b"a".to_vec(), 1),
+                (b"b".to_vec(), 1)
+            ]
+        );
+
+        let mut buf = vec![];
+        for k in &keys {
+            k.encode_to(&mut buf);
+        }
+        let mut slice = buf.as_slice();
+        for k in &keys {
+            assert_eq!(&InternalKey::decode_from(&mut slice).unwrap(), k);
+        }
+        assert!(slice.is_empty());
+
+        let mut buf = vec![];
+        put_varint(&mut buf, u64::MAX);
+        assert_eq!(get_varint(&mut buf.as_slice()).unwrap(), u64::MAX);
+        assert!(get_varint(&mut &buf[..3]).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Leveled compaction.
+//!
+//! Level 0 is compacted into level 1 once it holds
+//! `level0_file_num_compaction_trigger` files; a deeper level is compacted into the
+//! next one once it grows past its target size, one file at a time in a round-robin
+//! over the soliton_id space.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use fdb_traits::{CompactionFilter, CompactionFilterDecision, CompactionFilterStats, Result};
+
+use crate::cache::TableCache;
+use crate::codec::{InternalKey, ValueKind};
+use crate::iterator::{InternalIterator, MergingIterator};
+use crate::options::LsmOptions;
+use crate::range_del::{FragmentedRangeTombstones, RangeTombstone};
+use crate::sst::{TableBuilder, TableIterator};
+use crate::version::{FileMeta, Version};
+
+pub struct Compaction {
+    /// The input level; outputs are written to `level + 1`.
+    pub level: usize,
+    pub inputs: Vec<Arc<FileMeta>>,
+    /// The overlapping files of the output level.
+    pub next_inputs: Vec<Arc<FileMeta>>,
+}
+
+impl Compaction {
+    /// Builds a compaction of `inputs` from `level` together with every file of the
+    /// next level they overlap.
+    pub fn new(version: &Version, level: usize, inputs: Vec<Arc<FileMeta>>) -> Compaction {
+        let (start, end) = user_key_range(&inputs);
+        let next_inputs = version.overlapping_files(level + 1, &start, &end);
+        Compaction {
+            level,
+            inputs,
+            next_inputs,
+        }
+    }
+
+    pub fn all_inputs(&self) -> impl Iterator<Item = &Arc<FileMeta>> {
+        self.inputs.iter().chain(self.next_inputs.iter())
+    }
+
+    /// Whether no level below the output level holds soliton_ids of the compacted range.
+    pub fn is_bottommost_level(&self, version: &Version) -> bool {
+        let inputs: Vec<_> = self.all_inputs().cloned().collect();
+        let (start, end) = user_key_range(&inputs);
+        (self.level + 2..version.levels.len())
+            .all(|l| version.overlapping_files(l, &start, &end).is_empty())
+    }
+
+    /// A compaction of a single file with nothing to merge with just moves the file.
+    pub fn is_trivial_move(&self) -> bool {
+        self.inputs.len() == 1 && self.next_inputs.is_empty()
+    }
+}
+
+fn user_key_range(files: &[Arc<FileMeta>]) -> (Vec<u8>, Vec<u8>) {
+    let start = files
+        .iter()
+        .map(|f| &f.smallest.user_key)
+        .min()
+        .unwrap()
+        .clone();
+    let end = files
+        .iter()
+        .map(|f| &f.largest.user_key)
+        .max()
+        .unwrap()
+        .clone();
+    (start, end)
+}
+
+/// Picks the level most in need of compaction. `compact_pointer` holds, for each
+/// level, the largest user soliton_id of the last file compacted out of it.
+pub fn pick_compaction(
+    version: &Version,
+    opts: &LsmOptions,
+    compact_pointer: &mut [Vec<u8>],
+) -> Option<Compaction> {
+    let mut best: Option<(usize, f64)> = None;
+    for level in 0..version.levels.len() - 1 {
+        let score = if level == 0 {
+            version.levels[0].len() as f64 / opts.level0_file_num_compaction_trigger.max(1) as f64
+        } else {
+            version.level_size(level) as f64 / opts.max_bytes_for_level(level) as f64
+        };
+        if score >= 1.0 && best.is_none_or(|(_, s)| score > s) {
+            best = Some((level, score));
+        }
+    }
+    let (level, _) = best?;
+
+    let inputs = if level == 0 {
+        // Level 0 files overlap each other, take all of them.
+        version.levels[0].clone()
+    } else {
+        let files = &version.levels[level];
+        let pointer = &compact_pointer[level];
+        let file = files
+            .iter()
+            .find(|f| f.smallest.user_key.as_slice() > pointer.as_slice())
+            .unwrap_or(&files[0]);
+        compact_pointer[level] = file.largest.user_key.clone();
+        vec![file.clone()]
+    };
+    Some(Compaction::new(version, level, inputs))
+}
+
+/// Merges the inputs of `c` into new files of the output level.
+///
+/// The sorted sequence numbers of the live `snapshots` cut the sequence numbers into
+/// stripes: a version is visible to the first snapshot at or after it, and to no
+/// snapshot in the last stripe. Versions shadowed by a newer version of the same
+/// soliton_id or by a newer range tombstone of the same stripe are dropped, and so
+/// are deletions and range tombstones of the first stripe once no deeper level can
+/// hold an older version of their soliton_ids. Outputs are split at
+/// `target_file_size_base`, but never between two versions of the same user
+/// soliton_id nor inside a range tombstone, so that files of the output level stay
+/// disjoint.
+///
+/// The newest version of each live soliton_id is passed to `filter` unless a snapshot
+/// sees it; a removed soliton_id is written as a deletion, so that it keeps hiding
+/// older versions in deeper levels.
+#[allow(clippy::too_many_arguments)]
+pub fn run_compaction(
+    c: &Compaction,
+    version: &Version,
+    snapshots: &[u64],
+    opts: &LsmOptions,
+    dir: &Path,
+    cache: Option<&TableCache>,
+    new_table: &mut dyn FnMut() -> Result<(u64, TableBuilder)>,
+    mut filter: Option<Box<dyn CompactionFilter>>,
+    filter_stats: &mut CompactionFilterStats,
+) -> Result<Vec<FileMeta>> {
+    let output_level = c.level + 1;
+    let children = c
+        .all_inputs()
+        .map(|f| Box::new(TableIterator::new(f.table.clone(), false)) as Box<dyn InternalIterator>)
+        .collect();
+    let mut iter = MergingIterator::new(children);
+    iter.seek_to_first()?;
+    let stripe = |seq: u64| snapshots.partition_point(|&s| s < seq);
+    // The newest sequence number of the stripe of `seq`.
+    let stripe_top = |seq: u64| snapshots.get(stripe(seq)).copied().unwrap_or(u64::MAX);
+    let range_dels =
+        FragmentedRangeTombstones::new(c.all_inputs().flat_map(|f| f.table.range_tombstones()));
+    // Only the newest tombstone of each stripe of a fragment matters. The fragments
+    // are disjoint and sorted; the ones before `next_tombstone` have been written out.
+    let mut tombstones = Vec::new();
+    for f in range_dels.fragments() {
+        let mut last_stripe = None;
+        for &seq in &f.seqs {
+            let s = stripe(seq);
+            if last_stripe == Some(s)
+                || (s == 0 && is_base_level_for_range(version, output_level, &f.start, &f.end))
+            {
+                continue;
+            }
+            last_stripe = Some(s);
+            tombstones.push(RangeTombstone::new(&f.start, &f.end, seq));
+        }
+    }
+    let mut next_tombstone = 0;
+
+    let mut outputs = Vec::new();
+    let mut builder: Option<(u64, TableBuilder)> = None;
+    let mut current_user_key: Option<Vec<u8>> = None;
+    // The stripe of the last version seen of `current_user_key`.
+    let mut last_stripe = None;
+    while iter.valid() {
+        let mut soliton_id = iter.soliton_id().clone();
+        let mut causet_locale = iter.causet_locale().to_vec();
+        let first_version = current_user_key.as_deref() != Some(soliton_id.user_key.as_slice());
+        let seq_stripe = stripe(soliton_id.seq);
+        let shadowed = !first_version && last_stripe == Some(seq_stripe);
+        last_stripe = Some(seq_stripe);
+        let covered = range_dels.max_covering_seq(&soliton_id.user_key, stripe_top(soliton_id.seq))
+            > soliton_id.seq;
+        if first_version {
+            if let Some((number, mut b)) = builder.take() {
+                let user_key = soliton_id.user_key.as_slice();
+                let before = tombstones[next_tombstone..]
+                    .iter()
+                    .take_while(|t| t.start.as_slice() < user_key)
+                    .count();
+                let spanning =
+                    before > 0 && tombstones[next_tombstone + before - 1].end.as_slice() > user_key;
+                if b.file_size() >= opts.target_file_size_base && !spanning {
+                    for t in &tombstones[next_tombstone..next_tombstone + before] {
+                        b.add_range_tombstone(t);
+                    }
+                    next_tombstone += before;
+                    b.finish()?;
+                    outputs.push(FileMeta::open(dir, number, cache)?);
+                } else {
+                    builder = Some((number, b));
+                }
+            }
+            current_user_key = Some(soliton_id.user_key.clone());
+        }
+        if first_version && !covered && seq_stripe == snapshots.len() {
+            if let (Some(filter), ValueKind::Put) = (filter.as_mut(), soliton_id.kind) {
+                filter_stats.keys_filtered += 1;
+                match filter.filter(c.level, &soliton_id.user_key, &causet_locale) {
+                    CompactionFilterDecision::Keep => {}
+                    CompactionFilterDecision::Remove => {
+                        filter_stats.keys_removed += 1;
+                        soliton_id.kind = ValueKind::Delete;
+                        causet_locale.clear();
+                    }
+                    CompactionFilterDecision::ChangeValue(v) => {
+                        filter_stats.keys_changed += 1;
+                        causet_locale = v;
+                    }
+                }
+            }
+        }
+        let drop = shadowed
+            || covered
+            || (soliton_id.kind == ValueKind::Delete
+                && seq_stripe == 0
+                && is_base_level_for_key(version, output_level, &soliton_id));
+        if !drop {
+            if builder.is_none() {
+                builder = Some(new_table()?);
+            }
+            builder
+                .as_mut()
+                .unwrap()
+                .1
+                .add(&soliton_id, &causet_locale)?;
+        }
+        iter.next()?;
+    }
+    if builder.is_none() && next_tombstone < tombstones.len() {
+        builder = Some(new_table()?);
+    }
+    if let Some((number, mut b)) = builder {
+        for t in &tombstones[next_tombstone..] {
+            b.add_range_tombstone(t);
+        }
+        b.finish()?;
+        outputs.push(FileMeta::open(dir, number, cache)?);
+    }
+    Ok(outputs)
+}
+
+/// Whether no level below `output_level` may contain `soliton_id`'s user soliton_id.
+fn is_base_level_for_key(version: &Version, output_level: usize, soliton_id: &InternalKey) -> bool {
+    let user_key = soliton_id.user_key.as_slice();
+    version.levels[output_level + 1..]
+        .iter()
+        .flatten()
+        .all(|f| !f.overlaps(user_key, user_key))
+}
+
+/// Whether no level below `output_level` may contain a user soliton_id of `[start, end)`.
+fn is_base_level_for_range(
+    version: &Version,
+    output_level: usize,
+    start: &[u8],
+    end: &[u8],
+) -> bool {
+    version.levels[output_level + 1..]
+        .iter()
+        .flatten()
+        .all(|f| !f.overlaps_range(start, end))
+}
@@ -0,0 +1,344 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Block compression.
+//!
+//! Snappy blocks use the raw snappy format. LZ4 blocks use the LZ4 block format,
+//! prefixed with the uncompressed length as a varint. Both compressors are plain
+//! greedy matchers over a hash table of 4-byte sequences.
+
+use fdb_traits::{Compression, Error, Result};
+
+use crate::codec::{get_varint, put_varint};
+
+const HASH_BITS: u32 = 14;
+const MAX_OFFSET: usize = u16::MAX as usize;
+
+/// Identifies the compression of a block in its trailer.
+pub fn compression_id(compression: Compression) -> u8 {
+    match compression {
+        Compression::None => 0,
+        Compression::Snappy => 1,
+        Compression::Lz4 => 2,
+        Compression::Zlib => 3,
+        Compression::Zstd => 4,
+    }
+}
+
+pub fn check_supported(compression: Compression) -> Result<()> {
+    match compression {
+        Compression::None | Compression::Snappy | Compression::Lz4 => Ok(()),
+        Compression::Zlib | Compression::Zstd => Err(Error::Engine(format!(
+            "{:?} compression is not supported",
+            compression
+        ))),
+    }
+}
+
+/// Compresses `data`, or returns `None` if that would not make it smaller.
+pub fn compress(compression: Compression, data: &[u8]) -> Option<Vec<u8>> {
+    let compressed = match compression {
+        Compression::Snappy => snappy_compress(data),
+        Compression::Lz4 => lz4_compress(data),
+        _ => return None,
+    };
+    if compressed.len() < data.len() {
+        Some(compressed)
+    } else {
+        None
+    }
+}
+
+/// Decompresses a block compressed with the compression `id`.
+pub fn decompress(id: u8, data: &[u8]) -> Result<Vec<u8>> {
+    match id {
+        0 => Ok(data.to_vec()),
+        1 => snappy_decompress(data),
+        2 => lz4_decompress(data),
+        _ => Err(Error::Corruption(format!(
+            "unknown block compression {}",
+            id
+        ))),
+    }
+}
+
+fn hash4(data: &[u8]) -> usize {
+    let v = u32::from_le_bytes(data[..4].try_into().unwrap());
+    (v.wrapping_mul(0x1e35_a7bd) >> (32 - HASH_BITS)) as usize
+}
+
+/// Calls `emit(literal_start, position, offset, len)` for each match found in
+/// `data[..limit]`, then returns the start of the trailing literals.
+fn find_matches(
+    data: &[u8],
+    limit: usize,
+    match_end: usize,
+    mut emit: impl FnMut(usize, usize, usize, usize),
+) -> usize {
+    let mut table = vec![usize::MAX; 1 << HASH_BITS];
+    let mut anchor = 0;
+    let mut i = 0;
+    while i + 4 <= limit {
+        let h = hash4(&data[i..]);
+        let candidate = table[h];
+        table[h] = i;
+        if candidate != usize::MAX
+            && i - candidate <= MAX_OFFSET
+            && data[candidate..candidate + 4] == data[i..i + 4]
+        {
+            let mut len = 4;
+            while i + len < match_end && data[candidate + len] == data[i + len] {
+                len += 1;
+            }
+            emit(anchor, i, i - candidate, len);
+            i += len;
+            anchor = i;
+        } else {
+            i += 1;
+        }
+    }
+    anchor
+}
+
+fn corruption(what: &str) -> Error {
+    Error::Corruption(format!("bad {} block", what))
+}
+
+fn take<'a>(buf: &mut &'a [u8], n: usize, what: &str) -> Result<&'a [u8]> {
+    if buf.len() < n {
+        return Err(corruption(what));
+    }
+    let (head, rest) = buf.split_at(n);
+    *buf = rest;
+    Ok(head)
+}
+
+fn copy_match(out: &mut Vec<u8>, offset: usize, len: usize, what: &str) -> Result<()> {
+    if offset == 0 || offset > out.len() {
+        return Err(corruption(what));
+    }
+    // The source may overlap the bytes being written.
+    for _ in 0..len {
+        out.push(out[out.len() - offset]);
+    }
+    Ok(())
+}
+
+fn snappy_literal(out: &mut Vec<u8>, literal: &[u8]) {
+    if literal.is_empty() {
+        return;
+    }
+    let n = literal.len() - 1;
+    if n < 60 {
+        out.push((n as u8) << 2);
+    } else {
+        let bytes = (usize::BITS - n.leading_zeros()).div_ceil(8) as usize;
+        out.push(((59 + bytes) as u8) << 2);
+        out.extend_from_slice(&n.to_le_bytes()[..bytes]);
+    }
+    out.extend_from_slice(literal);
+}
+
+fn snappy_compress(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() / 2 + 16);
+    put_varint(&mut out, data.len() as u64);
+    let rest = find_matches(
+        data,
+        data.len(),
+        data.len(),
+        |anchor, i, offset, mut len| {
+            snappy_literal(&mut out, &data[anchor..i]);
+            while len > 0 {
+                let n = len.min(64);
+                out.push((((n - 1) as u8) << 2) | 2);
+                out.extend_from_slice(&(offset as u16).to_le_bytes());
+                len -= n;
+            }
+        },
+    );
+    snappy_literal(&mut out, &data[rest..]);
+    out
+}
+
+fn snappy_decompress(mut buf: &[u8]) -> Result<Vec<u8>> {
+    const WHAT: &str = "snappy";
+    let buf = &mut buf;
+    let len = get_varint(buf)? as usize;
+    let mut out = Vec::with_capacity(len.min(buf.len().saturating_mul(8)));
+    while !buf.is_empty() {
+        let tag = take(buf, 1, WHAT)?[0];
+        let (offset, len) = match tag & 3 {
+            0 => {
+                let mut n = (tag >> 2) as usize;
+                if n >= 60 {
+                    let mut bytes = [0; 8];
+                    let extra = n - 59;
+                    bytes[..extra].copy_from_slice(take(buf, extra, WHAT)?);
+                    n = u64::from_le_bytes(bytes) as usize;
+                }
+                out.extend_from_slice(take(buf, n + 1, WHAT)?);
+                continue;
+            }
+            1 => {
+                let low = take(buf, 1, WHAT)?[0] as usize;
+                (
+                    (((tag >> 5) as usize) << 8) | low,
+                    4 + ((tag >> 2) & 7) as usize,
+                )
+            }
+            2 => {
+                let offset = u16::from_le_bytes(take(buf, 2, WHAT)?.try_into().unwrap());
+                (offset as usize, 1 + (tag >> 2) as usize)
+            }
+            _ => {
+                let offset = u32::from_le_bytes(take(buf, 4, WHAT)?.try_into().unwrap());
+                (offset as usize, 1 + (tag >> 2) as usize)
+            }
+        };
+        copy_match(&mut out, offset, len, WHAT)?;
+    }
+    if out.len() != len {
+        return Err(corruption(WHAT));
+    }
+    Ok(out)
+}
+
+/// The last 5 bytes of an LZ4 block are literals, and the last match starts at least
+/// 12 bytes before its end.
+const LZ4_LAST_LITERALS: usize = 5;
+const LZ4_MF_LIMIT: usize = 12;
+
+fn lz4_length(out: &mut Vec<u8>, len: usize) {
+    if len < 15 {
+        return;
+    }
+    let mut n = len - 15;
+    while n >= 255 {
+        out.push(255);
+        n -= 255;
+    }
+    out.push(n as u8);
+}
+
+fn lz4_compress(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() / 2 + 16);
+    put_varint(&mut out, data.len() as u64);
+    let limit = (data.len() + 4).saturating_sub(LZ4_MF_LIMIT);
+    let match_end = data.len().saturating_sub(LZ4_LAST_LITERALS);
+    let rest = find_matches(data, limit, match_end, |anchor, i, offset, len| {
+        let literal = i - anchor;
+        out.push(((literal.min(15) as u8) << 4) | (len - 4).min(15) as u8);
+        lz4_length(&mut out, literal);
+        out.extend_from_slice(&data[anchor..i]);
+        out.extend_from_slice(&(offset as u16).to_le_bytes());
+        lz4_length(&mut out, len - 4);
+    });
+    let literal = data.len() - rest;
+    out.push((literal.min(15) as u8) << 4);
+    lz4_length(&mut out, literal);
+    out.extend_from_slice(&data[rest..]);
+    out
+}
+
+fn lz4_decompress(mut buf: &[u8]) -> Result<Vec<u8>> {
+    const WHAT: &str = "lz4";
+    let buf = &mut buf;
+    let len = get_varint(buf)? as usize;
+    let mut out = Vec::with_capacity(len.min(buf.len().saturating_mul(8)));
+    let read_length = |buf: &mut &[u8], mut n: usize| -> Result<usize> {
+        if n == 15 {
+            loop {
+                let b = take(buf, 1, WHAT)?[0];
+                n += b as usize;
+                if b != 255 {
+                    break;
+                }
+            }
+        }
+        Ok(n)
+    };
+    while !buf.is_empty() {
+        let token = take(buf, 1, WHAT)?[0];
+        let literal = read_length(buf, (token >> 4) as usize)?;
+        out.extend_from_slice(take(buf, literal, WHAT)?);
+        if buf.is_empty() {
+            break;
+        }
+        let offset = u16::from_le_bytes(take(buf, 2, WHAT)?.try_into().unwrap());
+        let len = read_length(buf, (token & 15) as usize)? + 4;
+        copy_match(&mut out, offset as usize, len, WHAT)?;
+    }
+    if out.len() != len {
+        return Err(corruption(WHAT));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> Vec<Vec<u8>> {
+        let mut samples = vec![
+            Vec::new(),
+            b"a".to_vec(),
+            b"abcdabcdabcdabcd".to_vec(),
+            vec![7; 100_000],
+        ];
+        let mut text = Vec::new();
+        for i in 0..5000 {
+            text.extend_from_slice(
+                format!("soliton_id{:05} causet_locale{} ", i % 700, i).as_bytes(),
+            );
+        }
+        samples.push(text);
+        let mut x = 0x2545_f491_4f6c_dd1du64;
+        samples.push(
+            (0..10_000)
+                .map(|_| {
+                    x ^= x << 13;
+                    x ^= x >> 7;
+                    x ^= x << 17;
+                    (x % 7) as u8
+                })
+                .collect(),
+        );
+        samples
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for compression in [Compression::Snappy, Compression::Lz4] {
+            for data in samples() {
+                let id = compression_id(compression);
+                match compress(compression, &data) {
+                    Some(compressed) => {
+                        assert!(compressed.len() < data.len());
+                        assert_eq!(decompress(id, &compressed).unwrap(), data);
+                    }
+                    None => assert!(data.len() < 32, "{:?} {}", compression, data.len()),
+                }
+                // The compressors always produce valid blocks, even when not smaller.
+                let raw = match compression {
+                    Compression::Snappy => snappy_compress(&data),
+                    _ => lz4_compress(&data),
+                };
+                assert_eq!(decompress(id, &raw).unwrap(), data);
+                if !data.is_empty() {
+                    assert!(decompress(id, &raw[..raw.len() - 1]).is_err());
+                }
+            }
+        }
+        assert!(check_supported(Compression::Zstd).is_err());
+    }
+
+    #[test]
+    fn test_snappy_copy_forms() {
+        // A literal, then a copy with a 1-byte and one with a 4-byte offset, which the
+        // compressor never emits.
+        let block = [
+            0x0d, 0x08, b'a', b'b', b'c', 0x11, 0x03, 0x07, 0x03, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(snappy_decompress(&block).unwrap(), b"abcabcabcabca");
+        assert!(snappy_decompress(&[0x03, 0x11, 0x03]).is_err());
+    }
+}
@@ -0,0 +1,449 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Changing the options of a running einstein_merkle_tree.
+//!
+//! `LsmOptions` and `LsmEngine` implement `EinsteinDBOptions` and
+//! `EinsteinOptionsSetter`. Online options are read each time they are needed, so
+//! `LsmEngine::set_options` applies them to the running einstein_merkle_tree; the
+//! others are only read when it is opened. The effective options are written to
+//! `OPTIONS` when the einstein_merkle_tree is opened and whenever they change:
+//!
+//! ```text
+//!   options ::= (name " = " causet_locale "\n")*
+//! ```
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use fdb_traits::{
+    ChangeKind, Compression, EinsteinDBOptions, EinsteinOptionsSetter, Error, OptionChange,
+    Result,
+};
+
+use crate::cache::BlockCache;
+use crate::compression::check_supported;
+use crate::engine::LsmEngine;
+use crate::options::LsmOptions;
+
+pub const OPTIONS_FILE: &str = "OPTIONS";
+const OPTIONS_TMP_FILE: &str = "OPTIONS.tmp";
+
+/// The block cache is known by its capacity, 0 when there is none.
+const BLOCK_CACHE_SIZE: &str = "block_cache_size";
+
+struct OptionDef {
+    name: &'static str,
+    kind: ChangeKind,
+    get: fn(&LsmOptions) -> String,
+    set: fn(&mut LsmOptions, &str) -> Result<()>,
+}
+
+fn parse<T: FromStr>(name: &str, causet_locale: &str) -> Result<T> {
+    causet_locale.parse().map_err(|_| {
+        Error::Engine(format!(
+            "invalid causet_locale {:?} for option {}",
+            causet_locale, name
+        ))
+    })
+}
+
+fn join_list(list: &[String]) -> String {
+    list.join(",")
+}
+
+fn split_list(causet_locale: &str) -> Vec<String> {
+    causet_locale
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+fn compression_name(compression: Compression) -> &'static str {
+    match compression {
+        Compression::None => "none",
+        Compression::Snappy => "snappy",
+        Compression::Lz4 => "lz4",
+        Compression::Zlib => "zlib",
+        Compression::Zstd => "zstd",
+    }
+}
+
+fn parse_compression(causet_locale: &str) -> Result<Compression> {
+    let compression = match causet_locale {
+        "none" => Compression::None,
+        "snappy" => Compression::Snappy,
+        "lz4" => Compression::Lz4,
+        "zlib" => Compression::Zlib,
+        "zstd" => Compression::Zstd,
+        _ => {
+            return Err(Error::Engine(format!(
+                "invalid causet_locale {:?} for option compression",
+                causet_locale
+            )))
+        }
+    };
+    check_supported(compression)?;
+    Ok(compression)
+}
+
+macro_rules! option {
+    ($name:ident, $kind:ident) => {
+        OptionDef {
+            name: stringify!($name),
+            kind: ChangeKind::$kind,
+            get: |opts| opts.$name.to_string(),
+            set: |opts, causet_locale| {
+                opts.$name = parse(stringify!($name), causet_locale)?;
+                Ok(())
+            },
+        }
+    };
+}
+
+/// Every option, in the order they are persisted.
+const OPTIONS: &[OptionDef] = &[
+    option!(create_if_missing, RestartRequired),
+    OptionDef {
+        name: "namespaceds",
+        kind: ChangeKind::RestartRequired,
+        get: |opts| join_list(&opts.namespaceds),
+        set: |opts, causet_locale| {
+            opts.namespaceds = split_list(causet_locale);
+            Ok(())
+        },
+    },
+    option!(write_buffer_size, Online),
+    option!(block_size, Online),
+    option!(bloom_bits_per_key, Online),
+    OptionDef {
+        name: "compression",
+        kind: ChangeKind::Online,
+        get: |opts| compression_name(opts.compression).to_owned(),
+        set: |opts, causet_locale| {
+            opts.compression = parse_compression(causet_locale)?;
+            Ok(())
+        },
+    },
+    option!(num_levels, RestartRequired),
+    option!(level0_file_num_compaction_trigger, Online),
+    option!(max_bytes_for_level_base, Online),
+    option!(max_bytes_for_level_multiplier, Online),
+    option!(target_file_size_base, Online),
+    option!(disable_auto_compactions, Online),
+    OptionDef {
+        name: "ttl_namespaceds",
+        kind: ChangeKind::RestartRequired,
+        get: |opts| join_list(&opts.ttl_namespaceds),
+        set: |opts, causet_locale| {
+            opts.ttl_namespaceds = split_list(causet_locale);
+            Ok(())
+        },
+    },
+    option!(range_greedoids_size_distance, Online),
+    option!(range_greedoids_entries_distance, Online),
+    OptionDef {
+        name: "prefix_extractor_len",
+        kind: ChangeKind::RestartRequired,
+        get: |opts| {
+            opts.prefix_extractor_len
+                .map_or_else(String::new, |len| len.to_string())
+        },
+        set: |opts, causet_locale| {
+            opts.prefix_extractor_len = match causet_locale {
+                "" => None,
+                _ => Some(parse("prefix_extractor_len", causet_locale)?),
+            };
+            Ok(())
+        },
+    },
+    OptionDef {
+        name: BLOCK_CACHE_SIZE,
+        kind: ChangeKind::Online,
+        get: |opts| {
+            opts.block_cache
+                .as_ref()
+                .map_or(0, |cache| cache.capacity())
+                .to_string()
+        },
+        set: |opts, causet_locale| {
+            let capacity = parse(BLOCK_CACHE_SIZE, causet_locale)?;
+            match &opts.block_cache {
+                Some(cache) => cache.set_capacity(capacity),
+                None if capacity == 0 => {}
+                // With the shards and high priority pool of the default cache.
+                None => opts.block_cache = Some(BlockCache::new(capacity, 4, 0.5)),
+            }
+            Ok(())
+        },
+    },
+    option!(rate_bytes_per_sec, Online),
+];
+
+fn find_option(name: &str) -> Result<&'static OptionDef> {
+    OPTIONS
+        .iter()
+        .find(|def| def.name == name)
+        .ok_or_else(|| Error::Engine(format!("unknown option {}", name)))
+}
+
+impl EinsteinDBOptions for LsmOptions {
+    fn new() -> LsmOptions {
+        LsmOptions::default()
+    }
+
+    fn get_option(&self, name: &str) -> Result<String> {
+        Ok((find_option(name)?.get)(self))
+    }
+
+    /// Setting `block_cache_size` resizes the block cache, which clones of these
+    /// options share.
+    fn set_option(&mut self, name: &str, causet_locale: &str) -> Result<()> {
+        (find_option(name)?.set)(self, causet_locale)
+    }
+
+    fn diff(&self, new: &LsmOptions) -> Vec<OptionChange> {
+        diff_options(self, new)
+    }
+
+    fn get_max_background_jobs(&self) -> i32 {
+        0
+    }
+
+    fn get_rate_bytes_per_sec(&self) -> Option<i64> {
+        match self.rate_bytes_per_sec {
+            0 => None,
+            rate => Some(rate as i64),
+        }
+    }
+
+    fn set_rate_bytes_per_sec(&mut self, rate_bytes_per_sec: i64) -> Result<()> {
+        self.set_option("rate_bytes_per_sec", &rate_bytes_per_sec.to_string())
+    }
+
+    fn get_rate_limiter_auto_tuned(&self) -> Option<bool> {
+        None
+    }
+
+    fn set_rate_limiter_auto_tuned(&mut self, _: bool) -> Result<()> {
+        Err(Error::Engine(
+            "the rate limiter can not be auto tuned".to_owned(),
+        ))
+    }
+
+    fn get_block_cache_capacity(&self) -> Option<usize> {
+        self.block_cache.as_ref().map(BlockCache::capacity)
+    }
+
+    fn set_block_cache_capacity(&mut self, capacity: usize) -> Result<()> {
+        self.set_option(BLOCK_CACHE_SIZE, &capacity.to_string())
+    }
+
+    fn get_write_buffer_size(&self) -> usize {
+        self.write_buffer_size
+    }
+
+    fn set_write_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.set_option("write_buffer_size", &size.to_string())
+    }
+}
+
+/// The options whose causet_locales differ between `old` and `new`.
+pub fn diff_options(old: &LsmOptions, new: &LsmOptions) -> Vec<OptionChange> {
+    OPTIONS
+        .iter()
+        .filter_map(|def| {
+            let (old_value, new_value) = ((def.get)(old), (def.get)(new));
+            if old_value == new_value {
+                return None;
+            }
+            // A block cache can be resized, but not added to open tables.
+            let kind = if def.name == BLOCK_CACHE_SIZE && old.block_cache.is_none() {
+                ChangeKind::RestartRequired
+            } else {
+                def.kind
+            };
+            Some(OptionChange {
+                name: def.name,
+                old_value,
+                new_value,
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// `opts` with `changes` made. Every change must be online, and the block cache is
+/// only resized once all the others have been parsed.
+pub(crate) fn apply_online_changes(
+    opts: &LsmOptions,
+    changes: &[(&str, &str)],
+) -> Result<LsmOptions> {
+    let mut new = opts.clone();
+    let mut cache_capacity = None;
+    for &(name, causet_locale) in changes {
+        let def = find_option(name)?;
+        if def.kind != ChangeKind::Online {
+            return Err(Error::Engine(format!(
+                "option {} can only be changed with a restart",
+                name
+            )));
+        }
+        if name == BLOCK_CACHE_SIZE {
+            cache_capacity = Some(parse::<usize>(name, causet_locale)?);
+        } else {
+            (def.set)(&mut new, causet_locale)?;
+        }
+    }
+    match (&new.block_cache, cache_capacity) {
+        (_, None) | (None, Some(0)) => {}
+        (Some(cache), Some(capacity)) => cache.set_capacity(capacity),
+        (None, Some(_)) => {
+            return Err(Error::Engine(
+                "the block cache can only be added with a restart".to_owned(),
+            ))
+        }
+    }
+    Ok(new)
+}
+
+/// Persists `opts` to the `OPTIONS` file of `dir`, replacing it atomically.
+pub(crate) fn write_options_file(dir: &Path, opts: &LsmOptions) -> Result<()> {
+    let mut content = String::new();
+    for def in OPTIONS {
+        content.push_str(&format!("{} = {}\n", def.name, (def.get)(opts)));
+    }
+    let tmp = dir.join(OPTIONS_TMP_FILE);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(&tmp)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&tmp, dir.join(OPTIONS_FILE))?;
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+/// Sets `opts` to the options persisted in `dir`, those of the last time the
+/// einstein_merkle_tree there ran. Returns whether there were any.
+pub fn load_options(dir: &Path, opts: &mut LsmOptions) -> Result<bool> {
+    let path = dir.join(OPTIONS_FILE);
+    if !path.exists() {
+        return Ok(false);
+    }
+    for line in fs::read_to_string(&path)?.lines() {
+        let (name, causet_locale) = line.split_once(" = ").ok_or_else(|| {
+            Error::Corruption(format!("bad line in {}: {:?}", OPTIONS_FILE, line))
+        })?;
+        opts.set_option(name, causet_locale)?;
+    }
+    Ok(true)
+}
+
+impl EinsteinOptionsSetter for LsmEngine {
+    type DBOptions = LsmOptions;
+
+    fn get_db_options(&self) -> LsmOptions {
+        LsmOptions::clone(&self.options())
+    }
+
+    fn set_db_options(&self, options: &[(&str, &str)]) -> Result<()> {
+        self.set_options(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = BlockCache::new(1 << 20, 0, 0.5);
+        let opts = LsmOptions {
+            block_cache: Some(cache.clone()),
+            ..Default::default()
+        };
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts.clone()).unwrap();
+
+        let new = LsmOptions {
+            write_buffer_size: 1024,
+            num_levels: 5,
+            rate_bytes_per_sec: 10 << 20,
+            block_cache: Some(BlockCache::new(2 << 20, 0, 0.5)),
+            ..opts.clone()
+        };
+        let changes = einstein_merkle_tree.update_db_options(&new).unwrap();
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|c| (c.name, c.new_value.as_str(), c.kind))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("write_buffer_size", "1024", ChangeKind::Online),
+                ("num_levels", "5", ChangeKind::RestartRequired),
+                (BLOCK_CACHE_SIZE, "2097152", ChangeKind::Online),
+                ("rate_bytes_per_sec", "10485760", ChangeKind::Online),
+            ]
+        );
+        let running = einstein_merkle_tree.options();
+        assert_eq!(running.write_buffer_size, 1024);
+        assert_eq!(running.num_levels, 7);
+        assert_eq!(cache.capacity(), 2 << 20);
+        assert_eq!(diff_options(&running, &new).len(), 1);
+
+        // A failed change changes nothing.
+        for changes in [
+            &[("write_buffer_size", "2048"), ("num_levels", "3")][..],
+            &[("write_buffer_size", "2048"), ("compression", "zstd")],
+            &[(BLOCK_CACHE_SIZE, "0"), ("block_size", "big")],
+            &[("no_such_option", "1")],
+        ] {
+            assert!(einstein_merkle_tree.set_options(changes).is_err());
+        }
+        assert_eq!(einstein_merkle_tree.options().write_buffer_size, 1024);
+        assert_eq!(cache.capacity(), 2 << 20);
+
+        // Online changes survive a restart through the persisted options.
+        einstein_merkle_tree
+            .set_options(&[("disable_auto_compactions", "true")])
+            .unwrap();
+        drop(einstein_merkle_tree);
+        let mut loaded = LsmOptions::default();
+        assert!(load_options(dir.path(), &mut loaded).unwrap());
+        assert!(diff_options(&running, &loaded)
+            .iter()
+            .all(|c| c.name == "disable_auto_compactions"));
+        assert!(loaded.disable_auto_compactions);
+        assert!(!load_options(&dir.path().join("missing"), &mut loaded).unwrap());
+    }
+
+    #[test]
+    fn test_db_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::new()).unwrap();
+        let mut opts = einstein_merkle_tree.get_db_options();
+        assert_eq!(opts.get_rate_bytes_per_sec(), None);
+        opts.set_rate_bytes_per_sec(1 << 20).unwrap();
+        opts.set_write_buffer_size(4096).unwrap();
+        opts.set_block_cache_capacity(1 << 20).unwrap();
+        assert!(opts.set_rate_limiter_auto_tuned(true).is_err());
+
+        let changes = einstein_merkle_tree.update_db_options(&opts).unwrap();
+        assert!(changes.iter().all(|c| c.kind == ChangeKind::Online));
+        let running = einstein_merkle_tree.get_db_options();
+        assert_eq!(running.get_rate_bytes_per_sec(), Some(1 << 20));
+        assert_eq!(running.get_write_buffer_size(), 4096);
+        assert_eq!(running.get_block_cache_capacity(), Some(1 << 20));
+        assert!(running.diff(&opts).is_empty());
+        assert!(einstein_merkle_tree
+            .set_db_options(&[("num_levels", "3")])
+            .is_err());
+    }
+}
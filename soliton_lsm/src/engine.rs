@@ -0,0 +1,1600 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The einstein_merkle_tree: a write-ahead log shared by all causet_merge families, and
+//! per family a memtable and a leveled set of SST files.
+//!
+//! Writes are serialized by the state lock. A full memtable is frozen into the
+//! immutable list of its family and flushed on the writing thread, which then runs
+//! the compactions the tree needs. Flushes and compactions build their tables
+//! outside the state lock and only take it to install them; the jobs lock keeps
+//! one job at a time. Readers only take the state lock to clone the memtables and
+//! version they read from.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use fdb_traits::{
+    CompactionFilterContext, CompactionFilterExt, CompactionFilterFactory, CompactionFilterStats,
+    Error, FdbWriteBatch, ImportMode, IterOptions, Iterable, Mutable, Peekable, ReadOptions,
+    Result, WriteBatch, WriteCommand, WriteOptions,
+};
+
+use crate::cache::{BlockCacheCounters, BlockCacheStats, TableCache};
+use crate::codec::{get_fixed_u64, InternalKey, ValueKind};
+use crate::compaction::{pick_compaction, run_compaction, Compaction};
+use crate::compression::check_supported;
+use crate::config::{apply_online_changes, write_options_file};
+use crate::iterator::{InternalIterator, LsmIterator, MemtableIterator, MergingIterator};
+use crate::memtable::{Lookup, Memtable};
+use crate::options::{LsmOptions, NAMESPACED_DEFAULT};
+use crate::range_del::{RangeDelAggregator, RangeTombstone};
+use crate::range_greedoids::RangeGreedoidsCollector;
+use crate::rate_limiter::RateLimiter;
+use crate::sst::{TableBuilder, TableIterator, TableReader};
+use crate::ttl::TtlGreedoidsCollector;
+use crate::version::{log_file_path, table_file_path, FileMeta, ManifestData, Version};
+use crate::wal::{read_log, LogWriter};
+use crate::write_batch::LsmWriteBatch;
+
+struct NamespacedState {
+    mem: Arc<Memtable>,
+    /// Frozen memtables waiting to be flushed, newest first.
+    imm: Vec<Arc<Memtable>>,
+    version: Arc<Version>,
+    /// Where the next compaction of each level starts, see `pick_compaction`.
+    compact_pointer: Vec<Vec<u8>>,
+    compaction_filter_factory: Option<Arc<dyn CompactionFilterFactory>>,
+    filter_stats: CompactionFilterStats,
+    cache_counters: Arc<BlockCacheCounters>,
+}
+
+struct EngineState {
+    log: LogWriter,
+    /// The number of `log`.
+    log_file_number: u64,
+    /// Logs older than this have been flushed to SST files.
+    log_number: u64,
+    next_file_number: u64,
+    last_seq: u64,
+    namespaceds: BTreeMap<String, NamespacedState>,
+    /// The sequence numbers of the live snapshots, with how many were taken at each.
+    snapshots: BTreeMap<u64, usize>,
+}
+
+impl EngineState {
+    fn namespaced(&self, name: &str) -> Result<&NamespacedState> {
+        self.namespaceds
+            .get(name)
+            .ok_or_else(|| Error::NamespacedName(name.to_owned()))
+    }
+
+    fn new_file_number(&mut self) -> u64 {
+        self.next_file_number += 1;
+        self.next_file_number - 1
+    }
+
+    fn manifest(&self) -> ManifestData {
+        ManifestData {
+            next_file_number: self.next_file_number,
+            last_seq: self.last_seq,
+            log_number: self.log_number,
+            namespaceds: self
+                .namespaceds
+                .iter()
+                .map(|(name, ns)| {
+                    let levels = ns
+                        .version
+                        .levels
+                        .iter()
+                        .map(|files| files.iter().map(|f| f.number).collect())
+                        .collect();
+                    (name.clone(), levels)
+                })
+                .collect(),
+        }
+    }
+
+    /// Applies a alexandrov_poset_process record to the memtables.
+    fn apply(&mut self, first_seq: u64, wb: &FdbWriteBatch) -> Result<()> {
+        for (i, command) in wb.iter().enumerate() {
+            let seq = first_seq + i as u64;
+            match command {
+                WriteCommand::Put {
+                    namespaced,
+                    soliton_id,
+                    causet_locale,
+                } => self
+                    .mem(namespaced)?
+                    .add(seq, ValueKind::Put, soliton_id, causet_locale),
+                WriteCommand::Delete {
+                    namespaced,
+                    soliton_id,
+                } => self
+                    .mem(namespaced)?
+                    .add(seq, ValueKind::Delete, soliton_id, b""),
+                WriteCommand::DeleteRange {
+                    namespaced,
+                    begin_soliton_id: start,
+                    end_soliton_id: end,
+                } => {
+                    let mem = self.mem(namespaced)?;
+                    if start < end {
+                        mem.add_range_tombstone(seq, start, end);
+                    }
+                }
+            }
+            self.last_seq = seq;
+        }
+        Ok(())
+    }
+
+    fn mem(&self, name: &str) -> Result<&Memtable> {
+        Ok(&self.namespaced(name)?.mem)
+    }
+
+    /// Moves the non-empty memtables to the immutable lists of their causet_merge
+    /// families, and starts a new log for the writes that follow.
+    fn freeze_memtables(&mut self, dir: &Path) -> Result<()> {
+        let mut frozen = false;
+        for ns in self.namespaceds.values_mut() {
+            if !ns.mem.is_empty() {
+                let mem = std::mem::replace(&mut ns.mem, Arc::new(Memtable::new()));
+                ns.imm.insert(0, mem);
+                frozen = true;
+            }
+        }
+        if frozen {
+            let log_number = self.new_file_number();
+            self.log = LogWriter::create(&log_file_path(dir, log_number))?;
+            self.log_file_number = log_number;
+        }
+        Ok(())
+    }
+}
+
+impl NamespacedState {
+    /// The mutable memtable, then the frozen ones, newest first.
+    fn memtables(&self) -> impl Iterator<Item = &Arc<Memtable>> {
+        std::iter::once(&self.mem).chain(&self.imm)
+    }
+}
+
+fn new_iterator(
+    ns: &NamespacedState,
+    read_seq: u64,
+    opts: IterOptions,
+    prefix_len: Option<usize>,
+) -> LsmIterator {
+    let mut children: Vec<Box<dyn InternalIterator>> = Vec::new();
+    let mut range_dels = RangeDelAggregator::default();
+    for mem in ns.memtables() {
+        children.push(Box::new(MemtableIterator::new(mem.clone())));
+        range_dels.add(&mem.fragmented_range_tombstones());
+    }
+    children.extend(ns.version.iterators(opts.fill_cache));
+    ns.version.add_range_tombstones(&mut range_dels);
+    LsmIterator::new(
+        MergingIterator::new(children),
+        range_dels,
+        read_seq,
+        opts,
+        prefix_len,
+    )
+}
+
+struct EngineCore {
+    local_path: PathBuf,
+    /// Replaced as a whole when options change, so that each operation reads one
+    /// consistent set.
+    opts: Mutex<Arc<LsmOptions>>,
+    rate_limiter: Arc<RateLimiter>,
+    state: Mutex<EngineState>,
+    /// Held by flushes, compactions and every other job that replaces versions,
+    /// see `LsmEngine::jobs`.
+    jobs: Mutex<()>,
+}
+
+/// A handle to an open einstein_merkle_tree. Clones share the same einstein_merkle_tree.
+#[derive(Clone)]
+pub struct LsmEngine {
+    core: Arc<EngineCore>,
+}
+
+impl LsmEngine {
+    /// Opens the einstein_merkle_tree at `local_path`, replaying the write-ahead log of
+    /// writes that were not flushed before the einstein_merkle_tree was last closed.
+    pub fn open(local_path: impl AsRef<Path>, opts: LsmOptions) -> Result<LsmEngine> {
+        let dir = local_path.as_ref();
+        check_supported(opts.compression)?;
+        if !dir.exists() {
+            if !opts.create_if_missing {
+                return Err(Error::Engine(format!("{} does not exist", dir.display())));
+            }
+            fs::create_dir_all(dir)?;
+        }
+        let manifest = ManifestData::read(dir)?.unwrap_or_default();
+
+        let mut names: Vec<&str> = vec![NAMESPACED_DEFAULT];
+        names.extend(manifest.namespaceds.keys().map(|n| n.as_str()));
+        names.extend(opts.namespaceds.iter().map(|n| n.as_str()));
+        let mut namespaceds = BTreeMap::new();
+        let mut live_files = HashSet::new();
+        for name in names {
+            if namespaceds.contains_key(name) {
+                continue;
+            }
+            let mut version = Version::new(opts.num_levels);
+            let cache_counters = Arc::new(BlockCacheCounters::default());
+            let cache = opts.block_cache.as_ref().map(|cache| TableCache {
+                cache: cache.clone(),
+                counters: cache_counters.clone(),
+            });
+            for (level, numbers) in manifest
+                .namespaceds
+                .get(name)
+                .into_iter()
+                .flatten()
+                .enumerate()
+            {
+                if level >= opts.num_levels && !numbers.is_empty() {
+                    return Err(Error::Engine(format!(
+                        "causet_merge family {} has files at level {}, but only {} levels are configured",
+                        name, level, opts.num_levels
+                    )));
+                }
+                for &number in numbers {
+                    let file = FileMeta::open(dir, number, cache.as_ref())?;
+                    version.add_file(level, Arc::new(file));
+                    live_files.insert(number);
+                }
+            }
+            namespaceds.insert(
+                name.to_owned(),
+                NamespacedState {
+                    mem: Arc::new(Memtable::new()),
+                    imm: Vec::new(),
+                    version: Arc::new(version),
+                    compact_pointer: vec![Vec::new(); opts.num_levels],
+                    compaction_filter_factory: None,
+                    filter_stats: CompactionFilterStats::default(),
+                    cache_counters,
+                },
+            );
+        }
+
+        // Sort the files of the directory out: logs to replay, and SSTs and logs a
+        // crash left behind.
+        let mut logs = Vec::new();
+        let mut max_number = manifest.next_file_number;
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let (stem, ext) = match (path.file_stem().and_then(|s| s.to_str()), path.extension()) {
+                (Some(stem), Some(ext)) => (stem, ext),
+                _ => continue,
+            };
+            let number = match stem.parse::<u64>() {
+                Ok(n) => n,
+                Err(_) => continue,
+            };
+            max_number = max_number.max(number + 1);
+            if ext == "log" && number >= manifest.log_number {
+                logs.push(number);
+            } else if ext == "log" || (ext == "sst" && !live_files.contains(&number)) {
+                fs::remove_file(&path)?;
+            }
+        }
+        logs.sort_unstable();
+
+        let mut state = EngineState {
+            log: LogWriter::create(&log_file_path(dir, max_number))?,
+            log_file_number: max_number,
+            log_number: manifest.log_number,
+            next_file_number: max_number + 1,
+            last_seq: manifest.last_seq,
+            namespaceds,
+            snapshots: BTreeMap::new(),
+        };
+        for number in logs {
+            for record in read_log(&log_file_path(dir, number))? {
+                let mut buf = record.as_slice();
+                let first_seq = get_fixed_u64(&mut buf)?;
+                state.apply(first_seq, &FdbWriteBatch::from_data(buf)?)?;
+            }
+        }
+        state.manifest().write(dir)?;
+        write_options_file(dir, &opts)?;
+
+        Ok(LsmEngine {
+            core: Arc::new(EngineCore {
+                local_path: dir.to_owned(),
+                rate_limiter: Arc::new(RateLimiter::new(opts.rate_bytes_per_sec)),
+                opts: Mutex::new(Arc::new(opts)),
+                state: Mutex::new(state),
+                jobs: Mutex::new(()),
+            }),
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, EngineState> {
+        self.core.state.lock().unwrap()
+    }
+
+    pub fn local_path(&self) -> &Path {
+        &self.core.local_path
+    }
+
+    /// The options the einstein_merkle_tree runs with.
+    pub fn options(&self) -> Arc<LsmOptions> {
+        self.core.opts.lock().unwrap().clone()
+    }
+
+    /// Changes online options of the running einstein_merkle_tree and persists the
+    /// effective options. If any change fails, none is made.
+    pub fn set_options(&self, changes: &[(&str, &str)]) -> Result<()> {
+        let mut current = self.core.opts.lock().unwrap();
+        let opts = apply_online_changes(&current, changes)?;
+        write_options_file(&self.core.local_path, &opts)?;
+        self.core
+            .rate_limiter
+            .set_bytes_per_sec(opts.rate_bytes_per_sec);
+        *current = Arc::new(opts);
+        Ok(())
+    }
+
+    pub fn namespaced_names(&self) -> Vec<String> {
+        self.state().namespaceds.keys().cloned().collect()
+    }
+
+    pub(crate) fn current_version(&self, namespaced: &str) -> Result<Arc<Version>> {
+        Ok(self.state().namespaced(namespaced)?.version.clone())
+    }
+
+    /// The memtables of `namespaced`: the mutable one, then the frozen ones, newest
+    /// first.
+    pub(crate) fn current_memtables(&self, namespaced: &str) -> Result<Vec<Arc<Memtable>>> {
+        Ok(self
+            .state()
+            .namespaced(namespaced)?
+            .memtables()
+            .cloned()
+            .collect())
+    }
+
+    pub(crate) fn check_namespaced(&self, name: &str) -> Result<()> {
+        self.state().namespaced(name).map(|_| ())
+    }
+
+    /// The sequence number of the last committed write.
+    pub(crate) fn last_sequence_number(&self) -> u64 {
+        self.state().last_seq
+    }
+
+    /// The block cache hits and misses of the tables of `namespaced`.
+    pub fn block_cache_stats(&self, namespaced: &str) -> Result<BlockCacheStats> {
+        Ok(self.state().namespaced(namespaced)?.cache_counters.stats())
+    }
+
+    pub fn num_files_at_level(&self, namespaced: &str, level: usize) -> Result<usize> {
+        let state = self.state();
+        let ns = state.namespaced(namespaced)?;
+        Ok(ns.version.levels.get(level).map_or(0, |files| files.len()))
+    }
+
+    /// Reads the newest version of `soliton_id` visible at `read_seq`, or as of now.
+    pub(crate) fn get_value_at(
+        &self,
+        opts: &ReadOptions,
+        namespaced: &str,
+        soliton_id: &[u8],
+        read_seq: Option<u64>,
+    ) -> Result<Option<Vec<u8>>> {
+        let (mems, version, seq) = {
+            let state = self.state();
+            let ns = state.namespaced(namespaced)?;
+            let seq = read_seq.unwrap_or(state.last_seq);
+            let mems: Vec<_> = ns.memtables().cloned().collect();
+            (mems, ns.version.clone(), seq)
+        };
+        let lookup = match mems
+            .iter()
+            .map(|mem| mem.get(soliton_id, seq))
+            .find(|lookup| *lookup != Lookup::NotFound)
+        {
+            Some(found) => found,
+            None => version.get(soliton_id, seq, opts.fill_cache)?,
+        };
+        Ok(match lookup {
+            Lookup::Found(v) => Some(v),
+            Lookup::Deleted | Lookup::NotFound => None,
+        })
+    }
+
+    /// Iterates over the soliton_ids of `namespaced` visible at `read_seq`, or as of
+    /// now; later writes are not seen.
+    pub(crate) fn iterator_at(
+        &self,
+        namespaced: &str,
+        opts: IterOptions,
+        read_seq: Option<u64>,
+    ) -> Result<LsmIterator> {
+        let state = self.state();
+        let ns = state.namespaced(namespaced)?;
+        let read_seq = read_seq.unwrap_or(state.last_seq);
+        Ok(new_iterator(
+            ns,
+            read_seq,
+            opts,
+            self.options().prefix_extractor_len,
+        ))
+    }
+
+    /// Registers a snapshot at the last committed write; compactions keep what it
+    /// sees until `release_snapshot`.
+    pub(crate) fn acquire_snapshot(&self) -> u64 {
+        let mut state = self.state();
+        let seq = state.last_seq;
+        *state.snapshots.entry(seq).or_default() += 1;
+        seq
+    }
+
+    /// The sequence number of the oldest live snapshot.
+    pub(crate) fn oldest_snapshot(&self) -> Option<u64> {
+        self.state().snapshots.keys().next().copied()
+    }
+
+    pub(crate) fn release_snapshot(&self, seq: u64) {
+        let mut state = self.state();
+        if let Some(count) = state.snapshots.get_mut(&seq) {
+            *count -= 1;
+            if *count == 0 {
+                state.snapshots.remove(&seq);
+            }
+        }
+    }
+
+    pub fn put(&self, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()> {
+        self.put_namespaced(NAMESPACED_DEFAULT, soliton_id, causet_locale)
+    }
+
+    pub fn put_namespaced(
+        &self,
+        namespaced: &str,
+        soliton_id: &[u8],
+        causet_locale: &[u8],
+    ) -> Result<()> {
+        let mut wb = LsmWriteBatch::new(self);
+        wb.put_namespaced(namespaced, soliton_id, causet_locale)?;
+        wb.write_opt(&WriteOptions::default())
+    }
+
+    pub fn delete(&self, soliton_id: &[u8]) -> Result<()> {
+        self.delete_namespaced(NAMESPACED_DEFAULT, soliton_id)
+    }
+
+    pub fn delete_namespaced(&self, namespaced: &str, soliton_id: &[u8]) -> Result<()> {
+        let mut wb = LsmWriteBatch::new(self);
+        wb.delete_namespaced(namespaced, soliton_id)?;
+        wb.write_opt(&WriteOptions::default())
+    }
+
+    pub fn delete_range_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<()> {
+        let mut wb = LsmWriteBatch::new(self);
+        wb.delete_range_namespaced(namespaced, start, end)?;
+        wb.write_opt(&WriteOptions::default())
+    }
+
+    pub(crate) fn write(&self, wb: &FdbWriteBatch, opts: &WriteOptions) -> Result<()> {
+        if wb.is_empty() {
+            return Ok(());
+        }
+        {
+            let mut state = self.state();
+            let first_seq = state.last_seq + 1;
+            if !opts.disable_wal {
+                let mut record = Vec::with_capacity(8 + wb.data_size());
+                record.extend_from_slice(&first_seq.to_le_bytes());
+                record.extend_from_slice(wb.data());
+                state.log.add_record(&record, opts.sync)?;
+            }
+            state.apply(first_seq, wb)?;
+            let buffer_size = self.options().write_buffer_size;
+            if state
+                .namespaceds
+                .values()
+                .all(|ns| ns.mem.approximate_size() < buffer_size)
+            {
+                return Ok(());
+            }
+            state.freeze_memtables(&self.core.local_path)?;
+        }
+        let _jobs = self.jobs();
+        self.flush_frozen()?;
+        self.maybe_compact()
+    }
+
+    pub(crate) fn sync_log(&self) -> Result<()> {
+        self.state().log.sync()
+    }
+
+    /// Flushes the memtables of all causet_merge families to level 0.
+    pub(crate) fn flush_memtables(&self) -> Result<()> {
+        let _jobs = self.jobs();
+        self.freeze_and_flush()?;
+        self.maybe_compact()
+    }
+
+    /// Waits for the running flush or compaction. Everything that replaces the
+    /// version of a causet_merge family holds this, so that the files a job reads
+    /// stay live while it runs outside the state lock.
+    fn jobs(&self) -> MutexGuard<'_, ()> {
+        self.core.jobs.lock().unwrap()
+    }
+
+    /// Whether writes are held back: memtables fill up faster than they are flushed,
+    /// so the writes that freeze one wait for the flushes before them.
+    pub(crate) fn is_write_stalled(&self) -> bool {
+        self.state().namespaceds.values().any(|ns| ns.imm.len() > 1)
+    }
+
+    /// Freezes the memtables and flushes them. The jobs lock must be held.
+    fn freeze_and_flush(&self) -> Result<()> {
+        self.state().freeze_memtables(&self.core.local_path)?;
+        self.flush_frozen()
+    }
+
+    /// Flushes the frozen memtables to level 0, oldest first. The tables are built
+    /// outside the state lock, which is only taken again to install them. The jobs
+    /// lock must be held.
+    fn flush_frozen(&self) -> Result<()> {
+        let (frozen, log_file_number) = {
+            let state = self.state();
+            let frozen: Vec<(String, Vec<Arc<Memtable>>)> = state
+                .namespaceds
+                .iter()
+                .filter(|(_, ns)| !ns.imm.is_empty())
+                .map(|(name, ns)| (name.clone(), ns.imm.clone()))
+                .collect();
+            // The writes of the memtables frozen so far are all in older logs.
+            (frozen, state.log_file_number)
+        };
+        if frozen.is_empty() {
+            return Ok(());
+        }
+
+        let dir = &self.core.local_path;
+        let mut flushed = Vec::with_capacity(frozen.len());
+        let mut created = Vec::new();
+        let res = (|| -> Result<()> {
+            for (name, imm) in &frozen {
+                let cache = self.table_cache(&self.state(), name);
+                let mut files = Vec::with_capacity(imm.len());
+                for mem in imm.iter().rev() {
+                    let (number, mut builder) = self.new_table(name)?;
+                    created.push(number);
+                    for (soliton_id, causet_locale) in mem.iter() {
+                        builder.add(&soliton_id, &causet_locale)?;
+                    }
+                    for tombstone in mem.range_tombstones() {
+                        builder.add_range_tombstone(&tombstone);
+                    }
+                    builder.finish()?;
+                    files.push(Arc::new(FileMeta::open(dir, number, cache.as_ref())?));
+                }
+                flushed.push((name, files));
+            }
+            Ok(())
+        })();
+        if let Err(e) = res {
+            remove_tables(dir, created);
+            return Err(e);
+        }
+
+        let obsolete_logs = {
+            let mut state = self.state();
+            for (name, files) in flushed {
+                let ns = state.namespaceds.get_mut(name).unwrap();
+                let mut version = (*ns.version).clone();
+                // Memtables frozen meanwhile went to the front of the list.
+                ns.imm.truncate(ns.imm.len() - files.len());
+                for file in files {
+                    version.add_file(0, file);
+                }
+                ns.version = Arc::new(version);
+            }
+            let obsolete_logs = state.log_number..log_file_number;
+            state.log_number = log_file_number;
+            state.manifest().write(dir)?;
+            obsolete_logs
+        };
+        for number in obsolete_logs {
+            let path = log_file_path(dir, number);
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the external `tables` into new files of `namespaced`, all written with the
+    /// next sequence number. Their range tombstones are carried over too; if there
+    /// are any, the entries get the sequence number after them, so that the
+    /// tombstones only delete what the tables are ingested over. The files go to
+    /// the deepest level that neither they nor a level above overlap. The tables
+    /// must not overlap each other.
+    pub(crate) fn ingest(
+        &self,
+        namespaced: &str,
+        mode: ImportMode,
+        tables: &[Arc<TableReader>],
+    ) -> Result<()> {
+        let _jobs = self.jobs();
+        let ranges: Vec<(&[u8], &[u8])> = tables
+            .iter()
+            .map(|t| {
+                let props = t.greedoids();
+                (
+                    props.smallest_key.as_ref().unwrap().user_key.as_slice(),
+                    props.largest_key.as_ref().unwrap().user_key.as_slice(),
+                )
+            })
+            .collect();
+        let dir = &self.core.local_path;
+        let mut state = loop {
+            let mut state = self.state();
+            let ns = state.namespaced(namespaced)?;
+            let mem_overlaps = ranges
+                .iter()
+                .any(|&(start, end)| ns.memtables().any(|mem| mem.overlaps(start, end)));
+            match mode {
+                ImportMode::Insert => {
+                    let version = &ns.version;
+                    if mem_overlaps
+                        || ranges.iter().any(|&(start, end)| {
+                            (0..version.levels.len())
+                                .any(|l| !version.overlapping_files(l, start, end).is_empty())
+                        })
+                    {
+                        return Err(Error::Engine(format!(
+                            "ingested files overlap existing data of causet_merge family {}",
+                            namespaced
+                        )));
+                    }
+                }
+                // Memtable entries are read before any file, flush them so that they
+                // do not hide the newer ingested versions. Writes may come in while
+                // the lock is released, so check again.
+                ImportMode::Overwrite if mem_overlaps => {
+                    state.freeze_memtables(dir)?;
+                    drop(state);
+                    self.flush_frozen()?;
+                    continue;
+                }
+                ImportMode::Overwrite => {}
+            }
+            break state;
+        };
+
+        // The copies are made under the state lock, so that no write nor snapshot
+        // comes between their sequence number and their installation.
+        let tombstone_seq = state.last_seq + 1;
+        let seq = if tables.iter().any(|t| !t.range_tombstones().is_empty()) {
+            tombstone_seq + 1
+        } else {
+            tombstone_seq
+        };
+        let mut files = Vec::with_capacity(tables.len());
+        for table in tables {
+            let number = state.new_file_number();
+            files.push(number);
+            let res = self
+                .create_table(number, namespaced)
+                .and_then(|mut builder| {
+                    let mut iter = TableIterator::new(table.clone(), false);
+                    iter.seek_to_first()?;
+                    while iter.valid() {
+                        let soliton_id = iter.soliton_id();
+                        builder.add(
+                            &InternalKey::new(&soliton_id.user_key, seq, soliton_id.kind),
+                            iter.causet_locale(),
+                        )?;
+                        iter.next()?;
+                    }
+                    for t in table.range_tombstones() {
+                        builder.add_range_tombstone(&RangeTombstone::new(
+                            &t.start,
+                            &t.end,
+                            tombstone_seq,
+                        ));
+                    }
+                    builder.finish()?;
+                    Ok(())
+                });
+            if let Err(e) = res {
+                remove_tables(dir, files);
+                return Err(e);
+            }
+        }
+
+        let mut version = (*state.namespaceds[namespaced].version).clone();
+        let cache = self.table_cache(&state, namespaced);
+        for number in files {
+            let file = FileMeta::open(dir, number, cache.as_ref())?;
+            let (start, end) = (&file.smallest.user_key, &file.largest.user_key);
+            let level = (1..version.levels.len())
+                .take_while(|&l| {
+                    (0..=l).all(|l| version.overlapping_files(l, start, end).is_empty())
+                })
+                .last()
+                .unwrap_or(0);
+            version.add_file(level, Arc::new(file));
+        }
+        state.last_seq = seq;
+        state.namespaceds.get_mut(namespaced).unwrap().version = Arc::new(version);
+        state.manifest().write(dir)?;
+        drop(state);
+        self.maybe_compact()
+    }
+
+    /// Drops the files of `namespaced` that lie in `[start, end)`. With
+    /// `rewrite_boundaries`, the memtables are flushed if they overlap the range,
+    /// and the files that straddle its bounds are rewritten without it, so that no
+    /// soliton_id of the range is left. Otherwise soliton_ids of the memtables and of
+    /// straddling files are kept, and older versions they hide may show again.
+    pub(crate) fn delete_files_in_range(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+        rewrite_boundaries: bool,
+    ) -> Result<()> {
+        let _jobs = self.jobs();
+        let mem_overlaps = self
+            .state()
+            .namespaced(namespaced)?
+            .memtables()
+            .any(|mem| mem.overlaps(start, end));
+        if start >= end {
+            return Ok(());
+        }
+        if rewrite_boundaries && mem_overlaps {
+            self.freeze_and_flush()?;
+        }
+
+        let dir = &self.core.local_path;
+        let current = self.current_version(namespaced)?;
+        let mut version = (*current).clone();
+        let mut removed = HashSet::new();
+        let mut created = Vec::new();
+        let res = (|| -> Result<()> {
+            for (level, files) in current.levels.iter().enumerate() {
+                let mut kept = Vec::with_capacity(files.len());
+                for f in files {
+                    if f.is_within(start, end) {
+                        removed.insert(f.number);
+                    } else if rewrite_boundaries && f.overlaps_range(start, end) {
+                        removed.insert(f.number);
+                        // The rewritten file keeps the place of the original one, its
+                        // bounds only shrink.
+                        if let Some(file) =
+                            self.rewrite_without_range(namespaced, f, start, end, &mut created)?
+                        {
+                            kept.push(Arc::new(file));
+                        }
+                    } else {
+                        kept.push(f.clone());
+                    }
+                }
+                version.levels[level] = kept;
+            }
+            Ok(())
+        })();
+        if let Err(e) = res {
+            remove_tables(dir, created);
+            return Err(e);
+        }
+        if removed.is_empty() {
+            return Ok(());
+        }
+        {
+            let mut state = self.state();
+            state.namespaceds.get_mut(namespaced).unwrap().version = Arc::new(version);
+            state.manifest().write(dir)?;
+        }
+        for number in removed {
+            fs::remove_file(table_file_path(dir, number))?;
+        }
+        Ok(())
+    }
+
+    /// Copies `file` without the soliton_ids of `[start, end)`, or returns `None` if
+    /// nothing is left.
+    fn rewrite_without_range(
+        &self,
+        namespaced: &str,
+        file: &FileMeta,
+        start: &[u8],
+        end: &[u8],
+        created: &mut Vec<u64>,
+    ) -> Result<Option<FileMeta>> {
+        let (number, mut builder) = self.new_table(namespaced)?;
+        created.push(number);
+        let mut iter = TableIterator::new(file.table.clone(), false);
+        iter.seek_to_first()?;
+        while iter.valid() {
+            let user_key = iter.soliton_id().user_key.as_slice();
+            if user_key < start || user_key >= end {
+                builder.add(iter.soliton_id(), iter.causet_locale())?;
+            }
+            iter.next()?;
+        }
+        for t in file.table.range_tombstones() {
+            if t.start.as_slice() < start {
+                let t_end = t.end.as_slice().min(start);
+                builder.add_range_tombstone(&RangeTombstone::new(&t.start, t_end, t.seq));
+            }
+            if t.end.as_slice() > end {
+                let t_start = t.start.as_slice().max(end);
+                builder.add_range_tombstone(&RangeTombstone::new(t_start, &t.end, t.seq));
+            }
+        }
+        if builder.is_empty() {
+            drop(builder);
+            fs::remove_file(table_file_path(&self.core.local_path, number))?;
+            return Ok(None);
+        }
+        builder.finish()?;
+        let cache = self.table_cache(&self.state(), namespaced);
+        FileMeta::open(&self.core.local_path, number, cache.as_ref()).map(Some)
+    }
+
+    /// How the tables of `namespaced` read through the block cache, if there is one.
+    fn table_cache(&self, state: &EngineState, namespaced: &str) -> Option<TableCache> {
+        let cache = self.options().block_cache.clone()?;
+        Some(TableCache {
+            cache,
+            counters: state.namespaceds[namespaced].cache_counters.clone(),
+        })
+    }
+
+    /// Creates the next SST of `namespaced`. Takes the state lock for its number.
+    fn new_table(&self, namespaced: &str) -> Result<(u64, TableBuilder)> {
+        let number = self.state().new_file_number();
+        Ok((number, self.create_table(number, namespaced)?))
+    }
+
+    /// Creates SST `number` of `namespaced`, with the greedoids collectors it needs.
+    fn create_table(&self, number: u64, namespaced: &str) -> Result<TableBuilder> {
+        let opts = self.options();
+        let mut builder = TableBuilder::create(
+            &table_file_path(&self.core.local_path, number),
+            opts.block_size,
+            opts.bloom_bits_per_key,
+        )?;
+        builder.set_compression(opts.compression);
+        builder.set_rate_limiter(self.core.rate_limiter.clone());
+        builder.add_collector(Box::new(RangeGreedoidsCollector::new(&opts)));
+        if opts.is_ttl_namespaced(namespaced) {
+            builder.add_collector(Box::new(TtlGreedoidsCollector::default()));
+        }
+        Ok(builder)
+    }
+
+    /// Runs the compactions the versions need. The jobs lock must be held.
+    fn maybe_compact(&self) -> Result<()> {
+        let opts = self.options();
+        if opts.disable_auto_compactions {
+            return Ok(());
+        }
+        for name in self.namespaced_names() {
+            loop {
+                let c = {
+                    let mut state = self.state();
+                    let ns = state.namespaceds.get_mut(&name).unwrap();
+                    let version = ns.version.clone();
+                    pick_compaction(&version, &opts, &mut ns.compact_pointer)
+                };
+                match c {
+                    Some(c) => self.install_compaction(&name, &c, false)?,
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compacts every file of `namespaced` that overlaps `[start, end]` (unbounded
+    /// when `None`) down to the deepest level holding such a file, dropping every
+    /// deleted and overwritten version no snapshot sees on the way.
+    pub fn compact_range_namespaced(
+        &self,
+        namespaced: &str,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+    ) -> Result<()> {
+        let _jobs = self.jobs();
+        self.check_namespaced(namespaced)?;
+        self.freeze_and_flush()?;
+        let start = start.unwrap_or(b"");
+        let overlaps = |f: &Arc<FileMeta>| {
+            !f.ends_before(start) && end.is_none_or(|e| f.smallest.user_key.as_slice() <= e)
+        };
+        let version = self.current_version(namespaced)?;
+        let deepest = (1..version.levels.len())
+            .filter(|&l| version.levels[l].iter().any(overlaps))
+            .max()
+            .unwrap_or(1);
+        for level in 0..deepest {
+            let version = self.current_version(namespaced)?;
+            let inputs: Vec<_> = if level == 0 {
+                // Level 0 files may overlap each other; moving only some of them down
+                // could put an older version above a newer one.
+                version.levels[0].clone()
+            } else {
+                version.levels[level]
+                    .iter()
+                    .filter(|f| overlaps(f))
+                    .cloned()
+                    .collect()
+            };
+            if inputs.is_empty() {
+                continue;
+            }
+            let c = Compaction::new(&version, level, inputs);
+            self.install_compaction(namespaced, &c, true)?;
+        }
+        self.maybe_compact()
+    }
+
+    pub fn compact_range(&self, start: Option<&[u8]>, end: Option<&[u8]>) -> Result<()> {
+        self.compact_range_namespaced(NAMESPACED_DEFAULT, start, end)
+    }
+
+    /// Runs `c` and replaces its inputs by its outputs. Manual compactions always
+    /// rewrite their inputs, so that every soliton_id goes through the compaction filter.
+    /// The outputs are written outside the state lock; the jobs lock must be held,
+    /// so that the inputs are still live when they are replaced.
+    fn install_compaction(&self, namespaced: &str, c: &Compaction, is_manual: bool) -> Result<()> {
+        let dir = &self.core.local_path;
+        let trivial = !is_manual && c.is_trivial_move();
+        let mut filter_stats = CompactionFilterStats::default();
+        let outputs = if trivial {
+            vec![c.inputs[0].clone()]
+        } else {
+            let (current, filter, snapshots, cache) = {
+                let state = self.state();
+                let ns = &state.namespaceds[namespaced];
+                let current = ns.version.clone();
+                let filter = ns.compaction_filter_factory.as_ref().and_then(|factory| {
+                    factory.create_compaction_filter(&CompactionFilterContext {
+                        namespaced,
+                        output_level: c.level + 1,
+                        is_bottommost_level: c.is_bottommost_level(&current),
+                        is_manual_compaction: is_manual,
+                    })
+                });
+                // Snapshots taken later see the newest version of every input, which
+                // is kept.
+                let snapshots: Vec<u64> = state.snapshots.keys().copied().collect();
+                let cache = self.table_cache(&state, namespaced);
+                (current, filter, snapshots, cache)
+            };
+            let outputs = run_compaction(
+                c,
+                &current,
+                &snapshots,
+                &self.options(),
+                dir,
+                cache.as_ref(),
+                &mut || self.new_table(namespaced),
+                filter,
+                &mut filter_stats,
+            )?;
+            outputs.into_iter().map(Arc::new).collect()
+        };
+
+        let removed: HashSet<u64> = c.all_inputs().map(|f| f.number).collect();
+        {
+            let mut state = self.state();
+            let ns = state.namespaceds.get_mut(namespaced).unwrap();
+            let mut version = (*ns.version).clone();
+            version.remove_files(&removed);
+            for output in outputs {
+                version.add_file(c.level + 1, output);
+            }
+            ns.version = Arc::new(version);
+            let stats = &mut ns.filter_stats;
+            stats.keys_filtered += filter_stats.keys_filtered;
+            stats.keys_removed += filter_stats.keys_removed;
+            stats.keys_changed += filter_stats.keys_changed;
+            state.manifest().write(dir)?;
+        }
+        if !trivial {
+            for number in removed {
+                fs::remove_file(table_file_path(dir, number))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes the tables a failed job created.
+fn remove_tables(dir: &Path, numbers: Vec<u64>) {
+    for number in numbers {
+        let _ = fs::remove_file(table_file_path(dir, number));
+    }
+}
+
+impl Iterable for LsmEngine {
+    type Iterator = LsmIterator;
+
+    fn iterator_opt(&self, namespaced: &str, opts: IterOptions) -> Result<LsmIterator> {
+        self.iterator_at(namespaced, opts, None)
+    }
+}
+
+impl Peekable for LsmEngine {
+    fn get_value_namespaced_opt(
+        &self,
+        opts: &ReadOptions,
+        namespaced: &str,
+        soliton_id: &[u8],
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_value_at(opts, namespaced, soliton_id, None)
+    }
+}
+
+impl CompactionFilterExt for LsmEngine {
+    fn set_compaction_filter_factory(
+        &self,
+        namespaced: &str,
+        factory: Option<Arc<dyn CompactionFilterFactory>>,
+    ) -> Result<()> {
+        let mut state = self.state();
+        state.namespaced(namespaced)?;
+        state
+            .namespaceds
+            .get_mut(namespaced)
+            .unwrap()
+            .compaction_filter_factory = factory;
+        Ok(())
+    }
+
+    fn compaction_filter_stats(&self, namespaced: &str) -> Result<CompactionFilterStats> {
+        Ok(self.state().namespaced(namespaced)?.filter_stats)
+    }
+}
+
+impl Drop for EngineCore {
+    fn drop(&mut self) {
+        if let Ok(state) = self.state.get_mut() {
+            let _ = state.log.sync();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::BlockCache;
+    use crate::version::MANIFEST_FILE;
+    use fdb_traits::{
+        CompactionFilter, CompactionFilterDecision, Iterator, MiscExt, SeekKey, WriteBatchExt,
+    };
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::thread;
+
+    fn small_opts() -> LsmOptions {
+        LsmOptions {
+            namespaceds: vec!["write".to_owned()],
+            write_buffer_size: 4 * 1024,
+            block_size: 512,
+            level0_file_num_compaction_trigger: 2,
+            max_bytes_for_level_base: 16 * 1024,
+            max_bytes_for_level_multiplier: 4,
+            target_file_size_base: 8 * 1024,
+            ..Default::default()
+        }
+    }
+
+    fn collect(iter: &mut LsmIterator) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut res = Vec::new();
+        iter.seek_to_first().unwrap();
+        while iter.valid() {
+            res.push((iter.soliton_id().to_vec(), iter.causet_locale().to_vec()));
+            iter.next().unwrap();
+        }
+        res
+    }
+
+    #[test]
+    fn test_read_write_recover() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        assert_eq!(einstein_merkle_tree.namespaced_names(), vec!["default"]);
+        einstein_merkle_tree.put(b"k1", b"v1").unwrap();
+        einstein_merkle_tree.put(b"k2", b"v2").unwrap();
+        einstein_merkle_tree.delete(b"k1").unwrap();
+        assert!(matches!(
+            einstein_merkle_tree.put_namespaced("write", b"k", b"v"),
+            Err(Error::NamespacedName(_))
+        ));
+
+        let mut wb = einstein_merkle_tree.write_alexandrov_poset_process();
+        wb.put(b"k3", b"v3").unwrap();
+        wb.put(b"k4", b"v4").unwrap();
+        wb.delete_range(b"k2", b"k4").unwrap();
+        assert_eq!(wb.count(), 3);
+        wb.write_opt(&WriteOptions {
+            sync: true,
+            disable_wal: false,
+        })
+        .unwrap();
+        assert_eq!(einstein_merkle_tree.get_latest_sequence_number(), 6);
+        assert_eq!(einstein_merkle_tree.get_value(b"k1").unwrap(), None);
+        assert_eq!(einstein_merkle_tree.get_value(b"k2").unwrap(), None);
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"k4").unwrap(),
+            Some(b"v4".to_vec())
+        );
+        drop(einstein_merkle_tree);
+
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        assert_eq!(einstein_merkle_tree.get_latest_sequence_number(), 6);
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(collect(&mut iter), vec![(b"k4".to_vec(), b"v4".to_vec())]);
+
+        // Writes that skipped the log only survive a flush.
+        einstein_merkle_tree.put(b"k5", b"v5").unwrap();
+        let mut wb = einstein_merkle_tree.write_alexandrov_poset_process();
+        wb.put(b"k6", b"v6").unwrap();
+        wb.write_opt(&WriteOptions {
+            sync: false,
+            disable_wal: true,
+        })
+        .unwrap();
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"k5").unwrap(),
+            Some(b"v5".to_vec())
+        );
+        assert_eq!(einstein_merkle_tree.get_value(b"k6").unwrap(), None);
+    }
+
+    #[test]
+    fn test_flush_and_compaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), small_opts()).unwrap();
+        for round in 0..5 {
+            for i in 0..500u32 {
+                let soliton_id = format!("k{:05}", i * 7 % 500);
+                let causet_locale = format!("v{}-{}", round, i);
+                einstein_merkle_tree
+                    .put_namespaced("write", soliton_id.as_bytes(), causet_locale.as_bytes())
+                    .unwrap();
+                if i % 5 == round {
+                    einstein_merkle_tree
+                        .delete_namespaced("write", soliton_id.as_bytes())
+                        .unwrap();
+                }
+            }
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+        assert!(einstein_merkle_tree.num_files_at_level("write", 0).unwrap() < 2);
+        assert!(einstein_merkle_tree.num_files_at_level("write", 1).unwrap() > 0);
+
+        let expected: BTreeMap<Vec<u8>, Vec<u8>> = {
+            let mut m = BTreeMap::new();
+            for round in 0..5 {
+                for i in 0..500u32 {
+                    let soliton_id = format!("k{:05}", i * 7 % 500).into_bytes();
+                    m.insert(soliton_id.clone(), format!("v{}-{}", round, i).into_bytes());
+                    if i % 5 == round {
+                        m.remove(&soliton_id);
+                    }
+                }
+            }
+            m
+        };
+        let check = |einstein_merkle_tree: &LsmEngine| {
+            let mut iter = einstein_merkle_tree.iterator_namespaced("write").unwrap();
+            assert_eq!(
+                collect(&mut iter),
+                expected.clone().into_iter().collect::<Vec<_>>()
+            );
+            for i in 0..500u32 {
+                let soliton_id = format!("k{:05}", i).into_bytes();
+                assert_eq!(
+                    einstein_merkle_tree
+                        .get_value_namespaced("write", &soliton_id)
+                        .unwrap()
+                        .as_ref(),
+                    expected.get(&soliton_id)
+                );
+            }
+        };
+        check(&einstein_merkle_tree);
+
+        einstein_merkle_tree
+            .compact_range_namespaced("write", None, None)
+            .unwrap();
+        assert_eq!(
+            einstein_merkle_tree.num_files_at_level("write", 0).unwrap(),
+            0
+        );
+        check(&einstein_merkle_tree);
+        drop(einstein_merkle_tree);
+
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), small_opts()).unwrap();
+        check(&einstein_merkle_tree);
+        let ssts = fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|e| {
+                e.as_ref()
+                    .unwrap()
+                    .path()
+                    .extension()
+                    .is_some_and(|e| e == "sst")
+            })
+            .count();
+        let live: usize = (0..7)
+            .map(|l| einstein_merkle_tree.num_files_at_level("write", l).unwrap())
+            .sum();
+        assert_eq!(ssts, live);
+        assert!(dir.path().join(MANIFEST_FILE).exists());
+    }
+
+    #[test]
+    fn test_iterator_is_consistent() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        einstein_merkle_tree.put(b"a", b"1").unwrap();
+        einstein_merkle_tree.put(b"c", b"3").unwrap();
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        einstein_merkle_tree.put(b"b", b"2").unwrap();
+        einstein_merkle_tree.delete(b"a").unwrap();
+        einstein_merkle_tree.flush(true).unwrap();
+        assert!(iter.seek(SeekKey::Key(b"b")).unwrap());
+        assert_eq!(iter.soliton_id(), b"c");
+        assert_eq!(
+            collect(&mut iter),
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"c".to_vec(), b"3".to_vec())
+            ]
+        );
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(
+            collect(&mut iter),
+            vec![
+                (b"b".to_vec(), b"2".to_vec()),
+                (b"c".to_vec(), b"3".to_vec())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frozen_memtables() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        einstein_merkle_tree.put(b"a", b"1").unwrap();
+        einstein_merkle_tree
+            .delete_range_namespaced(NAMESPACED_DEFAULT, b"c", b"e")
+            .unwrap();
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        let frozen = einstein_merkle_tree
+            .current_memtables(NAMESPACED_DEFAULT)
+            .unwrap();
+        einstein_merkle_tree
+            .state()
+            .freeze_memtables(dir.path())
+            .unwrap();
+        einstein_merkle_tree.put(b"b", b"2").unwrap();
+        einstein_merkle_tree.put(b"d", b"4").unwrap();
+        einstein_merkle_tree.put(b"a", b"3").unwrap();
+
+        // Writes after the freeze went to a new table, the frozen one is shared.
+        let mems = einstein_merkle_tree
+            .current_memtables(NAMESPACED_DEFAULT)
+            .unwrap();
+        assert_eq!(mems.len(), 2);
+        assert!(Arc::ptr_eq(&mems[1], &frozen[0]));
+        assert_eq!(frozen[0].iter().count(), 1);
+        assert_eq!(collect(&mut iter), vec![(b"a".to_vec(), b"1".to_vec())]);
+        let expected = vec![
+            (b"a".to_vec(), b"3".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+            (b"d".to_vec(), b"4".to_vec()),
+        ];
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(collect(&mut iter), expected);
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"a").unwrap(),
+            Some(b"3".to_vec())
+        );
+
+        einstein_merkle_tree.flush(true).unwrap();
+        assert_eq!(
+            einstein_merkle_tree
+                .current_memtables(NAMESPACED_DEFAULT)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            einstein_merkle_tree
+                .num_files_at_level(NAMESPACED_DEFAULT, 0)
+                .unwrap(),
+            2
+        );
+        let logs = fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|e| {
+                e.as_ref()
+                    .unwrap()
+                    .path()
+                    .extension()
+                    .is_some_and(|e| e == "log")
+            })
+            .count();
+        assert_eq!(logs, 1);
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(collect(&mut iter), expected);
+    }
+
+    struct PrefixFilterFactory;
+
+    struct PrefixFilter;
+
+    impl CompactionFilter for PrefixFilter {
+        fn filter(&mut self, _: usize, soliton_id: &[u8], _: &[u8]) -> CompactionFilterDecision {
+            match soliton_id.first() {
+                Some(b'x') => CompactionFilterDecision::Remove,
+                Some(b'c') => CompactionFilterDecision::ChangeValue(b"changed".to_vec()),
+                _ => CompactionFilterDecision::Keep,
+            }
+        }
+    }
+
+    impl CompactionFilterFactory for PrefixFilterFactory {
+        fn name(&self) -> &str {
+            "prefix"
+        }
+
+        fn create_compaction_filter(
+            &self,
+            context: &CompactionFilterContext<'_>,
+        ) -> Option<Box<dyn CompactionFilter>> {
+            assert!(context.is_manual_compaction);
+            assert!(context.is_bottommost_level);
+            Some(Box::new(PrefixFilter))
+        }
+    }
+
+    #[test]
+    fn test_compaction_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        for soliton_id in [&b"a"[..], b"c1", b"c2", b"x1", b"x2"] {
+            einstein_merkle_tree.put(soliton_id, b"v").unwrap();
+        }
+        einstein_merkle_tree.delete(b"c2").unwrap();
+        einstein_merkle_tree
+            .set_compaction_filter_factory(NAMESPACED_DEFAULT, Some(Arc::new(PrefixFilterFactory)))
+            .unwrap();
+        assert!(einstein_merkle_tree
+            .set_compaction_filter_factory("write", None)
+            .is_err());
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(
+            collect(&mut iter),
+            vec![
+                (b"a".to_vec(), b"v".to_vec()),
+                (b"c1".to_vec(), b"changed".to_vec()),
+            ]
+        );
+        assert_eq!(
+            einstein_merkle_tree
+                .compaction_filter_stats(NAMESPACED_DEFAULT)
+                .unwrap(),
+            CompactionFilterStats {
+                keys_filtered: 4,
+                keys_removed: 2,
+                keys_changed: 1,
+            }
+        );
+    }
+
+    /// Holds the first soliton_id of each compaction until told to go on.
+    struct GateFilterFactory {
+        started: Mutex<Sender<()>>,
+        resume: Arc<Mutex<Receiver<()>>>,
+    }
+
+    struct GateFilter {
+        started: Option<Sender<()>>,
+        resume: Arc<Mutex<Receiver<()>>>,
+    }
+
+    impl CompactionFilter for GateFilter {
+        fn filter(&mut self, _: usize, _: &[u8], _: &[u8]) -> CompactionFilterDecision {
+            if let Some(started) = self.started.take() {
+                started.send(()).unwrap();
+                self.resume.lock().unwrap().recv().unwrap();
+            }
+            CompactionFilterDecision::Keep
+        }
+    }
+
+    impl CompactionFilterFactory for GateFilterFactory {
+        fn name(&self) -> &str {
+            "gate"
+        }
+
+        fn create_compaction_filter(
+            &self,
+            _: &CompactionFilterContext<'_>,
+        ) -> Option<Box<dyn CompactionFilter>> {
+            Some(Box::new(GateFilter {
+                started: Some(self.started.lock().unwrap().clone()),
+                resume: self.resume.clone(),
+            }))
+        }
+    }
+
+    #[test]
+    fn test_compaction_outside_state_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        for soliton_id in [&b"a"[..], b"b", b"c"] {
+            einstein_merkle_tree.put(soliton_id, b"v").unwrap();
+        }
+        let (started_tx, started_rx) = channel();
+        let (resume_tx, resume_rx) = channel();
+        einstein_merkle_tree
+            .set_compaction_filter_factory(
+                NAMESPACED_DEFAULT,
+                Some(Arc::new(GateFilterFactory {
+                    started: Mutex::new(started_tx),
+                    resume: Arc::new(Mutex::new(resume_rx)),
+                })),
+            )
+            .unwrap();
+        let compaction = {
+            let einstein_merkle_tree = einstein_merkle_tree.clone();
+            thread::spawn(move || einstein_merkle_tree.compact_range(None, None))
+        };
+
+        // The compaction is writing its outputs: reads and writes go on meanwhile.
+        started_rx.recv().unwrap();
+        assert_eq!(
+            einstein_merkle_tree
+                .num_files_at_level(NAMESPACED_DEFAULT, 0)
+                .unwrap(),
+            1
+        );
+        einstein_merkle_tree.put(b"b", b"new").unwrap();
+        einstein_merkle_tree.delete(b"c").unwrap();
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"a").unwrap(),
+            Some(b"v".to_vec())
+        );
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"b").unwrap(),
+            Some(b"new".to_vec())
+        );
+        let snapshot_seq = einstein_merkle_tree.acquire_snapshot();
+        assert!(!einstein_merkle_tree.is_write_stalled());
+
+        resume_tx.send(()).unwrap();
+        compaction.join().unwrap().unwrap();
+        assert_eq!(
+            einstein_merkle_tree
+                .num_files_at_level(NAMESPACED_DEFAULT, 0)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            einstein_merkle_tree
+                .num_files_at_level(NAMESPACED_DEFAULT, 1)
+                .unwrap(),
+            1
+        );
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(
+            collect(&mut iter),
+            vec![
+                (b"a".to_vec(), b"v".to_vec()),
+                (b"b".to_vec(), b"new".to_vec())
+            ]
+        );
+        let at_snapshot = |soliton_id: &[u8]| {
+            einstein_merkle_tree
+                .get_value_at(
+                    &ReadOptions::default(),
+                    NAMESPACED_DEFAULT,
+                    soliton_id,
+                    Some(snapshot_seq),
+                )
+                .unwrap()
+        };
+        assert_eq!(at_snapshot(b"b"), Some(b"new".to_vec()));
+        assert_eq!(at_snapshot(b"c"), None);
+        einstein_merkle_tree.release_snapshot(snapshot_seq);
+    }
+
+    #[test]
+    fn test_block_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = BlockCache::new(64 * 1024, 2, 0.5);
+        let opts = LsmOptions {
+            block_cache: Some(cache.clone()),
+            ..small_opts()
+        };
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts).unwrap();
+        for i in 0..2000u32 {
+            let soliton_id = format!("k{:05}", i);
+            einstein_merkle_tree
+                .put(soliton_id.as_bytes(), b"v")
+                .unwrap();
+            einstein_merkle_tree
+                .put_namespaced("write", soliton_id.as_bytes(), b"v")
+                .unwrap();
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+        let pinned = cache.pinned_usage();
+        assert!(pinned > 0);
+        assert_eq!(cache.usage(), pinned);
+        let stats = |namespaced| {
+            let einstein_merkle_tree = &einstein_merkle_tree;
+            let base = einstein_merkle_tree.block_cache_stats(namespaced).unwrap();
+            move || {
+                let now = einstein_merkle_tree.block_cache_stats(namespaced).unwrap();
+                (now.hits - base.hits, now.misses - base.misses)
+            }
+        };
+        let (default_stats, write_stats) = (stats(NAMESPACED_DEFAULT), stats("write"));
+
+        // Reads that do not fill the cache miss every time.
+        let no_fill = ReadOptions { fill_cache: false };
+        for _ in 0..2 {
+            einstein_merkle_tree
+                .get_value_namespaced_opt(&no_fill, NAMESPACED_DEFAULT, b"k00100")
+                .unwrap();
+        }
+        assert_eq!(default_stats(), (0, 2));
+        assert_eq!(cache.usage(), pinned);
+
+        for _ in 0..3 {
+            einstein_merkle_tree.get_value(b"k00100").unwrap();
+        }
+        assert_eq!(default_stats(), (2, 3));
+        assert!(cache.usage() > pinned);
+        assert_eq!(write_stats(), (0, 0));
+
+        // Both causet_merge families scanning stay within the shared budget.
+        let mut iter = einstein_merkle_tree.iterator_namespaced("write").unwrap();
+        assert_eq!(collect(&mut iter).len(), 2000);
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        assert_eq!(collect(&mut iter).len(), 2000);
+        assert!(cache.usage() <= cache.capacity());
+        assert!(
+            einstein_merkle_tree
+                .block_cache_stats("write")
+                .unwrap()
+                .misses
+                > 0
+        );
+    }
+}
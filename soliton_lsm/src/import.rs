@@ -0,0 +1,565 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! External SST files.
+//!
+//! An external file is an ordinary table whose entries and range tombstones all
+//! have sequence number 0. Ingestion copies it into the einstein_merkle_tree, see
+//! `LsmEngine::ingest`;
+//! `LsmSstReader` reads one back, as backups do.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use fdb_traits::{
+    Compression, Error, ExternalSstFileInfo, ImportExt, IngestExternalFileOptions, Result, SstExt,
+    SstWriter,
+};
+
+use crate::codec::{crc32, InternalKey, ValueKind};
+use crate::compression::check_supported;
+use crate::engine::LsmEngine;
+use crate::iterator::InternalIterator;
+use crate::options::LsmOptions;
+use crate::range_del::RangeTombstone;
+use crate::sst::{TableBuilder, TableIterator, TableReader};
+
+pub struct LsmSstWriter {
+    local_path: PathBuf,
+    builder: TableBuilder,
+    smallest_soliton_id: Option<Vec<u8>>,
+    last_soliton_id: Option<Vec<u8>>,
+    /// The bounds of the range tombstones.
+    range_bounds: Option<(Vec<u8>, Vec<u8>)>,
+    num_entries: u64,
+}
+
+impl LsmSstWriter {
+    /// Creates the external file `local_path`, with the table options of `opts`.
+    pub fn create(
+        local_path: &Path,
+        opts: &LsmOptions,
+        compression: Compression,
+    ) -> Result<LsmSstWriter> {
+        check_supported(compression)?;
+        let mut builder =
+            TableBuilder::create(local_path, opts.block_size, opts.bloom_bits_per_key)?;
+        builder.set_compression(compression);
+        Ok(LsmSstWriter {
+            local_path: local_path.to_owned(),
+            builder,
+            smallest_soliton_id: None,
+            last_soliton_id: None,
+            range_bounds: None,
+            num_entries: 0,
+        })
+    }
+
+    /// Deletes `[begin_soliton_id, end_soliton_id)` from the data the file is
+    /// ingested over. Entries of the file itself are kept, and range deletions may
+    /// be added in any order.
+    pub fn delete_range(&mut self, begin_soliton_id: &[u8], end_soliton_id: &[u8]) -> Result<()> {
+        if begin_soliton_id >= end_soliton_id {
+            return Err(Error::Engine(format!(
+                "empty range deletion added to {}",
+                self.local_path.display()
+            )));
+        }
+        self.builder
+            .add_range_tombstone(&RangeTombstone::new(begin_soliton_id, end_soliton_id, 0));
+        let (start, end) = self
+            .range_bounds
+            .get_or_insert_with(|| (begin_soliton_id.to_vec(), end_soliton_id.to_vec()));
+        if begin_soliton_id < start.as_slice() {
+            *start = begin_soliton_id.to_vec();
+        }
+        if end_soliton_id > end.as_slice() {
+            *end = end_soliton_id.to_vec();
+        }
+        self.num_entries += 1;
+        Ok(())
+    }
+
+    fn add(&mut self, soliton_id: &[u8], kind: ValueKind, causet_locale: &[u8]) -> Result<()> {
+        if self
+            .last_soliton_id
+            .as_deref()
+            .is_some_and(|last| last >= soliton_id)
+        {
+            return Err(Error::Engine(format!(
+                "soliton_ids added to {} are not strictly ascending",
+                self.local_path.display()
+            )));
+        }
+        self.builder
+            .add(&InternalKey::new(soliton_id, 0, kind), causet_locale)?;
+        if self.smallest_soliton_id.is_none() {
+            self.smallest_soliton_id = Some(soliton_id.to_vec());
+        }
+        self.last_soliton_id = Some(soliton_id.to_vec());
+        self.num_entries += 1;
+        Ok(())
+    }
+}
+
+impl SstWriter for LsmSstWriter {
+    fn put(&mut self, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()> {
+        self.add(soliton_id, ValueKind::Put, causet_locale)
+    }
+
+    fn delete(&mut self, soliton_id: &[u8]) -> Result<()> {
+        self.add(soliton_id, ValueKind::Delete, b"")
+    }
+
+    fn file_size(&self) -> u64 {
+        self.builder.file_size()
+    }
+
+    /// The bounds of the file cover its range deletions; a largest soliton_id that
+    /// ends one is exclusive.
+    fn finish(self) -> Result<ExternalSstFileInfo> {
+        let points = self.smallest_soliton_id.zip(self.last_soliton_id);
+        let bounds = match (points, self.range_bounds) {
+            (Some((s, l)), Some((start, end))) => Some((s.min(start), l.max(end))),
+            (points, range_bounds) => points.or(range_bounds),
+        };
+        let (smallest_soliton_id, largest_soliton_id) = match bounds {
+            Some(bounds) => bounds,
+            None => {
+                drop(self.builder);
+                let _ = fs::remove_file(&self.local_path);
+                return Err(Error::Engine(format!(
+                    "external file {} has no entries",
+                    self.local_path.display()
+                )));
+            }
+        };
+        self.builder.finish()?;
+        let data = fs::read(&self.local_path)?;
+        Ok(ExternalSstFileInfo {
+            local_path: self.local_path,
+            smallest_soliton_id,
+            largest_soliton_id,
+            num_entries: self.num_entries,
+            file_size: data.len() as u64,
+            crc32: crc32(&data),
+        })
+    }
+}
+
+pub struct LsmSstReader {
+    table: Arc<TableReader>,
+}
+
+impl LsmSstReader {
+    pub fn open(local_path: &Path) -> Result<LsmSstReader> {
+        Ok(LsmSstReader {
+            table: Arc::new(TableReader::open(local_path)?),
+        })
+    }
+
+    /// Calls `f` with each entry in order: its soliton_id, and its causet_locale
+    /// or `None` for a delete, until `f` returns `false`.
+    pub fn scan(&self, mut f: impl FnMut(&[u8], Option<&[u8]>) -> Result<bool>) -> Result<()> {
+        let mut iter = TableIterator::new(self.table.clone(), false);
+        iter.seek_to_first()?;
+        while iter.valid() {
+            let soliton_id = iter.soliton_id();
+            let causet_locale = match soliton_id.kind {
+                ValueKind::Put => Some(iter.causet_locale()),
+                ValueKind::Delete => None,
+            };
+            if !f(&soliton_id.user_key, causet_locale)? {
+                break;
+            }
+            iter.next()?;
+        }
+        Ok(())
+    }
+}
+
+impl SstExt for LsmEngine {
+    type SstWriter = LsmSstWriter;
+
+    fn sst_writer(
+        &self,
+        namespaced: &str,
+        local_path: &Path,
+        compression: Compression,
+    ) -> Result<LsmSstWriter> {
+        self.check_namespaced(namespaced)?;
+        LsmSstWriter::create(local_path, &self.options(), compression)
+    }
+}
+
+/// Opens and checks an external file against its `info`.
+fn open_external_file(
+    info: &ExternalSstFileInfo,
+    opts: &IngestExternalFileOptions,
+) -> Result<TableReader> {
+    let local_path = info.local_path.display();
+    if opts.verify_checksum {
+        let data = fs::read(&info.local_path)?;
+        if data.len() as u64 != info.file_size || crc32(&data) != info.crc32 {
+            return Err(Error::Corruption(format!(
+                "external file {} does not match its checksum",
+                local_path
+            )));
+        }
+    }
+    let table = TableReader::open(&info.local_path)?;
+    let props = table.greedoids();
+    let matches = |k: &Option<InternalKey>, expected: &[u8]| {
+        k.as_ref()
+            .is_some_and(|k| k.user_key == expected && (k.seq == 0 || k.is_range_bound()))
+    };
+    if !matches(&props.smallest_key, &info.smallest_soliton_id)
+        || !matches(&props.largest_key, &info.largest_soliton_id)
+        || props.num_entries + props.num_range_deletions != info.num_entries
+        || props.largest_seq != 0
+    {
+        return Err(Error::Corruption(format!(
+            "external file {} does not match its info",
+            local_path
+        )));
+    }
+    Ok(table)
+}
+
+impl ImportExt for LsmEngine {
+    fn ingest_external_file_namespaced(
+        &self,
+        namespaced: &str,
+        opts: &IngestExternalFileOptions,
+        files: &[ExternalSstFileInfo],
+    ) -> Result<()> {
+        self.check_namespaced(namespaced)?;
+        let mut files: Vec<&ExternalSstFileInfo> = files.iter().collect();
+        files.sort_by(|a, b| a.smallest_soliton_id.cmp(&b.smallest_soliton_id));
+        for pair in files.windows(2) {
+            if pair[0].largest_soliton_id >= pair[1].smallest_soliton_id {
+                return Err(Error::Engine(format!(
+                    "external files {} and {} overlap",
+                    pair[0].local_path.display(),
+                    pair[1].local_path.display()
+                )));
+            }
+        }
+        let tables = files
+            .into_iter()
+            .map(|info| open_external_file(info, opts).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+        if tables.is_empty() {
+            return Ok(());
+        }
+        self.ingest(namespaced, opts.mode, &tables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdb_traits::{ImportMode, MiscExt, Peekable, NAMESPACED_DEFAULT};
+
+    fn write_file(
+        einstein_merkle_tree: &LsmEngine,
+        local_path: &Path,
+        compression: Compression,
+        entries: &[(&[u8], Option<&[u8]>)],
+    ) -> ExternalSstFileInfo {
+        let mut writer = einstein_merkle_tree
+            .sst_writer(NAMESPACED_DEFAULT, local_path, compression)
+            .unwrap();
+        for &(soliton_id, causet_locale) in entries {
+            match causet_locale {
+                Some(causet_locale) => writer.put(soliton_id, causet_locale).unwrap(),
+                None => writer.delete(soliton_id).unwrap(),
+            }
+        }
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn test_sst_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        let local_path = dir.path().join("external.sst");
+        let mut writer = einstein_merkle_tree
+            .sst_writer(NAMESPACED_DEFAULT, &local_path, Compression::None)
+            .unwrap();
+        writer.put(b"b", b"1").unwrap();
+        assert!(writer.put(b"b", b"2").is_err());
+        assert!(writer.put(b"a", b"2").is_err());
+        writer.delete(b"c").unwrap();
+        let info = writer.finish().unwrap();
+        assert_eq!(info.smallest_soliton_id, b"b");
+        assert_eq!(info.largest_soliton_id, b"c");
+        assert_eq!(info.num_entries, 2);
+        assert_eq!(info.file_size, fs::metadata(&local_path).unwrap().len());
+        let mut entries = Vec::new();
+        LsmSstReader::open(&local_path)
+            .unwrap()
+            .scan(|k, v| {
+                entries.push((k.to_vec(), v.map(|v| v.to_vec())));
+                Ok(true)
+            })
+            .unwrap();
+        assert_eq!(
+            entries,
+            vec![(b"b".to_vec(), Some(b"1".to_vec())), (b"c".to_vec(), None)]
+        );
+
+        let empty = einstein_merkle_tree
+            .sst_writer(NAMESPACED_DEFAULT, &local_path, Compression::None)
+            .unwrap();
+        assert!(empty.finish().is_err());
+        assert!(!local_path.exists());
+        assert!(einstein_merkle_tree
+            .sst_writer(NAMESPACED_DEFAULT, &local_path, Compression::Zstd)
+            .is_err());
+        assert!(einstein_merkle_tree
+            .sst_writer("missing", &local_path, Compression::None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_ingest_external_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let ext = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        einstein_merkle_tree.put(b"a", b"old").unwrap();
+        einstein_merkle_tree.put(b"k", b"old").unwrap();
+        einstein_merkle_tree.flush(true).unwrap();
+        einstein_merkle_tree.put(b"m", b"old").unwrap();
+
+        let opts = IngestExternalFileOptions {
+            verify_checksum: true,
+            ..Default::default()
+        };
+        let values: Vec<Vec<u8>> = (0..1000)
+            .map(|i| format!("causet_locale{:04}", i % 10).into_bytes())
+            .collect();
+        let soliton_ids: Vec<Vec<u8>> = (0..1000)
+            .map(|i| format!("x{:04}", i).into_bytes())
+            .collect();
+        let entries: Vec<(&[u8], Option<&[u8]>)> = soliton_ids
+            .iter()
+            .zip(&values)
+            .map(|(k, v)| (k.as_slice(), Some(v.as_slice())))
+            .collect();
+        let fresh = write_file(
+            &einstein_merkle_tree,
+            &ext.path().join("1.sst"),
+            Compression::Snappy,
+            &entries,
+        );
+        let compressed = fresh.file_size;
+        let conflict = write_file(
+            &einstein_merkle_tree,
+            &ext.path().join("2.sst"),
+            Compression::Lz4,
+            &[(b"b", Some(b"new")), (b"k", Some(b"new")), (b"m", None)],
+        );
+        // Either all files are ingested or none is.
+        assert!(einstein_merkle_tree
+            .ingest_external_file_namespaced(
+                NAMESPACED_DEFAULT,
+                &opts,
+                &[fresh.clone(), conflict.clone()]
+            )
+            .is_err());
+        assert_eq!(einstein_merkle_tree.get_value(b"x0000").unwrap(), None);
+        assert!(einstein_merkle_tree
+            .ingest_external_file_namespaced(
+                NAMESPACED_DEFAULT,
+                &opts,
+                &[fresh.clone(), fresh.clone()]
+            )
+            .is_err());
+
+        let seq = einstein_merkle_tree.get_latest_sequence_number();
+        einstein_merkle_tree
+            .ingest_external_file_namespaced(NAMESPACED_DEFAULT, &opts, &[fresh])
+            .unwrap();
+        assert_eq!(einstein_merkle_tree.get_latest_sequence_number(), seq + 1);
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"x0123").unwrap(),
+            Some(b"causet_locale0003".to_vec())
+        );
+        let uncompressed = write_file(
+            &einstein_merkle_tree,
+            &ext.path().join("3.sst"),
+            Compression::None,
+            &entries,
+        );
+        assert!(compressed < uncompressed.file_size);
+
+        let overwrite = IngestExternalFileOptions {
+            mode: ImportMode::Overwrite,
+            ..opts.clone()
+        };
+        einstein_merkle_tree
+            .ingest_external_file_namespaced(NAMESPACED_DEFAULT, &overwrite, &[conflict])
+            .unwrap();
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"a").unwrap(),
+            Some(b"old".to_vec())
+        );
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"b").unwrap(),
+            Some(b"new".to_vec())
+        );
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"k").unwrap(),
+            Some(b"new".to_vec())
+        );
+        assert_eq!(einstein_merkle_tree.get_value(b"m").unwrap(), None);
+        // Newer writes shadow ingested causet_locales.
+        einstein_merkle_tree.put(b"b", b"newer").unwrap();
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"b").unwrap(),
+            Some(b"newer".to_vec())
+        );
+
+        // Ingested files survive a restart and compaction.
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"k").unwrap(),
+            Some(b"new".to_vec())
+        );
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"b").unwrap(),
+            Some(b"newer".to_vec())
+        );
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"x0999").unwrap(),
+            Some(b"causet_locale0009".to_vec())
+        );
+        assert_eq!(einstein_merkle_tree.get_value(b"m").unwrap(), None);
+    }
+
+    #[test]
+    fn test_ingest_range_tombstones() {
+        let dir = tempfile::tempdir().unwrap();
+        let ext = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        for soliton_id in [&b"a"[..], b"b", b"c", b"d"] {
+            einstein_merkle_tree.put(soliton_id, b"old").unwrap();
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+        einstein_merkle_tree.put(b"bb", b"old").unwrap();
+
+        let local_path = ext.path().join("1.sst");
+        let mut writer = einstein_merkle_tree
+            .sst_writer(NAMESPACED_DEFAULT, &local_path, Compression::None)
+            .unwrap();
+        writer.put(b"c", b"new").unwrap();
+        writer.delete_range(b"b", b"d").unwrap();
+        assert!(writer.delete_range(b"d", b"d").is_err());
+        let info = writer.finish().unwrap();
+        assert_eq!(info.smallest_soliton_id, b"b");
+        assert_eq!(info.largest_soliton_id, b"d");
+        assert_eq!(info.num_entries, 2);
+
+        let opts = IngestExternalFileOptions {
+            verify_checksum: true,
+            ..Default::default()
+        };
+        assert!(einstein_merkle_tree
+            .ingest_external_file_namespaced(NAMESPACED_DEFAULT, &opts, std::slice::from_ref(&info))
+            .is_err());
+        let overwrite = IngestExternalFileOptions {
+            mode: ImportMode::Overwrite,
+            ..opts.clone()
+        };
+        let seq = einstein_merkle_tree.get_latest_sequence_number();
+        einstein_merkle_tree
+            .ingest_external_file_namespaced(NAMESPACED_DEFAULT, &overwrite, &[info])
+            .unwrap();
+        // The tombstone deletes what the file is ingested over, not the file itself.
+        assert_eq!(einstein_merkle_tree.get_latest_sequence_number(), seq + 2);
+        let expected = [
+            (&b"a"[..], Some(&b"old"[..])),
+            (b"b", None),
+            (b"bb", None),
+            (b"c", Some(b"new")),
+            (b"d", Some(b"old")),
+        ];
+        let check = |einstein_merkle_tree: &LsmEngine| {
+            for (soliton_id, causet_locale) in expected {
+                assert_eq!(
+                    einstein_merkle_tree
+                        .get_value(soliton_id)
+                        .unwrap()
+                        .as_deref(),
+                    causet_locale,
+                    "{:?}",
+                    soliton_id
+                );
+            }
+        };
+        check(&einstein_merkle_tree);
+        let version = einstein_merkle_tree
+            .current_version(NAMESPACED_DEFAULT)
+            .unwrap();
+        assert_eq!(
+            version
+                .levels
+                .iter()
+                .flatten()
+                .map(|f| f.table.range_tombstones().len())
+                .sum::<usize>(),
+            1
+        );
+
+        // A file of range deletions only.
+        let local_path = ext.path().join("2.sst");
+        let mut writer = einstein_merkle_tree
+            .sst_writer(NAMESPACED_DEFAULT, &local_path, Compression::None)
+            .unwrap();
+        writer.delete_range(b"x", b"z").unwrap();
+        let info = writer.finish().unwrap();
+        einstein_merkle_tree
+            .ingest_external_file_namespaced(NAMESPACED_DEFAULT, &opts, &[info])
+            .unwrap();
+
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        check(&einstein_merkle_tree);
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        check(&einstein_merkle_tree);
+    }
+
+    #[test]
+    fn test_ingest_checksum_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        let local_path = dir.path().join("external.sst");
+        let mut info = write_file(
+            &einstein_merkle_tree,
+            &local_path,
+            Compression::None,
+            &[(b"a", Some(b"1"))],
+        );
+        info.crc32 ^= 1;
+        let opts = IngestExternalFileOptions {
+            verify_checksum: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            einstein_merkle_tree.ingest_external_file_namespaced(
+                NAMESPACED_DEFAULT,
+                &opts,
+                &[info.clone()]
+            ),
+            Err(Error::Corruption(_))
+        ));
+        info.largest_soliton_id = b"b".to_vec();
+        assert!(einstein_merkle_tree
+            .ingest_external_file_namespaced(NAMESPACED_DEFAULT, &Default::default(), &[info])
+            .is_err());
+        assert_eq!(einstein_merkle_tree.get_value(b"a").unwrap(), None);
+    }
+}
@@ -0,0 +1,661 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::ops::Bound;
+use std::sync::Arc;
+
+use fdb_traits::{IterOptions, Result, SeekKey};
+
+use crate::codec::{InternalKey, ValueKind};
+use crate::memtable::Memtable;
+use crate::range_del::RangeDelAggregator;
+
+/// An iterator over internal soliton_ids, i.e. over every version of every soliton_id.
+pub trait InternalIterator: Send {
+    fn valid(&self) -> bool;
+
+    fn seek_to_first(&mut self) -> Result<()>;
+
+    /// Positions at the first entry `>= target`.
+    fn seek(&mut self, target: &InternalKey) -> Result<()>;
+
+    fn seek_to_last(&mut self) -> Result<()>;
+
+    /// Positions at the last entry `<= target`.
+    fn seek_for_prev(&mut self, target: &InternalKey) -> Result<()>;
+
+    fn next(&mut self) -> Result<()>;
+
+    fn prev(&mut self) -> Result<()>;
+
+    fn soliton_id(&self) -> &InternalKey;
+
+    fn causet_locale(&self) -> &[u8];
+
+    /// An upper bound of the sequence numbers of the entries of the iterator.
+    fn largest_seq(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+pub struct MemtableIterator {
+    mem: Arc<Memtable>,
+    current: Option<(InternalKey, Vec<u8>)>,
+}
+
+impl MemtableIterator {
+    pub fn new(mem: Arc<Memtable>) -> MemtableIterator {
+        MemtableIterator { mem, current: None }
+    }
+
+    fn set(&mut self, bound: Bound<&InternalKey>) {
+        self.current = self
+            .mem
+            .next_entry(bound)
+            .map(|(k, v)| (k.clone(), v.clone()));
+    }
+
+    fn set_back(&mut self, bound: Bound<&InternalKey>) {
+        self.current = self
+            .mem
+            .prev_entry(bound)
+            .map(|(k, v)| (k.clone(), v.clone()));
+    }
+}
+
+impl InternalIterator for MemtableIterator {
+    fn valid(&self) -> bool {
+        self.current.is_some()
+    }
+
+    fn seek_to_first(&mut self) -> Result<()> {
+        self.set(Bound::Unbounded);
+        Ok(())
+    }
+
+    fn seek(&mut self, target: &InternalKey) -> Result<()> {
+        self.set(Bound::Included(target));
+        Ok(())
+    }
+
+    fn seek_to_last(&mut self) -> Result<()> {
+        self.set_back(Bound::Unbounded);
+        Ok(())
+    }
+
+    fn seek_for_prev(&mut self, target: &InternalKey) -> Result<()> {
+        self.set_back(Bound::Included(target));
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        let current = self.current.take().unwrap().0;
+        self.set(Bound::Excluded(&current));
+        Ok(())
+    }
+
+    fn prev(&mut self) -> Result<()> {
+        let current = self.current.take().unwrap().0;
+        self.set_back(Bound::Excluded(&current));
+        Ok(())
+    }
+
+    fn soliton_id(&self) -> &InternalKey {
+        &self.current.as_ref().unwrap().0
+    }
+
+    fn causet_locale(&self) -> &[u8] {
+        &self.current.as_ref().unwrap().1
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Reverse,
+}
+
+/// Merges several sorted internal iterators into one.
+///
+/// Moving forward, every child is positioned at its first entry after the previous
+/// one returned; moving backward, at its last entry before it. Changing direction
+/// repositions the children.
+pub struct MergingIterator {
+    children: Vec<Box<dyn InternalIterator>>,
+    current: Option<usize>,
+    direction: Direction,
+}
+
+impl MergingIterator {
+    pub fn new(children: Vec<Box<dyn InternalIterator>>) -> MergingIterator {
+        MergingIterator {
+            children,
+            current: None,
+            direction: Direction::Forward,
+        }
+    }
+
+    fn find_smallest(&mut self) {
+        self.current = None;
+        for (i, child) in self.children.iter().enumerate() {
+            if !child.valid() {
+                continue;
+            }
+            match self.current {
+                Some(c) if self.children[c].soliton_id() <= child.soliton_id() => {}
+                _ => self.current = Some(i),
+            }
+        }
+    }
+
+    fn find_largest(&mut self) {
+        self.current = None;
+        for (i, child) in self.children.iter().enumerate() {
+            if !child.valid() {
+                continue;
+            }
+            match self.current {
+                Some(c) if self.children[c].soliton_id() >= child.soliton_id() => {}
+                _ => self.current = Some(i),
+            }
+        }
+    }
+}
+
+impl MergingIterator {
+    /// Seeks to `target` the children positioned before it whose entries are all
+    /// older than `seq`. Only used moving forward. A range tombstone written at `seq` and covering the soliton_ids
+    /// up to `target` hides what they skip.
+    pub fn skip_older(&mut self, target: &InternalKey, seq: u64) -> Result<()> {
+        for child in &mut self.children {
+            if child.valid() && child.largest_seq() < seq && child.soliton_id() < target {
+                child.seek(target)?;
+            }
+        }
+        self.find_smallest();
+        Ok(())
+    }
+}
+
+impl InternalIterator for MergingIterator {
+    fn valid(&self) -> bool {
+        self.current.is_some()
+    }
+
+    fn seek_to_first(&mut self) -> Result<()> {
+        for child in &mut self.children {
+            child.seek_to_first()?;
+        }
+        self.direction = Direction::Forward;
+        self.find_smallest();
+        Ok(())
+    }
+
+    fn seek(&mut self, target: &InternalKey) -> Result<()> {
+        for child in &mut self.children {
+            child.seek(target)?;
+        }
+        self.direction = Direction::Forward;
+        self.find_smallest();
+        Ok(())
+    }
+
+    fn seek_to_last(&mut self) -> Result<()> {
+        for child in &mut self.children {
+            child.seek_to_last()?;
+        }
+        self.direction = Direction::Reverse;
+        self.find_largest();
+        Ok(())
+    }
+
+    fn seek_for_prev(&mut self, target: &InternalKey) -> Result<()> {
+        for child in &mut self.children {
+            child.seek_for_prev(target)?;
+        }
+        self.direction = Direction::Reverse;
+        self.find_largest();
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        let current = self.current.unwrap();
+        if self.direction == Direction::Reverse {
+            let soliton_id = self.children[current].soliton_id().clone();
+            for (i, child) in self.children.iter_mut().enumerate() {
+                if i != current {
+                    child.seek(&soliton_id)?;
+                    if child.valid() && child.soliton_id() == &soliton_id {
+                        child.next()?;
+                    }
+                }
+            }
+            self.direction = Direction::Forward;
+        }
+        self.children[current].next()?;
+        self.find_smallest();
+        Ok(())
+    }
+
+    fn prev(&mut self) -> Result<()> {
+        let current = self.current.unwrap();
+        if self.direction == Direction::Forward {
+            let soliton_id = self.children[current].soliton_id().clone();
+            for (i, child) in self.children.iter_mut().enumerate() {
+                if i != current {
+                    child.seek_for_prev(&soliton_id)?;
+                    if child.valid() && child.soliton_id() == &soliton_id {
+                        child.prev()?;
+                    }
+                }
+            }
+            self.direction = Direction::Reverse;
+        }
+        self.children[current].prev()?;
+        self.find_largest();
+        Ok(())
+    }
+
+    fn soliton_id(&self) -> &InternalKey {
+        self.children[self.current.unwrap()].soliton_id()
+    }
+
+    fn causet_locale(&self) -> &[u8] {
+        self.children[self.current.unwrap()].causet_locale()
+    }
+}
+
+/// Iterates over the live soliton_ids visible at a sequence number: the newest version
+/// of each soliton_id is returned unless it is a deletion or a newer range tombstone
+/// covers it.
+///
+/// Moving forward, `inner` is positioned at the version returned; moving backward,
+/// at the last entry before the soliton_id returned, since every version of a
+/// soliton_id has to be read to find its newest visible one.
+pub struct LsmIterator {
+    inner: MergingIterator,
+    range_dels: RangeDelAggregator,
+    read_seq: u64,
+    lower_bound: Option<Vec<u8>>,
+    upper_bound: Option<Vec<u8>>,
+    /// With `prefix_same_as_start`, the length of the prefixes and the prefix the
+    /// iterator stays within since its last seek.
+    prefix_len: Option<usize>,
+    prefix: Option<Vec<u8>>,
+    key_only: bool,
+    direction: Direction,
+    valid: bool,
+    soliton_id: Vec<u8>,
+    causet_locale: Vec<u8>,
+}
+
+impl LsmIterator {
+    pub(crate) fn new(
+        inner: MergingIterator,
+        range_dels: RangeDelAggregator,
+        read_seq: u64,
+        opts: IterOptions,
+        prefix_len: Option<usize>,
+    ) -> LsmIterator {
+        LsmIterator {
+            inner,
+            range_dels,
+            read_seq,
+            lower_bound: opts.lower_bound,
+            upper_bound: opts.upper_bound,
+            prefix_len: prefix_len.filter(|_| opts.prefix_same_as_start),
+            prefix: None,
+            key_only: opts.key_only,
+            direction: Direction::Forward,
+            valid: false,
+            soliton_id: Vec::new(),
+            causet_locale: Vec::new(),
+        }
+    }
+
+    fn prefix_of<'a>(&self, soliton_id: &'a [u8]) -> Option<&'a [u8]> {
+        self.prefix_len
+            .map(|n| &soliton_id[..n.min(soliton_id.len())])
+    }
+
+    fn out_of_prefix(&self, user_key: &[u8]) -> bool {
+        self.prefix
+            .as_deref()
+            .is_some_and(|p| self.prefix_of(user_key) != Some(p))
+    }
+
+    fn past_upper_bound(&self, user_key: &[u8]) -> bool {
+        self.upper_bound.as_deref().is_some_and(|b| user_key >= b) || self.out_of_prefix(user_key)
+    }
+
+    fn before_lower_bound(&self, user_key: &[u8]) -> bool {
+        self.lower_bound.as_deref().is_some_and(|b| user_key < b) || self.out_of_prefix(user_key)
+    }
+
+    fn set_causet_locale(&mut self, causet_locale: &[u8]) {
+        self.causet_locale.clear();
+        if !self.key_only {
+            self.causet_locale.extend_from_slice(causet_locale);
+        }
+    }
+
+    /// Skips forward to the next visible entry whose user soliton_id is not `skip`.
+    fn find_next_user_entry(&mut self, mut skip: Option<Vec<u8>>) -> Result<bool> {
+        while self.inner.valid() {
+            let k = self.inner.soliton_id();
+            if self.past_upper_bound(&k.user_key) {
+                break;
+            }
+            if k.seq > self.read_seq || skip.as_deref() == Some(k.user_key.as_slice()) {
+                self.inner.next()?;
+                continue;
+            }
+            match k.kind {
+                ValueKind::Delete => {
+                    skip = Some(k.user_key.clone());
+                    self.inner.next()?;
+                }
+                ValueKind::Put => {
+                    if let Some((seq, end)) = self.range_dels.covering(&k.user_key, self.read_seq) {
+                        if seq > k.seq {
+                            // Tables older than the tombstone have nothing to show
+                            // before its end.
+                            skip = Some(k.user_key.clone());
+                            let target = InternalKey::range_bound(end);
+                            self.inner.skip_older(&target, seq)?;
+                            continue;
+                        }
+                    }
+                    self.soliton_id = k.user_key.clone();
+                    let causet_locale = self.inner.causet_locale().to_vec();
+                    self.set_causet_locale(&causet_locale);
+                    self.valid = true;
+                    return Ok(true);
+                }
+            }
+        }
+        self.valid = false;
+        Ok(false)
+    }
+
+    /// Moves backward to the previous user soliton_id with a visible entry, reading
+    /// all of its versions.
+    fn find_prev_user_entry(&mut self) -> Result<bool> {
+        while self.inner.valid() {
+            let user_key = self.inner.soliton_id().user_key.clone();
+            if self.before_lower_bound(&user_key) {
+                break;
+            }
+            // Versions come oldest first, the last visible one is the newest.
+            let mut newest = None;
+            while self.inner.valid() && self.inner.soliton_id().user_key == user_key {
+                let k = self.inner.soliton_id();
+                if k.seq <= self.read_seq {
+                    newest = Some((k.seq, k.kind));
+                    if k.kind == ValueKind::Put {
+                        let causet_locale = self.inner.causet_locale().to_vec();
+                        self.set_causet_locale(&causet_locale);
+                    }
+                }
+                self.inner.prev()?;
+            }
+            if let Some((seq, ValueKind::Put)) = newest {
+                let covered = self
+                    .range_dels
+                    .covering(&user_key, self.read_seq)
+                    .is_some_and(|(t, _)| t > seq);
+                if !covered {
+                    self.soliton_id = user_key;
+                    self.valid = true;
+                    return Ok(true);
+                }
+            }
+        }
+        self.valid = false;
+        Ok(false)
+    }
+
+    /// Positions at the first visible soliton_id `>= target`, within the bounds.
+    fn seek_forward(&mut self, target: Option<&[u8]>) -> Result<bool> {
+        self.direction = Direction::Forward;
+        let target = match (target, self.lower_bound.as_deref()) {
+            (Some(t), Some(b)) => Some(t.max(b)),
+            (t, b) => t.or(b),
+        };
+        match target {
+            Some(t) => self.inner.seek(&InternalKey::lookup(t, self.read_seq))?,
+            None => self.inner.seek_to_first()?,
+        }
+        self.find_next_user_entry(None)
+    }
+
+    /// Positions at the last visible soliton_id `<= target`, within the bounds.
+    fn seek_backward(&mut self, target: Option<&[u8]>) -> Result<bool> {
+        self.direction = Direction::Reverse;
+        match (target, self.upper_bound.as_deref()) {
+            (Some(t), Some(b)) if t < b => self.inner.seek_for_prev(&InternalKey::last_bound(t))?,
+            (Some(t), None) => self.inner.seek_for_prev(&InternalKey::last_bound(t))?,
+            (_, Some(b)) => self.inner.seek_for_prev(&InternalKey::range_bound(b))?,
+            (None, None) => self.inner.seek_to_last()?,
+        }
+        self.find_prev_user_entry()
+    }
+
+    /// Starts a seek: with a target, the iterator stays within its prefix; without
+    /// one, within the prefix of the soliton_id it lands at.
+    fn seek_with_prefix(&mut self, target: Option<&[u8]>, forward: bool) -> Result<bool> {
+        self.prefix = target.and_then(|t| self.prefix_of(t)).map(<[u8]>::to_vec);
+        let valid = if forward {
+            self.seek_forward(target)?
+        } else {
+            self.seek_backward(target)?
+        };
+        if valid && self.prefix.is_none() {
+            self.prefix = self.prefix_of(&self.soliton_id).map(<[u8]>::to_vec);
+        }
+        Ok(valid)
+    }
+}
+
+impl fdb_traits::Iterator for LsmIterator {
+    fn seek(&mut self, soliton_id: SeekKey<'_>) -> Result<bool> {
+        match soliton_id {
+            SeekKey::Start => self.seek_with_prefix(None, true),
+            SeekKey::End => self.seek_with_prefix(None, false),
+            SeekKey::Key(k) => self.seek_with_prefix(Some(k), true),
+        }
+    }
+
+    fn seek_for_prev(&mut self, soliton_id: SeekKey<'_>) -> Result<bool> {
+        match soliton_id {
+            SeekKey::Start => self.seek_with_prefix(None, true),
+            SeekKey::End => self.seek_with_prefix(None, false),
+            SeekKey::Key(k) => self.seek_with_prefix(Some(k), false),
+        }
+    }
+
+    fn prev(&mut self) -> Result<bool> {
+        assert!(self.valid, "prev on an invalid iterator");
+        let current = std::mem::take(&mut self.soliton_id);
+        if self.direction == Direction::Forward {
+            self.direction = Direction::Reverse;
+            self.inner
+                .seek_for_prev(&InternalKey::range_bound(&current))?;
+        }
+        self.find_prev_user_entry()
+    }
+
+    fn next(&mut self) -> Result<bool> {
+        assert!(self.valid, "next on an invalid iterator");
+        let current = std::mem::take(&mut self.soliton_id);
+        if self.direction == Direction::Reverse {
+            self.direction = Direction::Forward;
+            self.inner.seek(&InternalKey::lookup(&current, u64::MAX))?;
+        } else {
+            self.inner.next()?;
+        }
+        self.find_next_user_entry(Some(current))
+    }
+
+    fn valid(&self) -> bool {
+        self.valid
+    }
+
+    fn soliton_id(&self) -> &[u8] {
+        assert!(self.valid);
+        &self.soliton_id
+    }
+
+    fn causet_locale(&self) -> &[u8] {
+        assert!(self.valid);
+        &self.causet_locale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use fdb_traits::{Iterable, Iterator, MiscExt};
+
+    use super::*;
+    use crate::engine::LsmEngine;
+    use crate::options::{LsmOptions, NAMESPACED_DEFAULT};
+
+    fn collect(iter: &mut LsmIterator, forward: bool) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut res = Vec::new();
+        let mut valid = if forward {
+            iter.seek_to_first().unwrap()
+        } else {
+            iter.seek_to_last().unwrap()
+        };
+        while valid {
+            res.push((iter.soliton_id().to_vec(), iter.causet_locale().to_vec()));
+            valid = if forward {
+                iter.next().unwrap()
+            } else {
+                iter.prev().unwrap()
+            };
+        }
+        res
+    }
+
+    fn soliton_id(i: usize) -> Vec<u8> {
+        format!("k{:02}", i).into_bytes()
+    }
+
+    #[test]
+    fn test_iterator_directions_and_bounds() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        // Versions spread over the last level, level 0 and the memtable.
+        let mut model = BTreeMap::new();
+        for i in 0..20 {
+            einstein_merkle_tree.put(&soliton_id(i), b"v1").unwrap();
+            model.insert(soliton_id(i), b"v1".to_vec());
+        }
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        for i in (0..20).step_by(2) {
+            einstein_merkle_tree.put(&soliton_id(i), b"v2").unwrap();
+            model.insert(soliton_id(i), b"v2".to_vec());
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+        einstein_merkle_tree.delete(&soliton_id(5)).unwrap();
+        einstein_merkle_tree
+            .delete_range_namespaced(NAMESPACED_DEFAULT, &soliton_id(10), &soliton_id(13))
+            .unwrap();
+        einstein_merkle_tree.put(&soliton_id(11), b"v3").unwrap();
+        for i in [5, 10, 12] {
+            model.remove(&soliton_id(i));
+        }
+        model.insert(soliton_id(11), b"v3".to_vec());
+
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        let expected: Vec<_> = model.clone().into_iter().collect();
+        assert_eq!(collect(&mut iter, true), expected);
+        let reversed: Vec<_> = expected.iter().rev().cloned().collect();
+        assert_eq!(collect(&mut iter, false), reversed);
+
+        assert!(iter.seek(SeekKey::Key(b"k055")).unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(6));
+        assert!(iter.seek_for_prev(SeekKey::Key(b"k055")).unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(4));
+        assert!(iter.seek_for_prev(SeekKey::Key(&soliton_id(13))).unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(13));
+        assert!(iter.prev().unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(11));
+        assert!(iter.next().unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(13));
+        assert!(iter.prev().unwrap());
+        assert!(iter.prev().unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(9));
+        assert!(!iter.seek_for_prev(SeekKey::Key(b"a")).unwrap());
+        assert!(!iter.seek(SeekKey::Key(b"z")).unwrap());
+
+        let mut opts = IterOptions::new(Some(soliton_id(3)), Some(soliton_id(12)));
+        opts.set_key_only(true);
+        let mut iter = einstein_merkle_tree
+            .iterator_opt(NAMESPACED_DEFAULT, opts)
+            .unwrap();
+        let expected: Vec<_> = model
+            .range(soliton_id(3)..soliton_id(12))
+            .map(|(k, _)| (k.clone(), Vec::new()))
+            .collect();
+        assert_eq!(collect(&mut iter, true), expected);
+        let reversed: Vec<_> = expected.iter().rev().cloned().collect();
+        assert_eq!(collect(&mut iter, false), reversed);
+        assert!(iter.seek(SeekKey::Key(b"a")).unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(3));
+        assert!(iter.seek_for_prev(SeekKey::Key(b"z")).unwrap());
+        assert_eq!(iter.soliton_id(), soliton_id(11));
+        assert!(!iter.seek(SeekKey::Key(&soliton_id(12))).unwrap());
+    }
+
+    #[test]
+    fn test_prefix_same_as_start() {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = LsmOptions {
+            prefix_extractor_len: Some(1),
+            ..Default::default()
+        };
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts).unwrap();
+        for soliton_id in [&b"a1"[..], b"b1", b"b2", b"c1"] {
+            einstein_merkle_tree.put(soliton_id, b"v").unwrap();
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+        einstein_merkle_tree.put(b"b3", b"v").unwrap();
+
+        let mut opts = IterOptions::default();
+        opts.set_prefix_same_as_start(true);
+        let mut iter = einstein_merkle_tree
+            .iterator_opt(NAMESPACED_DEFAULT, opts)
+            .unwrap();
+        let mut soliton_ids = Vec::new();
+        let mut valid = iter.seek(SeekKey::Key(b"b")).unwrap();
+        while valid {
+            soliton_ids.push(iter.soliton_id().to_vec());
+            valid = iter.next().unwrap();
+        }
+        assert_eq!(
+            soliton_ids,
+            vec![b"b1".to_vec(), b"b2".to_vec(), b"b3".to_vec()]
+        );
+        assert!(!iter.seek(SeekKey::Key(b"b4")).unwrap());
+        assert!(iter.seek_for_prev(SeekKey::Key(b"b4")).unwrap());
+        assert_eq!(iter.soliton_id(), b"b3");
+        assert!(iter.seek_to_last().unwrap());
+        assert_eq!(iter.soliton_id(), b"c1");
+        assert!(!iter.prev().unwrap());
+
+        // Without a prefix extractor the option is ignored.
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), LsmOptions::default()).unwrap();
+        einstein_merkle_tree.put(b"a1", b"v").unwrap();
+        einstein_merkle_tree.put(b"b1", b"v").unwrap();
+        let mut opts = IterOptions::default();
+        opts.set_prefix_same_as_start(true);
+        let mut iter = einstein_merkle_tree
+            .iterator_opt(NAMESPACED_DEFAULT, opts)
+            .unwrap();
+        assert_eq!(collect(&mut iter, true).len(), 2);
+    }
+}
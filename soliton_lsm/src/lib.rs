@@ -0,0 +1,39 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A native log-structured merge tree storage einstein_merkle_tree.
+//!
+//! `LsmEngine` persists writes to a write-ahead log, buffers them in a memtable per
+//! causet_merge family and flushes full memtables to sorted string tables, which
+//! are then compacted level by level.
+
+mod bloom;
+mod cache;
+mod codec;
+mod compaction;
+mod compression;
+mod config;
+mod engine;
+mod import;
+mod iterator;
+mod memtable;
+mod misc;
+mod options;
+mod range_del;
+mod range_greedoids;
+mod rate_limiter;
+mod snapshot;
+mod sst;
+mod ttl;
+mod version;
+mod wal;
+mod write_batch;
+
+pub use crate::cache::{BlockCache, BlockCacheStats};
+pub use crate::config::{diff_options, load_options, OPTIONS_FILE};
+pub use crate::engine::LsmEngine;
+pub use crate::import::{LsmSstReader, LsmSstWriter};
+pub use crate::iterator::LsmIterator;
+pub use crate::options::{LsmOptions, NAMESPACED_DEFAULT};
+pub use crate::snapshot::LsmSnapshot;
+pub use crate::sst::TableGreedoids;
+pub use crate::write_batch::LsmWriteBatch;
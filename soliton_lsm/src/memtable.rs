@@ -0,0 +1,179 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::ops::Bound;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crossbeam_skiplist::SkipMap;
+
+use crate::codec::{InternalKey, ValueKind};
+use crate::range_del::{FragmentedRangeTombstones, RangeTombstone};
+
+/// Per-entry bookkeeping added to the approximate memtable size.
+const ENTRY_OVERHEAD: usize = 32;
+
+/// The in-memory write buffer of a causet_merge family.
+///
+/// Entries live in a concurrent skiplist, so writes go into the table readers and
+/// iterators share through an `Arc`; these only see the sequence numbers they read
+/// at. Once full, the table is frozen: it is moved to the immutable list of its
+/// causet_merge family, where it is only read until it is flushed.
+#[derive(Default, Debug)]
+pub struct Memtable {
+    entries: SkipMap<InternalKey, Vec<u8>>,
+    range_tombstones: RwLock<RangeTombstones>,
+    approximate_size: AtomicUsize,
+}
+
+#[derive(Default, Debug)]
+struct RangeTombstones {
+    tombstones: Vec<RangeTombstone>,
+    /// `tombstones`, fragmented again after each range deletion.
+    fragmented: Arc<FragmentedRangeTombstones>,
+}
+
+/// The result of looking a user soliton_id up in one level of the tree.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Lookup {
+    Found(Vec<u8>),
+    Deleted,
+    NotFound,
+}
+
+impl Memtable {
+    pub fn new() -> Memtable {
+        Memtable::default()
+    }
+
+    pub fn add(&self, seq: u64, kind: ValueKind, user_key: &[u8], causet_locale: &[u8]) {
+        self.approximate_size.fetch_add(
+            user_key.len() + causet_locale.len() + ENTRY_OVERHEAD,
+            Ordering::Relaxed,
+        );
+        self.entries.insert(
+            InternalKey::new(user_key, seq, kind),
+            causet_locale.to_vec(),
+        );
+    }
+
+    pub fn add_range_tombstone(&self, seq: u64, start: &[u8], end: &[u8]) {
+        self.approximate_size
+            .fetch_add(start.len() + end.len() + ENTRY_OVERHEAD, Ordering::Relaxed);
+        let mut range_tombstones = self.range_tombstones.write().unwrap();
+        range_tombstones
+            .tombstones
+            .push(RangeTombstone::new(start, end, seq));
+        range_tombstones.fragmented =
+            Arc::new(FragmentedRangeTombstones::new(&range_tombstones.tombstones));
+    }
+
+    /// Returns the newest version of `user_key` whose sequence number is at most `seq`.
+    pub fn get(&self, user_key: &[u8], seq: u64) -> Lookup {
+        let lookup = InternalKey::lookup(user_key, seq);
+        let covering_seq = self
+            .fragmented_range_tombstones()
+            .max_covering_seq(user_key, seq);
+        match self.entries.lower_bound(Bound::Included(&lookup)) {
+            Some(e) if e.key().user_key == user_key && e.key().seq > covering_seq => {
+                match e.key().kind {
+                    ValueKind::Put => Lookup::Found(e.value().clone()),
+                    ValueKind::Delete => Lookup::Deleted,
+                }
+            }
+            _ if covering_seq != 0 => Lookup::Deleted,
+            _ => Lookup::NotFound,
+        }
+    }
+
+    /// Returns the first entry after `bound`.
+    pub fn next_entry(&self, bound: Bound<&InternalKey>) -> Option<(InternalKey, Vec<u8>)> {
+        self.entries
+            .lower_bound(bound)
+            .map(|e| (e.key().clone(), e.value().clone()))
+    }
+
+    pub fn prev_entry(&self, bound: Bound<&InternalKey>) -> Option<(InternalKey, Vec<u8>)> {
+        self.entries
+            .upper_bound(bound)
+            .map(|e| (e.key().clone(), e.value().clone()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (InternalKey, Vec<u8>)> + '_ {
+        self.entries
+            .iter()
+            .map(|e| (e.key().clone(), e.value().clone()))
+    }
+
+    /// Iterates over the entries whose user soliton_id is in `[start, end)`.
+    pub fn range<'a>(
+        &'a self,
+        start: &[u8],
+        end: &'a [u8],
+    ) -> impl Iterator<Item = (InternalKey, Vec<u8>)> + 'a {
+        self.entries
+            .range(InternalKey::lookup(start, u64::MAX)..)
+            .take_while(move |e| e.key().user_key.as_slice() < end)
+            .map(|e| (e.key().clone(), e.value().clone()))
+    }
+
+    pub fn range_tombstones(&self) -> Vec<RangeTombstone> {
+        self.range_tombstones.read().unwrap().tombstones.clone()
+    }
+
+    pub fn fragmented_range_tombstones(&self) -> Arc<FragmentedRangeTombstones> {
+        self.range_tombstones.read().unwrap().fragmented.clone()
+    }
+
+    /// Whether any entry or range tombstone lies in `[start, end]`.
+    pub fn overlaps(&self, start: &[u8], end: &[u8]) -> bool {
+        self.next_entry(Bound::Included(&InternalKey::lookup(start, u64::MAX)))
+            .is_some_and(|(k, _)| k.user_key.as_slice() <= end)
+            || self
+                .range_tombstones
+                .read()
+                .unwrap()
+                .tombstones
+                .iter()
+                .any(|t| t.start.as_slice() <= end && t.end.as_slice() > start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.range_tombstones.read().unwrap().tombstones.is_empty()
+    }
+
+    pub fn approximate_size(&self) -> usize {
+        self.approximate_size.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memtable_get_versions() {
+        let mem = Memtable::new();
+        mem.add(1, ValueKind::Put, b"k", b"v1");
+        mem.add(2, ValueKind::Delete, b"k", b"");
+        mem.add(3, ValueKind::Put, b"k", b"v3");
+        mem.add(2, ValueKind::Put, b"k2", b"x");
+
+        assert_eq!(mem.get(b"k", 0), Lookup::NotFound);
+        assert_eq!(mem.get(b"k", 1), Lookup::Found(b"v1".to_vec()));
+        assert_eq!(mem.get(b"k", 2), Lookup::Deleted);
+        assert_eq!(mem.get(b"k", u64::MAX), Lookup::Found(b"v3".to_vec()));
+        assert_eq!(mem.get(b"j", u64::MAX), Lookup::NotFound);
+        assert_eq!(mem.iter().count(), 4);
+        assert!(mem.approximate_size() > 0);
+
+        mem.add_range_tombstone(4, b"a", b"k2");
+        mem.add(5, ValueKind::Put, b"j", b"x");
+        assert_eq!(mem.get(b"k", u64::MAX), Lookup::Deleted);
+        assert_eq!(mem.get(b"k", 3), Lookup::Found(b"v3".to_vec()));
+        assert_eq!(mem.get(b"j", u64::MAX), Lookup::Found(b"x".to_vec()));
+        assert_eq!(mem.get(b"i", u64::MAX), Lookup::Deleted);
+        assert_eq!(mem.get(b"k2", u64::MAX), Lookup::Found(b"x".to_vec()));
+        assert!(mem.overlaps(b"b", b"c"));
+        assert!(!mem.overlaps(b"k3", b"z"));
+    }
+}
@@ -0,0 +1,403 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use fdb_traits::{
+    DeleteStrategy, IterOptions, Iterable, Iterator, MiscExt, Mutable, RangeGreedoidsExt, Result,
+    WriteBatch, WriteOptions,
+};
+
+use crate::engine::LsmEngine;
+use crate::version::MANIFEST_FILE;
+use crate::write_batch::LsmWriteBatch;
+
+impl MiscExt for LsmEngine {
+    /// The call returns once the flush is done whether `sync` is set or not, since
+    /// flushed tables are synced before they are installed.
+    fn flush(&self, _sync: bool) -> Result<()> {
+        self.flush_memtables()
+    }
+
+    /// Column families share the write-ahead log, so flushing one flushes all of them.
+    fn flush_namespaced(&self, namespaced: &str, sync: bool) -> Result<()> {
+        self.check_namespaced(namespaced)?;
+        self.flush(sync)
+    }
+
+    fn delete_all_in_range_namespaced(
+        &self,
+        namespaced: &str,
+        strategy: DeleteStrategy,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<()> {
+        match strategy {
+            DeleteStrategy::DeleteByKey => {
+                let mut opts = IterOptions::new(Some(start.to_vec()), Some(end.to_vec()));
+                opts.set_fill_cache(false);
+                opts.set_key_only(true);
+                let mut iter = self.iterator_opt(namespaced, opts)?;
+                let mut wb = LsmWriteBatch::new(self);
+                let mut valid = iter.seek_to_first()?;
+                while valid {
+                    wb.delete_namespaced(namespaced, iter.soliton_id())?;
+                    if wb.should_write_to_einstein_merkle_tree() {
+                        wb.write_opt(&WriteOptions::default())?;
+                        wb.clear();
+                    }
+                    valid = iter.next()?;
+                }
+                wb.write_opt(&WriteOptions::default())
+            }
+            DeleteStrategy::DeleteByRange => self.delete_range_namespaced(namespaced, start, end),
+            DeleteStrategy::DeleteFiles => {
+                self.delete_files_in_range(namespaced, start, end, false)
+            }
+            DeleteStrategy::DeleteByWriter => {
+                self.delete_files_in_range(namespaced, start, end, true)
+            }
+        }
+    }
+
+    fn get_range_entries_and_versions(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<Option<(u64, u64)>> {
+        let stats = self.get_range_stats_namespaced(namespaced, start, end)?;
+        if stats.num_entries == 0 {
+            return Ok(None);
+        }
+        Ok(Some((stats.num_entries, stats.num_versions)))
+    }
+
+    fn get_einstein_merkle_tree_used_size(&self) -> Result<u64> {
+        let mut used_size = 0;
+        for namespaced in self.namespaced_names() {
+            let version = self.current_version(&namespaced)?;
+            used_size += version
+                .levels
+                .iter()
+                .flatten()
+                .map(|f| f.file_size())
+                .sum::<u64>();
+            used_size += self
+                .current_memtables(&namespaced)?
+                .iter()
+                .map(|mem| mem.approximate_size() as u64)
+                .sum::<u64>();
+        }
+        Ok(used_size)
+    }
+
+    fn roughly_cleanup_ranges(&self, ranges: &[(Vec<u8>, Vec<u8>)]) -> Result<()> {
+        for namespaced in self.namespaced_names() {
+            for (start, end) in ranges {
+                self.delete_files_in_range(&namespaced, start, end, false)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_local_path(&self) -> String {
+        self.local_path().to_string_lossy().into_owned()
+    }
+
+    fn sync_wal(&self) -> Result<()> {
+        self.sync_log()
+    }
+
+    fn exists(local_path: &str) -> bool {
+        Path::new(local_path).join(MANIFEST_FILE).exists()
+    }
+
+    fn dump_stats(&self) -> Result<String> {
+        let mut stats = String::new();
+        for namespaced in self.namespaced_names() {
+            let version = self.current_version(&namespaced)?;
+            let mems = self.current_memtables(&namespaced)?;
+            let cache = self.block_cache_stats(&namespaced)?;
+            writeln!(stats, "** causet_merge family {} **", namespaced).unwrap();
+            let mem_size: usize = mems.iter().map(|mem| mem.approximate_size()).sum();
+            writeln!(
+                stats,
+                "memtables: {} ({} frozen), {} bytes",
+                mems.len(),
+                mems.len() - 1,
+                mem_size
+            )
+            .unwrap();
+            for (level, files) in version.levels.iter().enumerate() {
+                let size: u64 = files.iter().map(|f| f.file_size()).sum();
+                writeln!(stats, "L{}: {} files, {} bytes", level, files.len(), size).unwrap();
+            }
+            writeln!(
+                stats,
+                "block cache: {} hits, {} misses",
+                cache.hits, cache.misses
+            )
+            .unwrap();
+        }
+        Ok(stats)
+    }
+
+    fn get_latest_sequence_number(&self) -> u64 {
+        self.last_sequence_number()
+    }
+
+    fn get_oldest_snapshot_sequence_number(&self) -> Option<u64> {
+        self.oldest_snapshot()
+    }
+
+    /// Flushes and compactions run on the writing thread that freezes a memtable,
+    /// so only writes that freeze another one while frozen memtables are still
+    /// waiting to be flushed are held back.
+    fn is_stalled_or_stopped(&self) -> bool {
+        self.is_write_stalled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::{LsmOptions, NAMESPACED_DEFAULT};
+    use fdb_traits::{Peekable, SeekKey, SnapshotExt};
+
+    fn opts() -> LsmOptions {
+        LsmOptions {
+            write_buffer_size: 64 * 1024,
+            block_size: 512,
+            target_file_size_base: 8 * 1024,
+            ..Default::default()
+        }
+    }
+
+    fn soliton_id(i: usize) -> Vec<u8> {
+        format!("k{:04}", i).into_bytes()
+    }
+
+    /// Writes `k0000..k1999` and compacts them to the last level.
+    fn open_with_data(local_path: &std::path::Path) -> LsmEngine {
+        let einstein_merkle_tree = LsmEngine::open(local_path, opts()).unwrap();
+        for i in 0..2000 {
+            einstein_merkle_tree
+                .put(&soliton_id(i), b"causet_locale")
+                .unwrap();
+        }
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        einstein_merkle_tree
+    }
+
+    fn count(einstein_merkle_tree: &LsmEngine) -> usize {
+        let mut iter = einstein_merkle_tree.iterator().unwrap();
+        let mut n = 0;
+        iter.seek_to_first().unwrap();
+        while iter.valid() {
+            n += 1;
+            iter.next().unwrap();
+        }
+        n
+    }
+
+    fn num_files(einstein_merkle_tree: &LsmEngine) -> usize {
+        let version = einstein_merkle_tree
+            .current_version(NAMESPACED_DEFAULT)
+            .unwrap();
+        version.levels.iter().map(|files| files.len()).sum()
+    }
+
+    /// The entries and range tombstones of all files.
+    fn num_entries(einstein_merkle_tree: &LsmEngine) -> (u64, u64) {
+        let version = einstein_merkle_tree
+            .current_version(NAMESPACED_DEFAULT)
+            .unwrap();
+        version.levels.iter().flatten().fold((0, 0), |(e, r), f| {
+            let props = f.table.greedoids();
+            (e + props.num_entries, r + props.num_range_deletions)
+        })
+    }
+
+    #[test]
+    fn test_delete_by_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = open_with_data(dir.path());
+        einstein_merkle_tree
+            .delete_all_in_range(
+                DeleteStrategy::DeleteByRange,
+                &soliton_id(100),
+                &soliton_id(1500),
+            )
+            .unwrap();
+        einstein_merkle_tree.put(&soliton_id(1000), b"new").unwrap();
+
+        let check = |einstein_merkle_tree: &LsmEngine| {
+            let get = |i| einstein_merkle_tree.get_value(&soliton_id(i)).unwrap();
+            assert_eq!(get(99), Some(b"causet_locale".to_vec()));
+            assert_eq!(get(100), None);
+            assert_eq!(get(1000), Some(b"new".to_vec()));
+            assert_eq!(get(1499), None);
+            assert_eq!(get(1500), Some(b"causet_locale".to_vec()));
+            assert_eq!(count(einstein_merkle_tree), 601);
+            let mut iter = einstein_merkle_tree.iterator().unwrap();
+            iter.seek(SeekKey::Key(&soliton_id(100))).unwrap();
+            assert_eq!(iter.soliton_id(), soliton_id(1000));
+            iter.next().unwrap();
+            assert_eq!(iter.soliton_id(), soliton_id(1500));
+        };
+        check(&einstein_merkle_tree);
+        // The tombstone is replayed from the log, then flushed and compacted.
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts()).unwrap();
+        check(&einstein_merkle_tree);
+        einstein_merkle_tree.flush(true).unwrap();
+        assert_eq!(num_entries(&einstein_merkle_tree), (2001, 1));
+        check(&einstein_merkle_tree);
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        check(&einstein_merkle_tree);
+        assert_eq!(num_entries(&einstein_merkle_tree), (601, 0));
+    }
+
+    #[test]
+    fn test_delete_files_and_by_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = open_with_data(dir.path());
+        einstein_merkle_tree.put(&soliton_id(700), b"mem").unwrap();
+        let (start, end) = (soliton_id(100), soliton_id(1500));
+        let files = num_files(&einstein_merkle_tree);
+        assert!(files > 3);
+
+        einstein_merkle_tree
+            .delete_all_in_range(DeleteStrategy::DeleteFiles, &start, &end)
+            .unwrap();
+        assert!(num_files(&einstein_merkle_tree) < files);
+        assert_eq!(
+            einstein_merkle_tree.get_value(&soliton_id(700)).unwrap(),
+            Some(b"mem".to_vec())
+        );
+        assert!(count(&einstein_merkle_tree) > 601);
+
+        einstein_merkle_tree
+            .delete_all_in_range(DeleteStrategy::DeleteByWriter, &start, &end)
+            .unwrap();
+        let check = |einstein_merkle_tree: &LsmEngine| {
+            let get = |i| einstein_merkle_tree.get_value(&soliton_id(i)).unwrap();
+            assert_eq!(get(99), Some(b"causet_locale".to_vec()));
+            assert_eq!(get(100), None);
+            assert_eq!(get(700), None);
+            assert_eq!(get(1500), Some(b"causet_locale".to_vec()));
+            assert_eq!(count(einstein_merkle_tree), 600);
+            assert_eq!(num_entries(einstein_merkle_tree), (600, 0));
+        };
+        check(&einstein_merkle_tree);
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts()).unwrap();
+        check(&einstein_merkle_tree);
+    }
+
+    #[test]
+    fn test_delete_by_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts()).unwrap();
+        for soliton_id in [b"a", b"b", b"c"] {
+            einstein_merkle_tree.put(soliton_id, b"v").unwrap();
+        }
+        einstein_merkle_tree
+            .delete_all_in_range(DeleteStrategy::DeleteByKey, b"b", b"c")
+            .unwrap();
+        assert_eq!(einstein_merkle_tree.get_value(b"b").unwrap(), None);
+        assert_eq!(count(&einstein_merkle_tree), 2);
+        einstein_merkle_tree
+            .delete_all_in_range(DeleteStrategy::DeleteByRange, b"c", b"a")
+            .unwrap();
+        assert!(einstein_merkle_tree
+            .delete_all_in_range_namespaced("missing", DeleteStrategy::DeleteFiles, b"a", b"z")
+            .is_err());
+        assert_eq!(count(&einstein_merkle_tree), 2);
+    }
+
+    #[test]
+    fn test_range_entries_and_used_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = open_with_data(dir.path());
+        assert_eq!(
+            einstein_merkle_tree
+                .get_range_entries_and_versions(NAMESPACED_DEFAULT, &soliton_id(0), b"z")
+                .unwrap(),
+            Some((2000, 2000))
+        );
+        assert_eq!(
+            einstein_merkle_tree
+                .get_range_entries_and_versions(NAMESPACED_DEFAULT, b"x", b"z")
+                .unwrap(),
+            None
+        );
+        let used_size = einstein_merkle_tree
+            .get_einstein_merkle_tree_used_size()
+            .unwrap();
+        einstein_merkle_tree.put(b"z", b"causet_locale").unwrap();
+        assert!(used_size > 0);
+        assert!(
+            einstein_merkle_tree
+                .get_einstein_merkle_tree_used_size()
+                .unwrap()
+                > used_size
+        );
+    }
+
+    #[test]
+    fn test_maintenance() {
+        let dir = tempfile::tempdir().unwrap();
+        let local_path = dir.path().to_str().unwrap();
+        assert!(!LsmEngine::exists(local_path));
+        let einstein_merkle_tree = open_with_data(dir.path());
+        assert!(LsmEngine::exists(local_path));
+        assert_eq!(einstein_merkle_tree.get_local_path(), local_path);
+        assert_eq!(einstein_merkle_tree.get_latest_sequence_number(), 2000);
+
+        assert_eq!(
+            einstein_merkle_tree.get_oldest_snapshot_sequence_number(),
+            None
+        );
+        let snap = einstein_merkle_tree.snapshot();
+        einstein_merkle_tree.put(&soliton_id(0), b"new").unwrap();
+        let later = einstein_merkle_tree.snapshot();
+        assert_eq!(
+            einstein_merkle_tree.get_oldest_snapshot_sequence_number(),
+            Some(2000)
+        );
+        drop(snap);
+        assert_eq!(
+            einstein_merkle_tree.get_oldest_snapshot_sequence_number(),
+            Some(2001)
+        );
+        drop(later);
+
+        einstein_merkle_tree.sync_wal().unwrap();
+        einstein_merkle_tree
+            .flush_namespaced(NAMESPACED_DEFAULT, true)
+            .unwrap();
+        assert!(einstein_merkle_tree
+            .flush_namespaced("missing", true)
+            .is_err());
+        let mems = einstein_merkle_tree
+            .current_memtables(NAMESPACED_DEFAULT)
+            .unwrap();
+        assert_eq!(mems.len(), 1);
+        assert!(mems[0].is_empty());
+        let stats = einstein_merkle_tree.dump_stats().unwrap();
+        assert!(
+            stats.contains("** causet_merge family default **"),
+            "{}",
+            stats
+        );
+        assert!(!einstein_merkle_tree.is_stalled_or_stopped());
+
+        let files = num_files(&einstein_merkle_tree);
+        einstein_merkle_tree
+            .roughly_cleanup_ranges(&[(soliton_id(0), soliton_id(2000))])
+            .unwrap();
+        assert!(num_files(&einstein_merkle_tree) < files);
+    }
+}
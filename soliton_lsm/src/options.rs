@@ -0,0 +1,93 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+pub use fdb_traits::NAMESPACED_DEFAULT;
+
+use fdb_traits::Compression;
+
+use crate::cache::BlockCache;
+
+const KB: u64 = 1024;
+const MB: u64 = 1024 * KB;
+
+/// Options of an `LsmEngine`, shared by all of its causet_merge families. Some of
+/// them can be changed while it runs, see `config`.
+#[derive(Clone, Debug)]
+pub struct LsmOptions {
+    pub create_if_missing: bool,
+    /// Column families to open in addition to `default`. Families found in the
+    /// manifest are always opened.
+    pub namespaceds: Vec<String>,
+    /// Memtables are flushed to level 0 once one grows past this size.
+    pub write_buffer_size: usize,
+    /// Target uncompressed size of an SST data block.
+    pub block_size: usize,
+    pub bloom_bits_per_key: usize,
+    /// Compression of SST data blocks. Zlib and Zstd are not supported.
+    pub compression: Compression,
+    pub num_levels: usize,
+    /// Number of level 0 files that triggers a level 0 compaction.
+    pub level0_file_num_compaction_trigger: usize,
+    /// Target size of level 1; each following level is `max_bytes_for_level_multiplier`
+    /// times larger.
+    pub max_bytes_for_level_base: u64,
+    pub max_bytes_for_level_multiplier: u64,
+    /// Compaction outputs are split into files of about this size.
+    pub target_file_size_base: u64,
+    pub disable_auto_compactions: bool,
+    /// Column families whose causet_locales end with an expiry time, see
+    /// `fdb_traits::append_expire_ts`. Their SSTs record `TtlGreedoids`.
+    pub ttl_namespaceds: Vec<String>,
+    /// SSTs record `RangeGreedoids` at a soliton_id every time this many bytes or
+    /// entries were added since the last one.
+    pub range_greedoids_size_distance: u64,
+    pub range_greedoids_entries_distance: u64,
+    /// The length of the soliton_id prefixes `IterOptions::prefix_same_as_start`
+    /// iterators stay within. Without it, that option is ignored.
+    pub prefix_extractor_len: Option<usize>,
+    /// The cache data blocks are read through. Clones of a cache share its budget,
+    /// across causet_merge families and einstein_merkle_trees.
+    pub block_cache: Option<BlockCache>,
+    /// The rate flushes and compactions write at, in bytes per second; 0 for
+    /// unlimited.
+    pub rate_bytes_per_sec: u64,
+}
+
+impl Default for LsmOptions {
+    fn default() -> LsmOptions {
+        LsmOptions {
+            create_if_missing: true,
+            namespaceds: Vec::new(),
+            write_buffer_size: 64 * MB as usize,
+            block_size: 4 * KB as usize,
+            bloom_bits_per_key: 10,
+            compression: Compression::None,
+            num_levels: 7,
+            level0_file_num_compaction_trigger: 4,
+            max_bytes_for_level_base: 256 * MB,
+            max_bytes_for_level_multiplier: 10,
+            target_file_size_base: 32 * MB,
+            disable_auto_compactions: false,
+            ttl_namespaceds: Vec::new(),
+            range_greedoids_size_distance: 4 * MB,
+            range_greedoids_entries_distance: 40 * KB,
+            prefix_extractor_len: None,
+            block_cache: Some(BlockCache::new(8 * MB as usize, 4, 0.5)),
+            rate_bytes_per_sec: 0,
+        }
+    }
+}
+
+impl LsmOptions {
+    /// The size level `level` (>= 1) is compacted down at.
+    pub fn max_bytes_for_level(&self, level: usize) -> u64 {
+        let mut size = self.max_bytes_for_level_base;
+        for _ in 1..level {
+            size = size.saturating_mul(self.max_bytes_for_level_multiplier);
+        }
+        size
+    }
+
+    pub fn is_ttl_namespaced(&self, namespaced: &str) -> bool {
+        self.ttl_namespaceds.iter().any(|n| n == namespaced)
+    }
+}
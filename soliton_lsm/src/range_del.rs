@@ -0,0 +1,207 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Range tombstones.
+//!
+//! A range deletion is kept as a single tombstone, in the memtable and then in the
+//! range deletion block of SSTs, instead of a deletion per soliton_id. A tombstone
+//! `[start, end)` written at `seq` hides every version of a soliton_id of its range
+//! with a smaller sequence number.
+//!
+//! Readers fragment the tombstones of a memtable or table once: overlapping
+//! tombstones are cut at each other's bounds into disjoint, sorted fragments, each
+//! with the sequence numbers of the tombstones that cover it. Whether a soliton_id
+//! is covered is then a binary search.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use fdb_traits::Result;
+
+use crate::codec::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeTombstone {
+    pub start: Vec<u8>,
+    pub end: Vec<u8>,
+    pub seq: u64,
+}
+
+impl RangeTombstone {
+    pub fn new(start: &[u8], end: &[u8], seq: u64) -> RangeTombstone {
+        RangeTombstone {
+            start: start.to_vec(),
+            end: end.to_vec(),
+            seq,
+        }
+    }
+
+    pub fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_length_prefixed(buf, &self.start);
+        put_length_prefixed(buf, &self.end);
+        put_varint(buf, self.seq);
+    }
+
+    pub fn decode_from(buf: &mut &[u8]) -> Result<RangeTombstone> {
+        Ok(RangeTombstone {
+            start: get_length_prefixed(buf)?.to_vec(),
+            end: get_length_prefixed(buf)?.to_vec(),
+            seq: get_varint(buf)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fragment {
+    pub start: Vec<u8>,
+    pub end: Vec<u8>,
+    /// The sequence numbers of the tombstones covering the fragment, descending.
+    pub seqs: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FragmentedRangeTombstones {
+    fragments: Vec<Fragment>,
+}
+
+impl FragmentedRangeTombstones {
+    pub fn new<'a>(
+        tombstones: impl IntoIterator<Item = &'a RangeTombstone>,
+    ) -> FragmentedRangeTombstones {
+        // Sweeps the bounds of all tombstones in order, tracking the sequence numbers
+        // of the tombstones open between two consecutive bounds.
+        let mut bounds: BTreeMap<&[u8], Vec<(u64, bool)>> = BTreeMap::new();
+        for t in tombstones {
+            if t.start < t.end {
+                bounds.entry(&t.start).or_default().push((t.seq, true));
+                bounds.entry(&t.end).or_default().push((t.seq, false));
+            }
+        }
+        let mut fragments = Vec::new();
+        let mut open: BTreeMap<u64, usize> = BTreeMap::new();
+        let mut bounds = bounds.into_iter().peekable();
+        while let Some((bound, events)) = bounds.next() {
+            for (seq, is_start) in events {
+                if is_start {
+                    *open.entry(seq).or_default() += 1;
+                } else {
+                    let count = open.get_mut(&seq).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        open.remove(&seq);
+                    }
+                }
+            }
+            if let (Some((next, _)), false) = (bounds.peek(), open.is_empty()) {
+                fragments.push(Fragment {
+                    start: bound.to_vec(),
+                    end: next.to_vec(),
+                    seqs: open.keys().rev().copied().collect(),
+                });
+            }
+        }
+        FragmentedRangeTombstones { fragments }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fragments.is_empty()
+    }
+
+    pub fn fragments(&self) -> &[Fragment] {
+        &self.fragments
+    }
+
+    /// Returns the newest tombstone covering `user_key` visible at `read_seq`, as its
+    /// sequence number and the end of the fragment it was found in.
+    pub fn covering(&self, user_key: &[u8], read_seq: u64) -> Option<(u64, &[u8])> {
+        let idx = self
+            .fragments
+            .partition_point(|f| f.end.as_slice() <= user_key);
+        let fragment = self.fragments.get(idx)?;
+        if fragment.start.as_slice() > user_key {
+            return None;
+        }
+        let pos = fragment.seqs.partition_point(|&seq| seq > read_seq);
+        fragment
+            .seqs
+            .get(pos)
+            .map(|&seq| (seq, fragment.end.as_slice()))
+    }
+
+    /// The sequence number of the newest tombstone covering `user_key` visible at
+    /// `read_seq`, 0 if there is none.
+    pub fn max_covering_seq(&self, user_key: &[u8], read_seq: u64) -> u64 {
+        self.covering(user_key, read_seq).map_or(0, |(seq, _)| seq)
+    }
+}
+
+/// The tombstones of every memtable and table an iterator reads.
+#[derive(Default)]
+pub struct RangeDelAggregator {
+    lists: Vec<Arc<FragmentedRangeTombstones>>,
+}
+
+impl RangeDelAggregator {
+    pub fn add(&mut self, tombstones: &Arc<FragmentedRangeTombstones>) {
+        if !tombstones.is_empty() {
+            self.lists.push(tombstones.clone());
+        }
+    }
+
+    /// Like `FragmentedRangeTombstones::covering`, over all the tombstones added.
+    pub fn covering(&self, user_key: &[u8], read_seq: u64) -> Option<(u64, &[u8])> {
+        self.lists
+            .iter()
+            .filter_map(|list| list.covering(user_key, read_seq))
+            .max_by_key(|&(seq, _)| seq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fragment_tombstones() {
+        let tombstones = [
+            RangeTombstone::new(b"a", b"e", 5),
+            RangeTombstone::new(b"c", b"g", 8),
+            RangeTombstone::new(b"c", b"d", 3),
+            RangeTombstone::new(b"x", b"x", 9),
+            RangeTombstone::new(b"m", b"n", 1),
+        ];
+        let fragmented = FragmentedRangeTombstones::new(&tombstones);
+        let got: Vec<_> = fragmented
+            .fragments()
+            .iter()
+            .map(|f| (f.start.as_slice(), f.end.as_slice(), f.seqs.clone()))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                (&b"a"[..], &b"c"[..], vec![5]),
+                (b"c", b"d", vec![8, 5, 3]),
+                (b"d", b"e", vec![8, 5]),
+                (b"e", b"g", vec![8]),
+                (b"m", b"n", vec![1]),
+            ]
+        );
+
+        assert_eq!(fragmented.max_covering_seq(b"0", u64::MAX), 0);
+        assert_eq!(fragmented.max_covering_seq(b"a", u64::MAX), 5);
+        assert_eq!(fragmented.covering(b"c", u64::MAX), Some((8, &b"d"[..])));
+        assert_eq!(fragmented.covering(b"c", 7), Some((5, &b"d"[..])));
+        assert_eq!(fragmented.max_covering_seq(b"c", 2), 0);
+        assert_eq!(fragmented.max_covering_seq(b"g", u64::MAX), 0);
+        assert_eq!(fragmented.max_covering_seq(b"mm", u64::MAX), 1);
+        assert_eq!(fragmented.max_covering_seq(b"x", u64::MAX), 0);
+
+        let mut agg = RangeDelAggregator::default();
+        agg.add(&Arc::new(fragmented));
+        agg.add(&Arc::new(FragmentedRangeTombstones::new(&[
+            RangeTombstone::new(b"b", b"z", 6),
+        ])));
+        assert_eq!(agg.covering(b"b", u64::MAX), Some((6, &b"z"[..])));
+        assert_eq!(agg.covering(b"d", u64::MAX), Some((8, &b"e"[..])));
+        assert_eq!(agg.covering(b"z", u64::MAX), None);
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! `RangeGreedoids` sampled while SSTs are written.
+//!
+//! Every `range_greedoids_size_distance` bytes or `range_greedoids_entries_distance`
+//! entries, and at the last soliton_id of the table, the collector records the
+//! soliton_id together with the size, entries and versions added so far. The stats of
+//! a range of a table are then the difference between the offsets recorded just
+//! before its bounds, without reading any data block.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use fdb_traits::{Error, RangeGreedoidsExt, RangeStats, Result};
+
+use crate::codec::{get_length_prefixed, get_varint, put_length_prefixed, put_varint, InternalKey};
+use crate::engine::LsmEngine;
+use crate::options::LsmOptions;
+use crate::sst::{TableGreedoids, TableGreedoidsCollector};
+use crate::version::FileMeta;
+
+/// Name of the `RangeGreedoids` in `TableGreedoids::user_collected`.
+pub const RANGE_GREEDOIDS_NAME: &str = "einsteindb.range";
+
+/// The sampled soliton_ids of a table, ascending, each with the stats of the table up
+/// to and including it.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RangeGreedoids {
+    pub offsets: Vec<(Vec<u8>, RangeStats)>,
+}
+
+impl RangeGreedoids {
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        put_varint(&mut buf, self.offsets.len() as u64);
+        for (soliton_id, offset) in &self.offsets {
+            put_length_prefixed(&mut buf, soliton_id);
+            put_varint(&mut buf, offset.size);
+            put_varint(&mut buf, offset.num_entries);
+            put_varint(&mut buf, offset.num_versions);
+        }
+        buf
+    }
+
+    /// The stats of the soliton_ids before `soliton_id`, as of the last sample before it.
+    fn offset_before(&self, soliton_id: &[u8]) -> RangeStats {
+        let idx = self
+            .offsets
+            .partition_point(|(k, _)| k.as_slice() < soliton_id);
+        match idx {
+            0 => RangeStats::default(),
+            _ => self.offsets[idx - 1].1,
+        }
+    }
+
+    /// The approximate stats of `[start, end)`.
+    pub fn get_range_stats(&self, start: &[u8], end: &[u8]) -> RangeStats {
+        let (s, e) = (self.offset_before(start), self.offset_before(end));
+        RangeStats {
+            size: e.size.saturating_sub(s.size),
+            num_entries: e.num_entries.saturating_sub(s.num_entries),
+            num_versions: e.num_versions.saturating_sub(s.num_versions),
+        }
+    }
+
+    /// The sampled soliton_ids of `[start, end)`, each with the size added since the
+    /// previous one.
+    fn sizes_in_range(&self, start: &[u8], end: &[u8]) -> Vec<(&[u8], u64)> {
+        let mut prev = self.offset_before(start).size;
+        self.offsets
+            .iter()
+            .skip_while(|(k, _)| k.as_slice() < start)
+            .take_while(|(k, _)| k.as_slice() < end)
+            .map(|(k, offset)| {
+                let size = offset.size.saturating_sub(prev);
+                prev = offset.size;
+                (k.as_slice(), size)
+            })
+            .collect()
+    }
+}
+
+pub struct RangeGreedoidsCollector {
+    size_distance: u64,
+    entries_distance: u64,
+    current: RangeStats,
+    last_sample: RangeStats,
+    last_soliton_id: Option<Vec<u8>>,
+    greedoids: RangeGreedoids,
+}
+
+impl RangeGreedoidsCollector {
+    pub fn new(opts: &LsmOptions) -> RangeGreedoidsCollector {
+        RangeGreedoidsCollector {
+            size_distance: opts.range_greedoids_size_distance,
+            entries_distance: opts.range_greedoids_entries_distance,
+            current: RangeStats::default(),
+            last_sample: RangeStats::default(),
+            last_soliton_id: None,
+            greedoids: RangeGreedoids::default(),
+        }
+    }
+}
+
+impl TableGreedoidsCollector for RangeGreedoidsCollector {
+    fn add(&mut self, soliton_id: &InternalKey, causet_locale: &[u8]) {
+        let user_key = &soliton_id.user_key;
+        if self.last_soliton_id.as_ref() != Some(user_key) {
+            self.current.num_versions += 1;
+            self.last_soliton_id = Some(user_key.clone());
+        }
+        self.current.num_entries += 1;
+        self.current.size += (user_key.len() + causet_locale.len()) as u64;
+        if self.current.size - self.last_sample.size >= self.size_distance
+            || self.current.num_entries - self.last_sample.num_entries >= self.entries_distance
+        {
+            self.greedoids
+                .offsets
+                .push((user_key.clone(), self.current));
+            self.last_sample = self.current;
+        }
+    }
+
+    fn finish(&mut self, user_collected: &mut BTreeMap<String, Vec<u8>>) {
+        let last_soliton_id = match self.last_soliton_id.take() {
+            Some(soliton_id) => soliton_id,
+            None => return,
+        };
+        if self.current != self.last_sample {
+            self.greedoids.offsets.push((last_soliton_id, self.current));
+        }
+        user_collected.insert(RANGE_GREEDOIDS_NAME.to_owned(), self.greedoids.encode());
+    }
+}
+
+/// Returns the `RangeGreedoids` recorded in `props`. Tables written without them are
+/// taken as a single sample at their largest soliton_id.
+pub fn decode_range_greedoids(props: &TableGreedoids) -> Result<RangeGreedoids> {
+    let mut buf = match props.user_collected.get(RANGE_GREEDOIDS_NAME) {
+        Some(buf) => buf.as_slice(),
+        None => {
+            let offsets = props
+                .largest_key
+                .iter()
+                .map(|k| {
+                    let stats = RangeStats {
+                        size: props.raw_key_size + props.raw_value_size,
+                        num_entries: props.num_entries,
+                        num_versions: props.num_entries,
+                    };
+                    (k.user_key.clone(), stats)
+                })
+                .collect();
+            return Ok(RangeGreedoids { offsets });
+        }
+    };
+    let n = get_varint(&mut buf)?;
+    let mut offsets = Vec::with_capacity(n as usize);
+    for _ in 0..n {
+        let soliton_id = get_length_prefixed(&mut buf)?.to_vec();
+        let stats = RangeStats {
+            size: get_varint(&mut buf)?,
+            num_entries: get_varint(&mut buf)?,
+            num_versions: get_varint(&mut buf)?,
+        };
+        offsets.push((soliton_id, stats));
+    }
+    if !buf.is_empty() {
+        return Err(Error::Corruption(
+            "trailing bytes in range greedoids".to_owned(),
+        ));
+    }
+    Ok(RangeGreedoids { offsets })
+}
+
+impl LsmEngine {
+    fn files_in_range(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<Vec<Arc<FileMeta>>> {
+        let version = self.current_version(namespaced)?;
+        Ok(version
+            .levels
+            .iter()
+            .flatten()
+            .filter(|f| f.overlaps_range(start, end))
+            .cloned()
+            .collect())
+    }
+}
+
+impl RangeGreedoidsExt for LsmEngine {
+    fn get_range_stats_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<RangeStats> {
+        // The memtables are counted exactly.
+        let mut stats = RangeStats::default();
+        for mem in self.current_memtables(namespaced)? {
+            let mut last_soliton_id: Option<Vec<u8>> = None;
+            for (soliton_id, causet_locale) in mem.range(start, end) {
+                if last_soliton_id.as_ref() != Some(&soliton_id.user_key) {
+                    stats.num_versions += 1;
+                }
+                stats.num_entries += 1;
+                stats.size += (soliton_id.user_key.len() + causet_locale.len()) as u64;
+                last_soliton_id = Some(soliton_id.user_key);
+            }
+        }
+        for f in self.files_in_range(namespaced, start, end)? {
+            let file_stats =
+                decode_range_greedoids(f.table.greedoids())?.get_range_stats(start, end);
+            stats.size += file_stats.size;
+            stats.num_entries += file_stats.num_entries;
+            stats.num_versions += file_stats.num_versions;
+        }
+        Ok(stats)
+    }
+
+    fn get_range_approximate_split_soliton_ids_namespaced(
+        &self,
+        namespaced: &str,
+        start: &[u8],
+        end: &[u8],
+        count: usize,
+    ) -> Result<Vec<Vec<u8>>> {
+        let mut greedoids = Vec::new();
+        for f in self.files_in_range(namespaced, start, end)? {
+            greedoids.push(decode_range_greedoids(f.table.greedoids())?);
+        }
+        let mut sizes: Vec<_> = greedoids
+            .iter()
+            .flat_map(|g| g.sizes_in_range(start, end))
+            .collect();
+        sizes.sort_unstable();
+        let total: u64 = sizes.iter().map(|(_, size)| size).sum();
+
+        // Picks the first sampled soliton_id past each `total / (count + 1)` boundary.
+        let mut res: Vec<Vec<u8>> = Vec::new();
+        let mut acc = 0;
+        for (soliton_id, size) in sizes {
+            acc += size;
+            if res.len() == count {
+                break;
+            }
+            let boundary = total * (res.len() as u64 + 1) / (count as u64 + 1);
+            if acc >= boundary
+                && soliton_id > start
+                && res.last().is_none_or(|last| last.as_slice() < soliton_id)
+            {
+                res.push(soliton_id.to_vec());
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdb_traits::{MiscExt, NAMESPACED_DEFAULT};
+
+    fn soliton_id(i: usize) -> Vec<u8> {
+        format!("k{:04}", i).into_bytes()
+    }
+
+    #[test]
+    fn test_range_greedoids() {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = LsmOptions {
+            range_greedoids_size_distance: 1024,
+            range_greedoids_entries_distance: 64,
+            ..Default::default()
+        };
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts).unwrap();
+        // 1000 soliton_ids of 10 bytes with causet_locales of 10 bytes, and a second
+        // version of the first 100.
+        for i in 0..1000 {
+            einstein_merkle_tree
+                .put(&soliton_id(i), b"causet_loc")
+                .unwrap();
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+        for i in 0..100 {
+            einstein_merkle_tree
+                .put(&soliton_id(i), b"causet_loc")
+                .unwrap();
+        }
+
+        let stats = einstein_merkle_tree
+            .get_range_stats_namespaced(NAMESPACED_DEFAULT, b"", b"z")
+            .unwrap();
+        assert_eq!(
+            stats,
+            RangeStats {
+                size: 1100 * 15,
+                num_entries: 1100,
+                num_versions: 1100,
+            }
+        );
+
+        // Half of the flushed soliton_ids, within a sample on each side.
+        let stats = einstein_merkle_tree
+            .get_range_stats_namespaced(NAMESPACED_DEFAULT, &soliton_id(500), b"z")
+            .unwrap();
+        assert!((436..=564).contains(&stats.num_entries), "{:?}", stats);
+        assert_eq!(stats.size, stats.num_entries * 15);
+        assert_eq!(
+            einstein_merkle_tree
+                .get_range_approximate_size_namespaced(NAMESPACED_DEFAULT, b"x", b"z")
+                .unwrap(),
+            0
+        );
+
+        let split_soliton_ids = einstein_merkle_tree
+            .get_range_approximate_split_soliton_ids_namespaced(NAMESPACED_DEFAULT, b"", b"z", 3)
+            .unwrap();
+        assert_eq!(split_soliton_ids.len(), 3);
+        for (i, k) in split_soliton_ids.iter().enumerate() {
+            let n: usize = std::str::from_utf8(&k[1..]).unwrap().parse().unwrap();
+            let expected = 250 * (i + 1);
+            assert!(n.abs_diff(expected) <= 64, "{:?}", split_soliton_ids);
+        }
+    }
+}
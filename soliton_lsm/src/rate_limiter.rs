@@ -0,0 +1,85 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Limits the rate flushes and compactions write at, so that they leave disk
+//! bandwidth to foreground reads and writes.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long writes may go at full speed after being idle.
+const MAX_BURST: Duration = Duration::from_millis(100);
+
+struct Bucket {
+    /// Bytes that may be written without waiting; negative when writers are ahead.
+    available: f64,
+    last_refill: Instant,
+}
+
+/// A token bucket shared by the writers it limits. A rate of 0 means unlimited.
+pub struct RateLimiter {
+    bytes_per_sec: AtomicU64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new(bytes_per_sec: u64) -> RateLimiter {
+        RateLimiter {
+            bytes_per_sec: AtomicU64::new(bytes_per_sec),
+            bucket: Mutex::new(Bucket {
+                available: 0.0,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn bytes_per_sec(&self) -> u64 {
+        self.bytes_per_sec.load(Ordering::Relaxed)
+    }
+
+    /// Changes the rate; writers already waiting keep their delay.
+    pub fn set_bytes_per_sec(&self, bytes_per_sec: u64) {
+        self.bytes_per_sec.store(bytes_per_sec, Ordering::Relaxed);
+    }
+
+    /// Blocks until `bytes` may be written.
+    pub fn request(&self, bytes: usize) {
+        let rate = self.bytes_per_sec() as f64;
+        if rate == 0.0 {
+            return;
+        }
+        // Holding the lock while sleeping makes writers wait in turn.
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.last_refill).as_secs_f64() * rate;
+        bucket.available = (bucket.available + refill).min(MAX_BURST.as_secs_f64() * rate);
+        bucket.last_refill = now;
+        bucket.available -= bytes as f64;
+        if bucket.available < 0.0 {
+            thread::sleep(Duration::from_secs_f64(-bucket.available / rate));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new(0);
+        let start = Instant::now();
+        limiter.request(1 << 30);
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        // 300 KB at 1 MB/s take 300ms, less the 100ms burst saved up while idle.
+        limiter.set_bytes_per_sec(1 << 20);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.request(100 << 10);
+        }
+        assert!(start.elapsed() >= Duration::from_millis(190));
+        assert_eq!(limiter.bytes_per_sec(), 1 << 20);
+    }
+}
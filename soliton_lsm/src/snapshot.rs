@@ -0,0 +1,138 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use fdb_traits::{IterOptions, Iterable, Peekable, ReadOptions, Result, Snapshot, SnapshotExt};
+
+use crate::engine::LsmEngine;
+use crate::iterator::LsmIterator;
+
+/// Reads the einstein_merkle_tree as of the last write committed when it was taken.
+///
+/// Files dropped by `DeleteStrategy::DeleteFiles` and `DeleteByWriter` and files
+/// ingested later do not respect snapshots.
+pub struct LsmSnapshot {
+    einstein_merkle_tree: LsmEngine,
+    seq: u64,
+}
+
+impl Snapshot for LsmSnapshot {
+    fn sequence_number(&self) -> u64 {
+        self.seq
+    }
+}
+
+impl Peekable for LsmSnapshot {
+    fn get_value_namespaced_opt(
+        &self,
+        opts: &ReadOptions,
+        namespaced: &str,
+        soliton_id: &[u8],
+    ) -> Result<Option<Vec<u8>>> {
+        self.einstein_merkle_tree
+            .get_value_at(opts, namespaced, soliton_id, Some(self.seq))
+    }
+}
+
+impl Iterable for LsmSnapshot {
+    type Iterator = LsmIterator;
+
+    fn iterator_opt(&self, namespaced: &str, opts: IterOptions) -> Result<LsmIterator> {
+        self.einstein_merkle_tree
+            .iterator_at(namespaced, opts, Some(self.seq))
+    }
+}
+
+impl Drop for LsmSnapshot {
+    fn drop(&mut self) {
+        self.einstein_merkle_tree.release_snapshot(self.seq);
+    }
+}
+
+impl SnapshotExt for LsmEngine {
+    type Snapshot = LsmSnapshot;
+
+    fn snapshot(&self) -> LsmSnapshot {
+        LsmSnapshot {
+            einstein_merkle_tree: self.clone(),
+            seq: self.acquire_snapshot(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fdb_traits::{Iterator, NAMESPACED_DEFAULT};
+
+    use super::*;
+
+    fn num_entries(einstein_merkle_tree: &LsmEngine) -> u64 {
+        let version = einstein_merkle_tree
+            .current_version(NAMESPACED_DEFAULT)
+            .unwrap();
+        version
+            .levels
+            .iter()
+            .flatten()
+            .map(|f| f.table.greedoids().num_entries)
+            .sum()
+    }
+
+    #[test]
+    fn test_snapshot_survives_compaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), Default::default()).unwrap();
+        einstein_merkle_tree.put(b"a", b"1").unwrap();
+        einstein_merkle_tree.put(b"b", b"1").unwrap();
+        einstein_merkle_tree.put(b"c", b"1").unwrap();
+        let snap1 = einstein_merkle_tree.snapshot();
+        einstein_merkle_tree.put(b"a", b"2").unwrap();
+        einstein_merkle_tree.delete(b"b").unwrap();
+        einstein_merkle_tree
+            .delete_range_namespaced(NAMESPACED_DEFAULT, b"c", b"d")
+            .unwrap();
+        let snap2 = einstein_merkle_tree.snapshot();
+        einstein_merkle_tree.put(b"a", b"3").unwrap();
+        assert_eq!(snap2.sequence_number(), snap1.sequence_number() + 3);
+
+        let check = |snap: &LsmSnapshot, expected: &[(&[u8], &[u8])]| {
+            let mut iter = snap.iterator().unwrap();
+            let mut got = Vec::new();
+            let mut valid = iter.seek_to_first().unwrap();
+            while valid {
+                got.push((iter.soliton_id().to_vec(), iter.causet_locale().to_vec()));
+                valid = iter.next().unwrap();
+            }
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|(k, v)| (k.to_vec(), v.to_vec()))
+                .collect();
+            assert_eq!(got, expected);
+            for (k, v) in expected {
+                assert_eq!(snap.get_value(&k).unwrap(), Some(v));
+            }
+        };
+        for _ in 0..2 {
+            check(&snap1, &[(b"a", b"1"), (b"b", b"1"), (b"c", b"1")]);
+            check(&snap2, &[(b"a", b"2")]);
+            assert_eq!(snap2.get_value(b"b").unwrap(), None);
+            assert_eq!(snap2.get_value(b"c").unwrap(), None);
+            einstein_merkle_tree.compact_range(None, None).unwrap();
+        }
+        assert_eq!(num_entries(&einstein_merkle_tree), 6);
+
+        // Versions only the released snapshots saw go with the next compaction of
+        // their file.
+        drop(snap1);
+        einstein_merkle_tree.put(b"a", b"4").unwrap();
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        check(&snap2, &[(b"a", b"2")]);
+        assert_eq!(num_entries(&einstein_merkle_tree), 2);
+        drop(snap2);
+        einstein_merkle_tree.put(b"a", b"5").unwrap();
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        assert_eq!(num_entries(&einstein_merkle_tree), 1);
+        assert_eq!(
+            einstein_merkle_tree.get_value(b"a").unwrap(),
+            Some(b"5".to_vec())
+        );
+    }
+}
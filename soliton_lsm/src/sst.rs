@@ -0,0 +1,803 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Sorted string table files.
+//!
+//! ```text
+//!   file   ::= data-block* filter-block range-del-block greedoids-block index-block footer
+//!   block  ::= payload compression: u8 crc32(payload compression): u32
+//!   entry  ::= internal-soliton_id len(causet_locale): varint causet_locale
+//!   index  ::= (internal-soliton_id offset: u64 size: u64)*      last soliton_id of each data block
+//!   footer ::= index-handle filter-handle range-del-handle greedoids-handle magic: u64
+//! ```
+//!
+//! The filter block is a bloom filter over the user soliton_ids of the table. The
+//! range deletion block holds the range tombstones of the table, see `range_del`.
+//! Index, filter, range tombstones and greedoids are loaded when the table is
+//! opened; data blocks are read on demand, through the block cache if the table has
+//! one. Only data blocks are compressed, see `compression`.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use fdb_traits::{Compression, Error, Result};
+
+use crate::bloom::{bloom_hash, build_filter, may_contain};
+use crate::cache::{Block, CacheReservation, Priority, TableCache};
+use crate::codec::*;
+use crate::compression::{compress, compression_id, decompress};
+use crate::iterator::InternalIterator;
+use crate::memtable::Lookup;
+use crate::range_del::{FragmentedRangeTombstones, RangeTombstone};
+use crate::rate_limiter::RateLimiter;
+
+const MAGIC: u64 = 0x534f_4c49_544f_4e31;
+const BLOCK_TRAILER_SIZE: usize = 5;
+const FOOTER_SIZE: usize = 9 * 8;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockHandle {
+    pub offset: u64,
+    pub size: u64,
+}
+
+impl BlockHandle {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.offset.to_le_bytes());
+        buf.extend_from_slice(&self.size.to_le_bytes());
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<BlockHandle> {
+        Ok(BlockHandle {
+            offset: get_fixed_u64(buf)?,
+            size: get_fixed_u64(buf)?,
+        })
+    }
+}
+
+/// Statistics collected while building a table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableGreedoids {
+    pub num_entries: u64,
+    pub num_deletions: u64,
+    pub num_range_deletions: u64,
+    pub raw_key_size: u64,
+    pub raw_value_size: u64,
+    pub smallest_seq: u64,
+    pub largest_seq: u64,
+    pub smallest_key: Option<InternalKey>,
+    pub largest_key: Option<InternalKey>,
+    /// Greedoids added by collectors outside the einstein_merkle_tree, by name.
+    pub user_collected: BTreeMap<String, Vec<u8>>,
+}
+
+impl TableGreedoids {
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for v in [
+            self.num_entries,
+            self.num_deletions,
+            self.num_range_deletions,
+            self.raw_key_size,
+            self.raw_value_size,
+            self.smallest_seq,
+            self.largest_seq,
+        ] {
+            put_varint(&mut buf, v);
+        }
+        for k in [&self.smallest_key, &self.largest_key] {
+            match k {
+                Some(k) => {
+                    buf.push(1);
+                    k.encode_to(&mut buf);
+                }
+                None => buf.push(0),
+            }
+        }
+        put_varint(&mut buf, self.user_collected.len() as u64);
+        for (name, causet_locale) in &self.user_collected {
+            put_length_prefixed(&mut buf, name.as_bytes());
+            put_length_prefixed(&mut buf, causet_locale);
+        }
+        buf
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<TableGreedoids> {
+        let buf = &mut buf;
+        let mut props = TableGreedoids {
+            num_entries: get_varint(buf)?,
+            num_deletions: get_varint(buf)?,
+            num_range_deletions: get_varint(buf)?,
+            raw_key_size: get_varint(buf)?,
+            raw_value_size: get_varint(buf)?,
+            smallest_seq: get_varint(buf)?,
+            largest_seq: get_varint(buf)?,
+            ..Default::default()
+        };
+        for k in [&mut props.smallest_key, &mut props.largest_key] {
+            let (&present, rest) = buf
+                .split_first()
+                .ok_or_else(|| Error::Corruption("truncated table greedoids".to_owned()))?;
+            *buf = rest;
+            if present == 1 {
+                *k = Some(InternalKey::decode_from(buf)?);
+            }
+        }
+        for _ in 0..get_varint(buf)? {
+            let name = String::from_utf8(get_length_prefixed(buf)?.to_vec())
+                .map_err(|e| Error::Corruption(format!("bad greedoid name: {}", e)))?;
+            props
+                .user_collected
+                .insert(name, get_length_prefixed(buf)?.to_vec());
+        }
+        Ok(props)
+    }
+}
+
+/// Collects greedoids of a table while it is built, into
+/// `TableGreedoids::user_collected`.
+pub trait TableGreedoidsCollector {
+    fn add(&mut self, soliton_id: &InternalKey, causet_locale: &[u8]);
+
+    fn finish(&mut self, user_collected: &mut BTreeMap<String, Vec<u8>>);
+}
+
+/// Writes a table from entries added in ascending internal soliton_id order.
+pub struct TableBuilder {
+    path: PathBuf,
+    file: BufWriter<File>,
+    offset: u64,
+    block_size: usize,
+    bloom_bits_per_key: usize,
+    compression: Compression,
+    block: Vec<u8>,
+    last_key: Option<InternalKey>,
+    index: Vec<(InternalKey, BlockHandle)>,
+    key_hashes: Vec<u32>,
+    range_tombstones: Vec<RangeTombstone>,
+    props: TableGreedoids,
+    collectors: Vec<Box<dyn TableGreedoidsCollector>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl TableBuilder {
+    pub fn create(
+        path: &Path,
+        block_size: usize,
+        bloom_bits_per_key: usize,
+    ) -> Result<TableBuilder> {
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(TableBuilder {
+            path: path.to_owned(),
+            file: BufWriter::new(file),
+            offset: 0,
+            block_size,
+            bloom_bits_per_key,
+            compression: Compression::None,
+            block: Vec::new(),
+            last_key: None,
+            index: Vec::new(),
+            key_hashes: Vec::new(),
+            range_tombstones: Vec::new(),
+            props: TableGreedoids {
+                smallest_seq: u64::MAX,
+                ..Default::default()
+            },
+            collectors: Vec::new(),
+            rate_limiter: None,
+        })
+    }
+
+    /// Sets the compression of data blocks; it must pass `check_supported`.
+    pub fn set_compression(&mut self, compression: Compression) {
+        self.compression = compression;
+    }
+
+    /// Makes block writes wait on `rate_limiter`.
+    pub fn set_rate_limiter(&mut self, rate_limiter: Arc<RateLimiter>) {
+        self.rate_limiter = Some(rate_limiter);
+    }
+
+    pub fn add_collector(&mut self, collector: Box<dyn TableGreedoidsCollector>) {
+        self.collectors.push(collector);
+    }
+
+    pub fn add(&mut self, soliton_id: &InternalKey, causet_locale: &[u8]) -> Result<()> {
+        if let Some(last) = &self.last_key {
+            if last >= soliton_id {
+                return Err(Error::Engine(format!(
+                    "soliton_ids added to {} out of order",
+                    self.path.display()
+                )));
+            }
+            if last.user_key != soliton_id.user_key {
+                self.key_hashes.push(bloom_hash(&soliton_id.user_key));
+            }
+        } else {
+            self.key_hashes.push(bloom_hash(&soliton_id.user_key));
+            self.props.smallest_key = Some(soliton_id.clone());
+        }
+        soliton_id.encode_to(&mut self.block);
+        put_length_prefixed(&mut self.block, causet_locale);
+
+        let props = &mut self.props;
+        props.num_entries += 1;
+        if soliton_id.kind == ValueKind::Delete {
+            props.num_deletions += 1;
+        }
+        props.raw_key_size += soliton_id.user_key.len() as u64;
+        props.raw_value_size += causet_locale.len() as u64;
+        props.smallest_seq = props.smallest_seq.min(soliton_id.seq);
+        props.largest_seq = props.largest_seq.max(soliton_id.seq);
+        for collector in &mut self.collectors {
+            collector.add(soliton_id, causet_locale);
+        }
+        self.last_key = Some(soliton_id.clone());
+
+        if self.block.len() >= self.block_size {
+            self.flush_block()?;
+        }
+        Ok(())
+    }
+
+    /// Adds a range tombstone. Tombstones may be added in any order, before or after
+    /// the entries they cover; the bounds of the table include their ranges.
+    pub fn add_range_tombstone(&mut self, tombstone: &RangeTombstone) {
+        let props = &mut self.props;
+        props.num_range_deletions += 1;
+        props.smallest_seq = props.smallest_seq.min(tombstone.seq);
+        props.largest_seq = props.largest_seq.max(tombstone.seq);
+        self.range_tombstones.push(tombstone.clone());
+    }
+
+    /// Whether neither an entry nor a range tombstone was added.
+    pub fn is_empty(&self) -> bool {
+        self.last_key.is_none() && self.range_tombstones.is_empty()
+    }
+
+    /// The size the file would have if it were finished now, roughly.
+    pub fn file_size(&self) -> u64 {
+        self.offset + self.block.len() as u64
+    }
+
+    fn write_block(&mut self, payload: &[u8], compression: Compression) -> Result<BlockHandle> {
+        let compressed = compress(compression, payload);
+        let (payload, id) = match &compressed {
+            Some(compressed) => (compressed.as_slice(), compression_id(compression)),
+            None => (payload, compression_id(Compression::None)),
+        };
+        let handle = BlockHandle {
+            offset: self.offset,
+            size: payload.len() as u64,
+        };
+        let mut trailer = [id, 0, 0, 0, 0];
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(payload);
+        hasher.update(&trailer[..1]);
+        trailer[1..].copy_from_slice(&hasher.finalize().to_le_bytes());
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.request(payload.len() + BLOCK_TRAILER_SIZE);
+        }
+        self.file.write_all(payload)?;
+        self.file.write_all(&trailer)?;
+        self.offset += (payload.len() + BLOCK_TRAILER_SIZE) as u64;
+        Ok(handle)
+    }
+
+    fn flush_block(&mut self) -> Result<()> {
+        if self.block.is_empty() {
+            return Ok(());
+        }
+        let block = std::mem::take(&mut self.block);
+        let handle = self.write_block(&block, self.compression)?;
+        self.index.push((self.last_key.clone().unwrap(), handle));
+        Ok(())
+    }
+
+    /// Writes the metadata blocks and syncs the file.
+    pub fn finish(mut self) -> Result<TableGreedoids> {
+        self.flush_block()?;
+        if self.props.smallest_seq == u64::MAX {
+            self.props.smallest_seq = 0;
+        }
+        self.props.largest_key = self.last_key.clone();
+        for t in &self.range_tombstones {
+            let (start, end) = (
+                InternalKey::range_bound(&t.start),
+                InternalKey::range_bound(&t.end),
+            );
+            let props = &mut self.props;
+            if props.smallest_key.as_ref().is_none_or(|k| &start < k) {
+                props.smallest_key = Some(start);
+            }
+            if props.largest_key.as_ref().is_none_or(|k| &end > k) {
+                props.largest_key = Some(end);
+            }
+        }
+        for collector in &mut self.collectors {
+            collector.finish(&mut self.props.user_collected);
+        }
+
+        let filter = build_filter(&self.key_hashes, self.bloom_bits_per_key);
+        let filter_handle = self.write_block(&filter, Compression::None)?;
+        let mut range_del = Vec::new();
+        for t in &self.range_tombstones {
+            t.encode_to(&mut range_del);
+        }
+        let range_del_handle = self.write_block(&range_del, Compression::None)?;
+        let props = self.props.encode();
+        let props_handle = self.write_block(&props, Compression::None)?;
+        let mut index = Vec::new();
+        for (soliton_id, handle) in &self.index {
+            soliton_id.encode_to(&mut index);
+            handle.encode_to(&mut index);
+        }
+        let index_handle = self.write_block(&index, Compression::None)?;
+
+        let mut footer = Vec::with_capacity(FOOTER_SIZE);
+        index_handle.encode_to(&mut footer);
+        filter_handle.encode_to(&mut footer);
+        range_del_handle.encode_to(&mut footer);
+        props_handle.encode_to(&mut footer);
+        footer.extend_from_slice(&MAGIC.to_le_bytes());
+        self.file.write_all(&footer)?;
+        self.file.flush()?;
+        self.file.get_ref().sync_all()?;
+        Ok(self.props)
+    }
+}
+
+/// An open table. Index, filter, range tombstones and greedoids are kept in memory.
+pub struct TableReader {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    index: Vec<(InternalKey, BlockHandle)>,
+    filter: Vec<u8>,
+    range_tombstones: Vec<RangeTombstone>,
+    fragmented: Arc<FragmentedRangeTombstones>,
+    props: TableGreedoids,
+    cache: Option<(TableCache, u64)>,
+    /// The charge of the index and filter in the block cache.
+    _reservation: Option<CacheReservation>,
+}
+
+impl TableReader {
+    pub fn open(path: &Path) -> Result<TableReader> {
+        let file = File::open(path)?;
+        let size = file.metadata()?.len();
+        if size < FOOTER_SIZE as u64 {
+            return Err(Error::Corruption(format!(
+                "{} is too short to be a table",
+                path.display()
+            )));
+        }
+        let mut footer = vec![0; FOOTER_SIZE];
+        file.read_exact_at(&mut footer, size - FOOTER_SIZE as u64)?;
+        let mut buf = footer.as_slice();
+        let index_handle = BlockHandle::decode_from(&mut buf)?;
+        let filter_handle = BlockHandle::decode_from(&mut buf)?;
+        let range_del_handle = BlockHandle::decode_from(&mut buf)?;
+        let props_handle = BlockHandle::decode_from(&mut buf)?;
+        if get_fixed_u64(&mut buf)? != MAGIC {
+            return Err(Error::Corruption(format!(
+                "{} has a bad table magic number",
+                path.display()
+            )));
+        }
+
+        let mut reader = TableReader {
+            path: path.to_owned(),
+            file,
+            size,
+            index: Vec::new(),
+            filter: Vec::new(),
+            range_tombstones: Vec::new(),
+            fragmented: Arc::default(),
+            props: TableGreedoids::default(),
+            cache: None,
+            _reservation: None,
+        };
+        reader.filter = reader.read_block(filter_handle)?;
+        let range_del = reader.read_block(range_del_handle)?;
+        let mut buf = range_del.as_slice();
+        while !buf.is_empty() {
+            reader
+                .range_tombstones
+                .push(RangeTombstone::decode_from(&mut buf)?);
+        }
+        reader.fragmented = Arc::new(FragmentedRangeTombstones::new(&reader.range_tombstones));
+        reader.props = TableGreedoids::decode(&reader.read_block(props_handle)?)?;
+        let index = reader.read_block(index_handle)?;
+        let mut buf = index.as_slice();
+        while !buf.is_empty() {
+            let soliton_id = InternalKey::decode_from(&mut buf)?;
+            reader
+                .index
+                .push((soliton_id, BlockHandle::decode_from(&mut buf)?));
+        }
+        Ok(reader)
+    }
+
+    pub fn file_size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn greedoids(&self) -> &TableGreedoids {
+        &self.props
+    }
+
+    pub fn range_tombstones(&self) -> &[RangeTombstone] {
+        &self.range_tombstones
+    }
+
+    pub fn fragmented_range_tombstones(&self) -> &Arc<FragmentedRangeTombstones> {
+        &self.fragmented
+    }
+
+    fn read_block(&self, handle: BlockHandle) -> Result<Vec<u8>> {
+        let mut data = vec![0; handle.size as usize + BLOCK_TRAILER_SIZE];
+        self.file.read_exact_at(&mut data, handle.offset)?;
+        let size = handle.size as usize;
+        let checksum = u32::from_le_bytes(data[size + 1..].try_into().unwrap());
+        data.truncate(size + 1);
+        if crc32(&data) != checksum {
+            return Err(Error::Corruption(format!(
+                "block checksum mismatch in {} at offset {}",
+                self.path.display(),
+                handle.offset
+            )));
+        }
+        let id = data.pop().unwrap();
+        decompress(id, &data)
+    }
+
+    /// Reads data blocks through `cache` from now on, and charges the index and
+    /// filter to it.
+    pub fn set_block_cache(&mut self, cache: TableCache) {
+        let id = cache.cache.new_id();
+        let index_size: usize = self.index.iter().map(|(k, _)| k.user_key.len() + 32).sum();
+        self._reservation = Some(cache.cache.reserve(id, index_size + self.filter.len()));
+        self.cache = Some((cache, id));
+    }
+
+    fn read_data_block(&self, idx: usize, fill_cache: bool) -> Result<Block> {
+        let handle = self.index[idx].1;
+        if let Some((cache, id)) = &self.cache {
+            let block = cache.cache.lookup(*id, handle.offset);
+            cache.counters.record(block.is_some());
+            if let Some(block) = block {
+                return Ok(block);
+            }
+        }
+        let data = self.read_block(handle)?;
+        let mut entries = Vec::new();
+        let mut buf = data.as_slice();
+        while !buf.is_empty() {
+            let soliton_id = InternalKey::decode_from(&mut buf)?;
+            entries.push((soliton_id, get_length_prefixed(&mut buf)?.to_vec()));
+        }
+        let block = Arc::new(entries);
+        if let (Some((cache, id)), true) = (&self.cache, fill_cache) {
+            cache
+                .cache
+                .insert(*id, handle.offset, block.clone(), data.len(), Priority::Low);
+        }
+        Ok(block)
+    }
+
+    /// The index of the first block that may contain soliton_ids `>= target`.
+    fn find_block(&self, target: &InternalKey) -> usize {
+        self.index.partition_point(|(last, _)| last < target)
+    }
+
+    pub fn may_contain(&self, user_key: &[u8]) -> bool {
+        may_contain(&self.filter, bloom_hash(user_key))
+    }
+
+    /// Returns the newest version of `user_key` whose sequence number is at most `seq`.
+    pub fn get(&self, user_key: &[u8], seq: u64, fill_cache: bool) -> Result<Lookup> {
+        let covering_seq = self.fragmented.max_covering_seq(user_key, seq);
+        let not_found = if covering_seq != 0 {
+            Lookup::Deleted
+        } else {
+            Lookup::NotFound
+        };
+        if !self.may_contain(user_key) {
+            return Ok(not_found);
+        }
+        let target = InternalKey::lookup(user_key, seq);
+        let idx = self.find_block(&target);
+        if idx == self.index.len() {
+            return Ok(not_found);
+        }
+        let entries = self.read_data_block(idx, fill_cache)?;
+        let pos = entries.partition_point(|(k, _)| k < &target);
+        Ok(match entries.get(pos) {
+            Some((k, v)) if k.user_key == user_key && k.seq > covering_seq => match k.kind {
+                ValueKind::Put => Lookup::Found(v.clone()),
+                ValueKind::Delete => Lookup::Deleted,
+            },
+            _ => not_found,
+        })
+    }
+}
+
+/// Iterates over the entries of a table, one data block in memory at a time.
+pub struct TableIterator {
+    table: Arc<TableReader>,
+    fill_cache: bool,
+    block_idx: usize,
+    entries: Block,
+    pos: usize,
+}
+
+impl TableIterator {
+    pub fn new(table: Arc<TableReader>, fill_cache: bool) -> TableIterator {
+        let block_idx = table.index.len();
+        TableIterator {
+            table,
+            fill_cache,
+            block_idx,
+            entries: Block::default(),
+            pos: 0,
+        }
+    }
+
+    fn load_block(&mut self, idx: usize) -> Result<()> {
+        self.block_idx = idx;
+        self.pos = 0;
+        self.entries = if idx < self.table.index.len() {
+            self.table.read_data_block(idx, self.fill_cache)?
+        } else {
+            Block::default()
+        };
+        Ok(())
+    }
+
+    /// Positions at the last entry of block `idx`, or before the first entry of the
+    /// table if there is no such block.
+    fn load_block_back(&mut self, idx: Option<usize>) -> Result<()> {
+        match idx {
+            Some(idx) => {
+                self.load_block(idx)?;
+                self.pos = self.entries.len() - 1;
+            }
+            None => self.load_block(self.table.index.len())?,
+        }
+        Ok(())
+    }
+}
+
+impl InternalIterator for TableIterator {
+    fn valid(&self) -> bool {
+        self.pos < self.entries.len()
+    }
+
+    fn seek_to_first(&mut self) -> Result<()> {
+        self.load_block(0)
+    }
+
+    fn seek(&mut self, target: &InternalKey) -> Result<()> {
+        let idx = self.table.find_block(target);
+        if idx != self.block_idx {
+            self.load_block(idx)?;
+        }
+        self.pos = self.entries.partition_point(|(k, _)| k < target);
+        Ok(())
+    }
+
+    fn seek_to_last(&mut self) -> Result<()> {
+        self.load_block_back(self.table.index.len().checked_sub(1))
+    }
+
+    fn seek_for_prev(&mut self, target: &InternalKey) -> Result<()> {
+        // The first block ending after `target` may still start before it.
+        let idx = self.table.index.partition_point(|(last, _)| last <= target);
+        if idx == self.table.index.len() {
+            return self.seek_to_last();
+        }
+        if idx != self.block_idx {
+            self.load_block(idx)?;
+        }
+        match self.entries.partition_point(|(k, _)| k <= target) {
+            0 => self.load_block_back(idx.checked_sub(1)),
+            n => {
+                self.pos = n - 1;
+                Ok(())
+            }
+        }
+    }
+
+    fn next(&mut self) -> Result<()> {
+        self.pos += 1;
+        if self.pos >= self.entries.len() && self.block_idx < self.table.index.len() {
+            self.load_block(self.block_idx + 1)?;
+        }
+        Ok(())
+    }
+
+    fn prev(&mut self) -> Result<()> {
+        if self.pos > 0 {
+            self.pos -= 1;
+            return Ok(());
+        }
+        self.load_block_back(self.block_idx.checked_sub(1))
+    }
+
+    fn soliton_id(&self) -> &InternalKey {
+        &self.entries[self.pos].0
+    }
+
+    fn causet_locale(&self) -> &[u8] {
+        &self.entries[self.pos].1
+    }
+
+    fn largest_seq(&self) -> u64 {
+        self.table.props.largest_seq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_build_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("000001.sst");
+        let mut builder = TableBuilder::create(&path, 256, 10).unwrap();
+        for i in 0..1000u64 {
+            let soliton_id = format!("k{:04}", i);
+            if i % 10 == 0 {
+                builder
+                    .add(
+                        &InternalKey::new(soliton_id.as_bytes(), i + 2, ValueKind::Delete),
+                        b"",
+                    )
+                    .unwrap();
+            }
+            builder
+                .add(
+                    &InternalKey::new(soliton_id.as_bytes(), i + 1, ValueKind::Put),
+                    soliton_id.as_bytes(),
+                )
+                .unwrap();
+        }
+        assert!(builder
+            .add(&InternalKey::new(b"a", 1, ValueKind::Put), b"")
+            .is_err());
+        let props = builder.finish().unwrap();
+        assert_eq!(props.num_entries, 1100);
+        assert_eq!(props.num_deletions, 100);
+
+        let table = Arc::new(TableReader::open(&path).unwrap());
+        assert_eq!(table.greedoids(), &props);
+        assert_eq!(
+            table.greedoids().smallest_key.as_ref().unwrap().user_key,
+            b"k0000"
+        );
+        assert!(table.index.len() > 10);
+
+        assert_eq!(
+            table.get(b"k0001", u64::MAX, true).unwrap(),
+            Lookup::Found(b"k0001".to_vec())
+        );
+        assert_eq!(table.get(b"k0001", 1, true).unwrap(), Lookup::NotFound);
+        assert_eq!(
+            table.get(b"k0010", u64::MAX, true).unwrap(),
+            Lookup::Deleted
+        );
+        assert_eq!(
+            table.get(b"k0010", 11, true).unwrap(),
+            Lookup::Found(b"k0010".to_vec())
+        );
+        assert_eq!(
+            table.get(b"k2000", u64::MAX, true).unwrap(),
+            Lookup::NotFound
+        );
+
+        let mut iter = TableIterator::new(table.clone(), true);
+        iter.seek_to_first().unwrap();
+        let mut count = 0;
+        let mut last: Option<InternalKey> = None;
+        while iter.valid() {
+            assert!(last.is_none_or(|l| &l < iter.soliton_id()));
+            last = Some(iter.soliton_id().clone());
+            count += 1;
+            iter.next().unwrap();
+        }
+        assert_eq!(count, 1100);
+        iter.seek(&InternalKey::lookup(b"k0500", u64::MAX)).unwrap();
+        assert_eq!(iter.soliton_id().user_key, b"k0500");
+        iter.seek(&InternalKey::lookup(b"k9", u64::MAX)).unwrap();
+        assert!(!iter.valid());
+
+        iter.seek_to_last().unwrap();
+        let mut count = 0;
+        last = None;
+        while iter.valid() {
+            assert!(last.is_none_or(|l| &l > iter.soliton_id()));
+            last = Some(iter.soliton_id().clone());
+            count += 1;
+            iter.prev().unwrap();
+        }
+        assert_eq!(count, 1100);
+        iter.seek_for_prev(&InternalKey::last_bound(b"k0500"))
+            .unwrap();
+        assert_eq!(
+            iter.soliton_id(),
+            &InternalKey::new(b"k0500", 501, ValueKind::Put)
+        );
+        iter.seek_for_prev(&InternalKey::range_bound(b"k0500"))
+            .unwrap();
+        assert_eq!(iter.soliton_id().user_key, b"k0499");
+        iter.seek_for_prev(&InternalKey::range_bound(b"k0000"))
+            .unwrap();
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn test_table_range_tombstones() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("000001.sst");
+        let mut builder = TableBuilder::create(&path, 4096, 10).unwrap();
+        builder
+            .add(&InternalKey::new(b"c", 5, ValueKind::Put), b"new")
+            .unwrap();
+        builder
+            .add(&InternalKey::new(b"c", 2, ValueKind::Put), b"old")
+            .unwrap();
+        builder.add_range_tombstone(&RangeTombstone::new(b"b", b"d", 3));
+        builder.add_range_tombstone(&RangeTombstone::new(b"a", b"c", 1));
+        let props = builder.finish().unwrap();
+        assert_eq!(props.num_range_deletions, 2);
+        assert_eq!((props.smallest_seq, props.largest_seq), (1, 5));
+        assert_eq!(props.smallest_key, Some(InternalKey::range_bound(b"a")));
+        assert_eq!(props.largest_key, Some(InternalKey::range_bound(b"d")));
+
+        let table = TableReader::open(&path).unwrap();
+        assert_eq!(table.greedoids(), &props);
+        assert_eq!(table.range_tombstones().len(), 2);
+        assert_eq!(table.get(b"a", u64::MAX, true).unwrap(), Lookup::Deleted);
+        assert_eq!(table.get(b"a", 0, true).unwrap(), Lookup::NotFound);
+        assert_eq!(
+            table.get(b"c", u64::MAX, true).unwrap(),
+            Lookup::Found(b"new".to_vec())
+        );
+        assert_eq!(table.get(b"c", 4, true).unwrap(), Lookup::Deleted);
+        assert_eq!(
+            table.get(b"c", 2, true).unwrap(),
+            Lookup::Found(b"old".to_vec())
+        );
+        assert_eq!(table.get(b"d", u64::MAX, true).unwrap(), Lookup::NotFound);
+    }
+
+    #[test]
+    fn test_table_corruption() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("000001.sst");
+        let mut builder = TableBuilder::create(&path, 4096, 10).unwrap();
+        builder
+            .add(&InternalKey::new(b"k", 1, ValueKind::Put), b"causet_locale")
+            .unwrap();
+        builder.finish().unwrap();
+        let mut data = std::fs::read(&path).unwrap();
+        data[2] ^= 0xff;
+        std::fs::write(&path, &data).unwrap();
+        let table = TableReader::open(&path).unwrap();
+        assert!(matches!(
+            table.get(b"k", u64::MAX, true),
+            Err(Error::Corruption(_))
+        ));
+        std::fs::write(&path, &data[..10]).unwrap();
+        assert!(TableReader::open(&path).is_err());
+    }
+}
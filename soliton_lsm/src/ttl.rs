@@ -0,0 +1,151 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! `TtlGreedoids` of the SSTs of causet_merge families listed in
+//! `LsmOptions::ttl_namespaceds`.
+
+use std::collections::BTreeMap;
+
+use fdb_traits::{split_expire_ts, Error, Result, TtlGreedoids, TtlGreedoidsExt};
+
+use crate::codec::{get_varint, put_varint, InternalKey, ValueKind};
+use crate::engine::LsmEngine;
+use crate::sst::{TableGreedoids, TableGreedoidsCollector};
+use crate::version::table_file_path;
+
+/// Name of the `TtlGreedoids` in `TableGreedoids::user_collected`.
+pub const TTL_GREEDOIDS_NAME: &str = "einsteindb.ttl";
+
+#[derive(Default)]
+pub struct TtlGreedoidsCollector {
+    greedoids: TtlGreedoids,
+}
+
+impl TableGreedoidsCollector for TtlGreedoidsCollector {
+    fn add(&mut self, soliton_id: &InternalKey, causet_locale: &[u8]) {
+        if soliton_id.kind != ValueKind::Put {
+            return;
+        }
+        if let Some((_, expire_ts)) = split_expire_ts(causet_locale) {
+            self.greedoids.add(expire_ts);
+        }
+    }
+
+    fn finish(&mut self, user_collected: &mut BTreeMap<String, Vec<u8>>) {
+        if self.greedoids.is_empty() {
+            return;
+        }
+        let mut buf = Vec::new();
+        put_varint(&mut buf, self.greedoids.max_expire_ts);
+        put_varint(&mut buf, self.greedoids.min_expire_ts);
+        user_collected.insert(TTL_GREEDOIDS_NAME.to_owned(), buf);
+    }
+}
+
+/// Returns the `TtlGreedoids` recorded in `props`, if any.
+pub fn decode_ttl_greedoids(props: &TableGreedoids) -> Result<Option<TtlGreedoids>> {
+    let mut buf = match props.user_collected.get(TTL_GREEDOIDS_NAME) {
+        Some(buf) => buf.as_slice(),
+        None => return Ok(None),
+    };
+    let greedoids = TtlGreedoids {
+        max_expire_ts: get_varint(&mut buf)?,
+        min_expire_ts: get_varint(&mut buf)?,
+    };
+    if !buf.is_empty() {
+        return Err(Error::Corruption(
+            "trailing bytes in ttl greedoids".to_owned(),
+        ));
+    }
+    Ok(Some(greedoids))
+}
+
+impl TtlGreedoidsExt for LsmEngine {
+    fn get_range_ttl_greedoids_namespaced(
+        &self,
+        namespaced: &str,
+        start_soliton_id: &[u8],
+        end_soliton_id: &[u8],
+    ) -> Result<Vec<(String, TtlGreedoids)>> {
+        let version = self.current_version(namespaced)?;
+        let mut res = Vec::new();
+        for level in 0..version.levels.len() {
+            for f in version.overlapping_files(level, start_soliton_id, end_soliton_id) {
+                if let Some(greedoids) = decode_ttl_greedoids(f.table.greedoids())? {
+                    let local_path = table_file_path(self.local_path(), f.number);
+                    res.push((local_path.display().to_string(), greedoids));
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::LsmOptions;
+    use fdb_traits::{append_expire_ts, MiscExt, NAMESPACED_DEFAULT};
+
+    #[test]
+    fn test_ttl_greedoids() {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = LsmOptions {
+            namespaceds: vec!["write".to_owned()],
+            ttl_namespaceds: vec![NAMESPACED_DEFAULT.to_owned()],
+            ..Default::default()
+        };
+        let einstein_merkle_tree = LsmEngine::open(dir.path(), opts).unwrap();
+        for (soliton_id, expire_ts) in [(&b"a"[..], 0), (b"b", 20), (b"c", 10), (b"d", 30)] {
+            let mut causet_locale = b"v".to_vec();
+            append_expire_ts(&mut causet_locale, expire_ts);
+            einstein_merkle_tree
+                .put(soliton_id, &causet_locale)
+                .unwrap();
+            einstein_merkle_tree
+                .put_namespaced("write", soliton_id, &causet_locale)
+                .unwrap();
+        }
+        einstein_merkle_tree.flush(true).unwrap();
+
+        let greedoids = einstein_merkle_tree
+            .get_range_ttl_greedoids_namespaced(NAMESPACED_DEFAULT, b"", b"z")
+            .unwrap();
+        assert_eq!(greedoids.len(), 1);
+        assert_eq!(
+            greedoids[0].1,
+            TtlGreedoids {
+                max_expire_ts: 30,
+                min_expire_ts: 10,
+            }
+        );
+        assert!(einstein_merkle_tree
+            .get_range_ttl_greedoids_namespaced(NAMESPACED_DEFAULT, b"x", b"z")
+            .unwrap()
+            .is_empty());
+        assert!(einstein_merkle_tree
+            .get_range_ttl_greedoids_namespaced("write", b"", b"z")
+            .unwrap()
+            .is_empty());
+
+        // Compaction outputs only count the causet_locales they keep.
+        einstein_merkle_tree.delete(b"d").unwrap();
+        einstein_merkle_tree.compact_range(None, None).unwrap();
+        assert_eq!(
+            einstein_merkle_tree
+                .num_files_at_level(NAMESPACED_DEFAULT, 0)
+                .unwrap(),
+            0
+        );
+        let compacted = einstein_merkle_tree
+            .get_range_ttl_greedoids_namespaced(NAMESPACED_DEFAULT, b"", b"z")
+            .unwrap();
+        assert_eq!(compacted.len(), 1);
+        assert_eq!(
+            compacted[0].1,
+            TtlGreedoids {
+                max_expire_ts: 20,
+                min_expire_ts: 10,
+            }
+        );
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The set of live SST files of each causet_merge family and the manifest that
+//! persists it.
+//!
+//! The manifest is small, so it is rewritten as a whole (to `MANIFEST.tmp`, then
+//! renamed over `MANIFEST`) every time the file set changes.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use fdb_traits::{Error, Result};
+
+use crate::cache::TableCache;
+use crate::codec::*;
+use crate::iterator::InternalIterator;
+use crate::memtable::Lookup;
+use crate::range_del::RangeDelAggregator;
+use crate::sst::{TableIterator, TableReader};
+
+pub const MANIFEST_FILE: &str = "MANIFEST";
+const MANIFEST_TMP_FILE: &str = "MANIFEST.tmp";
+
+pub fn table_file_path(dir: &Path, number: u64) -> PathBuf {
+    dir.join(format!("{:06}.sst", number))
+}
+
+pub fn log_file_path(dir: &Path, number: u64) -> PathBuf {
+    dir.join(format!("{:06}.log", number))
+}
+
+/// A live SST file.
+///
+/// The bounds of a file cover the ranges of its tombstones. A largest soliton_id that
+/// is the end of a range tombstone (`InternalKey::range_bound`) is exclusive.
+pub struct FileMeta {
+    pub number: u64,
+    pub table: Arc<TableReader>,
+    pub smallest: InternalKey,
+    pub largest: InternalKey,
+}
+
+impl FileMeta {
+    /// Opens table `number`, reading through `cache` if there is one.
+    pub fn open(dir: &Path, number: u64, cache: Option<&TableCache>) -> Result<FileMeta> {
+        let mut table = TableReader::open(&table_file_path(dir, number))?;
+        if let Some(cache) = cache {
+            table.set_block_cache(cache.clone());
+        }
+        let props = table.greedoids();
+        let (smallest, largest) = match (&props.smallest_key, &props.largest_key) {
+            (Some(s), Some(l)) => (s.clone(), l.clone()),
+            _ => return Err(Error::Corruption(format!("table {} is empty", number))),
+        };
+        Ok(FileMeta {
+            number,
+            table: Arc::new(table),
+            smallest,
+            largest,
+        })
+    }
+
+    pub fn file_size(&self) -> u64 {
+        self.table.file_size()
+    }
+
+    /// Whether every user soliton_id of the file is smaller than `user_key`.
+    pub fn ends_before(&self, user_key: &[u8]) -> bool {
+        match self.largest.user_key.as_slice().cmp(user_key) {
+            Ordering::Less => true,
+            Ordering::Equal => self.largest.is_range_bound(),
+            Ordering::Greater => false,
+        }
+    }
+
+    /// Whether the user soliton_id range of the file intersects `[start, end]`.
+    pub fn overlaps(&self, start: &[u8], end: &[u8]) -> bool {
+        !self.ends_before(start) && self.smallest.user_key.as_slice() <= end
+    }
+
+    /// Whether the user soliton_id range of the file intersects `[start, end)`.
+    pub fn overlaps_range(&self, start: &[u8], end: &[u8]) -> bool {
+        !self.ends_before(start) && self.smallest.user_key.as_slice() < end
+    }
+
+    /// Whether the user soliton_id range of the file lies in `[start, end)`.
+    pub fn is_within(&self, start: &[u8], end: &[u8]) -> bool {
+        self.smallest.user_key.as_slice() >= start && self.ends_before(end)
+    }
+}
+
+/// The files of one causet_merge family. Level 0 files may overlap and are kept in
+/// the order they were added, oldest first; files of deeper levels are disjoint and
+/// sorted by soliton_id.
+#[derive(Clone)]
+pub struct Version {
+    pub levels: Vec<Vec<Arc<FileMeta>>>,
+}
+
+impl Version {
+    pub fn new(num_levels: usize) -> Version {
+        Version {
+            levels: vec![Vec::new(); num_levels],
+        }
+    }
+
+    pub fn add_file(&mut self, level: usize, file: Arc<FileMeta>) {
+        let files = &mut self.levels[level];
+        let pos = if level == 0 {
+            files.len()
+        } else {
+            files.partition_point(|f| f.smallest < file.smallest)
+        };
+        files.insert(pos, file);
+    }
+
+    pub fn remove_files(&mut self, numbers: &HashSet<u64>) {
+        for files in &mut self.levels {
+            files.retain(|f| !numbers.contains(&f.number));
+        }
+    }
+
+    pub fn level_size(&self, level: usize) -> u64 {
+        self.levels[level].iter().map(|f| f.file_size()).sum()
+    }
+
+    pub fn overlapping_files(&self, level: usize, start: &[u8], end: &[u8]) -> Vec<Arc<FileMeta>> {
+        self.levels[level]
+            .iter()
+            .filter(|f| f.overlaps(start, end))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the newest version of `user_key` whose sequence number is at most `seq`.
+    pub fn get(&self, user_key: &[u8], seq: u64, fill_cache: bool) -> Result<Lookup> {
+        for file in self.levels[0].iter().rev() {
+            if !file.overlaps(user_key, user_key) {
+                continue;
+            }
+            match file.table.get(user_key, seq, fill_cache)? {
+                Lookup::NotFound => {}
+                found => return Ok(found),
+            }
+        }
+        for files in &self.levels[1..] {
+            let idx = files.partition_point(|f| f.ends_before(user_key));
+            if let Some(file) = files.get(idx) {
+                if file.smallest.user_key.as_slice() <= user_key {
+                    match file.table.get(user_key, seq, fill_cache)? {
+                        Lookup::NotFound => {}
+                        found => return Ok(found),
+                    }
+                }
+            }
+        }
+        Ok(Lookup::NotFound)
+    }
+
+    pub fn iterators(&self, fill_cache: bool) -> Vec<Box<dyn InternalIterator>> {
+        self.levels
+            .iter()
+            .flatten()
+            .map(|f| {
+                Box::new(TableIterator::new(f.table.clone(), fill_cache))
+                    as Box<dyn InternalIterator>
+            })
+            .collect()
+    }
+
+    pub fn add_range_tombstones(&self, range_dels: &mut RangeDelAggregator) {
+        for f in self.levels.iter().flatten() {
+            range_dels.add(f.table.fragmented_range_tombstones());
+        }
+    }
+}
+
+/// What the manifest records.
+#[derive(Debug, Default, PartialEq)]
+pub struct ManifestData {
+    pub next_file_number: u64,
+    pub last_seq: u64,
+    /// Logs with a smaller number have been flushed completely.
+    pub log_number: u64,
+    /// The file numbers of each level of each causet_merge family.
+    pub namespaceds: BTreeMap<String, Vec<Vec<u64>>>,
+}
+
+impl ManifestData {
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        put_varint(&mut buf, self.next_file_number);
+        put_varint(&mut buf, self.last_seq);
+        put_varint(&mut buf, self.log_number);
+        put_varint(&mut buf, self.namespaceds.len() as u64);
+        for (name, levels) in &self.namespaceds {
+            put_length_prefixed(&mut buf, name.as_bytes());
+            put_varint(&mut buf, levels.len() as u64);
+            for files in levels {
+                put_varint(&mut buf, files.len() as u64);
+                for number in files {
+                    put_varint(&mut buf, *number);
+                }
+            }
+        }
+        buf
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<ManifestData> {
+        let buf = &mut buf;
+        let mut data = ManifestData {
+            next_file_number: get_varint(buf)?,
+            last_seq: get_varint(buf)?,
+            log_number: get_varint(buf)?,
+            namespaceds: BTreeMap::new(),
+        };
+        for _ in 0..get_varint(buf)? {
+            let name = String::from_utf8(get_length_prefixed(buf)?.to_vec())
+                .map_err(|e| Error::Corruption(format!("bad causet_merge family name: {}", e)))?;
+            let mut levels = Vec::new();
+            for _ in 0..get_varint(buf)? {
+                let mut files = Vec::new();
+                for _ in 0..get_varint(buf)? {
+                    files.push(get_varint(buf)?);
+                }
+                levels.push(files);
+            }
+            data.namespaceds.insert(name, levels);
+        }
+        Ok(data)
+    }
+
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        let payload = self.encode();
+        let tmp = dir.join(MANIFEST_TMP_FILE);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&tmp)?;
+        file.write_all(&crc32(&payload).to_le_bytes())?;
+        file.write_all(&payload)?;
+        file.sync_all()?;
+        fs::rename(&tmp, dir.join(MANIFEST_FILE))?;
+        File::open(dir)?.sync_all()?;
+        Ok(())
+    }
+
+    pub fn read(dir: &Path) -> Result<Option<ManifestData>> {
+        let path = dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let data = fs::read(&path)?;
+        let mut buf = data.as_slice();
+        let checksum = get_fixed_u32(&mut buf)?;
+        if crc32(buf) != checksum {
+            return Err(Error::Corruption("manifest checksum mismatch".to_owned()));
+        }
+        ManifestData::decode(buf).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(ManifestData::read(dir.path()).unwrap(), None);
+        let mut data = ManifestData {
+            next_file_number: 10,
+            last_seq: 42,
+            log_number: 9,
+            namespaceds: BTreeMap::new(),
+        };
+        data.namespaceds
+            .insert("default".to_owned(), vec![vec![3, 5], vec![], vec![7]]);
+        data.namespaceds.insert("write".to_owned(), vec![vec![]; 3]);
+        data.write(dir.path()).unwrap();
+        assert_eq!(ManifestData::read(dir.path()).unwrap(), Some(data));
+
+        let path = dir.path().join(MANIFEST_FILE);
+        let mut bytes = fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        fs::write(&path, bytes).unwrap();
+        assert!(ManifestData::read(dir.path()).is_err());
+    }
+}
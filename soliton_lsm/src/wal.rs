@@ -0,0 +1,117 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The write-ahead log.
+//!
+//! Every committed write alexandrov_poset_process is appended as one record
+//!
+//! ```text
+//!   record ::= crc32(payload): u32 | len(payload): u32 | payload
+//! ```
+//!
+//! On recovery records are replayed until the end of the file or the first torn or
+//! corrupted record, which can only be the tail written during a crash.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Read, Write};
+use std::path::Path;
+
+use fdb_traits::Result;
+
+use crate::codec::crc32;
+
+const HEADER_SIZE: usize = 8;
+
+pub struct LogWriter {
+    file: BufWriter<File>,
+}
+
+impl LogWriter {
+    pub fn create(path: &Path) -> Result<LogWriter> {
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(LogWriter {
+            file: BufWriter::new(file),
+        })
+    }
+
+    pub fn add_record(&mut self, payload: &[u8], sync: bool) -> Result<()> {
+        let mut header = [0; HEADER_SIZE];
+        header[..4].copy_from_slice(&crc32(payload).to_le_bytes());
+        header[4..].copy_from_slice(&(payload.len() as u32).to_le_bytes());
+        self.file.write_all(&header)?;
+        self.file.write_all(payload)?;
+        self.file.flush()?;
+        if sync {
+            self.file.get_ref().sync_data()?;
+        }
+        Ok(())
+    }
+
+    pub fn sync(&mut self) -> Result<()> {
+        self.file.flush()?;
+        self.file.get_ref().sync_data()?;
+        Ok(())
+    }
+}
+
+/// Reads every intact record of the log at `path`.
+pub fn read_log(path: &Path) -> Result<Vec<Vec<u8>>> {
+    let mut data = Vec::new();
+    File::open(path)?.read_to_end(&mut data)?;
+    let mut records = Vec::new();
+    let mut rest = data.as_slice();
+    while rest.len() >= HEADER_SIZE {
+        let checksum = u32::from_le_bytes(rest[..4].try_into().unwrap());
+        let len = u32::from_le_bytes(rest[4..8].try_into().unwrap()) as usize;
+        if rest.len() < HEADER_SIZE + len {
+            break;
+        }
+        let payload = &rest[HEADER_SIZE..HEADER_SIZE + len];
+        if crc32(payload) != checksum {
+            break;
+        }
+        records.push(payload.to_vec());
+        rest = &rest[HEADER_SIZE + len..];
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_replay_stops_at_torn_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("000001.log");
+        let mut writer = LogWriter::create(&path).unwrap();
+        writer.add_record(b"first", false).unwrap();
+        writer.add_record(b"second", true).unwrap();
+        writer.add_record(b"third", false).unwrap();
+        drop(writer);
+        assert_eq!(
+            read_log(&path).unwrap(),
+            vec![b"first".to_vec(), b"second".to_vec(), b"third".to_vec()]
+        );
+
+        // Cut the last record in half, then corrupt the second one.
+        let len = std::fs::metadata(&path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(len - 2)
+            .unwrap();
+        assert_eq!(
+            read_log(&path).unwrap(),
+            vec![b"first".to_vec(), b"second".to_vec()]
+        );
+        let mut data = std::fs::read(&path).unwrap();
+        data[HEADER_SIZE * 2 + 5] ^= 0xff;
+        std::fs::write(&path, data).unwrap();
+        assert_eq!(read_log(&path).unwrap(), vec![b"first".to_vec()]);
+    }
+}
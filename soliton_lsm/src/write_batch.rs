@@ -0,0 +1,124 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Write alexandrov_poset_processes.
+//!
+//! The commands of a alexandrov_poset_process are kept serialized in an `FdbWriteBatch`,
+//! and logged as
+//!
+//! ```text
+//!   record ::= first-seq: u64 | FdbWriteBatch::data()
+//! ```
+//!
+//! Command `i` of a alexandrov_poset_process is written with sequence number `first-seq + i`.
+
+use fdb_traits::{FdbWriteBatch, Mutable, Result, WriteBatch, WriteBatchExt, WriteOptions};
+
+use crate::engine::LsmEngine;
+
+pub struct LsmWriteBatch {
+    einstein_merkle_tree: LsmEngine,
+    wb: FdbWriteBatch,
+}
+
+impl LsmWriteBatch {
+    pub fn new(einstein_merkle_tree: &LsmEngine) -> LsmWriteBatch {
+        LsmWriteBatch::with_capacity(einstein_merkle_tree, 0)
+    }
+
+    pub fn as_inner(&self) -> &FdbWriteBatch {
+        &self.wb
+    }
+}
+
+impl Mutable for LsmWriteBatch {
+    fn put_namespaced(
+        &mut self,
+        namespaced: &str,
+        soliton_id: &[u8],
+        causet_locale: &[u8],
+    ) -> Result<()> {
+        self.einstein_merkle_tree.check_namespaced(namespaced)?;
+        self.wb
+            .put_namespaced(namespaced, soliton_id, causet_locale)
+    }
+
+    fn delete_namespaced(&mut self, namespaced: &str, soliton_id: &[u8]) -> Result<()> {
+        self.einstein_merkle_tree.check_namespaced(namespaced)?;
+        self.wb.delete_namespaced(namespaced, soliton_id)
+    }
+
+    fn delete_range_namespaced(
+        &mut self,
+        namespaced: &str,
+        begin_soliton_id: &[u8],
+        end_soliton_id: &[u8],
+    ) -> Result<()> {
+        self.einstein_merkle_tree.check_namespaced(namespaced)?;
+        self.wb
+            .delete_range_namespaced(namespaced, begin_soliton_id, end_soliton_id)
+    }
+}
+
+impl WriteBatch<LsmEngine> for LsmWriteBatch {
+    fn with_capacity(e: &LsmEngine, cap: usize) -> LsmWriteBatch {
+        LsmWriteBatch {
+            einstein_merkle_tree: e.clone(),
+            wb: FdbWriteBatch::with_capacity(cap),
+        }
+    }
+
+    fn write_opt(&self, opts: &WriteOptions) -> Result<()> {
+        self.einstein_merkle_tree.write(&self.wb, opts)
+    }
+
+    fn data_size(&self) -> usize {
+        self.wb.data_size()
+    }
+
+    fn count(&self) -> usize {
+        self.wb.count()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.wb.is_empty()
+    }
+
+    fn should_write_to_einstein_merkle_tree(&self) -> bool {
+        self.count() > LsmEngine::WRITE_BATCH_MAX_CAUSET_KEYS
+    }
+
+    fn clear(&mut self) {
+        self.wb.clear()
+    }
+
+    fn push_save_point(&mut self, name: &str) {
+        self.wb.push_save_point(name)
+    }
+
+    fn pop_save_point(&mut self) -> Result<()> {
+        self.wb.pop_save_point().map(|_| ())
+    }
+
+    fn rollback_to_save_point(&mut self) -> Result<()> {
+        self.wb.rollback_to_save_point().map(|_| ())
+    }
+}
+
+impl WriteBatchExt for LsmEngine {
+    type WriteBatch = LsmWriteBatch;
+    type WriteBatchVec = LsmWriteBatch;
+
+    const WRITE_BATCH_MAX_CAUSET_KEYS: usize = 256;
+
+    fn support_write_alexandrov_poset_process_vec(&self) -> bool {
+        true
+    }
+
+    fn write_alexandrov_poset_process(&self) -> LsmWriteBatch {
+        LsmWriteBatch::new(self)
+    }
+
+    fn write_alexandrov_poset_process_with_cap(&self, cap: usize) -> LsmWriteBatch {
+        LsmWriteBatch::with_capacity(self, cap)
+    }
+}
@@ -387,6 +387,43 @@ pub struct MVRSInfo {
     pub replicas_versions: Vec<u64>,
     pub replicas_data: Vec<Value>,
     pub replicas_status: Vec<MVSRStatus>,
+    pub replicas_roles: Vec<MVSRReplicaRole>,
+}
+
+
+impl MVRSInfo {
+    /// The replicas whose data is checked against each other; a witness keeps
+    /// none.
+    pub fn checked_replicas(&self) -> impl Iterator<Item = &String> {
+        self.replicas
+            .iter()
+            .zip(&self.replicas_roles)
+            .filter(|(_, role)| **role != MVSRReplicaRole::Witness)
+            .map(|(replica, _)| replica)
+    }
+
+    /// The replicas counted in the quorum; a learner is not until it is
+    /// promoted.
+    pub fn voting_replicas(&self) -> impl Iterator<Item = &String> {
+        self.replicas
+            .iter()
+            .zip(&self.replicas_roles)
+            .filter(|(_, role)| **role != MVSRReplicaRole::Learner)
+            .map(|(replica, _)| replica)
+    }
+}
+
+
+/// The role of a replica, as the replicas of a brane in violetabftstore have
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MVSRReplicaRole {
+    Voter,
+    /// Is sent the data and the log, and promoted to a voter once it caught
+    /// up.
+    Learner,
+    /// Votes and keeps the log, but not the data; it never serves reads.
+    Witness,
 }
 
 
@@ -436,6 +473,7 @@ pub struct MVSR {
     pub replicas_versions: Vec<u64>,
     pub replicas_data: Vec<Value>,
     pub replicas_status: Vec<MVSRStatus>,
+    pub replicas_roles: Vec<MVSRReplicaRole>,
 }
 
 
@@ -452,6 +490,9 @@ pub struct MVSRStatusType {
 pub enum MVSRStatusTypeType {
     OK,
     FAIL,
+    /// The checksum of the replica differed from the others', it is being
+    /// made again from a snapshot of the leader.
+    REPAIRING,
 }
 
 
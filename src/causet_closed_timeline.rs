@@ -64,6 +64,7 @@ use std::sync::Arc;
 use std::sync::atomic::{ AtomicUsize, Ordering };
 
 use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicUsize, Partitioning};
 use std::sync::mpsc::{channel, Sender, Receiver};
@@ -121,6 +122,63 @@ use mysql::{MysqlOptionResult, MysqlOption, MysqlOptionResult};
 use neo4j::{Neo4j, Neo4jError, Neo4jResult, Neo4jOption, Neo4jOptionResult};
 use neo4j::{Neo4jOptionResult, Neo4jOption, Neo4jOptionResult};
 
+use soliton::read_pool::ClosedTs;
+
+/// The timestamps the branes of a store are closed at, as its peers apply the
+/// log. No write at or before the timestamp a brane is closed at is applied
+/// after it, so any replica of the brane can serve a read at that timestamp
+/// from what it applied, without asking the leader.
+#[derive(Debug, Default)]
+pub struct ClosedTimeline {
+    branes: RwLock<HashMap<u64, u64>>,
+}
+
+impl ClosedTimeline {
+    pub fn new() -> ClosedTimeline {
+        ClosedTimeline::default()
+    }
+
+    /// Closes `brane_id` at `ts`, as its peer here applied the log up to
+    /// where the leader closed it. A brane is never reopened, so an older `ts`
+    /// changes nothing.
+    pub fn close(&self, brane_id: u64, ts: u64) {
+        let mut branes = self.branes.write().unwrap();
+        let closed_ts = branes.entry(brane_id).or_insert(0);
+        *closed_ts = (*closed_ts).max(ts);
+    }
+
+    /// `new_brane_id`, split off `brane_id`, holds data closed at the same
+    /// timestamp.
+    pub fn split(&self, brane_id: u64, new_brane_id: u64) {
+        let closed_ts = self.closed_ts(brane_id);
+        self.close(new_brane_id, closed_ts);
+    }
+
+    /// `source_id` is merged into `target_id`: the merged brane is closed
+    /// where both were.
+    pub fn merge(&self, source_id: u64, target_id: u64) {
+        let mut branes = self.branes.write().unwrap();
+        let source_ts = branes.remove(&source_id).unwrap_or(0);
+        if let Some(closed_ts) = branes.get_mut(&target_id) {
+            *closed_ts = (*closed_ts).min(source_ts);
+        }
+    }
+
+    /// Forgets `brane_id`, whose peer here is gone; it serves no stale read
+    /// until it is closed again.
+    pub fn remove(&self, brane_id: u64) {
+        self.branes.write().unwrap().remove(&brane_id);
+    }
+}
+
+impl ClosedTs for ClosedTimeline {
+    fn closed_ts(&self, brane_id: u64) -> u64 {
+        let branes = self.branes.read().unwrap();
+        branes.get(&brane_id).copied().unwrap_or(0)
+    }
+}
+
+
 ///! A `Causet` is a causet of causets.
 /// It is a causet of causets, where causets are causets of causets, and so on.
 
@@ -464,6 +522,47 @@ where
     }
     Ok(())
 }
+#[cfg(test)]
+mod closed_timeline_tests {
+    use super::*;
+    use soliton::read_pool::{route_read, ReadConsistency, ReadRoute};
+
+    #[test]
+    fn test_closed_timeline() {
+        let timeline = ClosedTimeline::new();
+        assert_eq!(timeline.closed_ts(1), 0);
+        timeline.close(1, 10);
+        // Never reopened.
+        timeline.close(1, 5);
+        assert_eq!(timeline.closed_ts(1), 10);
+
+        timeline.split(1, 2);
+        assert_eq!(timeline.closed_ts(2), 10);
+        timeline.close(2, 20);
+        timeline.close(3, 15);
+        timeline.merge(3, 2);
+        assert_eq!(timeline.closed_ts(2), 15);
+        assert_eq!(timeline.closed_ts(3), 0);
+        // A source not closed here leaves the merged brane not closed.
+        timeline.merge(4, 1);
+        assert_eq!(timeline.closed_ts(1), 0);
+
+        timeline.remove(2);
+        assert_eq!(timeline.closed_ts(2), 0);
+    }
+
+    #[test]
+    fn test_closed_timeline_routes_stale_reads() {
+        let timeline = ClosedTimeline::new();
+        timeline.close(1, 10);
+        let route = |ts| route_read(1, ReadConsistency::Stale(ts), false, &timeline);
+        assert_eq!(route(10).unwrap(), ReadRoute::Local);
+        assert!(route(11).is_err());
+        timeline.close(1, 11);
+        assert_eq!(route(11).unwrap(), ReadRoute::Local);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
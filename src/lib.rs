@@ -31,6 +31,7 @@ use causet::AllegroPoset;
 use petgraph::dot::Config;
 
 mod causal_set;
+pub mod causet_closed_timeline;
 mod config;
 
 mod encoder;
@@ -54,6 +55,7 @@ extern crate petgraph;
 extern crate slog;
 extern crate futures;
 extern crate futures;
+extern crate violetabft;
 
 use core::num::flt2dec::decoder;
 
@@ -363,30 +365,8 @@ impl AppendEntriesResponse {
 }
 
 
-#[derive(Serialize, Deserialize)]
-pub struct RequestVote {
-
-    pub term: u64,
-    pub candidate_id: u64,
-    pub last_log_index: u64,
-    pub last_log_term: u64,
-}
-
-
-impl RequestVote {
-    pub fn new() -> RequestVote {
-        RequestVote {
-            term: 0,
-            candidate_id: 0,
-            last_log_index: 0,
-            last_log_term: 0,
-        }
-    }
-
-    pub fn get_term(&mut self) -> io::Result<()> {
-        Ok(())
-    }
-}
+/// Stepped into a `violetabft::RawNode` through `BaseEinsteinDb`.
+pub use violetabft::RequestVote;
 
 
 #[derive(Serialize, Deserialize)]
@@ -409,61 +389,9 @@ impl RequestVoteResponse {
     }
 }
 
-///BASE EINSTEINDB is a variation of ACID
-
-pub trait BaseEinsteinDb {
-    fn append_log(&mut self, log: String) -> io::Result<()> {
-        if self.get_log_len() == 0 {
-            self.set_log_len(1);
-        } else {
-            self.set_log_len(self.get_log_len() + 1);
-        }
-
-        self.set_log(self.get_log_len(), log);
-        Ok(())
-    }
-    fn append_entries(&mut self, entries: Vec<String>) -> io::Result<()> {
-        //berolinasql error
-
-        let mut log_len = self.get_log_len();
-        for entry in entries {
-            log_len = log_len + 1;
-            self.set_log(log_len, entry);
-        }
-
-        self.set_log_len(log_len);
-
-        self.get_event_slice();
-        self.get_causal_set();
-        self.get_poset();
-        self.get_config();
-        self.get_db()
-    }
-    fn request_vote(&mut self, request_vote: RequestVote) {
-        //berolinasql error
-        let mut event_slice = self.get_event_slice();
-        event_slice.set_event_slice(self.get_log_len());
-
-        let mut causal_set = self.get_causal_set();
-        causal_set.set_causal_set(self.get_log_len());
-
-        let mut poset = self.get_poset();
-        poset.set_poset(self.get_log_len());
-
-        let mut config = self.get_config();
-        config.set_config(self.get_log_len());
-
-        if self.get_log_len() != 0 {
-            self.set_log_len(self.get_log_len() + 1);
-
-            let mut db = self.get_db() as *mut EinsteinDB;
-
-            db.set_db(self.get_log_len());
-        } else {
-            self.set_log_len(1);
-        }
-    }
-}
+///BASE EINSTEINDB is a variation of ACID; the replicated log is
+/// implemented by `violetabft::RawNode`.
+pub use violetabft::BaseEinsteinDb;
 
 
 
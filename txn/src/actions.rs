@@ -0,0 +1,418 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The Percolator actions on one soliton_id. Each reads the soliton_id's state
+//! through an `MvccReader` and records what it changes in an `MvccTxn`; the
+//! caller holds the latch of the soliton_id until the changes are written.
+
+use crate::engine::Snapshot;
+use crate::errors::{Error, LockInfo, Result};
+use crate::lock::{Lock, LockType};
+use crate::reader::{MvccReader, TxnCommitRecord};
+use crate::txn::MvccTxn;
+use crate::write::{Write, WriteType, SHORT_VALUE_MAX_LEN};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MutationOp {
+    Put,
+    Delete,
+    /// Locks the soliton_id without changing it, so that a concurrent write of
+    /// it conflicts.
+    Lock,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mutation {
+    pub op: MutationOp,
+    pub soliton_id: Vec<u8>,
+    pub causet_locale: Vec<u8>,
+}
+
+impl Mutation {
+    pub fn put(soliton_id: Vec<u8>, causet_locale: Vec<u8>) -> Mutation {
+        Mutation {
+            op: MutationOp::Put,
+            soliton_id,
+            causet_locale,
+        }
+    }
+
+    pub fn delete(soliton_id: Vec<u8>) -> Mutation {
+        Mutation {
+            op: MutationOp::Delete,
+            soliton_id,
+            causet_locale: Vec::new(),
+        }
+    }
+
+    pub fn lock(soliton_id: Vec<u8>) -> Mutation {
+        Mutation {
+            op: MutationOp::Lock,
+            soliton_id,
+            causet_locale: Vec::new(),
+        }
+    }
+}
+
+/// What the prewrites of one transaction share.
+pub struct TxnProps<'a> {
+    pub primary: &'a [u8],
+    pub lock_ttl: u64,
+    /// Of a pessimistic transaction, which prewrites only soliton_ids it locked;
+    /// 0 for an optimistic one.
+    pub for_update_ts: u64,
+    pub use_async_commit: bool,
+    /// With async commit, the soliton_ids other than the primary.
+    pub secondaries: &'a [Vec<u8>],
+    /// The least commit_ts of the locks.
+    pub min_commit_ts: u64,
+}
+
+/// What became of a transaction, as its primary tells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxnStatus {
+    /// Still alive: the primary lock, its `min_commit_ts` maybe pushed past the
+    /// caller.
+    Locked(Lock),
+    Committed(u64),
+    RolledBack,
+    /// Rolled back just now, as its lock expired.
+    TtlExpired,
+}
+
+/// What became of a transaction on a secondary soliton_id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecondaryLockStatus {
+    Locked(Lock),
+    Committed(u64),
+    RolledBack,
+}
+
+fn key_is_locked(soliton_id: &[u8], lock: Lock) -> Error {
+    Error::KeyIsLocked(Box::new(LockInfo {
+        soliton_id: soliton_id.to_vec(),
+        lock,
+    }))
+}
+
+/// The lock that prewrites `mutation`, or `None` if it was prewritten before.
+/// A causet_locale too long for the lock is written to the default causet_merge
+/// family at once.
+pub fn prewrite<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    props: &TxnProps<'_>,
+    mutation: Mutation,
+) -> Result<Option<Lock>> {
+    let soliton_id = &mutation.soliton_id;
+    match reader.load_lock(soliton_id)? {
+        Some(lock) if lock.start_ts != txn.start_ts => return Err(key_is_locked(soliton_id, lock)),
+        Some(lock) if lock.lock_type != LockType::Pessimistic => return Ok(None),
+        // Conflicts were checked when the pessimistic lock was taken.
+        Some(_) => {}
+        None if props.for_update_ts > 0 => {
+            return Err(Error::PessimisticLockNotFound {
+                soliton_id: soliton_id.clone(),
+                start_ts: txn.start_ts,
+            })
+        }
+        None => {
+            // Also fails if the transaction was rolled back here.
+            if let Some((commit_ts, write)) = reader.seek_write(soliton_id, u64::MAX)? {
+                if commit_ts >= txn.start_ts {
+                    return Err(Error::WriteConflict {
+                        soliton_id: soliton_id.clone(),
+                        start_ts: txn.start_ts,
+                        conflict_start_ts: write.start_ts,
+                        conflict_commit_ts: commit_ts,
+                    });
+                }
+            }
+        }
+    }
+
+    let lock_type = match mutation.op {
+        MutationOp::Put => LockType::Put,
+        MutationOp::Delete => LockType::Delete,
+        MutationOp::Lock => LockType::Lock,
+    };
+    let mut lock = Lock::new(
+        lock_type,
+        props.primary.to_vec(),
+        txn.start_ts,
+        props.lock_ttl,
+    );
+    lock.for_update_ts = props.for_update_ts;
+    lock.min_commit_ts = props.min_commit_ts;
+    if mutation.op == MutationOp::Put {
+        if mutation.causet_locale.len() <= SHORT_VALUE_MAX_LEN {
+            lock.short_value = Some(mutation.causet_locale);
+        } else {
+            txn.put_value(soliton_id, txn.start_ts, mutation.causet_locale);
+        }
+    }
+    if props.use_async_commit {
+        lock.use_async_commit = true;
+        if soliton_id.as_slice() == props.primary {
+            lock.secondaries = props.secondaries.to_vec();
+        }
+    }
+    Ok(Some(lock))
+}
+
+pub fn commit<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+    commit_ts: u64,
+) -> Result<()> {
+    match reader.load_lock(soliton_id)? {
+        Some(lock) if lock.start_ts == txn.start_ts => {
+            if commit_ts < lock.min_commit_ts {
+                return Err(Error::CommitTsExpired {
+                    soliton_id: soliton_id.to_vec(),
+                    start_ts: txn.start_ts,
+                    commit_ts,
+                    min_commit_ts: lock.min_commit_ts,
+                });
+            }
+            // A pessimistic lock that was never prewritten has nothing to commit.
+            let write_type = WriteType::from_lock_type(lock.lock_type).ok_or_else(|| {
+                Error::TxnLockNotFound {
+                    soliton_id: soliton_id.to_vec(),
+                    start_ts: txn.start_ts,
+                }
+            })?;
+            let write = Write::new(write_type, txn.start_ts, lock.short_value);
+            txn.put_write(soliton_id, commit_ts, &write);
+            txn.unlock_key(soliton_id);
+            Ok(())
+        }
+        _ => match reader.get_txn_commit_record(soliton_id, txn.start_ts)? {
+            TxnCommitRecord::Committed { .. } => Ok(()),
+            _ => Err(Error::TxnLockNotFound {
+                soliton_id: soliton_id.to_vec(),
+                start_ts: txn.start_ts,
+            }),
+        },
+    }
+}
+
+/// Writes the record that keeps a late prewrite of the transaction from
+/// succeeding; not if another transaction committed at the same timestamp,
+/// whose record it would hide.
+fn put_rollback<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+) -> Result<()> {
+    if let Some((commit_ts, _)) = reader.seek_write(soliton_id, txn.start_ts)? {
+        if commit_ts == txn.start_ts {
+            return Ok(());
+        }
+    }
+    txn.put_write(soliton_id, txn.start_ts, &Write::new_rollback(txn.start_ts));
+    Ok(())
+}
+
+fn rollback_lock<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+    lock: &Lock,
+) -> Result<()> {
+    if lock.lock_type == LockType::Put && lock.short_value.is_none() {
+        txn.delete_value(soliton_id, lock.start_ts);
+    }
+    put_rollback(txn, reader, soliton_id)?;
+    txn.unlock_key(soliton_id);
+    Ok(())
+}
+
+/// Rolls the transaction back on `soliton_id`, whether or not it got to lock
+/// it; fails if it committed.
+pub fn rollback<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+) -> Result<()> {
+    match reader.load_lock(soliton_id)? {
+        Some(lock) if lock.start_ts == txn.start_ts => {
+            rollback_lock(txn, reader, soliton_id, &lock)
+        }
+        _ => match reader.get_txn_commit_record(soliton_id, txn.start_ts)? {
+            TxnCommitRecord::Committed { commit_ts, .. } => Err(Error::Committed {
+                soliton_id: soliton_id.to_vec(),
+                start_ts: txn.start_ts,
+                commit_ts,
+            }),
+            TxnCommitRecord::RolledBack => Ok(()),
+            TxnCommitRecord::None => put_rollback(txn, reader, soliton_id),
+        },
+    }
+}
+
+/// The status of the transaction whose primary is `primary`, for a transaction
+/// started at `caller_start_ts` that met one of its locks.
+///
+/// An expired lock is rolled back. A live one has its `min_commit_ts` pushed
+/// past the caller, so that the caller may read past it, unless the
+/// transaction commits asynchronously and may have decided its commit_ts
+/// already. A transaction with no trace is rolled back if
+/// `rollback_if_not_exist`, so that its prewrite fails if it comes late.
+pub fn check_txn_status<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    primary: &[u8],
+    caller_start_ts: u64,
+    current_ts: u64,
+    rollback_if_not_exist: bool,
+) -> Result<TxnStatus> {
+    match reader.load_lock(primary)? {
+        Some(mut lock) if lock.start_ts == txn.start_ts => {
+            if lock.is_expired(current_ts) {
+                rollback_lock(txn, reader, primary, &lock)?;
+                return Ok(TxnStatus::TtlExpired);
+            }
+            if !lock.use_async_commit
+                && lock.lock_type != LockType::Pessimistic
+                && caller_start_ts != u64::MAX
+                && caller_start_ts >= lock.min_commit_ts
+            {
+                lock.min_commit_ts = caller_start_ts + 1;
+                txn.put_lock(primary, &lock);
+            }
+            Ok(TxnStatus::Locked(lock))
+        }
+        _ => match reader.get_txn_commit_record(primary, txn.start_ts)? {
+            TxnCommitRecord::Committed { commit_ts, .. } => Ok(TxnStatus::Committed(commit_ts)),
+            TxnCommitRecord::RolledBack => Ok(TxnStatus::RolledBack),
+            TxnCommitRecord::None if rollback_if_not_exist => {
+                put_rollback(txn, reader, primary)?;
+                Ok(TxnStatus::RolledBack)
+            }
+            TxnCommitRecord::None => Err(Error::TxnNotFound {
+                soliton_id: primary.to_vec(),
+                start_ts: txn.start_ts,
+            }),
+        },
+    }
+}
+
+/// The status of an asynchronously committing transaction on a secondary
+/// soliton_id. One it has not prewritten is rolled back, which decides the
+/// whole transaction.
+pub fn check_secondary_lock<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+) -> Result<SecondaryLockStatus> {
+    match reader.load_lock(soliton_id)? {
+        Some(lock) if lock.start_ts == txn.start_ts => {
+            if lock.lock_type == LockType::Pessimistic {
+                rollback_lock(txn, reader, soliton_id, &lock)?;
+                return Ok(SecondaryLockStatus::RolledBack);
+            }
+            Ok(SecondaryLockStatus::Locked(lock))
+        }
+        _ => match reader.get_txn_commit_record(soliton_id, txn.start_ts)? {
+            TxnCommitRecord::Committed { commit_ts, .. } => {
+                Ok(SecondaryLockStatus::Committed(commit_ts))
+            }
+            TxnCommitRecord::RolledBack => Ok(SecondaryLockStatus::RolledBack),
+            TxnCommitRecord::None => {
+                put_rollback(txn, reader, soliton_id)?;
+                Ok(SecondaryLockStatus::RolledBack)
+            }
+        },
+    }
+}
+
+/// Extends the TTL of the primary lock to `advise_ttl` if that is longer; the
+/// TTL.
+pub fn txn_heart_beat<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    primary: &[u8],
+    advise_ttl: u64,
+) -> Result<u64> {
+    match reader.load_lock(primary)? {
+        Some(mut lock) if lock.start_ts == txn.start_ts => {
+            if advise_ttl > lock.ttl {
+                lock.ttl = advise_ttl;
+                txn.put_lock(primary, &lock);
+            }
+            Ok(lock.ttl)
+        }
+        _ => Err(Error::TxnNotFound {
+            soliton_id: primary.to_vec(),
+            start_ts: txn.start_ts,
+        }),
+    }
+}
+
+/// Takes a pessimistic lock on `soliton_id`, failing if a version newer than
+/// `for_update_ts` was committed.
+pub fn acquire_pessimistic_lock<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+    primary: &[u8],
+    for_update_ts: u64,
+    lock_ttl: u64,
+) -> Result<()> {
+    if let Some(mut lock) = reader.load_lock(soliton_id)? {
+        if lock.start_ts != txn.start_ts {
+            return Err(key_is_locked(soliton_id, lock));
+        }
+        if lock.lock_type == LockType::Pessimistic && for_update_ts > lock.for_update_ts {
+            lock.for_update_ts = for_update_ts;
+            txn.put_lock(soliton_id, &lock);
+        }
+        return Ok(());
+    }
+    if let Some((commit_ts, write)) = reader.seek_write(soliton_id, u64::MAX)? {
+        if commit_ts > for_update_ts {
+            return Err(Error::WriteConflict {
+                soliton_id: soliton_id.to_vec(),
+                start_ts: txn.start_ts,
+                conflict_start_ts: write.start_ts,
+                conflict_commit_ts: commit_ts,
+            });
+        }
+    }
+    // Rolled back by a transaction that found it dead.
+    if reader.get_txn_commit_record(soliton_id, txn.start_ts)? == TxnCommitRecord::RolledBack {
+        return Err(Error::TxnLockNotFound {
+            soliton_id: soliton_id.to_vec(),
+            start_ts: txn.start_ts,
+        });
+    }
+    let mut lock = Lock::new(
+        LockType::Pessimistic,
+        primary.to_vec(),
+        txn.start_ts,
+        lock_ttl,
+    );
+    lock.for_update_ts = for_update_ts;
+    txn.put_lock(soliton_id, &lock);
+    Ok(())
+}
+
+/// Releases the pessimistic lock on `soliton_id`, unless it was taken again at
+/// a later `for_update_ts`.
+pub fn pessimistic_rollback<S: Snapshot>(
+    txn: &mut MvccTxn,
+    reader: &MvccReader<S>,
+    soliton_id: &[u8],
+    for_update_ts: u64,
+) -> Result<()> {
+    if let Some(lock) = reader.load_lock(soliton_id)? {
+        if lock.start_ts == txn.start_ts
+            && lock.lock_type == LockType::Pessimistic
+            && lock.for_update_ts <= for_update_ts
+        {
+            txn.unlock_key(soliton_id);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,623 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Transactions as a client runs them: writes are buffered until commit, which
+//! prewrites them all and then commits the primary, the point at which the
+//! transaction is committed, before the secondaries. Locks met on the way are
+//! resolved by asking their primaries.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use pd::tso::extract_physical;
+use pd::PdClient;
+use violetabftstore::ReadConsistency;
+
+use crate::actions::{Mutation, MutationOp, TxnStatus};
+use crate::engine::Engine;
+use crate::errors::{Error, LockInfo, Result};
+use crate::storage::{
+    read_ts, PessimisticLockRequest, PrewriteRequest, SecondaryLocksStatus, Storage,
+};
+
+const BACKOFF: Duration = Duration::from_millis(5);
+const MAX_BACKOFFS: usize = 400;
+const MAX_LOCK_RETRIES: usize = 10;
+
+#[derive(Clone, Debug)]
+pub struct TxnOptions {
+    /// Takes a pessimistic lock on each soliton_id as it is written or read for
+    /// update, rather than finding conflicts at commit.
+    pub pessimistic: bool,
+    /// Commits as soon as all the soliton_ids are prewritten, without waiting for
+    /// the primary to be committed.
+    pub use_async_commit: bool,
+    /// Commits in the prewrite.
+    pub try_one_pc: bool,
+    /// In milliseconds.
+    pub lock_ttl: u64,
+    /// How long a pessimistic lock request waits for another transaction's lock.
+    pub lock_wait_timeout: Duration,
+}
+
+impl Default for TxnOptions {
+    fn default() -> TxnOptions {
+        TxnOptions {
+            pessimistic: false,
+            use_async_commit: false,
+            try_one_pc: false,
+            lock_ttl: 3000,
+            lock_wait_timeout: Duration::from_secs(1),
+        }
+    }
+}
+
+pub struct TxnClient<E: Engine> {
+    storage: Arc<Storage<E>>,
+    pd: Arc<dyn PdClient>,
+}
+
+impl<E: Engine> Clone for TxnClient<E> {
+    fn clone(&self) -> TxnClient<E> {
+        TxnClient {
+            storage: self.storage.clone(),
+            pd: self.pd.clone(),
+        }
+    }
+}
+
+impl<E: Engine> TxnClient<E> {
+    pub fn new(storage: Arc<Storage<E>>, pd: Arc<dyn PdClient>) -> TxnClient<E> {
+        TxnClient { storage, pd }
+    }
+
+    pub fn storage(&self) -> &Arc<Storage<E>> {
+        &self.storage
+    }
+
+    pub fn begin(&self, opts: TxnOptions) -> Result<Transaction<E>> {
+        Ok(Transaction {
+            client: self.clone(),
+            start_ts: self.pd.get_tso()?,
+            for_update_ts: 0,
+            opts,
+            primary: None,
+            mutations: BTreeMap::new(),
+            locked: BTreeSet::new(),
+        })
+    }
+
+    /// The causet_locale of `soliton_id` as of `ts`, resolving the locks in the
+    /// way.
+    pub fn get(&self, soliton_id: &[u8], ts: u64) -> Result<Option<Vec<u8>>> {
+        self.with_lock_resolving(ts, || self.storage.get(soliton_id, ts))
+    }
+
+    /// Like `get`, served as `consistency` asks; a stale read is at the
+    /// timestamp it carries.
+    pub fn get_with(
+        &self,
+        soliton_id: &[u8],
+        ts: u64,
+        consistency: ReadConsistency,
+    ) -> Result<Option<Vec<u8>>> {
+        self.with_lock_resolving(read_ts(ts, consistency), || {
+            self.storage.get_with(soliton_id, ts, consistency)
+        })
+    }
+
+    pub fn scan(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+        ts: u64,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.with_lock_resolving(ts, || self.storage.scan(start, end, limit, ts))
+    }
+
+    pub fn scan_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+        ts: u64,
+        consistency: ReadConsistency,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.with_lock_resolving(read_ts(ts, consistency), || {
+            self.storage.scan_with(start, end, limit, ts, consistency)
+        })
+    }
+
+    /// Closes the storage at the current timestamp, or below the oldest lock;
+    /// the closed timestamp, at which stale reads may be served by any replica.
+    /// The owner calls it periodically.
+    pub fn close_ts(&self) -> Result<u64> {
+        self.storage.close_ts(self.pd.get_tso()?)
+    }
+
+    /// Runs `f` until it is not stopped by a lock, resolving the lock or, while
+    /// its transaction is alive, backing off.
+    fn with_lock_resolving<T>(
+        &self,
+        caller_start_ts: u64,
+        mut f: impl FnMut() -> Result<T>,
+    ) -> Result<T> {
+        for _ in 0..MAX_BACKOFFS {
+            match f() {
+                Err(Error::KeyIsLocked(info)) => {
+                    if !self.resolve_lock(&info, caller_start_ts)? {
+                        thread::sleep(BACKOFF);
+                    }
+                }
+                res => return res,
+            }
+        }
+        f()
+    }
+
+    /// Settles the transaction of the lock in `info` if it is decided, or dead;
+    /// whether the caller may retry at once, which it may also if it pushed the
+    /// transaction past itself.
+    pub fn resolve_lock(&self, info: &LockInfo, caller_start_ts: u64) -> Result<bool> {
+        let lock = &info.lock;
+        let current_ts = self.pd.get_tso()?;
+        let status = self.storage.check_txn_status(
+            &lock.primary,
+            lock.start_ts,
+            caller_start_ts,
+            current_ts,
+            true,
+        )?;
+        let commit_ts = match status {
+            TxnStatus::Committed(commit_ts) => commit_ts,
+            TxnStatus::RolledBack | TxnStatus::TtlExpired => 0,
+            TxnStatus::Locked(primary) if primary.use_async_commit => {
+                // Committing as it is, unless it is long dead; then what its
+                // secondaries tell decides.
+                if !primary.is_expired(current_ts) {
+                    return Ok(false);
+                }
+                let commit_ts = match self
+                    .storage
+                    .check_secondary_locks(&primary.secondaries, lock.start_ts)?
+                {
+                    SecondaryLocksStatus::Locked(locks) => locks
+                        .iter()
+                        .map(|l| l.min_commit_ts)
+                        .fold(primary.min_commit_ts, u64::max),
+                    SecondaryLocksStatus::Committed(commit_ts) => commit_ts,
+                    SecondaryLocksStatus::RolledBack => 0,
+                };
+                let mut soliton_ids = primary.secondaries.clone();
+                soliton_ids.push(lock.primary.clone());
+                self.storage
+                    .resolve_lock(lock.start_ts, commit_ts, &soliton_ids)?;
+                return Ok(true);
+            }
+            TxnStatus::Locked(primary) => return Ok(primary.min_commit_ts > caller_start_ts),
+        };
+        self.storage.resolve_lock(
+            lock.start_ts,
+            commit_ts,
+            std::slice::from_ref(&info.soliton_id),
+        )?;
+        Ok(true)
+    }
+}
+
+pub struct Transaction<E: Engine> {
+    client: TxnClient<E>,
+    start_ts: u64,
+    for_update_ts: u64,
+    opts: TxnOptions,
+    primary: Option<Vec<u8>>,
+    mutations: BTreeMap<Vec<u8>, Mutation>,
+    /// The soliton_ids locked pessimistically.
+    locked: BTreeSet<Vec<u8>>,
+}
+
+impl<E: Engine> Transaction<E> {
+    pub fn start_ts(&self) -> u64 {
+        self.start_ts
+    }
+
+    pub fn get(&mut self, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        match self.mutations.get(soliton_id) {
+            Some(m) if m.op == MutationOp::Put => Ok(Some(m.causet_locale.clone())),
+            Some(m) if m.op == MutationOp::Delete => Ok(None),
+            _ => self.client.get(soliton_id, self.start_ts),
+        }
+    }
+
+    /// Locks `soliton_id` and reads its latest causet_locale: pessimistically,
+    /// or in an optimistic transaction by failing the commit if it changes.
+    pub fn get_for_update(&mut self, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.lock_keys(&[soliton_id.to_vec()])?;
+        if let Some(m) = self.mutations.get(soliton_id) {
+            match m.op {
+                MutationOp::Put => return Ok(Some(m.causet_locale.clone())),
+                MutationOp::Delete => return Ok(None),
+                MutationOp::Lock => {}
+            }
+        }
+        let ts = if self.opts.pessimistic {
+            self.for_update_ts
+        } else {
+            self.start_ts
+        };
+        self.client.get(soliton_id, ts)
+    }
+
+    pub fn put(&mut self, soliton_id: Vec<u8>, causet_locale: Vec<u8>) -> Result<()> {
+        self.lock_for_write(&soliton_id)?;
+        self.mutations
+            .insert(soliton_id.clone(), Mutation::put(soliton_id, causet_locale));
+        Ok(())
+    }
+
+    pub fn delete(&mut self, soliton_id: Vec<u8>) -> Result<()> {
+        self.lock_for_write(&soliton_id)?;
+        self.mutations
+            .insert(soliton_id.clone(), Mutation::delete(soliton_id));
+        Ok(())
+    }
+
+    fn lock_for_write(&mut self, soliton_id: &[u8]) -> Result<()> {
+        if self.opts.pessimistic {
+            self.lock_keys(&[soliton_id.to_vec()])?;
+        }
+        Ok(())
+    }
+
+    /// Locks `soliton_ids`: pessimistically at once, waiting for other
+    /// transactions' locks, or optimistically at commit.
+    pub fn lock_keys(&mut self, soliton_ids: &[Vec<u8>]) -> Result<()> {
+        if !self.opts.pessimistic {
+            for soliton_id in soliton_ids {
+                self.mutations
+                    .entry(soliton_id.clone())
+                    .or_insert_with(|| Mutation::lock(soliton_id.clone()));
+            }
+            return Ok(());
+        }
+        let soliton_ids: Vec<Vec<u8>> = soliton_ids
+            .iter()
+            .filter(|k| !self.locked.contains(*k))
+            .cloned()
+            .collect();
+        if soliton_ids.is_empty() {
+            return Ok(());
+        }
+        let primary = self
+            .primary
+            .get_or_insert_with(|| soliton_ids[0].clone())
+            .clone();
+        let mut attempt = 0;
+        loop {
+            // A newer version committed since is a conflict only at an older
+            // for_update_ts: retry at a new one.
+            let for_update_ts = self.client.pd.get_tso()?;
+            let req = PessimisticLockRequest {
+                soliton_ids: soliton_ids.clone(),
+                primary: primary.clone(),
+                start_ts: self.start_ts,
+                for_update_ts,
+                lock_ttl: self.opts.lock_ttl,
+                wait_timeout: Some(self.opts.lock_wait_timeout),
+            };
+            match self.client.storage.acquire_pessimistic_lock(&req) {
+                Ok(()) => {
+                    self.for_update_ts = self.for_update_ts.max(for_update_ts);
+                    self.locked.extend(soliton_ids);
+                    return Ok(());
+                }
+                Err(Error::WriteConflict { .. }) if attempt < MAX_LOCK_RETRIES => {}
+                Err(Error::KeyIsLocked(info)) if attempt < MAX_LOCK_RETRIES => {
+                    self.client.resolve_lock(&info, self.start_ts)?;
+                }
+                Err(e) => return Err(e),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Extends the TTL of the transaction's locks, so that a long transaction is
+    /// not taken for dead; the TTL.
+    pub fn heartbeat(&self) -> Result<u64> {
+        let primary = self
+            .primary
+            .as_ref()
+            .ok_or_else(|| Error::Other("the transaction has no locks".to_owned()))?;
+        let now = self.client.pd.get_tso()?;
+        let advise_ttl = extract_physical(now).saturating_sub(extract_physical(self.start_ts))
+            + self.opts.lock_ttl;
+        self.client
+            .storage
+            .txn_heart_beat(primary, self.start_ts, advise_ttl)
+    }
+
+    /// Commits the transaction; its commit_ts, or 0 if it wrote nothing.
+    pub fn commit(mut self) -> Result<u64> {
+        // Pessimistic locks that were only read for update are prewritten as
+        // locks, so that committing releases them.
+        for soliton_id in &self.locked {
+            self.mutations
+                .entry(soliton_id.clone())
+                .or_insert_with(|| Mutation::lock(soliton_id.clone()));
+        }
+        if self.mutations.is_empty() {
+            return Ok(0);
+        }
+        let primary = self
+            .primary
+            .get_or_insert_with(|| self.mutations.keys().next().unwrap().clone())
+            .clone();
+        let secondaries: Vec<Vec<u8>> = self
+            .mutations
+            .keys()
+            .filter(|k| **k != primary)
+            .cloned()
+            .collect();
+        let req = PrewriteRequest {
+            mutations: self.mutations.values().cloned().collect(),
+            primary: primary.clone(),
+            start_ts: self.start_ts,
+            lock_ttl: self.opts.lock_ttl,
+            for_update_ts: if self.opts.pessimistic {
+                self.for_update_ts
+            } else {
+                0
+            },
+            use_async_commit: self.opts.use_async_commit,
+            secondaries: secondaries.clone(),
+            try_one_pc: self.opts.try_one_pc,
+        };
+        let client = self.client.clone();
+        let res =
+            client.with_lock_resolving(self.start_ts, || client.storage.prewrite(req.clone()));
+        let res = match res {
+            Ok(res) => res,
+            Err(e) => {
+                let _ = self.rollback();
+                return Err(e);
+            }
+        };
+        if res.one_pc_commit_ts > 0 {
+            return Ok(res.one_pc_commit_ts);
+        }
+        if self.opts.use_async_commit {
+            // Committed already; this only spares readers resolving the locks.
+            let _ = client
+                .storage
+                .commit(&[primary], self.start_ts, res.min_commit_ts);
+            let _ = client
+                .storage
+                .commit(&secondaries, self.start_ts, res.min_commit_ts);
+            return Ok(res.min_commit_ts);
+        }
+        let commit_ts = loop {
+            let commit_ts = client.pd.get_tso()?;
+            match client
+                .storage
+                .commit(std::slice::from_ref(&primary), self.start_ts, commit_ts)
+            {
+                Ok(()) => break commit_ts,
+                // Pushed by a reader in the meantime.
+                Err(Error::CommitTsExpired { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        };
+        let _ = client
+            .storage
+            .commit(&secondaries, self.start_ts, commit_ts);
+        Ok(commit_ts)
+    }
+
+    pub fn rollback(self) -> Result<()> {
+        let mut soliton_ids: BTreeSet<Vec<u8>> = self.mutations.into_keys().collect();
+        soliton_ids.extend(self.locked);
+        let soliton_ids: Vec<Vec<u8>> = soliton_ids.into_iter().collect();
+        if soliton_ids.is_empty() {
+            return Ok(());
+        }
+        self.client.storage.rollback(&soliton_ids, self.start_ts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use pd::{LocalClient, PdConfig, PdServer};
+    use tempfile::TempDir;
+    use violetabftstore::cluster::Cluster;
+    use violetabftstore::StoreConfig;
+
+    use super::*;
+    use crate::codec::encode_key;
+    use crate::engine::ClusterEngine;
+
+    fn balance(client: &TxnClient<ClusterEngine>, soliton_id: &[u8]) -> u64 {
+        let ts = client.pd.get_tso().unwrap();
+        let v = client.get(soliton_id, ts).unwrap().unwrap();
+        String::from_utf8(v).unwrap().parse().unwrap()
+    }
+
+    fn transfer(
+        client: &TxnClient<ClusterEngine>,
+        opts: TxnOptions,
+        from: &[u8],
+        to: &[u8],
+        amount: u64,
+    ) -> Result<u64> {
+        let mut txn = client.begin(opts)?;
+        let a: u64 = String::from_utf8(txn.get_for_update(from)?.unwrap())
+            .unwrap()
+            .parse()
+            .unwrap();
+        let b: u64 = String::from_utf8(txn.get_for_update(to)?.unwrap())
+            .unwrap()
+            .parse()
+            .unwrap();
+        txn.put(from.to_vec(), (a - amount).to_string().into_bytes())?;
+        txn.put(to.to_vec(), (b + amount).to_string().into_bytes())?;
+        txn.commit()
+    }
+
+    /// A client of three stores, with the branes split at "m".
+    fn new_client(dir: &TempDir) -> TxnClient<ClusterEngine> {
+        let cfg = StoreConfig {
+            violetabft_election_ticks: 5,
+            violetabft_heartbeat_ticks: 1,
+            sync_log: false,
+            ..Default::default()
+        };
+        let mut cluster = Cluster::new(dir.path(), 3, cfg).unwrap();
+        cluster.split(&encode_key(b"m")).unwrap();
+        let engine = ClusterEngine::new(Arc::new(Mutex::new(cluster)));
+        let pd = Arc::new(LocalClient::new(Arc::new(
+            PdServer::new(PdConfig::default()).unwrap(),
+        )));
+        TxnClient::new(Arc::new(Storage::new(engine)), pd)
+    }
+
+    #[test]
+    fn test_transactions_over_cluster() {
+        let dir = TempDir::new().unwrap();
+        let client = new_client(&dir);
+
+        // The accounts span both branes.
+        let mut txn = client
+            .begin(TxnOptions {
+                try_one_pc: true,
+                ..Default::default()
+            })
+            .unwrap();
+        txn.put(b"a".to_vec(), b"100".to_vec()).unwrap();
+        txn.put(b"x".to_vec(), b"100".to_vec()).unwrap();
+        assert!(txn.commit().unwrap() > 0);
+
+        transfer(&client, TxnOptions::default(), b"a", b"x", 10).unwrap();
+        let async_commit = TxnOptions {
+            use_async_commit: true,
+            ..Default::default()
+        };
+        transfer(&client, async_commit, b"x", b"a", 5).unwrap();
+        let pessimistic = TxnOptions {
+            pessimistic: true,
+            ..Default::default()
+        };
+        transfer(&client, pessimistic.clone(), b"a", b"x", 20).unwrap();
+        assert_eq!(balance(&client, b"a"), 75);
+        assert_eq!(balance(&client, b"x"), 125);
+
+        // Of two optimistic transactions writing the same soliton_id, the later
+        // commit fails.
+        let mut t1 = client.begin(TxnOptions::default()).unwrap();
+        let mut t2 = client.begin(TxnOptions::default()).unwrap();
+        t1.put(b"a".to_vec(), b"0".to_vec()).unwrap();
+        t2.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+        t1.commit().unwrap();
+        assert!(matches!(t2.commit(), Err(Error::WriteConflict { .. })));
+
+        // A pessimistic transaction waits for another's lock, and then reads
+        // what it committed.
+        let mut t3 = client.begin(pessimistic.clone()).unwrap();
+        t3.put(b"a".to_vec(), b"75".to_vec()).unwrap();
+        let waiter = {
+            let client = client.clone();
+            thread::spawn(move || transfer(&client, pessimistic, b"a", b"x", 25))
+        };
+        thread::sleep(Duration::from_millis(50));
+        t3.commit().unwrap();
+        waiter.join().unwrap().unwrap();
+        assert_eq!(balance(&client, b"a"), 50);
+        assert_eq!(balance(&client, b"x"), 150);
+
+        // A transaction that died after prewriting is rolled back by the
+        // readers meeting its expired locks.
+        let start_ts = client.pd.get_tso().unwrap();
+        client
+            .storage
+            .prewrite(PrewriteRequest {
+                mutations: vec![
+                    Mutation::put(b"a".to_vec(), b"0".to_vec()),
+                    Mutation::put(b"x".to_vec(), b"0".to_vec()),
+                ],
+                primary: b"a".to_vec(),
+                start_ts,
+                lock_ttl: 0,
+                ..Default::default()
+            })
+            .unwrap();
+        thread::sleep(Duration::from_millis(5));
+        assert_eq!(balance(&client, b"x"), 150);
+        assert_eq!(balance(&client, b"a"), 50);
+        let ts = client.pd.get_tso().unwrap();
+        assert!(client
+            .storage
+            .scan_lock(ts, b"", b"", 0)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            client.scan(b"", b"", 0, ts).unwrap(),
+            vec![
+                (b"a".to_vec(), b"50".to_vec()),
+                (b"x".to_vec(), b"150".to_vec())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_follower_and_stale_reads() {
+        let dir = TempDir::new().unwrap();
+        let client = new_client(&dir);
+        let write = |v: &[u8]| {
+            let mut txn = client.begin(TxnOptions::default()).unwrap();
+            txn.put(b"a".to_vec(), v.to_vec()).unwrap();
+            txn.put(b"x".to_vec(), v.to_vec()).unwrap();
+            txn.commit().unwrap()
+        };
+        write(b"1");
+        let closed = client.close_ts().unwrap();
+        let commit_ts = write(b"2");
+        assert!(commit_ts > closed);
+
+        // A stale read sees the data as of the closed timestamp, a follower read
+        // the latest.
+        let stale = ReadConsistency::Stale(closed);
+        assert_eq!(client.get_with(b"a", 0, stale).unwrap().unwrap(), b"1");
+        assert_eq!(
+            client.scan_with(b"", b"", 0, 0, stale).unwrap(),
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"x".to_vec(), b"1".to_vec())
+            ]
+        );
+        let ts = client.pd.get_tso().unwrap();
+        let follower = ReadConsistency::Follower;
+        assert_eq!(client.get_with(b"x", ts, follower).unwrap().unwrap(), b"2");
+        // Past the closed timestamp, the leader serves it.
+        let stale = ReadConsistency::Stale(ts);
+        assert_eq!(client.get_with(b"x", 0, stale).unwrap().unwrap(), b"2");
+
+        // The storage is not closed at or past a lock, which may still commit
+        // below the current timestamp.
+        let start_ts = client.pd.get_tso().unwrap();
+        client
+            .storage
+            .prewrite(PrewriteRequest {
+                mutations: vec![Mutation::put(b"x".to_vec(), b"3".to_vec())],
+                primary: b"x".to_vec(),
+                start_ts,
+                lock_ttl: 3000,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(client.close_ts().unwrap(), start_ts - 1);
+        let stale = ReadConsistency::Stale(start_ts - 1);
+        assert_eq!(client.get_with(b"x", 0, stale).unwrap().unwrap(), b"2");
+    }
+}
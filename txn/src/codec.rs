@@ -0,0 +1,128 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Keys of the MVCC causet_merge families.
+//!
+//! A soliton_id is stored memcomparable-encoded, so that no encoded soliton_id is
+//! a prefix of another, and its versions follow it, with the timestamp inverted
+//! so that newer versions sort first:
+//!
+//! ```text
+//!   lock:    encode(soliton_id)
+//!   write:   encode(soliton_id) | !commit_ts
+//!   default: encode(soliton_id) | !start_ts
+//! ```
+//!
+//! The encoding cuts the soliton_id into groups of 8 bytes, the last one padded
+//! with zeros, each followed by a marker of `0xff` less the padding in it.
+
+use violetabft::{Error, Result};
+
+const ENC_GROUP_SIZE: usize = 8;
+const ENC_MARKER: u8 = 0xff;
+const TS_LEN: usize = 8;
+
+pub fn encode_key(soliton_id: &[u8]) -> Vec<u8> {
+    let groups = soliton_id.len() / ENC_GROUP_SIZE + 1;
+    let mut encoded = Vec::with_capacity(groups * (ENC_GROUP_SIZE + 1) + TS_LEN);
+    for chunk in soliton_id.chunks(ENC_GROUP_SIZE) {
+        encoded.extend_from_slice(chunk);
+        let pad = ENC_GROUP_SIZE - chunk.len();
+        encoded.resize(encoded.len() + pad, 0);
+        encoded.push(ENC_MARKER - pad as u8);
+    }
+    if soliton_id.len().is_multiple_of(ENC_GROUP_SIZE) {
+        encoded.resize(encoded.len() + ENC_GROUP_SIZE, 0);
+        encoded.push(ENC_MARKER - ENC_GROUP_SIZE as u8);
+    }
+    encoded
+}
+
+/// Decodes the encoded soliton_id at the start of `data`; the soliton_id and the
+/// rest of `data`.
+pub fn decode_key(data: &[u8]) -> Result<(Vec<u8>, &[u8])> {
+    let mut soliton_id = Vec::new();
+    let mut rest = data;
+    loop {
+        if rest.len() < ENC_GROUP_SIZE + 1 {
+            return Err(Error::Corruption(format!(
+                "truncated encoded soliton_id {:?}",
+                data
+            )));
+        }
+        let (group, tail) = rest.split_at(ENC_GROUP_SIZE + 1);
+        rest = tail;
+        let pad = (ENC_MARKER - group[ENC_GROUP_SIZE]) as usize;
+        if pad > ENC_GROUP_SIZE
+            || group[ENC_GROUP_SIZE - pad..ENC_GROUP_SIZE]
+                .iter()
+                .any(|&b| b != 0)
+        {
+            return Err(Error::Corruption(format!(
+                "invalid encoded soliton_id {:?}",
+                data
+            )));
+        }
+        soliton_id.extend_from_slice(&group[..ENC_GROUP_SIZE - pad]);
+        if pad > 0 {
+            return Ok((soliton_id, rest));
+        }
+    }
+}
+
+pub fn append_ts(encoded: &[u8], ts: u64) -> Vec<u8> {
+    let mut soliton_id = Vec::with_capacity(encoded.len() + TS_LEN);
+    soliton_id.extend_from_slice(encoded);
+    soliton_id.extend_from_slice(&(!ts).to_be_bytes());
+    soliton_id
+}
+
+/// Splits a soliton_id of the write or default causet_merge family into the
+/// encoded soliton_id and the timestamp.
+pub fn split_ts(soliton_id: &[u8]) -> Result<(&[u8], u64)> {
+    if soliton_id.len() < TS_LEN {
+        return Err(Error::Corruption(format!(
+            "soliton_id {:?} has no timestamp",
+            soliton_id
+        )));
+    }
+    let (encoded, ts) = soliton_id.split_at(soliton_id.len() - TS_LEN);
+    Ok((encoded, !u64::from_be_bytes(ts.try_into().unwrap())))
+}
+
+/// The end of the versions of the encoded soliton_id: the least soliton_id
+/// greater than every soliton_id it prefixes. The last marker is below `0xff`,
+/// so it can be incremented.
+pub fn versions_end(encoded: &[u8]) -> Vec<u8> {
+    let mut end = encoded.to_vec();
+    *end.last_mut().unwrap() += 1;
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_codec() {
+        let ids: Vec<&[u8]> = vec![b"", b"a", b"a\0", b"ab", b"abcdefgh", b"abcdefgh\0", b"b"];
+        let mut last: Option<Vec<u8>> = None;
+        for id in &ids {
+            let encoded = encode_key(id);
+            let (decoded, rest) = decode_key(&encoded).unwrap();
+            assert_eq!(&decoded, id);
+            assert!(rest.is_empty());
+
+            // Versions of a soliton_id sort newest first, and before the next
+            // soliton_id.
+            let newer = append_ts(&encoded, 20);
+            let older = append_ts(&encoded, 10);
+            assert!(encoded < newer && newer < older && older < versions_end(&encoded));
+            assert_eq!(split_ts(&older).unwrap(), (&encoded[..], 10));
+            if let Some(last) = last {
+                assert!(versions_end(&last) <= encoded);
+            }
+            last = Some(encoded);
+        }
+        assert!(decode_key(b"ab").is_err());
+    }
+}
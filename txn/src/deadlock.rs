@@ -0,0 +1,134 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The deadlock detector: the graph of transactions waiting for the pessimistic
+//! locks of others. A wait that would close a cycle is refused, which breaks the
+//! deadlock. Waits not renewed within the TTL are forgotten, in case their
+//! clean up was missed.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+struct WaitFor {
+    soliton_ids: HashSet<Vec<u8>>,
+    since: Instant,
+}
+
+pub struct DetectTable {
+    /// For each waiting transaction, the transactions it waits for.
+    wait_for_map: HashMap<u64, HashMap<u64, WaitFor>>,
+    ttl: Duration,
+}
+
+impl DetectTable {
+    pub fn new(ttl: Duration) -> DetectTable {
+        DetectTable {
+            wait_for_map: HashMap::new(),
+            ttl,
+        }
+    }
+
+    /// Records that `txn_ts` waits for the lock of `lock_ts` on `soliton_id`,
+    /// unless that closes a cycle: then the transactions in it, from `txn_ts`
+    /// on.
+    pub fn detect(&mut self, txn_ts: u64, lock_ts: u64, soliton_id: &[u8]) -> Option<Vec<u64>> {
+        let now = Instant::now();
+        let ttl = self.ttl;
+        for wait_for in self.wait_for_map.values_mut() {
+            wait_for.retain(|_, w| now.duration_since(w.since) < ttl);
+        }
+        self.wait_for_map.retain(|_, wait_for| !wait_for.is_empty());
+
+        if let Some(mut chain) = self.find_path(lock_ts, txn_ts) {
+            chain.insert(0, txn_ts);
+            return Some(chain);
+        }
+        let w = self
+            .wait_for_map
+            .entry(txn_ts)
+            .or_default()
+            .entry(lock_ts)
+            .or_insert_with(|| WaitFor {
+                soliton_ids: HashSet::new(),
+                since: now,
+            });
+        w.soliton_ids.insert(soliton_id.to_vec());
+        w.since = now;
+        None
+    }
+
+    /// A path of waits from `from` to `to`, both included.
+    fn find_path(&self, from: u64, to: u64) -> Option<Vec<u64>> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![vec![from]];
+        while let Some(path) = stack.pop() {
+            let last = *path.last().unwrap();
+            if last == to {
+                return Some(path);
+            }
+            if !visited.insert(last) {
+                continue;
+            }
+            if let Some(wait_for) = self.wait_for_map.get(&last) {
+                for &next in wait_for.keys() {
+                    let mut path = path.clone();
+                    path.push(next);
+                    stack.push(path);
+                }
+            }
+        }
+        None
+    }
+
+    /// Forgets that `txn_ts` waits for the lock of `lock_ts` on `soliton_id`.
+    pub fn clean_up_wait_for(&mut self, txn_ts: u64, lock_ts: u64, soliton_id: &[u8]) {
+        if let Some(wait_for) = self.wait_for_map.get_mut(&txn_ts) {
+            if let Some(w) = wait_for.get_mut(&lock_ts) {
+                w.soliton_ids.remove(soliton_id);
+                if w.soliton_ids.is_empty() {
+                    wait_for.remove(&lock_ts);
+                }
+            }
+            if wait_for.is_empty() {
+                self.wait_for_map.remove(&txn_ts);
+            }
+        }
+    }
+
+    /// Forgets the waits of a finished transaction.
+    pub fn clean_up(&mut self, txn_ts: u64) {
+        self.wait_for_map.remove(&txn_ts);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_waiting(&self, txn_ts: u64) -> bool {
+        self.wait_for_map.contains_key(&txn_ts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let mut table = DetectTable::new(Duration::from_secs(3));
+        assert_eq!(table.detect(1, 2, b"k2"), None);
+        assert_eq!(table.detect(2, 3, b"k3"), None);
+        assert_eq!(table.detect(3, 1, b"k1"), Some(vec![3, 1, 2, 3]));
+
+        // Once 2 stops waiting, 3 may wait for 1.
+        table.clean_up_wait_for(2, 3, b"k3");
+        assert_eq!(table.detect(3, 1, b"k1"), None);
+        assert_eq!(table.detect(1, 3, b"k3"), Some(vec![1, 3, 1]));
+        table.clean_up(3);
+        assert_eq!(table.detect(1, 3, b"k3"), None);
+
+        // Waits expire.
+        let mut table = DetectTable::new(Duration::from_millis(10));
+        assert_eq!(table.detect(1, 2, b"k"), None);
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(table.detect(2, 1, b"k"), None);
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! What transactions need of the KV layer: reading the MVCC causet_merge
+//! families, and writing their changes.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use fdb_traits::{
+    IterOptions, Iterable, Iterator, Mutable, Peekable, SeekKey, SnapshotExt, WriteBatch,
+    WriteBatchExt, NAMESPACED_DEFAULT,
+};
+use soliton_lsm::{LsmEngine, LsmOptions, LsmSnapshot};
+use violetabftstore::cluster::Cluster;
+use violetabftstore::{keys, ReadConsistency, Request};
+
+use crate::errors::Result;
+
+pub const CF_DEFAULT: &str = NAMESPACED_DEFAULT;
+pub const CF_LOCK: &str = "lock";
+pub const CF_WRITE: &str = "write";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Modify {
+    Put(&'static str, Vec<u8>, Vec<u8>),
+    Delete(&'static str, Vec<u8>),
+}
+
+pub trait Snapshot: Send {
+    fn get_cf(&self, cf: &str, soliton_id: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// At most `limit` pairs of `[start, end)`, or all if it is 0; an empty end
+    /// is unbounded.
+    fn scan_cf(
+        &self,
+        cf: &str,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
+}
+
+pub trait Engine: Send + Sync {
+    type Snap: Snapshot;
+
+    fn snapshot(&self) -> Result<Self::Snap>;
+
+    /// A snapshot whose reads are served as `consistency` asks.
+    fn snapshot_with(&self, consistency: ReadConsistency) -> Result<Self::Snap>;
+
+    /// Writes `modifies` in order; those of one soliton_id atomically.
+    fn write(&self, modifies: Vec<Modify>) -> Result<()>;
+
+    /// Lets stale reads at `ts` be served by any replica: the caller promises
+    /// that every write at or before it was made already.
+    fn close_ts(&self, ts: u64) -> Result<()>;
+}
+
+/// A local einstein_merkle_tree, with the MVCC causet_merge families.
+#[derive(Clone)]
+pub struct LocalEngine {
+    kv: LsmEngine,
+}
+
+impl LocalEngine {
+    /// Wraps `kv`, which must have the lock and write causet_merge families.
+    pub fn new(kv: LsmEngine) -> LocalEngine {
+        LocalEngine { kv }
+    }
+
+    pub fn open(path: &Path) -> Result<LocalEngine> {
+        let opts = LsmOptions {
+            namespaceds: vec![CF_LOCK.to_owned(), CF_WRITE.to_owned()],
+            ..Default::default()
+        };
+        Ok(LocalEngine::new(LsmEngine::open(path, opts)?))
+    }
+
+    pub fn kv(&self) -> &LsmEngine {
+        &self.kv
+    }
+}
+
+pub struct LocalSnapshot(LsmSnapshot);
+
+impl Snapshot for LocalSnapshot {
+    fn get_cf(&self, cf: &str, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.0.get_value_namespaced(cf, soliton_id)?)
+    }
+
+    fn scan_cf(
+        &self,
+        cf: &str,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let upper = (!end.is_empty()).then(|| end.to_vec());
+        let mut iter = self
+            .0
+            .iterator_opt(cf, IterOptions::new(Some(start.to_vec()), upper))?;
+        let mut pairs = Vec::new();
+        let mut valid = iter.seek(SeekKey::Start)?;
+        while valid && (limit == 0 || pairs.len() < limit) {
+            pairs.push((iter.soliton_id().to_vec(), iter.causet_locale().to_vec()));
+            valid = iter.next()?;
+        }
+        Ok(pairs)
+    }
+}
+
+impl Engine for LocalEngine {
+    type Snap = LocalSnapshot;
+
+    fn snapshot(&self) -> Result<LocalSnapshot> {
+        Ok(LocalSnapshot(self.kv.snapshot()))
+    }
+
+    /// The only replica serves every read.
+    fn snapshot_with(&self, _: ReadConsistency) -> Result<LocalSnapshot> {
+        self.snapshot()
+    }
+
+    fn write(&self, modifies: Vec<Modify>) -> Result<()> {
+        let mut wb = self.kv.write_alexandrov_poset_process();
+        for m in modifies {
+            match m {
+                Modify::Put(cf, soliton_id, causet_locale) => {
+                    wb.put_namespaced(cf, &soliton_id, &causet_locale)?
+                }
+                Modify::Delete(cf, soliton_id) => wb.delete_namespaced(cf, &soliton_id)?,
+            }
+        }
+        wb.write(&self.kv)?;
+        Ok(())
+    }
+
+    fn close_ts(&self, _: u64) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The kv einstein_merkle_tree of one store as of one point, read by the
+/// soliton_ids of the branes' data, as observers of what the store applies
+/// need.
+pub struct StoreSnapshot(pub LsmSnapshot);
+
+impl Snapshot for StoreSnapshot {
+    fn get_cf(&self, cf: &str, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .0
+            .get_value_namespaced(cf, &keys::data_key(soliton_id))?)
+    }
+
+    fn scan_cf(
+        &self,
+        cf: &str,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let opts = IterOptions::new(Some(keys::data_key(start)), Some(keys::data_end_key(end)));
+        let mut iter = self.0.iterator_opt(cf, opts)?;
+        let mut pairs = Vec::new();
+        let mut valid = iter.seek(SeekKey::Start)?;
+        while valid && (limit == 0 || pairs.len() < limit) {
+            let soliton_id = keys::origin_key(iter.soliton_id()).to_vec();
+            pairs.push((soliton_id, iter.causet_locale().to_vec()));
+            valid = iter.next()?;
+        }
+        Ok(pairs)
+    }
+}
+
+/// A cluster of stores: each read goes to a replica of its brane as its
+/// snapshot's consistency asks, and each brane's share of a write is proposed
+/// as one command.
+#[derive(Clone)]
+pub struct ClusterEngine {
+    cluster: Arc<Mutex<Cluster>>,
+}
+
+impl ClusterEngine {
+    pub fn new(cluster: Arc<Mutex<Cluster>>) -> ClusterEngine {
+        ClusterEngine { cluster }
+    }
+
+    pub fn cluster(&self) -> &Arc<Mutex<Cluster>> {
+        &self.cluster
+    }
+}
+
+/// Reads of the cluster itself, each served when it is made: they see every
+/// write applied before them as their consistency allows, but not as of one
+/// point.
+pub struct ClusterSnapshot {
+    cluster: Arc<Mutex<Cluster>>,
+    consistency: ReadConsistency,
+}
+
+impl Snapshot for ClusterSnapshot {
+    fn get_cf(&self, cf: &str, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .cluster
+            .lock()
+            .unwrap()
+            .get_with(cf, soliton_id, self.consistency)?)
+    }
+
+    fn scan_cf(
+        &self,
+        cf: &str,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        Ok(self
+            .cluster
+            .lock()
+            .unwrap()
+            .scan_with(cf, start, end, limit, self.consistency)?)
+    }
+}
+
+impl Engine for ClusterEngine {
+    type Snap = ClusterSnapshot;
+
+    /// Reads through the log of each brane.
+    fn snapshot(&self) -> Result<ClusterSnapshot> {
+        self.snapshot_with(ReadConsistency::Strong)
+    }
+
+    fn snapshot_with(&self, consistency: ReadConsistency) -> Result<ClusterSnapshot> {
+        Ok(ClusterSnapshot {
+            cluster: self.cluster.clone(),
+            consistency,
+        })
+    }
+
+    fn write(&self, modifies: Vec<Modify>) -> Result<()> {
+        let requests = modifies
+            .into_iter()
+            .map(|m| match m {
+                Modify::Put(cf, soliton_id, causet_locale) => Request::Put {
+                    namespaced: cf.to_owned(),
+                    soliton_id,
+                    causet_locale,
+                },
+                Modify::Delete(cf, soliton_id) => Request::Delete {
+                    namespaced: cf.to_owned(),
+                    soliton_id,
+                },
+            })
+            .collect();
+        Ok(self.cluster.lock().unwrap().write(requests)?)
+    }
+
+    fn close_ts(&self, ts: u64) -> Result<()> {
+        Ok(self.cluster.lock().unwrap().close_ts(ts)?)
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::lock::Lock;
+
+/// A soliton_id found locked by another transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockInfo {
+    pub soliton_id: Vec<u8>,
+    pub lock: Lock,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The soliton_id is locked by another transaction, which must be resolved or
+    /// waited for.
+    KeyIsLocked(Box<LockInfo>),
+    /// Another transaction committed a newer version of the soliton_id.
+    WriteConflict {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+        conflict_start_ts: u64,
+        conflict_commit_ts: u64,
+    },
+    /// Waiting for the lock would close a cycle of transactions waiting for each
+    /// other; the start_ts of the transactions in it, from this one on.
+    Deadlock {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+        lock_ts: u64,
+        wait_chain: Vec<u64>,
+    },
+    /// The transaction's lock on the soliton_id is gone: it was rolled back, or
+    /// never taken.
+    TxnLockNotFound {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+    },
+    /// Neither a lock nor a commit record of the transaction is left.
+    TxnNotFound {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+    },
+    /// A pessimistic transaction prewrote a soliton_id it holds no pessimistic
+    /// lock on.
+    PessimisticLockNotFound {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+    },
+    /// The commit_ts is below the least one the lock was pushed to.
+    CommitTsExpired {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+        commit_ts: u64,
+        min_commit_ts: u64,
+    },
+    /// A rollback found the transaction committed.
+    Committed {
+        soliton_id: Vec<u8>,
+        start_ts: u64,
+        commit_ts: u64,
+    },
+    Engine(fdb_traits::Error),
+    Store(violetabftstore::Error),
+    Pd(pd::Error),
+    Codec(violetabft::Error),
+    Other(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::KeyIsLocked(info) => write!(
+                f,
+                "soliton_id {:?} is locked by transaction {}",
+                info.soliton_id, info.lock.start_ts
+            ),
+            Error::WriteConflict {
+                soliton_id,
+                start_ts,
+                conflict_start_ts,
+                conflict_commit_ts,
+            } => write!(
+                f,
+                "write conflict on soliton_id {:?}: transaction {} committed at {}, after {} started",
+                soliton_id, conflict_start_ts, conflict_commit_ts, start_ts
+            ),
+            Error::Deadlock {
+                soliton_id,
+                start_ts,
+                lock_ts,
+                wait_chain,
+            } => write!(
+                f,
+                "deadlock: transaction {} waiting for {} on soliton_id {:?}, wait chain {:?}",
+                start_ts, lock_ts, soliton_id, wait_chain
+            ),
+            Error::TxnLockNotFound {
+                soliton_id,
+                start_ts,
+            } => write!(
+                f,
+                "lock of transaction {} on soliton_id {:?} not found",
+                start_ts, soliton_id
+            ),
+            Error::TxnNotFound {
+                soliton_id,
+                start_ts,
+            } => write!(
+                f,
+                "transaction {} with primary {:?} not found",
+                start_ts, soliton_id
+            ),
+            Error::PessimisticLockNotFound {
+                soliton_id,
+                start_ts,
+            } => write!(
+                f,
+                "pessimistic lock of transaction {} on soliton_id {:?} not found",
+                start_ts, soliton_id
+            ),
+            Error::CommitTsExpired {
+                soliton_id,
+                start_ts,
+                commit_ts,
+                min_commit_ts,
+            } => write!(
+                f,
+                "commit_ts {} of transaction {} on soliton_id {:?} is below min_commit_ts {}",
+                commit_ts, start_ts, soliton_id, min_commit_ts
+            ),
+            Error::Committed {
+                soliton_id,
+                start_ts,
+                commit_ts,
+            } => write!(
+                f,
+                "transaction {} on soliton_id {:?} is committed at {}",
+                start_ts, soliton_id, commit_ts
+            ),
+            Error::Engine(e) => write!(f, "einstein_merkle_tree error: {}", e),
+            Error::Store(e) => write!(f, "store error: {}", e),
+            Error::Pd(e) => write!(f, "pd error: {}", e),
+            Error::Codec(e) => write!(f, "codec error: {}", e),
+            Error::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Engine(e) => Some(e),
+            Error::Store(e) => Some(e),
+            Error::Pd(e) => Some(e),
+            Error::Codec(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<fdb_traits::Error> for Error {
+    fn from(e: fdb_traits::Error) -> Error {
+        Error::Engine(e)
+    }
+}
+
+impl From<violetabftstore::Error> for Error {
+    fn from(e: violetabftstore::Error) -> Error {
+        Error::Store(e)
+    }
+}
+
+impl From<pd::Error> for Error {
+    fn from(e: pd::Error) -> Error {
+        Error::Pd(e)
+    }
+}
+
+impl From<violetabft::Error> for Error {
+    fn from(e: violetabft::Error) -> Error {
+        Error::Codec(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,41 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Latches serialize the commands on the same soliton_ids, so that each reads
+//! the state it changes without another command changing it in between.
+//! Soliton_ids hash to slots, and a command takes its slots in order, so that
+//! two commands never wait for each other.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, MutexGuard};
+
+pub(crate) struct Latches {
+    slots: Vec<Mutex<()>>,
+}
+
+impl Latches {
+    pub fn new(size: usize) -> Latches {
+        Latches {
+            slots: (0..size.max(1)).map(|_| Mutex::new(())).collect(),
+        }
+    }
+
+    /// Takes the latches of `soliton_ids`; they are released when the guards
+    /// are dropped.
+    pub fn acquire<K: AsRef<[u8]>>(&self, soliton_ids: &[K]) -> Vec<MutexGuard<'_, ()>> {
+        let mut slots: Vec<usize> = soliton_ids
+            .iter()
+            .map(|soliton_id| {
+                let mut hasher = DefaultHasher::new();
+                soliton_id.as_ref().hash(&mut hasher);
+                hasher.finish() as usize % self.slots.len()
+            })
+            .collect();
+        slots.sort_unstable();
+        slots.dedup();
+        slots
+            .into_iter()
+            .map(|slot| self.slots[slot].lock().unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Percolator transactions over MVCC.
+//!
+//! Each committed version of a soliton_id is kept under its commit_ts in the
+//! write causet_merge family, pointing at its causet_locale in the default one
+//! unless it is short enough to be kept inline; a transaction in flight holds a
+//! lock in the lock causet_merge family. A transaction reads as of its start_ts
+//! and commits in two phases: it prewrites every soliton_id, locking it and
+//! checking that nothing newer was committed, then commits the primary
+//! soliton_id, which decides it, and the others after.
+//!
+//! Async commit decides the commit_ts when prewriting, so that the transaction
+//! is committed once every soliton_id is prewritten; 1PC commits right in the
+//! prewrite. Pessimistic transactions lock soliton_ids as they write them, and
+//! wait for each other's locks, with a deadlock detector breaking cycles. Locks
+//! carry a TTL that heartbeats extend; one found expired is rolled back by the
+//! transaction that met it.
+//!
+//! Reads may be served by followers, or, at a timestamp the storage closed,
+//! by any replica without asking the leader: closing pushes `max_ts` past it
+//! and stays below every lock, so nothing commits at or before it later.
+
+mod actions;
+mod client;
+mod codec;
+mod deadlock;
+mod engine;
+mod errors;
+mod latches;
+mod lock;
+mod reader;
+mod storage;
+mod txn;
+mod write;
+
+pub use crate::actions::{Mutation, MutationOp, SecondaryLockStatus, TxnStatus};
+pub use crate::client::{Transaction, TxnClient, TxnOptions};
+pub use crate::codec::{append_ts, decode_key, encode_key, split_ts};
+pub use crate::deadlock::DetectTable;
+pub use crate::engine::{
+    ClusterEngine, ClusterSnapshot, Engine, LocalEngine, LocalSnapshot, Modify, Snapshot,
+    StoreSnapshot, CF_DEFAULT, CF_LOCK, CF_WRITE,
+};
+pub use crate::errors::{Error, LockInfo, Result};
+pub use crate::lock::{Lock, LockType};
+pub use crate::reader::{MvccReader, TxnCommitRecord};
+pub use crate::storage::{
+    PessimisticLockRequest, PrewriteRequest, PrewriteResult, SecondaryLocksStatus, Storage,
+};
+pub use crate::write::{Write, WriteType, SHORT_VALUE_MAX_LEN};
+pub use violetabftstore::ReadConsistency;
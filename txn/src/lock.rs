@@ -0,0 +1,130 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Locks, kept in the lock causet_merge family at the encoded soliton_id while a
+//! transaction holds it.
+
+use pd::tso::extract_physical;
+use violetabft::codec::{get_bytes, get_u8, get_varint, put_bytes, put_varint};
+use violetabft::{Codec, Error as CodecError, Result as CodecResult};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LockType {
+    /// Prewritten with a causet_locale to put.
+    #[default]
+    Put,
+    /// Prewritten to delete.
+    Delete,
+    /// Prewritten only to lock: commits no new causet_locale.
+    Lock,
+    /// Taken by a pessimistic transaction before its prewrite; readers ignore
+    /// it.
+    Pessimistic,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Lock {
+    pub lock_type: LockType,
+    /// The soliton_id whose lock decides whether the transaction committed.
+    pub primary: Vec<u8>,
+    pub start_ts: u64,
+    /// How long, in milliseconds from the physical time of `start_ts`, the lock
+    /// is kept without a heartbeat.
+    pub ttl: u64,
+    /// A causet_locale short enough to be kept in the lock, and then in the write
+    /// record.
+    pub short_value: Option<Vec<u8>>,
+    /// Of a pessimistic transaction, the timestamp its conflicts were checked
+    /// at.
+    pub for_update_ts: u64,
+    /// The least commit_ts the transaction may commit at: readers of the
+    /// soliton_id push it past their timestamps.
+    pub min_commit_ts: u64,
+    /// The transaction commits once all its soliton_ids are prewritten, at the
+    /// greatest `min_commit_ts` of their locks.
+    pub use_async_commit: bool,
+    /// On the primary lock of an async commit, the other soliton_ids.
+    pub secondaries: Vec<Vec<u8>>,
+}
+
+impl Lock {
+    pub fn new(lock_type: LockType, primary: Vec<u8>, start_ts: u64, ttl: u64) -> Lock {
+        Lock {
+            lock_type,
+            primary,
+            start_ts,
+            ttl,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the lock outlived its TTL at `current_ts`, so that its
+    /// transaction may be rolled back.
+    pub fn is_expired(&self, current_ts: u64) -> bool {
+        extract_physical(self.start_ts) + self.ttl < extract_physical(current_ts)
+    }
+}
+
+const FLAG_SHORT_VALUE: u8 = 1;
+const FLAG_ASYNC_COMMIT: u8 = 1 << 1;
+
+impl Codec for Lock {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.push(match self.lock_type {
+            LockType::Put => b'P',
+            LockType::Delete => b'D',
+            LockType::Lock => b'L',
+            LockType::Pessimistic => b'S',
+        });
+        put_bytes(buf, &self.primary);
+        put_varint(buf, self.start_ts);
+        put_varint(buf, self.ttl);
+        put_varint(buf, self.for_update_ts);
+        put_varint(buf, self.min_commit_ts);
+        let mut flags = 0;
+        if self.short_value.is_some() {
+            flags |= FLAG_SHORT_VALUE;
+        }
+        if self.use_async_commit {
+            flags |= FLAG_ASYNC_COMMIT;
+        }
+        buf.push(flags);
+        if let Some(v) = &self.short_value {
+            put_bytes(buf, v);
+        }
+        if self.use_async_commit {
+            put_varint(buf, self.secondaries.len() as u64);
+            for s in &self.secondaries {
+                put_bytes(buf, s);
+            }
+        }
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<Lock> {
+        let lock_type = match get_u8(buf)? {
+            b'P' => LockType::Put,
+            b'D' => LockType::Delete,
+            b'L' => LockType::Lock,
+            b'S' => LockType::Pessimistic,
+            t => return Err(CodecError::Corruption(format!("unknown lock type {}", t))),
+        };
+        let mut lock = Lock::new(
+            lock_type,
+            get_bytes(buf)?.to_vec(),
+            get_varint(buf)?,
+            get_varint(buf)?,
+        );
+        lock.for_update_ts = get_varint(buf)?;
+        lock.min_commit_ts = get_varint(buf)?;
+        let flags = get_u8(buf)?;
+        if flags & FLAG_SHORT_VALUE != 0 {
+            lock.short_value = Some(get_bytes(buf)?.to_vec());
+        }
+        if flags & FLAG_ASYNC_COMMIT != 0 {
+            lock.use_async_commit = true;
+            for _ in 0..get_varint(buf)? {
+                lock.secondaries.push(get_bytes(buf)?.to_vec());
+            }
+        }
+        Ok(lock)
+    }
+}
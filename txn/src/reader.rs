@@ -0,0 +1,216 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Reads of the MVCC causet_merge families, by user soliton_id and timestamp.
+
+use violetabft::Codec;
+
+use crate::codec::{append_ts, decode_key, encode_key, split_ts, versions_end};
+use crate::engine::{Snapshot, CF_DEFAULT, CF_LOCK, CF_WRITE};
+use crate::errors::{Error, LockInfo, Result};
+use crate::lock::{Lock, LockType};
+use crate::write::{Write, WriteType};
+
+/// What became of a transaction on a soliton_id, as its write records tell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxnCommitRecord {
+    Committed {
+        commit_ts: u64,
+        write: Write,
+    },
+    RolledBack,
+    /// Neither: it is locked, or was never prewritten.
+    None,
+}
+
+pub struct MvccReader<S: Snapshot> {
+    snap: S,
+}
+
+impl<S: Snapshot> MvccReader<S> {
+    pub fn new(snap: S) -> MvccReader<S> {
+        MvccReader { snap }
+    }
+
+    pub fn snapshot(&self) -> &S {
+        &self.snap
+    }
+
+    pub fn load_lock(&self, soliton_id: &[u8]) -> Result<Option<Lock>> {
+        match self.snap.get_cf(CF_LOCK, &encode_key(soliton_id))? {
+            Some(v) => Ok(Some(Lock::decode(&v)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// The newest write record of `soliton_id` committed at or before `ts`, with
+    /// its commit_ts.
+    pub fn seek_write(&self, soliton_id: &[u8], ts: u64) -> Result<Option<(u64, Write)>> {
+        let encoded = encode_key(soliton_id);
+        let pairs = self.snap.scan_cf(
+            CF_WRITE,
+            &append_ts(&encoded, ts),
+            &versions_end(&encoded),
+            1,
+        )?;
+        match pairs.into_iter().next() {
+            Some((k, v)) => Ok(Some((split_ts(&k)?.1, Write::decode(&v)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// The newest put or delete of `soliton_id` committed at or before `ts`,
+    /// skipping locks and rollbacks.
+    pub fn get_write(&self, soliton_id: &[u8], mut ts: u64) -> Result<Option<(u64, Write)>> {
+        loop {
+            match self.seek_write(soliton_id, ts)? {
+                Some((commit_ts, write)) => match write.write_type {
+                    WriteType::Put | WriteType::Delete => return Ok(Some((commit_ts, write))),
+                    WriteType::Lock | WriteType::Rollback if commit_ts > 0 => ts = commit_ts - 1,
+                    _ => return Ok(None),
+                },
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// The causet_locale `write` puts, or `None` if it does not.
+    pub fn load_value(&self, soliton_id: &[u8], write: Write) -> Result<Option<Vec<u8>>> {
+        if write.write_type != WriteType::Put {
+            return Ok(None);
+        }
+        if let Some(v) = write.short_value {
+            return Ok(Some(v));
+        }
+        let default_key = append_ts(&encode_key(soliton_id), write.start_ts);
+        match self.snap.get_cf(CF_DEFAULT, &default_key)? {
+            Some(v) => Ok(Some(v)),
+            None => Err(Error::Other(format!(
+                "causet_locale of soliton_id {:?} written at {} is missing",
+                soliton_id, write.start_ts
+            ))),
+        }
+    }
+
+    /// The causet_locale of `soliton_id` as of `ts`.
+    ///
+    /// Fails with `KeyIsLocked` if a transaction holds a lock on it that it may
+    /// commit at or before `ts`, unless the transaction is in `bypass_locks`.
+    pub fn get(&self, soliton_id: &[u8], ts: u64, bypass_locks: &[u64]) -> Result<Option<Vec<u8>>> {
+        if let Some(lock) = self.load_lock(soliton_id)? {
+            check_lock(soliton_id, lock, ts, bypass_locks)?;
+        }
+        match self.get_write(soliton_id, ts)? {
+            Some((_, write)) => self.load_value(soliton_id, write),
+            None => Ok(None),
+        }
+    }
+
+    /// At most `limit` pairs of `[start, end)` as of `ts`, or all if it is 0; an
+    /// empty end is unbounded. Fails as `get` does on the first lock in the
+    /// way.
+    pub fn scan(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        ts: u64,
+        limit: usize,
+        bypass_locks: &[u64],
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        for (soliton_id, lock) in self.scan_locks(start, end, |_| true, 0)? {
+            check_lock(&soliton_id, lock, ts, bypass_locks)?;
+        }
+        let (start, end) = encode_range(start, end);
+        let mut pairs = Vec::new();
+        let mut last: Option<Vec<u8>> = None;
+        for (k, _) in self.snap.scan_cf(CF_WRITE, &start, &end, 0)? {
+            let (soliton_id, _) = decode_key(&k)?;
+            if last.as_ref() == Some(&soliton_id) {
+                continue;
+            }
+            if let Some((_, write)) = self.get_write(&soliton_id, ts)? {
+                if let Some(v) = self.load_value(&soliton_id, write)? {
+                    pairs.push((soliton_id.clone(), v));
+                    if limit > 0 && pairs.len() >= limit {
+                        break;
+                    }
+                }
+            }
+            last = Some(soliton_id);
+        }
+        Ok(pairs)
+    }
+
+    /// At most `limit` locks in `[start, end)` that `filter` accepts, or all if
+    /// it is 0, with their soliton_ids; an empty end is unbounded.
+    pub fn scan_locks(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        filter: impl Fn(&Lock) -> bool,
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Lock)>> {
+        let (start, end) = encode_range(start, end);
+        let mut locks = Vec::new();
+        for (k, v) in self.snap.scan_cf(CF_LOCK, &start, &end, 0)? {
+            let lock = Lock::decode(&v)?;
+            if filter(&lock) {
+                locks.push((decode_key(&k)?.0, lock));
+                if limit > 0 && locks.len() >= limit {
+                    break;
+                }
+            }
+        }
+        Ok(locks)
+    }
+
+    /// What became of the transaction started at `start_ts` on `soliton_id`.
+    pub fn get_txn_commit_record(
+        &self,
+        soliton_id: &[u8],
+        start_ts: u64,
+    ) -> Result<TxnCommitRecord> {
+        // A transaction commits after it starts, so only the newer records matter.
+        let encoded = encode_key(soliton_id);
+        let end = match start_ts.checked_sub(1) {
+            Some(ts) => append_ts(&encoded, ts),
+            None => versions_end(&encoded),
+        };
+        for (k, v) in self.snap.scan_cf(CF_WRITE, &encoded, &end, 0)? {
+            let commit_ts = split_ts(&k)?.1;
+            let write = Write::decode(&v)?;
+            if write.start_ts != start_ts {
+                continue;
+            }
+            if write.write_type == WriteType::Rollback {
+                return Ok(TxnCommitRecord::RolledBack);
+            }
+            return Ok(TxnCommitRecord::Committed { commit_ts, write });
+        }
+        Ok(TxnCommitRecord::None)
+    }
+}
+
+fn encode_range(start: &[u8], end: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let end = if end.is_empty() {
+        Vec::new()
+    } else {
+        encode_key(end)
+    };
+    (encode_key(start), end)
+}
+
+/// Whether a read at `ts` must wait for `lock`: it must if the lock's
+/// transaction started at or before `ts` and may still commit at or before it.
+fn check_lock(soliton_id: &[u8], lock: Lock, ts: u64, bypass_locks: &[u64]) -> Result<()> {
+    if lock.start_ts > ts
+        || matches!(lock.lock_type, LockType::Lock | LockType::Pessimistic)
+        || lock.min_commit_ts > ts
+        || bypass_locks.contains(&lock.start_ts)
+    {
+        return Ok(());
+    }
+    Err(Error::KeyIsLocked(Box::new(LockInfo {
+        soliton_id: soliton_id.to_vec(),
+        lock,
+    })))
+}
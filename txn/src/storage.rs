@@ -0,0 +1,809 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The transactional commands, run against an `Engine`.
+//!
+//! A command takes the latches of its soliton_ids, reads their state from a
+//! snapshot, and writes what its actions changed at once, or nothing if one of
+//! them fails.
+//!
+//! Async commit and 1PC decide the commit_ts when prewriting, from `max_ts`,
+//! the greatest timestamp read at: a transaction must not commit at or below a
+//! timestamp that was read before its locks were visible. So reads push
+//! `max_ts` and check locks under a shared guard, and these prewrites hold it
+//! exclusively until their locks are written.
+//!
+//! Closing a timestamp for stale reads pushes `max_ts` the same way, then keeps
+//! below the locks it finds: what commits at or before the closed timestamp
+//! later can only be a transaction locked already, whose lock a stale read
+//! would meet.
+//!
+//! A pessimistic lock request that meets another transaction's lock waits for
+//! locks to be released, for at most its wait timeout, unless the deadlock
+//! detector finds that waiting would close a cycle.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use violetabftstore::ReadConsistency;
+
+use crate::actions::{self, Mutation, SecondaryLockStatus, TxnProps, TxnStatus};
+use crate::deadlock::DetectTable;
+use crate::engine::Engine;
+use crate::errors::{Error, LockInfo, Result};
+use crate::latches::Latches;
+use crate::lock::Lock;
+use crate::reader::MvccReader;
+use crate::txn::MvccTxn;
+use crate::write::{Write, WriteType};
+
+const LATCH_SLOTS: usize = 2048;
+const WAIT_FOR_TTL: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Debug, Default)]
+pub struct PrewriteRequest {
+    pub mutations: Vec<Mutation>,
+    pub primary: Vec<u8>,
+    pub start_ts: u64,
+    pub lock_ttl: u64,
+    /// Of a pessimistic transaction, which prewrites only soliton_ids it locked;
+    /// 0 for an optimistic one.
+    pub for_update_ts: u64,
+    pub use_async_commit: bool,
+    /// With async commit, the soliton_ids other than the primary.
+    pub secondaries: Vec<Vec<u8>>,
+    /// Commits at once instead of locking, if all the soliton_ids are in this
+    /// request.
+    pub try_one_pc: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrewriteResult {
+    /// With async commit, the least commit_ts of the locks.
+    pub min_commit_ts: u64,
+    /// With 1PC, the commit_ts.
+    pub one_pc_commit_ts: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PessimisticLockRequest {
+    pub soliton_ids: Vec<Vec<u8>>,
+    pub primary: Vec<u8>,
+    pub start_ts: u64,
+    pub for_update_ts: u64,
+    pub lock_ttl: u64,
+    /// How long to wait for a lock held by another transaction; not at all if
+    /// `None`.
+    pub wait_timeout: Option<Duration>,
+}
+
+/// What became of an asynchronously committing transaction, as its secondary
+/// soliton_ids tell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecondaryLocksStatus {
+    /// Prewritten on all of them: it commits at the greatest `min_commit_ts`.
+    Locked(Vec<Lock>),
+    Committed(u64),
+    RolledBack,
+}
+
+pub struct Storage<E: Engine> {
+    engine: E,
+    latches: Latches,
+    max_ts: AtomicU64,
+    max_ts_guard: RwLock<()>,
+    detector: Mutex<DetectTable>,
+    /// Bumped whenever locks are released, waking the waiters.
+    released: Mutex<u64>,
+    released_cv: Condvar,
+}
+
+impl<E: Engine> Storage<E> {
+    pub fn new(engine: E) -> Storage<E> {
+        Storage {
+            engine,
+            latches: Latches::new(LATCH_SLOTS),
+            max_ts: AtomicU64::new(0),
+            max_ts_guard: RwLock::new(()),
+            detector: Mutex::new(DetectTable::new(WAIT_FOR_TTL)),
+            released: Mutex::new(0),
+            released_cv: Condvar::new(),
+        }
+    }
+
+    pub fn engine(&self) -> &E {
+        &self.engine
+    }
+
+    fn reader(&self) -> Result<MvccReader<E::Snap>> {
+        Ok(MvccReader::new(self.engine.snapshot()?))
+    }
+
+    fn reader_with(&self, consistency: ReadConsistency) -> Result<MvccReader<E::Snap>> {
+        Ok(MvccReader::new(self.engine.snapshot_with(consistency)?))
+    }
+
+    fn write(&self, txn: MvccTxn) -> Result<()> {
+        if txn.is_empty() {
+            return Ok(());
+        }
+        self.engine.write(txn.into_modifies())
+    }
+
+    fn notify_released(&self) {
+        *self.released.lock().unwrap() += 1;
+        self.released_cv.notify_all();
+    }
+
+    /// Runs `action` on each soliton_id under their latches, and writes what
+    /// they changed.
+    fn run<K: AsRef<[u8]>>(
+        &self,
+        start_ts: u64,
+        soliton_ids: &[K],
+        mut action: impl FnMut(&mut MvccTxn, &MvccReader<E::Snap>, &[u8]) -> Result<()>,
+    ) -> Result<()> {
+        let _latches = self.latches.acquire(soliton_ids);
+        let reader = self.reader()?;
+        let mut txn = MvccTxn::new(start_ts);
+        for soliton_id in soliton_ids {
+            action(&mut txn, &reader, soliton_id.as_ref())?;
+        }
+        self.write(txn)
+    }
+
+    /// The causet_locale of `soliton_id` as of `ts`.
+    pub fn get(&self, soliton_id: &[u8], ts: u64) -> Result<Option<Vec<u8>>> {
+        self.get_with(soliton_id, ts, ReadConsistency::Strong)
+    }
+
+    /// Like `get`, served as `consistency` asks. A stale read is at the
+    /// timestamp it carries, rather than `ts`.
+    pub fn get_with(
+        &self,
+        soliton_id: &[u8],
+        ts: u64,
+        consistency: ReadConsistency,
+    ) -> Result<Option<Vec<u8>>> {
+        let ts = read_ts(ts, consistency);
+        let _guard = self.max_ts_guard.read().unwrap();
+        self.max_ts.fetch_max(ts, Ordering::SeqCst);
+        self.reader_with(consistency)?.get(soliton_id, ts, &[])
+    }
+
+    /// At most `limit` pairs of `[start, end)` as of `ts`, or all if it is 0; an
+    /// empty end is unbounded.
+    pub fn scan(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+        ts: u64,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.scan_with(start, end, limit, ts, ReadConsistency::Strong)
+    }
+
+    pub fn scan_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+        ts: u64,
+        consistency: ReadConsistency,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let ts = read_ts(ts, consistency);
+        let _guard = self.max_ts_guard.read().unwrap();
+        self.max_ts.fetch_max(ts, Ordering::SeqCst);
+        self.reader_with(consistency)?
+            .scan(start, end, ts, limit, &[])
+    }
+
+    /// Pushes `max_ts` to `ts`, once the prewrites deciding a commit_ts wrote
+    /// their locks; those after decide one past `ts`.
+    pub fn push_max_ts(&self, ts: u64) {
+        let _guard = self.max_ts_guard.read().unwrap();
+        self.max_ts.fetch_max(ts, Ordering::SeqCst);
+    }
+
+    /// Closes the engine for stale reads at `ts`, a timestamp just taken from the
+    /// TSO, or below the oldest lock if that is earlier; the closed timestamp.
+    pub fn close_ts(&self, ts: u64) -> Result<u64> {
+        self.push_max_ts(ts);
+        let oldest = self
+            .reader()?
+            .scan_locks(b"", b"", |_| true, 0)?
+            .into_iter()
+            .map(|(_, lock)| lock.start_ts)
+            .min();
+        let closed = match oldest {
+            Some(start_ts) => ts.min(start_ts.saturating_sub(1)),
+            None => ts,
+        };
+        self.engine.close_ts(closed)?;
+        Ok(closed)
+    }
+
+    pub fn prewrite(&self, req: PrewriteRequest) -> Result<PrewriteResult> {
+        let soliton_ids: Vec<&[u8]> = req.mutations.iter().map(|m| &m.soliton_id[..]).collect();
+        let _latches = self.latches.acquire(&soliton_ids);
+        let decides_commit_ts = req.use_async_commit || req.try_one_pc;
+        let _guard = decides_commit_ts.then(|| self.max_ts_guard.write().unwrap());
+        let min_commit_ts = if decides_commit_ts {
+            (self
+                .max_ts
+                .load(Ordering::SeqCst)
+                .max(req.for_update_ts)
+                .max(req.start_ts))
+                + 1
+        } else {
+            0
+        };
+        let props = TxnProps {
+            primary: &req.primary,
+            lock_ttl: req.lock_ttl,
+            for_update_ts: req.for_update_ts,
+            use_async_commit: req.use_async_commit && !req.try_one_pc,
+            secondaries: &req.secondaries,
+            min_commit_ts,
+        };
+        let reader = self.reader()?;
+        let mut txn = MvccTxn::new(req.start_ts);
+        let mut locks = Vec::with_capacity(req.mutations.len());
+        for m in &req.mutations {
+            if let Some(lock) = actions::prewrite(&mut txn, &reader, &props, m.clone())? {
+                locks.push((&m.soliton_id, lock));
+            }
+        }
+        if !req.try_one_pc {
+            for (soliton_id, lock) in &locks {
+                txn.put_lock(soliton_id, lock);
+            }
+            self.write(txn)?;
+            return Ok(PrewriteResult {
+                min_commit_ts,
+                one_pc_commit_ts: 0,
+            });
+        }
+        for (soliton_id, lock) in locks {
+            let write_type = WriteType::from_lock_type(lock.lock_type).unwrap();
+            let write = Write::new(write_type, req.start_ts, lock.short_value);
+            txn.put_write(soliton_id, min_commit_ts, &write);
+            // Of a pessimistic transaction.
+            txn.unlock_key(soliton_id);
+        }
+        self.write(txn)?;
+        if req.for_update_ts > 0 {
+            self.finish(req.start_ts);
+        }
+        Ok(PrewriteResult {
+            min_commit_ts: 0,
+            one_pc_commit_ts: min_commit_ts,
+        })
+    }
+
+    /// Forgets the waits of a finished transaction, and wakes those waiting for
+    /// its locks.
+    fn finish(&self, start_ts: u64) {
+        self.detector.lock().unwrap().clean_up(start_ts);
+        self.notify_released();
+    }
+
+    pub fn commit(&self, soliton_ids: &[Vec<u8>], start_ts: u64, commit_ts: u64) -> Result<()> {
+        self.run(start_ts, soliton_ids, |txn, reader, soliton_id| {
+            actions::commit(txn, reader, soliton_id, commit_ts)
+        })?;
+        self.finish(start_ts);
+        Ok(())
+    }
+
+    pub fn rollback(&self, soliton_ids: &[Vec<u8>], start_ts: u64) -> Result<()> {
+        self.run(start_ts, soliton_ids, |txn, reader, soliton_id| {
+            actions::rollback(txn, reader, soliton_id)
+        })?;
+        self.finish(start_ts);
+        Ok(())
+    }
+
+    /// The status of the transaction started at `lock_ts` whose primary is
+    /// `primary`; see `actions::check_txn_status`.
+    pub fn check_txn_status(
+        &self,
+        primary: &[u8],
+        lock_ts: u64,
+        caller_start_ts: u64,
+        current_ts: u64,
+        rollback_if_not_exist: bool,
+    ) -> Result<TxnStatus> {
+        let mut status = None;
+        self.run(lock_ts, &[primary], |txn, reader, primary| {
+            status = Some(actions::check_txn_status(
+                txn,
+                reader,
+                primary,
+                caller_start_ts,
+                current_ts,
+                rollback_if_not_exist,
+            )?);
+            Ok(())
+        })?;
+        let status = status.unwrap();
+        if status == TxnStatus::TtlExpired {
+            self.finish(lock_ts);
+        }
+        Ok(status)
+    }
+
+    /// The status of the asynchronously committing transaction started at
+    /// `start_ts` on its secondary soliton_ids; the first of them it has not
+    /// prewritten rolls it back.
+    pub fn check_secondary_locks(
+        &self,
+        soliton_ids: &[Vec<u8>],
+        start_ts: u64,
+    ) -> Result<SecondaryLocksStatus> {
+        let mut locks = Vec::with_capacity(soliton_ids.len());
+        let mut decided = None;
+        self.run(start_ts, soliton_ids, |txn, reader, soliton_id| {
+            if decided.is_some() {
+                return Ok(());
+            }
+            match actions::check_secondary_lock(txn, reader, soliton_id)? {
+                SecondaryLockStatus::Locked(lock) => locks.push(lock),
+                SecondaryLockStatus::Committed(commit_ts) => {
+                    decided = Some(SecondaryLocksStatus::Committed(commit_ts))
+                }
+                SecondaryLockStatus::RolledBack => decided = Some(SecondaryLocksStatus::RolledBack),
+            }
+            Ok(())
+        })?;
+        Ok(decided.unwrap_or(SecondaryLocksStatus::Locked(locks)))
+    }
+
+    /// Extends the TTL of the primary lock to `advise_ttl` if that is longer;
+    /// the TTL.
+    pub fn txn_heart_beat(&self, primary: &[u8], start_ts: u64, advise_ttl: u64) -> Result<u64> {
+        let mut ttl = 0;
+        self.run(start_ts, &[primary], |txn, reader, primary| {
+            ttl = actions::txn_heart_beat(txn, reader, primary, advise_ttl)?;
+            Ok(())
+        })?;
+        Ok(ttl)
+    }
+
+    /// At most `limit` locks in `[start, end)` of transactions started at or
+    /// before `max_ts`, or all if it is 0.
+    pub fn scan_lock(
+        &self,
+        max_ts: u64,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Result<Vec<LockInfo>> {
+        let locks = self
+            .reader()?
+            .scan_locks(start, end, |lock| lock.start_ts <= max_ts, limit)?;
+        Ok(locks
+            .into_iter()
+            .map(|(soliton_id, lock)| LockInfo { soliton_id, lock })
+            .collect())
+    }
+
+    /// Commits the locks of the transaction started at `start_ts` at
+    /// `commit_ts`, or rolls them back if it is 0: those on `soliton_ids`, or
+    /// all of them if it is empty.
+    pub fn resolve_lock(
+        &self,
+        start_ts: u64,
+        commit_ts: u64,
+        soliton_ids: &[Vec<u8>],
+    ) -> Result<()> {
+        let soliton_ids = if soliton_ids.is_empty() {
+            self.reader()?
+                .scan_locks(b"", b"", |lock| lock.start_ts == start_ts, 0)?
+                .into_iter()
+                .map(|(soliton_id, _)| soliton_id)
+                .collect()
+        } else {
+            soliton_ids.to_vec()
+        };
+        if commit_ts == 0 {
+            self.rollback(&soliton_ids, start_ts)
+        } else {
+            self.commit(&soliton_ids, start_ts, commit_ts)
+        }
+    }
+
+    /// Takes pessimistic locks on all of `req.soliton_ids`, or none.
+    pub fn acquire_pessimistic_lock(&self, req: &PessimisticLockRequest) -> Result<()> {
+        let deadline = req.wait_timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let released = *self.released.lock().unwrap();
+            let info = match self.run(req.start_ts, &req.soliton_ids, |txn, reader, soliton_id| {
+                actions::acquire_pessimistic_lock(
+                    txn,
+                    reader,
+                    soliton_id,
+                    &req.primary,
+                    req.for_update_ts,
+                    req.lock_ttl,
+                )
+            }) {
+                Err(Error::KeyIsLocked(info)) => info,
+                res => return res,
+            };
+            let deadline = match deadline {
+                Some(deadline) if Instant::now() < deadline => deadline,
+                _ => return Err(Error::KeyIsLocked(info)),
+            };
+            let lock_ts = info.lock.start_ts;
+            let detected =
+                self.detector
+                    .lock()
+                    .unwrap()
+                    .detect(req.start_ts, lock_ts, &info.soliton_id);
+            if let Some(wait_chain) = detected {
+                return Err(Error::Deadlock {
+                    soliton_id: info.soliton_id,
+                    start_ts: req.start_ts,
+                    lock_ts,
+                    wait_chain,
+                });
+            }
+            self.wait_for_release(released, deadline);
+            self.detector.lock().unwrap().clean_up_wait_for(
+                req.start_ts,
+                lock_ts,
+                &info.soliton_id,
+            );
+        }
+    }
+
+    /// Waits until locks are released after `released` was read, or `deadline`.
+    fn wait_for_release(&self, released: u64, deadline: Instant) {
+        let mut current = self.released.lock().unwrap();
+        while *current == released {
+            let now = Instant::now();
+            if now >= deadline {
+                return;
+            }
+            current = self
+                .released_cv
+                .wait_timeout(current, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Releases the pessimistic locks on `soliton_ids` taken at or before
+    /// `for_update_ts`.
+    pub fn pessimistic_rollback(
+        &self,
+        soliton_ids: &[Vec<u8>],
+        start_ts: u64,
+        for_update_ts: u64,
+    ) -> Result<()> {
+        self.run(start_ts, soliton_ids, |txn, reader, soliton_id| {
+            actions::pessimistic_rollback(txn, reader, soliton_id, for_update_ts)
+        })?;
+        self.finish(start_ts);
+        Ok(())
+    }
+}
+
+/// The timestamp a read as of `ts` is made at: a stale read carries its own.
+pub(crate) fn read_ts(ts: u64, consistency: ReadConsistency) -> u64 {
+    match consistency {
+        ReadConsistency::Stale(stale_ts) => stale_ts,
+        _ => ts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use pd::tso::compose_ts;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::engine::LocalEngine;
+    use crate::lock::LockType;
+
+    fn new_storage() -> (TempDir, Storage<LocalEngine>) {
+        let dir = TempDir::new().unwrap();
+        let engine = LocalEngine::open(dir.path()).unwrap();
+        (dir, Storage::new(engine))
+    }
+
+    fn prewrite_req(mutations: Vec<Mutation>, start_ts: u64) -> PrewriteRequest {
+        PrewriteRequest {
+            primary: mutations[0].soliton_id.clone(),
+            mutations,
+            start_ts,
+            lock_ttl: 100,
+            ..Default::default()
+        }
+    }
+
+    fn must_locked(storage: &Storage<LocalEngine>, soliton_id: &[u8], ts: u64) -> Lock {
+        match storage.get(soliton_id, ts) {
+            Err(Error::KeyIsLocked(info)) => info.lock,
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_prewrite_commit_rollback() {
+        let (_dir, storage) = new_storage();
+        let long = vec![b'v'; 1000];
+        let req = prewrite_req(
+            vec![
+                Mutation::put(b"k1".to_vec(), b"v1".to_vec()),
+                Mutation::put(b"k2".to_vec(), long.clone()),
+                Mutation::lock(b"k3".to_vec()),
+            ],
+            10,
+        );
+        storage.prewrite(req.clone()).unwrap();
+        // Prewriting again changes nothing.
+        storage.prewrite(req).unwrap();
+        assert_eq!(must_locked(&storage, b"k1", 15).primary, b"k1");
+        assert_eq!(storage.get(b"k1", 5).unwrap(), None);
+        assert_eq!(storage.get(b"k3", 15).unwrap(), None);
+
+        // Another transaction fails on the locks, and on the versions committed
+        // after it started.
+        let req = prewrite_req(vec![Mutation::put(b"k2".to_vec(), b"x".to_vec())], 12);
+        assert!(matches!(storage.prewrite(req), Err(Error::KeyIsLocked(_))));
+        let keys = vec![b"k1".to_vec(), b"k2".to_vec(), b"k3".to_vec()];
+        storage.commit(&keys, 10, 20).unwrap();
+        storage.commit(&keys, 10, 20).unwrap();
+        let req = prewrite_req(vec![Mutation::put(b"k2".to_vec(), b"x".to_vec())], 12);
+        assert!(matches!(
+            storage.prewrite(req),
+            Err(Error::WriteConflict {
+                conflict_commit_ts: 20,
+                ..
+            })
+        ));
+        assert_eq!(storage.get(b"k1", 15).unwrap(), None);
+        assert_eq!(storage.get(b"k1", 20).unwrap().unwrap(), b"v1");
+        assert_eq!(storage.get(b"k2", 25).unwrap().unwrap(), long);
+        assert_eq!(
+            storage.scan(b"k", b"", 0, 25).unwrap(),
+            vec![
+                (b"k1".to_vec(), b"v1".to_vec()),
+                (b"k2".to_vec(), long.clone())
+            ]
+        );
+        assert!(matches!(
+            storage.rollback(&[b"k1".to_vec()], 10),
+            Err(Error::Committed { commit_ts: 20, .. })
+        ));
+
+        // A deletion rolled back leaves the old version; its late prewrite
+        // fails.
+        let req = prewrite_req(vec![Mutation::delete(b"k1".to_vec())], 30);
+        storage.prewrite(req.clone()).unwrap();
+        storage.rollback(&[b"k1".to_vec()], 30).unwrap();
+        assert_eq!(storage.get(b"k1", 40).unwrap().unwrap(), b"v1");
+        assert!(matches!(
+            storage.commit(&[b"k1".to_vec()], 30, 40),
+            Err(Error::TxnLockNotFound { .. })
+        ));
+        assert!(matches!(
+            storage.prewrite(req),
+            Err(Error::WriteConflict { .. })
+        ));
+    }
+
+    #[test]
+    fn test_txn_status_and_resolve() {
+        let (_dir, storage) = new_storage();
+        let ts = |physical, logical| compose_ts(physical, logical);
+        let start_ts = ts(1000, 0);
+        let req = prewrite_req(
+            vec![
+                Mutation::put(b"p".to_vec(), b"v".to_vec()),
+                Mutation::put(b"s".to_vec(), b"v".to_vec()),
+            ],
+            start_ts,
+        );
+        storage.prewrite(req).unwrap();
+
+        // Alive: a reader pushes it past itself, and reads past it.
+        let reader_ts = ts(1050, 0);
+        match storage
+            .check_txn_status(b"p", start_ts, reader_ts, reader_ts, true)
+            .unwrap()
+        {
+            TxnStatus::Locked(lock) => assert_eq!(lock.min_commit_ts, reader_ts + 1),
+            s => panic!("{:?}", s),
+        }
+        assert_eq!(storage.get(b"p", reader_ts).unwrap(), None);
+        assert!(matches!(
+            storage.commit(&[b"p".to_vec()], start_ts, reader_ts),
+            Err(Error::CommitTsExpired { .. })
+        ));
+
+        // A heartbeat keeps it alive past its first TTL.
+        assert_eq!(storage.txn_heart_beat(b"p", start_ts, 500).unwrap(), 500);
+        assert_eq!(storage.txn_heart_beat(b"p", start_ts, 200).unwrap(), 500);
+        let now = ts(1200, 0);
+        assert!(matches!(
+            storage
+                .check_txn_status(b"p", start_ts, 0, now, true)
+                .unwrap(),
+            TxnStatus::Locked(_)
+        ));
+
+        // Expired, it is rolled back, and then its secondary with it.
+        let now = ts(1600, 0);
+        assert_eq!(
+            storage
+                .check_txn_status(b"p", start_ts, 0, now, true)
+                .unwrap(),
+            TxnStatus::TtlExpired
+        );
+        assert_eq!(
+            storage
+                .check_txn_status(b"p", start_ts, 0, now, true)
+                .unwrap(),
+            TxnStatus::RolledBack
+        );
+        assert_eq!(storage.scan_lock(u64::MAX, b"", b"", 0).unwrap().len(), 1);
+        storage.resolve_lock(start_ts, 0, &[]).unwrap();
+        assert!(storage.scan_lock(u64::MAX, b"", b"", 0).unwrap().is_empty());
+        assert_eq!(storage.get(b"s", now).unwrap(), None);
+
+        // A transaction that left no trace is rolled back only if asked to.
+        assert!(matches!(
+            storage.check_txn_status(b"q", 7, 0, now, false),
+            Err(Error::TxnNotFound { .. })
+        ));
+        assert_eq!(
+            storage.check_txn_status(b"q", 7, 0, now, true).unwrap(),
+            TxnStatus::RolledBack
+        );
+        let req = prewrite_req(vec![Mutation::put(b"q".to_vec(), b"v".to_vec())], 7);
+        assert!(matches!(
+            storage.prewrite(req),
+            Err(Error::WriteConflict { .. })
+        ));
+    }
+
+    #[test]
+    fn test_async_commit_and_one_pc() {
+        let (_dir, storage) = new_storage();
+        // A read at 50 comes first: the transaction must commit after it.
+        assert_eq!(storage.get(b"a", 50).unwrap(), None);
+        let mut req = prewrite_req(
+            vec![
+                Mutation::put(b"a".to_vec(), b"1".to_vec()),
+                Mutation::put(b"b".to_vec(), b"2".to_vec()),
+            ],
+            10,
+        );
+        req.use_async_commit = true;
+        req.secondaries = vec![b"b".to_vec()];
+        let res = storage.prewrite(req).unwrap();
+        assert_eq!(res.min_commit_ts, 51);
+        assert_eq!(storage.get(b"a", 50).unwrap(), None);
+        let primary = must_locked(&storage, b"a", 60);
+        assert!(primary.use_async_commit);
+        assert_eq!(primary.secondaries, vec![b"b".to_vec()]);
+
+        // Every soliton_id is prewritten: the transaction commits at the
+        // greatest min_commit_ts.
+        match storage.check_secondary_locks(&[b"b".to_vec()], 10).unwrap() {
+            SecondaryLocksStatus::Locked(locks) => assert_eq!(locks[0].min_commit_ts, 51),
+            s => panic!("{:?}", s),
+        }
+        storage
+            .resolve_lock(10, 51, &[b"a".to_vec(), b"b".to_vec()])
+            .unwrap();
+        assert_eq!(storage.get(b"b", 51).unwrap().unwrap(), b"2");
+        assert_eq!(
+            storage.check_secondary_locks(&[b"b".to_vec()], 10).unwrap(),
+            SecondaryLocksStatus::Committed(51)
+        );
+        // One it has not prewritten rolls it back.
+        assert_eq!(
+            storage.check_secondary_locks(&[b"c".to_vec()], 11).unwrap(),
+            SecondaryLocksStatus::RolledBack
+        );
+
+        let mut req = prewrite_req(
+            vec![
+                Mutation::put(b"a".to_vec(), b"3".to_vec()),
+                Mutation::delete(b"b".to_vec()),
+            ],
+            70,
+        );
+        req.try_one_pc = true;
+        let res = storage.prewrite(req).unwrap();
+        assert_eq!(res.one_pc_commit_ts, 71);
+        assert!(storage.scan_lock(u64::MAX, b"", b"", 0).unwrap().is_empty());
+        assert_eq!(storage.get(b"a", 71).unwrap().unwrap(), b"3");
+        assert_eq!(storage.get(b"b", 71).unwrap(), None);
+        assert_eq!(storage.get(b"b", 70).unwrap().unwrap(), b"2");
+    }
+
+    #[test]
+    fn test_pessimistic_lock_and_deadlock() {
+        let (_dir, storage) = new_storage();
+        let storage = Arc::new(storage);
+        let lock_req = |soliton_id: &[u8], start_ts, wait_timeout| PessimisticLockRequest {
+            soliton_ids: vec![soliton_id.to_vec()],
+            primary: soliton_id.to_vec(),
+            start_ts,
+            for_update_ts: start_ts,
+            lock_ttl: 3000,
+            wait_timeout,
+        };
+        storage
+            .acquire_pessimistic_lock(&lock_req(b"a", 10, None))
+            .unwrap();
+        storage
+            .acquire_pessimistic_lock(&lock_req(b"b", 20, None))
+            .unwrap();
+        // Readers ignore pessimistic locks.
+        assert_eq!(storage.get(b"a", 30).unwrap(), None);
+        assert!(matches!(
+            storage.acquire_pessimistic_lock(&lock_req(b"b", 10, Some(Duration::from_millis(10)))),
+            Err(Error::KeyIsLocked(_))
+        ));
+
+        // 10 waits for 20 on b; 20 then waiting for 10 on a closes a cycle.
+        let waiter = {
+            let storage = storage.clone();
+            thread::spawn(move || {
+                let mut req = lock_req(b"b", 10, Some(Duration::from_secs(10)));
+                req.primary = b"a".to_vec();
+                storage.acquire_pessimistic_lock(&req)
+            })
+        };
+        while !storage.detector.lock().unwrap().is_waiting(10) {
+            thread::sleep(Duration::from_millis(1));
+        }
+        let mut req = lock_req(b"a", 20, Some(Duration::from_secs(10)));
+        req.primary = b"b".to_vec();
+        let err = match storage.acquire_pessimistic_lock(&req) {
+            Err(Error::Deadlock { wait_chain, .. }) => wait_chain,
+            r => panic!("{:?}", r),
+        };
+        assert_eq!(err, vec![20, 10, 20]);
+        storage
+            .pessimistic_rollback(&[b"b".to_vec()], 20, 20)
+            .unwrap();
+        waiter.join().unwrap().unwrap();
+
+        // Prewriting needs the pessimistic locks.
+        let mut req = prewrite_req(
+            vec![
+                Mutation::put(b"a".to_vec(), b"1".to_vec()),
+                Mutation::put(b"c".to_vec(), b"1".to_vec()),
+            ],
+            10,
+        );
+        req.for_update_ts = 10;
+        assert!(matches!(
+            storage.prewrite(req.clone()),
+            Err(Error::PessimisticLockNotFound { .. })
+        ));
+        req.mutations[1] = Mutation::put(b"b".to_vec(), b"2".to_vec());
+        storage.prewrite(req).unwrap();
+        assert_eq!(must_locked(&storage, b"b", 30).lock_type, LockType::Put);
+        storage
+            .commit(&[b"a".to_vec(), b"b".to_vec()], 10, 40)
+            .unwrap();
+        assert_eq!(storage.get(b"b", 40).unwrap().unwrap(), b"2");
+
+        // A lock taken at a for_update_ts before the commit conflicts.
+        assert!(matches!(
+            storage.acquire_pessimistic_lock(&lock_req(b"a", 35, None)),
+            Err(Error::WriteConflict { .. })
+        ));
+    }
+}
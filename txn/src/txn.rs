@@ -0,0 +1,60 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The changes a command makes to the MVCC causet_merge families, collected to
+//! be written at once.
+
+use violetabft::Codec;
+
+use crate::codec::{append_ts, encode_key};
+use crate::engine::{Modify, CF_DEFAULT, CF_LOCK, CF_WRITE};
+use crate::lock::Lock;
+use crate::write::Write;
+
+pub struct MvccTxn {
+    pub start_ts: u64,
+    modifies: Vec<Modify>,
+}
+
+impl MvccTxn {
+    pub fn new(start_ts: u64) -> MvccTxn {
+        MvccTxn {
+            start_ts,
+            modifies: Vec::new(),
+        }
+    }
+
+    pub fn put_lock(&mut self, soliton_id: &[u8], lock: &Lock) {
+        self.modifies
+            .push(Modify::Put(CF_LOCK, encode_key(soliton_id), lock.encode()));
+    }
+
+    pub fn unlock_key(&mut self, soliton_id: &[u8]) {
+        self.modifies
+            .push(Modify::Delete(CF_LOCK, encode_key(soliton_id)));
+    }
+
+    pub fn put_value(&mut self, soliton_id: &[u8], ts: u64, causet_locale: Vec<u8>) {
+        let soliton_id = append_ts(&encode_key(soliton_id), ts);
+        self.modifies
+            .push(Modify::Put(CF_DEFAULT, soliton_id, causet_locale));
+    }
+
+    pub fn delete_value(&mut self, soliton_id: &[u8], ts: u64) {
+        let soliton_id = append_ts(&encode_key(soliton_id), ts);
+        self.modifies.push(Modify::Delete(CF_DEFAULT, soliton_id));
+    }
+
+    pub fn put_write(&mut self, soliton_id: &[u8], commit_ts: u64, write: &Write) {
+        let soliton_id = append_ts(&encode_key(soliton_id), commit_ts);
+        self.modifies
+            .push(Modify::Put(CF_WRITE, soliton_id, write.encode()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modifies.is_empty()
+    }
+
+    pub fn into_modifies(self) -> Vec<Modify> {
+        self.modifies
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Write records, kept in the write causet_merge family at the encoded
+//! soliton_id and commit_ts: what a committed transaction did to the
+//! soliton_id, or that it was rolled back.
+
+use violetabft::codec::{get_bytes, get_u8, get_varint, put_bytes, put_varint};
+use violetabft::{Codec, Error as CodecError, Result as CodecResult};
+
+use crate::lock::LockType;
+
+/// Causet_locales up to this long are kept in the lock and the write record
+/// rather than in the default causet_merge family.
+pub const SHORT_VALUE_MAX_LEN: usize = 255;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WriteType {
+    Put,
+    Delete,
+    /// The soliton_id was only locked; the causet_locale is that of an earlier
+    /// version.
+    Lock,
+    /// The transaction was rolled back; written at its start_ts, so that a late
+    /// prewrite of it fails.
+    Rollback,
+}
+
+impl WriteType {
+    /// The write that commits a lock of `lock_type`, if it can be committed.
+    pub fn from_lock_type(lock_type: LockType) -> Option<WriteType> {
+        match lock_type {
+            LockType::Put => Some(WriteType::Put),
+            LockType::Delete => Some(WriteType::Delete),
+            LockType::Lock => Some(WriteType::Lock),
+            LockType::Pessimistic => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Write {
+    pub write_type: WriteType,
+    pub start_ts: u64,
+    pub short_value: Option<Vec<u8>>,
+}
+
+impl Write {
+    pub fn new(write_type: WriteType, start_ts: u64, short_value: Option<Vec<u8>>) -> Write {
+        Write {
+            write_type,
+            start_ts,
+            short_value,
+        }
+    }
+
+    pub fn new_rollback(start_ts: u64) -> Write {
+        Write::new(WriteType::Rollback, start_ts, None)
+    }
+}
+
+impl Codec for Write {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.push(match self.write_type {
+            WriteType::Put => b'P',
+            WriteType::Delete => b'D',
+            WriteType::Lock => b'L',
+            WriteType::Rollback => b'R',
+        });
+        put_varint(buf, self.start_ts);
+        if let Some(v) = &self.short_value {
+            put_bytes(buf, v);
+        }
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<Write> {
+        let write_type = match get_u8(buf)? {
+            b'P' => WriteType::Put,
+            b'D' => WriteType::Delete,
+            b'L' => WriteType::Lock,
+            b'R' => WriteType::Rollback,
+            t => return Err(CodecError::Corruption(format!("unknown write type {}", t))),
+        };
+        let start_ts = get_varint(buf)?;
+        let short_value = if buf.is_empty() {
+            None
+        } else {
+            Some(get_bytes(buf)?.to_vec())
+        };
+        Ok(Write::new(write_type, start_ts, short_value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock::Lock;
+
+    #[test]
+    fn test_lock_and_write_codec() {
+        let mut lock = Lock::new(LockType::Put, b"pk".to_vec(), 10, 3000);
+        assert_eq!(Lock::decode(&lock.encode()).unwrap(), lock);
+        lock.short_value = Some(b"v".to_vec());
+        lock.for_update_ts = 12;
+        lock.min_commit_ts = 13;
+        lock.use_async_commit = true;
+        lock.secondaries = vec![b"s1".to_vec(), Vec::new()];
+        assert_eq!(Lock::decode(&lock.encode()).unwrap(), lock);
+
+        for write in [
+            Write::new(WriteType::Put, 10, Some(b"v".to_vec())),
+            Write::new(WriteType::Put, 10, Some(Vec::new())),
+            Write::new(WriteType::Delete, 10, None),
+            Write::new_rollback(10),
+        ] {
+            assert_eq!(Write::decode(&write.encode()).unwrap(), write);
+        }
+    }
+}
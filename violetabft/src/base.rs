@@ -0,0 +1,149 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! `BaseEinsteinDb`, the replicated log the root crate exposes, over a
+//! `RawNode`.
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Error;
+use crate::message::{Entry, Message, MessageType};
+use crate::raw_node::RawNode;
+use crate::storage::Storage;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestVote {
+    pub term: u64,
+    pub candidate_id: u64,
+    pub last_log_index: u64,
+    pub last_log_term: u64,
+}
+
+impl RequestVote {
+    pub fn new() -> RequestVote {
+        RequestVote::default()
+    }
+
+    pub fn get_term(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+///BASE EINSTEINDB is a variation of ACID
+///
+/// A member of a replicated log, implemented by `RawNode`. Logs are proposed
+/// to the VioletaBFT group and a `RequestVote` is stepped as the message it
+/// is; what the node then sends, persists and applies comes out of its
+/// `Ready`.
+pub trait BaseEinsteinDb {
+    fn append_log(&mut self, log: String) -> io::Result<()>;
+
+    /// Appends all of `entries`, in order, or none of them.
+    fn append_entries(&mut self, entries: Vec<String>) -> io::Result<()>;
+
+    fn request_vote(&mut self, request_vote: RequestVote) -> io::Result<()>;
+}
+
+fn io_error(e: Error) -> io::Error {
+    io::Error::other(e.to_string())
+}
+
+impl<T: Storage> BaseEinsteinDb for RawNode<T> {
+    /// Fails unless the node is the leader; followers drop proposals.
+    fn append_log(&mut self, log: String) -> io::Result<()> {
+        self.propose(Vec::new(), log.into_bytes()).map_err(io_error)
+    }
+
+    /// The entries are proposed in one message, so that the leader appends
+    /// them together or drops them together.
+    fn append_entries(&mut self, entries: Vec<String>) -> io::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut m = Message::new(MessageType::Propose, 0, self.violetabft.id);
+        m.entries = entries
+            .into_iter()
+            .map(|e| Entry {
+                data: e.into_bytes(),
+                ..Default::default()
+            })
+            .collect();
+        self.violetabft.step(m).map_err(io_error)
+    }
+
+    fn request_vote(&mut self, request_vote: RequestVote) -> io::Result<()> {
+        let mut m = Message::new(
+            MessageType::RequestVote,
+            self.violetabft.id,
+            request_vote.candidate_id,
+        );
+        m.term = request_vote.term;
+        m.index = request_vote.last_log_index;
+        m.log_term = request_vote.last_log_term;
+        self.step(m).map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::SimNetwork;
+
+    fn logs(logs: &[&str]) -> Vec<String> {
+        logs.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_append_entries() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 1);
+        network.elect(1);
+        let last_index =
+            |network: &SimNetwork| network.node(1).node.violetabft.violetabft_log.last_index();
+        let before = last_index(&network);
+        let node = &mut network.node_mut(1).node;
+        node.append_entries(logs(&["a", "b", "c"])).unwrap();
+        node.append_entries(Vec::new()).unwrap();
+        assert_eq!(last_index(&network), before + 3);
+        network.settle();
+        let applied: Vec<Vec<u8>> = ["a", "b", "c"]
+            .iter()
+            .map(|l| l.as_bytes().to_vec())
+            .collect();
+        for id in [1, 2, 3] {
+            assert_eq!(network.node(id).applied, applied, "node {}", id);
+        }
+
+        // While leadership is handed over, the whole batch is dropped.
+        network.isolate(2);
+        network.node_mut(1).node.transfer_leader(2).unwrap();
+        let before = last_index(&network);
+        let node = &mut network.node_mut(1).node;
+        assert!(node.append_entries(logs(&["d", "e"])).is_err());
+        assert_eq!(last_index(&network), before);
+
+        // So it is by a follower.
+        let follower = &mut network.node_mut(3).node;
+        assert!(follower.append_entries(logs(&["f"])).is_err());
+        assert!(follower.append_log("f".to_owned()).is_err());
+    }
+
+    #[test]
+    fn test_request_vote() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 1);
+        let node = &mut network.node_mut(1).node;
+        let term = node.violetabft.term;
+        node.request_vote(RequestVote {
+            term: term + 1,
+            candidate_id: 2,
+            ..RequestVote::new()
+        })
+        .unwrap();
+        assert_eq!(node.violetabft.term, term + 1);
+        assert_eq!(node.violetabft.vote, 2);
+        let resp = node.ready().messages;
+        assert_eq!(resp.len(), 1);
+        assert_eq!(resp[0].msg_type, MessageType::RequestVoteResponse);
+        assert!(!resp[0].reject);
+    }
+}
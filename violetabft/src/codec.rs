@@ -0,0 +1,110 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Varint and length-prefixed encoding of the messages, entries and states that
+//! are persisted or sent over the wire.
+
+use crate::errors::{Error, Result};
+
+pub fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+pub fn get_varint(buf: &mut &[u8]) -> Result<u64> {
+    let mut v = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&b, rest) = buf
+            .split_first()
+            .ok_or_else(|| Error::Corruption("truncated varint".to_owned()))?;
+        *buf = rest;
+        v |= u64::from(b & 0x7f) << shift;
+        if b < 0x80 {
+            return Ok(v);
+        }
+    }
+    Err(Error::Corruption("varint overflows u64".to_owned()))
+}
+
+pub fn get_u8(buf: &mut &[u8]) -> Result<u8> {
+    let (&b, rest) = buf
+        .split_first()
+        .ok_or_else(|| Error::Corruption("truncated byte".to_owned()))?;
+    *buf = rest;
+    Ok(b)
+}
+
+pub fn put_bytes(buf: &mut Vec<u8>, data: &[u8]) {
+    put_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+pub fn get_bytes<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len = get_varint(buf)? as usize;
+    if buf.len() < len {
+        return Err(Error::Corruption("truncated bytes".to_owned()));
+    }
+    let (data, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(data)
+}
+
+pub fn put_ids(buf: &mut Vec<u8>, ids: &[u64]) {
+    put_varint(buf, ids.len() as u64);
+    for &id in ids {
+        put_varint(buf, id);
+    }
+}
+
+pub fn get_ids(buf: &mut &[u8]) -> Result<Vec<u64>> {
+    let n = get_varint(buf)?;
+    (0..n).map(|_| get_varint(buf)).collect()
+}
+
+/// Types with a stable binary encoding.
+pub trait Codec: Sized {
+    fn encode_to(&self, buf: &mut Vec<u8>);
+
+    fn decode_from(buf: &mut &[u8]) -> Result<Self>;
+
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.encode_to(&mut buf);
+        buf
+    }
+
+    /// Decodes `data`, which must hold exactly one causet_locale.
+    fn decode(mut data: &[u8]) -> Result<Self> {
+        let v = Self::decode_from(&mut data)?;
+        if !data.is_empty() {
+            return Err(Error::Corruption("trailing bytes".to_owned()));
+        }
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_and_bytes() {
+        let mut buf = Vec::new();
+        for v in [0, 1, 127, 128, 300, u64::MAX] {
+            put_varint(&mut buf, v);
+        }
+        put_bytes(&mut buf, b"violeta");
+        put_ids(&mut buf, &[3, 1, 2]);
+        let mut data = buf.as_slice();
+        for v in [0, 1, 127, 128, 300, u64::MAX] {
+            assert_eq!(get_varint(&mut data).unwrap(), v);
+        }
+        assert_eq!(get_bytes(&mut data).unwrap(), b"violeta");
+        assert_eq!(get_ids(&mut data).unwrap(), vec![3, 1, 2]);
+        assert!(data.is_empty());
+        assert!(get_varint(&mut data).is_err());
+        assert!(get_bytes(&mut &[5, 1][..]).is_err());
+    }
+}
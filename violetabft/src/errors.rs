@@ -0,0 +1,65 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageError {
+    /// The requested index was compacted away; a snapshot is needed.
+    Compacted,
+    /// The requested index is past the last entry.
+    Unavailable,
+    /// The snapshot is older than what the storage holds.
+    SnapshotOutOfDate,
+    /// The snapshot is being built; ask again later.
+    SnapshotTemporarilyUnavailable,
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Store(StorageError),
+    ConfigInvalid(String),
+    /// The proposal was not appended, because the node is not the leader or a
+    /// configuration change is already pending.
+    ProposalDropped,
+    /// The configuration change can not be made from the current configuration.
+    ConfChange(String),
+    /// The step of a message from or to an unknown peer.
+    StepPeerNotFound,
+    /// The step of a local message to the node.
+    StepLocalMsg,
+    /// Persisted data could not be decoded.
+    Corruption(String),
+    Io(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Store(e) => write!(f, "storage error: {:?}", e),
+            Error::ConfigInvalid(msg) => write!(f, "invalid config: {}", msg),
+            Error::ProposalDropped => write!(f, "proposal dropped"),
+            Error::ConfChange(msg) => write!(f, "invalid configuration change: {}", msg),
+            Error::StepPeerNotFound => write!(f, "peer not found"),
+            Error::StepLocalMsg => write!(f, "local message stepped from outside"),
+            Error::Corruption(msg) => write!(f, "corruption: {}", msg),
+            Error::Io(msg) => write!(f, "io error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<StorageError> for Error {
+    fn from(e: StorageError) -> Error {
+        Error::Store(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Io(e.to_string())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,39 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A deterministic VioletaBFT (Raft) consensus core: leader election with
+//! pre-vote, log replication, commit index tracking, snapshot install,
+//! membership changes through joint consensus and read-index.
+//!
+//! A `RawNode` owns no thread, clock or socket. The application ticks it, steps
+//! the messages it receives, and handles each `Ready`: persisting to its
+//! `Storage`, sending through its `Transport` and applying committed entries.
+//! `sim` runs a group of nodes over an in-process network that can be
+//! partitioned and lose messages.
+
+mod base;
+pub mod codec;
+mod errors;
+mod message;
+mod raft;
+mod raw_node;
+mod read_only;
+pub mod sim;
+mod storage;
+mod tracker;
+mod transport;
+mod violetabft_log;
+
+pub use base::{BaseEinsteinDb, RequestVote};
+pub use codec::Codec;
+pub use errors::{Error, Result, StorageError};
+pub use message::{
+    ConfChange, ConfChangeSingle, ConfChangeType, ConfState, Entry, EntryType, HardState, Message,
+    MessageType, Snapshot, SnapshotMetadata, INVALID_ID, INVALID_INDEX,
+};
+pub use raft::{Config, SoftState, StateRole, VioletaBft};
+pub use raw_node::{RawNode, Ready};
+pub use read_only::ReadState;
+pub use storage::{MemStorage, MemStorageCore, RaftState, Storage};
+pub use tracker::{Progress, ProgressState, ProgressTracker};
+pub use transport::Transport;
+pub use violetabft_log::VioletaBftLog;
@@ -0,0 +1,490 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The entries, states and messages of the protocol.
+
+use crate::codec::*;
+use crate::errors::{Error, Result};
+
+/// No node has this id; a node with no leader or no vote holds it.
+pub const INVALID_ID: u64 = 0;
+/// The index before the first entry of a log.
+pub const INVALID_INDEX: u64 = 0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EntryType {
+    #[default]
+    Normal,
+    /// The data is an encoded `ConfChange`.
+    ConfChange,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Entry {
+    pub entry_type: EntryType,
+    pub term: u64,
+    pub index: u64,
+    pub data: Vec<u8>,
+    /// Opaque to the protocol; lets the proposer recognize its entry when applied.
+    pub context: Vec<u8>,
+}
+
+impl Entry {
+    /// The bytes the entry takes in messages, roughly.
+    pub fn size(&self) -> u64 {
+        (self.data.len() + self.context.len() + 24) as u64
+    }
+}
+
+impl Codec for Entry {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.push(match self.entry_type {
+            EntryType::Normal => 0,
+            EntryType::ConfChange => 1,
+        });
+        put_varint(buf, self.term);
+        put_varint(buf, self.index);
+        put_bytes(buf, &self.data);
+        put_bytes(buf, &self.context);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<Entry> {
+        let entry_type = match get_u8(buf)? {
+            0 => EntryType::Normal,
+            1 => EntryType::ConfChange,
+            t => return Err(Error::Corruption(format!("unknown entry type {}", t))),
+        };
+        Ok(Entry {
+            entry_type,
+            term: get_varint(buf)?,
+            index: get_varint(buf)?,
+            data: get_bytes(buf)?.to_vec(),
+            context: get_bytes(buf)?.to_vec(),
+        })
+    }
+}
+
+/// The state a node must persist before it answers a message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HardState {
+    pub term: u64,
+    pub vote: u64,
+    pub commit: u64,
+}
+
+impl Codec for HardState {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_varint(buf, self.term);
+        put_varint(buf, self.vote);
+        put_varint(buf, self.commit);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<HardState> {
+        Ok(HardState {
+            term: get_varint(buf)?,
+            vote: get_varint(buf)?,
+            commit: get_varint(buf)?,
+        })
+    }
+}
+
+/// The membership of a group. While a joint configuration is in effect,
+/// `voters_outgoing` holds the voters of the configuration it leaves, and decisions
+/// need a majority of both.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfState {
+    pub voters: Vec<u64>,
+    pub learners: Vec<u64>,
+    pub voters_outgoing: Vec<u64>,
+    /// Outgoing voters that become learners when the joint configuration is left.
+    pub learners_next: Vec<u64>,
+    /// Whether the leader leaves the joint configuration on its own once it is
+    /// applied.
+    pub auto_leave: bool,
+}
+
+impl ConfState {
+    pub fn with_voters(voters: Vec<u64>) -> ConfState {
+        ConfState {
+            voters,
+            ..Default::default()
+        }
+    }
+}
+
+impl Codec for ConfState {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_ids(buf, &self.voters);
+        put_ids(buf, &self.learners);
+        put_ids(buf, &self.voters_outgoing);
+        put_ids(buf, &self.learners_next);
+        buf.push(self.auto_leave as u8);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<ConfState> {
+        Ok(ConfState {
+            voters: get_ids(buf)?,
+            learners: get_ids(buf)?,
+            voters_outgoing: get_ids(buf)?,
+            learners_next: get_ids(buf)?,
+            auto_leave: get_u8(buf)? != 0,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotMetadata {
+    pub conf_state: ConfState,
+    /// The index and term of the last entry the snapshot covers.
+    pub index: u64,
+    pub term: u64,
+}
+
+/// The state machine as of an entry, replacing the log up to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    pub data: Vec<u8>,
+    pub metadata: SnapshotMetadata,
+}
+
+impl Snapshot {
+    pub fn is_empty(&self) -> bool {
+        self.metadata.index == INVALID_INDEX
+    }
+}
+
+impl Codec for Snapshot {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_bytes(buf, &self.data);
+        self.metadata.conf_state.encode_to(buf);
+        put_varint(buf, self.metadata.index);
+        put_varint(buf, self.metadata.term);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<Snapshot> {
+        Ok(Snapshot {
+            data: get_bytes(buf)?.to_vec(),
+            metadata: SnapshotMetadata {
+                conf_state: ConfState::decode_from(buf)?,
+                index: get_varint(buf)?,
+                term: get_varint(buf)?,
+            },
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfChangeType {
+    AddNode,
+    RemoveNode,
+    AddLearnerNode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfChangeSingle {
+    pub change_type: ConfChangeType,
+    pub node_id: u64,
+}
+
+/// A membership change. A change of at most one voter is made directly; larger
+/// ones go through a joint configuration, which the leader leaves on its own once
+/// it is applied. An empty change leaves the joint configuration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfChange {
+    pub changes: Vec<ConfChangeSingle>,
+    pub context: Vec<u8>,
+}
+
+impl ConfChange {
+    pub fn new(changes: Vec<ConfChangeSingle>) -> ConfChange {
+        ConfChange {
+            changes,
+            context: Vec::new(),
+        }
+    }
+
+    pub fn single(change_type: ConfChangeType, node_id: u64) -> ConfChange {
+        ConfChange::new(vec![ConfChangeSingle {
+            change_type,
+            node_id,
+        }])
+    }
+
+    /// Whether the change leaves a joint configuration.
+    pub fn leave_joint(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Codec for ConfChange {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_varint(buf, self.changes.len() as u64);
+        for c in &self.changes {
+            buf.push(match c.change_type {
+                ConfChangeType::AddNode => 0,
+                ConfChangeType::RemoveNode => 1,
+                ConfChangeType::AddLearnerNode => 2,
+            });
+            put_varint(buf, c.node_id);
+        }
+        put_bytes(buf, &self.context);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<ConfChange> {
+        let n = get_varint(buf)?;
+        let mut changes = Vec::new();
+        for _ in 0..n {
+            let change_type = match get_u8(buf)? {
+                0 => ConfChangeType::AddNode,
+                1 => ConfChangeType::RemoveNode,
+                2 => ConfChangeType::AddLearnerNode,
+                t => return Err(Error::Corruption(format!("unknown conf change type {}", t))),
+            };
+            changes.push(ConfChangeSingle {
+                change_type,
+                node_id: get_varint(buf)?,
+            });
+        }
+        Ok(ConfChange {
+            changes,
+            context: get_bytes(buf)?.to_vec(),
+        })
+    }
+}
+
+macro_rules! message_types {
+    ($($(#[$doc:meta])* $name:ident = $id:expr,)*) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub enum MessageType {
+            #[default]
+            $($(#[$doc])* $name,)*
+        }
+
+        impl MessageType {
+            fn to_u8(self) -> u8 {
+                match self {
+                    $(MessageType::$name => $id,)*
+                }
+            }
+
+            fn from_u8(t: u8) -> Result<MessageType> {
+                match t {
+                    $($id => Ok(MessageType::$name),)*
+                    t => Err(Error::Corruption(format!("unknown message type {}", t))),
+                }
+            }
+        }
+    };
+}
+
+message_types! {
+    /// Local: starts an election.
+    Hup = 0,
+    /// Local: makes the leader send heartbeats.
+    Beat = 1,
+    /// Local: appends the entries of the message, on the leader.
+    Propose = 2,
+    Append = 3,
+    AppendResponse = 4,
+    RequestVote = 5,
+    RequestVoteResponse = 6,
+    RequestPreVote = 7,
+    RequestPreVoteResponse = 8,
+    Snapshot = 9,
+    Heartbeat = 10,
+    HeartbeatResponse = 11,
+    /// Local: makes the leader step down unless a quorum was recently active.
+    CheckQuorum = 12,
+    /// Local: the transport could not reach `from`.
+    Unreachable = 13,
+    /// Local: whether the snapshot sent to `from` was delivered, `reject` if not.
+    SnapStatus = 14,
+    /// Local: makes the leader hand its leadership over to `from`.
+    TransferLeader = 15,
+    /// Tells the transferee of a leadership to campaign at once.
+    TimeoutNow = 16,
+    /// Asks for the index a read must wait for; `context` identifies the read.
+    /// A follower forwards it to its leader.
+    ReadIndex = 17,
+    /// The leader's answer to a forwarded `ReadIndex`, with the index.
+    ReadIndexResp = 18,
+}
+
+impl MessageType {
+    /// Whether the message never leaves the node.
+    pub fn is_local(self) -> bool {
+        matches!(
+            self,
+            MessageType::Hup
+                | MessageType::Beat
+                | MessageType::Propose
+                | MessageType::CheckQuorum
+                | MessageType::Unreachable
+                | MessageType::SnapStatus
+                | MessageType::TransferLeader
+        )
+    }
+
+    pub fn is_response(self) -> bool {
+        matches!(
+            self,
+            MessageType::AppendResponse
+                | MessageType::RequestVoteResponse
+                | MessageType::RequestPreVoteResponse
+                | MessageType::HeartbeatResponse
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Message {
+    pub msg_type: MessageType,
+    pub to: u64,
+    pub from: u64,
+    pub term: u64,
+    /// With `Append`, the term of the entry at `index`; with votes, the term of the
+    /// candidate's last entry.
+    pub log_term: u64,
+    pub index: u64,
+    pub entries: Vec<Entry>,
+    pub commit: u64,
+    pub snapshot: Option<Snapshot>,
+    pub reject: bool,
+    /// With a rejected `AppendResponse`, the last index of the follower's log.
+    pub reject_hint: u64,
+    pub context: Vec<u8>,
+}
+
+impl Message {
+    pub fn new(msg_type: MessageType, to: u64, from: u64) -> Message {
+        Message {
+            msg_type,
+            to,
+            from,
+            ..Default::default()
+        }
+    }
+}
+
+impl Codec for Message {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.push(self.msg_type.to_u8());
+        for v in [
+            self.to,
+            self.from,
+            self.term,
+            self.log_term,
+            self.index,
+            self.commit,
+            self.reject_hint,
+        ] {
+            put_varint(buf, v);
+        }
+        buf.push(self.reject as u8);
+        put_varint(buf, self.entries.len() as u64);
+        for e in &self.entries {
+            e.encode_to(buf);
+        }
+        match &self.snapshot {
+            Some(snapshot) => {
+                buf.push(1);
+                snapshot.encode_to(buf);
+            }
+            None => buf.push(0),
+        }
+        put_bytes(buf, &self.context);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> Result<Message> {
+        let msg_type = MessageType::from_u8(get_u8(buf)?)?;
+        let mut v = [0; 7];
+        for v in &mut v {
+            *v = get_varint(buf)?;
+        }
+        let reject = get_u8(buf)? != 0;
+        let n = get_varint(buf)?;
+        let entries = (0..n)
+            .map(|_| Entry::decode_from(buf))
+            .collect::<Result<_>>()?;
+        let snapshot = match get_u8(buf)? {
+            0 => None,
+            _ => Some(Snapshot::decode_from(buf)?),
+        };
+        Ok(Message {
+            msg_type,
+            to: v[0],
+            from: v[1],
+            term: v[2],
+            log_term: v[3],
+            index: v[4],
+            commit: v[5],
+            reject_hint: v[6],
+            reject,
+            entries,
+            snapshot,
+            context: get_bytes(buf)?.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_roundtrip() {
+        let cc = ConfChange::new(vec![
+            ConfChangeSingle {
+                change_type: ConfChangeType::AddNode,
+                node_id: 4,
+            },
+            ConfChangeSingle {
+                change_type: ConfChangeType::RemoveNode,
+                node_id: 1,
+            },
+        ]);
+        assert_eq!(ConfChange::decode(&cc.encode()).unwrap(), cc);
+
+        let m = Message {
+            msg_type: MessageType::Snapshot,
+            to: 2,
+            from: 1,
+            term: 5,
+            log_term: 4,
+            index: 10,
+            entries: vec![Entry {
+                entry_type: EntryType::ConfChange,
+                term: 5,
+                index: 11,
+                data: cc.encode(),
+                context: b"ctx".to_vec(),
+            }],
+            commit: 9,
+            snapshot: Some(Snapshot {
+                data: b"state".to_vec(),
+                metadata: SnapshotMetadata {
+                    conf_state: ConfState {
+                        voters: vec![1, 2, 3],
+                        learners: vec![4],
+                        voters_outgoing: vec![1, 2],
+                        learners_next: vec![],
+                        auto_leave: true,
+                    },
+                    index: 10,
+                    term: 4,
+                },
+            }),
+            reject: true,
+            reject_hint: 7,
+            context: Vec::new(),
+        };
+        assert_eq!(Message::decode(&m.encode()).unwrap(), m);
+        let hs = HardState {
+            term: 3,
+            vote: 2,
+            commit: 1,
+        };
+        assert_eq!(HardState::decode(&hs.encode()).unwrap(), hs);
+        assert!(Message::decode(&[99]).is_err());
+        assert!(Entry::decode(&[0, 1, 1, 0, 0, 7]).is_err());
+    }
+}
@@ -0,0 +1,1099 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The protocol state machine of one node: it steps messages and ticks, and
+//! queues the messages to send in `msgs`.
+//!
+//! Nothing here reads a clock or a random source: election timeouts are drawn
+//! from a generator seeded by the node id, so a run replays exactly.
+
+use std::collections::BTreeMap;
+use std::mem;
+
+use crate::codec::Codec;
+use crate::errors::{Error, Result, StorageError};
+use crate::message::{
+    ConfChange, ConfChangeType, ConfState, Entry, EntryType, HardState, Message, MessageType,
+    Snapshot, INVALID_ID,
+};
+use crate::read_only::{ReadOnly, ReadState};
+use crate::storage::Storage;
+use crate::tracker::{Configuration, ProgressState, ProgressTracker, VoteResult};
+use crate::violetabft_log::VioletaBftLog;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub id: u64,
+    /// The ticks without hearing from a leader after which a follower campaigns;
+    /// the actual timeout is drawn from `[election_tick, 2 * election_tick)`.
+    pub election_tick: usize,
+    /// The ticks between the heartbeats of a leader.
+    pub heartbeat_tick: usize,
+    /// The bytes of entries in one append message, at least one entry.
+    pub max_size_per_msg: u64,
+    /// The append messages in flight to a replicating peer.
+    pub max_inflight_msgs: usize,
+    /// Whether a node first checks it could win an election before it bumps its
+    /// term, so that a partitioned node does not disrupt the group when it returns.
+    pub pre_vote: bool,
+    /// Whether a leader steps down when it did not hear from a quorum for an
+    /// election timeout, and followers ignore votes while they hear from one.
+    pub check_quorum: bool,
+    /// The index the application already applied, when restarting.
+    pub applied: u64,
+    /// Whether the node never campaigns, though it votes if it is a voter: it
+    /// can not serve as the leader.
+    pub never_campaign: bool,
+}
+
+impl Config {
+    pub fn new(id: u64) -> Config {
+        Config {
+            id,
+            election_tick: 10,
+            heartbeat_tick: 1,
+            max_size_per_msg: 1024 * 1024,
+            max_inflight_msgs: 256,
+            pre_vote: true,
+            check_quorum: true,
+            applied: 0,
+            never_campaign: false,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.id == INVALID_ID {
+            return Err(Error::ConfigInvalid("invalid node id".to_owned()));
+        }
+        if self.heartbeat_tick == 0 {
+            return Err(Error::ConfigInvalid(
+                "heartbeat tick must be greater than 0".to_owned(),
+            ));
+        }
+        if self.election_tick <= self.heartbeat_tick {
+            return Err(Error::ConfigInvalid(
+                "election tick must be greater than heartbeat tick".to_owned(),
+            ));
+        }
+        if self.max_inflight_msgs == 0 {
+            return Err(Error::ConfigInvalid(
+                "max inflight messages must be greater than 0".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateRole {
+    Follower,
+    /// Asks for pre-votes before it becomes a candidate.
+    PreCandidate,
+    Candidate,
+    Leader,
+}
+
+/// The state of a node that need not be persisted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SoftState {
+    pub leader_id: u64,
+    pub raft_state: StateRole,
+}
+
+pub struct VioletaBft<T: Storage> {
+    pub id: u64,
+    pub term: u64,
+    pub vote: u64,
+    pub violetabft_log: VioletaBftLog<T>,
+    pub prs: ProgressTracker,
+    pub state: StateRole,
+    pub leader_id: u64,
+    /// The messages to send, taken by each `Ready`.
+    pub msgs: Vec<Message>,
+    /// The index of the last configuration change appended; no other may be
+    /// proposed until it is applied.
+    pub pending_conf_index: u64,
+    /// The peer the leadership is being handed over to; no proposal is taken
+    /// meanwhile.
+    pub lead_transferee: Option<u64>,
+    /// The reads confirmed since the last `Ready`, taken by it.
+    pub read_states: Vec<ReadState>,
+    read_only: ReadOnly,
+    /// The reads that came before the leader committed an entry of its term.
+    pending_read_index_messages: Vec<Message>,
+    max_msg_size: u64,
+    pre_vote: bool,
+    check_quorum: bool,
+    never_campaign: bool,
+    election_elapsed: usize,
+    heartbeat_elapsed: usize,
+    election_timeout: usize,
+    heartbeat_timeout: usize,
+    randomized_election_timeout: usize,
+    rng: u64,
+}
+
+/// The context of the votes of a node campaigning to take over a leadership,
+/// which voters grant even while they hear from the leader.
+const CAMPAIGN_TRANSFER: &[u8] = b"CampaignTransfer";
+
+fn vote_resp_type(t: MessageType) -> MessageType {
+    match t {
+        MessageType::RequestVote => MessageType::RequestVoteResponse,
+        MessageType::RequestPreVote => MessageType::RequestPreVoteResponse,
+        t => panic!("{:?} is not a vote request", t),
+    }
+}
+
+impl<T: Storage> VioletaBft<T> {
+    pub fn new(config: &Config, store: T) -> Result<VioletaBft<T>> {
+        config.validate()?;
+        let raft_state = store.initial_state()?;
+        let violetabft_log = VioletaBftLog::new(store)?;
+        let mut r = VioletaBft {
+            id: config.id,
+            term: 0,
+            vote: INVALID_ID,
+            violetabft_log,
+            prs: ProgressTracker::new(config.max_inflight_msgs),
+            state: StateRole::Follower,
+            leader_id: INVALID_ID,
+            msgs: Vec::new(),
+            pending_conf_index: 0,
+            lead_transferee: None,
+            read_states: Vec::new(),
+            read_only: ReadOnly::default(),
+            pending_read_index_messages: Vec::new(),
+            max_msg_size: config.max_size_per_msg,
+            pre_vote: config.pre_vote,
+            check_quorum: config.check_quorum,
+            never_campaign: config.never_campaign,
+            election_elapsed: 0,
+            heartbeat_elapsed: 0,
+            election_timeout: config.election_tick,
+            heartbeat_timeout: config.heartbeat_tick,
+            randomized_election_timeout: 0,
+            rng: config.id.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1,
+        };
+        let next_idx = r.violetabft_log.last_index() + 1;
+        r.prs.apply_conf(
+            Configuration::from_conf_state(&raft_state.conf_state),
+            next_idx,
+        );
+        let hs = raft_state.hard_state;
+        if hs != HardState::default() {
+            r.violetabft_log.commit_to(hs.commit);
+            r.term = hs.term;
+            r.vote = hs.vote;
+        }
+        if config.applied > 0 {
+            r.violetabft_log.applied_to(config.applied);
+        }
+        r.become_follower(r.term, INVALID_ID);
+        Ok(r)
+    }
+
+    pub fn soft_state(&self) -> SoftState {
+        SoftState {
+            leader_id: self.leader_id,
+            raft_state: self.state,
+        }
+    }
+
+    pub fn hard_state(&self) -> HardState {
+        HardState {
+            term: self.term,
+            vote: self.vote,
+            commit: self.violetabft_log.committed,
+        }
+    }
+
+    /// Whether the node may campaign: it is a voter of its configuration, and
+    /// not configured to never campaign.
+    pub fn promotable(&self) -> bool {
+        !self.never_campaign && self.prs.conf.is_voter(self.id)
+    }
+
+    fn next_rand(&mut self) -> u64 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng = x;
+        x
+    }
+
+    fn reset_randomized_election_timeout(&mut self) {
+        let spread = self.next_rand() % self.election_timeout as u64;
+        self.randomized_election_timeout = self.election_timeout + spread as usize;
+    }
+
+    fn reset(&mut self, term: u64) {
+        if self.term != term {
+            self.term = term;
+            self.vote = INVALID_ID;
+        }
+        self.leader_id = INVALID_ID;
+        self.election_elapsed = 0;
+        self.heartbeat_elapsed = 0;
+        self.reset_randomized_election_timeout();
+        self.prs.reset_votes();
+        self.prs
+            .reset_progress(self.id, self.violetabft_log.last_index());
+        self.pending_conf_index = 0;
+        self.lead_transferee = None;
+        // Reads waiting for the confirmation of a leadership are abandoned.
+        self.read_only = ReadOnly::default();
+        self.pending_read_index_messages.clear();
+    }
+
+    pub fn become_follower(&mut self, term: u64, leader_id: u64) {
+        self.reset(term);
+        self.leader_id = leader_id;
+        self.state = StateRole::Follower;
+    }
+
+    fn become_pre_candidate(&mut self) {
+        assert_ne!(self.state, StateRole::Leader, "a leader can not campaign");
+        // Neither the term nor the vote changes until the pre-election is won.
+        self.state = StateRole::PreCandidate;
+        self.leader_id = INVALID_ID;
+        self.prs.reset_votes();
+    }
+
+    fn become_candidate(&mut self) {
+        assert_ne!(self.state, StateRole::Leader, "a leader can not campaign");
+        self.reset(self.term + 1);
+        self.vote = self.id;
+        self.state = StateRole::Candidate;
+    }
+
+    fn become_leader(&mut self) {
+        assert_ne!(
+            self.state,
+            StateRole::Follower,
+            "a follower must campaign first"
+        );
+        self.reset(self.term);
+        self.leader_id = self.id;
+        self.state = StateRole::Leader;
+        if let Some(pr) = self.prs.progress.get_mut(&self.id) {
+            pr.become_replicate();
+        }
+        // A configuration change in the log may still be uncommitted; the next one
+        // waits for it.
+        self.pending_conf_index = self.violetabft_log.last_index();
+        // Entries of earlier terms are only committed along with one of this term.
+        self.append_entry(&mut [Entry::default()]);
+    }
+
+    pub fn tick(&mut self) {
+        match self.state {
+            StateRole::Leader => self.tick_heartbeat(),
+            _ => self.tick_election(),
+        }
+    }
+
+    fn tick_election(&mut self) {
+        self.election_elapsed += 1;
+        if self.promotable() && self.election_elapsed >= self.randomized_election_timeout {
+            self.election_elapsed = 0;
+            let _ = self.step(Message::new(MessageType::Hup, INVALID_ID, self.id));
+        }
+    }
+
+    fn tick_heartbeat(&mut self) {
+        self.heartbeat_elapsed += 1;
+        self.election_elapsed += 1;
+        if self.election_elapsed >= self.election_timeout {
+            self.election_elapsed = 0;
+            // The transferee did not take over in time.
+            self.lead_transferee = None;
+            if self.check_quorum {
+                let _ = self.step(Message::new(MessageType::CheckQuorum, INVALID_ID, self.id));
+            }
+        }
+        if self.state != StateRole::Leader {
+            return;
+        }
+        if self.heartbeat_elapsed >= self.heartbeat_timeout {
+            self.heartbeat_elapsed = 0;
+            let _ = self.step(Message::new(MessageType::Beat, INVALID_ID, self.id));
+        }
+    }
+
+    fn send(&mut self, mut m: Message) {
+        // Votes carry the term they are for; everything else the node's.
+        if m.term == 0 {
+            m.term = self.term;
+        }
+        self.msgs.push(m);
+    }
+
+    /// Appends `entries` as entries of this term, counting them as replicated to
+    /// the leader itself.
+    fn append_entry(&mut self, entries: &mut [Entry]) {
+        let last_index = self.violetabft_log.last_index();
+        for (i, e) in entries.iter_mut().enumerate() {
+            e.term = self.term;
+            e.index = last_index + 1 + i as u64;
+        }
+        let last_index = self.violetabft_log.append(entries);
+        if let Some(pr) = self.prs.progress.get_mut(&self.id) {
+            pr.maybe_update(last_index);
+        }
+        self.maybe_commit();
+    }
+
+    fn maybe_commit(&mut self) -> bool {
+        let committed = self.prs.committed();
+        self.violetabft_log.maybe_commit(committed, self.term)
+    }
+
+    fn peers(&self) -> Vec<u64> {
+        self.prs
+            .progress
+            .keys()
+            .copied()
+            .filter(|&id| id != self.id)
+            .collect()
+    }
+
+    fn send_append(&mut self, to: u64) {
+        self.maybe_send_append(to, true);
+    }
+
+    /// Sends `to` the entries it misses, or a snapshot if they were compacted.
+    /// Returns whether a message was sent.
+    fn maybe_send_append(&mut self, to: u64, send_if_empty: bool) -> bool {
+        let next_idx = match self.prs.progress.get(&to) {
+            Some(pr) if !pr.is_paused() => pr.next_idx,
+            _ => return false,
+        };
+        let term = self.violetabft_log.term(next_idx - 1);
+        let entries = self
+            .violetabft_log
+            .entries(next_idx, Some(self.max_msg_size));
+        let mut m = Message::new(MessageType::Append, to, self.id);
+        match (term, entries) {
+            (Ok(term), Ok(entries)) => {
+                if entries.is_empty() && !send_if_empty {
+                    return false;
+                }
+                let last = entries.last().map_or(next_idx - 1, |e| e.index);
+                m.index = next_idx - 1;
+                m.log_term = term;
+                m.entries = entries;
+                m.commit = self.violetabft_log.committed;
+                self.prs.progress.get_mut(&to).unwrap().update_sent(last);
+            }
+            (Err(Error::Store(StorageError::Compacted)), _)
+            | (_, Err(Error::Store(StorageError::Compacted))) => {
+                let snapshot = match self.violetabft_log.snapshot(0) {
+                    Ok(snapshot) if !snapshot.is_empty() => snapshot,
+                    // Not ready yet; the next heartbeat response tries again.
+                    _ => return false,
+                };
+                self.prs
+                    .progress
+                    .get_mut(&to)
+                    .unwrap()
+                    .become_snapshot(snapshot.metadata.index);
+                m.msg_type = MessageType::Snapshot;
+                m.snapshot = Some(snapshot);
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("unexpected error reading the log: {}", e),
+        }
+        self.send(m);
+        true
+    }
+
+    fn bcast_append(&mut self) {
+        for id in self.peers() {
+            self.send_append(id);
+        }
+    }
+
+    /// Sends heartbeats carrying the latest read waiting for a quorum, so that a
+    /// lost answer is made up for.
+    fn bcast_heartbeat(&mut self) {
+        let ctx = self.read_only.last_pending_request_ctx();
+        self.bcast_heartbeat_with_ctx(ctx.unwrap_or_default());
+    }
+
+    fn bcast_heartbeat_with_ctx(&mut self, ctx: Vec<u8>) {
+        for id in self.peers() {
+            let matched = self.prs.progress[&id].matched;
+            let mut m = Message::new(MessageType::Heartbeat, id, self.id);
+            // A follower can only commit what it is known to hold.
+            m.commit = matched.min(self.violetabft_log.committed);
+            m.context = ctx.clone();
+            self.send(m);
+        }
+    }
+
+    /// Whether the commit index is one of this term: until it is, it may lag
+    /// behind what an earlier leader committed.
+    fn committed_entry_in_current_term(&self) -> bool {
+        self.violetabft_log
+            .term(self.violetabft_log.committed)
+            .is_ok_and(|t| t == self.term)
+    }
+
+    /// Confirms the leadership for the read `m` at the current commit index.
+    fn handle_read_index(&mut self, m: Message) {
+        let index = self.violetabft_log.committed;
+        let alone = BTreeMap::from([(self.id, true)]);
+        if self.prs.conf.vote_result(&alone) == VoteResult::Won {
+            self.respond_read_index(m, index);
+            return;
+        }
+        let ctx = m.context.clone();
+        self.read_only.add_request(index, m, self.id);
+        self.bcast_heartbeat_with_ctx(ctx);
+    }
+
+    fn respond_read_index(&mut self, req: Message, index: u64) {
+        if req.from == INVALID_ID || req.from == self.id {
+            self.read_states.push(ReadState {
+                index,
+                request_ctx: req.context,
+            });
+            return;
+        }
+        let mut resp = Message::new(MessageType::ReadIndexResp, req.from, self.id);
+        resp.index = index;
+        resp.context = req.context;
+        self.send(resp);
+    }
+
+    /// The reads waiting for an entry of the term are served once one is
+    /// committed.
+    fn release_pending_read_index(&mut self) {
+        if !self.committed_entry_in_current_term() {
+            return;
+        }
+        for m in mem::take(&mut self.pending_read_index_messages) {
+            self.handle_read_index(m);
+        }
+    }
+
+    /// The reads waiting for a quorum to confirm the leadership.
+    pub fn pending_read_count(&self) -> usize {
+        self.read_only.pending_read_count() + self.pending_read_index_messages.len()
+    }
+
+    /// Whether committed configuration changes wait to be applied; the node does
+    /// not campaign until they are, as its configuration may be outdated.
+    fn has_unapplied_conf_changes(&self) -> bool {
+        let lo = self.violetabft_log.applied + 1;
+        let hi = self.violetabft_log.committed + 1;
+        match self.violetabft_log.slice(lo, hi, None) {
+            Ok(entries) => entries
+                .iter()
+                .any(|e| e.entry_type == EntryType::ConfChange),
+            Err(_) => false,
+        }
+    }
+
+    fn hup(&mut self) {
+        if self.state == StateRole::Leader || !self.promotable() {
+            return;
+        }
+        if self.has_unapplied_conf_changes() {
+            return;
+        }
+        self.campaign(self.pre_vote, false);
+    }
+
+    fn poll(&mut self, id: u64, granted: bool) -> VoteResult {
+        self.prs.record_vote(id, granted);
+        self.prs.tally_votes()
+    }
+
+    /// Campaigns for the next term; a `transfer` campaign, which the leader asked
+    /// for, skips the pre-vote and is granted votes within the leader's lease.
+    fn campaign(&mut self, pre_vote: bool, transfer: bool) {
+        let (vote_msg, term) = if pre_vote {
+            self.become_pre_candidate();
+            (MessageType::RequestPreVote, self.term + 1)
+        } else {
+            self.become_candidate();
+            (MessageType::RequestVote, self.term)
+        };
+        if self.poll(self.id, true) == VoteResult::Won {
+            // The only voter.
+            if pre_vote {
+                self.campaign(false, transfer);
+            } else {
+                self.become_leader();
+            }
+            return;
+        }
+        let voters: Vec<u64> = self
+            .prs
+            .conf
+            .voters
+            .union(&self.prs.conf.voters_outgoing)
+            .copied()
+            .filter(|&id| id != self.id)
+            .collect();
+        for id in voters {
+            let mut m = Message::new(vote_msg, id, self.id);
+            m.term = term;
+            m.index = self.violetabft_log.last_index();
+            m.log_term = self.violetabft_log.last_term();
+            if transfer {
+                m.context = CAMPAIGN_TRANSFER.to_vec();
+            }
+            self.send(m);
+        }
+    }
+
+    pub fn step(&mut self, m: Message) -> Result<()> {
+        if m.term == 0 {
+            // A local message.
+        } else if m.term > self.term {
+            if matches!(
+                m.msg_type,
+                MessageType::RequestVote | MessageType::RequestPreVote
+            ) {
+                // A node that hears from a leader ignores those that lost touch
+                // with it, so that they can not take over.
+                let in_lease = self.check_quorum
+                    && m.context != CAMPAIGN_TRANSFER
+                    && self.leader_id != INVALID_ID
+                    && self.election_elapsed < self.election_timeout;
+                if in_lease {
+                    return Ok(());
+                }
+            }
+            match m.msg_type {
+                // A pre-vote is for a term not started yet.
+                MessageType::RequestPreVote => {}
+                MessageType::RequestPreVoteResponse if !m.reject => {}
+                MessageType::Append | MessageType::Heartbeat | MessageType::Snapshot => {
+                    self.become_follower(m.term, m.from)
+                }
+                _ => self.become_follower(m.term, INVALID_ID),
+            }
+        } else if m.term < self.term {
+            if (self.check_quorum || self.pre_vote)
+                && matches!(m.msg_type, MessageType::Append | MessageType::Heartbeat)
+            {
+                // A leader partitioned away would not learn of the new term
+                // otherwise, as the vote requests that carry it were ignored.
+                self.send(Message::new(MessageType::AppendResponse, m.from, self.id));
+            } else if m.msg_type == MessageType::RequestPreVote {
+                let mut resp = Message::new(MessageType::RequestPreVoteResponse, m.from, self.id);
+                resp.term = self.term;
+                resp.reject = true;
+                self.send(resp);
+            }
+            return Ok(());
+        }
+
+        match m.msg_type {
+            MessageType::Hup => self.hup(),
+            MessageType::RequestVote | MessageType::RequestPreVote => self.handle_vote(m),
+            _ => match self.state {
+                StateRole::Leader => return self.step_leader(m),
+                StateRole::Candidate | StateRole::PreCandidate => return self.step_candidate(m),
+                StateRole::Follower => return self.step_follower(m),
+            },
+        }
+        Ok(())
+    }
+
+    fn handle_vote(&mut self, m: Message) {
+        let can_vote = self.vote == m.from
+            || (self.vote == INVALID_ID && self.leader_id == INVALID_ID)
+            || (m.msg_type == MessageType::RequestPreVote && m.term > self.term);
+        let mut resp = Message::new(vote_resp_type(m.msg_type), m.from, self.id);
+        if can_vote && self.violetabft_log.is_up_to_date(m.index, m.log_term) {
+            // A granted pre-vote is for the term asked.
+            resp.term = m.term;
+            if m.msg_type == MessageType::RequestVote {
+                self.election_elapsed = 0;
+                self.vote = m.from;
+            }
+        } else {
+            resp.term = self.term;
+            resp.reject = true;
+        }
+        self.send(resp);
+    }
+
+    fn step_leader(&mut self, mut m: Message) -> Result<()> {
+        match m.msg_type {
+            MessageType::Beat => {
+                self.bcast_heartbeat();
+                return Ok(());
+            }
+            MessageType::CheckQuorum => {
+                if !self.prs.quorum_recently_active(self.id) {
+                    self.become_follower(self.term, INVALID_ID);
+                }
+                return Ok(());
+            }
+            MessageType::Propose => {
+                if m.entries.is_empty() || self.lead_transferee.is_some() {
+                    return Err(Error::ProposalDropped);
+                }
+                let mut pending_conf_index = None;
+                for (i, e) in m.entries.iter().enumerate() {
+                    if e.entry_type != EntryType::ConfChange {
+                        continue;
+                    }
+                    if pending_conf_index.is_some()
+                        || self.pending_conf_index > self.violetabft_log.applied
+                    {
+                        return Err(Error::ProposalDropped);
+                    }
+                    // Joint configurations are left before anything else changes.
+                    let cc = ConfChange::decode(&e.data)?;
+                    if cc.leave_joint() != self.prs.conf.is_joint() {
+                        return Err(Error::ProposalDropped);
+                    }
+                    pending_conf_index = Some(self.violetabft_log.last_index() + 1 + i as u64);
+                }
+                if let Some(idx) = pending_conf_index {
+                    self.pending_conf_index = idx;
+                }
+                self.append_entry(&mut m.entries);
+                self.bcast_append();
+                return Ok(());
+            }
+            MessageType::TransferLeader => {
+                let transferee = m.from;
+                if transferee == self.id
+                    || self.lead_transferee == Some(transferee)
+                    || !self.prs.conf.is_voter(transferee)
+                {
+                    return Ok(());
+                }
+                let matched = match self.prs.progress.get(&transferee) {
+                    Some(pr) => pr.matched,
+                    None => return Err(Error::StepPeerNotFound),
+                };
+                // The transfer is abandoned if it does not complete in an election
+                // timeout.
+                self.election_elapsed = 0;
+                self.lead_transferee = Some(transferee);
+                if matched == self.violetabft_log.last_index() {
+                    self.send_timeout_now(transferee);
+                } else {
+                    self.send_append(transferee);
+                }
+                return Ok(());
+            }
+            MessageType::ReadIndex => {
+                if self.committed_entry_in_current_term() {
+                    self.handle_read_index(m);
+                } else {
+                    self.pending_read_index_messages.push(m);
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let from = m.from;
+        let last_index = self.violetabft_log.last_index();
+        let pr = match self.prs.progress.get_mut(&from) {
+            Some(pr) => pr,
+            None => return Err(Error::StepPeerNotFound),
+        };
+        pr.recent_active = true;
+        match m.msg_type {
+            MessageType::AppendResponse if m.reject => {
+                if !pr.maybe_decr_to(m.index, m.reject_hint) {
+                    return Ok(());
+                }
+                if pr.state == ProgressState::Replicate {
+                    pr.become_probe();
+                }
+                self.send_append(from);
+            }
+            MessageType::AppendResponse => {
+                let was_paused = pr.is_paused();
+                if !pr.maybe_update(m.index) {
+                    return Ok(());
+                }
+                match pr.state {
+                    ProgressState::Probe => pr.become_replicate(),
+                    ProgressState::Snapshot if pr.matched >= pr.pending_snapshot => {
+                        pr.become_replicate()
+                    }
+                    ProgressState::Replicate => pr.inflights.free_to(m.index),
+                    ProgressState::Snapshot => {}
+                }
+                if self.maybe_commit() {
+                    self.release_pending_read_index();
+                    self.bcast_append();
+                } else if was_paused {
+                    self.send_append(from);
+                }
+                while self.maybe_send_append(from, false) {}
+                if self.lead_transferee == Some(from)
+                    && self.prs.progress[&from].matched == self.violetabft_log.last_index()
+                {
+                    self.send_timeout_now(from);
+                }
+            }
+            MessageType::HeartbeatResponse => {
+                pr.paused = false;
+                if pr.matched < last_index {
+                    self.send_append(from);
+                }
+                let confirmed = match self.read_only.recv_ack(from, &m.context) {
+                    Some(acks) => self.prs.conf.vote_result(acks) == VoteResult::Won,
+                    None => false,
+                };
+                if confirmed {
+                    for rs in self.read_only.advance(&m.context) {
+                        self.respond_read_index(rs.req, rs.index);
+                    }
+                }
+            }
+            MessageType::SnapStatus if pr.state == ProgressState::Snapshot => {
+                if m.reject {
+                    pr.pending_snapshot = 0;
+                }
+                // Wait for the answer to the snapshot, or to the next heartbeat.
+                pr.become_probe();
+                pr.paused = true;
+            }
+            MessageType::Unreachable if pr.state == ProgressState::Replicate => {
+                pr.become_probe();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn send_timeout_now(&mut self, to: u64) {
+        self.send(Message::new(MessageType::TimeoutNow, to, self.id));
+    }
+
+    fn step_candidate(&mut self, m: Message) -> Result<()> {
+        match m.msg_type {
+            MessageType::Propose | MessageType::ReadIndex => return Err(Error::ProposalDropped),
+            MessageType::Append => {
+                self.become_follower(m.term, m.from);
+                self.handle_append(m);
+            }
+            MessageType::Heartbeat => {
+                self.become_follower(m.term, m.from);
+                self.handle_heartbeat(m);
+            }
+            MessageType::Snapshot => {
+                self.become_follower(m.term, m.from);
+                self.handle_snapshot(m);
+            }
+            MessageType::RequestPreVoteResponse | MessageType::RequestVoteResponse => {
+                let pre_vote = self.state == StateRole::PreCandidate;
+                if pre_vote != (m.msg_type == MessageType::RequestPreVoteResponse) {
+                    return Ok(());
+                }
+                match self.poll(m.from, !m.reject) {
+                    VoteResult::Won if pre_vote => self.campaign(false, false),
+                    VoteResult::Won => {
+                        self.become_leader();
+                        self.bcast_append();
+                    }
+                    VoteResult::Lost => self.become_follower(self.term, INVALID_ID),
+                    VoteResult::Pending => {}
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn step_follower(&mut self, m: Message) -> Result<()> {
+        match m.msg_type {
+            MessageType::Propose => return Err(Error::ProposalDropped),
+            MessageType::Append => {
+                self.election_elapsed = 0;
+                self.leader_id = m.from;
+                self.handle_append(m);
+            }
+            MessageType::Heartbeat => {
+                self.election_elapsed = 0;
+                self.leader_id = m.from;
+                self.handle_heartbeat(m);
+            }
+            MessageType::Snapshot => {
+                self.election_elapsed = 0;
+                self.leader_id = m.from;
+                self.handle_snapshot(m);
+            }
+            MessageType::TimeoutNow if self.promotable() => self.campaign(false, true),
+            MessageType::ReadIndex => {
+                if self.leader_id == INVALID_ID {
+                    return Err(Error::ProposalDropped);
+                }
+                let mut m = m;
+                m.to = self.leader_id;
+                self.send(m);
+            }
+            MessageType::ReadIndexResp => self.read_states.push(ReadState {
+                index: m.index,
+                request_ctx: m.context,
+            }),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_append(&mut self, m: Message) {
+        let mut resp = Message::new(MessageType::AppendResponse, m.from, self.id);
+        if m.index < self.violetabft_log.committed {
+            resp.index = self.violetabft_log.committed;
+        } else {
+            match self
+                .violetabft_log
+                .maybe_append(m.index, m.log_term, m.commit, &m.entries)
+            {
+                Some(last) => resp.index = last,
+                None => {
+                    resp.index = m.index;
+                    resp.reject = true;
+                    resp.reject_hint = self.violetabft_log.last_index();
+                }
+            }
+        }
+        self.send(resp);
+    }
+
+    fn handle_heartbeat(&mut self, m: Message) {
+        self.violetabft_log.commit_to(m.commit);
+        let mut resp = Message::new(MessageType::HeartbeatResponse, m.from, self.id);
+        resp.context = m.context;
+        self.send(resp);
+    }
+
+    fn handle_snapshot(&mut self, m: Message) {
+        let mut resp = Message::new(MessageType::AppendResponse, m.from, self.id);
+        let snapshot = m.snapshot.unwrap_or_default();
+        resp.index = if self.restore(snapshot) {
+            self.violetabft_log.last_index()
+        } else {
+            self.violetabft_log.committed
+        };
+        self.send(resp);
+    }
+
+    /// Replaces the log and configuration by `snapshot`, unless the log already
+    /// holds what it covers. Returns whether it did.
+    pub fn restore(&mut self, snapshot: Snapshot) -> bool {
+        let meta = &snapshot.metadata;
+        if meta.index <= self.violetabft_log.committed {
+            return false;
+        }
+        if self.violetabft_log.match_term(meta.index, meta.term) {
+            // The entries are here already; only the commit index moves.
+            self.violetabft_log.commit_to(meta.index);
+            return false;
+        }
+        let conf = Configuration::from_conf_state(&meta.conf_state);
+        if !conf.ids().contains(&self.id) {
+            // A snapshot from a configuration this node is not part of.
+            return false;
+        }
+        self.violetabft_log.restore(snapshot);
+        let last_index = self.violetabft_log.last_index();
+        self.prs.apply_conf(conf, last_index + 1);
+        self.prs.reset_progress(self.id, last_index);
+        true
+    }
+
+    /// Applies a committed configuration change, returning the new configuration.
+    pub fn apply_conf_change(&mut self, cc: &ConfChange) -> Result<ConfState> {
+        let mut conf = self.prs.conf.clone();
+        if cc.leave_joint() {
+            if !conf.is_joint() {
+                return Err(Error::ConfChange("not in a joint configuration".to_owned()));
+            }
+            conf.voters_outgoing.clear();
+            conf.learners.append(&mut conf.learners_next);
+            conf.auto_leave = false;
+        } else {
+            if conf.is_joint() {
+                return Err(Error::ConfChange(
+                    "already in a joint configuration".to_owned(),
+                ));
+            }
+            let old_voters = conf.voters.clone();
+            let mut new_voters = old_voters.clone();
+            for c in &cc.changes {
+                match c.change_type {
+                    ConfChangeType::AddNode => new_voters.insert(c.node_id),
+                    ConfChangeType::RemoveNode | ConfChangeType::AddLearnerNode => {
+                        new_voters.remove(&c.node_id)
+                    }
+                };
+            }
+            if new_voters.is_empty() {
+                return Err(Error::ConfChange("removing all voters".to_owned()));
+            }
+            // Changing more than one voter at once could let two disjoint majorities
+            // decide: go through a configuration that needs both.
+            let joint = old_voters.symmetric_difference(&new_voters).count() > 1;
+            for c in &cc.changes {
+                let id = c.node_id;
+                conf.learners.remove(&id);
+                conf.learners_next.remove(&id);
+                if c.change_type == ConfChangeType::AddLearnerNode {
+                    // An outgoing voter can only become a learner once it is out.
+                    if joint && old_voters.contains(&id) {
+                        conf.learners_next.insert(id);
+                    } else {
+                        conf.learners.insert(id);
+                    }
+                }
+            }
+            conf.voters = new_voters;
+            if joint {
+                conf.voters_outgoing = old_voters;
+                conf.auto_leave = true;
+            }
+        }
+        let conf_state = conf.to_conf_state();
+        self.prs
+            .apply_conf(conf, self.violetabft_log.last_index() + 1);
+
+        if self.state == StateRole::Leader {
+            if !self.promotable() {
+                // Removed or demoted: let the remaining voters elect a leader.
+                self.become_follower(self.term, INVALID_ID);
+            } else if self.maybe_commit() {
+                self.bcast_append();
+            } else {
+                for id in self.peers() {
+                    self.maybe_send_append(id, false);
+                }
+            }
+        }
+        Ok(conf_state)
+    }
+
+    /// Records that the application applied the log up to `applied`. A leader in
+    /// a joint configuration it entered on its own leaves it once it is applied.
+    pub fn commit_apply(&mut self, applied: u64) {
+        self.violetabft_log.applied_to(applied);
+        let conf = &self.prs.conf;
+        if self.state == StateRole::Leader
+            && conf.is_joint()
+            && conf.auto_leave
+            && applied >= self.pending_conf_index
+        {
+            let mut m = Message::new(MessageType::Propose, INVALID_ID, self.id);
+            m.entries = vec![Entry {
+                entry_type: EntryType::ConfChange,
+                data: ConfChange::default().encode(),
+                ..Default::default()
+            }];
+            self.step(m)
+                .expect("leaving the joint configuration is always allowed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemStorage;
+
+    fn new_node(id: u64, voters: Vec<u64>) -> VioletaBft<MemStorage> {
+        let storage = MemStorage::new_with_conf_state(ConfState::with_voters(voters));
+        VioletaBft::new(&Config::new(id), storage).unwrap()
+    }
+
+    fn take(r: &mut VioletaBft<MemStorage>, t: MessageType) -> Vec<Message> {
+        let (taken, rest) = r.msgs.drain(..).partition(|m| m.msg_type == t);
+        r.msgs = rest;
+        taken
+    }
+
+    #[test]
+    fn test_single_node_commit() {
+        let mut r = new_node(1, vec![1]);
+        assert!(Config::new(INVALID_ID).validate().is_err());
+        for _ in 0..20 {
+            r.tick();
+        }
+        // A pre-vote and a vote of its own.
+        assert_eq!((r.state, r.term), (StateRole::Leader, 1));
+        let mut m = Message::new(MessageType::Propose, INVALID_ID, 1);
+        m.entries = vec![Entry {
+            data: b"v".to_vec(),
+            ..Default::default()
+        }];
+        r.step(m).unwrap();
+        assert_eq!(r.violetabft_log.committed, 2);
+    }
+
+    #[test]
+    fn test_vote_rules() {
+        let mut r = new_node(1, vec![1, 2, 3]);
+        r.violetabft_log.append(&[Entry {
+            term: 2,
+            index: 1,
+            ..Default::default()
+        }]);
+
+        // A candidate whose log is behind is refused.
+        let mut vote = Message::new(MessageType::RequestVote, 1, 2);
+        vote.term = 3;
+        vote.log_term = 1;
+        vote.index = 5;
+        r.step(vote.clone()).unwrap();
+        let resp = take(&mut r, MessageType::RequestVoteResponse);
+        assert!(resp[0].reject);
+        assert_eq!(r.term, 3);
+
+        // One that is up to date gets the vote, and no other candidate of the term.
+        vote.log_term = 2;
+        vote.index = 1;
+        r.step(vote.clone()).unwrap();
+        assert!(!take(&mut r, MessageType::RequestVoteResponse)[0].reject);
+        assert_eq!(r.vote, 2);
+        vote.from = 3;
+        r.step(vote).unwrap();
+        assert!(take(&mut r, MessageType::RequestVoteResponse)[0].reject);
+
+        // A pre-vote for a later term does not change the term.
+        let mut pre_vote = Message::new(MessageType::RequestPreVote, 1, 3);
+        pre_vote.term = 4;
+        pre_vote.log_term = 2;
+        pre_vote.index = 1;
+        r.step(pre_vote).unwrap();
+        let resp = take(&mut r, MessageType::RequestPreVoteResponse);
+        assert!(!resp[0].reject);
+        assert_eq!((resp[0].term, r.term), (4, 3));
+    }
+
+    #[test]
+    fn test_never_campaign() {
+        let storage = MemStorage::new_with_conf_state(ConfState::with_voters(vec![1, 2, 3]));
+        let cfg = Config {
+            never_campaign: true,
+            ..Config::new(1)
+        };
+        let mut r = VioletaBft::new(&cfg, storage).unwrap();
+        for _ in 0..50 {
+            r.tick();
+        }
+        assert_eq!((r.state, r.term), (StateRole::Follower, 0));
+        assert!(r.msgs.is_empty());
+        r.step(Message::new(MessageType::TimeoutNow, 1, 2)).unwrap();
+        assert_eq!(r.state, StateRole::Follower);
+
+        // It still votes.
+        let mut vote = Message::new(MessageType::RequestVote, 1, 2);
+        vote.term = 1;
+        r.step(vote).unwrap();
+        assert!(!take(&mut r, MessageType::RequestVoteResponse)[0].reject);
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The interface the application drives a node through.
+//!
+//! The application ticks the node, steps the messages it receives and proposes
+//! commands. Whenever `has_ready` holds it takes a `Ready`, and in this order:
+//! persists its snapshot, entries and hard state; sends its messages; applies its
+//! committed entries; then calls `advance`.
+
+use std::mem;
+
+use crate::codec::Codec;
+use crate::errors::{Error, Result};
+use crate::message::{
+    ConfChange, ConfState, Entry, EntryType, HardState, Message, MessageType, Snapshot,
+};
+use crate::raft::{Config, SoftState, VioletaBft};
+use crate::read_only::ReadState;
+use crate::storage::Storage;
+
+/// What changed since the last `Ready`.
+#[derive(Debug, Default)]
+pub struct Ready {
+    pub soft_state: Option<SoftState>,
+    pub hard_state: Option<HardState>,
+    /// The entries to persist.
+    pub entries: Vec<Entry>,
+    /// The snapshot to persist and apply.
+    pub snapshot: Option<Snapshot>,
+    /// The entries to apply.
+    pub committed_entries: Vec<Entry>,
+    pub messages: Vec<Message>,
+    /// The reads confirmed, to serve once the log is applied up to their index.
+    pub read_states: Vec<ReadState>,
+}
+
+pub struct RawNode<T: Storage> {
+    pub violetabft: VioletaBft<T>,
+    prev_ss: SoftState,
+    prev_hs: HardState,
+    max_committed_size: u64,
+}
+
+impl<T: Storage> RawNode<T> {
+    pub fn new(config: &Config, store: T) -> Result<RawNode<T>> {
+        let violetabft = VioletaBft::new(config, store)?;
+        Ok(RawNode {
+            prev_ss: violetabft.soft_state(),
+            prev_hs: violetabft.hard_state(),
+            violetabft,
+            max_committed_size: config.max_size_per_msg,
+        })
+    }
+
+    pub fn tick(&mut self) {
+        self.violetabft.tick();
+    }
+
+    /// Starts an election now.
+    pub fn campaign(&mut self) -> Result<()> {
+        self.violetabft
+            .step(Message::new(MessageType::Hup, 0, self.violetabft.id))
+    }
+
+    /// Hands the leadership over to `transferee` once its log is up to date, if
+    /// this node leads; proposals are dropped until the transfer ends.
+    pub fn transfer_leader(&mut self, transferee: u64) -> Result<()> {
+        self.violetabft.step(Message::new(
+            MessageType::TransferLeader,
+            self.violetabft.id,
+            transferee,
+        ))
+    }
+
+    pub fn propose(&mut self, context: Vec<u8>, data: Vec<u8>) -> Result<()> {
+        let mut m = Message::new(MessageType::Propose, 0, self.violetabft.id);
+        m.entries = vec![Entry {
+            data,
+            context,
+            ..Default::default()
+        }];
+        self.violetabft.step(m)
+    }
+
+    /// Asks for the index a read identified by `ctx` must wait for; it comes in
+    /// the `read_states` of a later `Ready`, unless leadership changes first.
+    pub fn read_index(&mut self, ctx: Vec<u8>) -> Result<()> {
+        let mut m = Message::new(MessageType::ReadIndex, 0, self.violetabft.id);
+        m.context = ctx;
+        self.violetabft.step(m)
+    }
+
+    /// Proposes a membership change. Once it is committed, the application passes
+    /// it to `apply_conf_change`.
+    pub fn propose_conf_change(&mut self, context: Vec<u8>, cc: &ConfChange) -> Result<()> {
+        let mut m = Message::new(MessageType::Propose, 0, self.violetabft.id);
+        m.entries = vec![Entry {
+            entry_type: EntryType::ConfChange,
+            data: cc.encode(),
+            context,
+            ..Default::default()
+        }];
+        self.violetabft.step(m)
+    }
+
+    pub fn apply_conf_change(&mut self, cc: &ConfChange) -> Result<ConfState> {
+        self.violetabft.apply_conf_change(cc)
+    }
+
+    /// Steps a message received from a peer.
+    pub fn step(&mut self, m: Message) -> Result<()> {
+        if m.msg_type.is_local() {
+            return Err(Error::StepLocalMsg);
+        }
+        if m.msg_type.is_response() && !self.violetabft.prs.progress.contains_key(&m.from) {
+            return Err(Error::StepPeerNotFound);
+        }
+        self.violetabft.step(m)
+    }
+
+    /// Tells the leader `id` could not be reached, so that it stops streaming to it.
+    pub fn report_unreachable(&mut self, id: u64) {
+        let m = Message::new(MessageType::Unreachable, 0, id);
+        let _ = self.violetabft.step(m);
+    }
+
+    /// Tells the leader whether the snapshot it sent `id` was delivered.
+    pub fn report_snapshot(&mut self, id: u64, delivered: bool) {
+        let mut m = Message::new(MessageType::SnapStatus, 0, id);
+        m.reject = !delivered;
+        let _ = self.violetabft.step(m);
+    }
+
+    pub fn has_ready(&self) -> bool {
+        let r = &self.violetabft;
+        r.soft_state() != self.prev_ss
+            || r.hard_state() != self.prev_hs
+            || !r.msgs.is_empty()
+            || !r.read_states.is_empty()
+            || !r.violetabft_log.unstable_entries().is_empty()
+            || r.violetabft_log.unstable.snapshot.is_some()
+            || r.violetabft_log.has_next_entries()
+    }
+
+    pub fn ready(&mut self) -> Ready {
+        let r = &mut self.violetabft;
+        let ss = r.soft_state();
+        let hs = r.hard_state();
+        Ready {
+            soft_state: (ss != self.prev_ss).then_some(ss),
+            hard_state: (hs != self.prev_hs).then_some(hs),
+            entries: r.violetabft_log.unstable_entries().to_vec(),
+            snapshot: r.violetabft_log.unstable.snapshot.clone(),
+            committed_entries: r.violetabft_log.next_entries(Some(self.max_committed_size)),
+            messages: mem::take(&mut r.msgs),
+            read_states: mem::take(&mut r.read_states),
+        }
+    }
+
+    /// Records that everything `rd` asked for was done.
+    pub fn advance(&mut self, rd: Ready) {
+        if let Some(ss) = rd.soft_state {
+            self.prev_ss = ss;
+        }
+        if let Some(hs) = rd.hard_state {
+            self.prev_hs = hs;
+        }
+        let log = &mut self.violetabft.violetabft_log;
+        if let Some(e) = rd.entries.last() {
+            log.stable_to(e.index, e.term);
+        }
+        if let Some(snapshot) = &rd.snapshot {
+            log.stable_snap_to(snapshot.metadata.index);
+            self.violetabft.commit_apply(snapshot.metadata.index);
+        }
+        if let Some(e) = rd.committed_entries.last() {
+            self.violetabft.commit_apply(e.index);
+        }
+    }
+}
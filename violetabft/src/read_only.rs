@@ -0,0 +1,84 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Read-index: the commit index a read must wait for to see every write
+//! acknowledged before it, confirmed without appending to the log.
+//!
+//! The leader records its commit index with the request and sends a heartbeat
+//! carrying the request's context. Once a quorum answers, no other leader can
+//! have committed anything past that index, and the read, from whichever node
+//! asked, may be served once it applied it.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::message::Message;
+
+/// A read that may be served once the node applied the log up to `index`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReadState {
+    pub index: u64,
+    /// The context the read was asked with.
+    pub request_ctx: Vec<u8>,
+}
+
+pub(crate) struct ReadIndexStatus {
+    pub req: Message,
+    pub index: u64,
+    pub acks: BTreeMap<u64, bool>,
+}
+
+/// The reads a leader confirms its leadership for, in the order they came.
+#[derive(Default)]
+pub(crate) struct ReadOnly {
+    pending: HashMap<Vec<u8>, ReadIndexStatus>,
+    queue: VecDeque<Vec<u8>>,
+}
+
+impl ReadOnly {
+    /// Records a read at the commit index `index`, acknowledged by the leader
+    /// `self_id` itself. A context already pending is ignored.
+    pub fn add_request(&mut self, index: u64, req: Message, self_id: u64) {
+        let ctx = req.context.clone();
+        if self.pending.contains_key(&ctx) {
+            return;
+        }
+        let mut acks = BTreeMap::new();
+        acks.insert(self_id, true);
+        self.pending
+            .insert(ctx.clone(), ReadIndexStatus { req, index, acks });
+        self.queue.push_back(ctx);
+    }
+
+    /// Records that `id` answered the heartbeat of `ctx`, returning who did so far.
+    pub fn recv_ack(&mut self, id: u64, ctx: &[u8]) -> Option<&BTreeMap<u64, bool>> {
+        let status = self.pending.get_mut(ctx)?;
+        status.acks.insert(id, true);
+        Some(&status.acks)
+    }
+
+    /// Takes the reads up to the one of `ctx`: confirming it confirms those
+    /// before it too.
+    pub fn advance(&mut self, ctx: &[u8]) -> Vec<ReadIndexStatus> {
+        if !self.pending.contains_key(ctx) {
+            return Vec::new();
+        }
+        let mut confirmed = Vec::new();
+        while let Some(c) = self.queue.pop_front() {
+            let done = c == ctx;
+            confirmed.push(self.pending.remove(&c).unwrap());
+            if done {
+                break;
+            }
+        }
+        confirmed
+    }
+
+    /// The context of the latest read waiting for a quorum, which heartbeats
+    /// carry until it is confirmed.
+    pub fn last_pending_request_ctx(&self) -> Option<Vec<u8>> {
+        self.queue.back().cloned()
+    }
+
+    pub fn pending_read_count(&self) -> usize {
+        self.queue.len()
+    }
+}
@@ -0,0 +1,616 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! An in-process network of nodes over `MemStorage`, for testing the protocol
+//! under partitions and message loss.
+//!
+//! Everything runs on the calling thread in a deterministic order, and losses
+//! are drawn from a seeded generator, so that a failing run can be replayed.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::codec::{get_bytes, put_bytes, Codec};
+use crate::errors::Result;
+use crate::message::{ConfChange, ConfState, EntryType, Message, MessageType};
+use crate::raft::{Config, StateRole};
+use crate::raw_node::RawNode;
+use crate::read_only::ReadState;
+use crate::storage::MemStorage;
+use crate::transport::Transport;
+
+/// The steps after which `SimNetwork::settle` gives up, as the nodes would then
+/// exchange messages forever.
+const MAX_SETTLE_STEPS: usize = 100_000;
+
+/// A node and the state machine it applies to: the list of the commands applied.
+pub struct SimNode {
+    pub node: RawNode<MemStorage>,
+    pub storage: MemStorage,
+    pub applied: Vec<Vec<u8>>,
+    pub applied_index: u64,
+    pub conf_state: ConfState,
+    /// The reads confirmed so far.
+    pub read_states: Vec<ReadState>,
+}
+
+impl SimNode {
+    fn new(config: &Config, conf_state: ConfState) -> SimNode {
+        let storage = MemStorage::new_with_conf_state(conf_state.clone());
+        SimNode {
+            node: RawNode::new(config, storage.clone()).unwrap(),
+            storage,
+            applied: Vec::new(),
+            applied_index: 0,
+            conf_state,
+            read_states: Vec::new(),
+        }
+    }
+
+    pub fn state(&self) -> StateRole {
+        self.node.violetabft.state
+    }
+
+    pub fn term(&self) -> u64 {
+        self.node.violetabft.term
+    }
+
+    /// Snapshots the state machine at the applied index and discards the log up
+    /// to it.
+    pub fn compact(&mut self) {
+        let mut data = Vec::new();
+        for cmd in &self.applied {
+            put_bytes(&mut data, cmd);
+        }
+        self.storage
+            .wl()
+            .create_snapshot(self.applied_index, self.conf_state.clone(), data)
+            .unwrap();
+    }
+
+    /// Persists, sends and applies what the node has ready. Returns whether there
+    /// was anything.
+    fn handle_ready(&mut self, router: &mut SimRouter) -> bool {
+        if !self.node.has_ready() {
+            return false;
+        }
+        let mut rd = self.node.ready();
+        self.read_states.append(&mut rd.read_states);
+        if let Some(snapshot) = &rd.snapshot {
+            self.storage.wl().apply_snapshot(snapshot.clone()).unwrap();
+            let mut data = snapshot.data.as_slice();
+            self.applied.clear();
+            while !data.is_empty() {
+                self.applied.push(get_bytes(&mut data).unwrap().to_vec());
+            }
+            self.applied_index = snapshot.metadata.index;
+            self.conf_state = snapshot.metadata.conf_state.clone();
+        }
+        self.storage.wl().append(&rd.entries).unwrap();
+        if let Some(hs) = rd.hard_state {
+            self.storage.wl().set_hard_state(hs);
+        }
+        for m in &rd.messages {
+            router.send(m.clone()).unwrap();
+        }
+        for e in &rd.committed_entries {
+            match e.entry_type {
+                EntryType::Normal if e.data.is_empty() => {}
+                EntryType::Normal => self.applied.push(e.data.clone()),
+                EntryType::ConfChange => {
+                    let cc = ConfChange::decode(&e.data).unwrap();
+                    let cs = self.node.apply_conf_change(&cc).unwrap();
+                    self.storage.wl().set_conf_state(cs.clone());
+                    self.conf_state = cs;
+                }
+            }
+            self.applied_index = e.index;
+        }
+        self.node.advance(rd);
+        true
+    }
+}
+
+/// The transport of the network: queues messages, unless their link is cut or
+/// they are drawn to be lost.
+pub struct SimRouter {
+    queue: VecDeque<Message>,
+    cut: BTreeSet<(u64, u64)>,
+    drop_percent: u64,
+    rng: u64,
+    /// The snapshots sent, with whether they were delivered, to report to their
+    /// senders.
+    snapshot_status: Vec<(u64, u64, bool)>,
+}
+
+impl SimRouter {
+    fn lost(&mut self, m: &Message) -> bool {
+        if self.cut.contains(&(m.from, m.to)) {
+            return true;
+        }
+        if self.drop_percent == 0 {
+            return false;
+        }
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng = x;
+        x % 100 < self.drop_percent
+    }
+}
+
+impl Transport for SimRouter {
+    fn send(&mut self, msg: Message) -> Result<()> {
+        let lost = self.lost(&msg);
+        if msg.msg_type == MessageType::Snapshot {
+            self.snapshot_status.push((msg.from, msg.to, !lost));
+        }
+        if !lost {
+            self.queue.push_back(msg);
+        }
+        Ok(())
+    }
+}
+
+pub struct SimNetwork {
+    pub nodes: BTreeMap<u64, SimNode>,
+    pub router: SimRouter,
+    new_config: Box<dyn Fn(u64) -> Config>,
+}
+
+impl SimNetwork {
+    /// A group of `ids` with the default config, which has pre-vote and the quorum
+    /// check on.
+    pub fn new(ids: &[u64], seed: u64) -> SimNetwork {
+        SimNetwork::with_config(ids, seed, Config::new)
+    }
+
+    pub fn with_config(
+        ids: &[u64],
+        seed: u64,
+        new_config: impl Fn(u64) -> Config + 'static,
+    ) -> SimNetwork {
+        let mut network = SimNetwork {
+            nodes: BTreeMap::new(),
+            router: SimRouter {
+                queue: VecDeque::new(),
+                cut: BTreeSet::new(),
+                drop_percent: 0,
+                rng: seed | 1,
+                snapshot_status: Vec::new(),
+            },
+            new_config: Box::new(new_config),
+        };
+        for &id in ids {
+            network.add_node(id, ConfState::with_voters(ids.to_vec()));
+        }
+        network
+    }
+
+    /// Starts a node. A node joining an existing group starts with an empty
+    /// configuration, and is sent a snapshot of one that includes it: compact the
+    /// leader once the change is applied.
+    pub fn add_node(&mut self, id: u64, conf_state: ConfState) {
+        let node = SimNode::new(&(self.new_config)(id), conf_state);
+        self.nodes.insert(id, node);
+    }
+
+    pub fn node(&self, id: u64) -> &SimNode {
+        &self.nodes[&id]
+    }
+
+    pub fn node_mut(&mut self, id: u64) -> &mut SimNode {
+        self.nodes.get_mut(&id).unwrap()
+    }
+
+    /// Cuts every link between `a` and `b`, both ways.
+    pub fn partition(&mut self, a: &[u64], b: &[u64]) {
+        for &x in a {
+            for &y in b {
+                self.router.cut.insert((x, y));
+                self.router.cut.insert((y, x));
+            }
+        }
+    }
+
+    pub fn isolate(&mut self, id: u64) {
+        let others: Vec<u64> = self.nodes.keys().copied().filter(|&o| o != id).collect();
+        self.partition(&[id], &others);
+    }
+
+    /// Restores every link, and stops losing messages.
+    pub fn heal(&mut self) {
+        self.router.cut.clear();
+        self.router.drop_percent = 0;
+    }
+
+    pub fn set_drop_percent(&mut self, percent: u64) {
+        self.router.drop_percent = percent;
+    }
+
+    /// Handles every ready and delivers every message until the network is quiet.
+    pub fn settle(&mut self) {
+        for _ in 0..MAX_SETTLE_STEPS {
+            let mut busy = false;
+            for node in self.nodes.values_mut() {
+                busy |= node.handle_ready(&mut self.router);
+            }
+            for (from, to, delivered) in self.router.snapshot_status.drain(..) {
+                if let Some(node) = self.nodes.get_mut(&from) {
+                    node.node.report_snapshot(to, delivered);
+                    busy = true;
+                }
+            }
+            while let Some(m) = self.router.queue.pop_front() {
+                busy = true;
+                if let Some(node) = self.nodes.get_mut(&m.to) {
+                    // Messages from removed peers are refused; that is fine.
+                    let _ = node.node.step(m);
+                }
+            }
+            if !busy {
+                return;
+            }
+        }
+        panic!("the network did not settle");
+    }
+
+    /// Ticks every node `n` times, settling after each tick.
+    pub fn tick(&mut self, n: usize) {
+        for _ in 0..n {
+            for node in self.nodes.values_mut() {
+                node.node.tick();
+            }
+            self.settle();
+        }
+    }
+
+    /// Makes `id` campaign and settles.
+    pub fn elect(&mut self, id: u64) {
+        self.node_mut(id).node.campaign().unwrap();
+        self.settle();
+    }
+
+    /// The leader of the highest term, if any.
+    pub fn leader(&self) -> Option<u64> {
+        self.nodes
+            .iter()
+            .filter(|(_, n)| n.state() == StateRole::Leader)
+            .max_by_key(|(_, n)| n.term())
+            .map(|(&id, _)| id)
+    }
+
+    /// Proposes `data` on `id` and settles.
+    pub fn propose(&mut self, id: u64, data: &[u8]) -> Result<()> {
+        self.node_mut(id).node.propose(Vec::new(), data.to_vec())?;
+        self.settle();
+        Ok(())
+    }
+
+    pub fn propose_conf_change(&mut self, id: u64, cc: &ConfChange) -> Result<()> {
+        self.node_mut(id).node.propose_conf_change(Vec::new(), cc)?;
+        self.settle();
+        Ok(())
+    }
+
+    /// Asks `id` for a read index with `ctx` and settles.
+    pub fn read_index(&mut self, id: u64, ctx: &[u8]) -> Result<()> {
+        self.node_mut(id).node.read_index(ctx.to_vec())?;
+        self.settle();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::ConfChangeSingle;
+    use crate::message::ConfChangeType::*;
+    use crate::storage::Storage;
+
+    fn cmds(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| format!("cmd{}", i).into_bytes()).collect()
+    }
+
+    fn assert_applied(network: &SimNetwork, ids: &[u64], expected: &[Vec<u8>]) {
+        for id in ids {
+            assert_eq!(network.node(*id).applied, expected, "node {}", id);
+        }
+    }
+
+    #[test]
+    fn test_election_and_replication() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 1);
+        network.tick(30);
+        let leader = network.leader().unwrap();
+        let leaders = network
+            .nodes
+            .values()
+            .filter(|n| n.state() == StateRole::Leader)
+            .count();
+        assert_eq!(leaders, 1);
+        for cmd in cmds(5) {
+            network.propose(leader, &cmd).unwrap();
+        }
+        assert_applied(&network, &[1, 2, 3], &cmds(5));
+
+        let follower = if leader == 1 { 2 } else { 1 };
+        assert_eq!(
+            network.propose(follower, b"x"),
+            Err(crate::errors::Error::ProposalDropped)
+        );
+
+        // Replaying the same seed gives the same run.
+        let mut replay = SimNetwork::new(&[1, 2, 3], 1);
+        replay.tick(30);
+        assert_eq!(replay.leader(), Some(leader));
+    }
+
+    #[test]
+    fn test_pre_vote_under_partition() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 2);
+        network.elect(1);
+        assert_eq!(network.leader(), Some(1));
+        let term = network.node(1).term();
+
+        // The isolated node keeps failing pre-votes, and so keeps its term.
+        network.isolate(3);
+        network.tick(100);
+        assert_eq!(network.node(3).term(), term);
+        assert_eq!(network.node(3).state(), StateRole::PreCandidate);
+        network.propose(1, b"during").unwrap();
+        assert_applied(&network, &[1, 2], &[b"during".to_vec()]);
+
+        // When it returns, it catches up without disrupting the leader.
+        network.heal();
+        network.tick(5);
+        assert_eq!(network.leader(), Some(1));
+        assert_eq!(network.node(1).term(), term);
+        assert_applied(&network, &[1, 2, 3], &[b"during".to_vec()]);
+
+        // Without pre-vote it would have bumped its term.
+        let mut network = SimNetwork::with_config(&[1, 2, 3], 2, |id| Config {
+            pre_vote: false,
+            ..Config::new(id)
+        });
+        network.elect(1);
+        network.isolate(3);
+        network.tick(100);
+        assert!(network.node(3).term() > term);
+    }
+
+    #[test]
+    fn test_leader_partitioned_away() {
+        let mut network = SimNetwork::new(&[1, 2, 3, 4, 5], 3);
+        network.elect(1);
+        network.propose(1, b"a").unwrap();
+
+        // The old leader can not commit in the minority, and steps down once it
+        // notices; the majority elects another leader.
+        network.partition(&[1, 2], &[3, 4, 5]);
+        network
+            .node_mut(1)
+            .node
+            .propose(Vec::new(), b"lost".to_vec())
+            .unwrap();
+        network.tick(50);
+        assert_ne!(network.node(1).state(), StateRole::Leader);
+        let leader = network.leader().unwrap();
+        assert!([3, 4, 5].contains(&leader));
+        network.propose(leader, b"b").unwrap();
+
+        network.heal();
+        network.tick(5);
+        let expected = vec![b"a".to_vec(), b"b".to_vec()];
+        assert_applied(&network, &[1, 2, 3, 4, 5], &expected);
+    }
+
+    #[test]
+    fn test_leader_transfer() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 7);
+        network.elect(1);
+        network.propose(1, b"a").unwrap();
+        let term = network.node(1).term();
+
+        // A lagging transferee is caught up first, then takes over at once.
+        network.isolate(3);
+        network.propose(1, b"b").unwrap();
+        network.heal();
+        network.node_mut(1).node.transfer_leader(3).unwrap();
+        network.settle();
+        assert_eq!(network.leader(), Some(3));
+        assert_eq!(network.node(3).term(), term + 1);
+        network.propose(3, b"c").unwrap();
+        let expected = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+        assert_applied(&network, &[1, 2, 3], &expected);
+
+        // A transferee that does not answer: proposals are dropped until the
+        // transfer is abandoned.
+        network.isolate(1);
+        network.node_mut(3).node.transfer_leader(1).unwrap();
+        network.settle();
+        assert_eq!(
+            network.propose(3, b"d"),
+            Err(crate::errors::Error::ProposalDropped)
+        );
+        network.heal();
+        network.tick(20);
+        assert_eq!(network.leader(), Some(3));
+        network.propose(3, b"d").unwrap();
+    }
+
+    #[test]
+    fn test_read_index() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 8);
+        network.elect(1);
+        for cmd in cmds(3) {
+            network.propose(1, &cmd).unwrap();
+        }
+        let committed = network.node(1).node.violetabft.violetabft_log.committed;
+
+        // The leader and a follower both learn the commit index, and have it
+        // applied once they settled.
+        for (id, ctx) in [(1, b"r1"), (2, b"r2")] {
+            network.read_index(id, ctx).unwrap();
+            let node = network.node(id);
+            assert_eq!(
+                node.read_states,
+                vec![ReadState {
+                    index: committed,
+                    request_ctx: ctx.to_vec(),
+                }]
+            );
+            assert!(node.applied_index >= committed);
+        }
+
+        // A leader cut off from the quorum can not confirm its leadership, while
+        // the other side elects a leader that commits more.
+        network.isolate(1);
+        network.read_index(1, b"r3").unwrap();
+        assert_eq!(network.node(1).node.violetabft.pending_read_count(), 1);
+        network.tick(30);
+        let leader = network.leader().unwrap();
+        assert_ne!(leader, 1);
+        network.propose(leader, b"x").unwrap();
+        network.read_index(3, b"r4").unwrap();
+        let rs = network.node(3).read_states.last().unwrap().clone();
+        assert_eq!(rs.request_ctx, b"r4");
+        assert!(rs.index > committed);
+        assert_eq!(network.node(1).read_states.len(), 1);
+
+        // Once it hears of the new term, the stale leader drops the read.
+        network.heal();
+        network.tick(5);
+        assert_eq!(network.node(1).state(), StateRole::Follower);
+        assert_eq!(network.node(1).node.violetabft.pending_read_count(), 0);
+        assert_eq!(network.node(1).read_states.len(), 1);
+    }
+
+    #[test]
+    fn test_message_loss() {
+        let mut network = SimNetwork::new(&[1, 2, 3, 4, 5], 4);
+        network.set_drop_percent(30);
+        let mut proposed = 0;
+        for _ in 0..200 {
+            network.tick(1);
+            if let Some(leader) = network.leader() {
+                if network
+                    .propose(leader, &cmds(proposed + 1)[proposed])
+                    .is_ok()
+                {
+                    proposed += 1;
+                }
+            }
+        }
+        assert!(proposed > 0);
+        network.heal();
+        network.tick(30);
+        // Whatever was lost with a leader, every node applied the same commands, in
+        // the order they were proposed.
+        let applied = network.node(1).applied.clone();
+        assert!(!applied.is_empty());
+        assert_applied(&network, &[1, 2, 3, 4, 5], &applied);
+        let all = cmds(proposed);
+        let mut positions = applied
+            .iter()
+            .map(|c| all.iter().position(|x| x == c).unwrap());
+        let mut prev = positions.next().unwrap();
+        for pos in positions {
+            assert!(pos > prev);
+            prev = pos;
+        }
+    }
+
+    #[test]
+    fn test_snapshot_install() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 5);
+        network.elect(1);
+        network.isolate(3);
+        for cmd in cmds(10) {
+            network.propose(1, &cmd).unwrap();
+        }
+        network.node_mut(1).compact();
+        network.node_mut(2).compact();
+        assert!(network.node(1).storage.first_index().unwrap() > 10);
+
+        // The entries node 3 misses are gone: it gets a snapshot.
+        network.heal();
+        network.tick(5);
+        assert_applied(&network, &[1, 2, 3], &cmds(10));
+        let storage = &network.node(3).storage;
+        assert_eq!(
+            storage.first_index().unwrap(),
+            network.node(1).applied_index + 1
+        );
+        network.propose(1, b"after").unwrap();
+        assert_eq!(network.node(3).applied.last().unwrap(), b"after");
+    }
+
+    #[test]
+    fn test_joint_membership_change() {
+        let mut network = SimNetwork::new(&[1, 2, 3], 6);
+        network.elect(1);
+        network.propose(1, b"a").unwrap();
+
+        // Replacing two voters goes through a joint configuration, which needs a
+        // majority of 4 and 5 as well: nothing commits before they start.
+        let cc = ConfChange::new(vec![
+            ConfChangeSingle {
+                change_type: AddNode,
+                node_id: 4,
+            },
+            ConfChangeSingle {
+                change_type: AddNode,
+                node_id: 5,
+            },
+            ConfChangeSingle {
+                change_type: RemoveNode,
+                node_id: 3,
+            },
+        ]);
+        network.propose_conf_change(1, &cc).unwrap();
+        assert_eq!(network.node(1).conf_state.voters_outgoing, vec![1, 2, 3]);
+        network.propose(1, b"b").unwrap();
+        assert_eq!(network.node(1).applied, vec![b"a".to_vec()]);
+
+        // New nodes start empty and are sent a snapshot of the configuration that
+        // includes them; the leader then leaves the joint configuration on its own.
+        network.node_mut(1).compact();
+        network.add_node(4, ConfState::default());
+        network.add_node(5, ConfState::default());
+        network.tick(3);
+        let expected = ConfState::with_voters(vec![1, 2, 4, 5]);
+        for id in [1, 2, 4, 5] {
+            assert_eq!(network.node(id).conf_state, expected, "node {}", id);
+        }
+        assert_applied(&network, &[1, 2, 4, 5], &[b"a".to_vec(), b"b".to_vec()]);
+        network.nodes.remove(&3);
+
+        // One change at a time is made directly; a learner does not vote.
+        let cc = ConfChange::single(AddLearnerNode, 6);
+        network.propose_conf_change(1, &cc).unwrap();
+        network.node_mut(1).compact();
+        network.add_node(6, ConfState::default());
+        network.tick(3);
+        assert_eq!(network.node(6).conf_state.learners, vec![6]);
+        assert_applied(&network, &[6], &[b"a".to_vec(), b"b".to_vec()]);
+        network.node_mut(6).node.campaign().unwrap();
+        assert_eq!(network.node(6).state(), StateRole::Follower);
+
+        // Another change waits until the pending one is applied.
+        network
+            .node_mut(1)
+            .node
+            .propose_conf_change(Vec::new(), &ConfChange::single(AddNode, 6))
+            .unwrap();
+        assert!(network
+            .node_mut(1)
+            .node
+            .propose_conf_change(Vec::new(), &ConfChange::single(RemoveNode, 2))
+            .is_err());
+        network.settle();
+        assert_eq!(
+            network.node(6).conf_state,
+            ConfState::with_voters(vec![1, 2, 4, 5, 6])
+        );
+    }
+}
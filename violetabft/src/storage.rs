@@ -0,0 +1,308 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Where a node keeps the entries, hard state and snapshot it persisted.
+//!
+//! The node only reads from its `Storage`; the application writes what each `Ready`
+//! asks it to persist before calling `RawNode::advance`.
+
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::errors::{Result, StorageError};
+use crate::message::{ConfState, Entry, HardState, Snapshot, SnapshotMetadata};
+
+/// What a node restarts from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RaftState {
+    pub hard_state: HardState,
+    pub conf_state: ConfState,
+}
+
+pub trait Storage {
+    fn initial_state(&self) -> Result<RaftState>;
+
+    /// The entries of `[low, high)`, at least one and then as many as fit in
+    /// `max_size` bytes.
+    fn entries(&self, low: u64, high: u64, max_size: Option<u64>) -> Result<Vec<Entry>>;
+
+    /// The term of the entry at `idx`, which may be the index of the snapshot.
+    fn term(&self, idx: u64) -> Result<u64>;
+
+    /// The index of the first entry that is not covered by the snapshot.
+    fn first_index(&self) -> Result<u64>;
+
+    fn last_index(&self) -> Result<u64>;
+
+    /// A snapshot at least as recent as `request_index`, to send to a follower.
+    fn snapshot(&self, request_index: u64) -> Result<Snapshot>;
+}
+
+/// Keeps the first `max_size` bytes of `entries`, and at least one entry.
+pub fn limit_size(entries: &mut Vec<Entry>, max_size: Option<u64>) {
+    let max_size = match max_size {
+        Some(max_size) => max_size,
+        None => return,
+    };
+    let mut size = 0;
+    let keep = entries
+        .iter()
+        .take_while(|e| {
+            size += e.size();
+            size <= max_size
+        })
+        .count();
+    entries.truncate(keep.max(1));
+}
+
+#[derive(Default)]
+pub struct MemStorageCore {
+    raft_state: RaftState,
+    /// `entries[0]` is the entry after the snapshot.
+    entries: Vec<Entry>,
+    snapshot_metadata: SnapshotMetadata,
+    /// The application state of the snapshot.
+    snapshot_data: Vec<u8>,
+}
+
+impl MemStorageCore {
+    fn first_index(&self) -> u64 {
+        self.snapshot_metadata.index + 1
+    }
+
+    fn last_index(&self) -> u64 {
+        self.snapshot_metadata.index + self.entries.len() as u64
+    }
+
+    pub fn hard_state(&self) -> HardState {
+        self.raft_state.hard_state
+    }
+
+    pub fn set_hard_state(&mut self, hard_state: HardState) {
+        self.raft_state.hard_state = hard_state;
+    }
+
+    pub fn set_conf_state(&mut self, conf_state: ConfState) {
+        self.raft_state.conf_state = conf_state;
+    }
+
+    pub fn set_commit(&mut self, commit: u64) {
+        self.raft_state.hard_state.commit = commit;
+    }
+
+    /// Replaces the whole log by `snapshot`.
+    pub fn apply_snapshot(&mut self, snapshot: Snapshot) -> Result<()> {
+        let meta = snapshot.metadata;
+        if meta.index < self.first_index() {
+            return Err(StorageError::SnapshotOutOfDate.into());
+        }
+        let hs = &mut self.raft_state.hard_state;
+        hs.term = hs.term.max(meta.term);
+        hs.commit = hs.commit.max(meta.index);
+        self.raft_state.conf_state = meta.conf_state.clone();
+        self.entries.clear();
+        self.snapshot_metadata = meta;
+        self.snapshot_data = snapshot.data;
+        Ok(())
+    }
+
+    /// Appends `entries`, replacing the entries from the first of them on.
+    pub fn append(&mut self, entries: &[Entry]) -> Result<()> {
+        let first = match entries.first() {
+            Some(e) => e.index,
+            None => return Ok(()),
+        };
+        if first < self.first_index() {
+            return Err(StorageError::Other(format!(
+                "appending entry {} before the first index {}",
+                first,
+                self.first_index()
+            ))
+            .into());
+        }
+        if first > self.last_index() + 1 {
+            return Err(StorageError::Other(format!(
+                "appending entry {} leaves a gap after {}",
+                first,
+                self.last_index()
+            ))
+            .into());
+        }
+        self.entries.truncate((first - self.first_index()) as usize);
+        self.entries.extend_from_slice(entries);
+        Ok(())
+    }
+
+    /// Records the state machine as of `index`, with the configuration then, as the
+    /// snapshot sent to followers, and discards the entries it covers.
+    pub fn create_snapshot(
+        &mut self,
+        index: u64,
+        conf_state: ConfState,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        if index < self.snapshot_metadata.index {
+            return Err(StorageError::SnapshotOutOfDate.into());
+        }
+        if index > self.last_index() {
+            return Err(StorageError::Unavailable.into());
+        }
+        let term = self.term(index)?;
+        self.snapshot_metadata = SnapshotMetadata {
+            conf_state,
+            index,
+            term,
+        };
+        let offset = self.entries.first().map_or(index + 1, |e| e.index);
+        self.entries
+            .drain(..(index + 1).saturating_sub(offset) as usize);
+        self.snapshot_data = data;
+        Ok(())
+    }
+
+    fn term(&self, idx: u64) -> Result<u64> {
+        if idx == self.snapshot_metadata.index {
+            return Ok(self.snapshot_metadata.term);
+        }
+        if idx < self.first_index() {
+            return Err(StorageError::Compacted.into());
+        }
+        match self.entries.get((idx - self.first_index()) as usize) {
+            Some(e) => Ok(e.term),
+            None => Err(StorageError::Unavailable.into()),
+        }
+    }
+}
+
+/// A `Storage` in memory, for tests and for nodes whose state is rebuilt on restart.
+/// Clones share the same storage.
+#[derive(Clone, Default)]
+pub struct MemStorage {
+    core: Arc<RwLock<MemStorageCore>>,
+}
+
+impl MemStorage {
+    pub fn new() -> MemStorage {
+        MemStorage::default()
+    }
+
+    /// A storage for a node of a new group, with its initial configuration.
+    pub fn new_with_conf_state(conf_state: ConfState) -> MemStorage {
+        let storage = MemStorage::new();
+        storage.wl().raft_state.conf_state = conf_state;
+        storage
+    }
+
+    pub fn rl(&self) -> RwLockReadGuard<'_, MemStorageCore> {
+        self.core.read().unwrap()
+    }
+
+    pub fn wl(&self) -> RwLockWriteGuard<'_, MemStorageCore> {
+        self.core.write().unwrap()
+    }
+}
+
+impl Storage for MemStorage {
+    fn initial_state(&self) -> Result<RaftState> {
+        Ok(self.rl().raft_state.clone())
+    }
+
+    fn entries(&self, low: u64, high: u64, max_size: Option<u64>) -> Result<Vec<Entry>> {
+        let core = self.rl();
+        if low < core.first_index() {
+            return Err(StorageError::Compacted.into());
+        }
+        if high > core.last_index() + 1 {
+            return Err(StorageError::Unavailable.into());
+        }
+        let offset = core.first_index();
+        let mut entries = core.entries[(low - offset) as usize..(high - offset) as usize].to_vec();
+        limit_size(&mut entries, max_size);
+        Ok(entries)
+    }
+
+    fn term(&self, idx: u64) -> Result<u64> {
+        self.rl().term(idx)
+    }
+
+    fn first_index(&self) -> Result<u64> {
+        Ok(self.rl().first_index())
+    }
+
+    fn last_index(&self) -> Result<u64> {
+        Ok(self.rl().last_index())
+    }
+
+    fn snapshot(&self, request_index: u64) -> Result<Snapshot> {
+        let core = self.rl();
+        if core.snapshot_metadata.index < request_index {
+            return Err(StorageError::SnapshotTemporarilyUnavailable.into());
+        }
+        Ok(Snapshot {
+            data: core.snapshot_data.clone(),
+            metadata: core.snapshot_metadata.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+
+    fn entry(index: u64, term: u64) -> Entry {
+        Entry {
+            term,
+            index,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mem_storage() {
+        let storage = MemStorage::new();
+        storage
+            .wl()
+            .append(&[entry(1, 1), entry(2, 1), entry(3, 2)])
+            .unwrap();
+        assert_eq!(storage.last_index().unwrap(), 3);
+        assert_eq!(storage.term(3).unwrap(), 2);
+        assert_eq!(
+            storage.term(4),
+            Err(Error::Store(StorageError::Unavailable))
+        );
+
+        // Appending overwrites the conflicting suffix.
+        storage.wl().append(&[entry(3, 3), entry(4, 3)]).unwrap();
+        assert_eq!(
+            storage.entries(2, 5, None).unwrap(),
+            vec![entry(2, 1), entry(3, 3), entry(4, 3)]
+        );
+        assert_eq!(storage.entries(2, 5, Some(0)).unwrap().len(), 1);
+        assert!(storage.wl().append(&[entry(6, 3)]).is_err());
+
+        let conf_state = ConfState::with_voters(vec![1, 2, 3]);
+        storage
+            .wl()
+            .create_snapshot(3, conf_state.clone(), b"state".to_vec())
+            .unwrap();
+        let snapshot = storage.snapshot(3).unwrap();
+        assert_eq!((snapshot.metadata.index, snapshot.metadata.term), (3, 3));
+        assert_eq!(snapshot.metadata.conf_state, conf_state);
+        assert!(storage.snapshot(4).is_err());
+        // The snapshot replaces the entries it covers.
+        assert_eq!(storage.first_index().unwrap(), 4);
+        assert_eq!(storage.term(3).unwrap(), 3);
+        assert_eq!(storage.term(2), Err(Error::Store(StorageError::Compacted)));
+        assert_eq!(
+            storage.entries(3, 5, None),
+            Err(Error::Store(StorageError::Compacted))
+        );
+        assert_eq!(storage.entries(4, 5, None).unwrap(), vec![entry(4, 3)]);
+
+        let other = MemStorage::new();
+        other.wl().apply_snapshot(snapshot).unwrap();
+        assert_eq!(other.first_index().unwrap(), 4);
+        assert_eq!(other.last_index().unwrap(), 3);
+        assert_eq!(other.initial_state().unwrap().hard_state.commit, 3);
+        assert_eq!(other.initial_state().unwrap().conf_state, conf_state);
+    }
+}
@@ -0,0 +1,419 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! What the leader knows of the replication to each peer, and the quorums of the
+//! configuration it replicates in.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::message::ConfState;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressState {
+    /// The leader looks for the last entry the peer agrees on, one message at a
+    /// time.
+    Probe,
+    /// The leader streams entries, up to `max_inflight_msgs` unacknowledged ones.
+    Replicate,
+    /// The peer needs a snapshot; nothing else is sent until it is applied.
+    Snapshot,
+}
+
+/// The last entries of the append messages in flight to a peer.
+#[derive(Clone, Debug)]
+pub struct Inflights {
+    cap: usize,
+    buffer: VecDeque<u64>,
+}
+
+impl Inflights {
+    pub fn new(cap: usize) -> Inflights {
+        Inflights {
+            cap,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    pub fn full(&self) -> bool {
+        self.buffer.len() >= self.cap
+    }
+
+    pub fn add(&mut self, last: u64) {
+        self.buffer.push_back(last);
+    }
+
+    /// Frees the messages acknowledged by an answer up to `to`.
+    pub fn free_to(&mut self, to: u64) {
+        while self.buffer.front().is_some_and(|&last| last <= to) {
+            self.buffer.pop_front();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Progress {
+    /// The last index known to be replicated to the peer.
+    pub matched: u64,
+    /// The next index to send it.
+    pub next_idx: u64,
+    pub state: ProgressState,
+    /// In `Probe`, whether a message is in flight.
+    pub paused: bool,
+    /// In `Snapshot`, the index of the snapshot sent.
+    pub pending_snapshot: u64,
+    /// Whether the peer was heard from since the last quorum check.
+    pub recent_active: bool,
+    pub inflights: Inflights,
+    pub is_learner: bool,
+}
+
+impl Progress {
+    pub fn new(next_idx: u64, max_inflight: usize) -> Progress {
+        Progress {
+            matched: 0,
+            next_idx,
+            state: ProgressState::Probe,
+            paused: false,
+            pending_snapshot: 0,
+            recent_active: false,
+            inflights: Inflights::new(max_inflight),
+            is_learner: false,
+        }
+    }
+
+    fn reset_state(&mut self, state: ProgressState) {
+        self.paused = false;
+        self.pending_snapshot = 0;
+        self.state = state;
+        self.inflights.reset();
+    }
+
+    pub fn become_probe(&mut self) {
+        // After a snapshot, probing starts after it.
+        if self.state == ProgressState::Snapshot {
+            let pending_snapshot = self.pending_snapshot;
+            self.reset_state(ProgressState::Probe);
+            self.next_idx = (self.matched + 1).max(pending_snapshot + 1);
+        } else {
+            self.reset_state(ProgressState::Probe);
+            self.next_idx = self.matched + 1;
+        }
+    }
+
+    pub fn become_replicate(&mut self) {
+        self.reset_state(ProgressState::Replicate);
+        self.next_idx = self.matched + 1;
+    }
+
+    pub fn become_snapshot(&mut self, snapshot_idx: u64) {
+        self.reset_state(ProgressState::Snapshot);
+        self.pending_snapshot = snapshot_idx;
+    }
+
+    /// Records that the peer holds the log up to `n`. Returns whether that is news.
+    pub fn maybe_update(&mut self, n: u64) -> bool {
+        let updated = self.matched < n;
+        if updated {
+            self.matched = n;
+            self.paused = false;
+        }
+        self.next_idx = self.next_idx.max(n + 1);
+        updated
+    }
+
+    /// Moves `next_idx` back after the peer rejected the entries after `rejected`;
+    /// its log ends at `last_index`. Returns false for a stale rejection.
+    pub fn maybe_decr_to(&mut self, rejected: u64, last_index: u64) -> bool {
+        if self.state == ProgressState::Replicate {
+            if rejected <= self.matched {
+                return false;
+            }
+            self.next_idx = self.matched + 1;
+            return true;
+        }
+        // In probe, only the answer to the last message counts.
+        if self.next_idx == 0 || self.next_idx - 1 != rejected {
+            return false;
+        }
+        self.next_idx = rejected.min(last_index + 1).max(1);
+        self.paused = false;
+        true
+    }
+
+    /// Whether nothing should be sent to the peer for now.
+    pub fn is_paused(&self) -> bool {
+        match self.state {
+            ProgressState::Probe => self.paused,
+            ProgressState::Replicate => self.inflights.full(),
+            ProgressState::Snapshot => true,
+        }
+    }
+
+    /// Records that entries up to `last` were sent; `last` is `next_idx - 1` for a
+    /// message without entries.
+    pub fn update_sent(&mut self, last: u64) {
+        match self.state {
+            ProgressState::Replicate if last >= self.next_idx => {
+                self.next_idx = last + 1;
+                self.inflights.add(last);
+            }
+            ProgressState::Probe => self.paused = true,
+            ProgressState::Replicate | ProgressState::Snapshot => {}
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoteResult {
+    Pending,
+    Lost,
+    Won,
+}
+
+/// The index a majority of `voters` matched, `u64::MAX` if there are none.
+fn majority_committed(voters: &BTreeSet<u64>, matched: impl Fn(u64) -> u64) -> u64 {
+    if voters.is_empty() {
+        return u64::MAX;
+    }
+    let mut indexes: Vec<u64> = voters.iter().map(|&id| matched(id)).collect();
+    indexes.sort_unstable_by(|a, b| b.cmp(a));
+    indexes[voters.len() / 2]
+}
+
+fn majority_vote(voters: &BTreeSet<u64>, votes: &BTreeMap<u64, bool>) -> VoteResult {
+    if voters.is_empty() {
+        return VoteResult::Won;
+    }
+    let (mut granted, mut missing) = (0, 0);
+    for id in voters {
+        match votes.get(id) {
+            Some(true) => granted += 1,
+            Some(false) => {}
+            None => missing += 1,
+        }
+    }
+    let quorum = voters.len() / 2 + 1;
+    if granted >= quorum {
+        VoteResult::Won
+    } else if granted + missing >= quorum {
+        VoteResult::Pending
+    } else {
+        VoteResult::Lost
+    }
+}
+
+/// A configuration: its voters, and during a joint configuration the voters of
+/// the one it leaves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Configuration {
+    pub voters: BTreeSet<u64>,
+    pub voters_outgoing: BTreeSet<u64>,
+    pub learners: BTreeSet<u64>,
+    pub learners_next: BTreeSet<u64>,
+    pub auto_leave: bool,
+}
+
+impl Configuration {
+    pub fn from_conf_state(cs: &ConfState) -> Configuration {
+        Configuration {
+            voters: cs.voters.iter().copied().collect(),
+            voters_outgoing: cs.voters_outgoing.iter().copied().collect(),
+            learners: cs.learners.iter().copied().collect(),
+            learners_next: cs.learners_next.iter().copied().collect(),
+            auto_leave: cs.auto_leave,
+        }
+    }
+
+    pub fn to_conf_state(&self) -> ConfState {
+        ConfState {
+            voters: self.voters.iter().copied().collect(),
+            learners: self.learners.iter().copied().collect(),
+            voters_outgoing: self.voters_outgoing.iter().copied().collect(),
+            learners_next: self.learners_next.iter().copied().collect(),
+            auto_leave: self.auto_leave,
+        }
+    }
+
+    pub fn is_joint(&self) -> bool {
+        !self.voters_outgoing.is_empty()
+    }
+
+    pub fn is_voter(&self, id: u64) -> bool {
+        self.voters.contains(&id) || self.voters_outgoing.contains(&id)
+    }
+
+    /// Every member: voters of either side, learners and learners to be.
+    pub fn ids(&self) -> BTreeSet<u64> {
+        self.voters
+            .iter()
+            .chain(&self.voters_outgoing)
+            .chain(&self.learners)
+            .chain(&self.learners_next)
+            .copied()
+            .collect()
+    }
+
+    /// The index a quorum matched, according to `matched`.
+    pub fn committed_index(&self, matched: impl Fn(u64) -> u64) -> u64 {
+        let incoming = majority_committed(&self.voters, &matched);
+        let outgoing = majority_committed(&self.voters_outgoing, &matched);
+        match incoming.min(outgoing) {
+            u64::MAX => 0,
+            idx => idx,
+        }
+    }
+
+    pub fn vote_result(&self, votes: &BTreeMap<u64, bool>) -> VoteResult {
+        let incoming = majority_vote(&self.voters, votes);
+        let outgoing = majority_vote(&self.voters_outgoing, votes);
+        match (incoming, outgoing) {
+            (VoteResult::Won, VoteResult::Won) => VoteResult::Won,
+            (VoteResult::Lost, _) | (_, VoteResult::Lost) => VoteResult::Lost,
+            _ => VoteResult::Pending,
+        }
+    }
+}
+
+/// The configuration of a group, the progress of each member and the votes of an
+/// election.
+#[derive(Clone, Debug)]
+pub struct ProgressTracker {
+    pub conf: Configuration,
+    pub progress: BTreeMap<u64, Progress>,
+    pub votes: BTreeMap<u64, bool>,
+    max_inflight: usize,
+}
+
+impl ProgressTracker {
+    pub fn new(max_inflight: usize) -> ProgressTracker {
+        ProgressTracker {
+            conf: Configuration::default(),
+            progress: BTreeMap::new(),
+            votes: BTreeMap::new(),
+            max_inflight,
+        }
+    }
+
+    /// Switches to `conf`, tracking new members from `next_idx` on. New members
+    /// count as active until the next quorum check.
+    pub fn apply_conf(&mut self, conf: Configuration, next_idx: u64) {
+        let ids = conf.ids();
+        self.progress.retain(|id, _| ids.contains(id));
+        for id in ids {
+            let pr = self.progress.entry(id).or_insert_with(|| {
+                let mut pr = Progress::new(next_idx, self.max_inflight);
+                pr.recent_active = true;
+                pr
+            });
+            pr.is_learner = !conf.is_voter(id);
+        }
+        self.conf = conf;
+    }
+
+    /// Forgets the progress of every member, as a new term starts. `self_id`
+    /// holds the whole log.
+    pub fn reset_progress(&mut self, self_id: u64, last_index: u64) {
+        for (&id, pr) in &mut self.progress {
+            let is_learner = pr.is_learner;
+            *pr = Progress::new(last_index + 1, self.max_inflight);
+            pr.is_learner = is_learner;
+            if id == self_id {
+                pr.matched = last_index;
+            }
+        }
+    }
+
+    pub fn committed(&self) -> u64 {
+        self.conf
+            .committed_index(|id| self.progress.get(&id).map_or(0, |pr| pr.matched))
+    }
+
+    pub fn reset_votes(&mut self) {
+        self.votes.clear();
+    }
+
+    pub fn record_vote(&mut self, id: u64, granted: bool) {
+        self.votes.entry(id).or_insert(granted);
+    }
+
+    pub fn tally_votes(&self) -> VoteResult {
+        self.conf.vote_result(&self.votes)
+    }
+
+    /// Whether a quorum was heard from since the last check; `self_id` always was.
+    /// Clears the activity of every peer for the next check.
+    pub fn quorum_recently_active(&mut self, self_id: u64) -> bool {
+        let active: BTreeMap<u64, bool> = self
+            .progress
+            .iter_mut()
+            .map(|(&id, pr)| {
+                let active = id == self_id || pr.recent_active;
+                pr.recent_active = false;
+                (id, active)
+            })
+            .collect();
+        self.conf.vote_result(&active) == VoteResult::Won
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[u64]) -> BTreeSet<u64> {
+        ids.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_joint_quorums() {
+        let conf = Configuration {
+            voters: ids(&[1, 2, 3]),
+            voters_outgoing: ids(&[3, 4, 5]),
+            ..Default::default()
+        };
+        let matched = |id| [0, 10, 9, 8, 2, 1][id as usize];
+        // The incoming majority has 9, the outgoing one only 2.
+        assert_eq!(conf.committed_index(matched), 2);
+        let simple = Configuration {
+            voters: ids(&[1, 2, 3]),
+            ..Default::default()
+        };
+        assert_eq!(simple.committed_index(matched), 9);
+        assert_eq!(Configuration::default().committed_index(matched), 0);
+
+        let votes: BTreeMap<u64, bool> = [(1, true), (2, true)].into_iter().collect();
+        assert_eq!(simple.vote_result(&votes), VoteResult::Won);
+        assert_eq!(conf.vote_result(&votes), VoteResult::Pending);
+        let votes: BTreeMap<u64, bool> = [(1, true), (2, true), (4, false), (5, false)]
+            .into_iter()
+            .collect();
+        assert_eq!(conf.vote_result(&votes), VoteResult::Lost);
+    }
+
+    #[test]
+    fn test_progress_flow_control() {
+        let mut pr = Progress::new(5, 2);
+        assert!(!pr.is_paused());
+        pr.update_sent(7);
+        assert!(pr.is_paused());
+        // A stale rejection is ignored, the answer to the probe is not.
+        assert!(!pr.maybe_decr_to(6, 3));
+        assert!(pr.maybe_decr_to(4, 2));
+        assert_eq!(pr.next_idx, 3);
+        assert!(pr.maybe_update(2));
+        pr.become_replicate();
+        pr.update_sent(4);
+        pr.update_sent(6);
+        assert!(pr.is_paused());
+        assert_eq!(pr.next_idx, 7);
+        pr.inflights.free_to(4);
+        assert!(!pr.is_paused());
+        pr.become_snapshot(20);
+        assert!(pr.is_paused());
+        pr.become_probe();
+        assert_eq!(pr.next_idx, 21);
+    }
+}
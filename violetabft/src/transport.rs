@@ -0,0 +1,14 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use crate::errors::Result;
+use crate::message::Message;
+
+/// Delivers the messages of a node to its peers.
+///
+/// Delivery may be lost, duplicated or reordered: the protocol only relies on
+/// what each message says. A transport that knows a peer is unreachable, or that
+/// a snapshot was not delivered, should tell the sender through
+/// `RawNode::report_unreachable` and `RawNode::report_snapshot`.
+pub trait Transport {
+    fn send(&mut self, msg: Message) -> Result<()>;
+}
@@ -0,0 +1,416 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The log of a node: the entries its `Storage` persisted, followed by the ones
+//! it still has to persist.
+
+use crate::errors::{Result, StorageError};
+use crate::message::{Entry, Snapshot};
+use crate::storage::{limit_size, Storage};
+
+/// The snapshot and entries a node received or appended but has not persisted
+/// yet. `entries[0]` has index `offset`.
+#[derive(Debug, Default)]
+pub struct Unstable {
+    pub snapshot: Option<Snapshot>,
+    pub entries: Vec<Entry>,
+    pub offset: u64,
+}
+
+impl Unstable {
+    fn maybe_first_index(&self) -> Option<u64> {
+        self.snapshot.as_ref().map(|s| s.metadata.index + 1)
+    }
+
+    fn maybe_last_index(&self) -> Option<u64> {
+        match self.entries.len() {
+            0 => self.snapshot.as_ref().map(|s| s.metadata.index),
+            len => Some(self.offset + len as u64 - 1),
+        }
+    }
+
+    fn maybe_term(&self, idx: u64) -> Option<u64> {
+        if idx < self.offset {
+            return self
+                .snapshot
+                .as_ref()
+                .filter(|s| s.metadata.index == idx)
+                .map(|s| s.metadata.term);
+        }
+        self.entries
+            .get((idx - self.offset) as usize)
+            .map(|e| e.term)
+    }
+
+    /// Forgets the entries up to `idx` once they are persisted, unless they were
+    /// replaced since.
+    fn stable_to(&mut self, idx: u64, term: u64) {
+        if idx >= self.offset && self.maybe_term(idx) == Some(term) {
+            self.entries.drain(..(idx + 1 - self.offset) as usize);
+            self.offset = idx + 1;
+        }
+    }
+
+    fn stable_snap_to(&mut self, idx: u64) {
+        if self
+            .snapshot
+            .as_ref()
+            .is_some_and(|s| s.metadata.index == idx)
+        {
+            self.snapshot = None;
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.entries.clear();
+        self.offset = snapshot.metadata.index + 1;
+        self.snapshot = Some(snapshot);
+    }
+
+    fn truncate_and_append(&mut self, entries: &[Entry]) {
+        let after = entries[0].index;
+        if after == self.offset + self.entries.len() as u64 {
+            // Directly after the last entry.
+        } else if after <= self.offset {
+            // Replaces every unstable entry, and some stable ones.
+            self.offset = after;
+            self.entries.clear();
+        } else {
+            self.entries.truncate((after - self.offset) as usize);
+        }
+        self.entries.extend_from_slice(entries);
+    }
+
+    fn slice(&self, lo: u64, hi: u64) -> &[Entry] {
+        &self.entries[(lo - self.offset) as usize..(hi - self.offset) as usize]
+    }
+}
+
+pub struct VioletaBftLog<T: Storage> {
+    pub store: T,
+    pub unstable: Unstable,
+    /// The highest index known to be stored by a quorum.
+    pub committed: u64,
+    /// The highest index the application applied.
+    pub applied: u64,
+}
+
+impl<T: Storage> VioletaBftLog<T> {
+    pub fn new(store: T) -> Result<VioletaBftLog<T>> {
+        let first_index = store.first_index()?;
+        let last_index = store.last_index()?;
+        Ok(VioletaBftLog {
+            store,
+            unstable: Unstable {
+                offset: last_index + 1,
+                ..Default::default()
+            },
+            committed: first_index - 1,
+            applied: first_index - 1,
+        })
+    }
+
+    pub fn first_index(&self) -> u64 {
+        match self.unstable.maybe_first_index() {
+            Some(idx) => idx,
+            None => self.store.first_index().unwrap(),
+        }
+    }
+
+    pub fn last_index(&self) -> u64 {
+        match self.unstable.maybe_last_index() {
+            Some(idx) => idx,
+            None => self.store.last_index().unwrap(),
+        }
+    }
+
+    /// The term of the entry at `idx`. The index just before the first entry has
+    /// the term of the snapshot.
+    pub fn term(&self, idx: u64) -> Result<u64> {
+        if idx + 1 < self.first_index() {
+            return Err(StorageError::Compacted.into());
+        }
+        if idx > self.last_index() {
+            return Err(StorageError::Unavailable.into());
+        }
+        match self.unstable.maybe_term(idx) {
+            Some(term) => Ok(term),
+            None => self.store.term(idx),
+        }
+    }
+
+    pub fn last_term(&self) -> u64 {
+        self.term(self.last_index())
+            .expect("the last entry is always available")
+    }
+
+    pub fn match_term(&self, idx: u64, term: u64) -> bool {
+        self.term(idx).is_ok_and(|t| t == term)
+    }
+
+    /// The index of the first of `entries` whose term differs from the log's, or
+    /// 0 if they all match.
+    fn find_conflict(&self, entries: &[Entry]) -> u64 {
+        entries
+            .iter()
+            .find(|e| !self.match_term(e.index, e.term))
+            .map_or(0, |e| e.index)
+    }
+
+    /// Appends the `entries` a leader sent after `(idx, term)`, if the log holds
+    /// that entry. Returns the index of the last entry the log then agrees on.
+    pub fn maybe_append(
+        &mut self,
+        idx: u64,
+        term: u64,
+        committed: u64,
+        entries: &[Entry],
+    ) -> Option<u64> {
+        if !self.match_term(idx, term) {
+            return None;
+        }
+        let last_new = idx + entries.len() as u64;
+        let conflict = self.find_conflict(entries);
+        if conflict != 0 {
+            assert!(
+                conflict > self.committed,
+                "entry {} conflicts with the committed entries up to {}",
+                conflict,
+                self.committed
+            );
+            self.append(&entries[(conflict - (idx + 1)) as usize..]);
+        }
+        self.commit_to(committed.min(last_new));
+        Some(last_new)
+    }
+
+    /// Appends `entries`, replacing the entries from the first of them on.
+    pub fn append(&mut self, entries: &[Entry]) -> u64 {
+        if entries.is_empty() {
+            return self.last_index();
+        }
+        assert!(
+            entries[0].index > self.committed,
+            "appending entry {} before the commit index {}",
+            entries[0].index,
+            self.committed
+        );
+        self.unstable.truncate_and_append(entries);
+        self.last_index()
+    }
+
+    pub fn commit_to(&mut self, committed: u64) {
+        if committed > self.committed {
+            assert!(
+                committed <= self.last_index(),
+                "commit index {} is past the last index {}",
+                committed,
+                self.last_index()
+            );
+            self.committed = committed;
+        }
+    }
+
+    /// Commits up to `max_index` if that entry is of `term`: a leader only commits
+    /// entries of its own term by counting replicas.
+    pub fn maybe_commit(&mut self, max_index: u64, term: u64) -> bool {
+        if max_index > self.committed && self.match_term(max_index, term) {
+            self.commit_to(max_index);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn applied_to(&mut self, applied: u64) {
+        if applied == 0 {
+            return;
+        }
+        assert!(
+            applied <= self.committed && applied >= self.applied,
+            "applied index {} is not within [{}, {}]",
+            applied,
+            self.applied,
+            self.committed
+        );
+        self.applied = applied;
+    }
+
+    pub fn stable_to(&mut self, idx: u64, term: u64) {
+        self.unstable.stable_to(idx, term);
+    }
+
+    pub fn stable_snap_to(&mut self, idx: u64) {
+        self.unstable.stable_snap_to(idx);
+    }
+
+    pub fn unstable_entries(&self) -> &[Entry] {
+        &self.unstable.entries
+    }
+
+    /// The entries from `idx` on, as many as fit in `max_size`.
+    pub fn entries(&self, idx: u64, max_size: Option<u64>) -> Result<Vec<Entry>> {
+        let last = self.last_index();
+        if idx > last {
+            return Ok(Vec::new());
+        }
+        self.slice(idx, last + 1, max_size)
+    }
+
+    /// The committed entries the application has not applied yet.
+    pub fn next_entries(&self, max_size: Option<u64>) -> Vec<Entry> {
+        let lo = (self.applied + 1).max(self.first_index());
+        if self.committed < lo {
+            return Vec::new();
+        }
+        self.slice(lo, self.committed + 1, max_size)
+            .expect("unapplied committed entries are never compacted")
+    }
+
+    pub fn has_next_entries(&self) -> bool {
+        self.committed + 1 > (self.applied + 1).max(self.first_index())
+    }
+
+    /// The entries of `[lo, hi)`, as many as fit in `max_size`.
+    pub fn slice(&self, lo: u64, hi: u64, max_size: Option<u64>) -> Result<Vec<Entry>> {
+        if lo < self.first_index() {
+            return Err(StorageError::Compacted.into());
+        }
+        if hi > self.last_index() + 1 {
+            return Err(StorageError::Unavailable.into());
+        }
+        let mut entries = Vec::new();
+        if lo >= hi {
+            return Ok(entries);
+        }
+        let offset = self.unstable.offset;
+        if lo < offset {
+            let stored_hi = hi.min(offset);
+            entries = self.store.entries(lo, stored_hi, max_size)?;
+            if (entries.len() as u64) < stored_hi - lo {
+                return Ok(entries);
+            }
+        }
+        if hi > offset {
+            entries.extend_from_slice(self.unstable.slice(lo.max(offset), hi));
+        }
+        limit_size(&mut entries, max_size);
+        Ok(entries)
+    }
+
+    /// Whether a log ending at `(last_index, term)` is at least as up to date as
+    /// this one.
+    pub fn is_up_to_date(&self, last_index: u64, term: u64) -> bool {
+        term > self.last_term() || (term == self.last_term() && last_index >= self.last_index())
+    }
+
+    /// The snapshot to send to a follower that needs entries the log compacted.
+    pub fn snapshot(&self, request_index: u64) -> Result<Snapshot> {
+        match &self.unstable.snapshot {
+            Some(snapshot) if snapshot.metadata.index >= request_index => Ok(snapshot.clone()),
+            _ => self.store.snapshot(request_index),
+        }
+    }
+
+    /// Replaces the log by `snapshot`.
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        self.committed = snapshot.metadata.index;
+        self.unstable.restore(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+    use crate::message::SnapshotMetadata;
+    use crate::storage::MemStorage;
+
+    fn entry(index: u64, term: u64) -> Entry {
+        Entry {
+            term,
+            index,
+            ..Default::default()
+        }
+    }
+
+    fn indexes(entries: &[Entry]) -> Vec<(u64, u64)> {
+        entries.iter().map(|e| (e.index, e.term)).collect()
+    }
+
+    #[test]
+    fn test_append_and_conflicts() {
+        let store = MemStorage::new();
+        store.wl().append(&[entry(1, 1), entry(2, 1)]).unwrap();
+        let mut log = VioletaBftLog::new(store.clone()).unwrap();
+        assert_eq!((log.committed, log.last_index()), (0, 2));
+
+        // Entries that do not follow a matching entry are rejected.
+        assert_eq!(log.maybe_append(2, 2, 0, &[entry(3, 2)]), None);
+        assert_eq!(
+            log.maybe_append(2, 1, 1, &[entry(3, 2), entry(4, 2)]),
+            Some(4)
+        );
+        assert_eq!(log.committed, 1);
+        assert_eq!(indexes(log.unstable_entries()), vec![(3, 2), (4, 2)]);
+
+        // A new leader replaces the uncommitted suffix from the conflict on.
+        assert_eq!(
+            log.maybe_append(2, 1, 3, &[entry(3, 2), entry(4, 3)]),
+            Some(4)
+        );
+        assert_eq!(log.committed, 3);
+        assert_eq!(
+            indexes(&log.entries(1, None).unwrap()),
+            vec![(1, 1), (2, 1), (3, 2), (4, 3)]
+        );
+        // Entries the log already holds change nothing, even if shorter.
+        assert_eq!(log.maybe_append(2, 1, 3, &[entry(3, 2)]), Some(3));
+        assert_eq!(log.last_index(), 4);
+
+        assert!(log.is_up_to_date(4, 3));
+        assert!(log.is_up_to_date(1, 4));
+        assert!(!log.is_up_to_date(5, 2));
+        assert!(!log.maybe_commit(4, 2));
+        assert!(log.maybe_commit(4, 3));
+
+        // Persisting moves the entries to the storage.
+        store.wl().append(log.unstable_entries()).unwrap();
+        log.stable_to(4, 3);
+        assert!(log.unstable_entries().is_empty());
+        assert_eq!(
+            indexes(&log.next_entries(None)),
+            vec![(1, 1), (2, 1), (3, 2), (4, 3)]
+        );
+        log.applied_to(2);
+        assert_eq!(indexes(&log.next_entries(Some(0))), vec![(3, 2)]);
+        log.applied_to(4);
+        assert!(!log.has_next_entries());
+    }
+
+    #[test]
+    fn test_restore_snapshot() {
+        let store = MemStorage::new();
+        store.wl().append(&[entry(1, 1)]).unwrap();
+        let mut log = VioletaBftLog::new(store).unwrap();
+        log.restore(Snapshot {
+            data: Vec::new(),
+            metadata: SnapshotMetadata {
+                index: 10,
+                term: 3,
+                ..Default::default()
+            },
+        });
+        assert_eq!((log.first_index(), log.last_index()), (11, 10));
+        assert_eq!(log.committed, 10);
+        assert_eq!(log.term(10).unwrap(), 3);
+        assert_eq!(log.term(5), Err(Error::Store(StorageError::Compacted)));
+        assert_eq!(
+            log.slice(5, 11, None),
+            Err(Error::Store(StorageError::Compacted))
+        );
+        assert_eq!(log.snapshot(10).unwrap().metadata.term, 3);
+        assert_eq!(log.append(&[entry(11, 3)]), 11);
+        log.stable_snap_to(10);
+        assert!(log.unstable.snapshot.is_none());
+    }
+}
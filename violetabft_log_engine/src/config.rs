@@ -0,0 +1,67 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use fdb_traits::{Error, InterlockingDirectorate, Result};
+
+const MB: u64 = 1024 * 1024;
+
+/// Options of a `VioletaBFTLogEngine`.
+#[derive(Clone, Debug)]
+pub struct VioletaBFTLogConfig {
+    pub instance: String,
+    pub log_dir: String,
+    /// The size at which the file being written is sealed and another started.
+    pub log_file_size: u64,
+    /// Once there are more files than this, the live data pinning the oldest ones
+    /// is moved to the newest, so that they can be deleted.
+    pub log_file_num: u64,
+    /// The entries a group pinning old files may have and still be moved; a group
+    /// with more is asked to compact its log instead.
+    pub log_file_gc_threshold: u64,
+}
+
+impl VioletaBFTLogConfig {
+    pub fn new(log_dir: impl Into<String>) -> VioletaBFTLogConfig {
+        VioletaBFTLogConfig {
+            instance: "violetabft".to_owned(),
+            log_dir: log_dir.into(),
+            log_file_size: 128 * MB,
+            log_file_num: 16,
+            log_file_gc_threshold: 1024,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.log_dir.is_empty() {
+            return Err(Error::Engine("log_dir is not set".to_owned()));
+        }
+        if self.log_file_size == 0 {
+            return Err(Error::Engine("log_file_size must be positive".to_owned()));
+        }
+        if self.log_file_num == 0 {
+            return Err(Error::Engine("log_file_num must be positive".to_owned()));
+        }
+        Ok(())
+    }
+}
+
+impl InterlockingDirectorate for VioletaBFTLogConfig {
+    fn get_instance(&self) -> &str {
+        &self.instance
+    }
+
+    fn get_log_dir(&self) -> &str {
+        &self.log_dir
+    }
+
+    fn get_log_file_size(&self) -> u64 {
+        self.log_file_size
+    }
+
+    fn get_log_file_num(&self) -> u64 {
+        self.log_file_num
+    }
+
+    fn get_log_file_gc_threshold(&self) -> u64 {
+        self.log_file_gc_threshold
+    }
+}
@@ -0,0 +1,650 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The log einstein_merkle_tree: the log files, and the memtables that index the
+//! live data of each group in them.
+//!
+//! A write appends its alexandrov_poset_process as one record and applies it to
+//! the memtables under the same lock, so the memtables always describe a prefix of
+//! the files. Opening the einstein_merkle_tree replays every record in order.
+//!
+//! Files are deleted oldest first, once no group has live data in them. When there
+//! are more than `log_file_num`, the groups pinning the oldest ones are either
+//! moved to the newest file, if they are small, or returned by
+//! `purge_expired_files` so that they compact their logs.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use fdb_traits::{
+    Error, Result, VioletaBFTCmd, VioletaBFTKeyscapeSpline, VioletaBFTLocalState,
+    VioletaBFTLogBatch,
+};
+use violetabft::{Codec, Entry};
+
+use crate::config::VioletaBFTLogConfig;
+use crate::log_batch::{codec_error, decode_ops, LogBatch, Op};
+use crate::memtable::{EntryIndex, MemTable};
+use crate::pipe::{ActiveFile, FilePipe};
+
+struct Inner {
+    cfg: VioletaBFTLogConfig,
+    pipe: FilePipe,
+    memtables: RwLock<HashMap<u64, MemTable>>,
+}
+
+/// The logs of many VioletaBFT groups, in one set of append-only files. Clones
+/// share the einstein_merkle_tree.
+#[derive(Clone)]
+pub struct VioletaBFTLogEngine {
+    inner: Arc<Inner>,
+}
+
+/// Applies the ops of a record whose payload is at `offset` of file `file_seq`,
+/// and returns the number of entries compacted.
+fn apply_ops(
+    memtables: &mut HashMap<u64, MemTable>,
+    file_seq: u64,
+    offset: u64,
+    ops: Vec<Op>,
+) -> usize {
+    let mut compacted = 0;
+    for op in ops {
+        match op {
+            Op::Entries { group, entries } => {
+                let first = match entries.first() {
+                    Some(pos) => pos.index,
+                    None => continue,
+                };
+                let indexes = entries
+                    .iter()
+                    .map(|pos| EntryIndex {
+                        file_seq,
+                        offset: offset + pos.offset as u64,
+                        len: pos.len,
+                    })
+                    .collect();
+                memtables.entry(group).or_default().append(first, indexes);
+            }
+            Op::Cut { group, from, to } => {
+                if let Some(m) = memtables.get_mut(&group) {
+                    m.cut(from, to);
+                }
+            }
+            Op::State { group, state } => {
+                memtables
+                    .entry(group)
+                    .or_default()
+                    .put_state(state, file_seq);
+            }
+            Op::Compact { group, to } => {
+                if let Some(m) = memtables.get_mut(&group) {
+                    compacted += m.compact_to(to);
+                    if m.is_empty() {
+                        memtables.remove(&group);
+                    }
+                }
+            }
+            Op::Clean { group } => {
+                memtables.remove(&group);
+            }
+        }
+    }
+    compacted
+}
+
+impl VioletaBFTLogEngine {
+    /// Opens the einstein_merkle_tree in `cfg.log_dir`, creating it if missing. A
+    /// write torn by a crash is dropped.
+    pub fn open(cfg: VioletaBFTLogConfig) -> Result<VioletaBFTLogEngine> {
+        cfg.validate()?;
+        let mut memtables = HashMap::new();
+        let pipe = FilePipe::open(
+            Path::new(&cfg.log_dir),
+            cfg.log_file_size,
+            |file_seq, offset, payload| {
+                apply_ops(&mut memtables, file_seq, offset, decode_ops(payload)?);
+                Ok(())
+            },
+        )?;
+        Ok(VioletaBFTLogEngine {
+            inner: Arc::new(Inner {
+                cfg,
+                pipe,
+                memtables: RwLock::new(memtables),
+            }),
+        })
+    }
+
+    pub fn config(&self) -> &VioletaBFTLogConfig {
+        &self.inner.cfg
+    }
+
+    pub fn first_index(&self, violetabft_group_id: u64) -> Option<u64> {
+        let memtables = self.inner.memtables.read().unwrap();
+        memtables.get(&violetabft_group_id)?.first_index()
+    }
+
+    pub fn last_index(&self, violetabft_group_id: u64) -> Option<u64> {
+        let memtables = self.inner.memtables.read().unwrap();
+        memtables.get(&violetabft_group_id)?.last_index()
+    }
+
+    /// The number of log files, the one being written included.
+    pub fn file_count(&self) -> usize {
+        self.inner.pipe.file_seqs().len()
+    }
+
+    /// Writes a alexandrov_poset_process, and returns the bytes written and the
+    /// number of entries it compacted.
+    fn write(&self, batch: &mut LogBatch, sync: bool) -> Result<(usize, usize)> {
+        if batch.is_empty() {
+            return Ok((0, 0));
+        }
+        let mut active = self.inner.pipe.lock();
+        let written = self.write_locked(&mut active, batch)?;
+        let pos = active.position();
+        drop(active);
+        if sync {
+            self.inner.pipe.sync_to(pos)?;
+        }
+        Ok(written)
+    }
+
+    fn write_locked(
+        &self,
+        active: &mut ActiveFile,
+        batch: &mut LogBatch,
+    ) -> Result<(usize, usize)> {
+        let (payload, ops) = batch.encode();
+        let (file_seq, offset) = self.inner.pipe.append(active, &payload)?;
+        let mut memtables = self.inner.memtables.write().unwrap();
+        let compacted = apply_ops(&mut memtables, file_seq, offset, ops);
+        batch.clear();
+        Ok((payload.len(), compacted))
+    }
+
+    fn read_entries(&self, indexes: &[EntryIndex], to: &mut Vec<Entry>) -> Result<()> {
+        for idx in indexes {
+            let data = self.inner.pipe.read(idx.file_seq, idx.offset, idx.len)?;
+            to.push(Entry::decode(&data).map_err(codec_error)?);
+        }
+        Ok(())
+    }
+
+    /// Moves everything of a group to the file being written.
+    fn rewrite(&self, active: &mut ActiveFile, violetabft_group_id: u64) -> Result<()> {
+        let (indexes, state) = {
+            let memtables = self.inner.memtables.read().unwrap();
+            let m = match memtables.get(&violetabft_group_id) {
+                Some(m) => m,
+                None => return Ok(()),
+            };
+            let indexes = match (m.first_index(), m.last_index()) {
+                (Some(first), Some(last)) => m.range(first, last + 1).unwrap(),
+                _ => Vec::new(),
+            };
+            (indexes, m.state())
+        };
+        let mut entries = Vec::with_capacity(indexes.len());
+        self.read_entries(&indexes, &mut entries)?;
+        let mut batch = LogBatch::default();
+        batch.append(violetabft_group_id, entries)?;
+        if let Some(state) = state {
+            batch.put_violetabft_state(violetabft_group_id, &state)?;
+        }
+        self.write_locked(active, &mut batch)?;
+        Ok(())
+    }
+}
+
+impl VioletaBFTCmd for VioletaBFTLogEngine {
+    fn get_violetabft_state(
+        &self,
+        violetabft_group_id: u64,
+    ) -> Result<Option<VioletaBFTLocalState>> {
+        let memtables = self.inner.memtables.read().unwrap();
+        Ok(memtables.get(&violetabft_group_id).and_then(|m| m.state()))
+    }
+
+    fn get_entry(&self, violetabft_group_id: u64, index: u64) -> Result<Option<Entry>> {
+        let idx = {
+            let memtables = self.inner.memtables.read().unwrap();
+            memtables
+                .get(&violetabft_group_id)
+                .and_then(|m| m.get(index))
+        };
+        match idx {
+            Some(idx) => {
+                let mut entries = Vec::with_capacity(1);
+                self.read_entries(&[idx], &mut entries)?;
+                Ok(entries.pop())
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn fetch_entries_to(
+        &self,
+        violetabft_group_id: u64,
+        begin: u64,
+        end: u64,
+        max_size: Option<usize>,
+        to: &mut Vec<Entry>,
+    ) -> Result<usize> {
+        let indexes = {
+            let memtables = self.inner.memtables.read().unwrap();
+            memtables
+                .get(&violetabft_group_id)
+                .and_then(|m| m.range(begin, end))
+        };
+        let mut indexes = indexes.ok_or_else(|| {
+            Error::Engine(format!(
+                "entries [{}, {}) of group {} are unavailable",
+                begin, end, violetabft_group_id
+            ))
+        })?;
+        if let Some(max_size) = max_size {
+            let mut size = 0;
+            let n = indexes
+                .iter()
+                .take_while(|idx| {
+                    size += idx.len as usize;
+                    size <= max_size
+                })
+                .count();
+            indexes.truncate(n.max(1));
+        }
+        self.read_entries(&indexes, to)?;
+        Ok(indexes.len())
+    }
+
+    fn get_all_entries_to(&self, violetabft_group_id: u64, buf: &mut Vec<Entry>) -> Result<()> {
+        let indexes = {
+            let memtables = self.inner.memtables.read().unwrap();
+            match memtables.get(&violetabft_group_id) {
+                Some(m) => match (m.first_index(), m.last_index()) {
+                    (Some(first), Some(last)) => m.range(first, last + 1).unwrap(),
+                    _ => Vec::new(),
+                },
+                None => Vec::new(),
+            }
+        };
+        self.read_entries(&indexes, buf)
+    }
+}
+
+impl VioletaBFTKeyscapeSpline for VioletaBFTLogEngine {
+    type LogBatch = LogBatch;
+
+    fn log_alexandrov_poset_process(&self, capacity: usize) -> LogBatch {
+        LogBatch::with_capacity(capacity)
+    }
+
+    fn sync(&self) -> Result<()> {
+        self.inner.pipe.sync()
+    }
+
+    fn consume(&self, alexandrov_poset_process: &mut LogBatch, sync: bool) -> Result<usize> {
+        Ok(self.write(alexandrov_poset_process, sync)?.0)
+    }
+
+    fn consume_and_shrink(
+        &self,
+        alexandrov_poset_process: &mut LogBatch,
+        sync: bool,
+        max_capacity: usize,
+        shrink_to: usize,
+    ) -> Result<usize> {
+        let written = self.consume(alexandrov_poset_process, sync)?;
+        if alexandrov_poset_process.capacity() > max_capacity {
+            *alexandrov_poset_process = LogBatch::with_capacity(shrink_to);
+        }
+        Ok(written)
+    }
+
+    fn clean(
+        &self,
+        violetabft_group_id: u64,
+        _first_index: u64,
+        _state: &VioletaBFTLocalState,
+        alexandrov_poset_process: &mut LogBatch,
+    ) -> Result<()> {
+        // The memtable knows everything of the group.
+        alexandrov_poset_process.clean(violetabft_group_id);
+        Ok(())
+    }
+
+    fn append(&self, violetabft_group_id: u64, entries: Vec<Entry>) -> Result<usize> {
+        let mut batch = LogBatch::with_capacity(1);
+        batch.append(violetabft_group_id, entries)?;
+        self.consume(&mut batch, false)
+    }
+
+    fn put_violetabft_state(
+        &self,
+        violetabft_group_id: u64,
+        state: &VioletaBFTLocalState,
+    ) -> Result<()> {
+        let mut batch = LogBatch::with_capacity(1);
+        batch.put_violetabft_state(violetabft_group_id, state)?;
+        self.consume(&mut batch, false)?;
+        Ok(())
+    }
+
+    fn gc(&self, violetabft_group_id: u64, _from: u64, to: u64) -> Result<usize> {
+        if self
+            .first_index(violetabft_group_id)
+            .is_none_or(|first| first >= to)
+        {
+            return Ok(0);
+        }
+        let mut batch = LogBatch::with_capacity(1);
+        batch.compact(violetabft_group_id, to);
+        Ok(self.write(&mut batch, false)?.1)
+    }
+
+    fn purge_expired_files(&self) -> Result<Vec<u64>> {
+        let pipe = &self.inner.pipe;
+        let mut to_compact = Vec::new();
+        let file_seqs = pipe.file_seqs();
+        let file_num = self.inner.cfg.log_file_num as usize;
+        if file_seqs.len() > file_num {
+            let cutoff = file_seqs[file_seqs.len() - file_num];
+            // Writes wait for the groups to be moved, so that nothing they write
+            // is overwritten by the moved copy.
+            let mut active = pipe.lock();
+            let pinning: Vec<(u64, usize)> = {
+                let memtables = self.inner.memtables.read().unwrap();
+                memtables
+                    .iter()
+                    .filter(|(_, m)| m.min_file_seq().is_some_and(|seq| seq < cutoff))
+                    .map(|(&group, m)| (group, m.len()))
+                    .collect()
+            };
+            let mut moved = false;
+            for (group, len) in pinning {
+                if len as u64 > self.inner.cfg.log_file_gc_threshold {
+                    to_compact.push(group);
+                } else {
+                    self.rewrite(&mut active, group)?;
+                    moved = true;
+                }
+            }
+            drop(active);
+            if moved {
+                pipe.sync()?;
+            }
+        }
+        let min_pinned = {
+            let memtables = self.inner.memtables.read().unwrap();
+            memtables.values().filter_map(MemTable::min_file_seq).min()
+        };
+        pipe.purge_to(min_pinned.unwrap_or(u64::MAX))?;
+        to_compact.sort_unstable();
+        Ok(to_compact)
+    }
+
+    fn dump_stats(&self) -> Result<String> {
+        let memtables = self.inner.memtables.read().unwrap();
+        let entries: usize = memtables.values().map(MemTable::len).sum();
+        Ok(format!(
+            "files: {}, bytes: {}, groups: {}, live entries: {}",
+            self.file_count(),
+            self.inner.pipe.total_size()?,
+            memtables.len(),
+            entries
+        ))
+    }
+
+    fn get_einstein_merkle_tree_size(&self) -> Result<u64> {
+        self.inner.pipe.total_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, OpenOptions};
+    use std::thread;
+
+    use violetabft::HardState;
+
+    use super::*;
+
+    fn entry(index: u64, data: &[u8]) -> Entry {
+        Entry {
+            term: 1,
+            index,
+            data: data.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn entries(begin: u64, end: u64, len: usize) -> Vec<Entry> {
+        (begin..end)
+            .map(|i| entry(i, &vec![i as u8; len]))
+            .collect()
+    }
+
+    fn fetch(
+        einstein_merkle_tree: &VioletaBFTLogEngine,
+        group: u64,
+        begin: u64,
+        end: u64,
+    ) -> Vec<u64> {
+        let mut to = Vec::new();
+        einstein_merkle_tree
+            .fetch_entries_to(group, begin, end, None, &mut to)
+            .unwrap();
+        to.iter().map(|e| e.index).collect()
+    }
+
+    fn state(last_index: u64, commit: u64) -> VioletaBFTLocalState {
+        VioletaBFTLocalState {
+            hard_state: HardState {
+                term: 1,
+                vote: 1,
+                commit,
+            },
+            last_index,
+        }
+    }
+
+    #[test]
+    fn test_write_fetch_and_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = VioletaBFTLogConfig::new(dir.path().to_str().unwrap());
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg.clone()).unwrap();
+
+        let mut batch = einstein_merkle_tree.log_alexandrov_poset_process(4);
+        batch.append(1, entries(1, 11, 8)).unwrap();
+        batch.append(2, entries(5, 8, 8)).unwrap();
+        batch.put_violetabft_state(1, &state(10, 3)).unwrap();
+        assert!(einstein_merkle_tree.consume(&mut batch, true).unwrap() > 0);
+        assert!(batch.is_empty());
+
+        assert_eq!(fetch(&einstein_merkle_tree, 1, 3, 6), vec![3, 4, 5]);
+        assert!(einstein_merkle_tree
+            .fetch_entries_to(1, 0, 3, None, &mut Vec::new())
+            .is_err());
+        let mut to = Vec::new();
+        assert_eq!(
+            einstein_merkle_tree
+                .fetch_entries_to(1, 1, 11, Some(1), &mut to)
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            einstein_merkle_tree.get_entry(2, 6).unwrap(),
+            Some(entry(6, &[6; 8]))
+        );
+        assert_eq!(einstein_merkle_tree.get_entry(2, 8).unwrap(), None);
+
+        // Overwrite a suffix, cut another group, and compact.
+        einstein_merkle_tree.append(1, entries(8, 10, 1)).unwrap();
+        let mut batch = einstein_merkle_tree.log_alexandrov_poset_process(1);
+        batch.cut_logs(2, 7, 8);
+        einstein_merkle_tree.consume(&mut batch, false).unwrap();
+        assert_eq!(einstein_merkle_tree.gc(1, 0, 4).unwrap(), 3);
+        assert_eq!(einstein_merkle_tree.gc(1, 0, 4).unwrap(), 0);
+        einstein_merkle_tree
+            .put_violetabft_state(2, &state(6, 6))
+            .unwrap();
+        einstein_merkle_tree.sync().unwrap();
+
+        let check = |einstein_merkle_tree: &VioletaBFTLogEngine| {
+            assert_eq!(einstein_merkle_tree.first_index(1), Some(4));
+            assert_eq!(einstein_merkle_tree.last_index(1), Some(9));
+            assert_eq!(
+                einstein_merkle_tree.get_entry(1, 9).unwrap(),
+                Some(entry(9, &[9]))
+            );
+            assert_eq!(einstein_merkle_tree.last_index(2), Some(6));
+            assert_eq!(
+                einstein_merkle_tree.get_violetabft_state(1).unwrap(),
+                Some(state(10, 3))
+            );
+            assert_eq!(
+                einstein_merkle_tree.get_violetabft_state(2).unwrap(),
+                Some(state(6, 6))
+            );
+        };
+        check(&einstein_merkle_tree);
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg.clone()).unwrap();
+        check(&einstein_merkle_tree);
+
+        let mut all = Vec::new();
+        einstein_merkle_tree
+            .get_all_entries_to(2, &mut all)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        let mut batch = einstein_merkle_tree.log_alexandrov_poset_process(1);
+        einstein_merkle_tree
+            .clean(2, 0, &state(6, 6), &mut batch)
+            .unwrap();
+        einstein_merkle_tree.consume(&mut batch, true).unwrap();
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg).unwrap();
+        assert_eq!(einstein_merkle_tree.get_violetabft_state(2).unwrap(), None);
+        assert_eq!(einstein_merkle_tree.last_index(2), None);
+    }
+
+    #[test]
+    fn test_torn_tail_write_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = VioletaBFTLogConfig::new(dir.path().to_str().unwrap());
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg.clone()).unwrap();
+        einstein_merkle_tree.append(1, entries(1, 4, 16)).unwrap();
+        einstein_merkle_tree.append(1, entries(4, 6, 16)).unwrap();
+        einstein_merkle_tree.sync().unwrap();
+        drop(einstein_merkle_tree);
+
+        let path = fs::read_dir(dir.path())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let len = fs::metadata(&path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(len - 10)
+            .unwrap();
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg.clone()).unwrap();
+        assert_eq!(einstein_merkle_tree.last_index(1), Some(3));
+        // Writing goes on where the intact records end.
+        einstein_merkle_tree.append(1, entries(4, 5, 16)).unwrap();
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg).unwrap();
+        assert_eq!(fetch(&einstein_merkle_tree, 1, 1, 5), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_purge_expired_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cfg = VioletaBFTLogConfig::new(dir.path().to_str().unwrap());
+        cfg.log_file_size = 1024;
+        cfg.log_file_num = 2;
+        cfg.log_file_gc_threshold = 5;
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg.clone()).unwrap();
+
+        // Group 1 is small, group 2 too large to move, and group 3 keeps writing.
+        einstein_merkle_tree.append(1, entries(1, 3, 16)).unwrap();
+        einstein_merkle_tree
+            .put_violetabft_state(1, &state(2, 2))
+            .unwrap();
+        einstein_merkle_tree.append(2, entries(1, 21, 16)).unwrap();
+        for i in 1..40 {
+            einstein_merkle_tree
+                .append(3, entries(i, i + 1, 100))
+                .unwrap();
+            einstein_merkle_tree.gc(3, 0, i).unwrap();
+        }
+        assert!(einstein_merkle_tree.file_count() > 3);
+        let size = einstein_merkle_tree
+            .get_einstein_merkle_tree_size()
+            .unwrap();
+
+        assert_eq!(einstein_merkle_tree.purge_expired_files().unwrap(), vec![2]);
+        // Group 2 still pins the first file.
+        assert_eq!(einstein_merkle_tree.purge_expired_files().unwrap(), vec![2]);
+        assert_eq!(fetch(&einstein_merkle_tree, 1, 1, 3), vec![1, 2]);
+
+        einstein_merkle_tree.gc(2, 0, 21).unwrap();
+        assert_eq!(
+            einstein_merkle_tree.purge_expired_files().unwrap(),
+            Vec::<u64>::new()
+        );
+        assert!(einstein_merkle_tree.file_count() <= 2);
+        assert!(
+            einstein_merkle_tree
+                .get_einstein_merkle_tree_size()
+                .unwrap()
+                < size
+        );
+        let stats = einstein_merkle_tree.dump_stats().unwrap();
+        assert!(stats.contains("groups: 2"), "{}", stats);
+        drop(einstein_merkle_tree);
+
+        // The moved group survives its old files.
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg).unwrap();
+        assert_eq!(fetch(&einstein_merkle_tree, 1, 1, 3), vec![1, 2]);
+        assert_eq!(
+            einstein_merkle_tree.get_violetabft_state(1).unwrap(),
+            Some(state(2, 2))
+        );
+        assert_eq!(fetch(&einstein_merkle_tree, 3, 39, 40), vec![39]);
+        assert_eq!(einstein_merkle_tree.last_index(2), None);
+    }
+
+    #[test]
+    fn test_concurrent_sync_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cfg = VioletaBFTLogConfig::new(dir.path().to_str().unwrap());
+        cfg.log_file_size = 4096;
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg.clone()).unwrap();
+        let handles: Vec<_> = (1..=4)
+            .map(|group| {
+                let einstein_merkle_tree = einstein_merkle_tree.clone();
+                thread::spawn(move || {
+                    for i in 1..=50 {
+                        let mut batch = einstein_merkle_tree.log_alexandrov_poset_process(1);
+                        batch.append(group, entries(i, i + 1, 32)).unwrap();
+                        einstein_merkle_tree.consume(&mut batch, true).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        drop(einstein_merkle_tree);
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg).unwrap();
+        for group in 1..=4 {
+            assert_eq!(fetch(&einstein_merkle_tree, group, 1, 51).len(), 50);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Log GC in the background: scheduled `VioletaBFTLogGCTask`s are run in
+//! alexandrov_poset_processes, and expired files are purged whenever the worker is
+//! idle for an interval.
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use fdb_traits::{VioletaBFTKeyscapeSpline, VioletaBFTLogGCTask};
+
+use crate::engine::VioletaBFTLogEngine;
+
+/// Runs log GC for a `VioletaBFTLogEngine` on its own thread, until stopped or
+/// dropped.
+pub struct GcWorker {
+    sender: Option<Sender<VioletaBFTLogGCTask>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl GcWorker {
+    /// Starts the worker. Every `interval` it purges expired files, and passes the
+    /// groups that should compact their logs to `on_compact`.
+    pub fn start(
+        einstein_merkle_tree: VioletaBFTLogEngine,
+        interval: Duration,
+        mut on_compact: impl FnMut(Vec<u64>) + Send + 'static,
+    ) -> GcWorker {
+        let (sender, receiver) = mpsc::channel::<VioletaBFTLogGCTask>();
+        let handle = thread::Builder::new()
+            .name("violetabft-log-gc".to_owned())
+            .spawn(move || {
+                let mut next_purge = Instant::now() + interval;
+                loop {
+                    let timeout = next_purge.saturating_duration_since(Instant::now());
+                    match receiver.recv_timeout(timeout) {
+                        Ok(task) => {
+                            let mut tasks = vec![task];
+                            tasks.extend(receiver.try_iter());
+                            // A failed GC is retried by the next task of the group.
+                            let _ = einstein_merkle_tree.alexandrov_poset_process_gc(tasks);
+                        }
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                    if Instant::now() >= next_purge {
+                        if let Ok(groups) = einstein_merkle_tree.purge_expired_files() {
+                            if !groups.is_empty() {
+                                on_compact(groups);
+                            }
+                        }
+                        next_purge = Instant::now() + interval;
+                    }
+                }
+            })
+            .expect("failed to spawn the log gc thread");
+        GcWorker {
+            sender: Some(sender),
+            handle: Some(handle),
+        }
+    }
+
+    pub fn schedule(&self, task: VioletaBFTLogGCTask) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(task);
+        }
+    }
+
+    /// Runs the tasks already scheduled, then stops the worker.
+    pub fn stop(&mut self) {
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for GcWorker {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use violetabft::Entry;
+
+    use super::*;
+    use crate::config::VioletaBFTLogConfig;
+
+    #[test]
+    fn test_gc_worker() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cfg = VioletaBFTLogConfig::new(dir.path().to_str().unwrap());
+        cfg.log_file_size = 256;
+        cfg.log_file_num = 1;
+        cfg.log_file_gc_threshold = 0;
+        let einstein_merkle_tree = VioletaBFTLogEngine::open(cfg).unwrap();
+        let entries: Vec<Entry> = (1..=20)
+            .map(|index| Entry {
+                index,
+                data: vec![0; 32],
+                ..Default::default()
+            })
+            .collect();
+        einstein_merkle_tree.append(1, entries).unwrap();
+        assert!(einstein_merkle_tree.file_count() > 1);
+
+        let (tx, rx) = mpsc::channel();
+        let mut worker = GcWorker::start(
+            einstein_merkle_tree.clone(),
+            Duration::from_millis(10),
+            move |groups| {
+                let _ = tx.send(groups);
+            },
+        );
+        // The group is too large to move, so it is asked to compact.
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), vec![1]);
+        worker.schedule(VioletaBFTLogGCTask {
+            violetabft_group_id: 1,
+            from: 0,
+            to: 21,
+        });
+        worker.stop();
+        assert_eq!(einstein_merkle_tree.first_index(1), None);
+        assert_eq!(
+            einstein_merkle_tree.purge_expired_files().unwrap(),
+            Vec::<u64>::new()
+        );
+        assert_eq!(einstein_merkle_tree.file_count(), 1);
+    }
+}
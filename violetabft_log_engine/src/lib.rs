@@ -0,0 +1,21 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! An append-only store of the logs of many VioletaBFT groups.
+//!
+//! `VioletaBFTLogEngine` writes each alexandrov_poset_process of entries and
+//! states as one checksummed record to the current log file, and indexes the live
+//! entries of every group in memory. Files are rotated at a fixed size and deleted
+//! once no group needs them; `GcWorker` compacts logs and purges files in the
+//! background.
+
+mod config;
+mod engine;
+mod gc_worker;
+mod log_batch;
+mod memtable;
+mod pipe;
+
+pub use crate::config::VioletaBFTLogConfig;
+pub use crate::engine::VioletaBFTLogEngine;
+pub use crate::gc_worker::GcWorker;
+pub use crate::log_batch::LogBatch;
@@ -0,0 +1,349 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The changes of one write, to the logs of any number of groups.
+//!
+//! A alexandrov_poset_process is written as the payload of one record:
+//!
+//! ```text
+//!   payload ::= count: varint | op*
+//!   op      ::= ENTRIES group n (index entry_bytes)* | CUT group from to
+//!             | STATE group hard_state last_index | COMPACT group to | CLEAN group
+//! ```
+//!
+//! Each entry is kept encoded on its own, so that the memtables can point at it.
+
+use fdb_traits::{Error, Result, VioletaBFTLocalState, VioletaBFTLogBatch};
+use violetabft::codec::{get_bytes, get_u8, get_varint, put_varint};
+use violetabft::{Codec, Entry, HardState};
+
+const TAG_ENTRIES: u8 = 1;
+const TAG_CUT: u8 = 2;
+const TAG_STATE: u8 = 3;
+const TAG_COMPACT: u8 = 4;
+const TAG_CLEAN: u8 = 5;
+
+pub(crate) fn codec_error(e: violetabft::Error) -> Error {
+    Error::Corruption(e.to_string())
+}
+
+/// Where an encoded entry is in a payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct EntryPos {
+    pub index: u64,
+    pub offset: u32,
+    pub len: u32,
+}
+
+/// A change to the memtables, as encoded in or decoded from a payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Op {
+    Entries {
+        group: u64,
+        entries: Vec<EntryPos>,
+    },
+    Cut {
+        group: u64,
+        from: u64,
+        to: u64,
+    },
+    State {
+        group: u64,
+        state: VioletaBFTLocalState,
+    },
+    Compact {
+        group: u64,
+        to: u64,
+    },
+    Clean {
+        group: u64,
+    },
+}
+
+enum Item {
+    Entries {
+        group: u64,
+        entries: Vec<Entry>,
+    },
+    Cut {
+        group: u64,
+        from: u64,
+        to: u64,
+    },
+    State {
+        group: u64,
+        state: VioletaBFTLocalState,
+    },
+    Compact {
+        group: u64,
+        to: u64,
+    },
+    Clean {
+        group: u64,
+    },
+}
+
+#[derive(Default)]
+pub struct LogBatch {
+    items: Vec<Item>,
+    size: usize,
+}
+
+impl LogBatch {
+    pub fn with_capacity(capacity: usize) -> LogBatch {
+        LogBatch {
+            items: Vec::with_capacity(capacity),
+            size: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.size = 0;
+    }
+
+    pub(crate) fn compact(&mut self, group: u64, to: u64) {
+        self.size += 16;
+        self.items.push(Item::Compact { group, to });
+    }
+
+    pub(crate) fn clean(&mut self, group: u64) {
+        self.size += 8;
+        self.items.push(Item::Clean { group });
+    }
+
+    /// Encodes the payload, and the changes it makes.
+    pub(crate) fn encode(&self) -> (Vec<u8>, Vec<Op>) {
+        let mut buf = Vec::with_capacity(self.size + 8);
+        let mut ops = Vec::with_capacity(self.items.len());
+        put_varint(&mut buf, self.items.len() as u64);
+        for item in &self.items {
+            match item {
+                Item::Entries { group, entries } => {
+                    buf.push(TAG_ENTRIES);
+                    put_varint(&mut buf, *group);
+                    put_varint(&mut buf, entries.len() as u64);
+                    let mut positions = Vec::with_capacity(entries.len());
+                    for e in entries {
+                        put_varint(&mut buf, e.index);
+                        let data = e.encode();
+                        put_varint(&mut buf, data.len() as u64);
+                        positions.push(EntryPos {
+                            index: e.index,
+                            offset: buf.len() as u32,
+                            len: data.len() as u32,
+                        });
+                        buf.extend_from_slice(&data);
+                    }
+                    ops.push(Op::Entries {
+                        group: *group,
+                        entries: positions,
+                    });
+                }
+                Item::Cut { group, from, to } => {
+                    buf.push(TAG_CUT);
+                    for v in [*group, *from, *to] {
+                        put_varint(&mut buf, v);
+                    }
+                    ops.push(Op::Cut {
+                        group: *group,
+                        from: *from,
+                        to: *to,
+                    });
+                }
+                Item::State { group, state } => {
+                    buf.push(TAG_STATE);
+                    put_varint(&mut buf, *group);
+                    state.hard_state.encode_to(&mut buf);
+                    put_varint(&mut buf, state.last_index);
+                    ops.push(Op::State {
+                        group: *group,
+                        state: *state,
+                    });
+                }
+                Item::Compact { group, to } => {
+                    buf.push(TAG_COMPACT);
+                    put_varint(&mut buf, *group);
+                    put_varint(&mut buf, *to);
+                    ops.push(Op::Compact {
+                        group: *group,
+                        to: *to,
+                    });
+                }
+                Item::Clean { group } => {
+                    buf.push(TAG_CLEAN);
+                    put_varint(&mut buf, *group);
+                    ops.push(Op::Clean { group: *group });
+                }
+            }
+        }
+        (buf, ops)
+    }
+}
+
+/// Decodes the changes a payload makes, without decoding its entries.
+pub(crate) fn decode_ops(payload: &[u8]) -> Result<Vec<Op>> {
+    let mut buf = payload;
+    let n = get_varint(&mut buf).map_err(codec_error)?;
+    let mut ops = Vec::new();
+    for _ in 0..n {
+        let tag = get_u8(&mut buf).map_err(codec_error)?;
+        let group = get_varint(&mut buf).map_err(codec_error)?;
+        let op = match tag {
+            TAG_ENTRIES => {
+                let count = get_varint(&mut buf).map_err(codec_error)?;
+                let mut entries = Vec::new();
+                for _ in 0..count {
+                    let index = get_varint(&mut buf).map_err(codec_error)?;
+                    let data = get_bytes(&mut buf).map_err(codec_error)?;
+                    entries.push(EntryPos {
+                        index,
+                        offset: (payload.len() - buf.len() - data.len()) as u32,
+                        len: data.len() as u32,
+                    });
+                }
+                Op::Entries { group, entries }
+            }
+            TAG_CUT => Op::Cut {
+                group,
+                from: get_varint(&mut buf).map_err(codec_error)?,
+                to: get_varint(&mut buf).map_err(codec_error)?,
+            },
+            TAG_STATE => Op::State {
+                group,
+                state: VioletaBFTLocalState {
+                    hard_state: HardState::decode_from(&mut buf).map_err(codec_error)?,
+                    last_index: get_varint(&mut buf).map_err(codec_error)?,
+                },
+            },
+            TAG_COMPACT => Op::Compact {
+                group,
+                to: get_varint(&mut buf).map_err(codec_error)?,
+            },
+            TAG_CLEAN => Op::Clean { group },
+            t => return Err(Error::Corruption(format!("unknown log op {}", t))),
+        };
+        ops.push(op);
+    }
+    if !buf.is_empty() {
+        return Err(Error::Corruption("trailing bytes in log record".to_owned()));
+    }
+    Ok(ops)
+}
+
+impl VioletaBFTLogBatch for LogBatch {
+    fn append(&mut self, violetabft_group_id: u64, entries: Vec<Entry>) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        for w in entries.windows(2) {
+            if w[1].index != w[0].index + 1 {
+                return Err(Error::Engine(format!(
+                    "entries of group {} are not contiguous: {} follows {}",
+                    violetabft_group_id, w[1].index, w[0].index
+                )));
+            }
+        }
+        self.size += entries.iter().map(|e| e.size() as usize).sum::<usize>();
+        self.items.push(Item::Entries {
+            group: violetabft_group_id,
+            entries,
+        });
+        Ok(())
+    }
+
+    fn cut_logs(&mut self, violetabft_group_id: u64, from: u64, to: u64) {
+        self.size += 24;
+        self.items.push(Item::Cut {
+            group: violetabft_group_id,
+            from,
+            to,
+        });
+    }
+
+    fn put_violetabft_state(
+        &mut self,
+        violetabft_group_id: u64,
+        state: &VioletaBFTLocalState,
+    ) -> Result<()> {
+        self.size += 40;
+        self.items.push(Item::State {
+            group: violetabft_group_id,
+            state: *state,
+        });
+        Ok(())
+    }
+
+    fn persist_size(&self) -> usize {
+        self.size
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn merge(&mut self, mut other: LogBatch) {
+        self.size += other.size;
+        self.items.append(&mut other.items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(index: u64, data: &[u8]) -> Entry {
+        Entry {
+            term: 1,
+            index,
+            data: data.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let mut batch = LogBatch::with_capacity(4);
+        batch
+            .append(1, vec![entry(5, b"a"), entry(6, b"bb")])
+            .unwrap();
+        assert!(batch
+            .append(1, vec![entry(8, b""), entry(10, b"")])
+            .is_err());
+        batch.cut_logs(2, 7, 9);
+        let state = VioletaBFTLocalState {
+            hard_state: HardState {
+                term: 3,
+                vote: 1,
+                commit: 6,
+            },
+            last_index: 6,
+        };
+        let mut other = LogBatch::default();
+        other.put_violetabft_state(1, &state).unwrap();
+        other.compact(2, 4);
+        other.clean(3);
+        batch.merge(other);
+        assert!(batch.persist_size() > 0);
+
+        let (payload, ops) = batch.encode();
+        assert_eq!(decode_ops(&payload).unwrap(), ops);
+        assert_eq!(ops.len(), 5);
+        match &ops[0] {
+            Op::Entries { group: 1, entries } => {
+                let pos = entries[1];
+                let data = &payload[pos.offset as usize..(pos.offset + pos.len) as usize];
+                assert_eq!(Entry::decode(data).unwrap(), entry(6, b"bb"));
+            }
+            op => panic!("unexpected {:?}", op),
+        }
+        assert_eq!(ops[2], Op::State { group: 1, state });
+        assert!(decode_ops(&payload[..payload.len() - 1]).is_err());
+
+        batch.clear();
+        assert!(batch.is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The in-memory index of a group: where each of its live entries, and its
+//! latest state, are in the log files.
+
+use std::collections::VecDeque;
+
+use fdb_traits::VioletaBFTLocalState;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct EntryIndex {
+    pub file_seq: u64,
+    pub offset: u64,
+    pub len: u32,
+}
+
+/// `entries[i]` is the entry at `first_index + i`. The files of the entries never
+/// decrease with their index, as entries are only ever written after the ones
+/// they follow or all moved together.
+#[derive(Debug, Default)]
+pub(crate) struct MemTable {
+    first_index: u64,
+    entries: VecDeque<EntryIndex>,
+    state: Option<(VioletaBFTLocalState, u64)>,
+}
+
+impl MemTable {
+    pub fn first_index(&self) -> Option<u64> {
+        (!self.entries.is_empty()).then_some(self.first_index)
+    }
+
+    pub fn last_index(&self) -> Option<u64> {
+        (!self.entries.is_empty()).then(|| self.first_index + self.entries.len() as u64 - 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.state.is_none()
+    }
+
+    /// Appends the entries from `first` on, replacing those from `first` on. A
+    /// gap after the last entry discards them all, as after a snapshot.
+    pub fn append(&mut self, first: u64, entries: Vec<EntryIndex>) {
+        if entries.is_empty() {
+            return;
+        }
+        match self.last_index() {
+            Some(last) if first >= self.first_index && first <= last + 1 => {
+                self.entries.truncate((first - self.first_index) as usize);
+            }
+            _ => {
+                self.entries.clear();
+                self.first_index = first;
+            }
+        }
+        self.entries.extend(entries);
+    }
+
+    /// Removes the entries of `[from, to)`. The entries after a cut in the middle
+    /// are removed too, as they could no longer be reached.
+    pub fn cut(&mut self, from: u64, to: u64) {
+        if from <= self.first_index {
+            self.compact_to(to);
+        } else if from < self.first_index + self.entries.len() as u64 {
+            self.entries.truncate((from - self.first_index) as usize);
+        }
+    }
+
+    /// Removes the entries before `to`, and returns how many there were.
+    pub fn compact_to(&mut self, to: u64) -> usize {
+        if to <= self.first_index {
+            return 0;
+        }
+        let n = ((to - self.first_index) as usize).min(self.entries.len());
+        self.entries.drain(..n);
+        self.first_index = to;
+        n
+    }
+
+    pub fn get(&self, index: u64) -> Option<EntryIndex> {
+        if index < self.first_index {
+            return None;
+        }
+        self.entries
+            .get((index - self.first_index) as usize)
+            .copied()
+    }
+
+    /// The indexes of the entries of `[begin, end)`, if all are here.
+    pub fn range(&self, begin: u64, end: u64) -> Option<Vec<EntryIndex>> {
+        if begin >= end {
+            return Some(Vec::new());
+        }
+        if begin < self.first_index || end > self.first_index + self.entries.len() as u64 {
+            return None;
+        }
+        let lo = (begin - self.first_index) as usize;
+        let hi = (end - self.first_index) as usize;
+        Some(self.entries.range(lo..hi).copied().collect())
+    }
+
+    pub fn state(&self) -> Option<VioletaBFTLocalState> {
+        self.state.map(|(state, _)| state)
+    }
+
+    pub fn put_state(&mut self, state: VioletaBFTLocalState, file_seq: u64) {
+        self.state = Some((state, file_seq));
+    }
+
+    /// The oldest file holding live data of the group.
+    pub fn min_file_seq(&self) -> Option<u64> {
+        let entries = self.entries.front().map(|e| e.file_seq);
+        let state = self.state.map(|(_, seq)| seq);
+        match (entries, state) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indexes(file_seq: u64, n: usize) -> Vec<EntryIndex> {
+        (0..n)
+            .map(|i| EntryIndex {
+                file_seq,
+                offset: i as u64,
+                len: 1,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_memtable() {
+        let mut m = MemTable::default();
+        assert!(m.is_empty());
+        m.append(5, indexes(1, 5));
+        assert_eq!((m.first_index(), m.last_index()), (Some(5), Some(9)));
+
+        // An overwrite from 8 on, then a gap that discards everything.
+        m.append(8, indexes(2, 3));
+        assert_eq!(m.last_index(), Some(10));
+        assert_eq!(m.get(7).unwrap().file_seq, 1);
+        assert_eq!(m.get(8).unwrap().file_seq, 2);
+        m.append(20, indexes(3, 1));
+        assert_eq!((m.first_index(), m.last_index()), (Some(20), Some(20)));
+
+        m.append(21, indexes(3, 9));
+        assert_eq!(m.compact_to(25), 5);
+        assert_eq!(m.compact_to(25), 0);
+        assert!(m.get(24).is_none());
+        assert_eq!(m.range(25, 30).unwrap().len(), 5);
+        assert!(m.range(24, 26).is_none());
+        assert!(m.range(29, 31).is_none());
+
+        m.cut(27, 40);
+        assert_eq!(m.last_index(), Some(26));
+        m.cut(0, 26);
+        assert_eq!((m.first_index(), m.len()), (Some(26), 1));
+
+        let state = VioletaBFTLocalState {
+            last_index: 26,
+            ..Default::default()
+        };
+        m.put_state(state, 2);
+        assert_eq!(m.state(), Some(state));
+        assert_eq!(m.min_file_seq(), Some(2));
+    }
+}
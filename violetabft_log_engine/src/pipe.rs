@@ -0,0 +1,351 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The log files: `{seq}.vlog` in the log dir, written one after another.
+//!
+//! ```text
+//!   file   ::= magic record*
+//!   record ::= crc32(payload): u32 | len(payload): u32 | payload
+//! ```
+//!
+//! Only the last file is written to; it is sealed and synced once it reaches the
+//! file size. A crash can only tear the tail of the last file, which recovery
+//! truncates; a bad record anywhere else is corruption.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+use fdb_traits::{Error, Result};
+
+const FILE_MAGIC: &[u8; 8] = b"VBFTLOG1";
+pub(crate) const RECORD_HEADER_SIZE: u64 = 8;
+const LOG_FILE_SUFFIX: &str = ".vlog";
+
+fn file_name(seq: u64) -> String {
+    format!("{:016}{}", seq, LOG_FILE_SUFFIX)
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    crc32fast::hash(data)
+}
+
+pub(crate) struct ActiveFile {
+    seq: u64,
+    file: Arc<File>,
+    size: u64,
+}
+
+impl ActiveFile {
+    /// The position after everything written to the pipe so far.
+    pub fn position(&self) -> (u64, u64) {
+        (self.seq, self.size)
+    }
+}
+
+pub(crate) struct FilePipe {
+    dir: PathBuf,
+    file_size: u64,
+    active: Mutex<ActiveFile>,
+    /// Every file, the active one included, for reads.
+    files: RwLock<BTreeMap<u64, Arc<File>>>,
+    /// The position up to which the pipe is known to be synced.
+    synced: Mutex<(u64, u64)>,
+}
+
+impl FilePipe {
+    /// Opens the files of `dir`, passing each intact record to `on_record` with
+    /// the file it is in and the offset of its payload.
+    pub fn open(
+        dir: &Path,
+        file_size: u64,
+        mut on_record: impl FnMut(u64, u64, &[u8]) -> Result<()>,
+    ) -> Result<FilePipe> {
+        fs::create_dir_all(dir)?;
+        let mut seqs = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name();
+            let name = name.to_string_lossy();
+            if let Some(seq) = name
+                .strip_suffix(LOG_FILE_SUFFIX)
+                .and_then(|s| s.parse::<u64>().ok())
+            {
+                seqs.push(seq);
+            }
+        }
+        seqs.sort_unstable();
+
+        let mut files = BTreeMap::new();
+        let mut active = None;
+        for (i, &seq) in seqs.iter().enumerate() {
+            let is_last = i + 1 == seqs.len();
+            let path = dir.join(file_name(seq));
+            let file = OpenOptions::new().read(true).write(true).open(&path)?;
+            let data = fs::read(&path)?;
+            let valid =
+                match scan_records(&data, |offset, payload| on_record(seq, offset, payload))? {
+                    None => data.len() as u64,
+                    Some(valid) if is_last => valid,
+                    Some(valid) => {
+                        return Err(Error::Corruption(format!(
+                            "bad record in sealed log file {} at offset {}",
+                            path.display(),
+                            valid
+                        )))
+                    }
+                };
+            if is_last {
+                if valid < FILE_MAGIC.len() as u64 {
+                    // Torn before its header was written.
+                    file.set_len(0)?;
+                    file.write_all_at(FILE_MAGIC, 0)?;
+                    file.sync_all()?;
+                    active = Some((seq, FILE_MAGIC.len() as u64));
+                } else {
+                    if valid < data.len() as u64 {
+                        file.set_len(valid)?;
+                        file.sync_all()?;
+                    }
+                    active = Some((seq, valid));
+                }
+            }
+            files.insert(seq, Arc::new(file));
+        }
+
+        let (seq, size) = match active {
+            Some(active) => active,
+            None => {
+                files.insert(1, Arc::new(create_file(dir, 1)?));
+                (1, FILE_MAGIC.len() as u64)
+            }
+        };
+        Ok(FilePipe {
+            dir: dir.to_owned(),
+            file_size,
+            active: Mutex::new(ActiveFile {
+                seq,
+                file: files[&seq].clone(),
+                size,
+            }),
+            files: RwLock::new(files),
+            synced: Mutex::new((seq, size)),
+        })
+    }
+
+    /// Locks the pipe for writing: records are appended in the order the lock is
+    /// taken.
+    pub fn lock(&self) -> MutexGuard<'_, ActiveFile> {
+        self.active.lock().unwrap()
+    }
+
+    /// Appends a record to the active file, and returns the offset of its payload.
+    /// Seals the file once it is full.
+    pub fn append(&self, active: &mut ActiveFile, payload: &[u8]) -> Result<(u64, u64)> {
+        let mut record = Vec::with_capacity(RECORD_HEADER_SIZE as usize + payload.len());
+        record.extend_from_slice(&crc32(payload).to_le_bytes());
+        record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        record.extend_from_slice(payload);
+        let offset = active.size;
+        active.file.write_all_at(&record, offset)?;
+        active.size += record.len() as u64;
+        let written = (active.seq, offset + RECORD_HEADER_SIZE);
+        if active.size >= self.file_size {
+            self.rotate(active)?;
+        }
+        Ok(written)
+    }
+
+    fn rotate(&self, active: &mut ActiveFile) -> Result<()> {
+        active.file.sync_data()?;
+        let seq = active.seq + 1;
+        let file = Arc::new(create_file(&self.dir, seq)?);
+        self.files.write().unwrap().insert(seq, file.clone());
+        *active = ActiveFile {
+            seq,
+            file,
+            size: FILE_MAGIC.len() as u64,
+        };
+        Ok(())
+    }
+
+    /// Syncs the pipe up to `pos` at least. Writers waiting to sync share the
+    /// fsync of whoever syncs first, which covers everything written then.
+    pub fn sync_to(&self, pos: (u64, u64)) -> Result<()> {
+        let mut synced = self.synced.lock().unwrap();
+        if *synced >= pos {
+            return Ok(());
+        }
+        let (file, end) = {
+            let active = self.lock();
+            (active.file.clone(), active.position())
+        };
+        // Sealed files were synced when they were sealed.
+        file.sync_data()?;
+        *synced = end;
+        Ok(())
+    }
+
+    pub fn sync(&self) -> Result<()> {
+        let pos = self.lock().position();
+        self.sync_to(pos)
+    }
+
+    pub fn read(&self, file_seq: u64, offset: u64, len: u32) -> Result<Vec<u8>> {
+        let file = self
+            .files
+            .read()
+            .unwrap()
+            .get(&file_seq)
+            .cloned()
+            .ok_or_else(|| Error::Engine(format!("log file {} was purged", file_seq)))?;
+        let mut data = vec![0; len as usize];
+        file.read_exact_at(&mut data, offset)?;
+        Ok(data)
+    }
+
+    pub fn file_seqs(&self) -> Vec<u64> {
+        self.files.read().unwrap().keys().copied().collect()
+    }
+
+    /// Deletes the sealed files before `seq`, returning how many there were.
+    pub fn purge_to(&self, seq: u64) -> Result<usize> {
+        let active_seq = self.lock().seq;
+        let seq = seq.min(active_seq);
+        let purged: Vec<u64> = {
+            let mut files = self.files.write().unwrap();
+            let keep = files.split_off(&seq);
+            let purged = files.keys().copied().collect();
+            *files = keep;
+            purged
+        };
+        for &seq in &purged {
+            fs::remove_file(self.dir.join(file_name(seq)))?;
+        }
+        Ok(purged.len())
+    }
+
+    pub fn total_size(&self) -> Result<u64> {
+        let files = self.files.read().unwrap();
+        let mut size = 0;
+        for file in files.values() {
+            size += file.metadata()?.len();
+        }
+        Ok(size)
+    }
+}
+
+fn create_file(dir: &Path, seq: u64) -> Result<File> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(dir.join(file_name(seq)))?;
+    file.write_all_at(FILE_MAGIC, 0)?;
+    file.sync_all()?;
+    // Make the new name durable.
+    File::open(dir)?.sync_all()?;
+    Ok(file)
+}
+
+/// Passes the records of a file to `on_record`, and returns the length of its
+/// intact prefix if the rest is torn or corrupted.
+fn scan_records(
+    data: &[u8],
+    mut on_record: impl FnMut(u64, &[u8]) -> Result<()>,
+) -> Result<Option<u64>> {
+    if data.len() < FILE_MAGIC.len() || &data[..FILE_MAGIC.len()] != FILE_MAGIC {
+        return Ok(Some(0));
+    }
+    let mut offset = FILE_MAGIC.len();
+    while offset < data.len() {
+        let rest = &data[offset..];
+        let header = RECORD_HEADER_SIZE as usize;
+        if rest.len() < header {
+            return Ok(Some(offset as u64));
+        }
+        let checksum = u32::from_le_bytes(rest[..4].try_into().unwrap());
+        let len = u32::from_le_bytes(rest[4..8].try_into().unwrap()) as usize;
+        if rest.len() < header + len || crc32(&rest[header..header + len]) != checksum {
+            return Ok(Some(offset as u64));
+        }
+        // A record that passed its checksum but does not decode is not a torn
+        // write, so its error is returned as is.
+        on_record((offset + header) as u64, &rest[header..header + len])?;
+        offset += header + len;
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(dir: &Path, file_size: u64) -> (FilePipe, Vec<(u64, Vec<u8>)>) {
+        let mut records = Vec::new();
+        let pipe = FilePipe::open(dir, file_size, |seq, _, payload| {
+            records.push((seq, payload.to_vec()));
+            Ok(())
+        })
+        .unwrap();
+        (pipe, records)
+    }
+
+    #[test]
+    fn test_rotate_and_recover_torn_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let (pipe, records) = open(dir.path(), 64);
+        assert!(records.is_empty());
+        let mut written = Vec::new();
+        for i in 0..5u8 {
+            let mut active = pipe.lock();
+            written.push(pipe.append(&mut active, &[i; 20]).unwrap());
+        }
+        pipe.sync().unwrap();
+        // Two records fill a file.
+        assert_eq!(pipe.file_seqs(), vec![1, 2, 3]);
+        assert_eq!(
+            pipe.read(written[3].0, written[3].1, 20).unwrap(),
+            vec![3; 20]
+        );
+        drop(pipe);
+
+        let (pipe, records) = open(dir.path(), 64);
+        let seqs: Vec<u64> = records.iter().map(|(seq, _)| *seq).collect();
+        assert_eq!(seqs, vec![1, 1, 2, 2, 3]);
+        drop(pipe);
+
+        // Tear the last record: it is dropped and the file truncated.
+        let last = dir.path().join(file_name(3));
+        let len = fs::metadata(&last).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&last)
+            .unwrap()
+            .set_len(len - 3)
+            .unwrap();
+        let (pipe, records) = open(dir.path(), 64);
+        assert_eq!(records.len(), 4);
+        assert_eq!(fs::metadata(&last).unwrap().len(), FILE_MAGIC.len() as u64);
+        let mut active = pipe.lock();
+        assert_eq!(pipe.append(&mut active, b"next").unwrap(), (3, 16));
+        drop(active);
+
+        assert_eq!(pipe.purge_to(3).unwrap(), 2);
+        assert_eq!(pipe.file_seqs(), vec![3]);
+        assert!(pipe.read(1, 16, 20).is_err());
+        drop(pipe);
+
+        // A bad record in a sealed file is corruption.
+        let (pipe, _) = open(dir.path(), 16);
+        let mut active = pipe.lock();
+        pipe.append(&mut active, b"seal").unwrap();
+        drop(active);
+        drop(pipe);
+        let sealed = dir.path().join(file_name(3));
+        let mut data = fs::read(&sealed).unwrap();
+        data[RECORD_HEADER_SIZE as usize + FILE_MAGIC.len()] ^= 0xff;
+        fs::write(&sealed, data).unwrap();
+        assert!(FilePipe::open(dir.path(), 16, |_, _, _| Ok(())).is_err());
+    }
+}
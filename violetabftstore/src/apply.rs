@@ -0,0 +1,627 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Applying committed entries to the kv einstein_merkle_tree.
+//!
+//! The entries a store applies in one round go to one write
+//! alexandrov_poset_process, written before any callback is called. A read
+//! writes what was applied before it first, so that it sees it. What changes
+//! the store besides the brane of the peer, like the peer a split creates, is
+//! returned as an `ExecResult` and done once the alexandrov_poset_process is
+//! written. Observers see the writes applied between the two.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use fdb_traits::{
+    ImportExt, ImportMode, IngestExternalFileOptions, Iterable, Mutable, Peekable, WriteBatch,
+    WriteBatchExt, WriteOptions,
+};
+use soliton_lsm::{LsmEngine, LsmWriteBatch};
+use violetabft::{Codec, ConfChange, ConfChangeType, ConfState, Entry, EntryType};
+
+use crate::brane::{Brane, BraneLocalState, MergeState, Peer, PeerRole, PeerState};
+use crate::cmd::{
+    check_brane_epoch, AdminRequest, Callback, CmdResponse, Request, Response, VioletaBFTCmdRequest,
+};
+use crate::errors::{Error, Result};
+use crate::keys;
+use crate::observer::{AppliedCmd, ApplyObserver};
+use crate::peer::{check_request_keys, BranePeer};
+use crate::peer_storage::{compute_hash, load_brane_state, write_initial_states};
+
+/// What the store does once the alexandrov_poset_process of an applied command is
+/// written.
+pub(crate) enum ExecResult {
+    /// The brane was split; `right` is created unless its peer here was removed
+    /// already.
+    Split {
+        old_end: Vec<u8>,
+        right: Option<Brane>,
+    },
+    /// The log up to `to` can be deleted.
+    CompactLog { to: u64 },
+    /// The brane took over `source`, whose peer here is destroyed.
+    CommitMerge { old_end: Vec<u8>, source: Brane },
+    /// The peer was removed from its brane.
+    Destroy,
+}
+
+pub(crate) enum ApplyOutcome {
+    Applied(Option<ExecResult>),
+    /// The entry commits a merge whose source peer here has not applied the
+    /// log up to the prepared merge: `(source id, commit)`.
+    WaitMergeSource(u64, u64),
+}
+
+pub(crate) struct ApplyContext {
+    kv: LsmEngine,
+    namespaceds: BTreeSet<String>,
+    wb: LsmWriteBatch,
+    cbs: Vec<(Callback, Result<CmdResponse>)>,
+    /// The writes applied, if they are observed.
+    applied: Option<Vec<AppliedCmd>>,
+}
+
+impl ApplyContext {
+    pub fn new(kv: &LsmEngine, observed: bool) -> ApplyContext {
+        ApplyContext {
+            kv: kv.clone(),
+            namespaceds: kv.namespaced_names().into_iter().collect(),
+            wb: kv.write_alexandrov_poset_process(),
+            cbs: Vec::new(),
+            applied: observed.then(Vec::new),
+        }
+    }
+
+    pub fn wb(&mut self) -> &mut LsmWriteBatch {
+        &mut self.wb
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if !self.wb.is_empty() {
+            self.wb.write_opt(&WriteOptions::default())?;
+            self.wb.clear();
+        }
+        Ok(())
+    }
+
+    /// Writes everything applied, shows the writes to the `observers` of store
+    /// `store_id`, then calls the callbacks.
+    pub fn finish(mut self, store_id: u64, observers: &[Arc<dyn ApplyObserver>]) -> Result<()> {
+        self.flush()?;
+        if let Some(applied) = self.applied.filter(|a| !a.is_empty()) {
+            for o in observers {
+                o.on_applied(store_id, &self.kv, &applied);
+            }
+        }
+        for (cb, res) in self.cbs {
+            cb(res);
+        }
+        Ok(())
+    }
+}
+
+fn write_local_state(wb: &mut LsmWriteBatch, state: &BraneLocalState) -> Result<()> {
+    wb.put(&keys::brane_state_key(state.brane.id), &state.encode())?;
+    Ok(())
+}
+
+/// Checks what can make a command fail without effect: those that pass change
+/// the kv einstein_merkle_tree, and only fail if it does.
+fn check_cmd(
+    ctx: &ApplyContext,
+    peer: &BranePeer,
+    req: &VioletaBFTCmdRequest,
+    index: u64,
+) -> Result<()> {
+    let local_state = peer.storage().local_state();
+    let brane = &local_state.brane;
+    match local_state.state {
+        PeerState::Tombstone => return Err(Error::BraneNotFound(brane.id)),
+        PeerState::Merging if !matches!(req.admin, Some(AdminRequest::RollbackMerge { .. })) => {
+            return Err(Error::MergeInProgress(brane.id))
+        }
+        _ => {}
+    }
+    check_brane_epoch(req, brane)?;
+    for r in &req.requests {
+        check_request_keys(r, brane)?;
+        let namespaced = match r {
+            Request::Get { namespaced, .. }
+            | Request::Scan { namespaced, .. }
+            | Request::Put { namespaced, .. }
+            | Request::Delete { namespaced, .. }
+            | Request::DeleteRange { namespaced, .. }
+            | Request::IngestSst { namespaced, .. } => namespaced,
+        };
+        if !ctx.namespaceds.contains(namespaced) {
+            return Err(Error::Other(format!(
+                "causet_merge family {} not found",
+                namespaced
+            )));
+        }
+        // Removed once the brane applied it: the replica replays the entry
+        // after a restart, having ingested it before. A witness ingests
+        // nothing.
+        if let Request::IngestSst { sst, .. } = r {
+            if !peer.peer.is_witness && !sst.local_path.exists() {
+                return Err(Error::Other(format!(
+                    "external file {} not found",
+                    sst.local_path.display()
+                )));
+            }
+        }
+    }
+    let joint = !local_state.conf_state.voters_outgoing.is_empty();
+    match &req.admin {
+        Some(AdminRequest::Split {
+            split_key,
+            new_peer_ids,
+            ..
+        }) => {
+            if !brane.contains(split_key) || *split_key == brane.start_key {
+                return Err(Error::Other(format!(
+                    "invalid split key {:?} for brane {}",
+                    split_key, brane.id
+                )));
+            }
+            if new_peer_ids.len() != brane.peers.len() {
+                return Err(Error::Other(format!(
+                    "{} new peers for the {} peers of brane {}",
+                    new_peer_ids.len(),
+                    brane.peers.len(),
+                    brane.id
+                )));
+            }
+            if joint {
+                return Err(Error::Other(format!(
+                    "brane {} is in a joint configuration",
+                    brane.id
+                )));
+            }
+        }
+        Some(AdminRequest::CompactLog {
+            compact_index,
+            compact_term,
+        }) => {
+            if *compact_index >= index {
+                return Err(Error::Other(format!(
+                    "compact index {} is not applied",
+                    compact_index
+                )));
+            }
+            let storage = peer.storage();
+            if *compact_index > storage.apply_state().truncated_index
+                && violetabft::Storage::term(storage, *compact_index)? != *compact_term
+            {
+                return Err(Error::Other(format!(
+                    "entry {} is not of term {}",
+                    compact_index, compact_term
+                )));
+            }
+        }
+        Some(AdminRequest::PrepareMerge { target }) => {
+            if target.id == brane.id || joint {
+                return Err(Error::Other(format!(
+                    "brane {} can not be merged into {}",
+                    brane.id, target.id
+                )));
+            }
+        }
+        Some(AdminRequest::CommitMerge { source, .. }) => {
+            let adjacent = (!source.end_key.is_empty() && source.end_key == brane.start_key)
+                || (!brane.end_key.is_empty() && brane.end_key == source.start_key);
+            if !adjacent {
+                return Err(Error::Other(format!(
+                    "brane {} is not adjacent to {}",
+                    source.id, brane.id
+                )));
+            }
+        }
+        Some(AdminRequest::RollbackMerge { commit }) => {
+            if local_state.merge_state.as_ref().map(|m| m.commit) != Some(*commit) {
+                return Err(Error::Other(format!(
+                    "brane {} has no merge prepared at {}",
+                    brane.id, commit
+                )));
+            }
+        }
+        Some(AdminRequest::ChangePeer { changes }) => {
+            for (change_type, p) in changes {
+                check_peer_change(brane, *change_type, p)?;
+            }
+        }
+        Some(AdminRequest::CloseTs { .. }) | Some(AdminRequest::ComputeHash) | None => {}
+    }
+    Ok(())
+}
+
+/// Checks that a change of peers keeps a peer on its store and a witness a
+/// witness, gives a store one peer of the brane at most, and makes no witness
+/// a learner.
+fn check_peer_change(brane: &Brane, change_type: ConfChangeType, p: &Peer) -> Result<()> {
+    let err = |msg: &str| {
+        Err(Error::Other(format!(
+            "invalid change of peer {:?} of brane {}: {}",
+            p, brane.id, msg
+        )))
+    };
+    match brane.peer(p.id) {
+        Some(old) if old.store_id != p.store_id || old.is_witness != p.is_witness => {
+            err("the peer is on another store, or a witness only one of them")
+        }
+        None if change_type != ConfChangeType::RemoveNode
+            && brane.peer_on_store(p.store_id).is_some() =>
+        {
+            err("the store has a peer of the brane already")
+        }
+        _ if change_type == ConfChangeType::AddLearnerNode && p.is_witness => {
+            err("a witness can not be a learner")
+        }
+        _ => Ok(()),
+    }
+}
+
+fn exec_requests(
+    ctx: &mut ApplyContext,
+    brane: &Brane,
+    requests: Vec<Request>,
+) -> Result<CmdResponse> {
+    let mut resp = CmdResponse::default();
+    let brane_end = keys::data_end_key(&brane.end_key);
+    for r in requests {
+        let r = match r {
+            Request::Get {
+                namespaced,
+                soliton_id,
+            } => {
+                ctx.flush()?;
+                let v = ctx
+                    .kv
+                    .get_value_namespaced(&namespaced, &keys::data_key(&soliton_id))?;
+                Response::Get(v)
+            }
+            Request::Scan {
+                namespaced,
+                start_key,
+                end_key,
+                limit,
+            } => {
+                ctx.flush()?;
+                let start = keys::data_key(&start_key);
+                let end = if end_key.is_empty() {
+                    brane_end.clone()
+                } else {
+                    keys::data_key(&end_key).min(brane_end.clone())
+                };
+                let mut pairs = Vec::new();
+                if start < end {
+                    ctx.kv
+                        .scan_namespaced(&namespaced, &start, &end, true, |k, v| {
+                            pairs.push((keys::origin_key(k).to_vec(), v.to_vec()));
+                            Ok(limit == 0 || pairs.len() < limit)
+                        })?;
+                }
+                Response::Scan(pairs)
+            }
+            Request::Put {
+                namespaced,
+                soliton_id,
+                causet_locale,
+            } => {
+                ctx.wb
+                    .put_namespaced(&namespaced, &keys::data_key(&soliton_id), &causet_locale)?;
+                Response::Put
+            }
+            Request::Delete {
+                namespaced,
+                soliton_id,
+            } => {
+                ctx.wb
+                    .delete_namespaced(&namespaced, &keys::data_key(&soliton_id))?;
+                Response::Delete
+            }
+            Request::DeleteRange {
+                namespaced,
+                start_key,
+                end_key,
+            } => {
+                let end = if end_key.is_empty() {
+                    brane_end.clone()
+                } else {
+                    keys::data_key(&end_key)
+                };
+                ctx.wb
+                    .delete_range_namespaced(&namespaced, &keys::data_key(&start_key), &end)?;
+                Response::DeleteRange
+            }
+            Request::IngestSst { namespaced, sst } => {
+                // Shadows what was applied before it, and is shadowed by what is
+                // after.
+                ctx.flush()?;
+                let opts = IngestExternalFileOptions {
+                    mode: ImportMode::Overwrite,
+                    verify_checksum: true,
+                };
+                ctx.kv
+                    .ingest_external_file_namespaced(&namespaced, &opts, &[sst])?;
+                Response::IngestSst
+            }
+        };
+        resp.responses.push(r);
+    }
+    Ok(resp)
+}
+
+fn exec_admin(
+    ctx: &mut ApplyContext,
+    peer: &mut BranePeer,
+    admin: AdminRequest,
+    index: u64,
+) -> Result<(CmdResponse, Option<ExecResult>)> {
+    let mut resp = CmdResponse::default();
+    let local_state = peer.storage_mut().local_state_mut();
+    let exec = match admin {
+        AdminRequest::Split {
+            split_key,
+            new_brane_id,
+            new_peer_ids,
+        } => {
+            let brane = &mut local_state.brane;
+            let old_end = brane.end_key.clone();
+            brane.brane_epoch.version += 1;
+            let right = Brane {
+                id: new_brane_id,
+                start_key: split_key.clone(),
+                end_key: brane.end_key.clone(),
+                brane_epoch: brane.brane_epoch,
+                peers: brane
+                    .peers
+                    .iter()
+                    .zip(new_peer_ids)
+                    .map(|(p, id)| Peer { id, ..*p })
+                    .collect(),
+            };
+            brane.end_key = split_key;
+            // The new peers have the roles of the peers on their stores.
+            let new_id =
+                |id: &u64| right.peers[brane.peers.iter().position(|p| p.id == *id).unwrap()].id;
+            let cs = &local_state.conf_state;
+            let right_conf = ConfState {
+                voters: cs.voters.iter().map(new_id).collect(),
+                learners: cs.learners.iter().map(new_id).collect(),
+                ..Default::default()
+            };
+            write_local_state(&mut ctx.wb, local_state)?;
+            resp.branes = vec![local_state.brane.clone(), right.clone()];
+            // A peer removed from the new brane already must not come back.
+            let right = match load_brane_state(&ctx.kv, right.id)? {
+                Some(_) => None,
+                None => {
+                    write_initial_states(&mut ctx.wb, &right, right_conf)?;
+                    Some(right)
+                }
+            };
+            Some(ExecResult::Split { old_end, right })
+        }
+        AdminRequest::CompactLog {
+            compact_index,
+            compact_term,
+        } => {
+            let apply_state = peer.storage_mut().apply_state_mut();
+            if compact_index <= apply_state.truncated_index {
+                None
+            } else {
+                apply_state.truncated_index = compact_index;
+                apply_state.truncated_term = compact_term;
+                Some(ExecResult::CompactLog { to: compact_index })
+            }
+        }
+        AdminRequest::PrepareMerge { target } => {
+            local_state.state = PeerState::Merging;
+            local_state.merge_state = Some(MergeState {
+                target,
+                commit: index,
+            });
+            let epoch = &mut local_state.brane.brane_epoch;
+            epoch.conf_ver += 1;
+            epoch.version += 1;
+            write_local_state(&mut ctx.wb, local_state)?;
+            resp.branes = vec![local_state.brane.clone()];
+            None
+        }
+        AdminRequest::CommitMerge { source, .. } => {
+            let brane = &mut local_state.brane;
+            let old_end = brane.end_key.clone();
+            if !brane.end_key.is_empty() && brane.end_key == source.start_key {
+                brane.end_key = source.end_key.clone();
+            } else {
+                brane.start_key = source.start_key.clone();
+            }
+            brane.brane_epoch.version =
+                brane.brane_epoch.version.max(source.brane_epoch.version) + 1;
+            write_local_state(&mut ctx.wb, local_state)?;
+            // The data of the source is the brane's now.
+            let tombstone = BraneLocalState {
+                brane: source.clone(),
+                state: PeerState::Tombstone,
+                ..Default::default()
+            };
+            write_local_state(&mut ctx.wb, &tombstone)?;
+            ctx.wb.delete(&keys::apply_state_key(source.id))?;
+            resp.branes = vec![local_state.brane.clone()];
+            Some(ExecResult::CommitMerge { old_end, source })
+        }
+        AdminRequest::RollbackMerge { .. } => {
+            local_state.state = PeerState::Normal;
+            local_state.merge_state = None;
+            local_state.brane.brane_epoch.version += 1;
+            write_local_state(&mut ctx.wb, local_state)?;
+            resp.branes = vec![local_state.brane.clone()];
+            None
+        }
+        AdminRequest::CloseTs { ts } => {
+            peer.closed_ts = peer.closed_ts.max(ts);
+            None
+        }
+        AdminRequest::ComputeHash => {
+            if !peer.peer.is_witness {
+                ctx.flush()?;
+                let hash = compute_hash(&ctx.kv, peer.brane())?;
+                peer.consistency_hash = Some((index, hash));
+            }
+            None
+        }
+        AdminRequest::ChangePeer { .. } => unreachable!("proposed as a configuration change"),
+    };
+    Ok((resp, exec))
+}
+
+/// Records the configuration `cs` a change led to. `req` is `None` for the change a leader
+/// proposes to leave a joint configuration.
+fn exec_conf_change(
+    ctx: &mut ApplyContext,
+    peer: &mut BranePeer,
+    cs: ConfState,
+    req: Option<VioletaBFTCmdRequest>,
+) -> Result<(CmdResponse, Option<ExecResult>)> {
+    let self_id = peer.peer.id;
+    let local_state = peer.storage_mut().local_state_mut();
+    if let Some(AdminRequest::ChangePeer { changes }) = req.and_then(|r| r.admin) {
+        for (change_type, p) in changes {
+            if change_type != ConfChangeType::RemoveNode && local_state.brane.peer(p.id).is_none() {
+                local_state.brane.peers.push(p);
+            }
+        }
+    }
+    let in_conf = |id: &u64| {
+        cs.voters.contains(id)
+            || cs.learners.contains(id)
+            || cs.voters_outgoing.contains(id)
+            || cs.learners_next.contains(id)
+    };
+    local_state.brane.peers.retain(|p| in_conf(&p.id));
+    // A voter being demoted still votes until the joint configuration is left.
+    for p in &mut local_state.brane.peers {
+        p.role = if cs.learners.contains(&p.id) {
+            PeerRole::Learner
+        } else {
+            PeerRole::Voter
+        };
+    }
+    local_state.brane.brane_epoch.conf_ver += 1;
+    let removed = !in_conf(&self_id);
+    local_state.conf_state = cs;
+    write_local_state(&mut ctx.wb, local_state)?;
+    let resp = CmdResponse {
+        branes: vec![local_state.brane.clone()],
+        ..Default::default()
+    };
+    if let Some(p) = local_state.brane.peer(self_id) {
+        // Promoted or demoted.
+        peer.peer = p;
+    }
+    Ok((resp, removed.then_some(ExecResult::Destroy)))
+}
+
+/// Serves `req`, which only reads, from what `peer` applied, outside of the log.
+pub(crate) fn exec_read(
+    kv: &LsmEngine,
+    peer: &BranePeer,
+    req: VioletaBFTCmdRequest,
+) -> Result<CmdResponse> {
+    if peer.peer.is_witness {
+        return Err(Error::IsWitness(peer.brane_id()));
+    }
+    let mut ctx = ApplyContext::new(kv, false);
+    check_cmd(&ctx, peer, &req, peer.storage().applied_index())?;
+    exec_requests(&mut ctx, peer.brane(), req.requests)
+}
+
+fn source_ready(peers: &BTreeMap<u64, BranePeer>, source_id: u64, commit: u64) -> bool {
+    // Without a peer here, the source was merged already, before a restart.
+    peers
+        .get(&source_id)
+        .is_none_or(|p| p.storage().applied_index() >= commit)
+}
+
+/// Applies a committed entry of `peer`. `peers` are the other peers of the
+/// store.
+pub(crate) fn apply_entry(
+    ctx: &mut ApplyContext,
+    peers: &BTreeMap<u64, BranePeer>,
+    peer: &mut BranePeer,
+    entry: &Entry,
+) -> Result<ApplyOutcome> {
+    if entry.data.is_empty() {
+        // The first entry of a leader: the proposals before it were lost.
+        peer.take_callback(entry.index, entry.term);
+        return Ok(ApplyOutcome::Applied(None));
+    }
+    let (req, cc) = match entry.entry_type {
+        EntryType::Normal => (Some(VioletaBFTCmdRequest::decode(&entry.data)?), None),
+        EntryType::ConfChange => {
+            let cc = ConfChange::decode(&entry.data)?;
+            let req = if entry.context.is_empty() {
+                None
+            } else {
+                Some(VioletaBFTCmdRequest::decode(&entry.context)?)
+            };
+            (req, Some(cc))
+        }
+    };
+    if let Some(AdminRequest::CommitMerge { source, commit }) =
+        req.as_ref().and_then(|r| r.admin.as_ref())
+    {
+        if !source_ready(peers, source.id, *commit) {
+            return Ok(ApplyOutcome::WaitMergeSource(source.id, *commit));
+        }
+    }
+    let cb = peer.take_callback(entry.index, entry.term);
+    let checked = match &req {
+        Some(req) => check_cmd(ctx, peer, req, entry.index),
+        None => Ok(()),
+    };
+    let res = match (checked, cc, req) {
+        (Err(e), ..) => Err(e),
+        // An invalid change has no effect.
+        (Ok(()), Some(cc), req) => match peer.raw_node.apply_conf_change(&cc) {
+            Ok(cs) => Ok(exec_conf_change(ctx, peer, cs, req)?),
+            Err(e) => Err(e.into()),
+        },
+        (Ok(()), None, Some(req)) => Ok(match req.admin {
+            Some(admin) => exec_admin(ctx, peer, admin, entry.index)?,
+            // A witness keeps the log only.
+            None if peer.peer.is_witness => (CmdResponse::default(), None),
+            None => {
+                let writes: Option<Vec<Request>> = ctx.applied.as_ref().map(|_| {
+                    req.requests
+                        .iter()
+                        .filter(|r| !r.is_read())
+                        .cloned()
+                        .collect()
+                });
+                let resp = exec_requests(ctx, peer.brane(), req.requests)?;
+                if let (Some(applied), Some(requests)) = (&mut ctx.applied, writes) {
+                    if !requests.is_empty() {
+                        applied.push(AppliedCmd {
+                            brane_id: peer.brane().id,
+                            index: entry.index,
+                            requests,
+                        });
+                    }
+                }
+                (resp, None)
+            }
+        }),
+        (Ok(()), None, None) => unreachable!("a normal entry holds a command"),
+    };
+    let (resp, exec) = match res {
+        Ok((resp, exec)) => (Ok(resp), exec),
+        Err(e) => (Err(e), None),
+    };
+    if let Some(cb) = cb {
+        ctx.cbs.push((cb, resp));
+    }
+    Ok(ApplyOutcome::Applied(exec))
+}
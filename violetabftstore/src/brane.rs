@@ -0,0 +1,333 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The metadata of branes, and the states a store persists for its peers.
+
+use violetabft::codec::{get_bytes, get_u8, get_varint, put_bytes, put_varint};
+use violetabft::{Codec, ConfState, Error as CodecError, Result as CodecResult};
+
+use crate::errors::{Error, Result};
+
+/// Changes whenever the range of a brane (`version`) or its peers (`conf_ver`)
+/// do, so that a request or message routed by outdated metadata can be told apart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BraneEpoch {
+    pub conf_ver: u64,
+    pub version: u64,
+}
+
+impl BraneEpoch {
+    /// Whether `self` is older than `other` in either respect.
+    pub fn is_stale(&self, other: &BraneEpoch) -> bool {
+        self.conf_ver < other.conf_ver || self.version < other.version
+    }
+}
+
+/// What a replica is in the VioletaBFT group of its brane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PeerRole {
+    #[default]
+    Voter,
+    /// Receives the log but does not vote, until it is promoted to a voter.
+    Learner,
+}
+
+/// A replica of a brane; its id is its id in the VioletaBFT group.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Peer {
+    pub id: u64,
+    pub store_id: u64,
+    pub role: PeerRole,
+    /// A witness stores the log but not the data: it votes, but neither leads
+    /// nor serves reads.
+    pub is_witness: bool,
+}
+
+impl Peer {
+    /// A voter holding the data.
+    pub fn new(id: u64, store_id: u64) -> Peer {
+        Peer {
+            id,
+            store_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_learner(&self) -> bool {
+        self.role == PeerRole::Learner
+    }
+
+    /// Whether the peer may become the leader of its brane.
+    pub fn can_lead(&self) -> bool {
+        self.role == PeerRole::Voter && !self.is_witness
+    }
+}
+
+const PEER_LEARNER: u8 = 1;
+const PEER_WITNESS: u8 = 2;
+
+pub(crate) fn put_peer(buf: &mut Vec<u8>, peer: &Peer) {
+    put_varint(buf, peer.id);
+    put_varint(buf, peer.store_id);
+    let mut flags = 0;
+    if peer.is_learner() {
+        flags |= PEER_LEARNER;
+    }
+    if peer.is_witness {
+        flags |= PEER_WITNESS;
+    }
+    buf.push(flags);
+}
+
+pub(crate) fn get_peer(buf: &mut &[u8]) -> CodecResult<Peer> {
+    let id = get_varint(buf)?;
+    let store_id = get_varint(buf)?;
+    let flags = get_u8(buf)?;
+    Ok(Peer {
+        id,
+        store_id,
+        role: match flags & PEER_LEARNER {
+            0 => PeerRole::Voter,
+            _ => PeerRole::Learner,
+        },
+        is_witness: flags & PEER_WITNESS != 0,
+    })
+}
+
+/// A range of soliton_ids `[start_key, end_key)`, replicated by its own VioletaBFT
+/// group. An empty `end_key` is the end of the soliton_id space.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Brane {
+    pub id: u64,
+    pub start_key: Vec<u8>,
+    pub end_key: Vec<u8>,
+    pub brane_epoch: BraneEpoch,
+    pub peers: Vec<Peer>,
+}
+
+impl Brane {
+    pub fn contains(&self, soliton_id: &[u8]) -> bool {
+        soliton_id >= self.start_key.as_slice()
+            && (self.end_key.is_empty() || soliton_id < self.end_key.as_slice())
+    }
+
+    pub fn check_key(&self, soliton_id: &[u8]) -> Result<()> {
+        if self.contains(soliton_id) {
+            return Ok(());
+        }
+        Err(Error::KeyNotInBrane {
+            soliton_id: soliton_id.to_vec(),
+            brane_id: self.id,
+            start_key: self.start_key.clone(),
+            end_key: self.end_key.clone(),
+        })
+    }
+
+    /// Whether the ranges of the branes overlap.
+    pub fn overlaps(&self, other: &Brane) -> bool {
+        (self.end_key.is_empty() || other.start_key < self.end_key)
+            && (other.end_key.is_empty() || self.start_key < other.end_key)
+    }
+
+    pub fn peer(&self, peer_id: u64) -> Option<Peer> {
+        self.peers.iter().find(|p| p.id == peer_id).copied()
+    }
+
+    pub fn peer_on_store(&self, store_id: u64) -> Option<Peer> {
+        self.peers.iter().find(|p| p.store_id == store_id).copied()
+    }
+
+    /// The configuration the roles of the peers make.
+    pub fn conf_state(&self) -> ConfState {
+        let (learners, voters) = self.peers.iter().partition(|p| p.is_learner());
+        let ids = |peers: Vec<&Peer>| peers.into_iter().map(|p| p.id).collect();
+        ConfState {
+            voters: ids(voters),
+            learners: ids(learners),
+            ..Default::default()
+        }
+    }
+}
+
+impl Codec for Brane {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_varint(buf, self.id);
+        put_bytes(buf, &self.start_key);
+        put_bytes(buf, &self.end_key);
+        put_varint(buf, self.brane_epoch.conf_ver);
+        put_varint(buf, self.brane_epoch.version);
+        put_varint(buf, self.peers.len() as u64);
+        for p in &self.peers {
+            put_peer(buf, p);
+        }
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<Brane> {
+        let id = get_varint(buf)?;
+        let start_key = get_bytes(buf)?.to_vec();
+        let end_key = get_bytes(buf)?.to_vec();
+        let brane_epoch = BraneEpoch {
+            conf_ver: get_varint(buf)?,
+            version: get_varint(buf)?,
+        };
+        let n = get_varint(buf)?;
+        let mut peers = Vec::new();
+        for _ in 0..n {
+            peers.push(get_peer(buf)?);
+        }
+        Ok(Brane {
+            id,
+            start_key,
+            end_key,
+            brane_epoch,
+            peers,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PeerState {
+    #[default]
+    Normal,
+    /// A merge into another brane was prepared: the brane takes no more commands
+    /// but the merge's.
+    Merging,
+    /// The peer was removed or merged away.
+    Tombstone,
+}
+
+/// A prepared merge of a brane into `target`, committed at `commit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeState {
+    pub target: Brane,
+    pub commit: u64,
+}
+
+/// What a store knows of one of its peers' brane, as of the applied index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BraneLocalState {
+    pub brane: Brane,
+    pub state: PeerState,
+    pub merge_state: Option<MergeState>,
+    /// The configuration of the VioletaBFT group, which `brane.peers` describe.
+    pub conf_state: ConfState,
+}
+
+impl Codec for BraneLocalState {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        self.brane.encode_to(buf);
+        buf.push(match self.state {
+            PeerState::Normal => 0,
+            PeerState::Merging => 1,
+            PeerState::Tombstone => 2,
+        });
+        match &self.merge_state {
+            Some(m) => {
+                buf.push(1);
+                m.target.encode_to(buf);
+                put_varint(buf, m.commit);
+            }
+            None => buf.push(0),
+        }
+        self.conf_state.encode_to(buf);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<BraneLocalState> {
+        let brane = Brane::decode_from(buf)?;
+        let state = match get_u8(buf)? {
+            0 => PeerState::Normal,
+            1 => PeerState::Merging,
+            2 => PeerState::Tombstone,
+            t => return Err(CodecError::Corruption(format!("unknown peer state {}", t))),
+        };
+        let merge_state = match get_u8(buf)? {
+            0 => None,
+            _ => Some(MergeState {
+                target: Brane::decode_from(buf)?,
+                commit: get_varint(buf)?,
+            }),
+        };
+        Ok(BraneLocalState {
+            brane,
+            state,
+            merge_state,
+            conf_state: ConfState::decode_from(buf)?,
+        })
+    }
+}
+
+/// How far a peer applied its log, and where the log was truncated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ApplyState {
+    pub applied_index: u64,
+    pub truncated_index: u64,
+    pub truncated_term: u64,
+}
+
+impl Codec for ApplyState {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_varint(buf, self.applied_index);
+        put_varint(buf, self.truncated_index);
+        put_varint(buf, self.truncated_term);
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<ApplyState> {
+        Ok(ApplyState {
+            applied_index: get_varint(buf)?,
+            truncated_index: get_varint(buf)?,
+            truncated_term: get_varint(buf)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brane(start: &[u8], end: &[u8]) -> Brane {
+        Brane {
+            id: 1,
+            start_key: start.to_vec(),
+            end_key: end.to_vec(),
+            brane_epoch: BraneEpoch {
+                conf_ver: 2,
+                version: 3,
+            },
+            peers: vec![Peer::new(4, 1), Peer::new(5, 2)],
+        }
+    }
+
+    #[test]
+    fn test_brane() {
+        let b = brane(b"b", b"d");
+        assert!(b.contains(b"b") && b.contains(b"c\xff"));
+        assert!(!b.contains(b"a") && !b.contains(b"d"));
+        assert!(b.check_key(b"d").is_err());
+        assert!(brane(b"", b"").contains(b"anything"));
+        assert!(b.overlaps(&brane(b"c", b"")));
+        assert!(!b.overlaps(&brane(b"d", b"")));
+        assert!(!b.overlaps(&brane(b"", b"b")));
+        assert_eq!(b.peer_on_store(2), Some(Peer::new(5, 2)));
+
+        let old = b.brane_epoch;
+        let mut new = old;
+        new.version += 1;
+        assert!(old.is_stale(&new) && !new.is_stale(&old));
+
+        let state = BraneLocalState {
+            brane: b.clone(),
+            state: PeerState::Merging,
+            merge_state: Some(MergeState {
+                target: brane(b"d", b""),
+                commit: 9,
+            }),
+            conf_state: ConfState::with_voters(vec![4, 5]),
+        };
+        assert_eq!(BraneLocalState::decode(&state.encode()).unwrap(), state);
+        let apply = ApplyState {
+            applied_index: 7,
+            truncated_index: 5,
+            truncated_term: 5,
+        };
+        assert_eq!(ApplyState::decode(&apply.encode()).unwrap(), apply);
+    }
+}
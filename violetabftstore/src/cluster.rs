@@ -0,0 +1,1771 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A cluster of stores in one process, driven deterministically: messages are
+//! queued and delivered in order, and time passes only when it is ticked.
+//!
+//! It plays the client, which routes by its `BraneCache` and learns from the
+//! errors of the stores, and the placement driver, which knows every store and
+//! starts splits, merges and changes of peers.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+use fdb_traits::{ExternalSstFileInfo, NAMESPACED_DEFAULT};
+use soliton_lsm::{LsmEngine, LsmOptions};
+use violetabft::ConfChangeType;
+use violetabft_log_engine::{VioletaBFTLogConfig, VioletaBFTLogEngine};
+
+use crate::brane::{Brane, Peer, PeerRole};
+use crate::cmd::{
+    AdminRequest, CmdHeader, CmdResponse, ReadConsistency, Request, Response, VioletaBFTCmdRequest,
+};
+use crate::config::StoreConfig;
+use crate::errors::{Error, Result};
+use crate::keys;
+use crate::observer::ApplyObserver;
+use crate::router::BraneCache;
+use crate::store::{bootstrap_brane, IdAllocator, Store};
+use crate::transport::{Transport, VioletaBFTMessage};
+
+/// The column families of the kv einstein_merkle_trees.
+const NAMESPACEDS: [&str; 3] = [NAMESPACED_DEFAULT, "lock", "write"];
+/// The rounds of `settle` after which the cluster is taken to be livelocked.
+const MAX_SETTLE_ROUNDS: usize = 10_000;
+/// The ticks a proposal, an election or a replica is waited for.
+const WAIT_TICKS: usize = 100;
+/// The times a request is routed again before it fails.
+const MAX_RETRIES: usize = 30;
+
+#[derive(Default)]
+struct Network {
+    queue: VecDeque<VioletaBFTMessage>,
+    /// The pairs of stores that can not reach each other, in both directions.
+    cut: HashSet<(u64, u64)>,
+}
+
+pub struct ClusterTransport {
+    store_id: u64,
+    network: Arc<Mutex<Network>>,
+}
+
+impl Transport for ClusterTransport {
+    fn send(&mut self, msg: VioletaBFTMessage) -> Result<()> {
+        let mut network = self.network.lock().unwrap();
+        if network.cut.contains(&(self.store_id, msg.to_peer.store_id)) {
+            return Err(Error::Other(format!(
+                "store {} is unreachable from store {}",
+                msg.to_peer.store_id, self.store_id
+            )));
+        }
+        network.queue.push_back(msg);
+        Ok(())
+    }
+}
+
+/// What a consistency check of a brane found.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsistencyCheck {
+    /// The index the replicas computed their checksums at.
+    pub index: u64,
+    /// The checksum of the data the replicas should have.
+    pub hash: u32,
+    /// The replicas whose data differs.
+    pub corrupt: Vec<Peer>,
+    /// The replicas that did not apply the log up to the check in time,
+    /// those on stopped stores among them.
+    pub lagging: Vec<Peer>,
+}
+
+/// Allocates ids in sequence.
+#[derive(Debug)]
+pub struct SeqIdAllocator(AtomicU64);
+
+impl SeqIdAllocator {
+    /// Allocates ids after `last`.
+    pub fn new(last: u64) -> SeqIdAllocator {
+        SeqIdAllocator(AtomicU64::new(last))
+    }
+}
+
+impl IdAllocator for SeqIdAllocator {
+    fn alloc_id(&self) -> Result<u64> {
+        Ok(self.0.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+}
+
+pub struct Cluster {
+    dir: PathBuf,
+    cfg: StoreConfig,
+    store_ids: Vec<u64>,
+    /// The running stores; a stopped one receives no messages.
+    stores: BTreeMap<u64, Store<ClusterTransport>>,
+    network: Arc<Mutex<Network>>,
+    id_allocator: Arc<dyn IdAllocator>,
+    cache: BraneCache,
+    ticks: u64,
+    /// Observers of every store, started or to be.
+    observers: Vec<Arc<dyn ApplyObserver>>,
+    /// The learners `tick` promotes once they caught up, with their brane.
+    promotions: Vec<(u64, Peer)>,
+}
+
+impl Cluster {
+    /// Bootstraps stores `1..=store_count` under `dir`, with one brane over all
+    /// soliton_ids replicated on every store, and waits for it to elect a leader.
+    pub fn new(dir: &Path, store_count: u64, cfg: StoreConfig) -> Result<Cluster> {
+        let id_allocator = Arc::new(SeqIdAllocator::new(store_count));
+        Cluster::with_id_allocator(dir, store_count, cfg, id_allocator)
+    }
+
+    /// Like `new`, with the ids of branes and peers allocated by `id_allocator`.
+    pub fn with_id_allocator(
+        dir: &Path,
+        store_count: u64,
+        cfg: StoreConfig,
+        id_allocator: Arc<dyn IdAllocator>,
+    ) -> Result<Cluster> {
+        let mut cluster = Cluster::empty(dir, (1..=store_count).collect(), cfg, id_allocator);
+        let mut brane = Brane {
+            id: cluster.id_allocator.alloc_id()?,
+            ..Default::default()
+        };
+        for &store_id in &cluster.store_ids {
+            brane
+                .peers
+                .push(Peer::new(cluster.id_allocator.alloc_id()?, store_id));
+        }
+        brane.brane_epoch.conf_ver = 1;
+        brane.brane_epoch.version = 1;
+        for store_id in cluster.store_ids.clone() {
+            let (kv, log) = cluster.open_engines(store_id)?;
+            bootstrap_brane(&kv, &log, &brane)?;
+            let store = cluster.open_store(store_id, kv, log)?;
+            cluster.stores.insert(store_id, store);
+        }
+        cluster.wait_leader(brane.id)?;
+        Ok(cluster)
+    }
+
+    /// Opens the stores of a cluster bootstrapped under `dir` before, and waits
+    /// for every brane to elect a leader. `id_allocator` must go on from the
+    /// ids allocated then.
+    pub fn open(
+        dir: &Path,
+        cfg: StoreConfig,
+        id_allocator: Arc<dyn IdAllocator>,
+    ) -> Result<Cluster> {
+        let mut store_ids = Vec::new();
+        for entry in fs::read_dir(dir).map_err(|e| Error::Engine(e.into()))? {
+            let name = entry.map_err(|e| Error::Engine(e.into()))?.file_name();
+            let id = name.to_str().and_then(|n| n.strip_prefix("store_"));
+            if let Some(id) = id.and_then(|id| id.parse().ok()) {
+                store_ids.push(id);
+            }
+        }
+        if store_ids.is_empty() {
+            return Err(Error::Other(format!("no store under {}", dir.display())));
+        }
+        store_ids.sort_unstable();
+        let mut cluster = Cluster::empty(dir, store_ids, cfg, id_allocator);
+        for store_id in cluster.store_ids.clone() {
+            cluster.start_store(store_id)?;
+        }
+        for brane in cluster.branes() {
+            cluster.wait_leader(brane.id)?;
+        }
+        Ok(cluster)
+    }
+
+    fn empty(
+        dir: &Path,
+        store_ids: Vec<u64>,
+        cfg: StoreConfig,
+        id_allocator: Arc<dyn IdAllocator>,
+    ) -> Cluster {
+        Cluster {
+            dir: dir.to_owned(),
+            cfg,
+            store_ids,
+            stores: BTreeMap::new(),
+            network: Arc::default(),
+            id_allocator,
+            cache: BraneCache::new(),
+            ticks: 0,
+            observers: Vec::new(),
+            promotions: Vec::new(),
+        }
+    }
+
+    fn open_engines(&self, store_id: u64) -> Result<(LsmEngine, VioletaBFTLogEngine)> {
+        let dir = self.dir.join(format!("store_{}", store_id));
+        let opts = LsmOptions {
+            namespaceds: NAMESPACEDS[1..].iter().map(|n| n.to_string()).collect(),
+            ..Default::default()
+        };
+        let kv = LsmEngine::open(dir.join("kv"), opts)?;
+        let log_dir = dir.join("violetabft");
+        let log = VioletaBFTLogEngine::open(VioletaBFTLogConfig::new(
+            log_dir.to_string_lossy().into_owned(),
+        ))?;
+        Ok((kv, log))
+    }
+
+    fn open_store(
+        &self,
+        store_id: u64,
+        kv: LsmEngine,
+        log: VioletaBFTLogEngine,
+    ) -> Result<Store<ClusterTransport>> {
+        let trans = ClusterTransport {
+            store_id,
+            network: self.network.clone(),
+        };
+        let mut store = Store::open(
+            store_id,
+            self.cfg.clone(),
+            kv,
+            log,
+            trans,
+            self.id_allocator.clone(),
+        )?;
+        for o in &self.observers {
+            store.add_observer(o.clone());
+        }
+        Ok(store)
+    }
+
+    pub fn store(&self, store_id: u64) -> Option<&Store<ClusterTransport>> {
+        self.stores.get(&store_id)
+    }
+
+    pub fn store_mut(&mut self, store_id: u64) -> Option<&mut Store<ClusterTransport>> {
+        self.stores.get_mut(&store_id)
+    }
+
+    /// Shows `observer` what every store applies from now on, and those started
+    /// later.
+    pub fn add_observer(&mut self, observer: Arc<dyn ApplyObserver>) {
+        for store in self.stores.values_mut() {
+            store.add_observer(observer.clone());
+        }
+        self.observers.push(observer);
+    }
+
+    pub fn store_ids(&self) -> &[u64] {
+        &self.store_ids
+    }
+
+    /// Starts store `store_id` with no branes; it gets peers once they are added
+    /// on it.
+    pub fn add_store(&mut self, store_id: u64) -> Result<()> {
+        if self.store_ids.contains(&store_id) {
+            return Err(Error::Other(format!("store {} exists already", store_id)));
+        }
+        self.store_ids.push(store_id);
+        self.start_store(store_id)
+    }
+
+    /// Handles the readies of the stores and delivers their messages until
+    /// nothing is left to do.
+    pub fn settle(&mut self) -> Result<()> {
+        for _ in 0..MAX_SETTLE_ROUNDS {
+            let mut progress = false;
+            for store in self.stores.values_mut() {
+                progress |= store.handle_ready()?;
+            }
+            let msgs: Vec<_> = self.network.lock().unwrap().queue.drain(..).collect();
+            for msg in msgs {
+                progress = true;
+                if let Some(store) = self.stores.get_mut(&msg.to_peer.store_id) {
+                    store.step(msg)?;
+                }
+            }
+            if !progress {
+                return Ok(());
+            }
+        }
+        Err(Error::Other("the cluster did not settle".to_owned()))
+    }
+
+    fn tick_stores(&mut self) -> Result<()> {
+        for store in self.stores.values_mut() {
+            store.tick()?;
+        }
+        self.settle()
+    }
+
+    /// Ticks every store once, promotes the learners added to be promoted
+    /// that caught up, merges small branes every `merge_check_ticks`, and
+    /// repairs inconsistent replicas every `consistency_check_ticks`.
+    pub fn tick(&mut self) -> Result<()> {
+        self.tick_stores()?;
+        self.promote_caught_up_learners();
+        self.ticks += 1;
+        if self.cfg.merge_check_ticks > 0 && self.ticks.is_multiple_of(self.cfg.merge_check_ticks) {
+            self.check_merge()?;
+        }
+        if self.cfg.consistency_check_ticks > 0
+            && self.ticks.is_multiple_of(self.cfg.consistency_check_ticks)
+        {
+            self.repair_inconsistent()?;
+        }
+        Ok(())
+    }
+
+    /// The leader of the brane with the highest term among the running stores.
+    pub fn leader(&self, brane_id: u64) -> Option<Peer> {
+        self.stores
+            .values()
+            .filter_map(|s| s.peer(brane_id))
+            .filter(|p| p.is_leader())
+            .max_by_key(|p| p.term())
+            .map(|p| p.peer)
+    }
+
+    pub fn wait_leader(&mut self, brane_id: u64) -> Result<Peer> {
+        for _ in 0..WAIT_TICKS {
+            if let Some(leader) = self.leader(brane_id) {
+                return Ok(leader);
+            }
+            self.tick_stores()?;
+        }
+        Err(Error::Other(format!(
+            "brane {} elected no leader",
+            brane_id
+        )))
+    }
+
+    /// The newest brane holding `soliton_id`, as the running stores know it.
+    pub fn lookup_brane(&self, soliton_id: &[u8]) -> Option<Brane> {
+        self.stores
+            .values()
+            .filter_map(|s| s.brane_for_key(soliton_id))
+            .max_by_key(|b| b.brane_epoch.version)
+            .cloned()
+    }
+
+    /// The newest brane with the id, as the running stores know it.
+    pub fn get_brane(&self, brane_id: u64) -> Option<Brane> {
+        self.stores
+            .values()
+            .filter_map(|s| s.peer(brane_id))
+            .filter(|p| p.is_initialized())
+            .map(|p| p.brane())
+            .max_by_key(|b| (b.brane_epoch.version, b.brane_epoch.conf_ver))
+            .cloned()
+    }
+
+    /// Every brane, in the order of their ranges.
+    pub fn branes(&self) -> Vec<Brane> {
+        let mut branes: BTreeMap<u64, Brane> = BTreeMap::new();
+        for brane in self.stores.values().flat_map(|s| s.branes()) {
+            match branes.get(&brane.id) {
+                Some(b) if !b.brane_epoch.is_stale(&brane.brane_epoch) => {}
+                _ => {
+                    branes.insert(brane.id, brane);
+                }
+            }
+        }
+        let mut branes: Vec<Brane> = branes.into_values().collect();
+        branes.sort_by(|a, b| a.start_key.cmp(&b.start_key));
+        branes
+    }
+
+    /// Sends `req` to the store of its peer, to propose, or to serve as
+    /// `consistency` asks if it is not strong, and waits for the result.
+    fn send_request(
+        &mut self,
+        req: VioletaBFTCmdRequest,
+        consistency: ReadConsistency,
+    ) -> Result<CmdResponse> {
+        let brane_id = req.header.brane_id;
+        let store = match self.stores.get_mut(&req.header.peer.store_id) {
+            Some(store) => store,
+            None => return Err(Error::NotLeader(brane_id, None)),
+        };
+        let (tx, rx) = mpsc::channel();
+        let cb = Box::new(move |res| {
+            let _ = tx.send(res);
+        });
+        match consistency {
+            ReadConsistency::Strong => store.propose(req, cb),
+            _ => store.read(req, consistency, cb),
+        }
+        for _ in 0..WAIT_TICKS {
+            self.settle()?;
+            if let Ok(res) = rx.try_recv() {
+                return res;
+            }
+            self.tick_stores()?;
+        }
+        Err(Error::StaleCommand)
+    }
+
+    /// Sends `requests`, which must all fall in the brane of `soliton_id`, to that
+    /// brane, routing again as the stores answer; the brane they were served by.
+    fn call(&mut self, soliton_id: &[u8], requests: Vec<Request>) -> Result<(Brane, CmdResponse)> {
+        self.call_with(soliton_id, requests, ReadConsistency::Strong)
+    }
+
+    /// Like `call`, for reads served as `consistency` asks. Those that are not
+    /// strong go to the followers first; a stale read the replicas can not
+    /// serve yet falls back to the leader.
+    fn call_with(
+        &mut self,
+        soliton_id: &[u8],
+        requests: Vec<Request>,
+        mut consistency: ReadConsistency,
+    ) -> Result<(Brane, CmdResponse)> {
+        for attempt in 0..MAX_RETRIES {
+            let (brane, leader) = match self.cache.locate(soliton_id) {
+                Some((brane, leader)) => (brane.clone(), leader),
+                None => {
+                    let brane = self.lookup_brane(soliton_id).ok_or_else(|| {
+                        Error::Other(format!("no brane holds soliton_id {:?}", soliton_id))
+                    })?;
+                    self.cache.update(brane.clone());
+                    (brane, None)
+                }
+            };
+            let peer = match consistency {
+                ReadConsistency::Strong => {
+                    leader.unwrap_or(brane.peers[attempt % brane.peers.len()])
+                }
+                _ => {
+                    let mut peers: Vec<Peer> = brane
+                        .peers
+                        .iter()
+                        .copied()
+                        .filter(|p| !p.is_witness)
+                        .collect();
+                    peers.sort_by_key(|p| Some(*p) == leader);
+                    peers[attempt % peers.len()]
+                }
+            };
+            let header = CmdHeader {
+                brane_id: brane.id,
+                peer,
+                brane_epoch: brane.brane_epoch,
+            };
+            let req = VioletaBFTCmdRequest::new(header, requests.clone());
+            match self.send_request(req, consistency) {
+                Ok(resp) => {
+                    if consistency == ReadConsistency::Strong {
+                        self.cache.update_leader(brane.id, Some(peer));
+                    }
+                    return Ok((brane, resp));
+                }
+                Err(Error::DataNotReady { .. }) => consistency = ReadConsistency::Strong,
+                Err(Error::NotLeader(brane_id, leader)) => {
+                    self.cache.update_leader(brane_id, leader);
+                    if leader.is_none() {
+                        self.tick_stores()?;
+                    }
+                }
+                Err(Error::EpochNotMatch(_, branes)) => {
+                    for b in branes {
+                        self.cache.update(b);
+                    }
+                }
+                Err(Error::BraneNotFound(brane_id))
+                | Err(Error::KeyNotInBrane { brane_id, .. }) => self.cache.invalidate(brane_id),
+                Err(Error::StaleCommand) => self.cache.update_leader(brane.id, None),
+                Err(Error::MergeInProgress(_))
+                | Err(Error::VioletaBFT(violetabft::Error::ProposalDropped)) => {
+                    self.tick_stores()?
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(Error::Other(format!(
+            "no brane served soliton_id {:?} after {} attempts",
+            soliton_id, MAX_RETRIES
+        )))
+    }
+
+    pub fn put(&mut self, soliton_id: &[u8], causet_locale: &[u8]) -> Result<()> {
+        self.put_namespaced(NAMESPACED_DEFAULT, soliton_id, causet_locale)
+    }
+
+    pub fn put_namespaced(
+        &mut self,
+        namespaced: &str,
+        soliton_id: &[u8],
+        causet_locale: &[u8],
+    ) -> Result<()> {
+        let req = Request::Put {
+            namespaced: namespaced.to_owned(),
+            soliton_id: soliton_id.to_vec(),
+            causet_locale: causet_locale.to_vec(),
+        };
+        self.call(soliton_id, vec![req]).map(|_| ())
+    }
+
+    pub fn get(&mut self, soliton_id: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.get_namespaced(NAMESPACED_DEFAULT, soliton_id)
+    }
+
+    pub fn get_namespaced(
+        &mut self,
+        namespaced: &str,
+        soliton_id: &[u8],
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_with(namespaced, soliton_id, ReadConsistency::Strong)
+    }
+
+    pub fn get_with(
+        &mut self,
+        namespaced: &str,
+        soliton_id: &[u8],
+        consistency: ReadConsistency,
+    ) -> Result<Option<Vec<u8>>> {
+        let req = Request::Get {
+            namespaced: namespaced.to_owned(),
+            soliton_id: soliton_id.to_vec(),
+        };
+        let (_, mut resp) = self.call_with(soliton_id, vec![req], consistency)?;
+        match resp.responses.pop() {
+            Some(Response::Get(v)) => Ok(v),
+            r => Err(Error::Other(format!("unexpected response {:?} to get", r))),
+        }
+    }
+
+    pub fn delete(&mut self, soliton_id: &[u8]) -> Result<()> {
+        let req = Request::Delete {
+            namespaced: NAMESPACED_DEFAULT.to_owned(),
+            soliton_id: soliton_id.to_vec(),
+        };
+        self.call(soliton_id, vec![req]).map(|_| ())
+    }
+
+    /// Writes `requests` in order, each brane's share of them atomically.
+    pub fn write(&mut self, mut requests: Vec<Request>) -> Result<()> {
+        while let Some(first) = requests.first() {
+            let soliton_id = first.soliton_id().to_vec();
+            let brane = self.lookup_brane(&soliton_id).ok_or_else(|| {
+                Error::Other(format!("no brane holds soliton_id {:?}", soliton_id))
+            })?;
+            let (batch, rest) = requests
+                .into_iter()
+                .partition(|r| brane.contains(r.soliton_id()));
+            self.call(&soliton_id, batch)?;
+            requests = rest;
+        }
+        Ok(())
+    }
+
+    /// At most `limit` pairs of `[start_key, end_key)`, or all if it is 0, across
+    /// branes; an empty end is unbounded.
+    pub fn scan(
+        &mut self,
+        start_key: &[u8],
+        end_key: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.scan_namespaced(NAMESPACED_DEFAULT, start_key, end_key, limit)
+    }
+
+    pub fn scan_namespaced(
+        &mut self,
+        namespaced: &str,
+        start_key: &[u8],
+        end_key: &[u8],
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.scan_with(
+            namespaced,
+            start_key,
+            end_key,
+            limit,
+            ReadConsistency::Strong,
+        )
+    }
+
+    pub fn scan_with(
+        &mut self,
+        namespaced: &str,
+        start_key: &[u8],
+        end_key: &[u8],
+        limit: usize,
+        consistency: ReadConsistency,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut pairs = Vec::new();
+        let mut cursor = start_key.to_vec();
+        loop {
+            let req = Request::Scan {
+                namespaced: namespaced.to_owned(),
+                start_key: cursor.clone(),
+                end_key: end_key.to_vec(),
+                limit: if limit == 0 { 0 } else { limit - pairs.len() },
+            };
+            let (brane, mut resp) = self.call_with(&cursor, vec![req], consistency)?;
+            match resp.responses.pop() {
+                Some(Response::Scan(kvs)) => pairs.extend(kvs),
+                r => return Err(Error::Other(format!("unexpected response {:?} to scan", r))),
+            }
+            if (limit > 0 && pairs.len() >= limit)
+                || brane.end_key.is_empty()
+                || (!end_key.is_empty() && brane.end_key.as_slice() >= end_key)
+            {
+                return Ok(pairs);
+            }
+            cursor = brane.end_key;
+        }
+    }
+
+    /// Waits for the peer of `brane` on store `store_id` to apply everything
+    /// committed before the call, as read-index tells it. Fails if the brane is
+    /// not as given there.
+    pub fn confirm_applied(&mut self, brane: &Brane, store_id: u64) -> Result<()> {
+        let peer = brane
+            .peer_on_store(store_id)
+            .ok_or(Error::BraneNotFound(brane.id))?;
+        let header = CmdHeader {
+            brane_id: brane.id,
+            peer,
+            brane_epoch: brane.brane_epoch,
+        };
+        let get = Request::Get {
+            namespaced: NAMESPACED_DEFAULT.to_owned(),
+            soliton_id: brane.start_key.clone(),
+        };
+        let req = VioletaBFTCmdRequest::new(header, vec![get]);
+        self.send_request(req, ReadConsistency::Follower)
+            .map(|_| ())
+    }
+
+    /// Ingests the external file `sst`, whose soliton_ids are data soliton_ids
+    /// of one brane, into `namespaced` there, and waits for the running
+    /// replicas to apply it.
+    pub fn ingest(&mut self, namespaced: &str, sst: ExternalSstFileInfo) -> Result<()> {
+        let soliton_id = keys::origin_key(&sst.smallest_soliton_id).to_vec();
+        let req = Request::IngestSst {
+            namespaced: namespaced.to_owned(),
+            sst,
+        };
+        let (brane, _) = self.call(&soliton_id, vec![req])?;
+        let leader = self.wait_leader(brane.id)?;
+        let index = self
+            .stores
+            .get(&leader.store_id)
+            .and_then(|s| s.peer(brane.id))
+            .map_or(0, |p| p.storage().applied_index());
+        let brane = self.get_brane(brane.id).unwrap_or(brane);
+        self.wait_applied(&brane, index)
+    }
+
+    /// Proposes the admin command `build` makes for the current brane to its
+    /// leader, until it is not rejected for routing.
+    fn call_admin(
+        &mut self,
+        brane_id: u64,
+        mut build: impl FnMut(&mut Cluster, &Brane) -> Result<AdminRequest>,
+    ) -> Result<CmdResponse> {
+        for _ in 0..MAX_RETRIES {
+            let brane = self
+                .get_brane(brane_id)
+                .ok_or(Error::BraneNotFound(brane_id))?;
+            let leader = self.wait_leader(brane_id)?;
+            let header = CmdHeader {
+                brane_id,
+                peer: leader,
+                brane_epoch: brane.brane_epoch,
+            };
+            let admin = build(self, &brane)?;
+            let req = VioletaBFTCmdRequest::admin(header, admin);
+            match self.send_request(req, ReadConsistency::Strong) {
+                Err(Error::NotLeader(..))
+                | Err(Error::EpochNotMatch(..))
+                | Err(Error::StaleCommand)
+                | Err(Error::VioletaBFT(violetabft::Error::ProposalDropped)) => {
+                    self.tick_stores()?
+                }
+                res => return res,
+            }
+        }
+        Err(Error::Other(format!(
+            "admin command to brane {} failed after {} attempts",
+            brane_id, MAX_RETRIES
+        )))
+    }
+
+    /// Closes every brane at `ts`, for stale reads at it. The caller promises
+    /// that every write at or before `ts` was made already; a brane being merged
+    /// stays closed where it was.
+    pub fn close_ts(&mut self, ts: u64) -> Result<()> {
+        for brane in self.branes() {
+            match self.call_admin(brane.id, |_, _| Ok(AdminRequest::CloseTs { ts })) {
+                Ok(_) | Err(Error::BraneNotFound(_)) | Err(Error::MergeInProgress(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits the brane holding `split_key` at it; the two halves.
+    pub fn split(&mut self, split_key: &[u8]) -> Result<(Brane, Brane)> {
+        let brane = self
+            .lookup_brane(split_key)
+            .ok_or_else(|| Error::Other(format!("no brane holds soliton_id {:?}", split_key)))?;
+        let resp = self.call_admin(brane.id, |cluster, brane| {
+            let new_brane_id = cluster.id_allocator.alloc_id()?;
+            let new_peer_ids = brane
+                .peers
+                .iter()
+                .map(|_| cluster.id_allocator.alloc_id())
+                .collect::<Result<_>>()?;
+            Ok(AdminRequest::Split {
+                split_key: split_key.to_vec(),
+                new_brane_id,
+                new_peer_ids,
+            })
+        })?;
+        let mut branes = resp.branes.into_iter();
+        match (branes.next(), branes.next()) {
+            (Some(left), Some(right)) => Ok((left, right)),
+            _ => Err(Error::Other("split returned no branes".to_owned())),
+        }
+    }
+
+    pub fn change_peer(
+        &mut self,
+        brane_id: u64,
+        changes: Vec<(ConfChangeType, Peer)>,
+    ) -> Result<Brane> {
+        let resp = self.call_admin(brane_id, |_, _| {
+            Ok(AdminRequest::ChangePeer {
+                changes: changes.clone(),
+            })
+        })?;
+        resp.branes
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Other("change of peers returned no brane".to_owned()))
+    }
+
+    /// Adds a voter of the brane on `store_id`.
+    pub fn add_peer(&mut self, brane_id: u64, store_id: u64) -> Result<Peer> {
+        let peer = Peer::new(self.id_allocator.alloc_id()?, store_id);
+        self.change_peer(brane_id, vec![(ConfChangeType::AddNode, peer)])?;
+        Ok(peer)
+    }
+
+    /// Adds a learner of the brane on `store_id`, which is sent the data and
+    /// the log but does not vote until it is promoted.
+    pub fn add_learner(&mut self, brane_id: u64, store_id: u64) -> Result<Peer> {
+        let peer = Peer {
+            role: PeerRole::Learner,
+            ..Peer::new(self.id_allocator.alloc_id()?, store_id)
+        };
+        self.change_peer(brane_id, vec![(ConfChangeType::AddLearnerNode, peer)])?;
+        Ok(peer)
+    }
+
+    /// Adds a witness of the brane on `store_id`: a voter that keeps the log
+    /// but not the data.
+    pub fn add_witness(&mut self, brane_id: u64, store_id: u64) -> Result<Peer> {
+        let peer = Peer {
+            is_witness: true,
+            ..Peer::new(self.id_allocator.alloc_id()?, store_id)
+        };
+        self.change_peer(brane_id, vec![(ConfChangeType::AddNode, peer)])?;
+        Ok(peer)
+    }
+
+    /// Adds a learner of the brane on `store_id` which `tick` promotes to a
+    /// voter once it caught up with the leader, so that a replica without the
+    /// data never counts towards a quorum.
+    pub fn add_promoting_learner(&mut self, brane_id: u64, store_id: u64) -> Result<Peer> {
+        let learner = self.add_learner(brane_id, store_id)?;
+        self.promotions.push((brane_id, learner));
+        Ok(learner)
+    }
+
+    /// Promotes the learners added by `add_promoting_learner` that caught up,
+    /// and forgets those removed or promoted otherwise. A promotion that fails
+    /// is tried again on the next tick.
+    fn promote_caught_up_learners(&mut self) {
+        for (brane_id, learner) in mem::take(&mut self.promotions) {
+            let current = self.get_brane(brane_id).and_then(|b| b.peer(learner.id));
+            if current.is_none_or(|p| !p.is_learner()) {
+                continue;
+            }
+            let voter = Peer {
+                role: PeerRole::Voter,
+                ..learner
+            };
+            if !self.caught_up(brane_id, learner.store_id)
+                || self
+                    .change_peer(brane_id, vec![(ConfChangeType::AddNode, voter)])
+                    .is_err()
+            {
+                self.promotions.push((brane_id, learner));
+            }
+        }
+    }
+
+    /// Promotes the learner of the brane on `store_id` to a voter once it
+    /// caught up with the leader.
+    pub fn promote_learner(&mut self, brane_id: u64, store_id: u64) -> Result<Peer> {
+        let learner = self
+            .get_brane(brane_id)
+            .and_then(|b| b.peer_on_store(store_id))
+            .filter(|p| p.is_learner())
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "brane {} has no learner on store {}",
+                    brane_id, store_id
+                ))
+            })?;
+        self.wait_caught_up(brane_id, store_id)?;
+        let voter = Peer {
+            role: PeerRole::Voter,
+            ..learner
+        };
+        self.change_peer(brane_id, vec![(ConfChangeType::AddNode, voter)])?;
+        Ok(voter)
+    }
+
+    /// The index the peer of the brane on `store_id` applied, 0 without one.
+    fn applied_index(&self, brane_id: u64, store_id: u64) -> u64 {
+        self.stores
+            .get(&store_id)
+            .and_then(|s| s.peer(brane_id))
+            .map_or(0, |p| p.storage().applied_index())
+    }
+
+    /// Whether the peer of the brane on `store_id` applied what its leader
+    /// applied.
+    fn caught_up(&self, brane_id: u64, store_id: u64) -> bool {
+        self.leader(brane_id).is_some_and(|leader| {
+            self.applied_index(brane_id, store_id) >= self.applied_index(brane_id, leader.store_id)
+        })
+    }
+
+    /// Waits for the peer of the brane on `store_id` to apply what its leader
+    /// applied when called.
+    fn wait_caught_up(&mut self, brane_id: u64, store_id: u64) -> Result<()> {
+        let leader = self.wait_leader(brane_id)?;
+        let index = self.applied_index(brane_id, leader.store_id);
+        for _ in 0..WAIT_TICKS {
+            if self.applied_index(brane_id, store_id) >= index {
+                return Ok(());
+            }
+            self.tick_stores()?;
+        }
+        Err(Error::Other(format!(
+            "the peer of brane {} on store {} did not catch up",
+            brane_id, store_id
+        )))
+    }
+
+    /// Has every replica of the brane compute a checksum of its data at the
+    /// same index, and compares them: the replicas that differ from the
+    /// checksum most agree on, or the leader's on a tie, are corrupt.
+    pub fn check_consistency(&mut self, brane_id: u64) -> Result<ConsistencyCheck> {
+        self.call_admin(brane_id, |_, _| Ok(AdminRequest::ComputeHash))?;
+        let leader = self.wait_leader(brane_id)?;
+        let hash_of = |c: &Cluster, store_id: u64| {
+            c.stores
+                .get(&store_id)
+                .and_then(|s| s.peer(brane_id))
+                .and_then(|p| p.consistency_hash())
+        };
+        let (index, leader_hash) = hash_of(self, leader.store_id).ok_or_else(|| {
+            Error::Other(format!(
+                "the leader of brane {} computed no checksum",
+                brane_id
+            ))
+        })?;
+        let brane = self
+            .get_brane(brane_id)
+            .ok_or(Error::BraneNotFound(brane_id))?;
+        let replicas: Vec<Peer> = brane
+            .peers
+            .iter()
+            .copied()
+            .filter(|p| !p.is_witness)
+            .collect();
+        for _ in 0..WAIT_TICKS {
+            let computed = replicas
+                .iter()
+                .all(|p| hash_of(self, p.store_id).is_some_and(|(i, _)| i == index));
+            if computed {
+                break;
+            }
+            self.tick_stores()?;
+        }
+        let mut check = ConsistencyCheck {
+            index,
+            ..Default::default()
+        };
+        let mut hashes = Vec::new();
+        for p in replicas {
+            match hash_of(self, p.store_id) {
+                Some((i, hash)) if i == index => hashes.push((p, hash)),
+                _ => check.lagging.push(p),
+            }
+        }
+        let mut counts: BTreeMap<u32, usize> = BTreeMap::new();
+        for (_, hash) in &hashes {
+            *counts.entry(*hash).or_default() += 1;
+        }
+        check.hash = counts
+            .into_iter()
+            .max_by_key(|&(hash, n)| (n, hash == leader_hash))
+            .map_or(leader_hash, |(hash, _)| hash);
+        check.corrupt = hashes
+            .into_iter()
+            .filter(|(_, hash)| *hash != check.hash)
+            .map(|(p, _)| p)
+            .collect();
+        Ok(check)
+    }
+
+    /// Replaces the replica of the brane on `store_id`, like one a consistency
+    /// check found corrupt, by one made from a snapshot of the leader: the
+    /// replica is removed with its data, and a peer of its role added on the
+    /// store again, as a learner until it caught up if it is to vote.
+    pub fn repair_peer(&mut self, brane_id: u64, store_id: u64) -> Result<Peer> {
+        let brane = self
+            .get_brane(brane_id)
+            .ok_or(Error::BraneNotFound(brane_id))?;
+        let old = brane.peer_on_store(store_id).ok_or_else(|| {
+            Error::Other(format!(
+                "brane {} has no peer on store {}",
+                brane_id, store_id
+            ))
+        })?;
+        if !self.stores.contains_key(&store_id) {
+            return Err(Error::Other(format!("store {} is not running", store_id)));
+        }
+        if self.leader(brane_id).is_some_and(|l| l.id == old.id) {
+            let to = brane
+                .peers
+                .iter()
+                .find(|p| p.id != old.id && p.can_lead())
+                .ok_or_else(|| {
+                    Error::Other(format!("brane {} has no other peer to lead", brane_id))
+                })?;
+            self.transfer_leader(brane_id, to.store_id)?;
+        }
+        self.change_peer(brane_id, vec![(ConfChangeType::RemoveNode, old)])?;
+        // The replica deletes its data once it applies its removal.
+        for _ in 0..WAIT_TICKS {
+            if self.stores[&store_id].peer(brane_id).is_none() {
+                break;
+            }
+            self.tick_stores()?;
+        }
+        if self.stores[&store_id].peer(brane_id).is_some() {
+            return Err(Error::Other(format!(
+                "the peer of brane {} on store {} was not removed",
+                brane_id, store_id
+            )));
+        }
+        if old.is_witness {
+            return self.add_witness(brane_id, store_id);
+        }
+        if old.is_learner() {
+            let learner = self.add_learner(brane_id, store_id)?;
+            self.wait_caught_up(brane_id, store_id)?;
+            return Ok(learner);
+        }
+        let learner = self.add_promoting_learner(brane_id, store_id)?;
+        for _ in 0..WAIT_TICKS {
+            self.tick_stores()?;
+            self.promote_caught_up_learners();
+            let current = self.get_brane(brane_id).and_then(|b| b.peer(learner.id));
+            if let Some(voter) = current.filter(|p| !p.is_learner()) {
+                return Ok(voter);
+            }
+        }
+        Err(Error::Other(format!(
+            "the peer of brane {} on store {} was not promoted",
+            brane_id, store_id
+        )))
+    }
+
+    /// Checks the consistency of every brane and repairs the replicas found
+    /// corrupt.
+    fn repair_inconsistent(&mut self) -> Result<()> {
+        for brane in self.branes() {
+            let check = self.check_consistency(brane.id)?;
+            for peer in check.corrupt {
+                self.repair_peer(brane.id, peer.store_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the peer of the brane on `store_id`.
+    pub fn remove_peer(&mut self, brane_id: u64, store_id: u64) -> Result<()> {
+        let peer = self
+            .get_brane(brane_id)
+            .and_then(|b| b.peer_on_store(store_id))
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "brane {} has no peer on store {}",
+                    brane_id, store_id
+                ))
+            })?;
+        self.change_peer(brane_id, vec![(ConfChangeType::RemoveNode, peer)])?;
+        Ok(())
+    }
+
+    /// Hands the leadership of the brane over to its peer on `store_id`, and
+    /// waits for it to take over.
+    pub fn transfer_leader(&mut self, brane_id: u64, store_id: u64) -> Result<()> {
+        let to = self
+            .get_brane(brane_id)
+            .and_then(|b| b.peer_on_store(store_id))
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "brane {} has no peer on store {}",
+                    brane_id, store_id
+                ))
+            })?;
+        for _ in 0..WAIT_TICKS {
+            let leader = self.wait_leader(brane_id)?;
+            if leader == to {
+                return Ok(());
+            }
+            self.stores
+                .get_mut(&leader.store_id)
+                .unwrap()
+                .transfer_leader(brane_id, to)?;
+            self.tick_stores()?;
+        }
+        Err(Error::Other(format!(
+            "brane {} did not transfer its leader to store {}",
+            brane_id, store_id
+        )))
+    }
+
+    /// Merges brane `source` into the adjacent brane `target`, whose peers must
+    /// be on the same stores; the merged brane.
+    ///
+    /// The source is frozen first, and the target takes it over once every
+    /// replica of the source applied the freeze. If the target can not, the
+    /// source is unfrozen.
+    pub fn merge(&mut self, source_id: u64, target_id: u64) -> Result<Brane> {
+        let source = self
+            .get_brane(source_id)
+            .ok_or(Error::BraneNotFound(source_id))?;
+        let target = self
+            .get_brane(target_id)
+            .ok_or(Error::BraneNotFound(target_id))?;
+        let adjacent = (!source.end_key.is_empty() && source.end_key == target.start_key)
+            || (!target.end_key.is_empty() && target.end_key == source.start_key);
+        if !adjacent {
+            return Err(Error::Other(format!(
+                "branes {} and {} are not adjacent",
+                source_id, target_id
+            )));
+        }
+        let stores = |b: &Brane| {
+            let mut ids: Vec<u64> = b.peers.iter().map(|p| p.store_id).collect();
+            ids.sort_unstable();
+            ids
+        };
+        if stores(&source) != stores(&target) {
+            return Err(Error::Other(format!(
+                "branes {} and {} are not on the same stores",
+                source_id, target_id
+            )));
+        }
+
+        let resp = self.call_admin(source_id, |cluster, _| {
+            let target = cluster
+                .get_brane(target_id)
+                .ok_or(Error::BraneNotFound(target_id))?;
+            Ok(AdminRequest::PrepareMerge { target })
+        })?;
+        let source = resp.branes.into_iter().next().unwrap_or(source);
+        let commit = self
+            .stores
+            .values()
+            .filter_map(|s| s.peer(source_id))
+            .find_map(|p| p.storage().local_state().merge_state.as_ref())
+            .map(|m| m.commit)
+            .ok_or_else(|| Error::Other(format!("brane {} prepared no merge", source_id)))?;
+        self.wait_applied(&source, commit)?;
+
+        match self.call_admin(target_id, |_, _| {
+            Ok(AdminRequest::CommitMerge {
+                source: source.clone(),
+                commit,
+            })
+        }) {
+            Ok(resp) => {
+                self.cache.invalidate(source_id);
+                resp.branes
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| Error::Other("merge returned no brane".to_owned()))
+            }
+            Err(e) => {
+                self.call_admin(source_id, |_, _| Ok(AdminRequest::RollbackMerge { commit }))?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Waits for every running peer of `brane` to apply the log up to `index`.
+    fn wait_applied(&mut self, brane: &Brane, index: u64) -> Result<()> {
+        for _ in 0..WAIT_TICKS {
+            let applied = brane.peers.iter().all(|p| {
+                self.stores.get(&p.store_id).is_none_or(|s| {
+                    s.peer(brane.id)
+                        .is_some_and(|peer| peer.storage().applied_index() >= index)
+                })
+            });
+            if applied {
+                return Ok(());
+            }
+            self.tick_stores()?;
+        }
+        Err(Error::Other(format!(
+            "brane {} did not apply index {}",
+            brane.id, index
+        )))
+    }
+
+    /// Merges the first pair of adjacent branes that are both smaller than
+    /// `merge_max_size`.
+    fn check_merge(&mut self) -> Result<()> {
+        let branes = self.branes();
+        for pair in branes.windows(2) {
+            let (left, right) = (&pair[0], &pair[1]);
+            if left.end_key.is_empty() || left.end_key != right.start_key {
+                continue;
+            }
+            let mut small = true;
+            for brane in pair {
+                let size = self
+                    .leader(brane.id)
+                    .and_then(|l| self.stores.get(&l.store_id))
+                    .map(|s| s.brane_size(brane.id, self.cfg.merge_max_size))
+                    .transpose()?;
+                small &= size.is_some_and(|s| s < self.cfg.merge_max_size);
+            }
+            if small {
+                return match self.merge(right.id, left.id) {
+                    Ok(_) | Err(Error::Other(_)) => Ok(()),
+                    Err(e) => Err(e),
+                };
+            }
+        }
+        Ok(())
+    }
+
+    /// Stops store `store_id` and opens it again from its einstein_merkle_trees.
+    pub fn restart_store(&mut self, store_id: u64) -> Result<()> {
+        self.stop_store(store_id);
+        self.start_store(store_id)
+    }
+
+    /// Stops store `store_id`; messages to it are lost until it is started.
+    pub fn stop_store(&mut self, store_id: u64) {
+        if let Some(store) = self.stores.remove(&store_id) {
+            for brane in store.branes() {
+                for o in &self.observers {
+                    o.on_brane_changed(store_id, brane.id);
+                }
+            }
+        }
+    }
+
+    pub fn start_store(&mut self, store_id: u64) -> Result<()> {
+        let (kv, log) = self.open_engines(store_id)?;
+        let store = self.open_store(store_id, kv, log)?;
+        self.stores.insert(store_id, store);
+        self.settle()
+    }
+
+    /// Cuts every store in `left` off from every store in `right`.
+    pub fn partition(&mut self, left: &[u64], right: &[u64]) {
+        let mut network = self.network.lock().unwrap();
+        for &a in left {
+            for &b in right {
+                network.cut.insert((a, b));
+                network.cut.insert((b, a));
+            }
+        }
+    }
+
+    pub fn heal(&mut self) {
+        self.network.lock().unwrap().cut.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use fdb_traits::{Compression, MiscExt, Peekable, SstWriter};
+    use soliton_lsm::LsmSstWriter;
+
+    use super::*;
+    use crate::observer::AppliedCmd;
+
+    fn test_config() -> StoreConfig {
+        StoreConfig {
+            violetabft_election_ticks: 5,
+            violetabft_heartbeat_ticks: 1,
+            sync_log: false,
+            split_check_ticks: 1000,
+            log_gc_ticks: 1000,
+            ..Default::default()
+        }
+    }
+
+    fn key(i: usize) -> Vec<u8> {
+        format!("k{:04}", i).into_bytes()
+    }
+
+    #[test]
+    fn test_split_and_routing() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        cluster.put(b"a", b"1").unwrap();
+        let (left, right) = cluster.split(b"m").unwrap();
+        assert_eq!(
+            (left.end_key.as_slice(), right.start_key.as_slice()),
+            (&b"m"[..], &b"m"[..])
+        );
+        assert!(left.brane_epoch.version > 1);
+
+        // The client still routes "z" to the brane before the split, and learns
+        // of it from the epoch error.
+        cluster.put(b"z", b"2").unwrap();
+        assert_eq!(cluster.cache.locate(b"z").unwrap().0.id, right.id);
+        assert_eq!(cluster.get(b"a").unwrap().unwrap(), b"1");
+        assert_eq!(cluster.get(b"z").unwrap().unwrap(), b"2");
+        assert_eq!(cluster.scan(b"", b"", 0).unwrap().len(), 2);
+        cluster.delete(b"a").unwrap();
+        assert_eq!(cluster.get(b"a").unwrap(), None);
+        for store_id in 1..=3 {
+            assert_eq!(cluster.store(store_id).unwrap().branes().len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_auto_split_and_merge() {
+        let dir = TempDir::new().unwrap();
+        let cfg = StoreConfig {
+            split_check_ticks: 1,
+            brane_max_size: 16 * 1024,
+            merge_check_ticks: 2,
+            merge_max_size: 1024,
+            ..test_config()
+        };
+        let mut cluster = Cluster::new(dir.path(), 3, cfg).unwrap();
+        for i in 0..200 {
+            cluster.put(&key(i), &[b'v'; 128]).unwrap();
+        }
+        for store_id in 1..=3 {
+            cluster.store(store_id).unwrap().kv().flush(true).unwrap();
+        }
+        for _ in 0..10 {
+            cluster.tick().unwrap();
+        }
+        let branes = cluster.branes();
+        assert!(branes.len() > 1, "{:?}", branes);
+        assert_eq!(cluster.scan(b"", b"", 0).unwrap().len(), 200);
+
+        let (left, right) = (branes[0].clone(), branes[1].clone());
+        let merged = cluster.merge(right.id, left.id).unwrap();
+        assert_eq!(merged.id, left.id);
+        assert_eq!(merged.end_key, right.end_key);
+        assert!(cluster.get_brane(right.id).is_none());
+        assert_eq!(cluster.branes().len(), branes.len() - 1);
+        assert_eq!(cluster.scan(b"", b"", 0).unwrap().len(), 200);
+        cluster.put(&key(0), b"x").unwrap();
+        assert_eq!(cluster.get(&key(0)).unwrap().unwrap(), b"x");
+
+        // Once emptied, the branes are merged back into one.
+        for i in 0..200 {
+            cluster.delete(&key(i)).unwrap();
+        }
+        for _ in 0..20 {
+            cluster.tick().unwrap();
+        }
+        assert_eq!(cluster.branes().len(), 1);
+    }
+
+    #[test]
+    fn test_change_peer_and_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let cfg = StoreConfig {
+            log_gc_ticks: 1,
+            log_gc_count_limit: 10,
+            log_gc_threshold: 1,
+            ..test_config()
+        };
+        let mut cluster = Cluster::new(dir.path(), 3, cfg).unwrap();
+        let brane_id = cluster.lookup_brane(b"").unwrap().id;
+        cluster.put(b"k1", b"v1").unwrap();
+        cluster.remove_peer(brane_id, 3).unwrap();
+        for _ in 0..10 {
+            cluster.tick().unwrap();
+        }
+        assert!(cluster.store(3).unwrap().peer(brane_id).is_none());
+        assert_eq!(cluster.get_brane(brane_id).unwrap().peers.len(), 2);
+
+        for i in 0..30 {
+            cluster.put(&key(i), b"v").unwrap();
+        }
+        for _ in 0..10 {
+            cluster.tick().unwrap();
+        }
+        let leader = cluster.leader(brane_id).unwrap();
+        let truncated = cluster
+            .store(leader.store_id)
+            .unwrap()
+            .peer(brane_id)
+            .unwrap()
+            .storage()
+            .apply_state()
+            .truncated_index;
+        assert!(truncated > 5);
+
+        // The log the new peer needs is gone, so it is sent a snapshot.
+        let peer = cluster.add_peer(brane_id, 3).unwrap();
+        for _ in 0..10 {
+            cluster.tick().unwrap();
+        }
+        let store = cluster.store(3).unwrap();
+        assert_eq!(store.peer(brane_id).unwrap().peer, peer);
+        let kv = store.kv();
+        assert_eq!(
+            kv.get_value(&keys::data_key(b"k1")).unwrap().unwrap(),
+            b"v1"
+        );
+        assert_eq!(
+            kv.get_value(&keys::data_key(&key(29))).unwrap().unwrap(),
+            b"v"
+        );
+
+        // A store joining later gets its replica the same way, and can lead.
+        cluster.add_store(4).unwrap();
+        cluster.add_peer(brane_id, 4).unwrap();
+        cluster.transfer_leader(brane_id, 4).unwrap();
+        assert_eq!(cluster.leader(brane_id).unwrap().store_id, 4);
+        cluster.put(b"k2", b"v2").unwrap();
+        assert_eq!(cluster.get(b"k2").unwrap().unwrap(), b"v2");
+    }
+
+    #[test]
+    fn test_learner_witness_and_repair() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        cluster.put(b"k1", b"v1").unwrap();
+        let brane_id = cluster.lookup_brane(b"").unwrap().id;
+        let get_on = |cluster: &Cluster, store_id: u64, k: &[u8]| {
+            let kv = cluster.store(store_id).unwrap().kv();
+            kv.get_value(&keys::data_key(k)).unwrap()
+        };
+
+        // A learner is sent the data, but can not lead until it is promoted.
+        cluster.add_store(4).unwrap();
+        let learner = cluster.add_learner(brane_id, 4).unwrap();
+        for _ in 0..10 {
+            cluster.tick().unwrap();
+        }
+        assert_eq!(get_on(&cluster, 4, b"k1").unwrap(), b"v1");
+        let conf_state = |cluster: &Cluster| {
+            let peer = cluster.store(4).unwrap().peer(brane_id).unwrap();
+            peer.storage().local_state().conf_state.clone()
+        };
+        assert_eq!(conf_state(&cluster).learners, vec![learner.id]);
+        assert!(cluster.transfer_leader(brane_id, 4).is_err());
+        let voter = cluster.promote_learner(brane_id, 4).unwrap();
+        assert!(voter.can_lead());
+        assert!(conf_state(&cluster).voters.contains(&voter.id));
+        assert_eq!(
+            cluster.get_brane(brane_id).unwrap().peer_on_store(4),
+            Some(voter)
+        );
+
+        // A witness votes, but keeps no data, leads nor serves reads.
+        cluster.add_store(5).unwrap();
+        let witness = cluster.add_witness(brane_id, 5).unwrap();
+        cluster.put(b"k2", b"v2").unwrap();
+        let brane = cluster.get_brane(brane_id).unwrap();
+        cluster.confirm_applied(&brane, 1).unwrap();
+        assert_eq!(get_on(&cluster, 5, b"k1"), None);
+        assert_eq!(get_on(&cluster, 5, b"k2"), None);
+        assert!(matches!(
+            cluster.confirm_applied(&brane, 5),
+            Err(Error::IsWitness(_))
+        ));
+        assert!(cluster.transfer_leader(brane_id, 5).is_err());
+
+        // A replica whose data differs is found, and made again.
+        cluster
+            .store(3)
+            .unwrap()
+            .kv()
+            .put(&keys::data_key(b"k1"), b"bad")
+            .unwrap();
+        let check = cluster.check_consistency(brane_id).unwrap();
+        let corrupt = brane.peer_on_store(3).unwrap();
+        assert_eq!((check.corrupt, check.lagging), (vec![corrupt], vec![]));
+        let repaired = cluster.repair_peer(brane_id, 3).unwrap();
+        assert!(repaired.id > corrupt.id && repaired.can_lead());
+        assert_eq!(get_on(&cluster, 3, b"k1").unwrap(), b"v1");
+        let check = cluster.check_consistency(brane_id).unwrap();
+        assert!(check.corrupt.is_empty() && check.lagging.is_empty());
+
+        // With two of the five voters down, the witness makes the quorum.
+        cluster.transfer_leader(brane_id, 1).unwrap();
+        cluster.stop_store(1);
+        cluster.stop_store(2);
+        cluster.put(b"k3", b"v3").unwrap();
+        assert_ne!(cluster.leader(brane_id).unwrap().store_id, witness.store_id);
+        assert_eq!(cluster.get(b"k3").unwrap().unwrap(), b"v3");
+        let check = cluster.check_consistency(brane_id).unwrap();
+        let mut lagging: Vec<u64> = check.lagging.iter().map(|p| p.store_id).collect();
+        lagging.sort_unstable();
+        assert_eq!(lagging, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_learner_promoted_after_catch_up() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        cluster.put(b"k1", b"v1").unwrap();
+        let brane_id = cluster.lookup_brane(b"").unwrap().id;
+        let role_on_4 = |cluster: &Cluster| {
+            let peer = cluster.get_brane(brane_id).unwrap().peer_on_store(4);
+            peer.map(|p| p.role)
+        };
+
+        // Cut off, the learner does not catch up and stays one.
+        cluster.add_store(4).unwrap();
+        let learner = cluster.add_promoting_learner(brane_id, 4).unwrap();
+        cluster.partition(&[4], &[1, 2, 3]);
+        cluster.put(b"k2", b"v2").unwrap();
+        for _ in 0..20 {
+            cluster.tick().unwrap();
+        }
+        assert_eq!(role_on_4(&cluster), Some(PeerRole::Learner));
+
+        cluster.heal();
+        for _ in 0..20 {
+            cluster.tick().unwrap();
+            if role_on_4(&cluster) == Some(PeerRole::Voter) {
+                break;
+            }
+        }
+        let voter = cluster
+            .get_brane(brane_id)
+            .unwrap()
+            .peer_on_store(4)
+            .unwrap();
+        assert_eq!((voter.id, voter.role), (learner.id, PeerRole::Voter));
+        let kv = cluster.store(4).unwrap().kv();
+        assert_eq!(
+            kv.get_value(&keys::data_key(b"k2")).unwrap().unwrap(),
+            b"v2"
+        );
+        let peer = cluster.store(4).unwrap().peer(brane_id).unwrap();
+        assert!(peer
+            .storage()
+            .local_state()
+            .conf_state
+            .voters
+            .contains(&voter.id));
+    }
+
+    #[test]
+    fn test_witness_never_serves_reads() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        cluster.put(b"k1", b"v1").unwrap();
+        let brane_id = cluster.lookup_brane(b"").unwrap().id;
+        cluster.add_store(4).unwrap();
+        let witness = cluster.add_witness(brane_id, 4).unwrap();
+        cluster.close_ts(10).unwrap();
+
+        let brane = cluster.get_brane(brane_id).unwrap();
+        let read_on_witness = |cluster: &mut Cluster, consistency| {
+            let header = CmdHeader {
+                brane_id,
+                peer: witness,
+                brane_epoch: brane.brane_epoch,
+            };
+            let get = Request::Get {
+                namespaced: NAMESPACED_DEFAULT.to_owned(),
+                soliton_id: b"k1".to_vec(),
+            };
+            cluster.send_request(VioletaBFTCmdRequest::new(header, vec![get]), consistency)
+        };
+        for consistency in [ReadConsistency::Follower, ReadConsistency::Stale(10)] {
+            let res = read_on_witness(&mut cluster, consistency);
+            assert!(matches!(res, Err(Error::IsWitness(_))), "{:?}", res);
+        }
+        let res = read_on_witness(&mut cluster, ReadConsistency::Strong);
+        assert!(matches!(res, Err(Error::NotLeader(..))), "{:?}", res);
+
+        // Reads sent to any replica are served by the others.
+        for _ in 0..10 {
+            for consistency in [ReadConsistency::Follower, ReadConsistency::Stale(10)] {
+                let v = cluster.get_with(NAMESPACED_DEFAULT, b"k1", consistency);
+                assert_eq!(v.unwrap().unwrap(), b"v1");
+            }
+        }
+    }
+
+    #[test]
+    fn test_checksum_mismatch_triggers_repair() {
+        let dir = TempDir::new().unwrap();
+        let cfg = StoreConfig {
+            consistency_check_ticks: 5,
+            ..test_config()
+        };
+        let mut cluster = Cluster::new(dir.path(), 3, cfg).unwrap();
+        cluster.put(b"k1", b"v1").unwrap();
+        let brane_id = cluster.lookup_brane(b"").unwrap().id;
+        let corrupt = cluster
+            .get_brane(brane_id)
+            .unwrap()
+            .peer_on_store(3)
+            .unwrap();
+        let kv = cluster.store(3).unwrap().kv();
+        kv.put(&keys::data_key(b"k1"), b"bad").unwrap();
+
+        for _ in 0..5 {
+            cluster.tick().unwrap();
+        }
+        let repaired = cluster
+            .get_brane(brane_id)
+            .unwrap()
+            .peer_on_store(3)
+            .unwrap();
+        assert!(repaired.id > corrupt.id && repaired.can_lead());
+        let kv = cluster.store(3).unwrap().kv();
+        assert_eq!(
+            kv.get_value(&keys::data_key(b"k1")).unwrap().unwrap(),
+            b"v1"
+        );
+        let check = cluster.check_consistency(brane_id).unwrap();
+        assert!(check.corrupt.is_empty() && check.lagging.is_empty());
+    }
+
+    #[test]
+    fn test_restart_and_partition() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        cluster.put(b"k1", b"v1").unwrap();
+        cluster.split(b"k5").unwrap();
+        cluster.put(b"k9", b"v9").unwrap();
+        for store_id in 1..=3 {
+            cluster.restart_store(store_id).unwrap();
+        }
+        assert_eq!(cluster.store(1).unwrap().branes().len(), 2);
+        assert_eq!(cluster.get(b"k1").unwrap().unwrap(), b"v1");
+        assert_eq!(cluster.get(b"k9").unwrap().unwrap(), b"v9");
+
+        // A leader cut off from the others is replaced.
+        let brane_id = cluster.lookup_brane(b"k1").unwrap().id;
+        let leader = cluster.wait_leader(brane_id).unwrap();
+        let others: Vec<u64> = (1..=3).filter(|&s| s != leader.store_id).collect();
+        cluster.partition(&[leader.store_id], &others);
+        cluster.put(b"k2", b"v2").unwrap();
+        cluster.heal();
+        assert_eq!(cluster.get(b"k2").unwrap().unwrap(), b"v2");
+    }
+
+    #[test]
+    fn test_follower_and_stale_reads() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        cluster.put(b"k1", b"v1").unwrap();
+        let brane = cluster.lookup_brane(b"k1").unwrap();
+        let leader = cluster.wait_leader(brane.id).unwrap();
+        let follower = *brane.peers.iter().find(|p| **p != leader).unwrap();
+
+        // A read on a follower waits for the index the leader confirms.
+        let read_on = |cluster: &mut Cluster, peer: Peer, consistency| {
+            let header = CmdHeader {
+                brane_id: brane.id,
+                peer,
+                brane_epoch: brane.brane_epoch,
+            };
+            let req = Request::Get {
+                namespaced: NAMESPACED_DEFAULT.to_owned(),
+                soliton_id: b"k1".to_vec(),
+            };
+            let (tx, rx) = mpsc::channel();
+            cluster.store_mut(peer.store_id).unwrap().read(
+                VioletaBFTCmdRequest::new(header, vec![req]),
+                consistency,
+                Box::new(move |res| tx.send(res).unwrap()),
+            );
+            cluster.settle().unwrap();
+            rx.try_recv().unwrap()
+        };
+        let resp = read_on(&mut cluster, follower, ReadConsistency::Follower).unwrap();
+        assert_eq!(resp.responses, vec![Response::Get(Some(b"v1".to_vec()))]);
+        assert_eq!(
+            cluster
+                .get_with(NAMESPACED_DEFAULT, b"k1", ReadConsistency::Follower)
+                .unwrap()
+                .unwrap(),
+            b"v1"
+        );
+
+        // A replica serves stale reads up to the timestamp the brane is closed
+        // at, and the cluster falls back to the leader past it.
+        assert!(matches!(
+            read_on(&mut cluster, follower, ReadConsistency::Stale(10)),
+            Err(Error::DataNotReady { closed_ts: 0, .. })
+        ));
+        cluster.close_ts(10).unwrap();
+        for p in &brane.peers {
+            let store = cluster.store(p.store_id).unwrap();
+            assert_eq!(store.peer(brane.id).unwrap().closed_ts(), 10);
+        }
+        assert!(read_on(&mut cluster, follower, ReadConsistency::Stale(10)).is_ok());
+        assert_eq!(
+            cluster
+                .get_with(NAMESPACED_DEFAULT, b"k1", ReadConsistency::Stale(11))
+                .unwrap()
+                .unwrap(),
+            b"v1"
+        );
+
+        // Both halves of a split are closed where the brane was.
+        let (_, right) = cluster.split(b"k5").unwrap();
+        let store = cluster.store(follower.store_id).unwrap();
+        assert_eq!(store.peer(right.id).unwrap().closed_ts(), 10);
+
+        // Without a leader, the followers still serve stale reads.
+        cluster.stop_store(leader.store_id);
+        let pairs = cluster
+            .scan_with(NAMESPACED_DEFAULT, b"", b"", 0, ReadConsistency::Stale(10))
+            .unwrap();
+        assert_eq!(pairs, vec![(b"k1".to_vec(), b"v1".to_vec())]);
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        applied: Mutex<Vec<(u64, AppliedCmd)>>,
+        changed: Mutex<Vec<(u64, u64)>>,
+    }
+
+    impl ApplyObserver for Recorder {
+        fn on_applied(&self, store_id: u64, _: &LsmEngine, cmds: &[AppliedCmd]) {
+            let mut applied = self.applied.lock().unwrap();
+            applied.extend(cmds.iter().map(|c| (store_id, c.clone())));
+        }
+
+        fn on_brane_changed(&self, store_id: u64, brane_id: u64) {
+            self.changed.lock().unwrap().push((store_id, brane_id));
+        }
+    }
+
+    #[test]
+    fn test_apply_observer() {
+        let dir = TempDir::new().unwrap();
+        let mut cluster = Cluster::new(dir.path(), 3, test_config()).unwrap();
+        let recorder = Arc::new(Recorder::default());
+        cluster.add_observer(recorder.clone());
+        cluster.put(b"a", b"1").unwrap();
+        cluster.get(b"a").unwrap();
+        cluster.delete(b"a").unwrap();
+
+        // Every store applies both writes, in order, and no read.
+        let brane = cluster.lookup_brane(b"a").unwrap();
+        let applied = recorder.applied.lock().unwrap().clone();
+        for store_id in 1..=3 {
+            let cmds: Vec<&AppliedCmd> = applied
+                .iter()
+                .filter(|(s, _)| *s == store_id)
+                .map(|(_, c)| c)
+                .collect();
+            assert_eq!(cmds.len(), 2);
+            assert!(cmds.iter().all(|c| c.brane_id == brane.id));
+            assert!(cmds[0].index < cmds[1].index);
+            assert!(matches!(cmds[0].requests[..], [Request::Put { .. }]));
+            assert!(matches!(cmds[1].requests[..], [Request::Delete { .. }]));
+        }
+        // Confirming reads through every replica.
+        for store_id in 1..=3 {
+            cluster.confirm_applied(&brane, store_id).unwrap();
+        }
+        assert_eq!(recorder.applied.lock().unwrap().len(), 6);
+
+        // A split and a stopped store are told.
+        cluster.split(b"m").unwrap();
+        cluster.stop_store(3);
+        let changed = recorder.changed.lock().unwrap().clone();
+        for store_id in 1..=3 {
+            assert!(changed.contains(&(store_id, brane.id)));
+        }
+        assert_eq!(changed.iter().filter(|(s, _)| *s == 3).count(), 3);
+        assert!(matches!(
+            cluster.confirm_applied(&brane, 1),
+            Err(Error::EpochNotMatch(..))
+        ));
+    }
+
+    #[test]
+    fn test_ingest_and_open() {
+        let dir = TempDir::new().unwrap();
+        let cluster_dir = dir.path().join("cluster");
+        let mut cluster = Cluster::new(&cluster_dir, 3, test_config()).unwrap();
+        cluster.split(b"m").unwrap();
+        cluster.put(b"b", b"old").unwrap();
+        let write_file = |name: &str, soliton_ids: &[&[u8]]| {
+            let path = dir.path().join(name);
+            let mut writer =
+                LsmSstWriter::create(&path, &LsmOptions::default(), Compression::None).unwrap();
+            for k in soliton_ids {
+                writer.put(&keys::data_key(k), b"ingested").unwrap();
+            }
+            writer.finish().unwrap()
+        };
+
+        let sst = write_file("1.sst", &[b"a", b"b"]);
+        cluster.ingest(NAMESPACED_DEFAULT, sst.clone()).unwrap();
+        // Every replica copied the file: it may go.
+        fs::remove_file(&sst.local_path).unwrap();
+        for store_id in 1..=3 {
+            let kv = cluster.store(store_id).unwrap().kv();
+            assert_eq!(
+                kv.get_value(&keys::data_key(b"b")).unwrap().unwrap(),
+                b"ingested"
+            );
+        }
+        cluster.put(b"a", b"new").unwrap();
+        assert_eq!(cluster.get(b"a").unwrap().unwrap(), b"new");
+        // A file across branes, or gone, is not ingested.
+        let across = write_file("2.sst", &[b"c", b"x"]);
+        assert!(cluster.ingest(NAMESPACED_DEFAULT, across).is_err());
+        assert!(cluster.ingest(NAMESPACED_DEFAULT, sst).is_err());
+        assert_eq!(cluster.get(b"c").unwrap(), None);
+
+        drop(cluster);
+        let id_allocator = Arc::new(SeqIdAllocator::new(100));
+        let mut cluster = Cluster::open(&cluster_dir, test_config(), id_allocator).unwrap();
+        assert_eq!(cluster.store_ids(), &[1, 2, 3]);
+        assert_eq!(cluster.branes().len(), 2);
+        assert_eq!(cluster.get(b"a").unwrap().unwrap(), b"new");
+        assert_eq!(cluster.get(b"b").unwrap().unwrap(), b"ingested");
+        cluster.split(b"t").unwrap();
+        assert!(Cluster::open(
+            &dir.path().join("none"),
+            test_config(),
+            Arc::new(SeqIdAllocator::new(0))
+        )
+        .is_err());
+    }
+}
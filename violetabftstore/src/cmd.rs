@@ -0,0 +1,595 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The commands proposed to a brane, and their responses.
+//!
+//! A command is encoded as the data of a normal entry, or, for a change of peers,
+//! as the context of a configuration change entry. Strong reads go through the
+//! log too, so that they see every write committed before them; the others are
+//! served by any replica, as `ReadConsistency` allows.
+
+use std::path::PathBuf;
+
+use fdb_traits::ExternalSstFileInfo;
+use violetabft::codec::{get_bytes, get_u8, get_varint, put_bytes, put_varint};
+use violetabft::{Codec, ConfChangeType, Error as CodecError, Result as CodecResult};
+
+use crate::brane::{get_peer, put_peer, Brane, BraneEpoch, Peer};
+use crate::errors::{Error, Result};
+use crate::keys;
+
+/// Who a command is for, and the epoch of the brane it was routed by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CmdHeader {
+    pub brane_id: u64,
+    pub peer: Peer,
+    pub brane_epoch: BraneEpoch,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Request {
+    Get {
+        namespaced: String,
+        soliton_id: Vec<u8>,
+    },
+    /// At most `limit` pairs of `[start_key, end_key)`, or all if it is 0, clipped
+    /// to the brane; an empty end is the end of the brane.
+    Scan {
+        namespaced: String,
+        start_key: Vec<u8>,
+        end_key: Vec<u8>,
+        limit: usize,
+    },
+    Put {
+        namespaced: String,
+        soliton_id: Vec<u8>,
+        causet_locale: Vec<u8>,
+    },
+    Delete {
+        namespaced: String,
+        soliton_id: Vec<u8>,
+    },
+    DeleteRange {
+        namespaced: String,
+        start_key: Vec<u8>,
+        end_key: Vec<u8>,
+    },
+    /// Ingests the external file `sst`, whose soliton_ids are data soliton_ids
+    /// of the brane, shadowing the versions of them there. Every replica
+    /// copies the file, which must stay where it is until they applied it.
+    IngestSst {
+        namespaced: String,
+        sst: ExternalSstFileInfo,
+    },
+}
+
+impl Request {
+    pub fn is_read(&self) -> bool {
+        matches!(self, Request::Get { .. } | Request::Scan { .. })
+    }
+
+    /// The soliton_id the request is routed by: the first of its range, if it has
+    /// one.
+    pub fn soliton_id(&self) -> &[u8] {
+        match self {
+            Request::Get { soliton_id, .. }
+            | Request::Put { soliton_id, .. }
+            | Request::Delete { soliton_id, .. } => soliton_id,
+            Request::Scan { start_key, .. } | Request::DeleteRange { start_key, .. } => start_key,
+            Request::IngestSst { sst, .. } => keys::origin_key(&sst.smallest_soliton_id),
+        }
+    }
+}
+
+/// How a read is served.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// By the leader, through the log.
+    #[default]
+    Strong,
+    /// By any replica, once it applied the log up to the commit index the
+    /// leader confirmed for the read: it sees every write committed before it.
+    Follower,
+    /// By any replica, from what it applied, if the brane is closed at the
+    /// timestamp: no write at or before it is applied later.
+    Stale(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminRequest {
+    /// Splits off `[split_key, end)` as brane `new_brane_id`, whose peers are on
+    /// the stores of the current ones, in order.
+    Split {
+        split_key: Vec<u8>,
+        new_brane_id: u64,
+        new_peer_ids: Vec<u64>,
+    },
+    /// Adds or removes peers; more than one voter at a time goes through a joint
+    /// configuration.
+    ChangePeer {
+        changes: Vec<(ConfChangeType, Peer)>,
+    },
+    /// Discards the log up to `compact_index`.
+    CompactLog {
+        compact_index: u64,
+        compact_term: u64,
+    },
+    /// Freezes the brane to merge it into `target`.
+    PrepareMerge { target: Brane },
+    /// Extends the brane over `source`, whose merge was prepared at `commit`.
+    CommitMerge { source: Brane, commit: u64 },
+    /// Unfreezes a brane whose merge, prepared at `commit`, failed.
+    RollbackMerge { commit: u64 },
+    /// Closes the brane at `ts`: the proposer promises that every write at or
+    /// before it was proposed already, so a replica that applied this entry can
+    /// serve stale reads at `ts`.
+    CloseTs { ts: u64 },
+    /// Has every replica holding data compute a checksum of the data of the
+    /// brane as of this entry, for them to be compared.
+    ComputeHash,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VioletaBFTCmdRequest {
+    pub header: CmdHeader,
+    pub requests: Vec<Request>,
+    pub admin: Option<AdminRequest>,
+}
+
+impl VioletaBFTCmdRequest {
+    pub fn new(header: CmdHeader, requests: Vec<Request>) -> VioletaBFTCmdRequest {
+        VioletaBFTCmdRequest {
+            header,
+            requests,
+            admin: None,
+        }
+    }
+
+    pub fn admin(header: CmdHeader, admin: AdminRequest) -> VioletaBFTCmdRequest {
+        VioletaBFTCmdRequest {
+            header,
+            requests: Vec::new(),
+            admin: Some(admin),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Response {
+    Get(Option<Vec<u8>>),
+    Scan(Vec<(Vec<u8>, Vec<u8>)>),
+    Put,
+    Delete,
+    DeleteRange,
+    IngestSst,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CmdResponse {
+    /// One for each request, in order.
+    pub responses: Vec<Response>,
+    /// The branes an admin command left, e.g. both halves of a split.
+    pub branes: Vec<Brane>,
+}
+
+/// Called once with the result of a proposed command.
+pub type Callback = Box<dyn FnOnce(Result<CmdResponse>) + Send>;
+
+/// Checks that a command was routed by the current epoch of `brane`, as far as the
+/// command cares: writes and reads need the same range, a change of peers the same
+/// peers.
+pub fn check_brane_epoch(req: &VioletaBFTCmdRequest, brane: &Brane) -> Result<()> {
+    let (check_ver, check_conf_ver) = match &req.admin {
+        None => (true, false),
+        Some(AdminRequest::CompactLog { .. }) | Some(AdminRequest::CloseTs { .. }) => {
+            (false, false)
+        }
+        Some(AdminRequest::ChangePeer { .. }) => (false, true),
+        Some(AdminRequest::ComputeHash) => (true, false),
+        Some(AdminRequest::Split { .. })
+        | Some(AdminRequest::PrepareMerge { .. })
+        | Some(AdminRequest::CommitMerge { .. })
+        | Some(AdminRequest::RollbackMerge { .. }) => (true, true),
+    };
+    let sent = req.header.brane_epoch;
+    let current = brane.brane_epoch;
+    if (check_ver && sent.version != current.version)
+        || (check_conf_ver && sent.conf_ver != current.conf_ver)
+    {
+        return Err(Error::EpochNotMatch(
+            format!(
+                "current epoch of brane {} is {:?}, but the command was sent with {:?}",
+                brane.id, current, sent
+            ),
+            vec![brane.clone()],
+        ));
+    }
+    Ok(())
+}
+
+const REQ_GET: u8 = 1;
+const REQ_SCAN: u8 = 2;
+const REQ_PUT: u8 = 3;
+const REQ_DELETE: u8 = 4;
+const REQ_DELETE_RANGE: u8 = 5;
+const REQ_INGEST_SST: u8 = 6;
+
+const ADMIN_SPLIT: u8 = 1;
+const ADMIN_CHANGE_PEER: u8 = 2;
+const ADMIN_COMPACT_LOG: u8 = 3;
+const ADMIN_PREPARE_MERGE: u8 = 4;
+const ADMIN_COMMIT_MERGE: u8 = 5;
+const ADMIN_ROLLBACK_MERGE: u8 = 6;
+const ADMIN_CLOSE_TS: u8 = 7;
+const ADMIN_COMPUTE_HASH: u8 = 8;
+
+fn put_str(buf: &mut Vec<u8>, s: &str) {
+    put_bytes(buf, s.as_bytes());
+}
+
+fn get_str(buf: &mut &[u8]) -> CodecResult<String> {
+    String::from_utf8(get_bytes(buf)?.to_vec())
+        .map_err(|_| CodecError::Corruption("invalid utf-8 string".to_owned()))
+}
+
+impl Codec for Request {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        match self {
+            Request::Get {
+                namespaced,
+                soliton_id,
+            } => {
+                buf.push(REQ_GET);
+                put_str(buf, namespaced);
+                put_bytes(buf, soliton_id);
+            }
+            Request::Scan {
+                namespaced,
+                start_key,
+                end_key,
+                limit,
+            } => {
+                buf.push(REQ_SCAN);
+                put_str(buf, namespaced);
+                put_bytes(buf, start_key);
+                put_bytes(buf, end_key);
+                put_varint(buf, *limit as u64);
+            }
+            Request::Put {
+                namespaced,
+                soliton_id,
+                causet_locale,
+            } => {
+                buf.push(REQ_PUT);
+                put_str(buf, namespaced);
+                put_bytes(buf, soliton_id);
+                put_bytes(buf, causet_locale);
+            }
+            Request::Delete {
+                namespaced,
+                soliton_id,
+            } => {
+                buf.push(REQ_DELETE);
+                put_str(buf, namespaced);
+                put_bytes(buf, soliton_id);
+            }
+            Request::DeleteRange {
+                namespaced,
+                start_key,
+                end_key,
+            } => {
+                buf.push(REQ_DELETE_RANGE);
+                put_str(buf, namespaced);
+                put_bytes(buf, start_key);
+                put_bytes(buf, end_key);
+            }
+            Request::IngestSst { namespaced, sst } => {
+                buf.push(REQ_INGEST_SST);
+                put_str(buf, namespaced);
+                put_str(buf, &sst.local_path.to_string_lossy());
+                put_bytes(buf, &sst.smallest_soliton_id);
+                put_bytes(buf, &sst.largest_soliton_id);
+                put_varint(buf, sst.num_entries);
+                put_varint(buf, sst.file_size);
+                put_varint(buf, sst.crc32 as u64);
+            }
+        }
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<Request> {
+        let tag = get_u8(buf)?;
+        let namespaced = get_str(buf)?;
+        Ok(match tag {
+            REQ_GET => Request::Get {
+                namespaced,
+                soliton_id: get_bytes(buf)?.to_vec(),
+            },
+            REQ_SCAN => Request::Scan {
+                namespaced,
+                start_key: get_bytes(buf)?.to_vec(),
+                end_key: get_bytes(buf)?.to_vec(),
+                limit: get_varint(buf)? as usize,
+            },
+            REQ_PUT => Request::Put {
+                namespaced,
+                soliton_id: get_bytes(buf)?.to_vec(),
+                causet_locale: get_bytes(buf)?.to_vec(),
+            },
+            REQ_DELETE => Request::Delete {
+                namespaced,
+                soliton_id: get_bytes(buf)?.to_vec(),
+            },
+            REQ_DELETE_RANGE => Request::DeleteRange {
+                namespaced,
+                start_key: get_bytes(buf)?.to_vec(),
+                end_key: get_bytes(buf)?.to_vec(),
+            },
+            REQ_INGEST_SST => Request::IngestSst {
+                namespaced,
+                sst: ExternalSstFileInfo {
+                    local_path: PathBuf::from(get_str(buf)?),
+                    smallest_soliton_id: get_bytes(buf)?.to_vec(),
+                    largest_soliton_id: get_bytes(buf)?.to_vec(),
+                    num_entries: get_varint(buf)?,
+                    file_size: get_varint(buf)?,
+                    crc32: get_varint(buf)? as u32,
+                },
+            },
+            t => return Err(CodecError::Corruption(format!("unknown request {}", t))),
+        })
+    }
+}
+
+impl Codec for AdminRequest {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        match self {
+            AdminRequest::Split {
+                split_key,
+                new_brane_id,
+                new_peer_ids,
+            } => {
+                buf.push(ADMIN_SPLIT);
+                put_bytes(buf, split_key);
+                put_varint(buf, *new_brane_id);
+                violetabft::codec::put_ids(buf, new_peer_ids);
+            }
+            AdminRequest::ChangePeer { changes } => {
+                buf.push(ADMIN_CHANGE_PEER);
+                put_varint(buf, changes.len() as u64);
+                for (change_type, peer) in changes {
+                    buf.push(match change_type {
+                        ConfChangeType::AddNode => 0,
+                        ConfChangeType::RemoveNode => 1,
+                        ConfChangeType::AddLearnerNode => 2,
+                    });
+                    put_peer(buf, peer);
+                }
+            }
+            AdminRequest::CompactLog {
+                compact_index,
+                compact_term,
+            } => {
+                buf.push(ADMIN_COMPACT_LOG);
+                put_varint(buf, *compact_index);
+                put_varint(buf, *compact_term);
+            }
+            AdminRequest::PrepareMerge { target } => {
+                buf.push(ADMIN_PREPARE_MERGE);
+                target.encode_to(buf);
+            }
+            AdminRequest::CommitMerge { source, commit } => {
+                buf.push(ADMIN_COMMIT_MERGE);
+                source.encode_to(buf);
+                put_varint(buf, *commit);
+            }
+            AdminRequest::RollbackMerge { commit } => {
+                buf.push(ADMIN_ROLLBACK_MERGE);
+                put_varint(buf, *commit);
+            }
+            AdminRequest::CloseTs { ts } => {
+                buf.push(ADMIN_CLOSE_TS);
+                put_varint(buf, *ts);
+            }
+            AdminRequest::ComputeHash => buf.push(ADMIN_COMPUTE_HASH),
+        }
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<AdminRequest> {
+        Ok(match get_u8(buf)? {
+            ADMIN_SPLIT => AdminRequest::Split {
+                split_key: get_bytes(buf)?.to_vec(),
+                new_brane_id: get_varint(buf)?,
+                new_peer_ids: violetabft::codec::get_ids(buf)?,
+            },
+            ADMIN_CHANGE_PEER => {
+                let n = get_varint(buf)?;
+                let mut changes = Vec::new();
+                for _ in 0..n {
+                    let change_type = match get_u8(buf)? {
+                        0 => ConfChangeType::AddNode,
+                        1 => ConfChangeType::RemoveNode,
+                        2 => ConfChangeType::AddLearnerNode,
+                        t => {
+                            return Err(CodecError::Corruption(format!(
+                                "unknown conf change type {}",
+                                t
+                            )))
+                        }
+                    };
+                    changes.push((change_type, get_peer(buf)?));
+                }
+                AdminRequest::ChangePeer { changes }
+            }
+            ADMIN_COMPACT_LOG => AdminRequest::CompactLog {
+                compact_index: get_varint(buf)?,
+                compact_term: get_varint(buf)?,
+            },
+            ADMIN_PREPARE_MERGE => AdminRequest::PrepareMerge {
+                target: Brane::decode_from(buf)?,
+            },
+            ADMIN_COMMIT_MERGE => AdminRequest::CommitMerge {
+                source: Brane::decode_from(buf)?,
+                commit: get_varint(buf)?,
+            },
+            ADMIN_ROLLBACK_MERGE => AdminRequest::RollbackMerge {
+                commit: get_varint(buf)?,
+            },
+            ADMIN_CLOSE_TS => AdminRequest::CloseTs {
+                ts: get_varint(buf)?,
+            },
+            ADMIN_COMPUTE_HASH => AdminRequest::ComputeHash,
+            t => {
+                return Err(CodecError::Corruption(format!(
+                    "unknown admin request {}",
+                    t
+                )))
+            }
+        })
+    }
+}
+
+impl Codec for VioletaBFTCmdRequest {
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        put_varint(buf, self.header.brane_id);
+        put_peer(buf, &self.header.peer);
+        put_varint(buf, self.header.brane_epoch.conf_ver);
+        put_varint(buf, self.header.brane_epoch.version);
+        put_varint(buf, self.requests.len() as u64);
+        for r in &self.requests {
+            r.encode_to(buf);
+        }
+        match &self.admin {
+            Some(admin) => {
+                buf.push(1);
+                admin.encode_to(buf);
+            }
+            None => buf.push(0),
+        }
+    }
+
+    fn decode_from(buf: &mut &[u8]) -> CodecResult<VioletaBFTCmdRequest> {
+        let header = CmdHeader {
+            brane_id: get_varint(buf)?,
+            peer: get_peer(buf)?,
+            brane_epoch: BraneEpoch {
+                conf_ver: get_varint(buf)?,
+                version: get_varint(buf)?,
+            },
+        };
+        let n = get_varint(buf)?;
+        let requests = (0..n)
+            .map(|_| Request::decode_from(buf))
+            .collect::<CodecResult<_>>()?;
+        let admin = match get_u8(buf)? {
+            0 => None,
+            _ => Some(AdminRequest::decode_from(buf)?),
+        };
+        Ok(VioletaBFTCmdRequest {
+            header,
+            requests,
+            admin,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brane::PeerRole;
+
+    #[test]
+    fn test_codec_and_epoch_check() {
+        let brane = Brane {
+            id: 2,
+            brane_epoch: BraneEpoch {
+                conf_ver: 1,
+                version: 3,
+            },
+            ..Default::default()
+        };
+        let header = CmdHeader {
+            brane_id: 2,
+            peer: Peer::new(3, 1),
+            brane_epoch: brane.brane_epoch,
+        };
+        let mut req = VioletaBFTCmdRequest::new(
+            header,
+            vec![
+                Request::Put {
+                    namespaced: "default".to_owned(),
+                    soliton_id: b"k".to_vec(),
+                    causet_locale: b"v".to_vec(),
+                },
+                Request::Scan {
+                    namespaced: "write".to_owned(),
+                    start_key: b"a".to_vec(),
+                    end_key: Vec::new(),
+                    limit: 10,
+                },
+                Request::IngestSst {
+                    namespaced: "write".to_owned(),
+                    sst: ExternalSstFileInfo {
+                        local_path: PathBuf::from("/import/1.sst"),
+                        smallest_soliton_id: keys::data_key(b"a"),
+                        largest_soliton_id: keys::data_key(b"c"),
+                        num_entries: 3,
+                        file_size: 4096,
+                        crc32: 0xdead_beef,
+                    },
+                },
+            ],
+        );
+        assert_eq!(VioletaBFTCmdRequest::decode(&req.encode()).unwrap(), req);
+        let admins = vec![
+            AdminRequest::Split {
+                split_key: b"m".to_vec(),
+                new_brane_id: 9,
+                new_peer_ids: vec![10, 11],
+            },
+            AdminRequest::ChangePeer {
+                changes: vec![
+                    (
+                        ConfChangeType::AddLearnerNode,
+                        Peer {
+                            role: PeerRole::Learner,
+                            ..Peer::new(4, 2)
+                        },
+                    ),
+                    (
+                        ConfChangeType::AddNode,
+                        Peer {
+                            is_witness: true,
+                            ..Peer::new(5, 3)
+                        },
+                    ),
+                ],
+            },
+            AdminRequest::CommitMerge {
+                source: brane.clone(),
+                commit: 7,
+            },
+            AdminRequest::CloseTs { ts: 42 },
+            AdminRequest::ComputeHash,
+        ];
+        for admin in admins {
+            let req = VioletaBFTCmdRequest::admin(header, admin);
+            assert_eq!(VioletaBFTCmdRequest::decode(&req.encode()).unwrap(), req);
+        }
+
+        // A write only cares about the range, a change of peers only about the
+        // peers.
+        assert!(check_brane_epoch(&req, &brane).is_ok());
+        req.header.brane_epoch.conf_ver = 0;
+        assert!(check_brane_epoch(&req, &brane).is_ok());
+        req.header.brane_epoch.version = 2;
+        assert!(matches!(
+            check_brane_epoch(&req, &brane),
+            Err(Error::EpochNotMatch(_, branes)) if branes == vec![brane.clone()]
+        ));
+        req.admin = Some(AdminRequest::ChangePeer {
+            changes: Vec::new(),
+        });
+        assert!(check_brane_epoch(&req, &brane).is_err());
+        req.header.brane_epoch.conf_ver = 1;
+        assert!(check_brane_epoch(&req, &brane).is_ok());
+    }
+}
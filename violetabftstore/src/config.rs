@@ -0,0 +1,89 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use crate::brane::Peer;
+use crate::errors::{Error, Result};
+
+const MB: u64 = 1024 * 1024;
+
+/// Options of a `Store`. Intervals are counted in ticks of the store.
+#[derive(Clone, Debug)]
+pub struct StoreConfig {
+    pub violetabft_election_ticks: usize,
+    pub violetabft_heartbeat_ticks: usize,
+    /// Whether writes to the log are synced before they are acknowledged.
+    pub sync_log: bool,
+    /// How often leaders check whether their brane outgrew `brane_max_size`.
+    pub split_check_ticks: u64,
+    /// The approximate size past which a brane is split in two.
+    pub brane_max_size: u64,
+    /// How often leaders compact the log entries every peer has.
+    pub log_gc_ticks: u64,
+    /// The applied entries past which the log is compacted even if a follower
+    /// still needs them; it is sent a snapshot instead.
+    pub log_gc_count_limit: u64,
+    /// The entries a compaction discards at least.
+    pub log_gc_threshold: u64,
+    /// How often adjacent branes are checked for a merge; 0 never.
+    pub merge_check_ticks: u64,
+    /// The size under which two adjacent branes are merged.
+    pub merge_max_size: u64,
+    /// How often the replicas of every brane are checked for consistency, and
+    /// those found corrupt repaired; 0 never.
+    pub consistency_check_ticks: u64,
+}
+
+impl Default for StoreConfig {
+    fn default() -> StoreConfig {
+        StoreConfig {
+            violetabft_election_ticks: 10,
+            violetabft_heartbeat_ticks: 2,
+            sync_log: true,
+            split_check_ticks: 10,
+            brane_max_size: 144 * MB,
+            log_gc_ticks: 10,
+            log_gc_count_limit: 72 * 1024,
+            log_gc_threshold: 50,
+            merge_check_ticks: 0,
+            merge_max_size: 20 * MB,
+            consistency_check_ticks: 0,
+        }
+    }
+}
+
+impl StoreConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.violetabft_heartbeat_ticks == 0 {
+            return Err(Error::Other(
+                "violetabft_heartbeat_ticks must be positive".to_owned(),
+            ));
+        }
+        if self.violetabft_election_ticks <= self.violetabft_heartbeat_ticks {
+            return Err(Error::Other(
+                "violetabft_election_ticks must be greater than violetabft_heartbeat_ticks"
+                    .to_owned(),
+            ));
+        }
+        if self.split_check_ticks == 0 || self.log_gc_ticks == 0 {
+            return Err(Error::Other(
+                "split_check_ticks and log_gc_ticks must be positive".to_owned(),
+            ));
+        }
+        if self.merge_check_ticks > 0 && self.merge_max_size >= self.brane_max_size {
+            return Err(Error::Other(
+                "merge_max_size must be less than brane_max_size".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// The configuration of the VioletaBFT node of `peer`; a witness never
+    /// campaigns.
+    pub(crate) fn violetabft_config(&self, peer: &Peer, applied: u64) -> violetabft::Config {
+        let mut cfg = violetabft::Config::new(peer.id);
+        cfg.election_tick = self.violetabft_election_ticks;
+        cfg.heartbeat_tick = self.violetabft_heartbeat_ticks;
+        cfg.applied = applied;
+        cfg.never_campaign = peer.is_witness;
+        cfg
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::brane::{Brane, Peer};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The store has no peer of the brane.
+    BraneNotFound(u64),
+    /// The peer is not the leader of its brane; the leader, if it is known.
+    NotLeader(u64, Option<Peer>),
+    /// The soliton_id is outside of the brane the request was routed to.
+    KeyNotInBrane {
+        soliton_id: Vec<u8>,
+        brane_id: u64,
+        start_key: Vec<u8>,
+        end_key: Vec<u8>,
+    },
+    /// The request was routed with an outdated epoch; the branes the store knows
+    /// now.
+    EpochNotMatch(String, Vec<Brane>),
+    /// The proposal was overwritten by a leader of a later term, or its peer was
+    /// destroyed, before it was applied. It may or may not take effect.
+    StaleCommand,
+    /// The brane is being merged and takes no other commands.
+    MergeInProgress(u64),
+    /// The replica can not serve a stale read at the timestamp: the brane is
+    /// closed up to `closed_ts` only.
+    DataNotReady {
+        brane_id: u64,
+        closed_ts: u64,
+    },
+    /// The peer of the brane is a witness, which holds no data to read.
+    IsWitness(u64),
+    VioletaBFT(violetabft::Error),
+    Engine(fdb_traits::Error),
+    Other(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BraneNotFound(id) => write!(f, "brane {} not found", id),
+            Error::NotLeader(id, leader) => {
+                write!(
+                    f,
+                    "peer is not the leader of brane {}, leader {:?}",
+                    id, leader
+                )
+            }
+            Error::KeyNotInBrane {
+                soliton_id,
+                brane_id,
+                start_key,
+                end_key,
+            } => write!(
+                f,
+                "soliton_id {:?} is not in brane {} [{:?}, {:?})",
+                soliton_id, brane_id, start_key, end_key
+            ),
+            Error::EpochNotMatch(msg, _) => write!(f, "epoch not match: {}", msg),
+            Error::StaleCommand => write!(f, "stale command"),
+            Error::MergeInProgress(id) => write!(f, "brane {} is being merged", id),
+            Error::DataNotReady {
+                brane_id,
+                closed_ts,
+            } => write!(
+                f,
+                "brane {} is only closed up to timestamp {}",
+                brane_id, closed_ts
+            ),
+            Error::IsWitness(id) => write!(f, "the peer of brane {} is a witness", id),
+            Error::VioletaBFT(e) => write!(f, "violetabft error: {}", e),
+            Error::Engine(e) => write!(f, "einstein_merkle_tree error: {}", e),
+            Error::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::VioletaBFT(e) => Some(e),
+            Error::Engine(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<violetabft::Error> for Error {
+    fn from(e: violetabft::Error) -> Error {
+        Error::VioletaBFT(e)
+    }
+}
+
+impl From<fdb_traits::Error> for Error {
+    fn from(e: fdb_traits::Error) -> Error {
+        Error::Engine(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
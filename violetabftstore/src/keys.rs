@@ -0,0 +1,105 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! How a store lays out its einstein_merkle_tree.
+//!
+//! The data of every brane is kept under `DATA_PREFIX` in each causet_merge
+//! family, so that a brane's data is one range of each. The state of the peers
+//! of the store is kept under `LOCAL_PREFIX` in the default causet_merge family:
+//!
+//! ```text
+//!   data soliton_id  ::= 'z' soliton_id
+//!   brane state ::= 0x01 0x03 brane_id: u64 BE 0x01
+//!   apply state ::= 0x01 0x02 brane_id: u64 BE 0x03
+//! ```
+
+pub const LOCAL_PREFIX: u8 = 0x01;
+pub const DATA_PREFIX: u8 = b'z';
+pub const DATA_MIN_KEY: &[u8] = &[DATA_PREFIX];
+pub const DATA_MAX_KEY: &[u8] = &[DATA_PREFIX + 1];
+
+const BRANE_META_PREFIX: u8 = 0x03;
+const BRANE_STATE_SUFFIX: u8 = 0x01;
+const APPLY_PREFIX: u8 = 0x02;
+const APPLY_STATE_SUFFIX: u8 = 0x03;
+
+/// The initial log of a brane ends at this index and term, so that a peer created
+/// by a message, which has none, is sent a snapshot.
+pub const VIOLETABFT_INIT_LOG_INDEX: u64 = 5;
+pub const VIOLETABFT_INIT_LOG_TERM: u64 = 5;
+
+pub fn data_key(soliton_id: &[u8]) -> Vec<u8> {
+    let mut k = Vec::with_capacity(soliton_id.len() + 1);
+    k.push(DATA_PREFIX);
+    k.extend_from_slice(soliton_id);
+    k
+}
+
+/// The data soliton_id of the end of a brane; an empty end is the end of the data.
+pub fn data_end_key(end_key: &[u8]) -> Vec<u8> {
+    if end_key.is_empty() {
+        DATA_MAX_KEY.to_vec()
+    } else {
+        data_key(end_key)
+    }
+}
+
+pub fn origin_key(data_key: &[u8]) -> &[u8] {
+    assert!(
+        data_key.first() == Some(&DATA_PREFIX),
+        "{:?} is not a data soliton_id",
+        data_key
+    );
+    &data_key[1..]
+}
+
+fn local_key(prefix: u8, brane_id: u64, suffix: u8) -> Vec<u8> {
+    let mut k = Vec::with_capacity(11);
+    k.push(LOCAL_PREFIX);
+    k.push(prefix);
+    k.extend_from_slice(&brane_id.to_be_bytes());
+    k.push(suffix);
+    k
+}
+
+pub fn brane_state_key(brane_id: u64) -> Vec<u8> {
+    local_key(BRANE_META_PREFIX, brane_id, BRANE_STATE_SUFFIX)
+}
+
+pub fn apply_state_key(brane_id: u64) -> Vec<u8> {
+    local_key(APPLY_PREFIX, brane_id, APPLY_STATE_SUFFIX)
+}
+
+/// The range holding the states of all branes.
+pub fn brane_meta_range() -> (Vec<u8>, Vec<u8>) {
+    (
+        vec![LOCAL_PREFIX, BRANE_META_PREFIX],
+        vec![LOCAL_PREFIX, BRANE_META_PREFIX + 1],
+    )
+}
+
+pub fn brane_id_from_state_key(soliton_id: &[u8]) -> Option<u64> {
+    if soliton_id.len() != 11 || soliton_id[..2] != [LOCAL_PREFIX, BRANE_META_PREFIX] {
+        return None;
+    }
+    Some(u64::from_be_bytes(soliton_id[2..10].try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(data_key(b"a"), b"za");
+        assert_eq!(origin_key(&data_key(b"a")), b"a");
+        assert_eq!(data_end_key(b""), DATA_MAX_KEY);
+        assert!(data_key(b"\xff\xff") < data_end_key(b""));
+
+        let (start, end) = brane_meta_range();
+        let k = brane_state_key(7);
+        assert!(start <= k && k < end);
+        assert_eq!(brane_id_from_state_key(&k), Some(7));
+        assert_eq!(brane_id_from_state_key(&apply_state_key(7)), None);
+        assert!(k.as_slice() < DATA_MIN_KEY);
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Multi-VioletaBFT: the soliton_id space is cut into branes, each replicated by
+//! its own VioletaBFT group, whose peers share the kv and log
+//! einstein_merkle_trees of their stores.
+//!
+//! A brane splits in two once it grows past a size, and two small adjacent branes
+//! merge. Every split, merge or change of peers bumps the brane's epoch, and a
+//! request routed by an older epoch is rejected with the branes as the store
+//! knows them now, so that the client's `BraneCache` catches up. Reads may be
+//! served by followers through read-index, or from any replica at a timestamp
+//! the brane was closed at. Observers follow what a store applies, for change
+//! data capture. Besides voters, a brane may have learners, which are sent the
+//! log but do not vote, and witnesses, which vote but keep only the log; the
+//! replicas holding data compute checksums of it at the same index, so that
+//! one that is corrupt is found and made again from a snapshot. `cluster` runs
+//! stores in one process over a deterministic network.
+
+mod apply;
+mod brane;
+pub mod cluster;
+mod cmd;
+mod config;
+mod errors;
+pub mod keys;
+mod observer;
+mod peer;
+mod peer_storage;
+mod router;
+mod store;
+mod transport;
+
+pub use crate::brane::{
+    ApplyState, Brane, BraneEpoch, BraneLocalState, MergeState, Peer, PeerRole, PeerState,
+};
+pub use crate::cmd::{
+    AdminRequest, Callback, CmdHeader, CmdResponse, ReadConsistency, Request, Response,
+    VioletaBFTCmdRequest,
+};
+pub use crate::config::StoreConfig;
+pub use crate::errors::{Error, Result};
+pub use crate::observer::{AppliedCmd, ApplyObserver};
+pub use crate::peer::BranePeer;
+pub use crate::peer_storage::{load_brane_state, write_initial_states, PeerStorage};
+pub use crate::router::BraneCache;
+pub use crate::store::{bootstrap_brane, IdAllocator, Store};
+pub use crate::transport::{Transport, VioletaBFTMessage};
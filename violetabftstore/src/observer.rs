@@ -0,0 +1,32 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Observing what a store applies.
+//!
+//! Every replica applies the same commands in the same order, so an observer
+//! of one store follows all the writes of a brane there, leader or not, as long
+//! as its data arrives by the log. A split or merge of the brane, a snapshot it
+//! takes or the removal of its peer is told instead, after which what the store
+//! applies for it may not be all of it.
+
+use soliton_lsm::LsmEngine;
+
+use crate::cmd::Request;
+
+/// The writes of a command a store applied, at `index` of its brane's log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedCmd {
+    pub brane_id: u64,
+    pub index: u64,
+    pub requests: Vec<Request>,
+}
+
+pub trait ApplyObserver: Send + Sync {
+    /// Called with the commands of store `store_id` that wrote to its kv
+    /// einstein_merkle_tree `kv`, in the order they were applied, once they are
+    /// written and before their proposers learn it.
+    fn on_applied(&self, store_id: u64, kv: &LsmEngine, cmds: &[AppliedCmd]);
+
+    /// Called when brane `brane_id` on store `store_id` changed its range, took
+    /// its data from a snapshot or lost its peer there.
+    fn on_brane_changed(&self, store_id: u64, brane_id: u64);
+}
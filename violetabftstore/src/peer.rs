@@ -0,0 +1,315 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A peer: the VioletaBFT node of one brane on a store, and the proposals and
+//! reads it waits for.
+
+use std::collections::{HashMap, VecDeque};
+
+use violetabft::{Codec, ConfChange, ConfChangeSingle, RawNode, ReadState, StateRole};
+
+use crate::brane::{Brane, Peer, PeerState};
+use crate::cmd::{
+    check_brane_epoch, AdminRequest, Callback, CmdHeader, Request, VioletaBFTCmdRequest,
+};
+use crate::config::StoreConfig;
+use crate::errors::{Error, Result};
+use crate::keys;
+use crate::peer_storage::PeerStorage;
+
+struct Proposal {
+    index: u64,
+    term: u64,
+    cb: Callback,
+}
+
+/// A read waiting for the leader to confirm its index, then for the peer to
+/// apply the log up to it.
+struct PendingRead {
+    id: u64,
+    index: Option<u64>,
+    req: VioletaBFTCmdRequest,
+    cb: Callback,
+}
+
+pub struct BranePeer {
+    pub peer: Peer,
+    pub raw_node: RawNode<PeerStorage>,
+    proposals: VecDeque<Proposal>,
+    reads: VecDeque<PendingRead>,
+    next_read_id: u64,
+    /// The timestamp the brane is closed at as of the applied log; not
+    /// persisted, so a restarted peer serves no stale read until it is closed
+    /// again.
+    pub(crate) closed_ts: u64,
+    /// The peers messages came from, which an uninitialized peer knows no other
+    /// way.
+    peer_cache: HashMap<u64, Peer>,
+    /// A committed `CommitMerge` waits for the local peer of its source brane to
+    /// apply the log up to the prepared merge: `(source id, commit)`.
+    pub(crate) wait_merge_source: Option<(u64, u64)>,
+    /// The checksum of the data of the brane as of the last `ComputeHash`
+    /// applied, and its index; not persisted.
+    pub(crate) consistency_hash: Option<(u64, u32)>,
+}
+
+impl BranePeer {
+    pub fn new(cfg: &StoreConfig, peer: Peer, storage: PeerStorage) -> Result<BranePeer> {
+        let applied = storage.applied_index();
+        let raw_node = RawNode::new(&cfg.violetabft_config(&peer, applied), storage)?;
+        Ok(BranePeer {
+            peer,
+            raw_node,
+            proposals: VecDeque::new(),
+            reads: VecDeque::new(),
+            next_read_id: 0,
+            closed_ts: 0,
+            peer_cache: HashMap::new(),
+            wait_merge_source: None,
+            consistency_hash: None,
+        })
+    }
+
+    pub fn storage(&self) -> &PeerStorage {
+        &self.raw_node.violetabft.violetabft_log.store
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut PeerStorage {
+        &mut self.raw_node.violetabft.violetabft_log.store
+    }
+
+    pub fn brane(&self) -> &Brane {
+        self.storage().brane()
+    }
+
+    pub fn brane_id(&self) -> u64 {
+        self.brane().id
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.storage().is_initialized()
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.raw_node.violetabft.state == StateRole::Leader
+    }
+
+    pub fn term(&self) -> u64 {
+        self.raw_node.violetabft.term
+    }
+
+    pub fn closed_ts(&self) -> u64 {
+        self.closed_ts
+    }
+
+    /// The index of the last `ComputeHash` the peer applied, and the checksum
+    /// of the data it computed there; a witness computes none.
+    pub fn consistency_hash(&self) -> Option<(u64, u32)> {
+        self.consistency_hash
+    }
+
+    pub fn leader(&self) -> Option<Peer> {
+        self.get_peer(self.raw_node.violetabft.leader_id)
+    }
+
+    pub(crate) fn get_peer(&self, peer_id: u64) -> Option<Peer> {
+        self.brane()
+            .peer(peer_id)
+            .or_else(|| self.peer_cache.get(&peer_id).copied())
+    }
+
+    pub(crate) fn cache_peer(&mut self, peer: Peer) {
+        self.peer_cache.insert(peer.id, peer);
+    }
+
+    pub fn header(&self) -> CmdHeader {
+        CmdHeader {
+            brane_id: self.brane_id(),
+            peer: self.peer,
+            brane_epoch: self.brane().brane_epoch,
+        }
+    }
+
+    /// Checks `req` against the brane as the peer knows it, so that a request
+    /// that can not succeed is not replicated.
+    fn pre_propose(&self, req: &VioletaBFTCmdRequest) -> Result<()> {
+        if !self.is_leader() {
+            return Err(Error::NotLeader(self.brane_id(), self.leader()));
+        }
+        let local_state = self.storage().local_state();
+        if local_state.state == PeerState::Merging
+            && !matches!(req.admin, Some(AdminRequest::RollbackMerge { .. }))
+        {
+            return Err(Error::MergeInProgress(self.brane_id()));
+        }
+        check_brane_epoch(req, self.brane())?;
+        for r in &req.requests {
+            check_request_keys(r, self.brane())?;
+        }
+        Ok(())
+    }
+
+    /// Proposes `req`; `cb` is called once it is applied, or fails.
+    pub fn propose(&mut self, req: VioletaBFTCmdRequest, cb: Callback) {
+        if let Err(e) = self.pre_propose(&req) {
+            cb(Err(e));
+            return;
+        }
+        let last_index = self.raw_node.violetabft.violetabft_log.last_index();
+        let res = match &req.admin {
+            Some(AdminRequest::ChangePeer { changes }) => {
+                let cc = ConfChange::new(
+                    changes
+                        .iter()
+                        .map(|(change_type, peer)| ConfChangeSingle {
+                            change_type: *change_type,
+                            node_id: peer.id,
+                        })
+                        .collect(),
+                );
+                self.raw_node.propose_conf_change(req.encode(), &cc)
+            }
+            _ => self.raw_node.propose(Vec::new(), req.encode()),
+        };
+        let index = self.raw_node.violetabft.violetabft_log.last_index();
+        match res {
+            Ok(()) if index > last_index => self.proposals.push_back(Proposal {
+                index,
+                term: self.term(),
+                cb,
+            }),
+            // Dropped, e.g. because a configuration change is pending.
+            Ok(()) | Err(violetabft::Error::ProposalDropped) => {
+                cb(Err(Error::VioletaBFT(violetabft::Error::ProposalDropped)))
+            }
+            Err(e) => cb(Err(e.into())),
+        }
+    }
+
+    /// Takes the callback of the proposal applied at `index` in `term`; proposals
+    /// before it were overwritten and fail.
+    pub(crate) fn take_callback(&mut self, index: u64, term: u64) -> Option<Callback> {
+        while let Some(p) = self.proposals.front() {
+            if p.index > index {
+                return None;
+            }
+            let p = self.proposals.pop_front().unwrap();
+            if p.index == index && p.term == term {
+                return Some(p.cb);
+            }
+            (p.cb)(Err(Error::StaleCommand));
+        }
+        None
+    }
+
+    /// Asks the leader for the index `req`, which only reads, must wait for;
+    /// the store serves it once the peer applied the log up to it.
+    pub fn read_index(&mut self, req: VioletaBFTCmdRequest, cb: Callback) {
+        if let Err(e) = self.pre_read(&req) {
+            cb(Err(e));
+            return;
+        }
+        let id = self.next_read_id;
+        self.next_read_id += 1;
+        match self.raw_node.read_index(id.to_be_bytes().to_vec()) {
+            Ok(()) => self.reads.push_back(PendingRead {
+                id,
+                index: None,
+                req,
+                cb,
+            }),
+            Err(e) => cb(Err(e.into())),
+        }
+    }
+
+    fn pre_read(&self, req: &VioletaBFTCmdRequest) -> Result<()> {
+        if self.storage().local_state().state == PeerState::Merging {
+            return Err(Error::MergeInProgress(self.brane_id()));
+        }
+        check_brane_epoch(req, self.brane())?;
+        for r in &req.requests {
+            check_request_keys(r, self.brane())?;
+        }
+        Ok(())
+    }
+
+    /// Records the indexes the leader confirmed for pending reads.
+    pub(crate) fn on_read_states(&mut self, states: Vec<ReadState>) {
+        for rs in states {
+            let id = match <[u8; 8]>::try_from(rs.request_ctx.as_slice()) {
+                Ok(ctx) => u64::from_be_bytes(ctx),
+                Err(_) => continue,
+            };
+            if let Some(read) = self.reads.iter_mut().find(|r| r.id == id) {
+                read.index = Some(rs.index);
+            }
+        }
+    }
+
+    /// Fails the reads whose index is not confirmed yet: the leadership changed,
+    /// and their requests were dropped.
+    pub(crate) fn fail_unconfirmed_reads(&mut self) {
+        let (confirmed, dropped): (VecDeque<_>, VecDeque<_>) =
+            self.reads.drain(..).partition(|r| r.index.is_some());
+        self.reads = confirmed;
+        for r in dropped {
+            (r.cb)(Err(Error::StaleCommand));
+        }
+    }
+
+    /// Takes the reads the applied log allows to serve.
+    pub(crate) fn take_ready_reads(&mut self) -> Vec<(VioletaBFTCmdRequest, Callback)> {
+        let applied = self.storage().applied_index();
+        let (ready, waiting): (VecDeque<_>, VecDeque<_>) = self
+            .reads
+            .drain(..)
+            .partition(|r| r.index.is_some_and(|i| i <= applied));
+        self.reads = waiting;
+        ready.into_iter().map(|r| (r.req, r.cb)).collect()
+    }
+
+    /// Fails every pending proposal and read, as the peer is destroyed.
+    pub(crate) fn clear_proposals(&mut self) {
+        for p in self.proposals.drain(..) {
+            (p.cb)(Err(Error::StaleCommand));
+        }
+        for r in self.reads.drain(..) {
+            (r.cb)(Err(Error::StaleCommand));
+        }
+    }
+}
+
+pub(crate) fn check_request_keys(r: &Request, brane: &Brane) -> Result<()> {
+    match r {
+        Request::Get { soliton_id, .. }
+        | Request::Put { soliton_id, .. }
+        | Request::Delete { soliton_id, .. } => brane.check_key(soliton_id),
+        Request::Scan { start_key, .. } => brane.check_key(start_key),
+        Request::DeleteRange {
+            start_key, end_key, ..
+        } => {
+            brane.check_key(start_key)?;
+            if !end_key.is_empty() && keys::data_key(end_key) > keys::data_end_key(&brane.end_key) {
+                return Err(Error::KeyNotInBrane {
+                    soliton_id: end_key.clone(),
+                    brane_id: brane.id,
+                    start_key: brane.start_key.clone(),
+                    end_key: brane.end_key.clone(),
+                });
+            }
+            Ok(())
+        }
+        Request::IngestSst { sst, .. } => {
+            for data_key in [&sst.smallest_soliton_id, &sst.largest_soliton_id] {
+                if !data_key.starts_with(keys::DATA_MIN_KEY) {
+                    return Err(Error::Other(format!(
+                        "{:?} of external file {} is not a data soliton_id",
+                        data_key,
+                        sst.local_path.display()
+                    )));
+                }
+                brane.check_key(keys::origin_key(data_key))?;
+            }
+            Ok(())
+        }
+    }
+}
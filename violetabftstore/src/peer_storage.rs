@@ -0,0 +1,537 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! The storage of a peer: its log in the log einstein_merkle_tree, under the id of
+//! its brane, and its states and data in the kv einstein_merkle_tree.
+
+use fdb_traits::{
+    Iterable, Mutable, Peekable, SnapshotExt, VioletaBFTCmd, VioletaBFTKeyscapeSpline,
+    VioletaBFTLocalState, VioletaBFTLogBatch, WriteBatch, WriteBatchExt, WriteOptions,
+    NAMESPACED_DEFAULT,
+};
+use soliton_lsm::{LsmEngine, LsmSnapshot, LsmWriteBatch};
+use violetabft::codec::{get_bytes, get_varint, put_bytes, put_varint};
+use violetabft::{
+    Codec, ConfState, Entry, HardState, RaftState, Snapshot, SnapshotMetadata, Storage,
+    StorageError,
+};
+use violetabft_log_engine::{LogBatch, VioletaBFTLogEngine};
+
+use crate::brane::{ApplyState, Brane, BraneLocalState, PeerState};
+use crate::errors::{Error, Result};
+use crate::keys::{self, VIOLETABFT_INIT_LOG_INDEX, VIOLETABFT_INIT_LOG_TERM};
+
+fn storage_error(e: impl ToString) -> violetabft::Error {
+    StorageError::Other(e.to_string()).into()
+}
+
+pub(crate) fn init_raft_state() -> VioletaBFTLocalState {
+    VioletaBFTLocalState {
+        hard_state: HardState {
+            term: VIOLETABFT_INIT_LOG_TERM,
+            vote: 0,
+            commit: VIOLETABFT_INIT_LOG_INDEX,
+        },
+        last_index: VIOLETABFT_INIT_LOG_INDEX,
+    }
+}
+
+pub(crate) fn init_apply_state() -> ApplyState {
+    ApplyState {
+        applied_index: VIOLETABFT_INIT_LOG_INDEX,
+        truncated_index: VIOLETABFT_INIT_LOG_INDEX,
+        truncated_term: VIOLETABFT_INIT_LOG_TERM,
+    }
+}
+
+/// Adds to `wb` the states of a new peer of `brane`, whose log ends at the
+/// initial index.
+pub fn write_initial_states(
+    wb: &mut LsmWriteBatch,
+    brane: &Brane,
+    conf_state: ConfState,
+) -> Result<()> {
+    let local_state = BraneLocalState {
+        brane: brane.clone(),
+        conf_state,
+        ..Default::default()
+    };
+    wb.put(&keys::brane_state_key(brane.id), &local_state.encode())?;
+    wb.put(
+        &keys::apply_state_key(brane.id),
+        &init_apply_state().encode(),
+    )?;
+    Ok(())
+}
+
+pub fn load_brane_state(kv: &LsmEngine, brane_id: u64) -> Result<Option<BraneLocalState>> {
+    match kv.get_value(&keys::brane_state_key(brane_id))? {
+        Some(v) => Ok(Some(BraneLocalState::decode(&v)?)),
+        None => Ok(None),
+    }
+}
+
+/// Adds to `wb` the deletion of the data of `brane` in every causet_merge family.
+pub(crate) fn delete_brane_data(
+    kv: &LsmEngine,
+    wb: &mut LsmWriteBatch,
+    brane: &Brane,
+) -> Result<()> {
+    let start = keys::data_key(&brane.start_key);
+    let end = keys::data_end_key(&brane.end_key);
+    for namespaced in kv.namespaced_names() {
+        wb.delete_range_namespaced(&namespaced, &start, &end)?;
+    }
+    Ok(())
+}
+
+/// A checksum of the data of `brane` in every causet_merge family.
+pub(crate) fn compute_hash(kv: &LsmEngine, brane: &Brane) -> Result<u32> {
+    let start = keys::data_key(&brane.start_key);
+    let end = keys::data_end_key(&brane.end_key);
+    let mut hasher = crc32fast::Hasher::new();
+    for namespaced in kv.namespaced_names() {
+        hasher.update(namespaced.as_bytes());
+        kv.scan_namespaced(&namespaced, &start, &end, false, |k, v| {
+            hasher.update(&(k.len() as u64).to_le_bytes());
+            hasher.update(k);
+            hasher.update(&(v.len() as u64).to_le_bytes());
+            hasher.update(v);
+            Ok(true)
+        })?;
+    }
+    Ok(hasher.finalize())
+}
+
+pub struct PeerStorage {
+    kv: LsmEngine,
+    log: VioletaBFTLogEngine,
+    local_state: BraneLocalState,
+    apply_state: ApplyState,
+    raft_state: VioletaBFTLocalState,
+    last_term: u64,
+}
+
+impl PeerStorage {
+    /// Loads the storage of a peer of an initialized brane.
+    pub fn load(
+        kv: LsmEngine,
+        log: VioletaBFTLogEngine,
+        local_state: BraneLocalState,
+    ) -> Result<PeerStorage> {
+        let brane_id = local_state.brane.id;
+        let apply_state = match kv.get_value(&keys::apply_state_key(brane_id))? {
+            Some(v) => ApplyState::decode(&v)?,
+            None => {
+                return Err(Error::Other(format!(
+                    "apply state of brane {} is missing",
+                    brane_id
+                )))
+            }
+        };
+        let mut raft_state = match log.get_violetabft_state(brane_id)? {
+            Some(state) => state,
+            // Created by a split whose log state was not written yet.
+            None if apply_state == init_apply_state() => init_raft_state(),
+            None => {
+                return Err(Error::Other(format!(
+                    "log state of brane {} is missing",
+                    brane_id
+                )))
+            }
+        };
+        // The kv einstein_merkle_tree is written first: after a crash the log may
+        // lag a snapshot applied to it.
+        if raft_state.last_index < apply_state.truncated_index {
+            raft_state.last_index = apply_state.truncated_index;
+        }
+        let hs = &mut raft_state.hard_state;
+        hs.commit = hs.commit.max(apply_state.applied_index);
+        hs.term = hs.term.max(apply_state.truncated_term);
+        let last_term = if raft_state.last_index == apply_state.truncated_index {
+            apply_state.truncated_term
+        } else {
+            match log.get_entry(brane_id, raft_state.last_index)? {
+                Some(e) => e.term,
+                None => {
+                    return Err(Error::Other(format!(
+                        "last entry {} of brane {} is missing",
+                        raft_state.last_index, brane_id
+                    )))
+                }
+            }
+        };
+        Ok(PeerStorage {
+            kv,
+            log,
+            local_state,
+            apply_state,
+            raft_state,
+            last_term,
+        })
+    }
+
+    /// The storage of a peer created by a message, which knows nothing of its
+    /// brane until it is sent a snapshot.
+    pub fn uninitialized(
+        kv: LsmEngine,
+        log: VioletaBFTLogEngine,
+        brane_id: u64,
+    ) -> Result<PeerStorage> {
+        // Keep what the peer voted for before a restart.
+        let hard_state = log
+            .get_violetabft_state(brane_id)?
+            .map(|s| s.hard_state)
+            .unwrap_or_default();
+        Ok(PeerStorage {
+            kv,
+            log,
+            local_state: BraneLocalState {
+                brane: Brane {
+                    id: brane_id,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            apply_state: ApplyState::default(),
+            raft_state: VioletaBFTLocalState {
+                hard_state: HardState {
+                    commit: 0,
+                    ..hard_state
+                },
+                last_index: 0,
+            },
+            last_term: 0,
+        })
+    }
+
+    pub fn brane(&self) -> &Brane {
+        &self.local_state.brane
+    }
+
+    pub fn local_state(&self) -> &BraneLocalState {
+        &self.local_state
+    }
+
+    pub(crate) fn local_state_mut(&mut self) -> &mut BraneLocalState {
+        &mut self.local_state
+    }
+
+    pub fn apply_state(&self) -> &ApplyState {
+        &self.apply_state
+    }
+
+    pub(crate) fn apply_state_mut(&mut self) -> &mut ApplyState {
+        &mut self.apply_state
+    }
+
+    pub fn raft_state(&self) -> &VioletaBFTLocalState {
+        &self.raft_state
+    }
+
+    pub fn applied_index(&self) -> u64 {
+        self.apply_state.applied_index
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        !self.local_state.brane.peers.is_empty()
+    }
+
+    pub(crate) fn set_hard_state(&mut self, hs: HardState) {
+        self.raft_state.hard_state = hs;
+    }
+
+    /// Adds the entries to `batch`, replacing those from the first of them on.
+    pub(crate) fn append(&mut self, entries: &[Entry], batch: &mut LogBatch) -> Result<()> {
+        let last = match entries.last() {
+            Some(e) => (e.index, e.term),
+            None => return Ok(()),
+        };
+        batch.append(self.local_state.brane.id, entries.to_vec())?;
+        self.raft_state.last_index = last.0;
+        self.last_term = last.1;
+        Ok(())
+    }
+
+    /// Builds a snapshot of the brane at the index applied to `kv_snap`.
+    fn generate_snapshot(&self, kv_snap: &LsmSnapshot) -> Result<Snapshot> {
+        let brane_id = self.local_state.brane.id;
+        let local_state = match kv_snap.get_value(&keys::brane_state_key(brane_id))? {
+            Some(v) => BraneLocalState::decode(&v)?,
+            None => return Err(Error::BraneNotFound(brane_id)),
+        };
+        let apply_state = match kv_snap.get_value(&keys::apply_state_key(brane_id))? {
+            Some(v) => ApplyState::decode(&v)?,
+            None => return Err(Error::BraneNotFound(brane_id)),
+        };
+        let mut data = local_state.encode();
+        let brane = &local_state.brane;
+        let start = keys::data_key(&brane.start_key);
+        let end = keys::data_end_key(&brane.end_key);
+        for namespaced in self.kv.namespaced_names() {
+            let mut pairs = Vec::new();
+            kv_snap.scan_namespaced(&namespaced, &start, &end, false, |k, v| {
+                pairs.push((k.to_vec(), v.to_vec()));
+                Ok(true)
+            })?;
+            put_bytes(&mut data, namespaced.as_bytes());
+            put_varint(&mut data, pairs.len() as u64);
+            for (k, v) in pairs {
+                put_bytes(&mut data, &k);
+                put_bytes(&mut data, &v);
+            }
+        }
+        let index = apply_state.applied_index;
+        Ok(Snapshot {
+            data,
+            metadata: SnapshotMetadata {
+                conf_state: local_state.conf_state,
+                index,
+                term: self.term(index)?,
+            },
+        })
+    }
+
+    /// Replaces the data and states of the peer by `snapshot`, in the kv
+    /// einstein_merkle_tree at once and in `batch` for the log. A `witness`
+    /// takes the states only.
+    pub(crate) fn apply_snapshot(
+        &mut self,
+        snapshot: &Snapshot,
+        batch: &mut LogBatch,
+        witness: bool,
+    ) -> Result<()> {
+        let brane_id = self.local_state.brane.id;
+        let mut data = snapshot.data.as_slice();
+        let local_state = BraneLocalState::decode_from(&mut data)?;
+        if local_state.brane.id != brane_id {
+            return Err(Error::Other(format!(
+                "snapshot of brane {} applied to brane {}",
+                local_state.brane.id, brane_id
+            )));
+        }
+        let index = snapshot.metadata.index;
+        let term = snapshot.metadata.term;
+        let apply_state = ApplyState {
+            applied_index: index,
+            truncated_index: index,
+            truncated_term: term,
+        };
+
+        let mut wb = self.kv.write_alexandrov_poset_process();
+        if self.is_initialized() {
+            delete_brane_data(&self.kv, &mut wb, &self.local_state.brane)?;
+        }
+        delete_brane_data(&self.kv, &mut wb, &local_state.brane)?;
+        while !data.is_empty() {
+            let namespaced = String::from_utf8(get_bytes(&mut data)?.to_vec())
+                .map_err(|_| Error::Other("invalid causet_merge family in snapshot".to_owned()))?;
+            for _ in 0..get_varint(&mut data)? {
+                let k = get_bytes(&mut data)?;
+                let v = get_bytes(&mut data)?;
+                if !witness {
+                    wb.put_namespaced(&namespaced, k, v)?;
+                }
+            }
+        }
+        wb.put_namespaced(
+            NAMESPACED_DEFAULT,
+            &keys::brane_state_key(brane_id),
+            &local_state.encode(),
+        )?;
+        wb.put_namespaced(
+            NAMESPACED_DEFAULT,
+            &keys::apply_state_key(brane_id),
+            &apply_state.encode(),
+        )?;
+        let mut opts = WriteOptions::default();
+        opts.set_sync(true);
+        wb.write_opt(&opts)?;
+
+        self.log.clean(brane_id, 0, &self.raft_state, batch)?;
+        self.raft_state.last_index = index;
+        let hs = &mut self.raft_state.hard_state;
+        hs.commit = hs.commit.max(index);
+        hs.term = hs.term.max(term);
+        self.last_term = term;
+        self.local_state = local_state;
+        self.apply_state = apply_state;
+        Ok(())
+    }
+
+    /// Adds to `wb` the tombstone of the peer, and the deletion of its data unless
+    /// `keep_data`, and to `batch` the deletion of its log.
+    pub(crate) fn clear(
+        &mut self,
+        wb: &mut LsmWriteBatch,
+        batch: &mut LogBatch,
+        keep_data: bool,
+    ) -> Result<()> {
+        let brane_id = self.local_state.brane.id;
+        if self.is_initialized() && !keep_data {
+            delete_brane_data(&self.kv, wb, &self.local_state.brane)?;
+        }
+        self.local_state.state = PeerState::Tombstone;
+        self.local_state.merge_state = None;
+        wb.put(&keys::brane_state_key(brane_id), &self.local_state.encode())?;
+        wb.delete(&keys::apply_state_key(brane_id))?;
+        self.log.clean(brane_id, 0, &self.raft_state, batch)?;
+        Ok(())
+    }
+}
+
+impl Storage for PeerStorage {
+    fn initial_state(&self) -> violetabft::Result<RaftState> {
+        Ok(RaftState {
+            hard_state: self.raft_state.hard_state,
+            conf_state: self.local_state.conf_state.clone(),
+        })
+    }
+
+    fn entries(
+        &self,
+        low: u64,
+        high: u64,
+        max_size: Option<u64>,
+    ) -> violetabft::Result<Vec<Entry>> {
+        if low <= self.apply_state.truncated_index {
+            return Err(StorageError::Compacted.into());
+        }
+        if high > self.raft_state.last_index + 1 {
+            return Err(StorageError::Unavailable.into());
+        }
+        let mut entries = Vec::with_capacity((high - low) as usize);
+        if low < high {
+            self.log
+                .fetch_entries_to(
+                    self.local_state.brane.id,
+                    low,
+                    high,
+                    max_size.map(|s| s as usize),
+                    &mut entries,
+                )
+                .map_err(storage_error)?;
+        }
+        Ok(entries)
+    }
+
+    fn term(&self, idx: u64) -> violetabft::Result<u64> {
+        if idx == self.apply_state.truncated_index {
+            return Ok(self.apply_state.truncated_term);
+        }
+        if idx < self.apply_state.truncated_index {
+            return Err(StorageError::Compacted.into());
+        }
+        if idx > self.raft_state.last_index {
+            return Err(StorageError::Unavailable.into());
+        }
+        if idx == self.raft_state.last_index {
+            return Ok(self.last_term);
+        }
+        match self
+            .log
+            .get_entry(self.local_state.brane.id, idx)
+            .map_err(storage_error)?
+        {
+            Some(e) => Ok(e.term),
+            None => Err(StorageError::Unavailable.into()),
+        }
+    }
+
+    fn first_index(&self) -> violetabft::Result<u64> {
+        Ok(self.apply_state.truncated_index + 1)
+    }
+
+    fn last_index(&self) -> violetabft::Result<u64> {
+        Ok(self.raft_state.last_index)
+    }
+
+    fn snapshot(&self, _request_index: u64) -> violetabft::Result<Snapshot> {
+        if !self.is_initialized() {
+            return Err(StorageError::SnapshotTemporarilyUnavailable.into());
+        }
+        self.generate_snapshot(&self.kv.snapshot())
+            .map_err(storage_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use soliton_lsm::LsmOptions;
+    use violetabft_log_engine::VioletaBFTLogConfig;
+
+    use super::*;
+    use crate::brane::Peer;
+
+    fn engines(dir: &std::path::Path) -> (LsmEngine, VioletaBFTLogEngine) {
+        let kv = LsmEngine::open(dir.join("kv"), LsmOptions::default()).unwrap();
+        let log = VioletaBFTLogEngine::open(VioletaBFTLogConfig::new(
+            dir.join("violetabft").to_str().unwrap(),
+        ))
+        .unwrap();
+        (kv, log)
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let (kv, log) = engines(&dir.path().join("a"));
+        let brane = Brane {
+            id: 1,
+            start_key: b"b".to_vec(),
+            end_key: b"d".to_vec(),
+            peers: vec![Peer::new(2, 1)],
+            ..Default::default()
+        };
+        let mut wb = kv.write_alexandrov_poset_process();
+        write_initial_states(&mut wb, &brane, ConfState::with_voters(vec![2])).unwrap();
+        for k in [&b"a"[..], b"b", b"c", b"d"] {
+            wb.put(&keys::data_key(k), k).unwrap();
+        }
+        wb.write_opt(&WriteOptions::default()).unwrap();
+        let local_state = load_brane_state(&kv, 1).unwrap().unwrap();
+        let storage = PeerStorage::load(kv, log, local_state).unwrap();
+        assert_eq!(
+            storage.first_index().unwrap(),
+            VIOLETABFT_INIT_LOG_INDEX + 1
+        );
+        assert_eq!(storage.last_index().unwrap(), VIOLETABFT_INIT_LOG_INDEX);
+        assert_eq!(
+            storage.term(VIOLETABFT_INIT_LOG_INDEX).unwrap(),
+            VIOLETABFT_INIT_LOG_TERM
+        );
+        assert_eq!(
+            storage.entries(1, 5, None),
+            Err(StorageError::Compacted.into())
+        );
+        let snapshot = storage.snapshot(0).unwrap();
+        assert_eq!(snapshot.metadata.index, VIOLETABFT_INIT_LOG_INDEX);
+        assert_eq!(snapshot.metadata.conf_state.voters, vec![2]);
+
+        // Only the range of the brane is sent.
+        let (kv2, log2) = engines(&dir.path().join("b"));
+        kv2.put(&keys::data_key(b"c"), b"stale").unwrap();
+        let mut storage2 = PeerStorage::uninitialized(kv2.clone(), log2.clone(), 1).unwrap();
+        assert!(!storage2.is_initialized());
+        let mut batch = log2.log_alexandrov_poset_process(0);
+        storage2
+            .apply_snapshot(&snapshot, &mut batch, false)
+            .unwrap();
+        storage2
+            .log
+            .put_violetabft_state(1, &storage2.raft_state)
+            .unwrap();
+        assert_eq!(storage2.brane(), &brane);
+        assert_eq!(kv2.get_value(&keys::data_key(b"c")).unwrap().unwrap(), b"c");
+        assert_eq!(kv2.get_value(&keys::data_key(b"a")).unwrap(), None);
+        assert_eq!(kv2.get_value(&keys::data_key(b"d")).unwrap(), None);
+        drop(storage2);
+        let reloaded = PeerStorage::load(
+            kv2.clone(),
+            log2,
+            load_brane_state(&kv2, 1).unwrap().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(reloaded.applied_index(), VIOLETABFT_INIT_LOG_INDEX);
+        assert_eq!(reloaded.last_index().unwrap(), VIOLETABFT_INIT_LOG_INDEX);
+    }
+}
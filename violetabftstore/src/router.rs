@@ -0,0 +1,119 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! Client-side routing: which brane holds a soliton_id, and which of its peers
+//! leads it, as last learned. Stores reject requests routed by an outdated
+//! entry, and their answers update it.
+
+use std::collections::BTreeMap;
+use std::ops::Bound::{Excluded, Unbounded};
+
+use crate::brane::{Brane, Peer};
+use crate::keys;
+
+#[derive(Clone, Debug, Default)]
+pub struct BraneCache {
+    /// The branes, with their leaders if known, by the data soliton_id of their
+    /// end.
+    branes: BTreeMap<Vec<u8>, (Brane, Option<Peer>)>,
+}
+
+impl BraneCache {
+    pub fn new() -> BraneCache {
+        BraneCache::default()
+    }
+
+    pub fn locate(&self, soliton_id: &[u8]) -> Option<(&Brane, Option<Peer>)> {
+        let (_, (brane, leader)) = self
+            .branes
+            .range((Excluded(keys::data_key(soliton_id)), Unbounded))
+            .next()?;
+        brane.contains(soliton_id).then_some((brane, *leader))
+    }
+
+    pub fn get(&self, brane_id: u64) -> Option<&Brane> {
+        self.branes
+            .values()
+            .find(|(b, _)| b.id == brane_id)
+            .map(|(b, _)| b)
+    }
+
+    /// Records `brane` in place of the entries it overlaps, unless an entry of
+    /// the same brane is newer.
+    pub fn update(&mut self, brane: Brane) {
+        let end = keys::data_end_key(&brane.end_key);
+        let overlapping: Vec<Vec<u8>> = self
+            .branes
+            .range((Excluded(keys::data_key(&brane.start_key)), Unbounded))
+            .take_while(|(_, (b, _))| keys::data_key(&b.start_key) < end)
+            .map(|(k, _)| k.clone())
+            .collect();
+        let mut leader = None;
+        for k in &overlapping {
+            let (b, l) = &self.branes[k];
+            if b.id == brane.id {
+                if b.brane_epoch.is_stale(&brane.brane_epoch) || b.brane_epoch == brane.brane_epoch
+                {
+                    leader = l.filter(|l| brane.peer(l.id).is_some());
+                } else {
+                    return;
+                }
+            }
+        }
+        for k in overlapping {
+            self.branes.remove(&k);
+        }
+        self.branes.insert(end, (brane, leader));
+    }
+
+    pub fn update_leader(&mut self, brane_id: u64, leader: Option<Peer>) {
+        if let Some((b, l)) = self.branes.values_mut().find(|(b, _)| b.id == brane_id) {
+            *l = leader.filter(|l| b.peer(l.id).is_some());
+        }
+    }
+
+    pub fn invalidate(&mut self, brane_id: u64) {
+        self.branes.retain(|_, (b, _)| b.id != brane_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brane::BraneEpoch;
+
+    fn brane(id: u64, start: &[u8], end: &[u8], version: u64) -> Brane {
+        Brane {
+            id,
+            start_key: start.to_vec(),
+            end_key: end.to_vec(),
+            brane_epoch: BraneEpoch {
+                conf_ver: 1,
+                version,
+            },
+            peers: vec![Peer::new(id * 10, 1)],
+        }
+    }
+
+    #[test]
+    fn test_brane_cache() {
+        let mut cache = BraneCache::new();
+        cache.update(brane(1, b"", b"", 1));
+        cache.update_leader(1, Some(Peer::new(10, 1)));
+        assert_eq!(cache.locate(b"x").unwrap().1.unwrap().id, 10);
+
+        // A split replaces the parent, and the leader is kept.
+        cache.update(brane(1, b"", b"m", 2));
+        assert_eq!(cache.locate(b"a").unwrap().1.unwrap().id, 10);
+        assert!(cache.locate(b"m").is_none());
+        cache.update(brane(2, b"m", b"", 2));
+        assert_eq!(cache.locate(b"m").unwrap().0.id, 2);
+
+        // An outdated answer does not override a newer entry.
+        cache.update(brane(1, b"", b"", 1));
+        assert_eq!(cache.locate(b"m").unwrap().0.id, 2);
+        assert_eq!(cache.get(1).unwrap().end_key, b"m");
+
+        cache.invalidate(2);
+        assert!(cache.locate(b"z").is_none());
+    }
+}
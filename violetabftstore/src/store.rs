@@ -0,0 +1,810 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+//! A store: the peers of the branes on one node, over one kv and one log
+//! einstein_merkle_tree.
+//!
+//! The store owns no thread. Its owner ticks it, steps the messages it receives,
+//! proposes commands and calls `handle_ready` until it returns `false`, in the
+//! way a `RawNode` is driven.
+
+use std::collections::BTreeMap;
+use std::mem;
+use std::ops::Bound::{Excluded, Unbounded};
+use std::sync::Arc;
+
+use fdb_traits::{
+    Iterable, MiscExt, Mutable, RangeGreedoidsExt, VioletaBFTKeyscapeSpline, VioletaBFTLogBatch,
+    WriteBatch, WriteBatchExt, WriteOptions, NAMESPACED_DEFAULT,
+};
+use soliton_lsm::LsmEngine;
+use violetabft::{Codec, ConfChangeType, MessageType, Ready, Storage};
+use violetabft_log_engine::VioletaBFTLogEngine;
+
+use crate::apply::{apply_entry, exec_read, ApplyContext, ApplyOutcome, ExecResult};
+use crate::brane::{Brane, BraneLocalState, Peer, PeerState};
+use crate::cmd::{AdminRequest, Callback, ReadConsistency, Request, VioletaBFTCmdRequest};
+use crate::config::StoreConfig;
+use crate::errors::{Error, Result};
+use crate::keys;
+use crate::observer::ApplyObserver;
+use crate::peer::BranePeer;
+use crate::peer_storage::{init_raft_state, load_brane_state, write_initial_states, PeerStorage};
+use crate::transport::{Transport, VioletaBFTMessage};
+
+/// Allocates ids of branes and peers, unique in the cluster.
+pub trait IdAllocator: Send + Sync {
+    fn alloc_id(&self) -> Result<u64>;
+}
+
+pub struct Store<T: Transport> {
+    id: u64,
+    cfg: StoreConfig,
+    kv: LsmEngine,
+    log: VioletaBFTLogEngine,
+    trans: T,
+    id_allocator: Arc<dyn IdAllocator>,
+    peers: BTreeMap<u64, BranePeer>,
+    /// The initialized branes, by the data soliton_id of their end.
+    brane_ranges: BTreeMap<Vec<u8>, u64>,
+    ticks: u64,
+    observers: Vec<Arc<dyn ApplyObserver>>,
+}
+
+/// Writes the states of the first brane of a cluster on a store, whose peers
+/// have the roles they are given.
+pub fn bootstrap_brane(kv: &LsmEngine, log: &VioletaBFTLogEngine, brane: &Brane) -> Result<()> {
+    let (start, end) = keys::brane_meta_range();
+    let mut bootstrapped = false;
+    kv.scan_namespaced(NAMESPACED_DEFAULT, &start, &end, false, |_, _| {
+        bootstrapped = true;
+        Ok(false)
+    })?;
+    if bootstrapped {
+        return Err(Error::Other("the store is bootstrapped already".to_owned()));
+    }
+    let mut wb = kv.write_alexandrov_poset_process();
+    write_initial_states(&mut wb, brane, brane.conf_state())?;
+    let mut opts = WriteOptions::default();
+    opts.set_sync(true);
+    wb.write_opt(&opts)?;
+    log.put_violetabft_state(brane.id, &init_raft_state())?;
+    log.sync()?;
+    Ok(())
+}
+
+impl<T: Transport> Store<T> {
+    /// Opens the store, with a peer for each brane whose state it holds.
+    pub fn open(
+        id: u64,
+        cfg: StoreConfig,
+        kv: LsmEngine,
+        log: VioletaBFTLogEngine,
+        trans: T,
+        id_allocator: Arc<dyn IdAllocator>,
+    ) -> Result<Store<T>> {
+        cfg.validate()?;
+        let (start, end) = keys::brane_meta_range();
+        let mut states = Vec::new();
+        kv.scan_namespaced(NAMESPACED_DEFAULT, &start, &end, false, |_, v| {
+            states.push(v.to_vec());
+            Ok(true)
+        })?;
+        let mut store = Store {
+            id,
+            cfg,
+            kv,
+            log,
+            trans,
+            id_allocator,
+            peers: BTreeMap::new(),
+            brane_ranges: BTreeMap::new(),
+            ticks: 0,
+            observers: Vec::new(),
+        };
+        for v in states {
+            let state = BraneLocalState::decode(&v)?;
+            if state.state == PeerState::Tombstone {
+                continue;
+            }
+            let peer = state.brane.peer_on_store(id).ok_or_else(|| {
+                Error::Other(format!(
+                    "brane {} has no peer on store {}",
+                    state.brane.id, id
+                ))
+            })?;
+            let brane_id = state.brane.id;
+            let storage = PeerStorage::load(store.kv.clone(), store.log.clone(), state)?;
+            let peer = BranePeer::new(&store.cfg, peer, storage)?;
+            store
+                .brane_ranges
+                .insert(keys::data_end_key(&peer.brane().end_key), brane_id);
+            store.peers.insert(brane_id, peer);
+        }
+        Ok(store)
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn kv(&self) -> &LsmEngine {
+        &self.kv
+    }
+
+    pub fn log(&self) -> &VioletaBFTLogEngine {
+        &self.log
+    }
+
+    /// Shows `observer` the writes applied from now on.
+    pub fn add_observer(&mut self, observer: Arc<dyn ApplyObserver>) {
+        self.observers.push(observer);
+    }
+
+    fn notify_brane_changed(&self, brane_id: u64) {
+        for o in &self.observers {
+            o.on_brane_changed(self.id, brane_id);
+        }
+    }
+
+    pub fn peer(&self, brane_id: u64) -> Option<&BranePeer> {
+        self.peers.get(&brane_id)
+    }
+
+    /// The initialized branes of the store, in the order of their ranges.
+    pub fn branes(&self) -> Vec<Brane> {
+        self.brane_ranges
+            .values()
+            .map(|id| self.peers[id].brane().clone())
+            .collect()
+    }
+
+    /// The initialized brane holding `soliton_id` here.
+    pub fn brane_for_key(&self, soliton_id: &[u8]) -> Option<&Brane> {
+        let (_, id) = self
+            .brane_ranges
+            .range((Excluded(keys::data_key(soliton_id)), Unbounded))
+            .next()?;
+        let brane = self.peers[id].brane();
+        brane.contains(soliton_id).then_some(brane)
+    }
+
+    /// The ids of the initialized branes overlapping `[start_key, end_key)`.
+    fn overlapping_branes(&self, start_key: &[u8], end_key: &[u8]) -> Vec<u64> {
+        let end = keys::data_end_key(end_key);
+        let mut ids = Vec::new();
+        for (_, id) in self
+            .brane_ranges
+            .range((Excluded(keys::data_key(start_key)), Unbounded))
+        {
+            if keys::data_key(&self.peers[id].brane().start_key) >= end {
+                break;
+            }
+            ids.push(*id);
+        }
+        ids
+    }
+
+    fn update_range(&mut self, old_end: Option<&[u8]>, brane: &Brane) {
+        if let Some(old_end) = old_end {
+            let old_end = keys::data_end_key(old_end);
+            if self.brane_ranges.get(&old_end) == Some(&brane.id) {
+                self.brane_ranges.remove(&old_end);
+            }
+        }
+        self.brane_ranges
+            .insert(keys::data_end_key(&brane.end_key), brane.id);
+    }
+
+    /// Proposes `req` to the peer it is for; `cb` is called once it is applied, or
+    /// fails.
+    pub fn propose(&mut self, req: VioletaBFTCmdRequest, cb: Callback) {
+        let brane_id = req.header.brane_id;
+        match self.peers.get_mut(&brane_id) {
+            Some(peer) if peer.is_initialized() && peer.peer.id == req.header.peer.id => {
+                peer.propose(req, cb)
+            }
+            _ => cb(Err(Error::BraneNotFound(brane_id))),
+        }
+    }
+
+    /// Serves `req`, which only reads, by the peer it is for as `consistency`
+    /// asks; `cb` is called with the result. A stale read is served at once, or
+    /// fails if the brane is not closed at its timestamp here.
+    pub fn read(&mut self, req: VioletaBFTCmdRequest, consistency: ReadConsistency, cb: Callback) {
+        if req.admin.is_some() || !req.requests.iter().all(Request::is_read) {
+            cb(Err(Error::Other(
+                "a command that writes can not be served as a read".to_owned(),
+            )));
+            return;
+        }
+        let brane_id = req.header.brane_id;
+        let peer = match self.peers.get_mut(&brane_id) {
+            Some(peer) if peer.is_initialized() && peer.peer.id == req.header.peer.id => peer,
+            _ => return cb(Err(Error::BraneNotFound(brane_id))),
+        };
+        if peer.peer.is_witness && consistency != ReadConsistency::Strong {
+            return cb(Err(Error::IsWitness(brane_id)));
+        }
+        match consistency {
+            ReadConsistency::Strong => peer.propose(req, cb),
+            ReadConsistency::Follower => peer.read_index(req, cb),
+            ReadConsistency::Stale(ts) if peer.closed_ts() < ts => cb(Err(Error::DataNotReady {
+                brane_id,
+                closed_ts: peer.closed_ts(),
+            })),
+            ReadConsistency::Stale(_) => cb(exec_read(&self.kv, peer, req)),
+        }
+    }
+
+    /// Proposes an admin command on behalf of the store; its result is only
+    /// seen through the brane.
+    fn propose_admin(&mut self, brane_id: u64, admin: AdminRequest) {
+        if let Some(peer) = self.peers.get_mut(&brane_id) {
+            let req = VioletaBFTCmdRequest::admin(peer.header(), admin);
+            peer.propose(req, Box::new(|_| {}));
+        }
+    }
+
+    /// Proposes a change of the peers of a brane led here on behalf of the
+    /// placement driver; its outcome shows in the brane.
+    pub fn change_peer(
+        &mut self,
+        brane_id: u64,
+        changes: Vec<(ConfChangeType, Peer)>,
+    ) -> Result<()> {
+        self.check_leader(brane_id)?;
+        self.propose_admin(brane_id, AdminRequest::ChangePeer { changes });
+        Ok(())
+    }
+
+    /// Hands the leadership of a brane led here over to `to`, which must be a
+    /// voter holding the data.
+    pub fn transfer_leader(&mut self, brane_id: u64, to: Peer) -> Result<()> {
+        self.check_leader(brane_id)?;
+        if !to.can_lead() {
+            return Err(Error::Other(format!(
+                "peer {:?} of brane {} can not lead",
+                to, brane_id
+            )));
+        }
+        let peer = self.peers.get_mut(&brane_id).unwrap();
+        Ok(peer.raw_node.transfer_leader(to.id)?)
+    }
+
+    fn check_leader(&self, brane_id: u64) -> Result<()> {
+        match self.peers.get(&brane_id) {
+            Some(peer) if peer.is_leader() => Ok(()),
+            Some(peer) => Err(Error::NotLeader(brane_id, peer.leader())),
+            None => Err(Error::BraneNotFound(brane_id)),
+        }
+    }
+
+    /// Starts an election in the brane now.
+    pub fn campaign(&mut self, brane_id: u64) -> Result<()> {
+        match self.peers.get_mut(&brane_id) {
+            Some(peer) => Ok(peer.raw_node.campaign()?),
+            None => Err(Error::BraneNotFound(brane_id)),
+        }
+    }
+
+    fn send(&mut self, msg: VioletaBFTMessage) {
+        let _ = self.trans.send(msg);
+    }
+
+    fn send_tombstone(&mut self, brane: &Brane, from: Peer, to: Peer) {
+        self.send(VioletaBFTMessage {
+            brane_id: brane.id,
+            from_peer: from,
+            to_peer: to,
+            brane_epoch: brane.brane_epoch,
+            is_tombstone: true,
+            ..Default::default()
+        });
+    }
+
+    /// Steps a message from a peer of another store, creating the receiving peer
+    /// if it is new.
+    pub fn step(&mut self, msg: VioletaBFTMessage) -> Result<()> {
+        if msg.to_peer.store_id != self.id {
+            return Err(Error::Other(format!(
+                "message for store {} sent to store {}",
+                msg.to_peer.store_id, self.id
+            )));
+        }
+        let brane_id = msg.brane_id;
+        if msg.is_tombstone {
+            let stale = self.peers.get(&brane_id).is_some_and(|p| {
+                p.peer.id == msg.to_peer.id
+                    && p.brane().brane_epoch.conf_ver <= msg.brane_epoch.conf_ver
+            });
+            if stale {
+                self.destroy_peer(brane_id, false)?;
+            }
+            return Ok(());
+        }
+        if !self.peers.contains_key(&brane_id) && !self.maybe_create_peer(&msg)? {
+            return Ok(());
+        }
+        let peer = &self.peers[&brane_id];
+        if peer.peer.id != msg.to_peer.id {
+            return Ok(());
+        }
+        let brane = peer.brane();
+        if peer.is_initialized()
+            && brane.peer(msg.from_peer.id).is_none()
+            && msg.brane_epoch.conf_ver < brane.brane_epoch.conf_ver
+        {
+            // A removed peer that does not know it yet.
+            let (brane, from) = (brane.clone(), peer.peer);
+            self.send_tombstone(&brane, from, msg.from_peer);
+            return Ok(());
+        }
+        if msg.message.msg_type == MessageType::Snapshot && !self.check_snapshot(&msg)? {
+            return Ok(());
+        }
+        let peer = self.peers.get_mut(&brane_id).unwrap();
+        peer.cache_peer(msg.from_peer);
+        // Responses from peers the node does not know any more are ignored.
+        let _ = peer.raw_node.step(msg.message);
+        Ok(())
+    }
+
+    /// Creates the peer `msg` is for, unless it was removed already or its brane
+    /// overlaps one here, which it will be split from.
+    fn maybe_create_peer(&mut self, msg: &VioletaBFTMessage) -> Result<bool> {
+        if !matches!(
+            msg.message.msg_type,
+            MessageType::Append | MessageType::Heartbeat | MessageType::Snapshot
+        ) {
+            return Ok(false);
+        }
+        if let Some(state) = load_brane_state(&self.kv, msg.brane_id)? {
+            let removed = state
+                .brane
+                .peer_on_store(self.id)
+                .is_some_and(|p| p.id >= msg.to_peer.id);
+            if removed || msg.brane_epoch.is_stale(&state.brane.brane_epoch) {
+                return Ok(false);
+            }
+        }
+        if !self
+            .overlapping_branes(&msg.start_key, &msg.end_key)
+            .is_empty()
+        {
+            return Ok(false);
+        }
+        let storage = PeerStorage::uninitialized(self.kv.clone(), self.log.clone(), msg.brane_id)?;
+        let peer = BranePeer::new(&self.cfg, msg.to_peer, storage)?;
+        self.peers.insert(msg.brane_id, peer);
+        Ok(true)
+    }
+
+    /// Whether the snapshot `msg` carries can be applied: its range must not
+    /// overlap another brane here.
+    fn check_snapshot(&self, msg: &VioletaBFTMessage) -> Result<bool> {
+        let snapshot = match &msg.message.snapshot {
+            Some(snapshot) => snapshot,
+            None => return Ok(false),
+        };
+        let state = BraneLocalState::decode_from(&mut snapshot.data.as_slice())?;
+        let brane = &state.brane;
+        Ok(self
+            .overlapping_branes(&brane.start_key, &brane.end_key)
+            .into_iter()
+            .all(|id| id == msg.brane_id))
+    }
+
+    /// Removes the peer of the brane, deleting its data unless `keep_data`.
+    fn destroy_peer(&mut self, brane_id: u64, keep_data: bool) -> Result<()> {
+        let mut peer = match self.peers.remove(&brane_id) {
+            Some(peer) => peer,
+            None => return Ok(()),
+        };
+        peer.clear_proposals();
+        let mut wb = self.kv.write_alexandrov_poset_process();
+        let mut batch = self.log.log_alexandrov_poset_process(0);
+        peer.storage_mut().clear(&mut wb, &mut batch, keep_data)?;
+        let mut opts = WriteOptions::default();
+        opts.set_sync(true);
+        wb.write_opt(&opts)?;
+        self.log.consume(&mut batch, self.cfg.sync_log)?;
+        let end = keys::data_end_key(&peer.brane().end_key);
+        if peer.is_initialized() && self.brane_ranges.get(&end) == Some(&brane_id) {
+            self.brane_ranges.remove(&end);
+        }
+        self.notify_brane_changed(brane_id);
+        Ok(())
+    }
+
+    /// Ticks every peer, and checks for splits and log compactions when it is
+    /// time to.
+    pub fn tick(&mut self) -> Result<()> {
+        self.ticks += 1;
+        for peer in self.peers.values_mut() {
+            peer.raw_node.tick();
+        }
+        if self.ticks.is_multiple_of(self.cfg.split_check_ticks) {
+            self.check_split()?;
+        }
+        if self.ticks.is_multiple_of(self.cfg.log_gc_ticks) {
+            self.check_log_gc()?;
+        }
+        Ok(())
+    }
+
+    /// The size of the data of `brane` in every causet_merge family, as the range
+    /// greedoids estimate it, and the largest family.
+    fn approximate_size(&self, brane: &Brane) -> Result<(u64, String)> {
+        let start = keys::data_key(&brane.start_key);
+        let end = keys::data_end_key(&brane.end_key);
+        let mut total = 0;
+        let mut largest = (0, NAMESPACED_DEFAULT.to_owned());
+        for namespaced in self.kv.namespaced_names() {
+            let size = self
+                .kv
+                .get_range_approximate_size_namespaced(&namespaced, &start, &end)?;
+            total += size;
+            if size > largest.0 {
+                largest = (size, namespaced);
+            }
+        }
+        Ok((total, largest.1))
+    }
+
+    /// The size of the data of the brane, as the range greedoids estimate it.
+    pub fn brane_approximate_size(&self, brane_id: u64) -> Result<u64> {
+        match self.peers.get(&brane_id) {
+            Some(peer) if peer.is_initialized() => Ok(self.approximate_size(peer.brane())?.0),
+            _ => Err(Error::BraneNotFound(brane_id)),
+        }
+    }
+
+    /// The exact size of the data of the brane, counted up to `limit`.
+    pub fn brane_size(&self, brane_id: u64, limit: u64) -> Result<u64> {
+        let brane = match self.peers.get(&brane_id) {
+            Some(peer) if peer.is_initialized() => peer.brane(),
+            _ => return Err(Error::BraneNotFound(brane_id)),
+        };
+        let start = keys::data_key(&brane.start_key);
+        let end = keys::data_end_key(&brane.end_key);
+        let mut size = 0;
+        for namespaced in self.kv.namespaced_names() {
+            self.kv
+                .scan_namespaced(&namespaced, &start, &end, false, |k, v| {
+                    size += (k.len() + v.len()) as u64;
+                    Ok(size <= limit)
+                })?;
+            if size > limit {
+                break;
+            }
+        }
+        Ok(size)
+    }
+
+    /// Proposes to split the branes led here that outgrew `brane_max_size`, in
+    /// the middle of their largest causet_merge family.
+    fn check_split(&mut self) -> Result<()> {
+        let mut splits = Vec::new();
+        for peer in self.peers.values() {
+            if !peer.is_leader() || peer.storage().local_state().state != PeerState::Normal {
+                continue;
+            }
+            let brane = peer.brane();
+            let (size, namespaced) = self.approximate_size(brane)?;
+            if size <= self.cfg.brane_max_size {
+                continue;
+            }
+            let split_keys = self.kv.get_range_approximate_split_soliton_ids_namespaced(
+                &namespaced,
+                &keys::data_key(&brane.start_key),
+                &keys::data_end_key(&brane.end_key),
+                1,
+            )?;
+            let split_key = match split_keys.first() {
+                Some(k) if k.first() == Some(&keys::DATA_PREFIX) => keys::origin_key(k).to_vec(),
+                _ => continue,
+            };
+            if brane.contains(&split_key) && split_key != brane.start_key {
+                splits.push((brane.id, split_key, brane.peers.len()));
+            }
+        }
+        for (brane_id, split_key, peer_count) in splits {
+            let new_brane_id = self.id_allocator.alloc_id()?;
+            let new_peer_ids = (0..peer_count)
+                .map(|_| self.id_allocator.alloc_id())
+                .collect::<Result<_>>()?;
+            self.propose_admin(
+                brane_id,
+                AdminRequest::Split {
+                    split_key,
+                    new_brane_id,
+                    new_peer_ids,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Proposes to compact the logs led here up to the entry every peer has, or
+    /// up to the applied one if that leaves too many, or if the log
+    /// einstein_merkle_tree needs the files they pin.
+    fn check_log_gc(&mut self) -> Result<()> {
+        let forced = self.log.purge_expired_files()?;
+        let mut compactions = Vec::new();
+        for (&brane_id, peer) in &self.peers {
+            if !peer.is_leader() {
+                continue;
+            }
+            let storage = peer.storage();
+            let applied = storage.applied_index();
+            let truncated = storage.apply_state().truncated_index;
+            if applied <= truncated {
+                continue;
+            }
+            let force = forced.contains(&brane_id);
+            let compact_index = if force || applied - truncated > self.cfg.log_gc_count_limit {
+                applied
+            } else {
+                let min_matched = peer
+                    .raw_node
+                    .violetabft
+                    .prs
+                    .progress
+                    .values()
+                    .map(|pr| pr.matched)
+                    .min()
+                    .unwrap_or(0);
+                min_matched.min(applied)
+            };
+            if compact_index <= truncated
+                || (!force && compact_index - truncated < self.cfg.log_gc_threshold)
+            {
+                continue;
+            }
+            let compact_term = storage.term(compact_index)?;
+            compactions.push((brane_id, compact_index, compact_term));
+        }
+        for (brane_id, compact_index, compact_term) in compactions {
+            self.propose_admin(
+                brane_id,
+                AdminRequest::CompactLog {
+                    compact_index,
+                    compact_term,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Persists, sends and applies what the peers have ready. Returns whether
+    /// there was anything.
+    pub fn handle_ready(&mut self) -> Result<bool> {
+        let ids: Vec<u64> = self
+            .peers
+            .iter()
+            .filter(|(_, p)| p.raw_node.has_ready())
+            .map(|(&id, _)| id)
+            .collect();
+        let mut batch = self.log.log_alexandrov_poset_process(ids.len());
+        let mut readies: Vec<(u64, Ready)> = Vec::with_capacity(ids.len());
+        let mut snapshotted = Vec::new();
+        for id in ids {
+            if let Some((source_id, commit)) = self.peers[&id].wait_merge_source {
+                let ready = self
+                    .peers
+                    .get(&source_id)
+                    .is_none_or(|p| p.storage().applied_index() >= commit);
+                if !ready {
+                    continue;
+                }
+                self.peers.get_mut(&id).unwrap().wait_merge_source = None;
+            }
+            let peer = self.peers.get_mut(&id).unwrap();
+            let mut rd = peer.raw_node.ready();
+            if rd.soft_state.is_some() {
+                peer.fail_unconfirmed_reads();
+            }
+            peer.on_read_states(mem::take(&mut rd.read_states));
+            let raft_state = *peer.storage().raft_state();
+            if let Some(snapshot) = &rd.snapshot {
+                let old_end = peer.is_initialized().then(|| peer.brane().end_key.clone());
+                let witness = peer.peer.is_witness;
+                peer.storage_mut()
+                    .apply_snapshot(snapshot, &mut batch, witness)?;
+                let brane = peer.brane().clone();
+                if let Some(p) = brane.peer(peer.peer.id) {
+                    peer.peer = p;
+                }
+                self.update_range(old_end.as_deref(), &brane);
+                snapshotted.push(id);
+            }
+            let peer = self.peers.get_mut(&id).unwrap();
+            let storage = peer.storage_mut();
+            storage.append(&rd.entries, &mut batch)?;
+            if let Some(hs) = rd.hard_state {
+                storage.set_hard_state(hs);
+            }
+            if *storage.raft_state() != raft_state || rd.snapshot.is_some() {
+                batch.put_violetabft_state(id, storage.raft_state())?;
+            }
+            readies.push((id, rd));
+        }
+        if readies.is_empty() {
+            return Ok(false);
+        }
+        if !batch.is_empty() {
+            self.log.consume(&mut batch, self.cfg.sync_log)?;
+        }
+        for id in snapshotted {
+            self.notify_brane_changed(id);
+        }
+
+        for (id, rd) in &mut readies {
+            let messages = mem::take(&mut rd.messages);
+            self.send_messages(*id, messages);
+        }
+
+        let mut ctx = ApplyContext::new(&self.kv, !self.observers.is_empty());
+        let mut results = Vec::new();
+        for (id, rd) in &mut readies {
+            let mut peer = self.peers.remove(id).unwrap();
+            let entries = mem::take(&mut rd.committed_entries);
+            let mut applied = Vec::with_capacity(entries.len());
+            for e in entries {
+                match apply_entry(&mut ctx, &self.peers, &mut peer, &e)? {
+                    ApplyOutcome::Applied(exec) => {
+                        applied.push(e);
+                        if let Some(exec) = exec {
+                            let destroyed = matches!(exec, ExecResult::Destroy);
+                            results.push((*id, exec));
+                            if destroyed {
+                                break;
+                            }
+                        }
+                    }
+                    ApplyOutcome::WaitMergeSource(source_id, commit) => {
+                        peer.wait_merge_source = Some((source_id, commit));
+                        break;
+                    }
+                }
+            }
+            if let Some(last) = applied.last() {
+                let apply_state = peer.storage_mut().apply_state_mut();
+                apply_state.applied_index = last.index;
+                let apply_state = *apply_state;
+                ctx.wb()
+                    .put(&keys::apply_state_key(*id), &apply_state.encode())?;
+            }
+            rd.committed_entries = applied;
+            self.peers.insert(*id, peer);
+        }
+        ctx.finish(self.id, &self.observers)?;
+
+        let mut gone = Vec::new();
+        for (id, exec) in results {
+            self.on_exec_result(id, exec, &mut gone)?;
+        }
+        for (id, rd) in readies {
+            if gone.contains(&id) {
+                continue;
+            }
+            if let Some(peer) = self.peers.get_mut(&id) {
+                peer.raw_node.advance(rd);
+                for (req, cb) in peer.take_ready_reads() {
+                    cb(exec_read(&self.kv, peer, req));
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn send_messages(&mut self, brane_id: u64, messages: Vec<violetabft::Message>) {
+        for m in messages {
+            let peer = &self.peers[&brane_id];
+            let to_peer = match peer.get_peer(m.to) {
+                Some(p) => p,
+                None => continue,
+            };
+            let brane = peer.brane();
+            let is_snapshot = m.msg_type == MessageType::Snapshot;
+            let msg = VioletaBFTMessage {
+                brane_id,
+                from_peer: peer.peer,
+                to_peer,
+                brane_epoch: brane.brane_epoch,
+                start_key: brane.start_key.clone(),
+                end_key: brane.end_key.clone(),
+                message: m,
+                is_tombstone: false,
+            };
+            let delivered = self.trans.send(msg).is_ok();
+            let peer = self.peers.get_mut(&brane_id).unwrap();
+            if !delivered {
+                peer.raw_node.report_unreachable(to_peer.id);
+            }
+            if is_snapshot {
+                peer.raw_node.report_snapshot(to_peer.id, delivered);
+            }
+        }
+    }
+
+    /// Does what applying a command of brane `id` asks of the store. The peers
+    /// destroyed or replaced are added to `gone`.
+    fn on_exec_result(&mut self, id: u64, exec: ExecResult, gone: &mut Vec<u64>) -> Result<()> {
+        match exec {
+            ExecResult::Split { old_end, right } => {
+                let left = self.peers[&id].brane().clone();
+                let was_leader = self.peers[&id].is_leader();
+                let closed_ts = self.peers[&id].closed_ts;
+                self.update_range(Some(&old_end), &left);
+                self.notify_brane_changed(id);
+                if let Some(right) = right {
+                    self.create_split_peer(right, was_leader, closed_ts, gone)?;
+                }
+            }
+            ExecResult::CompactLog { to } => {
+                // The applied data must be durable before the log is.
+                self.kv.sync_wal()?;
+                self.log.gc(id, 0, to + 1)?;
+            }
+            ExecResult::CommitMerge { old_end, source } => {
+                // The source may be closed at an earlier timestamp.
+                let source_closed_ts = self.peers.get(&source.id).map_or(0, |p| p.closed_ts);
+                let target = self.peers.get_mut(&id).unwrap();
+                target.closed_ts = target.closed_ts.min(source_closed_ts);
+                if self.peers.contains_key(&source.id) {
+                    self.destroy_peer(source.id, true)?;
+                    gone.push(source.id);
+                }
+                let brane = self.peers[&id].brane().clone();
+                self.update_range(Some(&old_end), &brane);
+                self.notify_brane_changed(id);
+            }
+            ExecResult::Destroy => {
+                self.destroy_peer(id, false)?;
+                gone.push(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the peer of the new brane of a split, closed at the timestamp of
+    /// the brane it was split from.
+    fn create_split_peer(
+        &mut self,
+        right: Brane,
+        campaign: bool,
+        closed_ts: u64,
+        gone: &mut Vec<u64>,
+    ) -> Result<()> {
+        let peer = match right.peer_on_store(self.id) {
+            Some(peer) => peer,
+            None => return Ok(()),
+        };
+        let mut raft_state = init_raft_state();
+        if let Some(existing) = self.peers.remove(&right.id) {
+            // Created by a message of the new brane before the split was applied
+            // here: it must not vote twice in a term.
+            let hs = existing.storage().raft_state().hard_state;
+            if hs.term > raft_state.hard_state.term {
+                raft_state.hard_state.term = hs.term;
+                raft_state.hard_state.vote = hs.vote;
+            }
+            gone.push(right.id);
+        }
+        self.log.put_violetabft_state(right.id, &raft_state)?;
+        let local_state =
+            load_brane_state(&self.kv, right.id)?.ok_or(Error::BraneNotFound(right.id))?;
+        let storage = PeerStorage::load(self.kv.clone(), self.log.clone(), local_state)?;
+        let mut new_peer = BranePeer::new(&self.cfg, peer, storage)?;
+        new_peer.closed_ts = closed_ts;
+        if campaign {
+            // The leader of the parent is likely to be elected; it saves the
+            // election timeout.
+            new_peer.raw_node.campaign()?;
+        }
+        self.update_range(None, &right);
+        self.peers.insert(right.id, new_peer);
+        Ok(())
+    }
+}
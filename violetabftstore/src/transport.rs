@@ -0,0 +1,32 @@
+// Copyright 2022 EinsteinDB Project Authors. Licensed under Apache-2.0.
+
+use violetabft::Message;
+
+use crate::brane::{BraneEpoch, Peer};
+use crate::errors::Result;
+
+/// A VioletaBFT message between two peers of a brane, with what the receiving
+/// store needs to create the peer if it has none, or to tell the sender it is
+/// stale.
+#[derive(Clone, Debug, Default)]
+pub struct VioletaBFTMessage {
+    pub brane_id: u64,
+    pub from_peer: Peer,
+    pub to_peer: Peer,
+    /// The epoch and range of the brane, as the sender knows it.
+    pub brane_epoch: BraneEpoch,
+    pub start_key: Vec<u8>,
+    pub end_key: Vec<u8>,
+    pub message: Message,
+    /// Tells `to_peer` that it was removed from the brane and should destroy
+    /// itself; `message` is empty.
+    pub is_tombstone: bool,
+}
+
+/// Delivers messages to the stores of their `to_peer`.
+///
+/// A transport that knows the message can not be delivered returns an error, so
+/// that the sender stops streaming to the peer.
+pub trait Transport {
+    fn send(&mut self, msg: VioletaBFTMessage) -> Result<()>;
+}